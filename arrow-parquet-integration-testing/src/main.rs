@@ -173,6 +173,7 @@ fn main() -> Result<()> {
         compression: args.compression.into(),
         version: args.version.into(),
         data_pagesize_limit: None,
+        int96_timestamps: false,
     };
 
     let encodings = schema
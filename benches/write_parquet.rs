@@ -18,6 +18,7 @@ fn write(array: &dyn Array, encoding: Encoding) -> Result<()> {
         compression: CompressionOptions::Uncompressed,
         version: Version::V1,
         data_pagesize_limit: None,
+        int96_timestamps: false,
     };
 
     let row_groups = RowGroupIterator::try_new(
@@ -36,7 +36,7 @@ fn main() -> Result<()> {
 fn write_ipc<W: Write + Seek>(writer: W, array: impl Array + 'static) -> Result<W> {
     let schema = vec![Field::new("a", array.data_type().clone(), false)].into();
 
-    let options = write::WriteOptions { compression: None };
+    let options = write::WriteOptions::default();
     let mut writer = write::FileWriter::new(writer, schema, None, options);
 
     let batch = Chunk::try_new(vec![Box::new(array) as Box<dyn Array>])?;
@@ -26,7 +26,7 @@ fn write(
     compression: Option<arrow2::io::ipc::write::Compression>,
 ) -> Result<Vec<u8>, Error> {
     let result = vec![];
-    let options = arrow2::io::ipc::write::WriteOptions { compression };
+    let options = arrow2::io::ipc::write::WriteOptions::default().with_compression(compression);
     let mut writer = arrow2::io::ipc::write::FileWriter::try_new(
         result,
         schema.clone(),
@@ -9,7 +9,7 @@ use arrow2::io::ipc::write;
 fn write_batches(path: &str, schema: Schema, chunks: &[Chunk<Box<dyn Array>>]) -> Result<()> {
     let file = File::create(path)?;
 
-    let options = write::WriteOptions { compression: None };
+    let options = write::WriteOptions::default();
     let mut writer = write::FileWriter::new(file, schema, None, options);
 
     writer.start()?;
@@ -23,6 +23,8 @@ fn main() -> Result<()> {
                     println!("batch: {:?}", idx)
                 }
                 Ok(read::StreamState::Waiting) => thread::sleep(Duration::from_millis(2000)),
+                Ok(read::StreamState::NewSchema(schema)) => println!("schema: {:?}", schema),
+                Ok(_) => (),
                 Err(l) => println!("{:?} ({})", l, idx),
             },
             None => break,
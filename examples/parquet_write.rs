@@ -17,6 +17,7 @@ fn write_chunk(path: &str, schema: Schema, chunk: Chunk<Box<dyn Array>>) -> Resu
         compression: CompressionOptions::Uncompressed,
         version: Version::V2,
         data_pagesize_limit: None,
+        int96_timestamps: false,
     };
 
     let iter = vec![Ok(chunk)];
@@ -18,6 +18,7 @@ async fn write_batch(path: &str, schema: Schema, columns: Chunk<Box<dyn Array>>)
         compression: CompressionOptions::Uncompressed,
         version: Version::V2,
         data_pagesize_limit: None,
+        int96_timestamps: false,
     };
 
     let mut stream = futures::stream::iter(vec![Ok(columns)].into_iter());
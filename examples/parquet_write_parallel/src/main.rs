@@ -48,6 +48,7 @@ fn parallel_write(path: &str, schema: Schema, chunks: &[Chunk]) -> Result<()> {
         compression: CompressionOptions::Snappy,
         version: Version::V2,
         data_pagesize_limit: None,
+        int96_timestamps: false,
     };
 
     let encoding_map = |data_type: &DataType| {
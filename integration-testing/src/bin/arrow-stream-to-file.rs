@@ -28,7 +28,7 @@ fn main() -> Result<()> {
 
     let writer = io::stdout();
 
-    let options = write::WriteOptions { compression: None };
+    let options = write::WriteOptions::default();
     let mut writer = write::FileWriter::try_new(
         writer,
         metadata.schema.clone(),
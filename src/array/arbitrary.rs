@@ -0,0 +1,320 @@
+//! [`proptest`] strategies of arrays of any [`DataType`].
+//!
+//! The arrays are built from strategies of their values, so that failing cases shrink
+//! towards shorter arrays with simpler values:
+//! ```
+//! use arrow2::array::Array;
+//! use proptest::prelude::*;
+//!
+//! proptest! {
+//!     fn slice_has_length(array in any::<Box<dyn Array>>(), length in 0..10usize) {
+//!         let length = length.min(array.len());
+//!         prop_assert_eq!(array.slice(0, length).len(), length);
+//!     }
+//! }
+//! # slice_has_length();
+//! ```
+use core::ops::Range;
+
+use proptest::collection::vec;
+use proptest::prelude::*;
+
+use crate::bitmap::Bitmap;
+use crate::datatypes::{DataType, Field, IntervalUnit, UnionMode};
+use crate::offset::{Offset, Offsets};
+use crate::types::{days_ms, f16, i256, months_days_ns, NativeType};
+use crate::util::value_ranges::{self, time_unit_factor};
+
+use super::*;
+
+/// The maximum number of items of the lists and maps, and of the values of the dictionaries
+const MAX_LENGTH: usize = 4;
+
+type ArrayStrategy = BoxedStrategy<Box<dyn Array>>;
+
+fn validity(length: usize, is_nullable: bool) -> BoxedStrategy<Option<Bitmap>> {
+    if is_nullable {
+        proptest::option::of(vec(proptest::bool::weighted(0.8), length))
+            .prop_map(|validity| validity.map(Bitmap::from_iter))
+            .boxed()
+    } else {
+        Just(None).boxed()
+    }
+}
+
+fn primitive<T: NativeType>(
+    data_type: DataType,
+    length: usize,
+    is_nullable: bool,
+    values: impl Strategy<Value = T> + 'static,
+) -> ArrayStrategy {
+    (vec(values, length), validity(length, is_nullable))
+        .prop_map(move |(values, validity)| {
+            PrimitiveArray::<T>::new(data_type.clone(), values.into(), validity).boxed()
+        })
+        .boxed()
+}
+
+fn utf8<O: Offset>(data_type: DataType, length: usize, is_nullable: bool) -> ArrayStrategy {
+    let values = vec(any::<char>(), 0..MAX_LENGTH).prop_map(String::from_iter);
+    (vec(values, length), validity(length, is_nullable))
+        .prop_map(move |(values, validity)| {
+            let offsets = Offsets::<O>::try_from_lengths(values.iter().map(|x| x.len())).unwrap();
+            let values = values.concat().into_bytes();
+            Utf8Array::<O>::new(data_type.clone(), offsets.into(), values.into(), validity).boxed()
+        })
+        .boxed()
+}
+
+fn binary<O: Offset>(data_type: DataType, length: usize, is_nullable: bool) -> ArrayStrategy {
+    let values = vec(any::<u8>(), 0..MAX_LENGTH);
+    (vec(values, length), validity(length, is_nullable))
+        .prop_map(move |(values, validity)| {
+            let offsets = Offsets::<O>::try_from_lengths(values.iter().map(|x| x.len())).unwrap();
+            let values = values.concat();
+            BinaryArray::<O>::new(data_type.clone(), offsets.into(), values.into(), validity)
+                .boxed()
+        })
+        .boxed()
+}
+
+fn list<O: Offset>(data_type: DataType, length: usize, is_nullable: bool) -> ArrayStrategy {
+    let child = ListArray::<O>::get_child_field(&data_type).clone();
+    (vec(0..MAX_LENGTH, length), validity(length, is_nullable))
+        .prop_flat_map(move |(lengths, validity)| {
+            let offsets = Offsets::<O>::try_from_lengths(lengths.into_iter()).unwrap();
+            let values = array_of_length(
+                &child.data_type,
+                offsets.last().to_usize(),
+                child.is_nullable,
+            );
+            let data_type = data_type.clone();
+            values.prop_map(move |values| {
+                ListArray::<O>::new(
+                    data_type.clone(),
+                    offsets.clone().into(),
+                    values,
+                    validity.clone(),
+                )
+                .boxed()
+            })
+        })
+        .boxed()
+}
+
+fn dictionary<K: DictionaryKey>(
+    data_type: DataType,
+    values_type: DataType,
+    length: usize,
+    is_nullable: bool,
+) -> ArrayStrategy {
+    (1..=MAX_LENGTH)
+        .prop_flat_map(move |values_length| {
+            let keys = vec(0..values_length, length).prop_map(|keys| {
+                keys.into_iter()
+                    .map(|x| K::try_from(x).ok().unwrap())
+                    .collect::<Vec<_>>()
+            });
+            (
+                keys,
+                validity(length, is_nullable),
+                array_of_length(&values_type, values_length, false),
+            )
+        })
+        .prop_map(move |(keys, validity, values)| {
+            let keys = PrimitiveArray::<K>::new(K::PRIMITIVE.into(), keys.into(), validity);
+            DictionaryArray::<K>::try_new(data_type.clone(), keys, values)
+                .unwrap()
+                .boxed()
+        })
+        .boxed()
+}
+
+fn union(
+    data_type: DataType,
+    fields: Vec<Field>,
+    ids: Option<Vec<i32>>,
+    mode: UnionMode,
+    length: usize,
+) -> ArrayStrategy {
+    vec(0..fields.len(), length)
+        .prop_flat_map(move |types| {
+            let values = fields
+                .iter()
+                .enumerate()
+                .map(|(i, field)| {
+                    let length = match mode {
+                        UnionMode::Sparse => types.len(),
+                        UnionMode::Dense => types.iter().filter(|x| **x == i).count(),
+                    };
+                    array_of_length(&field.data_type, length, field.is_nullable)
+                })
+                .collect::<Vec<_>>();
+            let offsets = mode.is_dense().then(|| {
+                let mut next = vec![0i32; fields.len()];
+                types
+                    .iter()
+                    .map(|x| {
+                        next[*x] += 1;
+                        next[*x] - 1
+                    })
+                    .collect::<Vec<_>>()
+            });
+            let types = types
+                .iter()
+                .map(|x| ids.as_ref().map_or(*x as i8, |ids| ids[*x] as i8))
+                .collect::<Vec<_>>();
+            let data_type = data_type.clone();
+            values.prop_map(move |values| {
+                UnionArray::new(
+                    data_type.clone(),
+                    types.clone().into(),
+                    values,
+                    offsets.clone().map(|x| x.into()),
+                )
+                .boxed()
+            })
+        })
+        .boxed()
+}
+
+/// Returns a [`Strategy`] of arrays of `data_type` and `length`, with nulls only if `is_nullable`
+fn array_of_length(data_type: &DataType, length: usize, is_nullable: bool) -> ArrayStrategy {
+    use DataType::*;
+    let dt = data_type.clone();
+    let (len, nullable) = (length, is_nullable);
+    match data_type.to_logical_type() {
+        Null => Just(NullArray::new(dt, length).boxed()).boxed(),
+        Boolean => (vec(any::<bool>(), length), validity(length, is_nullable))
+            .prop_map(move |(values, validity)| {
+                BooleanArray::new(dt.clone(), values.into(), validity).boxed()
+            })
+            .boxed(),
+        Int8 => primitive(dt, len, nullable, any::<i8>()),
+        Int16 => primitive(dt, len, nullable, any::<i16>()),
+        Int32 => primitive(dt, len, nullable, any::<i32>()),
+        Int64 | Duration(_) => primitive(dt, len, nullable, any::<i64>()),
+        UInt8 => primitive(dt, len, nullable, any::<u8>()),
+        UInt16 => primitive(dt, len, nullable, any::<u16>()),
+        UInt32 => primitive(dt, len, nullable, any::<u32>()),
+        UInt64 => primitive(dt, len, nullable, any::<u64>()),
+        // finite floats, so that arrays equal themselves
+        Float16 => primitive(dt, len, nullable, (-1e4f32..1e4).prop_map(f16::from_f32)),
+        Float32 => primitive(dt, len, nullable, -1e9f32..1e9),
+        Float64 => primitive(dt, len, nullable, -1e9f64..1e9),
+        // dates and timestamps between the years 1900 and 2100
+        Date32 => primitive(dt, len, nullable, value_ranges::DAYS),
+        Date64 => primitive(
+            dt,
+            len,
+            nullable,
+            value_ranges::DAYS.prop_map(|x| x as i64 * 86_400_000),
+        ),
+        Timestamp(unit, _) => {
+            let factor = time_unit_factor(*unit);
+            let values = value_ranges::SECONDS.prop_map(move |x| x * factor);
+            primitive(dt, len, nullable, values)
+        }
+        Time32(unit) => {
+            let max = 86_400 * time_unit_factor(*unit) as i32;
+            primitive(dt, len, nullable, 0..max)
+        }
+        Time64(unit) => primitive(dt, len, nullable, 0..86_400 * time_unit_factor(*unit)),
+        Interval(IntervalUnit::YearMonth) => primitive(dt, len, nullable, -1200..1200i32),
+        Interval(IntervalUnit::DayTime) => {
+            let values =
+                (-36500..36500i32, 0..86_400_000i32).prop_map(|(days, ms)| days_ms::new(days, ms));
+            primitive(dt, len, nullable, values)
+        }
+        Interval(IntervalUnit::MonthDayNano) => {
+            let values = (-1200..1200i32, -36500..36500i32, 0..86_400_000_000_000i64)
+                .prop_map(|(months, days, ns)| months_days_ns::new(months, days, ns));
+            primitive(dt, len, nullable, values)
+        }
+        Decimal(precision, _) => primitive(dt, len, nullable, value_ranges::decimal(*precision)),
+        Decimal256(precision, _) => primitive(
+            dt,
+            len,
+            nullable,
+            value_ranges::decimal(*precision).prop_map(i256::from),
+        ),
+        Binary => binary::<i32>(dt, len, nullable),
+        LargeBinary => binary::<i64>(dt, len, nullable),
+        Utf8 => utf8::<i32>(dt, len, nullable),
+        LargeUtf8 => utf8::<i64>(dt, len, nullable),
+        FixedSizeBinary(size) => (
+            vec(any::<u8>(), length * size),
+            validity(length, is_nullable),
+        )
+            .prop_map(move |(values, validity)| {
+                FixedSizeBinaryArray::new(dt.clone(), values.into(), validity).boxed()
+            })
+            .boxed(),
+        List(_) => list::<i32>(dt, len, nullable),
+        LargeList(_) => list::<i64>(dt, len, nullable),
+        FixedSizeList(child, size) => (
+            array_of_length(&child.data_type, length * size, child.is_nullable),
+            validity(length, is_nullable),
+        )
+            .prop_map(move |(values, validity)| {
+                FixedSizeListArray::new(dt.clone(), values, validity).boxed()
+            })
+            .boxed(),
+        Struct(fields) => {
+            let values = fields
+                .iter()
+                .map(|field| array_of_length(&field.data_type, length, field.is_nullable))
+                .collect::<Vec<_>>();
+            (values, validity(length, is_nullable))
+                .prop_map(move |(values, validity)| {
+                    StructArray::new(dt.clone(), values, validity).boxed()
+                })
+                .boxed()
+        }
+        Map(field, _) => {
+            let field = field.clone();
+            (vec(0..MAX_LENGTH, length), validity(length, is_nullable))
+                .prop_flat_map(move |(lengths, validity)| {
+                    let offsets = Offsets::<i32>::try_from_lengths(lengths.into_iter()).unwrap();
+                    // the entries of a map are never null
+                    let entries =
+                        array_of_length(&field.data_type, *offsets.last() as usize, false);
+                    let data_type = dt.clone();
+                    entries.prop_map(move |entries| {
+                        MapArray::new(
+                            data_type.clone(),
+                            offsets.clone().into(),
+                            entries,
+                            validity.clone(),
+                        )
+                        .boxed()
+                    })
+                })
+                .boxed()
+        }
+        Union(fields, ids, mode) => union(dt, fields.clone(), ids.clone(), *mode, length),
+        Dictionary(key_type, values_type, _) => match_integer_type!(key_type, |$T| {
+            dictionary::<$T>(dt, values_type.as_ref().clone(), length, is_nullable)
+        }),
+        Extension(_, _, _) => unreachable!(),
+    }
+}
+
+/// Returns a [`Strategy`] of arrays of `data_type` whose length is within `length`.
+///
+/// The values are valid for `data_type`: e.g. decimals fit in their precision, floats are finite,
+/// and children of nested arrays have nulls only if their field is nullable.
+pub fn array(data_type: DataType, length: Range<usize>) -> impl Strategy<Value = Box<dyn Array>> {
+    length.prop_flat_map(move |length| array_of_length(&data_type, length, true))
+}
+
+impl Arbitrary for Box<dyn Array> {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        any::<DataType>()
+            .prop_flat_map(|data_type| array(data_type, 0..16))
+            .boxed()
+    }
+}
@@ -1,3 +1,5 @@
+use alloc::{boxed::Box, vec::Vec};
+
 use crate::{
     bitmap::{
         utils::{BitmapIter, ZipValidity},
@@ -5,15 +7,21 @@ use crate::{
     },
     buffer::Buffer,
     datatypes::DataType,
-    error::Error,
+    error::{Error, Result},
     offset::{Offset, Offsets, OffsetsBuffer},
     trusted_len::TrustedLen,
 };
 
 use either::Either;
 
-use super::{specification::try_check_offsets_bounds, Array, GenericBinaryArray};
+use super::{
+    bitmap_to_bytes, buffer_to_bytes,
+    specification::try_check_offsets_bounds,
+    validation::{validate_offsets, validate_validity},
+    Array, ArrayValidation, GenericBinaryArray,
+};
 
+#[cfg(feature = "std")]
 mod ffi;
 pub(super) mod fmt;
 mod iterator;
@@ -77,7 +85,7 @@ impl<O: Offset> BinaryArray<O> {
         offsets: OffsetsBuffer<O>,
         values: Buffer<u8>,
         validity: Option<Bitmap>,
-    ) -> Result<Self, Error> {
+    ) -> Result<Self> {
         try_check_offsets_bounds(&offsets, values.len())?;
 
         if validity
@@ -115,12 +123,12 @@ impl<O: Offset> BinaryArray<O> {
     }
 
     /// Returns an iterator of `Option<&[u8]>` over every element of this array.
-    pub fn iter(&self) -> ZipValidity<&[u8], BinaryValueIter<O>, BitmapIter> {
+    pub fn iter(&self) -> ZipValidity<&[u8], BinaryValueIter<'_, O>, BitmapIter<'_>> {
         ZipValidity::new_with_validity(self.values_iter(), self.validity.as_ref())
     }
 
     /// Returns an iterator of `&[u8]` over every element of this array, ignoring the validity
-    pub fn values_iter(&self) -> BinaryValueIter<O> {
+    pub fn values_iter(&self) -> BinaryValueIter<'_, O> {
         BinaryValueIter::new(self)
     }
 
@@ -216,8 +224,8 @@ impl<O: Offset> BinaryArray<O> {
     }
 
     /// Boxes self into a [`std::sync::Arc<dyn Array>`].
-    pub fn arced(self) -> std::sync::Arc<dyn Array> {
-        std::sync::Arc::new(self)
+    pub fn arced(self) -> alloc::sync::Arc<dyn Array> {
+        alloc::sync::Arc::new(self)
     }
 
     /// Returns this [`BinaryArray`] with a new validity.
@@ -252,7 +260,7 @@ impl<O: Offset> BinaryArray<O> {
                     Some(bitmap),
                 )),
                 Right(mutable_bitmap) => match (
-                    self.values.get_mut().map(std::mem::take),
+                    self.values.get_mut().map(core::mem::take),
                     self.offsets.get_mut(),
                 ) {
                     (None, None) => Left(BinaryArray::new(
@@ -286,7 +294,7 @@ impl<O: Offset> BinaryArray<O> {
             }
         } else {
             match (
-                self.values.get_mut().map(std::mem::take),
+                self.values.get_mut().map(core::mem::take),
                 self.offsets.get_mut(),
             ) {
                 (None, None) => Left(BinaryArray::new(
@@ -380,6 +388,9 @@ impl<O: Offset> BinaryArray<O> {
     }
 
     /// Creates a [`BinaryArray`] from a [`TrustedLen`]
+    /// # Panics
+    /// Panics iff the total length in bytes exceeds `O`'s maximum value; use
+    /// [`Self::try_from_iter`] to handle it as an error.
     #[inline]
     pub fn from_trusted_len_iter<I, P>(iterator: I) -> Self
     where
@@ -390,25 +401,37 @@ impl<O: Offset> BinaryArray<O> {
         unsafe { Self::from_trusted_len_iter_unchecked(iterator) }
     }
 
+    /// Creates a new [`BinaryArray`] from an iterator of optional bytes.
+    /// # Errors
+    /// Errors with [`Error::Overflow`] iff the total length in bytes exceeds `O`'s maximum
+    /// value (`i32::MAX` or `i64::MAX` respectively).
+    pub fn try_from_iter<P: AsRef<[u8]>, I: IntoIterator<Item = Option<P>>>(
+        iter: I,
+    ) -> Result<Self> {
+        MutableBinaryArray::<O>::try_from_iter(iter).map(|x| x.into())
+    }
+
     /// Creates a [`BinaryArray`] from an falible iterator of trusted length.
     /// # Safety
     /// The iterator must be [`TrustedLen`](https://doc.rust-lang.org/std/iter/trait.TrustedLen.html).
     /// I.e. that `size_hint().1` correctly reports its length.
     #[inline]
-    pub unsafe fn try_from_trusted_len_iter_unchecked<E, I, P>(iterator: I) -> Result<Self, E>
+    pub unsafe fn try_from_trusted_len_iter_unchecked<E, I, P>(
+        iterator: I,
+    ) -> core::result::Result<Self, E>
     where
         P: AsRef<[u8]>,
-        I: IntoIterator<Item = Result<Option<P>, E>>,
+        I: IntoIterator<Item = core::result::Result<Option<P>, E>>,
     {
         MutableBinaryArray::<O>::try_from_trusted_len_iter_unchecked(iterator).map(|x| x.into())
     }
 
     /// Creates a [`BinaryArray`] from an fallible iterator of trusted length.
     #[inline]
-    pub fn try_from_trusted_len_iter<E, I, P>(iter: I) -> Result<Self, E>
+    pub fn try_from_trusted_len_iter<E, I, P>(iter: I) -> core::result::Result<Self, E>
     where
         P: AsRef<[u8]>,
-        I: TrustedLen<Item = Result<Option<P>, E>>,
+        I: TrustedLen<Item = core::result::Result<Option<P>, E>>,
     {
         // soundness: I: TrustedLen
         unsafe { Self::try_from_trusted_len_iter_unchecked(iter) }
@@ -417,12 +440,12 @@ impl<O: Offset> BinaryArray<O> {
 
 impl<O: Offset> Array for BinaryArray<O> {
     #[inline]
-    fn as_any(&self) -> &dyn std::any::Any {
+    fn as_any(&self) -> &dyn core::any::Any {
         self
     }
 
     #[inline]
-    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+    fn as_any_mut(&mut self) -> &mut dyn core::any::Any {
         self
     }
 
@@ -452,6 +475,22 @@ impl<O: Offset> Array for BinaryArray<O> {
     fn to_boxed(&self) -> Box<dyn Array> {
         Box::new(self.clone())
     }
+
+    fn buffers(&self) -> Vec<Option<Buffer<u8>>> {
+        vec![
+            self.validity.as_ref().map(bitmap_to_bytes),
+            Some(buffer_to_bytes(self.offsets.buffer())),
+            Some(self.values.clone()),
+        ]
+    }
+
+    fn validate(&self, validation: ArrayValidation) -> Result<()> {
+        if validation == ArrayValidation::Off {
+            return Ok(());
+        }
+        validate_validity(self.validity(), self.len())?;
+        validate_offsets(self.offsets(), self.values().len(), validation)
+    }
 }
 
 unsafe impl<O: Offset> GenericBinaryArray<O> for BinaryArray<O> {
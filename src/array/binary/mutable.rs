@@ -1,7 +1,8 @@
-use std::{iter::FromIterator, sync::Arc};
+use alloc::{boxed::Box, sync::Arc, vec::Vec};
+use core::iter::FromIterator;
 
 use crate::{
-    array::{Array, MutableArray, TryExtend, TryExtendFromSelf, TryPush},
+    array::{downcast_extend, Array, MutableArray, TryExtend, TryExtendFromSelf, TryPush},
     bitmap::{
         utils::{BitmapIter, ZipValidity},
         Bitmap, MutableBitmap,
@@ -130,7 +131,13 @@ impl<O: Offset> MutableBinaryArray<O> {
             .map(|_| value)
     }
 
-    fn try_from_iter<P: AsRef<[u8]>, I: IntoIterator<Item = Option<P>>>(iter: I) -> Result<Self> {
+    /// Creates a new [`MutableBinaryArray`] from an iterator.
+    /// # Error
+    /// This operation errors iff the total length in bytes on the iterator exceeds `O`'s maximum value.
+    /// (`i32::MAX` or `i64::MAX` respectively).
+    pub fn try_from_iter<P: AsRef<[u8]>, I: IntoIterator<Item = Option<P>>>(
+        iter: I,
+    ) -> Result<Self> {
         let iterator = iter.into_iter();
         let (lower, _) = iterator.size_hint();
         let mut primitive = Self::with_capacity(lower);
@@ -174,12 +181,12 @@ impl<O: Offset> MutableBinaryArray<O> {
     }
 
     /// Returns an iterator of `Option<&[u8]>`
-    pub fn iter(&self) -> ZipValidity<&[u8], MutableBinaryValuesIter<O>, BitmapIter> {
+    pub fn iter(&self) -> ZipValidity<&[u8], MutableBinaryValuesIter<'_, O>, BitmapIter<'_>> {
         ZipValidity::new(self.values_iter(), self.validity.as_ref().map(|x| x.iter()))
     }
 
     /// Returns an iterator over the values of this array
-    pub fn values_iter(&self) -> MutableBinaryValuesIter<O> {
+    pub fn values_iter(&self) -> MutableBinaryValuesIter<'_, O> {
         self.values.iter()
     }
 }
@@ -194,12 +201,12 @@ impl<O: Offset> MutableArray for MutableBinaryArray<O> {
     }
 
     fn as_box(&mut self) -> Box<dyn Array> {
-        let array: BinaryArray<O> = std::mem::take(self).into();
+        let array: BinaryArray<O> = core::mem::take(self).into();
         array.boxed()
     }
 
     fn as_arc(&mut self) -> Arc<dyn Array> {
-        let array: BinaryArray<O> = std::mem::take(self).into();
+        let array: BinaryArray<O> = core::mem::take(self).into();
         array.arced()
     }
 
@@ -207,11 +214,11 @@ impl<O: Offset> MutableArray for MutableBinaryArray<O> {
         self.values.data_type()
     }
 
-    fn as_any(&self) -> &dyn std::any::Any {
+    fn as_any(&self) -> &dyn core::any::Any {
         self
     }
 
-    fn as_mut_any(&mut self) -> &mut dyn std::any::Any {
+    fn as_mut_any(&mut self) -> &mut dyn core::any::Any {
         self
     }
 
@@ -227,6 +234,15 @@ impl<O: Offset> MutableArray for MutableBinaryArray<O> {
     fn shrink_to_fit(&mut self) {
         self.shrink_to_fit()
     }
+
+    fn try_extend_from_array(&mut self, other: &dyn Array, start: usize, len: usize) -> Result<()> {
+        let other = downcast_extend::<BinaryArray<O>>(other, start, len)?;
+        let length = self.len();
+        self.values
+            .try_extend_values_from_array(other, start, len)?;
+        extend_validity_from_bitmap(length, &mut self.validity, other.validity(), start, len);
+        Ok(())
+    }
 }
 
 impl<O: Offset, P: AsRef<[u8]>> FromIterator<Option<P>> for MutableBinaryArray<O> {
@@ -290,10 +306,10 @@ impl<O: Offset> MutableBinaryArray<O> {
     #[inline]
     pub unsafe fn try_from_trusted_len_iter_unchecked<E, I, P>(
         iterator: I,
-    ) -> std::result::Result<Self, E>
+    ) -> core::result::Result<Self, E>
     where
         P: AsRef<[u8]>,
-        I: IntoIterator<Item = std::result::Result<Option<P>, E>>,
+        I: IntoIterator<Item = core::result::Result<Option<P>, E>>,
     {
         let iterator = iterator.into_iter();
 
@@ -309,10 +325,10 @@ impl<O: Offset> MutableBinaryArray<O> {
 
     /// Creates a [`MutableBinaryArray`] from an falible iterator of trusted length.
     #[inline]
-    pub fn try_from_trusted_len_iter<E, I, P>(iterator: I) -> std::result::Result<Self, E>
+    pub fn try_from_trusted_len_iter<E, I, P>(iterator: I) -> core::result::Result<Self, E>
     where
         P: AsRef<[u8]>,
-        I: TrustedLen<Item = std::result::Result<Option<P>, E>>,
+        I: TrustedLen<Item = core::result::Result<Option<P>, E>>,
     {
         // soundness: I: TrustedLen
         unsafe { Self::try_from_trusted_len_iter_unchecked(iterator) }
@@ -1,9 +1,10 @@
-use std::{iter::FromIterator, sync::Arc};
+use alloc::{boxed::Box, sync::Arc, vec::Vec};
+use core::iter::FromIterator;
 
 use crate::{
     array::{
-        specification::try_check_offsets_bounds, Array, ArrayAccessor, ArrayValuesIter,
-        MutableArray, TryExtend, TryExtendFromSelf, TryPush,
+        downcast_extend, specification::try_check_offsets_bounds, Array, ArrayAccessor,
+        ArrayValuesIter, MutableArray, TryExtend, TryExtendFromSelf, TryPush,
     },
     bitmap::MutableBitmap,
     datatypes::DataType,
@@ -174,7 +175,7 @@ impl<O: Offset> MutableBinaryValuesArray<O> {
     }
 
     /// Returns an iterator of `&[u8]`
-    pub fn iter(&self) -> ArrayValuesIter<Self> {
+    pub fn iter(&self) -> ArrayValuesIter<'_, Self> {
         ArrayValuesIter::new(self)
     }
 
@@ -200,12 +201,12 @@ impl<O: Offset> MutableArray for MutableBinaryValuesArray<O> {
     }
 
     fn as_box(&mut self) -> Box<dyn Array> {
-        let (data_type, offsets, values) = std::mem::take(self).into_inner();
+        let (data_type, offsets, values) = core::mem::take(self).into_inner();
         BinaryArray::new(data_type, offsets.into(), values.into(), None).boxed()
     }
 
     fn as_arc(&mut self) -> Arc<dyn Array> {
-        let (data_type, offsets, values) = std::mem::take(self).into_inner();
+        let (data_type, offsets, values) = core::mem::take(self).into_inner();
         BinaryArray::new(data_type, offsets.into(), values.into(), None).arced()
     }
 
@@ -213,11 +214,11 @@ impl<O: Offset> MutableArray for MutableBinaryValuesArray<O> {
         &self.data_type
     }
 
-    fn as_any(&self) -> &dyn std::any::Any {
+    fn as_any(&self) -> &dyn core::any::Any {
         self
     }
 
-    fn as_mut_any(&mut self) -> &mut dyn std::any::Any {
+    fn as_mut_any(&mut self) -> &mut dyn core::any::Any {
         self
     }
 
@@ -233,6 +234,11 @@ impl<O: Offset> MutableArray for MutableBinaryValuesArray<O> {
     fn shrink_to_fit(&mut self) {
         self.shrink_to_fit()
     }
+
+    fn try_extend_from_array(&mut self, other: &dyn Array, start: usize, len: usize) -> Result<()> {
+        let other = downcast_extend::<BinaryArray<O>>(other, start, len)?;
+        self.try_extend_values_from_array(other, start, len)
+    }
 }
 
 impl<O: Offset, P: AsRef<[u8]>> FromIterator<P> for MutableBinaryValuesArray<O> {
@@ -353,6 +359,30 @@ unsafe impl<'a, O: Offset> ArrayAccessor<'a> for MutableBinaryValuesArray<O> {
     }
 }
 
+impl<O: Offset> MutableBinaryValuesArray<O> {
+    /// Extends itself with the values of `other` in `start..start + len`, ignoring its
+    /// validity.
+    /// # Errors
+    /// Errors iff the offsets overflow
+    pub(crate) fn try_extend_values_from_array(
+        &mut self,
+        other: &BinaryArray<O>,
+        start: usize,
+        len: usize,
+    ) -> Result<()> {
+        if len == 0 {
+            return Ok(());
+        }
+        let offsets = other.offsets();
+        let values_start = offsets.buffer()[start].to_usize();
+        let values_end = offsets.buffer()[start + len].to_usize();
+        self.offsets.try_extend_from_slice(offsets, start, len)?;
+        self.values
+            .extend_from_slice(&other.values()[values_start..values_end]);
+        Ok(())
+    }
+}
+
 impl<O: Offset> TryExtendFromSelf for MutableBinaryValuesArray<O> {
     fn try_extend_from_self(&mut self, other: &Self) -> Result<()> {
         self.values.extend_from_slice(&other.values);
@@ -1,4 +1,4 @@
-use std::iter::FromIterator;
+use core::iter::FromIterator;
 
 use super::{BooleanArray, MutableBooleanArray};
 
@@ -8,7 +8,7 @@ impl<P: AsRef<[Option<bool>]>> From<P> for BooleanArray {
     }
 }
 
-impl<Ptr: std::borrow::Borrow<Option<bool>>> FromIterator<Ptr> for BooleanArray {
+impl<Ptr: alloc::borrow::Borrow<Option<bool>>> FromIterator<Ptr> for BooleanArray {
     fn from_iter<I: IntoIterator<Item = Ptr>>(iter: I) -> Self {
         MutableBooleanArray::from_iter(iter).into()
     }
@@ -1,16 +1,20 @@
+use alloc::{boxed::Box, vec::Vec};
+
 use crate::{
     bitmap::{
         utils::{BitmapIter, ZipValidity},
         Bitmap, MutableBitmap,
     },
+    buffer::Buffer,
     datatypes::{DataType, PhysicalType},
-    error::Error,
+    error::{Error, Result},
     trusted_len::TrustedLen,
 };
 use either::Either;
 
-use super::Array;
+use super::{bitmap_to_bytes, validation::validate_validity, Array, ArrayValidation};
 
+#[cfg(feature = "std")]
 mod ffi;
 pub(super) mod fmt;
 mod from;
@@ -58,11 +62,7 @@ impl BooleanArray {
     /// This function errors iff:
     /// * The validity is not `None` and its length is different from `values`'s length
     /// * The `data_type`'s [`PhysicalType`] is not equal to [`PhysicalType::Boolean`].
-    pub fn try_new(
-        data_type: DataType,
-        values: Bitmap,
-        validity: Option<Bitmap>,
-    ) -> Result<Self, Error> {
+    pub fn try_new(data_type: DataType, values: Bitmap, validity: Option<Bitmap>) -> Result<Self> {
         if validity
             .as_ref()
             .map_or(false, |validity| validity.len() != values.len())
@@ -90,15 +90,32 @@ impl BooleanArray {
         Self::try_new(data_type, values, validity).unwrap()
     }
 
+    /// Creates a new [`BooleanArray`] without nulls from `length` bits of packed bytes
+    /// starting at bit `offset`, where the first bit is the least significant bit of the
+    /// first byte. This does not copy `bytes`.
+    /// # Errors
+    /// This function errors iff `offset + length > bytes.len() * 8`
+    /// # Example
+    /// ```
+    /// use arrow2::array::BooleanArray;
+    ///
+    /// let array = BooleanArray::from_packed(vec![0b00011010], 3, 1).unwrap();
+    /// assert_eq!(array, BooleanArray::from_slice([true, false, true]));
+    /// ```
+    pub fn from_packed(bytes: Vec<u8>, length: usize, offset: usize) -> Result<Self> {
+        let values = Bitmap::from_packed(bytes, length, offset)?;
+        Ok(Self::new(DataType::Boolean, values, None))
+    }
+
     /// Returns an iterator over the optional values of this [`BooleanArray`].
     #[inline]
-    pub fn iter(&self) -> ZipValidity<bool, BitmapIter, BitmapIter> {
+    pub fn iter(&self) -> ZipValidity<bool, BitmapIter<'_>, BitmapIter<'_>> {
         ZipValidity::new_with_validity(self.values().iter(), self.validity())
     }
 
     /// Returns an iterator over the values of this [`BooleanArray`].
     #[inline]
-    pub fn values_iter(&self) -> BitmapIter {
+    pub fn values_iter(&self) -> BitmapIter<'_> {
         self.values().iter()
     }
 
@@ -229,7 +246,7 @@ impl BooleanArray {
     /// # Panics
     /// This function panics if the function modifies the length of the [`MutableBitmap`].
     pub fn apply_values_mut<F: Fn(&mut MutableBitmap)>(&mut self, f: F) {
-        let values = std::mem::take(&mut self.values);
+        let values = core::mem::take(&mut self.values);
         let mut values = values.make_mut();
         f(&mut values);
         if let Some(validity) = &self.validity {
@@ -312,7 +329,7 @@ impl BooleanArray {
     #[inline]
     pub unsafe fn from_trusted_len_iter_unchecked<I, P>(iterator: I) -> Self
     where
-        P: std::borrow::Borrow<bool>,
+        P: alloc::borrow::Borrow<bool>,
         I: Iterator<Item = Option<P>>,
     {
         MutableBooleanArray::from_trusted_len_iter_unchecked(iterator).into()
@@ -322,7 +339,7 @@ impl BooleanArray {
     #[inline]
     pub fn from_trusted_len_iter<I, P>(iterator: I) -> Self
     where
-        P: std::borrow::Borrow<bool>,
+        P: alloc::borrow::Borrow<bool>,
         I: TrustedLen<Item = Option<P>>,
     {
         MutableBooleanArray::from_trusted_len_iter(iterator).into()
@@ -333,20 +350,22 @@ impl BooleanArray {
     /// The iterator must be [`TrustedLen`](https://doc.rust-lang.org/std/iter/trait.TrustedLen.html).
     /// I.e. that `size_hint().1` correctly reports its length.
     #[inline]
-    pub unsafe fn try_from_trusted_len_iter_unchecked<E, I, P>(iterator: I) -> Result<Self, E>
+    pub unsafe fn try_from_trusted_len_iter_unchecked<E, I, P>(
+        iterator: I,
+    ) -> core::result::Result<Self, E>
     where
-        P: std::borrow::Borrow<bool>,
-        I: Iterator<Item = Result<Option<P>, E>>,
+        P: alloc::borrow::Borrow<bool>,
+        I: Iterator<Item = core::result::Result<Option<P>, E>>,
     {
         Ok(MutableBooleanArray::try_from_trusted_len_iter_unchecked(iterator)?.into())
     }
 
     /// Creates a [`BooleanArray`] from a [`TrustedLen`].
     #[inline]
-    pub fn try_from_trusted_len_iter<E, I, P>(iterator: I) -> Result<Self, E>
+    pub fn try_from_trusted_len_iter<E, I, P>(iterator: I) -> core::result::Result<Self, E>
     where
-        P: std::borrow::Borrow<bool>,
-        I: TrustedLen<Item = Result<Option<P>, E>>,
+        P: alloc::borrow::Borrow<bool>,
+        I: TrustedLen<Item = core::result::Result<Option<P>, E>>,
     {
         Ok(MutableBooleanArray::try_from_trusted_len_iter(iterator)?.into())
     }
@@ -357,8 +376,8 @@ impl BooleanArray {
     }
 
     /// Boxes self into a [`std::sync::Arc<dyn Array>`].
-    pub fn arced(self) -> std::sync::Arc<dyn Array> {
-        std::sync::Arc::new(self)
+    pub fn arced(self) -> alloc::sync::Arc<dyn Array> {
+        alloc::sync::Arc::new(self)
     }
 
     /// Returns its internal representation
@@ -375,12 +394,12 @@ impl BooleanArray {
 
 impl Array for BooleanArray {
     #[inline]
-    fn as_any(&self) -> &dyn std::any::Any {
+    fn as_any(&self) -> &dyn core::any::Any {
         self
     }
 
     #[inline]
-    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+    fn as_any_mut(&mut self) -> &mut dyn core::any::Any {
         self
     }
 
@@ -403,14 +422,31 @@ impl Array for BooleanArray {
     fn slice(&self, offset: usize, length: usize) -> Box<dyn Array> {
         Box::new(self.slice(offset, length))
     }
+
     #[inline]
     unsafe fn slice_unchecked(&self, offset: usize, length: usize) -> Box<dyn Array> {
         Box::new(self.slice_unchecked(offset, length))
     }
+
     fn with_validity(&self, validity: Option<Bitmap>) -> Box<dyn Array> {
         Box::new(self.clone().with_validity(validity))
     }
+
     fn to_boxed(&self) -> Box<dyn Array> {
         Box::new(self.clone())
     }
+
+    fn buffers(&self) -> Vec<Option<Buffer<u8>>> {
+        vec![
+            self.validity.as_ref().map(bitmap_to_bytes),
+            Some(bitmap_to_bytes(&self.values)),
+        ]
+    }
+
+    fn validate(&self, validation: ArrayValidation) -> Result<()> {
+        if validation == ArrayValidation::Off {
+            return Ok(());
+        }
+        validate_validity(self.validity(), self.len())
+    }
 }
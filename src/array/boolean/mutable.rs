@@ -1,10 +1,11 @@
-use std::iter::FromIterator;
-use std::sync::Arc;
+use alloc::{boxed::Box, sync::Arc};
+use core::iter::FromIterator;
 
 use crate::{
     array::{
-        physical_binary::extend_validity, Array, MutableArray, TryExtend, TryExtendFromSelf,
-        TryPush,
+        downcast_extend,
+        physical_binary::{extend_validity, extend_validity_from_bitmap},
+        Array, MutableArray, TryExtend, TryExtendFromSelf, TryPush,
     },
     bitmap::MutableBitmap,
     datatypes::{DataType, PhysicalType},
@@ -168,7 +169,7 @@ impl MutableBooleanArray {
     #[inline]
     pub fn extend_trusted_len<I, P>(&mut self, iterator: I)
     where
-        P: std::borrow::Borrow<bool>,
+        P: alloc::borrow::Borrow<bool>,
         I: TrustedLen<Item = Option<P>>,
     {
         // Safety: `I` is `TrustedLen`
@@ -181,7 +182,7 @@ impl MutableBooleanArray {
     #[inline]
     pub unsafe fn extend_trusted_len_unchecked<I, P>(&mut self, iterator: I)
     where
-        P: std::borrow::Borrow<bool>,
+        P: alloc::borrow::Borrow<bool>,
         I: Iterator<Item = Option<P>>,
     {
         if let Some(validity) = self.validity.as_mut() {
@@ -234,7 +235,7 @@ impl MutableBooleanArray {
             // When the validity is None, all elements so far are valid. When one of the elements is set fo null,
             // the validity must be initialized.
             self.validity = Some(MutableBitmap::from_trusted_len_iter(
-                std::iter::repeat(true).take(self.len()),
+                core::iter::repeat(true).take(self.len()),
             ));
         }
         if let Some(x) = self.validity.as_mut() {
@@ -286,7 +287,7 @@ impl MutableBooleanArray {
     #[inline]
     pub unsafe fn from_trusted_len_iter_unchecked<I, P>(iterator: I) -> Self
     where
-        P: std::borrow::Borrow<bool>,
+        P: alloc::borrow::Borrow<bool>,
         I: Iterator<Item = Option<P>>,
     {
         let (validity, values) = trusted_len_unzip(iterator);
@@ -298,7 +299,7 @@ impl MutableBooleanArray {
     #[inline]
     pub fn from_trusted_len_iter<I, P>(iterator: I) -> Self
     where
-        P: std::borrow::Borrow<bool>,
+        P: alloc::borrow::Borrow<bool>,
         I: TrustedLen<Item = Option<P>>,
     {
         // Safety: `I` is `TrustedLen`
@@ -312,10 +313,10 @@ impl MutableBooleanArray {
     #[inline]
     pub unsafe fn try_from_trusted_len_iter_unchecked<E, I, P>(
         iterator: I,
-    ) -> std::result::Result<Self, E>
+    ) -> core::result::Result<Self, E>
     where
-        P: std::borrow::Borrow<bool>,
-        I: Iterator<Item = std::result::Result<Option<P>, E>>,
+        P: alloc::borrow::Borrow<bool>,
+        I: Iterator<Item = core::result::Result<Option<P>, E>>,
     {
         let (validity, values) = try_trusted_len_unzip(iterator)?;
 
@@ -330,10 +331,10 @@ impl MutableBooleanArray {
 
     /// Creates a [`BooleanArray`] from a [`TrustedLen`].
     #[inline]
-    pub fn try_from_trusted_len_iter<E, I, P>(iterator: I) -> std::result::Result<Self, E>
+    pub fn try_from_trusted_len_iter<E, I, P>(iterator: I) -> core::result::Result<Self, E>
     where
-        P: std::borrow::Borrow<bool>,
-        I: TrustedLen<Item = std::result::Result<Option<P>, E>>,
+        P: alloc::borrow::Borrow<bool>,
+        I: TrustedLen<Item = core::result::Result<Option<P>, E>>,
     {
         // Safety: `I` is `TrustedLen`
         unsafe { Self::try_from_trusted_len_iter_unchecked(iterator) }
@@ -356,7 +357,7 @@ impl MutableBooleanArray {
 #[inline]
 pub(crate) unsafe fn trusted_len_unzip<I, P>(iterator: I) -> (Option<MutableBitmap>, MutableBitmap)
 where
-    P: std::borrow::Borrow<bool>,
+    P: alloc::borrow::Borrow<bool>,
     I: Iterator<Item = Option<P>>,
 {
     let mut validity = MutableBitmap::new();
@@ -382,7 +383,7 @@ pub(crate) unsafe fn extend_trusted_len_unzip<I, P>(
     validity: &mut MutableBitmap,
     values: &mut MutableBitmap,
 ) where
-    P: std::borrow::Borrow<bool>,
+    P: alloc::borrow::Borrow<bool>,
     I: Iterator<Item = Option<P>>,
 {
     let (_, upper) = iterator.size_hint();
@@ -418,10 +419,10 @@ pub(crate) unsafe fn extend_trusted_len_unzip<I, P>(
 #[inline]
 pub(crate) unsafe fn try_trusted_len_unzip<E, I, P>(
     iterator: I,
-) -> std::result::Result<(MutableBitmap, MutableBitmap), E>
+) -> core::result::Result<(MutableBitmap, MutableBitmap), E>
 where
-    P: std::borrow::Borrow<bool>,
-    I: Iterator<Item = std::result::Result<Option<P>, E>>,
+    P: alloc::borrow::Borrow<bool>,
+    I: Iterator<Item = core::result::Result<Option<P>, E>>,
 {
     let (_, upper) = iterator.size_hint();
     let len = upper.expect("trusted_len_unzip requires an upper limit");
@@ -450,7 +451,7 @@ where
     Ok((null, values))
 }
 
-impl<Ptr: std::borrow::Borrow<Option<bool>>> FromIterator<Ptr> for MutableBooleanArray {
+impl<Ptr: alloc::borrow::Borrow<Option<bool>>> FromIterator<Ptr> for MutableBooleanArray {
     fn from_iter<I: IntoIterator<Item = Ptr>>(iter: I) -> Self {
         let iter = iter.into_iter();
         let (lower, _) = iter.size_hint();
@@ -489,12 +490,12 @@ impl MutableArray for MutableBooleanArray {
     }
 
     fn as_box(&mut self) -> Box<dyn Array> {
-        let array: BooleanArray = std::mem::take(self).into();
+        let array: BooleanArray = core::mem::take(self).into();
         array.boxed()
     }
 
     fn as_arc(&mut self) -> Arc<dyn Array> {
-        let array: BooleanArray = std::mem::take(self).into();
+        let array: BooleanArray = core::mem::take(self).into();
         array.arced()
     }
 
@@ -502,11 +503,11 @@ impl MutableArray for MutableBooleanArray {
         &self.data_type
     }
 
-    fn as_any(&self) -> &dyn std::any::Any {
+    fn as_any(&self) -> &dyn core::any::Any {
         self
     }
 
-    fn as_mut_any(&mut self) -> &mut dyn std::any::Any {
+    fn as_mut_any(&mut self) -> &mut dyn core::any::Any {
         self
     }
 
@@ -522,6 +523,19 @@ impl MutableArray for MutableBooleanArray {
     fn shrink_to_fit(&mut self) {
         self.shrink_to_fit()
     }
+
+    fn try_extend_from_array(
+        &mut self,
+        other: &dyn Array,
+        start: usize,
+        len: usize,
+    ) -> Result<(), Error> {
+        let other = downcast_extend::<BooleanArray>(other, start, len)?;
+        extend_validity_from_bitmap(self.len(), &mut self.validity, other.validity(), start, len);
+        let (slice, offset, _) = other.values().as_slice();
+        self.values.extend_from_slice(slice, offset + start, len);
+        Ok(())
+    }
 }
 
 impl Extend<Option<bool>> for MutableBooleanArray {
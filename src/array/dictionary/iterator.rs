@@ -1,3 +1,5 @@
+use alloc::boxed::Box;
+
 use crate::bitmap::utils::{BitmapIter, ZipValidity};
 use crate::scalar::Scalar;
 use crate::trusted_len::TrustedLen;
@@ -1,29 +1,34 @@
-use std::hint::unreachable_unchecked;
+use alloc::{boxed::Box, vec::Vec};
+use core::hint::unreachable_unchecked;
 
 use crate::{
     bitmap::{
         utils::{BitmapIter, ZipValidity},
         Bitmap,
     },
+    buffer::Buffer,
     datatypes::{DataType, IntegerType},
-    error::Error,
+    error::{Error, Result},
     scalar::{new_scalar, Scalar},
     trusted_len::TrustedLen,
     types::NativeType,
 };
 
+#[cfg(feature = "std")]
 mod ffi;
 pub(super) mod fmt;
 mod iterator;
+#[cfg(feature = "std")]
 mod mutable;
 use crate::array::specification::check_indexes_unchecked;
 mod typed_iterator;
 
 use crate::array::dictionary::typed_iterator::{DictValue, DictionaryValuesIterTyped};
 pub use iterator::*;
+#[cfg(feature = "std")]
 pub use mutable::*;
 
-use super::{new_empty_array, primitive::PrimitiveArray, Array};
+use super::{new_empty_array, primitive::PrimitiveArray, Array, ArrayValidation};
 use super::{new_null_array, specification::check_indexes};
 
 /// Trait denoting [`NativeType`]s that can be used as keys of a dictionary.
@@ -112,7 +117,7 @@ fn check_data_type(
     key_type: IntegerType,
     data_type: &DataType,
     values_data_type: &DataType,
-) -> Result<(), Error> {
+) -> Result<()> {
     if let DataType::Dictionary(key, value, _) = data_type.to_logical_type() {
         if *key != key_type {
             return Err(Error::oos(
@@ -146,7 +151,7 @@ impl<K: DictionaryKey> DictionaryArray<K> {
         data_type: DataType,
         keys: PrimitiveArray<K>,
         values: Box<dyn Array>,
-    ) -> Result<Self, Error> {
+    ) -> Result<Self> {
         check_data_type(K::KEY_TYPE, &data_type, values.data_type())?;
 
         if keys.null_count() != keys.len() {
@@ -172,7 +177,7 @@ impl<K: DictionaryKey> DictionaryArray<K> {
     /// # Errors
     /// This function errors iff
     /// * any of the keys's values is not represented in `usize` or is `>= values.len()`
-    pub fn try_from_keys(keys: PrimitiveArray<K>, values: Box<dyn Array>) -> Result<Self, Error> {
+    pub fn try_from_keys(keys: PrimitiveArray<K>, values: Box<dyn Array>) -> Result<Self> {
         let data_type = Self::default_data_type(values.data_type().clone());
         Self::try_new(data_type, keys, values)
     }
@@ -189,7 +194,7 @@ impl<K: DictionaryKey> DictionaryArray<K> {
         data_type: DataType,
         keys: PrimitiveArray<K>,
         values: Box<dyn Array>,
-    ) -> Result<Self, Error> {
+    ) -> Result<Self> {
         check_data_type(K::KEY_TYPE, &data_type, values.data_type())?;
 
         Ok(Self {
@@ -199,6 +204,32 @@ impl<K: DictionaryKey> DictionaryArray<K> {
         })
     }
 
+    /// Returns a new [`DictionaryArray`] whose invariants are checked at the level `validation`:
+    /// [`ArrayValidation::Full`] is equivalent to [`DictionaryArray::try_new`],
+    /// [`ArrayValidation::Basic`] to [`DictionaryArray::try_new_unchecked`] and
+    /// [`ArrayValidation::Off`] checks nothing.
+    /// # Errors
+    /// This function errors iff an invariant checked at the level `validation` does not hold.
+    /// # Safety
+    /// This function is unsound iff an invariant not checked at the level `validation` does
+    /// not hold.
+    pub unsafe fn try_new_with_validation(
+        data_type: DataType,
+        keys: PrimitiveArray<K>,
+        values: Box<dyn Array>,
+        validation: ArrayValidation,
+    ) -> Result<Self> {
+        match validation {
+            ArrayValidation::Off => Ok(Self {
+                data_type,
+                keys,
+                values,
+            }),
+            ArrayValidation::Basic => Self::try_new_unchecked(data_type, keys, values),
+            ArrayValidation::Full => Self::try_new(data_type, keys, values),
+        }
+    }
+
     /// Returns a new empty [`DictionaryArray`].
     pub fn new_empty(data_type: DataType) -> Self {
         let values = Self::try_get_child(&data_type).unwrap();
@@ -228,7 +259,9 @@ impl<K: DictionaryKey> DictionaryArray<K> {
     /// # Implementation
     /// This function will allocate a new [`Scalar`] per item and is usually not performant.
     /// Consider calling `keys_iter` and `values`, downcasting `values`, and iterating over that.
-    pub fn iter(&self) -> ZipValidity<Box<dyn Scalar>, DictionaryValuesIter<K>, BitmapIter> {
+    pub fn iter(
+        &self,
+    ) -> ZipValidity<Box<dyn Scalar>, DictionaryValuesIter<'_, K>, BitmapIter<'_>> {
         ZipValidity::new_with_validity(DictionaryValuesIter::new(self), self.keys.validity())
     }
 
@@ -236,7 +269,7 @@ impl<K: DictionaryKey> DictionaryArray<K> {
     /// # Implementation
     /// This function will allocate a new [`Scalar`] per item and is usually not performant.
     /// Consider calling `keys_iter` and `values`, downcasting `values`, and iterating over that.
-    pub fn values_iter(&self) -> DictionaryValuesIter<K> {
+    pub fn values_iter(&self) -> DictionaryValuesIter<'_, K> {
         DictionaryValuesIter::new(self)
     }
 
@@ -246,9 +279,7 @@ impl<K: DictionaryKey> DictionaryArray<K> {
     ///
     /// Panics if the keys of this [`DictionaryArray`] have any null types.
     /// If they do [`DictionaryArray::iter_typed`] should be called
-    pub fn values_iter_typed<V: DictValue>(
-        &self,
-    ) -> Result<DictionaryValuesIterTyped<K, V>, Error> {
+    pub fn values_iter_typed<V: DictValue>(&self) -> Result<DictionaryValuesIterTyped<'_, K, V>> {
         let keys = &self.keys;
         assert_eq!(keys.null_count(), 0);
         let values = self.values.as_ref();
@@ -263,7 +294,7 @@ impl<K: DictionaryKey> DictionaryArray<K> {
     /// This function panics if the `values` array
     pub fn iter_typed<V: DictValue>(
         &self,
-    ) -> Result<ZipValidity<V::IterValue<'_>, DictionaryValuesIterTyped<K, V>, BitmapIter>, Error>
+    ) -> Result<ZipValidity<V::IterValue<'_>, DictionaryValuesIterTyped<'_, K, V>, BitmapIter<'_>>>
     {
         let keys = &self.keys;
         let values = self.values.as_ref();
@@ -396,11 +427,11 @@ impl<K: DictionaryKey> DictionaryArray<K> {
     }
 
     /// Boxes self into a [`std::sync::Arc<dyn Array>`].
-    pub fn arced(self) -> std::sync::Arc<dyn Array> {
-        std::sync::Arc::new(self)
+    pub fn arced(self) -> alloc::sync::Arc<dyn Array> {
+        alloc::sync::Arc::new(self)
     }
 
-    pub(crate) fn try_get_child(data_type: &DataType) -> Result<&DataType, Error> {
+    pub(crate) fn try_get_child(data_type: &DataType) -> Result<&DataType> {
         Ok(match data_type.to_logical_type() {
             DataType::Dictionary(_, values, _) => values.as_ref(),
             _ => {
@@ -414,12 +445,12 @@ impl<K: DictionaryKey> DictionaryArray<K> {
 
 impl<K: DictionaryKey> Array for DictionaryArray<K> {
     #[inline]
-    fn as_any(&self) -> &dyn std::any::Any {
+    fn as_any(&self) -> &dyn core::any::Any {
         self
     }
 
     #[inline]
-    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+    fn as_any_mut(&mut self) -> &mut dyn core::any::Any {
         self
     }
 
@@ -452,4 +483,20 @@ impl<K: DictionaryKey> Array for DictionaryArray<K> {
     fn to_boxed(&self) -> Box<dyn Array> {
         Box::new(self.clone())
     }
+
+    fn buffers(&self) -> Vec<Option<Buffer<u8>>> {
+        Array::buffers(&self.keys)
+    }
+
+    fn direct_children(&self) -> Vec<Box<dyn Array>> {
+        vec![self.values.clone()]
+    }
+
+    fn validate(&self, validation: ArrayValidation) -> Result<()> {
+        self.keys.validate(validation)?;
+        if validation == ArrayValidation::Full && self.keys.null_count() != self.keys.len() {
+            check_indexes(self.keys.values(), self.values.len())?;
+        }
+        self.values.validate(validation)
+    }
 }
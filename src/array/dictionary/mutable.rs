@@ -4,7 +4,9 @@ use std::{collections::hash_map::DefaultHasher, sync::Arc};
 use hash_hasher::HashedMap;
 
 use crate::{
-    array::{primitive::MutablePrimitiveArray, Array, MutableArray, TryExtend, TryPush},
+    array::{
+        downcast_extend, primitive::MutablePrimitiveArray, Array, MutableArray, TryExtend, TryPush,
+    },
     bitmap::MutableBitmap,
     datatypes::DataType,
     error::{Error, Result},
@@ -65,6 +67,38 @@ impl<K: DictionaryKey, M: MutableArray> From<M> for MutableDictionaryArray<K, M>
     }
 }
 
+impl<K: DictionaryKey, M: MutableArray> MutableDictionaryArray<K, M> {
+    /// Creates an empty [`MutableDictionaryArray`] of [`DataType`] `data_type` whose
+    /// values are pushed to `values`.
+    /// # Errors
+    /// This function errors iff:
+    /// * The `data_type`'s physical type is not a dictionary keyed by `K`
+    /// * The `values`' data type is not equal to the values' data type of `data_type`
+    /// * `values` is not empty
+    pub fn try_empty(data_type: DataType, values: M) -> Result<Self> {
+        if data_type.to_physical_type() != crate::datatypes::PhysicalType::Dictionary(K::KEY_TYPE) {
+            return Err(Error::oos(
+                "MutableDictionaryArray must be initialized with a DataType::Dictionary keyed by K",
+            ));
+        }
+        if DictionaryArray::<K>::try_get_child(&data_type)? != values.data_type() {
+            return Err(Error::oos(
+                "MutableDictionaryArray expects the values' DataType to match its inner DataType",
+            ));
+        }
+        if !values.is_empty() {
+            return Err(Error::oos("MutableDictionaryArray expects empty values"));
+        }
+
+        Ok(Self {
+            data_type,
+            keys: MutablePrimitiveArray::<K>::new(),
+            map: HashedMap::default(),
+            values,
+        })
+    }
+}
+
 impl<K: DictionaryKey, M: MutableArray + Default> MutableDictionaryArray<K, M> {
     /// Creates an empty [`MutableDictionaryArray`].
     pub fn new() -> Self {
@@ -100,7 +134,7 @@ impl<K: DictionaryKey, M: MutableArray> MutableDictionaryArray<K, M> {
                 Ok(false)
             }
             None => {
-                let key = K::try_from(self.map.len()).map_err(|_| Error::Overflow)?;
+                let key = K::try_from(self.values.len()).map_err(|_| Error::Overflow)?;
                 self.map.insert(hash, key);
                 self.keys.push(Some(key));
                 Ok(true)
@@ -206,6 +240,41 @@ impl<K: DictionaryKey, M: 'static + MutableArray> MutableArray for MutableDictio
     fn shrink_to_fit(&mut self) {
         self.shrink_to_fit()
     }
+
+    /// The values referenced by `other` in `start..start + len` are appended to the values
+    /// without being deduplicated against the existing ones.
+    fn try_extend_from_array(&mut self, other: &dyn Array, start: usize, len: usize) -> Result<()> {
+        let other = downcast_extend::<DictionaryArray<K>>(other, start, len)?;
+        let keys = other.keys_iter().skip(start).take(len).collect::<Vec<_>>();
+        let range =
+            keys.iter()
+                .flatten()
+                .fold(None, |acc: Option<(usize, usize)>, &key| match acc {
+                    Some((min, max)) => Some((min.min(key), max.max(key))),
+                    None => Some((key, key)),
+                });
+        let (min, max) = match range {
+            Some(range) => range,
+            None => {
+                (0..len).for_each(|_| self.keys.push(None));
+                return Ok(());
+            }
+        };
+
+        // only the values in the range of the referenced keys are appended
+        let offset = self.values.len();
+        K::try_from(max - min + offset).map_err(|_| Error::Overflow)?;
+        self.values
+            .try_extend_from_array(other.values().as_ref(), min, max - min + 1)?;
+        self.keys.reserve(len);
+        keys.into_iter().try_for_each(|key| {
+            let key = key
+                .map(|key| K::try_from(key - min + offset).map_err(|_| Error::Overflow))
+                .transpose()?;
+            self.keys.push(key);
+            Ok(())
+        })
+    }
 }
 
 impl<K, M, T: Hash> TryExtend<Option<T>> for MutableDictionaryArray<K, M>
@@ -1,3 +1,5 @@
+use alloc::boxed::Box;
+
 use crate::offset::Offset;
 use crate::types::NativeType;
 
@@ -22,7 +24,7 @@ impl PartialEq for dyn Array + '_ {
     }
 }
 
-impl PartialEq<dyn Array> for std::sync::Arc<dyn Array + '_> {
+impl PartialEq<dyn Array> for alloc::sync::Arc<dyn Array + '_> {
     fn eq(&self, that: &dyn Array) -> bool {
         equal(&**self, that)
     }
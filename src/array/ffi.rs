@@ -38,7 +38,7 @@ macro_rules! ffi_dyn {
         let array = $array.as_any().downcast_ref::<$ty>().unwrap();
         (
             array.offset().unwrap(),
-            array.buffers(),
+            ToFfi::buffers(array),
             array.children(),
             None,
         )
@@ -76,7 +76,7 @@ pub fn offset_buffers_children_dictionary(array: &dyn Array) -> BuffersChildren
                 let array = array.as_any().downcast_ref::<DictionaryArray<$T>>().unwrap();
                 (
                     array.offset().unwrap(),
-                    array.buffers(),
+                    ToFfi::buffers(array),
                     array.children(),
                     Some(array.values().clone()),
                 )
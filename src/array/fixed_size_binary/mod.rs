@@ -1,7 +1,19 @@
-use crate::{bitmap::Bitmap, buffer::Buffer, datatypes::DataType, error::Error};
-
-use super::Array;
-
+use alloc::{boxed::Box, vec::Vec};
+
+use crate::{
+    bitmap::Bitmap,
+    buffer::Buffer,
+    datatypes::DataType,
+    error::{Error, Result},
+};
+
+use super::{
+    bitmap_to_bytes,
+    validation::{validate_fixed_size, validate_validity},
+    Array, ArrayValidation,
+};
+
+#[cfg(feature = "std")]
 mod ffi;
 pub(super) mod fmt;
 mod iterator;
@@ -30,7 +42,7 @@ impl FixedSizeBinaryArray {
         data_type: DataType,
         values: Buffer<u8>,
         validity: Option<Bitmap>,
-    ) -> Result<Self, Error> {
+    ) -> Result<Self> {
         let size = Self::maybe_get_size(&data_type)?;
 
         if values.len() % size != 0 {
@@ -90,8 +102,8 @@ impl FixedSizeBinaryArray {
     }
 
     /// Boxes self into a [`std::sync::Arc<dyn Array>`].
-    pub fn arced(self) -> std::sync::Arc<dyn Array> {
-        std::sync::Arc::new(self)
+    pub fn arced(self) -> alloc::sync::Arc<dyn Array> {
+        alloc::sync::Arc::new(self)
     }
 }
 
@@ -223,7 +235,7 @@ impl FixedSizeBinaryArray {
 }
 
 impl FixedSizeBinaryArray {
-    pub(crate) fn maybe_get_size(data_type: &DataType) -> Result<usize, Error> {
+    pub(crate) fn maybe_get_size(data_type: &DataType) -> Result<usize> {
         match data_type.to_logical_type() {
             DataType::FixedSizeBinary(size) => {
                 if *size == 0 {
@@ -244,12 +256,12 @@ impl FixedSizeBinaryArray {
 
 impl Array for FixedSizeBinaryArray {
     #[inline]
-    fn as_any(&self) -> &dyn std::any::Any {
+    fn as_any(&self) -> &dyn core::any::Any {
         self
     }
 
     #[inline]
-    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+    fn as_any_mut(&mut self) -> &mut dyn core::any::Any {
         self
     }
 
@@ -282,6 +294,21 @@ impl Array for FixedSizeBinaryArray {
     fn to_boxed(&self) -> Box<dyn Array> {
         Box::new(self.clone())
     }
+
+    fn buffers(&self) -> Vec<Option<Buffer<u8>>> {
+        vec![
+            self.validity.as_ref().map(bitmap_to_bytes),
+            Some(self.values.clone()),
+        ]
+    }
+
+    fn validate(&self, validation: ArrayValidation) -> Result<()> {
+        if validation == ArrayValidation::Off {
+            return Ok(());
+        }
+        validate_fixed_size(self.values.len(), self.size)?;
+        validate_validity(self.validity(), self.len())
+    }
 }
 
 impl FixedSizeBinaryArray {
@@ -289,7 +316,7 @@ impl FixedSizeBinaryArray {
     pub fn try_from_iter<P: AsRef<[u8]>, I: IntoIterator<Item = Option<P>>>(
         iter: I,
         size: usize,
-    ) -> Result<Self, Error> {
+    ) -> Result<Self> {
         MutableFixedSizeBinaryArray::try_from_iter(iter, size).map(|x| x.into())
     }
 
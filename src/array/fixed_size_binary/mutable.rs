@@ -1,7 +1,11 @@
-use std::sync::Arc;
+use alloc::{boxed::Box, string::ToString, sync::Arc, vec::Vec};
 
 use crate::{
-    array::{physical_binary::extend_validity, Array, MutableArray, TryExtendFromSelf},
+    array::{
+        downcast_extend,
+        physical_binary::{extend_validity, extend_validity_from_bitmap},
+        Array, MutableArray, TryExtendFromSelf,
+    },
     bitmap::MutableBitmap,
     datatypes::DataType,
     error::Error,
@@ -209,7 +213,7 @@ impl MutableFixedSizeBinaryArray {
     /// Assumes that the `i < self.len`.
     #[inline]
     pub unsafe fn value_unchecked(&self, i: usize) -> &[u8] {
-        std::slice::from_raw_parts(self.values.as_ptr().add(i * self.size), self.size)
+        core::slice::from_raw_parts(self.values.as_ptr().add(i * self.size), self.size)
     }
 
     /// Reserves `additional` slots.
@@ -254,8 +258,8 @@ impl MutableArray for MutableFixedSizeBinaryArray {
     fn as_box(&mut self) -> Box<dyn Array> {
         FixedSizeBinaryArray::new(
             DataType::FixedSizeBinary(self.size),
-            std::mem::take(&mut self.values).into(),
-            std::mem::take(&mut self.validity).map(|x| x.into()),
+            core::mem::take(&mut self.values).into(),
+            core::mem::take(&mut self.validity).map(|x| x.into()),
         )
         .boxed()
     }
@@ -263,8 +267,8 @@ impl MutableArray for MutableFixedSizeBinaryArray {
     fn as_arc(&mut self) -> Arc<dyn Array> {
         FixedSizeBinaryArray::new(
             DataType::FixedSizeBinary(self.size),
-            std::mem::take(&mut self.values).into(),
-            std::mem::take(&mut self.validity).map(|x| x.into()),
+            core::mem::take(&mut self.values).into(),
+            core::mem::take(&mut self.validity).map(|x| x.into()),
         )
         .arced()
     }
@@ -273,11 +277,11 @@ impl MutableArray for MutableFixedSizeBinaryArray {
         &self.data_type
     }
 
-    fn as_any(&self) -> &dyn std::any::Any {
+    fn as_any(&self) -> &dyn core::any::Any {
         self
     }
 
-    fn as_mut_any(&mut self) -> &mut dyn std::any::Any {
+    fn as_mut_any(&mut self) -> &mut dyn core::any::Any {
         self
     }
 
@@ -292,6 +296,25 @@ impl MutableArray for MutableFixedSizeBinaryArray {
     fn shrink_to_fit(&mut self) {
         self.shrink_to_fit()
     }
+
+    fn try_extend_from_array(
+        &mut self,
+        other: &dyn Array,
+        start: usize,
+        len: usize,
+    ) -> Result<(), Error> {
+        let other = downcast_extend::<FixedSizeBinaryArray>(other, start, len)?;
+        if other.size() != self.size {
+            return Err(Error::InvalidArgumentError(
+                "Cannot extend a MutableFixedSizeBinaryArray from an array of a different size"
+                    .to_string(),
+            ));
+        }
+        extend_validity_from_bitmap(self.len(), &mut self.validity, other.validity(), start, len);
+        self.values
+            .extend_from_slice(&other.values()[start * self.size..(start + len) * self.size]);
+        Ok(())
+    }
 }
 
 impl FixedSizeBinaryValues for MutableFixedSizeBinaryArray {
@@ -4,6 +4,7 @@ use crate::{
         ffi::{FromFfi, ToFfi},
         Array,
     },
+    bitmap::align,
     error::Result,
     ffi,
 };
@@ -18,25 +19,31 @@ unsafe impl ToFfi for FixedSizeListArray {
     }
 
     fn offset(&self) -> Option<usize> {
-        Some(
-            self.validity
-                .as_ref()
-                .map(|bitmap| bitmap.offset())
-                .unwrap_or_default(),
-        )
+        // the offset of a fixed size list applies to its values, which are already sliced.
+        match self.validity.as_ref() {
+            Some(bitmap) if bitmap.offset() != 0 => None,
+            _ => Some(0),
+        }
     }
 
     fn to_ffi_aligned(&self) -> Self {
-        self.clone()
+        let mut array = self.clone();
+        array.validity = array.validity.map(|bitmap| align(&bitmap, 0));
+        array
     }
 }
 
 impl<A: ffi::ArrowArrayRef> FromFfi<A> for FixedSizeListArray {
     unsafe fn try_from_ffi(array: A) -> Result<Self> {
         let data_type = array.data_type().clone();
+        let (_, size) = Self::try_child_and_size(&data_type)?;
+        let offset = array.array().offset();
+        let length = array.array().len();
+
         let validity = unsafe { array.validity() }?;
         let child = unsafe { array.child(0)? };
-        let values = ffi::try_from(child)?;
+        // the offset of a fixed size list applies to its values
+        let values = ffi::try_from(child)?.slice(offset * size, length * size);
 
         Self::try_new(data_type, values, validity)
     }
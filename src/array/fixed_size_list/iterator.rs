@@ -1,3 +1,5 @@
+use alloc::boxed::Box;
+
 use crate::{
     array::{Array, ArrayAccessor, ArrayValuesIter},
     bitmap::utils::{BitmapIter, ZipValidity},
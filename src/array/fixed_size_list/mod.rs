@@ -1,18 +1,26 @@
 use crate::{
     bitmap::Bitmap,
+    buffer::Buffer,
     datatypes::{DataType, Field},
-    error::Error,
+    error::{Error, Result},
 };
-use std::sync::Arc;
+use alloc::{boxed::Box, sync::Arc, vec::Vec};
 
-use super::{new_empty_array, new_null_array, Array};
+use super::{
+    bitmap_to_bytes, new_empty_array, new_null_array,
+    validation::{validate_fixed_size, validate_validity},
+    Array, ArrayValidation,
+};
 
+#[cfg(feature = "std")]
 mod ffi;
 pub(super) mod fmt;
 mod iterator;
 pub use iterator::*;
 mod mutable;
 pub use mutable::*;
+mod tensor;
+pub use tensor::NullRowPolicy;
 
 /// The Arrow's equivalent to an immutable `Vec<Option<[T; size]>>` where `T` is an Arrow type.
 /// Cloning and slicing this struct is `O(1)`.
@@ -37,7 +45,7 @@ impl FixedSizeListArray {
         data_type: DataType,
         values: Box<dyn Array>,
         validity: Option<Bitmap>,
-    ) -> Result<Self, Error> {
+    ) -> Result<Self> {
         let (child, size) = Self::try_child_and_size(&data_type)?;
 
         let child_data_type = &child.data_type;
@@ -206,7 +214,7 @@ impl FixedSizeListArray {
 }
 
 impl FixedSizeListArray {
-    pub(crate) fn try_child_and_size(data_type: &DataType) -> Result<(&Field, usize), Error> {
+    pub(crate) fn try_child_and_size(data_type: &DataType) -> Result<(&Field, usize)> {
         match data_type.to_logical_type() {
             DataType::FixedSizeList(child, size) => {
                 if *size == 0 {
@@ -233,12 +241,12 @@ impl FixedSizeListArray {
 
 impl Array for FixedSizeListArray {
     #[inline]
-    fn as_any(&self) -> &dyn std::any::Any {
+    fn as_any(&self) -> &dyn core::any::Any {
         self
     }
 
     #[inline]
-    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+    fn as_any_mut(&mut self) -> &mut dyn core::any::Any {
         self
     }
 
@@ -268,4 +276,21 @@ impl Array for FixedSizeListArray {
     fn to_boxed(&self) -> Box<dyn Array> {
         Box::new(self.clone())
     }
+
+    fn buffers(&self) -> Vec<Option<Buffer<u8>>> {
+        vec![self.validity.as_ref().map(bitmap_to_bytes)]
+    }
+
+    fn direct_children(&self) -> Vec<Box<dyn Array>> {
+        vec![self.values.clone()]
+    }
+
+    fn validate(&self, validation: ArrayValidation) -> Result<()> {
+        if validation == ArrayValidation::Off {
+            return Ok(());
+        }
+        validate_fixed_size(self.values.len(), self.size)?;
+        validate_validity(self.validity(), self.len())?;
+        self.values().validate(validation)
+    }
 }
@@ -1,9 +1,10 @@
-use std::sync::Arc;
+use alloc::{boxed::Box, string::ToString, sync::Arc};
 
 use crate::{
     array::{
-        physical_binary::extend_validity, Array, MutableArray, TryExtend, TryExtendFromSelf,
-        TryPush,
+        downcast_extend,
+        physical_binary::{extend_validity, extend_validity_from_bitmap},
+        Array, MutableArray, TryExtend, TryExtendFromSelf, TryPush,
     },
     bitmap::MutableBitmap,
     datatypes::{DataType, Field},
@@ -50,7 +51,7 @@ impl<M: MutableArray> MutableFixedSizeListArray<M> {
     /// Creates a new [`MutableFixedSizeListArray`] from a [`MutableArray`], [`DataType`] and size.
     pub fn new_from(values: M, data_type: DataType, size: usize) -> Self {
         assert_eq!(values.len(), 0);
-        match data_type {
+        match data_type.to_logical_type() {
             DataType::FixedSizeList(..) => (),
             _ => panic!("data type must be FixedSizeList (got {data_type:?})"),
         };
@@ -143,7 +144,7 @@ impl<M: MutableArray + 'static> MutableArray for MutableFixedSizeListArray<M> {
         FixedSizeListArray::new(
             self.data_type.clone(),
             self.values.as_box(),
-            std::mem::take(&mut self.validity).map(|x| x.into()),
+            core::mem::take(&mut self.validity).map(|x| x.into()),
         )
         .boxed()
     }
@@ -152,7 +153,7 @@ impl<M: MutableArray + 'static> MutableArray for MutableFixedSizeListArray<M> {
         FixedSizeListArray::new(
             self.data_type.clone(),
             self.values.as_box(),
-            std::mem::take(&mut self.validity).map(|x| x.into()),
+            core::mem::take(&mut self.validity).map(|x| x.into()),
         )
         .arced()
     }
@@ -161,11 +162,11 @@ impl<M: MutableArray + 'static> MutableArray for MutableFixedSizeListArray<M> {
         &self.data_type
     }
 
-    fn as_any(&self) -> &dyn std::any::Any {
+    fn as_any(&self) -> &dyn core::any::Any {
         self
     }
 
-    fn as_mut_any(&mut self) -> &mut dyn std::any::Any {
+    fn as_mut_any(&mut self) -> &mut dyn core::any::Any {
         self
     }
 
@@ -188,6 +189,24 @@ impl<M: MutableArray + 'static> MutableArray for MutableFixedSizeListArray<M> {
     fn shrink_to_fit(&mut self) {
         self.shrink_to_fit()
     }
+
+    fn try_extend_from_array(&mut self, other: &dyn Array, start: usize, len: usize) -> Result<()> {
+        let other = downcast_extend::<FixedSizeListArray>(other, start, len)?;
+        if other.size() != self.size {
+            return Err(Error::InvalidArgumentError(
+                "Cannot extend a MutableFixedSizeListArray from an array of a different size"
+                    .to_string(),
+            ));
+        }
+        let length = self.len();
+        self.values.try_extend_from_array(
+            other.values().as_ref(),
+            start * self.size,
+            len * self.size,
+        )?;
+        extend_validity_from_bitmap(length, &mut self.validity, other.validity(), start, len);
+        Ok(())
+    }
 }
 
 impl<M, I, T> TryExtend<Option<I>> for MutableFixedSizeListArray<M>
@@ -0,0 +1,122 @@
+use alloc::{boxed::Box, string::ToString, vec::Vec};
+
+use crate::{
+    array::{Array, PrimitiveArray},
+    bitmap::Bitmap,
+    buffer::Buffer,
+    datatypes::DataType,
+    error::{Error, Result},
+    types::NativeType,
+};
+
+use super::FixedSizeListArray;
+
+/// How [`FixedSizeListArray::to_values_matrix`] handles the rows of null slots
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NullRowPolicy {
+    /// Keep the rows of null slots, whose values are undefined
+    Keep,
+    /// Replace the values of the rows of null slots by zeros
+    Zero,
+    /// Remove the rows of null slots
+    Skip,
+    /// Error if there are null slots
+    Error,
+}
+
+impl FixedSizeListArray {
+    /// Returns the [`PrimitiveArray<T>`] of the values of this [`FixedSizeListArray`]
+    /// # Errors
+    /// Errors iff the values are not a [`PrimitiveArray<T>`].
+    pub(crate) fn primitive_values<T: NativeType>(&self) -> Result<&PrimitiveArray<T>> {
+        self.values()
+            .as_any()
+            .downcast_ref::<PrimitiveArray<T>>()
+            .ok_or_else(|| {
+                Error::InvalidArgumentError(format!(
+                    "The values of the FixedSizeListArray must be of type {:?} but are {:?}",
+                    T::PRIMITIVE,
+                    self.values().data_type()
+                ))
+            })
+    }
+
+    /// Returns the validity of the slots of this [`FixedSizeListArray`] that are valid and
+    /// whose values are all valid, or `None` if all of them are.
+    pub(crate) fn valid_rows(&self) -> Option<Bitmap> {
+        let values = match self.values().validity() {
+            Some(validity) if validity.unset_bits() > 0 => validity,
+            _ => return self.validity().filter(|x| x.unset_bits() > 0).cloned(),
+        };
+        let size = self.size();
+        Some(
+            (0..self.len())
+                .map(|i| self.is_valid(i) && (i * size..(i + 1) * size).all(|j| values.get_bit(j)))
+                .collect(),
+        )
+    }
+
+    /// Returns the values of this [`FixedSizeListArray`] as a row-major matrix with
+    /// [`FixedSizeListArray::size`] columns and one row per slot, e.g. to multiply it by another matrix.
+    ///
+    /// `nulls` declares how the rows of null slots are handled; with [`NullRowPolicy::Skip`],
+    /// the matrix has one row per valid slot. The validity of the values is ignored.
+    /// # Implementation
+    /// This operation is `O(1)` unless null slots are zeroed or skipped.
+    /// # Errors
+    /// Errors iff the values are not a [`PrimitiveArray<T>`], or `nulls` is
+    /// [`NullRowPolicy::Error`] and there are null slots.
+    pub fn to_values_matrix<T: NativeType>(&self, nulls: NullRowPolicy) -> Result<Buffer<T>> {
+        let values = self.primitive_values::<T>()?.values();
+        let validity = match self.validity() {
+            Some(validity) if validity.unset_bits() > 0 => validity,
+            _ => return Ok(values.clone()),
+        };
+
+        let rows = values.chunks_exact(self.size());
+        let rows = rows.zip(validity.iter());
+        match nulls {
+            NullRowPolicy::Keep => Ok(values.clone()),
+            NullRowPolicy::Zero => Ok(rows
+                .flat_map(|(row, is_valid)| {
+                    row.iter()
+                        .map(move |x| if is_valid { *x } else { T::default() })
+                })
+                .collect::<Vec<_>>()
+                .into()),
+            NullRowPolicy::Skip => Ok(rows
+                .filter(|(_, is_valid)| *is_valid)
+                .flat_map(|(row, _)| row.iter().copied())
+                .collect::<Vec<_>>()
+                .into()),
+            NullRowPolicy::Error => Err(Error::InvalidArgumentError(format!(
+                "The FixedSizeListArray has {} null slots",
+                validity.unset_bits()
+            ))),
+        }
+    }
+
+    /// Returns a new [`FixedSizeListArray`] with the same values grouped in slots of `size`,
+    /// e.g. to flatten a matrix per slot into its rows.
+    /// # Implementation
+    /// This operation is `O(1)`.
+    /// # Errors
+    /// Errors iff `size` is zero, the number of values is not a multiple of `size`, or
+    /// this array has null slots (whose validity can't be regrouped).
+    pub fn reshape(&self, size: usize) -> Result<Self> {
+        if self.null_count() > 0 {
+            return Err(Error::InvalidArgumentError(
+                "A FixedSizeListArray with null slots can't be reshaped".to_string(),
+            ));
+        }
+        let len = self.values().len();
+        if size == 0 || len / size * size != len {
+            return Err(Error::InvalidArgumentError(format!(
+                "{len} values can't be reshaped in slots of size {size}"
+            )));
+        }
+        let (field, _) = Self::get_child_and_size(self.data_type());
+        let data_type = DataType::FixedSizeList(Box::new(field.clone()), size);
+        Self::try_new(data_type, self.values().clone(), None)
+    }
+}
@@ -1,15 +1,32 @@
-use std::fmt::{Result, Write};
+use alloc::boxed::Box;
+use core::fmt::{Result, Write};
 
 use crate::bitmap::Bitmap;
+use crate::datatypes::get_registered_extension;
 
 use super::Array;
 
 /// Returns a function that writes the value of the element of `array`
 /// at position `index` to a [`Write`],
 /// writing `null` in the null slots.
+///
+/// Values of registered [`ExtensionType`](crate::datatypes::ExtensionType)s are written
+/// by their [`write_value`](crate::datatypes::ExtensionType::write_value).
 pub fn get_value_display<'a, F: Write + 'a>(
     array: &'a dyn Array,
     null: &'static str,
+) -> Box<dyn Fn(&mut F, usize) -> Result + 'a> {
+    if let Some(extension) = get_registered_extension(array.data_type()) {
+        return Box::new(move |f, index| extension.write_value(array, index, f));
+    }
+    get_storage_value_display(array, null)
+}
+
+/// Like [`get_value_display`], but writes the values of extension types as values
+/// of their storage types.
+pub(crate) fn get_storage_value_display<'a, F: Write + 'a>(
+    array: &'a dyn Array,
+    null: &'static str,
 ) -> Box<dyn Fn(&mut F, usize) -> Result + 'a> {
     use crate::datatypes::PhysicalType::*;
     match array.data_type().to_physical_type() {
@@ -88,7 +105,9 @@ pub fn get_value_display<'a, F: Write + 'a>(
         Union => Box::new(move |f, index| {
             super::union::fmt::write_value(array.as_any().downcast_ref().unwrap(), index, null, f)
         }),
-        Map => todo!(),
+        Map => Box::new(move |f, index| {
+            super::map::fmt::write_value(array.as_any().downcast_ref().unwrap(), index, null, f)
+        }),
         Dictionary(key_type) => match_integer_type!(key_type, |$T| {
             Box::new(move |f, index| {
                 super::dictionary::fmt::write_value::<$T,_>(array.as_any().downcast_ref().unwrap(), index, null, f)
@@ -1,4 +1,4 @@
-use std::sync::Arc;
+use alloc::{boxed::Box, sync::Arc, vec::Vec};
 
 use crate::{
     array::{Array, BinaryArray},
@@ -52,9 +52,9 @@ impl<'a, O: Offset> GrowableBinary<'a, O> {
 
     fn to(&mut self) -> BinaryArray<O> {
         let data_type = self.data_type.clone();
-        let validity = std::mem::take(&mut self.validity);
-        let offsets = std::mem::take(&mut self.offsets);
-        let values = std::mem::take(&mut self.values);
+        let validity = core::mem::take(&mut self.validity);
+        let offsets = core::mem::take(&mut self.offsets);
+        let values = core::mem::take(&mut self.values);
 
         BinaryArray::<O>::new(data_type, offsets.into(), values.into(), validity.into())
     }
@@ -1,4 +1,4 @@
-use std::sync::Arc;
+use alloc::{boxed::Box, sync::Arc, vec::Vec};
 
 use crate::{
     array::{Array, BooleanArray},
@@ -48,8 +48,8 @@ impl<'a> GrowableBoolean<'a> {
     }
 
     fn to(&mut self) -> BooleanArray {
-        let validity = std::mem::take(&mut self.validity);
-        let values = std::mem::take(&mut self.values);
+        let validity = core::mem::take(&mut self.validity);
+        let values = core::mem::take(&mut self.values);
 
         BooleanArray::new(self.data_type.clone(), values.into(), validity.into())
     }
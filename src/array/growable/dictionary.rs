@@ -1,9 +1,14 @@
-use std::sync::Arc;
+use alloc::{boxed::Box, sync::Arc, vec::Vec};
+use core::hash::{BuildHasher, Hash, Hasher};
+
+use ahash::RandomState;
 
 use crate::{
-    array::{Array, DictionaryArray, DictionaryKey, PrimitiveArray},
+    array::{equal, Array, BinaryArray, DictionaryArray, DictionaryKey, PrimitiveArray, Utf8Array},
     bitmap::MutableBitmap,
-    datatypes::DataType,
+    datatypes::{DataType, PhysicalType},
+    offset::Offset,
+    types::NativeType,
 };
 
 use super::{
@@ -14,8 +19,10 @@ use super::{
 
 /// Concrete [`Growable`] for the [`DictionaryArray`].
 /// # Implementation
-/// This growable does not perform collision checks and instead concatenates
-/// the values of each [`DictionaryArray`] one after the other.
+/// This growable does not perform collision checks between values and instead concatenates
+/// the distinct dictionaries of the [`DictionaryArray`]s one after the other. Arrays
+/// whose dictionaries are identical (the same array or equal values) share them, so that
+/// their keys are only re-keyed when dictionaries differ.
 pub struct GrowableDictionary<'a, K: DictionaryKey> {
     data_type: DataType,
     keys_values: Vec<&'a [K]>,
@@ -26,17 +33,95 @@ pub struct GrowableDictionary<'a, K: DictionaryKey> {
     extend_null_bits: Vec<ExtendNullBits<'a>>,
 }
 
-fn concatenate_values<K: DictionaryKey>(
-    arrays_keys: &[&PrimitiveArray<K>],
+fn hash_values<T: Hash>(values: impl Iterator<Item = T>) -> u64 {
+    let mut hasher = RandomState::with_seeds(0, 0, 0, 0).build_hasher();
+    values.for_each(|x| x.hash(&mut hasher));
+    hasher.finish()
+}
+
+fn hash_primitive<T: NativeType>(array: &PrimitiveArray<T>) -> u64 {
+    hash_values(array.iter().map(|x| x.map(bytemuck::bytes_of)))
+}
+
+fn hash_utf8<O: Offset>(array: &Utf8Array<O>) -> u64 {
+    hash_values(array.iter())
+}
+
+fn hash_binary<O: Offset>(array: &BinaryArray<O>) -> u64 {
+    hash_values(array.iter())
+}
+
+/// Returns a hash of the content of `array`, or `None` if its type is not supported.
+fn content_hash(array: &dyn Array) -> Option<u64> {
+    use PhysicalType::*;
+    let data_type = array.data_type();
+    let array = array.as_any();
+    Some(match data_type.to_physical_type() {
+        Primitive(primitive) => with_match_primitive_type!(primitive, |$T| {
+            hash_primitive::<$T>(array.downcast_ref().unwrap())
+        }),
+        Utf8 => hash_utf8::<i32>(array.downcast_ref().unwrap()),
+        LargeUtf8 => hash_utf8::<i64>(array.downcast_ref().unwrap()),
+        Binary => hash_binary::<i32>(array.downcast_ref().unwrap()),
+        LargeBinary => hash_binary::<i64>(array.downcast_ref().unwrap()),
+        _ => return None,
+    })
+}
+
+/// Returns, for every dictionary, the index of the first dictionary identical to it.
+///
+/// Dictionaries are identical if they are the same array, or if their content hashes match
+/// and their values are equal. Dictionaries of types without content hash are only
+/// deduplicated when they are the same array.
+fn deduplicate(arrays_values: &[&dyn Array]) -> Vec<usize> {
+    let hashes = arrays_values
+        .iter()
+        .map(|values| content_hash(*values))
+        .collect::<Vec<_>>();
+    let mut distinct: Vec<usize> = vec![];
+    arrays_values
+        .iter()
+        .enumerate()
+        .map(|(i, values)| {
+            let same = distinct.iter().copied().find(|&j| {
+                let other = arrays_values[j];
+                core::ptr::eq(
+                    *values as *const dyn Array as *const (),
+                    other as *const dyn Array as *const (),
+                ) || (hashes[i].is_some()
+                    && hashes[i] == hashes[j]
+                    && values.len() == other.len()
+                    && equal(*values, other))
+            });
+            same.unwrap_or_else(|| {
+                distinct.push(i);
+                i
+            })
+        })
+        .collect()
+}
+
+fn concatenate_values(
     arrays_values: &[&dyn Array],
     capacity: usize,
 ) -> (Box<dyn Array>, Vec<usize>) {
+    let dictionaries = deduplicate(arrays_values);
+    if dictionaries.iter().all(|&i| i == 0) {
+        // a single dictionary is shared by all arrays and requires no re-keying
+        return (arrays_values[0].to_boxed(), vec![0; arrays_values.len()]);
+    }
+
     let mut mutable = make_growable(arrays_values, false, capacity);
-    let mut offsets = Vec::with_capacity(arrays_keys.len() + 1);
-    offsets.push(0);
+    let mut offsets = Vec::with_capacity(arrays_values.len());
+    let mut len = 0;
     for (i, values) in arrays_values.iter().enumerate() {
-        mutable.extend(i, 0, values.len());
-        offsets.push(offsets[i] + values.len());
+        if dictionaries[i] == i {
+            mutable.extend(i, 0, values.len());
+            offsets.push(len);
+            len += values.len();
+        } else {
+            offsets.push(offsets[dictionaries[i]]);
+        }
     }
     (mutable.as_box(), offsets)
 }
@@ -70,7 +155,7 @@ impl<'a, T: DictionaryKey> GrowableDictionary<'a, T> {
             .map(|array| array.values().as_ref())
             .collect::<Vec<_>>();
 
-        let (values, offsets) = concatenate_values(&arrays_keys, &arrays_values, capacity);
+        let (values, offsets) = concatenate_values(&arrays_values, capacity);
 
         Self {
             data_type,
@@ -85,8 +170,8 @@ impl<'a, T: DictionaryKey> GrowableDictionary<'a, T> {
 
     #[inline]
     fn to(&mut self) -> DictionaryArray<T> {
-        let validity = std::mem::take(&mut self.key_validity);
-        let key_values = std::mem::take(&mut self.key_values);
+        let validity = core::mem::take(&mut self.key_validity);
+        let key_values = core::mem::take(&mut self.key_values);
 
         #[cfg(debug_assertions)]
         {
@@ -114,6 +199,10 @@ impl<'a, T: DictionaryKey> Growable<'a> for GrowableDictionary<'a, T> {
 
         let values = &self.keys_values[index][start..start + len];
         let offset = self.offsets[index];
+        if offset == 0 {
+            self.key_values.extend_from_slice(values);
+            return;
+        }
         self.key_values.extend(
             values
                 .iter()
@@ -1,4 +1,4 @@
-use std::sync::Arc;
+use alloc::{boxed::Box, sync::Arc, vec::Vec};
 
 use crate::{
     array::{Array, FixedSizeBinaryArray},
@@ -50,8 +50,8 @@ impl<'a> GrowableFixedSizeBinary<'a> {
     }
 
     fn to(&mut self) -> FixedSizeBinaryArray {
-        let validity = std::mem::take(&mut self.validity);
-        let values = std::mem::take(&mut self.values);
+        let validity = core::mem::take(&mut self.validity);
+        let values = core::mem::take(&mut self.values);
 
         FixedSizeBinaryArray::new(
             self.arrays[0].data_type().clone(),
@@ -1,4 +1,4 @@
-use std::sync::Arc;
+use alloc::{boxed::Box, sync::Arc, vec::Vec};
 
 use crate::{
     array::{Array, FixedSizeListArray},
@@ -66,7 +66,7 @@ impl<'a> GrowableFixedSizeList<'a> {
     }
 
     fn to(&mut self) -> FixedSizeListArray {
-        let validity = std::mem::take(&mut self.validity);
+        let validity = core::mem::take(&mut self.validity);
         let values = self.values.as_box();
 
         FixedSizeListArray::new(self.arrays[0].data_type().clone(), values, validity.into())
@@ -1,4 +1,4 @@
-use std::sync::Arc;
+use alloc::{boxed::Box, sync::Arc, vec::Vec};
 
 use crate::{
     array::{Array, ListArray},
@@ -73,8 +73,8 @@ impl<'a, O: Offset> GrowableList<'a, O> {
     }
 
     fn to(&mut self) -> ListArray<O> {
-        let validity = std::mem::take(&mut self.validity);
-        let offsets = std::mem::take(&mut self.offsets);
+        let validity = core::mem::take(&mut self.validity);
+        let offsets = core::mem::take(&mut self.offsets);
         let values = self.values.as_box();
 
         ListArray::<O>::new(
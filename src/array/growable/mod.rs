@@ -3,7 +3,7 @@
 
 use crate::array::*;
 use crate::datatypes::*;
-use std::sync::Arc;
+use alloc::{boxed::Box, sync::Arc, vec::Vec};
 
 mod binary;
 pub use binary::GrowableBinary;
@@ -19,6 +19,8 @@ mod primitive;
 pub use primitive::GrowablePrimitive;
 mod list;
 pub use list::GrowableList;
+mod map;
+pub use map::GrowableMap;
 mod structure;
 pub use structure::GrowableStruct;
 mod fixed_size_list;
@@ -67,10 +69,43 @@ macro_rules! dyn_growable {
     }};
 }
 
+/// A [`Growable`] over typed arrays and [`NullArray`]s, whose slices are inserted as nulls.
+struct GrowableWithNulls<'a> {
+    inner: Box<dyn Growable<'a> + 'a>,
+    // the index of each array on `inner`, or `None` if the array is a `NullArray`
+    indices: Vec<Option<usize>>,
+}
+
+impl<'a> Growable<'a> for GrowableWithNulls<'a> {
+    fn extend(&mut self, index: usize, start: usize, len: usize) {
+        match self.indices[index] {
+            Some(index) => self.inner.extend(index, start, len),
+            None => self.inner.extend_validity(len),
+        }
+    }
+
+    fn extend_validity(&mut self, additional: usize) {
+        self.inner.extend_validity(additional)
+    }
+
+    #[inline]
+    fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    fn as_box(&mut self) -> Box<dyn Array> {
+        self.inner.as_box()
+    }
+}
+
 /// Creates a new [`Growable`] from an arbitrary number of [`Array`]s.
+///
+/// [`NullArray`]s can be mixed with arrays of any other (non-union) type; their slots
+/// are inserted as nulls of that type.
 /// # Panics
 /// This function panics iff
-/// * the arrays do not have the same [`DataType`].
+/// * the arrays that are not [`NullArray`]s do not have the same [`DataType`].
+/// * [`NullArray`]s are mixed with [`UnionArray`]s.
 /// * `arrays.is_empty()`.
 pub fn make_growable<'a>(
     arrays: &[&'a dyn Array],
@@ -78,6 +113,42 @@ pub fn make_growable<'a>(
     capacity: usize,
 ) -> Box<dyn Growable<'a> + 'a> {
     assert!(!arrays.is_empty());
+    let is_null = |array: &dyn Array| array.data_type().to_physical_type() == PhysicalType::Null;
+
+    let typed = arrays
+        .iter()
+        .copied()
+        .filter(|array| !is_null(*array))
+        .collect::<Vec<_>>();
+    if typed.is_empty() || typed.len() == arrays.len() {
+        return make_typed_growable(arrays, use_validity, capacity);
+    }
+    assert!(
+        typed[0].data_type().to_physical_type() != PhysicalType::Union,
+        "Null arrays can't be mixed with union arrays"
+    );
+
+    let mut index = 0;
+    let indices = arrays
+        .iter()
+        .map(|array| {
+            (!is_null(*array)).then(|| {
+                index += 1;
+                index - 1
+            })
+        })
+        .collect();
+    Box::new(GrowableWithNulls {
+        inner: make_typed_growable(&typed, true, capacity),
+        indices,
+    })
+}
+
+fn make_typed_growable<'a>(
+    arrays: &[&'a dyn Array],
+    use_validity: bool,
+    capacity: usize,
+) -> Box<dyn Growable<'a> + 'a> {
     let data_type = arrays[0].data_type();
 
     use PhysicalType::*;
@@ -123,7 +194,7 @@ pub fn make_growable<'a>(
                 .collect::<Vec<_>>();
             Box::new(union::GrowableUnion::new(arrays, capacity))
         }
-        Map => todo!(),
+        Map => dyn_growable!(map::GrowableMap, arrays, use_validity, capacity),
         Dictionary(key_type) => {
             match_integer_type!(key_type, |$T| {
                 let arrays = arrays
@@ -1,4 +1,4 @@
-use std::sync::Arc;
+use alloc::{boxed::Box, sync::Arc};
 
 use crate::{
     array::{Array, NullArray},
@@ -1,4 +1,4 @@
-use std::sync::Arc;
+use alloc::{boxed::Box, sync::Arc, vec::Vec};
 
 use crate::{
     array::{Array, PrimitiveArray},
@@ -59,8 +59,8 @@ impl<'a, T: NativeType> GrowablePrimitive<'a, T> {
 
     #[inline]
     fn to(&mut self) -> PrimitiveArray<T> {
-        let validity = std::mem::take(&mut self.validity);
-        let values = std::mem::take(&mut self.values);
+        let validity = core::mem::take(&mut self.validity);
+        let values = core::mem::take(&mut self.values);
 
         PrimitiveArray::<T>::new(self.data_type.clone(), values.into(), validity.into())
     }
@@ -1,4 +1,4 @@
-use std::sync::Arc;
+use alloc::{boxed::Box, sync::Arc, vec::Vec};
 
 use crate::{
     array::{Array, StructArray},
@@ -65,8 +65,8 @@ impl<'a> GrowableStruct<'a> {
     }
 
     fn to(&mut self) -> StructArray {
-        let validity = std::mem::take(&mut self.validity);
-        let values = std::mem::take(&mut self.values);
+        let validity = core::mem::take(&mut self.validity);
+        let values = core::mem::take(&mut self.values);
         let values = values.into_iter().map(|mut x| x.as_box()).collect();
 
         StructArray::new(self.arrays[0].data_type().clone(), values, validity.into())
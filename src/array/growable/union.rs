@@ -1,4 +1,4 @@
-use std::sync::Arc;
+use alloc::{boxed::Box, sync::Arc, vec::Vec};
 
 use crate::array::{Array, UnionArray};
 
@@ -50,9 +50,9 @@ impl<'a> GrowableUnion<'a> {
     }
 
     fn to(&mut self) -> UnionArray {
-        let types = std::mem::take(&mut self.types);
-        let fields = std::mem::take(&mut self.fields);
-        let offsets = std::mem::take(&mut self.offsets);
+        let types = core::mem::take(&mut self.types);
+        let fields = core::mem::take(&mut self.fields);
+        let offsets = core::mem::take(&mut self.offsets);
         let fields = fields.into_iter().map(|mut x| x.as_box()).collect();
 
         UnionArray::new(
@@ -71,20 +71,21 @@ impl<'a> Growable<'a> for GrowableUnion<'a> {
         let types = &array.types()[start..start + len];
         self.types.extend(types);
         if let Some(x) = self.offsets.as_mut() {
-            let offsets = &array.offsets().unwrap()[start..start + len];
-
             // in a dense union, each slot has its own offset. We extend the fields accordingly.
-            for (&type_, &offset) in types.iter().zip(offsets.iter()) {
-                let field = &mut self.fields[type_ as usize];
+            for slot in start..start + len {
+                let (field_index, offset) = array.index(slot);
+                let field = &mut self.fields[field_index];
                 // The offset for the element that is about to be extended is the current length
                 // of the child field of the corresponding type. Note that this may be very
                 // different than the original offset from the array we are extending from as
                 // it is a function of the previous extensions to this child.
                 x.push(field.len() as i32);
-                field.extend(index, offset as usize, 1);
+                field.extend(index, offset, 1);
             }
-        } else {
-            // in a sparse union, every field has the same length => extend all fields equally
+        } else if len > 0 {
+            // in a sparse union, every field has the same length => extend all fields equally,
+            // from the slot of the fields corresponding to `start` (the array may be sliced)
+            let (_, start) = array.index(start);
             self.fields
                 .iter_mut()
                 .for_each(|field| field.extend(index, start, len))
@@ -1,4 +1,4 @@
-use std::sync::Arc;
+use alloc::{boxed::Box, sync::Arc, vec::Vec};
 
 use crate::{
     array::{Array, Utf8Array},
@@ -46,9 +46,9 @@ impl<'a, O: Offset> GrowableUtf8<'a, O> {
     }
 
     fn to(&mut self) -> Utf8Array<O> {
-        let validity = std::mem::take(&mut self.validity);
-        let offsets = std::mem::take(&mut self.offsets);
-        let values = std::mem::take(&mut self.values);
+        let validity = core::mem::take(&mut self.validity);
+        let offsets = core::mem::take(&mut self.offsets);
+        let values = core::mem::take(&mut self.values);
 
         #[cfg(debug_assertions)]
         {
@@ -1,3 +1,5 @@
+use alloc::{boxed::Box, vec::Vec};
+
 use crate::{array::Array, bitmap::MutableBitmap, offset::Offset};
 
 // function used to extend nulls from arrays. This function's lifetime is bound to the array
@@ -0,0 +1,71 @@
+use crate::{
+    array::{MutableArray, TryExtend, TryPush},
+    error::Error,
+    offset::Offset,
+    trusted_len::TrustedLen,
+};
+
+use super::{ListArray, MutableListArray};
+
+impl<O: Offset> ListArray<O> {
+    /// Creates a new [`ListArray`] from an iterator of optional lists, whose items are
+    /// pushed to `values`.
+    /// # Errors
+    /// This function errors iff `values` errors or the offsets overflow `O`.
+    /// # Example
+    /// ```
+    /// use arrow2::array::{ListArray, MutablePrimitiveArray};
+    ///
+    /// let data = vec![Some(vec![Some(1i32), None]), None, Some(vec![])];
+    /// let array = ListArray::<i32>::try_from_iter(MutablePrimitiveArray::<i32>::new(), data).unwrap();
+    /// assert_eq!(array.len(), 3);
+    /// assert_eq!(array.offsets().as_slice(), &[0, 2, 2, 2]);
+    /// ```
+    pub fn try_from_iter<M, I, P, T>(values: M, iter: I) -> Result<Self, Error>
+    where
+        M: MutableArray + TryExtend<Option<T>>,
+        I: IntoIterator<Item = Option<P>>,
+        P: IntoIterator<Item = Option<T>>,
+    {
+        let iter = iter.into_iter();
+        let mut array = MutableListArray::<O, M>::new_with_capacity(values, iter.size_hint().0);
+        array.try_extend(iter)?;
+        Ok(array.into())
+    }
+
+    /// Creates a new [`ListArray`] from a [`TrustedLen`] of optional lists, whose items are
+    /// pushed to `values`.
+    /// # Panics
+    /// This function panics iff `values` errors or the offsets overflow `O`.
+    pub fn from_trusted_len_iter<M, I, P, T>(values: M, iter: I) -> Self
+    where
+        M: MutableArray + TryExtend<Option<T>>,
+        I: TrustedLen<Item = Option<P>>,
+        P: IntoIterator<Item = Option<T>>,
+    {
+        Self::try_from_iter(values, iter).unwrap()
+    }
+
+    /// Creates a new [`ListArray`] from a fallible [`TrustedLen`] of optional lists, whose
+    /// items are pushed to `values`.
+    /// # Errors
+    /// This function errors on the first error of the iterator, or iff `values` errors or the
+    /// offsets overflow `O`.
+    pub fn try_from_trusted_len_iter<E, M, I, P, T>(values: M, iter: I) -> Result<Self, E>
+    where
+        E: From<Error>,
+        M: MutableArray + TryExtend<Option<T>>,
+        I: TrustedLen<Item = Result<Option<P>, E>>,
+        P: IntoIterator<Item = Option<T>>,
+    {
+        let (_, upper) = iter.size_hint();
+        let mut array = MutableListArray::<O, M>::new_with_capacity(
+            values,
+            upper.expect("try_from_trusted_len_iter requires an upper limit"),
+        );
+        for item in iter {
+            array.try_push(item?)?;
+        }
+        Ok(array.into())
+    }
+}
@@ -1,3 +1,5 @@
+use alloc::boxed::Box;
+
 use crate::array::{Array, ArrayAccessor, ArrayValuesIter};
 use crate::bitmap::utils::{BitmapIter, ZipValidity};
 use crate::offset::Offset;
@@ -46,12 +48,12 @@ impl<'a, O: Offset> ListArray<O> {
 
 struct Iter<T, I: Iterator<Item = Option<T>>> {
     current: i32,
-    offsets: std::vec::IntoIter<i32>,
+    offsets: alloc::vec::IntoIter<i32>,
     values: I,
 }
 
 impl<T, I: Iterator<Item = Option<T>> + Clone> Iterator for Iter<T, I> {
-    type Item = Option<std::iter::Take<std::iter::Skip<I>>>;
+    type Item = Option<core::iter::Take<core::iter::Skip<I>>>;
 
     fn next(&mut self) -> Option<Self::Item> {
         let next = self.offsets.next();
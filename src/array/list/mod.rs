@@ -1,15 +1,23 @@
 use crate::{
     bitmap::Bitmap,
+    buffer::Buffer,
     datatypes::{DataType, Field},
-    error::Error,
+    error::{Error, Result},
     offset::{Offset, Offsets, OffsetsBuffer},
 };
-use std::sync::Arc;
+use alloc::{boxed::Box, sync::Arc, vec::Vec};
 
-use super::{new_empty_array, specification::try_check_offsets_bounds, Array};
+use super::{
+    bitmap_to_bytes, buffer_to_bytes, new_empty_array,
+    specification::try_check_offsets_bounds,
+    validation::{validate_offsets, validate_validity},
+    Array, ArrayValidation,
+};
 
+#[cfg(feature = "std")]
 mod ffi;
 pub(super) mod fmt;
+mod from;
 mod iterator;
 pub use iterator::*;
 mod mutable;
@@ -40,7 +48,7 @@ impl<O: Offset> ListArray<O> {
         offsets: OffsetsBuffer<O>,
         values: Box<dyn Array>,
         validity: Option<Bitmap>,
-    ) -> Result<Self, Error> {
+    ) -> Result<Self> {
         try_check_offsets_bounds(&offsets, values.len())?;
 
         if validity
@@ -237,7 +245,7 @@ impl<O: Offset> ListArray<O> {
     /// Returns a the inner [`Field`]
     /// # Errors
     /// Panics iff the logical type is not consistent with this struct.
-    pub fn try_get_child(data_type: &DataType) -> Result<&Field, Error> {
+    pub fn try_get_child(data_type: &DataType) -> Result<&Field> {
         if O::IS_LARGE {
             match data_type.to_logical_type() {
                 DataType::LargeList(child) => Ok(child.as_ref()),
@@ -261,12 +269,12 @@ impl<O: Offset> ListArray<O> {
 
 impl<O: Offset> Array for ListArray<O> {
     #[inline]
-    fn as_any(&self) -> &dyn std::any::Any {
+    fn as_any(&self) -> &dyn core::any::Any {
         self
     }
 
     #[inline]
-    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+    fn as_any_mut(&mut self) -> &mut dyn core::any::Any {
         self
     }
 
@@ -300,4 +308,24 @@ impl<O: Offset> Array for ListArray<O> {
     fn to_boxed(&self) -> Box<dyn Array> {
         Box::new(self.clone())
     }
+
+    fn buffers(&self) -> Vec<Option<Buffer<u8>>> {
+        vec![
+            self.validity.as_ref().map(bitmap_to_bytes),
+            Some(buffer_to_bytes(self.offsets.buffer())),
+        ]
+    }
+
+    fn direct_children(&self) -> Vec<Box<dyn Array>> {
+        vec![self.values.clone()]
+    }
+
+    fn validate(&self, validation: ArrayValidation) -> Result<()> {
+        if validation == ArrayValidation::Off {
+            return Ok(());
+        }
+        validate_validity(self.validity(), self.len())?;
+        validate_offsets(self.offsets(), self.values().len(), validation)?;
+        self.values().validate(validation)
+    }
 }
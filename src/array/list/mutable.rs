@@ -1,9 +1,10 @@
-use std::sync::Arc;
+use alloc::{boxed::Box, sync::Arc};
 
 use crate::{
     array::{
-        physical_binary::extend_validity, Array, MutableArray, TryExtend, TryExtendFromSelf,
-        TryPush,
+        downcast_extend,
+        physical_binary::{extend_validity, extend_validity_from_bitmap},
+        Array, MutableArray, TryExtend, TryExtendFromSelf, TryPush,
     },
     bitmap::MutableBitmap,
     datatypes::{DataType, Field},
@@ -260,9 +261,9 @@ impl<O: Offset, M: MutableArray + 'static> MutableArray for MutableListArray<O,
     fn as_box(&mut self) -> Box<dyn Array> {
         ListArray::new(
             self.data_type.clone(),
-            std::mem::take(&mut self.offsets).into(),
+            core::mem::take(&mut self.offsets).into(),
             self.values.as_box(),
-            std::mem::take(&mut self.validity).map(|x| x.into()),
+            core::mem::take(&mut self.validity).map(|x| x.into()),
         )
         .boxed()
     }
@@ -270,9 +271,9 @@ impl<O: Offset, M: MutableArray + 'static> MutableArray for MutableListArray<O,
     fn as_arc(&mut self) -> Arc<dyn Array> {
         ListArray::new(
             self.data_type.clone(),
-            std::mem::take(&mut self.offsets).into(),
+            core::mem::take(&mut self.offsets).into(),
             self.values.as_box(),
-            std::mem::take(&mut self.validity).map(|x| x.into()),
+            core::mem::take(&mut self.validity).map(|x| x.into()),
         )
         .arced()
     }
@@ -281,11 +282,11 @@ impl<O: Offset, M: MutableArray + 'static> MutableArray for MutableListArray<O,
         &self.data_type
     }
 
-    fn as_any(&self) -> &dyn std::any::Any {
+    fn as_any(&self) -> &dyn core::any::Any {
         self
     }
 
-    fn as_mut_any(&mut self) -> &mut dyn std::any::Any {
+    fn as_mut_any(&mut self) -> &mut dyn core::any::Any {
         self
     }
 
@@ -301,4 +302,26 @@ impl<O: Offset, M: MutableArray + 'static> MutableArray for MutableListArray<O,
     fn shrink_to_fit(&mut self) {
         self.shrink_to_fit();
     }
+
+    fn try_extend_from_array(&mut self, other: &dyn Array, start: usize, len: usize) -> Result<()> {
+        let other = downcast_extend::<ListArray<O>>(other, start, len)?;
+        let offsets = other.offsets().buffer();
+        let values_start = offsets[start].to_usize();
+        let values_end = offsets[start + len].to_usize();
+        self.values.try_extend_from_array(
+            other.values().as_ref(),
+            values_start,
+            values_end - values_start,
+        )?;
+        self.offsets
+            .try_extend_from_slice(other.offsets(), start, len)?;
+        extend_validity_from_bitmap(
+            self.len() - len,
+            &mut self.validity,
+            other.validity(),
+            start,
+            len,
+        );
+        Ok(())
+    }
 }
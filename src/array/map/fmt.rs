@@ -1,17 +1,26 @@
-use std::fmt::{Debug, Formatter, Result, Write};
+use core::fmt::{Debug, Formatter, Result, Write};
 
-use super::super::fmt::{get_display, write_vec};
+use super::super::fmt::{get_display, write_map, write_vec};
+use super::super::StructArray;
 use super::MapArray;
 
+/// Writes the entries of the map at `index` as `{key: value, ...}`
 pub fn write_value<W: Write>(
     array: &MapArray,
     index: usize,
     null: &'static str,
     f: &mut W,
 ) -> Result {
-    let values = array.value(index);
-    let writer = |f: &mut W, index| get_display(values.as_ref(), null)(f, index);
-    write_vec(f, writer, None, values.len(), null, false)
+    let entries = array.value(index);
+    let entries = entries.as_any().downcast_ref::<StructArray>().unwrap();
+    let keys = get_display(entries.values()[0].as_ref(), null);
+    let values = get_display(entries.values()[1].as_ref(), null);
+    let writer = |f: &mut W, index| {
+        keys(f, index)?;
+        write!(f, ": ")?;
+        values(f, index)
+    };
+    write_map(f, writer, None, entries.values()[0].len(), null, false)
 }
 
 impl Debug for MapArray {
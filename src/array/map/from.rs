@@ -0,0 +1,146 @@
+use alloc::boxed::Box;
+
+use crate::{
+    array::{Array, MutableArray, StructArray, TryPush},
+    bitmap::MutableBitmap,
+    datatypes::{DataType, Field},
+    error::Error,
+    offset::Offsets,
+    trusted_len::TrustedLen,
+};
+
+use super::MapArray;
+
+/// The mutable arrays of a [`MapArray`] being built from an iterator
+struct Entries<K, V> {
+    keys: K,
+    values: V,
+    offsets: Offsets<i32>,
+    validity: MutableBitmap,
+}
+
+impl<K: MutableArray, V: MutableArray> Entries<K, V> {
+    fn new(keys: K, values: V, capacity: usize) -> Self {
+        Self {
+            keys,
+            values,
+            offsets: Offsets::with_capacity(capacity),
+            validity: MutableBitmap::with_capacity(capacity),
+        }
+    }
+
+    fn try_push<P, TK, TV>(&mut self, item: Option<P>) -> Result<(), Error>
+    where
+        K: TryPush<Option<TK>>,
+        V: TryPush<Option<TV>>,
+        P: IntoIterator<Item = (TK, Option<TV>)>,
+    {
+        let mut length = 0;
+        if let Some(entries) = item {
+            for (key, value) in entries {
+                self.keys.try_push(Some(key))?;
+                self.values.try_push(value)?;
+                length += 1;
+            }
+            self.validity.push(true);
+        } else {
+            self.validity.push(false);
+        }
+        self.offsets.try_push_usize(length)
+    }
+
+    fn finish(mut self) -> Result<MapArray, Error> {
+        let keys = self.keys.as_box();
+        let values = self.values.as_box();
+        let fields = vec![
+            Field::new("keys", keys.data_type().clone(), false),
+            Field::new("values", values.data_type().clone(), true),
+        ];
+        let field = StructArray::try_new(DataType::Struct(fields), vec![keys, values], None)?;
+        let data_type = DataType::Map(
+            Box::new(Field::new("entries", field.data_type().clone(), false)),
+            false,
+        );
+        MapArray::try_new(
+            data_type,
+            self.offsets.into(),
+            field.boxed(),
+            self.validity.into(),
+        )
+    }
+}
+
+impl MapArray {
+    /// Creates a new [`MapArray`] from an iterator of optional maps, whose keys are pushed to
+    /// `keys` and whose values are pushed to `values`.
+    /// # Errors
+    /// This function errors iff `keys` or `values` error or the offsets overflow.
+    /// # Example
+    /// ```
+    /// use arrow2::array::{MapArray, MutablePrimitiveArray, MutableUtf8Array};
+    ///
+    /// let data = vec![Some(vec![("a", Some(1i32)), ("b", None)]), None, Some(vec![])];
+    /// let array = MapArray::try_from_iter(
+    ///     MutableUtf8Array::<i32>::new(),
+    ///     MutablePrimitiveArray::<i32>::new(),
+    ///     data,
+    /// )
+    /// .unwrap();
+    /// assert_eq!(array.len(), 3);
+    /// assert_eq!(array.offsets().as_slice(), &[0, 2, 2, 2]);
+    /// ```
+    pub fn try_from_iter<K, V, I, P, TK, TV>(keys: K, values: V, iter: I) -> Result<Self, Error>
+    where
+        K: MutableArray + TryPush<Option<TK>>,
+        V: MutableArray + TryPush<Option<TV>>,
+        I: IntoIterator<Item = Option<P>>,
+        P: IntoIterator<Item = (TK, Option<TV>)>,
+    {
+        let iter = iter.into_iter();
+        let mut entries = Entries::new(keys, values, iter.size_hint().0);
+        for item in iter {
+            entries.try_push(item)?;
+        }
+        entries.finish()
+    }
+
+    /// Creates a new [`MapArray`] from a [`TrustedLen`] of optional maps, whose keys are
+    /// pushed to `keys` and whose values are pushed to `values`.
+    /// # Panics
+    /// This function panics iff `keys` or `values` error or the offsets overflow.
+    pub fn from_trusted_len_iter<K, V, I, P, TK, TV>(keys: K, values: V, iter: I) -> Self
+    where
+        K: MutableArray + TryPush<Option<TK>>,
+        V: MutableArray + TryPush<Option<TV>>,
+        I: TrustedLen<Item = Option<P>>,
+        P: IntoIterator<Item = (TK, Option<TV>)>,
+    {
+        Self::try_from_iter(keys, values, iter).unwrap()
+    }
+
+    /// Creates a new [`MapArray`] from a fallible [`TrustedLen`] of optional maps, whose keys
+    /// are pushed to `keys` and whose values are pushed to `values`.
+    /// # Errors
+    /// This function errors on the first error of the iterator, or iff `keys` or `values`
+    /// error or the offsets overflow.
+    pub fn try_from_trusted_len_iter<E, K, V, I, P, TK, TV>(
+        keys: K,
+        values: V,
+        iter: I,
+    ) -> Result<Self, E>
+    where
+        E: From<Error>,
+        K: MutableArray + TryPush<Option<TK>>,
+        V: MutableArray + TryPush<Option<TV>>,
+        I: TrustedLen<Item = Result<Option<P>, E>>,
+        P: IntoIterator<Item = (TK, Option<TV>)>,
+    {
+        let (_, upper) = iter.size_hint();
+        let capacity = upper.expect("try_from_trusted_len_iter requires an upper limit");
+        let mut entries = Entries::new(keys, values, capacity);
+        for item in iter {
+            entries.try_push(item?)?;
+        }
+        Ok(entries.finish()?)
+    }
+}
@@ -1,3 +1,5 @@
+use alloc::boxed::Box;
+
 use crate::array::Array;
 use crate::bitmap::utils::{BitmapIter, ZipValidity};
 use crate::trusted_len::TrustedLen;
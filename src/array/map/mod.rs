@@ -1,16 +1,28 @@
+use alloc::{boxed::Box, string::ToString, vec::Vec};
+
 use crate::{
     bitmap::Bitmap,
+    buffer::Buffer,
     datatypes::{DataType, Field},
-    error::Error,
+    error::{Error, Result},
     offset::OffsetsBuffer,
 };
 
-use super::{new_empty_array, specification::try_check_offsets_bounds, Array};
+use super::{
+    bitmap_to_bytes, buffer_to_bytes, new_empty_array,
+    specification::try_check_offsets_bounds,
+    validation::{validate_offsets, validate_validity},
+    Array, ArrayValidation, StructArray,
+};
 
+#[cfg(feature = "std")]
 mod ffi;
-mod fmt;
+pub(super) mod fmt;
+mod from;
 mod iterator;
 pub use iterator::*;
+mod mutable;
+pub use mutable::*;
 
 /// An array representing a (key, value), both of arbitrary logical types.
 #[derive(Clone)]
@@ -36,7 +48,7 @@ impl MapArray {
         offsets: OffsetsBuffer<i32>,
         field: Box<dyn Array>,
         validity: Option<Bitmap>,
-    ) -> Result<Self, Error> {
+    ) -> Result<Self> {
         try_check_offsets_bounds(&offsets, field.len())?;
 
         let inner_field = Self::try_get_field(&data_type)?;
@@ -130,8 +142,8 @@ impl MapArray {
     }
 
     /// Boxes self into a [`std::sync::Arc<dyn Array>`].
-    pub fn arced(self) -> std::sync::Arc<dyn Array> {
-        std::sync::Arc::new(self)
+    pub fn arced(self) -> alloc::sync::Arc<dyn Array> {
+        alloc::sync::Arc::new(self)
     }
 }
 
@@ -165,7 +177,7 @@ impl MapArray {
         }
     }
 
-    pub(crate) fn try_get_field(data_type: &DataType) -> Result<&Field, Error> {
+    pub(crate) fn try_get_field(data_type: &DataType) -> Result<&Field> {
         if let DataType::Map(field, _) = data_type.to_logical_type() {
             Ok(field.as_ref())
         } else {
@@ -219,16 +231,44 @@ impl MapArray {
         // soundness: the invariant of the struct
         self.field.slice_unchecked(start, length)
     }
+
+    /// Returns the `index`th child of the entries referenced by this array
+    fn entries_child(&self, index: usize) -> Box<dyn Array> {
+        let field = self
+            .field
+            .as_any()
+            .downcast_ref::<StructArray>()
+            .expect("The field of a MapArray must be a StructArray");
+        let start = self.offsets.buffer()[0] as usize;
+        let length = *self.offsets.last() as usize - start;
+        field.values()[index].slice(start, length)
+    }
+
+    /// Returns the keys of all entries of this array, flattened, i.e. the keys of the slot `i`
+    /// are at `offsets()[i] - offsets()[0]..offsets()[i + 1] - offsets()[0]`.
+    /// # Panics
+    /// Panics iff the field is not a [`StructArray`].
+    pub fn keys_array(&self) -> Box<dyn Array> {
+        self.entries_child(0)
+    }
+
+    /// Returns the values of all entries of this array, flattened, i.e. the values of the slot `i`
+    /// are at `offsets()[i] - offsets()[0]..offsets()[i + 1] - offsets()[0]`.
+    /// # Panics
+    /// Panics iff the field is not a [`StructArray`].
+    pub fn values_array(&self) -> Box<dyn Array> {
+        self.entries_child(1)
+    }
 }
 
 impl Array for MapArray {
     #[inline]
-    fn as_any(&self) -> &dyn std::any::Any {
+    fn as_any(&self) -> &dyn core::any::Any {
         self
     }
 
     #[inline]
-    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+    fn as_any_mut(&mut self) -> &mut dyn core::any::Any {
         self
     }
 
@@ -262,4 +302,24 @@ impl Array for MapArray {
     fn to_boxed(&self) -> Box<dyn Array> {
         Box::new(self.clone())
     }
+
+    fn buffers(&self) -> Vec<Option<Buffer<u8>>> {
+        vec![
+            self.validity.as_ref().map(bitmap_to_bytes),
+            Some(buffer_to_bytes(self.offsets.buffer())),
+        ]
+    }
+
+    fn direct_children(&self) -> Vec<Box<dyn Array>> {
+        vec![self.field.clone()]
+    }
+
+    fn validate(&self, validation: ArrayValidation) -> Result<()> {
+        if validation == ArrayValidation::Off {
+            return Ok(());
+        }
+        validate_validity(self.validity(), self.len())?;
+        validate_offsets(self.offsets(), self.field().len(), validation)?;
+        self.field().validate(validation)
+    }
 }
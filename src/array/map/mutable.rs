@@ -0,0 +1,213 @@
+use alloc::{boxed::Box, string::ToString, sync::Arc};
+
+use crate::{
+    array::{
+        downcast_extend, physical_binary::extend_validity_from_bitmap, Array, MutableArray,
+        MutableStructArray,
+    },
+    bitmap::MutableBitmap,
+    datatypes::DataType,
+    error::{Error, Result},
+    offset::Offsets,
+};
+
+use super::MapArray;
+
+/// The mutable version of [`MapArray`], whose entries are a [`MutableStructArray`] of
+/// keys and values.
+///
+/// Entries are pushed to [`MutableMapArray::mut_field`], after which
+/// [`MutableMapArray::try_push_valid`] closes the map containing them.
+#[derive(Debug)]
+pub struct MutableMapArray {
+    data_type: DataType,
+    offsets: Offsets<i32>,
+    field: MutableStructArray,
+    validity: Option<MutableBitmap>,
+}
+
+impl From<MutableMapArray> for MapArray {
+    fn from(mut other: MutableMapArray) -> Self {
+        MapArray::new(
+            other.data_type,
+            other.offsets.into(),
+            other.field.as_box(),
+            other.validity.map(|x| x.into()),
+        )
+    }
+}
+
+impl MutableMapArray {
+    /// Creates a new empty [`MutableMapArray`] whose entries are pushed to `field`.
+    /// # Errors
+    /// This function errors iff:
+    /// * The `data_type`'s physical type is not [`crate::datatypes::PhysicalType::Map`]
+    /// * The `field`'s `data_type` is not equal to the inner field of `data_type`
+    /// * `field` is not empty
+    pub fn try_new(data_type: DataType, field: MutableStructArray) -> Result<Self> {
+        let inner_field = MapArray::try_get_field(&data_type)?;
+        if field.data_type() != inner_field.data_type() {
+            return Err(Error::InvalidArgumentError(
+                "MutableMapArray expects `field.data_type` to match its inner DataType".to_string(),
+            ));
+        }
+        if !field.is_empty() {
+            return Err(Error::InvalidArgumentError(
+                "MutableMapArray expects an empty `field`".to_string(),
+            ));
+        }
+
+        Ok(Self {
+            data_type,
+            offsets: Offsets::new(),
+            field,
+            validity: None,
+        })
+    }
+
+    /// Needs to be called after the entries of a valid map were pushed to
+    /// [`MutableMapArray::mut_field`].
+    pub fn try_push_valid(&mut self) -> Result<()> {
+        let total_length = self.field.len();
+        let offset = *self.offsets.last() as usize;
+        let length = total_length.checked_sub(offset).ok_or(Error::Overflow)?;
+
+        self.offsets.try_push_usize(length)?;
+        if let Some(validity) = &mut self.validity {
+            validity.push(true)
+        }
+        Ok(())
+    }
+
+    fn push_null(&mut self) {
+        self.offsets.extend_constant(1);
+        match &mut self.validity {
+            Some(validity) => validity.push(false),
+            None => self.init_validity(),
+        }
+    }
+
+    fn init_validity(&mut self) {
+        let len = self.offsets.len_proxy();
+
+        let mut validity = MutableBitmap::with_capacity(self.offsets.capacity());
+        validity.extend_constant(len, true);
+        validity.set(len - 1, false);
+        self.validity = Some(validity)
+    }
+
+    /// Returns the length of this array
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.offsets.len_proxy()
+    }
+
+    /// The offsets
+    pub fn offsets(&self) -> &Offsets<i32> {
+        &self.offsets
+    }
+
+    /// The entries
+    pub fn field(&self) -> &MutableStructArray {
+        &self.field
+    }
+
+    /// The mutable entries
+    pub fn mut_field(&mut self) -> &mut MutableStructArray {
+        &mut self.field
+    }
+
+    /// Reserves `additional` slots.
+    pub fn reserve(&mut self, additional: usize) {
+        self.offsets.reserve(additional);
+        if let Some(x) = self.validity.as_mut() {
+            x.reserve(additional)
+        }
+    }
+
+    /// Shrinks the capacity of the [`MutableMapArray`] to fit its current length.
+    pub fn shrink_to_fit(&mut self) {
+        self.field.shrink_to_fit();
+        self.offsets.shrink_to_fit();
+        if let Some(validity) = &mut self.validity {
+            validity.shrink_to_fit()
+        }
+    }
+}
+
+impl MutableArray for MutableMapArray {
+    fn len(&self) -> usize {
+        MutableMapArray::len(self)
+    }
+
+    fn validity(&self) -> Option<&MutableBitmap> {
+        self.validity.as_ref()
+    }
+
+    fn as_box(&mut self) -> Box<dyn Array> {
+        MapArray::new(
+            self.data_type.clone(),
+            core::mem::take(&mut self.offsets).into(),
+            self.field.as_box(),
+            core::mem::take(&mut self.validity).map(|x| x.into()),
+        )
+        .boxed()
+    }
+
+    fn as_arc(&mut self) -> Arc<dyn Array> {
+        MapArray::new(
+            self.data_type.clone(),
+            core::mem::take(&mut self.offsets).into(),
+            self.field.as_box(),
+            core::mem::take(&mut self.validity).map(|x| x.into()),
+        )
+        .arced()
+    }
+
+    fn data_type(&self) -> &DataType {
+        &self.data_type
+    }
+
+    fn as_any(&self) -> &dyn core::any::Any {
+        self
+    }
+
+    fn as_mut_any(&mut self) -> &mut dyn core::any::Any {
+        self
+    }
+
+    #[inline]
+    fn push_null(&mut self) {
+        self.push_null()
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        self.reserve(additional)
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.shrink_to_fit();
+    }
+
+    fn try_extend_from_array(&mut self, other: &dyn Array, start: usize, len: usize) -> Result<()> {
+        let other = downcast_extend::<MapArray>(other, start, len)?;
+        let offsets = other.offsets().buffer();
+        let field_start = offsets[start] as usize;
+        let field_end = offsets[start + len] as usize;
+        self.field.try_extend_from_array(
+            other.field().as_ref(),
+            field_start,
+            field_end - field_start,
+        )?;
+        self.offsets
+            .try_extend_from_slice(other.offsets(), start, len)?;
+        extend_validity_from_bitmap(
+            self.len() - len,
+            &mut self.validity,
+            other.validity(),
+            start,
+            len,
+        );
+        Ok(())
+    }
+}
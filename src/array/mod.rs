@@ -16,20 +16,38 @@
 //!
 //! Most arrays contain a [`MutableArray`] counterpart that is neither clonable nor slicable, but
 //! can be operated in-place.
-use std::any::Any;
-use std::sync::Arc;
+use alloc::{boxed::Box, sync::Arc, vec::Vec};
+use core::any::Any;
+use core::ops::Range;
 
-use crate::error::Result;
+use crate::error::{Error, Result};
 use crate::{
     bitmap::{Bitmap, MutableBitmap},
+    buffer::Buffer,
     datatypes::DataType,
+    scalar::{new_scalar, Scalar},
+    types::NativeType,
 };
 
 pub(self) mod physical_binary;
 
+/// Converts an [`Array`] to a `&dyn Array`. It is implemented for every [`Array`], so that
+/// provided methods of [`Array`] can pass `self` to functions of `&dyn Array`.
+pub trait AsArray {
+    /// Converts itself to a `&dyn Array`
+    fn as_dyn_array(&self) -> &dyn Array;
+}
+
+impl<A: Array> AsArray for A {
+    #[inline]
+    fn as_dyn_array(&self) -> &dyn Array {
+        self
+    }
+}
+
 /// A trait representing an immutable Arrow array. Arrow arrays are trait objects
 /// that are infallibly downcasted to concrete types according to the [`Array::data_type`].
-pub trait Array: Send + Sync + dyn_clone::DynClone + 'static {
+pub trait Array: AsArray + Send + Sync + dyn_clone::DynClone + 'static {
     /// Converts itself to a reference of [`Any`], which enables downcasting to concrete types.
     fn as_any(&self) -> &dyn Any;
 
@@ -103,6 +121,37 @@ pub trait Array: Send + Sync + dyn_clone::DynClone + 'static {
     /// The caller must ensure that `offset + length <= self.len()`
     unsafe fn slice_unchecked(&self, offset: usize, length: usize) -> Box<dyn Array>;
 
+    /// Slices the [`Array`] to the slots in `range`, returning a new `Box<dyn Array>`.
+    /// # Implementation
+    /// This operation is `O(1)` over `len`.
+    /// # Panic
+    /// This function panics iff `range.start > range.end` or `range.end > self.len()`.
+    fn sliced(&self, range: Range<usize>) -> Box<dyn Array> {
+        assert!(
+            range.start <= range.end && range.end <= self.len(),
+            "the range {range:?} is out of bounds of an array of length {}",
+            self.len()
+        );
+        // Safety: we just checked bounds
+        unsafe { self.slice_unchecked(range.start, range.end - range.start) }
+    }
+
+    /// Slices the [`Array`], returning a new `Box<dyn Array>`.
+    /// # Implementation
+    /// This operation is `O(1)` over `len`.
+    /// # Error
+    /// This function errors iff `offset + length > self.len()`.
+    fn try_slice(&self, offset: usize, length: usize) -> Result<Box<dyn Array>> {
+        match offset.checked_add(length) {
+            // Safety: we just checked bounds
+            Some(end) if end <= self.len() => Ok(unsafe { self.slice_unchecked(offset, length) }),
+            _ => Err(Error::InvalidArgumentError(format!(
+                "the slice with offset {offset} and length {length} is out of bounds of an array of length {}",
+                self.len()
+            ))),
+        }
+    }
+
     /// Clones this [`Array`] with a new new assigned bitmap.
     /// # Panic
     /// This function panics iff `validity.len() != self.len()`.
@@ -110,6 +159,79 @@ pub trait Array: Send + Sync + dyn_clone::DynClone + 'static {
 
     /// Clone a `&dyn Array` to an owned `Box<dyn Array>`.
     fn to_boxed(&self) -> Box<dyn Array>;
+
+    /// Returns the slot `i` as a [`Scalar`], e.g. to access a row of any [`DataType`]
+    /// without downcasting.
+    /// # Panic
+    /// This function panics iff `i >= self.len()`.
+    fn get(&self, i: usize) -> Box<dyn Scalar> {
+        new_scalar(self.as_dyn_array(), i)
+    }
+
+    /// Returns the buffers of this [`Array`] as bytes, in the order of its layout in the Arrow
+    /// specification (e.g. the validity, offsets and values of a [`Utf8Array`]). A buffer is
+    /// [`None`] when it is absent, such as the validity of an array without one.
+    ///
+    /// The buffers start at the first slot of this [`Array`] (bitmaps at their first bit),
+    /// and offsets are not rebased, i.e. they point to the buffers or children they index.
+    ///
+    /// The default implementation returns no buffers, as for a [`NullArray`].
+    /// # Implementation
+    /// This is `O(1)` unless a bitmap does not start at a byte boundary, in which case
+    /// it is copied into one that does.
+    fn buffers(&self) -> Vec<Option<Buffer<u8>>> {
+        vec![]
+    }
+
+    /// Returns the child arrays of this [`Array`]: the values of lists, fixed size lists,
+    /// maps and dictionaries, and the fields of structs and unions.
+    ///
+    /// The default implementation returns no children.
+    fn direct_children(&self) -> Vec<Box<dyn Array>> {
+        vec![]
+    }
+
+    /// Validates the invariants of this [`Array`] and of its children at the level
+    /// `validation` (see [`ArrayValidation`]).
+    /// # Errors
+    /// Errors with [`Error::OutOfSpec`] iff an invariant does not hold.
+    fn validate(&self, validation: ArrayValidation) -> Result<()>;
+
+    /// Validates all invariants of this [`Array`] and of its children, i.e. [`Array::validate`]
+    /// with [`ArrayValidation::Full`].
+    ///
+    /// This should be called on arrays created from untrusted buffers (e.g. via FFI, `mmap`
+    /// or read from the network) before their values are accessed.
+    /// # Implementation
+    /// This is `O(N)`.
+    fn validate_full(&self) -> Result<()> {
+        self.validate(ArrayValidation::Full)
+    }
+}
+
+/// Returns the bytes of `buffer` as a [`Buffer<u8>`] sharing its memory.
+pub(crate) fn buffer_to_bytes<T: NativeType>(buffer: &Buffer<T>) -> Buffer<u8> {
+    let slice = buffer.as_slice();
+    // Safety: the region of `slice` is owned by the clone of `buffer`, which is immutable,
+    // and every bit pattern of a `NativeType` is a valid sequence of bytes.
+    unsafe {
+        Buffer::from_owner(
+            buffer.clone(),
+            slice.as_ptr().cast::<u8>(),
+            core::mem::size_of_val(slice),
+        )
+    }
+}
+
+/// Returns the bytes of `bitmap` as a [`Buffer<u8>`] whose first bit is the first bit of
+/// `bitmap`, copying it iff it does not start at a byte boundary.
+pub(crate) fn bitmap_to_bytes(bitmap: &Bitmap) -> Buffer<u8> {
+    let (slice, offset, _) = bitmap.as_slice();
+    if offset != 0 {
+        return bitmap_to_bytes(&crate::bitmap::unary(bitmap, |x| x));
+    }
+    // Safety: the region of `slice` is owned by the clone of `bitmap`, which is immutable.
+    unsafe { Buffer::from_owner(bitmap.clone(), slice.as_ptr(), slice.len()) }
 }
 
 dyn_clone::clone_trait_object!(Array);
@@ -127,7 +249,7 @@ pub(crate) trait Container {
 /// Mutable arrays cannot be cloned but can be mutated in place,
 /// thereby making them useful to perform numeric operations without allocations.
 /// As in [`Array`], concrete arrays (such as [`MutablePrimitiveArray`]) implement how they are mutated.
-pub trait MutableArray: std::fmt::Debug + Send + Sync {
+pub trait MutableArray: core::fmt::Debug + Send + Sync {
     /// The [`DataType`] of the array.
     fn data_type(&self) -> &DataType;
 
@@ -149,7 +271,7 @@ pub trait MutableArray: std::fmt::Debug + Send + Sync {
     // This provided implementation has an extra allocation as it first
     // boxes `self`, then converts the box into an `Arc`. Implementors may wish
     // to avoid an allocation by skipping the box completely.
-    fn as_arc(&mut self) -> std::sync::Arc<dyn Array> {
+    fn as_arc(&mut self) -> alloc::sync::Arc<dyn Array> {
         self.as_box().into()
     }
 
@@ -178,6 +300,44 @@ pub trait MutableArray: std::fmt::Debug + Send + Sync {
 
     /// Shrink the array to fit its length.
     fn shrink_to_fit(&mut self);
+
+    /// Tries to extend the array with the slots of `other` in `start..start + len`.
+    /// # Errors
+    /// This function errors iff:
+    /// * `other` is not the (immutable) counterpart of this array
+    /// * `start + len` is larger than the length of `other`
+    /// * the offsets or keys of this array overflow
+    ///
+    /// Implementors that do not support this return [`Error::NotYetImplemented`].
+    fn try_extend_from_array(&mut self, other: &dyn Array, start: usize, len: usize) -> Result<()> {
+        let _ = (other, start, len);
+        Err(Error::NotYetImplemented(format!(
+            "try_extend_from_array for {:?}",
+            self.data_type()
+        )))
+    }
+}
+
+/// Downcasts `other` to `A` and checks that `start..start + len` is in its bounds, as
+/// required by [`MutableArray::try_extend_from_array`].
+pub(crate) fn downcast_extend<A: Array + 'static>(
+    other: &dyn Array,
+    start: usize,
+    len: usize,
+) -> Result<&A> {
+    let other = other.as_any().downcast_ref::<A>().ok_or_else(|| {
+        Error::InvalidArgumentError(format!(
+            "Cannot extend a mutable array from an array of type {:?}",
+            other.data_type()
+        ))
+    })?;
+    match start.checked_add(len) {
+        Some(end) if end <= other.len() => Ok(other),
+        _ => Err(Error::InvalidArgumentError(format!(
+            "Cannot extend from the slots {start}..{start}+{len} of an array of length {}",
+            other.len()
+        ))),
+    }
 }
 
 impl MutableArray for Box<dyn MutableArray> {
@@ -201,11 +361,11 @@ impl MutableArray for Box<dyn MutableArray> {
         self.as_ref().data_type()
     }
 
-    fn as_any(&self) -> &dyn std::any::Any {
+    fn as_any(&self) -> &dyn core::any::Any {
         self.as_ref().as_any()
     }
 
-    fn as_mut_any(&mut self) -> &mut dyn std::any::Any {
+    fn as_mut_any(&mut self) -> &mut dyn core::any::Any {
         self.as_mut().as_mut_any()
     }
 
@@ -221,6 +381,10 @@ impl MutableArray for Box<dyn MutableArray> {
     fn reserve(&mut self, additional: usize) {
         self.as_mut().reserve(additional);
     }
+
+    fn try_extend_from_array(&mut self, other: &dyn Array, start: usize, len: usize) -> Result<()> {
+        self.as_mut().try_extend_from_array(other, start, len)
+    }
 }
 
 macro_rules! general_dyn {
@@ -279,8 +443,8 @@ macro_rules! with_match_primitive_type {(
     }
 })}
 
-impl std::fmt::Debug for dyn Array + '_ {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl core::fmt::Debug for dyn Array + '_ {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         use crate::datatypes::PhysicalType::*;
         match self.data_type().to_physical_type() {
             Null => fmt_dyn!(self, NullArray, f),
@@ -366,6 +530,204 @@ pub fn new_null_array(data_type: DataType, length: usize) -> Box<dyn Array> {
     }
 }
 
+/// Creates a new, empty [`MutableArray`] of [`DataType`] `data_type` with capacity for
+/// `capacity` values.
+///
+/// The concrete mutable array can be recovered via [`MutableArray::as_mut_any`], e.g. to
+/// push values to it. Nested arrays are backed by arrays created by this function:
+/// * [`DataType::List`] and [`DataType::LargeList`] by
+///   [`MutableListArray<O, Box<dyn MutableArray>>`]
+/// * [`DataType::FixedSizeList`] by [`MutableFixedSizeListArray<Box<dyn MutableArray>>`]
+/// * [`DataType::Struct`] by [`MutableStructArray`] and [`DataType::Map`] by [`MutableMapArray`]
+/// * [`DataType::Union`] by [`MutableUnionArray`]
+/// * [`DataType::Dictionary`] by [`MutableDictionaryArray`] whose values are
+///   [`MutableUtf8Array`], [`MutableBinaryArray`] or [`MutablePrimitiveArray`], or
+///   [`Box<dyn MutableArray>`] for every other type
+/// # Errors
+/// This function errors iff `data_type` is or contains a [`DataType::Union`] that
+/// [`MutableUnionArray::try_new`] rejects, e.g. with more than `i8::MAX` fields.
+/// # Example
+/// ```
+/// use arrow2::array::{new_mutable, Int32Array, MutablePrimitiveArray};
+/// use arrow2::datatypes::DataType;
+///
+/// let mut array = new_mutable(DataType::Int32, 2).unwrap();
+/// let values = array
+///     .as_mut_any()
+///     .downcast_mut::<MutablePrimitiveArray<i32>>()
+///     .unwrap();
+/// values.push(Some(1));
+/// values.push(None);
+/// assert_eq!(Int32Array::from([Some(1), None]), array.as_box().as_ref());
+/// ```
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub fn new_mutable(data_type: DataType, capacity: usize) -> Result<Box<dyn MutableArray>> {
+    use crate::datatypes::PhysicalType::*;
+    use crate::offset::Offsets;
+    Ok(match data_type.to_physical_type() {
+        Null => Box::new(MutableNullArray::new(data_type, 0)),
+        Boolean => Box::new(
+            MutableBooleanArray::try_new(data_type, MutableBitmap::with_capacity(capacity), None)
+                .unwrap(),
+        ),
+        Primitive(primitive) => with_match_primitive_type!(primitive, |$T| {
+            Box::new(MutablePrimitiveArray::<$T>::with_capacity_from(capacity, data_type))
+        }),
+        Binary => Box::new(
+            MutableBinaryArray::<i32>::try_new(
+                data_type,
+                Offsets::with_capacity(capacity),
+                vec![],
+                None,
+            )
+            .unwrap(),
+        ),
+        LargeBinary => Box::new(
+            MutableBinaryArray::<i64>::try_new(
+                data_type,
+                Offsets::with_capacity(capacity),
+                vec![],
+                None,
+            )
+            .unwrap(),
+        ),
+        FixedSizeBinary => {
+            let size = FixedSizeBinaryArray::get_size(&data_type);
+            Box::new(
+                MutableFixedSizeBinaryArray::try_new(
+                    data_type,
+                    Vec::with_capacity(capacity * size),
+                    None,
+                )
+                .unwrap(),
+            )
+        }
+        Utf8 => Box::new(
+            MutableUtf8Array::<i32>::try_new(
+                data_type,
+                Offsets::with_capacity(capacity),
+                vec![],
+                None,
+            )
+            .unwrap(),
+        ),
+        LargeUtf8 => Box::new(
+            MutableUtf8Array::<i64>::try_new(
+                data_type,
+                Offsets::with_capacity(capacity),
+                vec![],
+                None,
+            )
+            .unwrap(),
+        ),
+        List => {
+            let child = ListArray::<i32>::get_child_field(&data_type)
+                .data_type()
+                .clone();
+            let values = new_mutable(child, capacity)?;
+            Box::new(MutableListArray::<i32, _>::new_from(
+                values, data_type, capacity,
+            ))
+        }
+        LargeList => {
+            let child = ListArray::<i64>::get_child_field(&data_type)
+                .data_type()
+                .clone();
+            let values = new_mutable(child, capacity)?;
+            Box::new(MutableListArray::<i64, _>::new_from(
+                values, data_type, capacity,
+            ))
+        }
+        FixedSizeList => {
+            let (child, size) = FixedSizeListArray::get_child_and_size(&data_type);
+            let values = new_mutable(child.data_type().clone(), capacity * size)?;
+            Box::new(MutableFixedSizeListArray::new_from(values, data_type, size))
+        }
+        Struct => Box::new(new_mutable_struct(data_type, capacity)?),
+        Map => {
+            let field = MapArray::get_field(&data_type).data_type().clone();
+            let field = new_mutable_struct(field, capacity)?;
+            let mut array = MutableMapArray::try_new(data_type, field)?;
+            array.reserve(capacity);
+            Box::new(array)
+        }
+        Dictionary(key_type) => match_integer_type!(key_type, |$T| {
+            new_mutable_dictionary::<$T>(data_type, capacity)?
+        }),
+        Union => {
+            // the capacity of each field depends on the values pushed to it
+            let values = UnionArray::get_fields(&data_type)
+                .iter()
+                .map(|field| new_mutable(field.data_type().clone(), 0))
+                .collect::<Result<_>>()?;
+            let mut array = MutableUnionArray::try_new(data_type, values)?;
+            array.reserve(capacity);
+            Box::new(array)
+        }
+    })
+}
+
+#[cfg(feature = "std")]
+fn new_mutable_struct(data_type: DataType, capacity: usize) -> Result<MutableStructArray> {
+    let values = StructArray::get_fields(&data_type)
+        .iter()
+        .map(|field| new_mutable(field.data_type().clone(), capacity))
+        .collect::<Result<_>>()?;
+    Ok(MutableStructArray::new(data_type, values))
+}
+
+#[cfg(feature = "std")]
+fn new_mutable_dictionary<K: DictionaryKey>(
+    data_type: DataType,
+    capacity: usize,
+) -> Result<Box<dyn MutableArray>> {
+    use crate::datatypes::PhysicalType::*;
+    use crate::offset::Offsets;
+
+    fn new<K: DictionaryKey, M: MutableArray + 'static>(
+        data_type: DataType,
+        values: M,
+        capacity: usize,
+    ) -> Box<dyn MutableArray> {
+        let mut array = MutableDictionaryArray::<K, M>::try_empty(data_type, values).unwrap();
+        array.reserve(capacity);
+        Box::new(array)
+    }
+
+    // values with a `TryPush` are kept concrete so that values can be pushed to the dictionary
+    let values = DictionaryArray::<K>::try_get_child(&data_type)
+        .unwrap()
+        .clone();
+    Ok(match values.to_physical_type() {
+        Primitive(primitive) => with_match_primitive_type!(primitive, |$T| {
+            let values = MutablePrimitiveArray::<$T>::with_capacity_from(0, values);
+            new::<K, _>(data_type, values, capacity)
+        }),
+        Binary => {
+            let values =
+                MutableBinaryArray::<i32>::try_new(values, Offsets::new(), vec![], None).unwrap();
+            new::<K, _>(data_type, values, capacity)
+        }
+        LargeBinary => {
+            let values =
+                MutableBinaryArray::<i64>::try_new(values, Offsets::new(), vec![], None).unwrap();
+            new::<K, _>(data_type, values, capacity)
+        }
+        Utf8 => {
+            let values =
+                MutableUtf8Array::<i32>::try_new(values, Offsets::new(), vec![], None).unwrap();
+            new::<K, _>(data_type, values, capacity)
+        }
+        LargeUtf8 => {
+            let values =
+                MutableUtf8Array::<i64>::try_new(values, Offsets::new(), vec![], None).unwrap();
+            new::<K, _>(data_type, values, capacity)
+        }
+        _ => new::<K, _>(data_type, new_mutable(values, 0)?, capacity),
+    })
+}
+
 macro_rules! clone_dyn {
     ($array:expr, $ty:ty) => {{
         let f = |x: &$ty| Box::new(x.clone());
@@ -425,35 +787,63 @@ mod specification;
 mod struct_;
 mod union;
 mod utf8;
+mod validation;
 
+#[cfg(feature = "proptest")]
+#[cfg_attr(docsrs, doc(cfg(feature = "proptest")))]
+pub mod arbitrary;
 mod equal;
+#[cfg(feature = "std")]
 mod ffi;
 mod fmt;
 pub mod growable;
 mod iterator;
+#[cfg(feature = "nalgebra")]
+mod nalgebra;
+#[cfg(feature = "ndarray")]
+mod ndarray;
 pub mod ord;
+#[cfg(feature = "pyo3")]
+mod python;
+#[cfg(feature = "roaring")]
+mod roaring;
 
 pub(crate) use iterator::ArrayAccessor;
 pub use iterator::ArrayValuesIter;
 
 pub use equal::equal;
+pub(crate) use fmt::get_storage_value_display;
 pub use fmt::{get_display, get_value_display};
+#[cfg(feature = "compute_cast")]
+pub(crate) use primitive::fmt::write_decimal;
 
 pub use binary::{BinaryArray, BinaryValueIter, MutableBinaryArray, MutableBinaryValuesArray};
 pub use boolean::{BooleanArray, MutableBooleanArray};
-pub use dictionary::{DictionaryArray, DictionaryKey, MutableDictionaryArray};
+#[cfg(feature = "std")]
+pub use dictionary::MutableDictionaryArray;
+pub use dictionary::{DictionaryArray, DictionaryKey};
 pub use fixed_size_binary::{FixedSizeBinaryArray, MutableFixedSizeBinaryArray};
-pub use fixed_size_list::{FixedSizeListArray, MutableFixedSizeListArray};
+pub use fixed_size_list::{FixedSizeListArray, MutableFixedSizeListArray, NullRowPolicy};
 pub use list::{ListArray, ListValuesIter, MutableListArray};
-pub use map::MapArray;
-pub use null::NullArray;
+pub use map::{MapArray, MutableMapArray};
+pub use null::{MutableNullArray, NullArray};
 pub use primitive::*;
 pub use struct_::{MutableStructArray, StructArray};
-pub use union::UnionArray;
-pub use utf8::{MutableUtf8Array, MutableUtf8ValuesArray, Utf8Array, Utf8ValuesIter};
+pub use union::{MutableUnionArray, UnionArray};
+pub use utf8::{
+    DeferredUtf8Builder, MutableUtf8Array, MutableUtf8ValuesArray, Utf8Array, Utf8ValuesIter,
+};
+pub use validation::ArrayValidation;
 
+#[cfg(feature = "pyo3")]
+#[cfg_attr(docsrs, doc(cfg(feature = "pyo3")))]
+pub use python::{from_py, to_py};
+
+#[cfg(feature = "std")]
 pub(crate) use self::ffi::offset_buffers_children_dictionary;
+#[cfg(feature = "std")]
 pub(crate) use self::ffi::FromFfi;
+#[cfg(feature = "std")]
 pub(crate) use self::ffi::ToFfi;
 
 /// A trait describing the ability of a struct to create itself from a iterator.
@@ -469,6 +859,16 @@ pub trait TryPush<A> {
     fn try_push(&mut self, item: A) -> Result<()>;
 }
 
+/// A trait describing the ability of a tuple of [`MutableArray`]s to receive rows, whose fields
+/// are pushed to each of them. Used to create a [`StructArray`] from an iterator.
+pub trait TryPushRow<T> {
+    /// Tries to push a new row, or a null row to every array.
+    fn try_push_row(&mut self, row: Option<T>) -> Result<()>;
+
+    /// Converts the mutable arrays into [`Array`]s.
+    fn into_arrays(self) -> Vec<Box<dyn Array>>;
+}
+
 /// A trait describing the ability of a struct to extend from a reference of itself.
 /// Specialization of [`TryExtend`].
 pub trait TryExtendFromSelf {
@@ -0,0 +1,72 @@
+//! Conversions between arrays and [`nalgebra`] vectors and matrices.
+//!
+//! As with `ndarray`, the validity is kept separate from the values.
+use nalgebra::{DMatrix, DMatrixSlice, DVector, DVectorSlice, Dynamic};
+
+use crate::bitmap::Bitmap;
+use crate::datatypes::DataType;
+use crate::error::Result;
+use crate::types::NativeType;
+
+use super::{FixedSizeListArray, PrimitiveArray};
+
+#[cfg_attr(docsrs, doc(cfg(feature = "nalgebra")))]
+impl<T: NativeType> PrimitiveArray<T> {
+    /// Returns a zero-copy [`DVectorSlice`] of the values of this [`PrimitiveArray`].
+    ///
+    /// The values of null slots are undefined; use [`PrimitiveArray::validity`] to mask them.
+    pub fn as_nalgebra(&self) -> DVectorSlice<'_, T> {
+        DVectorSlice::from_slice(self.values().as_slice(), self.len())
+    }
+
+    /// Returns a new [`PrimitiveArray`] from the values of `values` and `validity`, without copying.
+    /// # Errors
+    /// This function errors iff the length of `validity` is not the length of `values`.
+    pub fn from_nalgebra(values: DVector<T>, validity: Option<Bitmap>) -> Result<Self> {
+        let values: Vec<T> = values.data.into();
+        Self::try_new(T::PRIMITIVE.into(), values.into(), validity)
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "nalgebra")))]
+impl FixedSizeListArray {
+    /// Returns a zero-copy [`DMatrixSlice`] of the values of this [`FixedSizeListArray`], with
+    /// one row per slot and [`FixedSizeListArray::size`] columns.
+    ///
+    /// The validity of the values is ignored and the values of null slots are undefined;
+    /// use [`FixedSizeListArray::validity`] to mask the rows of null slots.
+    /// # Errors
+    /// This function errors iff the values are not a [`PrimitiveArray<T>`].
+    pub fn as_nalgebra<T: NativeType>(&self) -> Result<DMatrixSlice<'_, T, Dynamic, Dynamic>> {
+        let values = self.primitive_values::<T>()?;
+        // the values are stored row by row
+        Ok(DMatrixSlice::from_slice_with_strides(
+            values.values().as_slice(),
+            self.len(),
+            self.size(),
+            self.size(),
+            1,
+        ))
+    }
+
+    /// Returns a new [`FixedSizeListArray`] with one slot per row of `values` and `validity`.
+    ///
+    /// Since [`DMatrix`] is stored column by column, this copies `values` (unless it has a single row).
+    /// # Errors
+    /// This function errors iff `values` has no columns or the length of `validity`
+    /// is not the number of rows of `values`.
+    pub fn from_nalgebra<T: NativeType>(
+        values: DMatrix<T>,
+        validity: Option<Bitmap>,
+    ) -> Result<Self> {
+        let size = values.ncols();
+        let values: Vec<T> = if values.nrows() == 1 {
+            values.data.into()
+        } else {
+            values.transpose().data.into()
+        };
+        let data_type = Self::default_datatype(DataType::from(T::PRIMITIVE), size);
+        let values = PrimitiveArray::<T>::from_vec(values).boxed();
+        Self::try_new(data_type, values, validity)
+    }
+}
@@ -0,0 +1,90 @@
+//! Zero-copy conversions between arrays and [`ndarray`] arrays.
+//!
+//! Arrow represents nulls by a validity bitmap that is separate from the values; the
+//! conversions in this module keep it separate: to [`ndarray`] they return a view of the
+//! values (whose values of null slots are undefined), and from [`ndarray`] they accept the
+//! validity as a separate [`Bitmap`].
+use ndarray::{Array1, Array2, ArrayView1, ArrayView2};
+
+use crate::bitmap::Bitmap;
+use crate::datatypes::DataType;
+use crate::error::{Error, Result};
+use crate::types::NativeType;
+
+use super::{FixedSizeListArray, PrimitiveArray};
+
+/// Returns the values of `array` in logical order, re-using its allocation when possible.
+fn into_vec<T: NativeType>(array: Array1<T>) -> Vec<T> {
+    let contiguous = array.as_slice().map(|slice| (slice.as_ptr(), slice.len()));
+    match contiguous {
+        Some((ptr, len)) => {
+            let mut vec = array.into_raw_vec();
+            if vec.as_ptr() != ptr || vec.len() != len {
+                // the array is a slice of its allocation
+                let offset = (ptr as usize - vec.as_ptr() as usize) / core::mem::size_of::<T>();
+                vec = vec[offset..offset + len].to_vec();
+            }
+            vec
+        }
+        None => array.iter().copied().collect(),
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "ndarray")))]
+impl<T: NativeType> PrimitiveArray<T> {
+    /// Returns a zero-copy [`ArrayView1`] of the values of this [`PrimitiveArray`].
+    ///
+    /// The values of null slots are undefined; use [`PrimitiveArray::validity`] to mask them.
+    pub fn as_ndarray(&self) -> ArrayView1<'_, T> {
+        ArrayView1::from(self.values().as_slice())
+    }
+
+    /// Returns a new [`PrimitiveArray`] with the values of `values` and `validity`.
+    ///
+    /// This is zero-copy when `values` is in standard layout and spans its whole allocation
+    /// (e.g. when it was not sliced).
+    /// # Errors
+    /// This function errors iff the length of `validity` is not the length of `values`.
+    pub fn from_ndarray(values: Array1<T>, validity: Option<Bitmap>) -> Result<Self> {
+        Self::try_new(T::PRIMITIVE.into(), into_vec(values).into(), validity)
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "ndarray")))]
+impl FixedSizeListArray {
+    /// Returns a zero-copy [`ArrayView2`] of the values of this [`FixedSizeListArray`], with
+    /// one row per slot and [`FixedSizeListArray::size`] columns.
+    ///
+    /// The validity of the values is ignored and the values of null slots are undefined;
+    /// use [`FixedSizeListArray::validity`] to mask the rows of null slots.
+    /// # Errors
+    /// This function errors iff the values are not a [`PrimitiveArray<T>`].
+    pub fn as_ndarray<T: NativeType>(&self) -> Result<ArrayView2<'_, T>> {
+        let values = self.primitive_values::<T>()?;
+        ArrayView2::from_shape((self.len(), self.size()), values.values().as_slice())
+            .map_err(|e| Error::InvalidArgumentError(e.to_string()))
+    }
+
+    /// Returns a new [`FixedSizeListArray`] with one slot per row of `values` and `validity`.
+    ///
+    /// This is zero-copy when `values` is in standard (row-major) layout and spans its whole
+    /// allocation.
+    /// # Errors
+    /// This function errors iff `values` has no columns or the length of `validity`
+    /// is not the number of rows of `values`.
+    pub fn from_ndarray<T: NativeType>(
+        values: Array2<T>,
+        validity: Option<Bitmap>,
+    ) -> Result<Self> {
+        let size = values.ncols();
+        let values = if values.is_standard_layout() {
+            let len = values.len();
+            into_vec(values.into_shape(len).unwrap())
+        } else {
+            values.iter().copied().collect()
+        };
+        let data_type = Self::default_datatype(DataType::from(T::PRIMITIVE), size);
+        let values = PrimitiveArray::<T>::from_vec(values).boxed();
+        Self::try_new(data_type, values, validity)
+    }
+}
@@ -1,9 +1,18 @@
-use crate::{bitmap::Bitmap, datatypes::DataType};
+use alloc::boxed::Box;
 
 use crate::{
-    array::{Array, FromFfi, ToFfi},
+    bitmap::{Bitmap, MutableBitmap},
+    datatypes::DataType,
+};
+
+use crate::{
+    array::{downcast_extend, Array, ArrayValidation, MutableArray},
     datatypes::PhysicalType,
-    error::Error,
+    error::{Error, Result},
+};
+#[cfg(feature = "std")]
+use crate::{
+    array::{FromFfi, ToFfi},
     ffi,
 };
 
@@ -19,7 +28,7 @@ impl NullArray {
     /// # Errors
     /// This function errors iff:
     /// * The `data_type`'s [`crate::datatypes::PhysicalType`] is not equal to [`crate::datatypes::PhysicalType::Null`].
-    pub fn try_new(data_type: DataType, length: usize) -> Result<Self, Error> {
+    pub fn try_new(data_type: DataType, length: usize) -> Result<Self> {
         if data_type.to_physical_type() != PhysicalType::Null {
             return Err(Error::oos(
                 "NullArray can only be initialized with a DataType whose physical type is Boolean",
@@ -53,8 +62,8 @@ impl NullArray {
     }
 
     /// Boxes self into a [`std::sync::Arc<dyn Array>`].
-    pub fn arced(self) -> std::sync::Arc<dyn Array> {
-        std::sync::Arc::new(self)
+    pub fn arced(self) -> alloc::sync::Arc<dyn Array> {
+        alloc::sync::Arc::new(self)
     }
 }
 
@@ -75,12 +84,12 @@ impl NullArray {
 
 impl Array for NullArray {
     #[inline]
-    fn as_any(&self) -> &dyn std::any::Any {
+    fn as_any(&self) -> &dyn core::any::Any {
         self
     }
 
     #[inline]
-    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+    fn as_any_mut(&mut self) -> &mut dyn core::any::Any {
         self
     }
 
@@ -113,14 +122,88 @@ impl Array for NullArray {
     fn to_boxed(&self) -> Box<dyn Array> {
         Box::new(self.clone())
     }
+
+    fn validate(&self, _: ArrayValidation) -> Result<()> {
+        Ok(())
+    }
 }
 
-impl std::fmt::Debug for NullArray {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl core::fmt::Debug for NullArray {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "NullArray({})", self.len())
     }
 }
 
+/// The mutable version of [`NullArray`], whose values can only be pushed as nulls.
+#[derive(Debug, Clone)]
+pub struct MutableNullArray {
+    data_type: DataType,
+    length: usize,
+}
+
+impl MutableNullArray {
+    /// Returns a new [`MutableNullArray`] of length `length`.
+    /// # Panics
+    /// This function panics iff:
+    /// * The `data_type`'s [`crate::datatypes::PhysicalType`] is not equal to [`crate::datatypes::PhysicalType::Null`].
+    pub fn new(data_type: DataType, length: usize) -> Self {
+        assert_eq!(
+            data_type.to_physical_type(),
+            PhysicalType::Null,
+            "MutableNullArray can only be initialized with a DataType whose physical type is Null"
+        );
+        Self { data_type, length }
+    }
+}
+
+impl From<MutableNullArray> for NullArray {
+    fn from(other: MutableNullArray) -> Self {
+        NullArray::new(other.data_type, other.length)
+    }
+}
+
+impl MutableArray for MutableNullArray {
+    fn data_type(&self) -> &DataType {
+        &self.data_type
+    }
+
+    fn len(&self) -> usize {
+        self.length
+    }
+
+    fn validity(&self) -> Option<&MutableBitmap> {
+        None
+    }
+
+    fn as_box(&mut self) -> Box<dyn Array> {
+        let length = core::mem::take(&mut self.length);
+        NullArray::new(self.data_type.clone(), length).boxed()
+    }
+
+    fn as_any(&self) -> &dyn core::any::Any {
+        self
+    }
+
+    fn as_mut_any(&mut self) -> &mut dyn core::any::Any {
+        self
+    }
+
+    fn push_null(&mut self) {
+        self.length += 1;
+    }
+
+    fn reserve(&mut self, _additional: usize) {}
+
+    fn shrink_to_fit(&mut self) {}
+
+    fn try_extend_from_array(&mut self, other: &dyn Array, start: usize, len: usize) -> Result<()> {
+        downcast_extend::<NullArray>(other, start, len)?;
+        self.length += len;
+        Ok(())
+    }
+}
+
+#[cfg(feature = "std")]
 unsafe impl ToFfi for NullArray {
     fn buffers(&self) -> Vec<Option<*const u8>> {
         // `None` is technically not required by the specification, but older C++ implementations require it, so leaving
@@ -137,8 +220,9 @@ unsafe impl ToFfi for NullArray {
     }
 }
 
+#[cfg(feature = "std")]
 impl<A: ffi::ArrowArrayRef> FromFfi<A> for NullArray {
-    unsafe fn try_from_ffi(array: A) -> Result<Self, Error> {
+    unsafe fn try_from_ffi(array: A) -> Result<Self> {
         let data_type = array.data_type().clone();
         Self::try_new(data_type, array.array().len())
     }
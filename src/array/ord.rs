@@ -1,6 +1,7 @@
 //! Contains functions and function factories to order values within arrays.
 
-use std::cmp::Ordering;
+use alloc::{boxed::Box, string::ToString, vec::Vec};
+use core::cmp::Ordering;
 
 use crate::datatypes::*;
 use crate::error::{Error, Result};
@@ -14,7 +15,7 @@ pub type DynComparator = Box<dyn Fn(usize, usize) -> Ordering + Send + Sync>;
 // Original implementation from https://doc.rust-lang.org/std/primitive.f32.html#method.total_cmp
 // TODO to change to use std when it becomes stable
 #[inline]
-pub fn total_cmp_f32(l: &f32, r: &f32) -> std::cmp::Ordering {
+pub fn total_cmp_f32(l: &f32, r: &f32) -> core::cmp::Ordering {
     let mut left = l.to_bits() as i32;
     let mut right = r.to_bits() as i32;
 
@@ -28,7 +29,7 @@ pub fn total_cmp_f32(l: &f32, r: &f32) -> std::cmp::Ordering {
 // Original implementation from https://doc.rust-lang.org/std/primitive.f64.html#method.total_cmp
 // TODO to change to use std when it becomes stable
 #[inline]
-pub fn total_cmp_f64(l: &f64, r: &f64) -> std::cmp::Ordering {
+pub fn total_cmp_f64(l: &f64, r: &f64) -> core::cmp::Ordering {
     let mut left = l.to_bits() as i64;
     let mut right = r.to_bits() as i64;
 
@@ -41,7 +42,7 @@ pub fn total_cmp_f64(l: &f64, r: &f64) -> std::cmp::Ordering {
 /// Total order of all native types whose Rust implementation
 /// that support total order.
 #[inline]
-pub fn total_cmp<T>(l: &T, r: &T) -> std::cmp::Ordering
+pub fn total_cmp<T>(l: &T, r: &T) -> core::cmp::Ordering
 where
     T: NativeType + Ord,
 {
@@ -139,16 +140,190 @@ where
     let left_keys = left.keys().values().clone();
     let right_keys = right.keys().values().clone();
 
-    let comparator = build_compare(left.values().as_ref(), right.values().as_ref())?;
+    // the values are compared once, so that comparing two items compares two integers
+    let (left_ranks, right_ranks) = dense_ranks(left.values().as_ref(), right.values().as_ref())?;
 
     Ok(Box::new(move |i: usize, j: usize| {
         // safety: all dictionaries keys are guaranteed to be castable to usize
         let key_left = unsafe { left_keys[i].as_usize() };
         let key_right = unsafe { right_keys[j].as_usize() };
-        (comparator)(key_left, key_right)
+        left_ranks[key_left].cmp(&right_ranks[key_right])
     }))
 }
 
+/// Returns the ranks of the items of `left` and `right` (see [`dense_ranks`]), where null items
+/// rank before all valid items, so that nested nulls are ordered first.
+fn nullable_ranks(left: &dyn Array, right: &dyn Array) -> Result<(Vec<usize>, Vec<usize>)> {
+    let (mut left_ranks, mut right_ranks) = dense_ranks(left, right)?;
+    for (ranks, array) in [(&mut left_ranks, left), (&mut right_ranks, right)] {
+        ranks.iter_mut().enumerate().for_each(|(i, rank)| {
+            *rank = if array.is_null(i) { 0 } else { *rank + 1 };
+        });
+    }
+    Ok((left_ranks, right_ranks))
+}
+
+fn compare_list<O: Offset>(left: &dyn Array, right: &dyn Array) -> Result<DynComparator> {
+    let left = left.as_any().downcast_ref::<ListArray<O>>().unwrap();
+    let right = right.as_any().downcast_ref::<ListArray<O>>().unwrap();
+
+    // the values are compared once, so that comparing two items compares two slices of integers
+    let (left_ranks, right_ranks) =
+        nullable_ranks(left.values().as_ref(), right.values().as_ref())?;
+    let left_offsets = left.offsets().clone();
+    let right_offsets = right.offsets().clone();
+
+    Ok(Box::new(move |i: usize, j: usize| {
+        let (left_start, left_end) = left_offsets.start_end(i);
+        let (right_start, right_end) = right_offsets.start_end(j);
+        left_ranks[left_start..left_end].cmp(&right_ranks[right_start..right_end])
+    }))
+}
+
+fn compare_fixed_size_list(left: &dyn Array, right: &dyn Array) -> Result<DynComparator> {
+    let left = left.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
+    let right = right.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
+
+    let (left_ranks, right_ranks) =
+        nullable_ranks(left.values().as_ref(), right.values().as_ref())?;
+    let size = left.size();
+
+    Ok(Box::new(move |i: usize, j: usize| {
+        left_ranks[i * size..(i + 1) * size].cmp(&right_ranks[j * size..(j + 1) * size])
+    }))
+}
+
+fn compare_struct(left: &dyn Array, right: &dyn Array) -> Result<DynComparator> {
+    let left = left.as_any().downcast_ref::<StructArray>().unwrap();
+    let right = right.as_any().downcast_ref::<StructArray>().unwrap();
+
+    let ranks = left
+        .values()
+        .iter()
+        .zip(right.values().iter())
+        .map(|(left, right)| nullable_ranks(left.as_ref(), right.as_ref()))
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(Box::new(move |i: usize, j: usize| {
+        ranks
+            .iter()
+            .map(|(left, right)| left[i].cmp(&right[j]))
+            .find(|ordering| *ordering != Ordering::Equal)
+            .unwrap_or(Ordering::Equal)
+    }))
+}
+
+/// Returns the dense ranks of the values of `left` and of `right` when ordered together.
+///
+/// Equal values have equal ranks and a value orders before another iff its rank is smaller,
+/// so values of both arrays (e.g. the values of two dictionaries) can be compared by comparing
+/// their ranks. Nulls are ranked by the value in their slot.
+/// # Example
+/// ```
+/// use arrow2::array::{ord::dense_ranks, Utf8Array};
+///
+/// # fn main() -> arrow2::error::Result<()> {
+/// let left = Utf8Array::<i32>::from_slice(["b", "a", "d"]);
+/// let right = Utf8Array::<i32>::from_slice(["c", "b"]);
+///
+/// let (left, right) = dense_ranks(&left, &right)?;
+/// assert_eq!(left, vec![1, 0, 3]);
+/// assert_eq!(right, vec![2, 1]);
+/// # Ok(())
+/// # }
+/// ```
+/// # Error
+/// Errors iff [`build_compare`] errors, e.g. when the arrays have different types.
+pub fn dense_ranks(left: &dyn Array, right: &dyn Array) -> Result<(Vec<usize>, Vec<usize>)> {
+    let compare_left = build_compare(left, left)?;
+    let compare_right = build_compare(right, right)?;
+    let compare = build_compare(left, right)?;
+
+    let mut left_order = (0..left.len()).collect::<Vec<_>>();
+    left_order.sort_unstable_by(|i, j| compare_left(*i, *j));
+    let mut right_order = (0..right.len()).collect::<Vec<_>>();
+    right_order.sort_unstable_by(|i, j| compare_right(*i, *j));
+
+    // merges both orders, where an item is `(is_left, index)`
+    let compare_items = |(lhs_is_left, lhs), (rhs_is_left, rhs)| match (lhs_is_left, rhs_is_left) {
+        (true, true) => compare_left(lhs, rhs),
+        (true, false) => compare(lhs, rhs),
+        (false, true) => compare(rhs, lhs).reverse(),
+        (false, false) => compare_right(lhs, rhs),
+    };
+
+    let mut left_ranks = vec![0; left.len()];
+    let mut right_ranks = vec![0; right.len()];
+    let mut left_order = left_order.into_iter().peekable();
+    let mut right_order = right_order.into_iter().peekable();
+    let mut rank = 0;
+    let mut previous = None;
+    loop {
+        let item = match (left_order.peek(), right_order.peek()) {
+            (Some(lhs), Some(rhs)) => {
+                if compare(*lhs, *rhs) != Ordering::Greater {
+                    (true, left_order.next().unwrap())
+                } else {
+                    (false, right_order.next().unwrap())
+                }
+            }
+            (Some(_), None) => (true, left_order.next().unwrap()),
+            (None, Some(_)) => (false, right_order.next().unwrap()),
+            (None, None) => break,
+        };
+        if let Some(previous) = previous {
+            if compare_items(previous, item) != Ordering::Equal {
+                rank += 1;
+            }
+        }
+        match item {
+            (true, index) => left_ranks[index] = rank,
+            (false, index) => right_ranks[index] = rank,
+        }
+        previous = Some(item);
+    }
+    Ok((left_ranks, right_ranks))
+}
+
+/// Whether [`build_compare`] supports arrays of `data_type`
+#[cfg(any(feature = "compute_sort", feature = "compute_comparison"))]
+pub(crate) fn can_build_compare(data_type: &DataType) -> bool {
+    let array = new_empty_array(data_type.clone());
+    build_compare(array.as_ref(), array.as_ref()).is_ok()
+}
+
+/// Returns the ranks of the items of `array` from the `ranks` of its values (see [`dense_ranks`]).
+/// Items whose key or value is null are null.
+#[cfg(any(feature = "compute_sort", feature = "compute_comparison"))]
+pub(crate) fn dictionary_ranks<K: DictionaryKey>(
+    array: &DictionaryArray<K>,
+    ranks: &[usize],
+) -> PrimitiveArray<u64> {
+    let keys = array.keys();
+    // null keys may be out of bounds of the values
+    let values = keys
+        .values()
+        .iter()
+        .map(|key| {
+            unsafe { ranks.get(key.as_usize()) }
+                .copied()
+                .unwrap_or_default() as u64
+        })
+        .collect::<Vec<_>>();
+
+    let validity = match array.values().validity() {
+        Some(values_validity) if values_validity.unset_bits() > 0 => {
+            let validity = keys.iter().map(|key| {
+                key.map(|key| values_validity.get_bit(unsafe { key.as_usize() }))
+                    .unwrap_or(false)
+            });
+            Some(crate::bitmap::Bitmap::from_trusted_len_iter(validity))
+        }
+        _ => keys.validity().cloned(),
+    };
+    PrimitiveArray::new(DataType::UInt64, values.into(), validity)
+}
+
 macro_rules! dyn_dict {
     ($key:ty, $lhs:expr, $rhs:expr) => {{
         let lhs = $lhs.as_any().downcast_ref().unwrap();
@@ -159,6 +334,10 @@ macro_rules! dyn_dict {
 
 /// returns a comparison function that compares values at two different slots
 /// between two [`Array`].
+///
+/// Lists are compared element-wise and then by their length, and structs field-wise. Null
+/// elements and fields order before all other values; nulls of `left` and `right` themselves
+/// are compared by the value in their slot.
 /// # Example
 /// ```
 /// use arrow2::array::{ord::build_compare, PrimitiveArray};
@@ -218,6 +397,10 @@ pub fn build_compare(left: &dyn Array, right: &dyn Array) -> Result<DynComparato
         (LargeUtf8, LargeUtf8) => compare_string::<i64>(left, right),
         (Binary, Binary) => compare_binary::<i32>(left, right),
         (LargeBinary, LargeBinary) => compare_binary::<i64>(left, right),
+        (List(_), List(_)) => compare_list::<i32>(left, right)?,
+        (LargeList(_), LargeList(_)) => compare_list::<i64>(left, right)?,
+        (FixedSizeList(_, _), FixedSizeList(_, _)) => compare_fixed_size_list(left, right)?,
+        (Struct(_), Struct(_)) => compare_struct(left, right)?,
         (Dictionary(key_type_lhs, ..), Dictionary(key_type_rhs, ..)) => {
             match (key_type_lhs, key_type_rhs) {
                 (IntegerType::UInt8, IntegerType::UInt8) => dyn_dict!(u8, left, right),
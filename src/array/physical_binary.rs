@@ -1,4 +1,6 @@
-use crate::bitmap::MutableBitmap;
+use alloc::vec::Vec;
+
+use crate::bitmap::{utils::count_zeros, Bitmap, MutableBitmap};
 use crate::offset::{Offset, Offsets};
 
 /// # Safety
@@ -7,11 +9,11 @@ use crate::offset::{Offset, Offsets};
 #[allow(clippy::type_complexity)]
 pub(crate) unsafe fn try_trusted_len_unzip<E, I, P, O>(
     iterator: I,
-) -> std::result::Result<(Option<MutableBitmap>, Offsets<O>, Vec<u8>), E>
+) -> core::result::Result<(Option<MutableBitmap>, Offsets<O>, Vec<u8>), E>
 where
     O: Offset,
     P: AsRef<[u8]>,
-    I: Iterator<Item = std::result::Result<Option<P>, E>>,
+    I: Iterator<Item = core::result::Result<Option<P>, E>>,
 {
     let (_, upper) = iterator.size_hint();
     let len = upper.expect("trusted_len_unzip requires an upper limit");
@@ -22,7 +24,7 @@ where
 
     let mut length = O::default();
     let mut dst = offsets.as_mut_ptr();
-    std::ptr::write(dst, length);
+    core::ptr::write(dst, length);
     dst = dst.add(1);
     for item in iterator {
         if let Some(item) = item? {
@@ -34,7 +36,7 @@ where
             null.push_unchecked(false);
         };
 
-        std::ptr::write(dst, length);
+        core::ptr::write(dst, length);
         dst = dst.add(1);
     }
     assert_eq!(
@@ -227,3 +229,30 @@ pub(crate) fn extend_validity(
         }
     }
 }
+
+/// Extends `validity` with the items of `other` in `start..start + len`
+pub(crate) fn extend_validity_from_bitmap(
+    length: usize,
+    validity: &mut Option<MutableBitmap>,
+    other: Option<&Bitmap>,
+    start: usize,
+    len: usize,
+) {
+    match (validity.as_mut(), other) {
+        (Some(validity), Some(other)) => {
+            let (slice, offset, _) = other.as_slice();
+            validity.extend_from_slice(slice, offset + start, len)
+        }
+        (Some(validity), None) => validity.extend_constant(len, true),
+        (None, Some(other)) => {
+            let (slice, offset, _) = other.as_slice();
+            // only allocate a validity when the items have nulls
+            if count_zeros(slice, offset + start, len) > 0 {
+                let mut new_validity = MutableBitmap::from_len_set(length);
+                new_validity.extend_from_slice(slice, offset + start, len);
+                *validity = Some(new_validity);
+            }
+        }
+        (None, None) => {}
+    }
+}
@@ -1,4 +1,5 @@
-use std::fmt::{Debug, Formatter, Result, Write};
+use alloc::{boxed::Box, string::ToString};
+use core::fmt::{Debug, Formatter, Result, Write};
 
 use crate::array::Array;
 use crate::datatypes::{IntervalUnit, TimeUnit};
@@ -19,6 +20,30 @@ macro_rules! dyn_primitive {
     }};
 }
 
+/// Writes the decimal whose absolute value has the digits `magnitude` and `scale`, i.e.
+/// with exactly `scale` fractional digits (e.g. `105` with scale 2 is written as `1.05`).
+pub(crate) fn write_decimal<W: Write>(
+    f: &mut W,
+    magnitude: &str,
+    is_negative: bool,
+    scale: usize,
+) -> Result {
+    if is_negative {
+        f.write_char('-')?;
+    }
+    if scale == 0 {
+        return f.write_str(magnitude);
+    }
+    if magnitude.len() > scale {
+        let (integer, fraction) = magnitude.split_at(magnitude.len() - scale);
+        write!(f, "{integer}.{fraction}")
+    } else {
+        f.write_str("0.")?;
+        (magnitude.len()..scale).try_for_each(|_| f.write_char('0'))?;
+        f.write_str(magnitude)
+    }
+}
+
 pub fn get_write_value<'a, T: NativeType, F: Write>(
     array: &'a PrimitiveArray<T>,
 ) -> Box<dyn Fn(&mut F, usize) -> Result + 'a> {
@@ -117,24 +142,26 @@ pub fn get_write_value<'a, T: NativeType, F: Write>(
         Duration(TimeUnit::Nanosecond) => dyn_primitive!(array, i64, |x| format!("{x}ns")),
         Decimal(_, scale) => {
             // The number 999.99 has a precision of 5 and scale of 2
-            let scale = *scale as u32;
-            let factor = 10i128.pow(scale);
-            let display = move |x: i128| {
-                let base = x / factor;
-                let decimals = (x - base * factor).abs();
-                format!("{base}.{decimals}")
-            };
-            dyn_primitive!(array, i128, display)
+            let scale = *scale;
+            let array = (array as &dyn Array)
+                .as_any()
+                .downcast_ref::<PrimitiveArray<i128>>()
+                .unwrap();
+            Box::new(move |f, index| {
+                let x = array.value(index);
+                write_decimal(f, &x.unsigned_abs().to_string(), x < 0, scale)
+            })
         }
         Decimal256(_, scale) => {
-            let scale = *scale as u32;
-            let factor = (ethnum::I256::ONE * 10).pow(scale);
-            let display = move |x: i256| {
-                let base = x.0 / factor;
-                let decimals = (x.0 - base * factor).abs();
-                format!("{base}.{decimals}")
-            };
-            dyn_primitive!(array, i256, display)
+            let scale = *scale;
+            let array = (array as &dyn Array)
+                .as_any()
+                .downcast_ref::<PrimitiveArray<i256>>()
+                .unwrap();
+            Box::new(move |f, index| {
+                let x = array.value(index).0;
+                write_decimal(f, &x.unsigned_abs().to_string(), x.is_negative(), scale)
+            })
         }
         _ => unreachable!(),
     }
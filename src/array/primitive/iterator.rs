@@ -24,7 +24,7 @@ impl<T: NativeType> IntoIterator for PrimitiveArray<T> {
 
 impl<'a, T: NativeType> IntoIterator for &'a PrimitiveArray<T> {
     type Item = Option<&'a T>;
-    type IntoIter = ZipValidity<&'a T, std::slice::Iter<'a, T>, BitmapIter<'a>>;
+    type IntoIter = ZipValidity<&'a T, core::slice::Iter<'a, T>, BitmapIter<'a>>;
 
     #[inline]
     fn into_iter(self) -> Self::IntoIter {
@@ -35,7 +35,7 @@ impl<'a, T: NativeType> IntoIterator for &'a PrimitiveArray<T> {
 impl<'a, T: NativeType> MutablePrimitiveArray<T> {
     /// Returns an iterator over `Option<T>`
     #[inline]
-    pub fn iter(&'a self) -> ZipValidity<&'a T, std::slice::Iter<'a, T>, BitmapIter<'a>> {
+    pub fn iter(&'a self) -> ZipValidity<&'a T, core::slice::Iter<'a, T>, BitmapIter<'a>> {
         ZipValidity::new(
             self.values().iter(),
             self.validity().as_ref().map(|x| x.iter()),
@@ -44,7 +44,7 @@ impl<'a, T: NativeType> MutablePrimitiveArray<T> {
 
     /// Returns an iterator of `T`
     #[inline]
-    pub fn values_iter(&'a self) -> std::slice::Iter<'a, T> {
+    pub fn values_iter(&'a self) -> core::slice::Iter<'a, T> {
         self.values().iter()
     }
 }
@@ -1,3 +1,5 @@
+use alloc::{boxed::Box, vec::Vec};
+
 use crate::{
     bitmap::{
         utils::{BitmapIter, ZipValidity},
@@ -5,14 +7,17 @@ use crate::{
     },
     buffer::Buffer,
     datatypes::*,
-    error::Error,
+    error::{Error, Result},
     trusted_len::TrustedLen,
     types::{days_ms, f16, i256, months_days_ns, NativeType},
 };
 
-use super::Array;
+use super::{
+    bitmap_to_bytes, buffer_to_bytes, validation::validate_validity, Array, ArrayValidation,
+};
 use either::Either;
 
+#[cfg(feature = "std")]
 mod ffi;
 pub(super) mod fmt;
 mod from_natural;
@@ -57,7 +62,7 @@ pub(super) fn check<T: NativeType>(
     data_type: &DataType,
     values: &[T],
     validity_len: Option<usize>,
-) -> Result<(), Error> {
+) -> Result<()> {
     if validity_len.map_or(false, |len| len != values.len()) {
         return Err(Error::oos(
             "validity mask length must match the number of values",
@@ -85,7 +90,7 @@ impl<T: NativeType> PrimitiveArray<T> {
         data_type: DataType,
         values: Buffer<T>,
         validity: Option<Bitmap>,
-    ) -> Result<Self, Error> {
+    ) -> Result<Self> {
         check(&data_type, &values, validity.as_ref().map(|v| v.len()))?;
         Ok(Self {
             data_type,
@@ -140,15 +145,30 @@ impl<T: NativeType> PrimitiveArray<T> {
         Self::new(T::PRIMITIVE.into(), values.into(), None)
     }
 
+    /// Creates a (non-null) [`PrimitiveArray`] from values shared with other owners.
+    /// This function is `O(1)`: the values are not copied.
+    /// # Examples
+    /// ```
+    /// use std::sync::Arc;
+    /// use arrow2::array::PrimitiveArray;
+    ///
+    /// let values = Arc::new(vec![1, 2, 3]);
+    /// let array = PrimitiveArray::from_vec_shared(values.clone());
+    /// assert_eq!(array.values().as_slice(), values.as_slice());
+    /// ```
+    pub fn from_vec_shared(values: alloc::sync::Arc<Vec<T>>) -> Self {
+        Self::new(T::PRIMITIVE.into(), values.into(), None)
+    }
+
     /// Returns an iterator over the values and validity, `Option<&T>`.
     #[inline]
-    pub fn iter(&self) -> ZipValidity<&T, std::slice::Iter<T>, BitmapIter> {
+    pub fn iter(&self) -> ZipValidity<&T, core::slice::Iter<'_, T>, BitmapIter<'_>> {
         ZipValidity::new_with_validity(self.values().iter(), self.validity())
     }
 
     /// Returns an iterator of the values, `&T`, ignoring the arrays' validity.
     #[inline]
-    pub fn values_iter(&self) -> std::slice::Iter<T> {
+    pub fn values_iter(&self) -> core::slice::Iter<'_, T> {
         self.values().iter()
     }
 
@@ -287,7 +307,7 @@ impl<T: NativeType> PrimitiveArray<T> {
     /// # Panics
     /// This function panics if the function `f` modifies the length of the [`Bitmap`].
     pub fn apply_validity<F: FnOnce(Bitmap) -> Bitmap>(&mut self, f: F) {
-        if let Some(validity) = std::mem::take(&mut self.validity) {
+        if let Some(validity) = core::mem::take(&mut self.validity) {
             self.set_validity(Some(f(validity)))
         }
     }
@@ -326,7 +346,7 @@ impl<T: NativeType> PrimitiveArray<T> {
                     self.values,
                     Some(bitmap),
                 )),
-                Right(mutable_bitmap) => match self.values.get_mut().map(std::mem::take) {
+                Right(mutable_bitmap) => match self.values.get_mut().map(core::mem::take) {
                     Some(values) => Right(
                         MutablePrimitiveArray::try_new(
                             self.data_type,
@@ -343,7 +363,7 @@ impl<T: NativeType> PrimitiveArray<T> {
                 },
             }
         } else {
-            match self.values.get_mut().map(std::mem::take) {
+            match self.values.get_mut().map(core::mem::take) {
                 Some(values) => {
                     Right(MutablePrimitiveArray::try_new(self.data_type, values, None).unwrap())
                 }
@@ -419,8 +439,8 @@ impl<T: NativeType> PrimitiveArray<T> {
     }
 
     /// Boxes self into a [`std::sync::Arc<dyn Array>`].
-    pub fn arced(self) -> std::sync::Arc<dyn Array> {
-        std::sync::Arc::new(self)
+    pub fn arced(self) -> alloc::sync::Arc<dyn Array> {
+        alloc::sync::Arc::new(self)
     }
 
     /// Alias for `Self::try_new(..).unwrap()`.
@@ -435,12 +455,12 @@ impl<T: NativeType> PrimitiveArray<T> {
 
 impl<T: NativeType> Array for PrimitiveArray<T> {
     #[inline]
-    fn as_any(&self) -> &dyn std::any::Any {
+    fn as_any(&self) -> &dyn core::any::Any {
         self
     }
 
     #[inline]
-    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+    fn as_any_mut(&mut self) -> &mut dyn core::any::Any {
         self
     }
 
@@ -470,6 +490,20 @@ impl<T: NativeType> Array for PrimitiveArray<T> {
     fn to_boxed(&self) -> Box<dyn Array> {
         Box::new(self.clone())
     }
+
+    fn buffers(&self) -> Vec<Option<Buffer<u8>>> {
+        vec![
+            self.validity.as_ref().map(bitmap_to_bytes),
+            Some(buffer_to_bytes(&self.values)),
+        ]
+    }
+
+    fn validate(&self, validation: ArrayValidation) -> Result<()> {
+        if validation == ArrayValidation::Off {
+            return Ok(());
+        }
+        validate_validity(self.validity(), self.len())
+    }
 }
 
 /// A type definition [`PrimitiveArray`] for `i8`
@@ -1,7 +1,8 @@
-use std::{iter::FromIterator, sync::Arc};
+use alloc::{boxed::Box, sync::Arc, vec::Vec};
+use core::iter::FromIterator;
 
-use crate::array::physical_binary::extend_validity;
-use crate::array::TryExtendFromSelf;
+use crate::array::physical_binary::{extend_validity, extend_validity_from_bitmap};
+use crate::array::{downcast_extend, TryExtendFromSelf};
 use crate::bitmap::Bitmap;
 use crate::{
     array::{Array, MutableArray, TryExtend, TryPush},
@@ -189,7 +190,7 @@ impl<T: NativeType> MutablePrimitiveArray<T> {
     #[inline]
     pub fn extend_trusted_len<P, I>(&mut self, iterator: I)
     where
-        P: std::borrow::Borrow<T>,
+        P: alloc::borrow::Borrow<T>,
         I: TrustedLen<Item = Option<P>>,
     {
         unsafe { self.extend_trusted_len_unchecked(iterator) }
@@ -201,7 +202,7 @@ impl<T: NativeType> MutablePrimitiveArray<T> {
     #[inline]
     pub unsafe fn extend_trusted_len_unchecked<P, I>(&mut self, iterator: I)
     where
-        P: std::borrow::Borrow<T>,
+        P: alloc::borrow::Borrow<T>,
         I: Iterator<Item = Option<P>>,
     {
         if let Some(validity) = self.validity.as_mut() {
@@ -389,8 +390,8 @@ impl<T: NativeType> MutableArray for MutablePrimitiveArray<T> {
     fn as_box(&mut self) -> Box<dyn Array> {
         PrimitiveArray::new(
             self.data_type.clone(),
-            std::mem::take(&mut self.values).into(),
-            std::mem::take(&mut self.validity).map(|x| x.into()),
+            core::mem::take(&mut self.values).into(),
+            core::mem::take(&mut self.validity).map(|x| x.into()),
         )
         .boxed()
     }
@@ -398,8 +399,8 @@ impl<T: NativeType> MutableArray for MutablePrimitiveArray<T> {
     fn as_arc(&mut self) -> Arc<dyn Array> {
         PrimitiveArray::new(
             self.data_type.clone(),
-            std::mem::take(&mut self.values).into(),
-            std::mem::take(&mut self.validity).map(|x| x.into()),
+            core::mem::take(&mut self.values).into(),
+            core::mem::take(&mut self.validity).map(|x| x.into()),
         )
         .arced()
     }
@@ -408,11 +409,11 @@ impl<T: NativeType> MutableArray for MutablePrimitiveArray<T> {
         &self.data_type
     }
 
-    fn as_any(&self) -> &dyn std::any::Any {
+    fn as_any(&self) -> &dyn core::any::Any {
         self
     }
 
-    fn as_mut_any(&mut self) -> &mut dyn std::any::Any {
+    fn as_mut_any(&mut self) -> &mut dyn core::any::Any {
         self
     }
 
@@ -427,6 +428,19 @@ impl<T: NativeType> MutableArray for MutablePrimitiveArray<T> {
     fn shrink_to_fit(&mut self) {
         self.shrink_to_fit()
     }
+
+    fn try_extend_from_array(
+        &mut self,
+        other: &dyn Array,
+        start: usize,
+        len: usize,
+    ) -> Result<(), Error> {
+        let other = downcast_extend::<PrimitiveArray<T>>(other, start, len)?;
+        extend_validity_from_bitmap(self.len(), &mut self.validity, other.validity(), start, len);
+        self.values
+            .extend_from_slice(&other.values()[start..start + len]);
+        Ok(())
+    }
 }
 
 impl<T: NativeType> MutablePrimitiveArray<T> {
@@ -442,7 +456,7 @@ impl<T: NativeType> MutablePrimitiveArray<T> {
     #[inline]
     pub unsafe fn from_trusted_len_iter_unchecked<I, P>(iterator: I) -> Self
     where
-        P: std::borrow::Borrow<T>,
+        P: alloc::borrow::Borrow<T>,
         I: Iterator<Item = Option<P>>,
     {
         let (validity, values) = trusted_len_unzip(iterator);
@@ -458,7 +472,7 @@ impl<T: NativeType> MutablePrimitiveArray<T> {
     #[inline]
     pub fn from_trusted_len_iter<I, P>(iterator: I) -> Self
     where
-        P: std::borrow::Borrow<T>,
+        P: alloc::borrow::Borrow<T>,
         I: TrustedLen<Item = Option<P>>,
     {
         unsafe { Self::from_trusted_len_iter_unchecked(iterator) }
@@ -471,10 +485,10 @@ impl<T: NativeType> MutablePrimitiveArray<T> {
     #[inline]
     pub unsafe fn try_from_trusted_len_iter_unchecked<E, I, P>(
         iter: I,
-    ) -> std::result::Result<Self, E>
+    ) -> core::result::Result<Self, E>
     where
-        P: std::borrow::Borrow<T>,
-        I: IntoIterator<Item = std::result::Result<Option<P>, E>>,
+        P: alloc::borrow::Borrow<T>,
+        I: IntoIterator<Item = core::result::Result<Option<P>, E>>,
     {
         let iterator = iter.into_iter();
 
@@ -489,10 +503,10 @@ impl<T: NativeType> MutablePrimitiveArray<T> {
 
     /// Creates a [`MutablePrimitiveArray`] from an fallible iterator of trusted length.
     #[inline]
-    pub fn try_from_trusted_len_iter<E, I, P>(iterator: I) -> std::result::Result<Self, E>
+    pub fn try_from_trusted_len_iter<E, I, P>(iterator: I) -> core::result::Result<Self, E>
     where
-        P: std::borrow::Borrow<T>,
-        I: TrustedLen<Item = std::result::Result<Option<P>, E>>,
+        P: alloc::borrow::Borrow<T>,
+        I: TrustedLen<Item = core::result::Result<Option<P>, E>>,
     {
         unsafe { Self::try_from_trusted_len_iter_unchecked(iterator) }
     }
@@ -525,7 +539,7 @@ impl<T: NativeType> MutablePrimitiveArray<T> {
     }
 }
 
-impl<T: NativeType, Ptr: std::borrow::Borrow<Option<T>>> FromIterator<Ptr>
+impl<T: NativeType, Ptr: alloc::borrow::Borrow<Option<T>>> FromIterator<Ptr>
     for MutablePrimitiveArray<T>
 {
     fn from_iter<I: IntoIterator<Item = Ptr>>(iter: I) -> Self {
@@ -568,7 +582,7 @@ pub(crate) unsafe fn extend_trusted_len_unzip<I, P, T>(
     buffer: &mut Vec<T>,
 ) where
     T: NativeType,
-    P: std::borrow::Borrow<T>,
+    P: alloc::borrow::Borrow<T>,
     I: Iterator<Item = Option<P>>,
 {
     let (_, upper) = iterator.size_hint();
@@ -596,7 +610,7 @@ pub(crate) unsafe fn extend_trusted_len_unzip<I, P, T>(
 pub(crate) unsafe fn trusted_len_unzip<I, P, T>(iterator: I) -> (Option<MutableBitmap>, Vec<T>)
 where
     T: NativeType,
-    P: std::borrow::Borrow<T>,
+    P: alloc::borrow::Borrow<T>,
     I: Iterator<Item = Option<P>>,
 {
     let mut validity = MutableBitmap::new();
@@ -614,11 +628,11 @@ where
 #[inline]
 pub(crate) unsafe fn try_trusted_len_unzip<E, I, P, T>(
     iterator: I,
-) -> std::result::Result<(Option<MutableBitmap>, Vec<T>), E>
+) -> core::result::Result<(Option<MutableBitmap>, Vec<T>), E>
 where
     T: NativeType,
-    P: std::borrow::Borrow<T>,
-    I: Iterator<Item = std::result::Result<Option<P>, E>>,
+    P: alloc::borrow::Borrow<T>,
+    I: Iterator<Item = core::result::Result<Option<P>, E>>,
 {
     let (_, upper) = iterator.size_hint();
     let len = upper.expect("trusted_len_unzip requires an upper limit");
@@ -635,7 +649,7 @@ where
             null.push(false);
             T::default()
         };
-        std::ptr::write(dst, item);
+        core::ptr::write(dst, item);
         dst = dst.add(1);
     }
     assert_eq!(
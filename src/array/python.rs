@@ -0,0 +1,76 @@
+//! Conversions of arrays to and from [pyarrow](https://arrow.apache.org/docs/python/) via
+//! the [C data interface](https://arrow.apache.org/docs/format/CDataInterface.html).
+use pyo3::exceptions::{
+    PyIOError, PyNotImplementedError, PyOverflowError, PyRuntimeError, PyValueError,
+};
+use pyo3::ffi::Py_uintptr_t;
+use pyo3::prelude::*;
+
+use crate::datatypes::Field;
+use crate::error::{Error, ErrorKind};
+use crate::ffi;
+
+use super::Array;
+
+/// Converts `error` to the matching Python exception
+fn to_py_err(error: Error) -> PyErr {
+    let message = error.to_string();
+    match error.kind() {
+        ErrorKind::Io => PyIOError::new_err(message),
+        ErrorKind::NotYetImplemented => PyNotImplementedError::new_err(message),
+        ErrorKind::Overflow => PyOverflowError::new_err(message),
+        ErrorKind::External => PyRuntimeError::new_err(message),
+        _ => PyValueError::new_err(message),
+    }
+}
+
+/// Exports `array` to a `pyarrow.Array`.
+///
+/// The buffers of `array` are shared with Python, not copied; they are released once
+/// both `array` and the `pyarrow.Array` are dropped.
+/// # Errors
+/// Errors iff `pyarrow` can't be imported or can't import `array`.
+pub fn to_py(array: &dyn Array, py: Python) -> PyResult<PyObject> {
+    let mut schema = Box::new(ffi::export_field_to_c(&Field::new(
+        "",
+        array.data_type().clone(),
+        true,
+    )));
+    let mut array = Box::new(ffi::export_array_to_c(array.to_boxed()));
+
+    let schema_ptr = &mut *schema as *mut ffi::ArrowSchema;
+    let array_ptr = &mut *array as *mut ffi::ArrowArray;
+
+    // pyarrow moves the structs out of the pointers, leaving released structs that are
+    // dropped with the boxes
+    let array = py.import("pyarrow")?.getattr("Array")?.call_method1(
+        "_import_from_c",
+        (array_ptr as Py_uintptr_t, schema_ptr as Py_uintptr_t),
+    )?;
+
+    Ok(array.to_object(py))
+}
+
+/// Imports a `pyarrow.Array` (or any object implementing `_export_to_c`) as an [`Array`].
+///
+/// The buffers of `obj` are shared with Rust, not copied.
+/// # Errors
+/// Errors iff `obj` can't be exported or its export is not a valid array.
+pub fn from_py(obj: &PyAny) -> PyResult<Box<dyn Array>> {
+    let mut array = Box::new(ffi::ArrowArray::empty());
+    let mut schema = Box::new(ffi::ArrowSchema::empty());
+
+    let array_ptr = &mut *array as *mut ffi::ArrowArray;
+    let schema_ptr = &mut *schema as *mut ffi::ArrowSchema;
+
+    // pyarrow writes the exported structs to the pointers
+    obj.call_method1(
+        "_export_to_c",
+        (array_ptr as Py_uintptr_t, schema_ptr as Py_uintptr_t),
+    )?;
+
+    let field = unsafe { ffi::import_field_from_c(schema.as_ref()) }.map_err(to_py_err)?;
+    let array = unsafe { ffi::import_array_from_c(*array, field.data_type) }.map_err(to_py_err)?;
+
+    Ok(array)
+}
@@ -0,0 +1,42 @@
+//! Conversions between selection indices and [`RoaringBitmap`].
+use roaring::RoaringBitmap;
+
+use super::PrimitiveArray;
+
+#[cfg_attr(docsrs, doc(cfg(feature = "roaring")))]
+impl PrimitiveArray<u32> {
+    /// Returns a new [`PrimitiveArray`] with the positions of `roaring` in ascending order,
+    /// e.g. to be used as indices of [`take`](crate::compute::take::take).
+    /// # Example
+    /// ```
+    /// use arrow2::array::UInt32Array;
+    /// use roaring::RoaringBitmap;
+    ///
+    /// let roaring = RoaringBitmap::from_iter([3, 0, 2]);
+    /// let indices = UInt32Array::from_roaring(&roaring);
+    /// assert_eq!(indices, UInt32Array::from_slice([0, 2, 3]));
+    /// ```
+    pub fn from_roaring(roaring: &RoaringBitmap) -> Self {
+        let mut values = Vec::with_capacity(roaring.len() as usize);
+        values.extend(roaring.iter());
+        Self::from_vec(values)
+    }
+
+    /// Returns a [`RoaringBitmap`] with the values of this [`PrimitiveArray`].
+    ///
+    /// Null slots are ignored and repeated values are inserted once.
+    /// # Example
+    /// ```
+    /// use arrow2::array::UInt32Array;
+    ///
+    /// let indices = UInt32Array::from([Some(3), None, Some(0), Some(3)]);
+    /// let roaring = indices.to_roaring();
+    /// assert_eq!(roaring.iter().collect::<Vec<_>>(), vec![0, 3]);
+    /// ```
+    pub fn to_roaring(&self) -> RoaringBitmap {
+        match self.validity() {
+            Some(validity) if validity.unset_bits() > 0 => self.iter().flatten().copied().collect(),
+            _ => self.values().iter().copied().collect(),
+        }
+    }
+}
@@ -134,7 +134,7 @@ pub(crate) unsafe fn check_indexes_unchecked<K: DictionaryKey>(
 
 pub fn check_indexes<K>(keys: &[K], len: usize) -> Result<()>
 where
-    K: std::fmt::Debug + Copy + TryInto<usize>,
+    K: core::fmt::Debug + Copy + TryInto<usize>,
 {
     keys.iter().try_for_each(|key| {
         let key: usize = (*key)
@@ -168,8 +168,8 @@ mod tests {
             for offset in 0..values.len() - 1 {
                 let offsets = vec![0, offset as i32, values.len() as i32].try_into().unwrap();
 
-                let mut is_valid = std::str::from_utf8(&values[..offset]).is_ok();
-                is_valid &= std::str::from_utf8(&values[offset..]).is_ok();
+                let mut is_valid = core::str::from_utf8(&values[..offset]).is_ok();
+                is_valid &= core::str::from_utf8(&values[offset..]).is_ok();
 
                 assert_eq!(try_check_utf8::<i32, Offsets<i32>>(&offsets, &values).is_ok(), is_valid)
             }
@@ -1,6 +1,6 @@
 use super::super::{ffi::ToFfi, Array, FromFfi};
 use super::StructArray;
-use crate::{error::Result, ffi};
+use crate::{bitmap::align, error::Result, ffi};
 
 unsafe impl ToFfi for StructArray {
     fn buffers(&self) -> Vec<Option<*const u8>> {
@@ -12,16 +12,17 @@ unsafe impl ToFfi for StructArray {
     }
 
     fn offset(&self) -> Option<usize> {
-        Some(
-            self.validity
-                .as_ref()
-                .map(|bitmap| bitmap.offset())
-                .unwrap_or_default(),
-        )
+        // the offset of a struct applies to its children, which are already sliced.
+        match self.validity.as_ref() {
+            Some(bitmap) if bitmap.offset() != 0 => None,
+            _ => Some(0),
+        }
     }
 
     fn to_ffi_aligned(&self) -> Self {
-        self.clone()
+        let mut array = self.clone();
+        array.validity = array.validity.map(|bitmap| align(&bitmap, 0));
+        array
     }
 }
 
@@ -30,11 +31,15 @@ impl<A: ffi::ArrowArrayRef> FromFfi<A> for StructArray {
         let data_type = array.data_type().clone();
         let fields = Self::get_fields(&data_type);
 
+        let offset = array.array().offset();
+        let length = array.array().len();
+
         let validity = unsafe { array.validity() }?;
         let values = (0..fields.len())
             .map(|index| {
                 let child = array.child(index)?;
-                ffi::try_from(child)
+                // the offset of a struct applies to its children
+                ffi::try_from(child).map(|child| child.slice(offset, length))
             })
             .collect::<Result<Vec<Box<dyn Array>>>>()?;
 
@@ -1,4 +1,4 @@
-use std::fmt::{Debug, Formatter, Result, Write};
+use core::fmt::{Debug, Formatter, Result, Write};
 
 use super::super::fmt::{get_display, write_map, write_vec};
 use super::StructArray;
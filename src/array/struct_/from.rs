@@ -0,0 +1,150 @@
+use alloc::{boxed::Box, vec::Vec};
+
+use crate::{
+    array::{Array, MutableArray, TryPush, TryPushRow},
+    bitmap::MutableBitmap,
+    datatypes::{DataType, Field},
+    error::Error,
+    trusted_len::TrustedLen,
+};
+
+use super::StructArray;
+
+macro_rules! impl_try_push_row {
+    ($(($M:ident, $T:ident, $i:tt)),+) => {
+        impl<$($M, $T),+> TryPushRow<($(Option<$T>,)+)> for ($($M,)+)
+        where
+            $($M: MutableArray + TryPush<Option<$T>>),+
+        {
+            fn try_push_row(&mut self, row: Option<($(Option<$T>,)+)>) -> Result<(), Error> {
+                match row {
+                    Some(row) => {
+                        $(self.$i.try_push(row.$i)?;)+
+                    }
+                    None => {
+                        $(self.$i.push_null();)+
+                    }
+                }
+                Ok(())
+            }
+
+            fn into_arrays(mut self) -> Vec<Box<dyn Array>> {
+                vec![$(self.$i.as_box()),+]
+            }
+        }
+    };
+}
+
+impl_try_push_row!((M0, T0, 0));
+impl_try_push_row!((M0, T0, 0), (M1, T1, 1));
+impl_try_push_row!((M0, T0, 0), (M1, T1, 1), (M2, T2, 2));
+impl_try_push_row!((M0, T0, 0), (M1, T1, 1), (M2, T2, 2), (M3, T3, 3));
+impl_try_push_row!(
+    (M0, T0, 0),
+    (M1, T1, 1),
+    (M2, T2, 2),
+    (M3, T3, 3),
+    (M4, T4, 4)
+);
+impl_try_push_row!(
+    (M0, T0, 0),
+    (M1, T1, 1),
+    (M2, T2, 2),
+    (M3, T3, 3),
+    (M4, T4, 4),
+    (M5, T5, 5)
+);
+
+fn finish<B, T>(names: &[&str], values: B, validity: MutableBitmap) -> Result<StructArray, Error>
+where
+    B: TryPushRow<T>,
+{
+    let values = values.into_arrays();
+    if names.len() != values.len() {
+        return Err(Error::InvalidArgumentError(format!(
+            "A StructArray with {} fields requires {} names, but got {}",
+            values.len(),
+            values.len(),
+            names.len()
+        )));
+    }
+    let fields = names
+        .iter()
+        .zip(values.iter())
+        .map(|(name, array)| Field::new(*name, array.data_type().clone(), true))
+        .collect();
+    StructArray::try_new(DataType::Struct(fields), values, validity.into())
+}
+
+impl StructArray {
+    /// Creates a new [`StructArray`] from an iterator of optional rows, whose fields are
+    /// pushed to the tuple of mutable arrays `values` and named after `names`.
+    /// # Errors
+    /// This function errors iff `values` error or `names` and `values` have different lengths.
+    /// # Example
+    /// ```
+    /// use arrow2::array::{Array, MutablePrimitiveArray, MutableUtf8Array, StructArray};
+    ///
+    /// let data = vec![Some((Some(1i32), Some("a"))), None, Some((None, Some("c")))];
+    /// let values = (
+    ///     MutablePrimitiveArray::<i32>::new(),
+    ///     MutableUtf8Array::<i32>::new(),
+    /// );
+    /// let array = StructArray::try_from_iter(&["a", "b"], values, data).unwrap();
+    /// assert_eq!(array.len(), 3);
+    /// assert_eq!(array.fields()[1].name, "b");
+    /// ```
+    pub fn try_from_iter<B, T, I>(names: &[&str], values: B, iter: I) -> Result<Self, Error>
+    where
+        B: TryPushRow<T>,
+        I: IntoIterator<Item = Option<T>>,
+    {
+        let iter = iter.into_iter();
+        let mut values = values;
+        let mut validity = MutableBitmap::with_capacity(iter.size_hint().0);
+        for row in iter {
+            validity.push(row.is_some());
+            values.try_push_row(row)?;
+        }
+        finish(names, values, validity)
+    }
+
+    /// Creates a new [`StructArray`] from a [`TrustedLen`] of optional rows, whose fields are
+    /// pushed to the tuple of mutable arrays `values` and named after `names`.
+    /// # Panics
+    /// This function panics iff `values` error or `names` and `values` have different lengths.
+    pub fn from_trusted_len_iter<B, T, I>(names: &[&str], values: B, iter: I) -> Self
+    where
+        B: TryPushRow<T>,
+        I: TrustedLen<Item = Option<T>>,
+    {
+        Self::try_from_iter(names, values, iter).unwrap()
+    }
+
+    /// Creates a new [`StructArray`] from a fallible [`TrustedLen`] of optional rows, whose
+    /// fields are pushed to the tuple of mutable arrays `values` and named after `names`.
+    /// # Errors
+    /// This function errors on the first error of the iterator, or iff `values` error or
+    /// `names` and `values` have different lengths.
+    pub fn try_from_trusted_len_iter<E, B, T, I>(
+        names: &[&str],
+        values: B,
+        iter: I,
+    ) -> Result<Self, E>
+    where
+        E: From<Error>,
+        B: TryPushRow<T>,
+        I: TrustedLen<Item = Result<Option<T>, E>>,
+    {
+        let (_, upper) = iter.size_hint();
+        let capacity = upper.expect("try_from_trusted_len_iter requires an upper limit");
+        let mut values = values;
+        let mut validity = MutableBitmap::with_capacity(capacity);
+        for row in iter {
+            let row = row?;
+            validity.push(row.is_some());
+            values.try_push_row(row)?;
+        }
+        Ok(finish(names, values, validity)?)
+    }
+}
@@ -1,3 +1,5 @@
+use alloc::{boxed::Box, vec::Vec};
+
 use crate::{
     bitmap::utils::{BitmapIter, ZipValidity},
     scalar::{new_scalar, Scalar},
@@ -1,13 +1,21 @@
+use alloc::{boxed::Box, vec::Vec};
+
 use crate::{
     bitmap::Bitmap,
+    buffer::Buffer,
     datatypes::{DataType, Field},
-    error::Error,
+    error::{Error, Result},
 };
 
-use super::{new_empty_array, new_null_array, Array};
+use super::{
+    bitmap_to_bytes, new_empty_array, new_null_array, validation::validate_validity, Array,
+    ArrayValidation,
+};
 
+#[cfg(feature = "std")]
 mod ffi;
 pub(super) mod fmt;
+mod from;
 mod iterator;
 mod mutable;
 pub use mutable::*;
@@ -49,7 +57,7 @@ impl StructArray {
         data_type: DataType,
         values: Vec<Box<dyn Array>>,
         validity: Option<Bitmap>,
-    ) -> Result<Self, Error> {
+    ) -> Result<Self> {
         let fields = Self::try_get_fields(&data_type)?;
         if fields.is_empty() {
             return Err(Error::oos("A StructArray must contain at least one field"));
@@ -227,8 +235,8 @@ impl StructArray {
     }
 
     /// Boxes self into a [`std::sync::Arc<dyn Array>`].
-    pub fn arced(self) -> std::sync::Arc<dyn Array> {
-        std::sync::Arc::new(self)
+    pub fn arced(self) -> alloc::sync::Arc<dyn Array> {
+        alloc::sync::Arc::new(self)
     }
 }
 
@@ -258,7 +266,7 @@ impl StructArray {
 
 impl StructArray {
     /// Returns the fields the `DataType::Struct`.
-    pub(crate) fn try_get_fields(data_type: &DataType) -> Result<&[Field], Error> {
+    pub(crate) fn try_get_fields(data_type: &DataType) -> Result<&[Field]> {
         match data_type.to_logical_type() {
             DataType::Struct(fields) => Ok(fields),
             _ => Err(Error::oos(
@@ -275,12 +283,12 @@ impl StructArray {
 
 impl Array for StructArray {
     #[inline]
-    fn as_any(&self) -> &dyn std::any::Any {
+    fn as_any(&self) -> &dyn core::any::Any {
         self
     }
 
     #[inline]
-    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+    fn as_any_mut(&mut self) -> &mut dyn core::any::Any {
         self
     }
 
@@ -314,4 +322,29 @@ impl Array for StructArray {
     fn to_boxed(&self) -> Box<dyn Array> {
         Box::new(self.clone())
     }
+
+    fn buffers(&self) -> Vec<Option<Buffer<u8>>> {
+        vec![self.validity.as_ref().map(bitmap_to_bytes)]
+    }
+
+    fn direct_children(&self) -> Vec<Box<dyn Array>> {
+        self.values.clone()
+    }
+
+    fn validate(&self, validation: ArrayValidation) -> Result<()> {
+        if validation == ArrayValidation::Off {
+            return Ok(());
+        }
+        validate_validity(self.validity(), self.len())?;
+        self.values().iter().try_for_each(|value| {
+            if value.len() != self.len() {
+                return Err(Error::oos(format!(
+                    "The values of a StructArray must have its length ({}), but a value has length {}",
+                    self.len(),
+                    value.len()
+                )));
+            }
+            value.validate(validation)
+        })
+    }
 }
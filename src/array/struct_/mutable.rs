@@ -1,7 +1,7 @@
-use std::sync::Arc;
+use alloc::{boxed::Box, string::ToString, sync::Arc, vec::Vec};
 
 use crate::{
-    array::{Array, MutableArray},
+    array::{downcast_extend, physical_binary::extend_validity_from_bitmap, Array, MutableArray},
     bitmap::MutableBitmap,
     datatypes::DataType,
     error::Error,
@@ -201,11 +201,11 @@ impl MutableArray for MutableStructArray {
     fn as_box(&mut self) -> Box<dyn Array> {
         StructArray::new(
             self.data_type.clone(),
-            std::mem::take(&mut self.values)
+            core::mem::take(&mut self.values)
                 .into_iter()
                 .map(|mut v| v.as_box())
                 .collect(),
-            std::mem::take(&mut self.validity).map(|x| x.into()),
+            core::mem::take(&mut self.validity).map(|x| x.into()),
         )
         .boxed()
     }
@@ -213,11 +213,11 @@ impl MutableArray for MutableStructArray {
     fn as_arc(&mut self) -> Arc<dyn Array> {
         StructArray::new(
             self.data_type.clone(),
-            std::mem::take(&mut self.values)
+            core::mem::take(&mut self.values)
                 .into_iter()
                 .map(|mut v| v.as_box())
                 .collect(),
-            std::mem::take(&mut self.validity).map(|x| x.into()),
+            core::mem::take(&mut self.validity).map(|x| x.into()),
         )
         .arced()
     }
@@ -226,11 +226,11 @@ impl MutableArray for MutableStructArray {
         &self.data_type
     }
 
-    fn as_any(&self) -> &dyn std::any::Any {
+    fn as_any(&self) -> &dyn core::any::Any {
         self
     }
 
-    fn as_mut_any(&mut self) -> &mut dyn std::any::Any {
+    fn as_mut_any(&mut self) -> &mut dyn core::any::Any {
         self
     }
 
@@ -245,4 +245,28 @@ impl MutableArray for MutableStructArray {
     fn reserve(&mut self, additional: usize) {
         self.reserve(additional)
     }
+
+    fn try_extend_from_array(
+        &mut self,
+        other: &dyn Array,
+        start: usize,
+        len: usize,
+    ) -> Result<(), Error> {
+        let other = downcast_extend::<StructArray>(other, start, len)?;
+        if other.values().len() != self.values.len() {
+            return Err(Error::InvalidArgumentError(
+                "Cannot extend a MutableStructArray from an array with a different number of fields"
+                    .to_string(),
+            ));
+        }
+        let length = self.len();
+        self.values
+            .iter_mut()
+            .zip(other.values())
+            .try_for_each(|(value, other)| {
+                value.try_extend_from_array(other.as_ref(), start, len)
+            })?;
+        extend_validity_from_bitmap(length, &mut self.validity, other.validity(), start, len);
+        Ok(())
+    }
 }
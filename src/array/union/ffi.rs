@@ -33,8 +33,10 @@ impl<A: ffi::ArrowArrayRef> FromFfi<A> for UnionArray {
         let data_type = array.data_type().clone();
         let fields = Self::get_fields(&data_type);
 
-        let mut types = unsafe { array.buffer::<i8>(0) }?;
-        let offsets = if Self::is_sparse(&data_type) {
+        // the buffers are already sliced by the offset of the array
+        let types = unsafe { array.buffer::<i8>(0) }?;
+        let is_sparse = Self::is_sparse(&data_type);
+        let offsets = if is_sparse {
             None
         } else {
             Some(unsafe { array.buffer::<i32>(1) }?)
@@ -45,14 +47,16 @@ impl<A: ffi::ArrowArrayRef> FromFfi<A> for UnionArray {
         let fields = (0..fields.len())
             .map(|index| {
                 let child = array.child(index)?;
-                ffi::try_from(child)
+                let child = ffi::try_from(child)?;
+                // the offset of a sparse union applies to its fields
+                Ok(if is_sparse {
+                    child.slice(offset, length)
+                } else {
+                    child
+                })
             })
             .collect::<Result<Vec<Box<dyn Array>>>>()?;
 
-        if offset > 0 {
-            types = types.slice(offset, length);
-        };
-
         Self::try_new(data_type, types, fields, offsets)
     }
 }
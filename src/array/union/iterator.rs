@@ -1,3 +1,5 @@
+use alloc::boxed::Box;
+
 use super::UnionArray;
 use crate::{scalar::Scalar, trusted_len::TrustedLen};
 
@@ -53,6 +55,6 @@ impl<'a> UnionArray {
     }
 }
 
-impl<'a> std::iter::ExactSizeIterator for UnionIter<'a> {}
+impl<'a> core::iter::ExactSizeIterator for UnionIter<'a> {}
 
 unsafe impl<'a> TrustedLen for UnionIter<'a> {}
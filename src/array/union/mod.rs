@@ -1,16 +1,24 @@
+use alloc::{boxed::Box, vec::Vec};
+
 use crate::{
     bitmap::Bitmap,
     buffer::Buffer,
     datatypes::{DataType, Field, UnionMode},
-    error::Error,
+    error::{Error, Result},
     scalar::{new_scalar, Scalar},
 };
 
-use super::{new_empty_array, new_null_array, Array};
+use super::{
+    buffer_to_bytes, growable::make_growable, new_empty_array, new_null_array, Array,
+    ArrayValidation,
+};
 
+#[cfg(feature = "std")]
 mod ffi;
 pub(super) mod fmt;
 mod iterator;
+mod mutable;
+pub use mutable::*;
 
 type UnionComponents<'a> = (&'a [Field], Option<&'a [i32]>, UnionMode);
 
@@ -46,51 +54,78 @@ impl UnionArray {
     /// * the fields's len is different from the `data_type`'s children's length
     /// * The number of `fields` is larger than `i8::MAX`
     /// * any of the values's data type is different from its corresponding children' data type
+    /// * any of the `types` is not declared by `data_type`
     pub fn try_new(
         data_type: DataType,
         types: Buffer<i8>,
         fields: Vec<Box<dyn Array>>,
         offsets: Option<Buffer<i32>>,
-    ) -> Result<Self, Error> {
+    ) -> Result<Self> {
+        // Safety: every invariant is checked with `ArrayValidation::Full`
+        unsafe {
+            Self::try_new_with_validation(data_type, types, fields, offsets, ArrayValidation::Full)
+        }
+    }
+
+    /// Returns a new [`UnionArray`] whose invariants are checked at the level `validation`:
+    /// [`ArrayValidation::Full`] is equivalent to [`UnionArray::try_new`],
+    /// [`ArrayValidation::Basic`] does not check the `types` and
+    /// [`ArrayValidation::Off`] only checks the ids of `data_type`, which are required to
+    /// map the `types` to the fields.
+    /// # Errors
+    /// This function errors iff an invariant checked at the level `validation` does not hold.
+    /// # Safety
+    /// This function is unsound iff an invariant not checked at the level `validation` does
+    /// not hold.
+    pub unsafe fn try_new_with_validation(
+        data_type: DataType,
+        types: Buffer<i8>,
+        fields: Vec<Box<dyn Array>>,
+        offsets: Option<Buffer<i32>>,
+        validation: ArrayValidation,
+    ) -> Result<Self> {
         let (f, ids, mode) = Self::try_get_all(&data_type)?;
 
-        if f.len() != fields.len() {
-            return Err(Error::oos(
-                "The number of `fields` must equal the number of children fields in DataType::Union",
-            ));
-        };
-        let number_of_fields: i8 = fields
-            .len()
-            .try_into()
-            .map_err(|_| Error::oos("The number of `fields` cannot be larger than i8::MAX"))?;
-
-        f
-            .iter().map(|a| a.data_type())
-            .zip(fields.iter().map(|a| a.data_type()))
-            .enumerate()
-            .try_for_each(|(index, (data_type, child))| {
-                if data_type != child {
-                    Err(Error::oos(format!(
-                        "The children DataTypes of a UnionArray must equal the children data types. 
-                         However, the field {index} has data type {data_type:?} but the value has data type {child:?}"
-                    )))
-                } else {
-                    Ok(())
-                }
-            })?;
+        if validation != ArrayValidation::Off {
+            if f.len() != fields.len() {
+                return Err(Error::oos(
+                    "The number of `fields` must equal the number of children fields in DataType::Union",
+                ));
+            };
+            if fields.len() > i8::MAX as usize {
+                return Err(Error::oos(
+                    "The number of `fields` cannot be larger than i8::MAX",
+                ));
+            }
 
-        if let Some(offsets) = &offsets {
-            if offsets.len() != types.len() {
+            f
+                .iter().map(|a| a.data_type())
+                .zip(fields.iter().map(|a| a.data_type()))
+                .enumerate()
+                .try_for_each(|(index, (data_type, child))| {
+                    if data_type != child {
+                        Err(Error::oos(format!(
+                            "The children DataTypes of a UnionArray must equal the children data types. 
+                             However, the field {index} has data type {data_type:?} but the value has data type {child:?}"
+                        )))
+                    } else {
+                        Ok(())
+                    }
+                })?;
+
+            if let Some(offsets) = &offsets {
+                if offsets.len() != types.len() {
+                    return Err(Error::oos(
+                        "In a UnionArray, the offsets' length must be equal to the number of types",
+                    ));
+                }
+            }
+            if offsets.is_none() != mode.is_sparse() {
                 return Err(Error::oos(
-                    "In a UnionArray, the offsets' length must be equal to the number of types",
+                    "In a sparse UnionArray, the offsets must be set (and vice-versa)",
                 ));
             }
         }
-        if offsets.is_none() != mode.is_sparse() {
-            return Err(Error::oos(
-                "In a sparse UnionArray, the offsets must be set (and vice-versa)",
-            ));
-        }
 
         // build hash
         let map = if let Some(&ids) = ids.as_ref() {
@@ -115,28 +150,28 @@ impl UnionArray {
                 hash[id as usize] = pos;
             }
 
-            types.iter().try_for_each(|&type_| {
-                if type_ < 0 {
-                    return Err(Error::oos("In a union, when the ids are set, every type must be >= 0"));
-                }
-                let id = hash[type_ as usize];
-                if id >= fields.len() {
-                    Err(Error::oos("In a union, when the ids are set, each id must be smaller than the number of fields."))
-                } else {
-                    Ok(())
-                }
-            })?;
+            if validation == ArrayValidation::Full {
+                types.iter().try_for_each(|&type_| {
+                    if type_ < 0 {
+                        return Err(Error::oos("In a union, when the ids are set, every type must be >= 0"));
+                    }
+                    let id = hash[type_ as usize];
+                    if id >= fields.len() {
+                        Err(Error::oos("In a union, when the ids are set, each id must be smaller than the number of fields."))
+                    } else {
+                        Ok(())
+                    }
+                })?;
+            }
 
             Some(hash)
         } else {
             // Safety: every type in types is smaller than number of fields
-            let mut is_valid = true;
-            for &type_ in types.iter() {
-                if type_ < 0 || type_ >= number_of_fields {
-                    is_valid = false
-                }
-            }
-            if !is_valid {
+            if validation == ArrayValidation::Full
+                && types
+                    .iter()
+                    .any(|&type_| type_ < 0 || type_ as usize >= fields.len())
+            {
                 return Err(Error::oos(
                     "Every type in `types` must be larger than 0 and smaller than the number of fields.",
                 ));
@@ -226,8 +261,8 @@ impl UnionArray {
     }
 
     /// Boxes self into a [`std::sync::Arc<dyn Array>`].
-    pub fn arced(self) -> std::sync::Arc<dyn Array> {
-        std::sync::Arc::new(self)
+    pub fn arced(self) -> alloc::sync::Arc<dyn Array> {
+        alloc::sync::Arc::new(self)
     }
 }
 
@@ -325,6 +360,24 @@ impl UnionArray {
         (type_, index)
     }
 
+    /// Returns the values of the field `field` selected by the slots of this array, in the order
+    /// of the slots. The result has the length of the number of slots of the field `field`.
+    /// # Implementation
+    /// This operation is `O(N)` where `N` is the length of this array.
+    /// # Panics
+    /// iff `field >= self.fields().len()`
+    pub fn child_selected(&self, field: usize) -> Box<dyn Array> {
+        let mut growable = make_growable(&[self.fields[field].as_ref()], false, self.len());
+        (0..self.len()).for_each(|index| {
+            // Safety: `index < self.len()`
+            let (type_, slot) = unsafe { self.index_unchecked(index) };
+            if type_ == field {
+                growable.extend(0, slot, 1)
+            }
+        });
+        growable.as_box()
+    }
+
     /// Returns the slot `index` as a [`Scalar`].
     /// # Panics
     /// iff `index >= self.len()`
@@ -347,12 +400,12 @@ impl UnionArray {
 }
 
 impl Array for UnionArray {
-    fn as_any(&self) -> &dyn std::any::Any {
+    fn as_any(&self) -> &dyn core::any::Any {
         self
     }
 
     #[inline]
-    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+    fn as_any_mut(&mut self) -> &mut dyn core::any::Any {
         self
     }
 
@@ -380,10 +433,89 @@ impl Array for UnionArray {
     fn to_boxed(&self) -> Box<dyn Array> {
         Box::new(self.clone())
     }
+
+    fn buffers(&self) -> Vec<Option<Buffer<u8>>> {
+        let mut buffers = vec![Some(buffer_to_bytes(&self.types))];
+        buffers.extend(self.offsets.as_ref().map(|x| Some(buffer_to_bytes(x))));
+        buffers
+    }
+
+    fn direct_children(&self) -> Vec<Box<dyn Array>> {
+        if self.offsets.is_some() {
+            self.fields.clone()
+        } else {
+            // the fields of sparse unions are not sliced
+            self.fields
+                .iter()
+                .map(|x| x.sliced(self.offset..self.offset + self.len()))
+                .collect()
+        }
+    }
+
+    fn validate(&self, validation: ArrayValidation) -> Result<()> {
+        if validation == ArrayValidation::Off {
+            return Ok(());
+        }
+        if Self::get_all(&self.data_type).0.len() != self.fields.len() {
+            return Err(Error::oos(
+                "The number of `fields` must equal the number of children fields in DataType::Union",
+            ));
+        }
+        if let Some(offsets) = &self.offsets {
+            if offsets.len() != self.types.len() {
+                return Err(Error::oos(
+                    "In a UnionArray, the offsets' length must be equal to the number of types",
+                ));
+            }
+        } else {
+            // the fields of sparse unions are not sliced
+            if let Some(field) = self
+                .fields
+                .iter()
+                .find(|field| field.len() < self.offset + self.len())
+            {
+                return Err(Error::oos(format!(
+                    "The fields of a sparse UnionArray must have at least its length ({}), but a field has length {}",
+                    self.offset + self.len(),
+                    field.len()
+                )));
+            }
+        }
+
+        if validation == ArrayValidation::Full {
+            let ids = Self::get_all(&self.data_type).1;
+            let is_declared = |type_: i8| match ids {
+                Some(ids) => ids.contains(&(type_ as i32)),
+                None => type_ >= 0 && (type_ as usize) < self.fields.len(),
+            };
+            if let Some(type_) = self.types.iter().find(|type_| !is_declared(**type_)) {
+                return Err(Error::oos(format!(
+                    "The type {type_} of a UnionArray is not declared by its data type"
+                )));
+            }
+            if self.offsets.is_some() {
+                (0..self.len()).try_for_each(|index| {
+                    let (field, slot) = self.index(index);
+                    let length = self.fields[field].len();
+                    if slot >= length {
+                        Err(Error::oos(format!(
+                            "The offset at slot {index} of a UnionArray is out of bounds of its field {field}, of length {length}"
+                        )))
+                    } else {
+                        Ok(())
+                    }
+                })?;
+            }
+        }
+
+        self.fields
+            .iter()
+            .try_for_each(|field| field.validate(validation))
+    }
 }
 
 impl UnionArray {
-    fn try_get_all(data_type: &DataType) -> Result<UnionComponents, Error> {
+    fn try_get_all(data_type: &DataType) -> Result<UnionComponents<'_>> {
         match data_type.to_logical_type() {
             DataType::Union(fields, ids, mode) => {
                 Ok((fields, ids.as_ref().map(|x| x.as_ref()), *mode))
@@ -0,0 +1,364 @@
+use alloc::{boxed::Box, string::ToString, sync::Arc, vec::Vec};
+
+use crate::{
+    array::{downcast_extend, growable::make_growable, Array, MutableArray, TryPush},
+    bitmap::MutableBitmap,
+    datatypes::{DataType, UnionMode},
+    error::Error,
+};
+
+use super::UnionArray;
+
+/// The mutable version of [`UnionArray`], built by pushing values to its fields via
+/// [`MutableUnionArray::push_variant`].
+///
+/// Values are stored in the dense layout (the values of each field are contiguous), and are
+/// converted to either layout via [`MutableUnionArray::into_dense`] and
+/// [`MutableUnionArray::into_sparse`]. Converting it to the layout of its [`DataType`] via
+/// [`From`] or [`MutableArray::as_box`] uses the [`UnionMode`] of the [`DataType`].
+#[derive(Debug)]
+pub struct MutableUnionArray {
+    data_type: DataType,
+    // the type id of each field
+    ids: Vec<i8>,
+    types: Vec<i8>,
+    // the index of each field of each slot
+    fields_indices: Vec<u8>,
+    offsets: Vec<i32>,
+    values: Vec<Box<dyn MutableArray>>,
+}
+
+impl MutableUnionArray {
+    /// Creates a new [`MutableUnionArray`].
+    /// # Panics
+    /// This function panics iff [`MutableUnionArray::try_new`] errors.
+    pub fn new(data_type: DataType, values: Vec<Box<dyn MutableArray>>) -> Self {
+        Self::try_new(data_type, values).unwrap()
+    }
+
+    /// Creates a new [`MutableUnionArray`] whose fields are `values`.
+    /// # Errors
+    /// This function errors iff:
+    /// * `data_type`'s logical type is not [`DataType::Union`]
+    /// * the number of `values` is different from the number of fields of `data_type`
+    /// * any of the `values`'s data type is different from its corresponding field's data type
+    /// * any of the `values` is not empty
+    pub fn try_new(data_type: DataType, values: Vec<Box<dyn MutableArray>>) -> Result<Self, Error> {
+        let (fields, ids, _) = UnionArray::try_get_all(&data_type)?;
+        if fields.len() != values.len() {
+            return Err(Error::oos(
+                "The number of `values` must equal the number of children fields in DataType::Union",
+            ));
+        }
+        if fields.len() > i8::MAX as usize {
+            return Err(Error::oos(
+                "The number of `values` cannot be larger than i8::MAX",
+            ));
+        }
+        fields
+            .iter()
+            .zip(values.iter())
+            .enumerate()
+            .try_for_each(|(index, (field, value))| {
+                if field.data_type() != value.data_type() {
+                    Err(Error::oos(format!(
+                        "The children DataTypes of a MutableUnionArray must equal the children data types. \
+                         However, the field {index} has data type {:?} but the value has data type {:?}",
+                        field.data_type(),
+                        value.data_type()
+                    )))
+                } else if !value.is_empty() {
+                    Err(Error::oos(format!(
+                        "The values of a MutableUnionArray must be empty, but the values of field {index} are not"
+                    )))
+                } else {
+                    Ok(())
+                }
+            })?;
+
+        let ids = match ids {
+            Some(ids) => {
+                if ids.len() != fields.len() {
+                    return Err(Error::oos(
+                        "In a union, when the ids are set, their length must be equal to the number of fields",
+                    ));
+                }
+                ids.iter()
+                    .map(|id| {
+                        i8::try_from(*id).map_err(|_| {
+                            Error::oos(
+                                "In a union, when the ids are set, every id must belong to [0, 128[",
+                            )
+                        })
+                    })
+                    .collect::<Result<Vec<_>, _>>()?
+            }
+            None => (0..fields.len() as i8).collect(),
+        };
+
+        Ok(Self {
+            data_type,
+            ids,
+            types: vec![],
+            fields_indices: vec![],
+            offsets: vec![],
+            values,
+        })
+    }
+
+    /// The values of each field. The values of a field only contain the values pushed to it.
+    pub fn values(&self) -> &Vec<Box<dyn MutableArray>> {
+        &self.values
+    }
+
+    /// The type ids of the slots
+    pub fn types(&self) -> &[i8] {
+        &self.types
+    }
+
+    /// Returns the values of the `i`th field, or `None` if they are not of type `A`.
+    pub fn value<A: MutableArray + 'static>(&mut self, i: usize) -> Option<&mut A> {
+        self.values
+            .get_mut(i)
+            .and_then(|values| values.as_mut_any().downcast_mut::<A>())
+    }
+
+    /// Pushes `value` to the `field`th field of the union, whose values are of type `A`.
+    /// # Errors
+    /// This function errors iff `field` is out of bounds, the values of the field are not
+    /// of type `A`, or `A` fails to push `value`.
+    /// # Example
+    /// ```
+    /// use arrow2::array::{MutablePrimitiveArray, MutableUnionArray, MutableUtf8Array};
+    /// use arrow2::datatypes::{DataType, Field, UnionMode};
+    ///
+    /// let fields = vec![
+    ///     Field::new("a", DataType::Int32, true),
+    ///     Field::new("b", DataType::Utf8, true),
+    /// ];
+    /// let data_type = DataType::Union(fields, None, UnionMode::Dense);
+    /// let mut array = MutableUnionArray::new(
+    ///     data_type,
+    ///     vec![
+    ///         Box::new(MutablePrimitiveArray::<i32>::new()),
+    ///         Box::new(MutableUtf8Array::<i32>::new()),
+    ///     ],
+    /// );
+    /// array.push_variant::<MutablePrimitiveArray<i32>, _>(0, Some(1)).unwrap();
+    /// array.push_variant::<MutableUtf8Array<i32>, _>(1, Some("a")).unwrap();
+    /// assert!(array.push_variant::<MutableUtf8Array<i32>, _>(0, Some("b")).is_err());
+    ///
+    /// let array = array.into_sparse();
+    /// assert_eq!(array.len(), 2);
+    /// assert_eq!(array.fields()[1].len(), 2);
+    /// ```
+    pub fn push_variant<A, T>(&mut self, field: usize, value: T) -> Result<(), Error>
+    where
+        A: MutableArray + TryPush<T> + 'static,
+    {
+        let num_fields = self.values.len();
+        let values = self
+            .values
+            .get_mut(field)
+            .ok_or_else(|| {
+                Error::InvalidArgumentError(format!(
+                    "The union has {num_fields} fields, but field {field} was pushed to"
+                ))
+            })?
+            .as_mut_any()
+            .downcast_mut::<A>()
+            .ok_or_else(|| {
+                Error::InvalidArgumentError(format!(
+                    "The values of field {field} of the union are not of type {}",
+                    core::any::type_name::<A>()
+                ))
+            })?;
+        let offset = i32::try_from(values.len()).map_err(|_| Error::Overflow)?;
+        values.try_push(value)?;
+        self.push_slot(field, offset);
+        Ok(())
+    }
+
+    fn push_slot(&mut self, field: usize, offset: i32) {
+        self.types.push(self.ids[field]);
+        self.fields_indices.push(field as u8);
+        self.offsets.push(offset);
+    }
+
+    /// Pushes a null slot, which is a null of the first field of the union.
+    /// # Panics
+    /// This function panics iff the union has no fields or the first field has more than
+    /// `i32::MAX` values.
+    pub fn push_null(&mut self) {
+        let offset = i32::try_from(self.values[0].len()).unwrap();
+        self.values[0].push_null();
+        self.push_slot(0, offset);
+    }
+
+    /// Reserves `additional` slots.
+    pub fn reserve(&mut self, additional: usize) {
+        self.types.reserve(additional);
+        self.fields_indices.reserve(additional);
+        self.offsets.reserve(additional);
+    }
+
+    /// Shrinks the capacity of the [`MutableUnionArray`] to fit its current length.
+    pub fn shrink_to_fit(&mut self) {
+        self.types.shrink_to_fit();
+        self.fields_indices.shrink_to_fit();
+        self.offsets.shrink_to_fit();
+        self.values
+            .iter_mut()
+            .for_each(|values| values.shrink_to_fit());
+    }
+
+    fn data_type_with_mode(&self, mode: UnionMode) -> DataType {
+        let (fields, ids, _) = UnionArray::get_all(&self.data_type);
+        let data_type = DataType::Union(fields.to_vec(), ids.map(|ids| ids.to_vec()), mode);
+        match &self.data_type {
+            DataType::Extension(name, _, metadata) => {
+                DataType::Extension(name.clone(), Box::new(data_type), metadata.clone())
+            }
+            _ => data_type,
+        }
+    }
+
+    fn take_dense(&mut self) -> UnionArray {
+        let fields = self
+            .values
+            .iter_mut()
+            .map(|values| values.as_box())
+            .collect();
+        self.fields_indices.clear();
+        UnionArray::new(
+            self.data_type_with_mode(UnionMode::Dense),
+            core::mem::take(&mut self.types).into(),
+            fields,
+            Some(core::mem::take(&mut self.offsets).into()),
+        )
+    }
+
+    fn take_sparse(&mut self) -> UnionArray {
+        let len = self.types.len();
+        let fields_indices = core::mem::take(&mut self.fields_indices);
+        let offsets = core::mem::take(&mut self.offsets);
+        let fields = self
+            .values
+            .iter_mut()
+            .enumerate()
+            .map(|(field, values)| {
+                let values = values.as_box();
+                let mut growable = make_growable(&[values.as_ref()], true, len);
+                // every slot of the union that is not of this field is null
+                fields_indices
+                    .iter()
+                    .zip(offsets.iter())
+                    .for_each(|(index, offset)| {
+                        if *index as usize == field {
+                            growable.extend(0, *offset as usize, 1)
+                        } else {
+                            growable.extend_validity(1)
+                        }
+                    });
+                growable.as_box()
+            })
+            .collect();
+        UnionArray::new(
+            self.data_type_with_mode(UnionMode::Sparse),
+            core::mem::take(&mut self.types).into(),
+            fields,
+            None,
+        )
+    }
+
+    /// Converts itself into a [`UnionArray`] with the dense layout.
+    pub fn into_dense(mut self) -> UnionArray {
+        self.take_dense()
+    }
+
+    /// Converts itself into a [`UnionArray`] with the sparse layout, where every field has the
+    /// length of the union, and is null on the slots of the other fields.
+    pub fn into_sparse(mut self) -> UnionArray {
+        self.take_sparse()
+    }
+
+    fn take(&mut self) -> UnionArray {
+        if UnionArray::is_sparse(&self.data_type) {
+            self.take_sparse()
+        } else {
+            self.take_dense()
+        }
+    }
+}
+
+impl From<MutableUnionArray> for UnionArray {
+    fn from(mut other: MutableUnionArray) -> Self {
+        other.take()
+    }
+}
+
+impl MutableArray for MutableUnionArray {
+    fn len(&self) -> usize {
+        self.types.len()
+    }
+
+    fn validity(&self) -> Option<&MutableBitmap> {
+        None
+    }
+
+    fn as_box(&mut self) -> Box<dyn Array> {
+        self.take().boxed()
+    }
+
+    fn as_arc(&mut self) -> Arc<dyn Array> {
+        self.take().arced()
+    }
+
+    fn data_type(&self) -> &DataType {
+        &self.data_type
+    }
+
+    fn as_any(&self) -> &dyn core::any::Any {
+        self
+    }
+
+    fn as_mut_any(&mut self) -> &mut dyn core::any::Any {
+        self
+    }
+
+    fn push_null(&mut self) {
+        self.push_null()
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        self.reserve(additional)
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.shrink_to_fit()
+    }
+
+    fn try_extend_from_array(
+        &mut self,
+        other: &dyn Array,
+        start: usize,
+        len: usize,
+    ) -> Result<(), Error> {
+        let other = downcast_extend::<UnionArray>(other, start, len)?;
+        if other.fields().len() != self.values.len() {
+            return Err(Error::InvalidArgumentError(
+                "Cannot extend a MutableUnionArray from an array with a different number of fields"
+                    .to_string(),
+            ));
+        }
+        self.reserve(len);
+        (start..start + len).try_for_each(|index| {
+            let (field, slot) = other.index(index);
+            let values = &mut self.values[field];
+            let offset = i32::try_from(values.len()).map_err(|_| Error::Overflow)?;
+            values.try_extend_from_array(other.fields()[field].as_ref(), slot, 1)?;
+            self.push_slot(field, offset);
+            Ok(())
+        })
+    }
+}
@@ -0,0 +1,156 @@
+use alloc::vec::Vec;
+use core::{iter::FromIterator, marker::PhantomData};
+
+use crate::{
+    bitmap::MutableBitmap,
+    error::{Error, Result},
+    offset::{Offset, Offsets},
+};
+
+use super::Utf8Array;
+
+/// A builder of [`Utf8Array`] from borrowed `&str`, e.g. referencing the buffer of a parser.
+///
+/// Unlike [`MutableUtf8Array`](super::MutableUtf8Array), which copies every value to its
+/// growing values buffer on push, this builder only keeps the references, and copies the values
+/// on [`DeferredUtf8Builder::freeze`] into a single allocation of exactly the total length of
+/// the values. This avoids the re-allocations (and thus the peak memory) of growing the values.
+/// # Example
+/// ```
+/// use arrow2::array::{DeferredUtf8Builder, Utf8Array};
+///
+/// let data = String::from("hello world");
+///
+/// let mut builder = DeferredUtf8Builder::<i32>::new();
+/// builder.push(Some(&data[..5]));
+/// builder.push(None);
+/// builder.push(Some(&data[6..]));
+///
+/// let array = builder.freeze().unwrap();
+/// assert_eq!(array, Utf8Array::<i32>::from([Some("hello"), None, Some("world")]));
+/// assert_eq!(array.values().len(), 10);
+/// ```
+#[derive(Debug, Clone)]
+pub struct DeferredUtf8Builder<'a, O: Offset> {
+    values: Vec<&'a str>,
+    validity: Option<MutableBitmap>,
+    values_len: usize,
+    phantom: PhantomData<O>,
+}
+
+impl<'a, O: Offset> Default for DeferredUtf8Builder<'a, O> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, O: Offset> DeferredUtf8Builder<'a, O> {
+    /// Returns a new empty [`DeferredUtf8Builder`].
+    pub fn new() -> Self {
+        Self::with_capacity(0)
+    }
+
+    /// Returns a new empty [`DeferredUtf8Builder`] with capacity for `capacity` values.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            values: Vec::with_capacity(capacity),
+            validity: None,
+            values_len: 0,
+            phantom: PhantomData,
+        }
+    }
+
+    /// The number of values pushed to this builder
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Whether no value was pushed to this builder
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The total length in bytes of the values pushed to this builder, i.e. the length of the
+    /// values buffer of [`DeferredUtf8Builder::freeze`]
+    #[inline]
+    pub fn values_len(&self) -> usize {
+        self.values_len
+    }
+
+    /// Pushes a new value to this builder.
+    #[inline]
+    pub fn push(&mut self, value: Option<&'a str>) {
+        match value {
+            Some(value) => self.push_value(value),
+            None => self.push_null(),
+        }
+    }
+
+    /// Pushes a new non-null value to this builder.
+    #[inline]
+    pub fn push_value(&mut self, value: &'a str) {
+        self.values_len += value.len();
+        self.values.push(value);
+        if let Some(validity) = &mut self.validity {
+            validity.push(true)
+        }
+    }
+
+    /// Pushes a new null value to this builder.
+    #[inline]
+    pub fn push_null(&mut self) {
+        self.values.push("");
+        match &mut self.validity {
+            Some(validity) => validity.push(false),
+            None => {
+                let mut validity = MutableBitmap::with_capacity(self.values.capacity());
+                validity.extend_constant(self.len(), true);
+                validity.set(self.len() - 1, false);
+                self.validity = Some(validity);
+            }
+        }
+    }
+
+    /// Copies the values pushed to this builder into a new [`Utf8Array`].
+    /// # Errors
+    /// Errors iff the total length of the values does not fit in `O`.
+    pub fn freeze(self) -> Result<Utf8Array<O>> {
+        O::from_usize(self.values_len).ok_or(Error::Overflow)?;
+
+        let mut offsets = Offsets::<O>::with_capacity(self.len());
+        let mut values = Vec::with_capacity(self.values_len);
+        for value in self.values {
+            // the total length was checked to fit in `O`
+            offsets.try_push_usize(value.len()).unwrap();
+            values.extend_from_slice(value.as_bytes());
+        }
+
+        // Safety: the values are `&str` and thus utf8, and the offsets are sound
+        Ok(unsafe {
+            Utf8Array::new_unchecked(
+                Utf8Array::<O>::default_data_type(),
+                offsets.into(),
+                values.into(),
+                self.validity.and_then(|x| x.into()),
+            )
+        })
+    }
+}
+
+impl<'a, O: Offset> Extend<Option<&'a str>> for DeferredUtf8Builder<'a, O> {
+    fn extend<I: IntoIterator<Item = Option<&'a str>>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+        self.values.reserve(iter.size_hint().0);
+        iter.for_each(|value| self.push(value))
+    }
+}
+
+impl<'a, O: Offset> FromIterator<Option<&'a str>> for DeferredUtf8Builder<'a, O> {
+    fn from_iter<I: IntoIterator<Item = Option<&'a str>>>(iter: I) -> Self {
+        let mut builder = Self::new();
+        builder.extend(iter);
+        builder
+    }
+}
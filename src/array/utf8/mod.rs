@@ -1,3 +1,5 @@
+use alloc::{boxed::Box, vec::Vec};
+
 use crate::{
     bitmap::{
         utils::{BitmapIter, ZipValidity},
@@ -13,16 +15,21 @@ use crate::{
 use either::Either;
 
 use super::{
+    bitmap_to_bytes, buffer_to_bytes,
     specification::{try_check_offsets_bounds, try_check_utf8},
-    Array, GenericBinaryArray,
+    validation::{validate_offsets, validate_validity},
+    Array, ArrayValidation, GenericBinaryArray,
 };
 
+mod deferred;
+#[cfg(feature = "std")]
 mod ffi;
 pub(super) mod fmt;
 mod from;
 mod iterator;
 mod mutable;
 mod mutable_values;
+pub use deferred::DeferredUtf8Builder;
 pub use iterator::*;
 pub use mutable::*;
 pub use mutable_values::MutableUtf8ValuesArray;
@@ -132,12 +139,12 @@ impl<O: Offset> Utf8Array<O> {
     }
 
     /// Returns an iterator of `Option<&str>`
-    pub fn iter(&self) -> ZipValidity<&str, Utf8ValuesIter<O>, BitmapIter> {
+    pub fn iter(&self) -> ZipValidity<&str, Utf8ValuesIter<'_, O>, BitmapIter<'_>> {
         ZipValidity::new_with_validity(self.values_iter(), self.validity())
     }
 
     /// Returns an iterator of `&str`
-    pub fn values_iter(&self) -> Utf8ValuesIter<O> {
+    pub fn values_iter(&self) -> Utf8ValuesIter<'_, O> {
         Utf8ValuesIter::new(self)
     }
 
@@ -168,7 +175,7 @@ impl<O: Offset> Utf8Array<O> {
         let slice = self.values.get_unchecked(start..end);
 
         // soundness: the invariant of the struct
-        std::str::from_utf8_unchecked(slice)
+        core::str::from_utf8_unchecked(slice)
     }
 
     /// Returns the [`DataType`] of this array.
@@ -237,8 +244,8 @@ impl<O: Offset> Utf8Array<O> {
     }
 
     /// Boxes self into a [`std::sync::Arc<dyn Array>`].
-    pub fn arced(self) -> std::sync::Arc<dyn Array> {
-        std::sync::Arc::new(self)
+    pub fn arced(self) -> alloc::sync::Arc<dyn Array> {
+        alloc::sync::Arc::new(self)
     }
 
     /// Returns this [`Utf8Array`] with a new validity.
@@ -275,7 +282,7 @@ impl<O: Offset> Utf8Array<O> {
                     )
                 }),
                 Right(mutable_bitmap) => match (
-                    self.values.get_mut().map(std::mem::take),
+                    self.values.get_mut().map(core::mem::take),
                     self.offsets.get_mut(),
                 ) {
                     (None, None) => {
@@ -323,7 +330,7 @@ impl<O: Offset> Utf8Array<O> {
             }
         } else {
             match (
-                self.values.get_mut().map(std::mem::take),
+                self.values.get_mut().map(core::mem::take),
                 self.offsets.get_mut(),
             ) {
                 (None, None) => Left(unsafe {
@@ -453,6 +460,34 @@ impl<O: Offset> Utf8Array<O> {
         Self::try_new_unchecked(data_type, offsets, values, validity).unwrap()
     }
 
+    /// Creates a new [`Utf8Array`] whose invariants are checked at the level `validation`:
+    /// [`ArrayValidation::Full`] is equivalent to [`Utf8Array::try_new`],
+    /// [`ArrayValidation::Basic`] to [`Utf8Array::try_new_unchecked`] and
+    /// [`ArrayValidation::Off`] checks nothing.
+    /// # Errors
+    /// This function errors iff an invariant checked at the level `validation` does not hold.
+    /// # Safety
+    /// This function is unsound iff an invariant not checked at the level `validation` does
+    /// not hold.
+    pub unsafe fn try_new_with_validation(
+        data_type: DataType,
+        offsets: OffsetsBuffer<O>,
+        values: Buffer<u8>,
+        validity: Option<Bitmap>,
+        validation: ArrayValidation,
+    ) -> Result<Self> {
+        match validation {
+            ArrayValidation::Off => Ok(Self {
+                data_type,
+                offsets,
+                values,
+                validity,
+            }),
+            ArrayValidation::Basic => Self::try_new_unchecked(data_type, offsets, values, validity),
+            ArrayValidation::Full => Self::try_new(data_type, offsets, values, validity),
+        }
+    }
+
     /// Returns a (non-null) [`Utf8Array`] created from a [`TrustedLen`] of `&str`.
     /// # Implementation
     /// This function is `O(N)`
@@ -463,6 +498,16 @@ impl<O: Offset> Utf8Array<O> {
         MutableUtf8Array::<O>::from_trusted_len_values_iter(iterator).into()
     }
 
+    /// Creates a new [`Utf8Array`] from an iterator of optional `&str`.
+    /// # Errors
+    /// Errors with [`Error::Overflow`] iff the total length in bytes exceeds `O`'s maximum
+    /// value (`i32::MAX` or `i64::MAX` respectively).
+    pub fn try_from_iter<P: AsRef<str>, I: IntoIterator<Item = Option<P>>>(
+        iter: I,
+    ) -> Result<Self> {
+        MutableUtf8Array::<O>::try_from_iter(iter).map(|x| x.into())
+    }
+
     /// Creates a new [`Utf8Array`] from a [`Iterator`] of `&str`.
     pub fn from_iter_values<T: AsRef<str>, I: Iterator<Item = T>>(iterator: I) -> Self {
         MutableUtf8Array::<O>::from_iter_values(iterator).into()
@@ -482,6 +527,9 @@ impl<O: Offset> Utf8Array<O> {
     }
 
     /// Creates a [`Utf8Array`] from an iterator of trusted length.
+    /// # Panics
+    /// Panics iff the total length in bytes exceeds `O`'s maximum value; use
+    /// [`Self::try_from_iter`] to handle it as an error.
     #[inline]
     pub fn from_trusted_len_iter<I, P>(iterator: I) -> Self
     where
@@ -498,20 +546,20 @@ impl<O: Offset> Utf8Array<O> {
     #[inline]
     pub unsafe fn try_from_trusted_len_iter_unchecked<E, I, P>(
         iterator: I,
-    ) -> std::result::Result<Self, E>
+    ) -> core::result::Result<Self, E>
     where
         P: AsRef<str>,
-        I: IntoIterator<Item = std::result::Result<Option<P>, E>>,
+        I: IntoIterator<Item = core::result::Result<Option<P>, E>>,
     {
         MutableUtf8Array::<O>::try_from_trusted_len_iter_unchecked(iterator).map(|x| x.into())
     }
 
     /// Creates a [`Utf8Array`] from an fallible iterator of trusted length.
     #[inline]
-    pub fn try_from_trusted_len_iter<E, I, P>(iter: I) -> std::result::Result<Self, E>
+    pub fn try_from_trusted_len_iter<E, I, P>(iter: I) -> core::result::Result<Self, E>
     where
         P: AsRef<str>,
-        I: TrustedLen<Item = std::result::Result<Option<P>, E>>,
+        I: TrustedLen<Item = core::result::Result<Option<P>, E>>,
     {
         MutableUtf8Array::<O>::try_from_trusted_len_iter(iter).map(|x| x.into())
     }
@@ -522,7 +570,7 @@ impl<O: Offset> Utf8Array<O> {
     /// # Panics
     /// This function panics if the function `f` modifies the length of the [`Bitmap`].
     pub fn apply_validity<F: FnOnce(Bitmap) -> Bitmap>(&mut self, f: F) {
-        if let Some(validity) = std::mem::take(&mut self.validity) {
+        if let Some(validity) = core::mem::take(&mut self.validity) {
             self.set_validity(Some(f(validity)))
         }
     }
@@ -530,12 +578,12 @@ impl<O: Offset> Utf8Array<O> {
 
 impl<O: Offset> Array for Utf8Array<O> {
     #[inline]
-    fn as_any(&self) -> &dyn std::any::Any {
+    fn as_any(&self) -> &dyn core::any::Any {
         self
     }
 
     #[inline]
-    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+    fn as_any_mut(&mut self) -> &mut dyn core::any::Any {
         self
     }
 
@@ -566,6 +614,26 @@ impl<O: Offset> Array for Utf8Array<O> {
     fn to_boxed(&self) -> Box<dyn Array> {
         Box::new(self.clone())
     }
+
+    fn buffers(&self) -> Vec<Option<Buffer<u8>>> {
+        vec![
+            self.validity.as_ref().map(bitmap_to_bytes),
+            Some(buffer_to_bytes(self.offsets.buffer())),
+            Some(self.values.clone()),
+        ]
+    }
+
+    fn validate(&self, validation: ArrayValidation) -> Result<()> {
+        if validation == ArrayValidation::Off {
+            return Ok(());
+        }
+        validate_validity(self.validity(), self.len())?;
+        validate_offsets(self.offsets(), self.values().len(), validation)?;
+        if validation == ArrayValidation::Full {
+            try_check_utf8(self.offsets(), self.values())?;
+        }
+        Ok(())
+    }
 }
 
 unsafe impl<O: Offset> GenericBinaryArray<O> for Utf8Array<O> {
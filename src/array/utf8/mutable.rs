@@ -1,6 +1,7 @@
-use std::{iter::FromIterator, sync::Arc};
+use alloc::{boxed::Box, string::String, sync::Arc, vec::Vec};
+use core::iter::FromIterator;
 
-use crate::array::{physical_binary::*, TryExtendFromSelf};
+use crate::array::{downcast_extend, physical_binary::*, TryExtendFromSelf};
 use crate::{
     array::{Array, MutableArray, TryExtend, TryPush},
     bitmap::{
@@ -184,7 +185,7 @@ impl<O: Offset> MutableUtf8Array<O> {
     }
 
     /// Returns an iterator of `Option<&str>`
-    pub fn iter(&self) -> ZipValidity<&str, MutableUtf8ValuesIter<O>, BitmapIter> {
+    pub fn iter(&self) -> ZipValidity<&str, MutableUtf8ValuesIter<'_, O>, BitmapIter<'_>> {
         ZipValidity::new(self.values_iter(), self.validity.as_ref().map(|x| x.iter()))
     }
 
@@ -209,7 +210,7 @@ impl<O: Offset> MutableUtf8Array<O> {
     }
 
     /// Returns an iterator of `&str`
-    pub fn values_iter(&self) -> MutableUtf8ValuesIter<O> {
+    pub fn values_iter(&self) -> MutableUtf8ValuesIter<'_, O> {
         self.values.iter()
     }
 }
@@ -236,12 +237,12 @@ impl<O: Offset> MutableArray for MutableUtf8Array<O> {
     }
 
     fn as_box(&mut self) -> Box<dyn Array> {
-        let array: Utf8Array<O> = std::mem::take(self).into();
+        let array: Utf8Array<O> = core::mem::take(self).into();
         array.boxed()
     }
 
     fn as_arc(&mut self) -> Arc<dyn Array> {
-        let array: Utf8Array<O> = std::mem::take(self).into();
+        let array: Utf8Array<O> = core::mem::take(self).into();
         array.arced()
     }
 
@@ -253,11 +254,11 @@ impl<O: Offset> MutableArray for MutableUtf8Array<O> {
         }
     }
 
-    fn as_any(&self) -> &dyn std::any::Any {
+    fn as_any(&self) -> &dyn core::any::Any {
         self
     }
 
-    fn as_mut_any(&mut self) -> &mut dyn std::any::Any {
+    fn as_mut_any(&mut self) -> &mut dyn core::any::Any {
         self
     }
 
@@ -273,6 +274,15 @@ impl<O: Offset> MutableArray for MutableUtf8Array<O> {
     fn shrink_to_fit(&mut self) {
         self.shrink_to_fit()
     }
+
+    fn try_extend_from_array(&mut self, other: &dyn Array, start: usize, len: usize) -> Result<()> {
+        let other = downcast_extend::<Utf8Array<O>>(other, start, len)?;
+        let length = self.len();
+        self.values
+            .try_extend_values_from_array(other, start, len)?;
+        extend_validity_from_bitmap(length, &mut self.validity, other.validity(), start, len);
+        Ok(())
+    }
 }
 
 impl<O: Offset, P: AsRef<str>> FromIterator<Option<P>> for MutableUtf8Array<O> {
@@ -411,7 +421,9 @@ impl<O: Offset> MutableUtf8Array<O> {
     /// # Error
     /// This operation errors iff the total length in bytes on the iterator exceeds `O`'s maximum value.
     /// (`i32::MAX` or `i64::MAX` respectively).
-    fn try_from_iter<P: AsRef<str>, I: IntoIterator<Item = Option<P>>>(iter: I) -> Result<Self> {
+    pub fn try_from_iter<P: AsRef<str>, I: IntoIterator<Item = Option<P>>>(
+        iter: I,
+    ) -> Result<Self> {
         let iterator = iter.into_iter();
         let (lower, _) = iterator.size_hint();
         let mut array = Self::with_capacity(lower);
@@ -428,10 +440,10 @@ impl<O: Offset> MutableUtf8Array<O> {
     #[inline]
     pub unsafe fn try_from_trusted_len_iter_unchecked<E, I, P>(
         iterator: I,
-    ) -> std::result::Result<Self, E>
+    ) -> core::result::Result<Self, E>
     where
         P: AsRef<str>,
-        I: IntoIterator<Item = std::result::Result<Option<P>, E>>,
+        I: IntoIterator<Item = core::result::Result<Option<P>, E>>,
     {
         let iterator = iterator.into_iter();
 
@@ -449,10 +461,10 @@ impl<O: Offset> MutableUtf8Array<O> {
 
     /// Creates a [`MutableUtf8Array`] from an falible iterator of trusted length.
     #[inline]
-    pub fn try_from_trusted_len_iter<E, I, P>(iterator: I) -> std::result::Result<Self, E>
+    pub fn try_from_trusted_len_iter<E, I, P>(iterator: I) -> core::result::Result<Self, E>
     where
         P: AsRef<str>,
-        I: TrustedLen<Item = std::result::Result<Option<P>, E>>,
+        I: TrustedLen<Item = core::result::Result<Option<P>, E>>,
     {
         // soundness: I: TrustedLen
         unsafe { Self::try_from_trusted_len_iter_unchecked(iterator) }
@@ -1,7 +1,9 @@
-use std::{iter::FromIterator, sync::Arc};
+use alloc::{boxed::Box, string::String, sync::Arc, vec::Vec};
+use core::iter::FromIterator;
 
 use crate::{
     array::{
+        downcast_extend,
         specification::{try_check_offsets_bounds, try_check_utf8},
         Array, ArrayValuesIter, MutableArray, TryExtend, TryExtendFromSelf, TryPush,
     },
@@ -209,11 +211,11 @@ impl<O: Offset> MutableUtf8ValuesArray<O> {
         let slice = self.values.get_unchecked(start..end);
 
         // soundness: the invariant of the struct
-        std::str::from_utf8_unchecked(slice)
+        core::str::from_utf8_unchecked(slice)
     }
 
     /// Returns an iterator of `&str`
-    pub fn iter(&self) -> ArrayValuesIter<Self> {
+    pub fn iter(&self) -> ArrayValuesIter<'_, Self> {
         ArrayValuesIter::new(self)
     }
 
@@ -239,12 +241,12 @@ impl<O: Offset> MutableArray for MutableUtf8ValuesArray<O> {
     }
 
     fn as_box(&mut self) -> Box<dyn Array> {
-        let array: Utf8Array<O> = std::mem::take(self).into();
+        let array: Utf8Array<O> = core::mem::take(self).into();
         array.boxed()
     }
 
     fn as_arc(&mut self) -> Arc<dyn Array> {
-        let array: Utf8Array<O> = std::mem::take(self).into();
+        let array: Utf8Array<O> = core::mem::take(self).into();
         array.arced()
     }
 
@@ -252,11 +254,11 @@ impl<O: Offset> MutableArray for MutableUtf8ValuesArray<O> {
         &self.data_type
     }
 
-    fn as_any(&self) -> &dyn std::any::Any {
+    fn as_any(&self) -> &dyn core::any::Any {
         self
     }
 
-    fn as_mut_any(&mut self) -> &mut dyn std::any::Any {
+    fn as_mut_any(&mut self) -> &mut dyn core::any::Any {
         self
     }
 
@@ -272,6 +274,11 @@ impl<O: Offset> MutableArray for MutableUtf8ValuesArray<O> {
     fn shrink_to_fit(&mut self) {
         self.shrink_to_fit()
     }
+
+    fn try_extend_from_array(&mut self, other: &dyn Array, start: usize, len: usize) -> Result<()> {
+        let other = downcast_extend::<Utf8Array<O>>(other, start, len)?;
+        self.try_extend_values_from_array(other, start, len)
+    }
 }
 
 impl<O: Offset, P: AsRef<str>> FromIterator<P> for MutableUtf8ValuesArray<O> {
@@ -388,6 +395,30 @@ impl<O: Offset, T: AsRef<str>> TryPush<T> for MutableUtf8ValuesArray<O> {
     }
 }
 
+impl<O: Offset> MutableUtf8ValuesArray<O> {
+    /// Extends itself with the values of `other` in `start..start + len`, ignoring its
+    /// validity.
+    /// # Errors
+    /// Errors iff the offsets overflow
+    pub(crate) fn try_extend_values_from_array(
+        &mut self,
+        other: &Utf8Array<O>,
+        start: usize,
+        len: usize,
+    ) -> Result<()> {
+        if len == 0 {
+            return Ok(());
+        }
+        let offsets = other.offsets();
+        let values_start = offsets.buffer()[start].to_usize();
+        let values_end = offsets.buffer()[start + len].to_usize();
+        self.offsets.try_extend_from_slice(offsets, start, len)?;
+        self.values
+            .extend_from_slice(&other.values()[values_start..values_end]);
+        Ok(())
+    }
+}
+
 impl<O: Offset> TryExtendFromSelf for MutableUtf8ValuesArray<O> {
     fn try_extend_from_self(&mut self, other: &Self) -> Result<()> {
         self.values.extend_from_slice(&other.values);
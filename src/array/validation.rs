@@ -0,0 +1,75 @@
+use crate::bitmap::Bitmap;
+use crate::error::{Error, Result};
+use crate::offset::{Offset, OffsetsBuffer};
+
+use super::specification::try_check_offsets_bounds;
+
+/// The level of validation of the invariants of an [`Array`](super::Array),
+/// see [`Array::validate`](super::Array::validate).
+///
+/// Arrays created by `try_new` satisfy the [`ArrayValidation::Full`] invariants of their own
+/// buffers. Arrays whose `O(N)` invariants are costly to check ([`Utf8Array`](super::Utf8Array),
+/// [`DictionaryArray`](super::DictionaryArray) and [`UnionArray`](super::UnionArray)) can instead be
+/// created via `try_new_with_validation`, which checks them at a given level.
+///
+/// Arrays created from untrusted buffers (e.g. via FFI, `mmap` or read from the network)
+/// may, however, violate invariants that the `unsafe` constructors used by these readers rely on
+/// the producer to uphold, which [`ArrayValidation::Full`] checks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ArrayValidation {
+    /// Nothing is checked
+    Off,
+    /// Checks the lengths of the buffers and children, the bounds of the last offset and that
+    /// the values of fixed size arrays are a multiple of their size, which is `O(1)` per array
+    #[default]
+    Basic,
+    /// Checks the lengths of the buffers and children and every value that other values depend
+    /// on, which is `O(N)`:
+    /// * offsets are non-negative and monotonically increasing
+    /// * utf8 values are valid utf8
+    /// * dictionary keys are within the bounds of the dictionary's values
+    /// * union types are declared by the union's data type, and union offsets are within the
+    ///   bounds of the union's fields
+    Full,
+}
+
+/// Checks that `validity`, if any, has length `len`
+pub(crate) fn validate_validity(validity: Option<&Bitmap>, len: usize) -> Result<()> {
+    match validity {
+        Some(validity) if validity.len() != len => Err(Error::oos(format!(
+            "The validity's length ({}) must equal the array's length ({len})",
+            validity.len()
+        ))),
+        _ => Ok(()),
+    }
+}
+
+/// Checks that `offsets` are within `values_len`, and, with [`ArrayValidation::Full`],
+/// that they are non-negative and monotonically increasing.
+pub(crate) fn validate_offsets<O: Offset>(
+    offsets: &OffsetsBuffer<O>,
+    values_len: usize,
+    validation: ArrayValidation,
+) -> Result<()> {
+    if validation == ArrayValidation::Full {
+        let offsets = offsets.buffer();
+        if offsets[0] < O::default() {
+            return Err(Error::oos("The first offset must be non-negative"));
+        }
+        if offsets.windows(2).any(|window| window[0] > window[1]) {
+            return Err(Error::oos("The offsets must be monotonically increasing"));
+        }
+    }
+    try_check_offsets_bounds(offsets, values_len)
+}
+
+/// Checks that `values_len` is a multiple of the `size` of a fixed size array
+pub(crate) fn validate_fixed_size(values_len: usize, size: usize) -> Result<()> {
+    if values_len.checked_rem(size) != Some(0) {
+        Err(Error::oos(format!(
+            "The values' length ({values_len}) of a fixed size array must be a multiple of its size ({size})"
+        )))
+    } else {
+        Ok(())
+    }
+}
@@ -34,7 +34,7 @@ pub fn unary_assign<T: BitChunk, F: Fn(T) -> T>(bitmap: &mut MutableBitmap, op:
         .copy_from_slice(&new_remainder.as_ref()[..len]);
 }
 
-impl std::ops::Not for MutableBitmap {
+impl core::ops::Not for MutableBitmap {
     type Output = Self;
 
     #[inline]
@@ -118,14 +118,14 @@ fn or_assign<T: BitChunk>(lhs: &mut MutableBitmap, rhs: &Bitmap) {
     }
 }
 
-impl<'a> std::ops::BitOrAssign<&'a Bitmap> for &mut MutableBitmap {
+impl<'a> core::ops::BitOrAssign<&'a Bitmap> for &mut MutableBitmap {
     #[inline]
     fn bitor_assign(&mut self, rhs: &'a Bitmap) {
         or_assign::<u64>(self, rhs)
     }
 }
 
-impl<'a> std::ops::BitOr<&'a Bitmap> for MutableBitmap {
+impl<'a> core::ops::BitOr<&'a Bitmap> for MutableBitmap {
     type Output = Self;
 
     #[inline]
@@ -150,14 +150,14 @@ fn and_assign<T: BitChunk>(lhs: &mut MutableBitmap, rhs: &Bitmap) {
     }
 }
 
-impl<'a> std::ops::BitAndAssign<&'a Bitmap> for &mut MutableBitmap {
+impl<'a> core::ops::BitAndAssign<&'a Bitmap> for &mut MutableBitmap {
     #[inline]
     fn bitand_assign(&mut self, rhs: &'a Bitmap) {
         and_assign::<u64>(self, rhs)
     }
 }
 
-impl<'a> std::ops::BitAnd<&'a Bitmap> for MutableBitmap {
+impl<'a> core::ops::BitAnd<&'a Bitmap> for MutableBitmap {
     type Output = Self;
 
     #[inline]
@@ -173,14 +173,14 @@ fn xor_assign<T: BitChunk>(lhs: &mut MutableBitmap, rhs: &Bitmap) {
     binary_assign(lhs, rhs, |x: T, y| x ^ y)
 }
 
-impl<'a> std::ops::BitXorAssign<&'a Bitmap> for &mut MutableBitmap {
+impl<'a> core::ops::BitXorAssign<&'a Bitmap> for &mut MutableBitmap {
     #[inline]
     fn bitxor_assign(&mut self, rhs: &'a Bitmap) {
         xor_assign::<u64>(self, rhs)
     }
 }
 
-impl<'a> std::ops::BitXor<&'a Bitmap> for MutableBitmap {
+impl<'a> core::ops::BitXor<&'a Bitmap> for MutableBitmap {
     type Output = Self;
 
     #[inline]
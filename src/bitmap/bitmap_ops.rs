@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
+
 use crate::bitmap::MutableBitmap;
-use std::ops::{BitAnd, BitOr, BitXor, Not};
+use core::ops::{BitAnd, BitOr, BitXor, Not};
 
 use crate::trusted_len::TrustedLen;
 
@@ -16,15 +18,15 @@ pub unsafe fn from_chunk_iter_unchecked<T: BitChunk, I: Iterator<Item = T>>(
 ) -> Vec<u8> {
     let (_, upper) = iterator.size_hint();
     let upper = upper.expect("try_from_trusted_len_iter requires an upper limit");
-    let len = upper * std::mem::size_of::<T>();
+    let len = upper * core::mem::size_of::<T>();
 
     let mut buffer = Vec::with_capacity(len);
 
     let mut dst = buffer.as_mut_ptr();
     for item in iterator {
         let bytes = item.to_ne_bytes();
-        for i in 0..std::mem::size_of::<T>() {
-            std::ptr::write(dst, bytes[i]);
+        for i in 0..core::mem::size_of::<T>() {
+            core::ptr::write(dst, bytes[i]);
             dst = dst.add(1);
         }
     }
@@ -66,7 +68,7 @@ where
         .zip(a4_chunks)
         .map(|(((a1, a2), a3), a4)| op(a1, a2, a3, a4));
     let buffer =
-        chunk_iter_to_vec(chunks.chain(std::iter::once(op(rem_a1, rem_a2, rem_a3, rem_a4))));
+        chunk_iter_to_vec(chunks.chain(core::iter::once(op(rem_a1, rem_a2, rem_a3, rem_a4))));
 
     let length = a1.len();
 
@@ -93,7 +95,7 @@ where
         .zip(a3_chunks)
         .map(|((a1, a2), a3)| op(a1, a2, a3));
 
-    let buffer = chunk_iter_to_vec(chunks.chain(std::iter::once(op(rem_a1, rem_a2, rem_a3))));
+    let buffer = chunk_iter_to_vec(chunks.chain(core::iter::once(op(rem_a1, rem_a2, rem_a3))));
 
     let length = a1.len();
 
@@ -115,7 +117,7 @@ where
         .zip(rhs_chunks)
         .map(|(left, right)| op(left, right));
 
-    let buffer = chunk_iter_to_vec(chunks.chain(std::iter::once(op(rem_lhs, rem_rhs))));
+    let buffer = chunk_iter_to_vec(chunks.chain(core::iter::once(op(rem_lhs, rem_rhs))));
 
     let length = lhs.len();
 
@@ -129,7 +131,7 @@ where
 {
     let rem = op(iter.remainder());
 
-    let iterator = iter.map(op).chain(std::iter::once(rem));
+    let iterator = iter.map(op).chain(core::iter::once(rem));
 
     let buffer = chunk_iter_to_vec(iterator);
 
@@ -152,10 +154,11 @@ where
 }
 
 // create a new [`Bitmap`] semantically equal to ``bitmap`` but with an offset equal to ``offset``
+#[cfg(feature = "std")]
 pub(crate) fn align(bitmap: &Bitmap, new_offset: usize) -> Bitmap {
     let length = bitmap.len();
 
-    let bitmap: Bitmap = std::iter::repeat(false)
+    let bitmap: Bitmap = core::iter::repeat(false)
         .take(new_offset)
         .chain(bitmap.iter())
         .collect();
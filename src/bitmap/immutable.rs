@@ -1,4 +1,7 @@
-use std::{iter::FromIterator, ops::Deref, sync::Arc};
+use alloc::{sync::Arc, vec::Vec};
+use core::iter::FromIterator;
+#[cfg(feature = "std")]
+use core::ops::Deref;
 
 use either::Either;
 
@@ -51,8 +54,8 @@ pub struct Bitmap {
     unset_bits: usize,
 }
 
-impl std::fmt::Debug for Bitmap {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl core::fmt::Debug for Bitmap {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         let (bytes, offset, len) = self.as_slice();
         fmt(bytes, offset, len, f)
     }
@@ -92,6 +95,46 @@ impl Bitmap {
         })
     }
 
+    /// Initializes a new [`Bitmap`] from `length` bits of packed bytes starting at bit `offset`,
+    /// where the first bit is the least significant bit of the first byte.
+    ///
+    /// This does not copy `bytes`.
+    /// # Errors
+    /// This function errors iff `offset + length > bytes.len() * 8`
+    /// # Example
+    /// ```
+    /// use arrow2::bitmap::Bitmap;
+    ///
+    /// let bitmap = Bitmap::from_packed(vec![0b00011010], 3, 1).unwrap();
+    /// assert_eq!(bitmap, Bitmap::from([true, false, true]));
+    /// ```
+    pub fn from_packed(bytes: Vec<u8>, length: usize, offset: usize) -> Result<Self, Error> {
+        let end = offset.checked_add(length).ok_or(Error::Overflow)?;
+        let bitmap = Self::try_new(bytes, end)?;
+        // Safety: `offset + length` is the length of `bitmap`
+        Ok(unsafe { bitmap.slice_unchecked(offset, length) })
+    }
+
+    /// Returns the packed bytes, offset (in bits) and length (in bits) of this [`Bitmap`].
+    ///
+    /// The bytes are returned without copying when this [`Bitmap`] is their only owner and
+    /// was not imported from the C data interface. Otherwise, the bytes spanned by this
+    /// [`Bitmap`] are copied, and the offset is smaller than 8.
+    /// # Example
+    /// ```
+    /// use arrow2::bitmap::Bitmap;
+    ///
+    /// let bitmap = Bitmap::from_packed(vec![0b00011010, 0b1], 3, 1).unwrap();
+    /// assert_eq!(bitmap.into_packed(), (vec![0b00011010, 0b1], 1, 3));
+    /// ```
+    pub fn into_packed(mut self) -> (Vec<u8>, usize, usize) {
+        if let Some(bytes) = Arc::get_mut(&mut self.bytes).and_then(|b| b.get_vec()) {
+            return (core::mem::take(bytes), self.offset, self.length);
+        }
+        let (bytes, offset, length) = self.as_slice();
+        (bytes.to_vec(), offset, length)
+    }
+
     /// Returns the length of the [`Bitmap`].
     #[inline]
     pub fn len(&self) -> usize {
@@ -119,6 +162,7 @@ impl Bitmap {
     /// Creates a new [`Bitmap`] from [`Bytes`] and a length.
     /// # Panic
     /// Panics iff `length <= bytes.len() * 8`
+    #[cfg(feature = "std")]
     #[inline]
     pub(crate) fn from_bytes(bytes: Bytes<u8>, length: usize) -> Self {
         assert!(length <= bytes.len() * 8);
@@ -182,22 +226,47 @@ impl Bitmap {
     /// The caller must ensure that `self.offset + offset + length <= self.len()`
     #[inline]
     pub unsafe fn slice_unchecked(mut self, offset: usize, length: usize) -> Self {
-        // count the smallest chunk
-        if length < self.length / 2 {
-            // count the null values in the slice
-            self.unset_bits = count_zeros(&self.bytes, self.offset + offset, length);
-        } else {
-            // subtract the null count of the chunks we slice off
-            let start_end = self.offset + offset + length;
-            let head_count = count_zeros(&self.bytes, self.offset, offset);
-            let tail_count = count_zeros(&self.bytes, start_end, self.length - length - offset);
-            self.unset_bits -= head_count + tail_count;
+        // bitmaps whose bits are all set or all unset are sliced without counting
+        if self.unset_bits == self.length {
+            self.unset_bits = length;
+        } else if self.unset_bits > 0 {
+            // count the smallest chunk
+            if length < self.length / 2 {
+                // count the null values in the slice
+                self.unset_bits = count_zeros(&self.bytes, self.offset + offset, length);
+            } else {
+                // subtract the null count of the chunks we slice off
+                let start_end = self.offset + offset + length;
+                let head_count = count_zeros(&self.bytes, self.offset, offset);
+                let tail_count = count_zeros(&self.bytes, start_end, self.length - length - offset);
+                self.unset_bits -= head_count + tail_count;
+            }
         }
         self.offset += offset;
         self.length = length;
         self
     }
 
+    /// Returns a slice of `self`, offseting by `offset` and truncating up to `length` bits,
+    /// sharing its bytes with `self`.
+    /// # Panic
+    /// Panics iff `offset + length > self.len()`.
+    #[inline]
+    #[must_use]
+    pub fn sliced(&self, offset: usize, length: usize) -> Self {
+        self.clone().slice(offset, length)
+    }
+
+    /// Returns a slice of `self`, offseting by `offset` and truncating up to `length` bits,
+    /// sharing its bytes with `self`.
+    /// # Safety
+    /// The caller must ensure that `offset + length <= self.len()`
+    #[inline]
+    #[must_use]
+    pub unsafe fn sliced_unchecked(&self, offset: usize, length: usize) -> Self {
+        self.clone().slice_unchecked(offset, length)
+    }
+
     /// Returns whether the bit at position `i` is set.
     /// # Panics
     /// Panics iff `i >= self.len()`.
@@ -216,12 +285,14 @@ impl Bitmap {
 
     /// Returns a pointer to the start of this [`Bitmap`] (ignores `offsets`)
     /// This pointer is allocated iff `self.len() > 0`.
+    #[cfg(feature = "std")]
     pub(crate) fn as_ptr(&self) -> *const u8 {
         self.bytes.deref().as_ptr()
     }
 
     /// Returns a pointer to the start of this [`Bitmap`] (ignores `offsets`)
     /// This pointer is allocated iff `self.len() > 0`.
+    #[cfg(feature = "std")]
     pub(crate) fn offset(&self) -> usize {
         self.offset
     }
@@ -239,7 +310,7 @@ impl Bitmap {
             Arc::get_mut(&mut self.bytes).and_then(|b| b.get_vec()),
         ) {
             (0, Some(v)) => {
-                let data = std::mem::take(v);
+                let data = core::mem::take(v);
                 Either::Right(MutableBitmap::from_vec(data, self.length))
             }
             _ => Either::Left(self),
@@ -255,7 +326,7 @@ impl Bitmap {
                     // re-align the bits (remove the offset)
                     let chunks = data.chunks::<u64>();
                     let remainder = chunks.remainder();
-                    let vec = chunk_iter_to_vec(chunks.chain(std::iter::once(remainder)));
+                    let vec = chunk_iter_to_vec(chunks.chain(core::iter::once(remainder)));
                     MutableBitmap::from_vec(vec, data.length)
                 } else {
                     MutableBitmap::from_vec(data.bytes.as_ref().to_vec(), data.length)
@@ -337,9 +408,9 @@ impl Bitmap {
 
     /// Creates a new [`Bitmap`] from a fallible iterator of booleans.
     #[inline]
-    pub fn try_from_trusted_len_iter<E, I: TrustedLen<Item = std::result::Result<bool, E>>>(
+    pub fn try_from_trusted_len_iter<E, I: TrustedLen<Item = core::result::Result<bool, E>>>(
         iterator: I,
-    ) -> std::result::Result<Self, E> {
+    ) -> core::result::Result<Self, E> {
         Ok(MutableBitmap::try_from_trusted_len_iter(iterator)?.into())
     }
 
@@ -349,10 +420,10 @@ impl Bitmap {
     #[inline]
     pub unsafe fn try_from_trusted_len_iter_unchecked<
         E,
-        I: Iterator<Item = std::result::Result<bool, E>>,
+        I: Iterator<Item = core::result::Result<bool, E>>,
     >(
         iterator: I,
-    ) -> std::result::Result<Self, E> {
+    ) -> core::result::Result<Self, E> {
         Ok(MutableBitmap::try_from_trusted_len_iter_unchecked(iterator)?.into())
     }
 }
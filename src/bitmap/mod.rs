@@ -15,3 +15,6 @@ mod assign_ops;
 pub use assign_ops::*;
 
 pub mod utils;
+
+#[cfg(feature = "roaring")]
+mod roaring;
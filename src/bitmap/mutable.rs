@@ -1,5 +1,6 @@
-use std::hint::unreachable_unchecked;
-use std::iter::FromIterator;
+use alloc::vec::Vec;
+use core::hint::unreachable_unchecked;
+use core::iter::FromIterator;
 
 use crate::bitmap::utils::{merge_reversed, set_bit_unchecked};
 use crate::error::Error;
@@ -50,8 +51,8 @@ pub struct MutableBitmap {
     length: usize,
 }
 
-impl std::fmt::Debug for MutableBitmap {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl core::fmt::Debug for MutableBitmap {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         fmt(&self.buffer, 0, self.len(), f)
     }
 }
@@ -269,7 +270,7 @@ impl MutableBitmap {
             let remaining = remaining >> 8usize.saturating_sub(additional);
             let remaining = remaining << offset;
             *last |= remaining;
-            std::cmp::min(additional, 8 - offset)
+            core::cmp::min(additional, 8 - offset)
         } else {
             0
         };
@@ -281,7 +282,7 @@ impl MutableBitmap {
             let required = (self.length + additional).saturating_add(7) / 8;
             // add remaining as full bytes
             self.buffer
-                .extend(std::iter::repeat(0b11111111u8).take(required - existing));
+                .extend(core::iter::repeat(0b11111111u8).take(required - existing));
             self.length += additional;
         }
     }
@@ -293,7 +294,7 @@ impl MutableBitmap {
             let last_index = self.buffer.len() - 1;
             let last = &mut self.buffer[last_index];
             *last &= 0b11111111u8 >> (8 - offset); // unset them
-            std::cmp::min(additional, 8 - offset)
+            core::cmp::min(additional, 8 - offset)
         } else {
             0
         };
@@ -578,9 +579,9 @@ impl MutableBitmap {
     }
 
     /// Creates a new [`MutableBitmap`] from an iterator of booleans.
-    pub fn try_from_trusted_len_iter<E, I>(iterator: I) -> std::result::Result<Self, E>
+    pub fn try_from_trusted_len_iter<E, I>(iterator: I) -> core::result::Result<Self, E>
     where
-        I: TrustedLen<Item = std::result::Result<bool, E>>,
+        I: TrustedLen<Item = core::result::Result<bool, E>>,
     {
         unsafe { Self::try_from_trusted_len_iter_unchecked(iterator) }
     }
@@ -590,9 +591,9 @@ impl MutableBitmap {
     /// The caller must guarantee that the iterator is `TrustedLen`.
     pub unsafe fn try_from_trusted_len_iter_unchecked<E, I>(
         mut iterator: I,
-    ) -> std::result::Result<Self, E>
+    ) -> core::result::Result<Self, E>
     where
-        I: Iterator<Item = std::result::Result<bool, E>>,
+        I: Iterator<Item = core::result::Result<bool, E>>,
     {
         let length = iterator.size_hint().1.unwrap();
 
@@ -652,7 +653,7 @@ impl MutableBitmap {
         let remaining = [items[items.len() - 1], 0];
         let bytes = items
             .windows(2)
-            .chain(std::iter::once(remaining.as_ref()))
+            .chain(core::iter::once(remaining.as_ref()))
             .map(|w| merge_reversed(w[0], w[1], 8 - own_offset))
             .take(additional.saturating_add(7) / 8);
         self.buffer.extend(bytes);
@@ -0,0 +1,59 @@
+//! Conversions between [`Bitmap`] and [`RoaringBitmap`].
+use roaring::RoaringBitmap;
+
+use crate::error::{Error, Result};
+
+use super::utils::SlicesIterator;
+use super::Bitmap;
+
+#[cfg_attr(docsrs, doc(cfg(feature = "roaring")))]
+impl Bitmap {
+    /// Returns a [`RoaringBitmap`] with the positions of the set bits of this [`Bitmap`].
+    /// # Errors
+    /// This function errors iff a set bit is at a position that does not fit in a `u32`.
+    /// # Example
+    /// ```
+    /// use arrow2::bitmap::Bitmap;
+    ///
+    /// let bitmap = Bitmap::from([true, false, true, true]);
+    /// let roaring = bitmap.to_roaring().unwrap();
+    /// assert_eq!(roaring.iter().collect::<Vec<_>>(), vec![0, 2, 3]);
+    /// ```
+    pub fn to_roaring(&self) -> Result<RoaringBitmap> {
+        let mut roaring = RoaringBitmap::new();
+        for (start, length) in SlicesIterator::new(self) {
+            let start = u32::try_from(start).map_err(|_| Error::Overflow)?;
+            let end = u32::try_from(start as usize + length - 1).map_err(|_| Error::Overflow)?;
+            roaring.insert_range(start..=end);
+        }
+        Ok(roaring)
+    }
+
+    /// Returns a [`Bitmap`] of `length` bits whose set bits are the positions in `roaring`.
+    /// # Errors
+    /// This function errors iff a position in `roaring` is not smaller than `length`.
+    /// # Example
+    /// ```
+    /// use arrow2::bitmap::Bitmap;
+    /// use roaring::RoaringBitmap;
+    ///
+    /// let roaring = RoaringBitmap::from_iter([0, 2, 3]);
+    /// let bitmap = Bitmap::from_roaring(&roaring, 5).unwrap();
+    /// assert_eq!(bitmap, Bitmap::from([true, false, true, true, false]));
+    /// ```
+    pub fn from_roaring(roaring: &RoaringBitmap, length: usize) -> Result<Self> {
+        if let Some(max) = roaring.max() {
+            if max as usize >= length {
+                return Err(Error::InvalidArgumentError(format!(
+                    "The position {max} of the roaring bitmap is out of bounds of a bitmap of length {length}"
+                )));
+            }
+        }
+        let mut bytes = vec![0u8; length.saturating_add(7) / 8];
+        roaring.iter().for_each(|position| {
+            let position = position as usize;
+            bytes[position / 8] |= 1 << (position % 8);
+        });
+        Bitmap::try_new(bytes, length)
+    }
+}
@@ -1,4 +1,4 @@
-use std::{convert::TryInto, slice::ChunksExact};
+use core::{convert::TryInto, slice::ChunksExact};
 
 use crate::trusted_len::TrustedLen;
 
@@ -10,7 +10,7 @@ pub struct BitChunksExact<'a, T: BitChunk> {
     iter: ChunksExact<'a, u8>,
     remainder: &'a [u8],
     remainder_len: usize,
-    phantom: std::marker::PhantomData<T>,
+    phantom: core::marker::PhantomData<T>,
 }
 
 impl<'a, T: BitChunk> BitChunksExact<'a, T> {
@@ -18,7 +18,7 @@ impl<'a, T: BitChunk> BitChunksExact<'a, T> {
     #[inline]
     pub fn new(bitmap: &'a [u8], length: usize) -> Self {
         assert!(length <= bitmap.len() * 8);
-        let size_of = std::mem::size_of::<T>();
+        let size_of = core::mem::size_of::<T>();
 
         let bitmap = &bitmap[..length.saturating_add(7) / 8];
 
@@ -31,7 +31,7 @@ impl<'a, T: BitChunk> BitChunksExact<'a, T> {
             iter,
             remainder,
             remainder_len,
-            phantom: std::marker::PhantomData,
+            phantom: core::marker::PhantomData,
         }
     }
 
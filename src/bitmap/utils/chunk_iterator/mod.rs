@@ -1,4 +1,4 @@
-use std::convert::TryInto;
+use core::convert::TryInto;
 
 mod chunks_exact;
 mod merge;
@@ -28,7 +28,7 @@ pub trait BitChunkIterExact<B: BitChunk>: TrustedLen<Item = B> {
 /// the stack with alignments of `uX`. This allows efficient iteration over bitmaps.
 #[derive(Debug)]
 pub struct BitChunks<'a, T: BitChunk> {
-    chunk_iterator: std::slice::ChunksExact<'a, u8>,
+    chunk_iterator: core::slice::ChunksExact<'a, u8>,
     current: T,
     remainder_bytes: &'a [u8],
     last_chunk: T,
@@ -36,7 +36,7 @@ pub struct BitChunks<'a, T: BitChunk> {
     /// offset inside a byte
     bit_offset: usize,
     len: usize,
-    phantom: std::marker::PhantomData<T>,
+    phantom: core::marker::PhantomData<T>,
 }
 
 /// writes `bytes` into `dst`.
@@ -44,8 +44,8 @@ pub struct BitChunks<'a, T: BitChunk> {
 fn copy_with_merge<T: BitChunk>(dst: &mut T::Bytes, bytes: &[u8], bit_offset: usize) {
     bytes
         .windows(2)
-        .chain(std::iter::once([bytes[bytes.len() - 1], 0].as_ref()))
-        .take(std::mem::size_of::<T>())
+        .chain(core::iter::once([bytes[bytes.len() - 1], 0].as_ref()))
+        .take(core::mem::size_of::<T>())
         .enumerate()
         .for_each(|(i, w)| {
             let val = merge_reversed(w[0], w[1], bit_offset);
@@ -60,7 +60,7 @@ impl<'a, T: BitChunk> BitChunks<'a, T> {
 
         let slice = &slice[offset / 8..];
         let bit_offset = offset % 8;
-        let size_of = std::mem::size_of::<T>();
+        let size_of = core::mem::size_of::<T>();
 
         let bytes_len = len / 8;
         let bytes_upper_len = (len + bit_offset + 7) / 8;
@@ -97,7 +97,7 @@ impl<'a, T: BitChunk> BitChunks<'a, T> {
             remainder_bytes,
             last_chunk,
             bit_offset,
-            phantom: std::marker::PhantomData,
+            phantom: core::marker::PhantomData,
         }
     }
 
@@ -121,7 +121,7 @@ impl<'a, T: BitChunk> BitChunks<'a, T> {
                 // all remaining bytes
                 self.remainder_bytes
                     .iter()
-                    .take(std::mem::size_of::<T>())
+                    .take(core::mem::size_of::<T>())
                     .enumerate()
                     .for_each(|(i, val)| remainder[i] = *val);
 
@@ -138,7 +138,7 @@ impl<'a, T: BitChunk> BitChunks<'a, T> {
 
     /// Returns the remainder bits in [`BitChunks::remainder`].
     pub fn remainder_len(&self) -> usize {
-        self.len - (std::mem::size_of::<T>() * ((self.len / 8) / std::mem::size_of::<T>()) * 8)
+        self.len - (core::mem::size_of::<T>() * ((self.len / 8) / core::mem::size_of::<T>()) * 8)
     }
 }
 
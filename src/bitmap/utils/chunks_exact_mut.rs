@@ -7,10 +7,10 @@ use super::BitChunk;
 /// `std::mem::size_of::<T>()`.
 #[derive(Debug)]
 pub struct BitChunksExactMut<'a, T: BitChunk> {
-    chunks: std::slice::ChunksExactMut<'a, u8>,
+    chunks: core::slice::ChunksExactMut<'a, u8>,
     remainder: &'a mut [u8],
     remainder_len: usize,
-    marker: std::marker::PhantomData<T>,
+    marker: core::marker::PhantomData<T>,
 }
 
 impl<'a, T: BitChunk> BitChunksExactMut<'a, T> {
@@ -18,7 +18,7 @@ impl<'a, T: BitChunk> BitChunksExactMut<'a, T> {
     #[inline]
     pub fn new(bitmap: &'a mut [u8], length: usize) -> Self {
         assert!(length <= bitmap.len() * 8);
-        let size_of = std::mem::size_of::<T>();
+        let size_of = core::mem::size_of::<T>();
 
         let bitmap = &mut bitmap[..length.saturating_add(7) / 8];
 
@@ -31,7 +31,7 @@ impl<'a, T: BitChunk> BitChunksExactMut<'a, T> {
             chunks,
             remainder,
             remainder_len,
-            marker: std::marker::PhantomData,
+            marker: core::marker::PhantomData,
         }
     }
 
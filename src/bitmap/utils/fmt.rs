@@ -1,4 +1,4 @@
-use std::fmt::Write;
+use core::fmt::Write;
 
 use super::is_set;
 
@@ -7,8 +7,8 @@ pub fn fmt(
     bytes: &[u8],
     offset: usize,
     length: usize,
-    f: &mut std::fmt::Formatter<'_>,
-) -> std::fmt::Result {
+    f: &mut core::fmt::Formatter<'_>,
+) -> core::fmt::Result {
     assert!(offset < 8);
 
     f.write_char('[')?;
@@ -25,7 +25,7 @@ pub fn fmt(
     for _ in 0..empty_before {
         f.write_char('_')?;
     }
-    let until = std::cmp::min(8, offset + remaining);
+    let until = core::cmp::min(8, offset + remaining);
     for i in offset..until {
         if is_set(first, offset + until - 1 - i) {
             f.write_char('1')?;
@@ -54,7 +54,7 @@ pub fn fmt(
         return Ok(());
     }
 
-    let last = bytes[std::cmp::min((length + offset + 7) / 8, bytes.len() - 1)];
+    let last = bytes[core::cmp::min((length + offset + 7) / 8, bytes.len() - 1)];
     let remaining = (length + offset) % 8;
     f.write_str(", ")?;
     f.write_str("0b")?;
@@ -6,7 +6,7 @@ mod iterator;
 mod slice_iterator;
 mod zip_validity;
 
-use std::convert::TryInto;
+use core::convert::TryInto;
 
 pub(crate) use chunk_iterator::merge_reversed;
 pub use chunk_iterator::{BitChunk, BitChunkIterExact, BitChunks, BitChunksExact};
@@ -122,9 +122,47 @@ pub fn count_zeros(slice: &[u8], offset: usize, len: usize) -> usize {
         slice = &slice[..last_index];
     }
 
-    // finally, count any and all bytes in the middle in groups of 8
+    // finally, count any and all bytes in the middle
+    set_count += count_ones(slice);
+
+    len - set_count
+}
+
+/// Returns the number of set bits in `slice`, using the population count instructions
+/// available at runtime.
+fn count_ones(slice: &[u8]) -> usize {
+    #[cfg(all(feature = "std", target_arch = "x86_64"))]
+    {
+        if std::is_x86_feature_detected!("avx2") && std::is_x86_feature_detected!("popcnt") {
+            // Safety: the features were detected at runtime
+            return unsafe { count_ones_avx2(slice) };
+        }
+        if std::is_x86_feature_detected!("popcnt") {
+            // Safety: the feature was detected at runtime
+            return unsafe { count_ones_popcnt(slice) };
+        }
+    }
+    count_ones_generic(slice)
+}
+
+#[cfg(all(feature = "std", target_arch = "x86_64"))]
+#[target_feature(enable = "avx2,popcnt")]
+unsafe fn count_ones_avx2(slice: &[u8]) -> usize {
+    count_ones_generic(slice)
+}
+
+#[cfg(all(feature = "std", target_arch = "x86_64"))]
+#[target_feature(enable = "popcnt")]
+unsafe fn count_ones_popcnt(slice: &[u8]) -> usize {
+    count_ones_generic(slice)
+}
+
+// inlined in the functions above so that it is compiled for their target features
+#[inline(always)]
+fn count_ones_generic(slice: &[u8]) -> usize {
+    // count the bytes in groups of 8
     let mut chunks = slice.chunks_exact(8);
-    set_count += chunks
+    let set_count = chunks
         .by_ref()
         .map(|chunk| {
             let a = u64::from_ne_bytes(chunk.try_into().unwrap());
@@ -133,11 +171,10 @@ pub fn count_zeros(slice: &[u8], offset: usize, len: usize) -> usize {
         .sum::<usize>();
 
     // and any bytes that do not fit in the group
-    set_count += chunks
-        .remainder()
-        .iter()
-        .map(|byte| byte.count_ones() as usize)
-        .sum::<usize>();
-
-    len - set_count
+    set_count
+        + chunks
+            .remainder()
+            .iter()
+            .map(|byte| byte.count_ones() as usize)
+            .sum::<usize>()
 }
@@ -15,7 +15,7 @@ enum State {
 /// For example, the bitmap `00101111` returns `[(0,4), (6,1)]`
 #[derive(Debug, Clone)]
 pub struct SlicesIterator<'a> {
-    values: std::slice::Iter<'a, u8>,
+    values: core::slice::Iter<'a, u8>,
     count: usize,
     mask: u8,
     max_len: usize,
@@ -90,14 +90,14 @@ impl<'a> Iterator for SlicesIterator<'a> {
                 // at the beginning of a byte => try to skip it all together
                 match (self.on_region, self.current_byte) {
                     (true, &255u8) => {
-                        self.len = std::cmp::min(self.max_len - self.start, self.len + 8);
+                        self.len = core::cmp::min(self.max_len - self.start, self.len + 8);
                         if let Some(v) = self.values.next() {
                             self.current_byte = v;
                         };
                         continue;
                     }
                     (false, &0) => {
-                        self.len = std::cmp::min(self.max_len - self.start, self.len + 8);
+                        self.len = core::cmp::min(self.max_len - self.start, self.len + 8);
                         if let Some(v) = self.values.next() {
                             self.current_byte = v;
                         };
@@ -1,7 +1,10 @@
-use std::{iter::FromIterator, ops::Deref, sync::Arc, usize};
+use alloc::{boxed::Box, sync::Arc, vec::Vec};
+use core::{iter::FromIterator, ops::Deref};
+
+use either::Either;
 
-use super::Bytes;
 use super::IntoIter;
+use super::{Bytes, BytesAllocator};
 
 /// [`Buffer`] is a contiguous memory region that can be shared across
 /// thread boundaries.
@@ -51,9 +54,9 @@ impl<T: PartialEq> PartialEq for Buffer<T> {
     }
 }
 
-impl<T: std::fmt::Debug> std::fmt::Debug for Buffer<T> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        std::fmt::Debug::fmt(&**self, f)
+impl<T: core::fmt::Debug> core::fmt::Debug for Buffer<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Debug::fmt(&**self, f)
     }
 }
 
@@ -116,6 +119,32 @@ impl<T> Buffer<T> {
         unsafe { self.data.get_unchecked(self.offset + index) }
     }
 
+    /// Creates a [`Buffer`] over the region `[ptr, ptr + length[` owned by `owner`, without copying it.
+    ///
+    /// `owner` is dropped once the last [`Buffer`] sharing the region is dropped. This allows
+    /// wrapping memory produced by other Rust libraries in `O(1)`.
+    /// # Example
+    /// ```
+    /// use arrow2::buffer::Buffer;
+    ///
+    /// let values: Box<[u32]> = vec![1, 2, 3].into_boxed_slice();
+    /// let ptr = values.as_ptr();
+    /// let buffer = unsafe { Buffer::from_owner(values, ptr, 3) };
+    /// assert_eq!(buffer.as_slice(), &[1, 2, 3]);
+    /// ```
+    /// # Safety
+    /// The caller must ensure that:
+    /// * `ptr` is non-null, aligned and valid for reads of `length` elements of `T`
+    /// * the region is neither mutated nor deallocated while `owner` is alive
+    pub unsafe fn from_owner<O: Send + Sync + 'static>(
+        owner: O,
+        ptr: *const T,
+        length: usize,
+    ) -> Self {
+        let owner = BytesAllocator::Owner(Box::new(owner));
+        Self::from_bytes(Bytes::from_foreign(ptr, length, owner))
+    }
+
     /// Returns a new [`Buffer`] that is a slice of this buffer starting at `offset`.
     /// Doing so allows the same memory region to be shared between buffers.
     /// # Panics
@@ -142,6 +171,7 @@ impl<T> Buffer<T> {
     }
 
     /// Returns a pointer to the start of this buffer.
+    #[cfg(feature = "std")]
     #[inline]
     pub(crate) fn as_ptr(&self) -> *const T {
         self.data.deref().as_ptr()
@@ -163,7 +193,22 @@ impl<T> Buffer<T> {
         if self.offset != 0 {
             None
         } else {
-            Arc::get_mut(&mut self.data).and_then(|b| b.get_vec())
+            let vec = Arc::get_mut(&mut self.data).and_then(|b| b.get_vec())?;
+            // the values past the slice are not referenced by any other buffer
+            vec.truncate(self.length);
+            Some(vec)
+        }
+    }
+
+    /// Converts this [`Buffer`] to a [`Vec`], returning itself if the conversion
+    /// is not possible
+    ///
+    /// This operation returns a [`Vec`] iff the conditions of [`Buffer::get_mut`] hold,
+    /// in which case no allocation nor copy is performed.
+    pub fn into_mut(mut self) -> Either<Self, Vec<T>> {
+        match self.get_mut() {
+            Some(vec) => Either::Right(core::mem::take(vec)),
+            None => Either::Left(self),
         }
     }
 
@@ -190,7 +235,30 @@ impl<T> From<Vec<T>> for Buffer<T> {
     }
 }
 
-impl<T> std::ops::Deref for Buffer<T> {
+impl<T: Send + Sync + 'static> From<Arc<Vec<T>>> for Buffer<T> {
+    /// Shares the values of `values` without copying them. If `values` is not shared,
+    /// its [`Vec`] is moved into the [`Buffer`] instead.
+    fn from(values: Arc<Vec<T>>) -> Self {
+        Arc::try_unwrap(values)
+            .map(Into::into)
+            .unwrap_or_else(|values| {
+                let (ptr, length) = (values.as_ptr(), values.len());
+                // Safety: `values` is immutable behind the `Arc` and owns the region
+                unsafe { Self::from_owner(values, ptr, length) }
+            })
+    }
+}
+
+impl<T: Send + Sync + 'static> From<Arc<[T]>> for Buffer<T> {
+    /// Shares the values of `values` without copying them.
+    fn from(values: Arc<[T]>) -> Self {
+        let (ptr, length) = (values.as_ptr(), values.len());
+        // Safety: `values` is immutable behind the `Arc` and owns the region
+        unsafe { Self::from_owner(values, ptr, length) }
+    }
+}
+
+impl<T> core::ops::Deref for Buffer<T> {
     type Target = [T];
 
     #[inline]
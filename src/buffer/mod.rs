@@ -3,9 +3,23 @@
 mod immutable;
 mod iterator;
 
+use alloc::boxed::Box;
+
+#[cfg(feature = "std")]
 use crate::ffi::InternalArrowArray;
 
-pub(crate) type Bytes<T> = foreign_vec::ForeignVec<InternalArrowArray, T>;
+/// The owner of a memory region that was not allocated by a [`Vec`] of this crate.
+// the owners are never read: they are only held so that they are dropped with the region
+#[allow(dead_code)]
+pub(crate) enum BytesAllocator {
+    /// The region was imported via the C data interface
+    #[cfg(feature = "std")]
+    InternalArrowArray(InternalArrowArray),
+    /// The region is owned by an arbitrary Rust value (e.g. a shared [`Vec`])
+    Owner(Box<dyn core::any::Any + Send + Sync>),
+}
+
+pub(crate) type Bytes<T> = foreign_vec::ForeignVec<BytesAllocator, T>;
 pub(super) use iterator::IntoIter;
 
 pub use immutable::Buffer;
@@ -1,8 +1,12 @@
 //! Contains [`Chunk`], a container of [`Array`] where every array has the
 //! same length.
 
+use alloc::{boxed::Box, string::ToString, vec::Vec};
+
 use crate::array::Array;
+use crate::datatypes::Schema;
 use crate::error::{Error, Result};
+use crate::scalar::Scalar;
 
 /// A vector of trait objects of [`Array`] where every item has
 /// the same length, [`Chunk::len`].
@@ -38,6 +42,63 @@ impl<A: AsRef<dyn Array>> Chunk<A> {
         Ok(Self { arrays })
     }
 
+    /// Creates a new [`Chunk`] whose arrays are validated against `schema`.
+    /// # Error
+    /// Iff the arrays are not valid against `schema`, i.e. iff any of
+    /// * the number of arrays differs from the number of fields
+    /// * an array does not have the same length as the first array
+    /// * an array does not have the data type of its field
+    /// * an array has nulls but its field is not nullable
+    ///
+    /// All mismatches are reported in the error, identified by the name of their field.
+    pub fn try_new_with_schema(schema: &Schema, arrays: Vec<A>) -> Result<Self> {
+        let mut errors = vec![];
+        if schema.fields.len() != arrays.len() {
+            errors.push(format!(
+                "the schema has {} fields but {} arrays were provided",
+                schema.fields.len(),
+                arrays.len()
+            ));
+        }
+
+        let len = arrays.first().map(|array| array.as_ref().len());
+        for (field, array) in schema.fields.iter().zip(arrays.iter()) {
+            let array = array.as_ref();
+            if Some(array.len()) != len {
+                errors.push(format!(
+                    "field \"{}\" has {} rows but the first array has {}",
+                    field.name,
+                    array.len(),
+                    len.unwrap_or_default()
+                ));
+            }
+            if array.data_type() != field.data_type() {
+                errors.push(format!(
+                    "field \"{}\" has data type {:?} but the array has data type {:?}",
+                    field.name,
+                    field.data_type(),
+                    array.data_type()
+                ));
+            }
+            if !field.is_nullable && array.null_count() > 0 {
+                errors.push(format!(
+                    "field \"{}\" is not nullable but the array has {} nulls",
+                    field.name,
+                    array.null_count()
+                ));
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(Self { arrays })
+        } else {
+            Err(Error::InvalidArgumentError(format!(
+                "Chunk does not match its schema: {}",
+                errors.join("; ")
+            )))
+        }
+    }
+
     /// returns the [`Array`]s in [`Chunk`]
     pub fn arrays(&self) -> &[A] {
         &self.arrays
@@ -61,6 +122,83 @@ impl<A: AsRef<dyn Array>> Chunk<A> {
         self.len() == 0
     }
 
+    /// Returns the row `i` as one [`Scalar`] per array, via [`Array::get`].
+    /// # Panic
+    /// Panics iff `i >= self.len()`.
+    /// # Example
+    /// ```
+    /// use arrow2::array::{Int32Array, Utf8Array};
+    /// use arrow2::chunk::Chunk;
+    /// use arrow2::scalar::{PrimitiveScalar, Scalar};
+    ///
+    /// let chunk = Chunk::new(vec![
+    ///     Int32Array::from_slice([1, 2]).boxed(),
+    ///     Utf8Array::<i32>::from([Some("a"), None]).boxed(),
+    /// ]);
+    ///
+    /// let row = chunk.row(1);
+    /// let expected = PrimitiveScalar::from(Some(2i32));
+    /// assert_eq!(row[0].as_ref(), &expected as &dyn Scalar);
+    /// assert!(!row[1].is_valid());
+    /// ```
+    pub fn row(&self, i: usize) -> Vec<Box<dyn Scalar>> {
+        self.arrays
+            .iter()
+            .map(|array| array.as_ref().get(i))
+            .collect()
+    }
+
+    /// Returns whether `other` has the same number of arrays as this [`Chunk`] and every array
+    /// is equal to the array of `other` at the same position.
+    ///
+    /// Unlike [`PartialEq`], this compares chunks of different containers of arrays
+    /// (e.g. `Box<dyn Array>` and `Arc<dyn Array>`). Use [`crate::util::diff::diff_chunks`]
+    /// to find where two chunks differ.
+    pub fn equals<B: AsRef<dyn Array>>(&self, other: &Chunk<B>) -> bool {
+        self.arrays.len() == other.arrays().len()
+            && self
+                .arrays
+                .iter()
+                .zip(other.arrays().iter())
+                .all(|(lhs, rhs)| crate::array::equal(lhs.as_ref(), rhs.as_ref()))
+    }
+
+    /// Returns a new [`Chunk`] with every array sliced to the rows in `range`.
+    /// # Panic
+    /// Iff `range.start > range.end` or `range.end > self.len()`.
+    pub fn sliced(&self, range: core::ops::Range<usize>) -> Chunk<Box<dyn Array>> {
+        assert!(
+            range.start <= range.end && range.end <= self.len(),
+            "the range {range:?} is out of bounds of a chunk of length {}",
+            self.len()
+        );
+        self.slice_arrays(range.start, range.end - range.start)
+    }
+
+    /// Returns a new [`Chunk`] with every array sliced to `length` rows starting at `offset`.
+    /// # Error
+    /// Iff `offset + length > self.len()`.
+    pub fn try_slice(&self, offset: usize, length: usize) -> Result<Chunk<Box<dyn Array>>> {
+        match offset.checked_add(length) {
+            Some(end) if end <= self.len() => Ok(self.slice_arrays(offset, length)),
+            _ => Err(Error::InvalidArgumentError(format!(
+                "the slice with offset {offset} and length {length} is out of bounds of a chunk of length {}",
+                self.len()
+            ))),
+        }
+    }
+
+    /// Slices every array, assuming `offset + length <= self.len()`.
+    fn slice_arrays(&self, offset: usize, length: usize) -> Chunk<Box<dyn Array>> {
+        let arrays = self
+            .arrays
+            .iter()
+            // Safety: the caller checked bounds and all arrays have the same length
+            .map(|array| unsafe { array.as_ref().slice_unchecked(offset, length) })
+            .collect();
+        Chunk { arrays }
+    }
+
     /// Consumes [`Chunk`] into its underlying arrays.
     /// The arrays are guaranteed to have the same length
     pub fn into_arrays(self) -> Vec<A> {
@@ -74,7 +212,7 @@ impl<A: AsRef<dyn Array>> From<Chunk<A>> for Vec<A> {
     }
 }
 
-impl<A: AsRef<dyn Array>> std::ops::Deref for Chunk<A> {
+impl<A: AsRef<dyn Array>> core::ops::Deref for Chunk<A> {
     type Target = [A];
 
     #[inline]
@@ -0,0 +1,423 @@
+//! Contains approximate aggregations: [`approx_distinct`], based on [`HyperLogLog`],
+//! and [`approx_quantile`], based on [`TDigest`].
+//!
+//! Both aggregations are backed by a state struct that can be updated from multiple arrays
+//! and merged with other states, so that they can be used in streaming and
+//! distributed contexts.
+use ahash::RandomState;
+use num_traits::AsPrimitive;
+
+use crate::array::{
+    Array, BinaryArray, BooleanArray, DictionaryArray, FixedSizeBinaryArray, PrimitiveArray,
+    Utf8Array,
+};
+use crate::datatypes::PhysicalType;
+use crate::error::{Error, Result};
+use crate::types::NativeType;
+
+/// The default precision of [`HyperLogLog`], corresponding to a standard error of ~0.8%.
+pub const DEFAULT_HLL_PRECISION: u8 = 14;
+
+/// The default compression of [`TDigest`].
+pub const DEFAULT_TDIGEST_COMPRESSION: f64 = 100.0;
+
+fn new_state() -> RandomState {
+    RandomState::with_seeds(0, 0, 0, 0)
+}
+
+/// A [HyperLogLog](https://en.wikipedia.org/wiki/HyperLogLog) sketch estimating the number of
+/// distinct non-null values of arrays.
+///
+/// Sketches with the same precision can be merged via [`HyperLogLog::merge`].
+/// # Implementation
+/// Values are hashed with a fixed-seed hasher. Hashes are only guaranteed to be stable within
+/// the same build of this crate, and sketches should therefore not be persisted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HyperLogLog {
+    precision: u8,
+    registers: Vec<u8>,
+}
+
+impl Default for HyperLogLog {
+    fn default() -> Self {
+        Self::new(DEFAULT_HLL_PRECISION)
+    }
+}
+
+impl HyperLogLog {
+    /// Returns a new [`HyperLogLog`] with `2^precision` registers.
+    /// # Panics
+    /// Panics iff `precision` is not between 4 and 18.
+    pub fn new(precision: u8) -> Self {
+        Self::try_new(precision).unwrap()
+    }
+
+    /// Returns a new [`HyperLogLog`] with `2^precision` registers.
+    /// # Errors
+    /// Errors iff `precision` is not between 4 and 18.
+    pub fn try_new(precision: u8) -> Result<Self> {
+        if !(4..=18).contains(&precision) {
+            return Err(Error::InvalidArgumentError(format!(
+                "HyperLogLog's precision must be between 4 and 18, got {precision}"
+            )));
+        }
+        Ok(Self {
+            precision,
+            registers: vec![0; 1 << precision],
+        })
+    }
+
+    /// The precision of this sketch
+    pub fn precision(&self) -> u8 {
+        self.precision
+    }
+
+    /// Adds a hashed value to this sketch.
+    #[inline]
+    pub fn add_hash(&mut self, hash: u64) {
+        let index = (hash >> (64 - self.precision)) as usize;
+        // the remaining bits, with a sentinel bit so that the rank is bounded
+        let remaining = (hash << self.precision) | (1 << (self.precision - 1));
+        let rank = remaining.leading_zeros() as u8 + 1;
+        let register = &mut self.registers[index];
+        *register = (*register).max(rank);
+    }
+
+    /// Adds all non-null values of `array` to this sketch.
+    /// # Errors
+    /// Errors iff the [`DataType`](crate::datatypes::DataType) of `array` is not supported.
+    /// Supported types are booleans, all primitive types, (large) utf8 and binary,
+    /// fixed size binary, and dictionaries of these.
+    pub fn update(&mut self, array: &dyn Array) -> Result<()> {
+        for_each_hash(array, &new_state(), &mut |hash| {
+            if let Some(hash) = hash {
+                self.add_hash(hash)
+            }
+        })
+    }
+
+    /// Merges `other` into this sketch.
+    /// # Errors
+    /// Errors iff the sketches have different precisions.
+    pub fn merge(&mut self, other: &Self) -> Result<()> {
+        if self.precision != other.precision {
+            return Err(Error::InvalidArgumentError(
+                "HyperLogLog sketches can only be merged when they have the same precision"
+                    .to_string(),
+            ));
+        }
+        self.registers
+            .iter_mut()
+            .zip(other.registers.iter())
+            .for_each(|(lhs, rhs)| *lhs = (*lhs).max(*rhs));
+        Ok(())
+    }
+
+    /// Returns the estimated number of distinct values added to this sketch.
+    pub fn estimate(&self) -> u64 {
+        let m = self.registers.len() as f64;
+        let alpha = match self.registers.len() {
+            16 => 0.673,
+            32 => 0.697,
+            64 => 0.709,
+            _ => 0.7213 / (1.0 + 1.079 / m),
+        };
+        let (sum, zeros) =
+            self.registers
+                .iter()
+                .fold((0.0f64, 0usize), |(sum, zeros), register| {
+                    (
+                        sum + 2f64.powi(-(*register as i32)),
+                        zeros + (*register == 0) as usize,
+                    )
+                });
+        let estimate = alpha * m * m / sum;
+
+        // small range correction via linear counting
+        let estimate = if estimate <= 2.5 * m && zeros > 0 {
+            m * (m / zeros as f64).ln()
+        } else {
+            estimate
+        };
+        estimate.round() as u64
+    }
+}
+
+fn hash_iter<T, I: Iterator<Item = Option<T>>>(
+    iter: I,
+    hash: impl Fn(T) -> u64,
+    f: &mut dyn FnMut(Option<u64>),
+) {
+    iter.for_each(|x| f(x.map(&hash)))
+}
+
+/// Calls `f` with the hash of every slot of `array`, or `None` if the slot is null.
+fn for_each_hash(
+    array: &dyn Array,
+    state: &RandomState,
+    f: &mut dyn FnMut(Option<u64>),
+) -> Result<()> {
+    use PhysicalType::*;
+    match array.data_type().to_physical_type() {
+        Null => (0..array.len()).for_each(|_| f(None)),
+        Boolean => {
+            let array = array.as_any().downcast_ref::<BooleanArray>().unwrap();
+            hash_iter(array.iter(), |x| state.hash_one(x), f)
+        }
+        Primitive(primitive) => with_match_primitive_type!(primitive, |$T| {
+            let array = array.as_any().downcast_ref::<PrimitiveArray<$T>>().unwrap();
+            hash_iter(array.iter(), |x| state.hash_one(x.to_le_bytes().as_ref()), f)
+        }),
+        Utf8 => {
+            let array = array.as_any().downcast_ref::<Utf8Array<i32>>().unwrap();
+            hash_iter(array.iter(), |x| state.hash_one(x.as_bytes()), f)
+        }
+        LargeUtf8 => {
+            let array = array.as_any().downcast_ref::<Utf8Array<i64>>().unwrap();
+            hash_iter(array.iter(), |x| state.hash_one(x.as_bytes()), f)
+        }
+        Binary => {
+            let array = array.as_any().downcast_ref::<BinaryArray<i32>>().unwrap();
+            hash_iter(array.iter(), |x| state.hash_one(x), f)
+        }
+        LargeBinary => {
+            let array = array.as_any().downcast_ref::<BinaryArray<i64>>().unwrap();
+            hash_iter(array.iter(), |x| state.hash_one(x), f)
+        }
+        FixedSizeBinary => {
+            let array = array
+                .as_any()
+                .downcast_ref::<FixedSizeBinaryArray>()
+                .unwrap();
+            hash_iter(array.iter(), |x| state.hash_one(x), f)
+        }
+        Dictionary(key_type) => match_integer_type!(key_type, |$T| {
+            let array = array
+                .as_any()
+                .downcast_ref::<DictionaryArray<$T>>()
+                .unwrap();
+            // hash every value once, and then use the keys to look them up
+            let mut hashes = Vec::with_capacity(array.values().len());
+            for_each_hash(array.values().as_ref(), state, &mut |hash| hashes.push(hash))?;
+            array
+                .keys_iter()
+                .for_each(|key| f(key.and_then(|key| hashes[key])))
+        }),
+        other => {
+            return Err(Error::NotYetImplemented(format!(
+                "approx_distinct is not implemented for {other:?}"
+            )))
+        }
+    };
+    Ok(())
+}
+
+/// Returns the approximate number of distinct non-null values in `array`,
+/// using a [`HyperLogLog`] with [`DEFAULT_HLL_PRECISION`].
+/// # Errors
+/// Errors iff the [`DataType`](crate::datatypes::DataType) of `array` is not supported.
+/// See [`HyperLogLog::update`] for the supported types.
+pub fn approx_distinct(array: &dyn Array) -> Result<u64> {
+    let mut sketch = HyperLogLog::default();
+    sketch.update(array)?;
+    Ok(sketch.estimate())
+}
+
+/// A cluster of values of a [`TDigest`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Centroid {
+    /// The mean of the values of this centroid
+    pub mean: f64,
+    /// The number of values of this centroid
+    pub weight: f64,
+}
+
+/// A [t-digest](https://arxiv.org/abs/1902.04023) sketch estimating quantiles of
+/// the non-null values of arrays.
+///
+/// Digests can be merged via [`TDigest::merge`].
+/// # Implementation
+/// Values are buffered and merged into the centroids whenever the buffer is full or
+/// a quantile is requested. NaNs are ignored.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TDigest {
+    compression: f64,
+    centroids: Vec<Centroid>,
+    buffer: Vec<Centroid>,
+    count: f64,
+    min: f64,
+    max: f64,
+}
+
+impl Default for TDigest {
+    fn default() -> Self {
+        Self::new(DEFAULT_TDIGEST_COMPRESSION)
+    }
+}
+
+impl TDigest {
+    /// Returns a new empty [`TDigest`]. Higher `compression` results in more centroids and
+    /// thus higher accuracy and memory usage.
+    /// # Panics
+    /// Panics iff `compression` is not positive.
+    pub fn new(compression: f64) -> Self {
+        assert!(compression > 0.0, "TDigest's compression must be positive");
+        Self {
+            compression,
+            centroids: vec![],
+            buffer: vec![],
+            count: 0.0,
+            min: f64::INFINITY,
+            max: f64::NEG_INFINITY,
+        }
+    }
+
+    /// The number of values in this digest
+    pub fn count(&self) -> u64 {
+        self.count as u64
+    }
+
+    /// Whether this digest has no values
+    pub fn is_empty(&self) -> bool {
+        self.count == 0.0
+    }
+
+    /// Returns the centroids of this digest, sorted by their mean.
+    pub fn centroids(&mut self) -> &[Centroid] {
+        self.compress();
+        &self.centroids
+    }
+
+    fn push(&mut self, centroid: Centroid) {
+        if centroid.mean.is_nan() || centroid.weight <= 0.0 {
+            return;
+        }
+        self.min = self.min.min(centroid.mean);
+        self.max = self.max.max(centroid.mean);
+        self.count += centroid.weight;
+        self.buffer.push(centroid);
+        if self.buffer.len() >= self.buffer_capacity() {
+            self.compress();
+        }
+    }
+
+    fn buffer_capacity(&self) -> usize {
+        (self.compression as usize).max(1) * 8
+    }
+
+    /// Adds a value to this digest.
+    #[inline]
+    pub fn add(&mut self, value: f64) {
+        self.push(Centroid {
+            mean: value,
+            weight: 1.0,
+        })
+    }
+
+    /// Adds all non-null values of `array` to this digest.
+    pub fn update<T: NativeType + AsPrimitive<f64>>(&mut self, array: &PrimitiveArray<T>) {
+        array.iter().flatten().for_each(|x| self.add(x.as_()));
+    }
+
+    /// Merges `other` into this digest.
+    pub fn merge(&mut self, other: &Self) {
+        other
+            .centroids
+            .iter()
+            .chain(other.buffer.iter())
+            .for_each(|centroid| self.push(*centroid));
+        // the means of compressed centroids are within the extremes of their values
+        self.min = self.min.min(other.min);
+        self.max = self.max.max(other.max);
+    }
+
+    fn compress(&mut self) {
+        if self.buffer.is_empty() {
+            return;
+        }
+        let mut centroids = std::mem::take(&mut self.centroids);
+        centroids.append(&mut self.buffer);
+        centroids.sort_unstable_by(|a, b| a.mean.partial_cmp(&b.mean).unwrap());
+
+        let total = self.count;
+        let mut result = Vec::with_capacity(centroids.len());
+        let mut iter = centroids.into_iter();
+        let mut current = iter.next().unwrap();
+        // the weight of all centroids before `current`
+        let mut cumulative = 0.0;
+        for centroid in iter {
+            let proposed = current.weight + centroid.weight;
+            let q0 = cumulative / total;
+            let q2 = (cumulative + proposed) / total;
+            let limit = 4.0 * total * (q0 * (1.0 - q0)).min(q2 * (1.0 - q2)) / self.compression;
+            if proposed <= limit {
+                current.mean += (centroid.mean - current.mean) * centroid.weight / proposed;
+                current.weight = proposed;
+            } else {
+                cumulative += current.weight;
+                result.push(current);
+                current = centroid;
+            }
+        }
+        result.push(current);
+        self.centroids = result;
+    }
+
+    /// Returns the estimated `q`-quantile of the values in this digest, or `None` if it is empty.
+    /// # Panics
+    /// Panics iff `q` is not between 0 and 1.
+    pub fn quantile(&mut self, q: f64) -> Option<f64> {
+        assert!(
+            (0.0..=1.0).contains(&q),
+            "quantiles must be between 0 and 1"
+        );
+        if self.is_empty() {
+            return None;
+        }
+        self.compress();
+        let centroids = &self.centroids;
+        if centroids.len() == 1 {
+            return Some(centroids[0].mean);
+        }
+
+        let target = q * self.count;
+
+        // before the center of the first centroid: interpolate from the minimum
+        let first = centroids[0];
+        if target < first.weight / 2.0 {
+            return Some(self.min + (first.mean - self.min) * target / (first.weight / 2.0));
+        }
+
+        let mut cumulative = 0.0;
+        for window in centroids.windows(2) {
+            let (left, right) = (window[0], window[1]);
+            let left_center = cumulative + left.weight / 2.0;
+            let right_center = cumulative + left.weight + right.weight / 2.0;
+            if target <= right_center {
+                let fraction = (target - left_center) / (right_center - left_center);
+                return Some(left.mean + (right.mean - left.mean) * fraction);
+            }
+            cumulative += left.weight;
+        }
+
+        // after the center of the last centroid: interpolate to the maximum
+        let last = centroids[centroids.len() - 1];
+        let last_center = self.count - last.weight / 2.0;
+        let fraction = (target - last_center) / (last.weight / 2.0);
+        Some(last.mean + (self.max - last.mean) * fraction)
+    }
+}
+
+/// Returns the approximate `q`-quantile of the non-null values of `array`,
+/// using a [`TDigest`] with [`DEFAULT_TDIGEST_COMPRESSION`].
+/// Returns `None` when `array` has no non-null values.
+/// # Panics
+/// Panics iff `q` is not between 0 and 1.
+pub fn approx_quantile<T>(array: &PrimitiveArray<T>, q: f64) -> Option<f64>
+where
+    T: NativeType + AsPrimitive<f64>,
+{
+    let mut digest = TDigest::default();
+    digest.update(array);
+    digest.quantile(q)
+}
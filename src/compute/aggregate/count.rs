@@ -0,0 +1,183 @@
+use std::collections::HashSet;
+use std::hash::Hash;
+
+use crate::array::{
+    Array, BinaryArray, BooleanArray, DictionaryArray, FixedSizeBinaryArray, PrimitiveArray,
+    Utf8Array,
+};
+use crate::bitmap::Bitmap;
+use crate::compute::utils::{canonical_f32, canonical_f64};
+use crate::datatypes::{DataType, PhysicalType, PrimitiveType};
+use crate::error::{Error, Result};
+use crate::types::f16;
+
+/// Returns the number of set bits of `lhs & rhs`, counted 64 bits at a time.
+fn set_bits_of_and(lhs: &Bitmap, rhs: &Bitmap) -> usize {
+    assert_eq!(lhs.len(), rhs.len());
+    let lhs = lhs.chunks::<u64>();
+    let rhs = rhs.chunks::<u64>();
+    let remainder = (lhs.remainder() & rhs.remainder()).count_ones() as usize;
+    lhs.zip(rhs)
+        .map(|(lhs, rhs)| (lhs & rhs).count_ones() as usize)
+        .sum::<usize>()
+        + remainder
+}
+
+/// Returns the number of non-null slots of `array` whose value is `true`.
+/// # Implementation
+/// This is `O(1)` when `array` has no validity, and otherwise counts the set bits of
+/// the values `AND` the validity 64 bits at a time.
+/// # Example
+/// ```
+/// use arrow2::array::BooleanArray;
+/// use arrow2::compute::aggregate::count_true;
+///
+/// let array = BooleanArray::from([Some(true), None, Some(false), Some(true)]);
+/// assert_eq!(count_true(&array), 2);
+/// ```
+pub fn count_true(array: &BooleanArray) -> usize {
+    match array.validity() {
+        None => array.values().len() - array.values().unset_bits(),
+        Some(validity) => set_bits_of_and(array.values(), validity),
+    }
+}
+
+/// Returns the number of non-null slots of `array` whose value is `false`.
+/// # Implementation
+/// See [`count_true`].
+/// # Example
+/// ```
+/// use arrow2::array::BooleanArray;
+/// use arrow2::compute::aggregate::count_false;
+///
+/// let array = BooleanArray::from([Some(true), None, Some(false), Some(true)]);
+/// assert_eq!(count_false(&array), 1);
+/// ```
+pub fn count_false(array: &BooleanArray) -> usize {
+    array.len() - array.null_count() - count_true(array)
+}
+
+fn count_distinct_values<T: Eq + Hash, I: Iterator<Item = T>>(values: I) -> usize {
+    values.collect::<HashSet<_>>().len()
+}
+
+/// Returns the number of distinct values of `array` at `indices`, which must be valid slots.
+fn count_distinct_at(array: &dyn Array, indices: &mut dyn Iterator<Item = usize>) -> Result<usize> {
+    use PhysicalType::*;
+    Ok(match array.data_type().to_physical_type() {
+        Null => 0,
+        Boolean => {
+            let array = array.as_any().downcast_ref::<BooleanArray>().unwrap();
+            count_distinct_values(indices.map(|i| array.value(i)))
+        }
+        // floats are compared by their canonical bits, so that `0.0 == -0.0` and all
+        // `NaN`s are equal to each other
+        Primitive(PrimitiveType::Float16) => {
+            let array = array
+                .as_any()
+                .downcast_ref::<PrimitiveArray<f16>>()
+                .unwrap();
+            count_distinct_values(indices.map(|i| canonical_f32(array.value(i).to_f32())))
+        }
+        Primitive(PrimitiveType::Float32) => {
+            let array = array
+                .as_any()
+                .downcast_ref::<PrimitiveArray<f32>>()
+                .unwrap();
+            count_distinct_values(indices.map(|i| canonical_f32(array.value(i))))
+        }
+        Primitive(PrimitiveType::Float64) => {
+            let array = array
+                .as_any()
+                .downcast_ref::<PrimitiveArray<f64>>()
+                .unwrap();
+            count_distinct_values(indices.map(|i| canonical_f64(array.value(i))))
+        }
+        Primitive(primitive) => with_match_primitive_type!(primitive, |$T| {
+            let array = array.as_any().downcast_ref::<PrimitiveArray<$T>>().unwrap();
+            let values = array.values();
+            count_distinct_values(indices.map(|i| bytemuck::bytes_of(&values[i])))
+        }),
+        Utf8 => {
+            let array = array.as_any().downcast_ref::<Utf8Array<i32>>().unwrap();
+            count_distinct_values(indices.map(|i| array.value(i)))
+        }
+        LargeUtf8 => {
+            let array = array.as_any().downcast_ref::<Utf8Array<i64>>().unwrap();
+            count_distinct_values(indices.map(|i| array.value(i)))
+        }
+        Binary => {
+            let array = array.as_any().downcast_ref::<BinaryArray<i32>>().unwrap();
+            count_distinct_values(indices.map(|i| array.value(i)))
+        }
+        LargeBinary => {
+            let array = array.as_any().downcast_ref::<BinaryArray<i64>>().unwrap();
+            count_distinct_values(indices.map(|i| array.value(i)))
+        }
+        FixedSizeBinary => {
+            let array = array
+                .as_any()
+                .downcast_ref::<FixedSizeBinaryArray>()
+                .unwrap();
+            count_distinct_values(indices.map(|i| array.value(i)))
+        }
+        Dictionary(key_type) => match_integer_type!(key_type, |$T| {
+            let array = array
+                .as_any()
+                .downcast_ref::<DictionaryArray<$T>>()
+                .unwrap();
+            // different keys may point to equal values
+            let values = array.values().as_ref();
+            let mut indices = indices
+                .map(|i| array.key_value(i))
+                .filter(|key| values.is_valid(*key));
+            count_distinct_at(values, &mut indices)?
+        }),
+        other => {
+            return Err(Error::NotYetImplemented(format!(
+                "count_distinct is not implemented for {other:?}"
+            )))
+        }
+    })
+}
+
+/// Returns the number of distinct non-null values of `array`.
+/// # Errors
+/// Errors iff the [`DataType`] of `array` is not supported (see [`can_count_distinct`]).
+/// # Example
+/// ```
+/// use arrow2::array::Utf8Array;
+/// use arrow2::compute::aggregate::count_distinct;
+///
+/// let array = Utf8Array::<i32>::from([Some("a"), None, Some("b"), Some("a")]);
+/// assert_eq!(count_distinct(&array).unwrap(), 2);
+/// ```
+pub fn count_distinct(array: &dyn Array) -> Result<usize> {
+    let mut indices = (0..array.len()).filter(|i| array.is_valid(*i));
+    count_distinct_at(array, &mut indices)
+}
+
+/// Whether [`count_distinct`] supports `data_type`: booleans, all primitive types, (large) utf8
+/// and binary, fixed size binary, and dictionaries of these.
+pub fn can_count_distinct(data_type: &DataType) -> bool {
+    use PhysicalType::*;
+    match data_type.to_physical_type() {
+        Dictionary(_) => {
+            if let DataType::Dictionary(_, values, _) = data_type.to_logical_type() {
+                can_count_distinct(values)
+            } else {
+                unreachable!()
+            }
+        }
+        physical_type => matches!(
+            physical_type,
+            Null | Boolean
+                | Primitive(_)
+                | Utf8
+                | LargeUtf8
+                | Binary
+                | LargeBinary
+                | FixedSizeBinary
+        ),
+    }
+}
@@ -1,10 +1,12 @@
+use multiversion::multiversion;
+
 use crate::bitmap::utils::{BitChunkIterExact, BitChunksExact};
 use crate::datatypes::{DataType, PhysicalType, PrimitiveType};
 use crate::error::{Error, Result};
 use crate::offset::Offset;
 use crate::scalar::*;
 use crate::types::simd::*;
-use crate::types::NativeType;
+use crate::types::{f16, NativeType};
 use crate::{
     array::{Array, BinaryArray, BooleanArray, PrimitiveArray, Utf8Array},
     bitmap::Bitmap,
@@ -31,6 +33,9 @@ pub trait SimdOrd<T> {
     fn new_max() -> Self;
 }
 
+#[multiversion]
+#[clone(target = "x86_64+avx2")]
+#[clone(target = "x86_64+avx")]
 fn nonnull_min_primitive<T>(values: &[T]) -> T
 where
     T: NativeType + Simd,
@@ -50,6 +55,9 @@ where
     reduced.min_element()
 }
 
+#[multiversion]
+#[clone(target = "x86_64+avx2")]
+#[clone(target = "x86_64+avx")]
 fn null_min_primitive_impl<T, I>(values: &[T], mut validity_masks: I) -> T
 where
     T: NativeType + Simd,
@@ -110,6 +118,9 @@ where
     }
 }
 
+#[multiversion]
+#[clone(target = "x86_64+avx2")]
+#[clone(target = "x86_64+avx")]
 fn nonnull_max_primitive<T>(values: &[T]) -> T
 where
     T: NativeType + Simd,
@@ -129,6 +140,9 @@ where
     reduced.max_element()
 }
 
+#[multiversion]
+#[clone(target = "x86_64+avx2")]
+#[clone(target = "x86_64+avx")]
 fn null_max_primitive_impl<T, I>(values: &[T], mut validity_masks: I) -> T
 where
     T: NativeType + Simd,
@@ -155,6 +169,84 @@ where
     reduced.max_element()
 }
 
+#[multiversion]
+#[clone(target = "x86_64+avx2")]
+#[clone(target = "x86_64+avx")]
+fn nonnull_min_max_primitive<T>(values: &[T]) -> (T, T)
+where
+    T: NativeType + Simd,
+    T::Simd: SimdOrd<T>,
+{
+    let chunks = values.chunks_exact(T::Simd::LANES);
+    let remainder = chunks.remainder();
+
+    let (min, max) = chunks.fold(
+        (T::Simd::new_min(), T::Simd::new_max()),
+        |(min, max), chunk| {
+            let chunk = T::Simd::from_chunk(chunk);
+            (min.min_lane(chunk), max.max_lane(chunk))
+        },
+    );
+
+    let min = min.min_lane(T::Simd::from_incomplete_chunk(remainder, T::Simd::MAX));
+    let max = max.max_lane(T::Simd::from_incomplete_chunk(remainder, T::Simd::MIN));
+
+    (min.min_element(), max.max_element())
+}
+
+#[multiversion]
+#[clone(target = "x86_64+avx2")]
+#[clone(target = "x86_64+avx")]
+fn null_min_max_primitive_impl<T, I>(values: &[T], mut validity_masks: I) -> (T, T)
+where
+    T: NativeType + Simd,
+    T::Simd: SimdOrd<T>,
+    I: BitChunkIterExact<<<T as Simd>::Simd as NativeSimd>::Chunk>,
+{
+    let mut chunks = values.chunks_exact(T::Simd::LANES);
+
+    let (min, max) = chunks.by_ref().zip(validity_masks.by_ref()).fold(
+        (T::Simd::new_min(), T::Simd::new_max()),
+        |(min, max), (chunk, validity_chunk)| {
+            let chunk = T::Simd::from_chunk(chunk);
+            let mask = || <T::Simd as NativeSimd>::Mask::from_chunk(validity_chunk);
+            (
+                min.min_lane(chunk.select(mask(), T::Simd::new_min())),
+                max.max_lane(chunk.select(mask(), T::Simd::new_max())),
+            )
+        },
+    );
+
+    let validity_chunk = validity_masks.remainder();
+    let mask = || <T::Simd as NativeSimd>::Mask::from_chunk(validity_chunk);
+    let remainder = chunks.remainder();
+    let min = min.min_lane(
+        T::Simd::from_incomplete_chunk(remainder, T::Simd::MAX).select(mask(), T::Simd::new_min()),
+    );
+    let max = max.max_lane(
+        T::Simd::from_incomplete_chunk(remainder, T::Simd::MIN).select(mask(), T::Simd::new_max()),
+    );
+
+    (min.min_element(), max.max_element())
+}
+
+/// # Panics
+/// iff `values.len() != bitmap.len()`.
+fn null_min_max_primitive<T>(values: &[T], bitmap: &Bitmap) -> (T, T)
+where
+    T: NativeType + Simd,
+    T::Simd: SimdOrd<T>,
+{
+    let (slice, offset, length) = bitmap.as_slice();
+    if offset == 0 {
+        let validity_masks = BitChunksExact::<<T::Simd as NativeSimd>::Chunk>::new(slice, length);
+        null_min_max_primitive_impl(values, validity_masks)
+    } else {
+        let validity_masks = bitmap.chunks::<<T::Simd as NativeSimd>::Chunk>();
+        null_min_max_primitive_impl(values, validity_masks)
+    }
+}
+
 /// Returns the minimum value in the array, according to the natural order.
 /// For floating point arrays any NaN values are considered to be greater than any other non-null value
 pub fn min_primitive<T>(array: &PrimitiveArray<T>) -> Option<T>
@@ -199,6 +291,28 @@ where
     })
 }
 
+/// Returns the minimum and maximum values in the array in a single pass, like
+/// [`min_primitive`] and [`max_primitive`].
+pub fn min_max_primitive<T>(array: &PrimitiveArray<T>) -> Option<(T, T)>
+where
+    T: NativeType + Simd,
+    T::Simd: SimdOrd<T>,
+{
+    let null_count = array.null_count();
+
+    // Includes case array.len() == 0
+    if null_count == array.len() {
+        return None;
+    }
+    let values = array.values();
+
+    Some(if let Some(validity) = array.validity() {
+        null_min_max_primitive(values, validity)
+    } else {
+        nonnull_min_max_primitive(values)
+    })
+}
+
 /// Helper to compute min/max of [`BinaryArray`] and [`Utf8Array`]
 macro_rules! min_max_binary_utf8 {
     ($array: expr, $cmp: expr) => {
@@ -247,6 +361,39 @@ pub fn min_string<O: Offset>(array: &Utf8Array<O>) -> Option<&str> {
     min_max_binary_utf8!(array, |a, b| a > b)
 }
 
+/// Returns the minimum and maximum of `values` in a single pass
+fn min_max_values<'a, T: PartialOrd + ?Sized + 'a>(
+    mut values: impl Iterator<Item = &'a T>,
+) -> Option<(&'a T, &'a T)> {
+    let first = values.next()?;
+    Some(values.fold((first, first), |(min, max), value| {
+        (
+            if value < min { value } else { min },
+            if value > max { value } else { max },
+        )
+    }))
+}
+
+/// Returns the minimum and maximum values in the binary array in a single pass, according
+/// to the natural order.
+pub fn min_max_binary<O: Offset>(array: &BinaryArray<O>) -> Option<(&[u8], &[u8])> {
+    if array.validity().is_some() {
+        min_max_values(array.iter().flatten())
+    } else {
+        min_max_values(array.values_iter())
+    }
+}
+
+/// Returns the minimum and maximum values in the string array in a single pass, according
+/// to the natural order.
+pub fn min_max_string<O: Offset>(array: &Utf8Array<O>) -> Option<(&str, &str)> {
+    if array.validity().is_some() {
+        min_max_values(array.iter().flatten())
+    } else {
+        min_max_values(array.values_iter())
+    }
+}
+
 /// Returns the minimum value in the boolean array.
 ///
 /// ```
@@ -303,6 +450,51 @@ pub fn max_boolean(array: &BooleanArray) -> Option<bool> {
     }
 }
 
+/// Returns the minimum and maximum values in the boolean array, counting its set bits once.
+///
+/// ```
+/// use arrow2::{
+///   array::BooleanArray,
+///   compute::aggregate::min_max_boolean,
+/// };
+///
+/// let a = BooleanArray::from(vec![Some(true), None, Some(false)]);
+/// assert_eq!(min_max_boolean(&a), Some((false, true)))
+/// ```
+pub fn min_max_boolean(array: &BooleanArray) -> Option<(bool, bool)> {
+    let valid = array.len() - array.null_count();
+    // short circuit if all nulls / zero length array
+    if valid == 0 {
+        return None;
+    }
+    let set = if let Some(validity) = array.validity() {
+        let values = array.values() & validity;
+        values.len() - values.unset_bits()
+    } else {
+        array.len() - array.values().unset_bits()
+    };
+    Some((set == valid, set > 0))
+}
+
+/// Returns the minimum value in the array like [`min_primitive`], computed over the
+/// values cast to `f32`.
+pub fn min_f16(array: &PrimitiveArray<f16>) -> Option<f16> {
+    min_primitive(&super::f16_to_f32(array)).map(f16::from_f32)
+}
+
+/// Returns the maximum value in the array like [`max_primitive`], computed over the
+/// values cast to `f32`.
+pub fn max_f16(array: &PrimitiveArray<f16>) -> Option<f16> {
+    max_primitive(&super::f16_to_f32(array)).map(f16::from_f32)
+}
+
+/// Returns the minimum and maximum values in the array like [`min_max_primitive`], computed
+/// over the values cast to `f32`.
+pub fn min_max_f16(array: &PrimitiveArray<f16>) -> Option<(f16, f16)> {
+    min_max_primitive(&super::f16_to_f32(array))
+        .map(|(min, max)| (f16::from_f32(min), f16::from_f32(max)))
+}
+
 macro_rules! dyn_generic {
     ($array_ty:ty, $scalar_ty:ty, $array:expr, $f:ident) => {{
         let array = $array.as_any().downcast_ref::<$array_ty>().unwrap();
@@ -340,6 +532,11 @@ macro_rules! with_match_primitive_type {(
 pub fn max(array: &dyn Array) -> Result<Box<dyn Scalar>> {
     Ok(match array.data_type().to_physical_type() {
         PhysicalType::Boolean => dyn_generic!(BooleanArray, BooleanScalar, array, max_boolean),
+        PhysicalType::Primitive(PrimitiveType::Float16) => {
+            let data_type = array.data_type().clone();
+            let array = array.as_any().downcast_ref().unwrap();
+            Box::new(PrimitiveScalar::new(data_type, max_f16(array)))
+        }
         PhysicalType::Primitive(primitive) => with_match_primitive_type!(primitive, |$T| {
             let data_type = array.data_type().clone();
             let array = array.as_any().downcast_ref().unwrap();
@@ -351,7 +548,7 @@ pub fn max(array: &dyn Array) -> Result<Box<dyn Scalar>> {
             dyn_generic!(BinaryArray<i32>, BinaryScalar<i32>, array, max_binary)
         }
         PhysicalType::LargeBinary => {
-            dyn_generic!(BinaryArray<i64>, BinaryScalar<i64>, array, min_binary)
+            dyn_generic!(BinaryArray<i64>, BinaryScalar<i64>, array, max_binary)
         }
         _ => {
             return Err(Error::InvalidArgumentError(format!(
@@ -368,6 +565,11 @@ pub fn max(array: &dyn Array) -> Result<Box<dyn Scalar>> {
 pub fn min(array: &dyn Array) -> Result<Box<dyn Scalar>> {
     Ok(match array.data_type().to_physical_type() {
         PhysicalType::Boolean => dyn_generic!(BooleanArray, BooleanScalar, array, min_boolean),
+        PhysicalType::Primitive(PrimitiveType::Float16) => {
+            let data_type = array.data_type().clone();
+            let array = array.as_any().downcast_ref().unwrap();
+            Box::new(PrimitiveScalar::new(data_type, min_f16(array)))
+        }
         PhysicalType::Primitive(primitive) => with_match_primitive_type!(primitive, |$T| {
             let data_type = array.data_type().clone();
             let array = array.as_any().downcast_ref().unwrap();
@@ -390,6 +592,77 @@ pub fn min(array: &dyn Array) -> Result<Box<dyn Scalar>> {
     })
 }
 
+macro_rules! dyn_generic_min_max {
+    ($array_ty:ty, $scalar_ty:ty, $array:expr, $f:ident) => {{
+        let array = $array.as_any().downcast_ref::<$array_ty>().unwrap();
+        let (min, max) = $f(array).unzip();
+        (
+            Box::new(<$scalar_ty>::new(min)) as Box<dyn Scalar>,
+            Box::new(<$scalar_ty>::new(max)) as Box<dyn Scalar>,
+        )
+    }};
+}
+
+/// Returns the minimum and maximum of [`Array`] in a single pass over its values, which is
+/// equivalent to, and faster than, calling [`min`] and [`max`].
+/// The scalars are null when all elements are null.
+/// # Error
+/// Errors iff the type does not support this operation.
+/// # Example
+/// ```
+/// use arrow2::array::Int32Array;
+/// use arrow2::compute::aggregate::min_max;
+/// use arrow2::scalar::PrimitiveScalar;
+///
+/// let array = Int32Array::from(&[Some(5), None, Some(-1), Some(3)]);
+/// let (min, max) = min_max(&array).unwrap();
+/// assert_eq!(min.as_ref(), &PrimitiveScalar::from(Some(-1i32)) as &dyn arrow2::scalar::Scalar);
+/// assert_eq!(max.as_ref(), &PrimitiveScalar::from(Some(5i32)) as &dyn arrow2::scalar::Scalar);
+/// ```
+pub fn min_max(array: &dyn Array) -> Result<(Box<dyn Scalar>, Box<dyn Scalar>)> {
+    Ok(match array.data_type().to_physical_type() {
+        PhysicalType::Boolean => {
+            dyn_generic_min_max!(BooleanArray, BooleanScalar, array, min_max_boolean)
+        }
+        PhysicalType::Primitive(PrimitiveType::Float16) => {
+            let data_type = array.data_type().clone();
+            let array = array.as_any().downcast_ref().unwrap();
+            let (min, max) = min_max_f16(array).unzip();
+            (
+                Box::new(PrimitiveScalar::new(data_type.clone(), min)),
+                Box::new(PrimitiveScalar::new(data_type, max)),
+            )
+        }
+        PhysicalType::Primitive(primitive) => with_match_primitive_type!(primitive, |$T| {
+            let data_type = array.data_type().clone();
+            let array = array.as_any().downcast_ref().unwrap();
+            let (min, max) = min_max_primitive::<$T>(array).unzip();
+            (
+                Box::new(PrimitiveScalar::<$T>::new(data_type.clone(), min)),
+                Box::new(PrimitiveScalar::<$T>::new(data_type, max)),
+            )
+        }),
+        PhysicalType::Utf8 => {
+            dyn_generic_min_max!(Utf8Array<i32>, Utf8Scalar<i32>, array, min_max_string)
+        }
+        PhysicalType::LargeUtf8 => {
+            dyn_generic_min_max!(Utf8Array<i64>, Utf8Scalar<i64>, array, min_max_string)
+        }
+        PhysicalType::Binary => {
+            dyn_generic_min_max!(BinaryArray<i32>, BinaryScalar<i32>, array, min_max_binary)
+        }
+        PhysicalType::LargeBinary => {
+            dyn_generic_min_max!(BinaryArray<i64>, BinaryScalar<i64>, array, min_max_binary)
+        }
+        _ => {
+            return Err(Error::InvalidArgumentError(format!(
+                "The `min_max` operator does not support type `{:?}`",
+                array.data_type(),
+            )))
+        }
+    })
+}
+
 /// Whether [`min`] supports `data_type`
 pub fn can_min(data_type: &DataType) -> bool {
     let physical = data_type.to_physical_type();
@@ -397,7 +670,16 @@ pub fn can_min(data_type: &DataType) -> bool {
         use PrimitiveType::*;
         matches!(
             primitive,
-            Int8 | Int16 | Int64 | Int128 | UInt8 | UInt16 | UInt32 | UInt64 | Float32 | Float64
+            Int8 | Int16
+                | Int64
+                | Int128
+                | UInt8
+                | UInt16
+                | UInt32
+                | UInt64
+                | Float16
+                | Float32
+                | Float64
         )
     } else {
         use PhysicalType::*;
@@ -9,7 +9,43 @@ mod min_max;
 #[cfg(feature = "compute_aggregate")]
 pub use min_max::*;
 
+#[cfg(feature = "compute_aggregate")]
+mod count;
+#[cfg(feature = "compute_aggregate")]
+pub use count::*;
+
+#[cfg(feature = "compute_aggregate")]
+mod segmented;
+#[cfg(feature = "compute_aggregate")]
+pub use segmented::*;
+
+#[cfg(feature = "compute_aggregate")]
+pub mod approx;
+
+#[cfg(feature = "compute_aggregate")]
+mod statistics;
+#[cfg(feature = "compute_aggregate")]
+pub use statistics::*;
+
 mod memory;
 pub use memory::*;
 #[cfg(feature = "compute_aggregate")]
 mod simd;
+
+/// Returns `array` as `f32`, over which the aggregations of `f16` are computed.
+#[cfg(feature = "compute_aggregate")]
+fn f16_to_f32(
+    array: &crate::array::PrimitiveArray<crate::types::f16>,
+) -> crate::array::PrimitiveArray<f32> {
+    // `f16::to_f32` is branchless and thus vectorized
+    let values = array
+        .values()
+        .iter()
+        .map(|x| x.to_f32())
+        .collect::<Vec<_>>();
+    crate::array::PrimitiveArray::new(
+        crate::datatypes::DataType::Float32,
+        values.into(),
+        array.validity().cloned(),
+    )
+}
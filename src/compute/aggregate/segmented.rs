@@ -0,0 +1,163 @@
+use crate::array::PrimitiveArray;
+use crate::bitmap::MutableBitmap;
+use crate::error::{Error, Result};
+use crate::types::NativeType;
+
+/// A [`NativeType`] that can be summed by [`segmented_sum`].
+pub trait SegmentedSum: NativeType {
+    /// Returns `self + other`, or `None` if the sum overflows
+    fn checked_sum(self, other: Self) -> Option<Self>;
+}
+
+macro_rules! integer_segmented_sum {
+    ($($type:ty),*) => {$(
+        impl SegmentedSum for $type {
+            #[inline]
+            fn checked_sum(self, other: Self) -> Option<Self> {
+                self.checked_add(other)
+            }
+        }
+    )*};
+}
+
+integer_segmented_sum!(i8, i16, i32, i64, i128, u8, u16, u32, u64);
+
+macro_rules! float_segmented_sum {
+    ($($type:ty),*) => {$(
+        impl SegmentedSum for $type {
+            /// Floats do not overflow: their sum is infinite instead
+            #[inline]
+            fn checked_sum(self, other: Self) -> Option<Self> {
+                Some(self + other)
+            }
+        }
+    )*};
+}
+
+float_segmented_sum!(f32, f64);
+
+/// Reduces every valid value of `values` into the group given by `group_ids` via `op`.
+/// Slots whose value or group id is null are ignored.
+fn segmented_reduce<T, F>(
+    values: &PrimitiveArray<T>,
+    group_ids: &PrimitiveArray<u32>,
+    num_groups: usize,
+    op: F,
+) -> Result<PrimitiveArray<T>>
+where
+    T: NativeType,
+    F: Fn(T, T) -> Result<T>,
+{
+    if values.len() != group_ids.len() {
+        return Err(Error::InvalidArgumentError(format!(
+            "Segmented aggregations require values and group ids of equal length, but they have {} and {} slots",
+            values.len(),
+            group_ids.len()
+        )));
+    }
+
+    let mut reduced = vec![T::default(); num_groups];
+    let mut validity = MutableBitmap::from_len_zeroed(num_groups);
+    for (value, group) in values.iter().zip(group_ids.iter()) {
+        let (value, group) = match (value, group) {
+            (Some(value), Some(group)) => (*value, *group as usize),
+            _ => continue,
+        };
+        if group >= num_groups {
+            return Err(Error::InvalidArgumentError(format!(
+                "The group id {group} is out of bounds for {num_groups} groups"
+            )));
+        }
+        if validity.get(group) {
+            reduced[group] = op(reduced[group], value)?;
+        } else {
+            reduced[group] = value;
+            validity.set(group, true);
+        }
+    }
+
+    Ok(PrimitiveArray::new(
+        values.data_type().clone(),
+        reduced.into(),
+        validity.into(),
+    ))
+}
+
+/// Returns the sum of the values of each of the `num_groups` groups, where `group_ids[i]` is
+/// the group of `values[i]`. Nulls in `values` and `group_ids` are ignored, and groups without
+/// valid values are null.
+///
+/// This allows the grouping to be owned by e.g. an external hash table, that assigns a group
+/// id to every slot, while the reduction is performed here in a single pass over `values`.
+/// Integer sums are checked, while float sums follow IEEE 754 and are infinite on overflow.
+/// # Errors
+/// Errors iff `values` and `group_ids` have different lengths, any group id is
+/// equal or larger than `num_groups`, or the integer sum of any group overflows
+/// (with [`Error::Overflow`]).
+/// # Example
+/// ```
+/// use arrow2::array::{Int32Array, UInt32Array};
+/// use arrow2::compute::aggregate::segmented_sum;
+///
+/// let values = Int32Array::from([Some(1), Some(2), None, Some(4)]);
+/// let group_ids = UInt32Array::from_slice([0, 1, 0, 0]);
+///
+/// let result = segmented_sum(&values, &group_ids, 3).unwrap();
+/// assert_eq!(result, Int32Array::from([Some(5), Some(2), None]));
+/// ```
+pub fn segmented_sum<T>(
+    values: &PrimitiveArray<T>,
+    group_ids: &PrimitiveArray<u32>,
+    num_groups: usize,
+) -> Result<PrimitiveArray<T>>
+where
+    T: SegmentedSum,
+{
+    segmented_reduce(values, group_ids, num_groups, |acc, value| {
+        acc.checked_sum(value).ok_or(Error::Overflow)
+    })
+}
+
+/// Returns the minimum value of each of the `num_groups` groups, where `group_ids[i]` is
+/// the group of `values[i]`. Nulls in `values` and `group_ids` are ignored, and groups without
+/// valid values are null.
+///
+/// Values are compared via [`PartialOrd`], so the result of a group with values that are not
+/// comparable (e.g. `NaN`) depends on the order of its values.
+/// # Errors
+/// Errors iff `values` and `group_ids` have different lengths, or any group id is
+/// equal or larger than `num_groups`.
+pub fn segmented_min<T>(
+    values: &PrimitiveArray<T>,
+    group_ids: &PrimitiveArray<u32>,
+    num_groups: usize,
+) -> Result<PrimitiveArray<T>>
+where
+    T: NativeType + PartialOrd,
+{
+    segmented_reduce(values, group_ids, num_groups, |acc, value| {
+        Ok(if value < acc { value } else { acc })
+    })
+}
+
+/// Returns the maximum value of each of the `num_groups` groups, where `group_ids[i]` is
+/// the group of `values[i]`. Nulls in `values` and `group_ids` are ignored, and groups without
+/// valid values are null.
+///
+/// Values are compared via [`PartialOrd`], so the result of a group with values that are not
+/// comparable (e.g. `NaN`) depends on the order of its values.
+/// # Errors
+/// Errors iff `values` and `group_ids` have different lengths, or any group id is
+/// equal or larger than `num_groups`.
+pub fn segmented_max<T>(
+    values: &PrimitiveArray<T>,
+    group_ids: &PrimitiveArray<u32>,
+    num_groups: usize,
+) -> Result<PrimitiveArray<T>>
+where
+    T: NativeType + PartialOrd,
+{
+    segmented_reduce(values, group_ids, num_groups, |acc, value| {
+        Ok(if value > acc { value } else { acc })
+    })
+}
@@ -0,0 +1,69 @@
+use crate::array::Array;
+use crate::chunk::Chunk;
+use crate::scalar::Scalar;
+
+use super::approx::HyperLogLog;
+use super::min_max;
+
+/// Statistics of a column, as stored by writers in the metadata of files (e.g. Parquet's
+/// column chunks) to allow readers to skip data.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ColumnStatistics {
+    /// The minimum value, or `None` when the type of the column does not support [`min_max`].
+    /// The scalar is null when all values are null.
+    pub min: Option<Box<dyn Scalar>>,
+    /// The maximum value, or `None` when the type of the column does not support [`min_max`].
+    /// The scalar is null when all values are null.
+    pub max: Option<Box<dyn Scalar>>,
+    /// The number of null values
+    pub null_count: usize,
+    /// The estimated number of distinct non-null values, or `None` when the type of the
+    /// column is not supported by [`HyperLogLog`]
+    pub distinct_count: Option<u64>,
+}
+
+impl ColumnStatistics {
+    /// Computes the statistics of `array`, reading its values once for the minimum and
+    /// maximum and once for the distinct count.
+    pub fn new(array: &dyn Array) -> Self {
+        let (min, max) = min_max(array).ok().unzip();
+
+        let mut sketch = HyperLogLog::default();
+        let distinct_count = sketch.update(array).ok().map(|_| sketch.estimate());
+
+        Self {
+            min,
+            max,
+            null_count: array.null_count(),
+            distinct_count,
+        }
+    }
+}
+
+/// Returns the [`ColumnStatistics`] of every column of `chunk`.
+/// # Example
+/// ```
+/// use arrow2::array::{Int32Array, Utf8Array};
+/// use arrow2::chunk::Chunk;
+/// use arrow2::compute::aggregate::chunk_statistics;
+/// use arrow2::scalar::{PrimitiveScalar, Scalar};
+///
+/// let chunk = Chunk::new(vec![
+///     Int32Array::from([Some(3), None, Some(1), Some(3)]).boxed(),
+///     Utf8Array::<i32>::from([Some("a"), Some("b"), None, None]).boxed(),
+/// ]);
+///
+/// let statistics = chunk_statistics(&chunk);
+/// let expected: Box<dyn Scalar> = Box::new(PrimitiveScalar::from(Some(1i32)));
+/// assert_eq!(statistics[0].min, Some(expected));
+/// assert_eq!(statistics[0].null_count, 1);
+/// assert_eq!(statistics[0].distinct_count, Some(2));
+/// assert_eq!(statistics[1].null_count, 2);
+/// ```
+pub fn chunk_statistics<A: AsRef<dyn Array>>(chunk: &Chunk<A>) -> Vec<ColumnStatistics> {
+    chunk
+        .arrays()
+        .iter()
+        .map(|array| ColumnStatistics::new(array.as_ref()))
+        .collect()
+}
@@ -1,13 +1,14 @@
 use std::ops::Add;
 
 use multiversion::multiversion;
+use num_traits::Float;
 
 use crate::bitmap::utils::{BitChunkIterExact, BitChunksExact};
 use crate::datatypes::{DataType, PhysicalType, PrimitiveType};
 use crate::error::{Error, Result};
 use crate::scalar::*;
 use crate::types::simd::*;
-use crate::types::NativeType;
+use crate::types::{f16, NativeType};
 use crate::{
     array::{Array, PrimitiveArray},
     bitmap::Bitmap,
@@ -21,6 +22,7 @@ pub trait Sum<T> {
 }
 
 #[multiversion]
+#[clone(target = "x86_64+avx2")]
 #[clone(target = "x86_64+avx")]
 fn nonnull_sum<T>(values: &[T]) -> T
 where
@@ -40,6 +42,7 @@ where
 /// # Panics
 /// iff `values.len() != bitmap.len()` or the operation overflows.
 #[multiversion]
+#[clone(target = "x86_64+avx2")]
 #[clone(target = "x86_64+avx")]
 fn null_sum_impl<T, I>(values: &[T], mut validity_masks: I) -> T
 where
@@ -104,13 +107,138 @@ where
     }
 }
 
+/// Returns the sum of values in `array`, computed in `f32` and rounded to the nearest `f16`.
+/// Returns `None` if the array is empty or only contains null values.
+pub fn sum_f16(array: &PrimitiveArray<f16>) -> Option<f16> {
+    sum_primitive(&super::f16_to_f32(array)).map(f16::from_f32)
+}
+
+/// The algorithm used to sum floating point numbers (see [`sum_float`] and [`sum_with`]).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Summation {
+    /// Accumulates the values one after the other (in SIMD lanes), as [`sum_primitive`].
+    /// The error grows linearly with the number of values.
+    #[default]
+    Naive,
+    /// Accumulates the values and the rounding error of each addition (second-order
+    /// Kahan–Babuška summation). The error does not grow with the number of values, at
+    /// roughly 8 times the cost of [`Summation::Naive`].
+    Compensated,
+    /// Recursively sums the two halves of the values (pairwise summation). The error grows
+    /// logarithmically with the number of values, at roughly the cost of [`Summation::Naive`].
+    Pairwise,
+}
+
+/// The number of values below which pairwise summation sums naively.
+const PAIRWISE_BLOCK_SIZE: usize = 128;
+
+/// Returns `lhs + rhs` and the rounding error of the addition.
+#[inline]
+fn two_sum<T: Float>(lhs: T, rhs: T) -> (T, T) {
+    let sum = lhs + rhs;
+    // the error is exact when computed from the larger of both
+    let error = if lhs.abs() >= rhs.abs() {
+        (lhs - sum) + rhs
+    } else {
+        (rhs - sum) + lhs
+    };
+    (sum, error)
+}
+
+fn compensated_sum<T: Float, I: Iterator<Item = T>>(values: I) -> T {
+    let mut sum = T::zero();
+    // the errors are accumulated themselves compensated, since their sum loses precision
+    // over many values
+    let mut compensation = T::zero();
+    let mut second_compensation = T::zero();
+    for value in values {
+        let (new_sum, error) = two_sum(sum, value);
+        sum = new_sum;
+        if !sum.is_finite() {
+            // the errors of infinities and NaNs are NaN: the sum stays non-finite without them
+            continue;
+        }
+        let (new_compensation, error) = two_sum(compensation, error);
+        compensation = new_compensation;
+        second_compensation = second_compensation + error;
+    }
+    if !sum.is_finite() {
+        return sum;
+    }
+    sum + (compensation + second_compensation)
+}
+
+/// Sums the valid `values`, where the value at `values[i]` is valid iff `is_valid(offset + i)`.
+fn pairwise_sum<T: Float, F: Fn(usize) -> bool>(values: &[T], is_valid: &F, offset: usize) -> T {
+    if values.len() <= PAIRWISE_BLOCK_SIZE {
+        values
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| is_valid(offset + i))
+            .fold(T::zero(), |acc, (_, value)| acc + *value)
+    } else {
+        let middle = values.len() / 2;
+        let (lhs, rhs) = values.split_at(middle);
+        pairwise_sum(lhs, is_valid, offset) + pairwise_sum(rhs, is_valid, offset + middle)
+    }
+}
+
+/// Returns the sum of values in `array`, computed with `summation`.
+///
+/// Returns `None` if the array is empty or only contains null values.
+/// # Example
+/// ```
+/// use arrow2::array::Float32Array;
+/// use arrow2::compute::aggregate::{sum_float, Summation};
+///
+/// // 1.0 is lost when added to 1e8 in f32
+/// let array = Float32Array::from_slice([1e8, 1.0, -1e8]);
+/// assert_eq!(sum_float(&array, Summation::Naive), Some(0.0));
+/// assert_eq!(sum_float(&array, Summation::Compensated), Some(1.0));
+/// ```
+pub fn sum_float<T>(array: &PrimitiveArray<T>, summation: Summation) -> Option<T>
+where
+    T: NativeType + Float + Simd + Add<Output = T> + std::iter::Sum<T>,
+    T::Simd: Add<Output = T::Simd> + Sum<T>,
+{
+    if array.null_count() == array.len() {
+        return None;
+    }
+
+    let values = array.values().as_slice();
+    Some(match (summation, array.validity()) {
+        (Summation::Naive, _) => return sum_primitive(array),
+        (Summation::Compensated, None) => compensated_sum(values.iter().copied()),
+        (Summation::Compensated, Some(validity)) => compensated_sum(
+            values
+                .iter()
+                .zip(validity.iter())
+                .filter_map(|(value, is_valid)| is_valid.then_some(*value)),
+        ),
+        (Summation::Pairwise, None) => pairwise_sum(values, &|_| true, 0),
+        (Summation::Pairwise, Some(validity)) => {
+            // Safety: `validity.len() == values.len()`
+            pairwise_sum(values, &|i| unsafe { validity.get_bit_unchecked(i) }, 0)
+        }
+    })
+}
+
 /// Whether [`sum`] supports `data_type`
 pub fn can_sum(data_type: &DataType) -> bool {
     if let PhysicalType::Primitive(primitive) = data_type.to_physical_type() {
         use PrimitiveType::*;
         matches!(
             primitive,
-            Int8 | Int16 | Int64 | Int128 | UInt8 | UInt16 | UInt32 | UInt64 | Float32 | Float64
+            Int8 | Int16
+                | Int64
+                | Int128
+                | UInt8
+                | UInt16
+                | UInt32
+                | UInt64
+                | Float16
+                | Float32
+                | Float64
         )
     } else {
         false
@@ -147,6 +275,11 @@ macro_rules! with_match_primitive_type {(
 /// Errors iff the operation is not supported.
 pub fn sum(array: &dyn Array) -> Result<Box<dyn Scalar>> {
     Ok(match array.data_type().to_physical_type() {
+        PhysicalType::Primitive(PrimitiveType::Float16) => {
+            let data_type = array.data_type().clone();
+            let array = array.as_any().downcast_ref().unwrap();
+            Box::new(PrimitiveScalar::new(data_type, sum_f16(array)))
+        }
         PhysicalType::Primitive(primitive) => with_match_primitive_type!(primitive, |$T| {
             let data_type = array.data_type().clone();
             let array = array.as_any().downcast_ref().unwrap();
@@ -160,3 +293,35 @@ pub fn sum(array: &dyn Array) -> Result<Box<dyn Scalar>> {
         }
     })
 }
+
+/// Returns the sum of all elements in `array` as a [`Scalar`] of the same physical
+/// and logical types as `array`, summing floating point numbers with `summation`.
+///
+/// Integers are summed as in [`sum`], and `f16` are summed in `f32`.
+/// # Error
+/// Errors iff the operation is not supported.
+pub fn sum_with(array: &dyn Array, summation: Summation) -> Result<Box<dyn Scalar>> {
+    let data_type = array.data_type().clone();
+    Ok(match array.data_type().to_physical_type() {
+        PhysicalType::Primitive(PrimitiveType::Float16) => {
+            let array = super::f16_to_f32(array.as_any().downcast_ref().unwrap());
+            let sum = sum_float(&array, summation).map(f16::from_f32);
+            Box::new(PrimitiveScalar::new(data_type, sum))
+        }
+        PhysicalType::Primitive(PrimitiveType::Float32) => {
+            let array = array.as_any().downcast_ref().unwrap();
+            Box::new(PrimitiveScalar::new(
+                data_type,
+                sum_float::<f32>(array, summation),
+            ))
+        }
+        PhysicalType::Primitive(PrimitiveType::Float64) => {
+            let array = array.as_any().downcast_ref().unwrap();
+            Box::new(PrimitiveScalar::new(
+                data_type,
+                sum_float::<f64>(array, summation),
+            ))
+        }
+        _ => return sum(array),
+    })
+}
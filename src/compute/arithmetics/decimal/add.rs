@@ -1,4 +1,6 @@
 //! Defines the addition arithmetic kernels for [`PrimitiveArray`] representing decimals.
+use alloc::{string::ToString, vec::Vec};
+
 use crate::{
     array::PrimitiveArray,
     compute::{
@@ -1,6 +1,8 @@
 //! Defines the division arithmetic kernels for Decimal
 //! `PrimitiveArrays`.
 
+use alloc::{string::ToString, vec::Vec};
+
 use crate::{
     array::PrimitiveArray,
     compute::{
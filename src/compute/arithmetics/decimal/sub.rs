@@ -1,5 +1,7 @@
 //! Defines the subtract arithmetic kernels for Decimal `PrimitiveArrays`.
 
+use alloc::{string::ToString, vec::Vec};
+
 use crate::{
     array::PrimitiveArray,
     compute::{
@@ -0,0 +1,157 @@
+//! Element-wise arithmetics of [`FixedSizeListArray`]s of primitive values, e.g. of
+//! embedding vectors.
+//!
+//! Each slot is a vector of [`FixedSizeListArray::size`] values. A slot of the result is null
+//! when the slot of any of the inputs is null.
+use alloc::vec::Vec;
+use core::ops::{Add, Div, Mul, Sub};
+
+use num_traits::Zero;
+
+use crate::{
+    array::{Array, FixedSizeListArray, PrimitiveArray},
+    compute::utils::combine_validities,
+    error::{Error, Result},
+};
+
+use super::basic::{self, NativeArithmetics};
+
+fn check(lhs: &FixedSizeListArray, rhs: &FixedSizeListArray) -> Result<()> {
+    if lhs.size() != rhs.size() || lhs.len() != rhs.len() {
+        return Err(Error::InvalidArgumentError(format!(
+            "Arithmetics requires FixedSizeListArrays of equal sizes and lengths, but got sizes {} and {} and lengths {} and {}",
+            lhs.size(),
+            rhs.size(),
+            lhs.len(),
+            rhs.len()
+        )));
+    }
+    Ok(())
+}
+
+fn binary<T, F>(
+    lhs: &FixedSizeListArray,
+    rhs: &FixedSizeListArray,
+    op: F,
+) -> Result<FixedSizeListArray>
+where
+    T: NativeArithmetics,
+    F: Fn(&PrimitiveArray<T>, &PrimitiveArray<T>) -> PrimitiveArray<T>,
+{
+    check(lhs, rhs)?;
+    let values = op(lhs.primitive_values()?, rhs.primitive_values()?);
+    let validity = combine_validities(lhs.validity(), rhs.validity());
+    FixedSizeListArray::try_new(lhs.data_type().clone(), values.boxed(), validity)
+}
+
+/// Adds two [`FixedSizeListArray`]s of `T` element-wise.
+/// # Errors
+/// Errors iff the arrays have different sizes or lengths, or their values are not of type `T`.
+/// # Panic
+/// Panics iff the addition overflows.
+pub fn add<T>(lhs: &FixedSizeListArray, rhs: &FixedSizeListArray) -> Result<FixedSizeListArray>
+where
+    T: NativeArithmetics + Add<Output = T>,
+{
+    binary::<T, _>(lhs, rhs, basic::add)
+}
+
+/// Subtracts two [`FixedSizeListArray`]s of `T` element-wise.
+/// # Errors
+/// Errors iff the arrays have different sizes or lengths, or their values are not of type `T`.
+/// # Panic
+/// Panics iff the subtraction overflows.
+pub fn sub<T>(lhs: &FixedSizeListArray, rhs: &FixedSizeListArray) -> Result<FixedSizeListArray>
+where
+    T: NativeArithmetics + Sub<Output = T>,
+{
+    binary::<T, _>(lhs, rhs, basic::sub)
+}
+
+/// Multiplies two [`FixedSizeListArray`]s of `T` element-wise.
+/// # Errors
+/// Errors iff the arrays have different sizes or lengths, or their values are not of type `T`.
+/// # Panic
+/// Panics iff the multiplication overflows.
+pub fn mul<T>(lhs: &FixedSizeListArray, rhs: &FixedSizeListArray) -> Result<FixedSizeListArray>
+where
+    T: NativeArithmetics + Mul<Output = T>,
+{
+    binary::<T, _>(lhs, rhs, basic::mul)
+}
+
+/// Divides two [`FixedSizeListArray`]s of `T` element-wise.
+/// # Errors
+/// Errors iff the arrays have different sizes or lengths, or their values are not of type `T`.
+/// # Panic
+/// Panics iff an integer is divided by zero.
+pub fn div<T>(lhs: &FixedSizeListArray, rhs: &FixedSizeListArray) -> Result<FixedSizeListArray>
+where
+    T: NativeArithmetics + Div<Output = T>,
+{
+    binary::<T, _>(lhs, rhs, basic::div)
+}
+
+/// Multiplies every value of a [`FixedSizeListArray`] of `T` by `rhs`, e.g. to scale vectors.
+/// # Errors
+/// Errors iff the values of `lhs` are not of type `T`.
+/// # Panic
+/// Panics iff the multiplication overflows.
+pub fn mul_scalar<T>(lhs: &FixedSizeListArray, rhs: &T) -> Result<FixedSizeListArray>
+where
+    T: NativeArithmetics + Mul<Output = T>,
+{
+    let values = basic::mul_scalar(lhs.primitive_values::<T>()?, rhs);
+    FixedSizeListArray::try_new(
+        lhs.data_type().clone(),
+        values.boxed(),
+        lhs.validity().cloned(),
+    )
+}
+
+/// Returns the dot product of each pair of slots of two [`FixedSizeListArray`]s of `T`,
+/// e.g. the similarity scores of two sets of (normalized) embedding vectors.
+///
+/// A slot of the result is null when either slot, or any of their values, is null.
+/// # Errors
+/// Errors iff the arrays have different sizes or lengths, or their values are not of type `T`.
+/// # Panic
+/// Panics iff the products or their sum overflow.
+/// # Example
+/// ```
+/// use arrow2::array::{FixedSizeListArray, Float32Array};
+/// use arrow2::compute::arithmetics::fixed_size_list::dot;
+/// use arrow2::datatypes::DataType;
+///
+/// let data_type = FixedSizeListArray::default_datatype(DataType::Float32, 2);
+/// let values = Float32Array::from_slice([1.0, 2.0, 3.0, 4.0]).boxed();
+/// let lhs = FixedSizeListArray::new(data_type.clone(), values, None);
+/// let values = Float32Array::from_slice([1.0, 0.0, 0.5, 0.5]).boxed();
+/// let rhs = FixedSizeListArray::new(data_type, values, None);
+///
+/// let result = dot::<f32>(&lhs, &rhs).unwrap();
+/// assert_eq!(result, Float32Array::from_slice([1.0, 3.5]));
+/// ```
+pub fn dot<T>(lhs: &FixedSizeListArray, rhs: &FixedSizeListArray) -> Result<PrimitiveArray<T>>
+where
+    T: NativeArithmetics + Add<Output = T> + Mul<Output = T> + Zero,
+{
+    check(lhs, rhs)?;
+    let lhs_values = lhs.primitive_values::<T>()?;
+    let rhs_values = rhs.primitive_values::<T>()?;
+
+    let size = lhs.size();
+    let values = lhs_values
+        .values()
+        .chunks_exact(size)
+        .zip(rhs_values.values().chunks_exact(size))
+        .map(|(lhs, rhs)| {
+            lhs.iter()
+                .zip(rhs.iter())
+                .fold(T::zero(), |acc, (lhs, rhs)| acc + *lhs * *rhs)
+        })
+        .collect::<Vec<_>>();
+
+    let validity = combine_validities(lhs.valid_rows().as_ref(), rhs.valid_rows().as_ref());
+    PrimitiveArray::try_new(lhs_values.data_type().clone(), values.into(), validity)
+}
@@ -14,14 +14,17 @@
 #[forbid(unsafe_code)]
 pub mod basic;
 pub mod decimal;
+pub mod fixed_size_list;
 pub mod time;
 
+use alloc::boxed::Box;
+
 use crate::{
     array::{Array, DictionaryArray, PrimitiveArray},
     bitmap::Bitmap,
     datatypes::{DataType, IntervalUnit, TimeUnit},
     scalar::{PrimitiveScalar, Scalar},
-    types::NativeType,
+    types::{f16, NativeType},
 };
 
 fn binary_dyn<T: NativeType, F: Fn(&PrimitiveArray<T>, &PrimitiveArray<T>) -> PrimitiveArray<T>>(
@@ -37,11 +40,18 @@ fn binary_dyn<T: NativeType, F: Fn(&PrimitiveArray<T>, &PrimitiveArray<T>) -> Pr
 // Macro to create a `match` statement with dynamic dispatch to functions based on
 // the array's logical types
 macro_rules! arith {
-    ($lhs:expr, $rhs:expr, $op:tt $(, decimal = $op_decimal:tt )? $(, duration = $op_duration:tt )? $(, interval = $op_interval:tt )? $(, timestamp = $op_timestamp:tt )?) => {{
+    ($lhs:expr, $rhs:expr, $op:tt $(, decimal = $op_decimal:tt )? $(, duration = $op_duration:tt )? $(, interval = $op_interval:tt )? $(, timestamp = $op_timestamp:tt )? $(, durations = $op_durations:tt )?) => {{
         let lhs = $lhs;
         let rhs = $rhs;
         use DataType::*;
         match (lhs.data_type(), rhs.data_type()) {
+            $ (
+            (Duration(lhs_unit), Duration(rhs_unit)) if lhs_unit != rhs_unit => {
+                let lhs = lhs.as_any().downcast_ref().unwrap();
+                let rhs = rhs.as_any().downcast_ref().unwrap();
+                time::$op_durations(lhs, rhs).map(|x| Box::new(x) as Box<dyn Array>).unwrap()
+            }
+            )?
             (Int8, Int8) => binary_dyn::<i8, _>(lhs, rhs, basic::$op),
             (Int16, Int16) => binary_dyn::<i16, _>(lhs, rhs, basic::$op),
             (Int32, Int32) => binary_dyn::<i32, _>(lhs, rhs, basic::$op),
@@ -52,6 +62,7 @@ macro_rules! arith {
             (UInt16, UInt16) => binary_dyn::<u16, _>(lhs, rhs, basic::$op),
             (UInt32, UInt32) => binary_dyn::<u32, _>(lhs, rhs, basic::$op),
             (UInt64, UInt64) => binary_dyn::<u64, _>(lhs, rhs, basic::$op),
+            (Float16, Float16) => binary_dyn::<f16, _>(lhs, rhs, basic::$op),
             (Float32, Float32) => binary_dyn::<f32, _>(lhs, rhs, basic::$op),
             (Float64, Float64) => binary_dyn::<f64, _>(lhs, rhs, basic::$op),
             $ (
@@ -142,6 +153,7 @@ macro_rules! arith_scalar {
             (UInt16, UInt16) => binary_scalar_dyn::<u16, _>(lhs, rhs, basic::$op),
             (UInt32, UInt32) => binary_scalar_dyn::<u32, _>(lhs, rhs, basic::$op),
             (UInt64, UInt64) => binary_scalar_dyn::<u64, _>(lhs, rhs, basic::$op),
+            (Float16, Float16) => binary_scalar_dyn::<f16, _>(lhs, rhs, basic::$op),
             (Float32, Float32) => binary_scalar_dyn::<f32, _>(lhs, rhs, basic::$op),
             (Float64, Float64) => binary_scalar_dyn::<f64, _>(lhs, rhs, basic::$op),
             $ (
@@ -192,6 +204,8 @@ macro_rules! arith_scalar {
 }
 
 /// Adds two [`Array`]s.
+///
+/// Durations of different time units are added in the finest of the two units.
 /// # Panic
 /// This function panics iff
 /// * the operation is not supported for the logical types (use [`can_add`] to check)
@@ -203,7 +217,8 @@ pub fn add(lhs: &dyn Array, rhs: &dyn Array) -> Box<dyn Array> {
         rhs,
         add,
         duration = add_duration,
-        interval = add_interval
+        interval = add_interval,
+        durations = add_durations
     )
 }
 
@@ -236,6 +251,7 @@ pub fn can_add(lhs: &DataType, rhs: &DataType) -> bool {
             | (UInt16, UInt16)
             | (UInt32, UInt32)
             | (UInt64, UInt64)
+            | (Float16, Float16)
             | (Float64, Float64)
             | (Float32, Float32)
             | (Duration(_), Duration(_))
@@ -252,6 +268,8 @@ pub fn can_add(lhs: &DataType, rhs: &DataType) -> bool {
 }
 
 /// Subtracts two [`Array`]s.
+///
+/// Durations of different time units are subtracted in the finest of the two units.
 /// # Panic
 /// This function panics iff
 /// * the opertion is not supported for the logical types (use [`can_sub`] to check)
@@ -264,7 +282,8 @@ pub fn sub(lhs: &dyn Array, rhs: &dyn Array) -> Box<dyn Array> {
         sub,
         decimal = sub,
         duration = subtract_duration,
-        timestamp = subtract_timestamps
+        timestamp = subtract_timestamps,
+        durations = subtract_durations
     )
 }
 
@@ -297,6 +316,7 @@ pub fn can_sub(lhs: &DataType, rhs: &DataType) -> bool {
             | (UInt16, UInt16)
             | (UInt32, UInt32)
             | (UInt64, UInt64)
+            | (Float16, Float16)
             | (Float64, Float64)
             | (Float32, Float32)
             | (Duration(_), Duration(_))
@@ -342,6 +362,7 @@ pub fn can_mul(lhs: &DataType, rhs: &DataType) -> bool {
             | (UInt16, UInt16)
             | (UInt32, UInt32)
             | (UInt64, UInt64)
+            | (Float16, Float16)
             | (Float64, Float64)
             | (Float32, Float32)
             | (Decimal(_, _), Decimal(_, _))
@@ -392,6 +413,7 @@ pub fn can_rem(lhs: &DataType, rhs: &DataType) -> bool {
             | (UInt16, UInt16)
             | (UInt32, UInt32)
             | (UInt64, UInt64)
+            | (Float16, Float16)
             | (Float64, Float64)
             | (Float32, Float32)
     )
@@ -412,7 +434,8 @@ macro_rules! with_match_negatable {(
         Int256 => __with_ty__! { i256 },
         DaysMs => __with_ty__! { days_ms },
         MonthDayNano => __with_ty__! { months_days_ns },
-        UInt8 | UInt16 | UInt32 | UInt64 | Float16 => todo!(),
+        UInt8 | UInt16 | UInt32 | UInt64 => todo!(),
+        Float16 => __with_ty__! { f16 },
         Float32 => __with_ty__! { f32 },
         Float64 => __with_ty__! { f64 },
     }
@@ -460,6 +483,7 @@ pub fn can_neg(data_type: &DataType) -> bool {
             | Primitive(Int16)
             | Primitive(Int32)
             | Primitive(Int64)
+            | Primitive(Float16)
             | Primitive(Float64)
             | Primitive(Float32)
             | Primitive(DaysMs)
@@ -9,13 +9,14 @@
 //! would yield a value that is possibly a few seconds off from the true
 //! elapsed time.
 
-use std::ops::{Add, Sub};
+use alloc::string::ToString;
+use core::ops::{Add, Sub};
 
 use num_traits::AsPrimitive;
 
 use crate::{
-    array::PrimitiveArray,
-    compute::arity::{binary, unary},
+    array::{Array, PrimitiveArray},
+    compute::arity::{binary, binary_checked, unary, unary_checked},
     datatypes::{DataType, TimeUnit},
     error::{Error, Result},
     scalar::{PrimitiveScalar, Scalar},
@@ -432,3 +433,337 @@ pub fn add_interval_scalar(
         )),
     }
 }
+
+/// Returns the number of nanoseconds in a [`TimeUnit`].
+fn nanoseconds_in(time_unit: TimeUnit) -> i64 {
+    match time_unit {
+        TimeUnit::Second => 1_000_000_000,
+        TimeUnit::Millisecond => 1_000_000,
+        TimeUnit::Microsecond => 1_000,
+        TimeUnit::Nanosecond => 1,
+    }
+}
+
+/// Returns the finest of two [`TimeUnit`]s and the factors converting values of `lhs` and
+/// `rhs` to it.
+fn promote_time_units(lhs: TimeUnit, rhs: TimeUnit) -> (TimeUnit, i64, i64) {
+    let time_unit = if nanoseconds_in(lhs) < nanoseconds_in(rhs) {
+        lhs
+    } else {
+        rhs
+    };
+    (
+        time_unit,
+        nanoseconds_in(lhs) / nanoseconds_in(time_unit),
+        nanoseconds_in(rhs) / nanoseconds_in(time_unit),
+    )
+}
+
+/// Applies `op` to two [`DataType::Duration`] arrays after converting both to the finest of
+/// their time units.
+fn durations_op<F>(
+    lhs: &PrimitiveArray<i64>,
+    rhs: &PrimitiveArray<i64>,
+    op: F,
+) -> Result<PrimitiveArray<i64>>
+where
+    F: Fn(i64, i64) -> Option<i64>,
+{
+    let (lhs_unit, rhs_unit) = match (lhs.data_type(), rhs.data_type()) {
+        (DataType::Duration(lhs_unit), DataType::Duration(rhs_unit)) => (*lhs_unit, *rhs_unit),
+        _ => {
+            return Err(Error::InvalidArgumentError(
+                "Incorrect data type for the arguments".to_string(),
+            ))
+        }
+    };
+    let (time_unit, lhs_scale, rhs_scale) = promote_time_units(lhs_unit, rhs_unit);
+
+    Ok(binary_checked(
+        lhs,
+        rhs,
+        DataType::Duration(time_unit),
+        |a, b| op(a.checked_mul(lhs_scale)?, b.checked_mul(rhs_scale)?),
+    ))
+}
+
+/// Adds two [`DataType::Duration`] arrays of any time units. The result has the finest
+/// of the two time units; values that overflow it are null.
+/// # Error
+/// This function errors iff the arrays are not durations.
+/// # Examples
+/// ```
+/// use arrow2::compute::arithmetics::time::add_durations;
+/// use arrow2::array::PrimitiveArray;
+/// use arrow2::datatypes::{DataType, TimeUnit};
+///
+/// let seconds = PrimitiveArray::from([Some(1i64), None, Some(3)])
+///     .to(DataType::Duration(TimeUnit::Second));
+/// let milliseconds = PrimitiveArray::from([Some(500i64), Some(1), Some(-500)])
+///     .to(DataType::Duration(TimeUnit::Millisecond));
+///
+/// let result = add_durations(&seconds, &milliseconds).unwrap();
+/// let expected = PrimitiveArray::from([Some(1500i64), None, Some(2500)])
+///     .to(DataType::Duration(TimeUnit::Millisecond));
+/// assert_eq!(result, expected);
+/// ```
+pub fn add_durations(
+    lhs: &PrimitiveArray<i64>,
+    rhs: &PrimitiveArray<i64>,
+) -> Result<PrimitiveArray<i64>> {
+    durations_op(lhs, rhs, |a, b| a.checked_add(b))
+}
+
+/// Subtracts two [`DataType::Duration`] arrays of any time units. The result has the finest
+/// of the two time units; values that overflow it are null.
+/// # Error
+/// This function errors iff the arrays are not durations.
+pub fn subtract_durations(
+    lhs: &PrimitiveArray<i64>,
+    rhs: &PrimitiveArray<i64>,
+) -> Result<PrimitiveArray<i64>> {
+    durations_op(lhs, rhs, |a, b| a.checked_sub(b))
+}
+
+/// Calculates the difference between two [`DataType::Timestamp`] arrays of any time units as a
+/// [`DataType::Duration`] with the finest of the two time units. Unlike
+/// [`subtract_timestamps`], the values are converted with integer arithmetic, and differences
+/// that overflow are null. Timestamps are instants in UTC, and may thus have any timezone.
+/// # Error
+/// This function errors iff the arrays are not timestamps.
+/// # Examples
+/// ```
+/// use arrow2::compute::arithmetics::time::sub_timestamps;
+/// use arrow2::array::PrimitiveArray;
+/// use arrow2::datatypes::{DataType, TimeUnit};
+///
+/// let seconds = PrimitiveArray::from([Some(10i64), None, Some(i64::MAX)])
+///     .to(DataType::Timestamp(TimeUnit::Second, None));
+/// let milliseconds = PrimitiveArray::from([Some(1500i64), Some(1), Some(0)])
+///     .to(DataType::Timestamp(TimeUnit::Millisecond, Some("+01:00".to_string())));
+///
+/// let result = sub_timestamps(&seconds, &milliseconds).unwrap();
+/// let expected = PrimitiveArray::from([Some(8500i64), None, None])
+///     .to(DataType::Duration(TimeUnit::Millisecond));
+/// assert_eq!(result, expected);
+/// ```
+pub fn sub_timestamps(
+    lhs: &PrimitiveArray<i64>,
+    rhs: &PrimitiveArray<i64>,
+) -> Result<PrimitiveArray<i64>> {
+    let (lhs_unit, rhs_unit) = match (lhs.data_type(), rhs.data_type()) {
+        (DataType::Timestamp(lhs_unit, _), DataType::Timestamp(rhs_unit, _)) => {
+            (*lhs_unit, *rhs_unit)
+        }
+        _ => {
+            return Err(Error::InvalidArgumentError(
+                "sub_timestamps requires both arguments to be timestamps".to_string(),
+            ))
+        }
+    };
+    let (time_unit, lhs_scale, rhs_scale) = promote_time_units(lhs_unit, rhs_unit);
+
+    Ok(binary_checked(
+        lhs,
+        rhs,
+        DataType::Duration(time_unit),
+        |a, b| {
+            a.checked_mul(lhs_scale)?
+                .checked_sub(b.checked_mul(rhs_scale)?)
+        },
+    ))
+}
+
+/// Adds a [`DataType::Duration`] array to a [`DataType::Timestamp`] array of any time units.
+/// The result is a timestamp with the timezone of `timestamp` and the finest of the two time
+/// units. Unlike [`add_duration`], the values are converted with integer arithmetic, and
+/// timestamps that overflow are null.
+/// # Error
+/// This function errors iff `timestamp` is not a timestamp or `duration` is not a duration.
+/// # Examples
+/// ```
+/// use arrow2::compute::arithmetics::time::checked_add_duration;
+/// use arrow2::array::PrimitiveArray;
+/// use arrow2::datatypes::{DataType, TimeUnit};
+///
+/// let timestamp = PrimitiveArray::from([Some(1i64), None, Some(i64::MAX)])
+///     .to(DataType::Timestamp(TimeUnit::Second, Some("UTC".to_string())));
+/// let duration = PrimitiveArray::from([Some(-1i64), Some(1), Some(1)])
+///     .to(DataType::Duration(TimeUnit::Microsecond));
+///
+/// let result = checked_add_duration(&timestamp, &duration).unwrap();
+/// let expected = PrimitiveArray::from([Some(999_999i64), None, None])
+///     .to(DataType::Timestamp(TimeUnit::Microsecond, Some("UTC".to_string())));
+/// assert_eq!(result, expected);
+/// ```
+pub fn checked_add_duration(
+    timestamp: &PrimitiveArray<i64>,
+    duration: &PrimitiveArray<i64>,
+) -> Result<PrimitiveArray<i64>> {
+    let (timestamp_unit, timezone, duration_unit) =
+        match (timestamp.data_type(), duration.data_type()) {
+            (DataType::Timestamp(timestamp_unit, timezone), DataType::Duration(duration_unit)) => {
+                (*timestamp_unit, timezone.clone(), *duration_unit)
+            }
+            _ => {
+                return Err(Error::InvalidArgumentError(
+                    "checked_add_duration requires a timestamp and a duration".to_string(),
+                ))
+            }
+        };
+    let (time_unit, lhs_scale, rhs_scale) = promote_time_units(timestamp_unit, duration_unit);
+
+    Ok(binary_checked(
+        timestamp,
+        duration,
+        DataType::Timestamp(time_unit, timezone),
+        |a, b| {
+            a.checked_mul(lhs_scale)?
+                .checked_add(b.checked_mul(rhs_scale)?)
+        },
+    ))
+}
+
+/// Returns the values of a [`DataType::Date32`] or [`DataType::Date64`] array in milliseconds.
+fn date_to_milliseconds(array: &dyn Array) -> Result<PrimitiveArray<i64>> {
+    match array.data_type() {
+        DataType::Date32 => {
+            let array = array
+                .as_any()
+                .downcast_ref::<PrimitiveArray<i32>>()
+                .unwrap();
+            // an `i32` of days always fits in an `i64` of milliseconds
+            Ok(unary(
+                array,
+                |x| x as i64 * temporal_conversions::MILLISECONDS_IN_DAY,
+                DataType::Date64,
+            ))
+        }
+        DataType::Date64 => Ok(array
+            .as_any()
+            .downcast_ref::<PrimitiveArray<i64>>()
+            .unwrap()
+            .clone()),
+        _ => Err(Error::InvalidArgumentError(
+            "date_diff requires both arguments to be dates".to_string(),
+        )),
+    }
+}
+
+/// Calculates the difference between two [`DataType::Date32`] or [`DataType::Date64`] arrays
+/// (in any combination) as a [`DataType::Duration`] of `time_unit`. Differences are truncated
+/// towards zero when `time_unit` is coarser than the dates, and differences that overflow are
+/// null.
+/// # Error
+/// This function errors iff the arrays are not dates.
+/// # Examples
+/// ```
+/// use arrow2::compute::arithmetics::time::date_diff;
+/// use arrow2::array::PrimitiveArray;
+/// use arrow2::datatypes::{DataType, TimeUnit};
+///
+/// let days = PrimitiveArray::from([Some(1i32), None, Some(0)]).to(DataType::Date32);
+/// let milliseconds = PrimitiveArray::from([Some(1500i64), Some(0), Some(1500)])
+///     .to(DataType::Date64);
+///
+/// let result = date_diff(&days, &milliseconds, TimeUnit::Second).unwrap();
+/// let expected = PrimitiveArray::from([Some(86_398i64), None, Some(-1)])
+///     .to(DataType::Duration(TimeUnit::Second));
+/// assert_eq!(result, expected);
+/// ```
+pub fn date_diff(
+    lhs: &dyn Array,
+    rhs: &dyn Array,
+    time_unit: TimeUnit,
+) -> Result<PrimitiveArray<i64>> {
+    let lhs = date_to_milliseconds(lhs)?;
+    let rhs = date_to_milliseconds(rhs)?;
+
+    let millisecond = nanoseconds_in(TimeUnit::Millisecond);
+    let unit = nanoseconds_in(time_unit);
+    Ok(binary_checked(
+        &lhs,
+        &rhs,
+        DataType::Duration(time_unit),
+        |a, b| {
+            let difference = a.checked_sub(b)?;
+            if unit > millisecond {
+                Some(difference / (unit / millisecond))
+            } else {
+                difference.checked_mul(millisecond / unit)
+            }
+        },
+    ))
+}
+
+/// Normalizes an interval such that its nanoseconds are less than a day and its days are
+/// less than a month (of 30 days), and all its components have the same sign, like
+/// postgres' `justify_interval`. Returns `None` if the months overflow.
+fn normalize_interval_value(interval: months_days_ns) -> Option<months_days_ns> {
+    const NANOSECONDS_IN_DAY: i64 =
+        temporal_conversions::SECONDS_IN_DAY * temporal_conversions::NANOSECONDS;
+
+    let mut ns = interval.ns() % NANOSECONDS_IN_DAY;
+    let mut days = interval.days() as i64 + interval.ns() / NANOSECONDS_IN_DAY;
+    let mut months = interval.months() as i64 + days / 30;
+    days %= 30;
+
+    if months > 0 && (days < 0 || (days == 0 && ns < 0)) {
+        months -= 1;
+        days += 30;
+    } else if months < 0 && (days > 0 || (days == 0 && ns > 0)) {
+        months += 1;
+        days -= 30;
+    }
+    if days > 0 && ns < 0 {
+        days -= 1;
+        ns += NANOSECONDS_IN_DAY;
+    } else if days < 0 && ns > 0 {
+        days += 1;
+        ns -= NANOSECONDS_IN_DAY;
+    }
+
+    Some(months_days_ns::new(
+        months.try_into().ok()?,
+        days as i32,
+        ns,
+    ))
+}
+
+/// Normalizes [`DataType::Interval`]s of [`IntervalUnit::MonthDayNano`] such that, for each
+/// interval, its nanoseconds are less than a day and its days are less than a month, and all
+/// its components have the same sign, like postgres' `justify_interval`. A day is 24 hours
+/// and a month is 30 days. Intervals whose months overflow are null.
+///
+/// [`IntervalUnit::MonthDayNano`]: crate::datatypes::IntervalUnit::MonthDayNano
+/// # Examples
+/// ```
+/// use arrow2::compute::arithmetics::time::normalize_interval;
+/// use arrow2::array::PrimitiveArray;
+/// use arrow2::datatypes::{DataType, IntervalUnit};
+/// use arrow2::types::months_days_ns;
+///
+/// let day = 86_400_000_000_000;
+/// let interval = PrimitiveArray::from_slice([
+///     months_days_ns::new(0, 31, day + 1),
+///     months_days_ns::new(1, -1, 0),
+/// ])
+/// .to(DataType::Interval(IntervalUnit::MonthDayNano));
+///
+/// let result = normalize_interval(&interval);
+/// let expected = PrimitiveArray::from_slice([
+///     months_days_ns::new(1, 2, 1),
+///     months_days_ns::new(0, 29, 0),
+/// ])
+/// .to(DataType::Interval(IntervalUnit::MonthDayNano));
+/// assert_eq!(result, expected);
+/// ```
+pub fn normalize_interval(
+    interval: &PrimitiveArray<months_days_ns>,
+) -> PrimitiveArray<months_days_ns> {
+    unary_checked(
+        interval,
+        normalize_interval_value,
+        interval.data_type().clone(),
+    )
+}
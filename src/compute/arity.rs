@@ -1,5 +1,7 @@
 //! Defines kernels suitable to perform operations to primitive arrays.
 
+use alloc::vec::Vec;
+
 use super::utils::{check_same_len, combine_validities};
 use crate::{
     array::PrimitiveArray,
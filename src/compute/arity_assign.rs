@@ -1,5 +1,7 @@
 //! Defines generics suitable to perform operations to [`PrimitiveArray`] in-place.
 
+use alloc::vec::Vec;
+
 use super::utils::check_same_len;
 use crate::{array::PrimitiveArray, types::NativeType};
 use either::Either;
@@ -1,5 +1,5 @@
 //! Contains bitwise operators: [`or`], [`and`], [`xor`] and [`not`].
-use std::ops::{BitAnd, BitOr, BitXor, Not};
+use core::ops::{BitAnd, BitOr, BitXor, Not};
 
 use crate::array::PrimitiveArray;
 use crate::compute::arity::{binary, unary};
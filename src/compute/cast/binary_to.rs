@@ -56,6 +56,19 @@ pub fn binary_to_large_utf8(
     Utf8Array::<i64>::try_new(to_data_type, offsets, values, from.validity().cloned())
 }
 
+/// Conversion of large binary to utf8
+/// # Errors
+/// This function errors if the values are not valid utf8 or do not fit in `i32` offsets
+pub fn binary_large_to_utf8(
+    from: &BinaryArray<i64>,
+    to_data_type: DataType,
+) -> Result<Utf8Array<i32>> {
+    let values = from.values().clone();
+    let offsets = from.offsets().try_into()?;
+
+    Utf8Array::<i32>::try_new(to_data_type, offsets, values, from.validity().cloned())
+}
+
 /// Casts a [`BinaryArray`] to a [`PrimitiveArray`] at best-effort using `lexical_core::parse_partial`, making any uncastable value as zero.
 pub fn partial_binary_to_primitive<O: Offset, T>(
     from: &BinaryArray<O>,
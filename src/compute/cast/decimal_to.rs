@@ -1,73 +1,21 @@
 use num_traits::{AsPrimitive, Float, NumCast};
 
+use crate::compute::decimal::{rescale_or_null, RoundingMode};
 use crate::error::Result;
 use crate::types::NativeType;
 use crate::{array::*, datatypes::DataType};
 
-#[inline]
-fn decimal_to_decimal_impl<F: Fn(i128) -> Option<i128>>(
-    from: &PrimitiveArray<i128>,
-    op: F,
-    to_precision: usize,
-    to_scale: usize,
-) -> PrimitiveArray<i128> {
-    let min_for_precision = 9_i128
-        .saturating_pow(1 + to_precision as u32)
-        .saturating_neg();
-    let max_for_precision = 9_i128.saturating_pow(1 + to_precision as u32);
-
-    let values = from.iter().map(|x| {
-        x.and_then(|x| {
-            op(*x).and_then(|x| {
-                if x > max_for_precision || x < min_for_precision {
-                    None
-                } else {
-                    Some(x)
-                }
-            })
-        })
-    });
-    PrimitiveArray::<i128>::from_trusted_len_iter(values)
-        .to(DataType::Decimal(to_precision, to_scale))
-}
-
-/// Returns a [`PrimitiveArray<i128>`] with the casted values. Values are `None` on overflow
+/// Returns a [`PrimitiveArray<i128>`] with the casted values, rounded half away from zero when
+/// the scale decreases. Values are `None` on overflow, or when they do not fit in `to_precision`
+/// (see [`rescale_or_null`]).
+/// # Errors
+/// Iff `to_precision` is not between 1 and 38.
 pub fn decimal_to_decimal(
     from: &PrimitiveArray<i128>,
     to_precision: usize,
     to_scale: usize,
-) -> PrimitiveArray<i128> {
-    let (from_precision, from_scale) =
-        if let DataType::Decimal(p, s) = from.data_type().to_logical_type() {
-            (*p, *s)
-        } else {
-            panic!("internal error: i128 is always a decimal")
-        };
-
-    if to_scale == from_scale && to_precision >= from_precision {
-        // fast path
-        return from.clone().to(DataType::Decimal(to_precision, to_scale));
-    }
-    // todo: other fast paths include increasing scale and precision by so that
-    // a number will never overflow (validity is preserved)
-
-    if from_scale > to_scale {
-        let factor = 10_i128.pow((from_scale - to_scale) as u32);
-        decimal_to_decimal_impl(
-            from,
-            |x: i128| x.checked_div(factor),
-            to_precision,
-            to_scale,
-        )
-    } else {
-        let factor = 10_i128.pow((to_scale - from_scale) as u32);
-        decimal_to_decimal_impl(
-            from,
-            |x: i128| x.checked_mul(factor),
-            to_precision,
-            to_scale,
-        )
-    }
+) -> Result<PrimitiveArray<i128>> {
+    rescale_or_null(from, to_precision, to_scale, RoundingMode::HalfUp)
 }
 
 pub(super) fn decimal_to_decimal_dyn(
@@ -76,7 +24,7 @@ pub(super) fn decimal_to_decimal_dyn(
     to_scale: usize,
 ) -> Result<Box<dyn Array>> {
     let from = from.as_any().downcast_ref().unwrap();
-    Ok(Box::new(decimal_to_decimal(from, to_precision, to_scale)))
+    Ok(Box::new(decimal_to_decimal(from, to_precision, to_scale)?))
 }
 
 /// Returns a [`PrimitiveArray<i128>`] with the casted values. Values are `None` on overflow
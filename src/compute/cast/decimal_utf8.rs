@@ -0,0 +1,240 @@
+use crate::{
+    array::{write_decimal, Array, PrimitiveArray, Utf8Array},
+    compute::decimal::RoundingMode,
+    datatypes::DataType,
+    offset::{Offset, Offsets},
+    types::i256,
+};
+
+/// The integers backing decimals, on which decimals are parsed without floating point math
+trait DecimalInteger: Copy + PartialOrd {
+    const ZERO: Self;
+
+    /// Returns `self * 10 + digit`, or `None` on overflow
+    fn push_digit(self, digit: u8) -> Option<Self>;
+
+    /// Returns `self * 10^exponent`, or `None` on overflow
+    fn mul_pow10(self, exponent: usize) -> Option<Self>;
+
+    /// Returns `self + 1`, or `None` on overflow
+    fn increment(self) -> Option<Self>;
+
+    fn is_odd(self) -> bool;
+
+    /// Whether `self` (non-negative) has at most `precision` digits
+    fn fits(self, precision: usize) -> bool;
+
+    fn negate(self) -> Self;
+}
+
+macro_rules! decimal_integer {
+    ($ty:ty, $zero:expr) => {
+        impl DecimalInteger for $ty {
+            const ZERO: Self = $zero;
+
+            fn push_digit(self, digit: u8) -> Option<Self> {
+                self.checked_mul(<$ty>::from(10u8))?
+                    .checked_add(<$ty>::from(digit))
+            }
+
+            fn mul_pow10(self, exponent: usize) -> Option<Self> {
+                if self == Self::ZERO {
+                    return Some(self);
+                }
+                let exponent = u32::try_from(exponent).ok()?;
+                self.checked_mul(<$ty>::from(10u8).checked_pow(exponent)?)
+            }
+
+            fn increment(self) -> Option<Self> {
+                self.checked_add(<$ty>::from(1u8))
+            }
+
+            fn is_odd(self) -> bool {
+                self % <$ty>::from(2u8) != Self::ZERO
+            }
+
+            fn fits(self, precision: usize) -> bool {
+                let max = u32::try_from(precision)
+                    .ok()
+                    .and_then(|precision| <$ty>::from(10u8).checked_pow(precision));
+                match max {
+                    Some(max) => self < max,
+                    // a precision larger than the integer means any value fits
+                    None => true,
+                }
+            }
+
+            fn negate(self) -> Self {
+                -self
+            }
+        }
+    };
+}
+
+decimal_integer!(i128, 0);
+decimal_integer!(ethnum::I256, ethnum::I256::ZERO);
+
+/// Parses `value` (e.g. `-12.345` or `1.2e3`) to a decimal with `precision` and `scale`,
+/// rounding the digits in excess of `scale` according to `rounding`, or returning `None` on
+/// excess (non-zero) digits when `rounding` is `None`. Returns `None` when `value` is not a
+/// number or does not fit in `precision`.
+fn parse_decimal<T: DecimalInteger>(
+    value: &[u8],
+    precision: usize,
+    scale: usize,
+    rounding: Option<RoundingMode>,
+) -> Option<T> {
+    let (is_negative, value) = match value.first() {
+        Some(b'-') => (true, &value[1..]),
+        Some(b'+') => (false, &value[1..]),
+        _ => (false, value),
+    };
+    let (mantissa, exponent) = match value.iter().position(|c| matches!(c, b'e' | b'E')) {
+        Some(position) => {
+            let exponent = std::str::from_utf8(&value[position + 1..]).ok()?;
+            (&value[..position], exponent.parse::<i32>().ok()? as i64)
+        }
+        None => (value, 0),
+    };
+    let (integer, fraction) = match mantissa.iter().position(|c| *c == b'.') {
+        Some(position) => (&mantissa[..position], &mantissa[position + 1..]),
+        None => (mantissa, &[][..]),
+    };
+    if (integer.is_empty() && fraction.is_empty())
+        || !integer.iter().chain(fraction).all(u8::is_ascii_digit)
+    {
+        return None;
+    }
+
+    let digits = integer.len() + fraction.len();
+    // the power of 10 the digits are multiplied by to have `scale`
+    let shift = scale as i64 + exponent - fraction.len() as i64;
+    let excess = if shift < 0 { (-shift) as usize } else { 0 };
+
+    let mut digits_iter = integer.iter().chain(fraction).map(|c| c - b'0');
+    let mut result = T::ZERO;
+    for digit in digits_iter.by_ref().take(digits.saturating_sub(excess)) {
+        result = result.push_digit(digit)?;
+    }
+
+    if excess > 0 {
+        // the first excess digit is an implicit leading zero when all digits are in excess
+        let first = if excess > digits {
+            0
+        } else {
+            digits_iter.next().unwrap()
+        };
+        let rest_is_zero = digits_iter.all(|digit| digit == 0);
+        let round_away = match rounding {
+            None if first != 0 || !rest_is_zero => return None,
+            None => false,
+            Some(RoundingMode::HalfUp) => first >= 5,
+            Some(RoundingMode::HalfEven) => {
+                first > 5 || (first == 5 && (!rest_is_zero || result.is_odd()))
+            }
+        };
+        if round_away {
+            result = result.increment()?;
+        }
+    } else {
+        result = result.mul_pow10(shift as usize)?;
+    }
+
+    if !result.fits(precision) {
+        return None;
+    }
+    Some(if is_negative { result.negate() } else { result })
+}
+
+/// Returns a [`PrimitiveArray<i128>`] of [`DataType::Decimal`] with the values of `from`
+/// parsed as decimals (e.g. `-12.345` or `1.2e3`) with `precision` and `scale`.
+///
+/// Values are parsed with integer math, and are thus exact. Values with more fractional digits
+/// than `scale` are rounded according to `rounding`, or are null when it is `None` (trailing
+/// zeros are not rounded). Values that are not numbers or do not fit in `precision` are null.
+pub fn utf8_to_decimal<O: Offset>(
+    from: &Utf8Array<O>,
+    precision: usize,
+    scale: usize,
+    rounding: Option<RoundingMode>,
+) -> PrimitiveArray<i128> {
+    let iter = from
+        .iter()
+        .map(|x| x.and_then(|x| parse_decimal(x.as_bytes(), precision, scale, rounding)));
+    PrimitiveArray::<i128>::from_trusted_len_iter(iter).to(DataType::Decimal(precision, scale))
+}
+
+/// Returns a [`PrimitiveArray<i256>`] of [`DataType::Decimal256`] with the values of `from`
+/// parsed as decimals with `precision` and `scale`, like [`utf8_to_decimal`].
+pub fn utf8_to_decimal256<O: Offset>(
+    from: &Utf8Array<O>,
+    precision: usize,
+    scale: usize,
+    rounding: Option<RoundingMode>,
+) -> PrimitiveArray<i256> {
+    let iter = from.iter().map(|x| {
+        x.and_then(|x| parse_decimal(x.as_bytes(), precision, scale, rounding))
+            .map(i256)
+    });
+    PrimitiveArray::<i256>::from_trusted_len_iter(iter).to(DataType::Decimal256(precision, scale))
+}
+
+/// Returns a [`Utf8Array`] with the valid values of `from` written by `write`
+fn to_utf8<T, O, F>(from: &PrimitiveArray<T>, write: F) -> Utf8Array<O>
+where
+    T: crate::types::NativeType,
+    O: Offset,
+    F: Fn(&mut String, T),
+{
+    let mut offsets = Offsets::<O>::with_capacity(from.len());
+    let mut values = String::new();
+    for (index, value) in from.values().iter().enumerate() {
+        let start = values.len();
+        if from.is_valid(index) {
+            write(&mut values, *value);
+        }
+        offsets.try_push_usize(values.len() - start).unwrap();
+    }
+
+    // Safety: the values are a `String` and thus utf8
+    unsafe {
+        Utf8Array::new_unchecked(
+            Utf8Array::<O>::default_data_type(),
+            offsets.into(),
+            values.into_bytes().into(),
+            from.validity().cloned(),
+        )
+    }
+}
+
+/// Returns the scale of a decimal `data_type`
+/// # Panics
+/// Iff `data_type`'s logical type is not [`DataType::Decimal`] or [`DataType::Decimal256`]
+fn get_scale(data_type: &DataType) -> usize {
+    match data_type.to_logical_type() {
+        DataType::Decimal(_, scale) | DataType::Decimal256(_, scale) => *scale,
+        _ => panic!("The data type {data_type:?} is not a decimal"),
+    }
+}
+
+/// Returns a [`Utf8Array`] with the values of a [`DataType::Decimal`] array written with
+/// exactly as many fractional digits as its scale (e.g. `1.05` or `-0.50`).
+/// # Panics
+/// Iff the logical type of `from` is not [`DataType::Decimal`], or the values overflow `O`.
+pub fn decimal_to_utf8<O: Offset>(from: &PrimitiveArray<i128>) -> Utf8Array<O> {
+    let scale = get_scale(from.data_type());
+    to_utf8(from, |f, x| {
+        write_decimal(f, &x.unsigned_abs().to_string(), x < 0, scale).unwrap()
+    })
+}
+
+/// Returns a [`Utf8Array`] with the values of a [`DataType::Decimal256`] array, like
+/// [`decimal_to_utf8`].
+/// # Panics
+/// Iff the logical type of `from` is not [`DataType::Decimal256`], or the values overflow `O`.
+pub fn decimal256_to_utf8<O: Offset>(from: &PrimitiveArray<i256>) -> Utf8Array<O> {
+    let scale = get_scale(from.data_type());
+    to_utf8(from, |f, x| {
+        write_decimal(f, &x.0.unsigned_abs().to_string(), x.0.is_negative(), scale).unwrap()
+    })
+}
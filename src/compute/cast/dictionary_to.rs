@@ -1,9 +1,21 @@
+use std::collections::hash_map::Entry;
+use std::hash::Hash;
+
+use ahash::AHashMap;
+
 use super::{primitive_as_primitive, primitive_to_primitive, CastOptions};
 use crate::{
-    array::{Array, DictionaryArray, DictionaryKey, PrimitiveArray},
-    compute::{cast::cast, take::take},
-    datatypes::DataType,
+    array::{
+        Array, BinaryArray, BooleanArray, DictionaryArray, DictionaryKey, PrimitiveArray, Utf8Array,
+    },
+    compute::{
+        cast::cast,
+        take::take,
+        utils::{canonical_f32, canonical_f64},
+    },
+    datatypes::{DataType, IntegerType, PhysicalType, PrimitiveType},
     error::{Error, Result},
+    types::f16,
 };
 
 macro_rules! key_cast {
@@ -137,6 +149,14 @@ pub(super) fn dictionary_cast_dyn<K: DictionaryKey + num_traits::NumCast>(
         DataType::Dictionary(to_keys_type, to_values_type, _) => {
             let values = cast(values.as_ref(), to_values_type, options)?;
 
+            if *to_keys_type == K::KEY_TYPE {
+                // Safety: the keys are unchanged and `cast` preserves the length of the values
+                return unsafe {
+                    DictionaryArray::<K>::try_new_unchecked(to_type.clone(), keys.clone(), values)
+                }
+                .map(|x| x.boxed());
+            }
+
             // create the appropriate array type
             let to_key_type = (*to_keys_type).into();
 
@@ -182,3 +202,146 @@ where
     // unwrap: The dictionary guarantees that the keys are not out-of-bounds.
     take(from.values().as_ref(), &indices).unwrap()
 }
+
+/// Returns the keys of `array` encoded as a dictionary and the index in `array` of the value
+/// of each key. `value` returns the value of a slot, which is equal for values of the same key.
+fn encode_keys<K: DictionaryKey, T: Hash + Eq>(
+    array: &dyn Array,
+    value: impl Fn(usize) -> T,
+) -> Result<(PrimitiveArray<K>, Vec<usize>)> {
+    let mut keys_of_values = AHashMap::<T, K>::default();
+    let mut indices = vec![];
+    let keys = (0..array.len())
+        .map(|index| {
+            if !array.is_valid(index) {
+                return Ok(None);
+            }
+            let key = match keys_of_values.entry(value(index)) {
+                Entry::Occupied(entry) => *entry.get(),
+                Entry::Vacant(entry) => {
+                    let key = K::try_from(indices.len()).map_err(|_| Error::Overflow)?;
+                    indices.push(index);
+                    *entry.insert(key)
+                }
+            };
+            Ok(Some(key))
+        })
+        .collect::<Result<PrimitiveArray<K>>>()?;
+    Ok((keys, indices))
+}
+
+fn dictionary_encode_dyn<K: DictionaryKey>(array: &dyn Array) -> Result<Box<dyn Array>> {
+    use PhysicalType::*;
+    let (keys, indices) = match array.data_type().to_physical_type() {
+        Boolean => {
+            let values = array.as_any().downcast_ref::<BooleanArray>().unwrap();
+            encode_keys::<K, _>(array, |index| values.value(index))
+        }
+        Primitive(PrimitiveType::Float16) => {
+            let values = array
+                .as_any()
+                .downcast_ref::<PrimitiveArray<f16>>()
+                .unwrap();
+            encode_keys::<K, _>(array, |index| canonical_f32(values.value(index).to_f32()))
+        }
+        Primitive(PrimitiveType::Float32) => {
+            let values = array
+                .as_any()
+                .downcast_ref::<PrimitiveArray<f32>>()
+                .unwrap();
+            encode_keys::<K, _>(array, |index| canonical_f32(values.value(index)))
+        }
+        Primitive(PrimitiveType::Float64) => {
+            let values = array
+                .as_any()
+                .downcast_ref::<PrimitiveArray<f64>>()
+                .unwrap();
+            encode_keys::<K, _>(array, |index| canonical_f64(values.value(index)))
+        }
+        Primitive(primitive) => with_match_primitive_type!(primitive, |$T| {
+            let values = array.as_any().downcast_ref::<PrimitiveArray<$T>>().unwrap();
+            encode_keys::<K, _>(array, |index| bytemuck::bytes_of(&values.values()[index]))
+        }),
+        Utf8 => {
+            let values = array.as_any().downcast_ref::<Utf8Array<i32>>().unwrap();
+            encode_keys::<K, _>(array, |index| values.value(index))
+        }
+        LargeUtf8 => {
+            let values = array.as_any().downcast_ref::<Utf8Array<i64>>().unwrap();
+            encode_keys::<K, _>(array, |index| values.value(index))
+        }
+        Binary => {
+            let values = array.as_any().downcast_ref::<BinaryArray<i32>>().unwrap();
+            encode_keys::<K, _>(array, |index| values.value(index))
+        }
+        LargeBinary => {
+            let values = array.as_any().downcast_ref::<BinaryArray<i64>>().unwrap();
+            encode_keys::<K, _>(array, |index| values.value(index))
+        }
+        Dictionary(_) => {
+            // re-encode the keys, keeping the values and whether they are ordered
+            if let DataType::Dictionary(_, values, is_ordered) = array.data_type().to_logical_type()
+            {
+                let data_type = DataType::Dictionary(K::KEY_TYPE, values.clone(), *is_ordered);
+                return cast(array, &data_type, CastOptions::default());
+            }
+            unreachable!()
+        }
+        _ => {
+            return Err(Error::NotYetImplemented(format!(
+                "Dictionary encoding of {:?} is not supported",
+                array.data_type()
+            )))
+        }
+    }?;
+
+    let indices = PrimitiveArray::<u64>::from_vec(indices.into_iter().map(|x| x as u64).collect());
+    let values = take(array, &indices)?;
+    let data_type = DataType::Dictionary(K::KEY_TYPE, Box::new(array.data_type().clone()), false);
+    DictionaryArray::try_new(data_type, keys, values).map(|x| x.boxed())
+}
+
+/// Encodes `array` as a [`DictionaryArray`] whose keys are of `key_type`, also known as packing.
+///
+/// The values of the dictionary are the distinct values of `array`, in the order of their
+/// first occurrence, and have the [`DataType`] of `array`; the dictionary is thus not ordered.
+/// Dictionaries are re-encoded with keys of `key_type`, keeping their values and whether
+/// they are ordered.
+/// Supported types are booleans, primitives, binaries, strings and dictionaries. Floats are
+/// compared by value, except that all NaNs are equal to each other.
+/// # Errors
+/// This function errors iff
+/// * the [`DataType`] of `array` is not supported
+/// * the number of distinct values does not fit in `key_type`
+/// # Example
+/// ```
+/// use arrow2::array::{Array, DictionaryArray, Int8Array, Utf8Array};
+/// use arrow2::compute::cast::{dictionary_decode, dictionary_encode};
+/// use arrow2::datatypes::IntegerType;
+///
+/// let array = Utf8Array::<i32>::from([Some("a"), None, Some("b"), Some("a")]);
+/// let encoded = dictionary_encode(&array, IntegerType::Int8).unwrap();
+/// let dictionary = encoded.as_any().downcast_ref::<DictionaryArray<i8>>().unwrap();
+/// assert_eq!(dictionary.keys(), &Int8Array::from([Some(0), None, Some(1), Some(0)]));
+/// assert_eq!(dictionary.values().len(), 2);
+///
+/// let decoded = dictionary_decode(encoded.as_ref());
+/// assert_eq!(array, decoded.as_ref());
+/// ```
+pub fn dictionary_encode(array: &dyn Array, key_type: IntegerType) -> Result<Box<dyn Array>> {
+    match_integer_type!(key_type, |$T| {
+        dictionary_encode_dyn::<$T>(array)
+    })
+}
+
+/// Decodes a [`DictionaryArray`] into an array of its values' [`DataType`], also known as
+/// unpacking. Arrays of other types are returned as is.
+pub fn dictionary_decode(array: &dyn Array) -> Box<dyn Array> {
+    match array.data_type().to_physical_type() {
+        PhysicalType::Dictionary(key_type) => match_integer_type!(key_type, |$T| {
+            let array = array.as_any().downcast_ref::<DictionaryArray<$T>>().unwrap();
+            dictionary_to_values(array)
+        }),
+        _ => array.to_boxed(),
+    }
+}
@@ -0,0 +1,167 @@
+use crate::{
+    array::*,
+    datatypes::{validate_extension, DataType, PhysicalType},
+    error::{Error, Result},
+};
+
+use super::{can_cast_types, cast, CastOptions};
+
+/// Returns `array` with `data_type`, which must have the same physical type as `array`.
+fn with_data_type(array: &dyn Array, data_type: DataType) -> Result<Box<dyn Array>> {
+    use PhysicalType::*;
+    if array.data_type().to_physical_type() != data_type.to_physical_type() {
+        return Err(Error::InvalidArgumentError(format!(
+            "Cannot use {data_type:?} as the type of an array of {:?}",
+            array.data_type()
+        )));
+    }
+    Ok(match data_type.to_physical_type() {
+        Null => NullArray::try_new(data_type, array.len())?.boxed(),
+        Boolean => {
+            let array = array.as_any().downcast_ref::<BooleanArray>().unwrap();
+            BooleanArray::try_new(data_type, array.values().clone(), array.validity().cloned())?
+                .boxed()
+        }
+        Primitive(primitive) => with_match_primitive_type!(primitive, |$T| {
+            let array = array.as_any().downcast_ref::<PrimitiveArray<$T>>().unwrap();
+            array.clone().to(data_type).boxed()
+        }),
+        Binary => binary_with_data_type::<i32>(array, data_type)?,
+        LargeBinary => binary_with_data_type::<i64>(array, data_type)?,
+        Utf8 => utf8_with_data_type::<i32>(array, data_type)?,
+        LargeUtf8 => utf8_with_data_type::<i64>(array, data_type)?,
+        FixedSizeBinary => {
+            let array = array
+                .as_any()
+                .downcast_ref::<FixedSizeBinaryArray>()
+                .unwrap();
+            array.clone().to(data_type).boxed()
+        }
+        List => list_with_data_type::<i32>(array, data_type)?,
+        LargeList => list_with_data_type::<i64>(array, data_type)?,
+        FixedSizeList => {
+            let array = array.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
+            FixedSizeListArray::try_new(
+                data_type,
+                array.values().clone(),
+                array.validity().cloned(),
+            )?
+            .boxed()
+        }
+        Struct => {
+            let array = array.as_any().downcast_ref::<StructArray>().unwrap();
+            StructArray::try_new(
+                data_type,
+                array.values().to_vec(),
+                array.validity().cloned(),
+            )?
+            .boxed()
+        }
+        Union => {
+            let array = array.as_any().downcast_ref::<UnionArray>().unwrap();
+            UnionArray::try_new(
+                data_type,
+                array.types().clone(),
+                array.fields().clone(),
+                array.offsets().cloned(),
+            )?
+            .boxed()
+        }
+        Map => {
+            let array = array.as_any().downcast_ref::<MapArray>().unwrap();
+            MapArray::try_new(
+                data_type,
+                array.offsets().clone(),
+                array.field().clone(),
+                array.validity().cloned(),
+            )?
+            .boxed()
+        }
+        Dictionary(key_type) => match_integer_type!(key_type, |$T| {
+            let array = array.as_any().downcast_ref::<DictionaryArray<$T>>().unwrap();
+            DictionaryArray::try_new(data_type, array.keys().clone(), array.values().clone())?
+                .boxed()
+        }),
+    })
+}
+
+fn binary_with_data_type<O: crate::offset::Offset>(
+    array: &dyn Array,
+    data_type: DataType,
+) -> Result<Box<dyn Array>> {
+    let array = array.as_any().downcast_ref::<BinaryArray<O>>().unwrap();
+    Ok(BinaryArray::<O>::try_new(
+        data_type,
+        array.offsets().clone(),
+        array.values().clone(),
+        array.validity().cloned(),
+    )?
+    .boxed())
+}
+
+fn utf8_with_data_type<O: crate::offset::Offset>(
+    array: &dyn Array,
+    data_type: DataType,
+) -> Result<Box<dyn Array>> {
+    let array = array.as_any().downcast_ref::<Utf8Array<O>>().unwrap();
+    Ok(Utf8Array::<O>::try_new(
+        data_type,
+        array.offsets().clone(),
+        array.values().clone(),
+        array.validity().cloned(),
+    )?
+    .boxed())
+}
+
+fn list_with_data_type<O: crate::offset::Offset>(
+    array: &dyn Array,
+    data_type: DataType,
+) -> Result<Box<dyn Array>> {
+    let array = array.as_any().downcast_ref::<ListArray<O>>().unwrap();
+    Ok(ListArray::<O>::try_new(
+        data_type,
+        array.offsets().clone(),
+        array.values().clone(),
+        array.validity().cloned(),
+    )?
+    .boxed())
+}
+
+/// Returns whether an array of `from_type` can be cast to `to_type` when either of them is
+/// a [`DataType::Extension`].
+pub(super) fn can_cast_extension(from_type: &DataType, to_type: &DataType) -> bool {
+    let from_type = match from_type {
+        DataType::Extension(_, storage, _) => storage.as_ref(),
+        _ => from_type,
+    };
+    match to_type {
+        // `validate_extension` also validates nested extensions, so cast to the storage of
+        // the innermost one to not validate them again
+        DataType::Extension(..) => {
+            validate_extension(to_type).is_ok()
+                && can_cast_types(from_type, to_type.to_logical_type())
+        }
+        _ => can_cast_types(from_type, to_type),
+    }
+}
+
+/// Casts `array` to `to_type` when either its type or `to_type` is a [`DataType::Extension`].
+/// Extensions are cast as their storage types and registered extensions are validated.
+pub(super) fn cast_extension(
+    array: &dyn Array,
+    to_type: &DataType,
+    options: CastOptions,
+) -> Result<Box<dyn Array>> {
+    let array = match array.data_type() {
+        DataType::Extension(_, storage, _) => with_data_type(array, storage.as_ref().clone())?,
+        _ => array.to_boxed(),
+    };
+    match to_type {
+        DataType::Extension(..) => {
+            validate_extension(to_type)?;
+            let array = cast(array.as_ref(), to_type.to_logical_type(), options)?;
+            with_data_type(array.as_ref(), to_type.clone())
+        }
+        _ => cast(array.as_ref(), to_type, options),
+    }
+}
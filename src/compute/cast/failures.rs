@@ -0,0 +1,136 @@
+use crate::{
+    array::{get_display, Array, DictionaryArray},
+    bitmap::{binary, utils::SlicesIterator, Bitmap},
+    datatypes::{DataType, PhysicalType},
+    error::{Error, Result},
+};
+
+use super::{cast, CastOptions};
+
+/// The rows of an array that failed to cast, i.e. that are valid in the array but null in
+/// the result of the cast, returned by [`cast_with_failures`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct CastFailures {
+    rows: Bitmap,
+    message: Option<String>,
+}
+
+impl CastFailures {
+    /// A [`Bitmap`] with the length of the cast array whose set bits are the rows that failed,
+    /// e.g. to [`filter`](crate::compute::filter::filter) them to a dead-letter output.
+    pub fn rows(&self) -> &Bitmap {
+        &self.rows
+    }
+
+    /// The indices of the rows that failed, in ascending order
+    pub fn indices(&self) -> impl Iterator<Item = usize> + '_ {
+        SlicesIterator::new(&self.rows).flat_map(|(start, len)| start..start + len)
+    }
+
+    /// The number of rows that failed
+    pub fn len(&self) -> usize {
+        self.rows.len() - self.rows.unset_bits()
+    }
+
+    /// Whether no row failed
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// A message describing the first row that failed, if any
+    pub fn message(&self) -> Option<&str> {
+        self.message.as_deref()
+    }
+}
+
+/// Returns the message describing the failure of the cast of `array` at `index` to `to_type`
+fn failure_message(array: &dyn Array, index: usize, to_type: &DataType) -> String {
+    let mut value = String::new();
+    // writing to a `String` is infallible
+    get_display(array, "null")(&mut value, index).unwrap();
+    format!(
+        "Unable to cast the value {value} at row {index} from {:?} to {to_type:?}",
+        array.data_type()
+    )
+}
+
+/// Returns the validity of the rows of `array`, where a row of a dictionary array is valid iff
+/// both its key and the value it points to are valid.
+fn row_validity(array: &dyn Array) -> Option<Bitmap> {
+    match array.data_type().to_physical_type() {
+        PhysicalType::Dictionary(key_type) => match_integer_type!(key_type, |$T| {
+            let array = array.as_any().downcast_ref::<DictionaryArray<$T>>().unwrap();
+            let values = array.values();
+            if array.null_count() == 0 && values.null_count() == 0 {
+                return None;
+            }
+            Some(
+                array
+                    .keys_values_iter()
+                    .zip(array.keys().iter())
+                    .map(|(index, key)| key.is_some() && values.is_valid(index))
+                    .collect(),
+            )
+        }),
+        _ => array.validity().cloned(),
+    }
+}
+
+/// Casts `array` to `to_type` like [`cast`], and returns the rows that failed to cast,
+/// i.e. that are valid in `array` but null in the result (e.g. overflows or unparsable strings).
+///
+/// When `collect_failures` is `false`, this function errors on the first failed row
+/// instead, so that a batch is either cast without losses or not cast at all.
+///
+/// Failures are detected on the rows of the result, not on nested values. A row of a
+/// dictionary array fails when the value it points to fails to cast; note that casting
+/// the values of a dictionary wraps numeric overflows instead of failing them.
+/// # Errors
+/// Errors iff the cast is not supported, or a row failed and `collect_failures` is `false`.
+/// # Example
+/// ```
+/// use arrow2::array::{Int32Array, Utf8Array};
+/// use arrow2::compute::cast::{cast_with_failures, CastOptions};
+/// use arrow2::datatypes::DataType;
+///
+/// let array = Utf8Array::<i32>::from([Some("1"), Some("a"), None, Some("3")]);
+/// let options = CastOptions::default();
+///
+/// let (result, failures) = cast_with_failures(&array, &DataType::Int32, options, true).unwrap();
+/// assert_eq!(Int32Array::from([Some(1), None, None, Some(3)]), result.as_ref());
+/// assert_eq!(failures.indices().collect::<Vec<_>>(), vec![1]);
+/// assert_eq!(
+///     failures.message(),
+///     Some("Unable to cast the value a at row 1 from Utf8 to Int32")
+/// );
+///
+/// assert!(cast_with_failures(&array, &DataType::Int32, options, false).is_err());
+/// ```
+pub fn cast_with_failures(
+    array: &dyn Array,
+    to_type: &DataType,
+    options: CastOptions,
+    collect_failures: bool,
+) -> Result<(Box<dyn Array>, CastFailures)> {
+    let result = cast(array, to_type, options)?;
+
+    let rows = match (
+        array.data_type(),
+        row_validity(array),
+        row_validity(result.as_ref()),
+    ) {
+        // all rows of a null array are null, and thus none can fail
+        (DataType::Null, _, _) | (_, _, None) => Bitmap::new_zeroed(array.len()),
+        (_, Some(validity), Some(result)) => binary(&validity, &result, |x, y| x & !y),
+        (_, None, Some(result)) => !&result,
+    };
+
+    let message = SlicesIterator::new(&rows)
+        .next()
+        .map(|(index, _)| failure_message(array, index, to_type));
+
+    match message {
+        Some(message) if !collect_failures => Err(Error::InvalidArgumentError(message)),
+        message => Ok((result, CastFailures { rows, message })),
+    }
+}
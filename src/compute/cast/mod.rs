@@ -3,19 +3,29 @@
 mod binary_to;
 mod boolean_to;
 mod decimal_to;
+mod decimal_utf8;
 mod dictionary_to;
+mod extension;
+mod failures;
 mod primitive_to;
+mod schema;
+mod union_to;
 mod utf8_to;
 
 pub use binary_to::*;
 pub use boolean_to::*;
 pub use decimal_to::*;
+pub use decimal_utf8::*;
 pub use dictionary_to::*;
+pub use failures::{cast_with_failures, CastFailures};
 pub use primitive_to::*;
+pub use schema::cast_chunk_to_schema;
+pub use union_to::*;
 pub use utf8_to::*;
 
 use crate::{
     array::*,
+    compute::decimal::RoundingMode,
     datatypes::*,
     error::{Error, Result},
     offset::{Offset, Offsets},
@@ -81,8 +91,14 @@ pub fn can_cast_types(from_type: &DataType, to_type: &DataType) -> bool {
 
     match (from_type, to_type) {
         (Null, _) | (_, Null) => true,
+        (Extension(..), _) | (_, Extension(..)) => {
+            extension::can_cast_extension(from_type, to_type)
+        }
         (Struct(_), _) => false,
         (_, Struct(_)) => false,
+        (Union(from_fields, from_ids, _), Union(to_fields, to_ids, _)) => {
+            from_fields == to_fields && from_ids == to_ids
+        }
         (FixedSizeList(list_from, _), List(list_to)) => {
             can_cast_types(&list_from.data_type, &list_to.data_type)
         }
@@ -117,24 +133,41 @@ pub fn can_cast_types(from_type: &DataType, to_type: &DataType) -> bool {
             is_numeric(to_type)
                 || matches!(
                     to_type,
-                    LargeUtf8 | Binary | Date32 | Date64 | Timestamp(TimeUnit::Nanosecond, _)
+                    LargeUtf8
+                        | Binary
+                        | LargeBinary
+                        | Date32
+                        | Date64
+                        | Timestamp(TimeUnit::Nanosecond, _)
+                        | Decimal(_, _)
+                        | Decimal256(_, _)
                 )
         }
         (LargeUtf8, to_type) => {
             is_numeric(to_type)
                 || matches!(
                     to_type,
-                    Utf8 | LargeBinary | Date32 | Date64 | Timestamp(TimeUnit::Nanosecond, _)
+                    Utf8 | Binary
+                        | LargeBinary
+                        | Date32
+                        | Date64
+                        | Timestamp(TimeUnit::Nanosecond, _)
+                        | Decimal(_, _)
+                        | Decimal256(_, _)
                 )
         }
 
         (Binary, to_type) => {
             is_numeric(to_type) || matches!(to_type, LargeBinary | Utf8 | LargeUtf8)
         }
-        (LargeBinary, to_type) => is_numeric(to_type) || matches!(to_type, Binary | LargeUtf8),
+        (LargeBinary, to_type) => {
+            is_numeric(to_type) || matches!(to_type, Binary | Utf8 | LargeUtf8)
+        }
 
         (Timestamp(_, _), Utf8) => true,
         (Timestamp(_, _), LargeUtf8) => true,
+        (Duration(_) | Interval(_), Utf8 | LargeUtf8) => true,
+        (Decimal(_, _) | Decimal256(_, _), Utf8 | LargeUtf8) => true,
         (_, Utf8) => is_numeric(from_type) || from_type == &Binary,
         (_, LargeUtf8) => is_numeric(from_type) || from_type == &LargeBinary,
 
@@ -230,7 +263,8 @@ pub fn can_cast_types(from_type: &DataType, to_type: &DataType) -> bool {
         (Int64, Float64) => true,
         (Int64, Decimal(_, _)) => true,
 
-        (Float16, Float32) => true,
+        (Float16, to_type) => is_numeric(to_type),
+        (from_type, Float16) => is_numeric(from_type),
 
         (Float32, UInt8) => true,
         (Float32, UInt16) => true,
@@ -415,11 +449,15 @@ fn cast_list_to_fixed_size_list(
 /// * Time32 and Time64: precision lost when going to higher interval
 /// * Timestamp and Date{32|64}: precision lost when going to higher interval
 /// * Temporal to/from backing primitive: zero-copy with data type change
+/// * Duration and Interval to Utf8: the value with the abbreviations of its units (e.g. `10ms`)
+/// * Dictionary to Dictionary: the keys are re-typed (erroring if they do not fit the new key
+///   type) and the values are cast, without decoding the dictionary
+/// * Union to Union with the same fields and ids: converted between the sparse and dense layouts
 /// Unsupported Casts
 /// * To or from `StructArray`
 /// * List to primitive
 /// * Utf8 to boolean
-/// * Interval and duration
+/// * Interval and duration, other than to Utf8 and between some interval units
 pub fn cast(array: &dyn Array, to_type: &DataType, options: CastOptions) -> Result<Box<dyn Array>> {
     use DataType::*;
     let from_type = array.data_type();
@@ -432,12 +470,26 @@ pub fn cast(array: &dyn Array, to_type: &DataType, options: CastOptions) -> Resu
     let as_options = options.with_wrapped(true);
     match (from_type, to_type) {
         (Null, _) | (_, Null) => Ok(new_null_array(to_type.clone(), array.len())),
+        (Extension(..), _) | (_, Extension(..)) => {
+            extension::cast_extension(array, to_type, options)
+        }
         (Struct(_), _) => Err(Error::NotYetImplemented(
             "Cannot cast from struct to other types".to_string(),
         )),
         (_, Struct(_)) => Err(Error::NotYetImplemented(
             "Cannot cast to struct from other types".to_string(),
         )),
+        (Union(from_fields, from_ids, _), Union(to_fields, to_ids, to_mode))
+            if from_fields == to_fields && from_ids == to_ids =>
+        {
+            let array = array.as_any().downcast_ref().unwrap();
+            Ok(if to_mode.is_sparse() {
+                union_to_sparse(array)
+            } else {
+                union_to_dense(array)
+            }
+            .boxed())
+        }
         (List(_), FixedSizeList(inner, size)) => cast_list_to_fixed_size_list(
             array.as_any().downcast_ref().unwrap(),
             inner.as_ref(),
@@ -539,10 +591,29 @@ pub fn cast(array: &dyn Array, to_type: &DataType, options: CastOptions) -> Resu
                 to_type.clone(),
             )
             .boxed()),
+            LargeBinary => Ok(utf8_to_large_binary(
+                array.as_any().downcast_ref().unwrap(),
+                to_type.clone(),
+            )
+            .boxed()),
             Timestamp(TimeUnit::Nanosecond, None) => utf8_to_naive_timestamp_ns_dyn::<i32>(array),
             Timestamp(TimeUnit::Nanosecond, Some(tz)) => {
                 utf8_to_timestamp_ns_dyn::<i32>(array, tz.clone())
             }
+            Decimal(p, s) => Ok(utf8_to_decimal::<i32>(
+                array.as_any().downcast_ref().unwrap(),
+                *p,
+                *s,
+                Some(RoundingMode::HalfUp),
+            )
+            .boxed()),
+            Decimal256(p, s) => Ok(utf8_to_decimal256::<i32>(
+                array.as_any().downcast_ref().unwrap(),
+                *p,
+                *s,
+                Some(RoundingMode::HalfUp),
+            )
+            .boxed()),
             _ => Err(Error::NotYetImplemented(format!(
                 "Casting from {from_type:?} to {to_type:?} not supported",
             ))),
@@ -561,6 +632,8 @@ pub fn cast(array: &dyn Array, to_type: &DataType, options: CastOptions) -> Resu
             Date32 => utf8_to_date32_dyn::<i64>(array),
             Date64 => utf8_to_date64_dyn::<i64>(array),
             Utf8 => utf8_large_to_utf8(array.as_any().downcast_ref().unwrap()).map(|x| x.boxed()),
+            Binary => utf8_large_to_binary(array.as_any().downcast_ref().unwrap(), to_type.clone())
+                .map(|x| x.boxed()),
             LargeBinary => Ok(utf8_to_binary::<i64>(
                 array.as_any().downcast_ref().unwrap(),
                 to_type.clone(),
@@ -570,6 +643,20 @@ pub fn cast(array: &dyn Array, to_type: &DataType, options: CastOptions) -> Resu
             Timestamp(TimeUnit::Nanosecond, Some(tz)) => {
                 utf8_to_timestamp_ns_dyn::<i64>(array, tz.clone())
             }
+            Decimal(p, s) => Ok(utf8_to_decimal::<i64>(
+                array.as_any().downcast_ref().unwrap(),
+                *p,
+                *s,
+                Some(RoundingMode::HalfUp),
+            )
+            .boxed()),
+            Decimal256(p, s) => Ok(utf8_to_decimal256::<i64>(
+                array.as_any().downcast_ref().unwrap(),
+                *p,
+                *s,
+                Some(RoundingMode::HalfUp),
+            )
+            .boxed()),
             _ => Err(Error::NotYetImplemented(format!(
                 "Casting from {from_type:?} to {to_type:?} not supported",
             ))),
@@ -597,6 +684,10 @@ pub fn cast(array: &dyn Array, to_type: &DataType, options: CastOptions) -> Resu
                 let array = Utf8Array::<i32>::from_trusted_len_iter(iter);
                 Ok(Box::new(array))
             }
+            LargeBinary => {
+                binary_large_to_utf8(array.as_any().downcast_ref().unwrap(), to_type.clone())
+                    .map(|x| x.boxed())
+            }
             Timestamp(from_unit, Some(tz)) => {
                 let from = array.as_any().downcast_ref().unwrap();
                 Ok(Box::new(timestamp_to_utf8::<i32>(from, *from_unit, tz)?))
@@ -605,6 +696,13 @@ pub fn cast(array: &dyn Array, to_type: &DataType, options: CastOptions) -> Resu
                 let from = array.as_any().downcast_ref().unwrap();
                 Ok(Box::new(naive_timestamp_to_utf8::<i32>(from, *from_unit)))
             }
+            Duration(_) | Interval(_) => temporal_to_utf8_dyn::<i32>(array),
+            Decimal(_, _) => {
+                Ok(decimal_to_utf8::<i32>(array.as_any().downcast_ref().unwrap()).boxed())
+            }
+            Decimal256(_, _) => {
+                Ok(decimal256_to_utf8::<i32>(array.as_any().downcast_ref().unwrap()).boxed())
+            }
             _ => Err(Error::NotYetImplemented(format!(
                 "Casting from {from_type:?} to {to_type:?} not supported",
             ))),
@@ -635,6 +733,13 @@ pub fn cast(array: &dyn Array, to_type: &DataType, options: CastOptions) -> Resu
                 let from = array.as_any().downcast_ref().unwrap();
                 Ok(Box::new(naive_timestamp_to_utf8::<i64>(from, *from_unit)))
             }
+            Duration(_) | Interval(_) => temporal_to_utf8_dyn::<i64>(array),
+            Decimal(_, _) => {
+                Ok(decimal_to_utf8::<i64>(array.as_any().downcast_ref().unwrap()).boxed())
+            }
+            Decimal256(_, _) => {
+                Ok(decimal256_to_utf8::<i64>(array.as_any().downcast_ref().unwrap()).boxed())
+            }
             _ => Err(Error::NotYetImplemented(format!(
                 "Casting from {from_type:?} to {to_type:?} not supported",
             ))),
@@ -812,6 +917,18 @@ pub fn cast(array: &dyn Array, to_type: &DataType, options: CastOptions) -> Resu
             let from = array.as_any().downcast_ref().unwrap();
             Ok(f16_to_f32(from).boxed())
         }
+        (Float16, to_type) if is_numeric(to_type) => {
+            let from = array.as_any().downcast_ref().unwrap();
+            cast(&f16_to_f32(from), to_type, options)
+        }
+        (Float32, Float16) => {
+            let from = array.as_any().downcast_ref().unwrap();
+            Ok(f32_to_f16(from).boxed())
+        }
+        (from_type, Float16) if is_numeric(from_type) => {
+            let from = cast(array, &Float32, options)?;
+            Ok(f32_to_f16(from.as_any().downcast_ref().unwrap()).boxed())
+        }
 
         (Float32, UInt8) => primitive_to_primitive_dyn::<f32, u8>(array, to_type, options),
         (Float32, UInt16) => primitive_to_primitive_dyn::<f32, u16>(array, to_type, options),
@@ -943,3 +1060,97 @@ fn cast_to_dictionary<K: DictionaryKey>(
         ))),
     }
 }
+
+fn promote_field(field: &Field, values: &dyn Array) -> Box<Field> {
+    Box::new(Field {
+        data_type: values.data_type().clone(),
+        ..field.clone()
+    })
+}
+
+/// Returns `array` with [`DataType::Utf8`], [`DataType::Binary`] and [`DataType::List`],
+/// also when nested in lists, structs and maps, promoted to their large variants with
+/// `i64` offsets.
+///
+/// Use this to concatenate or extend arrays whose values may exceed `i32::MAX` bytes or
+/// items. Other arrays are returned unchanged.
+/// # Implementation
+/// This operation only converts offsets; values are shared with `array`.
+/// # Example
+/// ```
+/// use arrow2::array::Utf8Array;
+/// use arrow2::compute::cast::promote_large;
+/// use arrow2::datatypes::DataType;
+///
+/// let array = Utf8Array::<i32>::from_slice(["a", "b"]);
+/// let result = promote_large(&array);
+/// assert_eq!(result.data_type(), &DataType::LargeUtf8);
+/// assert_eq!(Utf8Array::<i64>::from_slice(["a", "b"]), result.as_ref());
+/// ```
+pub fn promote_large(array: &dyn Array) -> Box<dyn Array> {
+    match array.data_type() {
+        DataType::Utf8 => utf8_to_large_utf8(array.as_any().downcast_ref().unwrap()).boxed(),
+        DataType::Binary => binary_to_large_binary(
+            array.as_any().downcast_ref().unwrap(),
+            DataType::LargeBinary,
+        )
+        .boxed(),
+        DataType::List(field) => {
+            let array = array.as_any().downcast_ref::<ListArray<i32>>().unwrap();
+            let values = promote_large(array.values().as_ref());
+            let data_type = DataType::LargeList(promote_field(field, values.as_ref()));
+            ListArray::<i64>::new(
+                data_type,
+                array.offsets().into(),
+                values,
+                array.validity().cloned(),
+            )
+            .boxed()
+        }
+        DataType::LargeList(field) => {
+            let array = array.as_any().downcast_ref::<ListArray<i64>>().unwrap();
+            let values = promote_large(array.values().as_ref());
+            let data_type = DataType::LargeList(promote_field(field, values.as_ref()));
+            ListArray::<i64>::new(
+                data_type,
+                array.offsets().clone(),
+                values,
+                array.validity().cloned(),
+            )
+            .boxed()
+        }
+        DataType::FixedSizeList(field, size) => {
+            let array = array.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
+            let values = promote_large(array.values().as_ref());
+            let data_type = DataType::FixedSizeList(promote_field(field, values.as_ref()), *size);
+            FixedSizeListArray::new(data_type, values, array.validity().cloned()).boxed()
+        }
+        DataType::Struct(fields) => {
+            let array = array.as_any().downcast_ref::<StructArray>().unwrap();
+            let values = array
+                .values()
+                .iter()
+                .map(|values| promote_large(values.as_ref()))
+                .collect::<Vec<_>>();
+            let fields = fields
+                .iter()
+                .zip(values.iter())
+                .map(|(field, values)| *promote_field(field, values.as_ref()))
+                .collect();
+            StructArray::new(DataType::Struct(fields), values, array.validity().cloned()).boxed()
+        }
+        DataType::Map(field, sorted) => {
+            let array = array.as_any().downcast_ref::<MapArray>().unwrap();
+            let entries = promote_large(array.field().as_ref());
+            let data_type = DataType::Map(promote_field(field, entries.as_ref()), *sorted);
+            MapArray::new(
+                data_type,
+                array.offsets().clone(),
+                entries,
+                array.validity().cloned(),
+            )
+            .boxed()
+        }
+        _ => array.to_boxed(),
+    }
+}
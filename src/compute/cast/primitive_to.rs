@@ -555,6 +555,51 @@ pub fn naive_timestamp_to_utf8<O: Offset>(
     }
 }
 
+/// Returns a [`Utf8Array`] where every element is the utf8 representation of the duration,
+/// i.e. its value followed by the abbreviation of `time_unit` (e.g. `10ms`).
+pub fn duration_to_utf8<O: Offset>(
+    from: &PrimitiveArray<i64>,
+    time_unit: TimeUnit,
+) -> Utf8Array<O> {
+    let suffix = match time_unit {
+        TimeUnit::Second => "s",
+        TimeUnit::Millisecond => "ms",
+        TimeUnit::Microsecond => "us",
+        TimeUnit::Nanosecond => "ns",
+    };
+    let iter = from.iter().map(|x| x.map(|x| format!("{x}{suffix}")));
+    Utf8Array::from_trusted_len_iter(iter)
+}
+
+/// Returns a [`Utf8Array`] where every element is the utf8 representation of the interval
+/// (e.g. `1m 2d 3ns` for [`IntervalUnit::MonthDayNano`]).
+pub fn interval_to_utf8<T: NativeType + std::fmt::Display, O: Offset>(
+    from: &PrimitiveArray<T>,
+) -> Utf8Array<O> {
+    let iter = from.iter().map(|x| x.map(|x| x.to_string()));
+    Utf8Array::from_trusted_len_iter(iter)
+}
+
+pub(super) fn temporal_to_utf8_dyn<O: Offset>(from: &dyn Array) -> Result<Box<dyn Array>> {
+    Ok(match from.data_type().to_logical_type() {
+        DataType::Duration(time_unit) => {
+            duration_to_utf8::<O>(from.as_any().downcast_ref().unwrap(), *time_unit).boxed()
+        }
+        DataType::Interval(IntervalUnit::YearMonth) => {
+            let from = from.as_any().downcast_ref::<PrimitiveArray<i32>>().unwrap();
+            let iter = from.iter().map(|x| x.map(|x| format!("{x}m")));
+            Utf8Array::<O>::from_trusted_len_iter(iter).boxed()
+        }
+        DataType::Interval(IntervalUnit::DayTime) => {
+            interval_to_utf8::<days_ms, O>(from.as_any().downcast_ref().unwrap()).boxed()
+        }
+        DataType::Interval(IntervalUnit::MonthDayNano) => {
+            interval_to_utf8::<months_days_ns, O>(from.as_any().downcast_ref().unwrap()).boxed()
+        }
+        _ => unreachable!(),
+    })
+}
+
 #[inline]
 fn days_ms_to_months_days_ns_scalar(from: days_ms) -> months_days_ns {
     months_days_ns::new(0, from.days(), from.milliseconds() as i64 * 1000)
@@ -587,3 +632,8 @@ pub fn months_to_months_days_ns(from: &PrimitiveArray<i32>) -> PrimitiveArray<mo
 pub fn f16_to_f32(from: &PrimitiveArray<f16>) -> PrimitiveArray<f32> {
     unary(from, |x| x.to_f32(), DataType::Float32)
 }
+
+/// Casts f32 into f16, rounding to the nearest value
+pub fn f32_to_f16(from: &PrimitiveArray<f32>) -> PrimitiveArray<f16> {
+    unary(from, f16::from_f32, DataType::Float16)
+}
@@ -0,0 +1,153 @@
+use crate::{
+    array::*,
+    chunk::Chunk,
+    datatypes::{DataType, Field, Schema},
+    error::{Error, Result},
+    offset::Offset,
+};
+
+use super::{cast, CastOptions};
+
+/// Returns the array of `field` in `arrays` of `fields`, or a null array if it does not exist.
+fn adapt_field(
+    fields: &[Field],
+    arrays: &[&dyn Array],
+    field: &Field,
+    length: usize,
+    options: CastOptions,
+) -> Result<Box<dyn Array>> {
+    match fields.iter().position(|x| x.name == field.name) {
+        Some(index) => adapt(arrays[index], &field.data_type, options),
+        None if field.is_nullable => Ok(new_null_array(field.data_type.clone(), length)),
+        None => Err(Error::InvalidArgumentError(format!(
+            "The field \"{}\" is not nullable and is missing from the array",
+            field.name
+        ))),
+    }
+}
+
+fn adapt_list<O: Offset>(
+    array: &ListArray<O>,
+    child: &Field,
+    to_type: &DataType,
+    options: CastOptions,
+) -> Result<Box<dyn Array>> {
+    let values = adapt(array.values().as_ref(), &child.data_type, options)?;
+    let data_type = if O::IS_LARGE {
+        DataType::LargeList(Box::new(child.clone()))
+    } else {
+        DataType::List(Box::new(child.clone()))
+    };
+    let array = ListArray::<O>::try_new(
+        data_type,
+        array.offsets().clone(),
+        values,
+        array.validity().cloned(),
+    )?;
+    cast(&array, to_type, options)
+}
+
+fn adapt(array: &dyn Array, to_type: &DataType, options: CastOptions) -> Result<Box<dyn Array>> {
+    if array.data_type() == to_type {
+        return Ok(array.to_boxed());
+    }
+    match (
+        array.data_type().to_logical_type(),
+        to_type.to_logical_type(),
+    ) {
+        (DataType::Null, _) => Ok(new_null_array(to_type.clone(), array.len())),
+        (DataType::Struct(fields), DataType::Struct(to_fields)) => {
+            let array = array.as_any().downcast_ref::<StructArray>().unwrap();
+            let arrays = array
+                .values()
+                .iter()
+                .map(|x| x.as_ref())
+                .collect::<Vec<_>>();
+            let values = to_fields
+                .iter()
+                .map(|field| adapt_field(fields, &arrays, field, array.len(), options))
+                .collect::<Result<Vec<_>>>()?;
+            Ok(StructArray::try_new(to_type.clone(), values, array.validity().cloned())?.boxed())
+        }
+        (DataType::List(_), DataType::List(to) | DataType::LargeList(to)) => {
+            adapt_list::<i32>(array.as_any().downcast_ref().unwrap(), to, to_type, options)
+        }
+        (DataType::LargeList(_), DataType::List(to) | DataType::LargeList(to)) => {
+            adapt_list::<i64>(array.as_any().downcast_ref().unwrap(), to, to_type, options)
+        }
+        (DataType::FixedSizeList(_, size), DataType::FixedSizeList(to, to_size))
+            if size == to_size =>
+        {
+            let array = array.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
+            let values = adapt(array.values().as_ref(), &to.data_type, options)?;
+            Ok(
+                FixedSizeListArray::try_new(to_type.clone(), values, array.validity().cloned())?
+                    .boxed(),
+            )
+        }
+        (DataType::Map(_, _), DataType::Map(to, _)) => {
+            let array = array.as_any().downcast_ref::<MapArray>().unwrap();
+            let field = adapt(array.field().as_ref(), &to.data_type, options)?;
+            Ok(MapArray::try_new(
+                to_type.clone(),
+                array.offsets().clone(),
+                field,
+                array.validity().cloned(),
+            )?
+            .boxed())
+        }
+        _ => cast(array, to_type, options),
+    }
+}
+
+/// Adapts `chunk`, whose arrays correspond to the fields of `schema`, to the schema `to`,
+/// e.g. to the schema returned by [`merge_schemas`](crate::datatypes::merge_schemas).
+///
+/// Fields are matched by name, also the fields of nested structs. Fields missing in `chunk`
+/// are filled with nulls and fields of different data types are [`cast`] to the data type of `to`.
+/// # Errors
+/// This function errors iff a field is missing from `chunk` but is not nullable on `to`,
+/// or a data type can't be cast.
+/// # Example
+/// ```
+/// use arrow2::array::{Int32Array, Int64Array, Utf8Array};
+/// use arrow2::chunk::Chunk;
+/// use arrow2::compute::cast::{cast_chunk_to_schema, CastOptions};
+/// use arrow2::datatypes::{DataType, Field, Schema};
+///
+/// let schema = Schema::from(vec![Field::new("a", DataType::Int32, false)]);
+/// let chunk = Chunk::new(vec![Int32Array::from_slice([1, 2]).boxed()]);
+///
+/// let to = Schema::from(vec![
+///     Field::new("b", DataType::Utf8, true),
+///     Field::new("a", DataType::Int64, false),
+/// ]);
+/// let chunk = cast_chunk_to_schema(&chunk, &schema, &to, CastOptions::default()).unwrap();
+/// assert_eq!(Utf8Array::<i32>::new_null(DataType::Utf8, 2), chunk.arrays()[0].as_ref());
+/// assert_eq!(Int64Array::from_slice([1, 2]), chunk.arrays()[1].as_ref());
+/// ```
+pub fn cast_chunk_to_schema<A: AsRef<dyn Array>>(
+    chunk: &Chunk<A>,
+    schema: &Schema,
+    to: &Schema,
+    options: CastOptions,
+) -> Result<Chunk<Box<dyn Array>>> {
+    if schema.fields.len() != chunk.arrays().len() {
+        return Err(Error::InvalidArgumentError(format!(
+            "The schema has {} fields but the chunk has {} arrays",
+            schema.fields.len(),
+            chunk.arrays().len()
+        )));
+    }
+    let arrays = chunk
+        .arrays()
+        .iter()
+        .map(|x| x.as_ref())
+        .collect::<Vec<_>>();
+    let arrays = to
+        .fields
+        .iter()
+        .map(|field| adapt_field(&schema.fields, &arrays, field, chunk.len(), options))
+        .collect::<Result<Vec<_>>>()?;
+    Chunk::try_new_with_schema(to, arrays)
+}
@@ -0,0 +1,71 @@
+use crate::array::{growable::make_growable, Array, UnionArray};
+use crate::datatypes::{DataType, UnionMode};
+
+fn with_mode(data_type: &DataType, mode: UnionMode) -> DataType {
+    match data_type {
+        DataType::Union(fields, ids, _) => DataType::Union(fields.clone(), ids.clone(), mode),
+        DataType::Extension(name, inner, metadata) => DataType::Extension(
+            name.clone(),
+            Box::new(with_mode(inner, mode)),
+            metadata.clone(),
+        ),
+        _ => unreachable!(),
+    }
+}
+
+/// Returns a dense [`UnionArray`] with the same values as `from`. The fields of the result
+/// only contain the values selected by its slots.
+/// # Implementation
+/// This operation is `O(N * F)` where `N` is the length of `from` and `F` its number of fields.
+pub fn union_to_dense(from: &UnionArray) -> UnionArray {
+    let fields = (0..from.fields().len())
+        .map(|field| from.child_selected(field))
+        .collect();
+
+    let mut lengths = vec![0i32; from.fields().len()];
+    let offsets = (0..from.len())
+        .map(|index| {
+            let (field, _) = from.index(index);
+            lengths[field] += 1;
+            lengths[field] - 1
+        })
+        .collect::<Vec<_>>();
+
+    UnionArray::new(
+        with_mode(from.data_type(), UnionMode::Dense),
+        from.types().clone(),
+        fields,
+        Some(offsets.into()),
+    )
+}
+
+/// Returns a sparse [`UnionArray`] with the same values as `from`. Every field of the result
+/// has the length of `from`, and is null on the slots of the other fields.
+/// # Implementation
+/// This operation is `O(N * F)` where `N` is the length of `from` and `F` its number of fields.
+pub fn union_to_sparse(from: &UnionArray) -> UnionArray {
+    let fields = from
+        .fields()
+        .iter()
+        .enumerate()
+        .map(|(field, values)| {
+            let mut growable = make_growable(&[values.as_ref()], true, from.len());
+            (0..from.len()).for_each(|index| {
+                let (type_, slot) = from.index(index);
+                if type_ == field {
+                    growable.extend(0, slot, 1)
+                } else {
+                    growable.extend_validity(1)
+                }
+            });
+            growable.as_box()
+        })
+        .collect();
+
+    UnionArray::new(
+        with_mode(from.data_type(), UnionMode::Sparse),
+        from.types().clone(),
+        fields,
+        None,
+    )
+}
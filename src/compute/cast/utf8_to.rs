@@ -1,12 +1,15 @@
-use chrono::Datelike;
+use chrono::format::{parse, Item, Parsed, StrftimeItems};
+use chrono::{DateTime, Datelike};
 
+#[cfg(feature = "chrono-tz")]
+use crate::temporal_conversions::parse_offset_tz;
 use crate::{
     array::*,
-    datatypes::DataType,
+    datatypes::{DataType, TimeUnit},
     error::Result,
     offset::Offset,
     temporal_conversions::{
-        utf8_to_naive_timestamp_ns as utf8_to_naive_timestamp_ns_,
+        parse_offset, utf8_to_naive_timestamp_ns as utf8_to_naive_timestamp_ns_,
         utf8_to_timestamp_ns as utf8_to_timestamp_ns_, EPOCH_DAYS_FROM_CE,
     },
     types::NativeType,
@@ -143,6 +146,121 @@ pub fn utf8_to_timestamp_ns<O: Offset>(
     utf8_to_timestamp_ns_(from, RFC3339, timezone)
 }
 
+/// Returns `format` where `%S` also parses an optional fraction of a second, unless `format`
+/// already defines how fractions of a second are parsed.
+fn with_optional_fraction(format: &str) -> String {
+    if format.contains("%.") || format.contains("%f") {
+        format.to_string()
+    } else {
+        format.replace("%S", "%S%.f")
+    }
+}
+
+/// Parses `value` with the first of `formats` that parses it, starting with `formats[*last]`,
+/// and sets `last` to the format that parsed it.
+fn parse_multi(value: &str, formats: &[Vec<Item>], last: &mut usize) -> Option<Parsed> {
+    let start = *last;
+    let order = std::iter::once(start).chain((0..formats.len()).filter(move |i| *i != start));
+    order.take(formats.len()).find_map(|i| {
+        let mut parsed = Parsed::new();
+        parse(&mut parsed, value, formats[i].iter()).ok()?;
+        *last = i;
+        Some(parsed)
+    })
+}
+
+/// Errors iff `timezone` is not parsable to an offset.
+fn check_timezone(timezone: &str) -> Result<()> {
+    if parse_offset(timezone).is_ok() {
+        return Ok(());
+    }
+    #[cfg(feature = "chrono-tz")]
+    {
+        parse_offset_tz(timezone).map(|_| ())
+    }
+    #[cfg(not(feature = "chrono-tz"))]
+    {
+        Err(crate::error::Error::InvalidArgumentError(format!(
+            "timezone \"{timezone}\" cannot be parsed (feature chrono-tz is not active)",
+        )))
+    }
+}
+
+/// Parses a [`Utf8Array`] to a timestamp, i.e. [`PrimitiveArray<i64>`] with type
+/// `Timestamp(Nanosecond, timezone)`, trying several formats for each value.
+///
+/// Each value is first parsed as RFC3339 (e.g. `2020-01-01T12:00:00.5+01:00`) and then with the
+/// first of `formats` (in [`chrono::format::strftime`] syntax) that parses it, starting with the
+/// format that parsed the previous value. A `%S` of a format without a fraction of a second
+/// also accepts an optional fraction (e.g. `%H:%M:%S` parses both `12:00:00` and `12:00:00.123`).
+/// # Implementation
+/// * when `timezone` is `None`, offsets are ignored (like [`utf8_to_naive_timestamp_ns`]).
+/// * when `timezone` is `Some`, values without an offset are null (like [`utf8_to_timestamp_ns`]).
+/// * Null elements remain null; non-parsable elements are null.
+/// # Error
+/// This function errors iff `timezone` is not parsable to an offset.
+/// # Example
+/// ```
+/// use arrow2::array::{Int64Array, Utf8Array};
+/// use arrow2::compute::cast::utf8_to_timestamp_multi;
+/// use arrow2::datatypes::{DataType, TimeUnit};
+///
+/// let array = Utf8Array::<i32>::from_slice([
+///     "1970-01-01T00:00:01Z",
+///     "1970-01-01 00:00:02.5",
+///     "01/01/1970 00:00:03",
+/// ]);
+/// let result =
+///     utf8_to_timestamp_multi(&array, &["%Y-%m-%d %H:%M:%S", "%d/%m/%Y %H:%M:%S"], None).unwrap();
+/// let expected = Int64Array::from_slice([1_000_000_000, 2_500_000_000, 3_000_000_000])
+///     .to(DataType::Timestamp(TimeUnit::Nanosecond, None));
+/// assert_eq!(result, expected);
+/// ```
+pub fn utf8_to_timestamp_multi<O: Offset>(
+    from: &Utf8Array<O>,
+    formats: &[&str],
+    timezone: Option<String>,
+) -> Result<PrimitiveArray<i64>> {
+    if let Some(timezone) = &timezone {
+        check_timezone(timezone)?;
+    }
+
+    let formats = formats
+        .iter()
+        .map(|format| with_optional_fraction(format))
+        .collect::<Vec<_>>();
+    let formats = formats
+        .iter()
+        .map(|format| StrftimeItems::new(format).collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+
+    let aware = timezone.is_some();
+    let mut last = 0;
+    let iter = from.iter().map(|value| {
+        let value = value?;
+        if let Ok(datetime) = DateTime::parse_from_rfc3339(value) {
+            return if aware {
+                datetime.timestamp_nanos_opt()
+            } else {
+                datetime.naive_local().and_utc().timestamp_nanos_opt()
+            };
+        }
+        let parsed = parse_multi(value, &formats, &mut last)?;
+        if aware {
+            parsed.to_datetime().ok()?.timestamp_nanos_opt()
+        } else {
+            parsed
+                .to_naive_datetime_with_offset(0)
+                .ok()?
+                .and_utc()
+                .timestamp_nanos_opt()
+        }
+    });
+
+    Ok(PrimitiveArray::from_trusted_len_iter(iter)
+        .to(DataType::Timestamp(TimeUnit::Nanosecond, timezone)))
+}
+
 /// Conversion of utf8
 pub fn utf8_to_large_utf8(from: &Utf8Array<i32>) -> Utf8Array<i64> {
     let data_type = Utf8Array::<i64>::default_data_type();
@@ -177,3 +295,29 @@ pub fn utf8_to_binary<O: Offset>(from: &Utf8Array<O>, to_data_type: DataType) ->
         )
     }
 }
+
+/// Conversion of utf8 to large binary
+pub fn utf8_to_large_binary(from: &Utf8Array<i32>, to_data_type: DataType) -> BinaryArray<i64> {
+    BinaryArray::<i64>::new(
+        to_data_type,
+        from.offsets().into(),
+        from.values().clone(),
+        from.validity().cloned(),
+    )
+}
+
+/// Conversion of large utf8 to binary
+/// # Errors
+/// This function errors if the values do not fit in `i32` offsets
+pub fn utf8_large_to_binary(
+    from: &Utf8Array<i64>,
+    to_data_type: DataType,
+) -> Result<BinaryArray<i32>> {
+    let offsets = from.offsets().try_into()?;
+    Ok(BinaryArray::<i32>::new(
+        to_data_type,
+        offsets,
+        from.values().clone(),
+        from.validity().cloned(),
+    ))
+}
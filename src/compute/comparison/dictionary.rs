@@ -0,0 +1,279 @@
+//! Comparison functions for [`DictionaryArray`]s.
+//!
+//! The values of both dictionaries are ranked once (see [`dense_ranks`]), and the items are
+//! compared by the ranks of their keys' values, so that the values are never materialized.
+//! Items whose key or value is null are null.
+//!
+//! A [`DictionaryArray`] can also be compared with an [`Array`] of the type of its values
+//! (e.g. [`lt_plain`]), which is ranked together with the values, and with a [`Scalar`] (e.g.
+//! [`lt_scalar`]), which is compared to the values once and broadcasted through the keys.
+use crate::array::{
+    ord::{dense_ranks, dictionary_ranks},
+    Array, BooleanArray, DictionaryArray, DictionaryKey, PrimitiveArray,
+};
+use crate::bitmap::{Bitmap, MutableBitmap};
+use crate::datatypes::{DataType, PhysicalType};
+use crate::scalar::{DictionaryScalar, Scalar};
+
+use super::{finish_eq_validities, finish_neq_validities, primitive};
+
+/// Returns the ranks of the items of `lhs` and `rhs` when their values are ordered together.
+/// # Panics
+/// Panics iff the values of the dictionaries have no natural order or differ in type
+fn ranks<K: DictionaryKey>(
+    lhs: &DictionaryArray<K>,
+    rhs: &DictionaryArray<K>,
+) -> (PrimitiveArray<u64>, PrimitiveArray<u64>) {
+    let (lhs_ranks, rhs_ranks) =
+        dense_ranks(lhs.values().as_ref(), rhs.values().as_ref()).unwrap_or_else(|e| panic!("{e}"));
+    (
+        dictionary_ranks(lhs, &lhs_ranks),
+        dictionary_ranks(rhs, &rhs_ranks),
+    )
+}
+
+/// Returns the ranks of the items of `lhs` and `rhs` when the values of `lhs` and `rhs` are
+/// ordered together.
+/// # Panics
+/// Panics iff `rhs` has no natural order or differs in type from the values of `lhs`
+fn plain_ranks<K: DictionaryKey>(
+    lhs: &DictionaryArray<K>,
+    rhs: &dyn Array,
+) -> (PrimitiveArray<u64>, PrimitiveArray<u64>) {
+    let (lhs_ranks, rhs_ranks) =
+        dense_ranks(lhs.values().as_ref(), rhs).unwrap_or_else(|e| panic!("{e}"));
+    let rhs_ranks = rhs_ranks
+        .into_iter()
+        .map(|rank| rank as u64)
+        .collect::<Vec<_>>();
+    (
+        dictionary_ranks(lhs, &lhs_ranks),
+        PrimitiveArray::new(DataType::UInt64, rhs_ranks.into(), rhs.validity().cloned()),
+    )
+}
+
+macro_rules! dictionary_op {
+    ($(#[$doc:meta])* $name:ident) => {
+        $(#[$doc])*
+        pub fn $name<K: DictionaryKey>(
+            lhs: &DictionaryArray<K>,
+            rhs: &DictionaryArray<K>,
+        ) -> BooleanArray {
+            let (lhs, rhs) = ranks(lhs, rhs);
+            primitive::$name(&lhs, &rhs)
+        }
+    };
+}
+
+dictionary_op!(
+    /// Perform `lhs == rhs` operation on two [`DictionaryArray`]s.
+    eq
+);
+dictionary_op!(
+    /// Perform `lhs == rhs` operation on two [`DictionaryArray`]s and include validities in comparison.
+    eq_and_validity
+);
+dictionary_op!(
+    /// Perform `lhs != rhs` operation on two [`DictionaryArray`]s.
+    neq
+);
+dictionary_op!(
+    /// Perform `lhs != rhs` operation on two [`DictionaryArray`]s and include validities in comparison.
+    neq_and_validity
+);
+dictionary_op!(
+    /// Perform `lhs < rhs` operation on two [`DictionaryArray`]s.
+    lt
+);
+dictionary_op!(
+    /// Perform `lhs <= rhs` operation on two [`DictionaryArray`]s.
+    lt_eq
+);
+dictionary_op!(
+    /// Perform `lhs > rhs` operation on two [`DictionaryArray`]s.
+    gt
+);
+dictionary_op!(
+    /// Perform `lhs >= rhs` operation on two [`DictionaryArray`]s.
+    gt_eq
+);
+
+macro_rules! plain_op {
+    ($(#[$doc:meta])* $name:ident, $op:ident) => {
+        $(#[$doc])*
+        /// # Panics
+        /// Panics iff `rhs` differs in type from the values of `lhs`, has no natural order or
+        /// differs in length from `lhs`.
+        pub fn $name<K: DictionaryKey>(lhs: &DictionaryArray<K>, rhs: &dyn Array) -> BooleanArray {
+            let (lhs, rhs) = plain_ranks(lhs, rhs);
+            primitive::$op(&lhs, &rhs)
+        }
+    };
+}
+
+plain_op!(
+    /// Perform `lhs == rhs` operation on a [`DictionaryArray`] and an [`Array`] of the type of its values.
+    eq_plain,
+    eq
+);
+plain_op!(
+    /// Perform `lhs == rhs` operation on a [`DictionaryArray`] and an [`Array`] of the type of its values
+    /// and include validities in comparison.
+    eq_plain_and_validity,
+    eq_and_validity
+);
+plain_op!(
+    /// Perform `lhs != rhs` operation on a [`DictionaryArray`] and an [`Array`] of the type of its values.
+    neq_plain,
+    neq
+);
+plain_op!(
+    /// Perform `lhs != rhs` operation on a [`DictionaryArray`] and an [`Array`] of the type of its values
+    /// and include validities in comparison.
+    neq_plain_and_validity,
+    neq_and_validity
+);
+plain_op!(
+    /// Perform `lhs < rhs` operation on a [`DictionaryArray`] and an [`Array`] of the type of its values.
+    lt_plain,
+    lt
+);
+plain_op!(
+    /// Perform `lhs <= rhs` operation on a [`DictionaryArray`] and an [`Array`] of the type of its values.
+    lt_eq_plain,
+    lt_eq
+);
+plain_op!(
+    /// Perform `lhs > rhs` operation on a [`DictionaryArray`] and an [`Array`] of the type of its values.
+    gt_plain,
+    gt
+);
+plain_op!(
+    /// Perform `lhs >= rhs` operation on a [`DictionaryArray`] and an [`Array`] of the type of its values.
+    gt_eq_plain,
+    gt_eq
+);
+
+/// Returns the value of `scalar`, unwrapping it if it is a [`DictionaryScalar`], or `None` if
+/// it is null.
+fn scalar_value(scalar: &dyn Scalar) -> Option<&dyn Scalar> {
+    let value = match scalar.data_type().to_physical_type() {
+        PhysicalType::Dictionary(key_type) => match_integer_type!(key_type, |$T| {
+            scalar
+                .as_any()
+                .downcast_ref::<DictionaryScalar<$T>>()
+                .unwrap()
+                .value()?
+                .as_ref()
+        }),
+        _ => scalar,
+    };
+    value.is_valid().then_some(value)
+}
+
+/// Broadcasts `values`, the result of comparing the values of `array` to a scalar, to the items
+/// of `array`. When either none or all of the values match, the result is a constant whose
+/// validity is the validity of the keys, and the keys are not traversed.
+fn broadcast<K: DictionaryKey>(values: &BooleanArray, array: &DictionaryArray<K>) -> BooleanArray {
+    let keys = array.keys();
+    if values.null_count() == 0 {
+        let bits = match values.values().unset_bits() {
+            unset if unset == values.len() => Some(Bitmap::new_zeroed(keys.len())),
+            0 => Some(MutableBitmap::from_len_set(keys.len()).into()),
+            _ => None,
+        };
+        if let Some(bits) = bits {
+            return BooleanArray::new(DataType::Boolean, bits, keys.validity().cloned());
+        }
+    }
+
+    // null keys may be out of bounds of the values
+    let bits = keys.values().iter().map(|key| {
+        let key = unsafe { key.as_usize() };
+        key < values.len() && values.values().get_bit(key)
+    });
+    let bits = Bitmap::from_trusted_len_iter(bits);
+    let validity = match values.validity() {
+        Some(values_validity) if values_validity.unset_bits() > 0 => {
+            let validity = keys.iter().map(|key| {
+                key.map(|key| values_validity.get_bit(unsafe { key.as_usize() }))
+                    .unwrap_or(false)
+            });
+            Some(Bitmap::from_trusted_len_iter(validity))
+        }
+        _ => keys.validity().cloned(),
+    };
+    BooleanArray::new(DataType::Boolean, bits, validity)
+}
+
+macro_rules! scalar_op {
+    ($(#[$doc:meta])* $name:ident) => {
+        $(#[$doc])*
+        ///
+        /// `rhs` is either of the type of the values of `lhs` or a [`DictionaryScalar`] thereof.
+        /// The result is null when `rhs` is null.
+        /// # Panics
+        /// Panics iff `rhs` differs in type from the values of `lhs`.
+        pub fn $name<K: DictionaryKey>(lhs: &DictionaryArray<K>, rhs: &dyn Scalar) -> BooleanArray {
+            let rhs = match scalar_value(rhs) {
+                Some(rhs) => rhs,
+                None => return BooleanArray::new_null(DataType::Boolean, lhs.len()),
+            };
+            let values = super::$name(lhs.values().as_ref(), rhs);
+            broadcast(&values, lhs)
+        }
+    };
+}
+
+scalar_op!(
+    /// Perform `lhs == rhs` operation on a [`DictionaryArray`] and a [`Scalar`].
+    eq_scalar
+);
+scalar_op!(
+    /// Perform `lhs != rhs` operation on a [`DictionaryArray`] and a [`Scalar`].
+    neq_scalar
+);
+scalar_op!(
+    /// Perform `lhs < rhs` operation on a [`DictionaryArray`] and a [`Scalar`].
+    lt_scalar
+);
+scalar_op!(
+    /// Perform `lhs <= rhs` operation on a [`DictionaryArray`] and a [`Scalar`].
+    lt_eq_scalar
+);
+scalar_op!(
+    /// Perform `lhs > rhs` operation on a [`DictionaryArray`] and a [`Scalar`].
+    gt_scalar
+);
+scalar_op!(
+    /// Perform `lhs >= rhs` operation on a [`DictionaryArray`] and a [`Scalar`].
+    gt_eq_scalar
+);
+
+/// Perform `lhs == rhs` operation on a [`DictionaryArray`] and a [`Scalar`] and include
+/// validities in comparison. See [`eq_scalar`] for the supported scalars.
+pub fn eq_scalar_and_validity<K: DictionaryKey>(
+    lhs: &DictionaryArray<K>,
+    rhs: &dyn Scalar,
+) -> BooleanArray {
+    if scalar_value(rhs).is_none() {
+        return BooleanArray::new_null(DataType::Boolean, lhs.len());
+    }
+    let out = eq_scalar(lhs, rhs);
+    let validity = out.validity().cloned();
+    finish_eq_validities(out.with_validity(None), validity, None)
+}
+
+/// Perform `lhs != rhs` operation on a [`DictionaryArray`] and a [`Scalar`] and include
+/// validities in comparison. See [`neq_scalar`] for the supported scalars.
+pub fn neq_scalar_and_validity<K: DictionaryKey>(
+    lhs: &DictionaryArray<K>,
+    rhs: &dyn Scalar,
+) -> BooleanArray {
+    if scalar_value(rhs).is_none() {
+        return BooleanArray::new_null(DataType::Boolean, lhs.len());
+    }
+    let out = neq_scalar(lhs, rhs);
+    let validity = out.validity().cloned();
+    finish_neq_validities(out.with_validity(None), validity, None)
+}
@@ -2,7 +2,8 @@
 //!
 //! The module contains functions that compare either an [`Array`] and a [`Scalar`]
 //! or two [`Array`]s (of the same [`DataType`]). The scalar-oriented functions are
-//! suffixed with `_scalar`.
+//! suffixed with `_scalar`. A [`DictionaryArray`] can also be compared with an [`Array`] or a
+//! [`Scalar`] of the [`DataType`] of its values, without decoding it (see [`dictionary`]).
 //!
 //! The functions are organized in two variants:
 //! * statically typed
@@ -50,15 +51,16 @@ use crate::scalar::*;
 
 pub mod binary;
 pub mod boolean;
+pub mod dictionary;
 pub mod primitive;
 pub mod utf8;
 
 mod simd;
 pub use simd::{Simd8, Simd8Lanes, Simd8PartialEq, Simd8PartialOrd};
 
-use super::take::take_boolean;
 use crate::bitmap::{binary, Bitmap};
 use crate::compute;
+use crate::compute::utils::combine_validities;
 pub(crate) use primitive::{
     compare_values_op as primitive_compare_values_op,
     compare_values_op_scalar as primitive_compare_values_op_scalar,
@@ -69,7 +71,7 @@ macro_rules! match_eq_ord {(
 ) => ({
     macro_rules! __with_ty__ {( $_ $T:ident ) => ( $($body)* )}
     use crate::datatypes::PrimitiveType::*;
-    use crate::types::i256;
+    use crate::types::{f16, i256};
     match $key_type {
         Int8 => __with_ty__! { i8 },
         Int16 => __with_ty__! { i16 },
@@ -83,7 +85,7 @@ macro_rules! match_eq_ord {(
         UInt16 => __with_ty__! { u16 },
         UInt32 => __with_ty__! { u32 },
         UInt64 => __with_ty__! { u64 },
-        Float16 => todo!(),
+        Float16 => __with_ty__! { f16 },
         Float32 => __with_ty__! { f32 },
         Float64 => __with_ty__! { f64 },
     }
@@ -115,9 +117,34 @@ macro_rules! match_eq {(
 })}
 
 macro_rules! compare {
-    ($lhs:expr, $rhs:expr, $op:tt, $p:tt) => {{
+    ($lhs:expr, $rhs:expr, $op:tt, $p:tt, $plain_op:tt, $mirrored_plain_op:tt) => {{
         let lhs = $lhs;
         let rhs = $rhs;
+
+        // a dictionary compared with an array of the type of its values
+        match (
+            lhs.data_type().to_logical_type(),
+            rhs.data_type().to_logical_type(),
+        ) {
+            (DataType::Dictionary(key_type, values, _), rhs_type)
+                if values.to_logical_type() == rhs_type =>
+            {
+                return match_integer_type!(key_type, |$T| {
+                    let lhs = lhs.as_any().downcast_ref().unwrap();
+                    dictionary::$plain_op::<$T>(lhs, rhs)
+                });
+            }
+            (lhs_type, DataType::Dictionary(key_type, values, _))
+                if values.to_logical_type() == lhs_type =>
+            {
+                return match_integer_type!(key_type, |$T| {
+                    let rhs = rhs.as_any().downcast_ref().unwrap();
+                    dictionary::$mirrored_plain_op::<$T>(rhs, lhs)
+                });
+            }
+            _ => {}
+        }
+
         assert_eq!(
             lhs.data_type().to_logical_type(),
             rhs.data_type().to_logical_type()
@@ -155,6 +182,13 @@ macro_rules! compare {
                 let rhs = rhs.as_any().downcast_ref().unwrap();
                 binary::$op::<i64>(lhs, rhs)
             }
+            Dictionary(key_type) => {
+                match_integer_type!(key_type, |$T| {
+                    let lhs = lhs.as_any().downcast_ref().unwrap();
+                    let rhs = rhs.as_any().downcast_ref().unwrap();
+                    dictionary::$op::<$T>(lhs, rhs)
+                })
+            }
             _ => todo!(
                 "Comparison between {:?} are not yet supported",
                 lhs.data_type()
@@ -167,22 +201,29 @@ macro_rules! compare {
 /// Use [`can_eq`] to check whether the operation is valid
 /// # Panic
 /// Panics iff either:
-/// * the arrays do not have have the same logical type
+/// * the arrays do not have have the same logical type, nor is one a dictionary of the other's type
 /// * the arrays do not have the same length
 /// * the operation is not supported for the logical type
 pub fn eq(lhs: &dyn Array, rhs: &dyn Array) -> BooleanArray {
-    compare!(lhs, rhs, eq, match_eq)
+    compare!(lhs, rhs, eq, match_eq, eq_plain, eq_plain)
 }
 
 /// `==` between two [`Array`]s and includes validities in comparison.
 /// Use [`can_eq`] to check whether the operation is valid
 /// # Panic
 /// Panics iff either:
-/// * the arrays do not have have the same logical type
+/// * the arrays do not have have the same logical type, nor is one a dictionary of the other's type
 /// * the arrays do not have the same length
 /// * the operation is not supported for the logical type
 pub fn eq_and_validity(lhs: &dyn Array, rhs: &dyn Array) -> BooleanArray {
-    compare!(lhs, rhs, eq_and_validity, match_eq)
+    compare!(
+        lhs,
+        rhs,
+        eq_and_validity,
+        match_eq,
+        eq_plain_and_validity,
+        eq_plain_and_validity
+    )
 }
 
 /// Returns whether a [`DataType`] is comparable is supported by [`eq`].
@@ -194,22 +235,29 @@ pub fn can_eq(data_type: &DataType) -> bool {
 /// Use [`can_neq`] to check whether the operation is valid
 /// # Panic
 /// Panics iff either:
-/// * the arrays do not have have the same logical type
+/// * the arrays do not have have the same logical type, nor is one a dictionary of the other's type
 /// * the arrays do not have the same length
 /// * the operation is not supported for the logical type
 pub fn neq(lhs: &dyn Array, rhs: &dyn Array) -> BooleanArray {
-    compare!(lhs, rhs, neq, match_eq)
+    compare!(lhs, rhs, neq, match_eq, neq_plain, neq_plain)
 }
 
 /// `!=` between two [`Array`]s and includes validities in comparison.
 /// Use [`can_neq`] to check whether the operation is valid
 /// # Panic
 /// Panics iff either:
-/// * the arrays do not have have the same logical type
+/// * the arrays do not have have the same logical type, nor is one a dictionary of the other's type
 /// * the arrays do not have the same length
 /// * the operation is not supported for the logical type
 pub fn neq_and_validity(lhs: &dyn Array, rhs: &dyn Array) -> BooleanArray {
-    compare!(lhs, rhs, neq_and_validity, match_eq)
+    compare!(
+        lhs,
+        rhs,
+        neq_and_validity,
+        match_eq,
+        neq_plain_and_validity,
+        neq_plain_and_validity
+    )
 }
 
 /// Returns whether a [`DataType`] is comparable is supported by [`neq`].
@@ -221,11 +269,11 @@ pub fn can_neq(data_type: &DataType) -> bool {
 /// Use [`can_lt`] to check whether the operation is valid
 /// # Panic
 /// Panics iff either:
-/// * the arrays do not have have the same logical type
+/// * the arrays do not have have the same logical type, nor is one a dictionary of the other's type
 /// * the arrays do not have the same length
 /// * the operation is not supported for the logical type
 pub fn lt(lhs: &dyn Array, rhs: &dyn Array) -> BooleanArray {
-    compare!(lhs, rhs, lt, match_eq_ord)
+    compare!(lhs, rhs, lt, match_eq_ord, lt_plain, gt_plain)
 }
 
 /// Returns whether a [`DataType`] is comparable is supported by [`lt`].
@@ -237,11 +285,11 @@ pub fn can_lt(data_type: &DataType) -> bool {
 /// Use [`can_lt_eq`] to check whether the operation is valid
 /// # Panic
 /// Panics iff either:
-/// * the arrays do not have have the same logical type
+/// * the arrays do not have have the same logical type, nor is one a dictionary of the other's type
 /// * the arrays do not have the same length
 /// * the operation is not supported for the logical type
 pub fn lt_eq(lhs: &dyn Array, rhs: &dyn Array) -> BooleanArray {
-    compare!(lhs, rhs, lt_eq, match_eq_ord)
+    compare!(lhs, rhs, lt_eq, match_eq_ord, lt_eq_plain, gt_eq_plain)
 }
 
 /// Returns whether a [`DataType`] is comparable is supported by [`lt`].
@@ -253,11 +301,11 @@ pub fn can_lt_eq(data_type: &DataType) -> bool {
 /// Use [`can_gt`] to check whether the operation is valid
 /// # Panic
 /// Panics iff either:
-/// * the arrays do not have have the same logical type
+/// * the arrays do not have have the same logical type, nor is one a dictionary of the other's type
 /// * the arrays do not have the same length
 /// * the operation is not supported for the logical type
 pub fn gt(lhs: &dyn Array, rhs: &dyn Array) -> BooleanArray {
-    compare!(lhs, rhs, gt, match_eq_ord)
+    compare!(lhs, rhs, gt, match_eq_ord, gt_plain, lt_plain)
 }
 
 /// Returns whether a [`DataType`] is comparable is supported by [`gt`].
@@ -269,11 +317,11 @@ pub fn can_gt(data_type: &DataType) -> bool {
 /// Use [`can_gt_eq`] to check whether the operation is valid
 /// # Panic
 /// Panics iff either:
-/// * the arrays do not have have the same logical type
+/// * the arrays do not have have the same logical type, nor is one a dictionary of the other's type
 /// * the arrays do not have the same length
 /// * the operation is not supported for the logical type
 pub fn gt_eq(lhs: &dyn Array, rhs: &dyn Array) -> BooleanArray {
-    compare!(lhs, rhs, gt_eq, match_eq_ord)
+    compare!(lhs, rhs, gt_eq, match_eq_ord, gt_eq_plain, lt_eq_plain)
 }
 
 /// Returns whether a [`DataType`] is comparable is supported by [`gt_eq`].
@@ -281,17 +329,124 @@ pub fn can_gt_eq(data_type: &DataType) -> bool {
     can_partial_eq_and_ord(data_type)
 }
 
+/// Returns, for every slot, whether the slots of `lhs` and `rhs` are equal, where two null
+/// slots are equal and a null slot is different from a non-null slot.
+/// This is used for types whose equality is not supported by [`eq`] (e.g. nested types).
+fn eq_slots(lhs: &dyn Array, rhs: &dyn Array) -> Bitmap {
+    assert_eq!(
+        lhs.data_type().to_logical_type(),
+        rhs.data_type().to_logical_type()
+    );
+    assert_eq!(lhs.len(), rhs.len());
+    (0..lhs.len())
+        .map(|i| equal(lhs.slice(i, 1).as_ref(), rhs.slice(i, 1).as_ref()))
+        .collect()
+}
+
+/// Returns the validity of the result of comparing `lhs` and `rhs` in Kleene logic.
+fn kleene_validity(lhs: &dyn Array, rhs: &dyn Array) -> Option<Bitmap> {
+    if lhs.data_type().to_logical_type() == &DataType::Null {
+        // a [`NullArray`] has no validity but all its slots are null
+        return Some(Bitmap::new_zeroed(lhs.len()));
+    }
+    combine_validities(lhs.validity(), rhs.validity())
+}
+
+/// `==` between two [`Array`]s of any [`DataType`] in Kleene logic, like SQL's `=`: a slot is
+/// null iff either of its sides is null.
+///
+/// Types supported by [`eq`] are compared by [`eq`] and all other types (e.g. nested types) are
+/// compared slot by slot, where the nulls inside non-null slots are equal to each other.
+/// # Panic
+/// Panics iff the arrays do not have the same logical type or the same length.
+/// # Example
+/// ```
+/// use arrow2::array::{BooleanArray, Int32Array};
+/// use arrow2::compute::comparison::eq_kleene;
+///
+/// let lhs = Int32Array::from([Some(1), None, Some(2), None]);
+/// let rhs = Int32Array::from([Some(1), Some(1), Some(1), None]);
+/// let result = eq_kleene(&lhs, &rhs);
+/// assert_eq!(result, BooleanArray::from([Some(true), None, Some(false), None]));
+/// ```
+pub fn eq_kleene(lhs: &dyn Array, rhs: &dyn Array) -> BooleanArray {
+    if can_eq(lhs.data_type()) {
+        return eq(lhs, rhs);
+    }
+    let values = eq_slots(lhs, rhs);
+    BooleanArray::new(DataType::Boolean, values, kleene_validity(lhs, rhs))
+}
+
+/// `!=` between two [`Array`]s of any [`DataType`] in Kleene logic, like SQL's `<>`: a slot is
+/// null iff either of its sides is null.
+///
+/// See [`eq_kleene`] for how the values are compared.
+/// # Panic
+/// Panics iff the arrays do not have the same logical type or the same length.
+pub fn neq_kleene(lhs: &dyn Array, rhs: &dyn Array) -> BooleanArray {
+    if can_neq(lhs.data_type()) {
+        return neq(lhs, rhs);
+    }
+    let values = !&eq_slots(lhs, rhs);
+    BooleanArray::new(DataType::Boolean, values, kleene_validity(lhs, rhs))
+}
+
+/// Returns whether the slots of two [`Array`]s of any [`DataType`] differ, like SQL's
+/// `IS DISTINCT FROM`: two null slots are not distinct and a null slot is distinct from a
+/// non-null slot. The result has no nulls.
+///
+/// See [`eq_kleene`] for how the values are compared.
+/// # Panic
+/// Panics iff the arrays do not have the same logical type or the same length.
+/// # Example
+/// ```
+/// use arrow2::array::{BooleanArray, Int32Array};
+/// use arrow2::compute::comparison::is_distinct_from;
+///
+/// let lhs = Int32Array::from([Some(1), None, Some(2), None]);
+/// let rhs = Int32Array::from([Some(1), Some(1), Some(1), None]);
+/// let result = is_distinct_from(&lhs, &rhs);
+/// assert_eq!(result, BooleanArray::from_slice([false, true, true, false]));
+/// ```
+pub fn is_distinct_from(lhs: &dyn Array, rhs: &dyn Array) -> BooleanArray {
+    if can_neq(lhs.data_type()) {
+        return neq_and_validity(lhs, rhs);
+    }
+    BooleanArray::new(DataType::Boolean, !&eq_slots(lhs, rhs), None)
+}
+
+/// Returns whether the slots of two [`Array`]s of any [`DataType`] are equal, like SQL's
+/// `IS NOT DISTINCT FROM`: two null slots are not distinct and a null slot is distinct from a
+/// non-null slot. The result has no nulls.
+///
+/// See [`eq_kleene`] for how the values are compared.
+/// # Panic
+/// Panics iff the arrays do not have the same logical type or the same length.
+pub fn is_not_distinct_from(lhs: &dyn Array, rhs: &dyn Array) -> BooleanArray {
+    if can_eq(lhs.data_type()) {
+        return eq_and_validity(lhs, rhs);
+    }
+    BooleanArray::new(DataType::Boolean, eq_slots(lhs, rhs), None)
+}
+
 macro_rules! compare_scalar {
     ($lhs:expr, $rhs:expr, $op:tt, $p:tt) => {{
         let lhs = $lhs;
         let rhs = $rhs;
+        if !rhs.is_valid() {
+            return BooleanArray::new_null(DataType::Boolean, lhs.len());
+        }
+        // the scalar is either of the type of the values or a dictionary thereof
+        if let DataType::Dictionary(key_type, _, _) = lhs.data_type().to_logical_type() {
+            return match_integer_type!(key_type, |$T| {
+                let lhs = lhs.as_any().downcast_ref().unwrap();
+                dictionary::$op::<$T>(lhs, rhs)
+            });
+        }
         assert_eq!(
             lhs.data_type().to_logical_type(),
             rhs.data_type().to_logical_type()
         );
-        if !rhs.is_valid() {
-            return BooleanArray::new_null(DataType::Boolean, lhs.len());
-        }
 
         use crate::datatypes::PhysicalType::*;
         match lhs.data_type().to_physical_type() {
@@ -326,14 +481,6 @@ macro_rules! compare_scalar {
                 let rhs = rhs.as_any().downcast_ref::<BinaryScalar<i64>>().unwrap();
                 binary::$op::<i64>(lhs, rhs.value().unwrap())
             }
-            Dictionary(key_type) => {
-                match_integer_type!(key_type, |$T| {
-                    let lhs = lhs.as_any().downcast_ref::<DictionaryArray<$T>>().unwrap();
-                    let values = $op(lhs.values().as_ref(), rhs);
-
-                    take_boolean(&values, lhs.keys())
-                })
-            }
             _ => todo!("Comparisons of {:?} are not yet supported", lhs.data_type()),
         }
     }};
@@ -343,7 +490,7 @@ macro_rules! compare_scalar {
 /// Use [`can_eq_scalar`] to check whether the operation is valid
 /// # Panic
 /// Panics iff either:
-/// * they do not have have the same logical type
+/// * they do not have have the same logical type, nor is `lhs` a dictionary of the type of `rhs`
 /// * the operation is not supported for the logical type
 pub fn eq_scalar(lhs: &dyn Array, rhs: &dyn Scalar) -> BooleanArray {
     compare_scalar!(lhs, rhs, eq_scalar, match_eq)
@@ -353,7 +500,7 @@ pub fn eq_scalar(lhs: &dyn Array, rhs: &dyn Scalar) -> BooleanArray {
 /// Use [`can_eq_scalar`] to check whether the operation is valid
 /// # Panic
 /// Panics iff either:
-/// * they do not have have the same logical type
+/// * they do not have have the same logical type, nor is `lhs` a dictionary of the type of `rhs`
 /// * the operation is not supported for the logical type
 pub fn eq_scalar_and_validity(lhs: &dyn Array, rhs: &dyn Scalar) -> BooleanArray {
     compare_scalar!(lhs, rhs, eq_scalar_and_validity, match_eq)
@@ -368,7 +515,7 @@ pub fn can_eq_scalar(data_type: &DataType) -> bool {
 /// Use [`can_neq_scalar`] to check whether the operation is valid
 /// # Panic
 /// Panics iff either:
-/// * they do not have have the same logical type
+/// * they do not have have the same logical type, nor is `lhs` a dictionary of the type of `rhs`
 /// * the operation is not supported for the logical type
 pub fn neq_scalar(lhs: &dyn Array, rhs: &dyn Scalar) -> BooleanArray {
     compare_scalar!(lhs, rhs, neq_scalar, match_eq)
@@ -378,7 +525,7 @@ pub fn neq_scalar(lhs: &dyn Array, rhs: &dyn Scalar) -> BooleanArray {
 /// Use [`can_neq_scalar`] to check whether the operation is valid
 /// # Panic
 /// Panics iff either:
-/// * they do not have have the same logical type
+/// * they do not have have the same logical type, nor is `lhs` a dictionary of the type of `rhs`
 /// * the operation is not supported for the logical type
 pub fn neq_scalar_and_validity(lhs: &dyn Array, rhs: &dyn Scalar) -> BooleanArray {
     compare_scalar!(lhs, rhs, neq_scalar_and_validity, match_eq)
@@ -393,7 +540,7 @@ pub fn can_neq_scalar(data_type: &DataType) -> bool {
 /// Use [`can_lt_scalar`] to check whether the operation is valid
 /// # Panic
 /// Panics iff either:
-/// * they do not have have the same logical type
+/// * they do not have have the same logical type, nor is `lhs` a dictionary of the type of `rhs`
 /// * the operation is not supported for the logical type
 pub fn lt_scalar(lhs: &dyn Array, rhs: &dyn Scalar) -> BooleanArray {
     compare_scalar!(lhs, rhs, lt_scalar, match_eq_ord)
@@ -408,7 +555,7 @@ pub fn can_lt_scalar(data_type: &DataType) -> bool {
 /// Use [`can_lt_eq_scalar`] to check whether the operation is valid
 /// # Panic
 /// Panics iff either:
-/// * they do not have have the same logical type
+/// * they do not have have the same logical type, nor is `lhs` a dictionary of the type of `rhs`
 /// * the operation is not supported for the logical type
 pub fn lt_eq_scalar(lhs: &dyn Array, rhs: &dyn Scalar) -> BooleanArray {
     compare_scalar!(lhs, rhs, lt_eq_scalar, match_eq_ord)
@@ -423,7 +570,7 @@ pub fn can_lt_eq_scalar(data_type: &DataType) -> bool {
 /// Use [`can_gt_scalar`] to check whether the operation is valid
 /// # Panic
 /// Panics iff either:
-/// * they do not have have the same logical type
+/// * they do not have have the same logical type, nor is `lhs` a dictionary of the type of `rhs`
 /// * the operation is not supported for the logical type
 pub fn gt_scalar(lhs: &dyn Array, rhs: &dyn Scalar) -> BooleanArray {
     compare_scalar!(lhs, rhs, gt_scalar, match_eq_ord)
@@ -438,7 +585,7 @@ pub fn can_gt_scalar(data_type: &DataType) -> bool {
 /// Use [`can_gt_eq_scalar`] to check whether the operation is valid
 /// # Panic
 /// Panics iff either:
-/// * they do not have have the same logical type
+/// * they do not have have the same logical type, nor is `lhs` a dictionary of the type of `rhs`
 /// * the operation is not supported for the logical type
 pub fn gt_eq_scalar(lhs: &dyn Array, rhs: &dyn Scalar) -> BooleanArray {
     compare_scalar!(lhs, rhs, gt_eq_scalar, match_eq_ord)
@@ -459,6 +606,10 @@ fn can_partial_eq_and_ord_scalar(data_type: &DataType) -> bool {
 
 // The list of operations currently supported.
 fn can_partial_eq_and_ord(data_type: &DataType) -> bool {
+    if let DataType::Dictionary(_, values, _) = data_type.to_logical_type() {
+        // dictionaries are compared by the ranks of their values
+        return ord::can_build_compare(values.as_ref());
+    }
     matches!(
         data_type,
         DataType::Boolean
@@ -477,6 +628,7 @@ fn can_partial_eq_and_ord(data_type: &DataType) -> bool {
             | DataType::UInt16
             | DataType::UInt32
             | DataType::UInt64
+            | DataType::Float16
             | DataType::Float32
             | DataType::Float64
             | DataType::Utf8
@@ -492,8 +644,7 @@ fn can_partial_eq(data_type: &DataType) -> bool {
     can_partial_eq_and_ord(data_type)
         || matches!(
             data_type.to_logical_type(),
-            DataType::Float16
-                | DataType::Interval(IntervalUnit::DayTime)
+            DataType::Interval(IntervalUnit::DayTime)
                 | DataType::Interval(IntervalUnit::MonthDayNano)
         )
 }
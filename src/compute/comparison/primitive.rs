@@ -1,4 +1,6 @@
 //! Comparison functions for [`PrimitiveArray`]
+use multiversion::multiversion;
+
 use crate::compute::comparison::{finish_eq_validities, finish_neq_validities};
 use crate::{
     array::{BooleanArray, PrimitiveArray},
@@ -10,6 +12,9 @@ use crate::{
 use super::super::utils::combine_validities;
 use super::simd::{Simd8, Simd8Lanes, Simd8PartialEq, Simd8PartialOrd};
 
+#[multiversion]
+#[clone(target = "x86_64+avx2")]
+#[clone(target = "x86_64+avx")]
 pub(crate) fn compare_values_op<T, F>(lhs: &[T], rhs: &[T], op: F) -> MutableBitmap
 where
     T: NativeType + Simd8,
@@ -38,6 +43,9 @@ where
     MutableBitmap::from_vec(values, lhs.len())
 }
 
+#[multiversion]
+#[clone(target = "x86_64+avx2")]
+#[clone(target = "x86_64+avx")]
 pub(crate) fn compare_values_op_scalar<T, F>(lhs: &[T], rhs: T, op: F) -> MutableBitmap
 where
     T: NativeType + Simd8,
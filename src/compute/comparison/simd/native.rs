@@ -13,8 +13,7 @@ simd8_native_all!(i32);
 simd8_native_all!(i128);
 simd8_native_all!(i256);
 simd8_native_all!(i64);
-simd8_native!(f16);
-simd8_native_partial_eq!(f16);
+simd8_native_all!(f16);
 simd8_native_all!(f32);
 simd8_native_all!(f64);
 simd8_native!(days_ms);
@@ -13,10 +13,16 @@
 //! assert_eq!(arr.len(), 3);
 //! ```
 
+use alloc::{boxed::Box, string::ToString, vec::Vec};
+
 use crate::array::{growable::make_growable, Array};
+use crate::datatypes::PhysicalType;
 use crate::error::{Error, Result};
 
 /// Concatenate multiple [Array] of the same type into a single [`Array`].
+///
+/// [`NullArray`](crate::array::NullArray)s can be concatenated with arrays of any other
+/// (non-union) type, in which case their slots are null in the result.
 pub fn concatenate(arrays: &[&dyn Array]) -> Result<Box<dyn Array>> {
     if arrays.is_empty() {
         return Err(Error::InvalidArgumentError(
@@ -24,9 +30,16 @@ pub fn concatenate(arrays: &[&dyn Array]) -> Result<Box<dyn Array>> {
         ));
     }
 
+    let is_null = |array: &&dyn Array| array.data_type().to_physical_type() == PhysicalType::Null;
+    let data_type = arrays
+        .iter()
+        .find(|array| !is_null(array))
+        .unwrap_or(&arrays[0])
+        .data_type();
     if arrays
         .iter()
-        .any(|array| array.data_type() != arrays[0].data_type())
+        .any(|array| !is_null(array) && array.data_type() != data_type)
+        || (data_type.to_physical_type() == PhysicalType::Union && arrays.iter().any(is_null))
     {
         return Err(Error::InvalidArgumentError(
             "It is not possible to concatenate arrays of different data types.".to_string(),
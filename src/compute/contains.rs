@@ -1,15 +1,20 @@
-//! Declares the [`contains`] operator
+//! Declares the [`contains`] and [`in_list`] operators
+
+use std::collections::HashSet;
+
+use ahash::RandomState;
 
 use crate::{
     array::{Array, BinaryArray, BooleanArray, ListArray, PrimitiveArray, Utf8Array},
     bitmap::Bitmap,
-    datatypes::DataType,
+    datatypes::{DataType, PhysicalType},
     error::{Error, Result},
     offset::Offset,
+    trusted_len::TrustedLen,
     types::NativeType,
 };
 
-use super::utils::combine_validities;
+use super::utils::{combine_validities, Bytes};
 
 /// Checks if a [`GenericListArray`] contains a value in the [`PrimitiveArray`]
 /// The validity will be equal to the `And` of both arrays.
@@ -114,85 +119,231 @@ where
     Ok(BooleanArray::new(DataType::Boolean, values, validity))
 }
 
-macro_rules! primitive {
-    ($list:expr, $values:expr, $l_ty:ty, $r_ty:ty) => {{
-        let list = $list.as_any().downcast_ref::<ListArray<$l_ty>>().unwrap();
-        let values = $values
-            .as_any()
-            .downcast_ref::<PrimitiveArray<$r_ty>>()
-            .unwrap();
-        contains_primitive(list, values)
-    }};
+/// Checks if a [`ListArray`] contains a value in the [`BooleanArray`]
+fn contains_boolean<O: Offset>(list: &ListArray<O>, values: &BooleanArray) -> Result<BooleanArray> {
+    if list.len() != values.len() {
+        return Err(Error::InvalidArgumentError(
+            "Contains requires arrays of the same length".to_string(),
+        ));
+    }
+    if list.values().data_type() != values.data_type() {
+        return Err(Error::InvalidArgumentError(
+            "Contains requires the inner array to be of the same logical type".to_string(),
+        ));
+    }
+
+    let validity = combine_validities(list.validity(), values.validity());
+
+    let values = list.iter().zip(values.iter()).map(|(list, values)| {
+        if list.is_none() | values.is_none() {
+            // validity takes care of this
+            return false;
+        };
+        let list = list.unwrap();
+        let list = list.as_any().downcast_ref::<BooleanArray>().unwrap();
+        let values = values.unwrap();
+        list.iter().any(|x| x.map(|x| x == values).unwrap_or(false))
+    });
+    let values = Bitmap::from_trusted_len_iter(values);
+
+    Ok(BooleanArray::new(DataType::Boolean, values, validity))
+}
+
+/// Returns whether each element in `values` is in the element of `list` at the same position.
+///
+/// The validity of the result is the `And` of the validities of `list` and `values`; nulls
+/// inside the lists are never equal to a value.
+/// # Errors
+/// Errors iff
+/// * `list` and `values` have different lengths
+/// * the values of `list` and `values` have different logical types
+/// * the type of `values` is not supported (i.e. is not boolean, primitive, binary or utf8)
+/// # Example
+/// ```
+/// use arrow2::array::{BooleanArray, Int32Array, ListArray, MutableListArray, MutablePrimitiveArray, TryExtend};
+/// use arrow2::compute::contains::contains_list;
+///
+/// let mut list = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+/// list.try_extend([Some(vec![Some(1), Some(2)]), Some(vec![Some(3)]), None]).unwrap();
+/// let list: ListArray<i32> = list.into();
+///
+/// let values = Int32Array::from_slice([2, 2, 2]);
+/// let result = contains_list(&list, &values).unwrap();
+/// assert_eq!(result, BooleanArray::from([Some(true), Some(false), None]));
+/// ```
+pub fn contains_list<O: Offset>(list: &ListArray<O>, values: &dyn Array) -> Result<BooleanArray> {
+    use PhysicalType::*;
+    match values.data_type().to_physical_type() {
+        Boolean => contains_boolean(list, values.as_any().downcast_ref().unwrap()),
+        Primitive(primitive) => with_match_primitive_type!(primitive, |$T| {
+            let values = values.as_any().downcast_ref::<PrimitiveArray<$T>>().unwrap();
+            contains_primitive(list, values)
+        }),
+        Utf8 => contains_utf8::<O, i32>(list, values.as_any().downcast_ref().unwrap()),
+        LargeUtf8 => contains_utf8::<O, i64>(list, values.as_any().downcast_ref().unwrap()),
+        Binary => contains_binary::<O, i32>(list, values.as_any().downcast_ref().unwrap()),
+        LargeBinary => contains_binary::<O, i64>(list, values.as_any().downcast_ref().unwrap()),
+        _ => Err(Error::NotYetImplemented(format!(
+            "Contains is not supported between logical types \"{:?}\" and \"{:?}\"",
+            list.data_type(),
+            values.data_type()
+        ))),
+    }
 }
 
 /// Returns whether each element in `values` is in each element from `list`
 pub fn contains(list: &dyn Array, values: &dyn Array) -> Result<BooleanArray> {
-    let list_data_type = list.data_type();
-    let values_data_type = values.data_type();
-
-    match (list_data_type, values_data_type) {
-        (DataType::List(_), DataType::Utf8) => {
-            let list = list.as_any().downcast_ref::<ListArray<i32>>().unwrap();
-            let values = values.as_any().downcast_ref::<Utf8Array<i32>>().unwrap();
-            contains_utf8(list, values)
-        }
-        (DataType::List(_), DataType::LargeUtf8) => {
-            let list = list.as_any().downcast_ref::<ListArray<i32>>().unwrap();
-            let values = values.as_any().downcast_ref::<Utf8Array<i64>>().unwrap();
-            contains_utf8(list, values)
+    match list.data_type().to_logical_type() {
+        DataType::List(_) => contains_list::<i32>(list.as_any().downcast_ref().unwrap(), values),
+        DataType::LargeList(_) => {
+            contains_list::<i64>(list.as_any().downcast_ref().unwrap(), values)
         }
-        (DataType::LargeList(_), DataType::LargeUtf8) => {
-            let list = list.as_any().downcast_ref::<ListArray<i64>>().unwrap();
-            let values = values.as_any().downcast_ref::<Utf8Array<i64>>().unwrap();
-            contains_utf8(list, values)
-        }
-        (DataType::LargeList(_), DataType::Utf8) => {
-            let list = list.as_any().downcast_ref::<ListArray<i64>>().unwrap();
-            let values = values.as_any().downcast_ref::<Utf8Array<i32>>().unwrap();
-            contains_utf8(list, values)
-        }
-        (DataType::List(_), DataType::Binary) => {
-            let list = list.as_any().downcast_ref::<ListArray<i32>>().unwrap();
-            let values = values.as_any().downcast_ref::<BinaryArray<i32>>().unwrap();
-            contains_binary(list, values)
-        }
-        (DataType::List(_), DataType::LargeBinary) => {
-            let list = list.as_any().downcast_ref::<ListArray<i32>>().unwrap();
-            let values = values.as_any().downcast_ref::<BinaryArray<i64>>().unwrap();
-            contains_binary(list, values)
-        }
-        (DataType::LargeList(_), DataType::LargeBinary) => {
-            let list = list.as_any().downcast_ref::<ListArray<i64>>().unwrap();
-            let values = values.as_any().downcast_ref::<BinaryArray<i64>>().unwrap();
-            contains_binary(list, values)
-        }
-        (DataType::LargeList(_), DataType::Binary) => {
-            let list = list.as_any().downcast_ref::<ListArray<i64>>().unwrap();
-            let values = values.as_any().downcast_ref::<BinaryArray<i32>>().unwrap();
-            contains_binary(list, values)
-        }
-        (DataType::List(_), DataType::Int8) => primitive!(list, values, i32, i8),
-        (DataType::List(_), DataType::Int16) => primitive!(list, values, i32, i16),
-        (DataType::List(_), DataType::Int32) => primitive!(list, values, i32, i32),
-        (DataType::List(_), DataType::Int64) => primitive!(list, values, i32, i64),
-        (DataType::List(_), DataType::UInt8) => primitive!(list, values, i32, u8),
-        (DataType::List(_), DataType::UInt16) => primitive!(list, values, i32, u16),
-        (DataType::List(_), DataType::UInt32) => primitive!(list, values, i32, u32),
-        (DataType::List(_), DataType::UInt64) => primitive!(list, values, i32, u64),
-        (DataType::List(_), DataType::Float32) => primitive!(list, values, i32, f32),
-        (DataType::List(_), DataType::Float64) => primitive!(list, values, i32, f64),
-        (DataType::LargeList(_), DataType::Int8) => primitive!(list, values, i64, i8),
-        (DataType::LargeList(_), DataType::Int16) => primitive!(list, values, i64, i16),
-        (DataType::LargeList(_), DataType::Int32) => primitive!(list, values, i64, i32),
-        (DataType::LargeList(_), DataType::Int64) => primitive!(list, values, i64, i64),
-        (DataType::LargeList(_), DataType::UInt8) => primitive!(list, values, i64, u8),
-        (DataType::LargeList(_), DataType::UInt16) => primitive!(list, values, i64, u16),
-        (DataType::LargeList(_), DataType::UInt32) => primitive!(list, values, i64, u32),
-        (DataType::LargeList(_), DataType::UInt64) => primitive!(list, values, i64, u64),
-        (DataType::LargeList(_), DataType::Float32) => primitive!(list, values, i64, f32),
-        (DataType::LargeList(_), DataType::Float64) => primitive!(list, values, i64, f64),
         _ => Err(Error::NotYetImplemented(format!(
-            "Contains is not supported between logical types \"{list_data_type:?}\" and \"{values_data_type:?}\""
+            "Contains is not supported between logical types \"{:?}\" and \"{:?}\"",
+            list.data_type(),
+            values.data_type()
         ))),
     }
 }
+
+/// The number of values of a set from which [`in_list`] uses a hash set instead of
+/// comparing each value against every value of the set.
+const HASH_SET_THRESHOLD: usize = 16;
+
+/// Returns a [`BooleanArray`] with the validity of `array` whose values are computed by `op`.
+fn in_set<'a, I, F>(array: &dyn Array, values: I, op: F) -> BooleanArray
+where
+    I: Iterator<Item = Option<&'a [u8]>> + TrustedLen,
+    F: Fn(&[u8]) -> bool,
+{
+    let values = values.map(|x| x.map(&op).unwrap_or(false));
+    let values = Bitmap::from_trusted_len_iter(values);
+    BooleanArray::new(DataType::Boolean, values, array.validity().cloned())
+}
+
+fn in_list_bytes<'a, I, S>(
+    array: &dyn Array,
+    values: I,
+    set: &dyn Array,
+    set_values: S,
+) -> BooleanArray
+where
+    I: Iterator<Item = Option<&'a [u8]>> + TrustedLen,
+    S: Iterator<Item = Option<&'a [u8]>>,
+{
+    let set_values = set_values.flatten();
+    if set.len() - set.null_count() >= HASH_SET_THRESHOLD {
+        let set = set_values.collect::<HashSet<_, RandomState>>();
+        in_set(array, values, |x| set.contains(x))
+    } else {
+        let set = set_values.collect::<Vec<_>>();
+        in_set(array, values, |x| set.contains(&x))
+    }
+}
+
+fn in_list_primitive<T: NativeType>(
+    array: &PrimitiveArray<T>,
+    set: &PrimitiveArray<T>,
+) -> BooleanArray {
+    let values = array.values().iter();
+    let validity = array.validity().cloned();
+    let is_large = set.len() - set.null_count() >= HASH_SET_THRESHOLD;
+    let set = set.iter().flatten().map(|x| Bytes::<T>(x.to_le_bytes()));
+
+    let values = if is_large {
+        let set = set.collect::<HashSet<_, RandomState>>();
+        Bitmap::from_trusted_len_iter(values.map(|x| set.contains(&Bytes(x.to_le_bytes()))))
+    } else {
+        let set = set.collect::<Vec<_>>();
+        Bitmap::from_trusted_len_iter(values.map(|x| set.contains(&Bytes(x.to_le_bytes()))))
+    };
+    BooleanArray::new(DataType::Boolean, values, validity)
+}
+
+fn in_list_boolean(array: &BooleanArray, set: &BooleanArray) -> BooleanArray {
+    let has_true = set.iter().any(|x| x == Some(true));
+    let has_false = set.iter().any(|x| x == Some(false));
+    let values = array
+        .values()
+        .iter()
+        .map(|x| if x { has_true } else { has_false });
+    let values = Bitmap::from_trusted_len_iter(values);
+    BooleanArray::new(DataType::Boolean, values, array.validity().cloned())
+}
+
+fn in_list_utf8<O: Offset>(array: &Utf8Array<O>, set: &Utf8Array<O>) -> BooleanArray {
+    let values = array.iter().map(|x| x.map(|x| x.as_bytes()));
+    let set_values = set.iter().map(|x| x.map(|x| x.as_bytes()));
+    in_list_bytes(array, values, set, set_values)
+}
+
+fn in_list_binary<O: Offset>(array: &BinaryArray<O>, set: &BinaryArray<O>) -> BooleanArray {
+    in_list_bytes(array, array.iter(), set, set.iter())
+}
+
+/// Returns whether each element of `array` is equal to any element of `set`, e.g. to evaluate
+/// SQL's `array IN (set)`.
+///
+/// The validity of the result is the validity of `array`; nulls in `set` are ignored.
+/// Floating point values are compared by their bit representation, i.e. `NaN` is in a set
+/// containing `NaN` and `-0.0` is not in a set containing only `0.0`.
+/// When `set` is large, its values are looked up via a hash set.
+/// # Errors
+/// Errors iff
+/// * `array` and `set` have different logical types
+/// * the type of `array` is not supported (i.e. is not boolean, primitive, binary or utf8)
+/// # Example
+/// ```
+/// use arrow2::array::{BooleanArray, Int32Array};
+/// use arrow2::compute::contains::in_list;
+///
+/// let array = Int32Array::from([Some(1), Some(2), None]);
+/// let set = Int32Array::from([Some(2), Some(3), None]);
+/// let result = in_list(&array, &set).unwrap();
+/// assert_eq!(result, BooleanArray::from([Some(false), Some(true), None]));
+/// ```
+pub fn in_list(array: &dyn Array, set: &dyn Array) -> Result<BooleanArray> {
+    if array.data_type() != set.data_type() {
+        return Err(Error::InvalidArgumentError(format!(
+            "In list requires the array and the set to be of the same logical type, got \"{:?}\" and \"{:?}\"",
+            array.data_type(),
+            set.data_type()
+        )));
+    }
+
+    use PhysicalType::*;
+    Ok(match array.data_type().to_physical_type() {
+        Boolean => in_list_boolean(
+            array.as_any().downcast_ref().unwrap(),
+            set.as_any().downcast_ref().unwrap(),
+        ),
+        Primitive(primitive) => with_match_primitive_type!(primitive, |$T| {
+            in_list_primitive::<$T>(
+                array.as_any().downcast_ref().unwrap(),
+                set.as_any().downcast_ref().unwrap(),
+            )
+        }),
+        Utf8 => in_list_utf8::<i32>(
+            array.as_any().downcast_ref().unwrap(),
+            set.as_any().downcast_ref().unwrap(),
+        ),
+        LargeUtf8 => in_list_utf8::<i64>(
+            array.as_any().downcast_ref().unwrap(),
+            set.as_any().downcast_ref().unwrap(),
+        ),
+        Binary => in_list_binary::<i32>(
+            array.as_any().downcast_ref().unwrap(),
+            set.as_any().downcast_ref().unwrap(),
+        ),
+        LargeBinary => in_list_binary::<i64>(
+            array.as_any().downcast_ref().unwrap(),
+            set.as_any().downcast_ref().unwrap(),
+        ),
+        _ => {
+            return Err(Error::NotYetImplemented(format!(
+                "In list is not supported for logical type \"{:?}\"",
+                array.data_type()
+            )))
+        }
+    })
+}
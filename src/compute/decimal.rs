@@ -0,0 +1,178 @@
+//! Contains kernels to change the precision and scale of decimal arrays.
+//!
+//! Only non-negative scales are supported, since the scale of [`DataType::Decimal`] is unsigned.
+use crate::array::{Array, PrimitiveArray};
+use crate::bitmap::MutableBitmap;
+use crate::datatypes::DataType;
+use crate::error::{Error, Result};
+
+/// The maximum precision of a [`DataType::Decimal`]
+const MAX_PRECISION: usize = 38;
+
+/// How values are rounded when their scale decreases.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum RoundingMode {
+    /// Rounds to the nearest value, and ties away from zero (e.g. `2.5 -> 3`, `-2.5 -> -3`)
+    #[default]
+    HalfUp,
+    /// Rounds to the nearest value, and ties to the nearest even value (e.g. `2.5 -> 2`,
+    /// `3.5 -> 4`), also known as banker's rounding
+    HalfEven,
+}
+
+/// Returns the precision and scale of `data_type`.
+/// # Error
+/// Errors iff `data_type`'s logical type is not [`DataType::Decimal`]
+fn precision_and_scale(data_type: &DataType) -> Result<(usize, usize)> {
+    if let DataType::Decimal(precision, scale) = data_type.to_logical_type() {
+        Ok((*precision, *scale))
+    } else {
+        Err(Error::InvalidArgumentError(format!(
+            "Rescaling requires a decimal array, but the array has type {data_type:?}"
+        )))
+    }
+}
+
+/// Returns `value` with scale `from_scale` rescaled to `to_scale`, or `None` on overflow.
+fn rescale_value(
+    value: i128,
+    from_scale: usize,
+    to_scale: usize,
+    rounding: RoundingMode,
+) -> Option<i128> {
+    if to_scale >= from_scale {
+        let factor = 10_i128.checked_pow((to_scale - from_scale) as u32);
+        return match factor {
+            Some(factor) => value.checked_mul(factor),
+            None => (value == 0).then_some(0),
+        };
+    }
+
+    let factor = match 10_i128.checked_pow((from_scale - to_scale) as u32) {
+        Some(factor) => factor,
+        // the factor is larger than any value, whose halves are therefore rounded down
+        None => return Some(0),
+    };
+    let quotient = value / factor;
+    let remainder = (value % factor).abs();
+    // compares `remainder` to half of `factor` without overflowing
+    let round_away = match remainder.cmp(&(factor - remainder)) {
+        std::cmp::Ordering::Less => false,
+        std::cmp::Ordering::Greater => true,
+        std::cmp::Ordering::Equal => match rounding {
+            RoundingMode::HalfUp => true,
+            RoundingMode::HalfEven => quotient % 2 != 0,
+        },
+    };
+    Some(if round_away {
+        quotient + value.signum()
+    } else {
+        quotient
+    })
+}
+
+/// Rescales every valid slot of `array` to `scale`, calling `on_overflow` with the index of every
+/// slot that overflows or does not fit in `precision`.
+fn rescale_impl<F: FnMut(usize) -> Result<()>>(
+    array: &PrimitiveArray<i128>,
+    precision: usize,
+    scale: usize,
+    rounding: RoundingMode,
+    mut on_overflow: F,
+) -> Result<Vec<i128>> {
+    let (_, from_scale) = precision_and_scale(array.data_type())?;
+    if precision == 0 || precision > MAX_PRECISION {
+        return Err(Error::InvalidArgumentError(format!(
+            "The precision of a decimal must be between 1 and {MAX_PRECISION}, but it is {precision}"
+        )));
+    }
+    let max = 10_i128.pow(precision as u32) - 1;
+
+    array
+        .values()
+        .iter()
+        .enumerate()
+        .map(|(index, value)| {
+            if !array.is_valid(index) {
+                return Ok(0);
+            }
+            match rescale_value(*value, from_scale, scale, rounding) {
+                Some(value) if (-max..=max).contains(&value) => Ok(value),
+                _ => on_overflow(index).map(|_| 0),
+            }
+        })
+        .collect()
+}
+
+/// Returns `array` rescaled to a [`DataType::Decimal`] of `precision` and `scale`, rounding
+/// values with `rounding` when the scale decreases.
+/// # Errors
+/// Errors iff
+/// * `array` is not a decimal array, or `precision` is not between 1 and 38
+/// * any value overflows or does not fit in `precision` (with [`Error::Overflow`])
+/// # Example
+/// ```
+/// use arrow2::array::PrimitiveArray;
+/// use arrow2::compute::decimal::{rescale, RoundingMode};
+/// use arrow2::datatypes::DataType;
+///
+/// // 0.25, 0.35, -0.25
+/// let array = PrimitiveArray::from_slice([25i128, 35, -25]).to(DataType::Decimal(3, 2));
+///
+/// let rescaled = rescale(&array, 2, 1, RoundingMode::HalfUp).unwrap();
+/// assert_eq!(rescaled.values().as_slice(), &[3, 4, -3]);
+///
+/// let rescaled = rescale(&array, 2, 1, RoundingMode::HalfEven).unwrap();
+/// assert_eq!(rescaled.values().as_slice(), &[2, 4, -2]);
+///
+/// assert!(rescale(&array, 1, 2, RoundingMode::HalfUp).is_err());
+/// ```
+pub fn rescale(
+    array: &PrimitiveArray<i128>,
+    precision: usize,
+    scale: usize,
+    rounding: RoundingMode,
+) -> Result<PrimitiveArray<i128>> {
+    let values = rescale_impl(array, precision, scale, rounding, |_| Err(Error::Overflow))?;
+    Ok(PrimitiveArray::new(
+        DataType::Decimal(precision, scale),
+        values.into(),
+        array.validity().cloned(),
+    ))
+}
+
+/// Returns `array` rescaled to a [`DataType::Decimal`] of `precision` and `scale`, rounding
+/// values with `rounding` when the scale decreases, and with null slots where values
+/// overflow or do not fit in `precision`.
+/// # Errors
+/// Errors iff `array` is not a decimal array, or `precision` is not between 1 and 38.
+pub fn rescale_or_null(
+    array: &PrimitiveArray<i128>,
+    precision: usize,
+    scale: usize,
+    rounding: RoundingMode,
+) -> Result<PrimitiveArray<i128>> {
+    let mut overflows = vec![];
+    let values = rescale_impl(array, precision, scale, rounding, |index| {
+        overflows.push(index);
+        Ok(())
+    })?;
+
+    let validity = if overflows.is_empty() {
+        array.validity().cloned()
+    } else {
+        let mut validity = match array.validity() {
+            Some(validity) => validity.clone().make_mut(),
+            None => MutableBitmap::from_len_set(array.len()),
+        };
+        overflows
+            .into_iter()
+            .for_each(|index| validity.set(index, false));
+        Some(validity.into())
+    };
+    Ok(PrimitiveArray::new(
+        DataType::Decimal(precision, scale),
+        values.into(),
+        validity,
+    ))
+}
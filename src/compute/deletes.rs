@@ -0,0 +1,182 @@
+//! Contains [`MaskedChunk`], a [`Chunk`] with lazily applied row-level deletes.
+//!
+//! Deleting rows from a [`Chunk`] requires rewriting all its columns. [`MaskedChunk`] instead
+//! stores the deleted rows in a [`Bitmap`] alongside the (unchanged) columns, and only
+//! materializes the remaining rows on demand, e.g. via [`MaskedChunk::materialize`].
+//! This is useful for merge-on-read deletes, where deletes are frequent and reads are rare.
+//! # Example
+//! ```
+//! use arrow2::array::{Array, Int32Array, Utf8Array};
+//! use arrow2::chunk::Chunk;
+//! use arrow2::compute::deletes::MaskedChunk;
+//!
+//! let chunk = Chunk::new(vec![
+//!     Int32Array::from_slice([1, 2, 3]).boxed(),
+//!     Utf8Array::<i32>::from_slice(["a", "b", "c"]).boxed(),
+//! ]);
+//! let mut masked = MaskedChunk::new(chunk);
+//! masked.delete_rows(&[1]).unwrap();
+//! assert_eq!(masked.len(), 2);
+//!
+//! let chunk = masked.materialize().unwrap();
+//! assert_eq!(Int32Array::from_slice([1, 3]), chunk.arrays()[0].as_ref());
+//! ```
+use crate::{
+    array::{Array, BooleanArray},
+    bitmap::{Bitmap, MutableBitmap},
+    chunk::Chunk,
+    datatypes::DataType,
+    error::{Error, Result},
+};
+
+use super::filter::{filter, filter_chunk};
+
+/// A [`Chunk`] whose deleted rows are stored in a mask instead of being removed from its columns.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MaskedChunk<A: AsRef<dyn Array>> {
+    chunk: Chunk<A>,
+    // a set bit denotes a deleted row
+    deleted: Bitmap,
+}
+
+impl<A: AsRef<dyn Array>> MaskedChunk<A> {
+    /// Creates a new [`MaskedChunk`] without deleted rows.
+    pub fn new(chunk: Chunk<A>) -> Self {
+        let deleted = Bitmap::new_zeroed(chunk.len());
+        Self { chunk, deleted }
+    }
+
+    /// Creates a new [`MaskedChunk`] where the rows whose bit is set in `deleted` are deleted.
+    /// # Errors
+    /// Errors iff `deleted` does not have the same length as `chunk`
+    pub fn try_new(chunk: Chunk<A>, deleted: Bitmap) -> Result<Self> {
+        check_length(&chunk, deleted.len())?;
+        Ok(Self { chunk, deleted })
+    }
+
+    /// Returns the underlying [`Chunk`], including its deleted rows.
+    pub fn chunk(&self) -> &Chunk<A> {
+        &self.chunk
+    }
+
+    /// Returns the mask of deleted rows of the underlying [`Chunk`].
+    pub fn deleted(&self) -> &Bitmap {
+        &self.deleted
+    }
+
+    /// Returns the number of deleted rows.
+    pub fn deleted_count(&self) -> usize {
+        self.deleted.len() - self.deleted.unset_bits()
+    }
+
+    /// Returns the number of rows that are not deleted.
+    pub fn len(&self) -> usize {
+        self.chunk.len() - self.deleted_count()
+    }
+
+    /// Returns whether all rows are deleted.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Deletes the rows whose bit is set in `deleted`.
+    /// Rows already deleted remain deleted.
+    /// # Errors
+    /// Errors iff `deleted` does not have the same length as the underlying [`Chunk`]
+    pub fn delete(&mut self, deleted: &Bitmap) -> Result<()> {
+        check_length(&self.chunk, deleted.len())?;
+        self.deleted = &self.deleted | deleted;
+        Ok(())
+    }
+
+    /// Deletes the rows at positions `rows` of the underlying [`Chunk`].
+    /// # Errors
+    /// Errors iff any of the rows is out of bounds
+    pub fn delete_rows(&mut self, rows: &[usize]) -> Result<()> {
+        let len = self.chunk.len();
+        if let Some(row) = rows.iter().find(|row| **row >= len) {
+            return Err(Error::InvalidArgumentError(format!(
+                "Cannot delete row {row} of a chunk with {len} rows"
+            )));
+        }
+        let mut deleted = MutableBitmap::from_len_zeroed(len);
+        rows.iter().for_each(|row| deleted.set(*row, true));
+        self.delete(&deleted.into())
+    }
+
+    /// Deletes the rows for which `predicate` is not `true` (i.e. `false` or null).
+    /// `predicate` applies to all rows of the underlying [`Chunk`], including deleted ones.
+    /// # Errors
+    /// Errors iff `predicate` does not have the same length as the underlying [`Chunk`]
+    pub fn retain(&mut self, predicate: &BooleanArray) -> Result<()> {
+        let retained = match predicate.validity() {
+            Some(validity) => predicate.values() & validity,
+            None => predicate.values().clone(),
+        };
+        self.delete(&!&retained)
+    }
+
+    /// Returns the selection of rows that are not deleted, usable by [`filter`].
+    pub fn selection(&self) -> BooleanArray {
+        BooleanArray::new(DataType::Boolean, !&self.deleted, None)
+    }
+
+    /// Returns the number of nulls of the column at `index` over the rows that are not deleted,
+    /// without materializing it.
+    /// # Panics
+    /// Panics iff `index` is out of bounds
+    pub fn null_count(&self, index: usize) -> usize {
+        let column = self.chunk.arrays()[index].as_ref();
+        match column.validity() {
+            // nulls that are deleted are the bits unset in both
+            Some(validity) => (validity | &self.deleted).unset_bits(),
+            None => 0,
+        }
+    }
+
+    /// Returns the column at `index` without its deleted rows.
+    /// # Panics
+    /// Panics iff `index` is out of bounds
+    pub fn column(&self, index: usize) -> Result<Box<dyn Array>> {
+        let column = self.chunk.arrays()[index].as_ref();
+        if self.deleted_count() == 0 {
+            return Ok(column.to_boxed());
+        }
+        filter(column, &self.selection())
+    }
+
+    /// Returns a new [`Chunk`] with the rows that are not deleted.
+    pub fn materialize(&self) -> Result<Chunk<Box<dyn Array>>> {
+        if self.deleted_count() == 0 {
+            return Chunk::try_new(
+                self.chunk
+                    .arrays()
+                    .iter()
+                    .map(|array| array.as_ref().to_boxed())
+                    .collect(),
+            );
+        }
+        filter_chunk(&self.chunk, &self.selection())
+    }
+
+    /// Consumes itself into the underlying [`Chunk`] and its mask of deleted rows.
+    pub fn into_inner(self) -> (Chunk<A>, Bitmap) {
+        (self.chunk, self.deleted)
+    }
+}
+
+impl<A: AsRef<dyn Array>> From<Chunk<A>> for MaskedChunk<A> {
+    fn from(chunk: Chunk<A>) -> Self {
+        Self::new(chunk)
+    }
+}
+
+fn check_length<A: AsRef<dyn Array>>(chunk: &Chunk<A>, length: usize) -> Result<()> {
+    if chunk.len() != length {
+        return Err(Error::InvalidArgumentError(format!(
+            "The mask of deleted rows must have the same length as the chunk ({}), but it has {length}",
+            chunk.len()
+        )));
+    }
+    Ok(())
+}
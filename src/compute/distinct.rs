@@ -0,0 +1,280 @@
+//! Contains operators to deduplicate [`Array`]s and [`Chunk`]s and to count their values.
+//!
+//! Values are equal when they are both null or when they are both valid and equal. Floating
+//! point values are compared by their bit representation, i.e. `NaN`s with the same bits are
+//! equal and `0.0` and `-0.0` are distinct.
+//! # Example
+//! ```
+//! use arrow2::array::{Int32Array, UInt32Array};
+//! use arrow2::compute::distinct::{distinct_indices, unique};
+//!
+//! let array = Int32Array::from([Some(1), Some(2), Some(1), None, None]);
+//! let indices = distinct_indices::<u32>(&array).unwrap();
+//! assert_eq!(indices, UInt32Array::from_slice([0, 1, 3]));
+//!
+//! let result = unique(&array).unwrap();
+//! assert_eq!(Int32Array::from([Some(1), Some(2), None]), result.as_ref());
+//! ```
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use ahash::RandomState;
+
+use crate::{
+    array::{
+        Array, BinaryArray, BooleanArray, DictionaryArray, DictionaryKey, PrimitiveArray, Utf8Array,
+    },
+    chunk::Chunk,
+    datatypes::PhysicalType,
+    error::{Error, Result},
+    types::{Index, NativeType},
+};
+
+use super::sort::row::{RowConverter, SortField};
+use super::take::take;
+use super::utils::Bytes;
+
+/// Calls `op` with the index, the group and whether the group is new, for each item of
+/// `iter`. Groups are numbered in the order of their first occurrence.
+fn group<K: Hash + Eq>(iter: impl Iterator<Item = K>, op: &mut dyn FnMut(usize, usize, bool)) {
+    let mut groups = HashMap::with_hasher(RandomState::new());
+    iter.enumerate().for_each(|(index, item)| {
+        let len = groups.len();
+        match groups.entry(item) {
+            Entry::Occupied(entry) => op(index, *entry.get(), false),
+            Entry::Vacant(entry) => {
+                entry.insert(len);
+                op(index, len, true)
+            }
+        }
+    })
+}
+
+fn group_primitive<T: NativeType>(
+    array: &PrimitiveArray<T>,
+    op: &mut dyn FnMut(usize, usize, bool),
+) {
+    group(
+        array.iter().map(|x| x.map(|x| Bytes::<T>(x.to_le_bytes()))),
+        op,
+    )
+}
+
+/// Calls `op` with the index, the group and whether the group is new, for each row of
+/// `columns`, where rows are compared via their row encoding.
+fn group_rows(columns: &[Box<dyn Array>], op: &mut dyn FnMut(usize, usize, bool)) -> Result<()> {
+    let fields = columns
+        .iter()
+        .map(|column| SortField::new(column.data_type().clone()))
+        .collect();
+    let mut converter = RowConverter::new(fields);
+    let rows = converter.convert_columns(columns)?;
+    group(rows.iter(), op);
+    Ok(())
+}
+
+/// Calls `op` with the index, the group and whether the group is new, for each value of
+/// `array`. Nulls belong to the same group.
+fn group_array(array: &dyn Array, op: &mut dyn FnMut(usize, usize, bool)) -> Result<()> {
+    use PhysicalType::*;
+    match array.data_type().to_physical_type() {
+        Boolean => {
+            let array = array.as_any().downcast_ref::<BooleanArray>().unwrap();
+            group(array.iter(), op)
+        }
+        Primitive(primitive) => with_match_primitive_type!(primitive, |$T| {
+            group_primitive::<$T>(array.as_any().downcast_ref().unwrap(), op)
+        }),
+        Utf8 => {
+            let array = array.as_any().downcast_ref::<Utf8Array<i32>>().unwrap();
+            group(array.iter(), op)
+        }
+        LargeUtf8 => {
+            let array = array.as_any().downcast_ref::<Utf8Array<i64>>().unwrap();
+            group(array.iter(), op)
+        }
+        Binary => {
+            let array = array.as_any().downcast_ref::<BinaryArray<i32>>().unwrap();
+            group(array.iter(), op)
+        }
+        LargeBinary => {
+            let array = array.as_any().downcast_ref::<BinaryArray<i64>>().unwrap();
+            group(array.iter(), op)
+        }
+        _ => return group_rows(&[array.to_boxed()], op),
+    };
+    Ok(())
+}
+
+/// Returns the first indices of every group as a [`PrimitiveArray`].
+fn to_indices<I: Index>(indices: Vec<usize>) -> Result<PrimitiveArray<I>> {
+    let indices = indices
+        .into_iter()
+        .map(|index| I::from_usize(index).ok_or(Error::Overflow))
+        .collect::<Result<Vec<_>>>()?;
+    Ok(PrimitiveArray::from_vec(indices))
+}
+
+/// Returns the indices of the first occurrence of each distinct value of `array`, in the
+/// order of their first occurrence.
+///
+/// Nulls are equal to each other, so that the index of the first null, if any, is included.
+/// # Errors
+/// Errors iff
+/// * the type of `array` is not supported (e.g. nested types). Primitive, boolean, binary
+///   and utf8 types are supported, as well as every type supported by
+///   [`RowConverter`], such as dictionaries.
+/// * an index does not fit in `I`
+pub fn distinct_indices<I: Index>(array: &dyn Array) -> Result<PrimitiveArray<I>> {
+    let mut indices = vec![];
+    group_array(array, &mut |index, _, is_new| {
+        if is_new {
+            indices.push(index)
+        }
+    })?;
+    to_indices(indices)
+}
+
+/// Returns the distinct values of `array`, in the order of their first occurrence.
+/// # Errors
+/// Errors iff the type of `array` is not supported by [`distinct_indices`].
+pub fn unique(array: &dyn Array) -> Result<Box<dyn Array>> {
+    let indices = distinct_indices::<u64>(array)?;
+    take(array, &indices)
+}
+
+/// Returns the indices of the first occurrence of each distinct row of `chunk`, in the
+/// order of their first occurrence. Rows are compared via their row encoding.
+/// # Errors
+/// Errors iff
+/// * the type of any column is not supported by [`RowConverter`]
+/// * an index does not fit in `I`
+pub fn distinct_chunk_indices<I: Index, A: AsRef<dyn Array>>(
+    chunk: &Chunk<A>,
+) -> Result<PrimitiveArray<I>> {
+    if chunk.arrays().is_empty() {
+        return Ok(PrimitiveArray::from_vec(vec![]));
+    }
+    let columns = chunk
+        .arrays()
+        .iter()
+        .map(|array| array.as_ref().to_boxed())
+        .collect::<Vec<_>>();
+    let mut indices = vec![];
+    group_rows(&columns, &mut |index, _, is_new| {
+        if is_new {
+            indices.push(index)
+        }
+    })?;
+    to_indices(indices)
+}
+
+/// Returns the distinct rows of `chunk`, in the order of their first occurrence.
+/// # Errors
+/// Errors iff the type of any column is not supported by [`RowConverter`].
+pub fn unique_chunk<A: AsRef<dyn Array>>(chunk: &Chunk<A>) -> Result<Chunk<Box<dyn Array>>> {
+    let indices = distinct_chunk_indices::<u64, _>(chunk)?;
+    let arrays = chunk
+        .arrays()
+        .iter()
+        .map(|array| take(array.as_ref(), &indices))
+        .collect::<Result<Vec<_>>>()?;
+    Chunk::try_new(arrays)
+}
+
+/// The distinct values of an array and the number of times each value occurs.
+pub type ValueCounts = (Box<dyn Array>, PrimitiveArray<i64>);
+
+fn dictionary_value_counts<K: DictionaryKey>(
+    array: &DictionaryArray<K>,
+    count_nulls: bool,
+) -> Result<ValueCounts> {
+    let values = array.values();
+    // the values of a dictionary may repeat, so keys are counted per group of values
+    let mut groups = Vec::with_capacity(values.len());
+    let mut firsts = vec![];
+    group_array(values.as_ref(), &mut |index, group, is_new| {
+        groups.push(group);
+        if is_new {
+            firsts.push(index)
+        }
+    })?;
+    // null keys are counted with the null values, or in a group of their own
+    let null_group = firsts
+        .iter()
+        .position(|&index| values.is_null(index))
+        .unwrap_or(firsts.len());
+
+    let mut counts = vec![0i64; firsts.len() + 1];
+    let mut order = vec![];
+    array.keys_iter().for_each(|key| {
+        let group = key.map(|key| groups[key]).unwrap_or(null_group);
+        if counts[group] == 0 {
+            order.push(group)
+        }
+        counts[group] += 1;
+    });
+    if !count_nulls {
+        order.retain(|&group| group != null_group);
+    }
+
+    let keys = order
+        .iter()
+        .map(|&group| {
+            firsts
+                .get(group)
+                .map(|&index| K::try_from(index).map_err(|_| Error::Overflow))
+                .transpose()
+        })
+        .collect::<Result<PrimitiveArray<K>>>()?;
+    let counts = order.iter().map(|&group| counts[group]).collect::<Vec<_>>();
+    let values = DictionaryArray::try_new(array.data_type().clone(), keys, values.clone())?;
+    Ok((values.boxed(), PrimitiveArray::from_vec(counts)))
+}
+
+/// Returns the distinct values of `array` and the number of times each value occurs, in
+/// the order of their first occurrence.
+///
+/// When `count_nulls` is true, nulls are counted as a (null) value; otherwise they are
+/// omitted from the result. The values of dictionaries are counted via their keys and
+/// returned as a [`DictionaryArray`] with the same values.
+/// # Errors
+/// Errors iff the type of `array` is not supported by [`distinct_indices`].
+/// # Example
+/// ```
+/// use arrow2::array::{Int64Array, Utf8Array};
+/// use arrow2::compute::distinct::value_counts;
+///
+/// let array = Utf8Array::<i32>::from([Some("a"), None, Some("b"), Some("a")]);
+/// let (values, counts) = value_counts(&array, false).unwrap();
+/// assert_eq!(Utf8Array::<i32>::from_slice(["a", "b"]), values.as_ref());
+/// assert_eq!(counts, Int64Array::from_slice([2, 1]));
+/// ```
+pub fn value_counts(array: &dyn Array, count_nulls: bool) -> Result<ValueCounts> {
+    if let PhysicalType::Dictionary(key_type) = array.data_type().to_physical_type() {
+        return match_integer_type!(key_type, |$T| {
+            dictionary_value_counts::<$T>(array.as_any().downcast_ref().unwrap(), count_nulls)
+        });
+    }
+
+    let mut indices = vec![];
+    let mut counts = vec![];
+    group_array(array, &mut |index, group, is_new| {
+        if is_new {
+            indices.push(index);
+            counts.push(1i64);
+        } else {
+            counts[group] += 1;
+        }
+    })?;
+    if !count_nulls {
+        if let Some(null_group) = indices.iter().position(|&index| array.is_null(index)) {
+            indices.remove(null_group);
+            counts.remove(null_group);
+        }
+    }
+
+    let values = take(array, &to_indices::<u64>(indices)?)?;
+    Ok((values, PrimitiveArray::from_vec(counts)))
+}
@@ -0,0 +1,157 @@
+//! Contains kernels to encode [`BinaryArray`]s as hexadecimal or base64 [`Utf8Array`]s and
+//! to decode them back, e.g. to write binary columns to text formats such as JSON or CSV.
+//!
+//! Every kernel allocates its output once, since the size of the encoded (or, for hex, decoded)
+//! values is known from the size of the input values. Null slots are neither encoded nor
+//! decoded, and are empty in the output.
+//! # Example
+//! ```
+//! use arrow2::array::{BinaryArray, Utf8Array};
+//! use arrow2::compute::encode::{base64_decode, base64_encode, hex_encode};
+//!
+//! let array = BinaryArray::<i32>::from([Some(b"arrow".as_ref()), None, Some(&[0xff, 0x00])]);
+//!
+//! let hex = hex_encode(&array).unwrap();
+//! assert_eq!(hex, Utf8Array::<i32>::from([Some("6172726f77"), None, Some("ff00")]));
+//!
+//! let base64 = base64_encode(&array).unwrap();
+//! assert_eq!(base64, Utf8Array::<i32>::from([Some("YXJyb3c="), None, Some("/wA=")]));
+//! assert_eq!(base64_decode(&base64).unwrap(), array);
+//! ```
+
+use crate::{
+    array::{Array, BinaryArray, Utf8Array},
+    error::{Error, Result},
+    offset::{Offset, Offsets},
+};
+
+/// Encodes every valid value of `array` into a single buffer, where `encoded_len` is the length
+/// of a value once encoded and `encode` encodes a value into a slice of exactly that length.
+fn encode<O, L, F>(array: &BinaryArray<O>, encoded_len: L, encode: F) -> Result<Utf8Array<O>>
+where
+    O: Offset,
+    L: Fn(usize) -> usize,
+    F: Fn(&[u8], &mut [u8]),
+{
+    let mut offsets = Offsets::<O>::with_capacity(array.len());
+    for (index, value) in array.values_iter().enumerate() {
+        let length = if array.is_valid(index) {
+            encoded_len(value.len())
+        } else {
+            0
+        };
+        offsets.try_push_usize(length)?;
+    }
+
+    let mut values = vec![0u8; offsets.last().to_usize()];
+    for (window, value) in offsets.as_slice().windows(2).zip(array.values_iter()) {
+        let (start, end) = (window[0].to_usize(), window[1].to_usize());
+        if start != end {
+            encode(value, &mut values[start..end]);
+        }
+    }
+
+    // Safety: hex and base64 are ASCII and thus utf8
+    Ok(unsafe {
+        Utf8Array::new_unchecked(
+            Utf8Array::<O>::default_data_type(),
+            offsets.into(),
+            values.into(),
+            array.validity().cloned(),
+        )
+    })
+}
+
+/// Decodes every valid value of `array` into a single buffer, where `max_decoded_len` is an
+/// upper bound of the length of a value once decoded and `decode` decodes a value into a slice
+/// of that length, returning the length of the decoded value.
+fn decode<O, L, F>(array: &Utf8Array<O>, max_decoded_len: L, decode: F) -> Result<BinaryArray<O>>
+where
+    O: Offset,
+    L: Fn(usize) -> usize,
+    F: Fn(&[u8], &mut [u8]) -> Result<usize>,
+{
+    let capacity = array
+        .values_iter()
+        .enumerate()
+        .filter(|(index, _)| array.is_valid(*index))
+        .map(|(_, value)| max_decoded_len(value.len()))
+        .sum();
+
+    let mut offsets = Offsets::<O>::with_capacity(array.len());
+    let mut values = vec![0u8; capacity];
+    let mut length = 0;
+    for (index, value) in array.values_iter().enumerate() {
+        let decoded = if array.is_valid(index) {
+            let end = length + max_decoded_len(value.len());
+            decode(value.as_bytes(), &mut values[length..end])?
+        } else {
+            0
+        };
+        length += decoded;
+        offsets.try_push_usize(decoded)?;
+    }
+    values.truncate(length);
+
+    Ok(BinaryArray::new(
+        BinaryArray::<O>::default_data_type(),
+        offsets.into(),
+        values.into(),
+        array.validity().cloned(),
+    ))
+}
+
+/// Encodes every value of `array` as lowercase hexadecimal.
+/// # Errors
+/// This function errors iff the encoded values overflow the offsets of `O`.
+pub fn hex_encode<O: Offset>(array: &BinaryArray<O>) -> Result<Utf8Array<O>> {
+    encode(
+        array,
+        |length| length * 2,
+        |value, encoded| hex::encode_to_slice(value, encoded).unwrap(),
+    )
+}
+
+/// Decodes every value of `array` from (lower or uppercase) hexadecimal.
+/// # Errors
+/// This function errors iff any valid value is not valid hexadecimal.
+pub fn hex_decode<O: Offset>(array: &Utf8Array<O>) -> Result<BinaryArray<O>> {
+    decode(
+        array,
+        |length| length / 2,
+        |value, decoded| {
+            hex::decode_to_slice(value, decoded).map_err(|error| {
+                Error::InvalidArgumentError(format!("Unable to decode hexadecimal value: {error}"))
+            })?;
+            Ok(decoded.len())
+        },
+    )
+}
+
+/// Encodes every value of `array` as base64 with the standard alphabet and padding.
+/// # Errors
+/// This function errors iff the encoded values overflow the offsets of `O`.
+pub fn base64_encode<O: Offset>(array: &BinaryArray<O>) -> Result<Utf8Array<O>> {
+    encode(
+        array,
+        |length| (length + 2) / 3 * 4,
+        |value, encoded| {
+            base64::encode_config_slice(value, base64::STANDARD, encoded);
+        },
+    )
+}
+
+/// Decodes every value of `array` from base64 with the standard alphabet and padding.
+/// # Errors
+/// This function errors iff any valid value is not valid base64.
+pub fn base64_decode<O: Offset>(array: &Utf8Array<O>) -> Result<BinaryArray<O>> {
+    decode(
+        array,
+        |length| (length + 3) / 4 * 3,
+        |value, decoded| {
+            base64::decode_config_slice(value, base64::STANDARD, decoded).map_err(|error| {
+                Error::InvalidArgumentError(format!("Unable to decode base64 value: {error}"))
+            })
+        },
+    )
+}
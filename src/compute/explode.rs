@@ -0,0 +1,66 @@
+//! Contains the operator [`explode`], that flattens lists into their items, as SQL's `UNNEST`.
+//! # Example
+//! ```
+//! use arrow2::array::{Int32Array, Int64Array, ListArray, MutableListArray, MutablePrimitiveArray, TryExtend, Utf8Array};
+//! use arrow2::compute::{explode::explode, take::take};
+//!
+//! let mut list = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+//! list.try_extend(vec![Some(vec![Some(1), Some(2)]), None, Some(vec![Some(3)])]).unwrap();
+//! let list: ListArray<i32> = list.into();
+//! let names = Utf8Array::<i32>::from_slice(["a", "b", "c"]);
+//!
+//! let (values, indices) = explode(&list).unwrap();
+//! assert_eq!(Int32Array::from_slice([1, 2, 3]), values.as_ref());
+//! assert_eq!(indices, Int64Array::from_slice([0, 0, 2]));
+//!
+//! // sibling columns are replicated via the indices
+//! let names = take(&names, &indices).unwrap();
+//! assert_eq!(Utf8Array::<i32>::from_slice(["a", "a", "c"]), names.as_ref());
+//! ```
+use crate::array::{growable::make_growable, Array, ListArray, PrimitiveArray};
+use crate::datatypes::DataType;
+use crate::error::{Error, Result};
+use crate::offset::Offset;
+
+/// Returns the items of every list of `list` and, for every item, the index of its list.
+///
+/// Null and empty lists have no items and therefore no index. The indices can be used with
+/// [`take`](crate::compute::take::take) to replicate the values of sibling columns.
+/// # Errors
+/// Errors iff `list` is neither a [`ListArray<i32>`] nor a [`ListArray<i64>`].
+pub fn explode(list: &dyn Array) -> Result<(Box<dyn Array>, PrimitiveArray<i64>)> {
+    match list.data_type().to_logical_type() {
+        DataType::List(_) => Ok(explode_list::<i32>(list.as_any().downcast_ref().unwrap())),
+        DataType::LargeList(_) => Ok(explode_list::<i64>(list.as_any().downcast_ref().unwrap())),
+        other => Err(Error::InvalidArgumentError(format!(
+            "Explode requires a list, but the array has type {other:?}"
+        ))),
+    }
+}
+
+fn explode_list<O: Offset>(list: &ListArray<O>) -> (Box<dyn Array>, PrimitiveArray<i64>) {
+    let offsets = list.offsets();
+    let mut indices = vec![];
+    offsets
+        .lengths()
+        .enumerate()
+        .filter(|(row, _)| list.is_valid(*row))
+        .for_each(|(row, len)| indices.resize(indices.len() + len, row as i64));
+
+    let values = list.values().as_ref();
+    let values = if list.null_count() > 0 {
+        // the items of null lists are skipped
+        let mut growable = make_growable(&[values], false, indices.len());
+        (0..list.len())
+            .filter(|row| list.is_valid(*row))
+            .for_each(|row| {
+                let (start, end) = offsets.start_end(row);
+                growable.extend(0, start, end - start)
+            });
+        growable.as_box()
+    } else {
+        let start = offsets.buffer()[0].to_usize();
+        values.slice(start, offsets.last().to_usize() - start)
+    };
+    (values, PrimitiveArray::from_vec(indices))
+}
@@ -0,0 +1,228 @@
+//! Defines kernels to replace null values: [`fill_null_forward`], [`fill_null_backward`]
+//! and [`fill_null_with`].
+//!
+//! Columns split in multiple arrays (e.g. over multiple [`Chunk`](crate::chunk::Chunk)s)
+//! can be filled as a whole via [`fill_null_forward_chunks`] and
+//! [`fill_null_backward_chunks`], which carry values over array boundaries.
+//! # Example
+//! ```
+//! use arrow2::array::Int32Array;
+//! use arrow2::compute::fill_null::{fill_null_backward, fill_null_forward};
+//!
+//! let array = Int32Array::from(&[None, Some(1), None, Some(3), None]);
+//!
+//! let result = fill_null_forward(&array);
+//! let expected = Int32Array::from(&[None, Some(1), Some(1), Some(3), Some(3)]);
+//! assert_eq!(expected, result.as_ref());
+//!
+//! let result = fill_null_backward(&array);
+//! let expected = Int32Array::from(&[Some(1), Some(1), Some(3), Some(3), None]);
+//! assert_eq!(expected, result.as_ref());
+//! ```
+use crate::{
+    array::{
+        growable::{make_growable, Growable},
+        Array,
+    },
+    bitmap::{utils::SlicesIterator, Bitmap},
+    datatypes::DataType,
+    error::{Error, Result},
+};
+
+/// Returns the validity of `array` if it has null values to fill.
+fn nulls_to_fill(array: &dyn Array) -> Option<&Bitmap> {
+    if array.data_type() == &DataType::Null {
+        return None;
+    }
+    array
+        .validity()
+        .filter(|validity| validity.unset_bits() > 0)
+}
+
+/// Returns `(start, length)` of every run of valid slots of `validity`
+fn valid_runs(validity: &Bitmap) -> Vec<(usize, usize)> {
+    SlicesIterator::new(validity).collect()
+}
+
+/// Returns a slice of length 1 of the last valid slot of `array`, if any.
+fn last_valid(array: &dyn Array) -> Option<Box<dyn Array>> {
+    (0..array.len())
+        .rev()
+        .find(|i| array.is_valid(*i))
+        .map(|i| array.slice(i, 1))
+}
+
+/// Returns a slice of length 1 of the first valid slot of `array`, if any.
+fn first_valid(array: &dyn Array) -> Option<Box<dyn Array>> {
+    (0..array.len())
+        .find(|i| array.is_valid(*i))
+        .map(|i| array.slice(i, 1))
+}
+
+/// Fills nulls of `array` with the last valid value before them, or with `carry` when there is
+/// no such value.
+fn forward(array: &dyn Array, carry: Option<&dyn Array>) -> Box<dyn Array> {
+    let validity = match nulls_to_fill(array) {
+        Some(validity) => validity,
+        None => return array.to_boxed(),
+    };
+
+    let mut arrays = vec![array];
+    arrays.extend(carry);
+    let mut growable = make_growable(&arrays, true, array.len());
+
+    // the (array, index) of the last valid value
+    let mut last = carry.map(|_| (1, 0));
+    let mut end = 0;
+    for (start, length) in valid_runs(validity) {
+        extend_nulls(growable.as_mut(), last, start - end);
+        growable.extend(0, start, length);
+        last = Some((0, start + length - 1));
+        end = start + length;
+    }
+    extend_nulls(growable.as_mut(), last, array.len() - end);
+    growable.as_box()
+}
+
+/// Fills nulls of `array` with the first valid value after them, or with `carry` when there is
+/// no such value.
+fn backward(array: &dyn Array, carry: Option<&dyn Array>) -> Box<dyn Array> {
+    let validity = match nulls_to_fill(array) {
+        Some(validity) => validity,
+        None => return array.to_boxed(),
+    };
+
+    let mut arrays = vec![array];
+    arrays.extend(carry);
+    let mut growable = make_growable(&arrays, true, array.len());
+
+    let mut end = 0;
+    for (start, length) in valid_runs(validity) {
+        extend_nulls(growable.as_mut(), Some((0, start)), start - end);
+        growable.extend(0, start, length);
+        end = start + length;
+    }
+    extend_nulls(growable.as_mut(), carry.map(|_| (1, 0)), array.len() - end);
+    growable.as_box()
+}
+
+/// Extends `growable` with `additional` copies of the value at `source`, or with nulls
+/// when `source` is `None`.
+fn extend_nulls(growable: &mut dyn Growable, source: Option<(usize, usize)>, additional: usize) {
+    match source {
+        Some((index, start)) => (0..additional).for_each(|_| growable.extend(index, start, 1)),
+        None => growable.extend_validity(additional),
+    }
+}
+
+/// Replaces every null of `array` by the last non-null value before it.
+/// Leading nulls remain null.
+pub fn fill_null_forward(array: &dyn Array) -> Box<dyn Array> {
+    forward(array, None)
+}
+
+/// Replaces every null of `array` by the first non-null value after it.
+/// Trailing nulls remain null.
+pub fn fill_null_backward(array: &dyn Array) -> Box<dyn Array> {
+    backward(array, None)
+}
+
+/// Applies [`fill_null_forward`] to `arrays` as if they were a single array,
+/// i.e. leading nulls of an array are filled with the last non-null value of a previous array.
+/// # Errors
+/// Errors iff the arrays do not have the same [`DataType`].
+pub fn fill_null_forward_chunks(arrays: &[&dyn Array]) -> Result<Vec<Box<dyn Array>>> {
+    check_same_type(arrays)?;
+    let mut carry: Option<Box<dyn Array>> = None;
+    Ok(arrays
+        .iter()
+        .map(|array| {
+            let filled = forward(*array, carry.as_deref());
+            if let Some(last) = last_valid(filled.as_ref()) {
+                carry = Some(last);
+            }
+            filled
+        })
+        .collect())
+}
+
+/// Applies [`fill_null_backward`] to `arrays` as if they were a single array,
+/// i.e. trailing nulls of an array are filled with the first non-null value of a following array.
+/// # Errors
+/// Errors iff the arrays do not have the same [`DataType`].
+pub fn fill_null_backward_chunks(arrays: &[&dyn Array]) -> Result<Vec<Box<dyn Array>>> {
+    check_same_type(arrays)?;
+    let mut carry: Option<Box<dyn Array>> = None;
+    let mut result = arrays
+        .iter()
+        .rev()
+        .map(|array| {
+            let filled = backward(*array, carry.as_deref());
+            if let Some(first) = first_valid(filled.as_ref()) {
+                carry = Some(first);
+            }
+            filled
+        })
+        .collect::<Vec<_>>();
+    result.reverse();
+    Ok(result)
+}
+
+fn check_same_type(arrays: &[&dyn Array]) -> Result<()> {
+    if let Some(first) = arrays.first() {
+        if arrays
+            .iter()
+            .any(|array| array.data_type() != first.data_type())
+        {
+            return Err(Error::InvalidArgumentError(
+                "Filling nulls across arrays requires all arrays to have the same data type"
+                    .to_string(),
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Replaces every null of `array` by the value of `fill` in the same slot.
+/// When `fill` has a single element, it is used to fill all nulls (i.e. like a scalar).
+/// # Errors
+/// Errors iff
+/// * `array` and `fill` do not have the same [`DataType`]
+/// * `fill` has neither a single element nor the same length as `array`
+pub fn fill_null_with(array: &dyn Array, fill: &dyn Array) -> Result<Box<dyn Array>> {
+    if array.data_type() != fill.data_type() {
+        return Err(Error::InvalidArgumentError(
+            "fill_null_with requires the array and the fill to have the same data type".to_string(),
+        ));
+    }
+    let is_scalar = fill.len() == 1;
+    if !is_scalar && fill.len() != array.len() {
+        return Err(Error::InvalidArgumentError(
+            "fill_null_with requires the fill to have a single element or the same length as the array"
+                .to_string(),
+        ));
+    }
+
+    let validity = match nulls_to_fill(array) {
+        Some(validity) => validity,
+        None => return Ok(array.to_boxed()),
+    };
+
+    let mut growable = make_growable(&[array, fill], true, array.len());
+    let mut end = 0;
+    for (start, length) in valid_runs(validity) {
+        if is_scalar {
+            (end..start).for_each(|_| growable.extend(1, 0, 1))
+        } else {
+            growable.extend(1, end, start - end)
+        }
+        growable.extend(0, start, length);
+        end = start + length;
+    }
+    if is_scalar {
+        (end..array.len()).for_each(|_| growable.extend(1, 0, 1))
+    } else {
+        growable.extend(1, end, array.len() - end)
+    }
+    Ok(growable.as_box())
+}
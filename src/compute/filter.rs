@@ -1,4 +1,6 @@
 //! Contains operators to filter arrays such as [`filter`].
+use alloc::{boxed::Box, vec::Vec};
+
 use crate::array::growable::{make_growable, Growable};
 use crate::bitmap::utils::{BitChunkIterExact, BitChunksExact};
 use crate::bitmap::{utils::SlicesIterator, Bitmap, MutableBitmap};
@@ -43,7 +45,7 @@ where
             if ones == leading_ones {
                 let size = leading_ones as usize;
                 unsafe {
-                    std::ptr::copy(chunk.as_ptr(), dst, size);
+                    core::ptr::copy(chunk.as_ptr(), dst, size);
                     dst = dst.add(size);
                 }
                 return;
@@ -105,7 +107,7 @@ where
             if ones == leading_ones {
                 let size = leading_ones as usize;
                 unsafe {
-                    std::ptr::copy(chunk.as_ptr(), dst, size);
+                    core::ptr::copy(chunk.as_ptr(), dst, size);
                     dst = dst.add(size);
 
                     new_validity.extend_from_slice(validity_chunk.to_ne_bytes().as_ref(), 0, size);
@@ -202,6 +204,60 @@ fn filter_growable<'a>(growable: &mut impl Growable<'a>, chunks: &[(usize, usize
         .for_each(|(start, len)| growable.extend(0, *start, *len));
 }
 
+/// Filters `array` by the `slices` of selected items, whose lengths sum to `filter_count`
+fn filter_slices(
+    array: &dyn Array,
+    slices: &[(usize, usize)],
+    filter_count: usize,
+) -> Box<dyn Array> {
+    use crate::datatypes::PhysicalType::*;
+    match array.data_type().to_physical_type() {
+        Primitive(primitive) => with_match_primitive_type!(primitive, |$T| {
+            let array = array.as_any().downcast_ref().unwrap();
+            let mut growable =
+                growable::GrowablePrimitive::<$T>::new(vec![array], false, filter_count);
+            filter_growable(&mut growable, slices);
+            let array: PrimitiveArray<$T> = growable.into();
+            Box::new(array)
+        }),
+        Utf8 => {
+            let array = array.as_any().downcast_ref::<Utf8Array<i32>>().unwrap();
+            let mut growable = growable::GrowableUtf8::new(vec![array], false, filter_count);
+            filter_growable(&mut growable, slices);
+            let array: Utf8Array<i32> = growable.into();
+            Box::new(array)
+        }
+        LargeUtf8 => {
+            let array = array.as_any().downcast_ref::<Utf8Array<i64>>().unwrap();
+            let mut growable = growable::GrowableUtf8::new(vec![array], false, filter_count);
+            filter_growable(&mut growable, slices);
+            let array: Utf8Array<i64> = growable.into();
+            Box::new(array)
+        }
+        Binary => {
+            let array = array.as_any().downcast_ref::<BinaryArray<i32>>().unwrap();
+            let mut growable = growable::GrowableBinary::new(vec![array], false, filter_count);
+            filter_growable(&mut growable, slices);
+            let array: BinaryArray<i32> = growable.into();
+            Box::new(array)
+        }
+        LargeBinary => {
+            let array = array.as_any().downcast_ref::<BinaryArray<i64>>().unwrap();
+            let mut growable = growable::GrowableBinary::new(vec![array], false, filter_count);
+            filter_growable(&mut growable, slices);
+            let array: BinaryArray<i64> = growable.into();
+            Box::new(array)
+        }
+        _ => {
+            let mut mutable = make_growable(&[array], false, filter_count);
+            slices
+                .iter()
+                .for_each(|(start, len)| mutable.extend(0, *start, *len));
+            mutable.as_box()
+        }
+    }
+}
+
 /// Returns a prepared function optimized to filter multiple arrays.
 /// Creating this function requires time, but using it is faster than [filter] when the
 /// same filter needs to be applied to multiple arrays (e.g. a multiple columns).
@@ -210,39 +266,8 @@ pub fn build_filter(filter: &BooleanArray) -> Result<Filter> {
     let filter_count = iter.slots();
     let chunks = iter.collect::<Vec<_>>();
 
-    use crate::datatypes::PhysicalType::*;
     Ok(Box::new(move |array: &dyn Array| {
-        match array.data_type().to_physical_type() {
-            Primitive(primitive) => with_match_primitive_type!(primitive, |$T| {
-                let array = array.as_any().downcast_ref().unwrap();
-                let mut growable =
-                    growable::GrowablePrimitive::<$T>::new(vec![array], false, filter_count);
-                filter_growable(&mut growable, &chunks);
-                let array: PrimitiveArray<$T> = growable.into();
-                Box::new(array)
-            }),
-            Utf8 => {
-                let array = array.as_any().downcast_ref::<Utf8Array<i32>>().unwrap();
-                let mut growable = growable::GrowableUtf8::new(vec![array], false, filter_count);
-                filter_growable(&mut growable, &chunks);
-                let array: Utf8Array<i32> = growable.into();
-                Box::new(array)
-            }
-            LargeUtf8 => {
-                let array = array.as_any().downcast_ref::<Utf8Array<i64>>().unwrap();
-                let mut growable = growable::GrowableUtf8::new(vec![array], false, filter_count);
-                filter_growable(&mut growable, &chunks);
-                let array: Utf8Array<i64> = growable.into();
-                Box::new(array)
-            }
-            _ => {
-                let mut mutable = make_growable(&[array], false, filter_count);
-                chunks
-                    .iter()
-                    .for_each(|(start, len)| mutable.extend(0, *start, *len));
-                mutable.as_box()
-            }
-        }
+        filter_slices(array, &chunks, filter_count)
     }))
 }
 
@@ -321,3 +346,107 @@ pub fn filter_chunk<A: AsRef<dyn Array>>(
     };
     Chunk::try_new(filtered_arrays)
 }
+
+/// Allocations reused by [`filter_chunk_with_scratch`] across calls.
+#[derive(Debug, Clone, Default)]
+pub struct Scratch {
+    slices: Vec<(usize, usize)>,
+}
+
+/// Returns a new [Chunk] with arrays containing only values matching the filter, like
+/// [`filter_chunk`], but reusing the allocations of `scratch`.
+///
+/// The slices of selected items are computed once per call and shared by all columns, and
+/// `scratch` holds them across calls, which avoids allocations when this is called in a loop.
+pub fn filter_chunk_with_scratch<A: AsRef<dyn Array>>(
+    columns: &Chunk<A>,
+    filter_values: &BooleanArray,
+    scratch: &mut Scratch,
+) -> Result<Chunk<Box<dyn Array>>> {
+    // nulls of the filter are interpreted as `false`
+    let mask = match filter_values.validity() {
+        Some(validity) if validity.unset_bits() > 0 => filter_values.values() & validity,
+        _ => filter_values.values().clone(),
+    };
+
+    let arrays = columns.arrays();
+    let false_count = mask.unset_bits();
+    if false_count == mask.len() {
+        return Chunk::try_new(
+            arrays
+                .iter()
+                .map(|array| new_empty_array(array.as_ref().data_type().clone()))
+                .collect(),
+        );
+    }
+    if false_count == 0 {
+        return Chunk::try_new(
+            arrays
+                .iter()
+                .map(|array| array.as_ref().to_boxed())
+                .collect(),
+        );
+    }
+
+    let iter = SlicesIterator::new(&mask);
+    let filter_count = iter.slots();
+    scratch.slices.clear();
+    scratch.slices.extend(iter);
+
+    Chunk::try_new(
+        arrays
+            .iter()
+            .map(|array| filter_slices(array.as_ref(), &scratch.slices, filter_count))
+            .collect(),
+    )
+}
+
+/// Returns a new [Chunk] with arrays containing only the rows whose positions are in `selection`.
+///
+/// The runs of consecutive positions of `selection` are copied directly, without converting it
+/// to a [`BooleanArray`] or to indices first.
+/// # Errors
+/// This function errors iff a position in `selection` is out of bounds of `columns`.
+/// # Example
+/// ```rust
+/// # use arrow2::array::{Array, Int32Array};
+/// # use arrow2::chunk::Chunk;
+/// # use arrow2::compute::filter::filter_by_roaring;
+/// # use roaring::RoaringBitmap;
+/// let columns = Chunk::new(vec![Int32Array::from_slice([5, 6, 7, 8, 9]).boxed()]);
+/// let selection = RoaringBitmap::from_iter([0, 2, 3]);
+/// let filtered = filter_by_roaring(&columns, &selection).unwrap();
+/// assert_eq!(filtered.arrays()[0].as_ref(), &Int32Array::from_slice([5, 7, 8]) as &dyn Array);
+/// ```
+#[cfg(feature = "roaring")]
+#[cfg_attr(docsrs, doc(cfg(feature = "roaring")))]
+pub fn filter_by_roaring<A: AsRef<dyn Array>>(
+    columns: &Chunk<A>,
+    selection: &roaring::RoaringBitmap,
+) -> Result<Chunk<Box<dyn Array>>> {
+    let length = columns.len();
+    if let Some(max) = selection.max() {
+        if max as usize >= length {
+            return Err(crate::error::Error::InvalidArgumentError(format!(
+                "The position {max} of the selection is out of bounds of a chunk of length {length}"
+            )));
+        }
+    }
+
+    let mut slices: Vec<(usize, usize)> = vec![];
+    for position in selection.iter().map(|position| position as usize) {
+        match slices.last_mut() {
+            Some((start, len)) if *start + *len == position => *len += 1,
+            _ => slices.push((position, 1)),
+        }
+    }
+    let filter_count = selection.len() as usize;
+
+    Chunk::try_new(
+        columns
+            .arrays()
+            .iter()
+            .map(|array| filter_slices(array.as_ref(), &slices, filter_count))
+            .collect(),
+    )
+}
@@ -1,10 +1,33 @@
-//! Contains the operator [`if_then_else`].
-use crate::array::{growable, Array, BooleanArray};
+//! Contains the conditional operators [`if_then_else`], [`nullif`] and [`coalesce`].
+//!
+//! Arrays of length 1 are treated as scalars and broadcast to the length of the other
+//! arguments.
+use alloc::{boxed::Box, string::ToString, vec::Vec};
+
+use crate::array::{
+    growable::{self, Growable},
+    new_empty_array, Array, BooleanArray,
+};
 use crate::bitmap::utils::SlicesIterator;
 use crate::error::{Error, Result};
 
+/// Extends `growable` with `len` values of the array at `index`, starting at `start`, or
+/// with `len` repetitions of its single value when it is a scalar.
+fn extend(growable: &mut dyn Growable, index: usize, is_scalar: bool, start: usize, len: usize) {
+    if is_scalar {
+        (0..len).for_each(|_| growable.extend(index, 0, 1))
+    } else {
+        growable.extend(index, start, len)
+    }
+}
+
 /// Returns the values from `lhs` if the predicate is `true` or from the `rhs` if the predicate is false
 /// Returns `None` if the predicate is `None`.
+///
+/// Either `lhs` or `rhs` may have length 1, in which case its value is used for every row.
+/// # Errors
+/// Errors iff `lhs` and `rhs` have different data types, or their length is neither 1 nor
+/// the length of `predicate`.
 /// # Example
 /// ```rust
 /// # use arrow2::error::Result;
@@ -35,41 +58,181 @@ pub fn if_then_else(
             rhs.data_type()
         )));
     }
-    if (lhs.len() != rhs.len()) | (lhs.len() != predicate.len()) {
+    let len = predicate.len();
+    if [lhs.len(), rhs.len()].iter().any(|&l| l != len && l != 1) {
         return Err(Error::InvalidArgumentError(format!(
-            "If then else requires all arguments to have the same length (predicate = {}, lhs = {}, rhs = {})",
+            "If then else requires all arguments to have the same length or length 1 (predicate = {}, lhs = {}, rhs = {})",
             predicate.len(),
             lhs.len(),
             rhs.len()
         )));
     }
 
+    let scalars = [lhs.len() == 1, rhs.len() == 1];
+
     let result = if predicate.null_count() > 0 {
-        let mut growable = growable::make_growable(&[lhs, rhs], true, lhs.len());
+        let mut growable = growable::make_growable(&[lhs, rhs], true, len);
         for (i, v) in predicate.iter().enumerate() {
             match v {
-                Some(v) => growable.extend(!v as usize, i, 1),
+                Some(v) => extend(growable.as_mut(), !v as usize, scalars[!v as usize], i, 1),
                 None => growable.extend_validity(1),
             }
         }
         growable.as_box()
     } else {
-        let mut growable = growable::make_growable(&[lhs, rhs], false, lhs.len());
+        let mut growable = growable::make_growable(&[lhs, rhs], false, len);
         let mut start_falsy = 0;
         let mut total_len = 0;
-        for (start, len) in SlicesIterator::new(predicate.values()) {
+        for (start, slice_len) in SlicesIterator::new(predicate.values()) {
             if start != start_falsy {
-                growable.extend(1, start_falsy, start - start_falsy);
+                extend(
+                    growable.as_mut(),
+                    1,
+                    scalars[1],
+                    start_falsy,
+                    start - start_falsy,
+                );
                 total_len += start - start_falsy;
             };
-            growable.extend(0, start, len);
-            total_len += len;
-            start_falsy = start + len;
+            extend(growable.as_mut(), 0, scalars[0], start, slice_len);
+            total_len += slice_len;
+            start_falsy = start + slice_len;
         }
-        if total_len != lhs.len() {
-            growable.extend(1, total_len, lhs.len() - total_len);
+        if total_len != len {
+            extend(growable.as_mut(), 1, scalars[1], total_len, len - total_len);
         }
         growable.as_box()
     };
     Ok(result)
 }
+
+/// Returns `array` with nulls wherever `condition` is `true`. Null conditions are treated
+/// as `false`, i.e. they keep the value of `array`.
+///
+/// Unlike `compute::nullif::nullif`, which nulls values equal to those of another array,
+/// this function accepts an arbitrary condition and supports every data type.
+/// # Errors
+/// Errors iff `array` and `condition` have different lengths.
+/// # Example
+/// ```rust
+/// use arrow2::array::{BooleanArray, Utf8Array};
+/// use arrow2::compute::if_then_else::nullif;
+///
+/// let array = Utf8Array::<i32>::from([Some("a"), Some("b"), None, Some("d")]);
+/// let condition = BooleanArray::from([Some(true), None, Some(false), Some(false)]);
+/// let result = nullif(&array, &condition).unwrap();
+///
+/// let expected = Utf8Array::<i32>::from([None, Some("b"), None, Some("d")]);
+/// assert_eq!(expected, result.as_ref());
+/// ```
+pub fn nullif(array: &dyn Array, condition: &BooleanArray) -> Result<Box<dyn Array>> {
+    if array.len() != condition.len() {
+        return Err(Error::InvalidArgumentError(format!(
+            "Nullif requires all arguments to have the same length (array = {}, condition = {})",
+            array.len(),
+            condition.len()
+        )));
+    }
+    let mask = match condition.validity() {
+        Some(validity) => condition.values() & validity,
+        None => condition.values().clone(),
+    };
+
+    let mut growable = growable::make_growable(&[array], true, array.len());
+    let mut end = 0;
+    for (start, len) in SlicesIterator::new(&mask) {
+        growable.extend(0, end, start - end);
+        growable.extend_validity(len);
+        end = start + len;
+    }
+    growable.extend(0, end, array.len() - end);
+    Ok(growable.as_box())
+}
+
+/// Returns, for every row, the first valid value of `arrays`, or null when all of them
+/// are null.
+///
+/// Arrays of length 1 are used for every row, so that e.g. a column can be coalesced with
+/// a default value.
+/// # Errors
+/// Errors iff
+/// * `arrays` is empty
+/// * the arrays have different data types
+/// * the lengths of the arrays other than 1 differ
+/// # Example
+/// ```rust
+/// use arrow2::array::Int32Array;
+/// use arrow2::compute::if_then_else::coalesce;
+///
+/// let a = Int32Array::from([Some(1), None, None]);
+/// let b = Int32Array::from([None, Some(2), None]);
+/// let default = Int32Array::from_slice([0]);
+/// let result = coalesce(&[&a, &b, &default]).unwrap();
+///
+/// let expected = Int32Array::from_slice([1, 2, 0]);
+/// assert_eq!(expected, result.as_ref());
+/// ```
+pub fn coalesce(arrays: &[&dyn Array]) -> Result<Box<dyn Array>> {
+    let first = arrays.first().ok_or_else(|| {
+        Error::InvalidArgumentError("Coalesce requires at least one argument".to_string())
+    })?;
+    if let Some(other) = arrays
+        .iter()
+        .find(|array| array.data_type() != first.data_type())
+    {
+        return Err(Error::InvalidArgumentError(format!(
+            "Coalesce requires the arguments to have the same datatypes ({:?} != {:?})",
+            first.data_type(),
+            other.data_type()
+        )));
+    }
+    let len = arrays
+        .iter()
+        .map(|array| array.len())
+        .find(|&len| len != 1)
+        .unwrap_or(1);
+    if arrays
+        .iter()
+        .any(|array| array.len() != len && array.len() != 1)
+    {
+        return Err(Error::InvalidArgumentError(format!(
+            "Coalesce requires all arguments to have the same length or length 1 ({:?})",
+            arrays.iter().map(|array| array.len()).collect::<Vec<_>>()
+        )));
+    }
+    if len == 0 {
+        return Ok(new_empty_array(first.data_type().clone()));
+    }
+    let scalars = arrays
+        .iter()
+        .map(|array| array.len() == 1)
+        .collect::<Vec<_>>();
+
+    // the index of the array whose value is taken, for every row
+    let choice = |row: usize| {
+        arrays
+            .iter()
+            .zip(scalars.iter())
+            .position(|(array, &is_scalar)| array.is_valid(if is_scalar { 0 } else { row }))
+    };
+
+    let mut growable = growable::make_growable(arrays, true, len);
+    let mut extend_run = |index: Option<usize>, start: usize, run: usize| match index {
+        Some(index) => extend(growable.as_mut(), index, scalars[index], start, run),
+        None => growable.extend_validity(run),
+    };
+    let mut start = 0;
+    let mut current = choice(0);
+    for row in 1..len {
+        let index = choice(row);
+        if index != current {
+            extend_run(current, start, row - start);
+            start = row;
+            current = index;
+        }
+    }
+    if len > 0 {
+        extend_run(current, start, len - start);
+    }
+    Ok(growable.as_box())
+}
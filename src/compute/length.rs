@@ -17,24 +17,25 @@
 
 //! Defines kernel for length of composite arrays
 
+use alloc::{boxed::Box, vec::Vec};
+
 use crate::{
     array::*,
-    datatypes::DataType,
+    bitmap::Bitmap,
+    datatypes::{DataType, PhysicalType},
     error::{Error, Result},
-    offset::Offset,
+    offset::{Offset, OffsetsBuffer},
     types::NativeType,
 };
 
-fn unary_offsets_string<O, F>(array: &Utf8Array<O>, op: F) -> PrimitiveArray<O>
+fn unary_offsets<O>(offsets: &OffsetsBuffer<O>, validity: Option<&Bitmap>) -> PrimitiveArray<O>
 where
     O: Offset + NativeType,
-    F: Fn(O) -> O,
 {
-    let values = array
-        .offsets()
+    let values = offsets
         .buffer()
         .windows(2)
-        .map(|offset| op(offset[1] - offset[0]))
+        .map(|offset| offset[1] - offset[0])
         .collect::<Vec<_>>();
 
     let data_type = if O::IS_LARGE {
@@ -43,19 +44,58 @@ where
         DataType::Int32
     };
 
-    PrimitiveArray::<O>::new(data_type, values.into(), array.validity().cloned())
+    PrimitiveArray::<O>::new(data_type, values.into(), validity.cloned())
+}
+
+fn constant(size: usize, len: usize, validity: Option<&Bitmap>) -> PrimitiveArray<i32> {
+    PrimitiveArray::<i32>::new(
+        DataType::Int32,
+        vec![size as i32; len].into(),
+        validity.cloned(),
+    )
 }
 
-/// Returns an array of integers with the number of bytes on each string of the array.
+macro_rules! offsets_length {
+    ($array:expr, $ty:ty) => {{
+        let array = $array.as_any().downcast_ref::<$ty>().unwrap();
+        Ok(Box::new(unary_offsets(array.offsets(), array.validity())))
+    }};
+}
+
+/// Returns an array of integers with the number of bytes on each string or binary of the array,
+/// or the number of elements on each list or map of the array.
+///
+/// The result is an [`Int64Array`] for large types (e.g. [`DataType::LargeUtf8`]) and
+/// an [`Int32Array`] otherwise. Null entries are null.
+/// # Errors
+/// This function errors iff the array's type is not supported (see [`can_length`]).
 pub fn length(array: &dyn Array) -> Result<Box<dyn Array>> {
-    match array.data_type() {
-        DataType::Utf8 => {
-            let array = array.as_any().downcast_ref::<Utf8Array<i32>>().unwrap();
-            Ok(Box::new(unary_offsets_string::<i32, _>(array, |x| x)))
+    match array.data_type().to_physical_type() {
+        PhysicalType::Utf8 => offsets_length!(array, Utf8Array<i32>),
+        PhysicalType::LargeUtf8 => offsets_length!(array, Utf8Array<i64>),
+        PhysicalType::Binary => offsets_length!(array, BinaryArray<i32>),
+        PhysicalType::LargeBinary => offsets_length!(array, BinaryArray<i64>),
+        PhysicalType::List => offsets_length!(array, ListArray<i32>),
+        PhysicalType::LargeList => offsets_length!(array, ListArray<i64>),
+        PhysicalType::Map => offsets_length!(array, MapArray),
+        PhysicalType::FixedSizeBinary => {
+            let array = array
+                .as_any()
+                .downcast_ref::<FixedSizeBinaryArray>()
+                .unwrap();
+            Ok(Box::new(constant(
+                array.size(),
+                array.len(),
+                array.validity(),
+            )))
         }
-        DataType::LargeUtf8 => {
-            let array = array.as_any().downcast_ref::<Utf8Array<i64>>().unwrap();
-            Ok(Box::new(unary_offsets_string::<i64, _>(array, |x| x)))
+        PhysicalType::FixedSizeList => {
+            let array = array.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
+            Ok(Box::new(constant(
+                array.size(),
+                array.len(),
+                array.validity(),
+            )))
         }
         _ => Err(Error::InvalidArgumentError(format!(
             "length not supported for {:?}",
@@ -78,5 +118,16 @@ pub fn length(array: &dyn Array) -> Result<Box<dyn Array>> {
 /// assert_eq!(can_length(&data_type), false);
 /// ```
 pub fn can_length(data_type: &DataType) -> bool {
-    matches!(data_type, DataType::Utf8 | DataType::LargeUtf8)
+    matches!(
+        data_type.to_physical_type(),
+        PhysicalType::Utf8
+            | PhysicalType::LargeUtf8
+            | PhysicalType::Binary
+            | PhysicalType::LargeBinary
+            | PhysicalType::List
+            | PhysicalType::LargeList
+            | PhysicalType::Map
+            | PhysicalType::FixedSizeBinary
+            | PhysicalType::FixedSizeList
+    )
 }
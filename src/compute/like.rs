@@ -1,11 +1,13 @@
-//! Contains "like" operators such as [`like_utf8`] and [`like_utf8_scalar`].
+//! Contains "like" operators such as [`like_utf8`] and [`like_utf8_scalar`], their case-insensitive
+//! counterparts such as [`ilike_utf8`], and variants with a custom escape character
+//! such as [`like_utf8_with_escape`].
 
 use ahash::AHashMap;
 use regex::bytes::Regex as BytesRegex;
 use regex::Regex;
 
 use crate::{
-    array::{BinaryArray, BooleanArray, Utf8Array},
+    array::{Array, BinaryArray, BooleanArray, DictionaryArray, DictionaryKey, Utf8Array},
     bitmap::Bitmap,
     compute::utils::combine_validities,
     datatypes::DataType,
@@ -13,6 +15,10 @@ use crate::{
     offset::Offset,
 };
 
+/// The escape character of LIKE patterns used by all operators without an explicit escape
+/// character, e.g. [`like_utf8`].
+pub const DEFAULT_ESCAPE: char = '\\';
+
 #[inline]
 fn is_like_pattern(c: char) -> bool {
     c == '%' || c == '_'
@@ -22,13 +28,13 @@ fn is_like_pattern(c: char) -> bool {
 ///
 /// 1. Replace like wildcards for regex expressions as the pattern will be evaluated using regex match: `%` => `.*` and `_` => `.`
 /// 2. Escape regex meta characters to match them and not be evaluated as regex special chars. For example: `.` => `\\.`
-/// 3. Replace escaped like wildcards removing the escape characters to be able to match it as a regex. For example: `\\%` => `%`
-fn replace_pattern(pattern: &str) -> String {
+/// 3. Replace escaped like wildcards removing the `escape` characters to be able to match it as a regex. For example: `\\%` => `%`
+fn replace_pattern(pattern: &str, escape: char) -> String {
     let mut result = String::new();
     let text = String::from(pattern);
     let mut chars_iter = text.chars().peekable();
     while let Some(c) = chars_iter.next() {
-        if c == '\\' {
+        if c == escape {
             let next = chars_iter.peek();
             match next {
                 Some(next) if is_like_pattern(*next) => {
@@ -37,8 +43,10 @@ fn replace_pattern(pattern: &str) -> String {
                     chars_iter.next();
                 }
                 _ => {
-                    result.push('\\');
-                    result.push('\\');
+                    if regex_syntax::is_meta_character(c) {
+                        result.push('\\');
+                    }
+                    result.push(c);
                 }
             }
         } else if regex_syntax::is_meta_character(c) {
@@ -55,10 +63,21 @@ fn replace_pattern(pattern: &str) -> String {
     result
 }
 
+/// Returns the [`Regex`] equivalent to the LIKE `pattern`.
+fn like_regex(pattern: &str, escape: char, case_insensitive: bool) -> Result<Regex> {
+    let flags = if case_insensitive { "(?i)" } else { "" };
+    let re_pattern = replace_pattern(pattern, escape);
+    Regex::new(&format!("{flags}^{re_pattern}$")).map_err(|e| {
+        Error::InvalidArgumentError(format!("Unable to build regex from LIKE pattern: {e}"))
+    })
+}
+
 #[inline]
 fn a_like_utf8<O: Offset, F: Fn(bool) -> bool>(
     lhs: &Utf8Array<O>,
     rhs: &Utf8Array<O>,
+    escape: char,
+    case_insensitive: bool,
     op: F,
 ) -> Result<BooleanArray> {
     if lhs.len() != rhs.len() {
@@ -78,12 +97,7 @@ fn a_like_utf8<O: Offset, F: Fn(bool) -> bool>(
                     let pattern = if let Some(pattern) = map.get(pattern) {
                         pattern
                     } else {
-                        let re_pattern = replace_pattern(pattern);
-                        let re = Regex::new(&format!("^{re_pattern}$")).map_err(|e| {
-                            Error::InvalidArgumentError(format!(
-                                "Unable to build regex from LIKE pattern: {e}"
-                            ))
-                        })?;
+                        let re = like_regex(pattern, escape, case_insensitive)?;
                         map.insert(pattern, re);
                         map.get(pattern).unwrap()
                     };
@@ -119,7 +133,32 @@ fn a_like_utf8<O: Offset, F: Fn(bool) -> bool>(
 /// assert_eq!(result, BooleanArray::from_slice(&[true, false, true, false, true]));
 /// ```
 pub fn like_utf8<O: Offset>(lhs: &Utf8Array<O>, rhs: &Utf8Array<O>) -> Result<BooleanArray> {
-    a_like_utf8(lhs, rhs, |x| x)
+    a_like_utf8(lhs, rhs, DEFAULT_ESCAPE, false, |x| x)
+}
+
+/// Returns `lhs LIKE rhs ESCAPE escape` operation on two [`Utf8Array`], i.e. [`like_utf8`] where
+/// wildcards preceded by `escape` (instead of [`DEFAULT_ESCAPE`]) are matched literally.
+/// # Error
+/// Errors iff:
+/// * the arrays have a different length
+/// * any of the patterns is not valid
+/// # Example
+/// ```
+/// use arrow2::array::{Utf8Array, BooleanArray};
+/// use arrow2::compute::like::like_utf8_with_escape;
+///
+/// let strings = Utf8Array::<i32>::from_slice(&["100%", "100$"]);
+/// let patterns = Utf8Array::<i32>::from_slice(&["100#%", "100#%"]);
+///
+/// let result = like_utf8_with_escape(&strings, &patterns, '#').unwrap();
+/// assert_eq!(result, BooleanArray::from_slice(&[true, false]));
+/// ```
+pub fn like_utf8_with_escape<O: Offset>(
+    lhs: &Utf8Array<O>,
+    rhs: &Utf8Array<O>,
+    escape: char,
+) -> Result<BooleanArray> {
+    a_like_utf8(lhs, rhs, escape, false, |x| x)
 }
 
 /// Returns `lhs NOT LIKE rhs` operation on two [`Utf8Array`].
@@ -129,34 +168,58 @@ pub fn like_utf8<O: Offset>(lhs: &Utf8Array<O>, rhs: &Utf8Array<O>) -> Result<Bo
 /// * `%` - The percent sign represents zero, one, or multiple characters
 /// * `_` - The underscore represents a single character
 pub fn nlike_utf8<O: Offset>(lhs: &Utf8Array<O>, rhs: &Utf8Array<O>) -> Result<BooleanArray> {
-    a_like_utf8(lhs, rhs, |x| !x)
+    a_like_utf8(lhs, rhs, DEFAULT_ESCAPE, false, |x| !x)
+}
+
+/// Returns `lhs ILIKE rhs` operation on two [`Utf8Array`], i.e. a case-insensitive [`like_utf8`].
+/// # Error
+/// Errors iff:
+/// * the arrays have a different length
+/// * any of the patterns is not valid
+/// # Example
+/// ```
+/// use arrow2::array::{Utf8Array, BooleanArray};
+/// use arrow2::compute::like::ilike_utf8;
+///
+/// let strings = Utf8Array::<i64>::from_slice(&["Arrow", "arrow", "Parquet"]);
+/// let patterns = Utf8Array::<i64>::from_slice(&["a%", "%RO_", "a%"]);
+///
+/// let result = ilike_utf8(&strings, &patterns).unwrap();
+/// assert_eq!(result, BooleanArray::from_slice(&[true, true, false]));
+/// ```
+pub fn ilike_utf8<O: Offset>(lhs: &Utf8Array<O>, rhs: &Utf8Array<O>) -> Result<BooleanArray> {
+    a_like_utf8(lhs, rhs, DEFAULT_ESCAPE, true, |x| x)
+}
+
+/// Returns `lhs NOT ILIKE rhs` operation on two [`Utf8Array`].
+pub fn nilike_utf8<O: Offset>(lhs: &Utf8Array<O>, rhs: &Utf8Array<O>) -> Result<BooleanArray> {
+    a_like_utf8(lhs, rhs, DEFAULT_ESCAPE, true, |x| !x)
 }
 
 fn a_like_utf8_scalar<O: Offset, F: Fn(bool) -> bool>(
     lhs: &Utf8Array<O>,
     rhs: &str,
+    escape: char,
+    case_insensitive: bool,
     op: F,
 ) -> Result<BooleanArray> {
     let validity = lhs.validity();
 
-    let values = if !rhs.contains(is_like_pattern) {
+    // the fast paths below are only valid for case-sensitive patterns without escaped wildcards
+    let is_plain = !case_insensitive && !rhs.contains(escape);
+
+    let values = if !case_insensitive && !rhs.contains(is_like_pattern) {
         Bitmap::from_trusted_len_iter(lhs.values_iter().map(|x| op(x == rhs)))
-    } else if rhs.ends_with('%')
-        && !rhs.ends_with("\\%")
-        && !rhs[..rhs.len() - 1].contains(is_like_pattern)
-    {
+    } else if is_plain && rhs.ends_with('%') && !rhs[..rhs.len() - 1].contains(is_like_pattern) {
         // fast path, can use starts_with
         let starts_with = &rhs[..rhs.len() - 1];
         Bitmap::from_trusted_len_iter(lhs.values_iter().map(|x| op(x.starts_with(starts_with))))
-    } else if rhs.starts_with('%') && !rhs[1..].contains(is_like_pattern) {
+    } else if is_plain && rhs.starts_with('%') && !rhs[1..].contains(is_like_pattern) {
         // fast path, can use ends_with
         let ends_with = &rhs[1..];
         Bitmap::from_trusted_len_iter(lhs.values_iter().map(|x| op(x.ends_with(ends_with))))
     } else {
-        let re_pattern = replace_pattern(rhs);
-        let re = Regex::new(&format!("^{re_pattern}$")).map_err(|e| {
-            Error::InvalidArgumentError(format!("Unable to build regex from LIKE pattern: {e}"))
-        })?;
+        let re = like_regex(rhs, escape, case_insensitive)?;
         Bitmap::from_trusted_len_iter(lhs.values_iter().map(|x| op(re.is_match(x))))
     };
     Ok(BooleanArray::new(
@@ -188,7 +251,29 @@ fn a_like_utf8_scalar<O: Offset, F: Fn(bool) -> bool>(
 /// assert_eq!(result, BooleanArray::from_slice(&[true, true, true, false]));
 /// ```
 pub fn like_utf8_scalar<O: Offset>(lhs: &Utf8Array<O>, rhs: &str) -> Result<BooleanArray> {
-    a_like_utf8_scalar(lhs, rhs, |x| x)
+    a_like_utf8_scalar(lhs, rhs, DEFAULT_ESCAPE, false, |x| x)
+}
+
+/// Returns `lhs LIKE rhs ESCAPE escape` operation, i.e. [`like_utf8_scalar`] where
+/// wildcards preceded by `escape` (instead of [`DEFAULT_ESCAPE`]) are matched literally.
+/// # Error
+/// Errors iff the pattern is not valid
+/// # Example
+/// ```
+/// use arrow2::array::{Utf8Array, BooleanArray};
+/// use arrow2::compute::like::like_utf8_scalar_with_escape;
+///
+/// let array = Utf8Array::<i32>::from_slice(&["a_b", "axb"]);
+///
+/// let result = like_utf8_scalar_with_escape(&array, "a#_b", '#').unwrap();
+/// assert_eq!(result, BooleanArray::from_slice(&[true, false]));
+/// ```
+pub fn like_utf8_scalar_with_escape<O: Offset>(
+    lhs: &Utf8Array<O>,
+    rhs: &str,
+    escape: char,
+) -> Result<BooleanArray> {
+    a_like_utf8_scalar(lhs, rhs, escape, false, |x| x)
 }
 
 /// Returns `lhs NOT LIKE rhs` operation.
@@ -198,7 +283,117 @@ pub fn like_utf8_scalar<O: Offset>(lhs: &Utf8Array<O>, rhs: &str) -> Result<Bool
 /// * `%` - The percent sign represents zero, one, or multiple characters
 /// * `_` - The underscore represents a single character
 pub fn nlike_utf8_scalar<O: Offset>(lhs: &Utf8Array<O>, rhs: &str) -> Result<BooleanArray> {
-    a_like_utf8_scalar(lhs, rhs, |x| !x)
+    a_like_utf8_scalar(lhs, rhs, DEFAULT_ESCAPE, false, |x| !x)
+}
+
+/// Returns `lhs ILIKE rhs` operation, i.e. a case-insensitive [`like_utf8_scalar`].
+/// # Error
+/// Errors iff the pattern is not valid
+/// # Example
+/// ```
+/// use arrow2::array::{Utf8Array, BooleanArray};
+/// use arrow2::compute::like::ilike_utf8_scalar;
+///
+/// let array = Utf8Array::<i64>::from_slice(&["Arrow", "ARROW", "BA"]);
+///
+/// let result = ilike_utf8_scalar(&array, "arr%").unwrap();
+/// assert_eq!(result, BooleanArray::from_slice(&[true, true, false]));
+/// ```
+pub fn ilike_utf8_scalar<O: Offset>(lhs: &Utf8Array<O>, rhs: &str) -> Result<BooleanArray> {
+    a_like_utf8_scalar(lhs, rhs, DEFAULT_ESCAPE, true, |x| x)
+}
+
+/// Returns `lhs NOT ILIKE rhs` operation.
+pub fn nilike_utf8_scalar<O: Offset>(lhs: &Utf8Array<O>, rhs: &str) -> Result<BooleanArray> {
+    a_like_utf8_scalar(lhs, rhs, DEFAULT_ESCAPE, true, |x| !x)
+}
+
+fn a_like_dictionary_scalar<K: DictionaryKey, F: Fn(bool) -> bool>(
+    lhs: &DictionaryArray<K>,
+    rhs: &str,
+    case_insensitive: bool,
+    op: F,
+) -> Result<BooleanArray> {
+    let values = lhs.values();
+    let values = match values.data_type().to_logical_type() {
+        DataType::Utf8 => a_like_utf8_scalar(
+            values.as_any().downcast_ref::<Utf8Array<i32>>().unwrap(),
+            rhs,
+            DEFAULT_ESCAPE,
+            case_insensitive,
+            op,
+        )?,
+        DataType::LargeUtf8 => a_like_utf8_scalar(
+            values.as_any().downcast_ref::<Utf8Array<i64>>().unwrap(),
+            rhs,
+            DEFAULT_ESCAPE,
+            case_insensitive,
+            op,
+        )?,
+        other => {
+            return Err(Error::InvalidArgumentError(format!(
+                "LIKE on a dictionary requires utf8 values, but got {other:?}"
+            )))
+        }
+    };
+
+    // the pattern was evaluated once per value: gather the results through the keys
+    let iter = lhs.keys_iter().map(|key| {
+        key.filter(|key| values.is_valid(*key))
+            .map(|key| values.value(key))
+    });
+    Ok(BooleanArray::from_trusted_len_iter(iter))
+}
+
+/// Returns `lhs LIKE rhs` operation on a [`DictionaryArray`] of utf8 values.
+///
+/// The pattern is evaluated once per value of the dictionary (and not once per slot), which
+/// is faster than [`like_utf8_scalar`] on the materialized array when values repeat.
+/// # Error
+/// Errors iff:
+/// * the values of the dictionary are not utf8
+/// * the pattern is not valid
+/// # Example
+/// ```
+/// use arrow2::array::{BooleanArray, DictionaryArray, MutableDictionaryArray, MutableUtf8Array, TryExtend};
+/// use arrow2::compute::like::like_dictionary_scalar;
+///
+/// let mut array = MutableDictionaryArray::<i32, MutableUtf8Array<i32>>::new();
+/// array.try_extend([Some("Arrow"), None, Some("BA"), Some("Arrow")]).unwrap();
+/// let array: DictionaryArray<i32> = array.into();
+///
+/// let result = like_dictionary_scalar(&array, "A%").unwrap();
+/// assert_eq!(result, BooleanArray::from([Some(true), None, Some(false), Some(true)]));
+/// ```
+pub fn like_dictionary_scalar<K: DictionaryKey>(
+    lhs: &DictionaryArray<K>,
+    rhs: &str,
+) -> Result<BooleanArray> {
+    a_like_dictionary_scalar(lhs, rhs, false, |x| x)
+}
+
+/// Returns `lhs NOT LIKE rhs` operation on a [`DictionaryArray`] of utf8 values.
+pub fn nlike_dictionary_scalar<K: DictionaryKey>(
+    lhs: &DictionaryArray<K>,
+    rhs: &str,
+) -> Result<BooleanArray> {
+    a_like_dictionary_scalar(lhs, rhs, false, |x| !x)
+}
+
+/// Returns `lhs ILIKE rhs` operation on a [`DictionaryArray`] of utf8 values.
+pub fn ilike_dictionary_scalar<K: DictionaryKey>(
+    lhs: &DictionaryArray<K>,
+    rhs: &str,
+) -> Result<BooleanArray> {
+    a_like_dictionary_scalar(lhs, rhs, true, |x| x)
+}
+
+/// Returns `lhs NOT ILIKE rhs` operation on a [`DictionaryArray`] of utf8 values.
+pub fn nilike_dictionary_scalar<K: DictionaryKey>(
+    lhs: &DictionaryArray<K>,
+    rhs: &str,
+) -> Result<BooleanArray> {
+    a_like_dictionary_scalar(lhs, rhs, true, |x| !x)
 }
 
 #[inline]
@@ -225,7 +420,7 @@ fn a_like_binary<O: Offset, F: Fn(bool) -> bool>(
                         pattern
                     } else {
                         let re_pattern = simdutf8::basic::from_utf8(pattern).unwrap();
-                        let re_pattern = replace_pattern(re_pattern);
+                        let re_pattern = replace_pattern(re_pattern, DEFAULT_ESCAPE);
                         let re = BytesRegex::new(&format!("^{re_pattern}$")).map_err(|e| {
                             Error::InvalidArgumentError(format!(
                                 "Unable to build regex from LIKE pattern: {e}"
@@ -304,7 +499,7 @@ fn a_like_binary_scalar<O: Offset, F: Fn(bool) -> bool>(
         let ends_with = &rhs[1..];
         Bitmap::from_trusted_len_iter(lhs.values_iter().map(|x| op(x.ends_with(ends_with))))
     } else {
-        let re_pattern = replace_pattern(pattern);
+        let re_pattern = replace_pattern(pattern, DEFAULT_ESCAPE);
         let re = BytesRegex::new(&format!("^{re_pattern}$")).map_err(|e| {
             Error::InvalidArgumentError(format!("Unable to build regex from LIKE pattern: {e}"))
         })?;
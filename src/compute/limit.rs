@@ -1,5 +1,7 @@
 //! Contains the operator [`limit`].
 
+use alloc::boxed::Box;
+
 use crate::array::Array;
 
 /// Returns the [`Array`] limited by `num_elements`.
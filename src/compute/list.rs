@@ -0,0 +1,112 @@
+//! Contains kernels over arrays of lists, such as [`list_element`].
+
+use crate::{
+    array::{growable::make_growable, Array, FixedSizeListArray, ListArray, MapArray},
+    datatypes::PhysicalType,
+    error::{Error, Result},
+    offset::Offset,
+};
+
+/// Returns the (start, length) of every slot of `array`, or `None` when the slot is null.
+fn validity_slots<'a, I: Iterator<Item = (usize, usize)> + 'a>(
+    slots: I,
+    array: &'a dyn Array,
+) -> impl Iterator<Item = Option<(usize, usize)>> + 'a {
+    slots
+        .enumerate()
+        .map(move |(i, slot)| (!array.is_null(i)).then_some(slot))
+}
+
+fn offsets_slots<O: Offset>(offsets: &[O]) -> impl Iterator<Item = (usize, usize)> + '_ {
+    offsets
+        .windows(2)
+        .map(|x| (x[0].to_usize(), (x[1] - x[0]).to_usize()))
+}
+
+fn element<I: Iterator<Item = Option<(usize, usize)>>>(
+    values: &dyn Array,
+    slots: I,
+    len: usize,
+    index: usize,
+) -> Box<dyn Array> {
+    let mut growable = make_growable(&[values], true, len);
+    for slot in slots {
+        match slot {
+            Some((start, length)) if index < length => growable.extend(0, start + index, 1),
+            _ => growable.extend_validity(1),
+        }
+    }
+    growable.as_box()
+}
+
+/// Returns the element at position `index` of every list of `array`, or null when
+/// the list is null or has `index` or fewer elements.
+///
+/// `array` must be a [`ListArray`], a [`FixedSizeListArray`] or a [`MapArray`] (whose elements
+/// are its entries). The result has the type of the elements of `array`.
+/// # Example
+/// ```
+/// use arrow2::array::{Int32Array, ListArray, MutableListArray, MutablePrimitiveArray, TryExtend};
+/// use arrow2::compute::list::list_element;
+///
+/// let mut array = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+/// array.try_extend([Some(vec![Some(1), Some(2)]), None, Some(vec![Some(3)])]).unwrap();
+/// let array: ListArray<i32> = array.into();
+///
+/// let result = list_element(&array, 1).unwrap();
+/// assert_eq!(result.as_ref(), &Int32Array::from([Some(2), None, None]) as &dyn arrow2::array::Array);
+/// ```
+/// # Errors
+/// This function errors iff `array` is not a list, fixed-size list or map.
+pub fn list_element(array: &dyn Array, index: usize) -> Result<Box<dyn Array>> {
+    let len = array.len();
+    Ok(match array.data_type().to_physical_type() {
+        PhysicalType::List => {
+            let list = array.as_any().downcast_ref::<ListArray<i32>>().unwrap();
+            let offsets = offsets_slots(list.offsets().buffer());
+            element(
+                list.values().as_ref(),
+                validity_slots(offsets, array),
+                len,
+                index,
+            )
+        }
+        PhysicalType::LargeList => {
+            let list = array.as_any().downcast_ref::<ListArray<i64>>().unwrap();
+            let offsets = offsets_slots(list.offsets().buffer());
+            element(
+                list.values().as_ref(),
+                validity_slots(offsets, array),
+                len,
+                index,
+            )
+        }
+        PhysicalType::Map => {
+            let map = array.as_any().downcast_ref::<MapArray>().unwrap();
+            let offsets = offsets_slots(map.offsets().buffer());
+            element(
+                map.field().as_ref(),
+                validity_slots(offsets, array),
+                len,
+                index,
+            )
+        }
+        PhysicalType::FixedSizeList => {
+            let list = array.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
+            let size = list.size();
+            let offsets = (0..len).map(|i| (i * size, size));
+            element(
+                list.values().as_ref(),
+                validity_slots(offsets, array),
+                len,
+                index,
+            )
+        }
+        _ => {
+            return Err(Error::InvalidArgumentError(format!(
+                "list_element not supported for {:?}",
+                array.data_type()
+            )))
+        }
+    })
+}
@@ -0,0 +1,103 @@
+//! Contains kernels over [`MapArray`]s, such as [`map_extract`].
+
+use crate::{
+    array::{growable::make_growable, Array, ListArray, MapArray},
+    compute::comparison::{can_eq_scalar, eq_scalar},
+    datatypes::DataType,
+    error::{Error, Result},
+    scalar::Scalar,
+};
+
+/// Returns the value whose key equals `key` of every map of `map`, or null when the map is null
+/// or has no such key. When a map has more than one entry with the key, the first is returned.
+///
+/// The result has the type of the values of `map`.
+/// # Example
+/// ```
+/// use arrow2::array::{Array, Int32Array, MapArray, StructArray, Utf8Array};
+/// use arrow2::compute::map::map_extract;
+/// use arrow2::datatypes::{DataType, Field};
+/// use arrow2::scalar::Utf8Scalar;
+///
+/// let entries = DataType::Struct(vec![
+///     Field::new("key", DataType::Utf8, false),
+///     Field::new("value", DataType::Int32, true),
+/// ]);
+/// let field = StructArray::new(
+///     entries.clone(),
+///     vec![
+///         Utf8Array::<i32>::from_slice(["a", "b", "b"]).boxed(),
+///         Int32Array::from_slice([1, 2, 3]).boxed(),
+///     ],
+///     None,
+/// );
+/// let map = MapArray::new(
+///     DataType::Map(Box::new(Field::new("entries", entries, false)), false),
+///     vec![0, 2, 3].try_into().unwrap(),
+///     field.boxed(),
+///     None,
+/// );
+///
+/// let result = map_extract(&map, &Utf8Scalar::<i32>::new(Some("a"))).unwrap();
+/// assert_eq!(result.as_ref(), &Int32Array::from([Some(1), None]) as &dyn Array);
+/// ```
+/// # Errors
+/// This function errors iff the type of `key` differs from the type of the keys of `map`, or
+/// keys of that type can't be compared.
+pub fn map_extract(map: &MapArray, key: &dyn Scalar) -> Result<Box<dyn Array>> {
+    let keys = map.keys_array();
+    if keys.data_type() != key.data_type() {
+        return Err(Error::InvalidArgumentError(format!(
+            "map_extract requires the key to be of the type of the keys of the map ({:?}), but it is of type {:?}",
+            keys.data_type(),
+            key.data_type()
+        )));
+    }
+    if !can_eq_scalar(keys.data_type()) {
+        return Err(Error::NotYetImplemented(format!(
+            "map_extract is not supported for keys of type {:?}",
+            keys.data_type()
+        )));
+    }
+    let values = map.values_array();
+
+    // a null key never equals `key`
+    let equal = eq_scalar(keys.as_ref(), key);
+    let equal = match equal.validity() {
+        Some(validity) => equal.values() & validity,
+        None => equal.values().clone(),
+    };
+
+    let offsets = map.offsets().buffer();
+    let first = offsets[0] as usize;
+    let mut growable = make_growable(&[values.as_ref()], true, map.len());
+    for (i, window) in offsets.windows(2).enumerate() {
+        let start = window[0] as usize - first;
+        let end = window[1] as usize - first;
+        let index = (!map.is_null(i))
+            .then(|| (start..end).find(|index| equal.get_bit(*index)))
+            .flatten();
+        match index {
+            Some(index) => growable.extend(0, index, 1),
+            None => growable.extend_validity(1),
+        }
+    }
+    Ok(growable.as_box())
+}
+
+/// Returns `map` as a [`ListArray`] whose items are the entries of the maps, a
+/// [`crate::array::StructArray`] of keys and values.
+///
+/// This operation is `O(1)`: the offsets, validity and entries are shared with `map`.
+pub fn map_entries_to_struct_list(map: &MapArray) -> ListArray<i32> {
+    let field = match map.data_type().to_logical_type() {
+        DataType::Map(field, _) => field.as_ref().clone(),
+        _ => unreachable!(),
+    };
+    ListArray::new(
+        DataType::List(Box::new(field)),
+        map.offsets().clone(),
+        map.field().clone(),
+        map.validity().cloned(),
+    )
+}
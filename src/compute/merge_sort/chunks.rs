@@ -0,0 +1,299 @@
+use std::cmp::Ordering;
+use std::iter::Fuse;
+
+use crate::array::Array;
+use crate::chunk::Chunk;
+use crate::compute::sort::row::{Row, RowConverter, Rows, SortField};
+use crate::datatypes::DataType;
+use crate::error::{Error, Result};
+
+use super::{take_arrays, MergeSlice, SortOptions};
+
+/// The state of one of the sorted iterators being merged
+struct Cursor<I: Iterator> {
+    iter: Fuse<I>,
+    /// the current chunk, its rows and the position of the next row to merge
+    current: Option<(Chunk<Box<dyn Array>>, Rows, usize)>,
+    /// the index of the current chunk in the chunks of the pending output, if any
+    pending: Option<usize>,
+}
+
+impl<I: Iterator> Cursor<I> {
+    fn row(&self) -> Option<Row<'_>> {
+        self.current
+            .as_ref()
+            .map(|(_, rows, position)| rows.row(*position))
+    }
+}
+
+/// An [`Iterator`] that merges N iterators of sorted [`Chunk`]s into a single iterator of
+/// sorted [`Chunk`]s, created by [`merge_sort_chunks`].
+pub struct MergeSortChunks<I>
+where
+    I: Iterator<Item = Result<Chunk<Box<dyn Array>>>>,
+{
+    cursors: Vec<Cursor<I>>,
+    sort_columns: Vec<(usize, SortOptions)>,
+    chunk_size: usize,
+    converter: Option<RowConverter>,
+    data_types: Option<Vec<DataType>>,
+    // the chunks and slices of the output being built
+    chunks: Vec<Chunk<Box<dyn Array>>>,
+    slices: Vec<MergeSlice>,
+    length: usize,
+    finished: bool,
+}
+
+impl<I> MergeSortChunks<I>
+where
+    I: Iterator<Item = Result<Chunk<Box<dyn Array>>>>,
+{
+    /// Advances the cursor `index` to its next non-empty chunk if its current chunk is exhausted
+    fn fill(&mut self, index: usize) -> Result<()> {
+        let cursor = &mut self.cursors[index];
+        if let Some((chunk, _, position)) = &cursor.current {
+            if *position < chunk.len() {
+                return Ok(());
+            }
+        }
+        cursor.current = None;
+        cursor.pending = None;
+
+        for chunk in cursor.iter.by_ref() {
+            let chunk = chunk?;
+            let data_types = chunk
+                .arrays()
+                .iter()
+                .map(|array| array.data_type().clone())
+                .collect::<Vec<_>>();
+            let expected = self.data_types.get_or_insert_with(|| data_types.clone());
+            if *expected != data_types {
+                return Err(Error::InvalidArgumentError(
+                    "All chunks being merged must have the same data types".to_string(),
+                ));
+            }
+            if chunk.is_empty() {
+                continue;
+            }
+
+            let columns = self
+                .sort_columns
+                .iter()
+                .map(|(column, _)| {
+                    chunk.arrays().get(*column).cloned().ok_or_else(|| {
+                        Error::InvalidArgumentError(format!(
+                            "The sort column {column} is out of bounds of chunks with {} columns",
+                            chunk.arrays().len()
+                        ))
+                    })
+                })
+                .collect::<Result<Vec<_>>>()?;
+            let converter = self.converter.get_or_insert_with(|| {
+                RowConverter::new(
+                    self.sort_columns
+                        .iter()
+                        .zip(columns.iter())
+                        .map(|((_, options), array)| {
+                            SortField::new_with_options(array.data_type().clone(), *options)
+                        })
+                        .collect(),
+                )
+            });
+            let rows = converter.convert_columns(&columns)?;
+            cursor.current = Some((chunk, rows, 0));
+            break;
+        }
+        Ok(())
+    }
+
+    /// Returns the index of the cursor with the smallest row and the index of the cursor with
+    /// the second smallest row, if any. Equal rows are ordered by the index of their cursor.
+    fn smallest(&self) -> Option<(usize, Option<usize>)> {
+        let mut smallest: Option<usize> = None;
+        let mut second: Option<usize> = None;
+        for (index, cursor) in self.cursors.iter().enumerate() {
+            let row = match cursor.row() {
+                Some(row) => row,
+                None => continue,
+            };
+            match smallest {
+                Some(current) if row >= self.cursors[current].row().unwrap() => {
+                    let is_second = match second {
+                        Some(second) => row < self.cursors[second].row().unwrap(),
+                        None => true,
+                    };
+                    if is_second {
+                        second = Some(index)
+                    }
+                }
+                _ => {
+                    second = smallest;
+                    smallest = Some(index);
+                }
+            }
+        }
+        smallest.map(|smallest| (smallest, second))
+    }
+
+    /// Appends to the output the longest run of rows of the cursor `index` that precede the
+    /// current row of the cursor `next`.
+    fn take_run(&mut self, index: usize, next: Option<usize>) {
+        let bound = next.and_then(|next| self.cursors[next].row().map(|row| (next, row)));
+        let cursor = &self.cursors[index];
+        let (chunk, rows, start) = cursor.current.as_ref().unwrap();
+        let start = *start;
+        let max_len = (chunk.len() - start).min(self.chunk_size - self.length);
+
+        let mut len = 1;
+        if let Some((next, bound)) = bound {
+            while len < max_len {
+                match rows.row(start + len).cmp(&bound) {
+                    Ordering::Less => len += 1,
+                    Ordering::Equal if index < next => len += 1,
+                    _ => break,
+                }
+            }
+        } else {
+            len = max_len;
+        }
+
+        let pending = match cursor.pending {
+            Some(pending) => pending,
+            None => {
+                self.chunks.push(chunk.clone());
+                self.chunks.len() - 1
+            }
+        };
+        let cursor = &mut self.cursors[index];
+        cursor.pending = Some(pending);
+        cursor.current.as_mut().unwrap().2 += len;
+        self.slices.push((pending, start, len));
+        self.length += len;
+    }
+
+    /// Builds the output from the pending slices
+    fn flush(&mut self) -> Result<Chunk<Box<dyn Array>>> {
+        let num_columns = self.data_types.as_ref().map_or(0, |x| x.len());
+        let arrays = (0..num_columns)
+            .map(|column| {
+                let arrays = self
+                    .chunks
+                    .iter()
+                    .map(|chunk| chunk.arrays()[column].as_ref())
+                    .collect::<Vec<_>>();
+                take_arrays(&arrays, self.slices.iter().copied(), None)
+            })
+            .collect();
+
+        self.chunks.clear();
+        self.slices.clear();
+        self.length = 0;
+        self.cursors
+            .iter_mut()
+            .for_each(|cursor| cursor.pending = None);
+        Chunk::try_new(arrays)
+    }
+
+    fn try_next(&mut self) -> Result<Option<Chunk<Box<dyn Array>>>> {
+        while self.length < self.chunk_size {
+            for index in 0..self.cursors.len() {
+                self.fill(index)?;
+            }
+            match self.smallest() {
+                Some((index, next)) => self.take_run(index, next),
+                None => break,
+            }
+        }
+        if self.length == 0 {
+            return Ok(None);
+        }
+        self.flush().map(Some)
+    }
+}
+
+impl<I> Iterator for MergeSortChunks<I>
+where
+    I: Iterator<Item = Result<Chunk<Box<dyn Array>>>>,
+{
+    type Item = Result<Chunk<Box<dyn Array>>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+        let result = self.try_next().transpose();
+        if !matches!(result, Some(Ok(_))) {
+            self.finished = true;
+        }
+        result
+    }
+}
+
+/// Merges `iterators` of sorted [`Chunk`]s into a single iterator of sorted [`Chunk`]s of
+/// at most `chunk_size` rows (a k-way merge), e.g. to merge the sorted runs of an external sort.
+///
+/// `sort_columns` are the indices of the columns that the chunks are sorted by, and their [`SortOptions`].
+/// Rows are compared via their [row format](crate::compute::sort::row), and the merge is stable:
+/// equal rows are yielded in the order of their iterators in `iterators`.
+/// Only one chunk of each iterator is kept in memory at a time.
+/// # Errors
+/// This function errors iff `chunk_size` is zero. The iterator errors when one of `iterators` errors,
+/// when the chunks have different data types, or when a sort column is out of bounds or not sortable.
+/// # Example
+/// ```rust
+/// use arrow2::array::Int32Array;
+/// use arrow2::chunk::Chunk;
+/// use arrow2::compute::merge_sort::{merge_sort_chunks, SortOptions};
+/// # use arrow2::error::Result;
+/// # fn main() -> Result<()> {
+/// let a = vec![Ok(Chunk::new(vec![Int32Array::from_slice([1, 4]).boxed()]))];
+/// let b = vec![
+///     Ok(Chunk::new(vec![Int32Array::from_slice([2]).boxed()])),
+///     Ok(Chunk::new(vec![Int32Array::from_slice([3, 5]).boxed()])),
+/// ];
+///
+/// let merged = merge_sort_chunks(
+///     vec![a.into_iter(), b.into_iter()],
+///     &[(0, SortOptions::default())],
+///     4,
+/// )?
+/// .collect::<Result<Vec<_>>>()?;
+///
+/// assert_eq!(merged.len(), 2);
+/// assert_eq!(Int32Array::from_slice([1, 2, 3, 4]), merged[0].arrays()[0].as_ref());
+/// assert_eq!(Int32Array::from_slice([5]), merged[1].arrays()[0].as_ref());
+/// # Ok(())
+/// # }
+/// ```
+pub fn merge_sort_chunks<I>(
+    iterators: Vec<I>,
+    sort_columns: &[(usize, SortOptions)],
+    chunk_size: usize,
+) -> Result<MergeSortChunks<I>>
+where
+    I: Iterator<Item = Result<Chunk<Box<dyn Array>>>>,
+{
+    if chunk_size == 0 {
+        return Err(Error::InvalidArgumentError(
+            "merge_sort_chunks requires a non-zero chunk size".to_string(),
+        ));
+    }
+    Ok(MergeSortChunks {
+        cursors: iterators
+            .into_iter()
+            .map(|iter| Cursor {
+                iter: iter.fuse(),
+                current: None,
+                pending: None,
+            })
+            .collect(),
+        sort_columns: sort_columns.to_vec(),
+        chunk_size,
+        converter: None,
+        data_types: None,
+        chunks: vec![],
+        slices: vec![],
+        length: 0,
+        finished: false,
+    })
+}
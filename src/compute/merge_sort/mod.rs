@@ -55,6 +55,15 @@
 //!
 //! To serialize slices, e.g. for checkpointing or transfer via Arrow's IPC, you can store
 //! them as 3 non-null primitive arrays (e.g. `PrimitiveArray<i64>`).
+//!
+//! ## Merge-sort streams of chunks
+//!
+//! When the sorted data does not fit in memory (e.g. the sorted runs of an external sort),
+//! use [`merge_sort_chunks`] to merge N iterators of sorted [`Chunk`](crate::chunk::Chunk)s
+//! into a single iterator of sorted chunks of bounded size.
+
+mod chunks;
+pub use chunks::{merge_sort_chunks, MergeSortChunks};
 
 use ahash::AHashMap;
 use std::cmp::Ordering;
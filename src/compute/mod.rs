@@ -11,7 +11,8 @@
 //! Some dynamically-typed operators have an auxiliary function, `can_*`, that returns
 //! true if the operator can be applied to the particular `DataType`.
 
-#[cfg(any(feature = "compute_aggregate", feature = "io_parquet"))]
+// `io_parquet` implies `io_ipc`; both use `aggregate::estimated_bytes_size`
+#[cfg(any(feature = "compute_aggregate", feature = "io_ipc"))]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_aggregate")))]
 pub mod aggregate;
 #[cfg(feature = "compute_arithmetics")]
@@ -40,6 +41,24 @@ pub mod concatenate;
 #[cfg(feature = "compute_contains")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_contains")))]
 pub mod contains;
+#[cfg(feature = "compute_decimal")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compute_decimal")))]
+pub mod decimal;
+#[cfg(feature = "compute_deletes")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compute_deletes")))]
+pub mod deletes;
+#[cfg(feature = "compute_distinct")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compute_distinct")))]
+pub mod distinct;
+#[cfg(feature = "compute_encode")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compute_encode")))]
+pub mod encode;
+#[cfg(feature = "compute_explode")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compute_explode")))]
+pub mod explode;
+#[cfg(feature = "compute_fill_null")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compute_fill_null")))]
+pub mod fill_null;
 #[cfg(feature = "compute_filter")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_filter")))]
 pub mod filter;
@@ -58,6 +77,12 @@ pub mod like;
 #[cfg(feature = "compute_limit")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_limit")))]
 pub mod limit;
+#[cfg(feature = "compute_list")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compute_list")))]
+pub mod list;
+#[cfg(feature = "compute_map")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compute_map")))]
+pub mod map;
 #[cfg(feature = "compute_merge_sort")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_merge_sort")))]
 pub mod merge_sort;
@@ -67,12 +92,24 @@ pub mod nullif;
 #[cfg(feature = "compute_partition")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_partition")))]
 pub mod partition;
+#[cfg(feature = "compute_rank")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compute_rank")))]
+pub mod rank;
 #[cfg(feature = "compute_regex_match")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_regex_match")))]
 pub mod regex_match;
+#[cfg(feature = "compute_resample")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compute_resample")))]
+pub mod resample;
+#[cfg(feature = "compute_selection")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compute_selection")))]
+pub mod selection;
 #[cfg(feature = "compute_sort")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_sort")))]
 pub mod sort;
+#[cfg(feature = "compute_struct")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compute_struct")))]
+pub mod struct_;
 #[cfg(feature = "compute_substring")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_substring")))]
 pub mod substring;
@@ -86,6 +123,9 @@ pub mod temporal;
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_utf8")))]
 pub mod utf8;
 mod utils;
+#[cfg(feature = "compute_vector")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compute_vector")))]
+pub mod vector;
 #[cfg(feature = "compute_window")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_window")))]
 pub mod window;
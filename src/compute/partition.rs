@@ -18,8 +18,13 @@
 //! Defines partition kernel for [`crate::array::Array`]
 
 use crate::array::ord::DynComparator;
+use crate::array::{Array, PrimitiveArray};
+use crate::chunk::Chunk;
+use crate::compute::hash::hash;
+use crate::compute::sort::row::{RowConverter, SortField};
 use crate::compute::sort::{build_compare, SortColumn};
 use crate::error::{Error, Result};
+use crate::types::Index;
 use std::cmp::Ordering;
 use std::iter::Iterator;
 use std::ops::Range;
@@ -115,3 +120,178 @@ impl Iterator for LexicographicalPartitionIterator {
         }
     }
 }
+
+/// Returns the columns `key_columns` of `chunk`.
+fn key_arrays<'a, A: AsRef<dyn Array>>(
+    chunk: &'a Chunk<A>,
+    key_columns: &[usize],
+) -> Result<Vec<&'a dyn Array>> {
+    if key_columns.is_empty() {
+        return Err(Error::InvalidArgumentError(
+            "Partitioning requires at least one key column".to_string(),
+        ));
+    }
+    key_columns
+        .iter()
+        .map(|column| {
+            chunk
+                .arrays()
+                .get(*column)
+                .map(|array| array.as_ref())
+                .ok_or_else(|| {
+                    Error::InvalidArgumentError(format!(
+                        "The key column {column} is out of bounds for a chunk of {} columns",
+                        chunk.arrays().len()
+                    ))
+                })
+        })
+        .collect()
+}
+
+/// Returns the indices of the rows of every partition, given the partition of every row.
+fn partition_indices<I: Index, P: Iterator<Item = usize>>(
+    partitions: P,
+    num_partitions: usize,
+) -> Result<Vec<PrimitiveArray<I>>> {
+    let mut indices = vec![vec![]; num_partitions];
+    for (row, partition) in partitions.enumerate() {
+        indices[partition].push(I::from_usize(row).ok_or(Error::Overflow)?);
+    }
+    Ok(indices
+        .into_iter()
+        .map(|indices| PrimitiveArray::new(I::PRIMITIVE.into(), indices.into(), None))
+        .collect())
+}
+
+/// Partitions the rows of `chunk` into `num_partitions` partitions by the hash of the
+/// columns `key_columns`, returning the indices of the rows of every partition, to be used
+/// with [`take`](crate::compute::take::take).
+///
+/// Rows with equal keys are assigned to the same partition, and nulls are equal to each
+/// other. Rows keep their relative order within each partition.
+/// # Example
+/// ```
+/// use arrow2::array::{Array, Int32Array, Utf8Array};
+/// use arrow2::chunk::Chunk;
+/// use arrow2::compute::partition::hash_partition;
+///
+/// let chunk = Chunk::new(vec![
+///     Int32Array::from_slice([1, 2, 1, 3]).boxed(),
+///     Utf8Array::<i32>::from_slice(["a", "b", "c", "d"]).boxed(),
+/// ]);
+///
+/// let partitions = hash_partition::<u32, _>(&chunk, &[0], 2).unwrap();
+/// assert_eq!(partitions.len(), 2);
+/// assert_eq!(partitions.iter().map(|p| p.len()).sum::<usize>(), 4);
+/// ```
+/// # Errors
+/// Errors iff `key_columns` is empty or out of bounds, `num_partitions` is zero, any key
+/// column can't be hashed (see [`crate::compute::hash::can_hash`]), or the number of rows
+/// does not fit in `I`.
+pub fn hash_partition<I: Index, A: AsRef<dyn Array>>(
+    chunk: &Chunk<A>,
+    key_columns: &[usize],
+    num_partitions: usize,
+) -> Result<Vec<PrimitiveArray<I>>> {
+    if num_partitions == 0 {
+        return Err(Error::InvalidArgumentError(
+            "Hash partitioning requires at least one partition".to_string(),
+        ));
+    }
+    let keys = key_arrays(chunk, key_columns)?;
+
+    let mut hashes = vec![0u64; chunk.len()];
+    for key in keys {
+        let key = hash(key)?;
+        hashes.iter_mut().zip(key.iter()).for_each(|(hash, key)| {
+            // nulls hash to 0, so that they are equal to each other
+            let key = key.copied().unwrap_or_default();
+            *hash ^= key
+                .wrapping_add(0x9e37_79b9_7f4a_7c15)
+                .wrapping_add(*hash << 6)
+                .wrapping_add(*hash >> 2);
+        });
+    }
+
+    partition_indices(
+        hashes
+            .into_iter()
+            .map(|hash| (hash % num_partitions as u64) as usize),
+        num_partitions,
+    )
+}
+
+/// Partitions the rows of `chunk` into `sorted_bounds.len() + 1` partitions by comparing the
+/// columns `key_columns` to the rows of `sorted_bounds`, returning the indices of the rows of
+/// every partition, to be used with [`take`](crate::compute::take::take).
+///
+/// `sorted_bounds` has one column per key column, sorted according to its [`SortColumn::options`].
+/// A row is assigned to the partition `p` such that `p` is the number of bounds smaller or equal
+/// to the row, i.e. the bound `i` is the (inclusive) lower bound of the partition `i + 1`.
+/// Rows keep their relative order within each partition.
+///
+/// The rows are compared via the [row format](crate::compute::sort::row).
+/// # Errors
+/// Errors iff `key_columns` is empty or out of bounds, the number or types of the columns of
+/// `sorted_bounds` differs from the key columns, the columns of `sorted_bounds` have different
+/// lengths, `sorted_bounds` is not sorted, any type is not supported by the row format, or the
+/// number of rows does not fit in `I`.
+pub fn range_partition<I: Index, A: AsRef<dyn Array>>(
+    chunk: &Chunk<A>,
+    key_columns: &[usize],
+    sorted_bounds: &[SortColumn],
+) -> Result<Vec<PrimitiveArray<I>>> {
+    let keys = key_arrays(chunk, key_columns)?;
+    if keys.len() != sorted_bounds.len() {
+        return Err(Error::InvalidArgumentError(format!(
+            "Range partitioning requires one bound column per key column, but there are {} key columns and {} bound columns",
+            keys.len(),
+            sorted_bounds.len()
+        )));
+    }
+
+    if let Some(bound) = sorted_bounds
+        .iter()
+        .find(|bound| bound.values.len() != sorted_bounds[0].values.len())
+    {
+        return Err(Error::InvalidArgumentError(format!(
+            "The bound columns of range partitioning must have equal lengths, but they have {} and {} rows",
+            sorted_bounds[0].values.len(),
+            bound.values.len()
+        )));
+    }
+
+    let fields = sorted_bounds
+        .iter()
+        .map(|bound| {
+            SortField::new_with_options(
+                bound.values.data_type().clone(),
+                bound.options.unwrap_or_default(),
+            )
+        })
+        .collect();
+    let mut converter = RowConverter::new(fields);
+    let bounds = sorted_bounds
+        .iter()
+        .map(|bound| bound.values.to_boxed())
+        .collect::<Vec<_>>();
+    let bounds = converter.convert_columns(&bounds)?;
+    let keys = keys
+        .into_iter()
+        .map(|key| key.to_boxed())
+        .collect::<Vec<_>>();
+    let rows = converter.convert_columns(&keys)?;
+
+    let bounds = bounds.iter().collect::<Vec<_>>();
+    if bounds.windows(2).any(|bounds| bounds[0] > bounds[1]) {
+        return Err(Error::InvalidArgumentError(
+            "The bounds of range partitioning must be sorted".to_string(),
+        ));
+    }
+
+    partition_indices(
+        rows.iter()
+            .map(|row| bounds.partition_point(|bound| *bound <= row)),
+        bounds.len() + 1,
+    )
+}
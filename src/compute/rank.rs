@@ -0,0 +1,138 @@
+//! Contains kernels to rank the values of an [`Array`], such as [`rank`], [`dense_rank`]
+//! and [`percent_rank`].
+//!
+//! Values are ranked by their position in the order defined by [`SortOptions`], where the
+//! first position has rank 1. Nulls are equal to each other and are ranked before or after
+//! all values according to [`SortOptions::nulls_first`].
+use std::cmp::Ordering;
+
+use crate::array::{Array, PrimitiveArray};
+use crate::compute::sort::{build_compare, SortOptions};
+use crate::error::Result;
+
+/// How [`rank`] ranks values that are equal.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash)]
+pub enum RankTies {
+    /// Equal values get the lowest rank of the group (e.g. `[1, 2, 2, 4]`), like SQL's `RANK`
+    #[default]
+    Min,
+    /// Equal values get the highest rank of the group (e.g. `[1, 3, 3, 4]`)
+    Max,
+    /// Equal values are ranked in the order they appear in the array (e.g. `[1, 2, 3, 4]`),
+    /// like SQL's `ROW_NUMBER`
+    First,
+}
+
+/// Options that define how [`rank`] should behave
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct RankOptions {
+    /// The order in which values are ranked
+    pub sort: SortOptions,
+    /// How equal values are ranked
+    pub ties: RankTies,
+}
+
+/// Calls `f` for every group of equal values in the order of `options`, with the
+/// position of the group in that order and the indices of its values in ascending order.
+fn for_each_group<F>(array: &dyn Array, options: &SortOptions, mut f: F) -> Result<()>
+where
+    F: FnMut(usize, &[usize]),
+{
+    let compare = build_compare(array, *options)?;
+
+    // a stable sort keeps equal values in the order they appear in the array
+    let mut indices = (0..array.len()).collect::<Vec<_>>();
+    indices.sort_by(|a, b| compare(*a, *b));
+
+    let mut start = 0;
+    while start < indices.len() {
+        let first = indices[start];
+        let end = indices[start + 1..]
+            .iter()
+            .position(|index| compare(first, *index) != Ordering::Equal)
+            .map(|position| start + 1 + position)
+            .unwrap_or(indices.len());
+        f(start, &indices[start..end]);
+        start = end;
+    }
+    Ok(())
+}
+
+/// Returns the rank of each value of `array`, where equal values are ranked according to
+/// [`RankOptions::ties`].
+/// # Errors
+/// This function errors iff the [`DataType`](crate::datatypes::DataType) of `array` can't be sorted.
+/// # Example
+/// ```
+/// use arrow2::array::{Int32Array, UInt64Array};
+/// use arrow2::compute::rank::{rank, RankOptions};
+///
+/// let array = Int32Array::from(&[Some(3), None, Some(1), Some(3)]);
+/// let ranks = rank(&array, &RankOptions::default()).unwrap();
+/// assert_eq!(ranks, UInt64Array::from_slice([3, 1, 2, 3]));
+/// ```
+pub fn rank(array: &dyn Array, options: &RankOptions) -> Result<PrimitiveArray<u64>> {
+    let mut ranks = vec![0u64; array.len()];
+    for_each_group(array, &options.sort, |start, indices| match options.ties {
+        RankTies::Min => indices
+            .iter()
+            .for_each(|index| ranks[*index] = start as u64 + 1),
+        RankTies::Max => indices
+            .iter()
+            .for_each(|index| ranks[*index] = (start + indices.len()) as u64),
+        RankTies::First => indices
+            .iter()
+            .enumerate()
+            .for_each(|(i, index)| ranks[*index] = (start + i) as u64 + 1),
+    })?;
+    Ok(PrimitiveArray::from_vec(ranks))
+}
+
+/// Returns the dense rank of each value of `array`: equal values have the same rank and
+/// there are no gaps between ranks (e.g. `[1, 2, 2, 3]`), like SQL's `DENSE_RANK`.
+/// # Errors
+/// This function errors iff the [`DataType`](crate::datatypes::DataType) of `array` can't be sorted.
+/// # Example
+/// ```
+/// use arrow2::array::{Int32Array, UInt64Array};
+/// use arrow2::compute::rank::dense_rank;
+/// use arrow2::compute::sort::SortOptions;
+///
+/// let array = Int32Array::from(&[Some(3), None, Some(1), Some(3)]);
+/// let ranks = dense_rank(&array, &SortOptions::default()).unwrap();
+/// assert_eq!(ranks, UInt64Array::from_slice([3, 1, 2, 3]));
+/// ```
+pub fn dense_rank(array: &dyn Array, options: &SortOptions) -> Result<PrimitiveArray<u64>> {
+    let mut ranks = vec![0u64; array.len()];
+    let mut rank = 0;
+    for_each_group(array, options, |_, indices| {
+        rank += 1;
+        indices.iter().for_each(|index| ranks[*index] = rank);
+    })?;
+    Ok(PrimitiveArray::from_vec(ranks))
+}
+
+/// Returns the relative rank of each value of `array`, `(rank - 1) / (len - 1)` where `rank`
+/// is the rank of [`RankTies::Min`], like SQL's `PERCENT_RANK`. All values have relative rank
+/// `0.0` when `array` has a single value.
+/// # Errors
+/// This function errors iff the [`DataType`](crate::datatypes::DataType) of `array` can't be sorted.
+/// # Example
+/// ```
+/// use arrow2::array::{Float64Array, Int32Array};
+/// use arrow2::compute::rank::percent_rank;
+/// use arrow2::compute::sort::SortOptions;
+///
+/// let array = Int32Array::from_slice([3, 2, 1, 3, 5]);
+/// let ranks = percent_rank(&array, &SortOptions::default()).unwrap();
+/// assert_eq!(ranks, Float64Array::from_slice([0.5, 0.25, 0.0, 0.5, 1.0]));
+/// ```
+pub fn percent_rank(array: &dyn Array, options: &SortOptions) -> Result<PrimitiveArray<f64>> {
+    let mut ranks = vec![0.0; array.len()];
+    let denominator = array.len().saturating_sub(1).max(1) as f64;
+    for_each_group(array, options, |start, indices| {
+        let rank = start as f64 / denominator;
+        indices.iter().for_each(|index| ranks[*index] = rank);
+    })?;
+    Ok(PrimitiveArray::from_vec(ranks))
+}
@@ -1,7 +1,11 @@
 //! Contains regex matching operators [`regex_match`] and [`regex_match_scalar`].
+//!
+//! Predicates evaluated over many arrays (e.g. every [`Chunk`](crate::chunk::Chunk) of a
+//! file) should compile their pattern once and use [`regex_match_compiled`], or
+//! [`regex_set_match`] to match multiple patterns at once.
 
 use ahash::AHashMap;
-use regex::Regex;
+use regex::{Regex, RegexSet};
 
 use crate::array::{BooleanArray, Utf8Array};
 use crate::bitmap::Bitmap;
@@ -61,7 +65,46 @@ pub fn regex_match<O: Offset>(values: &Utf8Array<O>, regex: &Utf8Array<O>) -> Re
 pub fn regex_match_scalar<O: Offset>(values: &Utf8Array<O>, regex: &str) -> Result<BooleanArray> {
     let regex = Regex::new(regex)
         .map_err(|e| Error::InvalidArgumentError(format!("Unable to compile regex: {e}")))?;
-    Ok(unary_utf8_boolean(values, |x| regex.is_match(x)))
+    Ok(regex_match_compiled(values, &regex))
+}
+
+/// Regex matches against a pre-compiled [`Regex`], so that it can be re-used across arrays.
+/// # Example
+/// ```
+/// use arrow2::array::{Utf8Array, BooleanArray};
+/// use arrow2::compute::regex_match::regex_match_compiled;
+/// use regex::Regex;
+///
+/// let regex = Regex::new("^A.A").unwrap();
+///
+/// let strings = Utf8Array::<i32>::from_slice(&vec!["ArAow", "A_B"]);
+/// let result = regex_match_compiled(&strings, &regex);
+/// assert_eq!(result, BooleanArray::from_slice(&vec![true, false]));
+///
+/// let strings = Utf8Array::<i32>::from_slice(&vec!["AAA"]);
+/// let result = regex_match_compiled(&strings, &regex);
+/// assert_eq!(result, BooleanArray::from_slice(&vec![true]));
+/// ```
+pub fn regex_match_compiled<O: Offset>(values: &Utf8Array<O>, regex: &Regex) -> BooleanArray {
+    unary_utf8_boolean(values, |x| regex.is_match(x))
+}
+
+/// Matches against any of the patterns of a [`RegexSet`], i.e. a slot is `true` iff
+/// at least one of the patterns matches it.
+/// # Example
+/// ```
+/// use arrow2::array::{Utf8Array, BooleanArray};
+/// use arrow2::compute::regex_match::regex_set_match;
+/// use regex::RegexSet;
+///
+/// let set = RegexSet::new(&["^a", "b$"]).unwrap();
+///
+/// let strings = Utf8Array::<i32>::from(&vec![Some("ac"), Some("cb"), Some("ca"), None]);
+/// let result = regex_set_match(&strings, &set);
+/// assert_eq!(result, BooleanArray::from(&vec![Some(true), Some(true), Some(false), None]));
+/// ```
+pub fn regex_set_match<O: Offset>(values: &Utf8Array<O>, set: &RegexSet) -> BooleanArray {
+    unary_utf8_boolean(values, |x| set.is_match(x))
 }
 
 fn unary_utf8_boolean<O: Offset, F: Fn(&str) -> bool>(
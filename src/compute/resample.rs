@@ -0,0 +1,195 @@
+//! Defines kernels to resample time series to a fixed interval, filling gaps.
+//!
+//! Resampling is done in two steps:
+//! 1. [`resample_indices`] computes the grid of timestamps and, for every slot of the grid,
+//!    the index of the row of the original series that falls in it (or null for gaps).
+//! 2. [`fill_gaps`] uses these indices to take the values of any column of the series,
+//!    filling the gaps according to a [`FillStrategy`].
+//! # Example
+//! ```
+//! use arrow2::array::{Int32Array, Int64Array};
+//! use arrow2::compute::resample::{fill_gaps, resample_indices, FillStrategy};
+//!
+//! let timestamps = Int64Array::from_slice([0, 10, 40]);
+//! let values = Int32Array::from_slice([1, 2, 3]);
+//!
+//! let (grid, indices) = resample_indices(&timestamps, 10).unwrap();
+//! assert_eq!(grid, Int64Array::from_slice([0, 10, 20, 30, 40]));
+//!
+//! let result = fill_gaps(&values, &indices, FillStrategy::Forward).unwrap();
+//! let expected = Int32Array::from_slice([1, 2, 2, 2, 3]);
+//! assert_eq!(expected, result.as_ref());
+//! ```
+use crate::{
+    array::{Array, BooleanArray, PrimitiveArray},
+    bitmap::{Bitmap, MutableBitmap},
+    datatypes::{DataType, PhysicalType},
+    error::{Error, Result},
+    types::NativeType,
+};
+
+use super::take::take;
+
+/// The strategy used by [`fill_gaps`] to fill slots of the grid without rows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FillStrategy {
+    /// Gaps are null
+    Null,
+    /// Gaps take the value of the last row before them (or null if there is none)
+    Forward,
+    /// Gaps are zero (or `false` for booleans)
+    Zero,
+}
+
+/// The maximum number of slots of the grid returned by [`resample_indices`].
+pub const MAX_GRID_LENGTH: usize = 1 << 24;
+
+/// Returns the grid of timestamps spaced by `interval` covering all `timestamps`, and, for each
+/// slot of the grid, the index of the last row of `timestamps` within `[slot, slot + interval)`.
+///
+/// The grid starts at the first timestamp rounded down to a multiple of `interval`. Slots
+/// without rows (gaps) have a null index. The grid has the same [`DataType`] as `timestamps`.
+/// # Errors
+/// Errors iff:
+/// * `interval` is not positive, or `timestamps` has nulls or is not sorted in ascending order
+/// * the grid would have more than [`MAX_GRID_LENGTH`] slots
+/// * a timestamp of the grid overflows `i64`
+pub fn resample_indices(
+    timestamps: &PrimitiveArray<i64>,
+    interval: i64,
+) -> Result<(PrimitiveArray<i64>, PrimitiveArray<u32>)> {
+    if interval <= 0 {
+        return Err(Error::InvalidArgumentError(
+            "The resampling interval must be positive".to_string(),
+        ));
+    }
+    if timestamps.null_count() > 0 {
+        return Err(Error::InvalidArgumentError(
+            "Timestamps to resample must not have nulls".to_string(),
+        ));
+    }
+    let values = timestamps.values().as_slice();
+    if values.windows(2).any(|w| w[0] > w[1]) {
+        return Err(Error::InvalidArgumentError(
+            "Timestamps to resample must be sorted in ascending order".to_string(),
+        ));
+    }
+    if values.is_empty() {
+        return Ok((
+            PrimitiveArray::new_empty(timestamps.data_type().clone()),
+            PrimitiveArray::new_empty(DataType::UInt32),
+        ));
+    }
+    if values.len() > u32::MAX as usize {
+        return Err(Error::Overflow);
+    }
+
+    let bucket = |x: i64| x.div_euclid(interval);
+    let first = bucket(values[0]);
+    let length = bucket(values[values.len() - 1])
+        .checked_sub(first)
+        .and_then(|span| span.checked_add(1))
+        .ok_or(Error::Overflow)?;
+    let length = usize::try_from(length).map_err(|_| Error::Overflow)?;
+    if length > MAX_GRID_LENGTH {
+        return Err(Error::InvalidArgumentError(format!(
+            "Resampling with an interval of {interval} requires {length} slots, \
+            more than the maximum of {MAX_GRID_LENGTH}"
+        )));
+    }
+
+    let grid = (0..length as i64)
+        .map(|i| (first + i).checked_mul(interval).ok_or(Error::Overflow))
+        .collect::<Result<Vec<_>>>()?;
+
+    let mut indices = vec![0u32; length];
+    let mut validity = MutableBitmap::from_len_zeroed(length);
+    values.iter().enumerate().for_each(|(index, value)| {
+        // within `[0, length)`: the span was checked above
+        let slot = (bucket(*value) - first) as usize;
+        // rows are sorted: the last row in the slot wins
+        indices[slot] = index as u32;
+        validity.set(slot, true);
+    });
+
+    Ok((
+        PrimitiveArray::new(timestamps.data_type().clone(), grid.into(), None),
+        PrimitiveArray::new(DataType::UInt32, indices.into(), validity.into()),
+    ))
+}
+
+/// Replaces every null index by the last non-null index before it.
+fn forward_fill_indices(indices: &PrimitiveArray<u32>) -> PrimitiveArray<u32> {
+    let mut last = None;
+    let filled = indices
+        .iter()
+        .map(|index| {
+            if index.is_some() {
+                last = index.copied();
+            }
+            last
+        })
+        .collect::<PrimitiveArray<u32>>();
+    filled.to(DataType::UInt32)
+}
+
+fn zero_fill_primitive<T: NativeType>(
+    array: &PrimitiveArray<T>,
+    gaps: &Bitmap,
+) -> PrimitiveArray<T> {
+    let values = array
+        .values()
+        .iter()
+        .zip(gaps.iter())
+        .map(|(value, is_gap)| if is_gap { T::default() } else { *value })
+        .collect::<Vec<_>>();
+    // gaps are valid
+    let validity = array.validity().map(|validity| validity | gaps);
+    PrimitiveArray::new(array.data_type().clone(), values.into(), validity)
+}
+
+fn zero_fill_boolean(array: &BooleanArray, gaps: &Bitmap) -> BooleanArray {
+    let values = array.values() & &!gaps;
+    let validity = array.validity().map(|validity| validity | gaps);
+    BooleanArray::new(array.data_type().clone(), values, validity)
+}
+
+/// Takes `values` at `indices`, as returned by [`resample_indices`], filling null indices
+/// according to `strategy`.
+/// # Errors
+/// Errors iff `strategy` is [`FillStrategy::Zero`] and `values` is neither a boolean
+/// nor a primitive array.
+pub fn fill_gaps(
+    values: &dyn Array,
+    indices: &PrimitiveArray<u32>,
+    strategy: FillStrategy,
+) -> Result<Box<dyn Array>> {
+    match strategy {
+        FillStrategy::Null => take(values, indices),
+        FillStrategy::Forward => take(values, &forward_fill_indices(indices)),
+        FillStrategy::Zero => {
+            let taken = take(values, indices)?;
+            let gaps = match indices.validity() {
+                Some(validity) => !validity,
+                None => return Ok(taken),
+            };
+            use PhysicalType::*;
+            match values.data_type().to_physical_type() {
+                Boolean => Ok(Box::new(zero_fill_boolean(
+                    taken.as_any().downcast_ref().unwrap(),
+                    &gaps,
+                ))),
+                Primitive(primitive) => with_match_primitive_type!(primitive, |$T| {
+                    Ok(Box::new(zero_fill_primitive::<$T>(
+                        taken.as_any().downcast_ref().unwrap(),
+                        &gaps,
+                    )))
+                }),
+                _ => Err(Error::NotYetImplemented(format!(
+                    "Zero-filling gaps of {:?} is not supported",
+                    values.data_type()
+                ))),
+            }
+        }
+    }
+}
@@ -0,0 +1,230 @@
+//! Defines [`Selection`], the rows of an array selected by a predicate or a gather, and
+//! kernels to compose selections without materializing the intermediate arrays.
+//!
+//! A pipeline that filters an array and then takes from the filtered array would usually
+//! allocate the filtered array only to gather from it. Instead, the filter and the indices can
+//! be composed into a single [`Selection`] that is applied once to the original array.
+//! # Example
+//! ```
+//! use arrow2::array::{BooleanArray, Int32Array, UInt32Array};
+//! use arrow2::compute::selection::{filter_then_take, Selection};
+//!
+//! let array = Int32Array::from_slice([10, 20, 30, 40, 50]);
+//! let mask = BooleanArray::from_slice([true, false, true, true, false]);
+//! let indices = UInt32Array::from([Some(2), None, Some(0)]);
+//!
+//! // equivalent to `take(filter(array, mask), indices)`
+//! let result = filter_then_take(&array, &mask, &indices).unwrap();
+//! assert_eq!(Int32Array::from([Some(40), None, Some(10)]), result.as_ref());
+//!
+//! // selections compose
+//! let selection = Selection::from(mask).then(&Selection::from(indices)).unwrap();
+//! assert_eq!(selection.to_indices().unwrap(), UInt32Array::from([Some(3), None, Some(0)]));
+//! ```
+use crate::{
+    array::{Array, BooleanArray, PrimitiveArray},
+    bitmap::{utils::SlicesIterator, Bitmap, MutableBitmap},
+    datatypes::DataType,
+    error::{Error, Result},
+    types::Index,
+};
+
+use super::{filter::filter, take::take};
+
+/// The rows of an array selected by a kernel, e.g. by a comparison (a mask) or by a sort (indices).
+#[derive(Debug, Clone, PartialEq)]
+pub enum Selection {
+    /// The rows whose slot is `true` are selected, in order. Null slots are not selected.
+    Mask(BooleanArray),
+    /// The rows at these indices are selected, in the order of the indices. Null indices
+    /// select null rows.
+    Indices(PrimitiveArray<u32>),
+}
+
+impl From<BooleanArray> for Selection {
+    fn from(mask: BooleanArray) -> Self {
+        Self::Mask(mask)
+    }
+}
+
+impl From<PrimitiveArray<u32>> for Selection {
+    fn from(indices: PrimitiveArray<u32>) -> Self {
+        Self::Indices(indices)
+    }
+}
+
+/// The values of `mask` that are `true` and valid
+fn selected(mask: &BooleanArray) -> Bitmap {
+    match mask.validity() {
+        Some(validity) => mask.values() & validity,
+        None => mask.values().clone(),
+    }
+}
+
+/// The indices of the slots of `mask` that are `true` and valid
+fn mask_to_indices(mask: &BooleanArray) -> Result<Vec<u32>> {
+    if mask.len() > u32::MAX as usize {
+        return Err(Error::Overflow);
+    }
+    let selected = selected(mask);
+    let mut indices = Vec::with_capacity(selected.len() - selected.unset_bits());
+    SlicesIterator::new(&selected)
+        .for_each(|(start, len)| indices.extend(start as u32..(start + len) as u32));
+    Ok(indices)
+}
+
+/// Returns `positions[indices[i]]` for every `i`, null when either is null.
+fn gather<O: Index>(
+    positions: &PrimitiveArray<u32>,
+    indices: &PrimitiveArray<O>,
+) -> Result<PrimitiveArray<u32>> {
+    let mut values = Vec::with_capacity(indices.len());
+    let mut validity = MutableBitmap::with_capacity(indices.len());
+    for index in indices.iter() {
+        let position = match index {
+            Some(index) => {
+                let index = index.to_usize();
+                if index >= positions.len() {
+                    return Err(Error::InvalidArgumentError(format!(
+                        "The index {index} is out of bounds of a selection of {} rows",
+                        positions.len()
+                    )));
+                }
+                positions.is_valid(index).then(|| positions.value(index))
+            }
+            None => None,
+        };
+        values.push(position.unwrap_or_default());
+        validity.push(position.is_some());
+    }
+    Ok(PrimitiveArray::new(
+        DataType::UInt32,
+        values.into(),
+        validity.into(),
+    ))
+}
+
+impl Selection {
+    /// The number of rows this selection selects
+    pub fn len(&self) -> usize {
+        match self {
+            Self::Mask(mask) => {
+                let selected = selected(mask);
+                selected.len() - selected.unset_bits()
+            }
+            Self::Indices(indices) => indices.len(),
+        }
+    }
+
+    /// Whether this selection selects no rows
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the indices of the rows this selection selects.
+    /// # Errors
+    /// Errors iff a mask is longer than [`u32::MAX`].
+    pub fn to_indices(&self) -> Result<PrimitiveArray<u32>> {
+        match self {
+            Self::Mask(mask) => Ok(PrimitiveArray::from_vec(mask_to_indices(mask)?)),
+            Self::Indices(indices) => Ok(indices.clone()),
+        }
+    }
+
+    /// Returns the rows of `array` selected by this selection, via [`filter`] or [`take`].
+    /// # Errors
+    /// Errors iff a mask does not have the length of `array`, or an index is out of bounds.
+    pub fn apply(&self, array: &dyn Array) -> Result<Box<dyn Array>> {
+        match self {
+            Self::Mask(mask) => {
+                if mask.len() != array.len() {
+                    return Err(Error::InvalidArgumentError(format!(
+                        "A mask of length {} can't select from an array of length {}",
+                        mask.len(),
+                        array.len()
+                    )));
+                }
+                filter(array, mask)
+            }
+            Self::Indices(indices) => {
+                if let Some(index) = indices
+                    .iter()
+                    .flatten()
+                    .find(|i| **i as usize >= array.len())
+                {
+                    return Err(Error::InvalidArgumentError(format!(
+                        "The index {index} is out of bounds of an array of length {}",
+                        array.len()
+                    )));
+                }
+                take(array, indices)
+            }
+        }
+    }
+
+    /// Returns the selection equivalent to applying `self` and then `other` to its result,
+    /// i.e. `other.apply(self.apply(array))` equals `self.then(other).apply(array)`.
+    /// # Errors
+    /// Errors iff `other` is a mask whose length is not [`Selection::len`], or `other` has
+    /// an index out of bounds of [`Selection::len`].
+    pub fn then(&self, other: &Selection) -> Result<Selection> {
+        let length = self.len();
+        match (self, other) {
+            (_, Self::Mask(second)) if second.len() != length => {
+                Err(Error::InvalidArgumentError(format!(
+                    "A mask of length {} can't select from a selection of {length} rows",
+                    second.len()
+                )))
+            }
+            (Self::Mask(first), Self::Mask(second)) => {
+                // scatter the second mask to the selected slots of the first
+                let second = selected(second);
+                let mut mask = MutableBitmap::from_len_zeroed(first.len());
+                let mut position = 0;
+                SlicesIterator::new(&selected(first)).for_each(|(start, len)| {
+                    (start..start + len).for_each(|index| {
+                        if second.get_bit(position) {
+                            mask.set(index, true)
+                        }
+                        position += 1;
+                    })
+                });
+                Ok(Self::Mask(BooleanArray::new(
+                    DataType::Boolean,
+                    mask.into(),
+                    None,
+                )))
+            }
+            (Self::Indices(first), Self::Mask(second)) => {
+                let indices = filter(first, second)?;
+                let indices = indices
+                    .as_any()
+                    .downcast_ref::<PrimitiveArray<u32>>()
+                    .unwrap();
+                Ok(Self::Indices(indices.clone()))
+            }
+            (_, Self::Indices(second)) => Ok(Self::Indices(gather(&self.to_indices()?, second)?)),
+        }
+    }
+}
+
+/// Returns `take(filter(array, mask), indices)` without materializing the filtered array,
+/// gathering the rows of `array` at once.
+/// # Errors
+/// Errors iff `mask` does not have the length of `array`, or an index is out of bounds of the
+/// filtered array.
+pub fn filter_then_take<O: Index>(
+    array: &dyn Array,
+    mask: &BooleanArray,
+    indices: &PrimitiveArray<O>,
+) -> Result<Box<dyn Array>> {
+    if mask.len() != array.len() {
+        return Err(Error::InvalidArgumentError(format!(
+            "A mask of length {} can't select from an array of length {}",
+            mask.len(),
+            array.len()
+        )));
+    }
+    let positions = PrimitiveArray::from_vec(mask_to_indices(mask)?);
+    take(array, &gather(&positions, indices)?)
+}
@@ -0,0 +1,264 @@
+//! Sorts data that does not fit in memory by spilling sorted runs to Arrow IPC files.
+//!
+//! [`external_sort`] buffers the input until it exceeds a memory budget, sorts the buffered
+//! chunks and writes them as a sorted run to an IPC file in a spill directory. Once the input
+//! is exhausted, the runs are read back and merged with
+//! [`merge_sort_chunks`](crate::compute::merge_sort::merge_sort_chunks), so that at most one
+//! chunk per run is kept in memory.
+use std::fs::{File, OpenOptions};
+use std::io::{BufReader, BufWriter};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use crate::array::Array;
+use crate::chunk::Chunk;
+use crate::compute::aggregate::estimated_bytes_size;
+use crate::compute::merge_sort::{merge_sort_chunks, MergeSortChunks};
+use crate::compute::take::take;
+use crate::datatypes::Schema;
+use crate::error::{Error, Result};
+use crate::io::ipc::read::{read_file_metadata, FileReader};
+use crate::io::ipc::write::{FileWriter, WriteOptions};
+
+use super::{lexsort_to_indices, SortColumn, SortOptions};
+
+type ChunkIter = Box<dyn Iterator<Item = Result<Chunk<Box<dyn Array>>>> + Send>;
+
+/// Options of [`external_sort`]
+#[derive(Debug, Clone)]
+pub struct ExternalSortOptions {
+    /// The indices of the columns to sort by and their [`SortOptions`], in order of priority
+    pub sort_columns: Vec<(usize, SortOptions)>,
+    /// The (estimated) number of bytes of input buffered in memory before it is sorted and
+    /// spilled to disk as a sorted run. Defaults to 256MB.
+    pub memory_budget: usize,
+    /// The maximum number of rows of the sorted chunks, both of the runs and of the output.
+    /// Defaults to 65536.
+    pub chunk_size: usize,
+    /// The directory where the runs are spilled to. Defaults to [`std::env::temp_dir`].
+    pub spill_dir: PathBuf,
+    /// The options to write the runs with, e.g. their compression
+    pub write_options: WriteOptions,
+}
+
+impl ExternalSortOptions {
+    /// Returns new [`ExternalSortOptions`] sorting by `sort_columns`, with default values
+    pub fn new(sort_columns: Vec<(usize, SortOptions)>) -> Self {
+        Self {
+            sort_columns,
+            memory_budget: 256 * 1024 * 1024,
+            chunk_size: 64 * 1024,
+            spill_dir: std::env::temp_dir(),
+            write_options: WriteOptions::default(),
+        }
+    }
+}
+
+/// A sorted run spilled to disk, deleted on drop
+#[derive(Debug)]
+struct SpillFile(PathBuf);
+
+impl Drop for SpillFile {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.0);
+    }
+}
+
+/// Used to name the spill files of concurrent sorts uniquely
+static SPILL_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+/// An [`Iterator`] of sorted [`Chunk`]s, created by [`external_sort`].
+/// The runs spilled to disk are deleted when it is dropped.
+pub struct ExternalSort {
+    merged: MergeSortChunks<ChunkIter>,
+    // declared after `merged` so that the files are closed before being deleted
+    files: Vec<SpillFile>,
+}
+
+impl ExternalSort {
+    /// The number of sorted runs spilled to disk
+    pub fn num_spilled_runs(&self) -> usize {
+        self.files.len()
+    }
+}
+
+impl Iterator for ExternalSort {
+    type Item = Result<Chunk<Box<dyn Array>>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.merged.next()
+    }
+}
+
+/// Sorts `chunk` by `sort_columns`
+fn sort_chunk(
+    chunk: &Chunk<Box<dyn Array>>,
+    sort_columns: &[(usize, SortOptions)],
+) -> Result<Chunk<Box<dyn Array>>> {
+    let columns = sort_columns
+        .iter()
+        .map(|(column, options)| {
+            let values = chunk.arrays().get(*column).ok_or_else(|| {
+                Error::InvalidArgumentError(format!(
+                    "The sort column {column} is out of bounds of chunks with {} columns",
+                    chunk.arrays().len()
+                ))
+            })?;
+            Ok(SortColumn {
+                values: values.as_ref(),
+                options: Some(*options),
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let indices = lexsort_to_indices::<u32>(&columns, None)?;
+    let arrays = chunk
+        .arrays()
+        .iter()
+        .map(|array| take(array.as_ref(), &indices))
+        .collect::<Result<Vec<_>>>()?;
+    Chunk::try_new(arrays)
+}
+
+/// Merges the sorted chunks in memory
+fn merge_in_memory(
+    chunks: Vec<Chunk<Box<dyn Array>>>,
+    options: &ExternalSortOptions,
+) -> Result<MergeSortChunks<ChunkIter>> {
+    let iterators = chunks
+        .into_iter()
+        .map(|chunk| Box::new(std::iter::once(Ok(chunk))) as ChunkIter)
+        .collect();
+    merge_sort_chunks(iterators, &options.sort_columns, options.chunk_size)
+}
+
+/// Writes the sorted chunks as a single sorted run to a new file in the spill directory
+fn spill(
+    chunks: Vec<Chunk<Box<dyn Array>>>,
+    schema: &Schema,
+    options: &ExternalSortOptions,
+) -> Result<SpillFile> {
+    let path = options.spill_dir.join(format!(
+        "arrow2-sort-{}-{}.arrow",
+        std::process::id(),
+        SPILL_COUNTER.fetch_add(1, Ordering::Relaxed)
+    ));
+    let file = OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(&path)?;
+    let spilled = SpillFile(path);
+
+    let mut writer = FileWriter::try_new(
+        BufWriter::new(file),
+        schema.clone(),
+        None,
+        options.write_options.clone(),
+    )?;
+    for chunk in merge_in_memory(chunks, options)? {
+        writer.write(&chunk?, None)?;
+    }
+    writer.finish()?;
+    Ok(spilled)
+}
+
+/// Returns an iterator over the chunks of a sorted run
+fn read_run(file: &SpillFile, options: &ExternalSortOptions) -> Result<ChunkIter> {
+    let mut reader = BufReader::new(File::open(&file.0)?);
+    let metadata = read_file_metadata(&mut reader)?;
+    let mut reader = FileReader::new(reader, metadata, None, None);
+    if let Some(dictionary) = &options.write_options.zstd_dictionary {
+        reader.set_zstd_dictionary(dictionary.clone());
+    }
+    Ok(Box::new(reader))
+}
+
+/// Sorts `chunks` of `schema` by [`ExternalSortOptions::sort_columns`], spilling sorted runs
+/// to Arrow IPC files whenever the buffered input exceeds [`ExternalSortOptions::memory_budget`].
+///
+/// The input is consumed by this function; the returned iterator merges the runs and
+/// yields sorted chunks of at most [`ExternalSortOptions::chunk_size`] rows. When the input
+/// fits in the memory budget, nothing is written to disk. The sort is stable.
+/// # Errors
+/// This function errors iff `chunks` errors, no sort column is given, a sort column is out
+/// of bounds or not sortable, the chunk size is zero, or the runs can't be written to the
+/// spill directory.
+/// # Example
+/// ```rust
+/// use arrow2::array::Int32Array;
+/// use arrow2::chunk::Chunk;
+/// use arrow2::compute::sort::external::{external_sort, ExternalSortOptions};
+/// use arrow2::compute::sort::SortOptions;
+/// use arrow2::datatypes::{DataType, Field, Schema};
+/// # use arrow2::error::Result;
+/// # fn main() -> Result<()> {
+/// let schema = Schema::from(vec![Field::new("a", DataType::Int32, false)]);
+/// let chunks = vec![
+///     Ok(Chunk::new(vec![Int32Array::from_slice([3, 1]).boxed()])),
+///     Ok(Chunk::new(vec![Int32Array::from_slice([2, 0]).boxed()])),
+/// ];
+///
+/// let mut options = ExternalSortOptions::new(vec![(0, SortOptions::default())]);
+/// // spill every chunk to disk
+/// options.memory_budget = 1;
+///
+/// let sorted = external_sort(chunks.into_iter(), &schema, &options)?;
+/// assert_eq!(sorted.num_spilled_runs(), 2);
+/// let sorted = sorted.collect::<Result<Vec<_>>>()?;
+/// assert_eq!(Int32Array::from_slice([0, 1, 2, 3]), sorted[0].arrays()[0].as_ref());
+/// # Ok(())
+/// # }
+/// ```
+pub fn external_sort<I>(
+    chunks: I,
+    schema: &Schema,
+    options: &ExternalSortOptions,
+) -> Result<ExternalSort>
+where
+    I: Iterator<Item = Result<Chunk<Box<dyn Array>>>>,
+{
+    if options.sort_columns.is_empty() {
+        return Err(Error::InvalidArgumentError(
+            "external_sort requires at least one sort column".to_string(),
+        ));
+    }
+    if options.chunk_size == 0 {
+        return Err(Error::InvalidArgumentError(
+            "external_sort requires a non-zero chunk size".to_string(),
+        ));
+    }
+
+    let mut files = vec![];
+    let mut buffered = vec![];
+    let mut buffered_bytes = 0;
+    for chunk in chunks {
+        let chunk = chunk?;
+        if chunk.is_empty() {
+            continue;
+        }
+        buffered_bytes += chunk
+            .arrays()
+            .iter()
+            .map(|array| estimated_bytes_size(array.as_ref()))
+            .sum::<usize>();
+        buffered.push(sort_chunk(&chunk, &options.sort_columns)?);
+
+        if buffered_bytes >= options.memory_budget {
+            files.push(spill(std::mem::take(&mut buffered), schema, options)?);
+            buffered_bytes = 0;
+        }
+    }
+
+    let merged = if files.is_empty() {
+        merge_in_memory(buffered, options)?
+    } else {
+        if !buffered.is_empty() {
+            files.push(spill(buffered, schema, options)?);
+        }
+        let runs = files
+            .iter()
+            .map(|file| read_run(file, options))
+            .collect::<Result<Vec<_>>>()?;
+        merge_sort_chunks(runs, &options.sort_columns, options.chunk_size)?
+    };
+    Ok(ExternalSort { merged, files })
+}
@@ -1,19 +1,16 @@
 //! Contains operators to sort individual and slices of [`Array`]s.
-use std::cmp::Ordering;
-
 use crate::array::ord;
 use crate::compute::take;
 use crate::datatypes::*;
 use crate::error::{Error, Result};
-use crate::offset::Offset;
-use crate::{
-    array::*,
-    types::{Index, NativeType},
-};
+use crate::{array::*, types::Index};
 
 mod binary;
 mod boolean;
 mod common;
+#[cfg(feature = "compute_sort_external")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compute_sort_external")))]
+pub mod external;
 mod lex_sort;
 mod primitive;
 mod utf8;
@@ -153,82 +150,51 @@ pub fn sort_to_indices<I: Index>(
             options,
             limit,
         )),
-        DataType::List(field) => {
-            let (v, n) = partition_validity(values);
-            match &field.data_type {
-                DataType::Int8 => Ok(sort_list::<I, i32, i8>(values, v, n, options, limit)),
-                DataType::Int16 => Ok(sort_list::<I, i32, i16>(values, v, n, options, limit)),
-                DataType::Int32 => Ok(sort_list::<I, i32, i32>(values, v, n, options, limit)),
-                DataType::Int64 => Ok(sort_list::<I, i32, i64>(values, v, n, options, limit)),
-                DataType::UInt8 => Ok(sort_list::<I, i32, u8>(values, v, n, options, limit)),
-                DataType::UInt16 => Ok(sort_list::<I, i32, u16>(values, v, n, options, limit)),
-                DataType::UInt32 => Ok(sort_list::<I, i32, u32>(values, v, n, options, limit)),
-                DataType::UInt64 => Ok(sort_list::<I, i32, u64>(values, v, n, options, limit)),
-                t => Err(Error::NotYetImplemented(format!(
-                    "Sort not supported for list type {t:?}"
-                ))),
-            }
-        }
-        DataType::LargeList(field) => {
-            let (v, n) = partition_validity(values);
-            match field.data_type() {
-                DataType::Int8 => Ok(sort_list::<I, i64, i8>(values, v, n, options, limit)),
-                DataType::Int16 => Ok(sort_list::<I, i64, i16>(values, v, n, options, limit)),
-                DataType::Int32 => Ok(sort_list::<I, i64, i32>(values, v, n, options, limit)),
-                DataType::Int64 => Ok(sort_list::<I, i64, i64>(values, v, n, options, limit)),
-                DataType::UInt8 => Ok(sort_list::<I, i64, u8>(values, v, n, options, limit)),
-                DataType::UInt16 => Ok(sort_list::<I, i64, u16>(values, v, n, options, limit)),
-                DataType::UInt32 => Ok(sort_list::<I, i64, u32>(values, v, n, options, limit)),
-                DataType::UInt64 => Ok(sort_list::<I, i64, u64>(values, v, n, options, limit)),
-                t => Err(Error::NotYetImplemented(format!(
-                    "Sort not supported for list type {t:?}"
-                ))),
-            }
-        }
-        DataType::FixedSizeList(field, _) => {
-            let (v, n) = partition_validity(values);
-            match field.data_type() {
-                DataType::Int8 => Ok(sort_list::<I, i32, i8>(values, v, n, options, limit)),
-                DataType::Int16 => Ok(sort_list::<I, i32, i16>(values, v, n, options, limit)),
-                DataType::Int32 => Ok(sort_list::<I, i32, i32>(values, v, n, options, limit)),
-                DataType::Int64 => Ok(sort_list::<I, i32, i64>(values, v, n, options, limit)),
-                DataType::UInt8 => Ok(sort_list::<I, i32, u8>(values, v, n, options, limit)),
-                DataType::UInt16 => Ok(sort_list::<I, i32, u16>(values, v, n, options, limit)),
-                DataType::UInt32 => Ok(sort_list::<I, i32, u32>(values, v, n, options, limit)),
-                DataType::UInt64 => Ok(sort_list::<I, i32, u64>(values, v, n, options, limit)),
-                t => Err(Error::NotYetImplemented(format!(
-                    "Sort not supported for list type {t:?}"
-                ))),
-            }
-        }
-        DataType::Dictionary(key_type, value_type, _) => match value_type.as_ref() {
-            DataType::Utf8 => Ok(sort_dict::<I, i32>(values, key_type, options, limit)),
-            DataType::LargeUtf8 => Ok(sort_dict::<I, i64>(values, key_type, options, limit)),
-            t => Err(Error::NotYetImplemented(format!(
-                "Sort not supported for dictionary type with keys {t:?}"
-            ))),
-        },
+        DataType::List(_)
+        | DataType::LargeList(_)
+        | DataType::FixedSizeList(_, _)
+        | DataType::Struct(_) => sort_nested::<I>(values, options, limit),
+        DataType::Dictionary(key_type, _, _) => sort_dict::<I>(values, key_type, options, limit),
         t => Err(Error::NotYetImplemented(format!(
             "Sort not supported for data type {t:?}"
         ))),
     }
 }
 
-fn sort_dict<I: Index, O: Offset>(
+/// Sorts a dictionary by the ranks of its values, so that values are compared once
+/// instead of on every comparison.
+fn sort_dict<I: Index>(
     values: &dyn Array,
     key_type: &IntegerType,
     options: &SortOptions,
     limit: Option<usize>,
-) -> PrimitiveArray<I> {
+) -> Result<PrimitiveArray<I>> {
     match_integer_type!(key_type, |$T| {
-        utf8::indices_sorted_unstable_by_dictionary::<I, $T, O>(
-            values.as_any().downcast_ref().unwrap(),
-            options,
-            limit,
-        )
+        let array = values.as_any().downcast_ref::<DictionaryArray<$T>>().unwrap();
+        let empty = new_empty_array(array.values().data_type().clone());
+        let (ranks, _) = ord::dense_ranks(array.values().as_ref(), empty.as_ref())?;
+        let ranks = ord::dictionary_ranks(array, &ranks);
+        sort_to_indices(&ranks, options, limit)
     })
 }
 
+/// Sorts a nested array by the ranks of its items (see [`ord::build_compare`]), so that nested
+/// values are compared once instead of on every comparison.
+fn sort_nested<I: Index>(
+    values: &dyn Array,
+    options: &SortOptions,
+    limit: Option<usize>,
+) -> Result<PrimitiveArray<I>> {
+    let empty = new_empty_array(values.data_type().clone());
+    let (ranks, _) = ord::dense_ranks(values, empty.as_ref())?;
+    let ranks = ranks
+        .into_iter()
+        .map(|rank| rank as u64)
+        .collect::<Vec<_>>();
+    let ranks = PrimitiveArray::new(DataType::UInt64, ranks.into(), values.validity().cloned());
+    sort_to_indices(&ranks, options, limit)
+}
+
 /// Checks if an array of type `datatype` can be sorted
 ///
 /// # Examples
@@ -266,22 +232,13 @@ pub fn can_sort(data_type: &DataType) -> bool {
         | DataType::LargeUtf8
         | DataType::Binary
         | DataType::LargeBinary => true,
-        DataType::List(field) | DataType::LargeList(field) | DataType::FixedSizeList(field, _) => {
-            matches!(
-                field.data_type(),
-                DataType::Int8
-                    | DataType::Int16
-                    | DataType::Int32
-                    | DataType::Int64
-                    | DataType::UInt8
-                    | DataType::UInt16
-                    | DataType::UInt32
-                    | DataType::UInt64
-            )
-        }
-        DataType::Dictionary(_, value_type, _) => {
-            matches!(*value_type.as_ref(), DataType::Utf8 | DataType::LargeUtf8)
-        }
+        // sorted by the ranks of their items
+        DataType::List(_)
+        | DataType::LargeList(_)
+        | DataType::FixedSizeList(_, _)
+        | DataType::Struct(_) => ord::can_build_compare(data_type),
+        // sorted by the ranks of their values
+        DataType::Dictionary(_, value_type, _) => ord::can_build_compare(value_type.as_ref()),
         _ => false,
     }
 }
@@ -304,70 +261,3 @@ impl Default for SortOptions {
         }
     }
 }
-
-fn sort_list<I, O, T>(
-    values: &dyn Array,
-    value_indices: Vec<I>,
-    null_indices: Vec<I>,
-    options: &SortOptions,
-    limit: Option<usize>,
-) -> PrimitiveArray<I>
-where
-    I: Index,
-    O: Offset,
-    T: NativeType + std::cmp::PartialOrd,
-{
-    let mut valids: Vec<(I, Box<dyn Array>)> = values
-        .as_any()
-        .downcast_ref::<FixedSizeListArray>()
-        .map_or_else(
-            || {
-                let values = values.as_any().downcast_ref::<ListArray<O>>().unwrap();
-                value_indices
-                    .iter()
-                    .copied()
-                    .map(|index| (index, values.value(index.to_usize())))
-                    .collect()
-            },
-            |values| {
-                value_indices
-                    .iter()
-                    .copied()
-                    .map(|index| (index, values.value(index.to_usize())))
-                    .collect()
-            },
-        );
-
-    if !options.descending {
-        valids.sort_by(|a, b| cmp_array(a.1.as_ref(), b.1.as_ref()))
-    } else {
-        valids.sort_by(|a, b| cmp_array(b.1.as_ref(), a.1.as_ref()))
-    }
-
-    let values = valids.iter().map(|tuple| tuple.0);
-
-    let mut values = if options.nulls_first {
-        null_indices.into_iter().chain(values).collect::<Vec<I>>()
-    } else {
-        values.chain(null_indices.into_iter()).collect::<Vec<I>>()
-    };
-
-    values.truncate(limit.unwrap_or(values.len()));
-
-    let data_type = I::PRIMITIVE.into();
-    PrimitiveArray::<I>::new(data_type, values.into(), None)
-}
-
-/// Compare two `Array`s based on the ordering defined in [ord](crate::array::ord).
-fn cmp_array(a: &dyn Array, b: &dyn Array) -> Ordering {
-    let cmp_op = ord::build_compare(a, b).unwrap();
-    let length = a.len().min(b.len());
-
-    for i in 0..length {
-        let result = cmp_op(i, i);
-        if result != Ordering::Equal {
-            return result;
-        }
-    }
-    a.len().cmp(&b.len())
-}
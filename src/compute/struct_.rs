@@ -0,0 +1,115 @@
+//! Contains operators to pack arrays into a [`StructArray`] and to extract fields from it.
+//! # Example
+//! ```
+//! use arrow2::array::{Array, Int32Array, Utf8Array};
+//! use arrow2::compute::struct_::{struct_field, struct_pack};
+//!
+//! let a = Int32Array::from_slice([1, 2]);
+//! let b = Utf8Array::<i32>::from_slice(["x", "y"]);
+//! let inner = struct_pack(vec![("b", &b)]).unwrap();
+//! let outer = struct_pack(vec![("a", &a as &dyn Array), ("inner", &inner)]).unwrap();
+//!
+//! let result = struct_field(&outer, "inner.b").unwrap();
+//! assert_eq!(b, result.as_ref());
+//! ```
+use crate::array::{Array, ListArray, MapArray, StructArray};
+use crate::datatypes::{DataType, Field};
+use crate::error::{Error, Result};
+use crate::offset::Offset;
+
+use super::utils::combine_validities;
+
+/// Returns a [`StructArray`] whose fields are named after and contain `fields`.
+///
+/// The fields are nullable and the struct itself has no validity.
+/// # Errors
+/// Errors iff `fields` is empty or the arrays have different lengths.
+pub fn struct_pack(fields: Vec<(&str, &dyn Array)>) -> Result<StructArray> {
+    let len = fields
+        .first()
+        .map(|(_, array)| array.len())
+        .ok_or_else(|| {
+            Error::InvalidArgumentError("Struct pack requires at least one field".to_string())
+        })?;
+    if let Some((name, array)) = fields.iter().find(|(_, array)| array.len() != len) {
+        return Err(Error::InvalidArgumentError(format!(
+            "Struct pack requires all fields to have the same length, but field \"{name}\" has length {} instead of {len}",
+            array.len()
+        )));
+    }
+
+    let (fields, values): (Vec<_>, Vec<_>) = fields
+        .into_iter()
+        .map(|(name, array)| {
+            let field = Field::new(name, array.data_type().clone(), true);
+            (field, array.to_boxed())
+        })
+        .unzip();
+    StructArray::try_new(DataType::Struct(fields), values, None)
+}
+
+/// Returns the field of `array` at `path`, a sequence of field names separated by `.`
+/// (e.g. `"a.b.c"`).
+///
+/// The validity of every struct along the path is merged into the returned array, so that
+/// a value is null whenever any of its parents is null.
+/// Lists and maps are traversed by extracting the field from their items: the result is a
+/// list with the same offsets and validity, whose items are the field. The fields of the
+/// entries of a map are usually named `key` and `value`.
+/// # Errors
+/// Errors iff a field of `path` does not exist or its parent is neither a struct, a list
+/// nor a map.
+pub fn struct_field(array: &dyn Array, path: &str) -> Result<Box<dyn Array>> {
+    path.split('.')
+        .try_fold(array.to_boxed(), |array, name| field(array.as_ref(), name))
+}
+
+fn field(array: &dyn Array, name: &str) -> Result<Box<dyn Array>> {
+    match array.data_type().to_logical_type() {
+        DataType::Struct(_) => {
+            let array = array.as_any().downcast_ref::<StructArray>().unwrap();
+            let index = array
+                .fields()
+                .iter()
+                .position(|field| field.name == name)
+                .ok_or_else(|| {
+                    Error::InvalidArgumentError(format!("Struct has no field \"{name}\""))
+                })?;
+            let child = &array.values()[index];
+            if child.data_type() == &DataType::Null {
+                return Ok(child.clone());
+            }
+            let validity = combine_validities(array.validity(), child.validity());
+            Ok(child.with_validity(validity))
+        }
+        DataType::List(_) => list_field::<i32>(array.as_any().downcast_ref().unwrap(), name),
+        DataType::LargeList(_) => list_field::<i64>(array.as_any().downcast_ref().unwrap(), name),
+        DataType::Map(_, _) => {
+            let array = array.as_any().downcast_ref::<MapArray>().unwrap();
+            let values = field(array.field().as_ref(), name)?;
+            let data_type = ListArray::<i32>::default_datatype(values.data_type().clone());
+            let list = ListArray::new(
+                data_type,
+                array.offsets().clone(),
+                values,
+                array.validity().cloned(),
+            );
+            Ok(list.boxed())
+        }
+        other => Err(Error::InvalidArgumentError(format!(
+            "Cannot extract field \"{name}\" from an array of type {other:?}"
+        ))),
+    }
+}
+
+fn list_field<O: Offset>(array: &ListArray<O>, name: &str) -> Result<Box<dyn Array>> {
+    let values = field(array.values().as_ref(), name)?;
+    let data_type = ListArray::<O>::default_datatype(values.data_type().clone());
+    let list = ListArray::new(
+        data_type,
+        array.offsets().clone(),
+        values,
+        array.validity().cloned(),
+    );
+    Ok(list.boxed())
+}
@@ -15,13 +15,14 @@
 // specific language governing permissions and limitations
 // under the License.
 
-//! Defines kernel to extract a substring of a \[Large\]StringArray
+//! Defines kernels to extract a substring of a \[Large\]StringArray and \[Large\]BinaryArray
+//! and a slice of each list of a \[Large\]ListArray.
 
 use crate::{
-    array::*,
+    array::{growable::make_growable, *},
     datatypes::DataType,
     error::{Error, Result},
-    offset::{Offset, Offsets},
+    offset::{Offset, Offsets, OffsetsBuffer},
 };
 
 fn utf8_substring<O: Offset>(array: &Utf8Array<O>, start: O, length: &Option<O>) -> Utf8Array<O> {
@@ -178,3 +179,116 @@ pub fn can_substring(data_type: &DataType) -> bool {
         DataType::LargeUtf8 | DataType::Utf8 | DataType::LargeBinary | DataType::Binary
     )
 }
+
+/// Returns the `(start, length)`, relative to the beginning of an item of length `len`,
+/// of the slice starting at `offset` with optional length `length`.
+/// A negative `offset` counts from the end of the item.
+fn slice_bounds(len: usize, offset: i64, length: Option<u64>) -> (usize, usize) {
+    let start = if offset >= 0 {
+        (offset as usize).min(len)
+    } else {
+        len.saturating_sub(offset.unsigned_abs() as usize)
+    };
+    let remaining = len - start;
+    let length = length.map_or(remaining, |length| (length as usize).min(remaining));
+    (start, length)
+}
+
+fn list_slice_typed<O: Offset>(
+    array: &ListArray<O>,
+    offset: i64,
+    length: Option<u64>,
+) -> Result<ListArray<O>> {
+    let slices = array
+        .offsets()
+        .buffer()
+        .windows(2)
+        .map(|w| {
+            let (start, end) = (w[0].to_usize(), w[1].to_usize());
+            let (slice_start, slice_length) = slice_bounds(end - start, offset, length);
+            (start + slice_start, slice_length)
+        })
+        .collect::<Vec<_>>();
+
+    // when each slice ends where the next one starts, the values can be shared
+    let is_contiguous = slices.windows(2).all(|w| w[0].0 + w[0].1 == w[1].0);
+
+    let (offsets, values): (OffsetsBuffer<O>, _) = if is_contiguous {
+        let first = slices.first().map_or(0, |(start, _)| *start);
+        let offsets = std::iter::once(first)
+            .chain(slices.iter().map(|(start, length)| start + length))
+            .map(|offset| O::from_usize(offset).ok_or(Error::Overflow))
+            .collect::<Result<Vec<_>>>()?;
+        (offsets.try_into()?, array.values().clone())
+    } else {
+        let values = array.values().as_ref();
+        let capacity = slices.iter().map(|(_, length)| length).sum();
+        let mut growable = make_growable(&[values], false, capacity);
+        slices
+            .iter()
+            .for_each(|(start, length)| growable.extend(0, *start, *length));
+        let offsets = Offsets::<O>::try_from_lengths(slices.iter().map(|(_, length)| *length))?;
+        (offsets.into(), growable.as_box())
+    };
+
+    ListArray::try_new(
+        array.data_type().clone(),
+        offsets,
+        values,
+        array.validity().cloned(),
+    )
+}
+
+/// Returns a [`ListArray`] with the slice starting at `offset` and with optional length `length`
+/// of each of the lists in `array`.
+///
+/// Like in Python, a negative `offset` counts from the end of each list, and slices out of
+/// bounds are truncated. The values of `array` are re-used without copying whenever the
+/// slices are contiguous (e.g. when `offset` is `0` and `length` is `None`).
+/// # Errors
+/// This function errors when the passed array is not a \[Large\]List array.
+/// # Example
+/// ```
+/// use arrow2::array::{ListArray, MutableListArray, MutablePrimitiveArray, TryExtend};
+/// use arrow2::compute::substring::list_slice;
+///
+/// let mut array = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+/// array.try_extend([Some(vec![Some(1), Some(2), Some(3)]), None, Some(vec![Some(4)])]).unwrap();
+/// let array: ListArray<i32> = array.into();
+///
+/// let result = list_slice(&array, -2, Some(1)).unwrap();
+///
+/// let mut expected = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+/// expected.try_extend([Some(vec![Some(2)]), None, Some(vec![Some(4)])]).unwrap();
+/// let expected: ListArray<i32> = expected.into();
+/// assert_eq!(expected, result.as_ref());
+/// ```
+pub fn list_slice(array: &dyn Array, offset: i64, length: Option<u64>) -> Result<Box<dyn Array>> {
+    match array.data_type() {
+        DataType::List(_) => Ok(Box::new(list_slice_typed(
+            array
+                .as_any()
+                .downcast_ref::<ListArray<i32>>()
+                .expect("A list is expected"),
+            offset,
+            length,
+        )?)),
+        DataType::LargeList(_) => Ok(Box::new(list_slice_typed(
+            array
+                .as_any()
+                .downcast_ref::<ListArray<i64>>()
+                .expect("A large list is expected"),
+            offset,
+            length,
+        )?)),
+        _ => Err(Error::InvalidArgumentError(format!(
+            "list_slice does not support type {:?}",
+            array.data_type()
+        ))),
+    }
+}
+
+/// Checks if an array of type `datatype` can perform [`list_slice`]
+pub fn can_list_slice(data_type: &DataType) -> bool {
+    matches!(data_type, DataType::List(_) | DataType::LargeList(_))
+}
@@ -0,0 +1,201 @@
+use alloc::{boxed::Box, vec::Vec};
+
+use crate::{
+    array::{Array, BinaryArray, PrimitiveArray, Utf8Array},
+    bitmap::{Bitmap, MutableBitmap},
+    chunk::Chunk,
+    error::Result,
+    offset::{Offset, Offsets, OffsetsBuffer},
+    types::NativeType,
+};
+
+use super::{take, Index};
+
+/// Allocations reused by [`take_chunk`] across columns and across calls.
+///
+/// The indices are converted once per call and shared by all columns, and the
+/// ranges of variable-sized values are computed in place before their values are copied.
+#[derive(Debug, Clone, Default)]
+pub struct Scratch {
+    indices: Vec<usize>,
+    ranges: Vec<(usize, usize)>,
+}
+
+impl Scratch {
+    /// Returns a new [`Scratch`] with capacity for `length` indices.
+    pub fn with_capacity(length: usize) -> Self {
+        Self {
+            indices: Vec::with_capacity(length),
+            ranges: Vec::with_capacity(length),
+        }
+    }
+}
+
+/// Returns the validity of taking `indices` from an array with `validity`
+fn take_validity(
+    validity: Option<&Bitmap>,
+    indices: &[usize],
+    nulls: Option<&Bitmap>,
+) -> Option<Bitmap> {
+    match (validity, nulls) {
+        (None, nulls) => nulls.cloned(),
+        (Some(validity), None) => {
+            let validity = indices.iter().map(|index| validity.get_bit(*index));
+            Some(MutableBitmap::from_trusted_len_iter(validity).into())
+        }
+        (Some(validity), Some(nulls)) => {
+            let validity = indices
+                .iter()
+                .zip(nulls.iter())
+                .map(|(index, is_valid)| is_valid && validity.get_bit(*index));
+            Some(MutableBitmap::from_trusted_len_iter(validity).into())
+        }
+    }
+}
+
+fn take_primitive<T: NativeType>(
+    array: &PrimitiveArray<T>,
+    indices: &[usize],
+    nulls: Option<&Bitmap>,
+) -> PrimitiveArray<T> {
+    let values = array.values();
+    let values = indices
+        .iter()
+        .map(|index| values[*index])
+        .collect::<Vec<_>>();
+    let validity = take_validity(array.validity(), indices, nulls);
+    PrimitiveArray::new(array.data_type().clone(), values.into(), validity)
+}
+
+/// Takes the values at `indices` of `offsets` and `values`, allocating the new values once
+fn take_offsets_values<O: Offset>(
+    offsets: &OffsetsBuffer<O>,
+    values: &[u8],
+    indices: &[usize],
+    nulls: Option<&Bitmap>,
+    ranges: &mut Vec<(usize, usize)>,
+) -> (OffsetsBuffer<O>, Vec<u8>) {
+    ranges.clear();
+    ranges.extend(indices.iter().enumerate().map(|(i, index)| {
+        if nulls.map(|x| x.get_bit(i)).unwrap_or(true) {
+            offsets.start_end(*index)
+        } else {
+            (0, 0)
+        }
+    }));
+
+    let new_offsets = Offsets::<O>::try_from_lengths(ranges.iter().map(|(start, end)| end - start))
+        .expect("the taken values to fit in the offsets");
+
+    let mut new_values = Vec::with_capacity(new_offsets.last().to_usize());
+    ranges
+        .iter()
+        .for_each(|(start, end)| new_values.extend_from_slice(&values[*start..*end]));
+    (new_offsets.into(), new_values)
+}
+
+fn take_utf8<O: Offset>(
+    array: &Utf8Array<O>,
+    indices: &[usize],
+    nulls: Option<&Bitmap>,
+    ranges: &mut Vec<(usize, usize)>,
+) -> Utf8Array<O> {
+    let (offsets, values) =
+        take_offsets_values(array.offsets(), array.values(), indices, nulls, ranges);
+    let validity = take_validity(array.validity(), indices, nulls);
+    // Safety: the values are whole utf8 values of `array`
+    unsafe { Utf8Array::new_unchecked(array.data_type().clone(), offsets, values.into(), validity) }
+}
+
+fn take_binary<O: Offset>(
+    array: &BinaryArray<O>,
+    indices: &[usize],
+    nulls: Option<&Bitmap>,
+    ranges: &mut Vec<(usize, usize)>,
+) -> BinaryArray<O> {
+    let (offsets, values) =
+        take_offsets_values(array.offsets(), array.values(), indices, nulls, ranges);
+    let validity = take_validity(array.validity(), indices, nulls);
+    BinaryArray::new(array.data_type().clone(), offsets, values.into(), validity)
+}
+
+/// Returns a new [`Chunk`] with the rows at `indices` of every column of `chunk`.
+/// Null indices are taken as nulls.
+///
+/// This is equivalent to [`take`] of every column, but the indices are validated and converted
+/// once for all columns, and `scratch` is reused across columns and calls, which avoids
+/// allocations when this is called in a loop.
+/// # Errors
+/// Errors iff [`take`] errors on a column
+/// # Panics
+/// Panics iff a non-null index is out of bounds of the chunk or [`take`] panics on a column
+pub fn take_chunk<A: AsRef<dyn Array>, O: Index>(
+    chunk: &Chunk<A>,
+    indices: &PrimitiveArray<O>,
+    scratch: &mut Scratch,
+) -> Result<Chunk<Box<dyn Array>>> {
+    // empty chunks only admit null indices, which `take` handles
+    if chunk.is_empty() || indices.is_empty() {
+        return chunk
+            .arrays()
+            .iter()
+            .map(|array| take(array.as_ref(), indices))
+            .collect::<Result<Vec<_>>>()
+            .and_then(Chunk::try_new);
+    }
+
+    let nulls = indices.validity().filter(|x| x.unset_bits() > 0);
+    scratch.indices.clear();
+    match nulls {
+        Some(nulls) => scratch.indices.extend(
+            indices
+                .values()
+                .iter()
+                .zip(nulls.iter())
+                .map(|(index, is_valid)| if is_valid { index.to_usize() } else { 0 }),
+        ),
+        None => scratch
+            .indices
+            .extend(indices.values().iter().map(|index| index.to_usize())),
+    };
+    if let Some(index) = scratch.indices.iter().find(|index| **index >= chunk.len()) {
+        panic!("Out-of-bounds index {index}")
+    }
+    let Scratch {
+        indices: taken,
+        ranges,
+    } = scratch;
+
+    use crate::datatypes::PhysicalType::*;
+    chunk
+        .arrays()
+        .iter()
+        .map(|array| {
+            let array = array.as_ref();
+            Ok(match array.data_type().to_physical_type() {
+                Primitive(primitive) => with_match_primitive_type!(primitive, |$T| {
+                    let array = array.as_any().downcast_ref().unwrap();
+                    take_primitive::<$T>(array, taken, nulls).boxed()
+                }),
+                Utf8 => {
+                    let array = array.as_any().downcast_ref().unwrap();
+                    take_utf8::<i32>(array, taken, nulls, ranges).boxed()
+                }
+                LargeUtf8 => {
+                    let array = array.as_any().downcast_ref().unwrap();
+                    take_utf8::<i64>(array, taken, nulls, ranges).boxed()
+                }
+                Binary => {
+                    let array = array.as_any().downcast_ref().unwrap();
+                    take_binary::<i32>(array, taken, nulls, ranges).boxed()
+                }
+                LargeBinary => {
+                    let array = array.as_any().downcast_ref().unwrap();
+                    take_binary::<i64>(array, taken, nulls, ranges).boxed()
+                }
+                _ => take(array, indices)?,
+            })
+        })
+        .collect::<Result<Vec<_>>>()
+        .and_then(Chunk::try_new)
+}
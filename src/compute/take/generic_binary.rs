@@ -1,3 +1,5 @@
+use alloc::vec::Vec;
+
 use crate::{
     array::{GenericBinaryArray, PrimitiveArray},
     bitmap::{Bitmap, MutableBitmap},
@@ -68,7 +70,7 @@ pub fn take_values_validity<O: Offset, I: Index, A: GenericBinaryArray<O>>(
         starts.push(start);
         length
     });
-    let offsets = std::iter::once(O::default())
+    let offsets = core::iter::once(O::default())
         .chain(offsets)
         .collect::<Vec<_>>();
     // Safety: by construction offsets are monotonically increasing
@@ -102,7 +104,7 @@ pub fn take_indices_validity<O: Offset, I: Index>(
         };
         length
     });
-    let offsets = std::iter::once(O::default())
+    let offsets = core::iter::once(O::default())
         .chain(offsets)
         .collect::<Vec<_>>();
     // Safety: by construction offsets are monotonically increasing
@@ -146,7 +148,7 @@ pub fn take_values_indices_validity<O: Offset, I: Index, A: GenericBinaryArray<O
         };
         length
     });
-    let offsets = std::iter::once(O::default())
+    let offsets = core::iter::once(O::default())
         .chain(offsets)
         .collect::<Vec<_>>();
     // Safety: by construction offsets are monotonically increasing
@@ -15,6 +15,8 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use alloc::vec::Vec;
+
 use crate::array::{
     growable::{Growable, GrowableList},
     ListArray, PrimitiveArray,
@@ -17,6 +17,8 @@
 
 //! Defines take kernel for [`Array`]
 
+use alloc::boxed::Box;
+
 use crate::{
     array::{new_empty_array, Array, NullArray, PrimitiveArray},
     datatypes::DataType,
@@ -26,14 +28,16 @@ use crate::{
 
 mod binary;
 mod boolean;
+mod chunk;
 mod dict;
 mod generic_binary;
 mod list;
 mod primitive;
 mod structure;
+mod union;
 mod utf8;
 
-pub(crate) use boolean::take as take_boolean;
+pub use chunk::{take_chunk, Scratch};
 
 /// Returns a new [`Array`] with only indices at `indices`. Null indices are taken as nulls.
 /// The returned array has a length equal to `indices.len()`.
@@ -90,6 +94,10 @@ pub fn take<O: Index>(values: &dyn Array, indices: &PrimitiveArray<O>) -> Result
             let array = values.as_any().downcast_ref().unwrap();
             Ok(Box::new(list::take::<i64, O>(array, indices)))
         }
+        Union => {
+            let array = values.as_any().downcast_ref().unwrap();
+            Ok(Box::new(union::take::<_>(array, indices)?))
+        }
         t => unimplemented!("Take not supported for data type {:?}", t),
     }
 }
@@ -136,5 +144,6 @@ pub fn can_take(data_type: &DataType) -> bool {
             | DataType::List(_)
             | DataType::LargeList(_)
             | DataType::Dictionary(..)
+            | DataType::Union(..)
     )
 }
@@ -1,3 +1,5 @@
+use alloc::vec::Vec;
+
 use crate::{
     array::{Array, PrimitiveArray},
     bitmap::{Bitmap, MutableBitmap},
@@ -15,6 +15,8 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use alloc::{boxed::Box, vec::Vec};
+
 use crate::{
     array::{Array, PrimitiveArray, StructArray},
     bitmap::{Bitmap, MutableBitmap},
@@ -0,0 +1,70 @@
+use alloc::vec::Vec;
+
+use crate::{
+    array::{Array, PrimitiveArray, UnionArray},
+    datatypes::DataType,
+    error::Result,
+};
+
+use super::Index;
+
+/// Returns the type id of the first field of the union `data_type`, used for null slots
+fn first_type(data_type: &DataType) -> i8 {
+    match data_type.to_logical_type() {
+        DataType::Union(_, Some(ids), _) => ids[0] as i8,
+        _ => 0,
+    }
+}
+
+/// `take` implementation for [`UnionArray`]s. Null indices take a null of the union's first field.
+pub fn take<I: Index>(array: &UnionArray, indices: &PrimitiveArray<I>) -> Result<UnionArray> {
+    let first_type = first_type(array.data_type());
+    let types = indices
+        .iter()
+        .map(|index| {
+            index
+                .map(|index| array.types()[index.to_usize()])
+                .unwrap_or(first_type)
+        })
+        .collect::<Vec<_>>();
+
+    let (fields, offsets) = if array.offsets().is_none() {
+        // in a sparse union, every field is taken at the same slots
+        let slots = indices
+            .iter()
+            .map(|index| index.map(|index| array.index(index.to_usize()).1 as u64))
+            .collect::<PrimitiveArray<u64>>();
+        let fields = array
+            .fields()
+            .iter()
+            .map(|field| super::take(field.as_ref(), &slots))
+            .collect::<Result<Vec<_>>>()?;
+        (fields, None)
+    } else {
+        // in a dense union, each field is taken at the slots of its own type
+        let mut slots = vec![vec![]; array.fields().len()];
+        let offsets = indices
+            .iter()
+            .map(|index| {
+                let (field, slot) = match index {
+                    Some(index) => {
+                        let (field, slot) = array.index(index.to_usize());
+                        (field, Some(slot as u64))
+                    }
+                    None => (0, None),
+                };
+                slots[field].push(slot);
+                slots[field].len() as i32 - 1
+            })
+            .collect::<Vec<_>>();
+        let fields = array
+            .fields()
+            .iter()
+            .zip(slots)
+            .map(|(field, slots)| super::take(field.as_ref(), &PrimitiveArray::<u64>::from(slots)))
+            .collect::<Result<Vec<_>>>()?;
+        (fields, Some(offsets.into()))
+    };
+
+    UnionArray::try_new(array.data_type().clone(), types.into(), fields, offsets)
+}
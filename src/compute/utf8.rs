@@ -1,12 +1,15 @@
 //! Defines common maps to a [`Utf8Array`]
 
 use crate::{
-    array::{Array, Utf8Array},
-    datatypes::DataType,
+    array::{Array, BinaryArray, BooleanArray, Int32Array, Utf8Array},
+    bitmap::Bitmap,
+    datatypes::{DataType, PhysicalType},
     error::{Error, Result},
-    offset::Offset,
+    offset::{Offset, Offsets, OffsetsBuffer},
 };
 
+use super::utils::combine_validities;
+
 /// utf8_apply will apply `Fn(&str) -> String` to every value in Utf8Array.
 pub fn utf8_apply<O: Offset, F: Fn(&str) -> String>(f: F, array: &Utf8Array<O>) -> Utf8Array<O> {
     let iter = array.values_iter().map(f);
@@ -15,31 +18,74 @@ pub fn utf8_apply<O: Offset, F: Fn(&str) -> String>(f: F, array: &Utf8Array<O>)
     new.with_validity(array.validity().cloned())
 }
 
-/// Returns a new `Array` where each of each of the elements is upper-cased.
-/// this function errors when the passed array is not a \[Large\]String array.
-pub fn upper(array: &dyn Array) -> Result<Box<dyn Array>> {
-    match array.data_type() {
-        DataType::LargeUtf8 => Ok(Box::new(utf8_apply(
-            str::to_uppercase,
-            array
-                .as_any()
-                .downcast_ref::<Utf8Array<i64>>()
-                .expect("A large string is expected"),
-        ))),
-        DataType::Utf8 => Ok(Box::new(utf8_apply(
-            str::to_uppercase,
-            array
-                .as_any()
-                .downcast_ref::<Utf8Array<i32>>()
-                .expect("A string is expected"),
-        ))),
+/// Applies `ascii` to every value of `array` in place of re-encoding them, keeping its offsets,
+/// or returns `None` when any value is not ASCII. `ascii` must map ASCII to ASCII.
+fn ascii_apply<O: Offset, F: Fn(&mut [u8])>(
+    array: &Utf8Array<O>,
+    ascii: F,
+) -> Option<Utf8Array<O>> {
+    let start = array.offsets().buffer()[0].to_usize();
+    let end = array.offsets().last().to_usize();
+    if !array.values()[start..end].is_ascii() {
+        return None;
+    }
+    let mut values = array.values()[..end].to_vec();
+    for window in array.offsets().buffer().windows(2) {
+        ascii(&mut values[window[0].to_usize()..window[1].to_usize()]);
+    }
+
+    // Safety: ASCII is utf8 and the offsets are at the same (char) boundaries
+    Some(unsafe {
+        Utf8Array::new_unchecked(
+            array.data_type().clone(),
+            array.offsets().clone(),
+            values.into(),
+            array.validity().cloned(),
+        )
+    })
+}
+
+/// Applies `f` to every value of `array`, a \[Large\]String array, or `ascii` to its values
+/// when all of them are ASCII.
+fn case_apply<F: Fn(&str) -> String, A: Fn(&mut [u8])>(
+    array: &dyn Array,
+    kernel: &str,
+    f: F,
+    ascii: A,
+) -> Result<Box<dyn Array>> {
+    fn apply<O: Offset, F: Fn(&str) -> String, A: Fn(&mut [u8])>(
+        array: &Utf8Array<O>,
+        f: F,
+        ascii: A,
+    ) -> Box<dyn Array> {
+        ascii_apply(array, ascii)
+            .unwrap_or_else(|| utf8_apply(f, array))
+            .boxed()
+    }
+
+    match array.data_type().to_physical_type() {
+        PhysicalType::LargeUtf8 => Ok(apply(downcast_utf8::<i64>(array, kernel)?, f, ascii)),
+        PhysicalType::Utf8 => Ok(apply(downcast_utf8::<i32>(array, kernel)?, f, ascii)),
         _ => Err(Error::InvalidArgumentError(format!(
-            "upper does not support type {:?}",
+            "{kernel} does not support type {:?}",
             array.data_type()
         ))),
     }
 }
 
+/// Returns a new `Array` where each of each of the elements is upper-cased.
+/// this function errors when the passed array is not a \[Large\]String array.
+///
+/// Arrays whose values are all ASCII are converted in place, without re-encoding their offsets.
+pub fn upper(array: &dyn Array) -> Result<Box<dyn Array>> {
+    case_apply(
+        array,
+        "upper",
+        str::to_uppercase,
+        <[u8]>::make_ascii_uppercase,
+    )
+}
+
 /// Checks if an array of type `datatype` can perform upper operation
 ///
 /// # Examples
@@ -59,27 +105,15 @@ pub fn can_upper(data_type: &DataType) -> bool {
 
 /// Returns a new `Array` where each of each of the elements is lower-cased.
 /// this function errors when the passed array is not a \[Large\]String array.
+///
+/// Arrays whose values are all ASCII are converted in place, without re-encoding their offsets.
 pub fn lower(array: &dyn Array) -> Result<Box<dyn Array>> {
-    match array.data_type() {
-        DataType::LargeUtf8 => Ok(Box::new(utf8_apply(
-            str::to_lowercase,
-            array
-                .as_any()
-                .downcast_ref::<Utf8Array<i64>>()
-                .expect("A large string is expected"),
-        ))),
-        DataType::Utf8 => Ok(Box::new(utf8_apply(
-            str::to_lowercase,
-            array
-                .as_any()
-                .downcast_ref::<Utf8Array<i32>>()
-                .expect("A string is expected"),
-        ))),
-        _ => Err(Error::InvalidArgumentError(format!(
-            "lower does not support type {:?}",
-            array.data_type()
-        ))),
-    }
+    case_apply(
+        array,
+        "lower",
+        str::to_lowercase,
+        <[u8]>::make_ascii_lowercase,
+    )
 }
 
 /// Checks if an array of type `datatype` can perform lower operation
@@ -98,3 +132,623 @@ pub fn lower(array: &dyn Array) -> Result<Box<dyn Array>> {
 pub fn can_lower(data_type: &DataType) -> bool {
     matches!(data_type, DataType::LargeUtf8 | DataType::Utf8)
 }
+
+fn initcap_str(value: &str) -> String {
+    let mut result = String::with_capacity(value.len());
+    let mut is_word = false;
+    for c in value.chars() {
+        if is_word {
+            result.extend(c.to_lowercase());
+        } else {
+            result.extend(c.to_uppercase());
+        }
+        is_word = c.is_alphanumeric();
+    }
+    result
+}
+
+fn initcap_ascii(values: &mut [u8]) {
+    let mut is_word = false;
+    for value in values {
+        if is_word {
+            value.make_ascii_lowercase();
+        } else {
+            value.make_ascii_uppercase();
+        }
+        is_word = value.is_ascii_alphanumeric();
+    }
+}
+
+/// Returns a new `Array` where the first letter of every word of each of the elements is
+/// upper-cased and the remaining letters are lower-cased, as SQL's `initcap`. Words are
+/// sequences of alphanumeric characters.
+///
+/// Arrays whose values are all ASCII are converted in place, without re-encoding their offsets.
+/// # Errors
+/// This function errors when the passed array is not a \[Large\]String array.
+/// # Example
+/// ```
+/// use arrow2::array::Utf8Array;
+/// use arrow2::compute::utf8::initcap;
+///
+/// let array = Utf8Array::<i32>::from([Some("hello WORLD"), Some("o'neil-smith"), None]);
+/// let result = initcap(&array).unwrap();
+/// let expected = Utf8Array::<i32>::from([Some("Hello World"), Some("O'Neil-Smith"), None]);
+/// assert_eq!(result.as_ref(), &expected as &dyn arrow2::array::Array);
+/// ```
+pub fn initcap(array: &dyn Array) -> Result<Box<dyn Array>> {
+    case_apply(array, "initcap", initcap_str, initcap_ascii)
+}
+
+/// How [`concat_str_with`] handles null values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ConcatNulls {
+    /// A slot is null when any of its values is null, as SQL's `||`.
+    #[default]
+    Propagate,
+    /// Null values are skipped together with their separator, as SQL's `concat_ws`.
+    /// A slot is null when all of its values are null.
+    Skip,
+}
+
+/// The offsets, values and validity of a column of variable-sized values.
+type Column<'a, O> = (&'a OffsetsBuffer<O>, &'a [u8], Option<&'a Bitmap>);
+
+fn concat_columns<O: Offset>(
+    columns: &[Column<O>],
+    length: usize,
+    separator: &[u8],
+    nulls: ConcatNulls,
+) -> Result<(Offsets<O>, Vec<u8>, Option<Bitmap>)> {
+    let validity = match nulls {
+        ConcatNulls::Propagate => columns.iter().fold(None, |acc, (_, _, validity)| {
+            combine_validities(acc.as_ref(), *validity)
+        }),
+        ConcatNulls::Skip => columns
+            .iter()
+            .map(|(_, _, validity)| *validity)
+            .collect::<Option<Vec<_>>>()
+            .and_then(|validities| {
+                let (first, rest) = validities.split_first()?;
+                Some(rest.iter().fold((*first).clone(), |acc, x| &acc | *x))
+            }),
+    };
+
+    // an upper bound of the size of the values, so that they are allocated once
+    let capacity = columns
+        .iter()
+        .map(|(offsets, _, _)| offsets.last().to_usize() - offsets.as_slice()[0].to_usize())
+        .sum::<usize>()
+        + separator.len() * columns.len().saturating_sub(1) * length;
+
+    let mut offsets = Offsets::<O>::with_capacity(length);
+    let mut values = Vec::<u8>::with_capacity(capacity);
+    for row in 0..length {
+        let start = values.len();
+        if validity.as_ref().map_or(true, |x| x.get_bit(row)) {
+            let mut is_first = true;
+            for (column_offsets, column_values, column_validity) in columns {
+                if !column_validity.map_or(true, |x| x.get_bit(row)) {
+                    continue;
+                }
+                if !is_first {
+                    values.extend_from_slice(separator);
+                }
+                is_first = false;
+                let (begin, end) = column_offsets.start_end(row);
+                values.extend_from_slice(&column_values[begin..end]);
+            }
+        }
+        offsets.try_push_usize(values.len() - start)?;
+    }
+    Ok((offsets, values, validity))
+}
+
+fn check_lengths(lengths: impl Iterator<Item = usize>) -> Result<usize> {
+    let mut lengths = lengths;
+    let length = lengths.next().ok_or_else(|| {
+        Error::InvalidArgumentError("concatenation requires at least one array".to_string())
+    })?;
+    if lengths.any(|x| x != length) {
+        return Err(Error::InvalidArgumentError(
+            "concatenation requires arrays of the same length".to_string(),
+        ));
+    }
+    Ok(length)
+}
+
+/// Concatenates the values of `arrays` element-wise, interleaved by `separator`.
+/// # Errors
+/// This function errors iff `arrays` is empty, the arrays have different lengths or
+/// the result overflows `O`.
+pub fn concat_utf8<O: Offset>(
+    arrays: &[&Utf8Array<O>],
+    separator: Option<&str>,
+    nulls: ConcatNulls,
+) -> Result<Utf8Array<O>> {
+    let length = check_lengths(arrays.iter().map(|array| array.len()))?;
+    let columns = arrays
+        .iter()
+        .map(|array| (array.offsets(), array.values().as_slice(), array.validity()))
+        .collect::<Vec<_>>();
+    let separator = separator.unwrap_or_default().as_bytes();
+    let (offsets, values, validity) = concat_columns(&columns, length, separator, nulls)?;
+
+    // Safety: the concatenation of utf8 values is utf8
+    Ok(unsafe {
+        Utf8Array::new_unchecked(
+            arrays[0].data_type().clone(),
+            offsets.into(),
+            values.into(),
+            validity,
+        )
+    })
+}
+
+/// Concatenates the values of `arrays` element-wise, interleaved by `separator`.
+/// # Errors
+/// This function errors iff `arrays` is empty, the arrays have different lengths or
+/// the result overflows `O`.
+pub fn concat_binary<O: Offset>(
+    arrays: &[&BinaryArray<O>],
+    separator: Option<&[u8]>,
+    nulls: ConcatNulls,
+) -> Result<BinaryArray<O>> {
+    let length = check_lengths(arrays.iter().map(|array| array.len()))?;
+    let columns = arrays
+        .iter()
+        .map(|array| (array.offsets(), array.values().as_slice(), array.validity()))
+        .collect::<Vec<_>>();
+    let separator = separator.unwrap_or_default();
+    let (offsets, values, validity) = concat_columns(&columns, length, separator, nulls)?;
+
+    Ok(BinaryArray::new(
+        arrays[0].data_type().clone(),
+        offsets.into(),
+        values.into(),
+        validity,
+    ))
+}
+
+fn downcast_all<'a, T: 'static>(arrays: &[&'a dyn Array]) -> Vec<&'a T> {
+    arrays
+        .iter()
+        .map(|array| array.as_any().downcast_ref::<T>().unwrap())
+        .collect()
+}
+
+/// Concatenates the values of `arrays` element-wise, interleaved by `separator`.
+/// A slot is null when any of its values is null.
+///
+/// This function supports \[Large\]String and \[Large\]Binary arrays, which must all
+/// have the same data type.
+/// # Errors
+/// This function errors iff `arrays` is empty, the arrays have different lengths or
+/// data types, or their data type is not supported.
+/// # Example
+/// ```
+/// use arrow2::array::Utf8Array;
+/// use arrow2::compute::utf8::concat_str;
+///
+/// let a = Utf8Array::<i32>::from([Some("a"), Some("b"), None]);
+/// let b = Utf8Array::<i32>::from([Some("c"), Some("d"), Some("e")]);
+///
+/// let result = concat_str(&[&a, &b], Some("-")).unwrap();
+/// assert_eq!(
+///     result.as_ref(),
+///     &Utf8Array::<i32>::from([Some("a-c"), Some("b-d"), None]) as &dyn arrow2::array::Array
+/// );
+/// ```
+pub fn concat_str(arrays: &[&dyn Array], separator: Option<&str>) -> Result<Box<dyn Array>> {
+    concat_str_with(arrays, separator, ConcatNulls::Propagate)
+}
+
+/// Concatenates the values of `arrays` element-wise, interleaved by `separator`, handling
+/// nulls according to `nulls`.
+/// # Errors
+/// This function errors iff `arrays` is empty, the arrays have different lengths or
+/// data types, or their data type is not supported.
+pub fn concat_str_with(
+    arrays: &[&dyn Array],
+    separator: Option<&str>,
+    nulls: ConcatNulls,
+) -> Result<Box<dyn Array>> {
+    let data_type = arrays
+        .first()
+        .ok_or_else(|| {
+            Error::InvalidArgumentError("concat_str requires at least one array".to_string())
+        })?
+        .data_type();
+    if arrays.iter().any(|array| array.data_type() != data_type) {
+        return Err(Error::InvalidArgumentError(
+            "concat_str requires arrays of the same data type".to_string(),
+        ));
+    }
+
+    match data_type.to_physical_type() {
+        PhysicalType::Utf8 => {
+            concat_utf8::<i32>(&downcast_all(arrays), separator, nulls).map(|x| x.boxed())
+        }
+        PhysicalType::LargeUtf8 => {
+            concat_utf8::<i64>(&downcast_all(arrays), separator, nulls).map(|x| x.boxed())
+        }
+        PhysicalType::Binary => concat_binary::<i32>(
+            &downcast_all(arrays),
+            separator.map(|x| x.as_bytes()),
+            nulls,
+        )
+        .map(|x| x.boxed()),
+        PhysicalType::LargeBinary => concat_binary::<i64>(
+            &downcast_all(arrays),
+            separator.map(|x| x.as_bytes()),
+            nulls,
+        )
+        .map(|x| x.boxed()),
+        _ => Err(Error::InvalidArgumentError(format!(
+            "concat_str does not support type {data_type:?}"
+        ))),
+    }
+}
+
+/// Checks if arrays of type `data_type` can be concatenated by [`concat_str`].
+pub fn can_concat_str(data_type: &DataType) -> bool {
+    matches!(
+        data_type.to_physical_type(),
+        PhysicalType::Utf8
+            | PhysicalType::LargeUtf8
+            | PhysicalType::Binary
+            | PhysicalType::LargeBinary
+    )
+}
+
+fn downcast_utf8<'a, O: Offset>(array: &'a dyn Array, kernel: &str) -> Result<&'a Utf8Array<O>> {
+    array
+        .as_any()
+        .downcast_ref::<Utf8Array<O>>()
+        .ok_or_else(|| {
+            Error::InvalidArgumentError(format!(
+                "{kernel} does not support type {:?}",
+                array.data_type()
+            ))
+        })
+}
+
+/// Checks that `lhs` and `rhs` are of the same \[Large\]String type and length, returning
+/// whether they are large.
+fn check_utf8_pair(lhs: &dyn Array, rhs: &dyn Array, kernel: &str) -> Result<bool> {
+    if lhs.data_type() != rhs.data_type() {
+        return Err(Error::InvalidArgumentError(format!(
+            "{kernel} requires arrays of the same data type, but they are {:?} and {:?}",
+            lhs.data_type(),
+            rhs.data_type()
+        )));
+    }
+    if lhs.len() != rhs.len() {
+        return Err(Error::InvalidArgumentError(format!(
+            "{kernel} requires arrays of the same length"
+        )));
+    }
+    match lhs.data_type().to_physical_type() {
+        PhysicalType::Utf8 => Ok(false),
+        PhysicalType::LargeUtf8 => Ok(true),
+        _ => Err(Error::InvalidArgumentError(format!(
+            "{kernel} does not support type {:?}",
+            lhs.data_type()
+        ))),
+    }
+}
+
+/// The 1-based position, in characters, of the first occurrence of `needle` in `haystack`,
+/// or 0 when `haystack` does not contain `needle`.
+fn position(haystack: &str, needle: &str) -> Result<i32> {
+    haystack.find(needle).map_or(Ok(0), |byte| {
+        i32::try_from(haystack[..byte].chars().count() + 1).map_err(|_| Error::Overflow)
+    })
+}
+
+fn strpos_values<'a, I: Iterator<Item = (&'a str, &'a str)>>(
+    values: I,
+    validity: Option<Bitmap>,
+) -> Result<Int32Array> {
+    let values = values
+        .map(|(haystack, needle)| position(haystack, needle))
+        .collect::<Result<Vec<_>>>()?;
+    Ok(Int32Array::new(DataType::Int32, values.into(), validity))
+}
+
+fn strpos_utf8<O: Offset>(haystack: &Utf8Array<O>, needle: &Utf8Array<O>) -> Result<Int32Array> {
+    let validity = combine_validities(haystack.validity(), needle.validity());
+    strpos_values(haystack.values_iter().zip(needle.values_iter()), validity)
+}
+
+/// Returns the 1-based position, in characters, of the first occurrence of the values of
+/// `needle` in the values of `haystack`, or 0 when the value does not occur, as SQL's `strpos`.
+/// A slot is null when either value is null. An empty needle is at position 1.
+/// # Errors
+/// This function errors iff the arrays are not of the same \[Large\]String type and length,
+/// or a position does not fit in an `i32`.
+/// # Example
+/// ```
+/// use arrow2::array::{Int32Array, Utf8Array};
+/// use arrow2::compute::utf8::strpos;
+///
+/// let haystack = Utf8Array::<i32>::from([Some("héllo"), Some("abc"), None]);
+/// let needle = Utf8Array::<i32>::from([Some("l"), Some("d"), Some("a")]);
+///
+/// let result = strpos(&haystack, &needle).unwrap();
+/// assert_eq!(result, Int32Array::from([Some(3), Some(0), None]));
+/// ```
+pub fn strpos(haystack: &dyn Array, needle: &dyn Array) -> Result<Int32Array> {
+    if check_utf8_pair(haystack, needle, "strpos")? {
+        strpos_utf8::<i64>(
+            downcast_utf8(haystack, "strpos")?,
+            downcast_utf8(needle, "strpos")?,
+        )
+    } else {
+        strpos_utf8::<i32>(
+            downcast_utf8(haystack, "strpos")?,
+            downcast_utf8(needle, "strpos")?,
+        )
+    }
+}
+
+/// Returns the 1-based position, in characters, of the first occurrence of `needle` in every
+/// value of `haystack`, or 0 when it does not occur. See [`strpos`].
+/// # Errors
+/// This function errors iff `haystack` is not a \[Large\]String array, or a position does not
+/// fit in an `i32`.
+pub fn strpos_scalar(haystack: &dyn Array, needle: &str) -> Result<Int32Array> {
+    match haystack.data_type().to_physical_type() {
+        PhysicalType::LargeUtf8 => {
+            let haystack = downcast_utf8::<i64>(haystack, "strpos")?;
+            let values = haystack.values_iter().map(|value| (value, needle));
+            strpos_values(values, haystack.validity().cloned())
+        }
+        _ => {
+            let haystack = downcast_utf8::<i32>(haystack, "strpos")?;
+            let values = haystack.values_iter().map(|value| (value, needle));
+            strpos_values(values, haystack.validity().cloned())
+        }
+    }
+}
+
+/// Alias of [`strpos`], as SQL's `instr(haystack, needle)`.
+/// # Errors
+/// See [`strpos`].
+pub fn instr(haystack: &dyn Array, needle: &dyn Array) -> Result<Int32Array> {
+    strpos(haystack, needle)
+}
+
+fn predicate_utf8<O: Offset, F: Fn(&str, &str) -> bool>(
+    lhs: &Utf8Array<O>,
+    rhs: &Utf8Array<O>,
+    op: F,
+) -> BooleanArray {
+    let validity = combine_validities(lhs.validity(), rhs.validity());
+    let values = lhs
+        .values_iter()
+        .zip(rhs.values_iter())
+        .map(|(lhs, rhs)| op(lhs, rhs));
+    BooleanArray::from_trusted_len_values_iter(values).with_validity(validity)
+}
+
+fn predicate<F: Fn(&str, &str) -> bool>(
+    lhs: &dyn Array,
+    rhs: &dyn Array,
+    kernel: &str,
+    op: F,
+) -> Result<BooleanArray> {
+    if check_utf8_pair(lhs, rhs, kernel)? {
+        Ok(predicate_utf8::<i64, _>(
+            downcast_utf8(lhs, kernel)?,
+            downcast_utf8(rhs, kernel)?,
+            op,
+        ))
+    } else {
+        Ok(predicate_utf8::<i32, _>(
+            downcast_utf8(lhs, kernel)?,
+            downcast_utf8(rhs, kernel)?,
+            op,
+        ))
+    }
+}
+
+fn predicate_scalar<F: Fn(&str) -> bool>(
+    array: &dyn Array,
+    kernel: &str,
+    op: F,
+) -> Result<BooleanArray> {
+    let array = match array.data_type().to_physical_type() {
+        PhysicalType::LargeUtf8 => {
+            let array = downcast_utf8::<i64>(array, kernel)?;
+            BooleanArray::from_trusted_len_values_iter(array.values_iter().map(op))
+                .with_validity(array.validity().cloned())
+        }
+        _ => {
+            let array = downcast_utf8::<i32>(array, kernel)?;
+            BooleanArray::from_trusted_len_values_iter(array.values_iter().map(op))
+                .with_validity(array.validity().cloned())
+        }
+    };
+    Ok(array)
+}
+
+/// Returns whether every value of `lhs` starts with the value of `rhs` in the same slot.
+/// A slot is null when either value is null.
+/// # Errors
+/// This function errors iff the arrays are not of the same \[Large\]String type and length.
+/// # Example
+/// ```
+/// use arrow2::array::{BooleanArray, Utf8Array};
+/// use arrow2::compute::utf8::starts_with;
+///
+/// let lhs = Utf8Array::<i32>::from([Some("arrow"), Some("parquet"), None]);
+/// let rhs = Utf8Array::<i32>::from([Some("ar"), Some("quet"), Some("")]);
+///
+/// let result = starts_with(&lhs, &rhs).unwrap();
+/// assert_eq!(result, BooleanArray::from([Some(true), Some(false), None]));
+/// ```
+pub fn starts_with(lhs: &dyn Array, rhs: &dyn Array) -> Result<BooleanArray> {
+    predicate(lhs, rhs, "starts_with", |lhs, rhs| lhs.starts_with(rhs))
+}
+
+/// Returns whether every value of `array` starts with `prefix`.
+/// # Errors
+/// This function errors iff `array` is not a \[Large\]String array.
+pub fn starts_with_scalar(array: &dyn Array, prefix: &str) -> Result<BooleanArray> {
+    predicate_scalar(array, "starts_with", |value| value.starts_with(prefix))
+}
+
+/// Returns whether every value of `lhs` ends with the value of `rhs` in the same slot.
+/// A slot is null when either value is null.
+/// # Errors
+/// This function errors iff the arrays are not of the same \[Large\]String type and length.
+pub fn ends_with(lhs: &dyn Array, rhs: &dyn Array) -> Result<BooleanArray> {
+    predicate(lhs, rhs, "ends_with", |lhs, rhs| lhs.ends_with(rhs))
+}
+
+/// Returns whether every value of `array` ends with `suffix`.
+/// # Errors
+/// This function errors iff `array` is not a \[Large\]String array.
+pub fn ends_with_scalar(array: &dyn Array, suffix: &str) -> Result<BooleanArray> {
+    predicate_scalar(array, "ends_with", |value| value.ends_with(suffix))
+}
+
+/// The part of `value` before the `count`th occurrence of `delimiter` (counted from the end
+/// when `count` is negative).
+fn substring_index_value<'a>(value: &'a str, delimiter: &str, count: i64) -> &'a str {
+    if count == 0 || delimiter.is_empty() {
+        return "";
+    }
+    let n = count.unsigned_abs() as usize;
+    if count > 0 {
+        match value.match_indices(delimiter).nth(n - 1) {
+            Some((index, _)) => &value[..index],
+            None => value,
+        }
+    } else {
+        match value.rmatch_indices(delimiter).nth(n - 1) {
+            Some((index, _)) => &value[index + delimiter.len()..],
+            None => value,
+        }
+    }
+}
+
+/// Returns the part of every value of `array` before the `count`th occurrence of `delimiter`
+/// or, when `count` is negative, after the `-count`th occurrence counted from the end,
+/// as SQL's `substring_index`. Values with fewer occurrences are returned whole, and a `count`
+/// of 0 or an empty `delimiter` returns empty strings.
+/// # Errors
+/// This function errors iff `array` is not a \[Large\]String array.
+/// # Example
+/// ```
+/// use arrow2::array::Utf8Array;
+/// use arrow2::compute::utf8::substring_index;
+///
+/// let array = Utf8Array::<i32>::from([Some("www.apache.org"), Some("arrow"), None]);
+///
+/// let result = substring_index(&array, ".", 2).unwrap();
+/// let expected = Utf8Array::<i32>::from([Some("www.apache"), Some("arrow"), None]);
+/// assert_eq!(result.as_ref(), &expected as &dyn arrow2::array::Array);
+///
+/// let result = substring_index(&array, ".", -2).unwrap();
+/// let expected = Utf8Array::<i32>::from([Some("apache.org"), Some("arrow"), None]);
+/// assert_eq!(result.as_ref(), &expected as &dyn arrow2::array::Array);
+/// ```
+pub fn substring_index(array: &dyn Array, delimiter: &str, count: i64) -> Result<Box<dyn Array>> {
+    fn apply<O: Offset>(array: &Utf8Array<O>, delimiter: &str, count: i64) -> Utf8Array<O> {
+        let values = array
+            .values_iter()
+            .map(|value| substring_index_value(value, delimiter, count));
+        Utf8Array::<O>::from_trusted_len_values_iter(values)
+            .with_validity(array.validity().cloned())
+    }
+
+    match array.data_type().to_physical_type() {
+        PhysicalType::LargeUtf8 => Ok(apply(
+            downcast_utf8::<i64>(array, "substring_index")?,
+            delimiter,
+            count,
+        )
+        .boxed()),
+        _ => Ok(apply(
+            downcast_utf8::<i32>(array, "substring_index")?,
+            delimiter,
+            count,
+        )
+        .boxed()),
+    }
+}
+
+/// A Unicode normalization form, see [Unicode Normalization Forms](https://unicode.org/reports/tr15/).
+#[cfg(feature = "compute_utf8_normalization")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compute_utf8_normalization")))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NormalizationForm {
+    /// Canonical decomposition, followed by canonical composition
+    Nfc,
+    /// Canonical decomposition
+    Nfd,
+    /// Compatibility decomposition, followed by canonical composition
+    Nfkc,
+    /// Compatibility decomposition
+    Nfkd,
+}
+
+#[cfg(feature = "compute_utf8_normalization")]
+fn normalize_utf8<O: Offset>(array: &Utf8Array<O>, form: NormalizationForm) -> Utf8Array<O> {
+    use unicode_normalization::UnicodeNormalization;
+
+    // ASCII is invariant under all normalization forms
+    let start = array.offsets().buffer()[0].to_usize();
+    let end = array.offsets().last().to_usize();
+    if array.values()[start..end].is_ascii() {
+        return array.clone();
+    }
+    match form {
+        NormalizationForm::Nfc => utf8_apply(|x| x.nfc().collect(), array),
+        NormalizationForm::Nfd => utf8_apply(|x| x.nfd().collect(), array),
+        NormalizationForm::Nfkc => utf8_apply(|x| x.nfkc().collect(), array),
+        NormalizationForm::Nfkd => utf8_apply(|x| x.nfkd().collect(), array),
+    }
+}
+
+/// Returns a new `Array` where each of the elements is normalized to the Unicode normalization
+/// `form`, e.g. so that equivalent strings with different encodings compare equal.
+///
+/// Arrays whose values are all ASCII are returned as they are, since ASCII is invariant under
+/// all normalization forms.
+/// # Errors
+/// This function errors when the passed array is not a \[Large\]String array.
+/// # Example
+/// ```
+/// use arrow2::array::Utf8Array;
+/// use arrow2::compute::utf8::{normalize, NormalizationForm};
+///
+/// // "é" as `e` followed by a combining acute accent, and the "ﬁ" ligature
+/// let array = Utf8Array::<i32>::from_slice(["e\u{301}", "\u{fb01}"]);
+///
+/// let result = normalize(&array, NormalizationForm::Nfc).unwrap();
+/// let expected = Utf8Array::<i32>::from_slice(["\u{e9}", "\u{fb01}"]);
+/// assert_eq!(result.as_ref(), &expected as &dyn arrow2::array::Array);
+///
+/// let result = normalize(&array, NormalizationForm::Nfkc).unwrap();
+/// let expected = Utf8Array::<i32>::from_slice(["\u{e9}", "fi"]);
+/// assert_eq!(result.as_ref(), &expected as &dyn arrow2::array::Array);
+/// ```
+#[cfg(feature = "compute_utf8_normalization")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compute_utf8_normalization")))]
+pub fn normalize(array: &dyn Array, form: NormalizationForm) -> Result<Box<dyn Array>> {
+    match array.data_type().to_physical_type() {
+        PhysicalType::LargeUtf8 => {
+            Ok(normalize_utf8(downcast_utf8::<i64>(array, "normalize")?, form).boxed())
+        }
+        PhysicalType::Utf8 => {
+            Ok(normalize_utf8(downcast_utf8::<i32>(array, "normalize")?, form).boxed())
+        }
+        _ => Err(Error::InvalidArgumentError(format!(
+            "normalize does not support type {:?}",
+            array.data_type()
+        ))),
+    }
+}
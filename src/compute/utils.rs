@@ -1,3 +1,7 @@
+use alloc::string::ToString;
+
+#[cfg(any(feature = "compute_contains", feature = "compute_distinct"))]
+use crate::types::NativeType;
 use crate::{
     array::Array,
     bitmap::Bitmap,
@@ -23,3 +27,50 @@ pub fn check_same_len(lhs: &dyn Array, rhs: &dyn Array) -> Result<()> {
     }
     Ok(())
 }
+
+/// The little endian bytes of a [`NativeType`], which are hashable.
+#[cfg(any(feature = "compute_contains", feature = "compute_distinct"))]
+pub struct Bytes<T: NativeType>(pub T::Bytes);
+
+#[cfg(any(feature = "compute_contains", feature = "compute_distinct"))]
+impl<T: NativeType> core::hash::Hash for Bytes<T> {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.0.as_ref().hash(state)
+    }
+}
+
+#[cfg(any(feature = "compute_contains", feature = "compute_distinct"))]
+impl<T: NativeType> PartialEq for Bytes<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.as_ref() == other.0.as_ref()
+    }
+}
+
+#[cfg(any(feature = "compute_contains", feature = "compute_distinct"))]
+impl<T: NativeType> Eq for Bytes<T> {}
+
+/// Returns the bits of `value`, with all zeros and all NaNs mapped to the same bits, so that
+/// values that compare as equal, and all NaNs, hash the same.
+#[cfg(any(feature = "compute_aggregate", feature = "compute_cast"))]
+pub(crate) fn canonical_f32(value: f32) -> u32 {
+    if value == 0.0 {
+        0
+    } else if value.is_nan() {
+        f32::NAN.to_bits()
+    } else {
+        value.to_bits()
+    }
+}
+
+/// Returns the bits of `value`, with all zeros and all NaNs mapped to the same bits, so that
+/// values that compare as equal, and all NaNs, hash the same.
+#[cfg(any(feature = "compute_aggregate", feature = "compute_cast"))]
+pub(crate) fn canonical_f64(value: f64) -> u64 {
+    if value == 0.0 {
+        0
+    } else if value.is_nan() {
+        f64::NAN.to_bits()
+    } else {
+        value.to_bits()
+    }
+}
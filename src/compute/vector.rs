@@ -0,0 +1,236 @@
+//! Distance and similarity kernels between vectors of floats, e.g. embeddings, stored as
+//! [`FixedSizeListArray`]s of [`PrimitiveArray`]s.
+//!
+//! Each kernel has two variants: one comparing the slots of two arrays pairwise (e.g. [`dot`]),
+//! and one comparing every slot of an array to a single query vector (e.g. [`dot_scalar`]).
+//! A slot of the result is null when the slot of an input, or any of its values, is null.
+//!
+//! The inner loops accumulate over [`LANES`] independent lanes so that they are
+//! auto-vectorized, and are compiled for AVX2 and AVX with runtime detection.
+use multiversion::multiversion;
+use num_traits::Float;
+
+use crate::array::{FixedSizeListArray, PrimitiveArray};
+use crate::bitmap::Bitmap;
+use crate::compute::arithmetics::{basic::NativeArithmetics, fixed_size_list};
+use crate::compute::utils::combine_validities;
+use crate::error::{Error, Result};
+
+/// The number of independent accumulators of the inner loops
+pub const LANES: usize = 16;
+
+/// The types of the values of the vectors supported by this module
+pub trait VectorType: NativeArithmetics + Float {}
+
+impl VectorType for f32 {}
+impl VectorType for f64 {}
+
+/// Reduces `lhs` and `rhs` by summing `op` over their pairs of values.
+#[inline(always)]
+fn reduce<T: VectorType, F: Fn(T, T) -> T>(lhs: &[T], rhs: &[T], op: F) -> T {
+    let mut acc = [T::zero(); LANES];
+    let mut lhs_chunks = lhs.chunks_exact(LANES);
+    let mut rhs_chunks = rhs.chunks_exact(LANES);
+    for (lhs, rhs) in lhs_chunks.by_ref().zip(rhs_chunks.by_ref()) {
+        for i in 0..LANES {
+            acc[i] = acc[i] + op(lhs[i], rhs[i]);
+        }
+    }
+    let remainder = lhs_chunks
+        .remainder()
+        .iter()
+        .zip(rhs_chunks.remainder())
+        .fold(T::zero(), |acc, (lhs, rhs)| acc + op(*lhs, *rhs));
+    acc.iter().fold(remainder, |acc, x| acc + *x)
+}
+
+#[multiversion]
+#[clone(target = "x86_64+avx2")]
+#[clone(target = "x86_64+avx")]
+fn dot_impl<T: VectorType>(lhs: &[T], rhs: &[T]) -> T {
+    reduce(lhs, rhs, |lhs, rhs| lhs * rhs)
+}
+
+#[multiversion]
+#[clone(target = "x86_64+avx2")]
+#[clone(target = "x86_64+avx")]
+fn squared_distance_impl<T: VectorType>(lhs: &[T], rhs: &[T]) -> T {
+    reduce(lhs, rhs, |lhs, rhs| {
+        let diff = lhs - rhs;
+        diff * diff
+    })
+}
+
+fn dot_row<T: VectorType>(lhs: &[T], rhs: &[T]) -> Option<T> {
+    Some(dot_impl(lhs, rhs))
+}
+
+fn l2_distance_row<T: VectorType>(lhs: &[T], rhs: &[T]) -> Option<T> {
+    Some(squared_distance_impl(lhs, rhs).sqrt())
+}
+
+fn cosine_similarity_row<T: VectorType>(lhs: &[T], rhs: &[T]) -> Option<T> {
+    let norms = (dot_impl(lhs, lhs) * dot_impl(rhs, rhs)).sqrt();
+    (norms != T::zero()).then(|| dot_impl(lhs, rhs) / norms)
+}
+
+fn values<T: VectorType>(array: &FixedSizeListArray) -> Result<&[T]> {
+    array
+        .values()
+        .as_any()
+        .downcast_ref::<PrimitiveArray<T>>()
+        .map(|values| values.values().as_slice())
+        .ok_or_else(|| {
+            Error::InvalidArgumentError(format!(
+                "Vector kernels require FixedSizeListArrays of {:?} values, but got {:?}",
+                T::PRIMITIVE,
+                array.values().data_type()
+            ))
+        })
+}
+
+/// Applies `op` to each pair of slots of `lhs` and `rhs`
+fn binary<T, F>(
+    lhs: &FixedSizeListArray,
+    rhs: &FixedSizeListArray,
+    op: F,
+) -> Result<PrimitiveArray<T>>
+where
+    T: VectorType,
+    F: Fn(&[T], &[T]) -> Option<T>,
+{
+    if lhs.size() != rhs.size() || lhs.len() != rhs.len() {
+        return Err(Error::InvalidArgumentError(format!(
+            "Vector kernels require FixedSizeListArrays of equal sizes and lengths, but got sizes {} and {} and lengths {} and {}",
+            lhs.size(),
+            rhs.size(),
+            lhs.len(),
+            rhs.len()
+        )));
+    }
+    let validity = combine_validities(lhs.valid_rows().as_ref(), rhs.valid_rows().as_ref());
+    let size = lhs.size();
+    let results = values::<T>(lhs)?
+        .chunks_exact(size)
+        .zip(values::<T>(rhs)?.chunks_exact(size))
+        .map(|(lhs, rhs)| op(lhs, rhs));
+    Ok(finish(results, validity))
+}
+
+/// Applies `op` to each slot of `lhs` and `rhs`
+fn binary_scalar<T, F>(lhs: &FixedSizeListArray, rhs: &[T], op: F) -> Result<PrimitiveArray<T>>
+where
+    T: VectorType,
+    F: Fn(&[T], &[T]) -> Option<T>,
+{
+    if lhs.size() != rhs.len() {
+        return Err(Error::InvalidArgumentError(format!(
+            "Vector kernels require a query of the size of the FixedSizeListArray ({}), but got {}",
+            lhs.size(),
+            rhs.len()
+        )));
+    }
+    let results = values::<T>(lhs)?
+        .chunks_exact(lhs.size())
+        .map(|lhs| op(lhs, rhs));
+    Ok(finish(results, lhs.valid_rows()))
+}
+
+fn finish<T: VectorType, I: Iterator<Item = Option<T>>>(
+    results: I,
+    validity: Option<Bitmap>,
+) -> PrimitiveArray<T> {
+    let array = results.collect::<PrimitiveArray<T>>();
+    let validity = combine_validities(array.validity(), validity.as_ref());
+    array.with_validity(validity)
+}
+
+/// Returns the dot product of each pair of slots of `lhs` and `rhs`
+/// (see [`fixed_size_list::dot`]).
+/// # Errors
+/// Errors iff the arrays have different sizes or lengths, or their values are not of type `T`.
+/// # Example
+/// ```
+/// use arrow2::array::{FixedSizeListArray, Float32Array};
+/// use arrow2::compute::vector::dot;
+/// use arrow2::datatypes::DataType;
+///
+/// let data_type = FixedSizeListArray::default_datatype(DataType::Float32, 2);
+/// let values = Float32Array::from_slice([1.0, 2.0, 3.0, 4.0]).boxed();
+/// let lhs = FixedSizeListArray::new(data_type.clone(), values, None);
+/// let values = Float32Array::from_slice([1.0, 0.0, 0.5, 0.5]).boxed();
+/// let rhs = FixedSizeListArray::new(data_type, values, None);
+///
+/// let result = dot::<f32>(&lhs, &rhs).unwrap();
+/// assert_eq!(result, Float32Array::from_slice([1.0, 3.5]));
+/// ```
+pub fn dot<T: VectorType>(
+    lhs: &FixedSizeListArray,
+    rhs: &FixedSizeListArray,
+) -> Result<PrimitiveArray<T>> {
+    fixed_size_list::dot(lhs, rhs)
+}
+
+/// Returns the dot product of each slot of `lhs` with `rhs`.
+/// # Errors
+/// Errors iff the length of `rhs` is not the size of `lhs`, or its values are not of type `T`.
+pub fn dot_scalar<T: VectorType>(lhs: &FixedSizeListArray, rhs: &[T]) -> Result<PrimitiveArray<T>> {
+    binary_scalar(lhs, rhs, dot_row)
+}
+
+/// Returns the cosine similarity of each pair of slots of `lhs` and `rhs`.
+///
+/// The similarity is null when either vector has a norm of zero.
+/// # Errors
+/// Errors iff the arrays have different sizes or lengths, or their values are not of type `T`.
+pub fn cosine_similarity<T: VectorType>(
+    lhs: &FixedSizeListArray,
+    rhs: &FixedSizeListArray,
+) -> Result<PrimitiveArray<T>> {
+    binary(lhs, rhs, cosine_similarity_row)
+}
+
+/// Returns the cosine similarity of each slot of `lhs` with `rhs`.
+///
+/// The similarity is null when either vector has a norm of zero.
+/// # Errors
+/// Errors iff the length of `rhs` is not the size of `lhs`, or its values are not of type `T`.
+/// # Example
+/// ```
+/// use arrow2::array::{FixedSizeListArray, Float32Array};
+/// use arrow2::compute::vector::cosine_similarity_scalar;
+/// use arrow2::datatypes::DataType;
+///
+/// let data_type = FixedSizeListArray::default_datatype(DataType::Float32, 2);
+/// let values = Float32Array::from_slice([2.0, 0.0, 0.0, 3.0, 0.0, 0.0]).boxed();
+/// let embeddings = FixedSizeListArray::new(data_type, values, None);
+///
+/// let result = cosine_similarity_scalar(&embeddings, &[1.0f32, 0.0]).unwrap();
+/// assert_eq!(result, Float32Array::from([Some(1.0), Some(0.0), None]));
+/// ```
+pub fn cosine_similarity_scalar<T: VectorType>(
+    lhs: &FixedSizeListArray,
+    rhs: &[T],
+) -> Result<PrimitiveArray<T>> {
+    binary_scalar(lhs, rhs, cosine_similarity_row)
+}
+
+/// Returns the euclidean (L2) distance between each pair of slots of `lhs` and `rhs`.
+/// # Errors
+/// Errors iff the arrays have different sizes or lengths, or their values are not of type `T`.
+pub fn l2_distance<T: VectorType>(
+    lhs: &FixedSizeListArray,
+    rhs: &FixedSizeListArray,
+) -> Result<PrimitiveArray<T>> {
+    binary(lhs, rhs, l2_distance_row)
+}
+
+/// Returns the euclidean (L2) distance between each slot of `lhs` and `rhs`.
+/// # Errors
+/// Errors iff the length of `rhs` is not the size of `lhs`, or its values are not of type `T`.
+pub fn l2_distance_scalar<T: VectorType>(
+    lhs: &FixedSizeListArray,
+    rhs: &[T],
+) -> Result<PrimitiveArray<T>> {
+    binary_scalar(lhs, rhs, l2_distance_row)
+}
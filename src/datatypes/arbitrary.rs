@@ -0,0 +1,155 @@
+//! [`proptest`] strategies of [`DataType`]s and [`Field`]s.
+use proptest::prelude::*;
+
+use super::{DataType, Field, IntegerType, IntervalUnit, TimeUnit, UnionMode};
+
+fn time_unit() -> impl Strategy<Value = TimeUnit> {
+    prop_oneof![
+        Just(TimeUnit::Second),
+        Just(TimeUnit::Millisecond),
+        Just(TimeUnit::Microsecond),
+        Just(TimeUnit::Nanosecond),
+    ]
+}
+
+fn integer_type() -> impl Strategy<Value = IntegerType> {
+    prop_oneof![
+        Just(IntegerType::Int8),
+        Just(IntegerType::Int16),
+        Just(IntegerType::Int32),
+        Just(IntegerType::Int64),
+        Just(IntegerType::UInt8),
+        Just(IntegerType::UInt16),
+        Just(IntegerType::UInt32),
+        Just(IntegerType::UInt64),
+    ]
+}
+
+fn timezone() -> impl Strategy<Value = Option<String>> {
+    prop_oneof![
+        Just(None),
+        Just(Some("UTC".to_string())),
+        Just(Some("+01:00".to_string())),
+    ]
+}
+
+/// Returns a [`Strategy`] of the non-nested [`DataType`]s, i.e. all but lists, structs, maps,
+/// unions, dictionaries and extensions.
+pub fn flat_data_type() -> impl Strategy<Value = DataType> {
+    use DataType::*;
+    prop_oneof![
+        Just(Null),
+        Just(Boolean),
+        Just(Int8),
+        Just(Int16),
+        Just(Int32),
+        Just(Int64),
+        Just(UInt8),
+        Just(UInt16),
+        Just(UInt32),
+        Just(UInt64),
+        Just(Float16),
+        Just(Float32),
+        Just(Float64),
+        (time_unit(), timezone()).prop_map(|(unit, tz)| Timestamp(unit, tz)),
+        Just(Date32),
+        Just(Date64),
+        prop_oneof![Just(TimeUnit::Second), Just(TimeUnit::Millisecond)].prop_map(Time32),
+        prop_oneof![Just(TimeUnit::Microsecond), Just(TimeUnit::Nanosecond)].prop_map(Time64),
+        time_unit().prop_map(Duration),
+        prop_oneof![
+            Just(IntervalUnit::YearMonth),
+            Just(IntervalUnit::DayTime),
+            Just(IntervalUnit::MonthDayNano),
+        ]
+        .prop_map(Interval),
+        Just(Binary),
+        (1..8usize).prop_map(FixedSizeBinary),
+        Just(LargeBinary),
+        Just(Utf8),
+        Just(LargeUtf8),
+        (1..=38usize)
+            .prop_flat_map(|precision| (Just(precision), 0..=precision))
+            .prop_map(|(precision, scale)| Decimal(precision, scale)),
+        (1..=76usize)
+            .prop_flat_map(|precision| (Just(precision), 0..=precision))
+            .prop_map(|(precision, scale)| Decimal256(precision, scale)),
+    ]
+}
+
+fn field(data_type: impl Strategy<Value = DataType>) -> impl Strategy<Value = Field> {
+    (data_type, any::<bool>())
+        .prop_map(|(data_type, is_nullable)| Field::new("item", data_type, is_nullable))
+}
+
+fn fields(data_type: impl Strategy<Value = DataType>) -> impl Strategy<Value = Vec<Field>> {
+    proptest::collection::vec(field(data_type), 1..4).prop_map(|fields| {
+        fields
+            .into_iter()
+            .enumerate()
+            .map(|(i, field)| Field {
+                name: format!("f{i}"),
+                ..field
+            })
+            .collect()
+    })
+}
+
+/// Returns a [`Strategy`] of [`DataType`]s nested up to `depth` levels.
+///
+/// Dictionaries are only of non-nested values, the keys of maps are non-nullable
+/// [`DataType::Utf8`] and unions have no explicit type ids.
+pub fn data_type(depth: u32) -> impl Strategy<Value = DataType> {
+    let dictionary = (integer_type(), flat_data_type(), any::<bool>())
+        .prop_filter(
+            "dictionaries of nulls are not supported",
+            |(_, values, _)| values != &DataType::Null,
+        )
+        .prop_map(|(key, values, is_sorted)| {
+            DataType::Dictionary(key, Box::new(values), is_sorted)
+        });
+    let leaf = prop_oneof![4 => flat_data_type(), 1 => dictionary];
+
+    leaf.prop_recursive(depth, 16, 3, |inner| {
+        prop_oneof![
+            field(inner.clone()).prop_map(|field| DataType::List(Box::new(field))),
+            field(inner.clone()).prop_map(|field| DataType::LargeList(Box::new(field))),
+            (field(inner.clone()), 1..4usize)
+                .prop_map(|(field, size)| DataType::FixedSizeList(Box::new(field), size)),
+            fields(inner.clone()).prop_map(DataType::Struct),
+            (field(inner.clone()), any::<bool>()).prop_map(|(value, is_sorted)| {
+                let entries = DataType::Struct(vec![
+                    Field::new("key", DataType::Utf8, false),
+                    Field {
+                        name: "value".to_string(),
+                        ..value
+                    },
+                ]);
+                DataType::Map(Box::new(Field::new("entries", entries, false)), is_sorted)
+            }),
+            (
+                fields(inner),
+                prop_oneof![Just(UnionMode::Dense), Just(UnionMode::Sparse)]
+            )
+                .prop_map(|(fields, mode)| DataType::Union(fields, None, mode)),
+        ]
+    })
+}
+
+impl Arbitrary for DataType {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        data_type(3).boxed()
+    }
+}
+
+impl Arbitrary for Field {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        field(any::<DataType>()).boxed()
+    }
+}
@@ -0,0 +1,350 @@
+//! Contains the [canonical extension types](https://arrow.apache.org/docs/format/CanonicalExtensions.html)
+//! `arrow.uuid`, `arrow.json` and `arrow.fixed_shape_tensor`.
+//!
+//! Their [`DataType`]s are regular [`DataType::Extension`]s and are thus written to and read
+//! from IPC and Parquet as any other extension. Use [`register_canonical_extension_types`] to
+//! validate and display them as described in [`ExtensionType`].
+//! # Example
+//! ```
+//! use arrow2::array::{Array, FixedSizeListArray, Int32Array};
+//! use arrow2::datatypes::canonical::{from_tensor, to_tensor, FixedShapeTensor};
+//! use arrow2::datatypes::{DataType, Field};
+//!
+//! // two 2x3 matrices
+//! let values = Int32Array::from_slice([1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12]).boxed();
+//! let data_type = DataType::FixedSizeList(Box::new(Field::new("item", DataType::Int32, true)), 6);
+//! let array = FixedSizeListArray::new(data_type, values, None);
+//!
+//! let tensor = FixedShapeTensor::try_new(vec![2, 3], None, None).unwrap();
+//! let tensors = to_tensor(&array, &tensor).unwrap();
+//! assert_eq!(tensors.data_type(), &tensor.data_type(DataType::Int32));
+//!
+//! let (array, shape) = from_tensor(&tensors).unwrap();
+//! assert_eq!(shape, tensor);
+//! ```
+use std::fmt::Write;
+use std::sync::Arc;
+
+use serde_derive::{Deserialize, Serialize};
+
+use crate::array::{Array, FixedSizeBinaryArray, FixedSizeListArray};
+use crate::error::{Error, Result};
+
+use super::{register_extension_type, DataType, ExtensionType, Field};
+
+/// The name of the UUID extension type
+pub const UUID: &str = "arrow.uuid";
+/// The name of the JSON extension type
+pub const JSON: &str = "arrow.json";
+/// The name of the fixed-shape tensor extension type
+pub const FIXED_SHAPE_TENSOR: &str = "arrow.fixed_shape_tensor";
+
+/// Registers [`UuidExtension`], [`JsonExtension`] and [`FixedShapeTensorExtension`].
+pub fn register_canonical_extension_types() {
+    register_extension_type(Arc::new(UuidExtension));
+    register_extension_type(Arc::new(JsonExtension));
+    register_extension_type(Arc::new(FixedShapeTensorExtension));
+}
+
+/// Returns the [`DataType`] of UUIDs, whose values are 16 bytes.
+pub fn uuid() -> DataType {
+    DataType::Extension(
+        UUID.to_string(),
+        Box::new(DataType::FixedSizeBinary(16)),
+        None,
+    )
+}
+
+/// Returns the [`DataType`] of JSON values stored as [`DataType::Utf8`].
+pub fn json() -> DataType {
+    DataType::Extension(JSON.to_string(), Box::new(DataType::Utf8), None)
+}
+
+/// Returns the [`DataType`] of JSON values stored as [`DataType::LargeUtf8`].
+pub fn large_json() -> DataType {
+    DataType::Extension(JSON.to_string(), Box::new(DataType::LargeUtf8), None)
+}
+
+/// Returns `metadata` if it is neither empty nor an empty JSON object
+fn non_empty_metadata(metadata: Option<&str>) -> Option<&str> {
+    metadata
+        .map(|metadata| metadata.trim())
+        .filter(|metadata| !metadata.is_empty() && !is_empty_object(metadata))
+}
+
+fn is_empty_object(metadata: &str) -> bool {
+    metadata
+        .strip_prefix('{')
+        .and_then(|metadata| metadata.strip_suffix('}'))
+        .map(|inner| inner.trim().is_empty())
+        .unwrap_or(false)
+}
+
+/// The [`ExtensionType`] of `arrow.uuid`, whose storage is [`DataType::FixedSizeBinary`] of
+/// 16 bytes and whose values are displayed in their hyphenated form.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UuidExtension;
+
+impl ExtensionType for UuidExtension {
+    fn name(&self) -> &str {
+        UUID
+    }
+
+    fn validate(&self, storage: &DataType, metadata: Option<&str>) -> Result<()> {
+        if storage != &DataType::FixedSizeBinary(16) {
+            return Err(Error::InvalidArgumentError(format!(
+                "The storage of {UUID} must be FixedSizeBinary(16), but it is {storage:?}"
+            )));
+        }
+        if non_empty_metadata(metadata).is_some() {
+            return Err(Error::InvalidArgumentError(format!(
+                "{UUID} does not support metadata"
+            )));
+        }
+        Ok(())
+    }
+
+    fn write_value(&self, array: &dyn Array, index: usize, f: &mut dyn Write) -> std::fmt::Result {
+        let array = array
+            .as_any()
+            .downcast_ref::<FixedSizeBinaryArray>()
+            .unwrap();
+        for (i, byte) in array.value(index).iter().enumerate() {
+            if matches!(i, 4 | 6 | 8 | 10) {
+                f.write_char('-')?;
+            }
+            write!(f, "{byte:02x}")?;
+        }
+        Ok(())
+    }
+}
+
+/// The [`ExtensionType`] of `arrow.json`, whose storage is [`DataType::Utf8`] or
+/// [`DataType::LargeUtf8`].
+///
+/// Values are not validated to be JSON, as this would require parsing them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct JsonExtension;
+
+impl ExtensionType for JsonExtension {
+    fn name(&self) -> &str {
+        JSON
+    }
+
+    fn validate(&self, storage: &DataType, metadata: Option<&str>) -> Result<()> {
+        if !matches!(storage, DataType::Utf8 | DataType::LargeUtf8) {
+            return Err(Error::InvalidArgumentError(format!(
+                "The storage of {JSON} must be Utf8 or LargeUtf8, but it is {storage:?}"
+            )));
+        }
+        if non_empty_metadata(metadata).is_some() {
+            return Err(Error::InvalidArgumentError(format!(
+                "{JSON} does not support metadata"
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// The [`ExtensionType`] of `arrow.fixed_shape_tensor`, whose storage is a
+/// [`DataType::FixedSizeList`] and whose metadata is a [`FixedShapeTensor`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FixedShapeTensorExtension;
+
+impl ExtensionType for FixedShapeTensorExtension {
+    fn name(&self) -> &str {
+        FIXED_SHAPE_TENSOR
+    }
+
+    fn validate(&self, storage: &DataType, metadata: Option<&str>) -> Result<()> {
+        let metadata = metadata.ok_or_else(|| {
+            Error::InvalidArgumentError(format!("{FIXED_SHAPE_TENSOR} requires metadata"))
+        })?;
+        let tensor = FixedShapeTensor::try_from_metadata(metadata)?;
+        match storage {
+            DataType::FixedSizeList(_, size) if *size == tensor.size() => Ok(()),
+            _ => Err(Error::InvalidArgumentError(format!(
+                "The storage of {FIXED_SHAPE_TENSOR} with shape {:?} must be a FixedSizeList of size {}, but it is {storage:?}",
+                tensor.shape,
+                tensor.size()
+            ))),
+        }
+    }
+}
+
+/// The shape of the tensors of an `arrow.fixed_shape_tensor`, i.e. its metadata.
+///
+/// Every slot of an array of this type is a tensor with `shape`, whose values are stored in
+/// row-major order in a [`DataType::FixedSizeList`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct FixedShapeTensor {
+    shape: Vec<usize>,
+    dim_names: Option<Vec<String>>,
+    permutation: Option<Vec<usize>>,
+}
+
+impl FixedShapeTensor {
+    /// Creates a new [`FixedShapeTensor`].
+    /// # Errors
+    /// Errors iff
+    /// * `shape` is empty or has a dimension of length 0
+    /// * the number of values of a tensor of `shape` overflows `usize`
+    /// * `dim_names` does not have one name per dimension
+    /// * `permutation` is not a permutation of the dimensions
+    pub fn try_new(
+        shape: Vec<usize>,
+        dim_names: Option<Vec<String>>,
+        permutation: Option<Vec<usize>>,
+    ) -> Result<Self> {
+        if shape.is_empty() || shape.contains(&0) {
+            return Err(Error::InvalidArgumentError(format!(
+                "The shape of a tensor must have dimensions of positive length, but it is {shape:?}"
+            )));
+        }
+        if shape
+            .iter()
+            .try_fold(1usize, |size, x| size.checked_mul(*x))
+            .is_none()
+        {
+            return Err(Error::InvalidArgumentError(format!(
+                "The number of values of a tensor of shape {shape:?} overflows usize"
+            )));
+        }
+        if let Some(dim_names) = &dim_names {
+            if dim_names.len() != shape.len() {
+                return Err(Error::InvalidArgumentError(format!(
+                    "A tensor with {} dimensions must have {} dimension names, but it has {}",
+                    shape.len(),
+                    shape.len(),
+                    dim_names.len()
+                )));
+            }
+        }
+        if let Some(permutation) = &permutation {
+            let mut sorted = permutation.clone();
+            sorted.sort_unstable();
+            if !sorted.iter().copied().eq(0..shape.len()) {
+                return Err(Error::InvalidArgumentError(format!(
+                    "{permutation:?} is not a permutation of the {} dimensions of a tensor",
+                    shape.len()
+                )));
+            }
+        }
+        Ok(Self {
+            shape,
+            dim_names,
+            permutation,
+        })
+    }
+
+    /// Returns the shape of the tensors
+    pub fn shape(&self) -> &[usize] {
+        &self.shape
+    }
+
+    /// Returns the names of the dimensions of the tensors, if any
+    pub fn dim_names(&self) -> Option<&[String]> {
+        self.dim_names.as_deref()
+    }
+
+    /// Returns the permutation of the dimensions of the tensors, if any
+    pub fn permutation(&self) -> Option<&[usize]> {
+        self.permutation.as_deref()
+    }
+
+    /// Returns the number of values of every tensor, which [`FixedShapeTensor::try_new`]
+    /// checks to fit in a `usize`.
+    pub fn size(&self) -> usize {
+        self.shape.iter().product()
+    }
+
+    /// Returns the [`DataType`] of an `arrow.fixed_shape_tensor` of this shape with values of
+    /// `value_type`.
+    pub fn data_type(&self, value_type: DataType) -> DataType {
+        DataType::Extension(
+            FIXED_SHAPE_TENSOR.to_string(),
+            Box::new(DataType::FixedSizeList(
+                Box::new(Field::new("item", value_type, true)),
+                self.size(),
+            )),
+            Some(self.to_metadata()),
+        )
+    }
+
+    /// Returns the [`FixedShapeTensor`] of `data_type`
+    /// # Errors
+    /// Errors iff `data_type` is not a valid `arrow.fixed_shape_tensor`
+    pub fn try_from_data_type(data_type: &DataType) -> Result<Self> {
+        match data_type {
+            DataType::Extension(name, storage, metadata) if name == FIXED_SHAPE_TENSOR => {
+                FixedShapeTensorExtension.validate(storage, metadata.as_deref())?;
+                Self::try_from_metadata(metadata.as_deref().unwrap())
+            }
+            _ => Err(Error::InvalidArgumentError(format!(
+                "{data_type:?} is not a {FIXED_SHAPE_TENSOR}"
+            ))),
+        }
+    }
+
+    /// Returns the JSON-encoded metadata of this shape
+    pub fn to_metadata(&self) -> String {
+        serde_json::to_string(&Metadata {
+            shape: self.shape.clone(),
+            dim_names: self.dim_names.clone(),
+            permutation: self.permutation.clone(),
+        })
+        .unwrap()
+    }
+
+    /// Returns the [`FixedShapeTensor`] of the JSON-encoded `metadata`
+    /// # Errors
+    /// Errors iff `metadata` is not a valid metadata of `arrow.fixed_shape_tensor`
+    pub fn try_from_metadata(metadata: &str) -> Result<Self> {
+        let metadata: Metadata = serde_json::from_str(metadata).map_err(|error| {
+            Error::InvalidArgumentError(format!(
+                "Invalid metadata of {FIXED_SHAPE_TENSOR}: {error}"
+            ))
+        })?;
+        Self::try_new(metadata.shape, metadata.dim_names, metadata.permutation)
+    }
+}
+
+/// The JSON representation of [`FixedShapeTensor`]
+#[derive(Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Metadata {
+    shape: Vec<usize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    dim_names: Option<Vec<String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    permutation: Option<Vec<usize>>,
+}
+
+/// Returns `array` as an `arrow.fixed_shape_tensor` of `tensor`.
+/// # Errors
+/// Errors iff the size of `array` is not the size of `tensor`
+pub fn to_tensor(
+    array: &FixedSizeListArray,
+    tensor: &FixedShapeTensor,
+) -> Result<FixedSizeListArray> {
+    let (field, _) = FixedSizeListArray::try_child_and_size(array.data_type())?;
+    FixedSizeListArray::try_new(
+        tensor.data_type(field.data_type().clone()),
+        array.values().clone(),
+        array.validity().cloned(),
+    )
+}
+
+/// Returns `array`, an `arrow.fixed_shape_tensor`, as a [`FixedSizeListArray`] and its
+/// [`FixedShapeTensor`].
+/// # Errors
+/// Errors iff `array` is not a valid `arrow.fixed_shape_tensor`
+pub fn from_tensor(array: &FixedSizeListArray) -> Result<(FixedSizeListArray, FixedShapeTensor)> {
+    let tensor = FixedShapeTensor::try_from_data_type(array.data_type())?;
+    let array = FixedSizeListArray::try_new(
+        array.data_type().to_logical_type().clone(),
+        array.values().clone(),
+        array.validity().cloned(),
+    )?;
+    Ok((array, tensor))
+}
@@ -0,0 +1,150 @@
+//! Contains the registry of [`ExtensionType`]s.
+//!
+//! A [`DataType::Extension`] is, by default, handled as its storage type. Registering an
+//! [`ExtensionType`] with the same name allows declaring the storage types and metadata valid
+//! for the extension and how its values are formatted. Registered extensions are:
+//! * validated when reading schemas from `io::ipc` and `io::parquet`
+//! * validated when casting to them via `compute::cast`
+//! * formatted via [`ExtensionType::write_value`] by [`get_display`](crate::array::get_display)
+use alloc::sync::Arc;
+use core::fmt::Write;
+#[cfg(feature = "std")]
+use std::{collections::BTreeMap, string::String, sync::RwLock};
+
+use crate::array::Array;
+use crate::error::{Error, Result};
+
+use super::DataType;
+
+/// A custom type, identified by its name, that [`DataType::Extension`]s of the same name
+/// are checked against.
+/// # Example
+/// ```
+/// use std::sync::Arc;
+/// use arrow2::datatypes::{register_extension_type, validate_extension, DataType, ExtensionType};
+/// use arrow2::error::{Error, Result};
+///
+/// #[derive(Debug)]
+/// struct Uuid;
+///
+/// impl ExtensionType for Uuid {
+///     fn name(&self) -> &str {
+///         "example.uuid"
+///     }
+///
+///     fn validate(&self, storage: &DataType, _: Option<&str>) -> Result<()> {
+///         match storage {
+///             DataType::FixedSizeBinary(16) => Ok(()),
+///             _ => Err(Error::InvalidArgumentError("uuids are 16 bytes".to_string())),
+///         }
+///     }
+/// }
+///
+/// register_extension_type(Arc::new(Uuid));
+///
+/// let data_type = DataType::Extension("example.uuid".to_string(), Box::new(DataType::Int32), None);
+/// assert!(validate_extension(&data_type).is_err());
+/// ```
+pub trait ExtensionType: core::fmt::Debug + Send + Sync {
+    /// The name of this extension, i.e. the value of the `ARROW:extension:name` key
+    fn name(&self) -> &str;
+
+    /// Validates that `storage` and `metadata` of a [`DataType::Extension`] of this extension
+    /// are valid. Defaults to accepting any storage and metadata.
+    fn validate(&self, storage: &DataType, metadata: Option<&str>) -> Result<()> {
+        let _ = (storage, metadata);
+        Ok(())
+    }
+
+    /// Writes the (non-null) value at `index` of `array`, whose [`DataType`] is an extension
+    /// of this type, to `f`. Defaults to writing the value of its storage type.
+    fn write_value(&self, array: &dyn Array, index: usize, f: &mut dyn Write) -> core::fmt::Result {
+        let mut f = f;
+        crate::array::get_storage_value_display(array, "None")(&mut f, index)
+    }
+}
+
+#[cfg(feature = "std")]
+type Registry = BTreeMap<String, Arc<dyn ExtensionType>>;
+
+#[cfg(feature = "std")]
+static REGISTRY: RwLock<Registry> = RwLock::new(BTreeMap::new());
+
+#[cfg(feature = "std")]
+fn registry<R>(op: impl FnOnce(&mut Registry) -> R) -> R {
+    // the registry is always in a valid state, even if a thread panicked while holding the lock
+    let mut registry = REGISTRY.write().unwrap_or_else(|e| e.into_inner());
+    op(&mut registry)
+}
+
+/// Registers `extension` under its name, returning the extension previously registered
+/// under the same name, if any.
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub fn register_extension_type(
+    extension: Arc<dyn ExtensionType>,
+) -> Option<Arc<dyn ExtensionType>> {
+    registry(|registry| registry.insert(extension.name().to_string(), extension))
+}
+
+/// Unregisters the extension registered under `name`, returning it, if any.
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub fn unregister_extension_type(name: &str) -> Option<Arc<dyn ExtensionType>> {
+    registry(|registry| registry.remove(name))
+}
+
+/// Returns the extension registered under `name`, if any.
+#[cfg(feature = "std")]
+pub fn get_extension_type(name: &str) -> Option<Arc<dyn ExtensionType>> {
+    let registry = REGISTRY.read().unwrap_or_else(|e| e.into_inner());
+    registry.get(name).cloned()
+}
+
+/// Returns the extension registered under `name`, which is always `None` since extensions
+/// can only be registered with the `std` feature.
+#[cfg(not(feature = "std"))]
+pub fn get_extension_type(name: &str) -> Option<Arc<dyn ExtensionType>> {
+    let _ = name;
+    None
+}
+
+/// Returns the registered extension of `data_type`, if it is a [`DataType::Extension`]
+/// whose name is registered.
+pub fn get_registered_extension(data_type: &DataType) -> Option<Arc<dyn ExtensionType>> {
+    match data_type {
+        DataType::Extension(name, _, _) => get_extension_type(name),
+        _ => None,
+    }
+}
+
+/// Validates every registered extension in `data_type`, including in its children.
+/// Extensions that are not registered are valid.
+/// # Errors
+/// Errors iff [`ExtensionType::validate`] of a registered extension errors.
+pub fn validate_extension(data_type: &DataType) -> Result<()> {
+    use DataType::*;
+    match data_type {
+        Extension(name, storage, metadata) => {
+            if let Some(extension) = get_extension_type(name) {
+                extension
+                    .validate(storage, metadata.as_deref())
+                    .map_err(|e| match e {
+                        Error::InvalidArgumentError(e) => Error::InvalidArgumentError(format!(
+                            "Invalid extension type \"{name}\": {e}"
+                        )),
+                        e => e,
+                    })?;
+            }
+            validate_extension(storage)
+        }
+        List(field) | LargeList(field) | FixedSizeList(field, _) | Map(field, _) => {
+            validate_extension(field.data_type())
+        }
+        Struct(fields) | Union(fields, _, _) => fields
+            .iter()
+            .try_for_each(|field| validate_extension(field.data_type())),
+        Dictionary(_, values, _) => validate_extension(values),
+        _ => Ok(()),
+    }
+}
@@ -1,3 +1,5 @@
+use alloc::string::String;
+
 use super::{DataType, Metadata};
 
 #[cfg(feature = "serde_types")]
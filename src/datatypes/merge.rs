@@ -0,0 +1,171 @@
+use alloc::{boxed::Box, string::ToString, vec::Vec};
+
+use crate::error::{Error, Result};
+
+use super::{DataType, Field, Metadata, Schema};
+
+/// Returns the integer type with the given signedness and number of bits, if any.
+fn integer(signed: bool, bits: usize) -> Option<DataType> {
+    use DataType::*;
+    Some(match (signed, bits) {
+        (true, 8) => Int8,
+        (true, 16) => Int16,
+        (true, 32) => Int32,
+        (true, 64) => Int64,
+        (false, 8) => UInt8,
+        (false, 16) => UInt16,
+        (false, 32) => UInt32,
+        (false, 64) => UInt64,
+        _ => return None,
+    })
+}
+
+/// Returns whether the type is a signed integer and its number of bits
+fn integer_info(data_type: &DataType) -> Option<(bool, usize)> {
+    use DataType::*;
+    Some(match data_type {
+        Int8 => (true, 8),
+        Int16 => (true, 16),
+        Int32 => (true, 32),
+        Int64 => (true, 64),
+        UInt8 => (false, 8),
+        UInt16 => (false, 16),
+        UInt32 => (false, 32),
+        UInt64 => (false, 64),
+        _ => return None,
+    })
+}
+
+/// Returns the smallest integer type that can represent all values of `lhs` and `rhs`.
+fn merge_integers(lhs: (bool, usize), rhs: (bool, usize)) -> Option<DataType> {
+    match (lhs, rhs) {
+        ((true, lhs), (true, rhs)) => integer(true, lhs.max(rhs)),
+        ((false, lhs), (false, rhs)) => integer(false, lhs.max(rhs)),
+        ((true, signed), (false, unsigned)) | ((false, unsigned), (true, signed)) => {
+            // an unsigned integer of `n` bits requires a signed integer of `2n` bits
+            integer(true, signed.max(unsigned * 2))
+        }
+    }
+}
+
+/// Merges `rhs` into `lhs`, keeping the values of `lhs` on conflicting keys.
+fn merge_metadata(lhs: &Metadata, rhs: &Metadata) -> Metadata {
+    let mut metadata = rhs.clone();
+    metadata.extend(lhs.iter().map(|(k, v)| (k.clone(), v.clone())));
+    metadata
+}
+
+fn merge_field(lhs: &Field, rhs: &Field) -> Result<Field> {
+    let data_type = merge_data_type(&lhs.name, &lhs.data_type, &rhs.data_type)?;
+    Ok(Field::new(
+        &lhs.name,
+        data_type,
+        lhs.is_nullable
+            | rhs.is_nullable
+            | (lhs.data_type == DataType::Null)
+            | (rhs.data_type == DataType::Null),
+    )
+    .with_metadata(merge_metadata(&lhs.metadata, &rhs.metadata)))
+}
+
+/// Merges the fields of `rhs` into `lhs` by name. Fields that only exist on one of them
+/// become nullable.
+fn merge_fields(lhs: &[Field], rhs: &[Field]) -> Result<Vec<Field>> {
+    let mut fields = lhs
+        .iter()
+        .map(|field| match rhs.iter().find(|x| x.name == field.name) {
+            Some(other) => merge_field(field, other),
+            None => Ok(Field {
+                is_nullable: true,
+                ..field.clone()
+            }),
+        })
+        .collect::<Result<Vec<_>>>()?;
+    fields.extend(
+        rhs.iter()
+            .filter(|field| !lhs.iter().any(|x| x.name == field.name))
+            .map(|field| Field {
+                is_nullable: true,
+                ..field.clone()
+            }),
+    );
+    Ok(fields)
+}
+
+fn merge_data_type(name: &str, lhs: &DataType, rhs: &DataType) -> Result<DataType> {
+    use DataType::*;
+    if lhs == rhs {
+        return Ok(lhs.clone());
+    }
+    let merged = match (lhs, rhs) {
+        (Null, other) | (other, Null) => Some(other.clone()),
+        (Float32, Float64) | (Float64, Float32) => Some(Float64),
+        (Utf8, LargeUtf8) | (LargeUtf8, Utf8) => Some(LargeUtf8),
+        (Binary, LargeBinary) | (LargeBinary, Binary) => Some(LargeBinary),
+        (Struct(lhs), Struct(rhs)) => Some(Struct(merge_fields(lhs, rhs)?)),
+        (List(lhs), List(rhs)) => Some(List(Box::new(merge_field(lhs, rhs)?))),
+        (List(lhs), LargeList(rhs))
+        | (LargeList(lhs), List(rhs))
+        | (LargeList(lhs), LargeList(rhs)) => Some(LargeList(Box::new(merge_field(lhs, rhs)?))),
+        (FixedSizeList(lhs, lhs_size), FixedSizeList(rhs, rhs_size)) if lhs_size == rhs_size => {
+            Some(FixedSizeList(Box::new(merge_field(lhs, rhs)?), *lhs_size))
+        }
+        (Map(lhs, lhs_sorted), Map(rhs, rhs_sorted)) => Some(Map(
+            Box::new(merge_field(lhs, rhs)?),
+            *lhs_sorted && *rhs_sorted,
+        )),
+        _ => match (integer_info(lhs), integer_info(rhs)) {
+            (Some(lhs), Some(rhs)) => merge_integers(lhs, rhs),
+            _ => None,
+        },
+    };
+    merged.ok_or_else(|| {
+        Error::InvalidArgumentError(format!(
+            "The field \"{name}\" has incompatible data types {lhs:?} and {rhs:?}"
+        ))
+    })
+}
+
+/// Unifies `schemas` into a single [`Schema`] that can represent the data of all of them,
+/// e.g. to read a dataset whose files were written with different (evolving) schemas.
+///
+/// Fields are matched by name and are ordered by their first appearance. Two fields are merged as follows:
+/// * a field is nullable if it is nullable or [`DataType::Null`] on any schema, or missing on some schema
+/// * [`DataType::Null`] is merged into any other type
+/// * integers are widened to the smallest integer type that represents both (e.g. `Int8` and `UInt8` to `Int16`)
+/// * `Float32` is widened to `Float64`, and `Utf8`, `Binary` and `List` to their large counterparts
+/// * the fields of structs are merged recursively, as are the fields of lists and maps
+/// * the metadata of fields and of the schemas are merged, keeping the first value of repeated keys
+///
+/// # Errors
+/// This function errors iff `schemas` is empty, or two fields with the same name have
+/// data types that can't be merged (e.g. `Int64` and `UInt64` or `Utf8` and `Int32`).
+/// # Example
+/// ```
+/// use arrow2::datatypes::{merge_schemas, DataType, Field, Schema};
+///
+/// let schema1 = Schema::from(vec![Field::new("a", DataType::Int32, false)]);
+/// let schema2 = Schema::from(vec![
+///     Field::new("a", DataType::Int64, false),
+///     Field::new("b", DataType::Utf8, false),
+/// ]);
+/// let schema = merge_schemas(&[schema1, schema2]).unwrap();
+/// assert_eq!(
+///     schema,
+///     Schema::from(vec![
+///         Field::new("a", DataType::Int64, false),
+///         Field::new("b", DataType::Utf8, true),
+///     ])
+/// );
+/// ```
+pub fn merge_schemas(schemas: &[Schema]) -> Result<Schema> {
+    let (first, rest) = schemas.split_first().ok_or_else(|| {
+        Error::InvalidArgumentError("merge_schemas requires at least one schema".to_string())
+    })?;
+    rest.iter().try_fold(first.clone(), |merged, schema| {
+        Ok(Schema {
+            fields: merge_fields(&merged.fields, &schema.fields)?,
+            metadata: merge_metadata(&merged.metadata, &schema.metadata),
+        })
+    })
+}
@@ -1,16 +1,30 @@
 #![forbid(unsafe_code)]
 //! Contains all metadata, such as [`PhysicalType`], [`DataType`], [`Field`] and [`Schema`].
 
+#[cfg(feature = "proptest")]
+#[cfg_attr(docsrs, doc(cfg(feature = "proptest")))]
+pub mod arbitrary;
+#[cfg(feature = "canonical_extensions")]
+#[cfg_attr(docsrs, doc(cfg(feature = "canonical_extensions")))]
+pub mod canonical;
+mod extension;
 mod field;
+mod merge;
 mod physical_type;
 mod schema;
+mod text;
 
+pub use extension::{
+    get_extension_type, get_registered_extension, validate_extension, ExtensionType,
+};
+#[cfg(feature = "std")]
+pub use extension::{register_extension_type, unregister_extension_type};
 pub use field::Field;
+pub use merge::merge_schemas;
 pub use physical_type::*;
 pub use schema::Schema;
 
-use std::collections::BTreeMap;
-use std::sync::Arc;
+use alloc::{boxed::Box, collections::BTreeMap, string::String, sync::Arc, vec::Vec};
 
 #[cfg(feature = "serde_types")]
 use serde_derive::{Deserialize, Serialize};
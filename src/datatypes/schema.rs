@@ -1,3 +1,5 @@
+use alloc::vec::Vec;
+
 use super::{Field, Metadata};
 
 #[cfg(feature = "serde_types")]
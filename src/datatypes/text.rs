@@ -0,0 +1,627 @@
+//! A compact text format of [`DataType`], [`Field`] and [`Schema`], e.g.
+//! `a: int64 not null, b: list<struct<x: float64, y: utf8>>`, to describe schemas in
+//! configuration files and catalogs.
+//!
+//! The format is implemented via [`std::fmt::Display`] and [`std::str::FromStr`], and is
+//! stable: every type is written as
+//! * its name, e.g. `int32`, `utf8` or `large_binary`
+//! * followed by its child fields in angle brackets, e.g. `list<int32>` or
+//!   `struct<a: int32, b: utf8 not null>`
+//! * followed by its parameters in parentheses, e.g. `timestamp(us, "UTC")`, `decimal(10, 2)`
+//!   or `fixed_size_list<float32>(3)`
+//!
+//! Fields are written as `name: type`, followed by `not null` when they are not nullable.
+//! The names of the children of lists (`item`) and maps (`key` and `value`) are omitted when
+//! they are the default ones. Names that are not identifiers and strings are double-quoted.
+//!
+//! The metadata of fields and schemas are not part of the format.
+//! # Example
+//! ```
+//! use arrow2::datatypes::{DataType, Field, Schema, TimeUnit};
+//!
+//! let schema: Schema = "a: int64 not null, b: list<struct<x: timestamp(ms, \"UTC\")>>"
+//!     .parse()
+//!     .unwrap();
+//!
+//! let timestamp = DataType::Timestamp(TimeUnit::Millisecond, Some("UTC".to_string()));
+//! let item = DataType::Struct(vec![Field::new("x", timestamp, true)]);
+//! assert_eq!(
+//!     schema,
+//!     Schema::from(vec![
+//!         Field::new("a", DataType::Int64, false),
+//!         Field::new("b", DataType::List(Box::new(Field::new("item", item, true))), true),
+//!     ])
+//! );
+//! assert_eq!(
+//!     schema.to_string(),
+//!     "a: int64 not null, b: list<struct<x: timestamp(ms, \"UTC\")>>"
+//! );
+//! ```
+use alloc::{
+    boxed::Box,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::fmt::{Display, Formatter, Result as FmtResult};
+use core::str::FromStr;
+
+use crate::error::{Error, Result};
+
+use super::{DataType, Field, IntegerType, IntervalUnit, Schema, TimeUnit, UnionMode};
+
+fn is_identifier(name: &str) -> bool {
+    let mut chars = name.chars();
+    matches!(chars.next(), Some(c) if c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+fn write_string(f: &mut Formatter<'_>, value: &str) -> FmtResult {
+    f.write_str("\"")?;
+    for c in value.chars() {
+        if c == '"' || c == '\\' {
+            f.write_str("\\")?;
+        }
+        write!(f, "{c}")?;
+    }
+    f.write_str("\"")
+}
+
+fn write_name(f: &mut Formatter<'_>, name: &str) -> FmtResult {
+    if is_identifier(name) {
+        f.write_str(name)
+    } else {
+        write_string(f, name)
+    }
+}
+
+/// Writes `field`, omitting its name when it is `default_name`
+fn write_field(f: &mut Formatter<'_>, field: &Field, default_name: Option<&str>) -> FmtResult {
+    if default_name != Some(field.name.as_str()) {
+        write_name(f, &field.name)?;
+        f.write_str(": ")?;
+    }
+    write!(f, "{}", field.data_type)?;
+    if !field.is_nullable {
+        f.write_str(" not null")?;
+    }
+    Ok(())
+}
+
+fn write_fields(f: &mut Formatter<'_>, fields: &[Field]) -> FmtResult {
+    for (index, field) in fields.iter().enumerate() {
+        if index > 0 {
+            f.write_str(", ")?;
+        }
+        write_field(f, field, None)?;
+    }
+    Ok(())
+}
+
+fn time_unit(unit: TimeUnit) -> &'static str {
+    match unit {
+        TimeUnit::Second => "s",
+        TimeUnit::Millisecond => "ms",
+        TimeUnit::Microsecond => "us",
+        TimeUnit::Nanosecond => "ns",
+    }
+}
+
+fn interval_unit(unit: IntervalUnit) -> &'static str {
+    match unit {
+        IntervalUnit::YearMonth => "year_month",
+        IntervalUnit::DayTime => "day_time",
+        IntervalUnit::MonthDayNano => "month_day_nano",
+    }
+}
+
+impl Display for DataType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        use DataType::*;
+        match self {
+            Null => f.write_str("null"),
+            Boolean => f.write_str("boolean"),
+            Int8 => f.write_str("int8"),
+            Int16 => f.write_str("int16"),
+            Int32 => f.write_str("int32"),
+            Int64 => f.write_str("int64"),
+            UInt8 => f.write_str("uint8"),
+            UInt16 => f.write_str("uint16"),
+            UInt32 => f.write_str("uint32"),
+            UInt64 => f.write_str("uint64"),
+            Float16 => f.write_str("float16"),
+            Float32 => f.write_str("float32"),
+            Float64 => f.write_str("float64"),
+            Timestamp(unit, tz) => {
+                write!(f, "timestamp({}", time_unit(*unit))?;
+                if let Some(tz) = tz {
+                    f.write_str(", ")?;
+                    write_string(f, tz)?;
+                }
+                f.write_str(")")
+            }
+            Date32 => f.write_str("date32"),
+            Date64 => f.write_str("date64"),
+            Time32(unit) => write!(f, "time32({})", time_unit(*unit)),
+            Time64(unit) => write!(f, "time64({})", time_unit(*unit)),
+            Duration(unit) => write!(f, "duration({})", time_unit(*unit)),
+            Interval(unit) => write!(f, "interval({})", interval_unit(*unit)),
+            Binary => f.write_str("binary"),
+            FixedSizeBinary(size) => write!(f, "fixed_size_binary({size})"),
+            LargeBinary => f.write_str("large_binary"),
+            Utf8 => f.write_str("utf8"),
+            LargeUtf8 => f.write_str("large_utf8"),
+            List(field) => {
+                f.write_str("list<")?;
+                write_field(f, field, Some("item"))?;
+                f.write_str(">")
+            }
+            FixedSizeList(field, size) => {
+                f.write_str("fixed_size_list<")?;
+                write_field(f, field, Some("item"))?;
+                write!(f, ">({size})")
+            }
+            LargeList(field) => {
+                f.write_str("large_list<")?;
+                write_field(f, field, Some("item"))?;
+                f.write_str(">")
+            }
+            Struct(fields) => {
+                f.write_str("struct<")?;
+                write_fields(f, fields)?;
+                f.write_str(">")
+            }
+            Union(fields, ids, mode) => {
+                match mode {
+                    UnionMode::Dense => f.write_str("dense_union<")?,
+                    UnionMode::Sparse => f.write_str("sparse_union<")?,
+                }
+                write_fields(f, fields)?;
+                f.write_str(">")?;
+                if let Some(ids) = ids {
+                    f.write_str("(")?;
+                    for (index, id) in ids.iter().enumerate() {
+                        if index > 0 {
+                            f.write_str(", ")?;
+                        }
+                        write!(f, "{id}")?;
+                    }
+                    f.write_str(")")?;
+                }
+                Ok(())
+            }
+            Map(field, keys_sorted) => {
+                f.write_str("map<")?;
+                match field.data_type.to_logical_type() {
+                    Struct(fields) if fields.len() == 2 => {
+                        write_field(f, &fields[0], Some("key"))?;
+                        f.write_str(", ")?;
+                        write_field(f, &fields[1], Some("value"))?;
+                    }
+                    // not a valid map; written such that it fails to be parsed
+                    other => write!(f, "{other}")?,
+                }
+                f.write_str(">")?;
+                if *keys_sorted {
+                    f.write_str("(sorted)")?;
+                }
+                Ok(())
+            }
+            Dictionary(key, values, is_sorted) => {
+                write!(f, "dictionary<{}, {values}>", DataType::from(*key))?;
+                if *is_sorted {
+                    f.write_str("(sorted)")?;
+                }
+                Ok(())
+            }
+            Decimal(precision, scale) => write!(f, "decimal({precision}, {scale})"),
+            Decimal256(precision, scale) => write!(f, "decimal256({precision}, {scale})"),
+            Extension(name, inner, metadata) => {
+                write!(f, "extension<{inner}>(")?;
+                write_string(f, name)?;
+                if let Some(metadata) = metadata {
+                    f.write_str(", ")?;
+                    write_string(f, metadata)?;
+                }
+                f.write_str(")")
+            }
+        }
+    }
+}
+
+impl Display for Field {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write_field(f, self, None)
+    }
+}
+
+impl Display for Schema {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write_fields(f, &self.fields)
+    }
+}
+
+/// A recursive descent parser of the text format
+struct Parser<'a> {
+    text: &'a str,
+    position: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn new(text: &'a str) -> Self {
+        Self { text, position: 0 }
+    }
+
+    fn error(&self, message: &str) -> Error {
+        Error::InvalidArgumentError(format!(
+            "Invalid data type \"{}\" at position {}: {message}",
+            self.text, self.position
+        ))
+    }
+
+    fn rest(&self) -> &'a str {
+        &self.text[self.position..]
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = self.rest();
+        self.position += rest.len() - rest.trim_start().len();
+    }
+
+    /// Consumes `token` if it is next
+    fn eat(&mut self, token: char) -> bool {
+        self.skip_whitespace();
+        if self.rest().starts_with(token) {
+            self.position += token.len_utf8();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, token: char) -> Result<()> {
+        if self.eat(token) {
+            Ok(())
+        } else {
+            Err(self.error(&format!("expected '{token}'")))
+        }
+    }
+
+    fn peek_identifier(&mut self) -> Option<&'a str> {
+        self.skip_whitespace();
+        let rest = self.rest();
+        let end = rest
+            .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+            .unwrap_or(rest.len());
+        let identifier = &rest[..end];
+        is_identifier(identifier).then_some(identifier)
+    }
+
+    fn identifier(&mut self) -> Result<&'a str> {
+        let identifier = self
+            .peek_identifier()
+            .ok_or_else(|| self.error("expected an identifier"))?;
+        self.position += identifier.len();
+        Ok(identifier)
+    }
+
+    fn string(&mut self) -> Result<String> {
+        self.expect('"')?;
+        let mut value = String::new();
+        let mut chars = self.rest().chars();
+        loop {
+            match chars.next() {
+                Some('"') => break,
+                Some('\\') => match chars.next() {
+                    Some(c) => value.push(c),
+                    None => break,
+                },
+                Some(c) => value.push(c),
+                None => {
+                    self.position = self.text.len();
+                    return Err(self.error("unterminated string"));
+                }
+            }
+        }
+        self.position = self.text.len() - chars.as_str().len();
+        Ok(value)
+    }
+
+    fn integer<T: FromStr>(&mut self) -> Result<T> {
+        self.skip_whitespace();
+        let rest = self.rest();
+        let end = rest
+            .find(|c: char| !(c.is_ascii_digit() || c == '-'))
+            .unwrap_or(rest.len());
+        let value = rest[..end]
+            .parse()
+            .map_err(|_| self.error("expected an integer"))?;
+        self.position += end;
+        Ok(value)
+    }
+
+    fn time_unit(&mut self) -> Result<TimeUnit> {
+        Ok(match self.identifier()? {
+            "s" => TimeUnit::Second,
+            "ms" => TimeUnit::Millisecond,
+            "us" => TimeUnit::Microsecond,
+            "ns" => TimeUnit::Nanosecond,
+            _ => return Err(self.error("expected a time unit (s, ms, us or ns)")),
+        })
+    }
+
+    fn unit_parameter(&mut self) -> Result<TimeUnit> {
+        self.expect('(')?;
+        let unit = self.time_unit()?;
+        self.expect(')')?;
+        Ok(unit)
+    }
+
+    fn precision_scale(&mut self) -> Result<(usize, usize)> {
+        self.expect('(')?;
+        let precision = self.integer()?;
+        self.expect(',')?;
+        let scale = self.integer()?;
+        self.expect(')')?;
+        Ok((precision, scale))
+    }
+
+    fn sorted_parameter(&mut self) -> Result<bool> {
+        if !self.eat('(') {
+            return Ok(false);
+        }
+        if self.identifier()? != "sorted" {
+            return Err(self.error("expected \"sorted\""));
+        }
+        self.expect(')')?;
+        Ok(true)
+    }
+
+    /// Parses a field, whose name is `default_name` when it is omitted
+    fn field(&mut self, default_name: Option<&str>) -> Result<Field> {
+        let start = self.position;
+        let name = if self.eat('"') {
+            self.position -= 1;
+            Some(self.string()?)
+        } else {
+            self.peek_identifier().map(|name| {
+                self.position += name.len();
+                name.to_string()
+            })
+        };
+        let name = match (name, default_name) {
+            (Some(name), _) if self.eat(':') => name,
+            (_, Some(default_name)) => {
+                // the name was omitted: what was read was the type
+                self.position = start;
+                default_name.to_string()
+            }
+            (_, None) => return Err(self.error("expected a field name followed by ':'")),
+        };
+
+        let data_type = self.data_type()?;
+
+        let is_nullable = if self.peek_identifier() == Some("not") {
+            self.identifier()?;
+            if self.identifier()? != "null" {
+                return Err(self.error("expected \"not null\""));
+            }
+            false
+        } else {
+            true
+        };
+        Ok(Field::new(name, data_type, is_nullable))
+    }
+
+    fn fields(&mut self) -> Result<Vec<Field>> {
+        self.expect('<')?;
+        let mut fields = vec![];
+        if !self.eat('>') {
+            loop {
+                fields.push(self.field(None)?);
+                if self.eat('>') {
+                    break;
+                }
+                self.expect(',')?;
+            }
+        }
+        Ok(fields)
+    }
+
+    fn child(&mut self) -> Result<Box<Field>> {
+        self.expect('<')?;
+        let field = self.field(Some("item"))?;
+        self.expect('>')?;
+        Ok(Box::new(field))
+    }
+
+    fn data_type(&mut self) -> Result<DataType> {
+        use DataType::*;
+        let start = self.position;
+        Ok(match self.identifier()? {
+            "null" => Null,
+            "boolean" => Boolean,
+            "int8" => Int8,
+            "int16" => Int16,
+            "int32" => Int32,
+            "int64" => Int64,
+            "uint8" => UInt8,
+            "uint16" => UInt16,
+            "uint32" => UInt32,
+            "uint64" => UInt64,
+            "float16" => Float16,
+            "float32" => Float32,
+            "float64" => Float64,
+            "timestamp" => {
+                self.expect('(')?;
+                let unit = self.time_unit()?;
+                let tz = if self.eat(',') {
+                    Some(self.string()?)
+                } else {
+                    None
+                };
+                self.expect(')')?;
+                Timestamp(unit, tz)
+            }
+            "date32" => Date32,
+            "date64" => Date64,
+            "time32" => Time32(self.unit_parameter()?),
+            "time64" => Time64(self.unit_parameter()?),
+            "duration" => Duration(self.unit_parameter()?),
+            "interval" => {
+                self.expect('(')?;
+                let unit =
+                    match self.identifier()? {
+                        "year_month" => IntervalUnit::YearMonth,
+                        "day_time" => IntervalUnit::DayTime,
+                        "month_day_nano" => IntervalUnit::MonthDayNano,
+                        _ => return Err(self.error(
+                            "expected an interval unit (year_month, day_time or month_day_nano)",
+                        )),
+                    };
+                self.expect(')')?;
+                Interval(unit)
+            }
+            "binary" => Binary,
+            "fixed_size_binary" => {
+                self.expect('(')?;
+                let size = self.integer()?;
+                self.expect(')')?;
+                FixedSizeBinary(size)
+            }
+            "large_binary" => LargeBinary,
+            "utf8" => Utf8,
+            "large_utf8" => LargeUtf8,
+            "list" => List(self.child()?),
+            "fixed_size_list" => {
+                let field = self.child()?;
+                self.expect('(')?;
+                let size = self.integer()?;
+                self.expect(')')?;
+                FixedSizeList(field, size)
+            }
+            "large_list" => LargeList(self.child()?),
+            "struct" => Struct(self.fields()?),
+            name @ ("dense_union" | "sparse_union") => {
+                let mode = UnionMode::sparse(name == "sparse_union");
+                let fields = self.fields()?;
+                let ids = if self.eat('(') {
+                    let mut ids = vec![self.integer()?];
+                    while self.eat(',') {
+                        ids.push(self.integer()?);
+                    }
+                    self.expect(')')?;
+                    Some(ids)
+                } else {
+                    None
+                };
+                Union(fields, ids, mode)
+            }
+            "map" => {
+                self.expect('<')?;
+                let key = self.field(Some("key"))?;
+                self.expect(',')?;
+                let value = self.field(Some("value"))?;
+                self.expect('>')?;
+                let entries = Field::new("entries", Struct(vec![key, value]), false);
+                Map(Box::new(entries), self.sorted_parameter()?)
+            }
+            "dictionary" => {
+                self.expect('<')?;
+                let key = match self.data_type()? {
+                    Int8 => IntegerType::Int8,
+                    Int16 => IntegerType::Int16,
+                    Int32 => IntegerType::Int32,
+                    Int64 => IntegerType::Int64,
+                    UInt8 => IntegerType::UInt8,
+                    UInt16 => IntegerType::UInt16,
+                    UInt32 => IntegerType::UInt32,
+                    UInt64 => IntegerType::UInt64,
+                    _ => return Err(self.error("the keys of a dictionary must be integers")),
+                };
+                self.expect(',')?;
+                let values = self.data_type()?;
+                self.expect('>')?;
+                Dictionary(key, Box::new(values), self.sorted_parameter()?)
+            }
+            "decimal" => {
+                let (precision, scale) = self.precision_scale()?;
+                Decimal(precision, scale)
+            }
+            "decimal256" => {
+                let (precision, scale) = self.precision_scale()?;
+                Decimal256(precision, scale)
+            }
+            "extension" => {
+                self.expect('<')?;
+                let inner = self.data_type()?;
+                self.expect('>')?;
+                self.expect('(')?;
+                let name = self.string()?;
+                let metadata = if self.eat(',') {
+                    Some(self.string()?)
+                } else {
+                    None
+                };
+                self.expect(')')?;
+                Extension(name, Box::new(inner), metadata)
+            }
+            _ => {
+                self.position = start;
+                return Err(self.error("unknown data type"));
+            }
+        })
+    }
+
+    fn end(&mut self) -> Result<()> {
+        self.skip_whitespace();
+        if self.position == self.text.len() {
+            Ok(())
+        } else {
+            Err(self.error("unexpected trailing characters"))
+        }
+    }
+}
+
+impl FromStr for DataType {
+    type Err = Error;
+
+    /// Parses a [`DataType`] from the text format of [`std::fmt::Display`].
+    fn from_str(text: &str) -> Result<Self> {
+        let mut parser = Parser::new(text);
+        let data_type = parser.data_type()?;
+        parser.end()?;
+        Ok(data_type)
+    }
+}
+
+impl FromStr for Field {
+    type Err = Error;
+
+    /// Parses a [`Field`] from the text format of [`std::fmt::Display`], e.g. `a: int32 not null`.
+    fn from_str(text: &str) -> Result<Self> {
+        let mut parser = Parser::new(text);
+        let field = parser.field(None)?;
+        parser.end()?;
+        Ok(field)
+    }
+}
+
+impl FromStr for Schema {
+    type Err = Error;
+
+    /// Parses a [`Schema`] from the text format of [`std::fmt::Display`], i.e. a comma-separated
+    /// list of fields. The empty string is the empty schema.
+    fn from_str(text: &str) -> Result<Self> {
+        let mut parser = Parser::new(text);
+        let mut fields = vec![];
+        parser.skip_whitespace();
+        if parser.position < text.len() {
+            fields.push(parser.field(None)?);
+            while parser.eat(',') {
+                fields.push(parser.field(None)?);
+            }
+        }
+        parser.end()?;
+        Ok(fields.into())
+    }
+}
@@ -1,5 +1,67 @@
 //! Defines [`Error`], representing all errors returned by this crate.
-use std::fmt::{Debug, Display, Formatter};
+//!
+//! Every [`Error`] has an [`ErrorKind`], which is stable across versions and can be used to map
+//! errors to e.g. user-facing errors without matching on their messages. Errors can be wrapped
+//! with context via [`Error::context`] or [`ResultExt`], which records where the context was
+//! added and preserves the original error as the [`source`](std::error::Error::source).
+//! # Example
+//! ```
+//! use arrow2::error::{Error, ErrorKind, Result, ResultExt};
+//!
+//! fn read_footer() -> Result<()> {
+//!     Err(Error::OutOfSpec("the footer is too short".to_string()))
+//! }
+//!
+//! let error = read_footer().context("reading file.arrow").unwrap_err();
+//! assert_eq!(error.kind(), ErrorKind::OutOfSpec);
+//! assert_eq!(error.to_string(), "reading file.arrow: the footer is too short");
+//! assert!(matches!(error.root(), Error::OutOfSpec(_)));
+//! assert!(error.location().is_some());
+//! ```
+use alloc::{boxed::Box, string::String};
+use core::fmt::{Debug, Display, Formatter};
+use core::panic::Location;
+
+/// The kind of an [`Error`], independent of its message and context.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ErrorKind {
+    /// See [`Error::NotYetImplemented`]
+    NotYetImplemented,
+    /// See [`Error::External`]
+    External,
+    /// See [`Error::Io`]
+    Io,
+    /// See [`Error::InvalidArgumentError`]
+    InvalidArgument,
+    /// See [`Error::ExternalFormat`]
+    ExternalFormat,
+    /// See [`Error::Overflow`]
+    Overflow,
+    /// See [`Error::OutOfSpec`]
+    OutOfSpec,
+}
+
+impl ErrorKind {
+    /// A stable, machine-readable code of this kind (e.g. `"out_of_spec"`)
+    pub fn code(&self) -> &'static str {
+        match self {
+            ErrorKind::NotYetImplemented => "not_yet_implemented",
+            ErrorKind::External => "external",
+            ErrorKind::Io => "io",
+            ErrorKind::InvalidArgument => "invalid_argument",
+            ErrorKind::ExternalFormat => "external_format",
+            ErrorKind::Overflow => "overflow",
+            ErrorKind::OutOfSpec => "out_of_spec",
+        }
+    }
+}
+
+impl Display for ErrorKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.code())
+    }
+}
 
 /// Enum with all errors in this crate.
 #[derive(Debug)]
@@ -8,8 +70,13 @@ pub enum Error {
     /// Returned when functionality is not yet available.
     NotYetImplemented(String),
     /// Wrapper for an error triggered by a dependency
+    #[cfg(feature = "std")]
     External(String, Box<dyn std::error::Error + Send + Sync>),
+    /// Wrapper for an error triggered by a dependency
+    #[cfg(not(feature = "std"))]
+    External(String, Box<dyn ExternalError + Send + Sync>),
     /// Wrapper for IO errors
+    #[cfg(feature = "std")]
     Io(std::io::Error),
     /// When an invalid argument is passed to a function.
     InvalidArgumentError(String),
@@ -20,16 +87,82 @@ pub enum Error {
     Overflow,
     /// Whenever incoming data from the C data interface, IPC or Flight does not fulfil the Arrow specification.
     OutOfSpec(String),
+    /// An error with context on what was being done when it happened
+    Context {
+        /// what was being done
+        context: String,
+        /// where the context was added
+        location: &'static Location<'static>,
+        /// the error
+        source: Box<Error>,
+    },
 }
 
 impl Error {
     /// Wraps an external error in an `Error`.
+    #[cfg(feature = "std")]
     pub fn from_external_error(error: impl std::error::Error + Send + Sync + 'static) -> Self {
-        Self::External("".to_string(), Box::new(error))
+        Self::External(String::new(), Box::new(error))
+    }
+
+    /// Wraps an external error in an `Error`.
+    #[cfg(not(feature = "std"))]
+    pub fn from_external_error(error: impl ExternalError + Send + Sync + 'static) -> Self {
+        Self::External(String::new(), Box::new(error))
+    }
+
+    /// The [`ErrorKind`] of this error (of its [`Error::root`] if it has context).
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            Error::NotYetImplemented(_) => ErrorKind::NotYetImplemented,
+            Error::External(_, _) => ErrorKind::External,
+            #[cfg(feature = "std")]
+            Error::Io(_) => ErrorKind::Io,
+            Error::InvalidArgumentError(_) => ErrorKind::InvalidArgument,
+            Error::ExternalFormat(_) => ErrorKind::ExternalFormat,
+            Error::Overflow => ErrorKind::Overflow,
+            Error::OutOfSpec(_) => ErrorKind::OutOfSpec,
+            Error::Context { source, .. } => source.kind(),
+        }
+    }
+
+    /// Wraps this error with `context` on what was being done when it happened, recording the
+    /// location of the caller.
+    #[track_caller]
+    pub fn context<C: Into<String>>(self, context: C) -> Self {
+        Self::Context {
+            context: context.into(),
+            location: Location::caller(),
+            source: Box::new(self),
+        }
+    }
+
+    /// The error without its context, i.e. the error that originally happened.
+    pub fn root(&self) -> &Error {
+        match self {
+            Error::Context { source, .. } => source.root(),
+            other => other,
+        }
     }
 
+    /// The location where the outermost context was added to this error, if any.
+    pub fn location(&self) -> Option<&'static Location<'static>> {
+        match self {
+            Error::Context { location, .. } => Some(location),
+            _ => None,
+        }
+    }
+
+    /// An [`Error::OutOfSpec`] whose message records the location of the caller.
+    #[track_caller]
     pub(crate) fn oos<A: Into<String>>(msg: A) -> Self {
-        Self::OutOfSpec(msg.into())
+        let location = Location::caller();
+        Self::OutOfSpec(format!(
+            "{} (at {}:{})",
+            msg.into(),
+            location.file(),
+            location.line()
+        ))
     }
 
     #[allow(dead_code)]
@@ -38,38 +171,39 @@ impl Error {
     }
 }
 
-impl From<::std::io::Error> for Error {
+#[cfg(feature = "std")]
+impl From<std::io::Error> for Error {
     fn from(error: std::io::Error) -> Self {
         Error::Io(error)
     }
 }
 
-impl From<std::str::Utf8Error> for Error {
-    fn from(error: std::str::Utf8Error) -> Self {
-        Error::External("".to_string(), Box::new(error))
+impl From<core::str::Utf8Error> for Error {
+    fn from(error: core::str::Utf8Error) -> Self {
+        Error::External(String::new(), Box::new(error))
     }
 }
 
-impl From<std::string::FromUtf8Error> for Error {
-    fn from(error: std::string::FromUtf8Error) -> Self {
-        Error::External("".to_string(), Box::new(error))
+impl From<alloc::string::FromUtf8Error> for Error {
+    fn from(error: alloc::string::FromUtf8Error) -> Self {
+        Error::External(String::new(), Box::new(error))
     }
 }
 
 impl From<simdutf8::basic::Utf8Error> for Error {
     fn from(error: simdutf8::basic::Utf8Error) -> Self {
-        Error::External("".to_string(), Box::new(error))
+        Error::External(String::new(), Box::new(error))
     }
 }
 
-impl From<std::collections::TryReserveError> for Error {
-    fn from(_: std::collections::TryReserveError) -> Error {
+impl From<alloc::collections::TryReserveError> for Error {
+    fn from(_: alloc::collections::TryReserveError) -> Error {
         Error::Overflow
     }
 }
 
 impl Display for Error {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         match self {
             Error::NotYetImplemented(source) => {
                 write!(f, "Not yet implemented: {}", &source)
@@ -77,6 +211,7 @@ impl Display for Error {
             Error::External(message, source) => {
                 write!(f, "External error{}: {}", message, &source)
             }
+            #[cfg(feature = "std")]
             Error::Io(desc) => write!(f, "Io error: {desc}"),
             Error::InvalidArgumentError(desc) => {
                 write!(f, "Invalid argument error: {desc}")
@@ -90,11 +225,62 @@ impl Display for Error {
             Error::OutOfSpec(message) => {
                 write!(f, "{message}")
             }
+            Error::Context {
+                context, source, ..
+            } => {
+                write!(f, "{context}: {source}")
+            }
         }
     }
 }
 
-impl std::error::Error for Error {}
+#[cfg(feature = "std")]
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::External(_, source) => Some(source.as_ref()),
+            Error::Io(source) => Some(source),
+            Error::Context { source, .. } => Some(source.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+/// An error of a dependency, wrapped by [`Error::External`] when the standard library (and
+/// thus [`std::error::Error`]) is not available.
+#[cfg(not(feature = "std"))]
+pub trait ExternalError: Debug + Display {}
+
+#[cfg(not(feature = "std"))]
+impl<T: Debug + Display> ExternalError for T {}
+
+/// Extension of [`Result`] to add context to its error.
+pub trait ResultExt<T> {
+    /// Wraps the error, if any, with `context`. See [`Error::context`].
+    fn context<C: Into<String>>(self, context: C) -> Result<T>;
+
+    /// Wraps the error, if any, with the context returned by `f`, which is only called on
+    /// error. See [`Error::context`].
+    fn with_context<C: Into<String>, F: FnOnce() -> C>(self, f: F) -> Result<T>;
+}
+
+impl<T> ResultExt<T> for Result<T> {
+    #[track_caller]
+    fn context<C: Into<String>>(self, context: C) -> Result<T> {
+        match self {
+            Ok(value) => Ok(value),
+            Err(error) => Err(error.context(context)),
+        }
+    }
+
+    #[track_caller]
+    fn with_context<C: Into<String>, F: FnOnce() -> C>(self, f: F) -> Result<T> {
+        match self {
+            Ok(value) => Ok(value),
+            Err(error) => Err(error.context(f())),
+        }
+    }
+}
 
-/// Typedef for a [`std::result::Result`] of an [`Error`].
-pub type Result<T> = std::result::Result<T, Error>;
+/// Typedef for a [`core::result::Result`] of an [`Error`].
+pub type Result<T> = core::result::Result<T, Error>;
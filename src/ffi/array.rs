@@ -4,7 +4,7 @@ use std::sync::Arc;
 use crate::{
     array::*,
     bitmap::{utils::bytes_for, Bitmap},
-    buffer::{Buffer, Bytes},
+    buffer::{Buffer, Bytes, BytesAllocator},
     datatypes::{DataType, PhysicalType},
     error::{Error, Result},
     ffi::schema::get_child,
@@ -237,7 +237,7 @@ unsafe fn create_buffer<T: NativeType>(
 
     let len = buffer_len(array, data_type, index)?;
     let offset = buffer_offset(array, data_type, index);
-    let bytes = Bytes::from_foreign(ptr, len, owner);
+    let bytes = Bytes::from_foreign(ptr, len, BytesAllocator::InternalArrowArray(owner));
 
     Ok(Buffer::from_bytes(bytes).slice(offset, len - offset))
 }
@@ -258,7 +258,7 @@ unsafe fn create_bitmap(
     let len: usize = array.length.try_into().expect("length to fit in `usize`");
     let offset: usize = array.offset.try_into().expect("Offset to fit in `usize`");
     let bytes_len = bytes_for(offset + len);
-    let bytes = Bytes::from_foreign(ptr, bytes_len, owner);
+    let bytes = Bytes::from_foreign(ptr, bytes_len, BytesAllocator::InternalArrowArray(owner));
 
     Ok(Bitmap::from_bytes(bytes, offset + len).slice(offset, len))
 }
@@ -385,7 +385,7 @@ unsafe fn create_dictionary(
     data_type: &DataType,
     parent: InternalArrowArray,
 ) -> Result<Option<ArrowArrayChild<'static>>> {
-    if let DataType::Dictionary(_, values, _) = data_type {
+    if let DataType::Dictionary(_, values, _) = data_type.to_logical_type() {
         let data_type = values.as_ref().clone();
         // catch what we can
         if array.dictionary.is_null() {
@@ -0,0 +1,182 @@
+//! Support for Arrow's [C Device Data Interface](https://arrow.apache.org/docs/format/CDeviceDataInterface.html)
+use crate::array::Array;
+use crate::datatypes::DataType;
+use crate::error::{Error, Result};
+
+use super::{export_array_to_c, import_array_from_c, ArrowArray};
+
+/// The type of the device where the buffers of an [`ArrowDeviceArray`] are allocated,
+/// one of the `ARROW_DEVICE_*` constants.
+pub type ArrowDeviceType = i32;
+
+/// The buffers are allocated on the CPU
+pub const ARROW_DEVICE_CPU: ArrowDeviceType = 1;
+/// The buffers are allocated on a CUDA GPU
+pub const ARROW_DEVICE_CUDA: ArrowDeviceType = 2;
+/// The buffers are allocated on the CPU, pinned by CUDA
+pub const ARROW_DEVICE_CUDA_HOST: ArrowDeviceType = 3;
+/// The buffers are allocated by OpenCL
+pub const ARROW_DEVICE_OPENCL: ArrowDeviceType = 4;
+/// The buffers are allocated on a Vulkan GPU
+pub const ARROW_DEVICE_VULKAN: ArrowDeviceType = 7;
+/// The buffers are allocated on a Metal GPU
+pub const ARROW_DEVICE_METAL: ArrowDeviceType = 8;
+/// The buffers are allocated on a Verilog simulator buffer
+pub const ARROW_DEVICE_VPI: ArrowDeviceType = 9;
+/// The buffers are allocated on an AMD ROCm GPU
+pub const ARROW_DEVICE_ROCM: ArrowDeviceType = 10;
+/// The buffers are allocated on the CPU, pinned by ROCm
+pub const ARROW_DEVICE_ROCM_HOST: ArrowDeviceType = 11;
+/// The buffers are allocated on a device reserved for extensions
+pub const ARROW_DEVICE_EXT_DEV: ArrowDeviceType = 12;
+/// The buffers are allocated by CUDA managed (unified) memory
+pub const ARROW_DEVICE_CUDA_MANAGED: ArrowDeviceType = 13;
+/// The buffers are allocated by Intel's oneAPI
+pub const ARROW_DEVICE_ONEAPI: ArrowDeviceType = 14;
+/// The buffers are allocated by WebGPU
+pub const ARROW_DEVICE_WEBGPU: ArrowDeviceType = 15;
+/// The buffers are allocated on a Qualcomm Hexagon DSP
+pub const ARROW_DEVICE_HEXAGON: ArrowDeviceType = 16;
+
+/// ABI-compatible struct for [`ArrowDeviceArray`](https://arrow.apache.org/docs/format/CDeviceDataInterface.html#structure-definitions):
+/// an [`ArrowArray`] whose buffers are allocated on a device, such as a GPU.
+#[repr(C)]
+#[derive(Debug)]
+pub struct ArrowDeviceArray {
+    array: ArrowArray,
+    device_id: i64,
+    device_type: ArrowDeviceType,
+    sync_event: *mut ::std::os::raw::c_void,
+    reserved: [i64; 3],
+}
+
+// Sound for the same reasons as `ArrowArray`. The `sync_event` is only read by consumers.
+unsafe impl Send for ArrowDeviceArray {}
+unsafe impl Sync for ArrowDeviceArray {}
+
+impl ArrowDeviceArray {
+    /// Creates a new [`ArrowDeviceArray`] of `array`, whose buffers are allocated on the device
+    /// `device_id` of type `device_type`, and that can be accessed once `sync_event`
+    /// (e.g. a pointer to a `cudaEvent_t`) is triggered, or immediately when it is null.
+    pub fn new(
+        array: ArrowArray,
+        device_type: ArrowDeviceType,
+        device_id: i64,
+        sync_event: *mut ::std::os::raw::c_void,
+    ) -> Self {
+        Self {
+            array,
+            device_id,
+            device_type,
+            sync_event,
+            reserved: [0; 3],
+        }
+    }
+
+    /// creates an empty [`ArrowDeviceArray`], which can be used to import data into
+    pub fn empty() -> Self {
+        Self::new(
+            ArrowArray::empty(),
+            ARROW_DEVICE_CPU,
+            -1,
+            std::ptr::null_mut(),
+        )
+    }
+
+    /// The [`ArrowArray`] whose buffers are allocated on the device
+    pub fn array(&self) -> &ArrowArray {
+        &self.array
+    }
+
+    /// The type of the device that the buffers are allocated on
+    pub fn device_type(&self) -> ArrowDeviceType {
+        self.device_type
+    }
+
+    /// The id of the device that the buffers are allocated on, among the devices
+    /// of its type (`-1` for the CPU)
+    pub fn device_id(&self) -> i64 {
+        self.device_id
+    }
+
+    /// The event that must be synchronized on before the buffers are accessed,
+    /// or null when they can be accessed immediately
+    pub fn sync_event(&self) -> *mut ::std::os::raw::c_void {
+        self.sync_event
+    }
+
+    /// Returns the [`ArrowArray`], releasing the device-specific information
+    pub fn into_array(self) -> ArrowArray {
+        self.array
+    }
+
+    /// Whether the buffers can be read by the CPU once the [`ArrowDeviceArray::sync_event`]
+    /// is triggered.
+    pub fn is_cpu_accessible(&self) -> bool {
+        matches!(
+            self.device_type,
+            ARROW_DEVICE_CPU | ARROW_DEVICE_CUDA_HOST | ARROW_DEVICE_ROCM_HOST
+        )
+    }
+}
+
+/// Exports an [`Box<dyn Array>`] to the C device data interface, as an array on the CPU.
+pub fn export_array_to_c_device(array: Box<dyn Array>) -> ArrowDeviceArray {
+    ArrowDeviceArray::new(
+        export_array_to_c(array),
+        ARROW_DEVICE_CPU,
+        -1,
+        std::ptr::null_mut(),
+    )
+}
+
+/// Imports an [`Array`] from the C device data interface.
+/// # Errors
+/// Errors iff the buffers of `array` can't be read by the CPU (e.g. they are allocated on a GPU),
+/// or they can only be read once a sync event is triggered. Use
+/// [`import_device_array_from_c_with_copy`] to import such arrays.
+/// # Safety
+/// This function is intrinsically `unsafe` and relies on a [`ArrowDeviceArray`]
+/// being valid according to the [C device data interface](https://arrow.apache.org/docs/format/CDeviceDataInterface.html) (FFI).
+pub unsafe fn import_device_array_from_c(
+    array: ArrowDeviceArray,
+    data_type: DataType,
+) -> Result<Box<dyn Array>> {
+    if !array.is_cpu_accessible() {
+        return Err(Error::NotYetImplemented(format!(
+            "Importing arrays allocated on the device type {} requires copying them to the CPU",
+            array.device_type
+        )));
+    }
+    if !array.sync_event.is_null() {
+        return Err(Error::NotYetImplemented(
+            "Importing arrays with a sync event requires synchronizing on it".to_string(),
+        ));
+    }
+    import_array_from_c(array.array, data_type)
+}
+
+/// Imports an [`Array`] from the C device data interface, calling `copy_to_host` to copy arrays
+/// whose buffers can't be read by the CPU, or that have a sync event, to the CPU.
+///
+/// `copy_to_host` is typically implemented by the library that allocated the device array
+/// (e.g. via `cudaMemcpy` after synchronizing on the sync event), and owns the
+/// [`ArrowDeviceArray`], which is released when it is dropped.
+/// # Safety
+/// This function is intrinsically `unsafe` and relies on a [`ArrowDeviceArray`]
+/// being valid according to the [C device data interface](https://arrow.apache.org/docs/format/CDeviceDataInterface.html) (FFI),
+/// and on `copy_to_host` returning a valid [`ArrowArray`] of `data_type` on the CPU.
+pub unsafe fn import_device_array_from_c_with_copy<F>(
+    array: ArrowDeviceArray,
+    data_type: DataType,
+    copy_to_host: F,
+) -> Result<Box<dyn Array>>
+where
+    F: FnOnce(ArrowDeviceArray) -> Result<ArrowArray>,
+{
+    if array.is_cpu_accessible() && array.sync_event.is_null() {
+        import_array_from_c(array.array, data_type)
+    } else {
+        import_array_from_c(copy_to_host(array)?, data_type)
+    }
+}
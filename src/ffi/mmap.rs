@@ -1,3 +1,4 @@
+use std::any::Any;
 use std::collections::VecDeque;
 use std::sync::Arc;
 
@@ -9,6 +10,7 @@ use crate::offset::Offset;
 use crate::io::ipc::read::{Dictionaries, OutOfSpecKind};
 use crate::io::ipc::read::{IpcBuffer, Node};
 use crate::io::ipc::IpcField;
+use crate::mmap::CopiedBuffer;
 use crate::types::NativeType;
 
 use super::{export_array_to_c, try_from, ArrowArray, InternalArrowArray};
@@ -17,6 +19,8 @@ use super::{export_array_to_c, try_from, ArrowArray, InternalArrowArray};
 struct PrivateData<T> {
     // the owner of the pointers' regions
     data: T,
+    // the owners of the regions of buffers copied because they were not aligned in `data`
+    copies: Vec<Box<dyn Any>>,
     buffers_ptr: Box<[*const std::os::raw::c_void]>,
     children_ptr: Box<[*mut ArrowArray]>,
     dictionary_ptr: Option<*mut ArrowArray>,
@@ -40,12 +44,52 @@ fn get_buffer_bounds(buffers: &mut VecDeque<IpcBuffer>) -> Result<(usize, usize)
     Ok((offset, length))
 }
 
-fn get_buffer<'a, T: NativeType>(
-    data: &'a [u8],
+/// Describes what happens to buffers that are not aligned with their type in the mapped region
+pub(crate) struct Unaligned {
+    // whether they are copied to an aligned region (instead of erroring)
+    copy: bool,
+    // the buffers copied so far
+    copied: Vec<CopiedBuffer>,
+    // the copies of the array being mapped, that are not yet owned by an `ArrowArray`
+    copies: Vec<Box<dyn Any>>,
+}
+
+impl Unaligned {
+    /// Unaligned buffers error
+    pub fn error() -> Self {
+        Self {
+            copy: false,
+            copied: vec![],
+            copies: vec![],
+        }
+    }
+
+    /// Unaligned buffers are copied
+    pub fn copy() -> Self {
+        Self {
+            copy: true,
+            copied: vec![],
+            copies: vec![],
+        }
+    }
+
+    /// Returns the buffers copied so far
+    pub fn into_copied(self) -> Vec<CopiedBuffer> {
+        self.copied
+    }
+
+    fn take_copies(&mut self) -> Vec<Box<dyn Any>> {
+        std::mem::take(&mut self.copies)
+    }
+}
+
+fn get_buffer<T: NativeType>(
+    data: &[u8],
     block_offset: usize,
     buffers: &mut VecDeque<IpcBuffer>,
     num_rows: usize,
-) -> Result<&'a [u8], Error> {
+    unaligned: &mut Unaligned,
+) -> Result<*const u8, Error> {
     let (offset, length) = get_buffer_bounds(buffers)?;
 
     // verify that they are in-bounds
@@ -53,17 +97,36 @@ fn get_buffer<'a, T: NativeType>(
         .get(block_offset + offset..block_offset + offset + length)
         .ok_or_else(|| Error::OutOfSpec("buffer out of bounds".to_string()))?;
 
-    // validate alignment
-    let v: &[T] = bytemuck::try_cast_slice(values)
-        .map_err(|_| Error::OutOfSpec("buffer not aligned for mmap".to_string()))?;
-
-    if v.len() < num_rows {
+    if length % std::mem::size_of::<T>() != 0 {
+        return Err(Error::OutOfSpec(
+            "buffer's length is not a multiple of its type's size in mmap".to_string(),
+        ));
+    }
+    if length / std::mem::size_of::<T>() < num_rows {
         return Err(Error::OutOfSpec(
             "buffer's length is too small in mmap".to_string(),
         ));
     }
 
-    Ok(values)
+    // validate alignment
+    if bytemuck::try_cast_slice::<u8, T>(values).is_ok() {
+        return Ok(values.as_ptr());
+    }
+    if !unaligned.copy {
+        return Err(Error::OutOfSpec("buffer not aligned for mmap".to_string()));
+    }
+
+    let mut copy = vec![T::default(); length / std::mem::size_of::<T>()];
+    bytemuck::cast_slice_mut::<T, u8>(&mut copy).copy_from_slice(values);
+    let ptr = copy.as_ptr() as *const u8;
+
+    unaligned.copied.push(CopiedBuffer {
+        offset: block_offset + offset,
+        length,
+        alignment: std::mem::align_of::<T>(),
+    });
+    unaligned.copies.push(Box::new(copy));
+    Ok(ptr)
 }
 
 fn get_validity<'a>(
@@ -92,6 +155,7 @@ fn create_array<
     II: Iterator<Item = ArrowArray>,
 >(
     data: Arc<T>,
+    copies: Vec<Box<dyn Any>>,
     num_rows: usize,
     null_count: usize,
     buffers: I,
@@ -115,6 +179,7 @@ fn create_array<
 
     let mut private_data = Box::new(PrivateData::<Arc<T>> {
         data,
+        copies,
         buffers_ptr,
         children_ptr,
         dictionary_ptr,
@@ -159,6 +224,7 @@ fn mmap_binary<O: Offset, T: AsRef<[u8]>>(
     node: &Node,
     block_offset: usize,
     buffers: &mut VecDeque<IpcBuffer>,
+    unaligned: &mut Unaligned,
 ) -> Result<ArrowArray, Error> {
     let num_rows: usize = node
         .length()
@@ -174,12 +240,14 @@ fn mmap_binary<O: Offset, T: AsRef<[u8]>>(
 
     let validity = get_validity(data_ref, block_offset, buffers, null_count)?.map(|x| x.as_ptr());
 
-    let offsets = get_buffer::<O>(data_ref, block_offset, buffers, num_rows + 1)?.as_ptr();
-    let values = get_buffer::<u8>(data_ref, block_offset, buffers, 0)?.as_ptr();
+    let offsets = get_buffer::<O>(data_ref, block_offset, buffers, num_rows + 1, unaligned)?;
+    let values = get_buffer::<u8>(data_ref, block_offset, buffers, 0, unaligned)?;
+    let copies = unaligned.take_copies();
 
     // NOTE: offsets and values invariants are _not_ validated
     Ok(create_array(
         data,
+        copies,
         num_rows,
         null_count,
         [validity, Some(offsets), Some(values)].into_iter(),
@@ -193,6 +261,7 @@ fn mmap_fixed_size_binary<T: AsRef<[u8]>>(
     node: &Node,
     block_offset: usize,
     buffers: &mut VecDeque<IpcBuffer>,
+    unaligned: &mut Unaligned,
 ) -> Result<ArrowArray, Error> {
     let num_rows: usize = node
         .length()
@@ -208,10 +277,12 @@ fn mmap_fixed_size_binary<T: AsRef<[u8]>>(
 
     let validity = get_validity(data_ref, block_offset, buffers, null_count)?.map(|x| x.as_ptr());
 
-    let values = get_buffer::<u8>(data_ref, block_offset, buffers, num_rows + 1)?.as_ptr();
+    let values = get_buffer::<u8>(data_ref, block_offset, buffers, num_rows + 1, unaligned)?;
+    let copies = unaligned.take_copies();
 
     Ok(create_array(
         data,
+        copies,
         num_rows,
         null_count,
         [validity, Some(values)].into_iter(),
@@ -225,6 +296,7 @@ fn mmap_null<T: AsRef<[u8]>>(
     node: &Node,
     _block_offset: usize,
     _buffers: &mut VecDeque<IpcBuffer>,
+    _: &mut Unaligned,
 ) -> Result<ArrowArray, Error> {
     let num_rows: usize = node
         .length()
@@ -238,6 +310,7 @@ fn mmap_null<T: AsRef<[u8]>>(
 
     Ok(create_array(
         data,
+        vec![],
         num_rows,
         null_count,
         [].into_iter(),
@@ -251,6 +324,7 @@ fn mmap_boolean<T: AsRef<[u8]>>(
     node: &Node,
     block_offset: usize,
     buffers: &mut VecDeque<IpcBuffer>,
+    _: &mut Unaligned,
 ) -> Result<ArrowArray, Error> {
     let num_rows: usize = node
         .length()
@@ -274,6 +348,7 @@ fn mmap_boolean<T: AsRef<[u8]>>(
 
     Ok(create_array(
         data,
+        vec![],
         num_rows,
         null_count,
         [validity, Some(values)].into_iter(),
@@ -287,6 +362,7 @@ fn mmap_primitive<P: NativeType, T: AsRef<[u8]>>(
     node: &Node,
     block_offset: usize,
     buffers: &mut VecDeque<IpcBuffer>,
+    unaligned: &mut Unaligned,
 ) -> Result<ArrowArray, Error> {
     let data_ref = data.as_ref().as_ref();
 
@@ -302,10 +378,12 @@ fn mmap_primitive<P: NativeType, T: AsRef<[u8]>>(
 
     let validity = get_validity(data_ref, block_offset, buffers, null_count)?.map(|x| x.as_ptr());
 
-    let values = get_buffer::<P>(data_ref, block_offset, buffers, num_rows)?.as_ptr();
+    let values = get_buffer::<P>(data_ref, block_offset, buffers, num_rows, unaligned)?;
+    let copies = unaligned.take_copies();
 
     Ok(create_array(
         data,
+        copies,
         num_rows,
         null_count,
         [validity, Some(values)].into_iter(),
@@ -324,6 +402,7 @@ fn mmap_list<O: Offset, T: AsRef<[u8]>>(
     dictionaries: &Dictionaries,
     field_nodes: &mut VecDeque<Node>,
     buffers: &mut VecDeque<IpcBuffer>,
+    unaligned: &mut Unaligned,
 ) -> Result<ArrowArray, Error> {
     let child = ListArray::<O>::try_get_child(data_type)?.data_type();
 
@@ -341,7 +420,8 @@ fn mmap_list<O: Offset, T: AsRef<[u8]>>(
 
     let validity = get_validity(data_ref, block_offset, buffers, null_count)?.map(|x| x.as_ptr());
 
-    let offsets = get_buffer::<O>(data_ref, block_offset, buffers, num_rows + 1)?.as_ptr();
+    let offsets = get_buffer::<O>(data_ref, block_offset, buffers, num_rows + 1, unaligned)?;
+    let copies = unaligned.take_copies();
 
     let values = get_array(
         data.clone(),
@@ -351,11 +431,13 @@ fn mmap_list<O: Offset, T: AsRef<[u8]>>(
         dictionaries,
         field_nodes,
         buffers,
+        unaligned,
     )?;
 
     // NOTE: offsets and values invariants are _not_ validated
     Ok(create_array(
         data,
+        copies,
         num_rows,
         null_count,
         [validity, Some(offsets)].into_iter(),
@@ -374,6 +456,7 @@ fn mmap_fixed_size_list<T: AsRef<[u8]>>(
     dictionaries: &Dictionaries,
     field_nodes: &mut VecDeque<Node>,
     buffers: &mut VecDeque<IpcBuffer>,
+    unaligned: &mut Unaligned,
 ) -> Result<ArrowArray, Error> {
     let child = FixedSizeListArray::try_child_and_size(data_type)?
         .0
@@ -401,10 +484,12 @@ fn mmap_fixed_size_list<T: AsRef<[u8]>>(
         dictionaries,
         field_nodes,
         buffers,
+        unaligned,
     )?;
 
     Ok(create_array(
         data,
+        vec![],
         num_rows,
         null_count,
         [validity].into_iter(),
@@ -423,6 +508,7 @@ fn mmap_struct<T: AsRef<[u8]>>(
     dictionaries: &Dictionaries,
     field_nodes: &mut VecDeque<Node>,
     buffers: &mut VecDeque<IpcBuffer>,
+    unaligned: &mut Unaligned,
 ) -> Result<ArrowArray, Error> {
     let children = StructArray::try_get_fields(data_type)?;
 
@@ -453,12 +539,14 @@ fn mmap_struct<T: AsRef<[u8]>>(
                 dictionaries,
                 field_nodes,
                 buffers,
+                unaligned,
             )
         })
         .collect::<Result<Vec<_>, Error>>()?;
 
     Ok(create_array(
         data,
+        vec![],
         num_rows,
         null_count,
         [validity].into_iter(),
@@ -477,6 +565,7 @@ fn mmap_dict<K: DictionaryKey, T: AsRef<[u8]>>(
     dictionaries: &Dictionaries,
     _: &mut VecDeque<Node>,
     buffers: &mut VecDeque<IpcBuffer>,
+    unaligned: &mut Unaligned,
 ) -> Result<ArrowArray, Error> {
     let num_rows: usize = node
         .length()
@@ -497,10 +586,12 @@ fn mmap_dict<K: DictionaryKey, T: AsRef<[u8]>>(
 
     let validity = get_validity(data_ref, block_offset, buffers, null_count)?.map(|x| x.as_ptr());
 
-    let values = get_buffer::<K>(data_ref, block_offset, buffers, num_rows)?.as_ptr();
+    let values = get_buffer::<K>(data_ref, block_offset, buffers, num_rows, unaligned)?;
+    let copies = unaligned.take_copies();
 
     Ok(create_array(
         data,
+        copies,
         num_rows,
         null_count,
         [validity, Some(values)].into_iter(),
@@ -509,6 +600,7 @@ fn mmap_dict<K: DictionaryKey, T: AsRef<[u8]>>(
     ))
 }
 
+#[allow(clippy::too_many_arguments)]
 fn get_array<T: AsRef<[u8]>>(
     data: Arc<T>,
     block_offset: usize,
@@ -517,6 +609,7 @@ fn get_array<T: AsRef<[u8]>>(
     dictionaries: &Dictionaries,
     field_nodes: &mut VecDeque<Node>,
     buffers: &mut VecDeque<IpcBuffer>,
+    unaligned: &mut Unaligned,
 ) -> Result<ArrowArray, Error> {
     use crate::datatypes::PhysicalType::*;
     let node = field_nodes
@@ -524,14 +617,16 @@ fn get_array<T: AsRef<[u8]>>(
         .ok_or_else(|| Error::from(OutOfSpecKind::ExpectedBuffer))?;
 
     match data_type.to_physical_type() {
-        Null => mmap_null(data, &node, block_offset, buffers),
-        Boolean => mmap_boolean(data, &node, block_offset, buffers),
+        Null => mmap_null(data, &node, block_offset, buffers, unaligned),
+        Boolean => mmap_boolean(data, &node, block_offset, buffers, unaligned),
         Primitive(p) => with_match_primitive_type!(p, |$T| {
-            mmap_primitive::<$T, _>(data, &node, block_offset, buffers)
+            mmap_primitive::<$T, _>(data, &node, block_offset, buffers, unaligned)
         }),
-        Utf8 | Binary => mmap_binary::<i32, _>(data, &node, block_offset, buffers),
-        FixedSizeBinary => mmap_fixed_size_binary(data, &node, block_offset, buffers),
-        LargeBinary | LargeUtf8 => mmap_binary::<i64, _>(data, &node, block_offset, buffers),
+        Utf8 | Binary => mmap_binary::<i32, _>(data, &node, block_offset, buffers, unaligned),
+        FixedSizeBinary => mmap_fixed_size_binary(data, &node, block_offset, buffers, unaligned),
+        LargeBinary | LargeUtf8 => {
+            mmap_binary::<i64, _>(data, &node, block_offset, buffers, unaligned)
+        }
         List => mmap_list::<i32, _>(
             data,
             &node,
@@ -541,6 +636,7 @@ fn get_array<T: AsRef<[u8]>>(
             dictionaries,
             field_nodes,
             buffers,
+            unaligned,
         ),
         LargeList => mmap_list::<i64, _>(
             data,
@@ -551,6 +647,7 @@ fn get_array<T: AsRef<[u8]>>(
             dictionaries,
             field_nodes,
             buffers,
+            unaligned,
         ),
         FixedSizeList => mmap_fixed_size_list(
             data,
@@ -561,6 +658,7 @@ fn get_array<T: AsRef<[u8]>>(
             dictionaries,
             field_nodes,
             buffers,
+            unaligned,
         ),
         Struct => mmap_struct(
             data,
@@ -571,6 +669,7 @@ fn get_array<T: AsRef<[u8]>>(
             dictionaries,
             field_nodes,
             buffers,
+            unaligned,
         ),
         Dictionary(key_type) => match_integer_type!(key_type, |$T| {
             mmap_dict::<$T, _>(
@@ -582,6 +681,7 @@ fn get_array<T: AsRef<[u8]>>(
                 dictionaries,
                 field_nodes,
                 buffers,
+                unaligned,
             )
         }),
         _ => todo!(),
@@ -589,6 +689,7 @@ fn get_array<T: AsRef<[u8]>>(
 }
 
 /// Maps a memory region to an [`Array`].
+#[allow(clippy::too_many_arguments)]
 pub(crate) unsafe fn mmap<T: AsRef<[u8]>>(
     data: Arc<T>,
     block_offset: usize,
@@ -597,6 +698,7 @@ pub(crate) unsafe fn mmap<T: AsRef<[u8]>>(
     dictionaries: &Dictionaries,
     field_nodes: &mut VecDeque<Node>,
     buffers: &mut VecDeque<IpcBuffer>,
+    unaligned: &mut Unaligned,
 ) -> Result<Box<dyn Array>, Error> {
     let array = get_array(
         data,
@@ -606,6 +708,7 @@ pub(crate) unsafe fn mmap<T: AsRef<[u8]>>(
         dictionaries,
         field_nodes,
         buffers,
+        unaligned,
     )?;
     // The unsafety comes from the fact that `array` is not necessarily valid -
     // the IPC file may be corrupted (e.g. invalid offsets or non-utf8 data)
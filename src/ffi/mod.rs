@@ -1,7 +1,9 @@
 //! contains FFI bindings to import and export [`Array`](crate::array::Array) via
 //! Arrow's [C Data Interface](https://arrow.apache.org/docs/format/CDataInterface.html)
+//! and [C Device Data Interface](https://arrow.apache.org/docs/format/CDeviceDataInterface.html)
 mod array;
 mod bridge;
+pub mod device;
 mod generated;
 #[cfg(feature = "io_ipc")]
 #[cfg_attr(docsrs, doc(cfg(feature = "io_ipc")))]
@@ -18,6 +20,10 @@ use crate::error::Result;
 
 use self::schema::to_field;
 
+pub use device::{
+    export_array_to_c_device, import_device_array_from_c, import_device_array_from_c_with_copy,
+    ArrowDeviceArray,
+};
 pub use generated::{ArrowArray, ArrowArrayStream, ArrowSchema};
 pub use stream::{export_iterator, ArrowArrayStreamReader};
 
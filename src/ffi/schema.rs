@@ -68,7 +68,11 @@ impl ArrowSchema {
         let children_ptr = schema_children(field.data_type(), &mut flags);
         let n_children = children_ptr.len() as i64;
 
-        let dictionary = if let DataType::Dictionary(_, values, is_ordered) = field.data_type() {
+        // the dictionary of an extension type whose storage is a dictionary is exported as
+        // the dictionary of the field, and the extension as its metadata.
+        let dictionary = if let DataType::Dictionary(_, values, is_ordered) =
+            field.data_type().to_logical_type()
+        {
             flags += *is_ordered as i64;
             // we do not store field info in the dict values, so can't recover it all :(
             let field = Field::new("", values.as_ref().clone(), true);
@@ -77,28 +81,7 @@ impl ArrowSchema {
             None
         };
 
-        let metadata = &field.metadata;
-
-        let metadata = if let DataType::Extension(name, _, extension_metadata) = field.data_type() {
-            // append extension information.
-            let mut metadata = metadata.clone();
-
-            // metadata
-            if let Some(extension_metadata) = extension_metadata {
-                metadata.insert(
-                    "ARROW:extension:metadata".to_string(),
-                    extension_metadata.clone(),
-                );
-            }
-
-            metadata.insert("ARROW:extension:name".to_string(), name.clone());
-
-            Some(metadata_to_bytes(&metadata))
-        } else if !metadata.is_empty() {
-            Some(metadata_to_bytes(metadata))
-        } else {
-            None
-        };
+        let metadata = encode_metadata(field);
 
         let name = CString::new(name).unwrap();
         let format = CString::new(format).unwrap();
@@ -197,7 +180,7 @@ pub(crate) unsafe fn to_field(schema: &ArrowSchema) -> Result<Field> {
     } else {
         to_data_type(schema)?
     };
-    let (metadata, extension) = unsafe { metadata_from_bytes(schema.metadata) };
+    let (metadata, extension) = unsafe { metadata_from_bytes(schema.metadata)? };
 
     let data_type = if let Some((name, extension_metadata)) = extension {
         DataType::Extension(name, Box::new(data_type), extension_metadata)
@@ -477,6 +460,30 @@ pub(super) fn get_child(data_type: &DataType, index: usize) -> Result<DataType>
     }
 }
 
+/// Returns the metadata of `field` encoded as in the C data interface, including the
+/// `ARROW:extension:name` and `ARROW:extension:metadata` keys of extension types.
+/// These keys in the metadata of `field` are ignored, since they are derived from its [`DataType`].
+fn encode_metadata(field: &Field) -> Option<Vec<u8>> {
+    let mut metadata = field
+        .metadata
+        .iter()
+        .filter(|(key, _)| *key != "ARROW:extension:name" && *key != "ARROW:extension:metadata")
+        .map(|(key, value)| (key.clone(), value.clone()))
+        .collect::<BTreeMap<_, _>>();
+
+    if let DataType::Extension(name, _, extension_metadata) = field.data_type() {
+        if let Some(extension_metadata) = extension_metadata {
+            metadata.insert(
+                "ARROW:extension:metadata".to_string(),
+                extension_metadata.clone(),
+            );
+        }
+        metadata.insert("ARROW:extension:name".to_string(), name.clone());
+    }
+
+    (!metadata.is_empty()).then(|| metadata_to_bytes(&metadata))
+}
+
 fn metadata_to_bytes(metadata: &BTreeMap<String, String>) -> Vec<u8> {
     let a = (metadata.len() as i32).to_ne_bytes().to_vec();
     metadata.iter().fold(a, |mut acc, (key, value)| {
@@ -493,30 +500,42 @@ unsafe fn read_ne_i32(ptr: *const u8) -> i32 {
     i32::from_ne_bytes(slice.try_into().unwrap())
 }
 
-unsafe fn read_bytes(ptr: *const u8, len: usize) -> &'static str {
+unsafe fn read_length(ptr: *const u8) -> Result<usize> {
+    read_ne_i32(ptr)
+        .try_into()
+        .map_err(|_| Error::OutOfSpec("The metadata of a schema has a negative length".to_string()))
+}
+
+unsafe fn read_bytes(ptr: *const u8, len: usize) -> Result<&'static str> {
     let slice = std::slice::from_raw_parts(ptr, len);
-    simdutf8::basic::from_utf8(slice).unwrap()
+    simdutf8::basic::from_utf8(slice).map_err(|_| {
+        Error::NotYetImplemented(
+            "Metadata of a schema with non-utf8 keys or values is not supported".to_string(),
+        )
+    })
 }
 
-unsafe fn metadata_from_bytes(data: *const ::std::os::raw::c_char) -> (Metadata, Extension) {
+unsafe fn metadata_from_bytes(
+    data: *const ::std::os::raw::c_char,
+) -> Result<(Metadata, Extension)> {
     let mut data = data as *const u8; // u8 = i8
     if data.is_null() {
-        return (Metadata::default(), None);
+        return Ok((Metadata::default(), None));
     };
-    let len = read_ne_i32(data);
+    let len = read_length(data)?;
     data = data.add(4);
 
     let mut result = BTreeMap::new();
     let mut extension_name = None;
     let mut extension_metadata = None;
     for _ in 0..len {
-        let key_len = read_ne_i32(data) as usize;
+        let key_len = read_length(data)?;
         data = data.add(4);
-        let key = read_bytes(data, key_len);
+        let key = read_bytes(data, key_len)?;
         data = data.add(key_len);
-        let value_len = read_ne_i32(data) as usize;
+        let value_len = read_length(data)?;
         data = data.add(4);
-        let value = read_bytes(data, value_len);
+        let value = read_bytes(data, value_len)?;
         data = data.add(value_len);
         match key {
             "ARROW:extension:name" => {
@@ -531,7 +550,7 @@ unsafe fn metadata_from_bytes(data: *const ::std::os::raw::c_char) -> (Metadata,
         };
     }
     let extension = extension_name.map(|name| (name, extension_metadata));
-    (result, extension)
+    Ok((result, extension))
 }
 
 #[cfg(test)]
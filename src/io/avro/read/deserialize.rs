@@ -331,7 +331,18 @@ fn deserialize_value<'a>(
     Ok(block)
 }
 
-fn skip_item<'a>(field: &Field, avro_field: &AvroSchema, mut block: &'a [u8]) -> Result<&'a [u8]> {
+/// Returns `block` without its first `length` bytes
+fn advance(block: &[u8], length: usize) -> Result<&[u8]> {
+    block
+        .get(length..)
+        .ok_or_else(|| Error::ExternalFormat("Avro data is shorter than its schema".to_string()))
+}
+
+pub(super) fn skip_item<'a>(
+    field: &Field,
+    avro_field: &AvroSchema,
+    mut block: &'a [u8],
+) -> Result<&'a [u8]> {
     if field.is_nullable {
         let variant = util::zigzag_i64(&mut block)?;
         let is_null_first = is_union_null_first(avro_field);
@@ -377,7 +388,7 @@ fn skip_item<'a>(field: &Field, avro_field: &AvroSchema, mut block: &'a [u8]) ->
                 }
 
                 if let Some(bytes) = bytes {
-                    block = &block[bytes..];
+                    block = advance(block, bytes)?;
                 } else {
                     for _ in 0..len {
                         block = skip_item(inner, avro_inner, block)?;
@@ -402,8 +413,7 @@ fn skip_item<'a>(field: &Field, avro_field: &AvroSchema, mut block: &'a [u8]) ->
         }
         _ => match field.data_type.to_physical_type() {
             PhysicalType::Boolean => {
-                let _ = block[0] == 1;
-                block = &block[1..];
+                block = advance(block, 1)?;
             }
             PhysicalType::Primitive(primitive) => match primitive {
                 PrimitiveType::Int32 => {
@@ -413,13 +423,13 @@ fn skip_item<'a>(field: &Field, avro_field: &AvroSchema, mut block: &'a [u8]) ->
                     let _ = util::zigzag_i64(&mut block)?;
                 }
                 PrimitiveType::Float32 => {
-                    block = &block[std::mem::size_of::<f32>()..];
+                    block = advance(block, std::mem::size_of::<f32>())?;
                 }
                 PrimitiveType::Float64 => {
-                    block = &block[std::mem::size_of::<f64>()..];
+                    block = advance(block, std::mem::size_of::<f64>())?;
                 }
                 PrimitiveType::MonthDayNano => {
-                    block = &block[12..];
+                    block = advance(block, 12)?;
                 }
                 PrimitiveType::Int128 => {
                     let avro_inner = match avro_field {
@@ -441,7 +451,7 @@ fn skip_item<'a>(field: &Field, avro_field: &AvroSchema, mut block: &'a [u8]) ->
                         AvroSchema::Fixed(b) => b.size,
                         _ => unreachable!(),
                     };
-                    block = &block[len..];
+                    block = advance(block, len)?;
                 }
                 _ => unreachable!(),
             },
@@ -451,7 +461,7 @@ fn skip_item<'a>(field: &Field, avro_field: &AvroSchema, mut block: &'a [u8]) ->
                         "Avro format contains a non-usize number of bytes".to_string(),
                     )
                 })?;
-                block = &block[len..];
+                block = advance(block, len)?;
             }
             PhysicalType::FixedSizeBinary => {
                 let len = if let DataType::FixedSizeBinary(len) = &field.data_type {
@@ -460,7 +470,7 @@ fn skip_item<'a>(field: &Field, avro_field: &AvroSchema, mut block: &'a [u8]) ->
                     unreachable!()
                 };
 
-                block = &block[len..];
+                block = advance(block, len)?;
             }
             PhysicalType::Dictionary(_) => {
                 let _ = util::zigzag_i64(&mut block)? as i32;
@@ -9,10 +9,14 @@ use avro_schema::schema::Field as AvroField;
 mod deserialize;
 pub use deserialize::deserialize;
 mod nested;
+mod resolution;
 mod schema;
+mod single;
 mod util;
 
+pub use resolution::SchemaResolution;
 pub use schema::infer_schema;
+pub use single::{read_confluent_header, SingleDecoder, CONFLUENT_MAGIC_BYTE};
 
 use crate::array::Array;
 use crate::chunk::Chunk;
@@ -25,6 +29,7 @@ pub struct Reader<R: Read> {
     avro_fields: Vec<AvroField>,
     fields: Vec<Field>,
     projection: Vec<bool>,
+    resolution: Option<SchemaResolution>,
 }
 
 impl<R: Read> Reader<R> {
@@ -42,6 +47,23 @@ impl<R: Read> Reader<R> {
             avro_fields: metadata.record.fields,
             fields,
             projection,
+            resolution: None,
+        }
+    }
+
+    /// Creates a new [`Reader`] of data written with the schema in `metadata` that
+    /// deserializes it into the reader's schema of `resolution`.
+    pub fn with_resolution(
+        reader: R,
+        metadata: FileMetadata,
+        resolution: SchemaResolution,
+    ) -> Self {
+        Self {
+            iter: block_iterator(reader, metadata.compression, metadata.marker),
+            avro_fields: metadata.record.fields,
+            fields: vec![],
+            projection: vec![],
+            resolution: Some(resolution),
         }
     }
 
@@ -58,10 +80,14 @@ impl<R: Read> Iterator for Reader<R> {
         let fields = &self.fields[..];
         let avro_fields = &self.avro_fields;
         let projection = &self.projection;
+        let resolution = self.resolution.as_ref();
 
-        self.iter
-            .next()
-            .transpose()
-            .map(|maybe_block| deserialize(maybe_block?, fields, avro_fields, projection))
+        self.iter.next().transpose().map(|maybe_block| {
+            let block = maybe_block?;
+            match resolution {
+                Some(resolution) => resolution.deserialize(block),
+                None => deserialize(block, fields, avro_fields, projection),
+            }
+        })
     }
 }
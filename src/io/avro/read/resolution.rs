@@ -0,0 +1,295 @@
+use std::collections::HashMap;
+
+use avro_schema::file::Block;
+use avro_schema::schema::{Field as AvroField, Record};
+use num_traits::AsPrimitive;
+
+use crate::array::growable::make_growable;
+use crate::array::{
+    new_null_array, Array, BinaryArray, ListArray, PrimitiveArray, StructArray, Utf8Array,
+};
+use crate::chunk::Chunk;
+use crate::datatypes::{DataType, Field};
+use crate::error::{Error, Result};
+use crate::types::NativeType;
+
+use super::{deserialize, infer_schema};
+
+/// How a field of the reader's schema is resolved from the writer's field
+#[derive(Debug, Clone)]
+enum Plan {
+    /// the writer's array is the reader's array
+    Identity,
+    /// the writer's array is promoted to the reader's [`DataType`]
+    Promote(DataType),
+    /// the children of the reader's struct are resolved from the children of the writer's
+    Struct(DataType, Vec<Child>),
+    /// the values of the reader's list are resolved from the values of the writer's
+    List(DataType, Box<Plan>),
+}
+
+/// How a child of the reader's schema is obtained
+#[derive(Debug, Clone)]
+enum Child {
+    /// resolved from the child of the writer with this index
+    Writer(usize, Plan),
+    /// a default value (an array of length 1) repeated for every row
+    Default(Box<dyn Array>),
+}
+
+fn is_promotable(writer: &DataType, reader: &DataType) -> bool {
+    use DataType::*;
+    matches!(
+        (writer, reader),
+        (Int32, Int64 | Float32 | Float64)
+            | (Int64, Float32 | Float64)
+            | (Float32, Float64)
+            | (Utf8, Binary)
+            | (Binary, Utf8)
+    )
+}
+
+fn resolve_children(
+    writer: &[Field],
+    reader: &[Field],
+    path: &str,
+    defaults: &HashMap<String, Box<dyn Array>>,
+) -> Result<Vec<Child>> {
+    reader
+        .iter()
+        .map(|reader| {
+            let path = if path.is_empty() {
+                reader.name.clone()
+            } else {
+                format!("{path}.{}", reader.name)
+            };
+            if let Some(index) = writer.iter().position(|f| f.name == reader.name) {
+                let plan = resolve_field(&writer[index], reader, &path, defaults)?;
+                return Ok(Child::Writer(index, plan));
+            }
+            if let Some(default) = defaults.get(&path) {
+                if default.len() != 1 || default.data_type() != reader.data_type() {
+                    return Err(Error::InvalidArgumentError(format!(
+                        "The default of the field \"{path}\" must be an array of length 1 and type {:?}",
+                        reader.data_type()
+                    )));
+                }
+                Ok(Child::Default(default.clone()))
+            } else if reader.is_nullable {
+                Ok(Child::Default(new_null_array(reader.data_type().clone(), 1)))
+            } else {
+                Err(Error::InvalidArgumentError(format!(
+                    "The field \"{path}\" of the reader's schema is not in the writer's schema, and has no default"
+                )))
+            }
+        })
+        .collect()
+}
+
+fn resolve_field(
+    writer: &Field,
+    reader: &Field,
+    path: &str,
+    defaults: &HashMap<String, Box<dyn Array>>,
+) -> Result<Plan> {
+    match (writer.data_type(), reader.data_type()) {
+        (w, r) if w == r => Ok(Plan::Identity),
+        (w, r) if is_promotable(w, r) => Ok(Plan::Promote(r.clone())),
+        (DataType::Struct(w), DataType::Struct(r)) => Ok(Plan::Struct(
+            reader.data_type().clone(),
+            resolve_children(w, r, path, defaults)?,
+        )),
+        (DataType::List(w), DataType::List(r)) => Ok(Plan::List(
+            reader.data_type().clone(),
+            Box::new(resolve_field(w, r, path, defaults)?),
+        )),
+        (w, r) => Err(Error::InvalidArgumentError(format!(
+            "The field \"{path}\" of type {w:?} in the writer's schema can't be read as {r:?}"
+        ))),
+    }
+}
+
+fn promote_primitive<I, O>(array: &dyn Array, data_type: &DataType) -> Box<dyn Array>
+where
+    I: NativeType + AsPrimitive<O>,
+    O: NativeType,
+{
+    let array = array.as_any().downcast_ref::<PrimitiveArray<I>>().unwrap();
+    let values = array.values().iter().map(|x| x.as_()).collect::<Vec<_>>();
+    PrimitiveArray::<O>::new(data_type.clone(), values.into(), array.validity().cloned()).boxed()
+}
+
+fn promote(array: &dyn Array, data_type: &DataType) -> Result<Box<dyn Array>> {
+    use DataType::*;
+    Ok(match (array.data_type(), data_type) {
+        (Int32, Int64) => promote_primitive::<i32, i64>(array, data_type),
+        (Int32, Float32) => promote_primitive::<i32, f32>(array, data_type),
+        (Int32, Float64) => promote_primitive::<i32, f64>(array, data_type),
+        (Int64, Float32) => promote_primitive::<i64, f32>(array, data_type),
+        (Int64, Float64) => promote_primitive::<i64, f64>(array, data_type),
+        (Float32, Float64) => promote_primitive::<f32, f64>(array, data_type),
+        (Utf8, Binary) => {
+            let array = array.as_any().downcast_ref::<Utf8Array<i32>>().unwrap();
+            BinaryArray::<i32>::new(
+                data_type.clone(),
+                array.offsets().clone(),
+                array.values().clone(),
+                array.validity().cloned(),
+            )
+            .boxed()
+        }
+        (Binary, Utf8) => {
+            let array = array.as_any().downcast_ref::<BinaryArray<i32>>().unwrap();
+            Utf8Array::<i32>::try_new(
+                data_type.clone(),
+                array.offsets().clone(),
+                array.values().clone(),
+                array.validity().cloned(),
+            )?
+            .boxed()
+        }
+        _ => unreachable!(),
+    })
+}
+
+/// Returns an array with `length` copies of the single value of `array`
+fn repeat(array: &dyn Array, length: usize) -> Box<dyn Array> {
+    let mut growable = make_growable(&[array], false, length);
+    (0..length).for_each(|_| growable.extend(0, 0, 1));
+    growable.as_box()
+}
+
+fn resolve_children_arrays(
+    arrays: &[Box<dyn Array>],
+    children: &[Child],
+    length: usize,
+) -> Result<Vec<Box<dyn Array>>> {
+    children
+        .iter()
+        .map(|child| match child {
+            Child::Writer(index, plan) => resolve_array(arrays[*index].clone(), plan),
+            Child::Default(default) => Ok(repeat(default.as_ref(), length)),
+        })
+        .collect()
+}
+
+fn resolve_array(array: Box<dyn Array>, plan: &Plan) -> Result<Box<dyn Array>> {
+    match plan {
+        Plan::Identity => Ok(array),
+        Plan::Promote(data_type) => promote(array.as_ref(), data_type),
+        Plan::Struct(data_type, children) => {
+            let array = array.as_any().downcast_ref::<StructArray>().unwrap();
+            let values = resolve_children_arrays(array.values(), children, array.len())?;
+            Ok(StructArray::try_new(data_type.clone(), values, array.validity().cloned())?.boxed())
+        }
+        Plan::List(data_type, plan) => {
+            let array = array.as_any().downcast_ref::<ListArray<i32>>().unwrap();
+            let values = resolve_array(array.values().clone(), plan)?;
+            Ok(ListArray::<i32>::try_new(
+                data_type.clone(),
+                array.offsets().clone(),
+                values,
+                array.validity().cloned(),
+            )?
+            .boxed())
+        }
+    }
+}
+
+/// The resolution of a reader's schema against the schema data was written with (the
+/// writer's schema), following Avro's
+/// [schema resolution](https://avro.apache.org/docs/current/spec.html#Schema+Resolution).
+///
+/// Fields of the reader's schema are matched to fields of the writer's schema by name
+/// (recursively, for records) and:
+/// * fields of the writer that are not in the reader are skipped
+/// * fields of the reader that are not in the writer are filled with their default value, or
+///   with nulls when they have no default and are nullable
+/// * `int` is promoted to `long`, `float` and `double`, `long` to `float` and `double`,
+///   `float` to `double`, and `string` and `bytes` to each other
+///
+/// Since field defaults are not part of the schemas of this crate, they are declared via
+/// arrays of length 1, by the path of their field (e.g. `a` or `a.b` for the field `b` of
+/// the record `a`).
+#[derive(Debug, Clone)]
+pub struct SchemaResolution {
+    fields: Vec<Field>,
+    writer_fields: Vec<Field>,
+    avro_fields: Vec<AvroField>,
+    projection: Vec<bool>,
+    columns: Vec<Child>,
+}
+
+impl SchemaResolution {
+    /// Resolves the reader's schema, `fields`, against the writer's schema, `writer`.
+    /// # Errors
+    /// Errors iff:
+    /// * a field of both schemas has types that can't be resolved
+    /// * a non-nullable field of the reader is not in the writer and has no default
+    /// * a default is not an array of length 1 with the type of its field
+    pub fn try_new(
+        writer: &Record,
+        fields: Vec<Field>,
+        defaults: HashMap<String, Box<dyn Array>>,
+    ) -> Result<Self> {
+        let writer_fields = infer_schema(writer)?.fields;
+        let mut columns = resolve_children(&writer_fields, &fields, "", &defaults)?;
+
+        // only the writer's fields used by the reader are deserialized
+        let mut projection = vec![false; writer_fields.len()];
+        columns.iter().for_each(|child| {
+            if let Child::Writer(index, _) = child {
+                projection[*index] = true
+            }
+        });
+        // which are positioned in the deserialized chunk by their order in the writer
+        columns.iter_mut().for_each(|child| {
+            if let Child::Writer(index, _) = child {
+                *index = projection[..*index].iter().filter(|x| **x).count();
+            }
+        });
+
+        Ok(Self {
+            fields,
+            writer_fields,
+            avro_fields: writer.fields.clone(),
+            projection,
+            columns,
+        })
+    }
+
+    /// The fields of the reader's schema, i.e. of the deserialized [`Chunk`]s
+    pub fn fields(&self) -> &[Field] {
+        &self.fields
+    }
+
+    /// Deserializes a [`Block`] written with the writer's schema into a [`Chunk`] of the
+    /// reader's schema.
+    /// # Errors
+    /// Errors iff the block is invalid, or a non-nullable (top-level) field of the reader is
+    /// null in the writer's data.
+    pub fn deserialize(&self, block: &Block) -> Result<Chunk<Box<dyn Array>>> {
+        let chunk = deserialize(
+            block,
+            &self.writer_fields,
+            &self.avro_fields,
+            &self.projection,
+        )?;
+        let arrays = resolve_children_arrays(chunk.arrays(), &self.columns, block.number_of_rows)?;
+
+        if let Some(field) = self
+            .fields
+            .iter()
+            .zip(arrays.iter())
+            .find_map(|(field, array)| {
+                (!field.is_nullable && array.null_count() > 0).then_some(field)
+            })
+        {
+            return Err(Error::ExternalFormat(format!(
+                "The non-nullable field \"{}\" of the reader's schema is null in the writer's data",
+                field.name
+            )));
+        }
+        Chunk::try_new(arrays)
+    }
+}
@@ -0,0 +1,154 @@
+use avro_schema::file::Block;
+use avro_schema::schema::{Field as AvroField, Record};
+
+use crate::array::Array;
+use crate::chunk::Chunk;
+use crate::datatypes::Field;
+use crate::error::{Error, Result};
+
+use super::deserialize::skip_item;
+use super::{deserialize, infer_schema};
+
+/// The first byte of messages in the Confluent wire format
+pub const CONFLUENT_MAGIC_BYTE: u8 = 0;
+
+/// Splits a message in the [Confluent wire format](https://docs.confluent.io/platform/current/schema-registry/fundamentals/serdes-develop/index.html#wire-format)
+/// into the id of its schema in the schema registry and its Avro-encoded datum.
+/// # Errors
+/// Errors iff the message does not start with [`CONFLUENT_MAGIC_BYTE`] followed by a 4-byte
+/// schema id.
+pub fn read_confluent_header(message: &[u8]) -> Result<(u32, &[u8])> {
+    match message {
+        [CONFLUENT_MAGIC_BYTE, a, b, c, d, datum @ ..] => {
+            Ok((u32::from_be_bytes([*a, *b, *c, *d]), datum))
+        }
+        _ => Err(Error::ExternalFormat(
+            "Confluent-framed messages must start with the magic byte 0 followed by a 4-byte schema id"
+                .to_string(),
+        )),
+    }
+}
+
+/// Decodes single-record messages in the Confluent wire format (e.g. consumed from Kafka)
+/// into [`Chunk`]s, without the object container format.
+///
+/// Messages are validated and accumulated by [`SingleDecoder::decode_single`], and
+/// deserialized together into arrays by [`SingleDecoder::flush`], so that a [`Chunk`] is
+/// produced per batch of messages.
+/// # Example
+/// ```
+/// use arrow2::array::Int64Array;
+/// use arrow2::io::avro::avro_schema::schema::{Field, Record, Schema};
+/// use arrow2::io::avro::read::SingleDecoder;
+///
+/// let record = Record::new("test", vec![Field::new("a", Schema::Long(None))]);
+/// let mut decoder = SingleDecoder::try_new(1, &record, None).unwrap();
+///
+/// // magic byte, schema id 1 and the zigzag-encoded longs 1 and -2
+/// decoder.decode_single(&[0, 0, 0, 0, 1, 2]).unwrap();
+/// decoder.decode_single(&[0, 0, 0, 0, 1, 3]).unwrap();
+/// assert_eq!(decoder.len(), 2);
+///
+/// let chunk = decoder.flush().unwrap();
+/// assert_eq!(&chunk.arrays()[0].as_ref(), &Int64Array::from_slice([1, -2]));
+/// assert!(decoder.is_empty());
+/// ```
+#[derive(Debug, Clone)]
+pub struct SingleDecoder {
+    schema_id: u32,
+    fields: Vec<Field>,
+    avro_fields: Vec<AvroField>,
+    projection: Vec<bool>,
+    data: Vec<u8>,
+    length: usize,
+}
+
+impl SingleDecoder {
+    /// Creates a new [`SingleDecoder`] of messages with `schema_id`, whose datums are encoded
+    /// with `record`. `projection` selects the fields of `record` to deserialize.
+    /// # Errors
+    /// Errors iff the schema of `record` can't be converted to arrow, or `projection` does not
+    /// have one entry per field of `record`.
+    pub fn try_new(schema_id: u32, record: &Record, projection: Option<Vec<bool>>) -> Result<Self> {
+        let fields = infer_schema(record)?.fields;
+        let projection = projection.unwrap_or_else(|| vec![true; fields.len()]);
+        if projection.len() != fields.len() {
+            return Err(Error::InvalidArgumentError(format!(
+                "The projection must have one entry per field of the record ({}), but it has {}",
+                fields.len(),
+                projection.len()
+            )));
+        }
+        Ok(Self {
+            schema_id,
+            fields,
+            avro_fields: record.fields.clone(),
+            projection,
+            data: vec![],
+            length: 0,
+        })
+    }
+
+    /// The fields of the [`Chunk`]s returned by [`SingleDecoder::flush`]
+    pub fn fields(&self) -> Vec<Field> {
+        self.fields
+            .iter()
+            .zip(self.projection.iter())
+            .filter(|(_, projected)| **projected)
+            .map(|(field, _)| field.clone())
+            .collect()
+    }
+
+    /// The number of messages decoded since the last [`SingleDecoder::flush`]
+    pub fn len(&self) -> usize {
+        self.length
+    }
+
+    /// Whether no messages were decoded since the last [`SingleDecoder::flush`]
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
+    /// Decodes a Confluent-framed `message` into this decoder.
+    /// # Errors
+    /// Errors iff the message is not framed, was written with another schema id or its datum
+    /// is not a single record of the schema. In that case, the message is not added.
+    pub fn decode_single(&mut self, message: &[u8]) -> Result<()> {
+        let (schema_id, datum) = read_confluent_header(message)?;
+        if schema_id != self.schema_id {
+            return Err(Error::ExternalFormat(format!(
+                "The message was written with the schema id {schema_id}, but the decoder expects {}",
+                self.schema_id
+            )));
+        }
+
+        // validate that the datum is exactly one record
+        let mut remaining = datum;
+        for (field, avro_field) in self.fields.iter().zip(self.avro_fields.iter()) {
+            remaining = skip_item(field, &avro_field.schema, remaining)?;
+        }
+        if !remaining.is_empty() {
+            return Err(Error::ExternalFormat(format!(
+                "The datum of the message has {} bytes after its record",
+                remaining.len()
+            )));
+        }
+
+        self.data.extend_from_slice(datum);
+        self.length += 1;
+        Ok(())
+    }
+
+    /// Deserializes the messages decoded since the last flush into a [`Chunk`], and
+    /// empties this decoder.
+    /// # Errors
+    /// Errors iff the values of the messages are invalid (e.g. non-utf8 strings). The messages
+    /// are dropped in that case.
+    pub fn flush(&mut self) -> Result<Chunk<Box<dyn Array>>> {
+        let block = Block::new(
+            std::mem::take(&mut self.length),
+            std::mem::take(&mut self.data),
+        );
+        deserialize(&block, &self.fields, &self.avro_fields, &self.projection)
+    }
+}
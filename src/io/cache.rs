@@ -0,0 +1,284 @@
+//! Contains [`ChunkCache`], a bounded, size-accounted cache of decoded [`Chunk`]s.
+//!
+//! Decoding a row group (Parquet) or a record batch (IPC) is often the most expensive
+//! part of serving a query. [`ChunkCache`] stores decoded [`Chunk`]s keyed by
+//! [`CacheKey`] (source, index and projection) and evicts the least recently used entries
+//! whenever the total weight of the cache exceeds its capacity.
+//!
+//! The readers integrate with it via
+//! [`ipc::read::FileReader::set_cache`](crate::io::ipc::read::FileReader::set_cache)
+//! and [`parquet::read::read_row_group_cached`](crate::io::parquet::read::read_row_group_cached).
+use std::collections::BTreeMap;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+
+use ahash::AHashMap;
+
+use crate::array::Array;
+use crate::chunk::Chunk;
+use crate::compute::aggregate::estimated_bytes_size;
+use crate::error::Result;
+
+/// A [`ChunkCache`] shared between readers.
+pub type SharedChunkCache = Arc<Mutex<ChunkCache>>;
+
+/// A function returning the weight (e.g. in bytes) of a [`Chunk`].
+pub type Weigher = Box<dyn Fn(&Chunk<Box<dyn Array>>) -> usize + Send + Sync>;
+
+/// The key of a [`Chunk`] in a [`ChunkCache`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CacheKey {
+    /// An identifier of the source of the chunk (e.g. a path or URL of the file).
+    pub source: Arc<str>,
+    /// The index of the chunk within the source (e.g. the row group or the IPC block)
+    pub index: usize,
+    /// The projection used to decode the chunk, if any.
+    pub projection: Option<Vec<usize>>,
+}
+
+impl CacheKey {
+    /// Creates a new [`CacheKey`].
+    pub fn new(source: impl Into<Arc<str>>, index: usize, projection: Option<Vec<usize>>) -> Self {
+        Self {
+            source: source.into(),
+            index,
+            projection,
+        }
+    }
+}
+
+/// Statistics of a [`ChunkCache`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CacheStats {
+    /// The number of lookups that found an entry
+    pub hits: usize,
+    /// The number of lookups that did not find an entry
+    pub misses: usize,
+    /// The number of entries evicted to make room for new entries
+    pub evictions: usize,
+}
+
+#[derive(Debug)]
+struct Entry {
+    chunk: Chunk<Box<dyn Array>>,
+    weight: usize,
+    tick: u64,
+}
+
+/// A bounded cache of decoded [`Chunk`]s with least-recently-used eviction.
+///
+/// Every entry has a weight, computed by a [`Weigher`] when the entry is inserted. By default,
+/// the weight is the sum of [`estimated_bytes_size`] of each of the chunk's arrays.
+/// The sum of the weights of all entries never exceeds [`ChunkCache::capacity`].
+/// # Implementation
+/// Lookups and insertions are `O(log N)` where `N` is the number of entries.
+/// Retrieving a [`Chunk`] clones it, which is `O(C)` where `C` is the number of columns.
+pub struct ChunkCache {
+    capacity: usize,
+    weight: usize,
+    tick: u64,
+    entries: AHashMap<CacheKey, Entry>,
+    // least recently used first
+    order: BTreeMap<u64, CacheKey>,
+    weigher: Weigher,
+    stats: CacheStats,
+}
+
+impl std::fmt::Debug for ChunkCache {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ChunkCache")
+            .field("capacity", &self.capacity)
+            .field("weight", &self.weight)
+            .field("len", &self.entries.len())
+            .field("stats", &self.stats)
+            .finish()
+    }
+}
+
+/// Returns the sum of [`estimated_bytes_size`] over the arrays of `chunk`.
+pub fn chunk_bytes_size(chunk: &Chunk<Box<dyn Array>>) -> usize {
+    chunk
+        .arrays()
+        .iter()
+        .map(|array| estimated_bytes_size(array.as_ref()))
+        .sum()
+}
+
+/// Locks `cache`, recovering it if a thread panicked while holding the lock.
+///
+/// A panic never leaves a [`ChunkCache`] inconsistent, since its [`Weigher`] is called before
+/// the cache is modified.
+pub fn lock(cache: &SharedChunkCache) -> MutexGuard<'_, ChunkCache> {
+    cache.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+impl ChunkCache {
+    /// Creates a new [`ChunkCache`] holding at most `capacity` bytes, as estimated
+    /// by [`chunk_bytes_size`].
+    pub fn new(capacity: usize) -> Self {
+        Self::with_weigher(capacity, Box::new(chunk_bytes_size))
+    }
+
+    /// Creates a new [`ChunkCache`] whose entries are weighted by `weigher`.
+    pub fn with_weigher(capacity: usize, weigher: Weigher) -> Self {
+        Self {
+            capacity,
+            weight: 0,
+            tick: 0,
+            entries: Default::default(),
+            order: Default::default(),
+            weigher,
+            stats: Default::default(),
+        }
+    }
+
+    /// Creates a new [`SharedChunkCache`] holding at most `capacity` bytes.
+    pub fn new_shared(capacity: usize) -> SharedChunkCache {
+        Arc::new(Mutex::new(Self::new(capacity)))
+    }
+
+    /// The maximum total weight of this cache
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// The current total weight of this cache
+    pub fn weight(&self) -> usize {
+        self.weight
+    }
+
+    /// The number of entries in this cache
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Whether this cache has no entries
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns the [`CacheStats`] of this cache
+    pub fn stats(&self) -> CacheStats {
+        self.stats
+    }
+
+    /// Whether `key` is in the cache. Unlike [`ChunkCache::get`], this does not
+    /// affect the eviction order nor the statistics.
+    pub fn contains(&self, key: &CacheKey) -> bool {
+        self.entries.contains_key(key)
+    }
+
+    fn next_tick(&mut self) -> u64 {
+        self.tick += 1;
+        self.tick
+    }
+
+    /// Returns the [`Chunk`] associated to `key`, marking it as the most recently used.
+    pub fn get(&mut self, key: &CacheKey) -> Option<Chunk<Box<dyn Array>>> {
+        let tick = self.next_tick();
+        match self.entries.get_mut(key) {
+            Some(entry) => {
+                self.order.remove(&entry.tick);
+                entry.tick = tick;
+                self.order.insert(tick, key.clone());
+                self.stats.hits += 1;
+                Some(entry.chunk.clone())
+            }
+            None => {
+                self.stats.misses += 1;
+                None
+            }
+        }
+    }
+
+    /// Inserts `chunk` associated to `key`, evicting the least recently used entries
+    /// until it fits. Returns whether the chunk was inserted, which only fails when
+    /// the weight of the chunk is larger than the capacity of the cache, in which case
+    /// the cache is unchanged.
+    pub fn insert(&mut self, key: CacheKey, chunk: Chunk<Box<dyn Array>>) -> bool {
+        let weight = (self.weigher)(&chunk);
+        if weight > self.capacity {
+            return false;
+        }
+        self.remove(&key);
+        while self.weight + weight > self.capacity {
+            self.evict();
+        }
+
+        let tick = self.next_tick();
+        self.order.insert(tick, key.clone());
+        self.entries.insert(
+            key,
+            Entry {
+                chunk,
+                weight,
+                tick,
+            },
+        );
+        self.weight += weight;
+        true
+    }
+
+    /// Returns the [`Chunk`] associated to `key` or, if not present, computes it via `f`
+    /// and inserts it.
+    /// # Errors
+    /// Errors iff `f` errors, in which case the cache is unchanged.
+    pub fn get_or_try_insert_with<F>(
+        &mut self,
+        key: CacheKey,
+        f: F,
+    ) -> Result<Chunk<Box<dyn Array>>>
+    where
+        F: FnOnce() -> Result<Chunk<Box<dyn Array>>>,
+    {
+        if let Some(chunk) = self.get(&key) {
+            return Ok(chunk);
+        }
+        let chunk = f()?;
+        self.insert(key, chunk.clone());
+        Ok(chunk)
+    }
+
+    /// Removes the entry associated to `key`, returning its [`Chunk`] if present.
+    pub fn remove(&mut self, key: &CacheKey) -> Option<Chunk<Box<dyn Array>>> {
+        let entry = self.entries.remove(key)?;
+        self.order.remove(&entry.tick);
+        self.weight -= entry.weight;
+        Some(entry.chunk)
+    }
+
+    /// Removes all entries from `source`.
+    pub fn remove_source(&mut self, source: &str) {
+        let keys = self
+            .entries
+            .keys()
+            .filter(|key| key.source.as_ref() == source)
+            .cloned()
+            .collect::<Vec<_>>();
+        for key in keys {
+            self.remove(&key);
+        }
+    }
+
+    /// Removes all entries from the cache. The statistics are preserved.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.order.clear();
+        self.weight = 0;
+    }
+
+    /// Changes the capacity of the cache, evicting entries if needed.
+    pub fn set_capacity(&mut self, capacity: usize) {
+        self.capacity = capacity;
+        while self.weight > self.capacity {
+            self.evict();
+        }
+    }
+
+    fn evict(&mut self) {
+        if let Some((_, key)) = self.order.pop_first() {
+            let entry = self.entries.remove(&key).unwrap();
+            self.weight -= entry.weight;
+            self.stats.evictions += 1;
+        }
+    }
+}
@@ -9,7 +9,7 @@ use crate::{
 
 use super::super::read_utils::{
     deserialize_batch as deserialize_batch_gen, deserialize_column as deserialize_column_gen,
-    ByteRecordGeneric,
+    deserialize_column_promote_large as deserialize_column_promote_large_gen, ByteRecordGeneric,
 };
 
 impl ByteRecordGeneric for ByteRecord {
@@ -29,6 +29,21 @@ pub fn deserialize_column(
     deserialize_column_gen(rows, column, datatype, line_number)
 }
 
+/// Deserializes `column` of `rows` into an [`Array`] of [`DataType`] `datatype`, promoting
+/// [`DataType::Utf8`] and [`DataType::Binary`] to [`DataType::LargeUtf8`] and
+/// [`DataType::LargeBinary`] when the values exceed their maximum length of `i32::MAX` bytes.
+///
+/// This function can be passed to [`deserialize_batch`]; the data types of the resulting
+/// arrays may then differ from the ones of `fields`.
+pub fn deserialize_column_promote_large(
+    rows: &[ByteRecord],
+    column: usize,
+    datatype: DataType,
+    line_number: usize,
+) -> Result<Box<dyn Array>> {
+    deserialize_column_promote_large_gen(rows, column, datatype, line_number)
+}
+
 /// Deserializes rows [`ByteRecord`] into a [`Chunk`].
 /// Note that this is a convenience function: column deserialization
 /// is trivially parallelizable (e.g. rayon).
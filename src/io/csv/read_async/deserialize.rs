@@ -1,4 +1,5 @@
 use csv_async::ByteRecord;
+use futures::{Stream, StreamExt};
 
 use crate::{
     array::Array,
@@ -9,7 +10,7 @@ use crate::{
 
 use super::super::read_utils::{
     deserialize_batch as deserialize_batch_gen, deserialize_column as deserialize_column_gen,
-    ByteRecordGeneric,
+    deserialize_column_promote_large as deserialize_column_promote_large_gen, ByteRecordGeneric,
 };
 
 impl ByteRecordGeneric for ByteRecord {
@@ -29,6 +30,21 @@ pub fn deserialize_column(
     deserialize_column_gen(rows, column, datatype, line_number)
 }
 
+/// Deserializes `column` of `rows` into an [`Array`] of [`DataType`] `datatype`, promoting
+/// [`DataType::Utf8`] and [`DataType::Binary`] to [`DataType::LargeUtf8`] and
+/// [`DataType::LargeBinary`] when the values exceed their maximum length of `i32::MAX` bytes.
+///
+/// This function can be passed to [`deserialize_batch`]; the data types of the resulting
+/// arrays may then differ from the ones of `fields`.
+pub fn deserialize_column_promote_large(
+    rows: &[ByteRecord],
+    column: usize,
+    datatype: DataType,
+    line_number: usize,
+) -> Result<Box<dyn Array>> {
+    deserialize_column_promote_large_gen(rows, column, datatype, line_number)
+}
+
 /// Deserializes rows [`ByteRecord`] into [`Chunk`].
 /// Note that this is a convenience function: column deserialization
 /// is trivially parallelizable (e.g. rayon).
@@ -44,3 +60,31 @@ where
 {
     deserialize_batch_gen(rows, fields, projection, line_number, deserialize_column)
 }
+
+/// Deserializes a [`Stream`] of batches of rows (e.g. from
+/// [`read_rows_stream`](super::read_rows_stream)) into a [`Stream`] of [`Chunk`]s, where
+/// `line_number` is the line number of the first row, used in error messages.
+pub fn deserialize_stream<S, F>(
+    rows: S,
+    fields: Vec<Field>,
+    projection: Option<Vec<usize>>,
+    mut line_number: usize,
+    deserialize_column: F,
+) -> impl Stream<Item = Result<Chunk<Box<dyn Array>>>>
+where
+    S: Stream<Item = Result<Vec<ByteRecord>>>,
+    F: Fn(&[ByteRecord], usize, DataType, usize) -> Result<Box<dyn Array>>,
+{
+    rows.map(move |rows| {
+        let rows = rows?;
+        let chunk = deserialize_batch(
+            &rows,
+            &fields,
+            projection.as_deref(),
+            line_number,
+            &deserialize_column,
+        );
+        line_number += rows.len();
+        chunk
+    })
+}
@@ -7,21 +7,13 @@ use crate::io::csv::utils::merge_schema;
 
 use futures::{AsyncRead, AsyncSeek};
 
-/// Infers the [`Field`]s of a CSV file by reading through the first n records up to `max_rows`.
-/// Seeks back to the begining of the file _after_ the header
-pub async fn infer_schema<R, F>(
-    reader: &mut AsyncReader<R>,
-    max_rows: Option<usize>,
-    has_header: bool,
-    infer: &F,
-) -> Result<(Vec<Field>, usize)>
+/// Returns the names of the columns of `reader`, creating default column names with the
+/// `column_` prefix when `has_header` is false.
+async fn read_headers<R>(reader: &mut AsyncReader<R>, has_header: bool) -> Result<Vec<String>>
 where
-    R: AsyncRead + AsyncSeek + Unpin + Send + Sync,
-    F: Fn(&[u8]) -> DataType,
+    R: AsyncRead + Unpin + Send + Sync,
 {
-    // get or create header names
-    // when has_header is false, creates default column names with column_ prefix
-    let headers: Vec<String> = if has_header {
+    Ok(if has_header {
         reader
             .headers()
             .await?
@@ -33,7 +25,34 @@ where
         (0..*first_record_count)
             .map(|i| format!("column_{}", i + 1))
             .collect()
-    };
+    })
+}
+
+/// Adds the [`DataType`]s of the values of `record` to `column_types`.
+fn infer_record<F>(record: &ByteRecord, column_types: &mut [AHashSet<DataType>], infer: &F)
+where
+    F: Fn(&[u8]) -> DataType,
+{
+    for (i, column) in column_types.iter_mut().enumerate() {
+        if let Some(string) = record.get(i) {
+            column.insert(infer(string));
+        }
+    }
+}
+
+/// Infers the [`Field`]s of a CSV file by reading through the first n records up to `max_rows`.
+/// Seeks back to the begining of the file _after_ the header
+pub async fn infer_schema<R, F>(
+    reader: &mut AsyncReader<R>,
+    max_rows: Option<usize>,
+    has_header: bool,
+    infer: &F,
+) -> Result<(Vec<Field>, usize)>
+where
+    R: AsyncRead + AsyncSeek + Unpin + Send + Sync,
+    F: Fn(&[u8]) -> DataType,
+{
+    let headers = read_headers(reader, has_header).await?;
 
     // save the csv reader position after reading headers
     let position = reader.position().clone();
@@ -51,12 +70,7 @@ where
             break;
         }
         records_count += 1;
-
-        for (i, column) in column_types.iter_mut().enumerate() {
-            if let Some(string) = record.get(i) {
-                column.insert(infer(string));
-            }
-        }
+        infer_record(&record, &mut column_types, infer);
     }
 
     let fields = merge_schema(&headers, &mut column_types);
@@ -66,3 +80,40 @@ where
 
     Ok((fields, records_count))
 }
+
+/// Infers the [`Field`]s of a CSV file by reading through the first n records up to `max_rows`,
+/// returning them alongside the [`Field`]s.
+///
+/// Unlike [`infer_schema`], this does not require the reader to be seekable (e.g. a stream
+/// from an object store): the records read during inference are returned so they can be
+/// deserialized (e.g. via [`deserialize_batch`](super::deserialize_batch)) before the
+/// remaining records of `reader`.
+pub async fn infer_schema_buffered<R, F>(
+    reader: &mut AsyncReader<R>,
+    max_rows: Option<usize>,
+    has_header: bool,
+    infer: &F,
+) -> Result<(Vec<Field>, Vec<ByteRecord>)>
+where
+    R: AsyncRead + Unpin + Send + Sync,
+    F: Fn(&[u8]) -> DataType,
+{
+    let headers = read_headers(reader, has_header).await?;
+
+    let mut column_types: Vec<AHashSet<DataType>> = vec![AHashSet::new(); headers.len()];
+
+    let mut records = vec![];
+    let max_records = max_rows.unwrap_or(usize::MAX);
+    while records.len() < max_records {
+        let mut record = ByteRecord::new();
+        if !reader.read_byte_record(&mut record).await? {
+            break;
+        }
+        infer_record(&record, &mut column_types, infer);
+        records.push(record);
+    }
+
+    let fields = merge_schema(&headers, &mut column_types);
+
+    Ok((fields, records))
+}
@@ -8,8 +8,10 @@ mod infer_schema;
 mod reader;
 
 pub use super::utils::infer;
-pub use deserialize::{deserialize_batch, deserialize_column};
-pub use infer_schema::infer_schema;
+pub use deserialize::{
+    deserialize_batch, deserialize_column, deserialize_column_promote_large, deserialize_stream,
+};
+pub use infer_schema::{infer_schema, infer_schema_buffered};
 pub use reader::*;
 
 pub use csv_async::Error as CSVError;
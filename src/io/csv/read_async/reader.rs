@@ -1,4 +1,4 @@
-use futures::AsyncRead;
+use futures::{AsyncRead, Stream};
 
 use super::{AsyncReader, ByteRecord};
 
@@ -37,3 +37,38 @@ where
     }
     Ok(row_number)
 }
+
+/// Returns a [`Stream`] of batches of up to `batch_size` rows of `reader`, e.g. to be
+/// deserialized by [`deserialize_stream`](super::deserialize_stream).
+///
+/// Rows are only read from `reader` when the stream is polled, so consumers that are slower
+/// than `reader` apply backpressure to it.
+pub fn read_rows_stream<R>(
+    reader: &mut AsyncReader<R>,
+    batch_size: usize,
+) -> impl Stream<Item = Result<Vec<ByteRecord>>> + '_
+where
+    R: AsyncRead + Unpin + Send + Sync,
+{
+    futures::stream::try_unfold((reader, 0), move |(reader, line_number)| async move {
+        let mut rows = Vec::with_capacity(batch_size);
+        while rows.len() < batch_size {
+            let mut row = ByteRecord::new();
+            let has_more = reader.read_byte_record(&mut row).await.map_err(|e| {
+                Error::External(
+                    format!(" at line {}", line_number + rows.len()),
+                    Box::new(e),
+                )
+            })?;
+            if !has_more {
+                break;
+            }
+            rows.push(row);
+        }
+        if rows.is_empty() {
+            return Ok(None);
+        }
+        let line_number = line_number + rows.len();
+        Ok(Some((rows, (reader, line_number))))
+    })
+}
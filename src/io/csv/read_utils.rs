@@ -110,21 +110,27 @@ where
 }
 
 #[inline]
-fn deserialize_utf8<O: Offset, B: ByteRecordGeneric>(rows: &[B], column: usize) -> Box<dyn Array> {
-    let iter = rows.iter().map(|row| match row.get(column) {
-        Some(bytes) => to_utf8(bytes),
-        None => None,
-    });
-    Box::new(Utf8Array::<O>::from_trusted_len_iter(iter))
+fn deserialize_utf8<O: Offset, B: ByteRecordGeneric>(
+    rows: &[B],
+    column: usize,
+) -> Result<Box<dyn Array>> {
+    let mut builder = DeferredUtf8Builder::<O>::with_capacity(rows.len());
+    builder.extend(rows.iter().map(|row| row.get(column).and_then(to_utf8)));
+    Ok(Box::new(builder.freeze()?))
 }
 
 #[inline]
 fn deserialize_binary<O: Offset, B: ByteRecordGeneric>(
     rows: &[B],
     column: usize,
-) -> Box<dyn Array> {
+) -> Result<Box<dyn Array>> {
     let iter = rows.iter().map(|row| row.get(column));
-    Box::new(BinaryArray::<O>::from_trusted_len_iter(iter))
+    // only `i32` offsets can realistically overflow
+    Ok(Box::new(if O::IS_LARGE {
+        BinaryArray::<O>::from_trusted_len_iter(iter)
+    } else {
+        BinaryArray::<O>::try_from_iter(iter)?
+    }))
 }
 
 #[inline]
@@ -143,6 +149,26 @@ fn deserialize_datetime<T: chrono::TimeZone>(string: &str, tz: &T) -> Option<i64
     }
 }
 
+/// Deserializes `column` of `rows` into an [`Array`] of [`DataType`] `datatype`, or of its
+/// large variant when `datatype` is [`DataType::Utf8`] or [`DataType::Binary`] and the
+/// values do not fit in it.
+pub(crate) fn deserialize_column_promote_large<B: ByteRecordGeneric>(
+    rows: &[B],
+    column: usize,
+    datatype: DataType,
+    line_number: usize,
+) -> Result<Box<dyn Array>> {
+    let large = match datatype {
+        DataType::Utf8 => DataType::LargeUtf8,
+        DataType::Binary => DataType::LargeBinary,
+        _ => return deserialize_column(rows, column, datatype, line_number),
+    };
+    match deserialize_column(rows, column, datatype, line_number) {
+        Err(Error::Overflow) => deserialize_column(rows, column, large, line_number),
+        other => other,
+    }
+}
+
 /// Deserializes `column` of `rows` into an [`Array`] of [`DataType`] `datatype`.
 #[inline]
 pub(crate) fn deserialize_column<B: ByteRecordGeneric>(
@@ -229,10 +255,10 @@ pub(crate) fn deserialize_column<B: ByteRecordGeneric>(
         Decimal(precision, scale) => deserialize_primitive(rows, column, datatype, |x| {
             deserialize_decimal(x, precision, scale)
         }),
-        Utf8 => deserialize_utf8::<i32, _>(rows, column),
-        LargeUtf8 => deserialize_utf8::<i64, _>(rows, column),
-        Binary => deserialize_binary::<i32, _>(rows, column),
-        LargeBinary => deserialize_binary::<i64, _>(rows, column),
+        Utf8 => deserialize_utf8::<i32, _>(rows, column)?,
+        LargeUtf8 => deserialize_utf8::<i64, _>(rows, column)?,
+        Binary => deserialize_binary::<i32, _>(rows, column)?,
+        LargeBinary => deserialize_binary::<i64, _>(rows, column)?,
         other => {
             return Err(Error::NotYetImplemented(format!(
                 "Deserializing type \"{other:?}\" is not implemented"
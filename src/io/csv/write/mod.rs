@@ -1,11 +1,13 @@
 //! APIs to write to CSV
 mod serialize;
+mod serializer;
 
 use super::super::iterator::StreamingIterator;
 
 use std::io::Write;
 
 pub use serialize::*;
+pub use serializer::CsvSerializer;
 
 use crate::array::Array;
 use crate::chunk::Chunk;
@@ -58,6 +60,9 @@ pub fn serialize<A: AsRef<dyn Array>>(
 }
 
 /// Writes [`Chunk`] to `writer` according to the serialization options `options`.
+///
+/// Use [`CsvSerializer`] to write many chunks, reusing its buffers and optionally
+/// serializing columns in parallel.
 pub fn write_chunk<W: Write, A: AsRef<dyn Array>>(
     writer: &mut W,
     columns: &Chunk<A>,
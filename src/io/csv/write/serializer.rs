@@ -0,0 +1,173 @@
+use std::io::Write;
+
+use crate::array::Array;
+use crate::chunk::Chunk;
+use crate::error::{Error, Result};
+
+use super::{new_serializer, SerializeOptions};
+
+/// The number of bytes of rows buffered before they are written to the writer
+const ROWS_CAPACITY: usize = 1 << 16;
+
+/// The serialized fields of a column, contiguous in `values` and ending at `ends`
+#[derive(Debug, Default)]
+struct ColumnBuffer {
+    values: Vec<u8>,
+    ends: Vec<usize>,
+}
+
+impl ColumnBuffer {
+    fn clear(&mut self) {
+        self.values.clear();
+        self.ends.clear();
+    }
+
+    fn field(&self, row: usize) -> &[u8] {
+        let start = if row == 0 { 0 } else { self.ends[row - 1] };
+        &self.values[start..self.ends[row]]
+    }
+}
+
+fn serialize_column(
+    array: &dyn Array,
+    options: &SerializeOptions,
+    buffer: &mut ColumnBuffer,
+) -> Result<()> {
+    buffer.clear();
+    buffer.ends.reserve(array.len());
+    let mut serializer = new_serializer(array, options)?;
+    while let Some(field) = serializer.next() {
+        buffer.values.extend_from_slice(field);
+        buffer.ends.push(buffer.values.len());
+    }
+    Ok(())
+}
+
+/// A reusable serializer of [`Chunk`]s to CSV.
+///
+/// Unlike [`write_chunk`](super::write_chunk), every column of a chunk is first serialized to
+/// its own buffer, optionally in parallel (one thread per group of columns), and the rows are
+/// then written to the writer in blocks. The buffers are kept across chunks, so that
+/// writing many chunks of similar sizes does not re-allocate them.
+/// # Example
+/// ```
+/// use arrow2::array::{Int32Array, Utf8Array};
+/// use arrow2::chunk::Chunk;
+/// use arrow2::io::csv::write::{CsvSerializer, SerializeOptions};
+///
+/// let chunk = Chunk::new(vec![
+///     Int32Array::from([Some(1), None]).boxed(),
+///     Utf8Array::<i32>::from_slice(["a", "b c"]).boxed(),
+/// ]);
+///
+/// let mut serializer = CsvSerializer::new(SerializeOptions::default()).with_parallel(true);
+/// let mut csv = vec![];
+/// serializer.write_chunk(&mut csv, &chunk).unwrap();
+/// serializer.write_chunk(&mut csv, &chunk).unwrap();
+/// assert_eq!(csv, b"1,a\n,b c\n1,a\n,b c\n");
+/// ```
+#[derive(Debug)]
+pub struct CsvSerializer {
+    options: SerializeOptions,
+    parallel: bool,
+    columns: Vec<ColumnBuffer>,
+    rows: Vec<u8>,
+}
+
+impl CsvSerializer {
+    /// Creates a new [`CsvSerializer`] serializing according to `options`.
+    pub fn new(options: SerializeOptions) -> Self {
+        Self {
+            options,
+            parallel: false,
+            columns: vec![],
+            rows: vec![],
+        }
+    }
+
+    /// Sets whether the columns of a chunk are serialized in parallel, on up to
+    /// [`std::thread::available_parallelism`] threads. Defaults to `false`.
+    pub fn with_parallel(mut self, parallel: bool) -> Self {
+        self.parallel = parallel;
+        self
+    }
+
+    /// The options this serializer serializes according to
+    pub fn options(&self) -> &SerializeOptions {
+        &self.options
+    }
+
+    fn serialize_columns<A: AsRef<dyn Array>>(&mut self, chunk: &Chunk<A>) -> Result<()> {
+        let arrays = chunk.arrays();
+        self.columns.resize_with(arrays.len(), Default::default);
+        let options = &self.options;
+
+        let threads = std::thread::available_parallelism()
+            .map(|x| x.get())
+            .unwrap_or(1)
+            .min(arrays.len());
+        if !self.parallel || threads <= 1 {
+            return arrays
+                .iter()
+                .zip(self.columns.iter_mut())
+                .try_for_each(|(array, buffer)| serialize_column(array.as_ref(), options, buffer));
+        }
+
+        let columns_per_thread = arrays.len() / threads + usize::from(arrays.len() % threads > 0);
+        let arrays = arrays.iter().map(|x| x.as_ref()).collect::<Vec<_>>();
+        std::thread::scope(|scope| {
+            let handles = arrays
+                .chunks(columns_per_thread)
+                .zip(self.columns.chunks_mut(columns_per_thread))
+                .map(|(arrays, buffers)| {
+                    scope.spawn(move || {
+                        arrays
+                            .iter()
+                            .zip(buffers.iter_mut())
+                            .try_for_each(|(array, buffer)| {
+                                serialize_column(*array, options, buffer)
+                            })
+                    })
+                })
+                .collect::<Vec<_>>();
+            handles.into_iter().try_for_each(|handle| {
+                handle.join().map_err(|_| {
+                    Error::ExternalFormat("A thread serializing CSV columns panicked".to_string())
+                })?
+            })
+        })
+    }
+
+    /// Writes `chunk` to `writer` as CSV rows.
+    /// # Errors
+    /// Errors iff a column can't be serialized or `writer` errors.
+    pub fn write_chunk<W: Write, A: AsRef<dyn Array>>(
+        &mut self,
+        writer: &mut W,
+        chunk: &Chunk<A>,
+    ) -> Result<()> {
+        if chunk.arrays().is_empty() {
+            return Ok(());
+        }
+        self.serialize_columns(chunk)?;
+
+        let columns = &self.columns[..chunk.arrays().len()];
+        self.rows.clear();
+        for row in 0..chunk.len() {
+            for column in columns {
+                self.rows.extend_from_slice(column.field(row));
+                self.rows.push(self.options.delimiter);
+            }
+            // replace the last delimiter with a new line
+            *self.rows.last_mut().unwrap() = b'\n';
+
+            if self.rows.len() >= ROWS_CAPACITY {
+                writer.write_all(&self.rows)?;
+                self.rows.clear();
+            }
+        }
+        writer.write_all(&self.rows)?;
+        self.rows.clear();
+        Ok(())
+    }
+}
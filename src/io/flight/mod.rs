@@ -11,17 +11,23 @@ use crate::{
     error::{Error, Result},
     io::ipc::read,
     io::ipc::write,
-    io::ipc::write::common::{encode_chunk, DictionaryTracker, EncodedData},
+    io::ipc::write::common::{encode_chunk, encode_chunk_split, DictionaryTracker, EncodedData},
 };
 
 use super::ipc::read::Dictionaries;
 use super::ipc::{IpcField, IpcSchema};
 
-pub use super::ipc::write::default_ipc_fields;
+pub use super::ipc::write::{
+    check_ipc_fields, default_ipc_fields, default_ipc_fields_from, hashed_ipc_fields,
+    schema_fingerprint,
+};
 pub use crate::io::ipc::write::common::WriteOptions;
 
 /// Serializes [`Chunk`] to a vector of [`FlightData`] representing the serialized dictionaries
 /// and a [`FlightData`] representing the batch.
+///
+/// The chunk is serialized as a single batch regardless of [`WriteOptions::max_batch_rows`] and
+/// [`WriteOptions::max_batch_bytes`]; use [`serialize_batches`] to split it.
 /// # Errors
 /// This function errors iff `fields` is not consistent with `columns`
 pub fn serialize_batch(
@@ -48,6 +54,38 @@ pub fn serialize_batch(
     Ok((flight_dictionaries, flight_batch))
 }
 
+/// Serializes [`Chunk`] to one or more batches bounded by [`WriteOptions::max_batch_rows`] and
+/// [`WriteOptions::max_batch_bytes`], e.g. to keep messages under gRPC's message size limit.
+/// Each batch is a vector of [`FlightData`] representing the serialized dictionaries it
+/// requires and a [`FlightData`] representing the batch.
+/// # Errors
+/// This function errors iff `fields` is not consistent with `columns`
+pub fn serialize_batches(
+    chunk: &Chunk<Box<dyn Array>>,
+    fields: &[IpcField],
+    options: &WriteOptions,
+) -> Result<Vec<(Vec<FlightData>, FlightData)>> {
+    if fields.len() != chunk.arrays().len() {
+        return Err(Error::InvalidArgumentError("The argument `fields` must be consistent with the columns' schema. Use e.g. &arrow2::io::flight::default_ipc_fields(&schema.fields)".to_string()));
+    }
+
+    let mut dictionary_tracker = DictionaryTracker {
+        dictionaries: Default::default(),
+        cannot_replace: false,
+    };
+
+    let batches = encode_chunk_split(chunk, fields, &mut dictionary_tracker, options)
+        .expect("DictionaryTracker configured above to not error on replacement");
+
+    Ok(batches
+        .into_iter()
+        .map(|(dictionaries, batch)| {
+            let dictionaries = dictionaries.into_iter().map(Into::into).collect();
+            (dictionaries, batch.into())
+        })
+        .collect())
+}
+
 impl From<EncodedData> for FlightData {
     fn from(data: EncodedData) -> Self {
         FlightData {
@@ -122,6 +160,26 @@ pub fn deserialize_batch(
     ipc_schema: &IpcSchema,
     dictionaries: &read::Dictionaries,
 ) -> Result<Chunk<Box<dyn Array>>> {
+    deserialize_batch_with_options(data, fields, ipc_schema, dictionaries, &Default::default())
+}
+
+/// Checks the sizes of the header and of the body of `data` against `options`.
+fn check_flight_data(data: &FlightData, options: &read::ReadOptions) -> Result<()> {
+    options.check_message_size(data.data_header.len())?;
+    options.check_message_size(data.data_body.len())
+}
+
+/// Deserializes [`FlightData`] representing a record batch message to [`Chunk`], limiting the
+/// resources used to read it to `options`.
+pub fn deserialize_batch_with_options(
+    data: &FlightData,
+    fields: &[Field],
+    ipc_schema: &IpcSchema,
+    dictionaries: &read::Dictionaries,
+    options: &read::ReadOptions,
+) -> Result<Chunk<Box<dyn Array>>> {
+    check_flight_data(data, options)?;
+
     // check that the data_header is a record batch message
     let message = arrow_format::ipc::MessageRef::read_as_root(&data.data_header)
         .map_err(|err| Error::OutOfSpec(format!("Unable to get root as message: {err:?}")))?;
@@ -143,6 +201,7 @@ pub fn deserialize_batch(
             &mut reader,
             0,
             length as u64,
+            options,
             &mut Default::default(),
         ),
         _ => Err(Error::nyi(
@@ -158,6 +217,20 @@ pub fn deserialize_dictionary(
     ipc_schema: &IpcSchema,
     dictionaries: &mut read::Dictionaries,
 ) -> Result<()> {
+    deserialize_dictionary_with_options(data, fields, ipc_schema, dictionaries, &Default::default())
+}
+
+/// Deserializes [`FlightData`], assuming it to be a dictionary message, into `dictionaries`,
+/// limiting the resources used to read it to `options`.
+pub fn deserialize_dictionary_with_options(
+    data: &FlightData,
+    fields: &[Field],
+    ipc_schema: &IpcSchema,
+    dictionaries: &mut read::Dictionaries,
+    options: &read::ReadOptions,
+) -> Result<()> {
+    check_flight_data(data, options)?;
+
     let message = ipc::MessageRef::read_as_root(&data.data_header)?;
 
     let chunk = if let ipc::MessageHeaderRef::DictionaryBatch(chunk) = message
@@ -179,6 +252,7 @@ pub fn deserialize_dictionary(
         &mut reader,
         0,
         length as u64,
+        options,
         &mut Default::default(),
     )?;
 
@@ -193,6 +267,23 @@ pub fn deserialize_message(
     ipc_schema: &IpcSchema,
     dictionaries: &mut Dictionaries,
 ) -> Result<Option<Chunk<Box<dyn Array>>>> {
+    deserialize_message_with_options(data, fields, ipc_schema, dictionaries, &Default::default())
+}
+
+/// Deserializes [`FlightData`] like [`deserialize_message`], limiting the resources used to
+/// read it to `options`.
+///
+/// Flight data from untrusted sources should be read with limits, since a corrupted or
+/// malicious message may otherwise declare arbitrarily large allocations.
+pub fn deserialize_message_with_options(
+    data: &FlightData,
+    fields: &[Field],
+    ipc_schema: &IpcSchema,
+    dictionaries: &mut Dictionaries,
+    options: &read::ReadOptions,
+) -> Result<Option<Chunk<Box<dyn Array>>>> {
+    check_flight_data(data, options)?;
+
     let FlightData {
         data_header,
         data_body,
@@ -220,6 +311,7 @@ pub fn deserialize_message(
                 &mut reader,
                 0,
                 length as u64,
+                options,
                 &mut Default::default(),
             )?;
 
@@ -237,6 +329,7 @@ pub fn deserialize_message(
                 &mut reader,
                 0,
                 length as u64,
+                options,
                 &mut Default::default(),
             )?;
             Ok(None)
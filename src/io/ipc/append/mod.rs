@@ -7,6 +7,7 @@ use std::io::{Read, Seek, SeekFrom, Write};
 
 use crate::error::{Error, Result};
 
+use super::checksum::{checksum_block, Checksums};
 use super::endianess::is_native_little_endian;
 use super::read::{self, FileMetadata};
 use super::write::common::DictionaryTracker;
@@ -23,7 +24,7 @@ impl<R: Read + Seek + Write> FileWriter<R> {
     /// * the file is not a valid Arrow IPC file
     pub fn try_from_file(
         mut writer: R,
-        metadata: FileMetadata,
+        mut metadata: FileMetadata,
         options: WriteOptions,
     ) -> Result<FileWriter<R>> {
         if metadata.ipc_schema.is_little_endian != is_native_little_endian() {
@@ -32,6 +33,10 @@ impl<R: Read + Seek + Write> FileWriter<R> {
             ));
         }
 
+        // the existing dictionaries were compressed with the dictionary of the appended ones
+        if metadata.options.zstd_dictionary.is_none() {
+            metadata.options.zstd_dictionary = options.zstd_dictionary.clone();
+        }
         let dictionaries =
             read::read_file_dictionaries(&mut writer, &metadata, &mut Default::default())?;
 
@@ -52,6 +57,25 @@ impl<R: Read + Seek + Write> FileWriter<R> {
             .map_err(|_| Error::oos("The block's body length must be a positive number"))?;
         let offset: u64 = offset + meta_data_length + body_length;
 
+        // the existing blocks are checksummed when the file was written without checksums
+        let checksums = match (options.checksum, metadata.checksums) {
+            (false, _) => Default::default(),
+            (true, Some(checksums)) => checksums,
+            (true, None) => Checksums {
+                dictionaries: metadata
+                    .dictionaries
+                    .iter()
+                    .flatten()
+                    .map(|block| checksum_block(&mut writer, block))
+                    .collect::<Result<_>>()?,
+                record_batches: metadata
+                    .blocks
+                    .iter()
+                    .map(|block| checksum_block(&mut writer, block))
+                    .collect::<Result<_>>()?,
+            },
+        };
+
         writer.seek(SeekFrom::Start(offset))?;
 
         Ok(FileWriter {
@@ -68,6 +92,9 @@ impl<R: Read + Seek + Write> FileWriter<R> {
                 cannot_replace: true,
             },
             encoded_message: Default::default(),
+            checksums,
+            // the custom metadata of the file is kept unless replaced
+            custom_metadata: metadata.custom_metadata,
         })
     }
 }
@@ -0,0 +1,225 @@
+//! CRC32 checksums of the blocks of Arrow IPC files, stored in the custom metadata of their footer.
+use std::io::{Cursor, Read, Seek, SeekFrom, Write};
+
+use arrow_format::ipc::{Block, KeyValue};
+
+use crate::error::{Error, Result};
+
+use super::read::OutOfSpecKind;
+
+/// The key of the footer's custom metadata with the checksums of the dictionary blocks
+const DICTIONARIES_KEY: &str = "ARROW2:dictionary_checksums";
+/// The key of the footer's custom metadata with the checksums of the record batch blocks
+const RECORD_BATCHES_KEY: &str = "ARROW2:record_batch_checksums";
+
+/// Whether `key` is a key of the footer's custom metadata reserved for the checksums
+pub(crate) fn is_checksum_key(key: &str) -> bool {
+    key == DICTIONARIES_KEY || key == RECORD_BATCHES_KEY
+}
+
+/// The CRC32 checksums of the blocks of an IPC file, in the order of the blocks in its footer.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(crate) struct Checksums {
+    pub dictionaries: Vec<u32>,
+    pub record_batches: Vec<u32>,
+}
+
+fn serialize(checksums: &[u32]) -> String {
+    checksums
+        .iter()
+        .map(|checksum| format!("{checksum:08x}"))
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+fn deserialize(value: &str, num_blocks: usize) -> Result<Vec<u32>> {
+    let checksums = if value.is_empty() {
+        vec![]
+    } else {
+        value
+            .split(',')
+            .map(|checksum| {
+                u32::from_str_radix(checksum, 16)
+                    .map_err(|_| Error::from(OutOfSpecKind::InvalidChecksums))
+            })
+            .collect::<Result<Vec<_>>>()?
+    };
+    if checksums.len() != num_blocks {
+        return Err(Error::from(OutOfSpecKind::InvalidChecksums));
+    }
+    Ok(checksums)
+}
+
+impl Checksums {
+    /// Serializes the checksums to the entries of the custom metadata of a footer
+    pub fn to_custom_metadata(&self) -> Vec<KeyValue> {
+        vec![
+            KeyValue {
+                key: Some(DICTIONARIES_KEY.to_string()),
+                value: Some(serialize(&self.dictionaries)),
+            },
+            KeyValue {
+                key: Some(RECORD_BATCHES_KEY.to_string()),
+                value: Some(serialize(&self.record_batches)),
+            },
+        ]
+    }
+
+    /// Deserializes the checksums from the entries of the custom metadata of a footer,
+    /// returning `None` when the file was written without checksums.
+    pub fn try_from_custom_metadata<'a, I: Iterator<Item = (&'a str, &'a str)>>(
+        entries: I,
+        num_dictionaries: usize,
+        num_record_batches: usize,
+    ) -> Result<Option<Self>> {
+        let mut dictionaries = None;
+        let mut record_batches = None;
+        for (key, value) in entries {
+            match key {
+                DICTIONARIES_KEY => dictionaries = Some(deserialize(value, num_dictionaries)?),
+                RECORD_BATCHES_KEY => {
+                    record_batches = Some(deserialize(value, num_record_batches)?)
+                }
+                _ => {}
+            }
+        }
+        match (dictionaries, record_batches) {
+            (Some(dictionaries), Some(record_batches)) => Ok(Some(Self {
+                dictionaries,
+                record_batches,
+            })),
+            (None, None) => Ok(None),
+            _ => Err(Error::from(OutOfSpecKind::InvalidChecksums)),
+        }
+    }
+}
+
+/// A [`Write`] that computes the checksum of the bytes written through it
+pub(crate) struct ChecksumWriter<W: Write> {
+    writer: W,
+    hasher: crc32fast::Hasher,
+}
+
+impl<W: Write> ChecksumWriter<W> {
+    pub fn new(writer: W) -> Self {
+        Self {
+            writer,
+            hasher: crc32fast::Hasher::new(),
+        }
+    }
+
+    /// Returns the checksum of the bytes written so far
+    pub fn finalize(self) -> u32 {
+        self.hasher.finalize()
+    }
+}
+
+impl<W: Write> Write for ChecksumWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        let written = self.writer.write(buf)?;
+        self.hasher.update(&buf[..written]);
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.writer.flush()
+    }
+}
+
+fn block_range(block: &Block) -> Result<(u64, u64)> {
+    let offset: u64 = block
+        .offset
+        .try_into()
+        .map_err(|_| Error::from(OutOfSpecKind::UnexpectedNegativeInteger))?;
+    let meta_data_length: u64 = block
+        .meta_data_length
+        .try_into()
+        .map_err(|_| Error::from(OutOfSpecKind::UnexpectedNegativeInteger))?;
+    let body_length: u64 = block
+        .body_length
+        .try_into()
+        .map_err(|_| Error::from(OutOfSpecKind::UnexpectedNegativeInteger))?;
+    Ok((offset, meta_data_length + body_length))
+}
+
+fn check(expected: u32, actual: u32) -> Result<()> {
+    if expected != actual {
+        return Err(Error::from(OutOfSpecKind::InvalidChecksum {
+            expected,
+            actual,
+        }));
+    }
+    Ok(())
+}
+
+/// Returns the checksum of `block` of the file in `reader`
+pub(crate) fn checksum_block<R: Read + Seek>(reader: &mut R, block: &Block) -> Result<u32> {
+    let (offset, length) = block_range(block)?;
+    reader.seek(SeekFrom::Start(offset))?;
+    let mut writer = ChecksumWriter::new(std::io::sink());
+    std::io::copy(&mut reader.by_ref().take(length), &mut writer)?;
+    Ok(writer.finalize())
+}
+
+/// The bytes of a block of a file, read at the positions of the file.
+pub(crate) struct BlockReader<'a> {
+    data: Cursor<&'a [u8]>,
+    offset: u64,
+}
+
+impl Read for BlockReader<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        self.data.read(buf)
+    }
+}
+
+impl Seek for BlockReader<'_> {
+    fn seek(&mut self, pos: SeekFrom) -> std::io::Result<u64> {
+        let pos = match pos {
+            SeekFrom::Start(position) => {
+                SeekFrom::Start(position.checked_sub(self.offset).ok_or_else(|| {
+                    std::io::Error::new(
+                        std::io::ErrorKind::InvalidInput,
+                        "cannot seek before the start of a block",
+                    )
+                })?)
+            }
+            pos => pos,
+        };
+        Ok(self.data.seek(pos)? + self.offset)
+    }
+}
+
+/// Reads `block` of the file in `reader` to `scratch` and errors iff its checksum is not
+/// `expected`. The returned [`BlockReader`] reads the block's bytes, so that the block is only
+/// read once from `reader`.
+pub(crate) fn read_verified_block<'a, R: Read + Seek>(
+    reader: &mut R,
+    block: &Block,
+    expected: u32,
+    scratch: &'a mut Vec<u8>,
+) -> Result<BlockReader<'a>> {
+    let (offset, length) = block_range(block)?;
+    reader.seek(SeekFrom::Start(offset))?;
+    scratch.clear();
+    let read = reader.by_ref().take(length).read_to_end(scratch)?;
+    if read as u64 != length {
+        return Err(Error::from(OutOfSpecKind::InvalidChecksums));
+    }
+    check(expected, crc32fast::hash(scratch))?;
+    Ok(BlockReader {
+        data: Cursor::new(scratch.as_slice()),
+        offset,
+    })
+}
+
+/// Errors iff the checksum of `block` of the file `data` is not `expected`
+pub(crate) fn verify_block_bytes(data: &[u8], block: &Block, expected: u32) -> Result<()> {
+    let (offset, length) = block_range(block)?;
+    let bytes = usize::try_from(offset)
+        .ok()
+        .zip(usize::try_from(length).ok())
+        .and_then(|(offset, length)| data.get(offset..offset.checked_add(length)?))
+        .ok_or_else(|| Error::from(OutOfSpecKind::InvalidChecksums))?;
+    check(expected, crc32fast::hash(bytes))
+}
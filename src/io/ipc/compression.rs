@@ -1,18 +1,85 @@
 use crate::error::Result;
 
+/// The magic number of an LZ4 frame, as specified by the Arrow format
+#[cfg(feature = "io_ipc_compression")]
+const LZ4_FRAME_MAGIC: [u8; 4] = [0x04, 0x22, 0x4d, 0x18];
+/// The magic number of the legacy LZ4 frame, written by old LZ4 command line tools
+#[cfg(feature = "io_ipc_compression")]
+const LZ4_LEGACY_FRAME_MAGIC: [u8; 4] = [0x02, 0x21, 0x4c, 0x18];
+
+/// Decompresses an LZ4 buffer into `output_buf`.
+///
+/// Besides the LZ4 frame format mandated by the Arrow format, this accepts buffers in the
+/// legacy LZ4 frame format and raw LZ4 blocks, which old Arrow Java and C++ versions wrote.
 #[cfg(feature = "io_ipc_compression")]
 #[cfg_attr(docsrs, doc(cfg(feature = "io_ipc_compression")))]
 pub fn decompress_lz4(input_buf: &[u8], output_buf: &mut [u8]) -> Result<()> {
     use std::io::Read;
-    let mut decoder = lz4::Decoder::new(input_buf)?;
-    decoder.read_exact(output_buf).map_err(|e| e.into())
+    if input_buf.starts_with(&LZ4_FRAME_MAGIC) {
+        let mut decoder = lz4::Decoder::new(input_buf)?;
+        decoder.read_exact(output_buf).map_err(|e| e.into())
+    } else if input_buf.starts_with(&LZ4_LEGACY_FRAME_MAGIC) {
+        decompress_lz4_legacy_frame(&input_buf[LZ4_LEGACY_FRAME_MAGIC.len()..], output_buf)
+    } else {
+        decompress_lz4_block(input_buf, output_buf)
+    }
+}
+
+/// Decompresses a raw LZ4 block whose decompressed length is the length of `output_buf`
+#[cfg(feature = "io_ipc_compression")]
+fn decompress_lz4_block(input_buf: &[u8], output_buf: &mut [u8]) -> Result<()> {
+    use crate::error::Error;
+    let expected = output_buf.len();
+    let size = i32::try_from(expected).map_err(|_| {
+        Error::OutOfSpec("An LZ4 block must decompress to at most i32::MAX bytes".to_string())
+    })?;
+    let length = lz4::block::decompress_to_buffer(input_buf, Some(size), output_buf)?;
+    if length != expected {
+        return Err(Error::OutOfSpec(format!(
+            "An LZ4 block decompressed to {length} bytes, but {expected} were expected"
+        )));
+    }
+    Ok(())
+}
+
+/// Decompresses the blocks of a legacy LZ4 frame (without its magic number), each prefixed by
+/// its compressed length
+#[cfg(feature = "io_ipc_compression")]
+fn decompress_lz4_legacy_frame(mut input_buf: &[u8], output_buf: &mut [u8]) -> Result<()> {
+    use crate::error::Error;
+    let mut written = 0;
+    while written < output_buf.len() {
+        if input_buf.len() < 4 {
+            return Err(Error::OutOfSpec(
+                "A legacy LZ4 frame ended before its decompressed length".to_string(),
+            ));
+        }
+        let (length, rest) = input_buf.split_at(4);
+        let length = u32::from_le_bytes(length.try_into().unwrap()) as usize;
+        if length > rest.len() {
+            return Err(Error::OutOfSpec(
+                "A legacy LZ4 block is longer than its frame".to_string(),
+            ));
+        }
+        let (block, rest) = rest.split_at(length);
+        input_buf = rest;
+
+        let remaining = &mut output_buf[written..];
+        let size = i32::try_from(remaining.len()).unwrap_or(i32::MAX);
+        written += lz4::block::decompress_to_buffer(block, Some(size), remaining)?;
+    }
+    Ok(())
 }
 
 #[cfg(feature = "io_ipc_compression")]
 #[cfg_attr(docsrs, doc(cfg(feature = "io_ipc_compression")))]
-pub fn decompress_zstd(input_buf: &[u8], output_buf: &mut [u8]) -> Result<()> {
+pub fn decompress_zstd(
+    input_buf: &[u8],
+    output_buf: &mut [u8],
+    dictionary: Option<&[u8]>,
+) -> Result<()> {
     use std::io::Read;
-    let mut decoder = zstd::Decoder::new(input_buf)?;
+    let mut decoder = zstd::Decoder::with_dictionary(input_buf, dictionary.unwrap_or_default())?;
     decoder.read_exact(output_buf).map_err(|e| e.into())
 }
 
@@ -23,18 +90,23 @@ pub fn decompress_lz4(_input_buf: &[u8], _output_buf: &mut [u8]) -> Result<()> {
 }
 
 #[cfg(not(feature = "io_ipc_compression"))]
-pub fn decompress_zstd(_input_buf: &[u8], _output_buf: &mut [u8]) -> Result<()> {
+pub fn decompress_zstd(
+    _input_buf: &[u8],
+    _output_buf: &mut [u8],
+    _dictionary: Option<&[u8]>,
+) -> Result<()> {
     use crate::error::Error;
     Err(Error::OutOfSpec("The crate was compiled without IPC compression. Use `io_ipc_compression` to read compressed IPC.".to_string()))
 }
 
 #[cfg(feature = "io_ipc_compression")]
 #[cfg_attr(docsrs, doc(cfg(feature = "io_ipc_compression")))]
-pub fn compress_lz4(input_buf: &[u8], output_buf: &mut Vec<u8>) -> Result<()> {
+pub fn compress_lz4(input_buf: &[u8], output_buf: &mut Vec<u8>, level: Option<i32>) -> Result<()> {
     use std::io::Write;
 
     use crate::error::Error;
     let mut encoder = lz4::EncoderBuilder::new()
+        .level(level.unwrap_or_default().max(0) as u32)
         .build(output_buf)
         .map_err(Error::from)?;
     encoder.write_all(input_buf)?;
@@ -43,18 +115,37 @@ pub fn compress_lz4(input_buf: &[u8], output_buf: &mut Vec<u8>) -> Result<()> {
 
 #[cfg(feature = "io_ipc_compression")]
 #[cfg_attr(docsrs, doc(cfg(feature = "io_ipc_compression")))]
-pub fn compress_zstd(input_buf: &[u8], output_buf: &mut Vec<u8>) -> Result<()> {
-    zstd::stream::copy_encode(input_buf, output_buf, 0).map_err(|e| e.into())
+pub fn compress_zstd(
+    input_buf: &[u8],
+    output_buf: &mut Vec<u8>,
+    level: Option<i32>,
+    dictionary: Option<&[u8]>,
+) -> Result<()> {
+    use std::io::Write;
+
+    let mut encoder = zstd::Encoder::with_dictionary(
+        output_buf,
+        level.unwrap_or_default(),
+        dictionary.unwrap_or_default(),
+    )?;
+    encoder.write_all(input_buf)?;
+    encoder.finish()?;
+    Ok(())
 }
 
 #[cfg(not(feature = "io_ipc_compression"))]
-pub fn compress_lz4(_input_buf: &[u8], _output_buf: &[u8]) -> Result<()> {
+pub fn compress_lz4(_input_buf: &[u8], _output_buf: &[u8], _level: Option<i32>) -> Result<()> {
     use crate::error::Error;
     Err(Error::OutOfSpec("The crate was compiled without IPC compression. Use `io_ipc_compression` to write compressed IPC.".to_string()))
 }
 
 #[cfg(not(feature = "io_ipc_compression"))]
-pub fn compress_zstd(_input_buf: &[u8], _output_buf: &[u8]) -> Result<()> {
+pub fn compress_zstd(
+    _input_buf: &[u8],
+    _output_buf: &[u8],
+    _level: Option<i32>,
+    _dictionary: Option<&[u8]>,
+) -> Result<()> {
     use crate::error::Error;
     Err(Error::OutOfSpec("The crate was compiled without IPC compression. Use `io_ipc_compression` to write compressed IPC.".to_string()))
 }
@@ -69,23 +160,77 @@ mod tests {
     fn round_trip_zstd() {
         let data: Vec<u8> = (0..200u8).map(|x| x % 10).collect();
         let mut buffer = vec![];
-        compress_zstd(&data, &mut buffer).unwrap();
+        compress_zstd(&data, &mut buffer, None, None).unwrap();
 
         let mut result = vec![0; 200];
-        decompress_zstd(&buffer, &mut result).unwrap();
+        decompress_zstd(&buffer, &mut result, None).unwrap();
         assert_eq!(data, result);
     }
 
+    #[cfg(feature = "io_ipc_compression")]
+    #[test]
+    #[cfg_attr(miri, ignore)] // ZSTD uses foreign calls that miri does not support
+    fn round_trip_zstd_dictionary() {
+        let data: Vec<u8> = (0..200u8).map(|x| x % 10).collect();
+        let dictionary: Vec<u8> = (0..100u8).collect();
+        let mut buffer = vec![];
+        compress_zstd(&data, &mut buffer, Some(19), Some(&dictionary)).unwrap();
+
+        let mut result = vec![0; 200];
+        decompress_zstd(&buffer, &mut result, Some(&dictionary)).unwrap();
+        assert_eq!(data, result);
+
+        // the dictionary is required to decompress
+        assert!(decompress_zstd(&buffer, &mut result, None).is_err());
+    }
+
     #[cfg(feature = "io_ipc_compression")]
     #[test]
     #[cfg_attr(miri, ignore)] // LZ4 uses foreign calls that miri does not support
     fn round_trip_lz4() {
         let data: Vec<u8> = (0..200u8).map(|x| x % 10).collect();
         let mut buffer = vec![];
-        compress_lz4(&data, &mut buffer).unwrap();
+        compress_lz4(&data, &mut buffer, Some(9)).unwrap();
+
+        let mut result = vec![0; 200];
+        decompress_lz4(&buffer, &mut result).unwrap();
+        assert_eq!(data, result);
+    }
+
+    #[cfg(feature = "io_ipc_compression")]
+    #[test]
+    #[cfg_attr(miri, ignore)] // LZ4 uses foreign calls that miri does not support
+    fn decompress_lz4_block() {
+        let data: Vec<u8> = (0..200u8).map(|x| x % 10).collect();
+        let buffer = lz4::block::compress(&data, None, false).unwrap();
 
         let mut result = vec![0; 200];
         decompress_lz4(&buffer, &mut result).unwrap();
         assert_eq!(data, result);
+
+        // the block must decompress to the expected length
+        let mut result = vec![0; 201];
+        assert!(decompress_lz4(&buffer, &mut result).is_err());
+    }
+
+    #[cfg(feature = "io_ipc_compression")]
+    #[test]
+    #[cfg_attr(miri, ignore)] // LZ4 uses foreign calls that miri does not support
+    fn decompress_lz4_legacy_frame() {
+        let data: Vec<u8> = (0..200u8).map(|x| x % 10).collect();
+        let mut buffer = LZ4_LEGACY_FRAME_MAGIC.to_vec();
+        for chunk in data.chunks(128) {
+            let block = lz4::block::compress(chunk, None, false).unwrap();
+            buffer.extend_from_slice(&(block.len() as u32).to_le_bytes());
+            buffer.extend_from_slice(&block);
+        }
+
+        let mut result = vec![0; 200];
+        decompress_lz4(&buffer, &mut result).unwrap();
+        assert_eq!(data, result);
+
+        // a truncated frame errors
+        let mut result = vec![0; 200];
+        assert!(decompress_lz4(&buffer[..buffer.len() - 1], &mut result).is_err());
     }
 }
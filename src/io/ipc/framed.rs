@@ -0,0 +1,314 @@
+//! Length-prefixed frames of Arrow IPC messages, to embed Arrow data in other containers.
+//!
+//! A frame is an [encapsulated IPC message](https://arrow.apache.org/docs/format/Columnar.html#encapsulated-message-format)
+//! prefixed by its length in bytes, as an `u64` in little endian. Frames can be concatenated,
+//! e.g. to send the dictionaries of a [`Chunk`] alongside it, and embedded as-is in payloads of
+//! other protocols (e.g. gRPC or Kafka messages), whose receivers can split them without
+//! parsing IPC messages.
+//!
+//! Because the prefix is 8 bytes long, a frame preserves the alignment of the message's
+//! body, which allows [`mmap_frames_unchecked`](crate::mmap::mmap_frames_unchecked) to create
+//! arrays that share the received buffer instead of copying it.
+//! # Example
+//! ```
+//! use arrow2::array::{Array, Int32Array};
+//! use arrow2::chunk::Chunk;
+//! use arrow2::datatypes::{DataType, Field, Schema};
+//! use arrow2::io::ipc::framed::{write_chunk_frames, write_schema_frame, FrameReader};
+//! use arrow2::io::ipc::write::{default_ipc_fields, WriteOptions};
+//! # fn main() -> arrow2::error::Result<()> {
+//! let schema = Schema::from(vec![Field::new("a", DataType::Int32, true)]);
+//! let chunk = Chunk::new(vec![Int32Array::from([Some(1), None]).boxed()]);
+//!
+//! // the sender
+//! let mut schema_payload = vec![];
+//! write_schema_frame(&mut schema_payload, &schema, None)?;
+//! let mut payload = vec![];
+//! let fields = default_ipc_fields(&schema.fields);
+//! write_chunk_frames(&mut payload, &chunk, &fields, &WriteOptions::default())?;
+//!
+//! // the receiver
+//! let mut reader = FrameReader::try_new(&schema_payload)?;
+//! let chunks = reader.read(&payload)?;
+//! assert_eq!(chunks, vec![chunk]);
+//! # Ok(())
+//! # }
+//! ```
+use std::io::{Cursor, Write};
+
+use arrow_format::ipc::planus::ReadAsRoot;
+use arrow_format::ipc::{MessageHeaderRef, MessageRef};
+
+use crate::array::Array;
+use crate::chunk::Chunk;
+use crate::datatypes::Schema;
+use crate::error::{Error, Result};
+
+use super::read::{deserialize_schema, read_dictionary, read_record_batch};
+use super::read::{Dictionaries, OutOfSpecKind, ReadOptions};
+use super::write::common::{
+    encode_chunk_split, pad_to_64, DictionaryTracker, EncodedData, WriteOptions,
+};
+use super::write::common_sync::write_message;
+use super::write::{default_ipc_fields, schema_to_bytes};
+use super::{IpcField, IpcSchema, CONTINUATION_MARKER};
+
+/// The length, in bytes, of the prefix of every frame
+pub const PREFIX_LENGTH: usize = 8;
+
+/// Returns the number of bytes [`write_message`] writes for `encoded`
+fn message_length(encoded: &EncodedData) -> usize {
+    let metadata_length = (encoded.ipc_message.len() + 8 + 7) & !7;
+    let body_length = encoded.arrow_data.len() + pad_to_64(encoded.arrow_data.len());
+    metadata_length + body_length
+}
+
+/// Writes `encoded` as a frame to `writer`, returning the number of bytes written.
+pub fn write_frame<W: Write>(writer: &mut W, encoded: &EncodedData) -> Result<usize> {
+    let length = message_length(encoded);
+    writer.write_all(&(length as u64).to_le_bytes())?;
+    let (metadata_length, body_length) = write_message(writer, encoded)?;
+    debug_assert_eq!(metadata_length + body_length, length);
+    Ok(PREFIX_LENGTH + length)
+}
+
+/// Writes a frame with the schema message of `schema` to `writer`, returning the number of
+/// bytes written. Use `ipc_fields` to declare dictionary ids of the schema.
+pub fn write_schema_frame<W: Write>(
+    writer: &mut W,
+    schema: &Schema,
+    ipc_fields: Option<&[IpcField]>,
+) -> Result<usize> {
+    let ipc_message = if let Some(ipc_fields) = ipc_fields {
+        schema_to_bytes(schema, ipc_fields)
+    } else {
+        schema_to_bytes(schema, &default_ipc_fields(&schema.fields))
+    };
+    let encoded = EncodedData {
+        ipc_message,
+        arrow_data: vec![],
+    };
+    write_frame(writer, &encoded)
+}
+
+/// Writes the frames of the dictionaries of `chunk` followed by the frame of `chunk` to
+/// `writer`, returning the number of bytes written.
+///
+/// The frames are self-contained: every call writes all dictionaries of `chunk`. `chunk` is
+/// written as multiple record batches when it exceeds the limits of `options`.
+/// # Errors
+/// This function errors iff `fields` is not consistent with `chunk`
+pub fn write_chunk_frames<W: Write>(
+    writer: &mut W,
+    chunk: &Chunk<Box<dyn Array>>,
+    fields: &[IpcField],
+    options: &WriteOptions,
+) -> Result<usize> {
+    if fields.len() != chunk.arrays().len() {
+        return Err(Error::InvalidArgumentError(
+            "The argument `fields` must be consistent with the columns of the chunk".to_string(),
+        ));
+    }
+
+    let mut dictionary_tracker = DictionaryTracker {
+        dictionaries: Default::default(),
+        cannot_replace: false,
+    };
+    let batches = encode_chunk_split(chunk, fields, &mut dictionary_tracker, options)?;
+
+    let mut written = 0;
+    for (encoded_dictionaries, encoded_chunk) in batches.iter() {
+        for encoded in encoded_dictionaries.iter() {
+            written += write_frame(writer, encoded)?;
+        }
+        written += write_frame(writer, encoded_chunk)?;
+    }
+    Ok(written)
+}
+
+/// A message of a frame
+pub(crate) struct Frame<'a> {
+    /// the flatbuffer of the message
+    metadata: &'a [u8],
+    pub(crate) message: MessageRef<'a>,
+    /// the offset of the message's body in the buffer
+    pub(crate) body_offset: usize,
+    body_length: usize,
+}
+
+/// Splits `data` in the messages of its frames
+pub(crate) fn read_frames(data: &[u8]) -> Result<Vec<Frame<'_>>> {
+    let mut frames = vec![];
+    let mut position = 0;
+    while position < data.len() {
+        let prefix = data
+            .get(position..position + PREFIX_LENGTH)
+            .ok_or_else(|| Error::oos("The prefix of a frame is truncated"))?;
+        let length: usize = u64::from_le_bytes(prefix.try_into().unwrap())
+            .try_into()
+            .map_err(|_| Error::Overflow)?;
+        let start = position + PREFIX_LENGTH;
+        let end = start
+            .checked_add(length)
+            .filter(|end| *end <= data.len())
+            .ok_or_else(|| Error::oos("A frame is longer than its buffer"))?;
+        frames.push(read_message(data, start, end)?);
+        position = end;
+    }
+    Ok(frames)
+}
+
+/// Reads the encapsulated message in `data[start..end]`
+fn read_message(data: &[u8], start: usize, end: usize) -> Result<Frame<'_>> {
+    let bytes = &data[start..end];
+    let mut metadata_start = 4;
+    let mut metadata_length = bytes
+        .get(..4)
+        .ok_or_else(|| Error::oos("The message of a frame is truncated"))?;
+    if metadata_length == CONTINUATION_MARKER {
+        metadata_length = bytes
+            .get(4..8)
+            .ok_or_else(|| Error::oos("The message of a frame is truncated"))?;
+        metadata_start = 8;
+    }
+    let metadata_length: usize = i32::from_le_bytes(metadata_length.try_into().unwrap())
+        .try_into()
+        .map_err(|_| Error::from(OutOfSpecKind::NegativeFooterLength))?;
+
+    let metadata = bytes
+        .get(metadata_start..metadata_start + metadata_length)
+        .ok_or_else(|| Error::oos("The message of a frame is truncated"))?;
+    let message = MessageRef::read_as_root(metadata)
+        .map_err(|err| Error::from(OutOfSpecKind::InvalidFlatbufferMessage(err)))?;
+
+    let body_length: usize = message
+        .body_length()
+        .map_err(|err| Error::from(OutOfSpecKind::InvalidFlatbufferBodyLength(err)))?
+        .try_into()
+        .map_err(|_| Error::from(OutOfSpecKind::UnexpectedNegativeInteger))?;
+    let body_offset = start + metadata_start + metadata_length;
+    body_offset
+        .checked_add(body_length)
+        .filter(|body_end| *body_end <= end)
+        .ok_or_else(|| Error::oos("The body of a message is longer than its frame"))?;
+
+    Ok(Frame {
+        metadata,
+        message,
+        body_offset,
+        body_length,
+    })
+}
+
+pub(crate) fn header<'a>(message: &MessageRef<'a>) -> Result<MessageHeaderRef<'a>> {
+    message
+        .header()
+        .map_err(|err| Error::from(OutOfSpecKind::InvalidFlatbufferHeader(err)))?
+        .ok_or_else(|| Error::from(OutOfSpecKind::MissingMessageHeader))
+}
+
+/// Reads [`Chunk`]s from frames written by [`write_chunk_frames`].
+///
+/// Dictionaries read from frames are kept, so that frames of chunks can re-use dictionaries
+/// of previous frames.
+#[derive(Debug, Clone)]
+pub struct FrameReader {
+    pub(crate) schema: Schema,
+    pub(crate) ipc_schema: IpcSchema,
+    pub(crate) dictionaries: Dictionaries,
+    options: ReadOptions,
+    scratch: Vec<u8>,
+}
+
+impl FrameReader {
+    /// Creates a new [`FrameReader`] from a frame written by [`write_schema_frame`].
+    /// # Errors
+    /// This function errors iff `schema_frame` is not a single frame with a valid schema message
+    pub fn try_new(schema_frame: &[u8]) -> Result<Self> {
+        let frames = read_frames(schema_frame)?;
+        let frame = match frames.as_slice() {
+            [frame] => frame,
+            _ => return Err(Error::oos("A schema must be a single frame")),
+        };
+        if !matches!(header(&frame.message)?, MessageHeaderRef::Schema(_)) {
+            return Err(Error::from(OutOfSpecKind::UnexpectedMessageType));
+        }
+        let (schema, ipc_schema) = deserialize_schema(frame.metadata)?;
+        Ok(Self {
+            schema,
+            ipc_schema,
+            dictionaries: Default::default(),
+            options: Default::default(),
+            scratch: vec![],
+        })
+    }
+
+    /// Sets the [`ReadOptions`] that the frames are read with (defaults to no limits).
+    ///
+    /// Frames from untrusted sources should be read with limits, since a corrupted or
+    /// malicious message may otherwise declare arbitrarily large allocations.
+    pub fn with_read_options(mut self, options: ReadOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Returns the [`Schema`] of the chunks read by this reader.
+    pub fn schema(&self) -> &Schema {
+        &self.schema
+    }
+
+    /// Returns the [`IpcSchema`] of the chunks read by this reader.
+    pub fn ipc_schema(&self) -> &IpcSchema {
+        &self.ipc_schema
+    }
+
+    /// Reads the frames in `data`, returning the [`Chunk`]s of its record batch messages.
+    /// Dictionary messages update the dictionaries of this reader.
+    /// # Errors
+    /// This function errors iff `data` is not a sequence of valid frames of record batch and
+    /// dictionary messages
+    pub fn read(&mut self, data: &[u8]) -> Result<Vec<Chunk<Box<dyn Array>>>> {
+        let mut chunks = vec![];
+        for frame in read_frames(data)? {
+            self.options.check_message_size(frame.metadata.len())?;
+            self.options.check_message_size(frame.body_length)?;
+            let body = &data[frame.body_offset..frame.body_offset + frame.body_length];
+            let mut reader = Cursor::new(body);
+            match header(&frame.message)? {
+                MessageHeaderRef::RecordBatch(batch) => {
+                    let version = frame
+                        .message
+                        .version()
+                        .map_err(|err| Error::from(OutOfSpecKind::InvalidFlatbufferVersion(err)))?;
+                    chunks.push(read_record_batch(
+                        batch,
+                        &self.schema.fields,
+                        &self.ipc_schema,
+                        None,
+                        None,
+                        &self.dictionaries,
+                        version,
+                        &mut reader,
+                        0,
+                        body.len() as u64,
+                        &self.options,
+                        &mut self.scratch,
+                    )?);
+                }
+                MessageHeaderRef::DictionaryBatch(batch) => read_dictionary(
+                    batch,
+                    &self.schema.fields,
+                    &self.ipc_schema,
+                    &mut self.dictionaries,
+                    &mut reader,
+                    0,
+                    body.len() as u64,
+                    &self.options,
+                    &mut self.scratch,
+                )?,
+                _ => return Err(Error::from(OutOfSpecKind::UnexpectedMessageType)),
+            }
+        }
+        Ok(chunks)
+    }
+}
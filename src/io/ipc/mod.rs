@@ -36,12 +36,12 @@
 //! # use arrow2::chunk::Chunk;
 //! # use arrow2::error::Error;
 //! // Setup the writer
-//! let path = "example.arrow".to_string();
+//! let path = std::env::temp_dir().join("example.arrow");
 //! let mut file = File::create(&path)?;
 //! let x_coord = Field::new("x", DataType::Int32, false);
 //! let y_coord = Field::new("y", DataType::Int32, false);
 //! let schema = Schema::from(vec![x_coord, y_coord]);
-//! let options = WriteOptions {compression: None};
+//! let options = WriteOptions::default();
 //! let mut writer = FileWriter::try_new(file, schema, None, options)?;
 //!
 //! // Setup the data
@@ -73,10 +73,12 @@
 //! [2](https://github.com/jorgecarleitao/arrow2/blob/main/examples/ipc_file_write.rs),
 //! [3](https://github.com/jorgecarleitao/arrow2/tree/main/examples/ipc_pyarrow)).
 
+pub(crate) mod checksum;
 mod compression;
 mod endianess;
 
 pub mod append;
+pub mod framed;
 pub mod read;
 pub mod write;
 
@@ -1,4 +1,5 @@
 use ahash::AHashMap;
+use std::cell::Cell;
 use std::collections::VecDeque;
 use std::io::{Read, Seek};
 
@@ -12,7 +13,7 @@ use crate::io::ipc::read::OutOfSpecKind;
 use crate::io::ipc::{IpcField, IpcSchema};
 
 use super::deserialize::{read, skip};
-use super::Dictionaries;
+use super::{Compression, Dictionaries, ReadOptions};
 
 #[derive(Debug, Eq, PartialEq, Hash)]
 enum ProjectionResult<A> {
@@ -87,6 +88,7 @@ pub fn read_record_batch<R: Read + Seek>(
     reader: &mut R,
     block_offset: u64,
     file_size: u64,
+    options: &ReadOptions,
     scratch: &mut Vec<u8>,
 ) -> Result<Chunk<Box<dyn Array>>> {
     assert_eq!(fields.len(), ipc_schema.fields.len());
@@ -113,6 +115,13 @@ pub fn read_record_batch<R: Read + Seek>(
             file_size,
         }));
     }
+    let buffers_size = usize::try_from(buffers_size).unwrap_or(usize::MAX);
+    if buffers_size > options.max_total_allocation {
+        return Err(Error::from(OutOfSpecKind::AllocationTooLarge {
+            size: buffers_size,
+            max_total_allocation: options.max_total_allocation,
+        }));
+    }
 
     let field_nodes = batch
         .nodes()
@@ -120,6 +129,31 @@ pub fn read_record_batch<R: Read + Seek>(
         .ok_or_else(|| Error::from(OutOfSpecKind::MissingMessageNodes))?;
     let mut field_nodes = field_nodes.iter().collect::<VecDeque<_>>();
 
+    // negative lengths error when the nodes are read
+    if let Some(length) = field_nodes
+        .iter()
+        .filter_map(|node| usize::try_from(node.length()).ok())
+        .find(|length| *length > options.max_array_len)
+    {
+        return Err(Error::from(OutOfSpecKind::ArrayTooLong {
+            length,
+            max_array_len: options.max_array_len,
+        }));
+    }
+
+    let remaining_allocation = Cell::new(options.max_total_allocation - buffers_size);
+    let compression = batch
+        .compression()
+        .map_err(|err| Error::from(OutOfSpecKind::InvalidFlatbufferCompression(err)))?
+        .map(|body| {
+            Compression::new(
+                body,
+                options.zstd_dictionary.as_deref(),
+                &remaining_allocation,
+                options.max_total_allocation,
+            )
+        });
+
     let columns = if let Some(projection) = projection {
         let projection =
             ProjectionIter::new(projection, fields.iter().zip(ipc_schema.fields.iter()));
@@ -135,9 +169,7 @@ pub fn read_record_batch<R: Read + Seek>(
                     dictionaries,
                     block_offset,
                     ipc_schema.is_little_endian,
-                    batch.compression().map_err(|err| {
-                        Error::from(OutOfSpecKind::InvalidFlatbufferCompression(err))
-                    })?,
+                    compression,
                     limit,
                     version,
                     scratch,
@@ -163,9 +195,7 @@ pub fn read_record_batch<R: Read + Seek>(
                     dictionaries,
                     block_offset,
                     ipc_schema.is_little_endian,
-                    batch.compression().map_err(|err| {
-                        Error::from(OutOfSpecKind::InvalidFlatbufferCompression(err))
-                    })?,
+                    compression,
                     limit,
                     version,
                     scratch,
@@ -237,6 +267,7 @@ pub fn read_dictionary<R: Read + Seek>(
     reader: &mut R,
     block_offset: u64,
     file_size: u64,
+    options: &ReadOptions,
     scratch: &mut Vec<u8>,
 ) -> Result<()> {
     if batch
@@ -284,6 +315,7 @@ pub fn read_dictionary<R: Read + Seek>(
         reader,
         block_offset,
         file_size,
+        options,
         scratch,
     )?;
 
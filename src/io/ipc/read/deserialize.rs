@@ -1,7 +1,6 @@
 use std::collections::VecDeque;
 use std::io::{Read, Seek};
 
-use arrow_format::ipc::BodyCompressionRef;
 use arrow_format::ipc::MetadataVersion;
 
 use crate::array::*;
@@ -10,7 +9,7 @@ use crate::error::Result;
 use crate::io::ipc::IpcField;
 
 use super::{array::*, Dictionaries};
-use super::{IpcBuffer, Node};
+use super::{Compression, IpcBuffer, Node};
 
 #[allow(clippy::too_many_arguments)]
 pub fn read<R: Read + Seek>(
@@ -22,7 +21,7 @@ pub fn read<R: Read + Seek>(
     dictionaries: &Dictionaries,
     block_offset: u64,
     is_little_endian: bool,
-    compression: Option<BodyCompressionRef>,
+    compression: Option<Compression>,
     limit: Option<usize>,
     version: MetadataVersion,
     scratch: &mut Vec<u8>,
@@ -95,6 +95,39 @@ pub enum OutOfSpecKind {
         /// The requested dictionary id
         requested_id: i64,
     },
+    /// The footer's custom metadata is an invalid flatbuffer
+    InvalidFlatbufferCustomMetadata(arrow_format::ipc::planus::Error),
+    /// The checksums in the footer's custom metadata do not correspond to the file's blocks
+    InvalidChecksums,
+    /// The checksum of a block differs from the checksum in the footer, i.e. the block is corrupted
+    InvalidChecksum {
+        /// The checksum in the footer
+        expected: u32,
+        /// The checksum of the block
+        actual: u32,
+    },
+    /// A message is larger than [`ReadOptions::max_message_size`](super::ReadOptions::max_message_size)
+    MessageTooLarge {
+        /// The size of the message in bytes
+        size: usize,
+        /// The maximum size of a message
+        max_message_size: usize,
+    },
+    /// An array is longer than [`ReadOptions::max_array_len`](super::ReadOptions::max_array_len)
+    ArrayTooLong {
+        /// The declared length of the array
+        length: usize,
+        /// The maximum length of an array
+        max_array_len: usize,
+    },
+    /// The buffers of a batch are larger than
+    /// [`ReadOptions::max_total_allocation`](super::ReadOptions::max_total_allocation)
+    AllocationTooLarge {
+        /// The number of bytes of the buffers (at least)
+        size: usize,
+        /// The maximum number of bytes of the buffers of a batch
+        max_total_allocation: usize,
+    },
 }
 
 impl From<OutOfSpecKind> for Error {
@@ -1,18 +1,21 @@
 use ahash::AHashMap;
 use std::convert::TryInto;
 use std::io::{Read, Seek, SeekFrom};
+use std::ops::Range;
 
 use crate::array::Array;
 use crate::chunk::Chunk;
-use crate::datatypes::Schema;
+use crate::datatypes::{Metadata, Schema};
 use crate::error::{Error, Result};
 use crate::io::ipc::IpcSchema;
 
+use super::super::checksum::{is_checksum_key, read_verified_block, Checksums};
 use super::super::{ARROW_MAGIC, CONTINUATION_MARKER};
 use super::common::*;
 use super::schema::fb_to_schema;
 use super::Dictionaries;
 use super::OutOfSpecKind;
+use super::ReadOptions;
 use arrow_format::ipc::planus::ReadAsRoot;
 
 /// Metadata of an Arrow IPC file, written in the footer of the file.
@@ -34,11 +37,92 @@ pub struct FileMetadata {
 
     /// The total size of the file in bytes
     pub size: u64,
+
+    /// The checksums of the blocks, when the file was written with them
+    pub(crate) checksums: Option<Checksums>,
+
+    /// The custom metadata of the footer
+    /// (see [`FileWriter::set_custom_metadata`](crate::io::ipc::write::FileWriter::set_custom_metadata))
+    pub custom_metadata: Metadata,
+
+    /// The limits of reading the file (see [`read_file_metadata_with_options`])
+    pub(crate) options: ReadOptions,
+}
+
+impl FileMetadata {
+    /// Creates a new [`FileMetadata`] of a file without checksums nor custom metadata, whose
+    /// batches and dictionaries are read with the default [`ReadOptions`].
+    pub fn new(
+        schema: Schema,
+        ipc_schema: IpcSchema,
+        blocks: Vec<arrow_format::ipc::Block>,
+        dictionaries: Option<Vec<arrow_format::ipc::Block>>,
+        size: u64,
+    ) -> Self {
+        Self {
+            schema,
+            ipc_schema,
+            blocks,
+            dictionaries,
+            size,
+            checksums: None,
+            custom_metadata: Default::default(),
+            options: Default::default(),
+        }
+    }
+
+    /// Whether the file contains checksums of its blocks, which are verified when they are read
+    /// (see [`WriteOptions::checksum`](crate::io::ipc::write::WriteOptions::checksum)).
+    pub fn has_checksums(&self) -> bool {
+        self.checksums.is_some()
+    }
+
+    /// The [`ReadOptions`] that the batches and dictionaries of the file are read with.
+    pub fn read_options(&self) -> &ReadOptions {
+        &self.options
+    }
+
+    /// Sets the [`ReadOptions`] that the batches and dictionaries of the file are read with,
+    /// e.g. the ZSTD dictionary the file was compressed with.
+    pub fn set_read_options(&mut self, options: ReadOptions) {
+        self.options = options;
+    }
+
+    /// Returns the byte ranges of the dictionaries and of the batches of `blocks`
+    /// (all the batches when `None`), e.g. to prefetch them from an object store
+    /// with `io::util::RangedAsyncReader::prefetch`.
+    /// # Errors
+    /// Errors iff the offset or length of a block is negative or overflows.
+    pub fn block_ranges(&self, blocks: Option<&[usize]>) -> Result<Vec<Range<u64>>> {
+        let range = |block: &arrow_format::ipc::Block| {
+            let start = u64::try_from(block.offset).ok();
+            let length = (block.meta_data_length as i64)
+                .checked_add(block.body_length)
+                .and_then(|length| u64::try_from(length).ok());
+            start
+                .zip(length)
+                .and_then(|(start, length)| Some(start..start.checked_add(length)?))
+                .ok_or_else(|| Error::oos("The offset or length of an IPC block is out of range"))
+        };
+        let dictionaries = self.dictionaries.iter().flatten().map(range);
+        match blocks {
+            Some(blocks) => dictionaries
+                .chain(
+                    blocks
+                        .iter()
+                        .filter_map(|index| self.blocks.get(*index))
+                        .map(range),
+                )
+                .collect(),
+            None => dictionaries.chain(self.blocks.iter().map(range)).collect(),
+        }
+    }
 }
 
 fn read_dictionary_message<R: Read + Seek>(
     reader: &mut R,
     offset: u64,
+    options: &ReadOptions,
     data: &mut Vec<u8>,
 ) -> Result<()> {
     let mut message_size: [u8; 4] = [0; 4];
@@ -52,6 +136,7 @@ fn read_dictionary_message<R: Read + Seek>(
     let message_length: usize = message_length
         .try_into()
         .map_err(|_| Error::from(OutOfSpecKind::NegativeFooterLength))?;
+    options.check_message_size(message_length)?;
 
     data.clear();
     data.try_reserve(message_length)?;
@@ -92,7 +177,7 @@ fn read_dictionary_block<R: Read + Seek>(
         .meta_data_length
         .try_into()
         .map_err(|_| Error::from(OutOfSpecKind::UnexpectedNegativeInteger))?;
-    read_dictionary_message(reader, offset, message_scratch)?;
+    read_dictionary_message(reader, offset, &metadata.options, message_scratch)?;
 
     let message = arrow_format::ipc::MessageRef::read_as_root(message_scratch.as_ref())
         .map_err(|err| Error::from(OutOfSpecKind::InvalidFlatbufferMessage(err)))?;
@@ -107,12 +192,16 @@ fn read_dictionary_block<R: Read + Seek>(
         reader,
         offset + length,
         metadata.size,
+        &metadata.options,
         dictionary_scratch,
     )
 }
 
 /// Reads all file's dictionaries, if any
 /// This function is IO-bounded
+///
+/// The dictionaries are read with the [`ReadOptions`] of `metadata`
+/// (see [`FileMetadata::set_read_options`]).
 pub fn read_file_dictionaries<R: Read + Seek>(
     reader: &mut R,
     metadata: &FileMetadata,
@@ -128,15 +217,28 @@ pub fn read_file_dictionaries<R: Read + Seek>(
     // use a temporary smaller scratch for the messages
     let mut message_scratch = Default::default();
 
-    for block in blocks {
-        read_dictionary_block(
-            reader,
-            metadata,
-            block,
-            &mut dictionaries,
-            &mut message_scratch,
-            scratch,
-        )?;
+    let mut block_scratch = vec![];
+    for (index, block) in blocks.iter().enumerate() {
+        if let Some(checksums) = &metadata.checksums {
+            let expected = checksums.dictionaries[index];
+            read_dictionary_block(
+                &mut read_verified_block(reader, block, expected, &mut block_scratch)?,
+                metadata,
+                block,
+                &mut dictionaries,
+                &mut message_scratch,
+                scratch,
+            )?;
+        } else {
+            read_dictionary_block(
+                reader,
+                metadata,
+                block,
+                &mut dictionaries,
+                &mut message_scratch,
+                scratch,
+            )?;
+        }
     }
     Ok(dictionaries)
 }
@@ -200,17 +302,55 @@ pub(super) fn deserialize_footer(footer_data: &[u8], size: u64) -> Result<FileMe
         })
         .transpose()?;
 
-    Ok(FileMetadata {
-        schema,
-        ipc_schema,
-        blocks,
-        dictionaries,
-        size,
-    })
+    let custom_metadata = footer
+        .custom_metadata()
+        .map_err(|err| Error::from(OutOfSpecKind::InvalidFlatbufferCustomMetadata(err)))?
+        .map(|entries| {
+            entries
+                .iter()
+                .filter_map(|kv| match kv.and_then(|kv| Ok((kv.key()?, kv.value()?))) {
+                    Ok((Some(key), Some(value))) => Some(Ok((key, value))),
+                    Ok(_) => None,
+                    Err(err) => Some(Err(Error::from(
+                        OutOfSpecKind::InvalidFlatbufferCustomMetadata(err),
+                    ))),
+                })
+                .collect::<Result<Vec<_>>>()
+        })
+        .transpose()?
+        .unwrap_or_default();
+    let checksums = Checksums::try_from_custom_metadata(
+        custom_metadata.iter().copied(),
+        dictionaries.as_ref().map(|x| x.len()).unwrap_or_default(),
+        blocks.len(),
+    )?;
+    let custom_metadata = custom_metadata
+        .into_iter()
+        .filter(|(key, _)| !is_checksum_key(key))
+        .map(|(key, value)| (key.to_string(), value.to_string()))
+        .collect();
+
+    let mut metadata = FileMetadata::new(schema, ipc_schema, blocks, dictionaries, size);
+    metadata.checksums = checksums;
+    metadata.custom_metadata = custom_metadata;
+    Ok(metadata)
 }
 
 /// Read the Arrow IPC file's metadata
 pub fn read_file_metadata<R: Read + Seek>(reader: &mut R) -> Result<FileMetadata> {
+    read_file_metadata_with_options(reader, ReadOptions::default())
+}
+
+/// Read the Arrow IPC file's metadata, limiting the resources used to read the file
+/// to `options`.
+///
+/// The options are kept in the returned [`FileMetadata`], so that they also apply to
+/// the batches and dictionaries read with it (e.g. by [`read_batch`] or a
+/// [`FileReader`](super::FileReader)).
+pub fn read_file_metadata_with_options<R: Read + Seek>(
+    reader: &mut R,
+    options: ReadOptions,
+) -> Result<FileMetadata> {
     // check if header contain the correct magic bytes
     let mut magic_buffer: [u8; 6] = [0; 6];
     let start = reader.stream_position()?;
@@ -220,6 +360,7 @@ pub fn read_file_metadata<R: Read + Seek>(reader: &mut R) -> Result<FileMetadata
     }
 
     let (end, footer_len) = read_footer_len(reader)?;
+    options.check_message_size(footer_len)?;
 
     // read footer
     reader.seek(SeekFrom::End(-10 - footer_len as i64))?;
@@ -231,7 +372,9 @@ pub fn read_file_metadata<R: Read + Seek>(reader: &mut R) -> Result<FileMetadata
         .take(footer_len as u64)
         .read_to_end(&mut serialized_footer)?;
 
-    deserialize_footer(&serialized_footer, end - start)
+    let mut metadata = deserialize_footer(&serialized_footer, end - start)?;
+    metadata.options = options;
+    Ok(metadata)
 }
 
 pub(crate) fn get_record_batch(
@@ -247,24 +390,14 @@ pub(crate) fn get_record_batch(
     }
 }
 
-/// Reads the record batch at position `index` from the reader.
-///
-/// This function is useful for random access to the file. For example, if
-/// you have indexed the file somewhere else, this allows pruning
-/// certain parts of the file.
-/// # Panics
-/// This function panics iff `index >= metadata.blocks.len()`
-#[allow(clippy::too_many_arguments)]
-pub fn read_batch<R: Read + Seek>(
+/// Reads the message of the record batch at position `index` into `message_scratch`,
+/// returning the offset of its body in the file.
+fn read_batch_message<R: Read + Seek>(
     reader: &mut R,
-    dictionaries: &Dictionaries,
     metadata: &FileMetadata,
-    projection: Option<&[usize]>,
-    limit: Option<usize>,
     index: usize,
     message_scratch: &mut Vec<u8>,
-    data_scratch: &mut Vec<u8>,
-) -> Result<Chunk<Box<dyn Array>>> {
+) -> Result<u64> {
     let block = metadata.blocks[index];
 
     let offset: u64 = block
@@ -288,6 +421,7 @@ pub fn read_batch<R: Read + Seek>(
     let meta_len = i32::from_le_bytes(meta_buf)
         .try_into()
         .map_err(|_| Error::from(OutOfSpecKind::UnexpectedNegativeInteger))?;
+    metadata.options.check_message_size(meta_len)?;
 
     message_scratch.clear();
     message_scratch.try_reserve(meta_len)?;
@@ -296,6 +430,131 @@ pub fn read_batch<R: Read + Seek>(
         .take(meta_len as u64)
         .read_to_end(message_scratch)?;
 
+    Ok(offset + length)
+}
+
+/// Reads the number of rows of the record batch at position `index` from the reader.
+///
+/// Only the message of the record batch is read, not its body. Together with [`read_batch`],
+/// this allows reading only the batches that overlap a range of rows.
+/// # Panics
+/// This function panics iff `index >= metadata.blocks.len()`
+pub fn read_batch_length<R: Read + Seek>(
+    reader: &mut R,
+    metadata: &FileMetadata,
+    index: usize,
+    message_scratch: &mut Vec<u8>,
+) -> Result<usize> {
+    read_batch_message(reader, metadata, index, message_scratch)?;
+
+    let message = arrow_format::ipc::MessageRef::read_as_root(message_scratch.as_ref())
+        .map_err(|err| Error::from(OutOfSpecKind::InvalidFlatbufferMessage(err)))?;
+
+    let batch = get_record_batch(message)?;
+
+    batch
+        .length()?
+        .try_into()
+        .map_err(|_| Error::from(OutOfSpecKind::UnexpectedNegativeInteger))
+}
+
+/// Reads the custom metadata of the record batch at position `index` from the reader.
+///
+/// Only the message of the record batch is read, not its body
+/// (see [`FileWriter::write_with_metadata`](crate::io::ipc::write::FileWriter::write_with_metadata)).
+/// # Panics
+/// This function panics iff `index >= metadata.blocks.len()`
+pub fn read_batch_custom_metadata<R: Read + Seek>(
+    reader: &mut R,
+    metadata: &FileMetadata,
+    index: usize,
+    message_scratch: &mut Vec<u8>,
+) -> Result<Metadata> {
+    read_batch_message(reader, metadata, index, message_scratch)?;
+
+    let message = arrow_format::ipc::MessageRef::read_as_root(message_scratch.as_ref())
+        .map_err(|err| Error::from(OutOfSpecKind::InvalidFlatbufferMessage(err)))?;
+
+    let mut custom_metadata = Metadata::default();
+    let entries = message
+        .custom_metadata()
+        .map_err(|err| Error::from(OutOfSpecKind::InvalidFlatbufferCustomMetadata(err)))?;
+    for kv in entries.into_iter().flatten() {
+        let kv =
+            kv.map_err(|err| Error::from(OutOfSpecKind::InvalidFlatbufferCustomMetadata(err)))?;
+        if let (Some(key), Some(value)) = (kv.key()?, kv.value()?) {
+            custom_metadata.insert(key.to_string(), value.to_string());
+        }
+    }
+    Ok(custom_metadata)
+}
+
+/// Reads the record batch at position `index` from the reader.
+///
+/// This function is useful for random access to the file. For example, if
+/// you have indexed the file somewhere else, this allows pruning
+/// certain parts of the file.
+///
+/// The batch is read with the [`ReadOptions`] of `metadata`
+/// (see [`FileMetadata::set_read_options`]).
+/// # Panics
+/// This function panics iff `index >= metadata.blocks.len()`
+#[allow(clippy::too_many_arguments)]
+pub fn read_batch<R: Read + Seek>(
+    reader: &mut R,
+    dictionaries: &Dictionaries,
+    metadata: &FileMetadata,
+    projection: Option<&[usize]>,
+    limit: Option<usize>,
+    index: usize,
+    message_scratch: &mut Vec<u8>,
+    data_scratch: &mut Vec<u8>,
+) -> Result<Chunk<Box<dyn Array>>> {
+    if let Some(checksums) = &metadata.checksums {
+        let mut block_scratch = vec![];
+        let mut reader = read_verified_block(
+            reader,
+            &metadata.blocks[index],
+            checksums.record_batches[index],
+            &mut block_scratch,
+        )?;
+        return read_batch_unchecked(
+            &mut reader,
+            dictionaries,
+            metadata,
+            projection,
+            limit,
+            index,
+            message_scratch,
+            data_scratch,
+        );
+    }
+    read_batch_unchecked(
+        reader,
+        dictionaries,
+        metadata,
+        projection,
+        limit,
+        index,
+        message_scratch,
+        data_scratch,
+    )
+}
+
+/// Reads the record batch at position `index` without verifying its checksum
+#[allow(clippy::too_many_arguments)]
+fn read_batch_unchecked<R: Read + Seek>(
+    reader: &mut R,
+    dictionaries: &Dictionaries,
+    metadata: &FileMetadata,
+    projection: Option<&[usize]>,
+    limit: Option<usize>,
+    index: usize,
+    message_scratch: &mut Vec<u8>,
+    data_scratch: &mut Vec<u8>,
+) -> Result<Chunk<Box<dyn Array>>> {
+    let body_offset = read_batch_message(reader, metadata, index, message_scratch)?;
+
     let message = arrow_format::ipc::MessageRef::read_as_root(message_scratch.as_ref())
         .map_err(|err| Error::from(OutOfSpecKind::InvalidFlatbufferMessage(err)))?;
 
@@ -312,8 +571,9 @@ pub fn read_batch<R: Read + Seek>(
             .version()
             .map_err(|err| Error::from(OutOfSpecKind::InvalidFlatbufferVersion(err)))?,
         reader,
-        offset + length,
+        body_offset,
         metadata.size,
+        &metadata.options,
         data_scratch,
     )
 }
@@ -18,6 +18,7 @@ use super::file::{deserialize_footer, get_record_batch};
 use super::Dictionaries;
 use super::FileMetadata;
 use super::OutOfSpecKind;
+use super::ReadOptions;
 
 /// Async reader for Arrow IPC files
 pub struct FileStream<'a> {
@@ -145,10 +146,26 @@ async fn read_footer_len<R: AsyncRead + AsyncSeek + Unpin>(reader: &mut R) -> Re
 
 /// Read the metadata from an IPC file.
 pub async fn read_file_metadata_async<R>(reader: &mut R) -> Result<FileMetadata>
+where
+    R: AsyncRead + AsyncSeek + Unpin,
+{
+    read_file_metadata_async_with_options(reader, ReadOptions::default()).await
+}
+
+/// Read the metadata from an IPC file, limiting the resources used to read the file
+/// to `options`.
+///
+/// The options are kept in the returned [`FileMetadata`], so that they also apply to
+/// the batches and dictionaries read by a [`FileStream`].
+pub async fn read_file_metadata_async_with_options<R>(
+    reader: &mut R,
+    options: ReadOptions,
+) -> Result<FileMetadata>
 where
     R: AsyncRead + AsyncSeek + Unpin,
 {
     let footer_size = read_footer_len(reader).await?;
+    options.check_message_size(footer_size)?;
     // Read footer
     reader.seek(SeekFrom::End(-10 - footer_size as i64)).await?;
 
@@ -159,7 +176,9 @@ where
         .read_to_end(&mut footer)
         .await?;
 
-    deserialize_footer(&footer, u64::MAX)
+    let mut metadata = deserialize_footer(&footer, u64::MAX)?;
+    metadata.options = options;
+    Ok(metadata)
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -194,6 +213,7 @@ where
     let meta_len = i32::from_le_bytes(meta_buf)
         .try_into()
         .map_err(|_| Error::from(OutOfSpecKind::UnexpectedNegativeInteger))?;
+    metadata.options.check_message_size(meta_len)?;
 
     meta_buffer.clear();
     meta_buffer.try_reserve(meta_len)?;
@@ -212,6 +232,7 @@ where
         .map_err(|err| Error::from(OutOfSpecKind::InvalidFlatbufferBodyLength(err)))?
         .try_into()
         .map_err(|_| Error::from(OutOfSpecKind::UnexpectedNegativeInteger))?;
+    metadata.options.check_message_size(block_length)?;
 
     block_buffer.clear();
     block_buffer.try_reserve(block_length)?;
@@ -235,6 +256,7 @@ where
         &mut cursor,
         0,
         metadata.size,
+        &metadata.options,
         scratch,
     )
 }
@@ -244,6 +266,7 @@ async fn read_dictionaries<R>(
     fields: &[Field],
     ipc_schema: &IpcSchema,
     blocks: &[Block],
+    options: &ReadOptions,
     scratch: &mut Vec<u8>,
 ) -> Result<Dictionaries>
 where
@@ -263,8 +286,9 @@ where
             .body_length
             .try_into()
             .map_err(|_| Error::from(OutOfSpecKind::NegativeFooterLength))?;
+        options.check_message_size(length)?;
 
-        read_dictionary_message(&mut reader, offset, &mut data).await?;
+        read_dictionary_message(&mut reader, offset, options, &mut data).await?;
 
         let message = arrow_format::ipc::MessageRef::read_as_root(data.as_ref())
             .map_err(|err| Error::from(OutOfSpecKind::InvalidFlatbufferMessage(err)))?;
@@ -291,6 +315,7 @@ where
                     &mut cursor,
                     0,
                     u64::MAX,
+                    options,
                     scratch,
                 )?;
             }
@@ -300,7 +325,12 @@ where
     Ok(dictionaries)
 }
 
-async fn read_dictionary_message<R>(mut reader: R, offset: u64, data: &mut Vec<u8>) -> Result<()>
+async fn read_dictionary_message<R>(
+    mut reader: R,
+    offset: u64,
+    options: &ReadOptions,
+    data: &mut Vec<u8>,
+) -> Result<()>
 where
     R: AsyncRead + AsyncSeek + Unpin,
 {
@@ -315,6 +345,7 @@ where
     let footer_size: usize = footer_size
         .try_into()
         .map_err(|_| Error::from(OutOfSpecKind::NegativeFooterLength))?;
+    options.check_message_size(footer_size)?;
 
     data.clear();
     data.try_reserve(footer_size)?;
@@ -338,6 +369,7 @@ async fn cached_read_dictionaries<R: AsyncRead + AsyncSeek + Unpin>(
                 &metadata.schema.fields,
                 &metadata.ipc_schema,
                 blocks,
+                &metadata.options,
                 &mut Default::default(),
             )
             .await?;
@@ -4,9 +4,13 @@
 //! which provides arbitrary access to any of its messages, and the
 //! [`StreamReader`](stream::StreamReader), which only supports reading
 //! data in the order it was written in.
+use std::cell::Cell;
+use std::sync::Arc;
+
 use ahash::AHashMap;
 
 use crate::array::Array;
+use crate::error::{Error, Result};
 
 mod array;
 mod common;
@@ -29,17 +33,147 @@ pub mod stream_async;
 pub mod file_async;
 
 pub(crate) use common::first_dict_field;
-#[cfg(feature = "io_flight")]
 pub(crate) use common::{read_dictionary, read_record_batch};
-pub use file::{read_batch, read_file_dictionaries, read_file_metadata, FileMetadata};
-pub use reader::FileReader;
+pub use file::{
+    read_batch, read_batch_custom_metadata, read_batch_length, read_file_dictionaries,
+    read_file_metadata, read_file_metadata_with_options, FileMetadata,
+};
+pub use reader::{read_file_from_bytes, FileReader};
 pub use schema::deserialize_schema;
-pub use stream::{read_stream_metadata, StreamMetadata, StreamReader, StreamState};
+pub use stream::{
+    read_stream_metadata, read_stream_metadata_with_options, StreamMetadata, StreamReader,
+    StreamState,
+};
 
 /// how dictionaries are tracked in this crate
 pub type Dictionaries = AHashMap<i64, Box<dyn Array>>;
 
 pub(crate) type Node<'a> = arrow_format::ipc::FieldNodeRef<'a>;
 pub(crate) type IpcBuffer<'a> = arrow_format::ipc::BufferRef<'a>;
-pub(crate) type Compression<'a> = arrow_format::ipc::BodyCompressionRef<'a>;
+
+/// Options of reading Arrow IPC data: the ZSTD dictionary the data was compressed with, and
+/// limits on the resources used to read it, protecting readers of untrusted files and
+/// streams from excessive allocations declared by malicious lengths.
+///
+/// Reads exceeding a limit error with [`Error::OutOfSpec`]. The default has no limits.
+/// New options may be added in minor versions: build them from [`ReadOptions::default`]
+/// and its `with_*` setters.
+/// # Example
+/// ```
+/// use arrow2::io::ipc::read::ReadOptions;
+///
+/// let options = ReadOptions::default()
+///     .with_max_message_size(1 << 20)
+///     .with_max_total_allocation(1 << 30);
+/// assert_eq!(options.max_array_len, usize::MAX);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct ReadOptions {
+    /// The maximum number of bytes of a message's metadata (or of a file's footer),
+    /// and of a message's body
+    pub max_message_size: usize,
+    /// The maximum length of an array
+    pub max_array_len: usize,
+    /// The maximum number of bytes of the buffers of a record batch or dictionary batch,
+    /// including the buffers decompressed from them
+    pub max_total_allocation: usize,
+    /// The ZSTD dictionary that the buffers were compressed with, if any
+    /// (see [`WriteOptions::zstd_dictionary`](crate::io::ipc::write::WriteOptions::zstd_dictionary)).
+    pub zstd_dictionary: Option<Arc<[u8]>>,
+}
+
+impl Default for ReadOptions {
+    fn default() -> Self {
+        Self {
+            max_message_size: usize::MAX,
+            max_array_len: usize::MAX,
+            max_total_allocation: usize::MAX,
+            zstd_dictionary: None,
+        }
+    }
+}
+
+impl ReadOptions {
+    /// Sets [`ReadOptions::max_message_size`]
+    pub fn with_max_message_size(mut self, max_message_size: usize) -> Self {
+        self.max_message_size = max_message_size;
+        self
+    }
+
+    /// Sets [`ReadOptions::max_array_len`]
+    pub fn with_max_array_len(mut self, max_array_len: usize) -> Self {
+        self.max_array_len = max_array_len;
+        self
+    }
+
+    /// Sets [`ReadOptions::max_total_allocation`]
+    pub fn with_max_total_allocation(mut self, max_total_allocation: usize) -> Self {
+        self.max_total_allocation = max_total_allocation;
+        self
+    }
+
+    /// Sets [`ReadOptions::zstd_dictionary`]
+    pub fn with_zstd_dictionary(mut self, zstd_dictionary: Option<Arc<[u8]>>) -> Self {
+        self.zstd_dictionary = zstd_dictionary;
+        self
+    }
+
+    /// Errors iff `size` is larger than [`ReadOptions::max_message_size`]
+    pub(crate) fn check_message_size(&self, size: usize) -> Result<()> {
+        if size > self.max_message_size {
+            return Err(Error::from(OutOfSpecKind::MessageTooLarge {
+                size,
+                max_message_size: self.max_message_size,
+            }));
+        }
+        Ok(())
+    }
+}
+
+/// The compression of the buffers of a record batch and the ZSTD dictionary to decompress them
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Compression<'a> {
+    body: arrow_format::ipc::BodyCompressionRef<'a>,
+    zstd_dictionary: Option<&'a [u8]>,
+    // the number of bytes that the decompressed buffers of the batch may still allocate
+    remaining_allocation: &'a Cell<usize>,
+    max_total_allocation: usize,
+}
+
+impl<'a> Compression<'a> {
+    pub fn new(
+        body: arrow_format::ipc::BodyCompressionRef<'a>,
+        zstd_dictionary: Option<&'a [u8]>,
+        remaining_allocation: &'a Cell<usize>,
+        max_total_allocation: usize,
+    ) -> Self {
+        Self {
+            body,
+            zstd_dictionary,
+            remaining_allocation,
+            max_total_allocation,
+        }
+    }
+
+    /// Reserves `size` bytes of the allocation of the batch for a decompressed buffer
+    pub fn allocate(&self, size: usize) -> Result<()> {
+        let remaining = self.remaining_allocation.get();
+        if size > remaining {
+            return Err(Error::from(OutOfSpecKind::AllocationTooLarge {
+                size: self.max_total_allocation - remaining + size,
+                max_total_allocation: self.max_total_allocation,
+            }));
+        }
+        self.remaining_allocation.set(remaining - size);
+        Ok(())
+    }
+
+    pub fn codec(
+        &self,
+    ) -> std::result::Result<arrow_format::ipc::CompressionType, arrow_format::ipc::planus::Error>
+    {
+        self.body.codec()
+    }
+}
 pub(crate) type Version = arrow_format::ipc::MetadataVersion;
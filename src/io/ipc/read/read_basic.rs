@@ -101,6 +101,9 @@ fn read_compressed_buffer<T: NativeType, R: Read + Seek>(
         ));
     }
 
+    // the length is not bounded by the (compressed) buffer length
+    compression.allocate(length.saturating_mul(std::mem::size_of::<T>()))?;
+
     // it is undefined behavior to call read_exact on un-initialized, https://doc.rust-lang.org/std/io/trait.Read.html#tymethod.read
     // see also https://github.com/MaikKlein/ash/issues/354#issue-781730580
     let mut buffer = vec![T::default(); length];
@@ -115,6 +118,7 @@ fn read_compressed_buffer<T: NativeType, R: Read + Seek>(
 
     let out_slice = bytemuck::cast_slice_mut(&mut buffer);
 
+    let dictionary = compression.zstd_dictionary;
     let compression = compression
         .codec()
         .map_err(|err| Error::from(OutOfSpecKind::InvalidFlatbufferCompression(err)))?;
@@ -124,7 +128,7 @@ fn read_compressed_buffer<T: NativeType, R: Read + Seek>(
             compression::decompress_lz4(&scratch[8..], out_slice)?;
         }
         arrow_format::ipc::CompressionType::Zstd => {
-            compression::decompress_zstd(&scratch[8..], out_slice)?;
+            compression::decompress_zstd(&scratch[8..], out_slice, dictionary)?;
         }
     }
     Ok(buffer)
@@ -199,12 +203,14 @@ fn read_compressed_bitmap<R: Read + Seek>(
     reader: &mut R,
     scratch: &mut Vec<u8>,
 ) -> Result<Vec<u8>> {
-    let mut buffer = vec![0; (length + 7) / 8];
+    compression.allocate(length.saturating_add(7) / 8)?;
+    let mut buffer = vec![0; length.saturating_add(7) / 8];
 
     scratch.clear();
     scratch.try_reserve(bytes)?;
     reader.by_ref().take(bytes as u64).read_to_end(scratch)?;
 
+    let dictionary = compression.zstd_dictionary;
     let compression = compression
         .codec()
         .map_err(|err| Error::from(OutOfSpecKind::InvalidFlatbufferCompression(err)))?;
@@ -214,7 +220,7 @@ fn read_compressed_bitmap<R: Read + Seek>(
             compression::decompress_lz4(&scratch[8..], &mut buffer)?;
         }
         arrow_format::ipc::CompressionType::Zstd => {
-            compression::decompress_zstd(&scratch[8..], &mut buffer)?;
+            compression::decompress_zstd(&scratch[8..], &mut buffer, dictionary)?;
         }
     }
     Ok(buffer)
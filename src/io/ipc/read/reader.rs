@@ -1,14 +1,19 @@
 use ahash::AHashMap;
 use std::io::{Read, Seek};
+use std::sync::Arc;
 
 use crate::array::Array;
 use crate::chunk::Chunk;
-use crate::datatypes::Schema;
-use crate::error::Result;
+use crate::datatypes::{Metadata, Schema};
+use crate::error::{Error, Result};
+use crate::io::cache::{lock, CacheKey, SharedChunkCache};
 
 use super::common::*;
 use super::Dictionaries;
-use super::{read_batch, read_file_dictionaries, FileMetadata};
+use super::{
+    read_batch, read_batch_custom_metadata, read_batch_length, read_file_dictionaries,
+    read_file_metadata, FileMetadata,
+};
 
 /// An iterator of [`Chunk`]s from an Arrow IPC file.
 pub struct FileReader<R: Read + Seek> {
@@ -21,6 +26,7 @@ pub struct FileReader<R: Read + Seek> {
     remaining: usize,
     data_scratch: Vec<u8>,
     message_scratch: Vec<u8>,
+    cache: Option<(SharedChunkCache, Arc<str>)>,
 }
 
 impl<R: Read + Seek> FileReader<R> {
@@ -50,9 +56,24 @@ impl<R: Read + Seek> FileReader<R> {
             current_block: 0,
             data_scratch: Default::default(),
             message_scratch: Default::default(),
+            cache: None,
         }
     }
 
+    /// Sets a [`SharedChunkCache`] used to store decoded record batches, identified by `source`
+    /// (e.g. the path of the file) and by the index of the block.
+    ///
+    /// When a block is in the cache, it is neither read nor decoded.
+    pub fn set_cache(&mut self, cache: SharedChunkCache, source: impl Into<Arc<str>>) {
+        self.cache = Some((cache, source.into()));
+    }
+
+    /// Sets the ZSTD dictionary that the file was compressed with
+    /// (see [`WriteOptions::zstd_dictionary`](crate::io::ipc::write::WriteOptions::zstd_dictionary)).
+    pub fn set_zstd_dictionary(&mut self, dictionary: impl Into<Arc<[u8]>>) {
+        self.metadata.options.zstd_dictionary = Some(dictionary.into());
+    }
+
     /// Return the schema of the file
     pub fn schema(&self) -> &Schema {
         self.projection
@@ -86,6 +107,108 @@ impl<R: Read + Seek> FileReader<R> {
         (self.data_scratch, self.message_scratch) = scratches;
     }
 
+    /// Returns the number of record batches in the file
+    pub fn num_batches(&self) -> usize {
+        self.metadata.blocks.len()
+    }
+
+    /// Returns the number of rows of the record batch at `index`.
+    ///
+    /// Only the message of the record batch is read, so that this can be used to select the
+    /// batches to read with [`FileReader::read_batch`].
+    /// # Errors
+    /// Errors iff `index >= self.num_batches()` or the message of the batch is invalid
+    pub fn batch_length(&mut self, index: usize) -> Result<usize> {
+        self.check_index(index)?;
+        read_batch_length(
+            &mut self.reader,
+            &self.metadata,
+            index,
+            &mut self.message_scratch,
+        )
+    }
+
+    /// Returns the custom metadata of the record batch at `index`.
+    ///
+    /// Only the message of the record batch is read (see [`read_batch_custom_metadata`]).
+    /// # Errors
+    /// Errors iff `index >= self.num_batches()` or the message of the batch is invalid
+    pub fn batch_custom_metadata(&mut self, index: usize) -> Result<Metadata> {
+        self.check_index(index)?;
+        read_batch_custom_metadata(
+            &mut self.reader,
+            &self.metadata,
+            index,
+            &mut self.message_scratch,
+        )
+    }
+
+    /// Reads the record batch at `index`, regardless of the batches that were read before.
+    ///
+    /// The dictionaries of the file are read once, on the first batch read, so batches can be
+    /// read in any order. The projection and cache of this reader are used but its limit is not,
+    /// and the iteration of this reader is not advanced.
+    /// # Errors
+    /// Errors iff `index >= self.num_batches()` or the batch is invalid
+    pub fn read_batch(&mut self, index: usize) -> Result<Chunk<Box<dyn Array>>> {
+        self.check_index(index)?;
+        self.read_block(index, usize::MAX)
+    }
+
+    fn check_index(&self, index: usize) -> Result<()> {
+        if index >= self.metadata.blocks.len() {
+            return Err(Error::InvalidArgumentError(format!(
+                "The batch index ({index}) must be smaller than the number of batches ({})",
+                self.metadata.blocks.len()
+            )));
+        }
+        Ok(())
+    }
+
+    /// Reads the batch of `block`, with at most `limit` rows, from the cache or the file
+    fn read_block(&mut self, block: usize, limit: usize) -> Result<Chunk<Box<dyn Array>>> {
+        self.read_dictionaries()?;
+
+        let key = self.cache.as_ref().map(|(_, source)| {
+            let projection = self.projection.as_ref().map(|x| x.0.clone());
+            CacheKey::new(source.clone(), block, projection)
+        });
+        let cached = key.as_ref().and_then(|key| {
+            let (cache, _) = self.cache.as_ref().unwrap();
+            lock(cache).get(key)
+        });
+
+        let chunk = if let Some(chunk) = cached {
+            limit_chunk(chunk, limit)
+        } else {
+            let chunk = read_batch(
+                &mut self.reader,
+                self.dictionaries.as_ref().unwrap(),
+                &self.metadata,
+                self.projection.as_ref().map(|x| x.0.as_ref()),
+                Some(limit),
+                block,
+                &mut self.message_scratch,
+                &mut self.data_scratch,
+            )?;
+            if let Some(key) = key {
+                // a chunk of `limit` rows may have been truncated by the limit
+                if chunk.len() < limit {
+                    let (cache, _) = self.cache.as_ref().unwrap();
+                    lock(cache).insert(key, chunk.clone());
+                }
+            }
+            chunk
+        };
+
+        Ok(if let Some((_, map, _)) = &self.projection {
+            // re-order according to projection
+            apply_projection(chunk, map)
+        } else {
+            chunk
+        })
+    }
+
     fn read_dictionaries(&mut self) -> Result<()> {
         if self.dictionaries.is_none() {
             self.dictionaries = Some(read_file_dictionaries(
@@ -107,32 +230,43 @@ impl<R: Read + Seek> Iterator for FileReader<R> {
             return None;
         }
 
-        match self.read_dictionaries() {
-            Ok(_) => {}
-            Err(e) => return Some(Err(e)),
-        };
-
         let block = self.current_block;
         self.current_block += 1;
 
-        let chunk = read_batch(
-            &mut self.reader,
-            self.dictionaries.as_ref().unwrap(),
-            &self.metadata,
-            self.projection.as_ref().map(|x| x.0.as_ref()),
-            Some(self.remaining),
-            block,
-            &mut self.message_scratch,
-            &mut self.data_scratch,
-        );
+        let chunk = self.read_block(block, self.remaining);
         self.remaining -= chunk.as_ref().map(|x| x.len()).unwrap_or_default();
-
-        let chunk = if let Some((_, map, _)) = &self.projection {
-            // re-order according to projection
-            chunk.map(|chunk| apply_projection(chunk, map))
-        } else {
-            chunk
-        };
         Some(chunk)
     }
 }
+
+fn limit_chunk(chunk: Chunk<Box<dyn Array>>, limit: usize) -> Chunk<Box<dyn Array>> {
+    if chunk.len() <= limit {
+        return chunk;
+    }
+    Chunk::new(
+        chunk
+            .into_arrays()
+            .into_iter()
+            .map(|array| array.slice(0, limit))
+            .collect(),
+    )
+}
+
+/// Reads every [`Chunk`] of the Arrow IPC file in `bytes`, e.g. the contents of a JavaScript
+/// `Uint8Array` when compiled to WASM, returning them with their [`Schema`].
+/// Use `projection` to only read certain columns, as in [`FileReader::new`].
+/// # Errors
+/// Errors iff `bytes` is not a valid Arrow IPC file.
+/// # Panic
+/// Panics iff the projection is not in increasing order
+pub fn read_file_from_bytes(
+    bytes: &[u8],
+    projection: Option<Vec<usize>>,
+) -> Result<(Schema, Vec<Chunk<Box<dyn Array>>>)> {
+    let mut reader = std::io::Cursor::new(bytes);
+    let metadata = read_file_metadata(&mut reader)?;
+    let reader = FileReader::new(reader, metadata, projection, None);
+    let schema = reader.schema().clone();
+    let chunks = reader.collect::<Result<Vec<_>>>()?;
+    Ok((schema, chunks))
+}
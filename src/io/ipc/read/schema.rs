@@ -4,8 +4,8 @@ use arrow_format::ipc::{
 
 use crate::{
     datatypes::{
-        get_extension, DataType, Extension, Field, IntegerType, IntervalUnit, Metadata, Schema,
-        TimeUnit, UnionMode,
+        get_extension, validate_extension, DataType, Extension, Field, IntegerType, IntervalUnit,
+        Metadata, Schema, TimeUnit, UnionMode,
     },
     error::{Error, Result},
 };
@@ -381,6 +381,10 @@ pub(super) fn fb_to_schema(schema: arrow_format::ipc::SchemaRef) -> Result<(Sche
         let (field, fields) = deserialize_field(field?)?;
         Ok((field, fields))
     }))?;
+    // this also validates the extensions of the children of each field
+    fields
+        .iter()
+        .try_for_each(|field| validate_extension(field.data_type()))?;
 
     let is_little_endian = match schema.endianness()? {
         arrow_format::ipc::Endianness::Little => true,
@@ -1,5 +1,7 @@
 use ahash::AHashMap;
+use std::collections::VecDeque;
 use std::io::Read;
+use std::sync::Arc;
 
 use arrow_format;
 use arrow_format::ipc::planus::ReadAsRoot;
@@ -15,6 +17,7 @@ use super::common::*;
 use super::schema::deserialize_stream_metadata;
 use super::Dictionaries;
 use super::OutOfSpecKind;
+use super::ReadOptions;
 
 /// Metadata of an Arrow IPC stream, written at the start of the stream
 #[derive(Debug, Clone)]
@@ -31,6 +34,17 @@ pub struct StreamMetadata {
 
 /// Reads the metadata of the stream
 pub fn read_stream_metadata<R: Read>(reader: &mut R) -> Result<StreamMetadata> {
+    read_stream_metadata_with_options(reader, ReadOptions::default())
+}
+
+/// Reads the metadata of the stream, erroring if its message is larger than
+/// [`ReadOptions::max_message_size`].
+///
+/// The options of reading the stream's batches are set with [`StreamReader::with_read_options`].
+pub fn read_stream_metadata_with_options<R: Read>(
+    reader: &mut R,
+    options: ReadOptions,
+) -> Result<StreamMetadata> {
     // determine metadata length
     let mut meta_size: [u8; 4] = [0; 4];
     reader.read_exact(&mut meta_size)?;
@@ -46,6 +60,7 @@ pub fn read_stream_metadata<R: Read>(reader: &mut R) -> Result<StreamMetadata> {
     let length: usize = meta_length
         .try_into()
         .map_err(|_| Error::from(OutOfSpecKind::NegativeFooterLength))?;
+    options.check_message_size(length)?;
 
     let mut buffer = vec![];
     buffer.try_reserve(length)?;
@@ -66,11 +81,18 @@ pub fn read_stream_metadata<R: Read>(reader: &mut R) -> Result<StreamMetadata> {
 /// data waiting in the stream and we read it; and finally (3)
 /// [`Some(StreamState::Waiting)`], which means that the stream is still "live", it
 /// just doesn't hold any data right now.
+///
+/// Readers that accept new schemas (see [`StreamReader::with_schema_evolution`]) may
+/// additionally yield [`StreamState::NewSchema`], after which items follow the new schema.
+/// New states may be added in minor versions.
+#[non_exhaustive]
 pub enum StreamState {
     /// A live stream without data
     Waiting,
     /// Next item in the stream
     Some(Chunk<Box<dyn Array>>),
+    /// The stream's schema was replaced by this (projected) schema
+    NewSchema(Schema),
 }
 
 impl StreamState {
@@ -78,7 +100,7 @@ impl StreamState {
     ///
     /// # Panics
     ///
-    /// If the `StreamState` was `Waiting`.
+    /// If the `StreamState` was `Waiting` or `NewSchema`.
     pub fn unwrap(self) -> Chunk<Box<dyn Array>> {
         if let StreamState::Some(batch) = self {
             batch
@@ -88,15 +110,142 @@ impl StreamState {
     }
 }
 
+/// The maximum length of the metadata of a message found when recovering a stream.
+/// Longer lengths are assumed to be corrupted.
+const MAX_RECOVERED_MESSAGE_LENGTH: usize = 1 << 20;
+
+/// A [`Read`] that tracks the number of bytes read and that can un-read bytes,
+/// so that a stream can be scanned for the start of a message.
+struct StreamSource<R: Read> {
+    reader: R,
+    unread: VecDeque<u8>,
+    position: u64,
+}
+
+impl<R: Read> StreamSource<R> {
+    fn new(reader: R) -> Self {
+        Self {
+            reader,
+            unread: Default::default(),
+            position: 0,
+        }
+    }
+
+    /// Pushes `bytes` back to the front of the source, so that they are read again
+    fn unread(&mut self, bytes: &[u8]) {
+        bytes.iter().rev().for_each(|x| self.unread.push_front(*x));
+        self.position -= bytes.len() as u64;
+    }
+}
+
+impl<R: Read> Read for StreamSource<R> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let read = if self.unread.is_empty() {
+            self.reader.read(buf)?
+        } else {
+            self.unread.read(buf)?
+        };
+        self.position += read as u64;
+        Ok(read)
+    }
+}
+
+/// Reads exactly `length` bytes into the end of `buffer`, returning whether there were enough bytes
+fn read_available<R: Read>(reader: &mut R, length: usize, buffer: &mut Vec<u8>) -> Result<bool> {
+    buffer.try_reserve(length)?;
+    let read = reader.by_ref().take(length as u64).read_to_end(buffer)?;
+    Ok(read == length)
+}
+
+/// Whether `message` is a valid message that the reader can continue from
+fn is_valid_message(message: &[u8], schema_evolution: bool) -> bool {
+    let message = if let Ok(message) = arrow_format::ipc::MessageRef::read_as_root(message) {
+        message
+    } else {
+        return false;
+    };
+    let is_supported = match message.header() {
+        Ok(Some(arrow_format::ipc::MessageHeaderRef::RecordBatch(_)))
+        | Ok(Some(arrow_format::ipc::MessageHeaderRef::DictionaryBatch(_))) => true,
+        Ok(Some(arrow_format::ipc::MessageHeaderRef::Schema(_))) => schema_evolution,
+        _ => false,
+    };
+    is_supported
+        && message.version().is_ok()
+        && matches!(message.body_length(), Ok(length) if length >= 0)
+}
+
+/// Scans `reader` for the next message, leaving it at the start of the message.
+/// Returns the offset of the message, or `None` if the data ended before a message was found.
+fn find_message<R: Read>(
+    reader: &mut StreamSource<R>,
+    schema_evolution: bool,
+    message_buffer: &mut Vec<u8>,
+) -> Result<Option<u64>> {
+    // the continuation marker, the length and the message of the candidate
+    let mut candidate = Vec::with_capacity(8);
+    loop {
+        // find a continuation marker
+        let mut byte = [0; 1];
+        while candidate.len() < 4 {
+            if reader.read(&mut byte)? == 0 {
+                reader.unread(&candidate);
+                return Ok(None);
+            }
+            if byte[0] == CONTINUATION_MARKER[candidate.len()] {
+                candidate.push(byte[0]);
+            } else {
+                candidate.clear();
+            }
+        }
+        let offset = reader.position - 4;
+
+        if !read_available(reader, 4, &mut candidate)? {
+            reader.unread(&candidate);
+            return Ok(None);
+        }
+        let length = i32::from_le_bytes(candidate[4..8].try_into().unwrap());
+        let is_valid = match usize::try_from(length) {
+            // the end of the stream
+            Ok(0) => true,
+            Ok(length) if length <= MAX_RECOVERED_MESSAGE_LENGTH => {
+                message_buffer.clear();
+                let is_complete = read_available(reader, length, message_buffer)?;
+                candidate.extend_from_slice(message_buffer);
+                if !is_complete {
+                    reader.unread(&candidate);
+                    return Ok(None);
+                }
+                is_valid_message(message_buffer, schema_evolution)
+            }
+            _ => false,
+        };
+
+        if is_valid {
+            // the message is read again by `read_next`
+            reader.unread(&candidate);
+            return Ok(Some(offset));
+        }
+        // the next marker may start within the candidate
+        reader.unread(&candidate[1..]);
+        candidate.clear();
+    }
+}
+
 /// Reads the next item, yielding `None` if the stream is done,
 /// and a [`StreamState`] otherwise.
+/// When `schema_evolution` is true, a schema message replaces `metadata`.
+#[allow(clippy::too_many_arguments)]
 fn read_next<R: Read>(
-    reader: &mut R,
-    metadata: &StreamMetadata,
+    reader: &mut StreamSource<R>,
+    message_offset: &mut Option<u64>,
+    metadata: &mut StreamMetadata,
+    schema_evolution: bool,
     dictionaries: &mut Dictionaries,
     message_buffer: &mut Vec<u8>,
     data_buffer: &mut Vec<u8>,
     projection: &Option<(Vec<usize>, AHashMap<usize, usize>, Schema)>,
+    options: &ReadOptions,
     scratch: &mut Vec<u8>,
 ) -> Result<Option<StreamState>> {
     // determine metadata length
@@ -115,6 +264,7 @@ fn read_next<R: Read>(
             };
         }
     }
+    *message_offset = Some(reader.position - 4);
 
     let meta_length = {
         // If a continuation marker is encountered, skip over it and read
@@ -133,6 +283,7 @@ fn read_next<R: Read>(
         // the stream has ended, mark the reader as finished
         return Ok(None);
     }
+    options.check_message_size(meta_length)?;
 
     message_buffer.clear();
     message_buffer.try_reserve(meta_length)?;
@@ -154,6 +305,7 @@ fn read_next<R: Read>(
         .map_err(|err| Error::from(OutOfSpecKind::InvalidFlatbufferBodyLength(err)))?
         .try_into()
         .map_err(|_| Error::from(OutOfSpecKind::UnexpectedNegativeInteger))?;
+    options.check_message_size(block_length)?;
 
     match header {
         arrow_format::ipc::MessageHeaderRef::RecordBatch(batch) => {
@@ -179,6 +331,7 @@ fn read_next<R: Read>(
                 &mut reader,
                 0,
                 file_size,
+                options,
                 scratch,
             );
 
@@ -210,20 +363,36 @@ fn read_next<R: Read>(
                 &mut dict_reader,
                 0,
                 file_size,
+                options,
                 scratch,
             )?;
 
             // read the next message until we encounter a RecordBatch message
             read_next(
                 reader,
+                message_offset,
                 metadata,
+                schema_evolution,
                 dictionaries,
                 message_buffer,
                 data_buffer,
                 projection,
+                options,
                 scratch,
             )
         }
+        arrow_format::ipc::MessageHeaderRef::Schema(_) if schema_evolution => {
+            // a schema message has no body, but skip it if there is one
+            std::io::copy(
+                &mut reader.by_ref().take(block_length as u64),
+                &mut std::io::sink(),
+            )?;
+
+            *metadata = deserialize_stream_metadata(message_buffer)?;
+            // dictionaries are declared by the schema, so the previous ones no longer apply
+            dictionaries.clear();
+            Ok(Some(StreamState::NewSchema(metadata.schema.clone())))
+        }
         _ => Err(Error::from(OutOfSpecKind::UnexpectedMessageType)),
     }
 }
@@ -235,14 +404,51 @@ fn read_next<R: Read>(
 ///
 /// For a more thorough walkthrough consult [this example](https://github.com/jorgecarleitao/arrow2/tree/main/examples/ipc_pyarrow).
 pub struct StreamReader<R: Read> {
-    reader: R,
+    reader: StreamSource<R>,
+    message_offset: Option<u64>,
     metadata: StreamMetadata,
     dictionaries: Dictionaries,
     finished: bool,
     data_buffer: Vec<u8>,
     message_buffer: Vec<u8>,
     projection: Option<(Vec<usize>, AHashMap<usize, usize>, Schema)>,
+    // the projection as declared by the user, re-applied to new schemas
+    declared_projection: Option<Vec<usize>>,
+    schema_evolution: bool,
+    read_options: ReadOptions,
     scratch: Vec<u8>,
+    recovery: bool,
+    // whether the last read errored and the stream must be scanned for the next message
+    needs_recovery: bool,
+}
+
+pub(super) fn project_schema(
+    schema: &Schema,
+    projection: Vec<usize>,
+) -> (Vec<usize>, AHashMap<usize, usize>, Schema) {
+    let (p, h, fields) = prepare_projection(&schema.fields, projection);
+    let schema = Schema {
+        fields,
+        metadata: schema.metadata.clone(),
+    };
+    (p, h, schema)
+}
+
+/// Applies `projection` to the new `schema` of a stream, erroring iff it is out of bounds
+pub(super) fn project_new_schema(
+    schema: &Schema,
+    projection: Vec<usize>,
+) -> Result<(Vec<usize>, AHashMap<usize, usize>, Schema)> {
+    if let Some(index) = projection
+        .iter()
+        .find(|&&index| index >= schema.fields.len())
+    {
+        return Err(Error::InvalidArgumentError(format!(
+            "The projection index {index} is out of bounds of the new schema with {} fields",
+            schema.fields.len()
+        )));
+    }
+    Ok(project_schema(schema, projection))
 }
 
 impl<R: Read> StreamReader<R> {
@@ -252,27 +458,98 @@ impl<R: Read> StreamReader<R> {
     /// encounter a schema.
     /// To check if the reader is done, use `is_finished(self)`
     pub fn new(reader: R, metadata: StreamMetadata, projection: Option<Vec<usize>>) -> Self {
-        let projection = projection.map(|projection| {
-            let (p, h, fields) = prepare_projection(&metadata.schema.fields, projection);
-            let schema = Schema {
-                fields,
-                metadata: metadata.schema.metadata.clone(),
-            };
-            (p, h, schema)
-        });
+        let declared_projection = projection.clone();
+        let projection = projection.map(|projection| project_schema(&metadata.schema, projection));
 
         Self {
-            reader,
+            reader: StreamSource::new(reader),
+            message_offset: None,
             metadata,
             dictionaries: Default::default(),
             finished: false,
             data_buffer: Default::default(),
             message_buffer: Default::default(),
             projection,
+            declared_projection,
+            schema_evolution: false,
+            read_options: Default::default(),
             scratch: Default::default(),
+            recovery: false,
+            needs_recovery: false,
         }
     }
 
+    /// Sets whether schema messages after the first one are accepted (defaults to `false`).
+    ///
+    /// When accepted, a new schema replaces the stream's schema and is yielded as
+    /// [`StreamState::NewSchema`]; subsequent batches are read with the new schema, and the
+    /// projection, if any, is applied to it. Otherwise, reading a schema message errors.
+    pub fn with_schema_evolution(mut self, schema_evolution: bool) -> Self {
+        self.schema_evolution = schema_evolution;
+        self
+    }
+
+    /// Sets the ZSTD dictionary that the stream was compressed with
+    /// (see [`WriteOptions::zstd_dictionary`](crate::io::ipc::write::WriteOptions::zstd_dictionary)).
+    pub fn with_zstd_dictionary(mut self, dictionary: impl Into<Arc<[u8]>>) -> Self {
+        self.read_options.zstd_dictionary = Some(dictionary.into());
+        self
+    }
+
+    /// Sets the [`ReadOptions`] limiting the sizes of the messages, the lengths of the arrays
+    /// and the allocations of the batches read (defaults to no limits).
+    ///
+    /// The options replace the ZSTD dictionary set by [`StreamReader::with_zstd_dictionary`];
+    /// set it with [`ReadOptions::with_zstd_dictionary`] instead.
+    ///
+    /// Streams from untrusted sources should be read with limits, since a corrupted or
+    /// malicious message may otherwise declare arbitrarily large allocations.
+    pub fn with_read_options(mut self, options: ReadOptions) -> Self {
+        self.read_options = options;
+        self
+    }
+
+    /// Sets whether the reader recovers from errors (defaults to `false`).
+    ///
+    /// When recovering, the read after an error first scans the stream for the next valid
+    /// message (see [`StreamReader::recover`]), so that a corrupted message is skipped instead
+    /// of failing every subsequent read. The error itself is still yielded.
+    pub fn with_recovery(mut self, recovery: bool) -> Self {
+        self.recovery = recovery;
+        self
+    }
+
+    /// Scans the stream forward for the next valid message, from which reading resumes.
+    ///
+    /// A valid message starts with the continuation marker, followed by its length and by a
+    /// record batch, dictionary batch or, with schema evolution, schema message (or by a length of
+    /// zero, marking the end of the stream). Returns the offset of the message found, or `None`
+    /// when the data ended before a message was found, in which case this can be called
+    /// again when more data is available.
+    ///
+    /// Streams written without continuation markers (before Arrow 0.15) can't be recovered.
+    /// Dictionaries of corrupted dictionary batches are not recovered.
+    pub fn recover(&mut self) -> Result<Option<u64>> {
+        find_message(
+            &mut self.reader,
+            self.schema_evolution,
+            &mut self.message_buffer,
+        )
+    }
+
+    /// Returns the offset of the message of the last item read, if any.
+    ///
+    /// Offsets are the number of bytes from the position of the reader when this
+    /// [`StreamReader`] was created, i.e. from the end of the stream's schema message.
+    pub fn message_offset(&self) -> Option<u64> {
+        self.message_offset
+    }
+
+    /// Returns the number of bytes read since this [`StreamReader`] was created.
+    pub fn position(&self) -> u64 {
+        self.reader.position
+    }
+
     /// Return the schema of the stream
     pub fn metadata(&self) -> &StreamMetadata {
         &self.metadata
@@ -295,17 +572,37 @@ impl<R: Read> StreamReader<R> {
         if self.finished {
             return Ok(None);
         }
+        if self.needs_recovery {
+            if self.recover()?.is_none() {
+                return Ok(Some(StreamState::Waiting));
+            }
+            self.needs_recovery = false;
+        }
         let batch = read_next(
             &mut self.reader,
-            &self.metadata,
+            &mut self.message_offset,
+            &mut self.metadata,
+            self.schema_evolution,
             &mut self.dictionaries,
             &mut self.message_buffer,
             &mut self.data_buffer,
             &self.projection,
+            &self.read_options,
             &mut self.scratch,
-        )?;
-        if batch.is_none() {
-            self.finished = true;
+        );
+        if batch.is_err() && self.recovery {
+            self.needs_recovery = true;
+        }
+        let batch = batch?;
+        match batch {
+            None => self.finished = true,
+            Some(StreamState::NewSchema(_)) => {
+                if let Some(projection) = self.declared_projection.clone() {
+                    self.projection = Some(project_new_schema(&self.metadata.schema, projection)?);
+                }
+                return Ok(Some(StreamState::NewSchema(self.schema().clone())));
+            }
+            _ => {}
         }
         Ok(batch)
     }
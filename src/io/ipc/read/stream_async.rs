@@ -1,5 +1,6 @@
 //! APIs to read Arrow streams asynchronously
 
+use ahash::AHashMap;
 use arrow_format::ipc::planus::ReadAsRoot;
 use futures::future::BoxFuture;
 use futures::AsyncRead;
@@ -9,13 +10,16 @@ use futures::Stream;
 
 use crate::array::*;
 use crate::chunk::Chunk;
+use crate::datatypes::Schema;
 use crate::error::{Error, Result};
 
 use super::super::CONTINUATION_MARKER;
-use super::common::{read_dictionary, read_record_batch};
+use super::common::{apply_projection, read_dictionary, read_record_batch};
 use super::schema::deserialize_stream_metadata;
+use super::stream::{project_new_schema, project_schema};
 use super::Dictionaries;
 use super::OutOfSpecKind;
+use super::ReadOptions;
 use super::StreamMetadata;
 
 /// A (private) state of stream messages
@@ -27,6 +31,14 @@ struct ReadState<R> {
     pub data_buffer: Vec<u8>,
     /// The internal buffer to read messages to
     pub message_buffer: Vec<u8>,
+    /// The options of reading the messages
+    pub options: ReadOptions,
+    /// Whether schema messages after the first one are accepted
+    pub schema_evolution: bool,
+    /// The projection of the current schema
+    pub projection: Option<(Vec<usize>, AHashMap<usize, usize>, Schema)>,
+    /// The projection as declared by the user, re-applied to new schemas
+    pub declared_projection: Option<Vec<usize>>,
 }
 
 /// The state of an Arrow stream
@@ -35,11 +47,25 @@ enum StreamState<R> {
     Waiting(ReadState<R>),
     /// The stream contain a new chunk
     Some((ReadState<R>, Chunk<Box<dyn Array>>)),
+    /// The stream's schema was replaced by the schema of the state
+    NewSchema(ReadState<R>),
 }
 
 /// Reads the [`StreamMetadata`] of the Arrow stream asynchronously
 pub async fn read_stream_metadata_async<R: AsyncRead + Unpin + Send>(
     reader: &mut R,
+) -> Result<StreamMetadata> {
+    read_stream_metadata_async_with_options(reader, ReadOptions::default()).await
+}
+
+/// Reads the [`StreamMetadata`] of the Arrow stream asynchronously, erroring if its message
+/// is larger than [`ReadOptions::max_message_size`].
+///
+/// The options of reading the stream's batches are set with
+/// [`AsyncStreamReader::new_with_options`].
+pub async fn read_stream_metadata_async_with_options<R: AsyncRead + Unpin + Send>(
+    reader: &mut R,
+    options: ReadOptions,
 ) -> Result<StreamMetadata> {
     // determine metadata length
     let mut meta_size: [u8; 4] = [0; 4];
@@ -56,6 +82,7 @@ pub async fn read_stream_metadata_async<R: AsyncRead + Unpin + Send>(
     let meta_len: usize = meta_len
         .try_into()
         .map_err(|_| Error::from(OutOfSpecKind::NegativeFooterLength))?;
+    options.check_message_size(meta_len)?;
 
     let mut meta_buffer = vec![];
     meta_buffer.try_reserve(meta_len)?;
@@ -107,6 +134,7 @@ async fn maybe_next<R: AsyncRead + Unpin + Send>(
         // the stream has ended, mark the reader as finished
         return Ok(None);
     }
+    state.options.check_message_size(meta_length)?;
 
     state.message_buffer.clear();
     state.message_buffer.try_reserve(meta_length)?;
@@ -128,6 +156,7 @@ async fn maybe_next<R: AsyncRead + Unpin + Send>(
         .map_err(|err| Error::from(OutOfSpecKind::InvalidFlatbufferBodyLength(err)))?
         .try_into()
         .map_err(|_| Error::from(OutOfSpecKind::UnexpectedNegativeInteger))?;
+    state.options.check_message_size(block_length)?;
 
     match header {
         arrow_format::ipc::MessageHeaderRef::RecordBatch(batch) => {
@@ -138,20 +167,26 @@ async fn maybe_next<R: AsyncRead + Unpin + Send>(
                 .read_to_end(&mut state.data_buffer)
                 .await?;
 
-            read_record_batch(
+            let chunk = read_record_batch(
                 batch,
                 &state.metadata.schema.fields,
                 &state.metadata.ipc_schema,
-                None,
+                state.projection.as_ref().map(|x| x.0.as_ref()),
                 None,
                 &state.dictionaries,
                 state.metadata.version,
                 &mut std::io::Cursor::new(&state.data_buffer),
                 0,
                 state.data_buffer.len() as u64,
+                &state.options,
                 &mut scratch,
-            )
-            .map(|chunk| Some(StreamState::Some((state, chunk))))
+            )?;
+            let chunk = match &state.projection {
+                // re-order according to projection
+                Some((_, map, _)) => apply_projection(chunk, map),
+                None => chunk,
+            };
+            Ok(Some(StreamState::Some((state, chunk))))
         }
         arrow_format::ipc::MessageHeaderRef::DictionaryBatch(batch) => {
             state.data_buffer.clear();
@@ -173,40 +208,121 @@ async fn maybe_next<R: AsyncRead + Unpin + Send>(
                 &mut dict_reader,
                 0,
                 file_size,
+                &state.options,
                 &mut scratch,
             )?;
 
             // read the next message until we encounter a Chunk<Box<dyn Array>> message
             Ok(Some(StreamState::Waiting(state)))
         }
+        arrow_format::ipc::MessageHeaderRef::Schema(_) if state.schema_evolution => {
+            // a schema message has no body, but skip it if there is one
+            futures::io::copy(
+                (&mut state.reader).take(block_length as u64),
+                &mut futures::io::sink(),
+            )
+            .await?;
+
+            state.metadata = deserialize_stream_metadata(&state.message_buffer)?;
+            // dictionaries are declared by the schema, so the previous ones no longer apply
+            state.dictionaries.clear();
+            if let Some(projection) = state.declared_projection.clone() {
+                state.projection = Some(project_new_schema(&state.metadata.schema, projection)?);
+            }
+            Ok(Some(StreamState::NewSchema(state)))
+        }
         _ => Err(Error::from(OutOfSpecKind::UnexpectedMessageType)),
     }
 }
 
 /// A [`Stream`] over an Arrow IPC stream that asynchronously yields [`Chunk`]s.
+///
+/// Readers that accept new schemas (see [`AsyncStreamReader::with_schema_evolution`]) replace
+/// their [`StreamMetadata`] when the stream declares a new schema; the chunks yielded
+/// afterwards follow [`AsyncStreamReader::schema`].
 pub struct AsyncStreamReader<'a, R: AsyncRead + Unpin + Send + 'a> {
     metadata: StreamMetadata,
+    // the schema of the chunks, i.e. the projected schema of `metadata`
+    schema: Schema,
+    // the state until the stream is first polled
+    state: Option<ReadState<R>>,
     future: Option<BoxFuture<'a, Result<Option<StreamState<R>>>>>,
 }
 
 impl<'a, R: AsyncRead + Unpin + Send + 'a> AsyncStreamReader<'a, R> {
     /// Creates a new [`AsyncStreamReader`]
     pub fn new(reader: R, metadata: StreamMetadata) -> Self {
+        Self::new_with_options(reader, metadata, ReadOptions::default())
+    }
+
+    /// Creates a new [`AsyncStreamReader`] that reads the stream's messages with `options`,
+    /// e.g. limiting their sizes.
+    ///
+    /// Streams from untrusted sources should be read with limits, since a corrupted or
+    /// malicious message may otherwise declare arbitrarily large allocations.
+    pub fn new_with_options(reader: R, metadata: StreamMetadata, options: ReadOptions) -> Self {
         let state = ReadState {
             reader,
             metadata: metadata.clone(),
             dictionaries: Default::default(),
             data_buffer: Default::default(),
             message_buffer: Default::default(),
+            options,
+            schema_evolution: false,
+            projection: None,
+            declared_projection: None,
         };
-        let future = Some(maybe_next(state).boxed());
-        Self { metadata, future }
+        Self {
+            schema: metadata.schema.clone(),
+            metadata,
+            state: Some(state),
+            future: None,
+        }
     }
 
-    /// Return the schema of the stream
+    /// Sets whether schema messages after the first one are accepted (defaults to `false`).
+    ///
+    /// When accepted, a new schema replaces the stream's [`StreamMetadata`] and subsequent
+    /// chunks are read with it. Otherwise, reading a schema message errors.
+    /// # Panics
+    /// Panics iff the stream was already polled.
+    pub fn with_schema_evolution(mut self, schema_evolution: bool) -> Self {
+        self.state
+            .as_mut()
+            .expect("the schema evolution must be set before polling the stream")
+            .schema_evolution = schema_evolution;
+        self
+    }
+
+    /// Sets the projection of the columns of the chunks read.
+    ///
+    /// With schema evolution, the projection is applied to every new schema, and reading a new
+    /// schema errors iff the projection is out of its bounds.
+    /// # Panics
+    /// Panics iff the stream was already polled or the projection is out of bounds of the
+    /// stream's schema.
+    pub fn with_projection(mut self, projection: Vec<usize>) -> Self {
+        let state = self
+            .state
+            .as_mut()
+            .expect("the projection must be set before polling the stream");
+        state.declared_projection = Some(projection.clone());
+        let projection = project_schema(&state.metadata.schema, projection);
+        self.schema = projection.2.clone();
+        state.projection = Some(projection);
+        self
+    }
+
+    /// Return the metadata of the stream
     pub fn metadata(&self) -> &StreamMetadata {
         &self.metadata
     }
+
+    /// Return the schema of the chunks yielded from now on, i.e. the schema of the stream
+    /// after the projection, if any
+    pub fn schema(&self) -> &Schema {
+        &self.schema
+    }
 }
 
 impl<'a, R: AsyncRead + Unpin + Send> Stream for AsyncStreamReader<'a, R> {
@@ -220,24 +336,39 @@ impl<'a, R: AsyncRead + Unpin + Send> Stream for AsyncStreamReader<'a, R> {
         use std::task::Poll;
         let me = Pin::into_inner(self);
 
-        match &mut me.future {
-            Some(fut) => match fut.as_mut().poll(cx) {
-                Poll::Ready(Ok(None)) => {
-                    me.future = None;
-                    Poll::Ready(None)
-                }
-                Poll::Ready(Ok(Some(StreamState::Some((state, batch))))) => {
-                    me.future = Some(Box::pin(maybe_next(state)));
-                    Poll::Ready(Some(Ok(batch)))
-                }
-                Poll::Ready(Ok(Some(StreamState::Waiting(_)))) => Poll::Pending,
-                Poll::Ready(Err(err)) => {
-                    me.future = None;
-                    Poll::Ready(Some(Err(err)))
-                }
-                Poll::Pending => Poll::Pending,
-            },
-            None => Poll::Ready(None),
+        if let Some(state) = me.state.take() {
+            me.future = Some(maybe_next(state).boxed());
+        }
+        loop {
+            return match &mut me.future {
+                Some(fut) => match fut.as_mut().poll(cx) {
+                    Poll::Ready(Ok(None)) => {
+                        me.future = None;
+                        Poll::Ready(None)
+                    }
+                    Poll::Ready(Ok(Some(StreamState::Some((state, batch))))) => {
+                        me.future = Some(Box::pin(maybe_next(state)));
+                        Poll::Ready(Some(Ok(batch)))
+                    }
+                    Poll::Ready(Ok(Some(StreamState::NewSchema(state)))) => {
+                        me.metadata = state.metadata.clone();
+                        me.schema = state
+                            .projection
+                            .as_ref()
+                            .map(|x| x.2.clone())
+                            .unwrap_or_else(|| state.metadata.schema.clone());
+                        me.future = Some(Box::pin(maybe_next(state)));
+                        continue;
+                    }
+                    Poll::Ready(Ok(Some(StreamState::Waiting(_)))) => Poll::Pending,
+                    Poll::Ready(Err(err)) => {
+                        me.future = None;
+                        Poll::Ready(Some(Err(err)))
+                    }
+                    Poll::Pending => Poll::Pending,
+                },
+                None => Poll::Ready(None),
+            };
         }
     }
 }
@@ -1,13 +1,17 @@
 use std::borrow::{Borrow, Cow};
+use std::ops::Range;
+use std::sync::Arc;
 
-use arrow_format::ipc::planus::Builder;
+use arrow_format::ipc::planus::{Builder, ReadAsRoot};
 
 use crate::array::*;
+use crate::bitmap::utils::bytes_for;
 use crate::chunk::Chunk;
 use crate::datatypes::*;
 use crate::error::{Error, Result};
 use crate::io::ipc::endianess::is_native_little_endian;
-use crate::io::ipc::read::Dictionaries;
+use crate::io::ipc::read::{Dictionaries, OutOfSpecKind};
+use crate::offset::{Offset, OffsetsBuffer};
 
 use super::super::IpcField;
 use super::{write, write_dictionary};
@@ -21,12 +25,129 @@ pub enum Compression {
     ZSTD,
 }
 
+/// A [`Compression`] codec and its configuration, used to compress the buffers of a record batch
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Compressor<'a> {
+    /// The codec
+    pub codec: Compression,
+    /// The compression level. `None` uses the default level of the codec.
+    pub level: Option<i32>,
+    /// A pre-trained dictionary. Only used by [`Compression::ZSTD`].
+    pub zstd_dictionary: Option<&'a [u8]>,
+}
+
 /// Options declaring the behaviour of writing to IPC
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+///
+/// New options may be added in minor versions: build them from [`WriteOptions::default`]
+/// and its `with_*` setters.
+/// # Example
+/// ```
+/// use arrow2::io::ipc::write::{Compression, WriteOptions};
+///
+/// let options = WriteOptions::default()
+///     .with_compression(Some(Compression::LZ4))
+///     .with_max_batch_rows(Some(1024));
+/// assert_eq!(options.max_batch_rows, Some(1024));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+#[non_exhaustive]
 pub struct WriteOptions {
     /// Whether the buffers should be compressed and which codec to use.
     /// Note: to use compression the crate must be compiled with feature `io_ipc_compression`.
     pub compression: Option<Compression>,
+    /// The compression level of [`WriteOptions::compression`]. `None` uses the default
+    /// level of the codec. The level of LZ4 is clamped to be non-negative; ZSTD
+    /// clamps the level to its supported range.
+    pub compression_level: Option<i32>,
+    /// A pre-trained ZSTD dictionary (e.g. from `zstd --train`) used to compress the buffers
+    /// when [`WriteOptions::compression`] is [`Compression::ZSTD`]. This reduces the size of
+    /// many small record batches; readers must be configured with the same dictionary.
+    pub zstd_dictionary: Option<Arc<[u8]>>,
+    /// Whether to write a CRC32 checksum of every dictionary and record batch block to the
+    /// custom metadata of the footer, so that readers detect corrupted blocks.
+    /// Only used by [`FileWriter`](super::FileWriter), since streams have no footer.
+    pub checksum: bool,
+    /// The maximum number of rows of a record batch. [`Chunk`]s with more rows are written
+    /// as multiple record batches. Defaults to no limit.
+    pub max_batch_rows: Option<usize>,
+    /// The approximate maximum number of bytes of the body of a record batch. [`Chunk`]s
+    /// whose body is estimated to be larger (before compression) are written as multiple
+    /// record batches, whose number of rows is derived from the estimated size per row.
+    /// A single row is never split. Defaults to no limit.
+    pub max_batch_bytes: Option<usize>,
+    /// Whether the body of every message, and thus every buffer, is aligned to 64 bytes
+    /// from the start of the file or stream, as recommended by the specification. This
+    /// allows every buffer to be memory-mapped without copies (see [`crate::mmap`]).
+    /// Otherwise bodies are aligned to 8 bytes, which is smaller but forces copies of buffers
+    /// whose types require a larger alignment. The async writers always align to 64 bytes.
+    pub align_to_64: bool,
+}
+
+impl WriteOptions {
+    /// Sets [`WriteOptions::compression`]
+    pub fn with_compression(mut self, compression: Option<Compression>) -> Self {
+        self.compression = compression;
+        self
+    }
+
+    /// Sets [`WriteOptions::compression_level`]
+    pub fn with_compression_level(mut self, compression_level: Option<i32>) -> Self {
+        self.compression_level = compression_level;
+        self
+    }
+
+    /// Sets [`WriteOptions::zstd_dictionary`]
+    pub fn with_zstd_dictionary(mut self, zstd_dictionary: Option<Arc<[u8]>>) -> Self {
+        self.zstd_dictionary = zstd_dictionary;
+        self
+    }
+
+    /// Sets [`WriteOptions::checksum`]
+    pub fn with_checksum(mut self, checksum: bool) -> Self {
+        self.checksum = checksum;
+        self
+    }
+
+    /// Sets [`WriteOptions::max_batch_rows`]
+    pub fn with_max_batch_rows(mut self, max_batch_rows: Option<usize>) -> Self {
+        self.max_batch_rows = max_batch_rows;
+        self
+    }
+
+    /// Sets [`WriteOptions::max_batch_bytes`]
+    pub fn with_max_batch_bytes(mut self, max_batch_bytes: Option<usize>) -> Self {
+        self.max_batch_bytes = max_batch_bytes;
+        self
+    }
+
+    /// Sets [`WriteOptions::align_to_64`]
+    pub fn with_align_to_64(mut self, align_to_64: bool) -> Self {
+        self.align_to_64 = align_to_64;
+        self
+    }
+
+    /// The [`Compressor`] declared by these options, if any
+    pub(crate) fn compressor(&self) -> Option<Compressor<'_>> {
+        self.compression.map(|codec| Compressor {
+            codec,
+            level: self.compression_level,
+            zstd_dictionary: self.zstd_dictionary.as_deref(),
+        })
+    }
+
+    /// The alignment of the bodies of the messages declared by these options
+    pub(crate) fn alignment(&self) -> usize {
+        if self.align_to_64 {
+            64
+        } else {
+            8
+        }
+    }
+
+    /// Whether chunks may be written as multiple record batches
+    pub(crate) fn is_split(&self) -> bool {
+        self.max_batch_rows.is_some() || self.max_batch_bytes.is_some()
+    }
 }
 
 fn encode_dictionary(
@@ -188,6 +309,167 @@ pub fn encode_chunk(
     Ok((encoded_dictionaries, encoded_message))
 }
 
+/// Encodes `chunk` into record batches bounded by [`WriteOptions::max_batch_rows`] and
+/// [`WriteOptions::max_batch_bytes`], each preceded by the dictionaries it requires.
+pub fn encode_chunk_split(
+    chunk: &Chunk<Box<dyn Array>>,
+    fields: &[IpcField],
+    dictionary_tracker: &mut DictionaryTracker,
+    options: &WriteOptions,
+) -> Result<Vec<(Vec<EncodedData>, EncodedData)>> {
+    if !options.is_split() || chunk.is_empty() {
+        return Ok(vec![encode_chunk(
+            chunk,
+            fields,
+            dictionary_tracker,
+            options,
+        )?]);
+    }
+
+    let max_rows = options.max_batch_rows.unwrap_or(usize::MAX).max(1);
+    let mut batches = vec![];
+    let mut offset = 0;
+    while offset < chunk.len() {
+        let mut length = max_rows.min(chunk.len() - offset);
+        let mut slice = slice_chunk(chunk, offset, length);
+        if let Some(max_bytes) = options.max_batch_bytes {
+            // shrink the slice by its estimated size per row until it is within the limit,
+            // so that every batch is encoded once
+            loop {
+                let size = estimated_chunk_size(&slice);
+                if size <= max_bytes || length == 1 {
+                    break;
+                }
+                let bytes_per_row = (size - 1) / length + 1;
+                length = (max_bytes / bytes_per_row).clamp(1, length - 1);
+                slice = slice_chunk(chunk, offset, length);
+            }
+        }
+        batches.push(encode_chunk(&slice, fields, dictionary_tracker, options)?);
+        offset += length;
+    }
+    Ok(batches)
+}
+
+fn slice_chunk(
+    chunk: &Chunk<Box<dyn Array>>,
+    offset: usize,
+    length: usize,
+) -> Chunk<Box<dyn Array>> {
+    Chunk::new(
+        chunk
+            .arrays()
+            .iter()
+            .map(|array| array.slice(offset, length))
+            .collect(),
+    )
+}
+
+/// The estimated size of the body of `chunk` when encoded, without padding nor compression.
+fn estimated_chunk_size(chunk: &Chunk<Box<dyn Array>>) -> usize {
+    chunk
+        .arrays()
+        .iter()
+        .map(|array| estimated_body_size(array.as_ref()))
+        .sum()
+}
+
+/// The estimated size of the buffers of `array` when encoded. The values of dictionaries
+/// are not included, since they are encoded in their own messages.
+fn estimated_body_size(array: &dyn Array) -> usize {
+    use PhysicalType::*;
+    let validity = array
+        .validity()
+        .map_or(0, |validity| bytes_for(validity.len()));
+    let values = match array.data_type().to_physical_type() {
+        Null => 0,
+        Boolean => bytes_for(array.len()),
+        Primitive(primitive) => with_match_primitive_type!(primitive, |$T| {
+            array.len() * std::mem::size_of::<$T>()
+        }),
+        Binary => {
+            let array = array.as_any().downcast_ref::<BinaryArray<i32>>().unwrap();
+            offsets_size(array.offsets()) + offsets_range(array.offsets()).len()
+        }
+        LargeBinary => {
+            let array = array.as_any().downcast_ref::<BinaryArray<i64>>().unwrap();
+            offsets_size(array.offsets()) + offsets_range(array.offsets()).len()
+        }
+        Utf8 => {
+            let array = array.as_any().downcast_ref::<Utf8Array<i32>>().unwrap();
+            offsets_size(array.offsets()) + offsets_range(array.offsets()).len()
+        }
+        LargeUtf8 => {
+            let array = array.as_any().downcast_ref::<Utf8Array<i64>>().unwrap();
+            offsets_size(array.offsets()) + offsets_range(array.offsets()).len()
+        }
+        FixedSizeBinary => {
+            let array = array
+                .as_any()
+                .downcast_ref::<FixedSizeBinaryArray>()
+                .unwrap();
+            array.values().len()
+        }
+        List => {
+            let array = array.as_any().downcast_ref::<ListArray<i32>>().unwrap();
+            offsets_size(array.offsets())
+                + estimated_child_size(array.offsets(), array.values().as_ref())
+        }
+        LargeList => {
+            let array = array.as_any().downcast_ref::<ListArray<i64>>().unwrap();
+            offsets_size(array.offsets())
+                + estimated_child_size(array.offsets(), array.values().as_ref())
+        }
+        Map => {
+            let array = array.as_any().downcast_ref::<MapArray>().unwrap();
+            offsets_size(array.offsets())
+                + estimated_child_size(array.offsets(), array.field().as_ref())
+        }
+        FixedSizeList => {
+            let array = array.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
+            estimated_body_size(array.values().as_ref())
+        }
+        Struct => {
+            let array = array.as_any().downcast_ref::<StructArray>().unwrap();
+            array
+                .values()
+                .iter()
+                .map(|values| estimated_body_size(values.as_ref()))
+                .sum()
+        }
+        Union => {
+            let array = array.as_any().downcast_ref::<UnionArray>().unwrap();
+            let offsets = array.offsets().map_or(0, |_| array.len() * 4);
+            array.len()
+                + offsets
+                + array
+                    .fields()
+                    .iter()
+                    .map(|field| estimated_body_size(field.as_ref()))
+                    .sum::<usize>()
+        }
+        Dictionary(key_type) => match_integer_type!(key_type, |$T| {
+            array.len() * std::mem::size_of::<$T>()
+        }),
+    };
+    validity + values
+}
+
+fn offsets_size<O: Offset>(offsets: &OffsetsBuffer<O>) -> usize {
+    offsets.buffer().len() * std::mem::size_of::<O>()
+}
+
+/// The range of the values referenced by `offsets`
+fn offsets_range<O: Offset>(offsets: &OffsetsBuffer<O>) -> Range<usize> {
+    offsets.buffer()[0].to_usize()..offsets.last().to_usize()
+}
+
+/// The estimated size of the items of `values` referenced by `offsets`
+fn estimated_child_size<O: Offset>(offsets: &OffsetsBuffer<O>, values: &dyn Array) -> usize {
+    let range = offsets_range(offsets);
+    estimated_body_size(values.slice(range.start, range.len()).as_ref())
+}
+
 // Amortizes `EncodedData` allocation.
 pub fn encode_chunk_amortized(
     chunk: &Chunk<Box<dyn Array>>,
@@ -251,7 +533,7 @@ fn chunk_to_bytes_amortized(
             &mut nodes,
             &mut offset,
             is_native_little_endian(),
-            options.compression,
+            options.compressor(),
         )
     }
 
@@ -277,6 +559,29 @@ fn chunk_to_bytes_amortized(
     encoded_message.arrow_data = arrow_data
 }
 
+/// Sets the custom metadata of the message of `encoded` to the entries of `metadata`
+pub(crate) fn set_custom_metadata(encoded: &mut EncodedData, metadata: &Metadata) -> Result<()> {
+    let message = arrow_format::ipc::MessageRef::read_as_root(&encoded.ipc_message)
+        .map_err(|err| Error::from(OutOfSpecKind::InvalidFlatbufferMessage(err)))?;
+    let mut message: arrow_format::ipc::Message = message
+        .try_into()
+        .map_err(|err| Error::from(OutOfSpecKind::InvalidFlatbufferMessage(err)))?;
+
+    message.custom_metadata = (!metadata.is_empty()).then(|| {
+        metadata
+            .iter()
+            .map(|(key, value)| arrow_format::ipc::KeyValue {
+                key: Some(key.clone()),
+                value: Some(value.clone()),
+            })
+            .collect()
+    });
+
+    let mut builder = Builder::new();
+    encoded.ipc_message = builder.finish(&message, None).to_vec();
+    Ok(())
+}
+
 /// Write dictionary values into two sets of bytes, one for the header (ipc::Schema::Message) and the
 /// other for the data
 fn dictionary_batch_to_bytes<K: DictionaryKey>(
@@ -296,7 +601,7 @@ fn dictionary_batch_to_bytes<K: DictionaryKey>(
         &mut nodes,
         &mut 0,
         is_little_endian,
-        options.compression,
+        options.compressor(),
         false,
     );
 
@@ -396,6 +701,16 @@ pub(crate) fn pad_to_64(len: usize) -> usize {
     ((len + 63) & !63) - len
 }
 
+/// Returns the length of the prefix and metadata of a message written at `position` of a
+/// file or stream, padded so that the body of the message starts at a multiple of `alignment`.
+#[inline]
+pub(crate) fn padded_metadata_len(position: usize, metadata_len: usize, alignment: usize) -> usize {
+    let prefix_size = 8; // the continuation marker and the message length
+    let end = position + prefix_size + metadata_len;
+    let padded_end = (end + alignment - 1) / alignment * alignment;
+    padded_end - position
+}
+
 /// An array [`Chunk`] with optional accompanying IPC fields.
 #[derive(Debug, Clone, PartialEq)]
 pub struct Record<'a> {
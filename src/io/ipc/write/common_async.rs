@@ -4,21 +4,23 @@ use futures::AsyncWriteExt;
 use crate::error::Result;
 
 use super::super::CONTINUATION_MARKER;
-use super::common::pad_to_64;
 use super::common::EncodedData;
+use super::common::{pad_to_64, padded_metadata_len};
 
-/// Write a message's IPC data and buffers, returning metadata and buffer data lengths written
+/// Write a message's IPC data and buffers at `position` of a file or stream, padding its
+/// metadata so that its body starts at a multiple of 64 bytes, returning metadata and buffer
+/// data lengths written
 pub async fn write_message<W: AsyncWrite + Unpin + Send>(
     mut writer: W,
     encoded: EncodedData,
+    position: usize,
 ) -> Result<(usize, usize)> {
     let arrow_data_len = encoded.arrow_data.len();
 
-    let a = 64 - 1;
     let buffer = encoded.ipc_message;
     let flatbuf_size = buffer.len();
     let prefix_size = 8; // the message length
-    let aligned_size = (flatbuf_size + prefix_size + a) & !a;
+    let aligned_size = padded_metadata_len(position, flatbuf_size, 64);
     let padding_bytes = aligned_size - flatbuf_size - prefix_size;
 
     write_continuation(&mut writer, (aligned_size - prefix_size) as i32).await?;
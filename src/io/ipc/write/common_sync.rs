@@ -3,18 +3,29 @@ use std::io::Write;
 use crate::error::Result;
 
 use super::super::CONTINUATION_MARKER;
-use super::common::pad_to_64;
 use super::common::EncodedData;
+use super::common::{pad_to_64, padded_metadata_len};
 
 /// Write a message's IPC data and buffers, returning metadata and buffer data lengths written
 pub fn write_message<W: Write>(writer: &mut W, encoded: &EncodedData) -> Result<(usize, usize)> {
+    write_message_at(writer, encoded, 0, 8)
+}
+
+/// Write a message's IPC data and buffers at `position` of a file or stream, padding its
+/// metadata so that its body starts at a multiple of `alignment`, returning metadata and
+/// buffer data lengths written
+pub(crate) fn write_message_at<W: Write>(
+    writer: &mut W,
+    encoded: &EncodedData,
+    position: usize,
+    alignment: usize,
+) -> Result<(usize, usize)> {
     let arrow_data_len = encoded.arrow_data.len();
 
-    let a = 8 - 1;
     let buffer = &encoded.ipc_message;
     let flatbuf_size = buffer.len();
     let prefix_size = 8;
-    let aligned_size = (flatbuf_size + prefix_size + a) & !a;
+    let aligned_size = padded_metadata_len(position, flatbuf_size, alignment);
     let padding_bytes = aligned_size - flatbuf_size - prefix_size;
 
     write_continuation(writer, (aligned_size - prefix_size) as i32)?;
@@ -24,8 +35,8 @@ pub fn write_message<W: Write>(writer: &mut W, encoded: &EncodedData) -> Result<
         writer.write_all(buffer)?;
     }
     // write padding
-    // aligned to a 8 byte boundary, so maximum is [u8;8]
-    const PADDING_MAX: [u8; 8] = [0u8; 8];
+    // aligned to at most a 64 byte boundary, so maximum is [u8;64]
+    const PADDING_MAX: [u8; 64] = [0u8; 64];
     writer.write_all(&PADDING_MAX[..padding_bytes])?;
 
     // write arrow data
@@ -5,7 +5,7 @@ use std::task::Poll;
 use arrow_format::ipc::{planus::Builder, Block, Footer, MetadataVersion};
 use futures::{future::BoxFuture, AsyncWrite, AsyncWriteExt, FutureExt, Sink};
 
-use super::common::{encode_chunk, DictionaryTracker, EncodedData, WriteOptions};
+use super::common::{encode_chunk_split, DictionaryTracker, EncodedData, WriteOptions};
 use super::common_async::{write_continuation, write_message};
 use super::schema::serialize_schema;
 use super::{default_ipc_fields, schema_to_bytes, Record};
@@ -13,7 +13,7 @@ use crate::datatypes::*;
 use crate::error::{Error, Result};
 use crate::io::ipc::{IpcField, ARROW_MAGIC};
 
-type WriteOutput<W> = (usize, Option<Block>, Vec<Block>, Option<W>);
+type WriteOutput<W> = (usize, Vec<Block>, Vec<Block>, Option<W>);
 
 ///  Sink that writes array [`chunks`](crate::chunk::Chunk) as an IPC file.
 ///
@@ -107,36 +107,40 @@ where
     async fn start(mut writer: W, encoded: EncodedData) -> Result<WriteOutput<W>> {
         writer.write_all(&ARROW_MAGIC[..]).await?;
         writer.write_all(&[0, 0]).await?;
-        let (meta, data) = write_message(&mut writer, encoded).await?;
+        // the schema is written after the magic and 2 bytes for alignment
+        let (meta, data) = write_message(&mut writer, encoded, 8).await?;
 
-        Ok((meta + data + 8, None, vec![], Some(writer)))
+        Ok((meta + data + 8, vec![], vec![], Some(writer)))
     }
 
     async fn write(
         mut writer: W,
         mut offset: usize,
-        record: EncodedData,
-        dictionaries: Vec<EncodedData>,
+        batches: Vec<(Vec<EncodedData>, EncodedData)>,
     ) -> Result<WriteOutput<W>> {
+        let mut record_blocks = vec![];
         let mut dict_blocks = vec![];
-        for dict in dictionaries {
-            let (meta, data) = write_message(&mut writer, dict).await?;
+        for (dictionaries, record) in batches {
+            for dict in dictionaries {
+                let (meta, data) = write_message(&mut writer, dict, offset).await?;
+                let block = Block {
+                    offset: offset as i64,
+                    meta_data_length: meta as i32,
+                    body_length: data as i64,
+                };
+                dict_blocks.push(block);
+                offset += meta + data;
+            }
+            let (meta, data) = write_message(&mut writer, record, offset).await?;
             let block = Block {
                 offset: offset as i64,
                 meta_data_length: meta as i32,
                 body_length: data as i64,
             };
-            dict_blocks.push(block);
+            record_blocks.push(block);
             offset += meta + data;
         }
-        let (meta, data) = write_message(&mut writer, record).await?;
-        let block = Block {
-            offset: offset as i64,
-            meta_data_length: meta as i32,
-            body_length: data as i64,
-        };
-        offset += meta + data;
-        Ok((offset, Some(block), dict_blocks, Some(writer)))
+        Ok((offset, record_blocks, dict_blocks, Some(writer)))
     }
 
     async fn finish(mut writer: W, footer: Footer) -> Result<WriteOutput<W>> {
@@ -152,19 +156,17 @@ where
         writer.write_all(&ARROW_MAGIC).await?;
         writer.close().await?;
 
-        Ok((0, None, vec![], None))
+        Ok((0, vec![], vec![], None))
     }
 
     fn poll_write(&mut self, cx: &mut std::task::Context<'_>) -> Poll<Result<()>> {
         if let Some(task) = &mut self.task {
             match futures::ready!(task.poll_unpin(cx)) {
-                Ok((offset, record, mut dictionaries, writer)) => {
+                Ok((offset, mut records, mut dictionaries, writer)) => {
                     self.task = None;
                     self.writer = writer;
                     self.offset = offset;
-                    if let Some(block) = record {
-                        self.record_blocks.push(block);
-                    }
+                    self.record_blocks.append(&mut records);
                     self.dictionary_blocks.append(&mut dictionaries);
                     Poll::Ready(Ok(()))
                 }
@@ -198,14 +200,14 @@ where
         if let Some(writer) = this.writer.take() {
             let fields = item.fields().unwrap_or_else(|| &this.fields[..]);
 
-            let (dictionaries, record) = encode_chunk(
+            let batches = encode_chunk_split(
                 item.columns(),
                 fields,
                 &mut this.dictionary_tracker,
                 &this.options,
             )?;
 
-            this.task = Some(Self::write(writer, this.offset, record, dictionaries).boxed());
+            this.task = Some(Self::write(writer, this.offset, batches).boxed());
             Ok(())
         } else {
             Err(Error::Io(std::io::Error::new(
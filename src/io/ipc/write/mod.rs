@@ -5,12 +5,12 @@ mod serialize;
 mod stream;
 pub(crate) mod writer;
 
-pub use common::{Compression, Record, WriteOptions};
-pub use schema::schema_to_bytes;
+pub use common::{Compression, Compressor, Record, WriteOptions};
+pub use schema::{schema_fingerprint, schema_to_bytes};
 pub use serialize::write;
 pub(self) use serialize::write_dictionary;
 pub use stream::StreamWriter;
-pub use writer::FileWriter;
+pub use writer::{write_file_to_vec, FileWriter};
 
 pub(crate) mod common_sync;
 
@@ -24,7 +24,10 @@ pub mod stream_async;
 #[cfg_attr(docsrs, doc(cfg(feature = "io_ipc_write_async")))]
 pub mod file_async;
 
+use std::collections::HashSet;
+
 use crate::datatypes::{DataType, Field};
+use crate::error::{Error, Result};
 
 use super::IpcField;
 
@@ -63,9 +66,170 @@ fn default_ipc_field(data_type: &DataType, current_id: &mut i64) -> IpcField {
 
 /// Assigns every dictionary field a unique ID
 pub fn default_ipc_fields(fields: &[Field]) -> Vec<IpcField> {
-    let mut dictionary_id = 0i64;
+    default_ipc_fields_from(fields, 0)
+}
+
+/// Assigns every dictionary field a unique ID, in depth-first order, starting from `first_id`.
+///
+/// Writers whose streams are multiplexed (e.g. by a Flight server) can use disjoint ranges
+/// of IDs to avoid dictionary ID collisions.
+pub fn default_ipc_fields_from(fields: &[Field], first_id: i64) -> Vec<IpcField> {
+    let mut dictionary_id = first_id;
     fields
         .iter()
         .map(|field| default_ipc_field(field.data_type().to_logical_type(), &mut dictionary_id))
         .collect()
 }
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+const FNV_PRIME: u64 = 0x100000001b3;
+
+/// Stable (across platforms and releases) 64-bit FNV-1a hash of `bytes`, continuing from `hash`.
+fn fnv1a(hash: u64, bytes: &[u8]) -> u64 {
+    bytes.iter().fold(hash, |hash, byte| {
+        (hash ^ *byte as u64).wrapping_mul(FNV_PRIME)
+    })
+}
+
+fn hashed_ipc_field(
+    data_type: &DataType,
+    path: &str,
+    namespace: &str,
+    used: &mut HashSet<i64>,
+) -> IpcField {
+    use crate::datatypes::DataType::*;
+    match data_type.to_logical_type() {
+        Map(inner, ..) | FixedSizeList(inner, _) | LargeList(inner) | List(inner) => IpcField {
+            fields: vec![hashed_ipc_field(
+                inner.data_type(),
+                &format!("{path}.{}", inner.name),
+                namespace,
+                used,
+            )],
+            dictionary_id: None,
+        },
+        Union(fields, ..) | Struct(fields) => IpcField {
+            fields: fields
+                .iter()
+                .map(|f| {
+                    hashed_ipc_field(
+                        f.data_type(),
+                        &format!("{path}.{}", f.name),
+                        namespace,
+                        used,
+                    )
+                })
+                .collect(),
+            dictionary_id: None,
+        },
+        Dictionary(_, data_type, _) => {
+            let hash = fnv1a(fnv1a(FNV_OFFSET_BASIS, namespace.as_bytes()), &[0]);
+            let hash = fnv1a(hash, path.as_bytes());
+            // ids are kept within the positive range of i32, as some implementations
+            // do not support larger ids.
+            let mut dictionary_id = (hash % (i32::MAX as u64 + 1)) as i64;
+            // resolve collisions within the schema deterministically
+            while !used.insert(dictionary_id) {
+                dictionary_id = (dictionary_id + 1) % (i32::MAX as i64 + 1);
+            }
+            IpcField {
+                fields: vec![hashed_ipc_field(
+                    data_type,
+                    &format!("{path}.[values]"),
+                    namespace,
+                    used,
+                )],
+                dictionary_id: Some(dictionary_id),
+            }
+        }
+        _ => IpcField {
+            fields: vec![],
+            dictionary_id: None,
+        },
+    }
+}
+
+/// Assigns every dictionary field an ID derived from a stable hash of `namespace` and the path
+/// of the field in the schema (e.g. `"a.b"`).
+///
+/// The IDs only depend on `namespace` and on the names of the fields. Independent writers
+/// that use distinct namespaces (e.g. a writer ID, or [`schema_fingerprint`] when schemas differ)
+/// produce streams with different IDs that can be multiplexed with a low probability of
+/// collision. IDs are unique within `fields` and in the range `0..=i32::MAX`.
+pub fn hashed_ipc_fields(fields: &[Field], namespace: &str) -> Vec<IpcField> {
+    let mut used = HashSet::new();
+    fields
+        .iter()
+        .map(|field| hashed_ipc_field(field.data_type(), &field.name, namespace, &mut used))
+        .collect()
+}
+
+fn check_ipc_field(
+    data_type: &DataType,
+    ipc_field: &IpcField,
+    used: &mut HashSet<i64>,
+) -> Result<()> {
+    use crate::datatypes::DataType::*;
+    let (children, is_dictionary): (Vec<&DataType>, _) = match data_type.to_logical_type() {
+        Map(inner, ..) | FixedSizeList(inner, _) | LargeList(inner) | List(inner) => {
+            (vec![inner.data_type()], false)
+        }
+        Union(fields, ..) | Struct(fields) => {
+            (fields.iter().map(|f| f.data_type()).collect(), false)
+        }
+        Dictionary(_, data_type, _) => (vec![data_type.as_ref()], true),
+        _ => (vec![], false),
+    };
+    match (is_dictionary, ipc_field.dictionary_id) {
+        (true, Some(id)) => {
+            if !used.insert(id) {
+                return Err(Error::InvalidArgumentError(format!(
+                    "The dictionary id {id} is assigned to more than one field"
+                )));
+            }
+        }
+        (true, None) => {
+            return Err(Error::InvalidArgumentError(
+                "Dictionary fields must have a dictionary id".to_string(),
+            ))
+        }
+        (false, Some(_)) => {
+            return Err(Error::InvalidArgumentError(format!(
+                "A dictionary id can't be assigned to a field of type {data_type:?}"
+            )))
+        }
+        (false, None) => {}
+    }
+    // the values of a dictionary field may omit their ipc field (e.g. when read from IPC)
+    if is_dictionary && ipc_field.fields.is_empty() {
+        return Ok(());
+    }
+    if children.len() != ipc_field.fields.len() {
+        return Err(Error::InvalidArgumentError(format!(
+            "A field of type {data_type:?} must have {} children ipc fields",
+            children.len()
+        )));
+    }
+    children
+        .into_iter()
+        .zip(ipc_field.fields.iter())
+        .try_for_each(|(data_type, ipc_field)| check_ipc_field(data_type, ipc_field, used))
+}
+
+/// Checks that `ipc_fields` (e.g. with user-provided dictionary IDs) are consistent
+/// with `fields` and that every dictionary field has a unique dictionary ID.
+/// # Errors
+/// This function errors iff the structure of `ipc_fields` does not match `fields`,
+/// a dictionary field has no ID, a non-dictionary field has an ID, or an ID is repeated.
+pub fn check_ipc_fields(fields: &[Field], ipc_fields: &[IpcField]) -> Result<()> {
+    if fields.len() != ipc_fields.len() {
+        return Err(Error::InvalidArgumentError(
+            "The number of ipc fields must equal the number of fields".to_string(),
+        ));
+    }
+    let mut used = HashSet::new();
+    fields
+        .iter()
+        .zip(ipc_fields.iter())
+        .try_for_each(|(field, ipc_field)| check_ipc_field(field.data_type(), ipc_field, &mut used))
+}
@@ -22,6 +22,20 @@ pub fn schema_to_bytes(schema: &Schema, ipc_fields: &[IpcField]) -> Vec<u8> {
     footer_data.to_vec()
 }
 
+/// Returns a fingerprint of `schema`: a stable (across platforms and releases) hash of its
+/// IPC representation, including the metadata of the schema and of its fields.
+///
+/// Two schemas have the same fingerprint iff they are equal (up to hash collisions),
+/// regardless of the dictionary IDs used to write them.
+pub fn schema_fingerprint(schema: &Schema) -> u64 {
+    let mut ipc_schema = serialize_schema(schema, &super::default_ipc_fields(&schema.fields));
+    ipc_schema.endianness = arrow_format::ipc::Endianness::Little;
+
+    let mut builder = Builder::new();
+    let bytes = builder.finish(&ipc_schema, None);
+    super::fnv1a(super::FNV_OFFSET_BASIS, bytes)
+}
+
 pub fn serialize_schema(schema: &Schema, ipc_fields: &[IpcField]) -> arrow_format::ipc::Schema {
     let endianness = if is_native_little_endian() {
         arrow_format::ipc::Endianness::Little
@@ -12,7 +12,7 @@ use crate::{
 
 use super::super::compression;
 use super::super::endianess::is_native_little_endian;
-use super::common::{pad_to_64, Compression};
+use super::common::{pad_to_64, Compression, Compressor};
 
 fn write_primitive<T: NativeType>(
     array: &PrimitiveArray<T>,
@@ -20,7 +20,7 @@ fn write_primitive<T: NativeType>(
     arrow_data: &mut Vec<u8>,
     offset: &mut i64,
     is_little_endian: bool,
-    compression: Option<Compression>,
+    compression: Option<Compressor>,
 ) {
     write_bitmap(
         array.validity(),
@@ -47,7 +47,7 @@ fn write_boolean(
     arrow_data: &mut Vec<u8>,
     offset: &mut i64,
     _: bool,
-    compression: Option<Compression>,
+    compression: Option<Compressor>,
 ) {
     write_bitmap(
         array.validity(),
@@ -76,7 +76,7 @@ fn write_generic_binary<O: Offset>(
     arrow_data: &mut Vec<u8>,
     offset: &mut i64,
     is_little_endian: bool,
-    compression: Option<Compression>,
+    compression: Option<Compressor>,
 ) {
     let offsets = offsets.buffer();
     write_bitmap(
@@ -125,7 +125,7 @@ fn write_binary<O: Offset>(
     arrow_data: &mut Vec<u8>,
     offset: &mut i64,
     is_little_endian: bool,
-    compression: Option<Compression>,
+    compression: Option<Compressor>,
 ) {
     write_generic_binary(
         array.validity(),
@@ -145,7 +145,7 @@ fn write_utf8<O: Offset>(
     arrow_data: &mut Vec<u8>,
     offset: &mut i64,
     is_little_endian: bool,
-    compression: Option<Compression>,
+    compression: Option<Compressor>,
 ) {
     write_generic_binary(
         array.validity(),
@@ -165,7 +165,7 @@ fn write_fixed_size_binary(
     arrow_data: &mut Vec<u8>,
     offset: &mut i64,
     _is_little_endian: bool,
-    compression: Option<Compression>,
+    compression: Option<Compressor>,
 ) {
     write_bitmap(
         array.validity(),
@@ -185,7 +185,7 @@ fn write_list<O: Offset>(
     nodes: &mut Vec<ipc::FieldNode>,
     offset: &mut i64,
     is_little_endian: bool,
-    compression: Option<Compression>,
+    compression: Option<Compressor>,
 ) {
     let offsets = array.offsets().buffer();
     let validity = array.validity();
@@ -242,7 +242,7 @@ pub fn write_struct(
     nodes: &mut Vec<ipc::FieldNode>,
     offset: &mut i64,
     is_little_endian: bool,
-    compression: Option<Compression>,
+    compression: Option<Compressor>,
 ) {
     write_bitmap(
         array.validity(),
@@ -272,7 +272,7 @@ pub fn write_union(
     nodes: &mut Vec<ipc::FieldNode>,
     offset: &mut i64,
     is_little_endian: bool,
-    compression: Option<Compression>,
+    compression: Option<Compressor>,
 ) {
     write_buffer(
         array.types(),
@@ -313,7 +313,7 @@ fn write_map(
     nodes: &mut Vec<ipc::FieldNode>,
     offset: &mut i64,
     is_little_endian: bool,
-    compression: Option<Compression>,
+    compression: Option<Compressor>,
 ) {
     let offsets = array.offsets().buffer();
     let validity = array.validity();
@@ -370,7 +370,7 @@ fn write_fixed_size_list(
     nodes: &mut Vec<ipc::FieldNode>,
     offset: &mut i64,
     is_little_endian: bool,
-    compression: Option<Compression>,
+    compression: Option<Compressor>,
 ) {
     write_bitmap(
         array.validity(),
@@ -400,7 +400,7 @@ pub(super) fn write_dictionary<K: DictionaryKey>(
     nodes: &mut Vec<ipc::FieldNode>,
     offset: &mut i64,
     is_little_endian: bool,
-    compression: Option<Compression>,
+    compression: Option<Compressor>,
     write_keys: bool,
 ) -> usize {
     if write_keys {
@@ -435,7 +435,7 @@ pub fn write(
     nodes: &mut Vec<ipc::FieldNode>,
     offset: &mut i64,
     is_little_endian: bool,
-    compression: Option<Compression>,
+    compression: Option<Compressor>,
 ) {
     nodes.push(ipc::FieldNode {
         length: array.len() as i64,
@@ -569,6 +569,24 @@ pub fn write(
     }
 }
 
+/// compresses `bytes` into `arrow_data` with `compressor`
+fn compress(bytes: &[u8], arrow_data: &mut Vec<u8>, compressor: Compressor) {
+    match compressor.codec {
+        Compression::LZ4 => {
+            compression::compress_lz4(bytes, arrow_data, compressor.level).unwrap();
+        }
+        Compression::ZSTD => {
+            compression::compress_zstd(
+                bytes,
+                arrow_data,
+                compressor.level,
+                compressor.zstd_dictionary,
+            )
+            .unwrap();
+        }
+    }
+}
+
 #[inline]
 fn pad_buffer_to_64(buffer: &mut Vec<u8>, length: usize) {
     let pad_len = pad_to_64(length);
@@ -581,19 +599,12 @@ fn write_bytes(
     buffers: &mut Vec<ipc::Buffer>,
     arrow_data: &mut Vec<u8>,
     offset: &mut i64,
-    compression: Option<Compression>,
+    compression: Option<Compressor>,
 ) {
     let start = arrow_data.len();
     if let Some(compression) = compression {
         arrow_data.extend_from_slice(&(bytes.len() as i64).to_le_bytes());
-        match compression {
-            Compression::LZ4 => {
-                compression::compress_lz4(bytes, arrow_data).unwrap();
-            }
-            Compression::ZSTD => {
-                compression::compress_zstd(bytes, arrow_data).unwrap();
-            }
-        }
+        compress(bytes, arrow_data, compression);
     } else {
         arrow_data.extend_from_slice(bytes);
     };
@@ -607,7 +618,7 @@ fn write_bitmap(
     buffers: &mut Vec<ipc::Buffer>,
     arrow_data: &mut Vec<u8>,
     offset: &mut i64,
-    compression: Option<Compression>,
+    compression: Option<Compressor>,
 ) {
     match bitmap {
         Some(bitmap) => {
@@ -638,7 +649,7 @@ fn write_buffer<T: NativeType>(
     arrow_data: &mut Vec<u8>,
     offset: &mut i64,
     is_little_endian: bool,
-    compression: Option<Compression>,
+    compression: Option<Compressor>,
 ) {
     let start = arrow_data.len();
     if let Some(compression) = compression {
@@ -674,7 +685,7 @@ fn _write_compressed_buffer_from_iter<T: NativeType, I: TrustedLen<Item = T>>(
     buffer: I,
     arrow_data: &mut Vec<u8>,
     is_little_endian: bool,
-    compression: Compression,
+    compression: Compressor,
 ) {
     let len = buffer.size_hint().0;
     let mut swapped = Vec::with_capacity(len * std::mem::size_of::<T>());
@@ -688,14 +699,7 @@ fn _write_compressed_buffer_from_iter<T: NativeType, I: TrustedLen<Item = T>>(
             .for_each(|x| swapped.extend_from_slice(x.as_ref()))
     };
     arrow_data.extend_from_slice(&(swapped.len() as i64).to_le_bytes());
-    match compression {
-        Compression::LZ4 => {
-            compression::compress_lz4(&swapped, arrow_data).unwrap();
-        }
-        Compression::ZSTD => {
-            compression::compress_zstd(&swapped, arrow_data).unwrap();
-        }
-    }
+    compress(&swapped, arrow_data, compression);
 }
 
 fn _write_buffer<T: NativeType>(buffer: &[T], arrow_data: &mut Vec<u8>, is_little_endian: bool) {
@@ -712,19 +716,12 @@ fn _write_compressed_buffer<T: NativeType>(
     buffer: &[T],
     arrow_data: &mut Vec<u8>,
     is_little_endian: bool,
-    compression: Compression,
+    compression: Compressor,
 ) {
     if is_little_endian == is_native_little_endian() {
         let bytes = bytemuck::cast_slice(buffer);
         arrow_data.extend_from_slice(&(bytes.len() as i64).to_le_bytes());
-        match compression {
-            Compression::LZ4 => {
-                compression::compress_lz4(bytes, arrow_data).unwrap();
-            }
-            Compression::ZSTD => {
-                compression::compress_zstd(bytes, arrow_data).unwrap();
-            }
-        }
+        compress(bytes, arrow_data, compression);
     } else {
         todo!()
     }
@@ -738,7 +735,7 @@ fn write_buffer_from_iter<T: NativeType, I: TrustedLen<Item = T>>(
     arrow_data: &mut Vec<u8>,
     offset: &mut i64,
     is_little_endian: bool,
-    compression: Option<Compression>,
+    compression: Option<Compressor>,
 ) {
     let start = arrow_data.len();
 
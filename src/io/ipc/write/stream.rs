@@ -6,8 +6,8 @@
 use std::io::Write;
 
 use super::super::IpcField;
-use super::common::{encode_chunk, DictionaryTracker, EncodedData, WriteOptions};
-use super::common_sync::{write_continuation, write_message};
+use super::common::{encode_chunk_split, DictionaryTracker, EncodedData, WriteOptions};
+use super::common_sync::{write_continuation, write_message_at};
 use super::{default_ipc_fields, schema_to_bytes};
 
 use crate::array::Array;
@@ -62,7 +62,9 @@ impl<W: Write> StreamWriter<W> {
             ipc_message: schema_to_bytes(schema, self.ipc_fields.as_ref().unwrap()),
             arrow_data: vec![],
         };
-        write_message(&mut self.writer, &encoded_message)?;
+        // every message has a multiple of the alignment of bytes, so the position is irrelevant
+        let alignment = self.write_options.alignment();
+        write_message_at(&mut self.writer, &encoded_message, 0, alignment)?;
         Ok(())
     }
 
@@ -83,18 +85,21 @@ impl<W: Write> StreamWriter<W> {
         #[allow(clippy::or_fun_call)]
         let fields = ipc_fields.unwrap_or(self.ipc_fields.as_ref().unwrap());
 
-        let (encoded_dictionaries, encoded_message) = encode_chunk(
+        let batches = encode_chunk_split(
             columns,
             fields,
             &mut self.dictionary_tracker,
             &self.write_options,
         )?;
 
-        for encoded_dictionary in encoded_dictionaries {
-            write_message(&mut self.writer, &encoded_dictionary)?;
-        }
+        let alignment = self.write_options.alignment();
+        for (encoded_dictionaries, encoded_message) in batches {
+            for encoded_dictionary in encoded_dictionaries {
+                write_message_at(&mut self.writer, &encoded_dictionary, 0, alignment)?;
+            }
 
-        write_message(&mut self.writer, &encoded_message)?;
+            write_message_at(&mut self.writer, &encoded_message, 0, alignment)?;
+        }
         Ok(())
     }
 
@@ -6,7 +6,7 @@ use futures::{future::BoxFuture, AsyncWrite, FutureExt, Sink};
 
 use super::super::IpcField;
 pub use super::common::WriteOptions;
-use super::common::{encode_chunk, DictionaryTracker, EncodedData};
+use super::common::{encode_chunk_split, DictionaryTracker, EncodedData};
 use super::common_async::{write_continuation, write_message};
 use super::{default_ipc_fields, schema_to_bytes, Record};
 
@@ -90,7 +90,7 @@ where
             arrow_data: vec![],
         };
         async move {
-            write_message(&mut writer, message).await?;
+            write_message(&mut writer, message, 0).await?;
             Ok(Some(writer))
         }
         .boxed()
@@ -98,7 +98,7 @@ where
 
     fn write(&mut self, record: Record<'_>) -> Result<()> {
         let fields = record.fields().unwrap_or(&self.fields[..]);
-        let (dictionaries, message) = encode_chunk(
+        let batches = encode_chunk_split(
             record.columns(),
             fields,
             &mut self.dictionary_tracker,
@@ -108,10 +108,12 @@ where
         if let Some(mut writer) = self.writer.take() {
             self.task = Some(
                 async move {
-                    for d in dictionaries {
-                        write_message(&mut writer, d).await?;
+                    for (dictionaries, message) in batches {
+                        for d in dictionaries {
+                            write_message(&mut writer, d, 0).await?;
+                        }
+                        write_message(&mut writer, message, 0).await?;
                     }
-                    write_message(&mut writer, message).await?;
                     Ok(Some(writer))
                 }
                 .boxed(),
@@ -6,7 +6,7 @@ use super::{
     super::IpcField,
     super::ARROW_MAGIC,
     common::{DictionaryTracker, EncodedData, WriteOptions},
-    common_sync::{write_continuation, write_message},
+    common_sync::{write_continuation, write_message_at},
     default_ipc_fields, schema, schema_to_bytes,
 };
 
@@ -14,7 +14,10 @@ use crate::array::Array;
 use crate::chunk::Chunk;
 use crate::datatypes::*;
 use crate::error::{Error, Result};
-use crate::io::ipc::write::common::encode_chunk_amortized;
+use crate::io::ipc::checksum::{ChecksumWriter, Checksums};
+use crate::io::ipc::write::common::{
+    encode_chunk_amortized, encode_chunk_split, set_custom_metadata,
+};
 
 #[derive(Clone, Copy, PartialEq, Eq)]
 pub(crate) enum State {
@@ -44,6 +47,10 @@ pub struct FileWriter<W: Write> {
     pub(crate) dictionary_tracker: DictionaryTracker,
     /// Buffer/scratch that is reused between writes
     pub(crate) encoded_message: EncodedData,
+    /// The checksums of the blocks written, when [`WriteOptions::checksum`] is set
+    pub(crate) checksums: Checksums,
+    /// The custom metadata written to the footer
+    pub(crate) custom_metadata: Metadata,
 }
 
 impl<W: Write> FileWriter<W> {
@@ -87,6 +94,8 @@ impl<W: Write> FileWriter<W> {
                 cannot_replace: true,
             },
             encoded_message: Default::default(),
+            checksums: Default::default(),
+            custom_metadata: Default::default(),
         }
     }
 
@@ -124,17 +133,50 @@ impl<W: Write> FileWriter<W> {
             arrow_data: vec![],
         };
 
-        let (meta, data) = write_message(&mut self.writer, &encoded_message)?;
+        let alignment = self.options.alignment();
+        let (meta, data) = write_message_at(&mut self.writer, &encoded_message, 8, alignment)?;
         self.block_offsets += meta + data + 8; // 8 <=> arrow magic + 2 bytes for alignment
         self.state = State::Started;
         Ok(())
     }
 
+    /// Sets the custom metadata written to the footer of the file when it is finished,
+    /// readable back via [`FileMetadata::custom_metadata`](crate::io::ipc::read::FileMetadata::custom_metadata).
+    ///
+    /// This is metadata of the file, such as its lineage; metadata of the data belongs
+    /// to [`Schema::metadata`].
+    pub fn set_custom_metadata(&mut self, metadata: Metadata) {
+        self.custom_metadata = metadata;
+    }
+
     /// Writes [`Chunk`] to the file
     pub fn write(
         &mut self,
         chunk: &Chunk<Box<dyn Array>>,
         ipc_fields: Option<&[IpcField]>,
+    ) -> Result<()> {
+        self.write_chunk(chunk, ipc_fields, None)
+    }
+
+    /// Writes [`Chunk`] to the file, with `metadata` as the custom metadata of its record
+    /// batch message, readable back via [`read_batch_custom_metadata`](crate::io::ipc::read::read_batch_custom_metadata).
+    ///
+    /// When [`WriteOptions::max_batch_rows`] or [`WriteOptions::max_batch_bytes`] split the
+    /// chunk, every record batch has `metadata`.
+    pub fn write_with_metadata(
+        &mut self,
+        chunk: &Chunk<Box<dyn Array>>,
+        ipc_fields: Option<&[IpcField]>,
+        metadata: &Metadata,
+    ) -> Result<()> {
+        self.write_chunk(chunk, ipc_fields, Some(metadata))
+    }
+
+    fn write_chunk(
+        &mut self,
+        chunk: &Chunk<Box<dyn Array>>,
+        ipc_fields: Option<&[IpcField]>,
+        metadata: Option<&Metadata>,
     ) -> Result<()> {
         if self.state != State::Started {
             return Err(Error::oos(
@@ -147,6 +189,22 @@ impl<W: Write> FileWriter<W> {
         } else {
             self.ipc_fields.as_ref()
         };
+        if self.options.is_split() {
+            let batches = encode_chunk_split(
+                chunk,
+                ipc_fields,
+                &mut self.dictionary_tracker,
+                &self.options,
+            )?;
+            for (encoded_dictionaries, mut encoded_message) in batches {
+                if let Some(metadata) = metadata {
+                    set_custom_metadata(&mut encoded_message, metadata)?;
+                }
+                self.write_encoded(encoded_dictionaries, &encoded_message)?;
+            }
+            return Ok(());
+        }
+
         let encoded_dictionaries = encode_chunk_amortized(
             chunk,
             ipc_fields,
@@ -154,10 +212,25 @@ impl<W: Write> FileWriter<W> {
             &self.options,
             &mut self.encoded_message,
         )?;
+        let mut encoded_message = std::mem::take(&mut self.encoded_message);
+        if let Some(metadata) = metadata {
+            set_custom_metadata(&mut encoded_message, metadata)?;
+        }
+        let result = self.write_encoded(encoded_dictionaries, &encoded_message);
+        self.encoded_message = encoded_message;
+        result
+    }
 
+    /// Writes the encoded dictionaries and record batch, tracking their blocks
+    fn write_encoded(
+        &mut self,
+        encoded_dictionaries: Vec<EncodedData>,
+        encoded_message: &EncodedData,
+    ) -> Result<()> {
         // add all dictionaries
         for encoded_dictionary in encoded_dictionaries {
-            let (meta, data) = write_message(&mut self.writer, &encoded_dictionary)?;
+            let (meta, data, checksum) = self.write_block(&encoded_dictionary)?;
+            self.checksums.dictionaries.extend(checksum);
 
             let block = arrow_format::ipc::Block {
                 offset: self.block_offsets as i64,
@@ -168,7 +241,8 @@ impl<W: Write> FileWriter<W> {
             self.block_offsets += meta + data;
         }
 
-        let (meta, data) = write_message(&mut self.writer, &self.encoded_message)?;
+        let (meta, data, checksum) = self.write_block(encoded_message)?;
+        self.checksums.record_batches.extend(checksum);
         // add a record block for the footer
         let block = arrow_format::ipc::Block {
             offset: self.block_offsets as i64,
@@ -180,6 +254,37 @@ impl<W: Write> FileWriter<W> {
         Ok(())
     }
 
+    /// Writes a message, returning its metadata and buffer data lengths and its checksum,
+    /// when [`WriteOptions::checksum`] is set
+    fn write_block(&mut self, encoded: &EncodedData) -> Result<(usize, usize, Option<u32>)> {
+        let (position, alignment) = (self.block_offsets, self.options.alignment());
+        if self.options.checksum {
+            let mut writer = ChecksumWriter::new(&mut self.writer);
+            let (meta, data) = write_message_at(&mut writer, encoded, position, alignment)?;
+            Ok((meta, data, Some(writer.finalize())))
+        } else {
+            let (meta, data) = write_message_at(&mut self.writer, encoded, position, alignment)?;
+            Ok((meta, data, None))
+        }
+    }
+
+    /// Returns the entries of the custom metadata of the footer: the custom metadata
+    /// and the checksums, when [`WriteOptions::checksum`] is set
+    fn footer_custom_metadata(&mut self) -> Option<Vec<arrow_format::ipc::KeyValue>> {
+        let mut entries = if self.options.checksum {
+            std::mem::take(&mut self.checksums).to_custom_metadata()
+        } else {
+            vec![]
+        };
+        entries.extend(std::mem::take(&mut self.custom_metadata).into_iter().map(
+            |(key, value)| arrow_format::ipc::KeyValue {
+                key: Some(key),
+                value: Some(value),
+            },
+        ));
+        (!entries.is_empty()).then_some(entries)
+    }
+
     /// Write footer and closing tag, then mark the writer as done
     pub fn finish(&mut self) -> Result<()> {
         if self.state != State::Started {
@@ -198,7 +303,7 @@ impl<W: Write> FileWriter<W> {
             schema: Some(Box::new(schema)),
             dictionaries: Some(std::mem::take(&mut self.dictionary_blocks)),
             record_batches: Some(std::mem::take(&mut self.record_blocks)),
-            custom_metadata: None,
+            custom_metadata: self.footer_custom_metadata(),
         };
         let mut builder = Builder::new();
         let footer_data = builder.finish(&root, None);
@@ -212,3 +317,38 @@ impl<W: Write> FileWriter<W> {
         Ok(())
     }
 }
+
+/// Writes `chunks` as an Arrow IPC file to a new [`Vec<u8>`], e.g. to hand the file to
+/// JavaScript as a `Uint8Array` when compiled to WASM.
+/// # Errors
+/// Errors iff a chunk does not match `schema`, or it can't be serialized (e.g. on overflow).
+/// # Example
+/// ```
+/// use arrow2::array::{Array, Int32Array};
+/// use arrow2::chunk::Chunk;
+/// use arrow2::datatypes::{DataType, Field, Schema};
+/// use arrow2::io::ipc::read::read_file_from_bytes;
+/// use arrow2::io::ipc::write::{write_file_to_vec, WriteOptions};
+///
+/// let schema = Schema::from(vec![Field::new("a", DataType::Int32, true)]);
+/// let chunk = Chunk::new(vec![Int32Array::from([Some(1), None]).boxed()]);
+///
+/// let bytes = write_file_to_vec(schema.clone(), None, &[chunk.clone()], Default::default()).unwrap();
+///
+/// let (read_schema, chunks) = read_file_from_bytes(&bytes, None).unwrap();
+/// assert_eq!(read_schema, schema);
+/// assert_eq!(chunks, vec![chunk]);
+/// ```
+pub fn write_file_to_vec(
+    schema: Schema,
+    ipc_fields: Option<Vec<IpcField>>,
+    chunks: &[Chunk<Box<dyn Array>>],
+    options: WriteOptions,
+) -> Result<Vec<u8>> {
+    let mut writer = FileWriter::try_new(vec![], schema, ipc_fields, options)?;
+    for chunk in chunks {
+        writer.write(chunk, None)?;
+    }
+    writer.finish()?;
+    Ok(writer.into_inner())
+}
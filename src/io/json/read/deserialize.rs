@@ -10,7 +10,7 @@ use crate::{
     array::*,
     bitmap::MutableBitmap,
     chunk::Chunk,
-    datatypes::{DataType, Field, IntervalUnit, Schema},
+    datatypes::{DataType, Field, IntervalUnit, Schema, UnionMode},
     error::Error,
     offset::{Offset, Offsets},
     types::{f16, NativeType},
@@ -210,8 +210,11 @@ fn deserialize_utf8_into<'a, O: Offset, A: Borrow<Value<'a>>>(
                 Number::Integer(number, exponent) | Number::Float(number, exponent) => {
                     scratch.clear();
                     scratch.extend_from_slice(number);
-                    scratch.push(b'e');
-                    scratch.extend_from_slice(exponent);
+                    if !exponent.is_empty() {
+                        scratch.push(b'e');
+                        scratch.extend_from_slice(exponent);
+                    }
+                    target.push(std::str::from_utf8(&scratch).ok())
                 }
             },
             Value::Bool(v) => target.push(Some(if *v { "true" } else { "false" })),
@@ -397,6 +400,63 @@ fn deserialize_struct<'a, A: Borrow<Value<'a>>>(rows: &[A], data_type: DataType)
     StructArray::new(data_type, values, validity.into())
 }
 
+/// Returns the index of the field of a union that `value` is deserialized into, or `None`
+/// if no field admits it.
+fn union_field(fields: &[Field], value: &Value) -> Option<usize> {
+    let position = |f: fn(&DataType) -> bool| fields.iter().position(|x| f(x.data_type()));
+    match value {
+        Value::Null => None,
+        Value::Bool(_) => position(|x| x == &DataType::Boolean),
+        Value::Number(Number::Integer(_, _)) => position(|x| {
+            matches!(
+                x,
+                DataType::Int8
+                    | DataType::Int16
+                    | DataType::Int32
+                    | DataType::Int64
+                    | DataType::UInt8
+                    | DataType::UInt16
+                    | DataType::UInt32
+                    | DataType::UInt64
+            )
+        })
+        .or_else(|| position(|x| matches!(x, DataType::Float32 | DataType::Float64))),
+        Value::Number(Number::Float(_, _)) => {
+            position(|x| matches!(x, DataType::Float32 | DataType::Float64))
+        }
+        Value::String(_) => position(|x| matches!(x, DataType::Utf8 | DataType::LargeUtf8)),
+        Value::Array(_) => position(|x| matches!(x, DataType::List(_) | DataType::LargeList(_))),
+        Value::Object(_) => position(|x| matches!(x, DataType::Struct(_))),
+    }
+}
+
+fn deserialize_union<'a, A: Borrow<Value<'a>>>(rows: &[A], data_type: DataType) -> UnionArray {
+    let fields = UnionArray::get_fields(&data_type);
+
+    let mut values = vec![vec![]; fields.len()];
+    let mut types = Vec::<i8>::with_capacity(rows.len());
+    let mut offsets = Vec::<i32>::with_capacity(rows.len());
+    rows.iter().for_each(|row| {
+        let row = row.borrow();
+        // values that no field admits (e.g. nulls) are nulls of the first field
+        let (field, row) = match union_field(fields, row) {
+            Some(field) => (field, row),
+            None => (0, &Value::Null),
+        };
+        types.push(field as i8);
+        offsets.push(values[field].len() as i32);
+        values[field].push(row);
+    });
+
+    let values = fields
+        .iter()
+        .zip(values)
+        .map(|(field, values)| _deserialize(&values, field.data_type().clone()))
+        .collect();
+
+    UnionArray::new(data_type, types.into(), values, Some(offsets.into()))
+}
+
 fn deserialize_dictionary<'a, K: DictionaryKey, A: Borrow<Value<'a>>>(
     rows: &[A],
     data_type: DataType,
@@ -564,6 +624,7 @@ pub(crate) fn _deserialize<'a, A: Borrow<Value<'a>>>(
         DataType::Binary => Box::new(deserialize_binary::<i32, _>(rows)),
         DataType::LargeBinary => Box::new(deserialize_binary::<i64, _>(rows)),
         DataType::Struct(_) => Box::new(deserialize_struct(rows, data_type)),
+        DataType::Union(_, None, UnionMode::Dense) => Box::new(deserialize_union(rows, data_type)),
         DataType::Dictionary(key_type, _, _) => {
             match_integer_type!(key_type, |$T| {
                 Box::new(deserialize_dictionary::<$T, _>(rows, data_type))
@@ -596,6 +657,112 @@ pub fn deserialize(json: &Value, data_type: DataType) -> Result<Box<dyn Array>,
     }
 }
 
+/// Returns `data_type` with [`DataType::Utf8`], [`DataType::Binary`] and [`DataType::List`]
+/// replaced by their large variants wherever deserializing `rows` would overflow their
+/// offsets.
+fn promote_large<'a, A: Borrow<Value<'a>>>(rows: &[A], data_type: &DataType) -> DataType {
+    let overflows = |len: usize| len > i32::MAX as usize;
+    let with_data_type = |field: &Field, data_type: DataType| Field {
+        data_type,
+        ..field.clone()
+    };
+    match data_type {
+        DataType::Utf8 => {
+            let len = rows
+                .iter()
+                .map(|row| match row.borrow() {
+                    Value::String(v) => v.len(),
+                    Value::Number(Number::Integer(number, exponent))
+                    | Value::Number(Number::Float(number, exponent)) => {
+                        number.len() + 1 + exponent.len()
+                    }
+                    Value::Bool(v) => if *v { "true" } else { "false" }.len(),
+                    _ => 0,
+                })
+                .sum();
+            if overflows(len) {
+                DataType::LargeUtf8
+            } else {
+                DataType::Utf8
+            }
+        }
+        DataType::Binary => {
+            let len = rows
+                .iter()
+                .map(|row| match row.borrow() {
+                    Value::String(v) => v.len(),
+                    _ => 0,
+                })
+                .sum();
+            if overflows(len) {
+                DataType::LargeBinary
+            } else {
+                DataType::Binary
+            }
+        }
+        DataType::List(field) | DataType::LargeList(field) => {
+            let empty = [];
+            let inner = rows
+                .iter()
+                .flat_map(|row| match row.borrow() {
+                    Value::Array(value) => value.iter(),
+                    _ => empty.iter(),
+                })
+                .collect::<Vec<_>>();
+            let field = Box::new(with_data_type(
+                field,
+                promote_large(&inner, field.data_type()),
+            ));
+            if matches!(data_type, DataType::LargeList(_)) || overflows(inner.len()) {
+                DataType::LargeList(field)
+            } else {
+                DataType::List(field)
+            }
+        }
+        DataType::Struct(fields) => {
+            let fields = fields
+                .iter()
+                .map(|field| {
+                    let values = rows
+                        .iter()
+                        .map(|row| match row.borrow() {
+                            Value::Object(value) => value.get(&field.name).unwrap_or(&Value::Null),
+                            _ => &Value::Null,
+                        })
+                        .collect::<Vec<_>>();
+                    with_data_type(field, promote_large(&values, field.data_type()))
+                })
+                .collect();
+            DataType::Struct(fields)
+        }
+        other => other.clone(),
+    }
+}
+
+/// Deserializes a `json` [`Value`] into an [`Array`] of [`DataType`], like [`deserialize`],
+/// but promoting [`DataType::Utf8`], [`DataType::Binary`] and [`DataType::List`] (also
+/// when nested) to their large variants wherever their offsets would overflow.
+///
+/// The data type of the resulting array may therefore differ from `data_type`.
+/// # Error
+/// This function errors iff either:
+/// * `json` is not a [`Value::Array`]
+/// * `data_type` is neither [`DataType::List`] nor [`DataType::LargeList`]
+pub fn deserialize_promote_large(
+    json: &Value,
+    data_type: DataType,
+) -> Result<Box<dyn Array>, Error> {
+    match json {
+        Value::Array(rows) => match data_type {
+            DataType::List(inner) | DataType::LargeList(inner) => {
+                Ok(_deserialize(rows, promote_large(rows, &inner.data_type)))
+            }
+            _ => Err(Error::nyi("read an Array from a non-Array data type")),
+        },
+        _ => Err(Error::nyi("read an Array from a non-Array JSON")),
+    }
+}
+
 fn allocate_array(f: &Field) -> Box<dyn MutableArray> {
     match f.data_type() {
         DataType::Int8 => Box::new(MutablePrimitiveArray::<i8>::new()),
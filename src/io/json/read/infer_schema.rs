@@ -9,15 +9,38 @@ use crate::error::{Error, Result};
 
 const ITEM_NAME: &str = "item";
 
+/// How values of different types (e.g. numbers and strings) at the same position are inferred.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum MixedTypes {
+    /// The types are coerced to a single type, falling back to [`DataType::Utf8`].
+    #[default]
+    Coerce,
+    /// The types are inferred as a dense [`DataType::Union`] with one field per kind of value
+    /// (boolean, number, string, list and object).
+    Union,
+}
+
+/// Options to infer [`DataType`]s from JSON.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct InferOptions {
+    /// How values of different types at the same position are inferred
+    pub mixed_types: MixedTypes,
+}
+
 /// Infers [`DataType`] from [`Value`].
 pub fn infer(json: &Value) -> Result<DataType> {
+    infer_with_options(json, &InferOptions::default())
+}
+
+/// Infers [`DataType`] from [`Value`] according to `options`.
+pub fn infer_with_options(json: &Value, options: &InferOptions) -> Result<DataType> {
     Ok(match json {
         Value::Bool(_) => DataType::Boolean,
-        Value::Array(array) => infer_array(array)?,
+        Value::Array(array) => infer_array(array, options)?,
         Value::Null => DataType::Null,
         Value::Number(number) => infer_number(number),
         Value::String(_) => DataType::Utf8,
-        Value::Object(inner) => infer_object(inner)?,
+        Value::Object(inner) => infer_object(inner, options)?,
     })
 }
 
@@ -69,11 +92,11 @@ fn filter_map_nulls(dt: DataType) -> Option<DataType> {
     }
 }
 
-fn infer_object(inner: &HashMap<String, Value>) -> Result<DataType> {
+fn infer_object(inner: &HashMap<String, Value>, options: &InferOptions) -> Result<DataType> {
     let fields = inner
         .iter()
         .filter_map(|(key, value)| {
-            infer(value)
+            infer_with_options(value, options)
                 .map(|dt| filter_map_nulls(dt).map(|dt| (key, dt)))
                 .transpose()
         })
@@ -85,17 +108,17 @@ fn infer_object(inner: &HashMap<String, Value>) -> Result<DataType> {
     Ok(DataType::Struct(fields))
 }
 
-fn infer_array(values: &[Value]) -> Result<DataType> {
+fn infer_array(values: &[Value], options: &InferOptions) -> Result<DataType> {
     let types = values
         .iter()
-        .map(infer)
+        .map(|value| infer_with_options(value, options))
         .filter_map(|x| x.map(filter_map_nulls).transpose())
         // deduplicate entries
         .collect::<Result<HashSet<_>>>()?;
 
     let dt = if !types.is_empty() {
         let types = types.into_iter().collect::<Vec<_>>();
-        coerce_data_type(&types)
+        coerce_data_type(&types, options)
     } else {
         DataType::Null
     };
@@ -116,13 +139,77 @@ fn infer_number(n: &Number) -> DataType {
     }
 }
 
+/// The kind of JSON value a [`DataType`] was inferred from, and the name of its union field
+fn kind(data_type: &DataType) -> &'static str {
+    match data_type {
+        DataType::Boolean => "boolean",
+        DataType::Int64 | DataType::Float64 => "number",
+        DataType::List(_) => "list",
+        DataType::Struct(_) => "object",
+        _ => "string",
+    }
+}
+
+/// Coerces each kind of [`DataType`] to a single one and returns a dense union of the kinds,
+/// or the single kind if there is only one.
+fn coerce_to_union<A: Borrow<DataType>>(datatypes: &[A], options: &InferOptions) -> DataType {
+    let kinds = datatypes
+        .iter()
+        .map(|x| x.borrow())
+        // unions inferred from different values are merged
+        .flat_map(|x| match x {
+            DataType::Union(fields, _, _) => fields.iter().map(|x| x.data_type()).collect(),
+            other => vec![other],
+        })
+        .filter(|x| **x != DataType::Null)
+        .fold(HashMap::<&str, Vec<&DataType>>::new(), |mut acc, x| {
+            acc.entry(kind(x)).or_default().push(x);
+            acc
+        });
+
+    let mut fields = kinds
+        .into_iter()
+        .map(|(name, dts)| {
+            let data_type = match name {
+                "number" if dts.contains(&&DataType::Float64) => DataType::Float64,
+                "number" => DataType::Int64,
+                "list" => {
+                    let inner = dts
+                        .iter()
+                        .filter_map(|x| match x {
+                            DataType::List(inner) => Some(inner.data_type()),
+                            _ => None,
+                        })
+                        .collect::<HashSet<_>>()
+                        .into_iter()
+                        .collect::<Vec<_>>();
+                    let inner = coerce_data_type(&inner, options);
+                    DataType::List(Box::new(Field::new(ITEM_NAME, inner, true)))
+                }
+                _ => coerce_data_type(&dts, options),
+            };
+            Field::new(name, data_type, true)
+        })
+        .collect::<Vec<_>>();
+
+    match fields.len() {
+        0 => DataType::Null,
+        1 => fields.pop().unwrap().data_type,
+        _ => DataType::Union(fields, None, UnionMode::Dense),
+    }
+}
+
 /// Coerce an heterogeneous set of [`DataType`] into a single one. Rules:
 /// * The empty set is coerced to `Null`
 /// * `Int64` and `Float64` are `Float64`
 /// * Lists and scalars are coerced to a list of a compatible scalar
 /// * Structs contain the union of all fields
-/// * All other types are coerced to `Utf8`
-pub(crate) fn coerce_data_type<A: Borrow<DataType>>(datatypes: &[A]) -> DataType {
+/// * All other types are coerced to `Utf8`, or to a dense union of their kinds with
+///   [`MixedTypes::Union`]
+pub(crate) fn coerce_data_type<A: Borrow<DataType>>(
+    datatypes: &[A],
+    options: &InferOptions,
+) -> DataType {
     use DataType::*;
 
     if datatypes.is_empty() {
@@ -167,10 +254,12 @@ pub(crate) fn coerce_data_type<A: Borrow<DataType>>(datatypes: &[A]) -> DataType
             .into_iter()
             .map(|(name, dts)| {
                 let dts = dts.into_iter().collect::<Vec<_>>();
-                Field::new(name, coerce_data_type(&dts), true)
+                Field::new(name, coerce_data_type(&dts, options), true)
             })
             .collect();
         return Struct(fields);
+    } else if options.mixed_types == MixedTypes::Union {
+        return coerce_to_union(datatypes, options);
     } else if datatypes.len() > 2 {
         return Utf8;
     }
@@ -179,15 +268,15 @@ pub(crate) fn coerce_data_type<A: Borrow<DataType>>(datatypes: &[A]) -> DataType
     return match (lhs, rhs) {
         (lhs, rhs) if lhs == rhs => lhs.clone(),
         (List(lhs), List(rhs)) => {
-            let inner = coerce_data_type(&[lhs.data_type(), rhs.data_type()]);
+            let inner = coerce_data_type(&[lhs.data_type(), rhs.data_type()], options);
             List(Box::new(Field::new(ITEM_NAME, inner, true)))
         }
         (scalar, List(list)) => {
-            let inner = coerce_data_type(&[scalar, list.data_type()]);
+            let inner = coerce_data_type(&[scalar, list.data_type()], options);
             List(Box::new(Field::new(ITEM_NAME, inner, true)))
         }
         (List(list), scalar) => {
-            let inner = coerce_data_type(&[scalar, list.data_type()]);
+            let inner = coerce_data_type(&[scalar, list.data_type()], options);
             List(Box::new(Field::new(ITEM_NAME, inner, true)))
         }
         (Float64, Int64) => Float64,
@@ -202,6 +291,10 @@ pub(crate) fn coerce_data_type<A: Borrow<DataType>>(datatypes: &[A]) -> DataType
 mod test {
     use super::*;
 
+    fn coerce_data_type<A: Borrow<DataType>>(datatypes: &[A]) -> DataType {
+        super::coerce_data_type(datatypes, &InferOptions::default())
+    }
+
     #[test]
     fn test_coersion_scalar_and_list() {
         use crate::datatypes::DataType::*;
@@ -3,8 +3,8 @@ mod deserialize;
 mod infer_schema;
 
 pub(crate) use deserialize::_deserialize;
-pub use deserialize::{deserialize, deserialize_records};
+pub use deserialize::{deserialize, deserialize_promote_large, deserialize_records};
 pub(crate) use infer_schema::coerce_data_type;
-pub use infer_schema::{infer, infer_records_schema};
+pub use infer_schema::{infer, infer_records_schema, infer_with_options, InferOptions, MixedTypes};
 
 pub use json_deserializer;
@@ -51,9 +51,24 @@ pub mod parquet;
 #[cfg_attr(docsrs, doc(cfg(feature = "io_avro")))]
 pub mod avro;
 
+#[cfg(feature = "io_serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "io_serde")))]
+pub mod serde;
+
 #[cfg(feature = "io_print")]
 #[cfg_attr(docsrs, doc(cfg(feature = "io_print")))]
 pub mod print;
 
+#[cfg(feature = "io_ipc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "io_ipc")))]
+pub mod cache;
+
+#[cfg(any(feature = "io_ipc_read_async", feature = "io_parquet"))]
+#[cfg_attr(
+    docsrs,
+    doc(cfg(any(feature = "io_ipc_read_async", feature = "io_parquet")))
+)]
+pub mod util;
+
 #[cfg(any(feature = "io_csv_write", feature = "io_avro", feature = "io_json"))]
 mod iterator;
@@ -9,7 +9,9 @@ use crate::{
     error::{Error, Result},
 };
 
-use super::super::super::json::read::{coerce_data_type, infer as infer_json};
+use super::super::super::json::read::{
+    coerce_data_type, infer_with_options as infer_json, InferOptions,
+};
 
 /// Reads up to a number of lines from `reader` into `rows` bounded by `limit`.
 fn read_rows<R: BufRead>(reader: &mut R, rows: &mut [String], limit: usize) -> Result<usize> {
@@ -102,6 +104,19 @@ impl<R: BufRead> FallibleStreamingIterator for FileReader<R> {
 pub fn infer<R: std::io::BufRead>(
     reader: &mut R,
     number_of_rows: Option<usize>,
+) -> Result<DataType> {
+    infer_with_options(reader, number_of_rows, &InferOptions::default())
+}
+
+/// Infers the [`DataType`] from an NDJSON file according to `options`, optionally only using
+/// `number_of_rows` rows.
+///
+/// Use [`crate::io::json::read::MixedTypes::Union`] to infer fields whose values have different
+/// types across rows (e.g. numbers and strings) as a dense [`DataType::Union`].
+pub fn infer_with_options<R: std::io::BufRead>(
+    reader: &mut R,
+    number_of_rows: Option<usize>,
+    options: &InferOptions,
 ) -> Result<DataType> {
     if reader.fill_buf().map(|b| b.is_empty())? {
         return Err(Error::ExternalFormat(
@@ -115,14 +130,14 @@ pub fn infer<R: std::io::BufRead>(
     let mut data_types = HashSet::new();
     while let Some(rows) = reader.next()? {
         let value = parse(rows[0].as_bytes())?; // 0 because it is row by row
-        let data_type = infer_json(&value)?;
+        let data_type = infer_json(&value, options)?;
         if data_type != DataType::Null {
             data_types.insert(data_type);
         }
     }
 
     let v: Vec<&DataType> = data_types.iter().collect();
-    Ok(coerce_data_type(&v))
+    Ok(coerce_data_type(&v, options))
 }
 
 /// Infers the [`DataType`] from an iterator of JSON strings. A limited number of
@@ -131,15 +146,23 @@ pub fn infer<R: std::io::BufRead>(
 /// # Implementation
 /// This implementation infers each row by going through the entire iterator.
 pub fn infer_iter<A: AsRef<str>>(rows: impl Iterator<Item = A>) -> Result<DataType> {
+    infer_iter_with_options(rows, &InferOptions::default())
+}
+
+/// Infers the [`DataType`] from an iterator of JSON strings according to `options`.
+pub fn infer_iter_with_options<A: AsRef<str>>(
+    rows: impl Iterator<Item = A>,
+    options: &InferOptions,
+) -> Result<DataType> {
     let mut data_types = HashSet::new();
     for row in rows {
         let v = parse(row.as_ref().as_bytes())?;
-        let data_type = infer_json(&v)?;
+        let data_type = infer_json(&v, options)?;
         if data_type != DataType::Null {
             data_types.insert(data_type);
         }
     }
 
     let v: Vec<&DataType> = data_types.iter().collect();
-    Ok(coerce_data_type(&v))
+    Ok(coerce_data_type(&v, options))
 }
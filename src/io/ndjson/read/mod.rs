@@ -4,5 +4,6 @@ pub use fallible_streaming_iterator::FallibleStreamingIterator;
 
 mod deserialize;
 mod file;
+pub use crate::io::json::read::{InferOptions, MixedTypes};
 pub use deserialize::{deserialize, deserialize_iter};
-pub use file::{infer, infer_iter, FileReader};
+pub use file::{infer, infer_iter, infer_iter_with_options, infer_with_options, FileReader};
@@ -0,0 +1,102 @@
+//! Async reader for ORC files
+use std::io::{Read, SeekFrom};
+
+use futures::{AsyncRead, AsyncReadExt, AsyncSeek, AsyncSeekExt};
+use orc_format::proto::{CompressionKind, Footer, Metadata, PostScript};
+use orc_format::read::{decompress::Decompressor, FileMetadata};
+use prost::Message;
+
+use crate::error::Error;
+
+use super::stripe::{stripe_range, Stripe};
+
+async fn read_range<R>(reader: &mut R, start: u64, length: u64) -> Result<Vec<u8>, Error>
+where
+    R: AsyncRead + AsyncSeek + Unpin,
+{
+    reader.seek(SeekFrom::Start(start)).await?;
+    let mut data = vec![];
+    data.try_reserve(length as usize)?;
+    reader.take(length).read_to_end(&mut data).await?;
+    if data.len() as u64 != length {
+        return Err(Error::oos("The ORC file ended before its footer"));
+    }
+    Ok(data)
+}
+
+fn decompress(bytes: &[u8], compression: CompressionKind) -> Result<Vec<u8>, Error> {
+    let mut buffer = vec![];
+    Decompressor::new(bytes, compression, vec![]).read_to_end(&mut buffer)?;
+    Ok(buffer)
+}
+
+fn decode<M: Message + Default>(bytes: &[u8]) -> Result<M, Error> {
+    M::decode(bytes).map_err(|error| orc_format::error::Error::from(error).into())
+}
+
+/// Reads the [`FileMetadata`] of an ORC file from an async reader.
+/// # Implementation
+/// This function performs exactly three reads to `reader`: the last byte of the file (the
+/// length of the postscript), the postscript, and the metadata and footer, whose lengths
+/// are declared in the postscript.
+pub async fn read_metadata_async<R>(reader: &mut R) -> Result<FileMetadata, Error>
+where
+    R: AsyncRead + AsyncSeek + Unpin,
+{
+    let file_len = reader.seek(SeekFrom::End(0)).await?;
+    let postscript_len_start = file_len
+        .checked_sub(1)
+        .ok_or_else(|| Error::oos("An ORC file must contain at least one byte"))?;
+    let postscript_len = read_range(reader, postscript_len_start, 1).await?[0] as u64;
+
+    let postscript_start = postscript_len_start
+        .checked_sub(postscript_len)
+        .ok_or_else(|| Error::oos("The ORC postscript is larger than the file"))?;
+    let postscript: PostScript =
+        decode(&read_range(reader, postscript_start, postscript_len).await?)?;
+
+    let footer_len = postscript
+        .footer_length
+        .ok_or_else(|| Error::oos("The ORC postscript must contain the footer's length"))?;
+    let metadata_len = postscript
+        .metadata_length
+        .ok_or_else(|| Error::oos("The ORC postscript must contain the metadata's length"))?;
+    let tail_start = footer_len
+        .checked_add(metadata_len)
+        .and_then(|tail_len| postscript_start.checked_sub(tail_len))
+        .ok_or_else(|| Error::oos("The ORC footer and metadata are larger than the file"))?;
+
+    // the metadata is followed by the footer
+    let tail = read_range(reader, tail_start, metadata_len + footer_len).await?;
+    let (metadata, footer) = tail.split_at(metadata_len as usize);
+    let compression = postscript.compression();
+    let metadata: Metadata = decode(&decompress(metadata, compression)?)?;
+    let footer: Footer = decode(&decompress(footer, compression)?)?;
+
+    Ok(FileMetadata {
+        postscript,
+        footer,
+        metadata,
+    })
+}
+
+/// Reads the stripe `stripe` of the file described by `metadata` from an async reader
+/// into a [`Stripe`], which can be decoded in a separate thread.
+/// # Implementation
+/// This function performs exactly one seek and one read to `reader`.
+pub async fn read_stripe_async<R>(
+    reader: &mut R,
+    metadata: &FileMetadata,
+    stripe: usize,
+) -> Result<Stripe, Error>
+where
+    R: AsyncRead + AsyncSeek + Unpin,
+{
+    let (offset, length) = stripe_range(metadata, stripe)?;
+    reader.seek(SeekFrom::Start(offset)).await?;
+
+    let mut data = vec![];
+    data.try_reserve(length as usize)?;
+    reader.take(length).read_to_end(&mut data).await?;
+    Stripe::try_new(metadata, stripe, data)
+}
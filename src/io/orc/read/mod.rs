@@ -13,6 +13,16 @@ use orc_format::proto::{Footer, Type};
 use orc_format::read::decode;
 use orc_format::read::Column;
 
+#[cfg(feature = "io_orc_async")]
+#[cfg_attr(docsrs, doc(cfg(feature = "io_orc_async")))]
+mod file_async;
+mod stripe;
+
+#[cfg(feature = "io_orc_async")]
+#[cfg_attr(docsrs, doc(cfg(feature = "io_orc_async")))]
+pub use file_async::{read_metadata_async, read_stripe_async};
+pub use stripe::{read_stripe, Stripe};
+
 /// Infers a [`Schema`] from the files' [`Footer`].
 /// # Errors
 /// This function errors if the type is not yet supported.
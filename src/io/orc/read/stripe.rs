@@ -0,0 +1,137 @@
+use std::io::{Cursor, Read, Seek, SeekFrom};
+
+use orc_format::proto::{Footer, StripeFooter};
+use orc_format::read::{read_stripe_column, read_stripe_footer, Column, FileMetadata};
+
+use crate::array::Array;
+use crate::chunk::Chunk;
+use crate::datatypes::Schema;
+use crate::error::Error;
+
+use super::deserialize;
+
+/// A stripe of an ORC file, read to memory, that can be decoded independently of the file and
+/// of the other stripes.
+///
+/// This struct is [`Send`], so that stripes read sequentially (e.g. from object storage) can be
+/// decoded in parallel, analogous to parquet's row groups.
+#[derive(Debug)]
+pub struct Stripe {
+    // metadata of a file whose only stripe is this stripe, starting at offset 0 of `data`
+    metadata: FileMetadata,
+    footer: StripeFooter,
+    data: Vec<u8>,
+}
+
+impl Stripe {
+    /// Returns a new [`Stripe`] from the stripe `stripe` of the file described by `metadata`,
+    /// and the stripe's bytes, `data` (its index, data and footer).
+    /// # Errors
+    /// Errors iff `stripe` is out of bounds or the stripe's footer is invalid.
+    pub fn try_new(metadata: &FileMetadata, stripe: usize, data: Vec<u8>) -> Result<Self, Error> {
+        let (_, length) = stripe_range(metadata, stripe)?;
+        if data.len() as u64 != length {
+            return Err(Error::ExternalFormat(format!(
+                "ORC stripe {stripe} has {length} bytes, but {} bytes were provided",
+                data.len()
+            )));
+        }
+
+        let mut information = metadata.footer.stripes[stripe].clone();
+        information.offset = Some(0);
+        let metadata = FileMetadata {
+            postscript: metadata.postscript.clone(),
+            footer: Footer {
+                stripes: vec![information],
+                types: metadata.footer.types.clone(),
+                ..Default::default()
+            },
+            metadata: Default::default(),
+        };
+
+        let mut reader = Cursor::new(data);
+        let footer = read_stripe_footer(&mut reader, &metadata, 0, &mut vec![])?;
+        Ok(Self {
+            metadata,
+            footer,
+            data: reader.into_inner(),
+        })
+    }
+
+    /// The number of rows of this stripe
+    pub fn number_of_rows(&self) -> usize {
+        self.metadata.footer.stripes[0].number_of_rows() as usize
+    }
+
+    /// The footer of this stripe
+    pub fn footer(&self) -> &StripeFooter {
+        &self.footer
+    }
+
+    /// Returns the ORC column `column` of this stripe, whose id is the index of its type
+    /// in the file's footer (`0` is the root struct).
+    pub fn column(&self, column: u32) -> Result<Column, Error> {
+        Ok(read_stripe_column(
+            &mut Cursor::new(&self.data),
+            &self.metadata,
+            0,
+            self.footer.clone(),
+            column,
+            vec![],
+        )?)
+    }
+
+    /// Deserializes this stripe into a [`Chunk`] whose arrays correspond to the fields of
+    /// `schema`, the schema inferred via [`infer_schema`](super::infer_schema).
+    /// # Errors
+    /// Errors iff `schema` does not correspond to the file's root struct, or any of its
+    /// columns can't be deserialized.
+    pub fn deserialize(&self, schema: &Schema) -> Result<Chunk<Box<dyn Array>>, Error> {
+        let columns = &self.metadata.footer.types[0].subtypes;
+        if columns.len() != schema.fields.len() {
+            return Err(Error::InvalidArgumentError(format!(
+                "The schema has {} fields, but the ORC file has {} columns",
+                schema.fields.len(),
+                columns.len()
+            )));
+        }
+        let arrays = schema
+            .fields
+            .iter()
+            .zip(columns.iter())
+            .map(|(field, column)| deserialize(field.data_type.clone(), &self.column(*column)?))
+            .collect::<Result<Vec<_>, Error>>()?;
+        Chunk::try_new(arrays)
+    }
+}
+
+/// Returns the offset and length of the stripe `stripe` in the file
+pub(super) fn stripe_range(metadata: &FileMetadata, stripe: usize) -> Result<(u64, u64), Error> {
+    let information = metadata.footer.stripes.get(stripe).ok_or_else(|| {
+        Error::InvalidArgumentError(format!(
+            "The ORC file has {} stripes, but stripe {stripe} was requested",
+            metadata.footer.stripes.len()
+        ))
+    })?;
+    let length =
+        information.index_length() + information.data_length() + information.footer_length();
+    Ok((information.offset(), length))
+}
+
+/// Reads the stripe `stripe` of the file described by `metadata` from `reader`
+/// into a [`Stripe`].
+/// # Implementation
+/// This function performs exactly one seek and one read to `reader`.
+pub fn read_stripe<R: Read + Seek>(
+    reader: &mut R,
+    metadata: &FileMetadata,
+    stripe: usize,
+) -> Result<Stripe, Error> {
+    let (offset, length) = stripe_range(metadata, stripe)?;
+    reader.seek(SeekFrom::Start(offset))?;
+
+    let mut data = vec![];
+    data.try_reserve(length as usize)?;
+    reader.take(length).read_to_end(&mut data)?;
+    Stripe::try_new(metadata, stripe, data)
+}
@@ -0,0 +1,161 @@
+//! APIs to read and query the bloom filters of parquet column chunks.
+use std::io::{Read, Seek};
+
+use parquet2::bloom_filter;
+
+use crate::datatypes::{DataType, PhysicalType as ArrowPhysicalType, PrimitiveType};
+use crate::error::Result;
+use crate::scalar::{
+    BinaryScalar, DictionaryScalar, FixedSizeBinaryScalar, PrimitiveScalar, Scalar, Utf8Scalar,
+};
+use crate::types::NativeType;
+
+use super::{ColumnChunkMetaData, PhysicalType};
+
+/// The (split block) bloom filter of a parquet column chunk.
+///
+/// Bloom filters have no false negatives: when [`BloomFilter::might_contain`] returns `false`,
+/// the column chunk does not contain the value and its row group can be skipped by point lookups.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BloomFilter {
+    physical_type: PhysicalType,
+    bitset: Vec<u8>,
+}
+
+impl BloomFilter {
+    /// Returns a new [`BloomFilter`] of a column chunk of `physical_type` from its `bitset`.
+    pub fn new(physical_type: PhysicalType, bitset: Vec<u8>) -> Self {
+        Self {
+            physical_type,
+            bitset,
+        }
+    }
+
+    /// The physical type of the column chunk of this [`BloomFilter`]
+    pub fn physical_type(&self) -> PhysicalType {
+        self.physical_type
+    }
+
+    /// The bitset of this [`BloomFilter`]
+    pub fn bitset(&self) -> &[u8] {
+        &self.bitset
+    }
+
+    /// Returns whether the column chunk might contain `scalar`.
+    ///
+    /// This returns `false` only if the column chunk does not contain `scalar`. It is conservative
+    /// and returns `true` for null scalars and for scalars whose data type can't be hashed
+    /// as the physical type of the column chunk.
+    pub fn might_contain(&self, scalar: &dyn Scalar) -> bool {
+        hash(scalar, self.physical_type)
+            .map(|hash| bloom_filter::is_in_set(&self.bitset, hash))
+            .unwrap_or(true)
+    }
+}
+
+fn primitive<T: NativeType>(scalar: &dyn Scalar) -> Option<T> {
+    *scalar
+        .as_any()
+        .downcast_ref::<PrimitiveScalar<T>>()
+        .unwrap()
+        .value()
+}
+
+/// Returns the value of an integer (or decimal) scalar
+fn integer(scalar: &dyn Scalar) -> Option<i128> {
+    use PrimitiveType::*;
+    match scalar.data_type().to_physical_type() {
+        ArrowPhysicalType::Primitive(primitive_type) => match primitive_type {
+            Int8 => primitive::<i8>(scalar).map(|x| x as i128),
+            Int16 => primitive::<i16>(scalar).map(|x| x as i128),
+            Int32 => primitive::<i32>(scalar).map(|x| x as i128),
+            Int64 => primitive::<i64>(scalar).map(|x| x as i128),
+            UInt8 => primitive::<u8>(scalar).map(|x| x as i128),
+            UInt16 => primitive::<u16>(scalar).map(|x| x as i128),
+            UInt32 => primitive::<u32>(scalar).map(|x| x as i128),
+            UInt64 => primitive::<u64>(scalar).map(|x| x as i128),
+            Int128 => primitive::<i128>(scalar),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Returns the bytes of a binary, utf8 or fixed-size binary scalar
+fn bytes(scalar: &dyn Scalar) -> Option<&[u8]> {
+    match scalar.data_type().to_physical_type() {
+        ArrowPhysicalType::Binary => scalar
+            .as_any()
+            .downcast_ref::<BinaryScalar<i32>>()
+            .unwrap()
+            .value(),
+        ArrowPhysicalType::LargeBinary => scalar
+            .as_any()
+            .downcast_ref::<BinaryScalar<i64>>()
+            .unwrap()
+            .value(),
+        ArrowPhysicalType::Utf8 => scalar
+            .as_any()
+            .downcast_ref::<Utf8Scalar<i32>>()
+            .unwrap()
+            .value()
+            .map(|x| x.as_bytes()),
+        ArrowPhysicalType::LargeUtf8 => scalar
+            .as_any()
+            .downcast_ref::<Utf8Scalar<i64>>()
+            .unwrap()
+            .value()
+            .map(|x| x.as_bytes()),
+        ArrowPhysicalType::FixedSizeBinary => scalar
+            .as_any()
+            .downcast_ref::<FixedSizeBinaryScalar>()
+            .unwrap()
+            .value(),
+        _ => None,
+    }
+}
+
+/// Returns the hash of `scalar` as it is written to a column chunk of `physical_type`,
+/// or `None` if it is null or can't be hashed as `physical_type`.
+fn hash(scalar: &dyn Scalar, physical_type: PhysicalType) -> Option<u64> {
+    if !scalar.is_valid() {
+        return None;
+    }
+    if let ArrowPhysicalType::Dictionary(key_type) = scalar.data_type().to_physical_type() {
+        return match_integer_type!(key_type, |$T| {
+            let scalar = scalar.as_any().downcast_ref::<DictionaryScalar<$T>>().unwrap();
+            hash(scalar.value()?.as_ref(), physical_type)
+        });
+    }
+    match (physical_type, scalar.data_type().to_logical_type()) {
+        (PhysicalType::Float, DataType::Float32) => {
+            primitive::<f32>(scalar).map(bloom_filter::hash_native)
+        }
+        (PhysicalType::Double, DataType::Float64) => {
+            primitive::<f64>(scalar).map(bloom_filter::hash_native)
+        }
+        // integers are written with wrapping semantics (e.g. `u32` as `i32`)
+        (PhysicalType::Int32, _) => integer(scalar).map(|x| bloom_filter::hash_native(x as i32)),
+        (PhysicalType::Int64, _) => integer(scalar).map(|x| bloom_filter::hash_native(x as i64)),
+        (PhysicalType::ByteArray, _) => bytes(scalar).map(bloom_filter::hash_byte),
+        (PhysicalType::FixedLenByteArray(size), DataType::Decimal(_, _)) if size <= 16 => {
+            // decimals are written as big-endian two's complement of `size` bytes
+            integer(scalar).map(|x| bloom_filter::hash_byte(&x.to_be_bytes()[16 - size..]))
+        }
+        (PhysicalType::FixedLenByteArray(_), _) => bytes(scalar).map(bloom_filter::hash_byte),
+        _ => None,
+    }
+}
+
+/// Reads the [`BloomFilter`] of the column chunk `column` from `reader`,
+/// returning `None` if the column chunk has no bloom filter.
+/// # Errors
+/// This function errors iff the bloom filter can't be read or is out of spec.
+pub fn read_bloom_filter<R: Read + Seek>(
+    column: &ColumnChunkMetaData,
+    reader: &mut R,
+) -> Result<Option<BloomFilter>> {
+    let mut bitset = vec![];
+    bloom_filter::read(column, reader, &mut bitset)?;
+    Ok((!bitset.is_empty()).then(|| BloomFilter::new(column.physical_type(), bitset)))
+}
@@ -0,0 +1,503 @@
+//! APIs to read partitioned datasets of parquet files.
+use std::collections::VecDeque;
+use std::fs::File;
+use std::io::{Read, Seek};
+use std::path::{Component, Path};
+
+use crate::array::{new_null_array, Array, DictionaryArray, Int32Array, Utf8Array};
+use crate::chunk::Chunk;
+use crate::compute::cast::{cast_chunk_to_schema, CastOptions};
+use crate::datatypes::{merge_schemas, DataType, Field, IntegerType, Schema};
+use crate::error::{Error, Result};
+use crate::io::parquet::write::HIVE_DEFAULT_PARTITION;
+
+use super::{infer_schema, read_metadata, FileReader, RowGroupMetaData};
+
+type RowGroupFilter = Box<dyn Fn(&Schema, &RowGroupMetaData) -> bool + Send + Sync>;
+
+/// A file of a dataset whose metadata was read.
+struct DatasetFile<R: Read + Seek> {
+    reader: R,
+    row_groups: Vec<RowGroupMetaData>,
+    schema: Schema,
+    partition: Vec<Option<String>>,
+}
+
+/// The chunks of the file being read.
+enum FileChunks<R: Read + Seek> {
+    /// The file has some of the projected fields, which are read from it
+    Columns {
+        reader: Box<FileReader<R>>,
+        schema: Schema,
+        partition: Vec<Option<String>>,
+    },
+    /// The file has none of the projected fields, so only the number of rows of its row groups
+    /// is needed
+    Rows {
+        num_rows: std::vec::IntoIter<usize>,
+        partition: Vec<Option<String>>,
+    },
+}
+
+/// Reads a dataset of parquet files, e.g. as written by
+/// [`DatasetWriter`](crate::io::parquet::write::DatasetWriter), as a single stream of [`Chunk`]s.
+///
+/// The schemas of the files are unified via [`merge_schemas`], and the chunks of every file are
+/// cast to the unified schema, with fields missing in a file filled with nulls. The partition
+/// columns of the files (e.g. `country=pt` in `root/country=pt/part-00000.parquet`) are appended
+/// to the fields of the files as dictionary-encoded [`DataType::Utf8`] columns.
+///
+/// Files are read in the order they were passed, and the projection and row-group filter are
+/// applied to every file, so that only the projected columns of the selected row groups are read.
+/// # Example
+/// ```no_run
+/// use arrow2::io::parquet::read::DatasetReader;
+///
+/// # fn main() -> arrow2::error::Result<()> {
+/// let paths = ["dataset/country=pt/part-00000.parquet", "dataset/country=de/part-00001.parquet"];
+/// let reader = DatasetReader::try_from_paths("dataset", &paths)?
+///     .with_projection(&["value", "country"])?
+///     .with_row_group_filter(|_, row_group| row_group.num_rows() > 0);
+///
+/// for chunk in reader {
+///     let chunk = chunk?;
+///     // the columns "value" and "country"
+///     assert_eq!(chunk.arrays().len(), 2);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub struct DatasetReader<R: Read + Seek> {
+    files: VecDeque<DatasetFile<R>>,
+    file_schema: Schema,
+    partition_fields: Vec<Field>,
+    projection: Option<Vec<usize>>,
+    row_group_filter: Option<RowGroupFilter>,
+    chunk_size: Option<usize>,
+    current: Option<FileChunks<R>>,
+}
+
+impl DatasetReader<File> {
+    /// Returns a new [`DatasetReader`] of the files at `paths`, whose partition columns are
+    /// parsed from the directories of `paths` below `root` (see [`parse_partition`]).
+    /// # Errors
+    /// Errors iff any file can't be opened or its metadata can't be read, or the files can't
+    /// be unified (see [`DatasetReader::try_new`]).
+    pub fn try_from_paths<P: AsRef<Path>, Q: AsRef<Path>>(root: P, paths: &[Q]) -> Result<Self> {
+        let files = paths
+            .iter()
+            .map(|path| {
+                let path = path.as_ref();
+                let partition = parse_partition(root.as_ref(), path)?;
+                Ok((File::open(path)?, partition))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Self::try_new(files)
+    }
+}
+
+impl<R: Read + Seek> DatasetReader<R> {
+    /// Returns a new [`DatasetReader`] of `files`, the reader of every file and the name and
+    /// value of its partition columns (`None` when the value is null).
+    ///
+    /// The metadata of every file is read to unify their schemas.
+    /// # Errors
+    /// Errors iff:
+    /// * `files` is empty
+    /// * the metadata of any file can't be read
+    /// * the schemas of the files can't be merged (see [`merge_schemas`])
+    /// * the files have different partition columns, or a partition column is also a field of
+    ///   the files
+    pub fn try_new(files: Vec<(R, Vec<(String, Option<String>)>)>) -> Result<Self> {
+        let names = files
+            .first()
+            .ok_or_else(|| {
+                Error::InvalidArgumentError("A dataset requires at least one file".to_string())
+            })?
+            .1
+            .iter()
+            .map(|(name, _)| name.clone())
+            .collect::<Vec<_>>();
+
+        let files = files
+            .into_iter()
+            .map(|(mut reader, partition)| {
+                if partition.len() != names.len()
+                    || partition.iter().zip(names.iter()).any(|((a, _), b)| a != b)
+                {
+                    return Err(Error::InvalidArgumentError(format!(
+                        "All files of a dataset must have the partition columns {names:?}"
+                    )));
+                }
+                let metadata = read_metadata(&mut reader)?;
+                let schema = infer_schema(&metadata)?;
+                Ok(DatasetFile {
+                    reader,
+                    row_groups: metadata.row_groups,
+                    schema,
+                    partition: partition.into_iter().map(|(_, value)| value).collect(),
+                })
+            })
+            .collect::<Result<VecDeque<_>>>()?;
+
+        let schemas = files
+            .iter()
+            .map(|file| file.schema.clone())
+            .collect::<Vec<_>>();
+        let file_schema = merge_schemas(&schemas)?;
+
+        if let Some(name) = names
+            .iter()
+            .find(|name| file_schema.fields.iter().any(|field| &field.name == *name))
+        {
+            return Err(Error::InvalidArgumentError(format!(
+                "The partition column \"{name}\" is also a field of the files of the dataset"
+            )));
+        }
+        let partition_fields = names
+            .into_iter()
+            .map(|name| {
+                let data_type =
+                    DataType::Dictionary(IntegerType::Int32, Box::new(DataType::Utf8), false);
+                Field::new(name, data_type, true)
+            })
+            .collect();
+
+        Ok(Self {
+            files,
+            file_schema,
+            partition_fields,
+            projection: None,
+            row_group_filter: None,
+            chunk_size: None,
+            current: None,
+        })
+    }
+
+    /// Restricts the columns read to the fields named `columns`, which may be fields of the
+    /// files or partition columns. The columns keep their order in the dataset, the fields of the
+    /// files followed by the partition columns.
+    /// # Errors
+    /// Errors iff any of `columns` is not a field of the dataset.
+    pub fn with_projection(mut self, columns: &[&str]) -> Result<Self> {
+        let fields = self.all_fields().collect::<Vec<_>>();
+        if let Some(name) = columns
+            .iter()
+            .find(|name| !fields.iter().any(|field| field.name == **name))
+        {
+            return Err(Error::InvalidArgumentError(format!(
+                "The column \"{name}\" is not a field of the dataset"
+            )));
+        }
+        self.projection = Some(
+            fields
+                .iter()
+                .enumerate()
+                .filter(|(_, field)| columns.contains(&field.name.as_str()))
+                .map(|(index, _)| index)
+                .collect(),
+        );
+        Ok(self)
+    }
+
+    /// Only reads the row groups for which `filter` returns `true`. `filter` is called with the
+    /// schema of the file of the row group, e.g. to [`deserialize`](super::statistics::deserialize)
+    /// its statistics.
+    #[must_use]
+    pub fn with_row_group_filter<F>(mut self, filter: F) -> Self
+    where
+        F: Fn(&Schema, &RowGroupMetaData) -> bool + Send + Sync + 'static,
+    {
+        self.row_group_filter = Some(Box::new(filter));
+        self
+    }
+
+    /// Sets the maximum number of rows of the chunks read from every file.
+    #[must_use]
+    pub fn with_chunk_size(mut self, chunk_size: usize) -> Self {
+        self.chunk_size = Some(chunk_size);
+        self
+    }
+
+    fn all_fields(&self) -> impl Iterator<Item = &Field> {
+        self.file_schema
+            .fields
+            .iter()
+            .chain(self.partition_fields.iter())
+    }
+
+    fn is_projected(&self, index: usize) -> bool {
+        self.projection
+            .as_ref()
+            .map_or(true, |projection| projection.contains(&index))
+    }
+
+    /// The unified and projected [`Schema`] of the fields of the files
+    fn projected_file_schema(&self) -> Schema {
+        Schema {
+            fields: self
+                .file_schema
+                .fields
+                .iter()
+                .enumerate()
+                .filter(|(index, _)| self.is_projected(*index))
+                .map(|(_, field)| field.clone())
+                .collect(),
+            metadata: self.file_schema.metadata.clone(),
+        }
+    }
+
+    /// Returns the [`Schema`] of the chunks read by this [`DatasetReader`]: the unified
+    /// fields of the files followed by the partition columns, restricted to the projection.
+    pub fn schema(&self) -> Schema {
+        let num_file_fields = self.file_schema.fields.len();
+        let mut schema = self.projected_file_schema();
+        schema.fields.extend(
+            self.partition_fields
+                .iter()
+                .enumerate()
+                .filter(|(index, _)| self.is_projected(num_file_fields + index))
+                .map(|(_, field)| field.clone()),
+        );
+        schema
+    }
+
+    fn next_file(&mut self) -> Option<FileChunks<R>> {
+        let file = self.files.pop_front()?;
+        let row_groups = file
+            .row_groups
+            .into_iter()
+            .filter(|row_group| {
+                self.row_group_filter
+                    .as_ref()
+                    .map_or(true, |filter| filter(&file.schema, row_group))
+            })
+            .collect::<Vec<_>>();
+
+        let projected = self.projected_file_schema();
+        let fields = file
+            .schema
+            .fields
+            .into_iter()
+            .filter(|field| projected.fields.iter().any(|x| x.name == field.name))
+            .collect::<Vec<_>>();
+
+        Some(if fields.is_empty() {
+            FileChunks::Rows {
+                num_rows: row_groups
+                    .iter()
+                    .map(|row_group| row_group.num_rows())
+                    .collect::<Vec<_>>()
+                    .into_iter(),
+                partition: file.partition,
+            }
+        } else {
+            let schema = Schema {
+                fields,
+                metadata: file.schema.metadata,
+            };
+            FileChunks::Columns {
+                reader: Box::new(FileReader::new(
+                    file.reader,
+                    row_groups,
+                    schema.clone(),
+                    self.chunk_size,
+                    None,
+                    None,
+                )),
+                schema,
+                partition: file.partition,
+            }
+        })
+    }
+
+    /// Casts the arrays of a file to the projected schema and appends the partition columns.
+    fn finish_chunk(
+        &self,
+        chunk: Option<(&Chunk<Box<dyn Array>>, &Schema)>,
+        length: usize,
+        partition: &[Option<String>],
+    ) -> Result<Chunk<Box<dyn Array>>> {
+        let projected = self.projected_file_schema();
+        let mut arrays = match chunk {
+            Some((chunk, schema)) => {
+                cast_chunk_to_schema(chunk, schema, &projected, CastOptions::default())?
+                    .into_arrays()
+            }
+            None => projected
+                .fields
+                .iter()
+                .map(|field| new_null_array(field.data_type().clone(), length))
+                .collect(),
+        };
+
+        let num_file_fields = self.file_schema.fields.len();
+        for (index, (field, value)) in self.partition_fields.iter().zip(partition).enumerate() {
+            if !self.is_projected(num_file_fields + index) {
+                continue;
+            }
+            let keys = match value {
+                Some(_) => Int32Array::from_vec(vec![0; length]),
+                None => Int32Array::new_null(DataType::Int32, length),
+            };
+            // the dictionary always has one value, so that its null keys point to a valid slot
+            let values = Utf8Array::<i32>::from([value.as_deref()]);
+            arrays.push(
+                DictionaryArray::try_new(field.data_type().clone(), keys, values.boxed())?.boxed(),
+            );
+        }
+        Chunk::try_new(arrays)
+    }
+}
+
+impl<R: Read + Seek> Iterator for DatasetReader<R> {
+    type Item = Result<Chunk<Box<dyn Array>>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if self.current.is_none() {
+                self.current = Some(self.next_file()?);
+            }
+            let current = self.current.take().unwrap();
+            let (result, current) = match current {
+                FileChunks::Columns {
+                    mut reader,
+                    schema,
+                    partition,
+                } => {
+                    let result = reader.next().map(|chunk| {
+                        chunk.and_then(|chunk| {
+                            self.finish_chunk(Some((&chunk, &schema)), chunk.len(), &partition)
+                        })
+                    });
+                    let current = FileChunks::Columns {
+                        reader,
+                        schema,
+                        partition,
+                    };
+                    (result, current)
+                }
+                FileChunks::Rows {
+                    mut num_rows,
+                    partition,
+                } => {
+                    let result = num_rows
+                        .next()
+                        .map(|num_rows| self.finish_chunk(None, num_rows, &partition));
+                    (
+                        result,
+                        FileChunks::Rows {
+                            num_rows,
+                            partition,
+                        },
+                    )
+                }
+            };
+            match result {
+                Some(result) => {
+                    self.current = Some(current);
+                    return Some(result);
+                }
+                // the current file is exhausted => try the next one
+                None => continue,
+            }
+        }
+    }
+}
+
+impl<R: Read + Seek> std::fmt::Debug for DatasetReader<R> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DatasetReader")
+            .field("remaining_files", &self.files.len())
+            .field("file_schema", &self.file_schema)
+            .field("partition_fields", &self.partition_fields)
+            .field("projection", &self.projection)
+            .field("chunk_size", &self.chunk_size)
+            .finish()
+    }
+}
+
+/// Reverts the escaping of Hive-style path components (e.g. `%2F` is read as `/`).
+fn unescape(value: &str) -> Result<String> {
+    let bytes = value.as_bytes();
+    let mut unescaped = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let byte = bytes[i];
+        if byte == b'%' {
+            let code = value
+                .get(i + 1..i + 3)
+                .and_then(|code| u8::from_str_radix(code, 16).ok())
+                .ok_or_else(|| {
+                    Error::InvalidArgumentError(format!(
+                        "The path component \"{value}\" contains an invalid escape sequence"
+                    ))
+                })?;
+            unescaped.push(code);
+            i += 3;
+        } else {
+            unescaped.push(byte);
+            i += 1;
+        }
+    }
+    String::from_utf8(unescaped).map_err(|_| {
+        Error::InvalidArgumentError(format!(
+            "The path component \"{value}\" is not valid utf8 once unescaped"
+        ))
+    })
+}
+
+/// Returns the name and value of the partition columns of the file at `path`, parsed from the
+/// Hive-style directories (`key=value`) between `root` and the file. This is the inverse of
+/// [`partition_path`](crate::io::parquet::write::partition_path).
+///
+/// Directories that are not of the form `key=value` are ignored, and the value
+/// `__HIVE_DEFAULT_PARTITION__` is read as null.
+/// # Errors
+/// Errors iff `path` is not below `root` or a directory contains invalid escape sequences.
+/// # Example
+/// ```
+/// use arrow2::io::parquet::read::parse_partition;
+///
+/// let partition = parse_partition(
+///     "dataset",
+///     "dataset/country=a%2Fb/year=__HIVE_DEFAULT_PARTITION__/part-00000.parquet",
+/// )
+/// .unwrap();
+/// assert_eq!(
+///     partition,
+///     vec![
+///         ("country".to_string(), Some("a/b".to_string())),
+///         ("year".to_string(), None),
+///     ]
+/// );
+/// ```
+pub fn parse_partition<P: AsRef<Path>, Q: AsRef<Path>>(
+    root: P,
+    path: Q,
+) -> Result<Vec<(String, Option<String>)>> {
+    let path = path.as_ref();
+    let relative = path.strip_prefix(root.as_ref()).map_err(|_| {
+        Error::InvalidArgumentError(format!(
+            "The path {path:?} is not below the root of the dataset"
+        ))
+    })?;
+    let directory = relative.parent().unwrap_or_else(|| Path::new(""));
+
+    directory
+        .components()
+        .filter_map(|component| match component {
+            Component::Normal(component) => component.to_str(),
+            _ => None,
+        })
+        .filter_map(|component| component.split_once('='))
+        .map(|(name, value)| {
+            let value = if value == HIVE_DEFAULT_PARTITION {
+                None
+            } else {
+                Some(unescape(value)?)
+            };
+            Ok((unescape(name)?, value))
+        })
+        .collect()
+}
@@ -15,9 +15,10 @@ use parquet2::read::get_page_iterator as _get_page_iterator;
 use parquet2::schema::types::PrimitiveType;
 
 use crate::{
-    array::{Array, DictionaryKey, FixedSizeListArray, ListArray},
+    array::{growable::make_growable, Array, DictionaryKey, FixedSizeListArray, ListArray},
+    bitmap::Bitmap,
     datatypes::{DataType, Field, IntervalUnit},
-    error::Result,
+    error::{Error, Result},
     offset::Offsets,
 };
 
@@ -47,7 +48,7 @@ fn create_list(
     data_type: DataType,
     nested: &mut NestedState,
     values: Box<dyn Array>,
-) -> Box<dyn Array> {
+) -> Result<Box<dyn Array>> {
     let (mut offsets, validity) = nested.nested.pop().unwrap().inner();
     match data_type.to_logical_type() {
         DataType::List(_) => {
@@ -59,32 +60,67 @@ fn create_list(
                 .try_into()
                 .expect("i64 offsets do not fit in i32 offsets");
 
-            Box::new(ListArray::<i32>::new(
+            Ok(Box::new(ListArray::<i32>::new(
                 data_type,
                 offsets.into(),
                 values,
                 validity.and_then(|x| x.into()),
-            ))
+            )))
         }
         DataType::LargeList(_) => {
             offsets.push(values.len() as i64);
 
-            Box::new(ListArray::<i64>::new(
+            Ok(Box::new(ListArray::<i64>::new(
                 data_type,
                 offsets.try_into().expect("List too large"),
                 values,
                 validity.and_then(|x| x.into()),
-            ))
+            )))
+        }
+        DataType::FixedSizeList(_, size) => {
+            offsets.push(values.len() as i64);
+            let validity: Option<Bitmap> = validity.and_then(|x| x.into());
+            let values = fixed_size_list_values(&offsets, validity.as_ref(), values, *size)?;
+            Ok(Box::new(FixedSizeListArray::try_new(
+                data_type, values, validity,
+            )?))
         }
-        DataType::FixedSizeList(_, _) => Box::new(FixedSizeListArray::new(
-            data_type,
-            values,
-            validity.and_then(|x| x.into()),
-        )),
         _ => unreachable!(),
     }
 }
 
+/// Returns the values of a [`FixedSizeListArray`] of `size` from the values of a parquet list,
+/// whose null slots have no values.
+/// # Errors
+/// Errors iff a valid slot does not have `size` values, or a null slot has values.
+fn fixed_size_list_values(
+    offsets: &[i64],
+    validity: Option<&Bitmap>,
+    values: Box<dyn Array>,
+    size: usize,
+) -> Result<Box<dyn Array>> {
+    let lengths = offsets.windows(2).map(|w| (w[1] - w[0]) as usize);
+    let is_valid = |i: usize| validity.map(|x| x.get_bit(i)).unwrap_or(true);
+    if lengths.clone().all(|length| length == size) {
+        return Ok(values);
+    }
+
+    let mut growable = make_growable(&[values.as_ref()], true, (offsets.len() - 1) * size);
+    for (i, length) in lengths.enumerate() {
+        let start = offsets[i] as usize;
+        match (is_valid(i), length) {
+            (true, length) if length == size => growable.extend(0, start, size),
+            (false, 0) => growable.extend_validity(size),
+            _ => {
+                return Err(Error::oos(format!(
+                    "The list of a FixedSizeList of size {size} has {length} values"
+                )))
+            }
+        }
+    }
+    Ok(growable.as_box())
+}
+
 fn is_primitive(data_type: &DataType) -> bool {
     matches!(
         data_type.to_physical_type(),
@@ -107,6 +143,7 @@ fn columns_to_iter_recursive<'a, I: 'a>(
     init: Vec<InitNested>,
     num_rows: usize,
     chunk_size: Option<usize>,
+    int96_overflow: Int96Overflow,
 ) -> Result<NestedArrayIter<'a>>
 where
     I: Pages,
@@ -119,6 +156,7 @@ where
                 field.data_type,
                 chunk_size,
                 num_rows,
+                int96_overflow,
             )?
             .map(|x| Ok((NestedState::new(vec![]), x?))),
         ));
@@ -162,6 +200,9 @@ fn n_columns(data_type: &DataType) -> usize {
 /// For nested types, `columns` must be composed by all parquet columns with associated types `types`.
 ///
 /// The arrays are guaranteed to be at most of size `chunk_size` and data type `field.data_type`.
+///
+/// `INT96` timestamps that overflow the [`TimeUnit`](crate::datatypes::TimeUnit) of `field` error
+/// (see [`column_iter_to_arrays_with_int96_overflow`]).
 pub fn column_iter_to_arrays<'a, I: 'a>(
     columns: Vec<I>,
     types: Vec<&PrimitiveType>,
@@ -172,8 +213,36 @@ pub fn column_iter_to_arrays<'a, I: 'a>(
 where
     I: Pages,
 {
+    column_iter_to_arrays_with_int96_overflow(
+        columns,
+        types,
+        field,
+        chunk_size,
+        num_rows,
+        Int96Overflow::default(),
+    )
+}
+
+/// Like [`column_iter_to_arrays`], but reads `INT96` timestamps that overflow the
+/// [`TimeUnit`](crate::datatypes::TimeUnit) of `field` according to `int96_overflow`.
+pub fn column_iter_to_arrays_with_int96_overflow<'a, I: Pages + 'a>(
+    columns: Vec<I>,
+    types: Vec<&PrimitiveType>,
+    field: Field,
+    chunk_size: Option<usize>,
+    num_rows: usize,
+    int96_overflow: Int96Overflow,
+) -> Result<ArrayIter<'a>> {
     Ok(Box::new(
-        columns_to_iter_recursive(columns, types, field, vec![], num_rows, chunk_size)?
-            .map(|x| x.map(|x| x.1)),
+        columns_to_iter_recursive(
+            columns,
+            types,
+            field,
+            vec![],
+            num_rows,
+            chunk_size,
+            int96_overflow,
+        )?
+        .map(|x| x.map(|x| x.1)),
     ))
 }
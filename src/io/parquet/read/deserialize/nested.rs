@@ -253,7 +253,7 @@ where
                 )?;
                 let iter = iter.map(move |x| {
                     let (mut nested, array) = x?;
-                    let array = create_list(field.data_type().clone(), &mut nested, array);
+                    let array = create_list(field.data_type().clone(), &mut nested, array)?;
                     Ok((nested, array))
                 });
                 Box::new(iter) as _
@@ -351,6 +351,14 @@ fn dict_read<'a, K: DictionaryKey, I: 'a + Pages>(
             chunk_size,
             |x: i32| x as u32,
         )),
+        UInt64 => primitive(primitive::NestedDictIter::<K, _, _, _, _>::new(
+            iter,
+            init,
+            data_type,
+            num_rows,
+            chunk_size,
+            |x: i64| x as u64,
+        )),
         Int8 => primitive(primitive::NestedDictIter::<K, _, _, _, _>::new(
             iter,
             init,
@@ -1,18 +1,18 @@
-use parquet2::{
-    schema::types::{
-        PhysicalType, PrimitiveLogicalType, PrimitiveType, TimeUnit as ParquetTimeUnit,
-    },
-    types::int96_to_i64_ns,
+use parquet2::schema::types::{
+    PhysicalType, PrimitiveLogicalType, PrimitiveType, TimeUnit as ParquetTimeUnit,
 };
 
 use crate::{
-    array::{Array, BinaryArray, DictionaryKey, MutablePrimitiveArray, PrimitiveArray, Utf8Array},
+    array::{
+        Array, BinaryArray, DictionaryArray, DictionaryKey, MutablePrimitiveArray, PrimitiveArray,
+        Utf8Array,
+    },
     datatypes::{DataType, IntervalUnit, TimeUnit},
     error::{Error, Result},
     types::{days_ms, NativeType},
 };
 
-use super::super::{ArrayIter, Pages};
+use super::super::{int96_to_time_unit_i128, ArrayIter, Int96Overflow, Pages};
 use super::binary;
 use super::boolean;
 use super::fixed_size_binary;
@@ -62,6 +62,7 @@ pub fn page_iter_to_arrays<'a, I: Pages + 'a>(
     data_type: DataType,
     chunk_size: Option<usize>,
     num_rows: usize,
+    int96_overflow: Int96Overflow,
 ) -> Result<ArrayIter<'a>> {
     use DataType::*;
 
@@ -139,6 +140,7 @@ pub fn page_iter_to_arrays<'a, I: Pages + 'a>(
                 num_rows,
                 chunk_size,
                 time_unit,
+                int96_overflow,
             );
         }
 
@@ -293,7 +295,15 @@ pub fn page_iter_to_arrays<'a, I: Pages + 'a>(
 
         Dictionary(key_type, _, _) => {
             return match_integer_type!(key_type, |$K| {
-                dict_read::<$K, _>(pages, physical_type, logical_type, data_type, num_rows, chunk_size)
+                dict_read::<$K, _>(
+                    pages,
+                    physical_type,
+                    logical_type,
+                    data_type,
+                    num_rows,
+                    chunk_size,
+                    int96_overflow,
+                )
             })
         }
 
@@ -336,6 +346,25 @@ fn unifiy_timestmap_unit(
     }
 }
 
+/// Narrows `INT96` timestamps, decoded exactly to `i128`, to the `i64`s of `data_type`
+fn int96_to_i64(
+    array: &PrimitiveArray<i128>,
+    data_type: DataType,
+    int96_overflow: Int96Overflow,
+) -> Result<PrimitiveArray<i64>> {
+    let array = array
+        .iter()
+        .map(|value| match value.map(|x| i64::try_from(*x)) {
+            Some(Ok(value)) => Ok(Some(value)),
+            Some(Err(_)) if int96_overflow == Int96Overflow::Null => Ok(None),
+            Some(Err(_)) => Err(Error::Overflow),
+            None => Ok(None),
+        })
+        .collect::<Result<PrimitiveArray<i64>>>()?;
+    Ok(array.to(data_type))
+}
+
+#[allow(clippy::too_many_arguments)]
 fn timestamp<'a, I: Pages + 'a>(
     pages: I,
     physical_type: &PhysicalType,
@@ -344,19 +373,19 @@ fn timestamp<'a, I: Pages + 'a>(
     num_rows: usize,
     chunk_size: Option<usize>,
     time_unit: TimeUnit,
+    int96_overflow: Int96Overflow,
 ) -> Result<ArrayIter<'a>> {
     if physical_type == &PhysicalType::Int96 {
-        let iter = primitive::Iter::new(pages, data_type, num_rows, chunk_size, int96_to_i64_ns);
-        let logical_type = PrimitiveLogicalType::Timestamp {
-            unit: ParquetTimeUnit::Nanoseconds,
-            is_adjusted_to_utc: false,
-        };
-        let (factor, is_multiplier) = unifiy_timestmap_unit(&Some(logical_type), time_unit);
-        return match (factor, is_multiplier) {
-            (1, _) => Ok(dyn_iter(iden(iter))),
-            (a, true) => Ok(dyn_iter(op(iter, move |x| x * a))),
-            (a, false) => Ok(dyn_iter(op(iter, move |x| x / a))),
-        };
+        let iter = primitive::Iter::new(
+            pages,
+            DataType::Decimal(38, 0),
+            num_rows,
+            chunk_size,
+            int96_to_time_unit_i128(time_unit),
+        );
+        return Ok(dyn_iter(iden(iter).map(move |array| {
+            int96_to_i64(&array?, data_type.clone(), int96_overflow)
+        })));
     };
 
     if physical_type != &PhysicalType::Int64 {
@@ -374,6 +403,7 @@ fn timestamp<'a, I: Pages + 'a>(
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 fn timestamp_dict<'a, K: DictionaryKey, I: Pages + 'a>(
     pages: I,
     physical_type: &PhysicalType,
@@ -382,29 +412,27 @@ fn timestamp_dict<'a, K: DictionaryKey, I: Pages + 'a>(
     num_rows: usize,
     chunk_size: Option<usize>,
     time_unit: TimeUnit,
+    int96_overflow: Int96Overflow,
 ) -> Result<ArrayIter<'a>> {
     if physical_type == &PhysicalType::Int96 {
-        let logical_type = PrimitiveLogicalType::Timestamp {
-            unit: ParquetTimeUnit::Nanoseconds,
-            is_adjusted_to_utc: false,
-        };
-        let (factor, is_multiplier) = unifiy_timestmap_unit(&Some(logical_type), time_unit);
-        return match (factor, is_multiplier) {
-            (a, true) => Ok(dyn_iter(primitive::DictIter::<K, _, _, _, _>::new(
-                pages,
-                DataType::Timestamp(TimeUnit::Nanosecond, None),
-                num_rows,
-                chunk_size,
-                move |x| int96_to_i64_ns(x) * a,
-            ))),
-            (a, false) => Ok(dyn_iter(primitive::DictIter::<K, _, _, _, _>::new(
-                pages,
-                DataType::Timestamp(TimeUnit::Nanosecond, None),
-                num_rows,
-                chunk_size,
-                move |x| int96_to_i64_ns(x) / a,
-            ))),
-        };
+        let values_data_type = DictionaryArray::<K>::try_get_child(&data_type)?.clone();
+        let iter = primitive::DictIter::<K, _, _, _, _>::new(
+            pages,
+            DataType::Dictionary(K::KEY_TYPE, Box::new(DataType::Decimal(38, 0)), false),
+            num_rows,
+            chunk_size,
+            int96_to_time_unit_i128(time_unit),
+        );
+        return Ok(dyn_iter(iter.map(move |array| {
+            let array = array?;
+            let values = array
+                .values()
+                .as_any()
+                .downcast_ref::<PrimitiveArray<i128>>()
+                .unwrap();
+            let values = int96_to_i64(values, values_data_type.clone(), int96_overflow)?;
+            DictionaryArray::try_new(data_type.clone(), array.keys().clone(), values.boxed())
+        })));
     };
 
     let (factor, is_multiplier) = unifiy_timestmap_unit(logical_type, time_unit);
@@ -433,6 +461,7 @@ fn dict_read<'a, K: DictionaryKey, I: Pages + 'a>(
     data_type: DataType,
     num_rows: usize,
     chunk_size: Option<usize>,
+    int96_overflow: Int96Overflow,
 ) -> Result<ArrayIter<'a>> {
     use DataType::*;
     let values_data_type = if let Dictionary(_, v, _) = &data_type {
@@ -504,6 +533,7 @@ fn dict_read<'a, K: DictionaryKey, I: Pages + 'a>(
                 num_rows,
                 chunk_size,
                 time_unit,
+                int96_overflow,
             );
         }
 
@@ -7,6 +7,7 @@ use crate::datatypes::{DataType, TimeUnit};
 use crate::trusted_len::TrustedLen;
 use crate::types::NativeType;
 
+use super::super::int96_to_time_unit;
 use super::ColumnPageStatistics;
 
 #[inline]
@@ -129,8 +130,12 @@ fn deserialize_int96<I: TrustedLen<Item = Option<[u32; 3]>>>(
     iter: I,
     data_type: DataType,
 ) -> Box<dyn Array> {
+    let to_time_unit = match data_type.to_logical_type() {
+        DataType::Timestamp(time_unit, _) => int96_to_time_unit(*time_unit),
+        _ => |x| Some(int96_to_i64_ns(x)),
+    };
     Box::new(
-        PrimitiveArray::<i64>::from_trusted_len_iter(iter.map(|x| x.map(int96_to_i64_ns)))
+        PrimitiveArray::<i64>::from_trusted_len_iter(iter.map(|x| x.and_then(to_time_unit)))
             .to(data_type),
     )
 }
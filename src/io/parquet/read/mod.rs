@@ -1,12 +1,23 @@
 //! APIs to read from Parquet format.
 #![allow(clippy::type_complexity)]
 
+#[cfg(feature = "io_parquet_bloom_filter")]
+#[cfg_attr(docsrs, doc(cfg(feature = "io_parquet_bloom_filter")))]
+pub mod bloom_filter;
+#[cfg(feature = "io_parquet_dataset")]
+#[cfg_attr(docsrs, doc(cfg(feature = "io_parquet_dataset")))]
+mod dataset;
 mod deserialize;
 mod file;
 pub mod indexes;
+#[cfg(feature = "io_parquet_progress")]
+#[cfg_attr(docsrs, doc(cfg(feature = "io_parquet_progress")))]
+pub mod progress;
 mod row_group;
+mod row_selection;
 pub mod schema;
 pub mod statistics;
+mod summary;
 
 use std::io::{Read, Seek};
 
@@ -32,12 +43,98 @@ pub use parquet2::{
     FallibleStreamingIterator,
 };
 
-use crate::{array::Array, error::Result};
+use crate::{array::Array, datatypes::TimeUnit, error::Result};
 
-pub use deserialize::{column_iter_to_arrays, get_page_iterator};
+#[cfg(feature = "io_parquet_bloom_filter")]
+pub use bloom_filter::{read_bloom_filter, BloomFilter};
+#[cfg(feature = "io_parquet_dataset")]
+#[cfg_attr(docsrs, doc(cfg(feature = "io_parquet_dataset")))]
+pub use dataset::{parse_partition, DatasetReader};
+pub use deserialize::{
+    column_iter_to_arrays, column_iter_to_arrays_with_int96_overflow, get_page_iterator,
+};
 pub use file::{FileReader, RowGroupReader};
+#[cfg(feature = "io_parquet_progress")]
+pub use progress::{PageCallback, PageProgress, ProgressPages};
 pub use row_group::*;
-pub use schema::{infer_schema, FileMetaData};
+pub use row_selection::{read_columns_many_with_selection, RowSelection, RowSelector};
+pub use schema::{infer_schema, infer_schema_with_options, FileMetaData, SchemaInferenceOptions};
+pub use summary::ParquetFileSummary;
+
+/// How to read parquet `INT96` timestamps that do not fit in an `i64` of the [`TimeUnit`]
+/// they are read to (see [`SchemaInferenceOptions::int96_coerce_to_timeunit`]).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Int96Overflow {
+    /// Reading fails with [`Error::Overflow`](crate::error::Error::Overflow)
+    #[default]
+    Error,
+    /// The value is read as null
+    Null,
+}
+
+/// Converts a parquet `INT96` timestamp to `units_per_second` since the epoch.
+/// This is exact: every `INT96` timestamp fits in an `i128` of nanoseconds.
+#[inline]
+fn int96_to_i128(value: [u32; 3], units_per_second: i64) -> i128 {
+    const JULIAN_DAY_OF_EPOCH: i128 = 2_440_588;
+    const SECONDS_PER_DAY: i128 = 86_400;
+    const NANOS_PER_SECOND: i64 = 1_000_000_000;
+
+    let day = value[2] as i128;
+    let nanoseconds = ((value[1] as i128) << 32) + value[0] as i128;
+    let seconds = (day - JULIAN_DAY_OF_EPOCH) * SECONDS_PER_DAY;
+
+    seconds * units_per_second as i128 + nanoseconds / (NANOS_PER_SECOND / units_per_second) as i128
+}
+
+/// Converts a parquet `INT96` timestamp to an `i64` of `units_per_second` since the epoch,
+/// returning `None` when it does not fit.
+#[inline]
+fn int96_to_i64(value: [u32; 3], units_per_second: i64) -> Option<i64> {
+    int96_to_i128(value, units_per_second).try_into().ok()
+}
+
+/// Converts a parquet `INT96` timestamp to an `i64` of microseconds since the epoch,
+/// returning `None` when it does not fit (days beyond ~292 thousand years from the epoch).
+#[inline]
+pub fn int96_to_i64_us(value: [u32; 3]) -> Option<i64> {
+    int96_to_i64(value, 1_000_000)
+}
+
+/// Converts a parquet `INT96` timestamp to an `i64` of milliseconds since the epoch,
+/// returning `None` when it does not fit.
+#[inline]
+pub fn int96_to_i64_ms(value: [u32; 3]) -> Option<i64> {
+    int96_to_i64(value, 1_000)
+}
+
+/// Converts a parquet `INT96` timestamp to an `i64` of seconds since the epoch,
+/// returning `None` when it does not fit.
+#[inline]
+pub fn int96_to_i64_s(value: [u32; 3]) -> Option<i64> {
+    int96_to_i64(value, 1)
+}
+
+/// Returns the conversion of parquet's `INT96` timestamps to `time_unit`,
+/// which is `None` when the value does not fit in an `i64`
+pub(crate) fn int96_to_time_unit(time_unit: TimeUnit) -> fn([u32; 3]) -> Option<i64> {
+    match time_unit {
+        TimeUnit::Second => int96_to_i64_s,
+        TimeUnit::Millisecond => int96_to_i64_ms,
+        TimeUnit::Microsecond => int96_to_i64_us,
+        TimeUnit::Nanosecond => |value| int96_to_i64(value, 1_000_000_000),
+    }
+}
+
+/// Returns the exact conversion of parquet's `INT96` timestamps to `time_unit`
+pub(crate) fn int96_to_time_unit_i128(time_unit: TimeUnit) -> fn([u32; 3]) -> i128 {
+    match time_unit {
+        TimeUnit::Second => |value| int96_to_i128(value, 1),
+        TimeUnit::Millisecond => |value| int96_to_i128(value, 1_000),
+        TimeUnit::Microsecond => |value| int96_to_i128(value, 1_000_000),
+        TimeUnit::Nanosecond => |value| int96_to_i128(value, 1_000_000_000),
+    }
+}
 
 /// Trait describing a [`FallibleStreamingIterator`] of [`Page`]
 pub trait Pages:
@@ -0,0 +1,113 @@
+//! APIs to observe the progress of deserializing parquet pages.
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use parquet2::page::Page;
+use parquet2::FallibleStreamingIterator;
+
+use super::{Pages, ParquetError};
+
+/// The progress of deserializing a column chunk, reported once per page read by
+/// [`ProgressPages`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PageProgress {
+    /// The index of the column chunk among the column chunks of the field being deserialized
+    pub column: usize,
+    /// The index of the page in the column chunk, starting at 0
+    pub page: usize,
+    /// Whether the page is a dictionary page
+    pub is_dictionary: bool,
+    /// The number of values (including nulls) of the page
+    pub num_values: usize,
+    /// The number of (uncompressed) bytes of the page
+    pub num_bytes: usize,
+    /// The number of values of all data pages read so far from the column chunk
+    pub total_values: usize,
+    /// The number of (uncompressed) bytes of all pages read so far from the column chunk
+    pub total_bytes: usize,
+    /// The time it took to read and decompress the page
+    pub elapsed: Duration,
+    /// The time spent decoding the previous page, i.e. between the previous page being
+    /// available and this page being requested
+    pub decode_elapsed: Option<Duration>,
+}
+
+/// A callback invoked by [`ProgressPages`] after every page is read.
+pub type PageCallback = Arc<dyn Fn(&PageProgress) + Send + Sync>;
+
+/// A [`Pages`] adaptor that calls a [`PageCallback`] with the [`PageProgress`] of every page
+/// read from the inner [`Pages`].
+///
+/// # Implementation
+/// Timings are measured with [`Instant`] around every call to the inner
+/// [`FallibleStreamingIterator::advance`], and therefore include IO and decompression.
+pub struct ProgressPages<I: Pages> {
+    iter: I,
+    callback: PageCallback,
+    column: usize,
+    page: usize,
+    total_values: usize,
+    total_bytes: usize,
+    available: Option<Instant>,
+}
+
+impl<I: Pages> ProgressPages<I> {
+    /// Returns a new [`ProgressPages`] reporting the progress of the pages of `iter`, the
+    /// `column`th column chunk of a field, to `callback`.
+    pub fn new(iter: I, column: usize, callback: PageCallback) -> Self {
+        Self {
+            iter,
+            callback,
+            column,
+            page: 0,
+            total_values: 0,
+            total_bytes: 0,
+            available: None,
+        }
+    }
+
+    /// Returns the inner [`Pages`], consuming itself.
+    pub fn into_inner(self) -> I {
+        self.iter
+    }
+}
+
+impl<I: Pages> FallibleStreamingIterator for ProgressPages<I> {
+    type Item = Page;
+    type Error = ParquetError;
+
+    fn advance(&mut self) -> Result<(), ParquetError> {
+        let start = Instant::now();
+        let decode_elapsed = self.available.take().map(|available| start - available);
+        self.iter.advance()?;
+        let elapsed = start.elapsed();
+
+        let (is_dictionary, num_values, num_bytes) = match self.iter.get() {
+            Some(Page::Data(page)) => (false, page.num_values(), page.buffer().len()),
+            Some(Page::Dict(page)) => (true, page.num_values, page.buffer.len()),
+            None => return Ok(()),
+        };
+        if !is_dictionary {
+            self.total_values += num_values;
+        }
+        self.total_bytes += num_bytes;
+        (self.callback)(&PageProgress {
+            column: self.column,
+            page: self.page,
+            is_dictionary,
+            num_values,
+            num_bytes,
+            total_values: self.total_values,
+            total_bytes: self.total_bytes,
+            elapsed,
+            decode_elapsed,
+        });
+        self.page += 1;
+        self.available = Some(Instant::now());
+        Ok(())
+    }
+
+    fn get(&self) -> Option<&Self::Item> {
+        self.iter.get()
+    }
+}
@@ -1,4 +1,5 @@
 use std::io::{Read, Seek};
+use std::ops::Range;
 
 use futures::{
     future::{try_join_all, BoxFuture},
@@ -8,13 +9,20 @@ use parquet2::{
     indexes::FilteredPage,
     metadata::ColumnChunkMetaData,
     read::{BasicDecompressor, IndexedPageReader, PageMetaData, PageReader},
+    schema::types::PrimitiveType,
 };
 
 use crate::{
-    array::Array, chunk::Chunk, datatypes::Field, error::Result,
+    array::{new_empty_array, Array},
+    chunk::Chunk,
+    datatypes::{Field, Schema},
+    error::Result,
+    io::cache::{lock, CacheKey, SharedChunkCache},
     io::parquet::read::column_iter_to_arrays,
 };
 
+#[cfg(feature = "io_parquet_progress")]
+use super::progress::{PageCallback, ProgressPages};
 use super::ArrayIter;
 use super::RowGroupMetaData;
 
@@ -92,6 +100,20 @@ pub fn get_field_columns<'a>(
         .collect()
 }
 
+/// Returns the byte ranges of the column chunks of `row_group` associated to `fields`,
+/// e.g. to prefetch them from an object store via
+/// [`RangedAsyncReader::prefetch`](crate::io::util::RangedAsyncReader::prefetch).
+pub fn column_chunk_ranges(row_group: &RowGroupMetaData, fields: &[Field]) -> Vec<Range<u64>> {
+    fields
+        .iter()
+        .flat_map(|field| get_field_columns(row_group.columns(), &field.name))
+        .map(|column| {
+            let (start, length) = column.byte_range();
+            start..start + length
+        })
+        .collect()
+}
+
 /// Returns all [`ColumnChunkMetaData`] associated to `field_name`.
 /// For non-nested parquet types, this returns a single column
 pub fn get_field_pages<'a, T>(
@@ -180,27 +202,22 @@ pub async fn read_columns_async<
     try_join_all(futures).await
 }
 
-type Pages = Box<
+pub(super) type CompressedPages = Box<
     dyn Iterator<Item = std::result::Result<parquet2::page::CompressedPage, parquet2::error::Error>>
         + Sync
         + Send,
 >;
 
-/// Converts a vector of columns associated with the parquet field whose name is [`Field`]
-/// to an iterator of [`Array`], [`ArrayIter`] of chunk size `chunk_size`.
-pub fn to_deserializer<'a>(
+/// Returns the compressed pages of `columns`, filtered by `pages` when provided, and their
+/// parquet types.
+pub(super) fn compressed_pages(
     columns: Vec<(&ColumnChunkMetaData, Vec<u8>)>,
-    field: Field,
-    num_rows: usize,
-    chunk_size: Option<usize>,
     pages: Option<Vec<Vec<FilteredPage>>>,
-) -> Result<ArrayIter<'a>> {
-    let chunk_size = chunk_size.map(|c| c.min(num_rows));
-
-    let (columns, types) = if let Some(pages) = pages {
-        let (columns, types): (Vec<_>, Vec<_>) = columns
+) -> (Vec<CompressedPages>, Vec<&PrimitiveType>) {
+    if let Some(pages) = pages {
+        columns
             .into_iter()
-            .zip(pages.into_iter())
+            .zip(pages)
             .map(|((column_meta, chunk), mut pages)| {
                 // de-offset the start, since we read in chunks (and offset is from start of file)
                 let mut meta: PageMetaData = column_meta.into();
@@ -215,17 +232,14 @@ pub fn to_deserializer<'a>(
                     vec![],
                     vec![],
                 );
-                let pages = Box::new(pages) as Pages;
                 (
-                    BasicDecompressor::new(pages, vec![]),
+                    Box::new(pages) as CompressedPages,
                     &column_meta.descriptor().descriptor.primitive_type,
                 )
             })
-            .unzip();
-
-        (columns, types)
+            .unzip()
     } else {
-        let (columns, types): (Vec<_>, Vec<_>) = columns
+        columns
             .into_iter()
             .map(|(column_meta, chunk)| {
                 let len = chunk.len();
@@ -236,16 +250,67 @@ pub fn to_deserializer<'a>(
                     vec![],
                     len * 2 + 1024,
                 );
-                let pages = Box::new(pages) as Pages;
                 (
-                    BasicDecompressor::new(pages, vec![]),
+                    Box::new(pages) as CompressedPages,
                     &column_meta.descriptor().descriptor.primitive_type,
                 )
             })
-            .unzip();
+            .unzip()
+    }
+}
 
-        (columns, types)
-    };
+/// Returns the decompressed pages of `columns`, filtered by `pages` when provided, and their
+/// parquet types.
+fn to_pages(
+    columns: Vec<(&ColumnChunkMetaData, Vec<u8>)>,
+    pages: Option<Vec<Vec<FilteredPage>>>,
+) -> (Vec<BasicDecompressor<CompressedPages>>, Vec<&PrimitiveType>) {
+    let (columns, types) = compressed_pages(columns, pages);
+    let columns = columns
+        .into_iter()
+        .map(|pages| BasicDecompressor::new(pages, vec![]))
+        .collect();
+    (columns, types)
+}
+
+/// Converts a vector of columns associated with the parquet field whose name is [`Field`]
+/// to an iterator of [`Array`], [`ArrayIter`] of chunk size `chunk_size`.
+pub fn to_deserializer<'a>(
+    columns: Vec<(&ColumnChunkMetaData, Vec<u8>)>,
+    field: Field,
+    num_rows: usize,
+    chunk_size: Option<usize>,
+    pages: Option<Vec<Vec<FilteredPage>>>,
+) -> Result<ArrayIter<'a>> {
+    let chunk_size = chunk_size.map(|c| c.min(num_rows));
+
+    let (columns, types) = to_pages(columns, pages);
+
+    column_iter_to_arrays(columns, types, field, chunk_size, num_rows)
+}
+
+/// Like [`to_deserializer`], but calls `callback` with the [`PageProgress`](super::PageProgress) of every page
+/// read while the returned [`ArrayIter`] is advanced, e.g. to drive a progress bar.
+///
+/// [`PageProgress::column`](super::PageProgress::column) is the index of the column chunk in `columns`.
+#[cfg(feature = "io_parquet_progress")]
+#[cfg_attr(docsrs, doc(cfg(feature = "io_parquet_progress")))]
+pub fn to_deserializer_with_progress<'a>(
+    columns: Vec<(&ColumnChunkMetaData, Vec<u8>)>,
+    field: Field,
+    num_rows: usize,
+    chunk_size: Option<usize>,
+    pages: Option<Vec<Vec<FilteredPage>>>,
+    callback: PageCallback,
+) -> Result<ArrayIter<'a>> {
+    let chunk_size = chunk_size.map(|c| c.min(num_rows));
+
+    let (columns, types) = to_pages(columns, pages);
+    let columns = columns
+        .into_iter()
+        .enumerate()
+        .map(|(column, pages)| ProgressPages::new(pages, column, callback.clone()))
+        .collect();
 
     column_iter_to_arrays(columns, types, field, chunk_size, num_rows)
 }
@@ -342,3 +407,56 @@ pub async fn read_columns_many_async<
             .collect()
     }
 }
+
+/// Reads the row group `index` of `row_groups` into a single [`Chunk`], using `cache`
+/// to skip reading and decoding row groups that were already decoded.
+///
+/// Row groups are identified in the cache by `source` (e.g. the path of the file),
+/// `index` and `projection`. `projection` are the indices of the fields of `schema` to read;
+/// when `None`, all fields are read.
+///
+/// # Implementation
+/// The cache is not locked while the row group is read, so that concurrent readers
+/// are not blocked by IO. As a consequence, two readers may decode the same row group.
+/// # Panics
+/// This function panics iff `index >= row_groups.len()`
+pub fn read_row_group_cached<R: Read + Seek>(
+    cache: &SharedChunkCache,
+    source: &str,
+    reader: &mut R,
+    row_groups: &[RowGroupMetaData],
+    index: usize,
+    schema: &Schema,
+    projection: Option<&[usize]>,
+) -> Result<Chunk<Box<dyn Array>>> {
+    let key = CacheKey::new(source, index, projection.map(|x| x.to_vec()));
+    if let Some(chunk) = lock(cache).get(&key) {
+        return Ok(chunk);
+    }
+
+    let row_group = &row_groups[index];
+    let fields = match projection {
+        Some(projection) => projection
+            .iter()
+            .map(|i| schema.fields[*i].clone())
+            .collect::<Vec<_>>(),
+        None => schema.fields.clone(),
+    };
+
+    let chunk = if row_group.num_rows() == 0 {
+        Chunk::new(
+            fields
+                .into_iter()
+                .map(|field| new_empty_array(field.data_type))
+                .collect(),
+        )
+    } else {
+        let columns = read_columns_many(reader, row_group, fields, None, None, None)?;
+        RowGroupDeserializer::new(columns, row_group.num_rows(), None)
+            .next()
+            .unwrap()?
+    };
+
+    lock(cache).insert(key, chunk.clone());
+    Ok(chunk)
+}
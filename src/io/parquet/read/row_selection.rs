@@ -0,0 +1,480 @@
+use std::collections::VecDeque;
+use std::io::{Read, Seek};
+
+use parquet2::indexes::{select_pages, PageLocation};
+use parquet2::read::decompress;
+
+use crate::{
+    array::{growable::make_growable, Array},
+    datatypes::{DataType, Field, PhysicalType},
+    error::{Error, Result},
+};
+
+use super::indexes::{compute_page_row_intervals, Interval};
+use super::row_group::{compressed_pages, CompressedPages};
+use super::{
+    column_iter_to_arrays, get_field_pages, read_columns, read_pages_locations, to_deserializer,
+    ArrayIter, FallibleStreamingIterator, Page, ParquetError, RowGroupMetaData,
+};
+
+/// A run of consecutive rows of a row group that are either selected or skipped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RowSelector {
+    /// The number of rows of this run
+    pub row_count: usize,
+    /// Whether the rows of this run are skipped
+    pub skip: bool,
+}
+
+impl RowSelector {
+    /// Returns a [`RowSelector`] that selects `row_count` rows
+    pub fn select(row_count: usize) -> Self {
+        Self {
+            row_count,
+            skip: false,
+        }
+    }
+
+    /// Returns a [`RowSelector`] that skips `row_count` rows
+    pub fn skip(row_count: usize) -> Self {
+        Self {
+            row_count,
+            skip: true,
+        }
+    }
+}
+
+/// The set of rows of a row group to deserialize, declared as alternating runs of
+/// skipped and selected rows (e.g. computed from the page index or from an external index).
+///
+/// Rows after the last [`RowSelector`] are skipped.
+/// # Example
+/// ```
+/// use arrow2::io::parquet::read::indexes::Interval;
+/// use arrow2::io::parquet::read::{RowSelection, RowSelector};
+///
+/// let selection = RowSelection::from(vec![
+///     RowSelector::skip(2),
+///     RowSelector::select(3),
+///     RowSelector::select(1),
+///     RowSelector::skip(4),
+/// ]);
+/// assert_eq!(selection.selected_rows(), 4);
+/// assert_eq!(selection.to_intervals(), vec![Interval::new(2, 4)]);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RowSelection {
+    selectors: Vec<RowSelector>,
+}
+
+impl RowSelection {
+    /// Creates a new [`RowSelection`] from its runs.
+    /// Consecutive runs of the same kind are merged and empty runs are dropped.
+    pub fn new(selectors: Vec<RowSelector>) -> Self {
+        let mut merged: Vec<RowSelector> = Vec::with_capacity(selectors.len());
+        for selector in selectors.into_iter().filter(|x| x.row_count > 0) {
+            match merged.last_mut() {
+                Some(last) if last.skip == selector.skip => last.row_count += selector.row_count,
+                _ => merged.push(selector),
+            }
+        }
+        Self { selectors: merged }
+    }
+
+    /// Creates a new [`RowSelection`] that selects the rows in `intervals` of a row group with
+    /// `num_rows` rows.
+    /// # Errors
+    /// This function errors iff the intervals are not sorted, overlap, or are out of bounds.
+    pub fn from_intervals(intervals: &[Interval], num_rows: usize) -> Result<Self> {
+        let mut selectors = Vec::with_capacity(intervals.len() * 2 + 1);
+        let mut position = 0;
+        for interval in intervals {
+            if interval.start < position {
+                return Err(Error::InvalidArgumentError(
+                    "The intervals of a row selection must be sorted and must not overlap"
+                        .to_string(),
+                ));
+            }
+            selectors.push(RowSelector::skip(interval.start - position));
+            selectors.push(RowSelector::select(interval.length));
+            position = interval
+                .start
+                .checked_add(interval.length)
+                .ok_or(Error::Overflow)?;
+        }
+        if position > num_rows {
+            return Err(Error::InvalidArgumentError(format!(
+                "The row selection ends at row {position} but the row group only has {num_rows} rows"
+            )));
+        }
+        selectors.push(RowSelector::skip(num_rows - position));
+        Ok(Self::new(selectors))
+    }
+
+    /// Returns the runs of this [`RowSelection`]
+    pub fn selectors(&self) -> &[RowSelector] {
+        &self.selectors
+    }
+
+    /// Returns the number of rows selected by this [`RowSelection`]
+    pub fn selected_rows(&self) -> usize {
+        self.selectors
+            .iter()
+            .filter(|x| !x.skip)
+            .map(|x| x.row_count)
+            .sum()
+    }
+
+    /// Returns the (row) intervals selected by this [`RowSelection`]
+    pub fn to_intervals(&self) -> Vec<Interval> {
+        let mut position = 0;
+        self.selectors
+            .iter()
+            .filter_map(|selector| {
+                let start = position;
+                position += selector.row_count;
+                (!selector.skip).then(|| Interval::new(start, selector.row_count))
+            })
+            .collect()
+    }
+}
+
+impl From<Vec<RowSelector>> for RowSelection {
+    fn from(selectors: Vec<RowSelector>) -> Self {
+        Self::new(selectors)
+    }
+}
+
+/// Consumes a [`RowSelection`] as consecutive chunks of rows are deserialized.
+#[derive(Debug)]
+struct SelectionState {
+    selectors: VecDeque<RowSelector>,
+}
+
+impl SelectionState {
+    fn new(selection: RowSelection) -> Self {
+        Self {
+            selectors: selection.selectors.into(),
+        }
+    }
+
+    /// Consumes the next `length` rows of the selection and returns the intervals of those
+    /// that are selected, relative to the first consumed row.
+    fn next_intervals(&mut self, length: usize) -> Vec<Interval> {
+        let mut intervals = vec![];
+        let mut position = 0;
+        while position < length {
+            let selector = match self.selectors.front_mut() {
+                Some(selector) => selector,
+                None => break,
+            };
+            let row_count = selector.row_count.min(length - position);
+            if !selector.skip {
+                intervals.push(Interval::new(position, row_count));
+            }
+            selector.row_count -= row_count;
+            if selector.row_count == 0 {
+                self.selectors.pop_front();
+            }
+            position += row_count;
+        }
+        intervals
+    }
+}
+
+/// Returns the rows of `array` in `intervals`.
+fn select(array: Box<dyn Array>, intervals: &[Interval]) -> Box<dyn Array> {
+    match intervals {
+        [interval] if interval.start == 0 && interval.length == array.len() => array,
+        [interval] => array.slice(interval.start, interval.length),
+        intervals => {
+            let length = intervals.iter().map(|x| x.length).sum();
+            let mut growable = make_growable(&[array.as_ref()], false, length);
+            intervals
+                .iter()
+                .for_each(|x| growable.extend(0, x.start, x.length));
+            growable.as_box()
+        }
+    }
+}
+
+/// An [`Iterator`] of arrays that only returns the rows selected by a [`RowSelection`]
+/// of the rows returned by an [`ArrayIter`], in chunks of `chunk_size` rows.
+struct SelectedArrayIter<'a> {
+    iter: ArrayIter<'a>,
+    selection: SelectionState,
+    chunk_size: usize,
+    // selected rows not yet returned
+    buffer: Vec<Box<dyn Array>>,
+    buffered: usize,
+}
+
+impl<'a> SelectedArrayIter<'a> {
+    fn new(iter: ArrayIter<'a>, selection: RowSelection, chunk_size: Option<usize>) -> Self {
+        Self {
+            iter,
+            selection: SelectionState::new(selection),
+            chunk_size: chunk_size.unwrap_or(usize::MAX).max(1),
+            buffer: vec![],
+            buffered: 0,
+        }
+    }
+}
+
+impl<'a> Iterator for SelectedArrayIter<'a> {
+    type Item = Result<Box<dyn Array>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.buffered < self.chunk_size {
+            let array = match self.iter.next() {
+                Some(Ok(array)) => array,
+                Some(Err(e)) => return Some(Err(e)),
+                None => break,
+            };
+            let intervals = self.selection.next_intervals(array.len());
+            if !intervals.is_empty() {
+                let array = select(array, &intervals);
+                self.buffered += array.len();
+                self.buffer.push(array);
+            }
+        }
+        if self.buffer.is_empty() {
+            return None;
+        }
+
+        let length = self.buffered.min(self.chunk_size);
+        self.buffered -= length;
+        if self.buffer.len() == 1 && self.buffer[0].len() == length {
+            return self.buffer.pop().map(Ok);
+        }
+
+        // concatenate the first `length` buffered rows and keep the remaining ones
+        let buffer = std::mem::take(&mut self.buffer);
+        let arrays = buffer.iter().map(|x| x.as_ref()).collect::<Vec<_>>();
+        let mut growable = make_growable(&arrays, false, length);
+        let mut remaining = length;
+        for (index, array) in arrays.iter().enumerate() {
+            let taken = array.len().min(remaining);
+            growable.extend(index, 0, taken);
+            remaining -= taken;
+            if remaining == 0 {
+                if taken < array.len() {
+                    self.buffer.push(array.slice(taken, array.len() - taken));
+                }
+                self.buffer.extend(buffer[index + 1..].iter().cloned());
+                break;
+            }
+        }
+        Some(Ok(growable.as_box()))
+    }
+}
+
+/// A [`FallibleStreamingIterator`] of decompressed pages that ignores the rows selected
+/// within each page, so that the pages are decoded whole.
+struct WholePages {
+    iter: CompressedPages,
+    buffer: Vec<u8>,
+    current: Option<Page>,
+}
+
+impl WholePages {
+    fn new(iter: CompressedPages) -> Self {
+        Self {
+            iter,
+            buffer: vec![],
+            current: None,
+        }
+    }
+}
+
+impl FallibleStreamingIterator for WholePages {
+    type Item = Page;
+    type Error = ParquetError;
+
+    fn advance(&mut self) -> std::result::Result<(), ParquetError> {
+        // re-use the buffer of the previous page
+        match self.current.take() {
+            Some(Page::Data(mut page)) => self.buffer = std::mem::take(page.buffer_mut()),
+            Some(Page::Dict(page)) => self.buffer = page.buffer,
+            None => {}
+        }
+        self.current = self
+            .iter
+            .next()
+            .transpose()?
+            .map(|page| decompress(page, &mut self.buffer))
+            .transpose()?
+            .map(|mut page| {
+                if let Page::Data(page) = &mut page {
+                    page.selected_rows = None;
+                }
+                page
+            });
+        Ok(())
+    }
+
+    fn get(&self) -> Option<&Self::Item> {
+        self.current.as_ref()
+    }
+}
+
+/// Whether the deserializer of `data_type` can skip the rows within a page.
+fn skips_rows_within_pages(data_type: &DataType) -> bool {
+    use PhysicalType::*;
+    !matches!(
+        data_type.to_physical_type(),
+        List | LargeList | FixedSizeList | Struct | Map | Union
+    )
+}
+
+/// Returns the smallest set of rows that contains `rows` and is a union of pages of every
+/// column in `pages` (the row intervals of the pages of each column).
+fn align_to_pages(rows: &[Interval], pages: &[Vec<Interval>]) -> Vec<Interval> {
+    let mut rows = rows.to_vec();
+    loop {
+        let mut changed = false;
+        for pages in pages {
+            let mut aligned: Vec<Interval> = vec![];
+            let mut candidates = rows.iter().peekable();
+            for page in pages {
+                let page_end = page.start + page.length;
+                // skip the rows before this page
+                while matches!(candidates.peek(), Some(x) if x.start + x.length <= page.start) {
+                    candidates.next();
+                }
+                let intersects = matches!(candidates.peek(), Some(x) if x.start < page_end);
+                if intersects {
+                    match aligned.last_mut() {
+                        Some(last) if last.start + last.length == page.start => {
+                            last.length += page.length
+                        }
+                        _ => aligned.push(*page),
+                    }
+                }
+            }
+            changed |= aligned != rows;
+            rows = aligned;
+        }
+        if !changed {
+            return rows;
+        }
+    }
+}
+
+/// Returns the [`RowSelection`] of `selected` relative to the rows of `rows`, as if the rows
+/// of `rows` were consecutive. `rows` must contain `selected`.
+fn relative_selection(selected: &[Interval], rows: &[Interval]) -> RowSelection {
+    let mut selectors = vec![];
+    let mut offset = 0;
+    let mut position = 0;
+    let mut selected = selected.iter().peekable();
+    for interval in rows {
+        let end = interval.start + interval.length;
+        while let Some(x) = selected.peek() {
+            if x.start >= end {
+                break;
+            }
+            let start = offset + x.start - interval.start;
+            selectors.push(RowSelector::skip(start - position));
+            selectors.push(RowSelector::select(x.length));
+            position = start + x.length;
+            selected.next();
+        }
+        offset += interval.length;
+    }
+    RowSelection::new(selectors)
+}
+
+/// Returns a vector of iterators of [`Array`] ([`ArrayIter`]) corresponding to the top
+/// level parquet fields whose name matches `fields`'s names, that only return the rows of
+/// `row_group` selected by `selection`.
+///
+/// `limit` applies to the selected rows. The iterators return [`RowSelection::selected_rows`]
+/// rows in total (or `limit`, if smaller), and can be combined into chunks via
+/// [`RowGroupDeserializer::new`](super::RowGroupDeserializer::new).
+///
+/// # Implementation
+/// When the row group has a page index, pages without selected rows are neither
+/// decompressed nor decoded. Non-nested fields skip unselected rows within pages while
+/// decoding; nested fields decode the pages that contain selected rows and drop the
+/// unselected rows afterwards. Without a page index, all pages are decoded.
+///
+/// Like [`read_columns_many`](super::read_columns_many), this operation is IO-bounded `O(C)`
+/// where C is the number of columns in the row group.
+/// # Errors
+/// This function errors iff `selection` has more rows than `row_group`, or the columns
+/// cannot be read or deserialized.
+pub fn read_columns_many_with_selection<'a, R: Read + Seek>(
+    reader: &mut R,
+    row_group: &RowGroupMetaData,
+    fields: Vec<Field>,
+    chunk_size: Option<usize>,
+    limit: Option<usize>,
+    selection: &RowSelection,
+) -> Result<Vec<ArrayIter<'a>>> {
+    let num_rows = row_group.num_rows();
+    let mut selected = selection.to_intervals();
+    if let Some(last) = selected.last() {
+        if last.start + last.length > num_rows {
+            return Err(Error::InvalidArgumentError(format!(
+                "The row selection has more rows than the row group ({num_rows})"
+            )));
+        }
+    }
+    let mut remaining = limit.unwrap_or(usize::MAX);
+    selected.retain_mut(|interval| {
+        interval.length = interval.length.min(remaining);
+        remaining -= interval.length;
+        interval.length > 0
+    });
+    let selected_rows = selected.iter().map(|x| x.length).sum();
+
+    // one vec per column; empty if the row group has no page index
+    let locations = read_pages_locations(reader, row_group.columns())?;
+
+    fields
+        .into_iter()
+        .map(|field| {
+            let columns = read_columns(reader, row_group.columns(), &field.name)?;
+            if locations.is_empty() {
+                let iter = to_deserializer(columns, field, num_rows, chunk_size, None)?;
+                let selection = relative_selection(&selected, &[Interval::new(0, num_rows)]);
+                return Ok(Box::new(SelectedArrayIter::new(iter, selection, chunk_size)) as _);
+            }
+            let locations: Vec<&Vec<PageLocation>> =
+                get_field_pages(row_group.columns(), &locations, &field.name);
+
+            if skips_rows_within_pages(&field.data_type) {
+                let pages = locations
+                    .iter()
+                    .map(|locations| select_pages(&selected, locations, num_rows))
+                    .collect::<std::result::Result<Vec<_>, ParquetError>>()?;
+                return to_deserializer(columns, field, selected_rows, chunk_size, Some(pages));
+            }
+
+            // the columns of a nested field decode the same pages in full
+            let page_rows = locations
+                .iter()
+                .map(|locations| compute_page_row_intervals(locations, num_rows))
+                .collect::<std::result::Result<Vec<_>, ParquetError>>()?;
+            let rows = align_to_pages(&selected, &page_rows);
+            let pages = locations
+                .iter()
+                .map(|locations| select_pages(&rows, locations, num_rows))
+                .collect::<std::result::Result<Vec<_>, ParquetError>>()?;
+            let length = rows.iter().map(|x| x.length).sum::<usize>();
+
+            let (columns, types) = compressed_pages(columns, Some(pages));
+            let columns = columns.into_iter().map(WholePages::new).collect();
+            let iter = column_iter_to_arrays(
+                columns,
+                types,
+                field,
+                chunk_size.map(|c| c.min(length)),
+                length,
+            )?;
+            let selection = relative_selection(&selected, &rows);
+            Ok(Box::new(SelectedArrayIter::new(iter, selection, chunk_size)) as _)
+        })
+        .collect()
+}
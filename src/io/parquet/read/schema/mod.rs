@@ -1,11 +1,11 @@
 //! APIs to handle Parquet <-> Arrow schemas.
-use crate::datatypes::Schema;
+use crate::datatypes::{DataType, Schema, TimeUnit};
 use crate::error::Result;
 
 mod convert;
 mod metadata;
 
-pub use convert::parquet_to_arrow_schema;
+pub use convert::{parquet_to_arrow_schema, parquet_to_arrow_schema_with_options};
 pub use metadata::read_schema_from_metadata;
 pub use parquet2::metadata::{FileMetaData, KeyValue, SchemaDescriptor};
 pub use parquet2::schema::types::ParquetType;
@@ -14,18 +14,100 @@ pub(crate) use convert::*;
 
 use self::metadata::parse_key_value_metadata;
 
+/// Options when inferring schemas from Parquet
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SchemaInferenceOptions {
+    /// When inferring schemas from the Parquet `INT96` timestamp type, this is the corresponding
+    /// [`TimeUnit`] in the inferred Arrow [`DataType::Timestamp`].
+    ///
+    /// Legacy writers (e.g. Spark) use `INT96` for timestamps whose range exceeds the range of
+    /// nanoseconds in an `i64` (years 1677 to 2262); coarser units read them without overflow.
+    /// Values outside the range of the unit are read according to
+    /// [`Int96Overflow`](crate::io::parquet::read::Int96Overflow).
+    pub int96_coerce_to_timeunit: TimeUnit,
+}
+
+impl Default for SchemaInferenceOptions {
+    fn default() -> Self {
+        SchemaInferenceOptions {
+            int96_coerce_to_timeunit: TimeUnit::Nanosecond,
+        }
+    }
+}
+
 /// Infers a [`Schema`] from parquet's [`FileMetaData`]. This first looks for the metadata key
 /// `"ARROW:schema"`; if it does not exist, it converts the parquet types declared in the
 /// file's parquet schema to Arrow's equivalent.
+///
+/// The schema in `"ARROW:schema"` is preferred since it preserves the logical types that
+/// parquet can't represent, such as [`DataType::Duration`], [`DataType::Dictionary`],
+/// [`DataType::FixedSizeList`] and the timezone of [`DataType::Timestamp`]. Fields of that schema
+/// that do not match the file's parquet schema (e.g. because the file was rewritten by another
+/// writer) are instead converted from the parquet types.
 /// # Error
 /// This function errors iff the key `"ARROW:schema"` exists but is not correctly encoded,
 /// indicating that that the file's arrow metadata was incorrectly written.
 pub fn infer_schema(file_metadata: &FileMetaData) -> Result<Schema> {
+    infer_schema_with_options(file_metadata, &None)
+}
+
+/// Like [`infer_schema`] but with configurable options which affect the behavior of inference
+/// of the parquet types (see [`SchemaInferenceOptions`]).
+/// # Error
+/// This function errors iff the key `"ARROW:schema"` exists but is not correctly encoded.
+pub fn infer_schema_with_options(
+    file_metadata: &FileMetaData,
+    options: &Option<SchemaInferenceOptions>,
+) -> Result<Schema> {
     let mut metadata = parse_key_value_metadata(file_metadata.key_value_metadata());
+    let parquet_fields = file_metadata.schema().fields();
 
     let schema = read_schema_from_metadata(&mut metadata)?;
-    Ok(schema.unwrap_or_else(|| {
-        let fields = parquet_to_arrow_schema(file_metadata.schema().fields());
-        Schema { fields, metadata }
-    }))
+    Ok(match schema {
+        Some(schema) if schema.fields.len() == parquet_fields.len() => {
+            let fields = schema
+                .fields
+                .into_iter()
+                .zip(parquet_fields)
+                .map(|(field, type_)| {
+                    if field.name == type_.name()
+                        && n_leaves(&field.data_type) == n_parquet_leaves(type_)
+                    {
+                        field
+                    } else {
+                        to_field(type_, &options.unwrap_or_default()).unwrap_or(field)
+                    }
+                })
+                .collect();
+            Schema {
+                fields,
+                metadata: schema.metadata,
+            }
+        }
+        _ => Schema {
+            fields: parquet_to_arrow_schema_with_options(parquet_fields, options),
+            metadata,
+        },
+    })
+}
+
+/// Returns the number of parquet columns of a field of `data_type`
+fn n_leaves(data_type: &DataType) -> usize {
+    match data_type.to_logical_type() {
+        DataType::List(inner)
+        | DataType::LargeList(inner)
+        | DataType::FixedSizeList(inner, _)
+        | DataType::Map(inner, _) => n_leaves(&inner.data_type),
+        DataType::Struct(fields) | DataType::Union(fields, _, _) => {
+            fields.iter().map(|x| n_leaves(&x.data_type)).sum()
+        }
+        _ => 1,
+    }
+}
+
+fn n_parquet_leaves(type_: &ParquetType) -> usize {
+    match type_ {
+        ParquetType::PrimitiveType(_) => 1,
+        ParquetType::GroupType { fields, .. } => fields.iter().map(n_parquet_leaves).sum(),
+    }
 }
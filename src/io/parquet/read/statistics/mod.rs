@@ -10,7 +10,6 @@ use parquet2::statistics::{
     BinaryStatistics, BooleanStatistics, FixedLenStatistics, PrimitiveStatistics,
     Statistics as ParquetStatistics,
 };
-use parquet2::types::int96_to_i64_ns;
 
 use crate::array::*;
 use crate::datatypes::IntervalUnit;
@@ -29,6 +28,7 @@ mod struct_;
 mod utf8;
 
 use self::list::DynMutableListArray;
+use super::int96_to_time_unit;
 
 use super::get_field_columns;
 
@@ -45,6 +45,169 @@ pub struct Statistics {
     pub max_value: Box<dyn Array>,
 }
 
+impl Statistics {
+    /// Returns the [`Statistics`] of each leaf (parquet column) of this field, in the
+    /// order of the parquet columns. For non-nested fields this is the statistics itself.
+    ///
+    /// The minimum and maximum of a leaf of a list or map are its values,
+    /// with one entry per row group.
+    pub fn leaves(&self) -> Vec<Statistics> {
+        let mut leaves = vec![];
+        push_leaves(
+            self.null_count.as_ref(),
+            self.distinct_count.as_ref(),
+            self.min_value.as_ref(),
+            self.max_value.as_ref(),
+            &mut leaves,
+        );
+        leaves
+    }
+
+    /// Returns these statistics with the minimum and maximum values downcasted to `A`.
+    /// # Errors
+    /// This function errors if the statistics are from a nested field (see [`Statistics::leaves`])
+    /// or if the minimum and maximum values are not of type `A`.
+    pub fn to_typed<A: Array + Clone>(&self) -> Result<TypedStatistics<A>> {
+        let as_counts = |array: &dyn Array| {
+            array
+                .as_any()
+                .downcast_ref::<UInt64Array>()
+                .cloned()
+                .ok_or_else(|| {
+                    Error::InvalidArgumentError(
+                        "Typed statistics are only available for non-nested fields".to_string(),
+                    )
+                })
+        };
+        let as_values = |array: &dyn Array| {
+            array.as_any().downcast_ref::<A>().cloned().ok_or_else(|| {
+                Error::InvalidArgumentError(format!(
+                    "The statistics of type {:?} are not of type {}",
+                    array.data_type(),
+                    std::any::type_name::<A>()
+                ))
+            })
+        };
+        Ok(TypedStatistics {
+            null_count: as_counts(self.null_count.as_ref())?,
+            distinct_count: as_counts(self.distinct_count.as_ref())?,
+            min_value: as_values(self.min_value.as_ref())?,
+            max_value: as_values(self.max_value.as_ref())?,
+        })
+    }
+}
+
+fn push_leaves(
+    null_count: &dyn Array,
+    distinct_count: &dyn Array,
+    min_value: &dyn Array,
+    max_value: &dyn Array,
+    leaves: &mut Vec<Statistics>,
+) {
+    match min_value.data_type().to_physical_type() {
+        PhysicalType::List => {
+            let values = |array: &dyn Array| {
+                array
+                    .as_any()
+                    .downcast_ref::<ListArray<i32>>()
+                    .unwrap()
+                    .values()
+                    .clone()
+            };
+            push_leaves(
+                values(null_count).as_ref(),
+                values(distinct_count).as_ref(),
+                values(min_value).as_ref(),
+                values(max_value).as_ref(),
+                leaves,
+            )
+        }
+        PhysicalType::LargeList => {
+            let values = |array: &dyn Array| {
+                array
+                    .as_any()
+                    .downcast_ref::<ListArray<i64>>()
+                    .unwrap()
+                    .values()
+                    .clone()
+            };
+            push_leaves(
+                values(null_count).as_ref(),
+                values(distinct_count).as_ref(),
+                values(min_value).as_ref(),
+                values(max_value).as_ref(),
+                leaves,
+            )
+        }
+        PhysicalType::Map => {
+            let field = |array: &dyn Array| {
+                array
+                    .as_any()
+                    .downcast_ref::<MapArray>()
+                    .unwrap()
+                    .field()
+                    .clone()
+            };
+            push_leaves(
+                field(null_count).as_ref(),
+                field(distinct_count).as_ref(),
+                field(min_value).as_ref(),
+                field(max_value).as_ref(),
+                leaves,
+            )
+        }
+        PhysicalType::Struct => {
+            let values = |array: &dyn Array| {
+                array
+                    .as_any()
+                    .downcast_ref::<StructArray>()
+                    .unwrap()
+                    .values()
+                    .to_vec()
+            };
+            let null_count = values(null_count);
+            let distinct_count = values(distinct_count);
+            let min_value = values(min_value);
+            let max_value = values(max_value);
+            null_count
+                .iter()
+                .zip(distinct_count.iter())
+                .zip(min_value.iter())
+                .zip(max_value.iter())
+                .for_each(|(((null_count, distinct_count), min_value), max_value)| {
+                    push_leaves(
+                        null_count.as_ref(),
+                        distinct_count.as_ref(),
+                        min_value.as_ref(),
+                        max_value.as_ref(),
+                        leaves,
+                    )
+                })
+        }
+        _ => leaves.push(Statistics {
+            null_count: null_count.to_boxed(),
+            distinct_count: distinct_count.to_boxed(),
+            min_value: min_value.to_boxed(),
+            max_value: max_value.to_boxed(),
+        }),
+    }
+}
+
+/// Arrow-deserialized parquet statistics of a non-nested field whose minimum and
+/// maximum values are of type `A` (e.g. [`Int32Array`] or [`Utf8Array<i32>`]).
+/// Each array has one entry per row group.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TypedStatistics<A: Array> {
+    /// number of nulls
+    pub null_count: UInt64Array,
+    /// number of dictinct values
+    pub distinct_count: UInt64Array,
+    /// Minimum
+    pub min_value: A,
+    /// Maximum
+    pub max_value: A,
+}
+
 /// Arrow-deserialized parquet Statistics of a file
 #[derive(Debug)]
 struct MutableStatistics {
@@ -200,6 +363,32 @@ fn make_mutable(data_type: &DataType, capacity: usize) -> Result<Box<dyn Mutable
     })
 }
 
+/// Returns the [`DataType`] of the min and max values of a field of `data_type`: the same,
+/// except that fixed size lists are lists, since they have a value per row group.
+fn create_min_max_dt(data_type: &DataType) -> DataType {
+    let with_data_type = |f: &Field| {
+        Box::new(Field {
+            data_type: create_min_max_dt(&f.data_type),
+            ..f.clone()
+        })
+    };
+    let new = match data_type.to_logical_type() {
+        DataType::FixedSizeList(f, _) | DataType::List(f) => DataType::List(with_data_type(f)),
+        DataType::LargeList(f) => DataType::LargeList(with_data_type(f)),
+        DataType::Map(f, ordered) => DataType::Map(with_data_type(f), *ordered),
+        DataType::Struct(fields) => {
+            DataType::Struct(fields.iter().map(|f| *with_data_type(f)).collect())
+        }
+        _ => return data_type.clone(),
+    };
+    // keep extension types unless they contain fixed size lists
+    if &new == data_type.to_logical_type() {
+        data_type.clone()
+    } else {
+        new
+    }
+}
+
 fn create_dt(data_type: &DataType) -> DataType {
     if let DataType::Struct(fields) = data_type.to_logical_type() {
         DataType::Struct(
@@ -225,6 +414,12 @@ fn create_dt(data_type: &DataType) -> DataType {
             create_dt(&f.data_type),
             f.is_nullable,
         )))
+    } else if let DataType::FixedSizeList(f, _) = data_type.to_logical_type() {
+        DataType::List(Box::new(Field::new(
+            &f.name,
+            create_dt(&f.data_type),
+            f.is_nullable,
+        )))
     } else {
         DataType::UInt64
     }
@@ -232,8 +427,8 @@ fn create_dt(data_type: &DataType) -> DataType {
 
 impl MutableStatistics {
     fn try_new(field: &Field) -> Result<Self> {
-        let min_value = make_mutable(&field.data_type, 0)?;
-        let max_value = make_mutable(&field.data_type, 0)?;
+        let min_value = make_mutable(&create_min_max_dt(&field.data_type), 0)?;
+        let max_value = make_mutable(&create_min_max_dt(&field.data_type), 0)?;
 
         let dt = create_dt(&field.data_type);
         Ok(Self {
@@ -461,6 +656,7 @@ fn push(
         Timestamp(time_unit, _) => {
             let time_unit = *time_unit;
             if physical_type == &ParquetPhysicalType::Int96 {
+                let to_time_unit = int96_to_time_unit(time_unit);
                 let from = from.map(|from| {
                     let from = from
                         .as_any()
@@ -470,21 +666,15 @@ fn push(
                         primitive_type: from.primitive_type.clone(),
                         null_count: from.null_count,
                         distinct_count: from.distinct_count,
-                        min_value: from.min_value.map(int96_to_i64_ns),
-                        max_value: from.max_value.map(int96_to_i64_ns),
+                        min_value: from.min_value.and_then(to_time_unit),
+                        max_value: from.max_value.and_then(to_time_unit),
                     }
                 });
-                primitive::push(
+                primitive::push::<i64, i64, _>(
                     from.as_ref().map(|x| x as &dyn ParquetStatistics),
                     min,
                     max,
-                    |x: i64| {
-                        Ok(primitive::timestamp(
-                            type_.logical_type.as_ref(),
-                            time_unit,
-                            x,
-                        ))
-                    },
+                    Ok,
                 )
             } else {
                 primitive::push(from, min, max, |x: i64| {
@@ -0,0 +1,170 @@
+//! APIs to summarize a parquet file from its metadata only.
+use crate::array::{Array, UInt64Array};
+use crate::chunk::Chunk;
+use crate::datatypes::{DataType, Field, Schema};
+use crate::error::Result;
+
+use super::statistics::{deserialize, Statistics};
+use super::{infer_schema, FileMetaData, RowGroupMetaData};
+
+/// A summary of a parquet file derived from its metadata (see [`read_metadata`](super::read_metadata)),
+/// i.e. without reading any data page.
+///
+/// Every array of this summary has one entry per row group, so that it can be used to e.g.
+/// prune row groups or build an index of files in a data catalog.
+/// # Example
+/// ```
+/// use std::io::Cursor;
+///
+/// use arrow2::array::{Array, Int32Array, UInt64Array};
+/// use arrow2::chunk::Chunk;
+/// use arrow2::datatypes::{DataType, Field, Schema};
+/// use arrow2::io::parquet::read::{read_metadata, ParquetFileSummary};
+/// use arrow2::io::parquet::write::{
+///     transverse, CompressionOptions, Encoding, FileWriter, RowGroupIterator, Version,
+///     WriteOptions,
+/// };
+///
+/// # fn main() -> arrow2::error::Result<()> {
+/// let schema = Schema::from(vec![Field::new("c1", DataType::Int32, true)]);
+/// let chunks = vec![
+///     Ok(Chunk::new(vec![Int32Array::from([Some(3), None]).boxed()])),
+///     Ok(Chunk::new(vec![Int32Array::from_slice([5, 4, 7]).boxed()])),
+/// ];
+/// let options = WriteOptions {
+///     write_statistics: true,
+///     compression: CompressionOptions::Uncompressed,
+///     version: Version::V2,
+///     data_pagesize_limit: None,
+///     int96_timestamps: false,
+/// };
+/// let encodings = vec![transverse(&DataType::Int32, |_| Encoding::Plain)];
+/// let row_groups = RowGroupIterator::try_new(chunks.into_iter(), &schema, options, encodings)?;
+/// let mut writer = FileWriter::try_new(Cursor::new(vec![]), schema, options)?;
+/// for group in row_groups {
+///     writer.write(group?)?;
+/// }
+/// writer.end(None)?;
+/// let file = writer.into_inner().into_inner();
+///
+/// let metadata = read_metadata(&mut Cursor::new(file))?;
+/// let summary = ParquetFileSummary::try_new(&metadata)?;
+/// assert_eq!(summary.num_rows(), &UInt64Array::from_slice([2, 3]));
+///
+/// let statistics = summary.statistics("c1").unwrap().to_typed::<Int32Array>()?;
+/// assert_eq!(statistics.null_count, UInt64Array::from([Some(1), Some(0)]));
+/// assert_eq!(statistics.min_value, Int32Array::from_slice([3, 4]));
+/// assert_eq!(statistics.max_value, Int32Array::from_slice([3, 7]));
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, PartialEq)]
+pub struct ParquetFileSummary {
+    schema: Schema,
+    num_rows: UInt64Array,
+    total_byte_size: UInt64Array,
+    statistics: Vec<Statistics>,
+}
+
+impl ParquetFileSummary {
+    /// Returns a new [`ParquetFileSummary`] of a file with `metadata`, whose schema is
+    /// inferred via [`infer_schema`].
+    /// # Errors
+    /// Errors iff the schema can't be inferred or the statistics can't be deserialized.
+    pub fn try_new(metadata: &FileMetaData) -> Result<Self> {
+        Self::try_new_with_schema(infer_schema(metadata)?, &metadata.row_groups)
+    }
+
+    /// Returns a new [`ParquetFileSummary`] of `row_groups`, with the statistics of the fields
+    /// of `schema`.
+    /// # Errors
+    /// Errors iff the statistics of a field can't be deserialized (e.g. invalid utf8).
+    pub fn try_new_with_schema(schema: Schema, row_groups: &[RowGroupMetaData]) -> Result<Self> {
+        let num_rows = UInt64Array::from_vec(
+            row_groups
+                .iter()
+                .map(|group| group.num_rows() as u64)
+                .collect(),
+        );
+        let total_byte_size = UInt64Array::from_vec(
+            row_groups
+                .iter()
+                .map(|group| group.total_byte_size() as u64)
+                .collect(),
+        );
+        let statistics = schema
+            .fields
+            .iter()
+            .map(|field| deserialize(field, row_groups))
+            .collect::<Result<_>>()?;
+        Ok(Self {
+            schema,
+            num_rows,
+            total_byte_size,
+            statistics,
+        })
+    }
+
+    /// The schema of the summarized fields
+    pub fn schema(&self) -> &Schema {
+        &self.schema
+    }
+
+    /// The number of row groups of the file
+    pub fn num_row_groups(&self) -> usize {
+        self.num_rows.len()
+    }
+
+    /// The number of rows of each row group
+    pub fn num_rows(&self) -> &UInt64Array {
+        &self.num_rows
+    }
+
+    /// The total uncompressed byte size of each row group
+    pub fn total_byte_size(&self) -> &UInt64Array {
+        &self.total_byte_size
+    }
+
+    /// The [`Statistics`] of each field of [`ParquetFileSummary::schema`]
+    pub fn fields_statistics(&self) -> &[Statistics] {
+        &self.statistics
+    }
+
+    /// The [`Statistics`] of the field named `name`, if any
+    pub fn statistics(&self, name: &str) -> Option<&Statistics> {
+        self.schema
+            .fields
+            .iter()
+            .position(|field| field.name == name)
+            .map(|index| &self.statistics[index])
+    }
+
+    /// Returns this summary as a [`Chunk`] with one row per row group, whose columns are
+    /// `num_rows`, `total_byte_size` and, for each field `{name}`, `{name}.null_count`,
+    /// `{name}.min` and `{name}.max`.
+    pub fn to_chunk(&self) -> (Schema, Chunk<Box<dyn Array>>) {
+        let mut fields = vec![
+            Field::new("num_rows", DataType::UInt64, false),
+            Field::new("total_byte_size", DataType::UInt64, false),
+        ];
+        let mut arrays = vec![
+            self.num_rows.clone().boxed(),
+            self.total_byte_size.clone().boxed(),
+        ];
+        for (field, statistics) in self.schema.fields.iter().zip(self.statistics.iter()) {
+            for (suffix, array) in [
+                ("null_count", &statistics.null_count),
+                ("min", &statistics.min_value),
+                ("max", &statistics.max_value),
+            ] {
+                fields.push(Field::new(
+                    format!("{}.{suffix}", field.name),
+                    array.data_type().clone(),
+                    true,
+                ));
+                arrays.push(array.clone());
+            }
+        }
+        (Schema::from(fields), Chunk::new(arrays))
+    }
+}
@@ -0,0 +1,468 @@
+//! APIs to write partitioned datasets of parquet files.
+use std::collections::{BTreeMap, HashMap};
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+
+use crate::array::{get_display, Array, PrimitiveArray};
+use crate::chunk::Chunk;
+use crate::compute::take::take;
+use crate::datatypes::Schema;
+use crate::error::{Error, Result};
+
+use super::{Encoding, FileWriter, KeyValue, RowGroupIterator, ThriftFileMetaData, WriteOptions};
+
+/// The directory name of partitions whose value is null, as used by Hive and Spark
+pub const HIVE_DEFAULT_PARTITION: &str = "__HIVE_DEFAULT_PARTITION__";
+
+/// A file written by a [`DatasetWriter`].
+#[derive(Debug, Clone)]
+pub struct WrittenFile {
+    /// The path of the file
+    pub path: PathBuf,
+    /// The name and value of the partition columns of the rows of the file
+    /// (`None` when the value is null)
+    pub partition: Vec<(String, Option<String>)>,
+    /// The number of rows of the file
+    pub num_rows: usize,
+    /// The size of the file in bytes
+    pub size: u64,
+    /// The metadata of the file
+    pub metadata: ThriftFileMetaData,
+}
+
+/// A [`Write`] that counts the number of bytes written to it.
+struct CountingWriter<W: Write> {
+    inner: W,
+    // shared since the writer is owned by a `FileWriter`
+    bytes: Arc<AtomicU64>,
+}
+
+impl<W: Write> Write for CountingWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        let written = self.inner.write(buf)?;
+        self.bytes.fetch_add(written as u64, Ordering::Relaxed);
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+struct PartitionFile {
+    path: PathBuf,
+    writer: FileWriter<CountingWriter<BufWriter<File>>>,
+    bytes: Arc<AtomicU64>,
+    num_rows: usize,
+}
+
+type PartitionKey = Vec<Option<String>>;
+
+/// Writes [`Chunk`]s to a dataset of parquet files partitioned by the values of some of its
+/// columns into Hive-style directories, `root/key1=value1/key2=value2/part-00000.parquet`.
+///
+/// The partition columns are not written to the files, since their values are encoded in
+/// the path. Every partition has at most one open file, which is rotated (closed and replaced by
+/// a new file) once it has [`DatasetWriter::with_max_rows_per_file`] rows or
+/// [`DatasetWriter::with_max_bytes_per_file`] bytes. Every chunk written to a partition is
+/// written as (at least) one row group.
+/// # Example
+/// ```no_run
+/// use arrow2::array::{Array, Int32Array, Utf8Array};
+/// use arrow2::chunk::Chunk;
+/// use arrow2::datatypes::{DataType, Field, Schema};
+/// use arrow2::io::parquet::write::{
+///     CompressionOptions, DatasetWriter, Encoding, Version, WriteOptions,
+/// };
+///
+/// # fn main() -> arrow2::error::Result<()> {
+/// let schema = Schema::from(vec![
+///     Field::new("country", DataType::Utf8, true),
+///     Field::new("value", DataType::Int32, true),
+/// ]);
+/// let options = WriteOptions {
+///     write_statistics: true,
+///     compression: CompressionOptions::Uncompressed,
+///     version: Version::V2,
+///     data_pagesize_limit: None,
+///     int96_timestamps: false,
+/// };
+///
+/// let mut writer = DatasetWriter::try_new(
+///     "dataset",
+///     schema,
+///     &["country"],
+///     options,
+///     vec![vec![Encoding::Plain]],
+/// )?
+/// .with_max_rows_per_file(1_000_000);
+///
+/// writer.write(&Chunk::new(vec![
+///     Utf8Array::<i32>::from_slice(["pt", "de", "pt"]).boxed(),
+///     Int32Array::from_slice([1, 2, 3]).boxed(),
+/// ]))?;
+///
+/// // dataset/country=de/part-00001.parquet and dataset/country=pt/part-00000.parquet
+/// let files = writer.finish()?;
+/// assert_eq!(files.len(), 2);
+/// # Ok(())
+/// # }
+/// ```
+pub struct DatasetWriter {
+    root: PathBuf,
+    schema: Schema,
+    partition_columns: Vec<usize>,
+    file_schema: Schema,
+    options: WriteOptions,
+    encodings: Vec<Vec<Encoding>>,
+    max_rows_per_file: Option<usize>,
+    max_bytes_per_file: Option<u64>,
+    key_value_metadata: Option<Vec<KeyValue>>,
+    open: BTreeMap<PartitionKey, PartitionFile>,
+    written: Vec<WrittenFile>,
+    num_files: usize,
+}
+
+impl DatasetWriter {
+    /// Returns a new [`DatasetWriter`] that writes chunks of `schema` to a dataset at `root`,
+    /// partitioned by the fields named `partition_columns`.
+    ///
+    /// `encodings` are the encodings of the fields of `schema` that are not partition columns,
+    /// as in [`RowGroupIterator::try_new`].
+    /// # Errors
+    /// Errors iff any of `partition_columns` is not a field of `schema`, all fields are partition
+    /// columns, or the number of `encodings` is different from the number of written fields.
+    pub fn try_new<P: Into<PathBuf>>(
+        root: P,
+        schema: Schema,
+        partition_columns: &[&str],
+        options: WriteOptions,
+        encodings: Vec<Vec<Encoding>>,
+    ) -> Result<Self> {
+        let partition_columns = partition_columns
+            .iter()
+            .map(|name| {
+                schema
+                    .fields
+                    .iter()
+                    .position(|field| field.name == *name)
+                    .ok_or_else(|| {
+                        Error::InvalidArgumentError(format!(
+                            "The partition column \"{name}\" is not a field of the schema"
+                        ))
+                    })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let file_schema = Schema {
+            fields: schema
+                .fields
+                .iter()
+                .enumerate()
+                .filter(|(index, _)| !partition_columns.contains(index))
+                .map(|(_, field)| field.clone())
+                .collect(),
+            metadata: schema.metadata.clone(),
+        };
+        if file_schema.fields.is_empty() {
+            return Err(Error::InvalidArgumentError(
+                "A partitioned dataset requires at least one field that is not a partition column"
+                    .to_string(),
+            ));
+        }
+        if encodings.len() != file_schema.fields.len() {
+            return Err(Error::InvalidArgumentError(format!(
+                "The number of encodings ({}) must equal the number of fields that are not partition columns ({})",
+                encodings.len(),
+                file_schema.fields.len()
+            )));
+        }
+
+        Ok(Self {
+            root: root.into(),
+            schema,
+            partition_columns,
+            file_schema,
+            options,
+            encodings,
+            max_rows_per_file: None,
+            max_bytes_per_file: None,
+            key_value_metadata: None,
+            open: BTreeMap::new(),
+            written: vec![],
+            num_files: 0,
+        })
+    }
+
+    /// Sets the maximum number of rows of every file.
+    /// # Panics
+    /// Panics iff `max_rows` is zero.
+    #[must_use]
+    pub fn with_max_rows_per_file(mut self, max_rows: usize) -> Self {
+        assert!(
+            max_rows > 0,
+            "files must be allowed to have at least one row"
+        );
+        self.max_rows_per_file = Some(max_rows);
+        self
+    }
+
+    /// Sets the number of bytes after which a file is rotated. Since files are only rotated
+    /// after a row group is written, files may be larger than `max_bytes`.
+    #[must_use]
+    pub fn with_max_bytes_per_file(mut self, max_bytes: u64) -> Self {
+        self.max_bytes_per_file = Some(max_bytes);
+        self
+    }
+
+    /// Sets the key-value metadata written to the footer of every file.
+    #[must_use]
+    pub fn with_key_value_metadata(mut self, key_value_metadata: Vec<KeyValue>) -> Self {
+        self.key_value_metadata = Some(key_value_metadata);
+        self
+    }
+
+    /// The [`Schema`] of the chunks written to this [`DatasetWriter`]
+    pub fn schema(&self) -> &Schema {
+        &self.schema
+    }
+
+    /// The [`Schema`] of the files, i.e. of the fields that are not partition columns
+    pub fn file_schema(&self) -> &Schema {
+        &self.file_schema
+    }
+
+    /// Writes `chunk` to the files of the partitions of its rows.
+    /// # Errors
+    /// Errors iff the chunk does not match the schema, or on any IO or encoding error.
+    pub fn write<A: AsRef<dyn Array>>(&mut self, chunk: &Chunk<A>) -> Result<()> {
+        if chunk.arrays().len() != self.schema.fields.len() {
+            return Err(Error::InvalidArgumentError(format!(
+                "The chunk has {} columns, but the schema has {} fields",
+                chunk.arrays().len(),
+                self.schema.fields.len()
+            )));
+        }
+        if chunk.is_empty() {
+            return Ok(());
+        }
+
+        let partitions = self.partitions(chunk)?;
+        let columns = chunk
+            .arrays()
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| !self.partition_columns.contains(index))
+            .map(|(_, array)| array.as_ref())
+            .collect::<Vec<_>>();
+
+        if partitions.len() == 1 {
+            let (key, _) = partitions.into_iter().next().unwrap();
+            let columns = columns.into_iter().map(|array| array.to_boxed()).collect();
+            return self.write_partition(key, Chunk::new(columns));
+        }
+        for (key, indices) in partitions {
+            let indices = PrimitiveArray::from_vec(indices);
+            let columns = columns
+                .iter()
+                .map(|array| take(*array, &indices))
+                .collect::<Result<Vec<_>>>()?;
+            self.write_partition(key, Chunk::new(columns))?;
+        }
+        Ok(())
+    }
+
+    /// Returns the indices of the rows of every partition of `chunk`, in the order the
+    /// partitions first appear in the chunk.
+    fn partitions<A: AsRef<dyn Array>>(
+        &self,
+        chunk: &Chunk<A>,
+    ) -> Result<Vec<(PartitionKey, Vec<u64>)>> {
+        let arrays = self
+            .partition_columns
+            .iter()
+            .map(|index| chunk.arrays()[*index].as_ref())
+            .collect::<Vec<_>>();
+        let displays = arrays
+            .iter()
+            .map(|array| get_display::<String>(*array, "null"))
+            .collect::<Vec<_>>();
+
+        let mut positions = HashMap::<PartitionKey, usize>::new();
+        let mut partitions = vec![];
+        for row in 0..chunk.len() {
+            let key = arrays
+                .iter()
+                .zip(displays.iter())
+                .map(|(array, display)| {
+                    if array.is_null(row) {
+                        return Ok(None);
+                    }
+                    let mut value = String::new();
+                    display(&mut value, row).map_err(|_| {
+                        Error::InvalidArgumentError(
+                            "The value of a partition column could not be formatted".to_string(),
+                        )
+                    })?;
+                    Ok(Some(value))
+                })
+                .collect::<Result<PartitionKey>>()?;
+
+            let position = *positions.entry(key).or_insert_with_key(|key| {
+                partitions.push((key.clone(), vec![]));
+                partitions.len() - 1
+            });
+            partitions[position].1.push(row as u64);
+        }
+        Ok(partitions)
+    }
+
+    fn write_partition(
+        &mut self,
+        key: PartitionKey,
+        mut chunk: Chunk<Box<dyn Array>>,
+    ) -> Result<()> {
+        while !chunk.is_empty() {
+            let num_rows = self.open.get(&key).map_or(0, |file| file.num_rows);
+            let length = self
+                .max_rows_per_file
+                .map_or(chunk.len(), |max| chunk.len().min(max - num_rows));
+
+            let (head, tail) = if length < chunk.len() {
+                (chunk.sliced(0..length), chunk.sliced(length..chunk.len()))
+            } else {
+                (chunk, Chunk::new(vec![]))
+            };
+            chunk = tail;
+
+            if !self.open.contains_key(&key) {
+                let file = self.new_file(&key)?;
+                self.open.insert(key.clone(), file);
+            }
+            let file = self.open.get_mut(&key).unwrap();
+
+            let row_groups = RowGroupIterator::try_new(
+                std::iter::once(Ok(head)),
+                &self.file_schema,
+                self.options,
+                self.encodings.clone(),
+            )?;
+            for group in row_groups {
+                file.writer.write(group?)?;
+            }
+            file.num_rows += length;
+
+            let is_full = self
+                .max_rows_per_file
+                .map_or(false, |max| file.num_rows >= max)
+                || self
+                    .max_bytes_per_file
+                    .map_or(false, |max| file.bytes.load(Ordering::Relaxed) >= max);
+            if is_full {
+                let file = self.open.remove(&key).unwrap();
+                self.close(key.clone(), file)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the name and value of the partition columns of the partition `key`
+    fn partition(&self, key: PartitionKey) -> Vec<(String, Option<String>)> {
+        self.partition_columns
+            .iter()
+            .zip(key)
+            .map(|(index, value)| (self.schema.fields[*index].name.clone(), value))
+            .collect()
+    }
+
+    fn new_file(&mut self, key: &PartitionKey) -> Result<PartitionFile> {
+        let directory = partition_path(&self.root, &self.partition(key.clone()));
+        std::fs::create_dir_all(&directory)?;
+
+        let path = directory.join(format!("part-{:05}.parquet", self.num_files));
+        self.num_files += 1;
+        let bytes = Arc::new(AtomicU64::new(0));
+        let writer = CountingWriter {
+            inner: BufWriter::new(File::create(&path)?),
+            bytes: bytes.clone(),
+        };
+        Ok(PartitionFile {
+            path,
+            writer: FileWriter::try_new(writer, self.file_schema.clone(), self.options)?,
+            bytes,
+            num_rows: 0,
+        })
+    }
+
+    fn close(&mut self, key: PartitionKey, mut file: PartitionFile) -> Result<()> {
+        let size = file.writer.end(self.key_value_metadata.clone())?;
+        let (mut writer, metadata) = file.writer.into_inner_and_metadata();
+        writer.flush()?;
+
+        self.written.push(WrittenFile {
+            path: file.path,
+            partition: self.partition(key),
+            num_rows: file.num_rows,
+            size,
+            metadata,
+        });
+        Ok(())
+    }
+
+    /// Closes all open files and returns all files written by this [`DatasetWriter`], in the
+    /// order they were closed.
+    pub fn finish(mut self) -> Result<Vec<WrittenFile>> {
+        let open = std::mem::take(&mut self.open);
+        for (key, file) in open {
+            self.close(key, file)?;
+        }
+        Ok(self.written)
+    }
+}
+
+/// Escapes the characters of `value` that can't be part of a path component in the same way as
+/// Hive (e.g. `/` is written as `%2F`).
+fn escape(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        if c.is_ascii_control()
+            || matches!(
+                c,
+                '"' | '#' | '%' | '\'' | '*' | '/' | ':' | '=' | '?' | '\\' | '{' | '[' | ']' | '^'
+            )
+        {
+            escaped.push_str(&format!("%{:02X}", c as u32));
+        } else {
+            escaped.push(c);
+        }
+    }
+    escaped
+}
+
+impl std::fmt::Debug for DatasetWriter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DatasetWriter")
+            .field("root", &self.root)
+            .field("schema", &self.schema)
+            .field("partition_columns", &self.partition_columns)
+            .field("max_rows_per_file", &self.max_rows_per_file)
+            .field("max_bytes_per_file", &self.max_bytes_per_file)
+            .finish()
+    }
+}
+
+/// Returns the directory of the partition whose partition columns have the names and values
+/// `partition` below `root`, as written by [`DatasetWriter`].
+pub fn partition_path(root: &Path, partition: &[(String, Option<String>)]) -> PathBuf {
+    let mut path = root.to_path_buf();
+    for (name, value) in partition {
+        let value = value
+            .as_deref()
+            .map_or_else(|| HIVE_DEFAULT_PARTITION.to_string(), escape);
+        path.push(format!("{}={value}", escape(name)));
+    }
+    path
+}
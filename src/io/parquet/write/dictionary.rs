@@ -1,3 +1,5 @@
+use std::cmp::Ordering;
+
 use parquet2::{
     encoding::{hybrid_rle::encode_u32, Encoding},
     page::{DictPage, Page},
@@ -8,7 +10,7 @@ use parquet2::{
 
 use crate::io::parquet::write::{slice_nested_leaf, utils};
 use crate::{
-    array::{Array, DictionaryArray, DictionaryKey},
+    array::{ord::build_compare, DictionaryArray, DictionaryKey},
     io::parquet::read::schema::is_nullable,
 };
 use crate::{bitmap::Bitmap, datatypes::DataType};
@@ -101,11 +103,50 @@ fn normalized_validity<K: DictionaryKey>(array: &DictionaryArray<K>) -> Option<B
     }
 }
 
+/// Returns the number of distinct values referenced by the valid keys of `array`, or `None`
+/// if the values of the dictionary can't be compared.
+///
+/// The values of a dictionary may repeat (e.g. after concatenating dictionaries), so the
+/// referenced values are sorted and compared, instead of counting the referenced keys.
+fn distinct_count<K: DictionaryKey>(
+    array: &DictionaryArray<K>,
+    validity: Option<&Bitmap>,
+) -> Option<usize> {
+    let values = array.values().as_ref();
+    let compare = build_compare(values, values).ok()?;
+
+    let mut referenced = MutableBitmap::from_len_zeroed(values.len());
+    if let Some(validity) = validity {
+        array
+            .keys_values_iter()
+            .zip(validity.iter())
+            .filter(|(_, is_valid)| *is_valid)
+            .for_each(|(key, _)| referenced.set(key, true));
+    } else {
+        array
+            .keys_values_iter()
+            .for_each(|key| referenced.set(key, true));
+    }
+    let referenced: Bitmap = referenced.into();
+
+    let mut indices = referenced
+        .iter()
+        .enumerate()
+        .filter_map(|(index, is_referenced)| is_referenced.then_some(index))
+        .collect::<Vec<_>>();
+    indices.sort_unstable_by(|lhs, rhs| compare(*lhs, *rhs));
+    let duplicates = indices
+        .windows(2)
+        .filter(|x| compare(x[0], x[1]) == Ordering::Equal)
+        .count();
+    Some(indices.len() - duplicates)
+}
+
 fn serialize_keys<K: DictionaryKey>(
     array: &DictionaryArray<K>,
     type_: PrimitiveType,
     nested: &[Nested],
-    statistics: ParquetStatistics,
+    mut statistics: ParquetStatistics,
     options: WriteOptions,
 ) -> Result<Page> {
     let mut buffer = vec![];
@@ -125,9 +166,16 @@ fn serialize_keys<K: DictionaryKey>(
         start,
     )?;
     let array = array.slice(start, len);
+    let validity = validity.map(|validity| validity.slice(start, len));
 
     serialize_keys_values(&array, validity.as_ref(), &mut buffer)?;
 
+    // the statistics of the dictionary page describe its values; the null and
+    // distinct counts of this column are the ones of its (sliced) keys.
+    let null_count = validity.as_ref().map(|x| x.unset_bits()).unwrap_or(0);
+    statistics.null_count = Some(null_count as i64);
+    statistics.distinct_count = distinct_count(&array, validity.as_ref()).map(|x| x as i64);
+
     let (num_values, num_rows) = if nested.len() == 1 {
         (array.len(), array.len())
     } else {
@@ -138,7 +186,7 @@ fn serialize_keys<K: DictionaryKey>(
         buffer,
         num_values,
         num_rows,
-        array.null_count(),
+        null_count,
         repetition_levels_byte_length,
         definition_levels_byte_length,
         Some(statistics),
@@ -8,19 +8,22 @@ use parquet2::write::WriteOptions as FileWriteOptions;
 use crate::datatypes::Schema;
 use crate::error::{Error, Result};
 
-use super::{schema::schema_to_metadata_key, to_parquet_schema, ThriftFileMetaData, WriteOptions};
+use super::super::ARROW_SCHEMA_META_KEY;
+use super::{
+    schema::schema_to_metadata_key, to_parquet_schema_with_options, ThriftFileMetaData,
+    WriteOptions,
+};
 
-/// Attaches [`Schema`] to `key_value_metadata`
+/// Attaches [`Schema`] to `key_value_metadata` under the key `"ARROW:schema"`, replacing
+/// any existing one, so that readers can recover the arrow types of the file.
 pub fn add_arrow_schema(
     schema: &Schema,
     key_value_metadata: Option<Vec<KeyValue>>,
 ) -> Option<Vec<KeyValue>> {
-    key_value_metadata
-        .map(|mut x| {
-            x.push(schema_to_metadata_key(schema));
-            x
-        })
-        .or_else(|| Some(vec![schema_to_metadata_key(schema)]))
+    let mut key_value_metadata = key_value_metadata.unwrap_or_default();
+    key_value_metadata.retain(|x| x.key != ARROW_SCHEMA_META_KEY);
+    key_value_metadata.push(schema_to_metadata_key(schema));
+    Some(key_value_metadata)
 }
 
 /// An interface to write a parquet to a [`Write`]
@@ -53,7 +56,7 @@ impl<W: Write> FileWriter<W> {
     /// # Error
     /// If it is unable to derive a parquet schema from [`Schema`].
     pub fn try_new(writer: W, schema: Schema, options: WriteOptions) -> Result<Self> {
-        let parquet_schema = to_parquet_schema(&schema)?;
+        let parquet_schema = to_parquet_schema_with_options(&schema, options)?;
 
         let created_by = Some("Arrow2 - Native Rust implementation of Arrow".to_string());
 
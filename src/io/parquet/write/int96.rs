@@ -0,0 +1,83 @@
+use parquet2::{
+    encoding::Encoding, page::DataPage, schema::types::PrimitiveType,
+    types::NativeType as ParquetNativeType,
+};
+
+use super::{utils, WriteOptions};
+use crate::{
+    array::{Array, PrimitiveArray},
+    datatypes::TimeUnit,
+    error::Result,
+    io::parquet::read::schema::is_nullable,
+};
+
+/// Converts a timestamp of `time_unit` to parquet's `INT96`: the nanoseconds of the day
+/// followed by the julian day.
+fn timestamp_to_int96(value: i64, time_unit: TimeUnit) -> [u32; 3] {
+    const JULIAN_DAY_OF_EPOCH: i64 = 2_440_588;
+    const SECONDS_PER_DAY: i64 = 86_400;
+
+    let units_per_second = match time_unit {
+        TimeUnit::Second => 1,
+        TimeUnit::Millisecond => 1_000,
+        TimeUnit::Microsecond => 1_000_000,
+        TimeUnit::Nanosecond => 1_000_000_000,
+    };
+    let units_per_day = SECONDS_PER_DAY * units_per_second;
+    let day = value.div_euclid(units_per_day) + JULIAN_DAY_OF_EPOCH;
+    let nanoseconds = value.rem_euclid(units_per_day) * (1_000_000_000 / units_per_second);
+    [nanoseconds as u32, (nanoseconds >> 32) as u32, day as u32]
+}
+
+fn encode_plain(
+    array: &PrimitiveArray<i64>,
+    time_unit: TimeUnit,
+    is_optional: bool,
+    buffer: &mut Vec<u8>,
+) {
+    let mut push =
+        |x: i64| buffer.extend_from_slice(&timestamp_to_int96(x, time_unit).to_le_bytes());
+    if is_optional {
+        // append the non-null values
+        array.iter().flatten().for_each(|x| push(*x));
+    } else {
+        array.values().iter().for_each(|x| push(*x));
+    }
+}
+
+/// Writes a timestamp array of `time_unit` as a plain-encoded page of parquet's `INT96`,
+/// without statistics.
+pub fn array_to_page(
+    array: &PrimitiveArray<i64>,
+    time_unit: TimeUnit,
+    options: WriteOptions,
+    type_: PrimitiveType,
+) -> Result<DataPage> {
+    let is_optional = is_nullable(&type_.field_info);
+
+    let mut buffer = vec![];
+    utils::write_def_levels(
+        &mut buffer,
+        is_optional,
+        array.validity(),
+        array.len(),
+        options.version,
+    )?;
+
+    let definition_levels_byte_length = buffer.len();
+
+    encode_plain(array, time_unit, is_optional, &mut buffer);
+
+    utils::build_plain_page(
+        buffer,
+        array.len(),
+        array.len(),
+        array.null_count(),
+        0,
+        definition_levels_byte_length,
+        None,
+        type_,
+        options,
+        Encoding::Plain,
+    )
+}
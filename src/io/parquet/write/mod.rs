@@ -14,9 +14,13 @@
 
 mod binary;
 mod boolean;
+#[cfg(feature = "io_parquet_dataset")]
+#[cfg_attr(docsrs, doc(cfg(feature = "io_parquet_dataset")))]
+mod dataset;
 mod dictionary;
 mod file;
 mod fixed_len_bytes;
+mod int96;
 mod nested;
 mod pages;
 mod primitive;
@@ -33,6 +37,7 @@ use crate::types::days_ms;
 use crate::types::NativeType;
 
 use parquet2::schema::types::PrimitiveType as ParquetPrimitiveType;
+use parquet2::schema::Repetition;
 pub use parquet2::{
     compression::{BrotliLevel, CompressionOptions, GzipLevel, ZstdLevel},
     encoding::Encoding,
@@ -58,10 +63,17 @@ pub struct WriteOptions {
     pub compression: CompressionOptions,
     /// The size to flush a page, defaults to 1024 * 1024 if None
     pub data_pagesize_limit: Option<usize>,
+    /// Whether to write the (non-nested, non-dictionary) timestamps as the deprecated `INT96`
+    /// physical type, as required by some legacy systems (e.g. older versions of Spark and Hive).
+    /// `INT96` has no statistics, so this requires `write_statistics` to be `false`.
+    pub int96_timestamps: bool,
 }
 
 use crate::compute::aggregate::estimated_bytes_size;
-pub use file::FileWriter;
+#[cfg(feature = "io_parquet_dataset")]
+#[cfg_attr(docsrs, doc(cfg(feature = "io_parquet_dataset")))]
+pub use dataset::{partition_path, DatasetWriter, WrittenFile, HIVE_DEFAULT_PARTITION};
+pub use file::{add_arrow_schema, FileWriter};
 pub use row_group::{row_group_iter, RowGroupIterator};
 pub use schema::to_parquet_type;
 pub use sink::FileSink;
@@ -114,6 +126,45 @@ pub fn to_parquet_schema(schema: &Schema) -> Result<SchemaDescriptor> {
     Ok(SchemaDescriptor::new("root".to_string(), parquet_types))
 }
 
+/// Creates the parquet [`SchemaDescriptor`] of a [`Schema`] written with `options`
+/// (see [`WriteOptions::int96_timestamps`]).
+/// # Errors
+/// If `options` sets both `int96_timestamps` and `write_statistics`, or if it is unable to
+/// derive a parquet schema from [`Schema`].
+pub fn to_parquet_schema_with_options(
+    schema: &Schema,
+    options: WriteOptions,
+) -> Result<SchemaDescriptor> {
+    if options.int96_timestamps && options.write_statistics {
+        return Err(Error::InvalidArgumentError(
+            "INT96 timestamps cannot be written with statistics".to_string(),
+        ));
+    }
+    let parquet_types = schema
+        .fields
+        .iter()
+        .map(|field| match field.data_type().to_logical_type() {
+            DataType::Timestamp(_, _) if options.int96_timestamps => {
+                let repetition = if field.is_nullable {
+                    Repetition::Optional
+                } else {
+                    Repetition::Required
+                };
+                Ok(ParquetType::try_from_primitive(
+                    field.name.clone(),
+                    ParquetPhysicalType::Int96,
+                    repetition,
+                    None,
+                    None,
+                    None,
+                )?)
+            }
+            _ => to_parquet_type(field),
+        })
+        .collect::<Result<Vec<_>>>()?;
+    Ok(SchemaDescriptor::new("root".to_string(), parquet_types))
+}
+
 /// Checks whether the `data_type` can be encoded as `encoding`.
 /// Note that this is whether this implementation supports it, which is a subset of
 /// what the parquet spec allows.
@@ -365,6 +416,23 @@ pub fn array_to_page_simple(
                 encoding,
             )
         }
+        DataType::Timestamp(time_unit, _) if type_.physical_type == ParquetPhysicalType::Int96 => {
+            if encoding != Encoding::Plain {
+                return Err(Error::InvalidArgumentError(format!(
+                    "INT96 timestamps cannot be encoded by {encoding:?}"
+                )));
+            }
+            if options.write_statistics {
+                return Err(Error::InvalidArgumentError(
+                    "INT96 timestamps cannot be written with statistics".to_string(),
+                ));
+            }
+            let array = array
+                .as_any()
+                .downcast_ref::<PrimitiveArray<i64>>()
+                .unwrap();
+            int96::array_to_page(array, *time_unit, options, type_)
+        }
         DataType::Int64
         | DataType::Date64
         | DataType::Time64(_)
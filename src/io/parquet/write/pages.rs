@@ -2,11 +2,11 @@ use parquet2::schema::types::{ParquetType, PrimitiveType as ParquetPrimitiveType
 use parquet2::{page::Page, write::DynIter};
 use std::fmt::Debug;
 
-use crate::array::{ListArray, StructArray};
+use crate::array::{growable::make_growable, FixedSizeListArray, ListArray, MapArray, StructArray};
 use crate::bitmap::Bitmap;
-use crate::datatypes::PhysicalType;
+use crate::datatypes::{DataType, Field, PhysicalType};
 use crate::io::parquet::read::schema::is_nullable;
-use crate::offset::Offset;
+use crate::offset::{Offset, Offsets};
 use crate::{
     array::Array,
     error::{Error, Result},
@@ -196,6 +196,123 @@ fn to_parquet_leaves_recursive(type_: ParquetType, leaves: &mut Vec<ParquetPrimi
     }
 }
 
+fn has_fixed_size_list(data_type: &DataType) -> bool {
+    match data_type.to_logical_type() {
+        DataType::FixedSizeList(_, _) => true,
+        DataType::List(inner) | DataType::LargeList(inner) | DataType::Map(inner, _) => {
+            has_fixed_size_list(&inner.data_type)
+        }
+        DataType::Struct(fields) => fields.iter().any(|f| has_fixed_size_list(&f.data_type)),
+        _ => false,
+    }
+}
+
+fn with_data_type(field: &Field, data_type: DataType) -> Field {
+    Field {
+        data_type,
+        ..field.clone()
+    }
+}
+
+/// Returns `array` with its [`FixedSizeListArray`]s converted to [`ListArray<i32>`]s, which is
+/// how they are written to parquet (as a `LIST`). The values of null slots are dropped, since
+/// null lists are written without values.
+fn fixed_size_list_to_list(array: &dyn Array) -> Result<Box<dyn Array>> {
+    match array.data_type().to_logical_type() {
+        DataType::FixedSizeList(field, size) => {
+            let array = array.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
+            let values = fixed_size_list_to_list(array.values().as_ref())?;
+            let (offsets, values) = match array.validity().filter(|x| x.unset_bits() > 0) {
+                None => {
+                    let lengths = (0..array.len()).map(|_| *size);
+                    (Offsets::<i32>::try_from_lengths(lengths)?, values)
+                }
+                Some(validity) => {
+                    let mut growable = make_growable(&[values.as_ref()], false, values.len());
+                    for (i, is_valid) in validity.iter().enumerate() {
+                        if is_valid {
+                            growable.extend(0, i * size, *size);
+                        }
+                    }
+                    let lengths = validity.iter().map(|x| if x { *size } else { 0 });
+                    (
+                        Offsets::<i32>::try_from_lengths(lengths)?,
+                        growable.as_box(),
+                    )
+                }
+            };
+            let data_type =
+                DataType::List(Box::new(with_data_type(field, values.data_type().clone())));
+            Ok(ListArray::<i32>::try_new(
+                data_type,
+                offsets.into(),
+                values,
+                array.validity().cloned(),
+            )?
+            .boxed())
+        }
+        DataType::List(field) => {
+            let array = array.as_any().downcast_ref::<ListArray<i32>>().unwrap();
+            let values = fixed_size_list_to_list(array.values().as_ref())?;
+            let data_type =
+                DataType::List(Box::new(with_data_type(field, values.data_type().clone())));
+            Ok(ListArray::<i32>::try_new(
+                data_type,
+                array.offsets().clone(),
+                values,
+                array.validity().cloned(),
+            )?
+            .boxed())
+        }
+        DataType::LargeList(field) => {
+            let array = array.as_any().downcast_ref::<ListArray<i64>>().unwrap();
+            let values = fixed_size_list_to_list(array.values().as_ref())?;
+            let data_type =
+                DataType::LargeList(Box::new(with_data_type(field, values.data_type().clone())));
+            Ok(ListArray::<i64>::try_new(
+                data_type,
+                array.offsets().clone(),
+                values,
+                array.validity().cloned(),
+            )?
+            .boxed())
+        }
+        DataType::Struct(fields) => {
+            let array = array.as_any().downcast_ref::<StructArray>().unwrap();
+            let values = array
+                .values()
+                .iter()
+                .map(|x| fixed_size_list_to_list(x.as_ref()))
+                .collect::<Result<Vec<_>>>()?;
+            let fields = fields
+                .iter()
+                .zip(values.iter())
+                .map(|(field, x)| with_data_type(field, x.data_type().clone()))
+                .collect();
+            Ok(
+                StructArray::try_new(DataType::Struct(fields), values, array.validity().cloned())?
+                    .boxed(),
+            )
+        }
+        DataType::Map(field, sorted) => {
+            let array = array.as_any().downcast_ref::<MapArray>().unwrap();
+            let values = fixed_size_list_to_list(array.field().as_ref())?;
+            let data_type = DataType::Map(
+                Box::new(with_data_type(field, values.data_type().clone())),
+                *sorted,
+            );
+            Ok(MapArray::try_new(
+                data_type,
+                array.offsets().clone(),
+                values,
+                array.validity().cloned(),
+            )?
+            .boxed())
+        }
+        _ => Ok(array.to_boxed()),
+    }
+}
+
 /// Returns a vector of iterators of [`Page`], one per leaf column in the array
 pub fn array_to_columns<A: AsRef<dyn Array> + Send + Sync>(
     array: A,
@@ -204,6 +321,13 @@ pub fn array_to_columns<A: AsRef<dyn Array> + Send + Sync>(
     encoding: &[Encoding],
 ) -> Result<Vec<DynIter<'static, Result<Page>>>> {
     let array = array.as_ref();
+    let converted;
+    let array = if has_fixed_size_list(array.data_type()) {
+        converted = fixed_size_list_to_list(array)?;
+        converted.as_ref()
+    } else {
+        array
+    };
     let nested = to_nested(array, &type_)?;
 
     let types = to_parquet_leaves(type_);
@@ -503,4 +627,46 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn test_map_fixed_size_list() {
+        let values = Int32Array::from_slice([1, 2, 3, 4, 5, 6]).boxed();
+        let inner = DataType::FixedSizeList(Box::new(Field::new("item", DataType::Int32, true)), 2);
+        let values = FixedSizeListArray::new(inner, values, None).boxed();
+        let keys = Utf8Array::<i32>::from_slice(["a", "b", "c"]).boxed();
+
+        let fields = vec![
+            Field::new("key", DataType::Utf8, false),
+            Field::new("value", values.data_type().clone(), true),
+        ];
+        let entries = StructArray::new(DataType::Struct(fields), vec![keys, values], None);
+        let data_type = DataType::Map(
+            Box::new(Field::new("entries", entries.data_type().clone(), false)),
+            false,
+        );
+        let array = MapArray::new(
+            data_type,
+            vec![0, 2, 2, 3].try_into().unwrap(),
+            entries.boxed(),
+            Some(Bitmap::from([true, false, true])),
+        );
+
+        assert!(has_fixed_size_list(array.data_type()));
+        let array = fixed_size_list_to_list(&array).unwrap();
+        assert!(!has_fixed_size_list(array.data_type()));
+
+        let array = array.as_any().downcast_ref::<MapArray>().unwrap();
+        assert_eq!(array.offsets().as_slice(), &[0, 2, 2, 3]);
+        assert_eq!(array.validity(), Some(&Bitmap::from([true, false, true])));
+        let entries = array
+            .field()
+            .as_any()
+            .downcast_ref::<StructArray>()
+            .unwrap();
+        let values = entries.values()[1]
+            .as_any()
+            .downcast_ref::<ListArray<i32>>()
+            .unwrap();
+        assert_eq!(values.offsets().as_slice(), &[0, 2, 4, 6]);
+    }
 }
@@ -2,7 +2,7 @@ use parquet2::{
     encoding::delta_bitpacked::encode,
     encoding::Encoding,
     page::DataPage,
-    schema::types::PrimitiveType,
+    schema::types::{IntegerType, PrimitiveConvertedType, PrimitiveLogicalType, PrimitiveType},
     statistics::{serialize_statistics, PrimitiveStatistics},
     types::NativeType as ParquetNativeType,
 };
@@ -163,6 +163,33 @@ where
     )
 }
 
+/// Whether `primitive_type` is annotated as an unsigned integer, whose values are ordered
+/// as unsigned integers (as opposed to the signed order of its physical type).
+fn is_unsigned(primitive_type: &PrimitiveType) -> bool {
+    matches!(
+        primitive_type.logical_type,
+        Some(PrimitiveLogicalType::Integer(
+            IntegerType::UInt8 | IntegerType::UInt16 | IntegerType::UInt32 | IntegerType::UInt64
+        ))
+    ) || matches!(
+        primitive_type.converted_type,
+        Some(
+            PrimitiveConvertedType::Uint8
+                | PrimitiveConvertedType::Uint16
+                | PrimitiveConvertedType::Uint32
+                | PrimitiveConvertedType::Uint64
+        )
+    )
+}
+
+/// Compares two integers as unsigned integers of the same width
+fn unsigned_ord<P: ParquetNativeType>(x: &P, y: &P) -> std::cmp::Ordering {
+    // little-endian bytes are compared from the most significant byte
+    let x = x.to_le_bytes();
+    let y = y.to_le_bytes();
+    x.as_ref().iter().rev().cmp(y.as_ref().iter().rev())
+}
+
 pub fn build_statistics<T, P>(
     array: &PrimitiveArray<T>,
     primitive_type: PrimitiveType,
@@ -172,6 +199,11 @@ where
     P: ParquetNativeType,
     T: num_traits::AsPrimitive<P>,
 {
+    let ord = if is_unsigned(&primitive_type) {
+        unsigned_ord::<P>
+    } else {
+        P::ord
+    };
     PrimitiveStatistics::<P> {
         primitive_type,
         null_count: Some(array.null_count() as i64),
@@ -183,7 +215,7 @@ where
                 let x: P = x.as_();
                 x
             })
-            .max_by(|x, y| x.ord(y)),
+            .max_by(ord),
         min_value: array
             .iter()
             .flatten()
@@ -191,6 +223,6 @@ where
                 let x: P = x.as_();
                 x
             })
-            .min_by(|x, y| x.ord(y)),
+            .min_by(ord),
     }
 }
@@ -11,8 +11,8 @@ use crate::{
 };
 
 use super::{
-    array_to_columns, to_parquet_schema, DynIter, DynStreamingIterator, Encoding, RowGroupIter,
-    SchemaDescriptor, WriteOptions,
+    array_to_columns, to_parquet_schema_with_options, DynIter, DynStreamingIterator, Encoding,
+    RowGroupIter, SchemaDescriptor, WriteOptions,
 };
 
 /// Maps a [`Chunk`] and parquet-specific options to an [`RowGroupIter`] used to
@@ -85,7 +85,7 @@ impl<A: AsRef<dyn Array> + 'static, I: Iterator<Item = Result<Chunk<A>>>> RowGro
                 "The number of encodings must equal the number of fields".to_string(),
             ));
         }
-        let parquet_schema = to_parquet_schema(schema)?;
+        let parquet_schema = to_parquet_schema_with_options(schema, options)?;
 
         Ok(Self {
             iter,
@@ -36,6 +36,7 @@ use super::{Encoding, SchemaDescriptor, WriteOptions};
 ///     compression: CompressionOptions::Uncompressed,
 ///     version: Version::V2,
 ///     data_pagesize_limit: None,
+///     int96_timestamps: false,
 /// };
 ///
 /// let mut buffer = vec![];
@@ -89,7 +90,8 @@ where
             ));
         }
 
-        let parquet_schema = crate::io::parquet::write::to_parquet_schema(&schema)?;
+        let parquet_schema =
+            crate::io::parquet::write::to_parquet_schema_with_options(&schema, options)?;
         let created_by = Some("Arrow2 - Native Rust implementation of Arrow".to_string());
         let writer = FileStreamer::new(
             writer,
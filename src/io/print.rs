@@ -1,4 +1,36 @@
 //! APIs to represent [`Chunk`] as a formatted table.
+//!
+//! [`write`] writes every row and value in full, while [`write_with_options`] and
+//! [`DisplayChunk`] can limit the number of rows and the width of columns via [`WriteOptions`].
+//! # Example
+//! ```
+//! use arrow2::array::{Array, Int32Array, Utf8Array};
+//! use arrow2::chunk::Chunk;
+//! use arrow2::io::print::{DisplayChunk, WriteOptions};
+//!
+//! let chunk = Chunk::new(vec![
+//!     Int32Array::from_slice([1, 2, 3]).boxed(),
+//!     Utf8Array::<i32>::from_slice(["a long value", "b", "c"]).boxed(),
+//! ]);
+//! let options = WriteOptions {
+//!     max_rows: Some(2),
+//!     max_column_width: Some(6),
+//!     ..Default::default()
+//! };
+//! let display = DisplayChunk::new(&chunk).with_names(&["a", "b"]).with_options(options);
+//! assert_eq!(
+//!     display.to_string(),
+//!     "+---+--------+
+//! | a | b      |
+//! +---+--------+
+//! | 1 | a lon… |
+//! | … | …      |
+//! | 3 | c      |
+//! +---+--------+"
+//! );
+//! ```
+
+use std::fmt::{Display, Formatter};
 
 use crate::{
     array::{get_display, Array},
@@ -7,34 +39,148 @@ use crate::{
 
 use comfy_table::{Cell, Table};
 
+/// The character written in place of omitted rows and truncated values
+const ELLIPSIS: char = '…';
+
+/// Options to write [`Chunk`]s as a table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct WriteOptions {
+    /// The maximum number of rows to write. When there are more rows, the first and last
+    /// rows are written, separated by a row of ellipses.
+    pub max_rows: Option<usize>,
+    /// The maximum number of characters of a value. Longer values are truncated and end
+    /// with an ellipsis.
+    pub max_column_width: Option<usize>,
+    /// The representation of null values. Defaults to an empty string.
+    pub null: &'static str,
+}
+
 /// Returns a visual representation of [`Chunk`]
 pub fn write<A: AsRef<dyn Array>, N: AsRef<str>>(chunks: &[Chunk<A>], names: &[N]) -> String {
+    write_with_options(chunks, names, &WriteOptions::default())
+}
+
+/// Returns a visual representation of [`Chunk`], limited according to `options`.
+pub fn write_with_options<A: AsRef<dyn Array>, N: AsRef<str>>(
+    chunks: &[Chunk<A>],
+    names: &[N],
+    options: &WriteOptions,
+) -> String {
+    table(chunks, names, options).to_string()
+}
+
+fn table<A: AsRef<dyn Array>, N: AsRef<str>>(
+    chunks: &[Chunk<A>],
+    names: &[N],
+    options: &WriteOptions,
+) -> Table {
     let mut table = Table::new();
     table.load_preset("||--+-++|    ++++++");
 
     if chunks.is_empty() {
-        return table.to_string();
+        return table;
     }
 
     let header = names.iter().map(|name| Cell::new(name.as_ref()));
     table.set_header(header);
 
+    let rows = chunks.iter().map(|chunk| chunk.len()).sum::<usize>();
+    // the rows written before and after the ellipses
+    let (head, tail) = match options.max_rows {
+        Some(max_rows) if rows > max_rows => (max_rows - max_rows / 2, max_rows / 2),
+        _ => (rows, 0),
+    };
+
+    let mut offset = 0;
     for chunk in chunks {
         let displayes = chunk
             .arrays()
             .iter()
-            .map(|array| get_display(array.as_ref(), ""))
+            .map(|array| get_display(array.as_ref(), options.null))
             .collect::<Vec<_>>();
 
         for row in 0..chunk.len() {
+            let index = offset + row;
+            if index == head && tail + head < rows {
+                let ellipses = (0..chunk.arrays().len()).map(|_| Cell::new(ELLIPSIS));
+                table.add_row(ellipses);
+            }
+            if index >= head && index < rows - tail {
+                continue;
+            }
             let mut cells = Vec::new();
             (0..chunk.arrays().len()).for_each(|col| {
                 let mut string = String::new();
                 displayes[col](&mut string, row).unwrap();
+                if let Some(max_column_width) = options.max_column_width {
+                    truncate(&mut string, max_column_width);
+                }
                 cells.push(Cell::new(string));
             });
             table.add_row(cells);
         }
+        offset += chunk.len();
+    }
+    table
+}
+
+/// Truncates `string` to `width` characters, the last being an ellipsis
+fn truncate(string: &mut String, width: usize) {
+    if string.chars().count() > width {
+        let end = string
+            .char_indices()
+            .nth(width.saturating_sub(1))
+            .map(|(i, _)| i)
+            .unwrap_or(string.len());
+        string.truncate(end);
+        if width > 0 {
+            string.push(ELLIPSIS);
+        }
+    }
+}
+
+/// A [`Display`] adaptor of a [`Chunk`], that writes it as a table.
+///
+/// Columns are named after their position unless names are declared via
+/// [`DisplayChunk::with_names`].
+#[derive(Debug, Clone)]
+pub struct DisplayChunk<'a, A: AsRef<dyn Array>> {
+    chunk: &'a Chunk<A>,
+    names: Option<Vec<String>>,
+    options: WriteOptions,
+}
+
+impl<'a, A: AsRef<dyn Array>> DisplayChunk<'a, A> {
+    /// Creates a new [`DisplayChunk`] writing all rows and values in full.
+    pub fn new(chunk: &'a Chunk<A>) -> Self {
+        Self {
+            chunk,
+            names: None,
+            options: WriteOptions::default(),
+        }
+    }
+
+    /// Sets the names of the columns.
+    pub fn with_names<N: AsRef<str>>(mut self, names: &[N]) -> Self {
+        self.names = Some(names.iter().map(|name| name.as_ref().to_string()).collect());
+        self
+    }
+
+    /// Sets the [`WriteOptions`] used to write the chunk.
+    pub fn with_options(mut self, options: WriteOptions) -> Self {
+        self.options = options;
+        self
+    }
+}
+
+impl<'a, A: AsRef<dyn Array>> Display for DisplayChunk<'a, A> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let names = self.names.clone().unwrap_or_else(|| {
+            (0..self.chunk.arrays().len())
+                .map(|i| i.to_string())
+                .collect()
+        });
+        let table = table(std::slice::from_ref(self.chunk), &names, &self.options);
+        write!(f, "{table}")
     }
-    table.to_string()
 }
@@ -0,0 +1,431 @@
+use serde::de::{
+    self,
+    value::{BorrowedStrDeserializer, SeqDeserializer},
+    Deserialize, DeserializeSeed, Visitor,
+};
+
+use crate::array::*;
+use crate::chunk::Chunk;
+use crate::datatypes::{DataType, Field, PhysicalType, PrimitiveType, TimeUnit};
+use crate::error::{Error, Result};
+use crate::offset::Offset;
+use crate::temporal_conversions;
+use crate::types::{f16, NativeType};
+
+/// Returns an iterator over the rows of `chunk`, deserialized as `T`, where `fields` are
+/// the fields of the arrays of `chunk` (e.g. the fields of its schema).
+///
+/// Every row is deserialized as a struct (e.g. a Rust struct deriving `Deserialize`) whose
+/// fields are the values of the arrays with the same name. Values are deserialized as:
+/// * nulls as `None`
+/// * booleans, integers and floats as the corresponding Rust types
+/// * utf8 arrays as (borrowed) strings or unit enum variants, binary arrays as bytes
+/// * lists as sequences (e.g. `Vec`), structs as nested structs and maps as maps
+/// * dictionaries as their values
+/// * dates, times and timestamps as strings (e.g. to `chrono` types) or as their integers
+/// # Errors
+/// Errors iff `fields` and the arrays of `chunk` have different lengths. Each row errors
+/// iff a value cannot be deserialized to its Rust type (e.g. a string to an integer).
+pub fn deserialize_rows<'a, T: Deserialize<'a>, A: AsRef<dyn Array>>(
+    chunk: &'a Chunk<A>,
+    fields: &'a [Field],
+) -> Result<impl Iterator<Item = Result<T>> + 'a> {
+    if fields.len() != chunk.arrays().len() {
+        return Err(Error::InvalidArgumentError(format!(
+            "The chunk has {} arrays but {} fields were given",
+            chunk.arrays().len(),
+            fields.len()
+        )));
+    }
+    Ok((0..chunk.len()).map(move |index| {
+        T::deserialize(RowDeserializer {
+            fields,
+            arrays: chunk.arrays(),
+            index,
+        })
+    }))
+}
+
+/// Deserializes all rows of `chunk` as `T`. See [`deserialize_rows`] for details.
+/// # Errors
+/// Errors iff [`deserialize_rows`] errors or any row cannot be deserialized.
+pub fn from_chunk<'a, T: Deserialize<'a>, A: AsRef<dyn Array>>(
+    chunk: &'a Chunk<A>,
+    fields: &'a [Field],
+) -> Result<Vec<T>> {
+    deserialize_rows(chunk, fields)?.collect()
+}
+
+fn mismatch(data_type: &DataType) -> Error {
+    Error::NotYetImplemented(format!(
+        "Deserializing values of type {data_type:?} is not supported"
+    ))
+}
+
+/// Deserializes the row `index` of arrays as a struct with `fields`.
+struct RowDeserializer<'a, A: AsRef<dyn Array>> {
+    fields: &'a [Field],
+    arrays: &'a [A],
+    index: usize,
+}
+
+impl<'de, A: AsRef<dyn Array>> de::Deserializer<'de> for RowDeserializer<'de, A> {
+    type Error = Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_map(StructAccess {
+            fields: self.fields,
+            arrays: self.arrays,
+            index: self.index,
+            position: 0,
+        })
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf option unit unit_struct newtype_struct seq tuple
+        tuple_struct map struct enum identifier ignored_any
+    }
+}
+
+/// Deserializes the value at `index` of `array`.
+struct ValueDeserializer<'a> {
+    array: &'a dyn Array,
+    index: usize,
+}
+
+impl<'a> ValueDeserializer<'a> {
+    fn new(array: &'a dyn Array, index: usize) -> Self {
+        Self { array, index }
+    }
+
+    fn is_null(&self) -> bool {
+        self.array.data_type() == &DataType::Null || self.array.is_null(self.index)
+    }
+
+    /// Returns the deserializer of the value of a dictionary.
+    fn resolve(self) -> Self {
+        match self.array.data_type().to_physical_type() {
+            PhysicalType::Dictionary(_) if self.is_null() => self,
+            PhysicalType::Dictionary(key_type) => match_integer_type!(key_type, |$T| {
+                let array = self.array.as_any().downcast_ref::<DictionaryArray<$T>>().unwrap();
+                Self::new(array.values().as_ref(), array.key_value(self.index))
+            }),
+            _ => self,
+        }
+    }
+
+    fn primitive<T: NativeType>(&self) -> T {
+        let array = self.array.as_any().downcast_ref::<PrimitiveArray<T>>();
+        array.unwrap().value(self.index)
+    }
+
+    /// Visits numbers as their native type, so that dates, times and timestamps are
+    /// deserialized as their integers when numbers are expected.
+    fn deserialize_number<V: Visitor<'a>>(self, visitor: V) -> Result<V::Value> {
+        let value = self.resolve();
+        match value.array.data_type().to_physical_type() {
+            PhysicalType::Primitive(_) if !value.is_null() => value.deserialize_native(visitor),
+            _ => de::Deserializer::deserialize_any(value, visitor),
+        }
+    }
+
+    /// Visits the value of a primitive array as its native type.
+    fn deserialize_native<V: Visitor<'a>>(self, visitor: V) -> Result<V::Value> {
+        use PrimitiveType::*;
+        match self.array.data_type().to_physical_type() {
+            PhysicalType::Primitive(primitive) => match primitive {
+                Int8 => visitor.visit_i8(self.primitive()),
+                Int16 => visitor.visit_i16(self.primitive()),
+                Int32 => visitor.visit_i32(self.primitive()),
+                Int64 => visitor.visit_i64(self.primitive()),
+                Int128 => visitor.visit_i128(self.primitive()),
+                UInt8 => visitor.visit_u8(self.primitive()),
+                UInt16 => visitor.visit_u16(self.primitive()),
+                UInt32 => visitor.visit_u32(self.primitive()),
+                UInt64 => visitor.visit_u64(self.primitive()),
+                Float16 => visitor.visit_f32(self.primitive::<f16>().to_f32()),
+                Float32 => visitor.visit_f32(self.primitive()),
+                Float64 => visitor.visit_f64(self.primitive()),
+                _ => Err(mismatch(self.array.data_type())),
+            },
+            _ => Err(mismatch(self.array.data_type())),
+        }
+    }
+
+    /// Returns dates, times and timestamps formatted as `chrono` deserializes them.
+    fn temporal(&self) -> Result<Option<String>> {
+        use temporal_conversions::*;
+        Ok(Some(match self.array.data_type().to_logical_type() {
+            DataType::Date32 => date32_to_date(self.primitive()).to_string(),
+            DataType::Date64 => date64_to_date(self.primitive()).to_string(),
+            DataType::Time32(TimeUnit::Second) => time32s_to_time(self.primitive()).to_string(),
+            DataType::Time32(_) => time32ms_to_time(self.primitive()).to_string(),
+            DataType::Time64(TimeUnit::Microsecond) => {
+                time64us_to_time(self.primitive()).to_string()
+            }
+            DataType::Time64(_) => time64ns_to_time(self.primitive()).to_string(),
+            DataType::Timestamp(unit, None) => {
+                format!("{:?}", timestamp_to_naive_datetime(self.primitive(), *unit))
+            }
+            DataType::Timestamp(unit, Some(tz)) => {
+                let value = self.primitive();
+                match parse_offset(tz) {
+                    Ok(tz) => timestamp_to_datetime(value, *unit, &tz).to_rfc3339(),
+                    #[cfg(feature = "chrono-tz")]
+                    Err(_) => {
+                        timestamp_to_datetime(value, *unit, &parse_offset_tz(tz)?).to_rfc3339()
+                    }
+                    #[cfg(not(feature = "chrono-tz"))]
+                    Err(_) => {
+                        return Err(Error::InvalidArgumentError(format!(
+                            "Timezone \"{tz}\" requires the feature \"chrono-tz\""
+                        )))
+                    }
+                }
+            }
+            _ => return Ok(None),
+        }))
+    }
+}
+
+fn utf8<O: Offset>(array: &dyn Array, index: usize) -> &str {
+    let array = array.as_any().downcast_ref::<Utf8Array<O>>().unwrap();
+    array.value(index)
+}
+
+fn binary<O: Offset>(array: &dyn Array, index: usize) -> &[u8] {
+    let array = array.as_any().downcast_ref::<BinaryArray<O>>().unwrap();
+    array.value(index)
+}
+
+fn list<O: Offset>(array: &dyn Array, index: usize) -> ListAccess<'_> {
+    let array = array.as_any().downcast_ref::<ListArray<O>>().unwrap();
+    let (start, end) = array.offsets().start_end(index);
+    ListAccess {
+        array: array.values().as_ref(),
+        index: start,
+        end,
+    }
+}
+
+macro_rules! deserialize_number {
+    ($($method:ident)*) => {
+        $(
+            fn $method<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+                self.deserialize_number(visitor)
+            }
+        )*
+    };
+}
+
+impl<'de> de::Deserializer<'de> for ValueDeserializer<'de> {
+    type Error = Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        if self.is_null() {
+            return visitor.visit_none();
+        }
+        if let Some(temporal) = self.temporal()? {
+            return visitor.visit_string(temporal);
+        }
+        use PhysicalType::*;
+        let (array, index) = (self.array, self.index);
+        match array.data_type().to_physical_type() {
+            Boolean => {
+                let array = array.as_any().downcast_ref::<BooleanArray>().unwrap();
+                visitor.visit_bool(array.value(index))
+            }
+            Primitive(_) => self.deserialize_native(visitor),
+            Utf8 => visitor.visit_borrowed_str(utf8::<i32>(array, index)),
+            LargeUtf8 => visitor.visit_borrowed_str(utf8::<i64>(array, index)),
+            Binary => visitor.visit_borrowed_bytes(binary::<i32>(array, index)),
+            LargeBinary => visitor.visit_borrowed_bytes(binary::<i64>(array, index)),
+            FixedSizeBinary => {
+                let array = array.as_any().downcast_ref::<FixedSizeBinaryArray>();
+                visitor.visit_borrowed_bytes(array.unwrap().value(index))
+            }
+            List => visitor.visit_seq(list::<i32>(array, index)),
+            LargeList => visitor.visit_seq(list::<i64>(array, index)),
+            FixedSizeList => {
+                let array = array.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
+                visitor.visit_seq(ListAccess {
+                    array: array.values().as_ref(),
+                    index: index * array.size(),
+                    end: (index + 1) * array.size(),
+                })
+            }
+            Struct => {
+                let array = array.as_any().downcast_ref::<StructArray>().unwrap();
+                visitor.visit_map(StructAccess {
+                    fields: array.fields(),
+                    arrays: array.values(),
+                    index,
+                    position: 0,
+                })
+            }
+            Map => {
+                let array = array.as_any().downcast_ref::<MapArray>().unwrap();
+                let entries = array.field().as_any().downcast_ref::<StructArray>();
+                let entries = entries.ok_or_else(|| mismatch(array.data_type()))?;
+                let (start, end) = array.offsets().start_end(index);
+                visitor.visit_map(MapAccess {
+                    keys: entries.values()[0].as_ref(),
+                    values: entries.values()[1].as_ref(),
+                    index: start,
+                    end,
+                })
+            }
+            Dictionary(_) => self.resolve().deserialize_any(visitor),
+            Null => visitor.visit_unit(),
+            Union => Err(mismatch(array.data_type())),
+        }
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        if self.is_null() {
+            visitor.visit_none()
+        } else {
+            visitor.visit_some(self)
+        }
+    }
+
+    fn deserialize_unit<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        if self.is_null() {
+            visitor.visit_unit()
+        } else {
+            self.deserialize_any(visitor)
+        }
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _: &'static str,
+        visitor: V,
+    ) -> Result<V::Value> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_seq<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        // bytes are deserialized as sequences (e.g. to `Vec<u8>`)
+        let bytes = match self.array.data_type().to_physical_type() {
+            PhysicalType::Binary if !self.is_null() => binary::<i32>(self.array, self.index),
+            PhysicalType::LargeBinary if !self.is_null() => binary::<i64>(self.array, self.index),
+            _ => return self.deserialize_any(visitor),
+        };
+        let mut seq = SeqDeserializer::<_, Error>::new(bytes.iter().copied());
+        let value = visitor.visit_seq(&mut seq)?;
+        seq.end()?;
+        Ok(value)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _: &'static str,
+        _: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value> {
+        // unit variants are deserialized from their names
+        let value = self.resolve();
+        let variant = match value.array.data_type().to_physical_type() {
+            PhysicalType::Utf8 if !value.is_null() => utf8::<i32>(value.array, value.index),
+            PhysicalType::LargeUtf8 if !value.is_null() => utf8::<i64>(value.array, value.index),
+            _ => return value.deserialize_any(visitor),
+        };
+        visitor.visit_enum(BorrowedStrDeserializer::new(variant))
+    }
+
+    deserialize_number! {
+        deserialize_i8 deserialize_i16 deserialize_i32 deserialize_i64 deserialize_i128
+        deserialize_u8 deserialize_u16 deserialize_u32 deserialize_u64
+        deserialize_f32 deserialize_f64
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool u128 char str string bytes byte_buf unit_struct tuple
+        tuple_struct map struct identifier ignored_any
+    }
+}
+
+/// Accesses the items of a list.
+struct ListAccess<'a> {
+    array: &'a dyn Array,
+    index: usize,
+    end: usize,
+}
+
+impl<'de> de::SeqAccess<'de> for ListAccess<'de> {
+    type Error = Error;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(&mut self, seed: T) -> Result<Option<T::Value>> {
+        if self.index == self.end {
+            return Ok(None);
+        }
+        let value = seed.deserialize(ValueDeserializer::new(self.array, self.index))?;
+        self.index += 1;
+        Ok(Some(value))
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.end - self.index)
+    }
+}
+
+/// Accesses the fields of a struct, by their names.
+struct StructAccess<'a, A: AsRef<dyn Array>> {
+    fields: &'a [Field],
+    arrays: &'a [A],
+    index: usize,
+    position: usize,
+}
+
+impl<'de, A: AsRef<dyn Array>> de::MapAccess<'de> for StructAccess<'de, A> {
+    type Error = Error;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(&mut self, seed: K) -> Result<Option<K::Value>> {
+        self.fields
+            .get(self.position)
+            .map(|field| seed.deserialize(BorrowedStrDeserializer::new(field.name.as_str())))
+            .transpose()
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(&mut self, seed: V) -> Result<V::Value> {
+        let array = self.arrays[self.position].as_ref();
+        self.position += 1;
+        seed.deserialize(ValueDeserializer::new(array, self.index))
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.fields.len() - self.position)
+    }
+}
+
+/// Accesses the entries of a map.
+struct MapAccess<'a> {
+    keys: &'a dyn Array,
+    values: &'a dyn Array,
+    index: usize,
+    end: usize,
+}
+
+impl<'de> de::MapAccess<'de> for MapAccess<'de> {
+    type Error = Error;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(&mut self, seed: K) -> Result<Option<K::Value>> {
+        if self.index == self.end {
+            return Ok(None);
+        }
+        seed.deserialize(ValueDeserializer::new(self.keys, self.index))
+            .map(Some)
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(&mut self, seed: V) -> Result<V::Value> {
+        let value = seed.deserialize(ValueDeserializer::new(self.values, self.index))?;
+        self.index += 1;
+        Ok(value)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.end - self.index)
+    }
+}
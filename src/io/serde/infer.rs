@@ -0,0 +1,389 @@
+use serde::ser::{self, Impossible, Serialize};
+
+use crate::datatypes::{DataType, Field, Schema};
+use crate::error::{Error, Result};
+
+const ITEM_NAME: &str = "item";
+
+/// The inferred [`DataType`] of a value and whether it is nullable.
+type Inferred = (DataType, bool);
+
+/// Infers the [`Schema`] of `rows`, whose items must serialize as structs (e.g. Rust
+/// structs deriving `Serialize`).
+///
+/// Every struct field becomes a [`Field`] whose [`DataType`] is inferred from the values of
+/// all rows. Fields are nullable iff they are serialized as options in any row (e.g. `Option`s).
+/// Fields that are always `None` are inferred as [`DataType::Null`].
+/// # Errors
+/// Errors iff
+/// * a row is not serialized as a struct
+/// * a value is not supported (e.g. enums with data)
+/// * the values of a field have different types in different rows
+pub fn infer_schema<T: Serialize>(rows: &[T]) -> Result<Schema> {
+    let (data_type, _) = rows.iter().try_fold((DataType::Null, false), |acc, row| {
+        let inferred = row.serialize(InferSerializer)?;
+        coerce(acc, inferred)
+    })?;
+    match with_nullable_nulls(data_type) {
+        DataType::Struct(fields) => Ok(fields.into()),
+        DataType::Null => Ok(Schema::default()),
+        other => Err(Error::InvalidArgumentError(format!(
+            "Rows must be serialized as structs, but a row was inferred as {other:?}"
+        ))),
+    }
+}
+
+/// Marks every field of [`DataType::Null`] as nullable. The items of empty lists and maps are
+/// inferred as non-nullable nulls, so that they do not make the items of other rows nullable.
+fn with_nullable_nulls(data_type: DataType) -> DataType {
+    let field = |field: Field| {
+        let is_nullable = field.is_nullable || field.data_type == DataType::Null;
+        Field::new(
+            field.name,
+            with_nullable_nulls(field.data_type),
+            is_nullable,
+        )
+    };
+    match data_type {
+        DataType::List(item) => DataType::List(Box::new(field(*item))),
+        DataType::Struct(fields) => DataType::Struct(fields.into_iter().map(field).collect()),
+        DataType::Map(entries, sorted) => DataType::Map(Box::new(field(*entries)), sorted),
+        other => other,
+    }
+}
+
+/// Returns the common type of two inferred values.
+fn coerce(lhs: Inferred, rhs: Inferred) -> Result<Inferred> {
+    use DataType::*;
+    let is_nullable = lhs.1 || rhs.1;
+    let data_type = match (lhs.0, rhs.0) {
+        (Null, other) | (other, Null) => other,
+        (lhs, rhs) if lhs == rhs => lhs,
+        (List(lhs), List(rhs)) => List(Box::new(coerce_field(*lhs, *rhs)?)),
+        (Struct(lhs), Struct(rhs)) => Struct(coerce_fields(lhs, rhs)?),
+        (Map(lhs, sorted), Map(rhs, _)) => Map(Box::new(coerce_field(*lhs, *rhs)?), sorted),
+        (lhs, rhs) => {
+            return Err(Error::InvalidArgumentError(format!(
+                "Values of types {lhs:?} and {rhs:?} cannot be stored in the same array"
+            )))
+        }
+    };
+    Ok((data_type, is_nullable))
+}
+
+fn coerce_field(lhs: Field, rhs: Field) -> Result<Field> {
+    let (data_type, is_nullable) = coerce(
+        (lhs.data_type, lhs.is_nullable),
+        (rhs.data_type, rhs.is_nullable),
+    )?;
+    Ok(Field::new(lhs.name, data_type, is_nullable))
+}
+
+/// Coerces the fields of two structs by name; fields missing from either are nullable.
+fn coerce_fields(mut lhs: Vec<Field>, rhs: Vec<Field>) -> Result<Vec<Field>> {
+    let mut seen = vec![false; lhs.len()];
+    for field in rhs {
+        match lhs.iter().position(|x| x.name == field.name) {
+            Some(index) => {
+                lhs[index] = coerce_field(lhs[index].clone(), field)?;
+                seen[index] = true;
+            }
+            None => lhs.push(Field::new(field.name, field.data_type, true)),
+        }
+    }
+    lhs.iter_mut()
+        .zip(seen)
+        .filter(|(_, seen)| !seen)
+        .for_each(|(field, _)| field.is_nullable = true);
+    Ok(lhs)
+}
+
+fn nyi(what: &str) -> Error {
+    Error::NotYetImplemented(format!("Inferring the DataType of {what} is not supported"))
+}
+
+/// A [`ser::Serializer`] that infers the [`DataType`] of a value.
+struct InferSerializer;
+
+impl ser::Serializer for InferSerializer {
+    type Ok = Inferred;
+    type Error = Error;
+
+    type SerializeSeq = InferList;
+    type SerializeTuple = InferList;
+    type SerializeTupleStruct = InferList;
+    type SerializeTupleVariant = Impossible<Inferred, Error>;
+    type SerializeMap = InferMap;
+    type SerializeStruct = InferStruct;
+    type SerializeStructVariant = Impossible<Inferred, Error>;
+
+    fn serialize_bool(self, _: bool) -> Result<Inferred> {
+        Ok((DataType::Boolean, false))
+    }
+
+    fn serialize_i8(self, _: i8) -> Result<Inferred> {
+        Ok((DataType::Int8, false))
+    }
+
+    fn serialize_i16(self, _: i16) -> Result<Inferred> {
+        Ok((DataType::Int16, false))
+    }
+
+    fn serialize_i32(self, _: i32) -> Result<Inferred> {
+        Ok((DataType::Int32, false))
+    }
+
+    fn serialize_i64(self, _: i64) -> Result<Inferred> {
+        Ok((DataType::Int64, false))
+    }
+
+    fn serialize_u8(self, _: u8) -> Result<Inferred> {
+        Ok((DataType::UInt8, false))
+    }
+
+    fn serialize_u16(self, _: u16) -> Result<Inferred> {
+        Ok((DataType::UInt16, false))
+    }
+
+    fn serialize_u32(self, _: u32) -> Result<Inferred> {
+        Ok((DataType::UInt32, false))
+    }
+
+    fn serialize_u64(self, _: u64) -> Result<Inferred> {
+        Ok((DataType::UInt64, false))
+    }
+
+    fn serialize_f32(self, _: f32) -> Result<Inferred> {
+        Ok((DataType::Float32, false))
+    }
+
+    fn serialize_f64(self, _: f64) -> Result<Inferred> {
+        Ok((DataType::Float64, false))
+    }
+
+    fn serialize_char(self, _: char) -> Result<Inferred> {
+        Ok((DataType::Utf8, false))
+    }
+
+    fn serialize_str(self, _: &str) -> Result<Inferred> {
+        Ok((DataType::Utf8, false))
+    }
+
+    fn serialize_bytes(self, _: &[u8]) -> Result<Inferred> {
+        Ok((DataType::Binary, false))
+    }
+
+    fn serialize_none(self) -> Result<Inferred> {
+        Ok((DataType::Null, true))
+    }
+
+    fn serialize_some<T: ?Sized + Serialize>(self, value: &T) -> Result<Inferred> {
+        let (data_type, _) = value.serialize(self)?;
+        Ok((data_type, true))
+    }
+
+    fn serialize_unit(self) -> Result<Inferred> {
+        Ok((DataType::Null, true))
+    }
+
+    fn serialize_unit_struct(self, _: &'static str) -> Result<Inferred> {
+        Ok((DataType::Null, true))
+    }
+
+    fn serialize_unit_variant(self, _: &'static str, _: u32, _: &'static str) -> Result<Inferred> {
+        Ok((DataType::Utf8, false))
+    }
+
+    fn serialize_newtype_struct<T: ?Sized + Serialize>(
+        self,
+        _: &'static str,
+        value: &T,
+    ) -> Result<Inferred> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: ?Sized + Serialize>(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: &T,
+    ) -> Result<Inferred> {
+        Err(nyi("enum variants with data"))
+    }
+
+    fn serialize_seq(self, _: Option<usize>) -> Result<InferList> {
+        Ok(InferList::default())
+    }
+
+    fn serialize_tuple(self, _: usize) -> Result<InferList> {
+        Ok(InferList::default())
+    }
+
+    fn serialize_tuple_struct(self, _: &'static str, _: usize) -> Result<InferList> {
+        Ok(InferList::default())
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeTupleVariant> {
+        Err(nyi("enum variants with data"))
+    }
+
+    fn serialize_map(self, _: Option<usize>) -> Result<InferMap> {
+        Ok(InferMap::default())
+    }
+
+    fn serialize_struct(self, _: &'static str, len: usize) -> Result<InferStruct> {
+        Ok(InferStruct {
+            fields: Vec::with_capacity(len),
+        })
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeStructVariant> {
+        Err(nyi("enum variants with data"))
+    }
+}
+
+/// Infers a [`DataType::List`] from the common type of its items.
+#[derive(Default)]
+struct InferList {
+    item: Option<Inferred>,
+}
+
+impl InferList {
+    fn push<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<()> {
+        let inferred = value.serialize(InferSerializer)?;
+        self.item = Some(match self.item.take() {
+            Some(item) => coerce(item, inferred)?,
+            None => inferred,
+        });
+        Ok(())
+    }
+
+    fn finish(self) -> Result<Inferred> {
+        let (data_type, is_nullable) = self.item.unwrap_or((DataType::Null, false));
+        let field = Field::new(ITEM_NAME, data_type, is_nullable);
+        Ok((DataType::List(Box::new(field)), false))
+    }
+}
+
+impl ser::SerializeSeq for InferList {
+    type Ok = Inferred;
+    type Error = Error;
+
+    fn serialize_element<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<()> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Inferred> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeTuple for InferList {
+    type Ok = Inferred;
+    type Error = Error;
+
+    fn serialize_element<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<()> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Inferred> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeTupleStruct for InferList {
+    type Ok = Inferred;
+    type Error = Error;
+
+    fn serialize_field<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<()> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Inferred> {
+        self.finish()
+    }
+}
+
+/// Infers a [`DataType::Map`] from the common types of its keys and values.
+#[derive(Default)]
+struct InferMap {
+    key: Option<Inferred>,
+    value: Option<Inferred>,
+}
+
+impl ser::SerializeMap for InferMap {
+    type Ok = Inferred;
+    type Error = Error;
+
+    fn serialize_key<T: ?Sized + Serialize>(&mut self, key: &T) -> Result<()> {
+        let inferred = key.serialize(InferSerializer)?;
+        self.key = Some(match self.key.take() {
+            Some(key) => coerce(key, inferred)?,
+            None => inferred,
+        });
+        Ok(())
+    }
+
+    fn serialize_value<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<()> {
+        let inferred = value.serialize(InferSerializer)?;
+        self.value = Some(match self.value.take() {
+            Some(value) => coerce(value, inferred)?,
+            None => inferred,
+        });
+        Ok(())
+    }
+
+    fn end(self) -> Result<Inferred> {
+        let (key, _) = self.key.unwrap_or((DataType::Null, false));
+        let (value, is_nullable) = self.value.unwrap_or((DataType::Null, false));
+        let entries = DataType::Struct(vec![
+            Field::new("key", key, false),
+            Field::new("value", value, is_nullable),
+        ]);
+        let field = Field::new("entries", entries, false);
+        Ok((DataType::Map(Box::new(field), false), false))
+    }
+}
+
+/// Infers a [`DataType::Struct`] from its fields.
+struct InferStruct {
+    fields: Vec<Field>,
+}
+
+impl ser::SerializeStruct for InferStruct {
+    type Ok = Inferred;
+    type Error = Error;
+
+    fn serialize_field<T: ?Sized + Serialize>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<()> {
+        let (data_type, is_nullable) = value.serialize(InferSerializer)?;
+        self.fields.push(Field::new(key, data_type, is_nullable));
+        Ok(())
+    }
+
+    fn skip_field(&mut self, key: &'static str) -> Result<()> {
+        // skipped fields (e.g. `skip_serializing_if`) are null in this row
+        self.fields.push(Field::new(key, DataType::Null, true));
+        Ok(())
+    }
+
+    fn end(self) -> Result<Inferred> {
+        Ok((DataType::Struct(self.fields), false))
+    }
+}
@@ -0,0 +1,57 @@
+//! APIs to serialize rows of [`serde::Serialize`] values (e.g. Rust structs) to a
+//! [`Chunk`](crate::chunk::Chunk) and to deserialize its rows back to [`serde::Deserialize`] values.
+//!
+//! Every row is serialized as a struct whose fields are pushed to the arrays of a chunk, so
+//! that applications can go from `Vec<MyStruct>` to Arrow without writing builders, and back.
+//! # Example
+//! ```
+//! use arrow2::array::{Int32Array, Utf8Array};
+//! use arrow2::datatypes::DataType;
+//! use arrow2::io::serde::{from_chunk, infer_schema, to_chunk};
+//! use serde_derive::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize, Debug, PartialEq)]
+//! struct Row {
+//!     id: i32,
+//!     name: Option<String>,
+//!     tags: Vec<String>,
+//! }
+//!
+//! let rows = vec![
+//!     Row { id: 1, name: Some("a".to_string()), tags: vec!["x".to_string()] },
+//!     Row { id: 2, name: None, tags: vec![] },
+//! ];
+//! let schema = infer_schema(&rows).unwrap();
+//! assert_eq!(schema.fields[0].data_type(), &DataType::Int32);
+//! assert!(schema.fields[1].is_nullable);
+//!
+//! let chunk = to_chunk(&rows, &schema.fields).unwrap();
+//! assert_eq!(Int32Array::from_slice([1, 2]), chunk.arrays()[0].as_ref());
+//! assert_eq!(Utf8Array::<i32>::from([Some("a"), None]), chunk.arrays()[1].as_ref());
+//! assert_eq!(chunk.arrays()[2].data_type(), schema.fields[2].data_type());
+//!
+//! let result: Vec<Row> = from_chunk(&chunk, &schema.fields).unwrap();
+//! assert_eq!(result, rows);
+//! ```
+
+mod deserialize;
+mod infer;
+mod serialize;
+
+pub use deserialize::{deserialize_rows, from_chunk};
+pub use infer::infer_schema;
+pub use serialize::to_chunk;
+
+use crate::error::Error;
+
+impl serde::ser::Error for Error {
+    fn custom<T: std::fmt::Display>(msg: T) -> Self {
+        Error::ExternalFormat(msg.to_string())
+    }
+}
+
+impl serde::de::Error for Error {
+    fn custom<T: std::fmt::Display>(msg: T) -> Self {
+        Error::ExternalFormat(msg.to_string())
+    }
+}
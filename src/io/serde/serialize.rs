@@ -0,0 +1,541 @@
+use num_traits::NumCast;
+use serde::ser::{self, Impossible, Serialize};
+
+use crate::array::*;
+use crate::chunk::Chunk;
+use crate::datatypes::{DataType, Field, PhysicalType, PrimitiveType};
+use crate::error::{Error, Result};
+use crate::offset::Offset;
+use crate::types::{f16, NativeType};
+
+/// Serializes `rows`, whose items must serialize as structs (e.g. Rust structs deriving
+/// `Serialize`), to a [`Chunk`] with one array per field of `fields`.
+///
+/// The values of every struct field are pushed to the array of the field with the same
+/// name. Struct fields without a corresponding field are ignored and fields missing from a
+/// struct are null. Options are null when `None`, sequences are pushed to lists, maps to
+/// maps and nested structs to struct arrays. Strings can also be pushed to dictionaries of
+/// utf8 values and unit enum variants are pushed as their names.
+///
+/// Use [`infer_schema`](super::infer_schema) to infer `fields` from `rows`.
+/// # Errors
+/// Errors iff
+/// * a row is not serialized as a struct
+/// * a value cannot be pushed to the array of its field (e.g. a string to an [`Int32Array`])
+/// * a number does not fit in the type of its array
+/// * a field has a type without a mutable array (e.g. [`DataType::Union`])
+pub fn to_chunk<T: Serialize>(rows: &[T], fields: &[Field]) -> Result<Chunk<Box<dyn Array>>> {
+    fields
+        .iter()
+        .try_for_each(|field| check_data_type(field.data_type()))?;
+    let data_type = DataType::Struct(fields.to_vec());
+    let mut array = MutableStructArray::new(
+        data_type,
+        fields
+            .iter()
+            .map(|field| new_mutable(field.data_type().clone(), rows.len()))
+            .collect::<Result<_>>()?,
+    );
+
+    for row in rows {
+        row.serialize(ArraySerializer::new(&mut array))?;
+    }
+    if array.validity().is_some() {
+        return Err(Error::InvalidArgumentError(
+            "Rows cannot be serialized as nulls".to_string(),
+        ));
+    }
+
+    let (_, values, _) = array.into_inner();
+    Chunk::try_new(values.into_iter().map(|mut x| x.as_box()).collect())
+}
+
+/// Checks that `data_type` and its children have a mutable array.
+fn check_data_type(data_type: &DataType) -> Result<()> {
+    match data_type.to_logical_type() {
+        DataType::Union(..) => Err(Error::NotYetImplemented(format!(
+            "Serializing to arrays of type {data_type:?} is not supported"
+        ))),
+        DataType::List(field)
+        | DataType::LargeList(field)
+        | DataType::FixedSizeList(field, _)
+        | DataType::Map(field, _) => check_data_type(field.data_type()),
+        DataType::Struct(fields) => fields
+            .iter()
+            .try_for_each(|field| check_data_type(field.data_type())),
+        DataType::Dictionary(_, values, _) => check_data_type(values),
+        _ => Ok(()),
+    }
+}
+
+fn mismatch(what: &str, data_type: &DataType) -> Error {
+    Error::InvalidArgumentError(format!(
+        "Cannot serialize {what} to an array of type {data_type:?}"
+    ))
+}
+
+fn downcast<'a, A: MutableArray + 'static>(
+    array: &'a mut dyn MutableArray,
+    what: &str,
+) -> Result<&'a mut A> {
+    if !array.as_any().is::<A>() {
+        return Err(mismatch(what, array.data_type()));
+    }
+    Ok(array.as_mut_any().downcast_mut::<A>().unwrap())
+}
+
+fn push_number<T, V>(array: &mut dyn MutableArray, value: V) -> Result<()>
+where
+    T: NativeType + NumCast,
+    V: NumCast + std::fmt::Display + Copy,
+{
+    let number = T::from(value).ok_or_else(|| {
+        Error::InvalidArgumentError(format!(
+            "The number {value} does not fit in an array of type {:?}",
+            array.data_type()
+        ))
+    })?;
+    downcast::<MutablePrimitiveArray<T>>(array, "a number")?.push(Some(number));
+    Ok(())
+}
+
+fn push_utf8<O: Offset>(array: &mut dyn MutableArray, value: &str) -> Result<()> {
+    match array.data_type().to_physical_type() {
+        PhysicalType::Dictionary(key_type) => match_integer_type!(key_type, |$T| {
+            downcast::<MutableDictionaryArray<$T, MutableUtf8Array<O>>>(array, "a string")?
+                .try_push(Some(value))
+        }),
+        _ => downcast::<MutableUtf8Array<O>>(array, "a string")?.try_push(Some(value)),
+    }
+}
+
+fn push_binary<O: Offset>(array: &mut dyn MutableArray, value: &[u8]) -> Result<()> {
+    match array.data_type().to_physical_type() {
+        PhysicalType::Dictionary(key_type) => match_integer_type!(key_type, |$T| {
+            downcast::<MutableDictionaryArray<$T, MutableBinaryArray<O>>>(array, "bytes")?
+                .try_push(Some(value))
+        }),
+        _ => downcast::<MutableBinaryArray<O>>(array, "bytes")?.try_push(Some(value)),
+    }
+}
+
+/// A [`ser::Serializer`] that pushes a value to a [`MutableArray`] created by [`new_mutable`].
+pub(super) struct ArraySerializer<'a> {
+    array: &'a mut dyn MutableArray,
+}
+
+impl<'a> ArraySerializer<'a> {
+    pub(super) fn new(array: &'a mut dyn MutableArray) -> Self {
+        Self { array }
+    }
+
+    fn push_integer<V: NumCast + std::fmt::Display + Copy>(self, value: V) -> Result<()> {
+        use PrimitiveType::*;
+        match self.array.data_type().to_physical_type() {
+            PhysicalType::Primitive(primitive) => match primitive {
+                Int8 => push_number::<i8, _>(self.array, value),
+                Int16 => push_number::<i16, _>(self.array, value),
+                Int32 => push_number::<i32, _>(self.array, value),
+                Int64 => push_number::<i64, _>(self.array, value),
+                UInt8 => push_number::<u8, _>(self.array, value),
+                UInt16 => push_number::<u16, _>(self.array, value),
+                UInt32 => push_number::<u32, _>(self.array, value),
+                UInt64 => push_number::<u64, _>(self.array, value),
+                _ => self.push_float(value),
+            },
+            _ => Err(mismatch("an integer", self.array.data_type())),
+        }
+    }
+
+    fn push_float<V: NumCast + std::fmt::Display + Copy>(self, value: V) -> Result<()> {
+        use PrimitiveType::*;
+        match self.array.data_type().to_physical_type() {
+            PhysicalType::Primitive(Float16) => push_number::<f16, _>(self.array, value),
+            PhysicalType::Primitive(Float32) => push_number::<f32, _>(self.array, value),
+            PhysicalType::Primitive(Float64) => push_number::<f64, _>(self.array, value),
+            _ => Err(mismatch("a number", self.array.data_type())),
+        }
+    }
+}
+
+impl<'a> ser::Serializer for ArraySerializer<'a> {
+    type Ok = ();
+    type Error = Error;
+
+    type SerializeSeq = ListSerializer<'a>;
+    type SerializeTuple = ListSerializer<'a>;
+    type SerializeTupleStruct = ListSerializer<'a>;
+    type SerializeTupleVariant = Impossible<(), Error>;
+    type SerializeMap = MapSerializer<'a>;
+    type SerializeStruct = StructSerializer<'a>;
+    type SerializeStructVariant = Impossible<(), Error>;
+
+    fn serialize_bool(self, value: bool) -> Result<()> {
+        downcast::<MutableBooleanArray>(self.array, "a boolean")?.push(Some(value));
+        Ok(())
+    }
+
+    fn serialize_i8(self, value: i8) -> Result<()> {
+        self.push_integer(value)
+    }
+
+    fn serialize_i16(self, value: i16) -> Result<()> {
+        self.push_integer(value)
+    }
+
+    fn serialize_i32(self, value: i32) -> Result<()> {
+        self.push_integer(value)
+    }
+
+    fn serialize_i64(self, value: i64) -> Result<()> {
+        self.push_integer(value)
+    }
+
+    fn serialize_u8(self, value: u8) -> Result<()> {
+        self.push_integer(value)
+    }
+
+    fn serialize_u16(self, value: u16) -> Result<()> {
+        self.push_integer(value)
+    }
+
+    fn serialize_u32(self, value: u32) -> Result<()> {
+        self.push_integer(value)
+    }
+
+    fn serialize_u64(self, value: u64) -> Result<()> {
+        self.push_integer(value)
+    }
+
+    fn serialize_f32(self, value: f32) -> Result<()> {
+        self.push_float(value)
+    }
+
+    fn serialize_f64(self, value: f64) -> Result<()> {
+        self.push_float(value)
+    }
+
+    fn serialize_char(self, value: char) -> Result<()> {
+        self.serialize_str(value.encode_utf8(&mut [0; 4]))
+    }
+
+    fn serialize_str(self, value: &str) -> Result<()> {
+        let data_type = self.array.data_type().clone();
+        let is_large = match data_type.to_physical_type() {
+            PhysicalType::Utf8 => false,
+            PhysicalType::LargeUtf8 => true,
+            PhysicalType::Dictionary(_) => match data_type.to_logical_type() {
+                DataType::Dictionary(_, values, _) => {
+                    values.to_physical_type() == PhysicalType::LargeUtf8
+                }
+                _ => unreachable!(),
+            },
+            _ => return Err(mismatch("a string", &data_type)),
+        };
+        if is_large {
+            push_utf8::<i64>(self.array, value)
+        } else {
+            push_utf8::<i32>(self.array, value)
+        }
+    }
+
+    fn serialize_bytes(self, value: &[u8]) -> Result<()> {
+        let data_type = self.array.data_type().clone();
+        let is_large = match data_type.to_physical_type() {
+            PhysicalType::Binary => false,
+            PhysicalType::LargeBinary => true,
+            PhysicalType::FixedSizeBinary => {
+                return downcast::<MutableFixedSizeBinaryArray>(self.array, "bytes")?
+                    .try_push(Some(value))
+            }
+            PhysicalType::Dictionary(_) => match data_type.to_logical_type() {
+                DataType::Dictionary(_, values, _) => {
+                    values.to_physical_type() == PhysicalType::LargeBinary
+                }
+                _ => unreachable!(),
+            },
+            _ => return Err(mismatch("bytes", &data_type)),
+        };
+        if is_large {
+            push_binary::<i64>(self.array, value)
+        } else {
+            push_binary::<i32>(self.array, value)
+        }
+    }
+
+    fn serialize_none(self) -> Result<()> {
+        self.array.push_null();
+        Ok(())
+    }
+
+    fn serialize_some<T: ?Sized + Serialize>(self, value: &T) -> Result<()> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<()> {
+        self.serialize_none()
+    }
+
+    fn serialize_unit_struct(self, _: &'static str) -> Result<()> {
+        self.serialize_none()
+    }
+
+    fn serialize_unit_variant(self, _: &'static str, _: u32, variant: &'static str) -> Result<()> {
+        self.serialize_str(variant)
+    }
+
+    fn serialize_newtype_struct<T: ?Sized + Serialize>(
+        self,
+        _: &'static str,
+        value: &T,
+    ) -> Result<()> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: ?Sized + Serialize>(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: &T,
+    ) -> Result<()> {
+        Err(mismatch(
+            "an enum variant with data",
+            self.array.data_type(),
+        ))
+    }
+
+    fn serialize_seq(self, _: Option<usize>) -> Result<ListSerializer<'a>> {
+        match self.array.data_type().to_physical_type() {
+            PhysicalType::List | PhysicalType::LargeList | PhysicalType::FixedSizeList => {
+                Ok(ListSerializer {
+                    array: self.array,
+                    len: 0,
+                })
+            }
+            _ => Err(mismatch("a sequence", self.array.data_type())),
+        }
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<ListSerializer<'a>> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_struct(self, _: &'static str, len: usize) -> Result<ListSerializer<'a>> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeTupleVariant> {
+        Err(mismatch(
+            "an enum variant with data",
+            self.array.data_type(),
+        ))
+    }
+
+    fn serialize_map(self, _: Option<usize>) -> Result<MapSerializer<'a>> {
+        Ok(MapSerializer {
+            array: downcast::<MutableMapArray>(self.array, "a map")?,
+        })
+    }
+
+    fn serialize_struct(self, _: &'static str, len: usize) -> Result<StructSerializer<'a>> {
+        Ok(StructSerializer {
+            array: downcast::<MutableStructArray>(self.array, "a struct")?,
+            is_set: vec![false; len],
+        })
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeStructVariant> {
+        Err(mismatch(
+            "an enum variant with data",
+            self.array.data_type(),
+        ))
+    }
+}
+
+/// Pushes the items of a sequence to a list array.
+pub(super) struct ListSerializer<'a> {
+    array: &'a mut dyn MutableArray,
+    len: usize,
+}
+
+impl<'a> ListSerializer<'a> {
+    fn push<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<()> {
+        let values = match self.array.data_type().to_physical_type() {
+            PhysicalType::List => {
+                downcast::<MutableListArray<i32, Box<dyn MutableArray>>>(self.array, "a sequence")?
+                    .mut_values()
+            }
+            PhysicalType::LargeList => {
+                downcast::<MutableListArray<i64, Box<dyn MutableArray>>>(self.array, "a sequence")?
+                    .mut_values()
+            }
+            _ => downcast::<MutableFixedSizeListArray<Box<dyn MutableArray>>>(
+                self.array,
+                "a sequence",
+            )?
+            .mut_values(),
+        };
+        value.serialize(ArraySerializer::new(values.as_mut()))?;
+        self.len += 1;
+        Ok(())
+    }
+
+    fn finish(self) -> Result<()> {
+        match self.array.data_type().to_physical_type() {
+            PhysicalType::List => {
+                downcast::<MutableListArray<i32, Box<dyn MutableArray>>>(self.array, "a sequence")?
+                    .try_push_valid()
+            }
+            PhysicalType::LargeList => {
+                downcast::<MutableListArray<i64, Box<dyn MutableArray>>>(self.array, "a sequence")?
+                    .try_push_valid()
+            }
+            _ => {
+                let list = downcast::<MutableFixedSizeListArray<Box<dyn MutableArray>>>(
+                    self.array,
+                    "a sequence",
+                )?;
+                if self.len != list.size() {
+                    return Err(Error::InvalidArgumentError(format!(
+                        "Cannot serialize a sequence of length {} to a list of size {}",
+                        self.len,
+                        list.size()
+                    )));
+                }
+                list.try_push_valid()
+            }
+        }
+    }
+}
+
+impl<'a> ser::SerializeSeq for ListSerializer<'a> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_element<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<()> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<()> {
+        self.finish()
+    }
+}
+
+impl<'a> ser::SerializeTuple for ListSerializer<'a> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_element<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<()> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<()> {
+        self.finish()
+    }
+}
+
+impl<'a> ser::SerializeTupleStruct for ListSerializer<'a> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_field<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<()> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<()> {
+        self.finish()
+    }
+}
+
+/// Pushes the entries of a map to a map array.
+pub(super) struct MapSerializer<'a> {
+    array: &'a mut MutableMapArray,
+}
+
+impl<'a> ser::SerializeMap for MapSerializer<'a> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_key<T: ?Sized + Serialize>(&mut self, key: &T) -> Result<()> {
+        let keys = &mut self.array.mut_field().mut_values()[0];
+        key.serialize(ArraySerializer::new(keys.as_mut()))
+    }
+
+    fn serialize_value<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<()> {
+        let field = self.array.mut_field();
+        value.serialize(ArraySerializer::new(field.mut_values()[1].as_mut()))?;
+        field.push(true);
+        Ok(())
+    }
+
+    fn end(self) -> Result<()> {
+        self.array.try_push_valid()
+    }
+}
+
+/// Pushes the fields of a struct to the arrays of a struct array with the same name.
+pub(super) struct StructSerializer<'a> {
+    array: &'a mut MutableStructArray,
+    is_set: Vec<bool>,
+}
+
+impl<'a> ser::SerializeStruct for StructSerializer<'a> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_field<T: ?Sized + Serialize>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<()> {
+        let index = StructArray::get_fields(self.array.data_type())
+            .iter()
+            .position(|field| field.name == key);
+        let index = match index {
+            Some(index) => index,
+            // fields that are not part of the struct array are ignored
+            None => return Ok(()),
+        };
+        if index >= self.is_set.len() {
+            self.is_set.resize(index + 1, false);
+        }
+        if std::mem::replace(&mut self.is_set[index], true) {
+            return Err(Error::InvalidArgumentError(format!(
+                "The field \"{key}\" was serialized more than once"
+            )));
+        }
+        let values = &mut self.array.mut_values()[index];
+        value.serialize(ArraySerializer::new(values.as_mut()))
+    }
+
+    fn skip_field(&mut self, _: &'static str) -> Result<()> {
+        // skipped fields (e.g. `skip_serializing_if`) are null, as missing fields
+        Ok(())
+    }
+
+    fn end(mut self) -> Result<()> {
+        let len = self.array.values().len();
+        self.is_set.resize(len, false);
+        self.array
+            .mut_values()
+            .iter_mut()
+            .zip(self.is_set)
+            .filter(|(_, is_set)| !is_set)
+            .for_each(|(values, _)| values.push_null());
+        self.array.push(true);
+        Ok(())
+    }
+}
@@ -0,0 +1,409 @@
+//! Contains [`RangedAsyncReader`], an [`AsyncRead`] + [`AsyncSeek`] adapter over stores that
+//! are read by byte ranges (e.g. object stores such as S3, GCS or Azure blob storage).
+//!
+//! On such stores every read is a network round-trip, which dominates the time of reading
+//! a file when the readers issue one request per small read (e.g. per column chunk or per
+//! IPC message). [`RangedAsyncReader`] mitigates this by
+//! * fetching at least [`RangedReaderOptions::min_request_size`] bytes per request,
+//! * prefetching the byte ranges of a projection (see
+//!   [`parquet::read::column_chunk_ranges`](crate::io::parquet::read::column_chunk_ranges) and
+//!   [`ipc::read::FileMetadata::block_ranges`](crate::io::ipc::read::FileMetadata::block_ranges))
+//!   with [`RangedAsyncReader::prefetch`], which coalesces adjacent ranges and fetches them
+//!   with bounded concurrency, and
+//! * retrying requests that failed with transient errors, with exponential backoff.
+//!
+//! The prefetched bytes are shared between clones of a reader, so that readers that require a
+//! factory of readers (e.g. [`read_columns_many_async`](crate::io::parquet::read::read_columns_many_async))
+//! can clone it.
+use std::collections::BTreeMap;
+use std::io::{Error, ErrorKind, Result, SeekFrom};
+use std::ops::Range;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll};
+use std::time::Duration;
+
+use futures::future::BoxFuture;
+use futures::{AsyncRead, AsyncSeek, FutureExt, StreamExt, TryStreamExt};
+
+/// A function fetching a range of bytes of a store, e.g. via an HTTP range request.
+pub type RangeFetch = Arc<dyn Fn(Range<u64>) -> BoxFuture<'static, Result<Vec<u8>>> + Send + Sync>;
+
+/// A function returning a future that completes after a [`Duration`], e.g. via `tokio::time::sleep`.
+pub type Sleep = Arc<dyn Fn(Duration) -> BoxFuture<'static, ()> + Send + Sync>;
+
+/// Options of a [`RangedAsyncReader`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RangedReaderOptions {
+    /// The maximum number of bytes between two ranges for them to be fetched in a single
+    /// request by [`RangedAsyncReader::prefetch`]. Defaults to 1MiB.
+    pub max_gap: u64,
+    /// The minimum number of bytes fetched by a read of bytes that were not prefetched.
+    /// Defaults to 64KiB.
+    pub min_request_size: u64,
+    /// The maximum number of concurrent requests of [`RangedAsyncReader::prefetch`].
+    /// Defaults to 8.
+    pub max_concurrency: usize,
+    /// The number of times a request that failed with a transient error (e.g.
+    /// [`ErrorKind::TimedOut`]) is retried. Defaults to 3.
+    pub max_retries: usize,
+    /// The time waited before the first retry of a request, doubled on every further retry
+    /// (see [`RangedAsyncReader::with_sleep`]). Defaults to 100ms.
+    pub initial_backoff: Duration,
+}
+
+impl Default for RangedReaderOptions {
+    fn default() -> Self {
+        Self {
+            max_gap: 1 << 20,
+            min_request_size: 1 << 16,
+            max_concurrency: 8,
+            max_retries: 3,
+            initial_backoff: Duration::from_millis(100),
+        }
+    }
+}
+
+/// Returns `ranges` sorted and merged such that ranges that overlap or are at most `max_gap`
+/// bytes apart are a single range. Empty ranges are ignored.
+/// # Example
+/// ```
+/// use arrow2::io::util::coalesce_ranges;
+///
+/// let ranges = coalesce_ranges(&[10..20, 0..5, 22..30, 100..110, 40..40], 2);
+/// assert_eq!(ranges, vec![0..5, 10..30, 100..110]);
+/// ```
+pub fn coalesce_ranges(ranges: &[Range<u64>], max_gap: u64) -> Vec<Range<u64>> {
+    let mut ranges = ranges
+        .iter()
+        .filter(|range| range.start < range.end)
+        .cloned()
+        .collect::<Vec<_>>();
+    ranges.sort_unstable_by_key(|range| range.start);
+
+    let mut coalesced: Vec<Range<u64>> = Vec::with_capacity(ranges.len());
+    for range in ranges {
+        match coalesced.last_mut() {
+            Some(last) if range.start <= last.end.saturating_add(max_gap) => {
+                last.end = last.end.max(range.end)
+            }
+            _ => coalesced.push(range),
+        }
+    }
+    coalesced
+}
+
+/// Whether a request failing with `kind` may succeed when retried
+fn is_transient(kind: ErrorKind) -> bool {
+    matches!(
+        kind,
+        ErrorKind::Interrupted
+            | ErrorKind::TimedOut
+            | ErrorKind::WouldBlock
+            | ErrorKind::ConnectionReset
+            | ErrorKind::ConnectionAborted
+            | ErrorKind::ConnectionRefused
+            | ErrorKind::NotConnected
+            | ErrorKind::BrokenPipe
+            | ErrorKind::UnexpectedEof
+    )
+}
+
+/// Fetches `range` via `fetch`, retrying up to `options.max_retries` times on transient errors
+/// and waiting with `sleep` (if any) an exponentially increasing time before each retry.
+async fn fetch_range(
+    fetch: RangeFetch,
+    range: Range<u64>,
+    options: RangedReaderOptions,
+    sleep: Option<Sleep>,
+    requests: Arc<AtomicUsize>,
+) -> Result<Vec<u8>> {
+    let expected = (range.end - range.start) as usize;
+    let mut retries = 0;
+    loop {
+        requests.fetch_add(1, Ordering::Relaxed);
+        let error = match fetch(range.clone()).await {
+            Ok(bytes) if bytes.len() == expected => return Ok(bytes),
+            Ok(bytes) => Error::new(
+                ErrorKind::UnexpectedEof,
+                format!(
+                    "fetching the range {range:?} returned {} bytes instead of {expected}",
+                    bytes.len()
+                ),
+            ),
+            Err(error) => error,
+        };
+        if retries == options.max_retries || !is_transient(error.kind()) {
+            return Err(error);
+        }
+        if let Some(sleep) = &sleep {
+            let factor = 2u32.saturating_pow(retries as u32);
+            sleep(options.initial_backoff.saturating_mul(factor)).await;
+        }
+        retries += 1;
+    }
+}
+
+type Prefetched = Arc<Mutex<BTreeMap<u64, Arc<Vec<u8>>>>>;
+
+/// Returns the bytes starting at `position` of the entry of `map` containing it, if any.
+fn find(map: &BTreeMap<u64, Arc<Vec<u8>>>, position: u64) -> Option<(u64, Arc<Vec<u8>>)> {
+    map.range(..=position)
+        .rev()
+        .find(|(start, bytes)| position < **start + bytes.len() as u64)
+        .map(|(start, bytes)| (*start, bytes.clone()))
+}
+
+/// An [`AsyncRead`] + [`AsyncSeek`] of a store read by byte ranges via a [`RangeFetch`]
+/// (see the [module documentation](self)).
+/// # Example
+/// ```
+/// use std::sync::Arc;
+///
+/// use futures::{AsyncReadExt, AsyncSeekExt, FutureExt};
+/// use arrow2::io::util::RangedAsyncReader;
+///
+/// # futures::executor::block_on(async {
+/// let data = Arc::new((0..100u8).collect::<Vec<_>>());
+/// let mut reader = RangedAsyncReader::new(data.len() as u64, move |range| {
+///     let data = data.clone();
+///     async move { Ok(data[range.start as usize..range.end as usize].to_vec()) }.boxed()
+/// });
+///
+/// reader.prefetch(&[10..20, 25..30]).await.unwrap();
+/// reader.seek(std::io::SeekFrom::Start(12)).await.unwrap();
+/// let mut buffer = [0; 15];
+/// reader.read_exact(&mut buffer).await.unwrap();
+/// assert_eq!(buffer, [12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26]);
+/// // a single request was necessary
+/// assert_eq!(reader.num_requests(), 1);
+/// # })
+/// ```
+pub struct RangedAsyncReader {
+    fetch: RangeFetch,
+    sleep: Option<Sleep>,
+    length: u64,
+    position: u64,
+    options: RangedReaderOptions,
+    prefetched: Prefetched,
+    requests: Arc<AtomicUsize>,
+    // the bytes of the last read that was not prefetched, and their position
+    buffer: Option<(u64, Arc<Vec<u8>>)>,
+    pending: Option<(u64, BoxFuture<'static, Result<Vec<u8>>>)>,
+}
+
+impl std::fmt::Debug for RangedAsyncReader {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RangedAsyncReader")
+            .field("length", &self.length)
+            .field("position", &self.position)
+            .field("options", &self.options)
+            .field("requests", &self.num_requests())
+            .finish()
+    }
+}
+
+impl Clone for RangedAsyncReader {
+    /// Returns a reader at the same position sharing the prefetched bytes of this reader.
+    fn clone(&self) -> Self {
+        Self {
+            fetch: self.fetch.clone(),
+            sleep: self.sleep.clone(),
+            length: self.length,
+            position: self.position,
+            options: self.options,
+            prefetched: self.prefetched.clone(),
+            requests: self.requests.clone(),
+            buffer: self.buffer.clone(),
+            pending: None,
+        }
+    }
+}
+
+impl RangedAsyncReader {
+    /// Creates a new [`RangedAsyncReader`] of a store of `length` bytes, read by `fetch`.
+    pub fn new<F>(length: u64, fetch: F) -> Self
+    where
+        F: Fn(Range<u64>) -> BoxFuture<'static, Result<Vec<u8>>> + Send + Sync + 'static,
+    {
+        Self {
+            fetch: Arc::new(fetch),
+            sleep: None,
+            length,
+            position: 0,
+            options: Default::default(),
+            prefetched: Default::default(),
+            requests: Default::default(),
+            buffer: None,
+            pending: None,
+        }
+    }
+
+    /// Sets the [`RangedReaderOptions`] of this reader.
+    pub fn with_options(mut self, options: RangedReaderOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Sets the function used to wait between the retries of a request, e.g.
+    /// `|duration| tokio::time::sleep(duration).boxed()`.
+    /// Without it, requests are retried immediately.
+    pub fn with_sleep<S>(mut self, sleep: S) -> Self
+    where
+        S: Fn(Duration) -> BoxFuture<'static, ()> + Send + Sync + 'static,
+    {
+        self.sleep = Some(Arc::new(sleep));
+        self
+    }
+
+    /// The options of this reader
+    pub fn options(&self) -> &RangedReaderOptions {
+        &self.options
+    }
+
+    /// The length in bytes of the store
+    pub fn len(&self) -> u64 {
+        self.length
+    }
+
+    /// Whether the store is empty
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
+    /// The number of requests issued (including retries) by this reader and its clones
+    pub fn num_requests(&self) -> usize {
+        self.requests.load(Ordering::Relaxed)
+    }
+
+    /// Fetches `ranges` so that subsequent reads of them, by this reader or its clones, do
+    /// not issue requests. Ranges are coalesced by [`RangedReaderOptions::max_gap`] and
+    /// fetched with up to [`RangedReaderOptions::max_concurrency`] concurrent requests.
+    /// Ranges that were already prefetched are not fetched again.
+    /// # Errors
+    /// Errors iff a request fails with a non-transient error, or after
+    /// [`RangedReaderOptions::max_retries`] retries.
+    pub async fn prefetch(&self, ranges: &[Range<u64>]) -> Result<()> {
+        let ranges = ranges
+            .iter()
+            .map(|range| range.start.min(self.length)..range.end.min(self.length))
+            .collect::<Vec<_>>();
+        let ranges = {
+            let prefetched = self.prefetched.lock().unwrap();
+            coalesce_ranges(&ranges, self.options.max_gap)
+                .into_iter()
+                .filter(|range| match find(&prefetched, range.start) {
+                    Some((start, bytes)) => start + (bytes.len() as u64) < range.end,
+                    None => true,
+                })
+                .collect::<Vec<_>>()
+        };
+
+        let fetched = futures::stream::iter(ranges)
+            .map(|range| {
+                let start = range.start;
+                fetch_range(
+                    self.fetch.clone(),
+                    range,
+                    self.options,
+                    self.sleep.clone(),
+                    self.requests.clone(),
+                )
+                .map(move |bytes| bytes.map(|bytes| (start, bytes)))
+            })
+            .buffer_unordered(self.options.max_concurrency.max(1))
+            .try_collect::<Vec<_>>()
+            .await?;
+
+        let mut prefetched = self.prefetched.lock().unwrap();
+        for (start, bytes) in fetched {
+            prefetched.insert(start, Arc::new(bytes));
+        }
+        Ok(())
+    }
+
+    /// Drops the prefetched bytes of this reader and its clones.
+    pub fn clear_prefetched(&self) {
+        self.prefetched.lock().unwrap().clear();
+    }
+
+    /// Returns the bytes starting at the current position, if they were fetched.
+    fn fetched(&self) -> Option<(u64, Arc<Vec<u8>>)> {
+        if let Some((start, bytes)) = &self.buffer {
+            if (*start..*start + bytes.len() as u64).contains(&self.position) {
+                return Some((*start, bytes.clone()));
+            }
+        }
+        find(&self.prefetched.lock().unwrap(), self.position)
+    }
+}
+
+impl AsyncRead for RangedAsyncReader {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<Result<usize>> {
+        if buf.is_empty() || self.position >= self.length {
+            return Poll::Ready(Ok(0));
+        }
+        loop {
+            if let Some((start, bytes)) = self.fetched() {
+                let offset = (self.position - start) as usize;
+                let length = buf.len().min(bytes.len() - offset);
+                buf[..length].copy_from_slice(&bytes[offset..offset + length]);
+                self.position += length as u64;
+                return Poll::Ready(Ok(length));
+            }
+
+            let this = &mut *self;
+            let (start, pending) = this.pending.get_or_insert_with(|| {
+                let requested = (buf.len() as u64).max(this.options.min_request_size);
+                let range = this.position..this.position.saturating_add(requested).min(this.length);
+                let pending = fetch_range(
+                    this.fetch.clone(),
+                    range,
+                    this.options,
+                    this.sleep.clone(),
+                    this.requests.clone(),
+                )
+                .boxed();
+                (this.position, pending)
+            });
+            let start = *start;
+            let bytes = match pending.poll_unpin(cx) {
+                Poll::Ready(bytes) => bytes,
+                Poll::Pending => return Poll::Pending,
+            };
+            this.pending = None;
+            this.buffer = Some((start, Arc::new(bytes?)));
+        }
+    }
+}
+
+impl AsyncSeek for RangedAsyncReader {
+    fn poll_seek(
+        mut self: Pin<&mut Self>,
+        _: &mut Context<'_>,
+        position: SeekFrom,
+    ) -> Poll<Result<u64>> {
+        let position = match position {
+            SeekFrom::Start(position) => position as i128,
+            SeekFrom::End(offset) => self.length as i128 + offset as i128,
+            SeekFrom::Current(offset) => self.position as i128 + offset as i128,
+        };
+        let position = u64::try_from(position).map_err(|_| {
+            Error::new(
+                ErrorKind::InvalidInput,
+                "invalid seek to a negative or overflowing position",
+            )
+        })?;
+        if self.position != position {
+            // a pending request is for the previous position
+            self.pending = None;
+        }
+        self.position = position;
+        Poll::Ready(Ok(position))
+    }
+}
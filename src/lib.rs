@@ -13,6 +13,10 @@
 #![cfg_attr(docsrs, feature(doc_cfg))]
 #![cfg_attr(feature = "simd", feature(portable_simd))]
 #![cfg_attr(feature = "nightly_build", feature(build_hasher_simple_hash_one))]
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[macro_use]
+extern crate alloc;
 
 #[macro_use]
 pub mod array;
@@ -25,6 +29,7 @@ pub mod error;
 pub mod mmap;
 
 pub mod offset;
+pub mod record_batch;
 pub mod scalar;
 pub mod trusted_len;
 pub mod types;
@@ -35,6 +40,8 @@ pub mod temporal_conversions;
 
 pub mod datatypes;
 
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
 pub mod ffi;
 pub mod util;
 
@@ -46,4 +53,5 @@ mod docs;
 pub use either::Either;
 
 // re-exported to construct dictionaries
+#[cfg(feature = "std")]
 pub use ahash::AHashMap;
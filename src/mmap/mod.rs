@@ -6,15 +6,17 @@ use crate::array::Array;
 use crate::chunk::Chunk;
 use crate::datatypes::{DataType, Field};
 use crate::error::Error;
-use crate::ffi::mmap;
+use crate::ffi::mmap::{self, Unaligned};
 
+use crate::io::ipc::checksum::verify_block_bytes;
+use crate::io::ipc::framed::{header, read_frames, FrameReader};
 use crate::io::ipc::read::file::{get_dictionary_batch, get_record_batch};
 use crate::io::ipc::read::{first_dict_field, Dictionaries, FileMetadata};
 use crate::io::ipc::read::{IpcBuffer, Node, OutOfSpecKind};
 use crate::io::ipc::{IpcField, CONTINUATION_MARKER};
 
 use arrow_format::ipc::planus::ReadAsRoot;
-use arrow_format::ipc::{Block, MessageRef, RecordBatchRef};
+use arrow_format::ipc::{Block, DictionaryBatchRef, MessageHeaderRef, MessageRef, RecordBatchRef};
 
 fn read_message(
     mut bytes: &[u8],
@@ -82,6 +84,7 @@ unsafe fn _mmap_record<T: AsRef<[u8]>>(
     batch: RecordBatchRef,
     offset: usize,
     dictionaries: &Dictionaries,
+    unaligned: &mut Unaligned,
 ) -> Result<Chunk<Box<dyn Array>>, Error> {
     let (mut buffers, mut field_nodes) = get_buffers_nodes(batch)?;
 
@@ -99,6 +102,7 @@ unsafe fn _mmap_record<T: AsRef<[u8]>>(
                 dictionaries,
                 &mut field_nodes,
                 &mut buffers,
+                unaligned,
             )
         })
         .collect::<Result<_, Error>>()
@@ -121,15 +125,19 @@ unsafe fn _mmap_unchecked<T: AsRef<[u8]>>(
         batch,
         offset,
         dictionaries,
+        &mut Unaligned::error(),
     )
 }
 
 /// Memory maps an record batch from an IPC file into a [`Chunk`].
+///
+/// When the file has checksums, the block of the record batch is read to verify its checksum.
 /// # Errors
 /// This function errors when:
 /// * The IPC file is not valid
+/// * the checksum of the record batch's block differs from the one in the file's footer
 /// * the buffers on the file are un-aligned with their corresponding data. This can happen when:
-///     * the file was written with 8-bit alignment
+///     * the file was written with 8-byte alignment (see [`WriteOptions::align_to_64`](crate::io::ipc::write::WriteOptions::align_to_64))
 ///     * the file contains type decimal 128 or 256
 /// # Safety
 /// The caller must ensure that `data` contains a valid buffers, for example:
@@ -140,9 +148,64 @@ pub unsafe fn mmap_unchecked<T: AsRef<[u8]>>(
     dictionaries: &Dictionaries,
     data: Arc<T>,
     chunk: usize,
+) -> Result<Chunk<Box<dyn Array>>, Error> {
+    mmap_chunk(metadata, dictionaries, data, chunk, &mut Unaligned::error())
+}
+
+/// A buffer of an IPC file that was copied when memory mapping it, because it was not aligned
+/// with its type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CopiedBuffer {
+    /// The offset of the buffer in the memory mapped region
+    pub offset: usize,
+    /// The length of the buffer in bytes
+    pub length: usize,
+    /// The alignment required by the type of the buffer
+    pub alignment: usize,
+}
+
+/// Memory maps an record batch from an IPC file into a [`Chunk`], copying the buffers that
+/// are not aligned with their type instead of erroring.
+///
+/// This is a fallback of [`mmap_unchecked`] for files that are only partially aligned, e.g.
+/// written with 8-byte alignment but containing decimals: the aligned buffers are still
+/// memory mapped, and the returned [`CopiedBuffer`]s report the ones that were copied.
+/// # Errors
+/// This function errors when:
+/// * The IPC file is not valid
+/// * the checksum of the record batch's block differs from the one in the file's footer
+/// # Safety
+/// The caller must ensure that `data` contains a valid buffers, for example:
+/// * Offsets in variable-sized containers must be in-bounds and increasing
+/// * Utf8 data is valid
+pub unsafe fn mmap_unchecked_copy_unaligned<T: AsRef<[u8]>>(
+    metadata: &FileMetadata,
+    dictionaries: &Dictionaries,
+    data: Arc<T>,
+    chunk: usize,
+) -> Result<(Chunk<Box<dyn Array>>, Vec<CopiedBuffer>), Error> {
+    let mut unaligned = Unaligned::copy();
+    let chunk = mmap_chunk(metadata, dictionaries, data, chunk, &mut unaligned)?;
+    Ok((chunk, unaligned.into_copied()))
+}
+
+unsafe fn mmap_chunk<T: AsRef<[u8]>>(
+    metadata: &FileMetadata,
+    dictionaries: &Dictionaries,
+    data: Arc<T>,
+    chunk: usize,
+    unaligned: &mut Unaligned,
 ) -> Result<Chunk<Box<dyn Array>>, Error> {
     let block = metadata.blocks[chunk];
 
+    if let Some(checksums) = &metadata.checksums {
+        verify_block_bytes(
+            data.as_ref().as_ref(),
+            &block,
+            checksums.record_batches[chunk],
+        )?;
+    }
+
     let (message, offset) = read_message(data.as_ref().as_ref(), block)?;
     let batch = get_record_batch(message)?;
     _mmap_record(
@@ -152,23 +215,64 @@ pub unsafe fn mmap_unchecked<T: AsRef<[u8]>>(
         batch,
         offset,
         dictionaries,
+        unaligned,
     )
 }
 
+/// Returns the number of rows of the record batch `chunk` of an IPC file in `data`.
+///
+/// Only the message of the record batch is read, so that this can be used to select the
+/// batches to memory map with [`mmap_unchecked`] (e.g. the ones overlapping a range of rows).
+/// # Panics
+/// This function panics iff `chunk >= metadata.blocks.len()`
+pub fn mmap_batch_length<T: AsRef<[u8]>>(
+    metadata: &FileMetadata,
+    data: &T,
+    chunk: usize,
+) -> Result<usize, Error> {
+    let (message, _) = read_message(data.as_ref(), metadata.blocks[chunk])?;
+    let batch = get_record_batch(message)?;
+    batch
+        .length()?
+        .try_into()
+        .map_err(|_| Error::from(OutOfSpecKind::UnexpectedNegativeInteger))
+}
+
 unsafe fn mmap_dictionary<T: AsRef<[u8]>>(
     metadata: &FileMetadata,
     data: Arc<T>,
     block: Block,
     dictionaries: &mut Dictionaries,
+    unaligned: &mut Unaligned,
 ) -> Result<(), Error> {
     let (message, offset) = read_message(data.as_ref().as_ref(), block)?;
     let batch = get_dictionary_batch(&message)?;
+    _mmap_dictionary(
+        &metadata.schema.fields,
+        &metadata.ipc_schema.fields,
+        data.clone(),
+        batch,
+        offset,
+        dictionaries,
+        unaligned,
+    )
+}
 
+/// Memory maps the dictionary `batch`, whose body starts at `offset` of `data`,
+/// into `dictionaries`.
+unsafe fn _mmap_dictionary<T: AsRef<[u8]>>(
+    fields: &[Field],
+    ipc_fields: &[IpcField],
+    data: Arc<T>,
+    batch: DictionaryBatchRef,
+    offset: usize,
+    dictionaries: &mut Dictionaries,
+    unaligned: &mut Unaligned,
+) -> Result<(), Error> {
     let id = batch
         .id()
         .map_err(|err| Error::from(OutOfSpecKind::InvalidFlatbufferId(err)))?;
-    let (first_field, first_ipc_field) =
-        first_dict_field(id, &metadata.schema.fields, &metadata.ipc_schema.fields)?;
+    let (first_field, first_ipc_field) = first_dict_field(id, fields, ipc_fields)?;
 
     let batch = batch
         .data()
@@ -194,6 +298,7 @@ unsafe fn mmap_dictionary<T: AsRef<[u8]>>(
         batch,
         offset,
         dictionaries,
+        unaligned,
     )?;
 
     dictionaries.insert(id, chunk.into_arrays().pop().unwrap());
@@ -202,6 +307,8 @@ unsafe fn mmap_dictionary<T: AsRef<[u8]>>(
 }
 
 /// Memory maps dictionaries from an IPC file into
+///
+/// When the file has checksums, the blocks of the dictionaries are read to verify their checksums.
 /// # Safety
 /// The caller must ensure that `data` contains a valid buffers, for example:
 /// * Offsets in variable-sized containers must be in-bounds and increasing
@@ -209,6 +316,29 @@ unsafe fn mmap_dictionary<T: AsRef<[u8]>>(
 pub unsafe fn mmap_dictionaries_unchecked<T: AsRef<[u8]>>(
     metadata: &FileMetadata,
     data: Arc<T>,
+) -> Result<Dictionaries, Error> {
+    mmap_dictionaries(metadata, data, &mut Unaligned::error())
+}
+
+/// Memory maps dictionaries from an IPC file, copying the buffers that are not aligned with
+/// their type instead of erroring. See [`mmap_unchecked_copy_unaligned`] for details.
+/// # Safety
+/// The caller must ensure that `data` contains a valid buffers, for example:
+/// * Offsets in variable-sized containers must be in-bounds and increasing
+/// * Utf8 data is valid
+pub unsafe fn mmap_dictionaries_unchecked_copy_unaligned<T: AsRef<[u8]>>(
+    metadata: &FileMetadata,
+    data: Arc<T>,
+) -> Result<(Dictionaries, Vec<CopiedBuffer>), Error> {
+    let mut unaligned = Unaligned::copy();
+    let dictionaries = mmap_dictionaries(metadata, data, &mut unaligned)?;
+    Ok((dictionaries, unaligned.into_copied()))
+}
+
+unsafe fn mmap_dictionaries<T: AsRef<[u8]>>(
+    metadata: &FileMetadata,
+    data: Arc<T>,
+    unaligned: &mut Unaligned,
 ) -> Result<Dictionaries, Error> {
     let blocks = if let Some(blocks) = &metadata.dictionaries {
         blocks
@@ -218,9 +348,56 @@ pub unsafe fn mmap_dictionaries_unchecked<T: AsRef<[u8]>>(
 
     let mut dictionaries = Default::default();
 
-    blocks
-        .iter()
-        .cloned()
-        .try_for_each(|block| mmap_dictionary(metadata, data.clone(), block, &mut dictionaries))?;
+    blocks.iter().enumerate().try_for_each(|(index, block)| {
+        if let Some(checksums) = &metadata.checksums {
+            verify_block_bytes(data.as_ref().as_ref(), block, checksums.dictionaries[index])?;
+        }
+        mmap_dictionary(metadata, data.clone(), *block, &mut dictionaries, unaligned)
+    })?;
     Ok(dictionaries)
 }
+
+/// Memory maps the frames in `data`, written by
+/// [`write_chunk_frames`](crate::io::ipc::framed::write_chunk_frames), into [`Chunk`]s.
+///
+/// Like [`FrameReader::read`], dictionary messages update the dictionaries of `reader`, but
+/// the arrays of the returned [`Chunk`]s share the buffers of `data` instead of copying them.
+/// # Errors
+/// This function errors when:
+/// * `data` is not a sequence of valid frames of record batch and dictionary messages
+/// * the messages are compressed
+/// * the buffers in `data` are un-aligned with their corresponding data
+/// # Safety
+/// The caller must ensure that `data` contains a valid buffers, for example:
+/// * Offsets in variable-sized containers must be in-bounds and increasing
+/// * Utf8 data is valid
+pub unsafe fn mmap_frames_unchecked<T: AsRef<[u8]>>(
+    reader: &mut FrameReader,
+    data: Arc<T>,
+) -> Result<Vec<Chunk<Box<dyn Array>>>, Error> {
+    let mut chunks = vec![];
+    for frame in read_frames(data.as_ref().as_ref())? {
+        match header(&frame.message)? {
+            MessageHeaderRef::RecordBatch(batch) => chunks.push(_mmap_record(
+                &reader.schema.fields,
+                &reader.ipc_schema.fields,
+                data.clone(),
+                batch,
+                frame.body_offset,
+                &reader.dictionaries,
+                &mut Unaligned::error(),
+            )?),
+            MessageHeaderRef::DictionaryBatch(batch) => _mmap_dictionary(
+                &reader.schema.fields,
+                &reader.ipc_schema.fields,
+                data.clone(),
+                batch,
+                frame.body_offset,
+                &mut reader.dictionaries,
+                &mut Unaligned::error(),
+            )?,
+            _ => return Err(Error::from(OutOfSpecKind::UnexpectedMessageType)),
+        }
+    }
+    Ok(chunks)
+}
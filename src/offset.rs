@@ -1,5 +1,6 @@
 //! Contains the declaration of [`Offset`]
-use std::hint::unreachable_unchecked;
+use alloc::vec::Vec;
+use core::hint::unreachable_unchecked;
 
 use crate::buffer::Buffer;
 use crate::error::Error;
@@ -368,7 +369,7 @@ impl<O: Offset> OffsetsBuffer<O> {
             .get_mut()
             .map(|x| {
                 let mut new = vec![O::zero()];
-                std::mem::swap(x, &mut new);
+                core::mem::swap(x, &mut new);
                 new
             })
             // Safety: Offsets and OffsetsBuffer share invariants
@@ -0,0 +1,185 @@
+//! Contains [`RecordBatch`], a [`Chunk`] of arrays paired with the [`Schema`] describing them.
+
+use alloc::{boxed::Box, string::ToString, sync::Arc};
+
+use crate::array::Array;
+use crate::chunk::Chunk;
+use crate::datatypes::Schema;
+use crate::error::{Error, Result};
+
+/// A [`Chunk`] whose arrays are described by the fields of a [`Schema`].
+///
+/// Every array of a [`RecordBatch`] has the length of the chunk and the data type of its field,
+/// and only arrays of nullable fields have nulls.
+/// # Example
+/// ```
+/// use std::sync::Arc;
+/// use arrow2::array::{Array, Int32Array, Utf8Array};
+/// use arrow2::chunk::Chunk;
+/// use arrow2::datatypes::{DataType, Field, Schema};
+/// use arrow2::record_batch::RecordBatch;
+///
+/// let schema = Schema::from(vec![
+///     Field::new("id", DataType::Int32, false),
+///     Field::new("name", DataType::Utf8, true),
+/// ]);
+/// let chunk = Chunk::new(vec![
+///     Int32Array::from_slice([1, 2]).boxed(),
+///     Utf8Array::<i32>::from([Some("a"), None]).boxed(),
+/// ]);
+/// let batch = RecordBatch::try_new(Arc::new(schema), chunk).unwrap();
+///
+/// assert_eq!(batch.len(), 2);
+/// let name = batch.column_by_name("name").unwrap();
+/// assert_eq!(name.null_count(), 1);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecordBatch<A: AsRef<dyn Array> = Box<dyn Array>> {
+    schema: Arc<Schema>,
+    chunk: Chunk<A>,
+}
+
+impl<A: AsRef<dyn Array>> RecordBatch<A> {
+    /// Creates a new [`RecordBatch`].
+    /// # Panic
+    /// Iff the arrays of `chunk` are not valid against `schema` (see [`RecordBatch::try_new`]).
+    pub fn new(schema: Arc<Schema>, chunk: Chunk<A>) -> Self {
+        Self::try_new(schema, chunk).unwrap()
+    }
+
+    /// Creates a new [`RecordBatch`].
+    /// # Error
+    /// Iff the arrays of `chunk` are not valid against `schema`, i.e. iff any of
+    /// * the number of arrays differs from the number of fields
+    /// * an array does not have the data type of its field
+    /// * an array has nulls but its field is not nullable
+    pub fn try_new(schema: Arc<Schema>, chunk: Chunk<A>) -> Result<Self> {
+        let chunk = Chunk::try_new_with_schema(&schema, chunk.into_arrays())?;
+        Ok(Self { schema, chunk })
+    }
+
+    /// Returns the [`Schema`] of this [`RecordBatch`].
+    pub fn schema(&self) -> &Arc<Schema> {
+        &self.schema
+    }
+
+    /// Returns the [`Chunk`] of this [`RecordBatch`].
+    pub fn chunk(&self) -> &Chunk<A> {
+        &self.chunk
+    }
+
+    /// Returns the arrays of this [`RecordBatch`], in the order of the fields of its schema.
+    pub fn columns(&self) -> &[A] {
+        self.chunk.arrays()
+    }
+
+    /// Returns the number of rows of every array.
+    pub fn len(&self) -> usize {
+        self.chunk.len()
+    }
+
+    /// Returns whether the arrays have any rows.
+    pub fn is_empty(&self) -> bool {
+        self.chunk.is_empty()
+    }
+
+    /// Returns the position of the first field named `name`, if any.
+    pub fn index_of(&self, name: &str) -> Option<usize> {
+        self.schema
+            .fields
+            .iter()
+            .position(|field| field.name == name)
+    }
+
+    /// Returns the array of the first field named `name`, if any.
+    pub fn column_by_name(&self, name: &str) -> Option<&A> {
+        self.index_of(name).map(|index| &self.chunk.arrays()[index])
+    }
+
+    /// Returns a new [`RecordBatch`] with the fields and arrays at `indices`, in that order.
+    /// # Error
+    /// Iff an index is out of bounds.
+    pub fn project(&self, indices: &[usize]) -> Result<Self>
+    where
+        A: Clone,
+    {
+        let fields = self.schema.fields.len();
+        if let Some(index) = indices.iter().find(|index| **index >= fields) {
+            return Err(Error::InvalidArgumentError(format!(
+                "Projected index {index} is out of bounds of a record batch with {fields} fields"
+            )));
+        }
+
+        let schema = Schema {
+            fields: indices
+                .iter()
+                .map(|index| self.schema.fields[*index].clone())
+                .collect(),
+            metadata: self.schema.metadata.clone(),
+        };
+        let arrays = indices
+            .iter()
+            .map(|index| self.chunk.arrays()[*index].clone())
+            .collect();
+        Ok(Self {
+            schema: Arc::new(schema),
+            chunk: Chunk::new(arrays),
+        })
+    }
+
+    /// Returns this [`RecordBatch`] with the first field named `from` renamed to `to`.
+    /// # Error
+    /// Iff there is no field named `from`.
+    pub fn rename(self, from: &str, to: &str) -> Result<Self> {
+        let index = self.index_of(from).ok_or_else(|| {
+            Error::InvalidArgumentError(format!("The record batch has no field named \"{from}\""))
+        })?;
+
+        let mut schema = Arc::try_unwrap(self.schema).unwrap_or_else(|schema| (*schema).clone());
+        schema.fields[index].name = to.to_string();
+        Ok(Self {
+            schema: Arc::new(schema),
+            chunk: self.chunk,
+        })
+    }
+
+    /// Consumes this [`RecordBatch`] into its [`Schema`] and [`Chunk`].
+    pub fn into_parts(self) -> (Arc<Schema>, Chunk<A>) {
+        (self.schema, self.chunk)
+    }
+}
+
+impl<A: AsRef<dyn Array>> From<RecordBatch<A>> for Chunk<A> {
+    fn from(batch: RecordBatch<A>) -> Self {
+        batch.chunk
+    }
+}
+
+/// Returns an iterator of [`RecordBatch`]es of `schema` from an iterator of [`Chunk`]s, such
+/// as the readers of [`crate::io`].
+/// # Example
+/// ```
+/// use std::sync::Arc;
+/// use arrow2::array::{Array, Int32Array};
+/// use arrow2::chunk::Chunk;
+/// use arrow2::datatypes::{DataType, Field, Schema};
+/// use arrow2::record_batch::with_schema;
+///
+/// let schema = Arc::new(Schema::from(vec![Field::new("a", DataType::Int32, false)]));
+/// let chunks = vec![Ok(Chunk::new(vec![Int32Array::from_slice([1]).boxed()]))];
+///
+/// let batches = with_schema(schema, chunks).collect::<Result<Vec<_>, _>>().unwrap();
+/// assert_eq!(batches[0].column_by_name("a").unwrap().len(), 1);
+/// ```
+pub fn with_schema<A, I>(
+    schema: Arc<Schema>,
+    chunks: I,
+) -> impl Iterator<Item = Result<RecordBatch<A>>>
+where
+    A: AsRef<dyn Array>,
+    I: IntoIterator<Item = Result<Chunk<A>>>,
+{
+    chunks
+        .into_iter()
+        .map(move |chunk| RecordBatch::try_new(schema.clone(), chunk?))
+}
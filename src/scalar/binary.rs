@@ -1,3 +1,5 @@
+use alloc::vec::Vec;
+
 use crate::{datatypes::DataType, offset::Offset};
 
 use super::Scalar;
@@ -6,7 +8,7 @@ use super::Scalar;
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct BinaryScalar<O: Offset> {
     value: Option<Vec<u8>>,
-    phantom: std::marker::PhantomData<O>,
+    phantom: core::marker::PhantomData<O>,
 }
 
 impl<O: Offset> BinaryScalar<O> {
@@ -15,7 +17,7 @@ impl<O: Offset> BinaryScalar<O> {
     pub fn new<P: Into<Vec<u8>>>(value: Option<P>) -> Self {
         Self {
             value: value.map(|x| x.into()),
-            phantom: std::marker::PhantomData,
+            phantom: core::marker::PhantomData,
         }
     }
 
@@ -35,7 +37,7 @@ impl<O: Offset, P: Into<Vec<u8>>> From<Option<P>> for BinaryScalar<O> {
 
 impl<O: Offset> Scalar for BinaryScalar<O> {
     #[inline]
-    fn as_any(&self) -> &dyn std::any::Any {
+    fn as_any(&self) -> &dyn core::any::Any {
         self
     }
 
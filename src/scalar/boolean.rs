@@ -24,7 +24,7 @@ impl BooleanScalar {
 
 impl Scalar for BooleanScalar {
     #[inline]
-    fn as_any(&self) -> &dyn std::any::Any {
+    fn as_any(&self) -> &dyn core::any::Any {
         self
     }
 
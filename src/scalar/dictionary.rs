@@ -1,4 +1,5 @@
-use std::any::Any;
+use alloc::boxed::Box;
+use core::any::Any;
 
 use crate::{array::*, datatypes::DataType};
 
@@ -8,7 +9,7 @@ use super::Scalar;
 #[derive(Debug, Clone)]
 pub struct DictionaryScalar<K: DictionaryKey> {
     value: Option<Box<dyn Scalar>>,
-    phantom: std::marker::PhantomData<K>,
+    phantom: core::marker::PhantomData<K>,
     data_type: DataType,
 }
 
@@ -28,7 +29,7 @@ impl<K: DictionaryKey> DictionaryScalar<K> {
     pub fn new(data_type: DataType, value: Option<Box<dyn Scalar>>) -> Self {
         Self {
             value,
-            phantom: std::marker::PhantomData,
+            phantom: core::marker::PhantomData,
             data_type,
         }
     }
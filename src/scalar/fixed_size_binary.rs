@@ -1,3 +1,5 @@
+use alloc::{boxed::Box, vec::Vec};
+
 use crate::datatypes::DataType;
 
 use super::Scalar;
@@ -43,7 +45,7 @@ impl FixedSizeBinaryScalar {
 
 impl Scalar for FixedSizeBinaryScalar {
     #[inline]
-    fn as_any(&self) -> &dyn std::any::Any {
+    fn as_any(&self) -> &dyn core::any::Any {
         self
     }
 
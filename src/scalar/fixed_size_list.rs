@@ -1,4 +1,5 @@
-use std::any::Any;
+use alloc::boxed::Box;
+use core::any::Any;
 
 use crate::{array::*, datatypes::DataType};
 
@@ -0,0 +1,238 @@
+use alloc::{boxed::Box, vec};
+use core::fmt::{Display, Formatter, Result};
+
+use crate::{
+    array::*,
+    bitmap::Bitmap,
+    datatypes::{DataType, PhysicalType},
+    offset::{Offset, OffsetsBuffer},
+    types::NativeType,
+};
+
+use super::*;
+
+/// The validity of an array of length 1 whose only slot is `is_valid`
+fn validity(is_valid: bool) -> Option<Bitmap> {
+    (!is_valid).then(|| Bitmap::new_zeroed(1))
+}
+
+fn primitive_to_array<T: NativeType>(scalar: &dyn Scalar) -> Box<dyn Array> {
+    let scalar = scalar
+        .as_any()
+        .downcast_ref::<PrimitiveScalar<T>>()
+        .unwrap();
+    PrimitiveArray::<T>::from([*scalar.value()])
+        .to(scalar.data_type().clone())
+        .boxed()
+}
+
+fn utf8_to_array<O: Offset>(scalar: &dyn Scalar) -> Box<dyn Array> {
+    let scalar = scalar.as_any().downcast_ref::<Utf8Scalar<O>>().unwrap();
+    Utf8Array::<O>::from([scalar.value()]).boxed()
+}
+
+fn binary_to_array<O: Offset>(scalar: &dyn Scalar) -> Box<dyn Array> {
+    let scalar = scalar.as_any().downcast_ref::<BinaryScalar<O>>().unwrap();
+    BinaryArray::<O>::from([scalar.value()]).boxed()
+}
+
+fn list_to_array<O: Offset>(scalar: &dyn Scalar) -> Box<dyn Array> {
+    let scalar = scalar.as_any().downcast_ref::<ListScalar<O>>().unwrap();
+    let length = O::from_usize(scalar.values().len()).unwrap();
+    ListArray::<O>::new(
+        scalar.data_type().clone(),
+        OffsetsBuffer::try_from(vec![O::zero(), length]).unwrap(),
+        scalar.values().clone(),
+        validity(scalar.is_valid()),
+    )
+    .boxed()
+}
+
+fn dictionary_to_array<K: DictionaryKey>(scalar: &dyn Scalar) -> Box<dyn Array> {
+    let scalar = scalar
+        .as_any()
+        .downcast_ref::<DictionaryScalar<K>>()
+        .unwrap();
+    let (key, values) = match scalar.value() {
+        Some(value) => (Some(K::default()), to_array(value.as_ref())),
+        None => {
+            let values_type = match scalar.data_type().to_logical_type() {
+                DataType::Dictionary(_, values_type, _) => values_type.as_ref().clone(),
+                _ => unreachable!(),
+            };
+            (None, new_empty_array(values_type))
+        }
+    };
+    DictionaryArray::<K>::try_new(
+        scalar.data_type().clone(),
+        PrimitiveArray::from([key]),
+        values,
+    )
+    .unwrap()
+    .boxed()
+}
+
+fn union_to_array(scalar: &dyn Scalar) -> Box<dyn Array> {
+    let scalar = scalar.as_any().downcast_ref::<UnionScalar>().unwrap();
+    let (fields, ids, mode) = match scalar.data_type().to_logical_type() {
+        DataType::Union(fields, ids, mode) => (fields, ids, mode),
+        _ => unreachable!(),
+    };
+    let type_ = scalar.type_();
+    let index = ids
+        .as_ref()
+        .and_then(|ids| ids.iter().position(|id| *id == type_ as i32))
+        .unwrap_or(type_ as usize);
+
+    let fields = fields
+        .iter()
+        .enumerate()
+        .map(|(i, field)| match (i == index, mode.is_sparse()) {
+            (true, _) => to_array(scalar.value().as_ref()),
+            (false, true) => new_null_array(field.data_type().clone(), 1),
+            (false, false) => new_empty_array(field.data_type().clone()),
+        })
+        .collect();
+    let offsets = (!mode.is_sparse()).then(|| vec![0].into());
+    UnionArray::new(
+        scalar.data_type().clone(),
+        vec![type_].into(),
+        fields,
+        offsets,
+    )
+    .boxed()
+}
+
+/// Returns an [`Array`] of length 1 whose only slot is `scalar`.
+pub(crate) fn to_array(scalar: &dyn Scalar) -> Box<dyn Array> {
+    use PhysicalType::*;
+    let data_type = scalar.data_type().clone();
+    match data_type.to_physical_type() {
+        Null => new_null_array(data_type, 1),
+        Boolean => {
+            let scalar = scalar.as_any().downcast_ref::<BooleanScalar>().unwrap();
+            BooleanArray::from([scalar.value()]).boxed()
+        }
+        Primitive(primitive) => with_match_primitive_type!(primitive, |$T| {
+            primitive_to_array::<$T>(scalar)
+        }),
+        Utf8 => utf8_to_array::<i32>(scalar),
+        LargeUtf8 => utf8_to_array::<i64>(scalar),
+        Binary => binary_to_array::<i32>(scalar),
+        LargeBinary => binary_to_array::<i64>(scalar),
+        List => list_to_array::<i32>(scalar),
+        LargeList => list_to_array::<i64>(scalar),
+        FixedSizeBinary => {
+            let scalar = scalar
+                .as_any()
+                .downcast_ref::<FixedSizeBinaryScalar>()
+                .unwrap();
+            match scalar.value() {
+                Some(value) => FixedSizeBinaryArray::new(data_type, value.to_vec().into(), None),
+                None => FixedSizeBinaryArray::new_null(data_type, 1),
+            }
+            .boxed()
+        }
+        FixedSizeList => {
+            let scalar = scalar
+                .as_any()
+                .downcast_ref::<FixedSizeListScalar>()
+                .unwrap();
+            match scalar.values() {
+                Some(values) => FixedSizeListArray::new(data_type, values.clone(), None),
+                None => FixedSizeListArray::new_null(data_type, 1),
+            }
+            .boxed()
+        }
+        Struct => {
+            let scalar = scalar.as_any().downcast_ref::<StructScalar>().unwrap();
+            if scalar.is_valid() {
+                let values = scalar
+                    .values()
+                    .iter()
+                    .map(|x| to_array(x.as_ref()))
+                    .collect();
+                StructArray::new(data_type, values, None).boxed()
+            } else {
+                StructArray::new_null(data_type, 1).boxed()
+            }
+        }
+        Union => union_to_array(scalar),
+        Map => {
+            let scalar = scalar.as_any().downcast_ref::<MapScalar>().unwrap();
+            let length = scalar.values().len() as i32;
+            MapArray::new(
+                data_type,
+                OffsetsBuffer::try_from(vec![0, length]).unwrap(),
+                scalar.values().clone(),
+                validity(scalar.is_valid()),
+            )
+            .boxed()
+        }
+        Dictionary(key_type) => match_integer_type!(key_type, |$T| {
+            dictionary_to_array::<$T>(scalar)
+        }),
+    }
+}
+
+/// Writes `scalar` like the slot of an array is written by [`get_display`], e.g. timestamps
+/// with their timezone and decimals with their scale.
+impl Display for dyn Scalar + '_ {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let array = to_array(self);
+        let display = get_display(array.as_ref(), "null");
+        display(f, 0)
+    }
+}
+
+macro_rules! impl_display {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl Display for $ty {
+                fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+                    Display::fmt(self as &dyn Scalar, f)
+                }
+            }
+        )*
+    };
+}
+
+impl_display!(
+    NullScalar,
+    BooleanScalar,
+    FixedSizeBinaryScalar,
+    FixedSizeListScalar,
+    StructScalar,
+    UnionScalar,
+    MapScalar,
+);
+
+impl<T: NativeType> Display for PrimitiveScalar<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        Display::fmt(self as &dyn Scalar, f)
+    }
+}
+
+impl<O: Offset> Display for Utf8Scalar<O> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        Display::fmt(self as &dyn Scalar, f)
+    }
+}
+
+impl<O: Offset> Display for BinaryScalar<O> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        Display::fmt(self as &dyn Scalar, f)
+    }
+}
+
+impl<O: Offset> Display for ListScalar<O> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        Display::fmt(self as &dyn Scalar, f)
+    }
+}
+
+impl<K: DictionaryKey> Display for DictionaryScalar<K> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        Display::fmt(self as &dyn Scalar, f)
+    }
+}
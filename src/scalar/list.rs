@@ -1,4 +1,5 @@
-use std::any::Any;
+use alloc::boxed::Box;
+use core::any::Any;
 
 use crate::{array::*, datatypes::DataType, offset::Offset};
 
@@ -10,7 +11,7 @@ use super::Scalar;
 pub struct ListScalar<O: Offset> {
     values: Box<dyn Array>,
     is_valid: bool,
-    phantom: std::marker::PhantomData<O>,
+    phantom: core::marker::PhantomData<O>,
     data_type: DataType,
 }
 
@@ -41,7 +42,7 @@ impl<O: Offset> ListScalar<O> {
         Self {
             values,
             is_valid,
-            phantom: std::marker::PhantomData,
+            phantom: core::marker::PhantomData,
             data_type,
         }
     }
@@ -1,12 +1,17 @@
 //! contains the [`Scalar`] trait object representing individual items of [`Array`](crate::array::Array)s,
 //! as well as concrete implementations such as [`BooleanScalar`].
-use std::any::Any;
+//!
+//! Every scalar implements [`Display`](core::fmt::Display) like the slots of arrays are written
+//! by [`get_display`](crate::array::get_display), e.g. timestamps with their timezone.
+use alloc::boxed::Box;
+use core::any::Any;
 
 use crate::{array::*, datatypes::*};
 
 mod dictionary;
 pub use dictionary::*;
 mod equal;
+mod fmt;
 mod primitive;
 pub use primitive::*;
 mod utf8;
@@ -25,12 +30,14 @@ mod fixed_size_list;
 pub use fixed_size_list::*;
 mod fixed_size_binary;
 pub use fixed_size_binary::*;
+mod map;
+pub use map::MapScalar;
 mod union;
 pub use union::UnionScalar;
 
 /// Trait object declaring an optional value with a [`DataType`].
 /// This strait is often used in APIs that accept multiple scalar types.
-pub trait Scalar: std::fmt::Debug + Send + Sync + dyn_clone::DynClone + 'static {
+pub trait Scalar: core::fmt::Debug + Send + Sync + dyn_clone::DynClone + 'static {
     /// convert itself to
     fn as_any(&self) -> &dyn Any;
 
@@ -83,7 +90,14 @@ macro_rules! dyn_new_list {
 }
 
 /// creates a new [`Scalar`] from an [`Array`].
+/// # Panics
+/// iff `index >= array.len()`
 pub fn new_scalar(array: &dyn Array, index: usize) -> Box<dyn Scalar> {
+    assert!(
+        index < array.len(),
+        "the index {index} is out of bounds of an array of length {}",
+        array.len()
+    );
     use PhysicalType::*;
     match array.data_type().to_physical_type() {
         Null => Box::new(NullScalar::new()),
@@ -156,7 +170,15 @@ pub fn new_scalar(array: &dyn Array, index: usize) -> Box<dyn Scalar> {
                 array.value(index),
             ))
         }
-        Map => todo!(),
+        Map => {
+            let array = array.as_any().downcast_ref::<MapArray>().unwrap();
+            let value = if array.is_valid(index) {
+                Some(array.value(index))
+            } else {
+                None
+            };
+            Box::new(MapScalar::new(array.data_type().clone(), value))
+        }
         Dictionary(key_type) => match_integer_type!(key_type, |$T| {
             let array = array
                 .as_any()
@@ -22,7 +22,7 @@ impl Default for NullScalar {
 
 impl Scalar for NullScalar {
     #[inline]
-    fn as_any(&self) -> &dyn std::any::Any {
+    fn as_any(&self) -> &dyn core::any::Any {
         self
     }
 
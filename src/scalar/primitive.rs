@@ -17,7 +17,7 @@ impl<T: NativeType> PrimitiveScalar<T> {
         if !data_type.to_physical_type().eq_primitive(T::PRIMITIVE) {
             Err(Error::InvalidArgumentError(format!(
                 "Type {} does not support logical type {:?}",
-                std::any::type_name::<T>(),
+                core::any::type_name::<T>(),
                 data_type
             )))
             .unwrap()
@@ -48,7 +48,7 @@ impl<T: NativeType> From<Option<T>> for PrimitiveScalar<T> {
 
 impl<T: NativeType> Scalar for PrimitiveScalar<T> {
     #[inline]
-    fn as_any(&self) -> &dyn std::any::Any {
+    fn as_any(&self) -> &dyn core::any::Any {
         self
     }
 
@@ -1,3 +1,5 @@
+use alloc::{boxed::Box, vec::Vec};
+
 use crate::datatypes::DataType;
 
 use super::Scalar;
@@ -39,7 +41,7 @@ impl StructScalar {
 
 impl Scalar for StructScalar {
     #[inline]
-    fn as_any(&self) -> &dyn std::any::Any {
+    fn as_any(&self) -> &dyn core::any::Any {
         self
     }
 
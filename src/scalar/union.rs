@@ -1,3 +1,5 @@
+use alloc::boxed::Box;
+
 use crate::datatypes::DataType;
 
 use super::Scalar;
@@ -36,7 +38,7 @@ impl UnionScalar {
 
 impl Scalar for UnionScalar {
     #[inline]
-    fn as_any(&self) -> &dyn std::any::Any {
+    fn as_any(&self) -> &dyn core::any::Any {
         self
     }
 
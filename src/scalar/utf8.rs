@@ -1,3 +1,5 @@
+use alloc::string::String;
+
 use crate::{datatypes::DataType, offset::Offset};
 
 use super::Scalar;
@@ -6,7 +8,7 @@ use super::Scalar;
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Utf8Scalar<O: Offset> {
     value: Option<String>,
-    phantom: std::marker::PhantomData<O>,
+    phantom: core::marker::PhantomData<O>,
 }
 
 impl<O: Offset> Utf8Scalar<O> {
@@ -15,7 +17,7 @@ impl<O: Offset> Utf8Scalar<O> {
     pub fn new<P: Into<String>>(value: Option<P>) -> Self {
         Self {
             value: value.map(|x| x.into()),
-            phantom: std::marker::PhantomData,
+            phantom: core::marker::PhantomData,
         }
     }
 
@@ -35,7 +37,7 @@ impl<O: Offset, P: Into<String>> From<Option<P>> for Utf8Scalar<O> {
 
 impl<O: Offset> Scalar for Utf8Scalar<O> {
     #[inline]
-    fn as_any(&self) -> &dyn std::any::Any {
+    fn as_any(&self) -> &dyn core::any::Any {
         self
     }
 
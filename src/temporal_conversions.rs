@@ -1,5 +1,7 @@
 //! Conversion methods for dates and times.
 
+use alloc::string::{String, ToString};
+
 use chrono::{
     format::{parse, Parsed, StrftimeItems},
     Datelike, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime,
@@ -1,5 +1,5 @@
 //! Declares [`TrustedLen`].
-use std::slice::Iter;
+use core::slice::Iter;
 
 /// An iterator of known, fixed size.
 /// A trait denoting Rusts' unstable [TrustedLen](https://doc.rust-lang.org/std/iter/trait.TrustedLen.html).
@@ -13,45 +13,45 @@ pub unsafe trait TrustedLen: Iterator {}
 
 unsafe impl<T> TrustedLen for Iter<'_, T> {}
 
-unsafe impl<B, I: TrustedLen, T: FnMut(I::Item) -> B> TrustedLen for std::iter::Map<I, T> {}
+unsafe impl<B, I: TrustedLen, T: FnMut(I::Item) -> B> TrustedLen for core::iter::Map<I, T> {}
 
-unsafe impl<'a, I, T: 'a> TrustedLen for std::iter::Copied<I>
+unsafe impl<'a, I, T: 'a> TrustedLen for core::iter::Copied<I>
 where
     I: TrustedLen<Item = &'a T>,
     T: Copy,
 {
 }
-unsafe impl<'a, I, T: 'a> TrustedLen for std::iter::Cloned<I>
+unsafe impl<'a, I, T: 'a> TrustedLen for core::iter::Cloned<I>
 where
     I: TrustedLen<Item = &'a T>,
     T: Clone,
 {
 }
 
-unsafe impl<I> TrustedLen for std::iter::Enumerate<I> where I: TrustedLen {}
+unsafe impl<I> TrustedLen for core::iter::Enumerate<I> where I: TrustedLen {}
 
-unsafe impl<A, B> TrustedLen for std::iter::Zip<A, B>
+unsafe impl<A, B> TrustedLen for core::iter::Zip<A, B>
 where
     A: TrustedLen,
     B: TrustedLen,
 {
 }
 
-unsafe impl<T> TrustedLen for std::slice::ChunksExact<'_, T> {}
+unsafe impl<T> TrustedLen for core::slice::ChunksExact<'_, T> {}
 
-unsafe impl<T> TrustedLen for std::slice::Windows<'_, T> {}
+unsafe impl<T> TrustedLen for core::slice::Windows<'_, T> {}
 
-unsafe impl<A, B> TrustedLen for std::iter::Chain<A, B>
+unsafe impl<A, B> TrustedLen for core::iter::Chain<A, B>
 where
     A: TrustedLen,
     B: TrustedLen<Item = A::Item>,
 {
 }
 
-unsafe impl<T> TrustedLen for std::iter::Once<T> {}
+unsafe impl<T> TrustedLen for core::iter::Once<T> {}
 
-unsafe impl<T> TrustedLen for std::vec::IntoIter<T> {}
+unsafe impl<T> TrustedLen for alloc::vec::IntoIter<T> {}
 
-unsafe impl<A: Clone> TrustedLen for std::iter::Repeat<A> {}
-unsafe impl<A, F: FnMut() -> A> TrustedLen for std::iter::RepeatWith<F> {}
-unsafe impl<A: TrustedLen> TrustedLen for std::iter::Take<A> {}
+unsafe impl<A: Clone> TrustedLen for core::iter::Repeat<A> {}
+unsafe impl<A, F: FnMut() -> A> TrustedLen for core::iter::RepeatWith<F> {}
+unsafe impl<A: TrustedLen> TrustedLen for core::iter::Take<A> {}
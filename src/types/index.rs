@@ -1,4 +1,4 @@
-use std::convert::TryFrom;
+use core::convert::TryFrom;
 
 use crate::trusted_len::TrustedLen;
 
@@ -8,8 +8,8 @@ use super::NativeType;
 /// that can be used to index a slot of an array.
 pub trait Index:
     NativeType
-    + std::ops::AddAssign
-    + std::ops::Sub<Output = Self>
+    + core::ops::AddAssign
+    + core::ops::Sub<Output = Self>
     + num_traits::One
     + num_traits::Num
     + num_traits::CheckedAdd
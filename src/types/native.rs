@@ -1,5 +1,7 @@
-use std::convert::TryFrom;
-use std::ops::Neg;
+use core::convert::TryFrom;
+use core::num::{ParseIntError, TryFromIntError};
+use core::ops::Neg;
+use core::str::FromStr;
 
 use bytemuck::{Pod, Zeroable};
 
@@ -14,8 +16,8 @@ pub trait NativeType:
     + Send
     + Sync
     + Sized
-    + std::fmt::Debug
-    + std::fmt::Display
+    + core::fmt::Debug
+    + core::fmt::Display
     + PartialEq
     + Default
 {
@@ -25,10 +27,10 @@ pub trait NativeType:
     /// Type denoting its representation as bytes.
     /// This is `[u8; N]` where `N = size_of::<T>`.
     type Bytes: AsRef<[u8]>
-        + std::ops::Index<usize, Output = u8>
-        + std::ops::IndexMut<usize, Output = u8>
+        + core::ops::Index<usize, Output = u8>
+        + core::ops::IndexMut<usize, Output = u8>
         + for<'a> TryFrom<&'a [u8]>
-        + std::fmt::Debug
+        + core::fmt::Debug
         + Default;
 
     /// To bytes in little endian
@@ -49,7 +51,7 @@ macro_rules! native_type {
         impl NativeType for $type {
             const PRIMITIVE: PrimitiveType = $primitive_type;
 
-            type Bytes = [u8; std::mem::size_of::<Self>()];
+            type Bytes = [u8; core::mem::size_of::<Self>()];
             #[inline]
             fn to_le_bytes(&self) -> Self::Bytes {
                 Self::to_le_bytes(*self)
@@ -299,14 +301,14 @@ impl NativeType for months_days_ns {
     }
 }
 
-impl std::fmt::Display for days_ms {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl core::fmt::Display for days_ms {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "{}d {}ms", self.days(), self.milliseconds())
     }
 }
 
-impl std::fmt::Display for months_days_ns {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl core::fmt::Display for months_days_ns {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "{}m {}d {}ns", self.months(), self.days(), self.ns())
     }
 }
@@ -370,120 +372,91 @@ impl f16 {
     }
 
     /// Casts this `f16` to `f32`
+    #[inline]
     pub fn to_f32(self) -> f32 {
-        let i = self.0;
-        // Check for signed zero
-        if i & 0x7FFFu16 == 0 {
-            return f32::from_bits((i as u32) << 16);
-        }
-
-        let half_sign = (i & 0x8000u16) as u32;
-        let half_exp = (i & 0x7C00u16) as u32;
-        let half_man = (i & 0x03FFu16) as u32;
-
-        // Check for an infinity or NaN when all exponent bits set
-        if half_exp == 0x7C00u32 {
-            // Check for signed infinity if mantissa is zero
-            if half_man == 0 {
-                let number = (half_sign << 16) | 0x7F80_0000u32;
-                return f32::from_bits(number);
-            } else {
-                // NaN, keep current mantissa but also set most significiant mantissa bit
-                let number = (half_sign << 16) | 0x7FC0_0000u32 | (half_man << 13);
-                return f32::from_bits(number);
-            }
-        }
+        half::f16::from_bits(self.0).to_f32()
+    }
 
-        // Calculate single-precision components with adjusted exponent
-        let sign = half_sign << 16;
-        // Unbias exponent
-        let unbiased_exp = ((half_exp as i32) >> 10) - 15;
+    /// Casts an `f32` into `f16`
+    #[inline]
+    pub fn from_f32(value: f32) -> Self {
+        f16(half::f16::from_f32(value).to_bits())
+    }
+}
 
-        // Check for subnormals, which will be normalized by adjusting exponent
-        if half_exp == 0 {
-            // Calculate how much to adjust the exponent by
-            let e = (half_man as u16).leading_zeros() - 6;
+impl PartialOrd for f16 {
+    #[inline]
+    fn partial_cmp(&self, other: &f16) -> Option<core::cmp::Ordering> {
+        self.to_f32().partial_cmp(&other.to_f32())
+    }
+}
 
-            // Rebias and adjust exponent
-            let exp = (127 - 15 - e) << 23;
-            let man = (half_man << (14 + e)) & 0x7F_FF_FFu32;
-            return f32::from_bits(sign | exp | man);
-        }
+impl Neg for f16 {
+    type Output = Self;
 
-        // Rebias exponent for a normalized normal
-        let exp = ((unbiased_exp + 127) as u32) << 23;
-        let man = (half_man & 0x03FFu32) << 13;
-        f32::from_bits(sign | exp | man)
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Self(self.0 ^ 0x8000u16)
     }
+}
 
-    /// Casts an `f32` into `f16`
-    pub fn from_f32(value: f32) -> Self {
-        let x: u32 = value.to_bits();
-
-        // Extract IEEE754 components
-        let sign = x & 0x8000_0000u32;
-        let exp = x & 0x7F80_0000u32;
-        let man = x & 0x007F_FFFFu32;
-
-        // Check for all exponent bits being set, which is Infinity or NaN
-        if exp == 0x7F80_0000u32 {
-            // Set mantissa MSB for NaN (and also keep shifted mantissa bits)
-            let nan_bit = if man == 0 { 0 } else { 0x0200u32 };
-            return f16(((sign >> 16) | 0x7C00u32 | nan_bit | (man >> 13)) as u16);
+// the result of a single operation in `f32` rounds to the correctly rounded `f16`
+macro_rules! f16_binary_op {
+    ($trait:ident, $method:ident) => {
+        impl core::ops::$trait for f16 {
+            type Output = Self;
+
+            #[inline]
+            fn $method(self, rhs: Self) -> Self::Output {
+                Self::from_f32(core::ops::$trait::$method(self.to_f32(), rhs.to_f32()))
+            }
         }
+    };
+}
 
-        // The number is normalized, start assembling half precision version
-        let half_sign = sign >> 16;
-        // Unbias the exponent, then bias for half precision
-        let unbiased_exp = ((exp >> 23) as i32) - 127;
-        let half_exp = unbiased_exp + 15;
+f16_binary_op!(Add, add);
+f16_binary_op!(Sub, sub);
+f16_binary_op!(Mul, mul);
+f16_binary_op!(Div, div);
+f16_binary_op!(Rem, rem);
 
-        // Check for exponent overflow, return +infinity
-        if half_exp >= 0x1F {
-            return f16((half_sign | 0x7C00u32) as u16);
-        }
+impl num_traits::ToPrimitive for f16 {
+    #[inline]
+    fn to_i64(&self) -> Option<i64> {
+        f16::to_f32(*self).to_i64()
+    }
 
-        // Check for underflow
-        if half_exp <= 0 {
-            // Check mantissa for what we can do
-            if 14 - half_exp > 24 {
-                // No rounding possibility, so this is a full underflow, return signed zero
-                return f16(half_sign as u16);
-            }
-            // Don't forget about hidden leading mantissa bit when assembling mantissa
-            let man = man | 0x0080_0000u32;
-            let mut half_man = man >> (14 - half_exp);
-            // Check for rounding (see comment above functions)
-            let round_bit = 1 << (13 - half_exp);
-            if (man & round_bit) != 0 && (man & (3 * round_bit - 1)) != 0 {
-                half_man += 1;
-            }
-            // No exponent for subnormals
-            return f16((half_sign | half_man) as u16);
-        }
+    #[inline]
+    fn to_u64(&self) -> Option<u64> {
+        f16::to_f32(*self).to_u64()
+    }
 
-        // Rebias the exponent
-        let half_exp = (half_exp as u32) << 10;
-        let half_man = man >> 13;
-        // Check for rounding (see comment above functions)
-        let round_bit = 0x0000_1000u32;
-        if (man & round_bit) != 0 && (man & (3 * round_bit - 1)) != 0 {
-            // Round it
-            f16(((half_sign | half_exp | half_man) + 1) as u16)
-        } else {
-            f16((half_sign | half_exp | half_man) as u16)
-        }
+    #[inline]
+    fn to_f32(&self) -> Option<f32> {
+        Some(f16::to_f32(*self))
+    }
+
+    #[inline]
+    fn to_f64(&self) -> Option<f64> {
+        Some(f16::to_f32(*self) as f64)
     }
 }
 
-impl std::fmt::Debug for f16 {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl num_traits::NumCast for f16 {
+    #[inline]
+    fn from<T: num_traits::ToPrimitive>(n: T) -> Option<Self> {
+        n.to_f32().map(Self::from_f32)
+    }
+}
+
+impl core::fmt::Debug for f16 {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "{:?}", self.to_f32())
     }
 }
 
-impl std::fmt::Display for f16 {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl core::fmt::Display for f16 {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "{}", self.to_f32())
     }
 }
@@ -513,16 +486,120 @@ impl NativeType for f16 {
 }
 
 /// Physical representation of a decimal
-#[derive(Clone, Copy, Default, Eq, Hash, PartialEq, PartialOrd)]
+#[derive(Clone, Copy, Default, Eq, Hash, PartialEq, PartialOrd, Ord)]
 #[allow(non_camel_case_types)]
 #[repr(C)]
 pub struct i256(pub ethnum::I256);
 
 impl i256 {
+    /// The smallest value representable by [`i256`].
+    pub const MIN: Self = Self(ethnum::I256::MIN);
+    /// The largest value representable by [`i256`].
+    pub const MAX: Self = Self(ethnum::I256::MAX);
+
     /// Returns a new [`i256`] from two `i128`.
     pub fn from_words(hi: i128, lo: i128) -> Self {
         Self(ethnum::I256::from_words(hi, lo))
     }
+
+    /// Returns the high and low words of this [`i256`], as in [`i256::from_words`].
+    pub fn into_words(self) -> (i128, i128) {
+        self.0.into_words()
+    }
+
+    /// Parses an [`i256`] from a string in the given `radix` (between 2 and 36), with an
+    /// optional sign.
+    /// # Errors
+    /// Errors iff `src` is not a valid number or does not fit in an [`i256`].
+    pub fn from_str_radix(src: &str, radix: u32) -> Result<Self, ParseIntError> {
+        ethnum::I256::from_str_radix(src, radix).map(Self)
+    }
+
+    /// Checked addition. Returns `None` on overflow.
+    #[inline]
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        self.0.checked_add(rhs.0).map(Self)
+    }
+
+    /// Checked subtraction. Returns `None` on overflow.
+    #[inline]
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        self.0.checked_sub(rhs.0).map(Self)
+    }
+
+    /// Checked multiplication. Returns `None` on overflow.
+    #[inline]
+    pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+        self.0.checked_mul(rhs.0).map(Self)
+    }
+
+    /// Checked division. Returns `None` if `rhs == 0` or on overflow (`MIN / -1`).
+    #[inline]
+    pub fn checked_div(self, rhs: Self) -> Option<Self> {
+        self.0.checked_div(rhs.0).map(Self)
+    }
+
+    /// Checked remainder. Returns `None` if `rhs == 0` or on overflow (`MIN % -1`).
+    #[inline]
+    pub fn checked_rem(self, rhs: Self) -> Option<Self> {
+        self.0.checked_rem(rhs.0).map(Self)
+    }
+
+    /// Checked negation. Returns `None` on overflow (`-MIN`).
+    #[inline]
+    pub fn checked_neg(self) -> Option<Self> {
+        self.0.checked_neg().map(Self)
+    }
+
+    /// Checked exponentiation. Returns `None` on overflow.
+    #[inline]
+    pub fn checked_pow(self, exp: u32) -> Option<Self> {
+        self.0.checked_pow(exp).map(Self)
+    }
+
+    /// Raises this [`i256`] to the power of `exp`.
+    /// # Panics
+    /// Panics on overflow in debug mode; wraps in release mode.
+    #[inline]
+    pub fn pow(self, exp: u32) -> Self {
+        Self(self.0.pow(exp))
+    }
+
+    /// Returns the absolute value of this [`i256`].
+    /// # Panics
+    /// Panics on overflow (`MIN`) in debug mode; wraps in release mode.
+    #[inline]
+    pub fn abs(self) -> Self {
+        Self(self.0.abs())
+    }
+
+    /// Returns `-1`, `0` or `1` depending on the sign of this [`i256`].
+    #[inline]
+    pub fn signum(self) -> Self {
+        Self(self.0.signum())
+    }
+}
+
+impl From<i128> for i256 {
+    fn from(value: i128) -> Self {
+        Self(ethnum::I256::from(value))
+    }
+}
+
+impl TryFrom<i256> for i128 {
+    type Error = TryFromIntError;
+
+    fn try_from(value: i256) -> Result<Self, Self::Error> {
+        i128::try_from(value.0)
+    }
+}
+
+impl FromStr for i256 {
+    type Err = ParseIntError;
+
+    fn from_str(src: &str) -> Result<Self, Self::Err> {
+        Self::from_str_radix(src, 10)
+    }
 }
 
 impl Neg for i256 {
@@ -530,19 +607,88 @@ impl Neg for i256 {
 
     #[inline]
     fn neg(self) -> Self::Output {
-        let (a, b) = self.0.into_words();
-        Self(ethnum::I256::from_words(-a, b))
+        Self(-self.0)
+    }
+}
+
+macro_rules! i256_binary_op {
+    ($trait:ident, $method:ident) => {
+        impl core::ops::$trait for i256 {
+            type Output = Self;
+
+            #[inline]
+            fn $method(self, rhs: Self) -> Self::Output {
+                Self(core::ops::$trait::$method(self.0, rhs.0))
+            }
+        }
+    };
+}
+
+i256_binary_op!(Add, add);
+i256_binary_op!(Sub, sub);
+i256_binary_op!(Mul, mul);
+i256_binary_op!(Div, div);
+i256_binary_op!(Rem, rem);
+
+impl num_traits::Zero for i256 {
+    fn zero() -> Self {
+        Self(ethnum::I256::ZERO)
+    }
+
+    fn is_zero(&self) -> bool {
+        self.0 == ethnum::I256::ZERO
     }
 }
 
-impl std::fmt::Debug for i256 {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl num_traits::One for i256 {
+    fn one() -> Self {
+        Self(ethnum::I256::ONE)
+    }
+}
+
+impl num_traits::Num for i256 {
+    type FromStrRadixErr = ParseIntError;
+
+    fn from_str_radix(src: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+        i256::from_str_radix(src, radix)
+    }
+}
+
+impl num_traits::Bounded for i256 {
+    fn min_value() -> Self {
+        Self::MIN
+    }
+
+    fn max_value() -> Self {
+        Self::MAX
+    }
+}
+
+macro_rules! i256_checked_op {
+    ($trait:ident, $method:ident) => {
+        impl num_traits::$trait for i256 {
+            #[inline]
+            fn $method(&self, rhs: &Self) -> Option<Self> {
+                i256::$method(*self, *rhs)
+            }
+        }
+    };
+}
+
+i256_checked_op!(CheckedAdd, checked_add);
+i256_checked_op!(CheckedSub, checked_sub);
+i256_checked_op!(CheckedMul, checked_mul);
+i256_checked_op!(CheckedDiv, checked_div);
+i256_checked_op!(CheckedRem, checked_rem);
+
+impl core::fmt::Debug for i256 {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "{:?}", self.0)
     }
 }
 
-impl std::fmt::Display for i256 {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl core::fmt::Display for i256 {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "{}", self.0)
     }
 }
@@ -557,57 +703,22 @@ impl NativeType for i256 {
 
     #[inline]
     fn to_le_bytes(&self) -> Self::Bytes {
-        let mut bytes = [0u8; 32];
-        let (a, b) = self.0.into_words();
-        let a = a.to_le_bytes();
-        (0..16).for_each(|i| {
-            bytes[i] = a[i];
-        });
-
-        let b = b.to_le_bytes();
-        (0..16).for_each(|i| {
-            bytes[i + 16] = b[i];
-        });
-
-        bytes
+        self.0.to_le_bytes()
     }
 
     #[inline]
     fn to_be_bytes(&self) -> Self::Bytes {
-        let mut bytes = [0u8; 32];
-        let (a, b) = self.0.into_words();
-
-        let b = b.to_be_bytes();
-        (0..16).for_each(|i| {
-            bytes[i] = b[i];
-        });
-
-        let a = a.to_be_bytes();
-        (0..16).for_each(|i| {
-            bytes[i + 16] = a[i];
-        });
-
-        bytes
+        self.0.to_be_bytes()
     }
 
     #[inline]
     fn from_be_bytes(bytes: Self::Bytes) -> Self {
-        let (b, a) = bytes.split_at(16);
-        let a: [u8; 16] = a.try_into().unwrap();
-        let b: [u8; 16] = b.try_into().unwrap();
-        let a = i128::from_be_bytes(a);
-        let b = i128::from_be_bytes(b);
-        Self(ethnum::I256::from_words(a, b))
+        Self(ethnum::I256::from_be_bytes(bytes))
     }
 
     #[inline]
     fn from_le_bytes(bytes: Self::Bytes) -> Self {
-        let (b, a) = bytes.split_at(16);
-        let a: [u8; 16] = a.try_into().unwrap();
-        let b: [u8; 16] = b.try_into().unwrap();
-        let a = i128::from_le_bytes(a);
-        let b = i128::from_le_bytes(b);
-        Self(ethnum::I256::from_words(a, b))
+        Self(ethnum::I256::from_le_bytes(bytes))
     }
 }
 
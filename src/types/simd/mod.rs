@@ -99,7 +99,7 @@ macro_rules! native_simd {
             }
         }
 
-        impl std::ops::Index<usize> for $name {
+        impl core::ops::Index<usize> for $name {
             type Output = $type;
 
             #[inline]
@@ -108,7 +108,7 @@ macro_rules! native_simd {
             }
         }
 
-        impl std::ops::IndexMut<usize> for $name {
+        impl core::ops::IndexMut<usize> for $name {
             #[inline]
             fn index_mut(&mut self, index: usize) -> &mut Self::Output {
                 &mut self.0[index]
@@ -1,5 +1,5 @@
 use crate::types::BitChunkIter;
-use std::convert::TryInto;
+use core::convert::TryInto;
 
 use super::*;
 
@@ -0,0 +1,209 @@
+//! Bit-packing and delta-encoding of unsigned integers, for columnar formats implemented on top
+//! of this crate.
+//!
+//! Values are packed least-significant bit first, i.e. the first value occupies the lowest
+//! `num_bits` bits of the first byte(s), which is the layout of Arrow's bitmaps and of
+//! Parquet's `BIT_PACKED` runs.
+//! # Example
+//! ```
+//! use arrow2::util::bitpack::{bit_width, delta_decode, delta_encode, pack, packed_len, unpack};
+//!
+//! let mut values = vec![100u32, 101, 103, 106, 110];
+//! delta_encode(&mut values);
+//! assert_eq!(values, vec![100, 1, 2, 3, 4]);
+//!
+//! // pack the deltas with the width of the largest delta
+//! let num_bits = bit_width(4);
+//! let mut packed = vec![0; packed_len(values.len() - 1, num_bits)];
+//! pack(&values[1..], num_bits, &mut packed);
+//! assert_eq!(packed.len(), 2);
+//!
+//! let mut unpacked = vec![100u32; 5];
+//! unpack(&packed, num_bits, &mut unpacked[1..]);
+//! delta_decode(&mut unpacked);
+//! assert_eq!(unpacked, vec![100, 101, 103, 106, 110]);
+//! ```
+
+mod private {
+    pub trait Sealed {}
+
+    impl Sealed for u8 {}
+    impl Sealed for u16 {}
+    impl Sealed for u32 {}
+    impl Sealed for u64 {}
+}
+
+/// Sealed trait describing the unsigned integers (`u8`, `u16`, `u32` and `u64`) that can be
+/// bit-packed.
+pub trait Unpackable: Copy + Default + core::fmt::Debug + PartialEq + private::Sealed {
+    /// The number of bits of this type
+    const BITS: usize;
+    /// Converts itself to [`u64`]
+    fn to_u64(self) -> u64;
+    /// Converts the lowest [`Unpackable::BITS`] bits of `value` to itself
+    fn from_u64(value: u64) -> Self;
+    /// `self + other`, wrapping around on overflow
+    fn wrapping_add(self, other: Self) -> Self;
+    /// `self - other`, wrapping around on overflow
+    fn wrapping_sub(self, other: Self) -> Self;
+}
+
+macro_rules! unpackable {
+    ($type:ty) => {
+        impl Unpackable for $type {
+            const BITS: usize = <$type>::BITS as usize;
+
+            #[inline]
+            fn to_u64(self) -> u64 {
+                self as u64
+            }
+
+            #[inline]
+            fn from_u64(value: u64) -> Self {
+                value as $type
+            }
+
+            #[inline]
+            fn wrapping_add(self, other: Self) -> Self {
+                <$type>::wrapping_add(self, other)
+            }
+
+            #[inline]
+            fn wrapping_sub(self, other: Self) -> Self {
+                <$type>::wrapping_sub(self, other)
+            }
+        }
+    };
+}
+
+unpackable!(u8);
+unpackable!(u16);
+unpackable!(u32);
+unpackable!(u64);
+
+/// Returns the number of bits needed to bitpack `max`
+#[inline]
+pub fn bit_width(max: u64) -> usize {
+    (64 - max.leading_zeros()) as usize
+}
+
+/// Returns the number of bytes needed to bitpack `length` values with `num_bits` bits each
+#[inline]
+pub fn packed_len(length: usize, num_bits: usize) -> usize {
+    (length * num_bits + 7) / 8
+}
+
+#[inline]
+fn mask(num_bits: usize) -> u64 {
+    if num_bits == 64 {
+        u64::MAX
+    } else {
+        (1u64 << num_bits) - 1
+    }
+}
+
+#[inline]
+fn check<T: Unpackable>(num_bits: usize, length: usize, packed: usize) {
+    assert!(
+        num_bits <= T::BITS,
+        "{num_bits} bits can't be packed from or unpacked to a type of {} bits",
+        T::BITS
+    );
+    assert!(
+        packed >= packed_len(length, num_bits),
+        "{length} values of {num_bits} bits require {} bytes, but only {packed} bytes are available",
+        packed_len(length, num_bits),
+    );
+}
+
+/// Packs the lowest `num_bits` bits of every value of `values` into `packed`.
+///
+/// Bits of `packed` that are not written to (at most 7 trailing bits) are set to zero.
+/// # Panics
+/// Panics iff `num_bits` is larger than the number of bits of `T`, or `packed` is smaller
+/// than [`packed_len`].
+/// # Implementation
+/// Values are accumulated into a 128-bit register that is written to `packed` 64 bits at a time.
+pub fn pack<T: Unpackable>(values: &[T], num_bits: usize, packed: &mut [u8]) {
+    check::<T>(num_bits, values.len(), packed.len());
+    if num_bits == 0 {
+        return;
+    }
+    let mask = mask(num_bits) as u128;
+
+    let mut offset = 0;
+    let mut acc = 0u128;
+    let mut filled = 0;
+    for value in values {
+        acc |= (value.to_u64() as u128 & mask) << filled;
+        filled += num_bits;
+        if filled >= 64 {
+            packed[offset..offset + 8].copy_from_slice(&(acc as u64).to_le_bytes());
+            offset += 8;
+            acc >>= 64;
+            filled -= 64;
+        }
+    }
+
+    let remaining = (filled + 7) / 8;
+    packed[offset..offset + remaining].copy_from_slice(&(acc as u64).to_le_bytes()[..remaining]);
+}
+
+/// Unpacks `values.len()` values of `num_bits` bits each from `packed` into `values`.
+/// # Panics
+/// Panics iff `num_bits` is larger than the number of bits of `T`, or `packed` is smaller
+/// than [`packed_len`].
+/// # Implementation
+/// Every value is read with a single unaligned 128-bit load, except at the end of `packed`.
+pub fn unpack<T: Unpackable>(packed: &[u8], num_bits: usize, values: &mut [T]) {
+    check::<T>(num_bits, values.len(), packed.len());
+    if num_bits == 0 {
+        values.iter_mut().for_each(|value| *value = T::default());
+        return;
+    }
+    let mask = mask(num_bits);
+
+    for (index, value) in values.iter_mut().enumerate() {
+        let bit = index * num_bits;
+        let byte = bit / 8;
+        let word = match packed.get(byte..byte + 16) {
+            Some(bytes) => u128::from_le_bytes(bytes.try_into().unwrap()),
+            None => {
+                let mut bytes = [0; 16];
+                let end = packed.len().min(byte + 16);
+                bytes[..end - byte].copy_from_slice(&packed[byte..end]);
+                u128::from_le_bytes(bytes)
+            }
+        };
+        *value = T::from_u64((word >> (bit % 8)) as u64 & mask);
+    }
+}
+
+/// Replaces every value of `values` but the first by its difference to the previous value,
+/// wrapping around on overflow. This is the inverse of [`delta_decode`].
+pub fn delta_encode<T: Unpackable>(values: &mut [T]) {
+    for i in (1..values.len()).rev() {
+        values[i] = values[i].wrapping_sub(values[i - 1]);
+    }
+}
+
+/// Replaces every value of `values` but the first by the sum of itself and the previous
+/// (decoded) value, wrapping around on overflow. This is the inverse of [`delta_encode`].
+pub fn delta_decode<T: Unpackable>(values: &mut [T]) {
+    for i in 1..values.len() {
+        values[i] = values[i].wrapping_add(values[i - 1]);
+    }
+}
+
+/// Maps `value` to an unsigned integer whose bit width grows with its absolute value
+/// (`0 -> 0, -1 -> 1, 1 -> 2, -2 -> 3, ...`), so that small negative deltas can be packed.
+#[inline]
+pub fn zigzag_encode(value: i64) -> u64 {
+    ((value << 1) ^ (value >> 63)) as u64
+}
+
+/// The inverse of [`zigzag_encode`].
+#[inline]
+pub fn zigzag_decode(value: u64) -> i64 {
+    ((value >> 1) as i64) ^ -((value & 1) as i64)
+}
@@ -0,0 +1,158 @@
+//! Utilities to find the first difference between [`Array`]s and [`Chunk`]s.
+//!
+//! Unlike comparing them via [`PartialEq`], the [`Difference`]s returned here identify
+//! where two arrays differ and contain the values that differ, rendered via
+//! [`get_display`], which makes them suitable for assertion messages.
+//! # Example
+//! ```
+//! use arrow2::array::{Array, Int32Array};
+//! use arrow2::chunk::Chunk;
+//! use arrow2::util::diff::diff_chunks;
+//!
+//! let left = Chunk::new(vec![Int32Array::from([Some(1), Some(2)]).boxed()]);
+//! let right = Chunk::new(vec![Int32Array::from([Some(1), None]).boxed()]);
+//! let difference = diff_chunks(&left, &right).unwrap();
+//! assert_eq!(
+//!     difference.to_string(),
+//!     "column 0: row 1 differs: left is 2, right is null"
+//! );
+//! ```
+use alloc::string::String;
+use core::fmt::{Display, Formatter};
+
+use crate::array::{equal, get_display, Array};
+use crate::chunk::Chunk;
+use crate::datatypes::DataType;
+
+/// The first difference between two [`Array`]s.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Difference {
+    /// The arrays have different data types
+    DataType {
+        /// The data type of the left array
+        left: DataType,
+        /// The data type of the right array
+        right: DataType,
+    },
+    /// The arrays have different lengths
+    Length {
+        /// The length of the left array
+        left: usize,
+        /// The length of the right array
+        right: usize,
+    },
+    /// The arrays have different values at `row`
+    Value {
+        /// The first row whose values differ
+        row: usize,
+        /// The value of the left array at `row`
+        left: String,
+        /// The value of the right array at `row`
+        right: String,
+    },
+}
+
+impl Display for Difference {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::DataType { left, right } => {
+                write!(f, "data types differ: left is {left:?}, right is {right:?}")
+            }
+            Self::Length { left, right } => {
+                write!(f, "lengths differ: left is {left}, right is {right}")
+            }
+            Self::Value { row, left, right } => {
+                write!(f, "row {row} differs: left is {left}, right is {right}")
+            }
+        }
+    }
+}
+
+/// The first difference between two [`Chunk`]s.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ChunkDifference {
+    /// The chunks have a different number of columns
+    Columns {
+        /// The number of columns of the left chunk
+        left: usize,
+        /// The number of columns of the right chunk
+        right: usize,
+    },
+    /// The arrays of column `index` differ
+    Column {
+        /// The index of the first column that differs
+        index: usize,
+        /// The first difference between the arrays of the column
+        difference: Difference,
+    },
+}
+
+impl Display for ChunkDifference {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Columns { left, right } => write!(
+                f,
+                "number of columns differ: left is {left}, right is {right}"
+            ),
+            Self::Column { index, difference } => write!(f, "column {index}: {difference}"),
+        }
+    }
+}
+
+/// Returns the first [`Difference`] between `left` and `right`, or `None` if they are equal.
+///
+/// Values are compared with the same semantics as [`equal`]; in particular, nested values
+/// (e.g. of structs, lists and maps) are compared as a whole and rendered as a whole.
+pub fn diff(left: &dyn Array, right: &dyn Array) -> Option<Difference> {
+    if left.data_type() != right.data_type() {
+        return Some(Difference::DataType {
+            left: left.data_type().clone(),
+            right: right.data_type().clone(),
+        });
+    }
+    if left.len() != right.len() {
+        return Some(Difference::Length {
+            left: left.len(),
+            right: right.len(),
+        });
+    }
+    if equal(left, right) {
+        return None;
+    }
+
+    let row = (0..left.len())
+        .find(|&row| !equal(left.slice(row, 1).as_ref(), right.slice(row, 1).as_ref()))?;
+    Some(Difference::Value {
+        row,
+        left: render(left, row),
+        right: render(right, row),
+    })
+}
+
+/// Returns the first [`ChunkDifference`] between `left` and `right`, or `None` if they are
+/// equal.
+pub fn diff_chunks<A: AsRef<dyn Array>, B: AsRef<dyn Array>>(
+    left: &Chunk<A>,
+    right: &Chunk<B>,
+) -> Option<ChunkDifference> {
+    if left.arrays().len() != right.arrays().len() {
+        return Some(ChunkDifference::Columns {
+            left: left.arrays().len(),
+            right: right.arrays().len(),
+        });
+    }
+    left.arrays()
+        .iter()
+        .zip(right.arrays().iter())
+        .enumerate()
+        .find_map(|(index, (left, right))| {
+            diff(left.as_ref(), right.as_ref())
+                .map(|difference| ChunkDifference::Column { index, difference })
+        })
+}
+
+fn render(array: &dyn Array, row: usize) -> String {
+    let mut string = String::new();
+    get_display(array, "null")(&mut string, row).unwrap();
+    string
+}
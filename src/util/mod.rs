@@ -17,6 +17,16 @@ mod lexical;
 ))]
 pub use lexical::*;
 
+pub mod bitpack;
+pub mod diff;
+
 #[cfg(feature = "benchmarks")]
 #[cfg_attr(docsrs, doc(cfg(feature = "benchmarks")))]
 pub mod bench_util;
+
+#[cfg(feature = "benchmarks")]
+#[cfg_attr(docsrs, doc(cfg(feature = "benchmarks")))]
+pub mod random;
+
+#[cfg(any(feature = "benchmarks", feature = "proptest"))]
+pub(crate) mod value_ranges;
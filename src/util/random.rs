@@ -0,0 +1,349 @@
+//! Random arrays of any [`DataType`], e.g. to fuzz round-trips of IO formats or to benchmark
+//! kernels over nested types.
+//!
+//! The arrays are generated from a seedable [`Rng`] so that failures can be reproduced:
+//! ```
+//! use arrow2::datatypes::{DataType, Field};
+//! use arrow2::util::random::{random_array, seedable_rng};
+//!
+//! let data_type = DataType::List(Box::new(Field::new("item", DataType::Utf8, true)));
+//! let array = random_array(&data_type, 100, 0.1, &mut seedable_rng(0)).unwrap();
+//! assert_eq!(array.len(), 100);
+//! assert_eq!(array.data_type(), &data_type);
+//! ```
+use rand::distributions::{Alphanumeric, DistString};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+use crate::array::*;
+use crate::bitmap::Bitmap;
+use crate::chunk::Chunk;
+use crate::datatypes::{DataType, IntervalUnit, Schema, UnionMode};
+use crate::error::Result;
+use crate::offset::{Offset, Offsets};
+use crate::types::{days_ms, f16, i256, months_days_ns, NativeType};
+use crate::util::value_ranges::{self, time_unit_factor};
+
+/// The maximum number of items of the random lists, maps and variable-sized binaries
+const MAX_LENGTH: usize = 8;
+
+/// The maximum number of values of the random dictionaries
+const MAX_DICTIONARY_LENGTH: usize = 16;
+
+/// Returns a [`StdRng`] seeded with `seed`
+pub fn seedable_rng(seed: u64) -> StdRng {
+    StdRng::seed_from_u64(seed)
+}
+
+fn random_validity<R: Rng>(rng: &mut R, length: usize, null_density: f32) -> Option<Bitmap> {
+    (null_density > 0.0).then(|| {
+        (0..length)
+            .map(|_| rng.gen::<f32>() >= null_density)
+            .collect()
+    })
+}
+
+fn random_primitive<T, R, F>(
+    rng: &mut R,
+    data_type: &DataType,
+    length: usize,
+    null_density: f32,
+    value: F,
+) -> Result<Box<dyn Array>>
+where
+    T: NativeType,
+    R: Rng,
+    F: Fn(&mut R) -> T,
+{
+    let values = (0..length).map(|_| value(rng)).collect::<Vec<_>>();
+    let validity = random_validity(rng, length, null_density);
+    Ok(PrimitiveArray::try_new(data_type.clone(), values.into(), validity)?.boxed())
+}
+
+fn random_offsets<O: Offset, R: Rng>(rng: &mut R, length: usize) -> Result<Offsets<O>> {
+    let lengths = (0..length)
+        .map(|_| rng.gen_range(0..=MAX_LENGTH))
+        .collect::<Vec<_>>();
+    Offsets::try_from_lengths(lengths.into_iter())
+}
+
+fn random_binary<O: Offset, R: Rng>(
+    rng: &mut R,
+    data_type: &DataType,
+    length: usize,
+    null_density: f32,
+) -> Result<Box<dyn Array>> {
+    let offsets = random_offsets::<O, _>(rng, length)?;
+    let values = (0..offsets.last().to_usize())
+        .map(|_| rng.gen::<u8>())
+        .collect::<Vec<_>>();
+    let validity = random_validity(rng, length, null_density);
+    Ok(
+        BinaryArray::<O>::try_new(data_type.clone(), offsets.into(), values.into(), validity)?
+            .boxed(),
+    )
+}
+
+fn random_utf8<O: Offset, R: Rng>(
+    rng: &mut R,
+    data_type: &DataType,
+    length: usize,
+    null_density: f32,
+) -> Result<Box<dyn Array>> {
+    let offsets = random_offsets::<O, _>(rng, length)?;
+    let values = Alphanumeric.sample_string(rng, offsets.last().to_usize());
+    let validity = random_validity(rng, length, null_density);
+    Ok(Utf8Array::<O>::try_new(
+        data_type.clone(),
+        offsets.into(),
+        values.into_bytes().into(),
+        validity,
+    )?
+    .boxed())
+}
+
+fn random_list<O: Offset, R: Rng>(
+    rng: &mut R,
+    data_type: &DataType,
+    length: usize,
+    null_density: f32,
+) -> Result<Box<dyn Array>> {
+    let child = ListArray::<O>::try_get_child(data_type)?;
+    let offsets = random_offsets::<O, _>(rng, length)?;
+    let values = random_child(
+        rng,
+        child.is_nullable,
+        &child.data_type,
+        offsets.last().to_usize(),
+        null_density,
+    )?;
+    let validity = random_validity(rng, length, null_density);
+    Ok(ListArray::<O>::try_new(data_type.clone(), offsets.into(), values, validity)?.boxed())
+}
+
+fn random_dictionary<K: DictionaryKey, R: Rng>(
+    rng: &mut R,
+    data_type: &DataType,
+    values_type: &DataType,
+    length: usize,
+    null_density: f32,
+) -> Result<Box<dyn Array>> {
+    let values_length = length.clamp(1, MAX_DICTIONARY_LENGTH);
+    let values = random_array(values_type, values_length, 0.0, rng)?;
+    let keys = (0..length)
+        .map(|_| {
+            // `values_length` fits in any key type
+            K::try_from(rng.gen_range(0..values_length)).ok().unwrap()
+        })
+        .collect::<Vec<_>>();
+    let validity = random_validity(rng, length, null_density);
+    let keys = PrimitiveArray::<K>::new(K::PRIMITIVE.into(), keys.into(), validity);
+    Ok(DictionaryArray::<K>::try_new(data_type.clone(), keys, values)?.boxed())
+}
+
+/// Returns a random array of a child field, that has no nulls unless it is nullable
+fn random_child<R: Rng>(
+    rng: &mut R,
+    is_nullable: bool,
+    data_type: &DataType,
+    length: usize,
+    null_density: f32,
+) -> Result<Box<dyn Array>> {
+    let null_density = if is_nullable { null_density } else { 0.0 };
+    random_array(data_type, length, null_density, rng)
+}
+
+/// Returns a random value of a decimal of `precision` digits, up to 38
+fn random_decimal<R: Rng>(rng: &mut R, precision: usize) -> i128 {
+    rng.gen_range(value_ranges::decimal(precision))
+}
+
+/// Returns a random array of `data_type` and `length`, whose slots are null with probability
+/// `null_density`.
+///
+/// The values are valid for their [`DataType`], e.g. decimals fit in their precision, times
+/// are within a day and the keys of dictionaries are within bounds. Children of nested types
+/// have nulls only if their field is nullable, and the values of dictionaries have no nulls.
+/// # Errors
+/// This function errors iff `data_type` is invalid, e.g. a [`DataType::Map`] whose field
+/// is not a struct.
+pub fn random_array<R: Rng>(
+    data_type: &DataType,
+    length: usize,
+    null_density: f32,
+    rng: &mut R,
+) -> Result<Box<dyn Array>> {
+    use DataType::*;
+    let nd = null_density;
+    match data_type.to_logical_type() {
+        Null => Ok(NullArray::try_new(data_type.clone(), length)?.boxed()),
+        Boolean => {
+            let values = (0..length).map(|_| rng.gen::<bool>()).collect::<Bitmap>();
+            let validity = random_validity(rng, length, nd);
+            Ok(BooleanArray::try_new(data_type.clone(), values, validity)?.boxed())
+        }
+        Int8 => random_primitive(rng, data_type, length, nd, |rng| rng.gen::<i8>()),
+        Int16 => random_primitive(rng, data_type, length, nd, |rng| rng.gen::<i16>()),
+        Int32 => random_primitive(rng, data_type, length, nd, |rng| rng.gen::<i32>()),
+        Int64 | Duration(_) => random_primitive(rng, data_type, length, nd, |rng| rng.gen::<i64>()),
+        UInt8 => random_primitive(rng, data_type, length, nd, |rng| rng.gen::<u8>()),
+        UInt16 => random_primitive(rng, data_type, length, nd, |rng| rng.gen::<u16>()),
+        UInt32 => random_primitive(rng, data_type, length, nd, |rng| rng.gen::<u32>()),
+        UInt64 => random_primitive(rng, data_type, length, nd, |rng| rng.gen::<u64>()),
+        Float16 => random_primitive(rng, data_type, length, nd, |rng| {
+            f16::from_f32(rng.gen::<f32>())
+        }),
+        Float32 => random_primitive(rng, data_type, length, nd, |rng| rng.gen::<f32>()),
+        Float64 => random_primitive(rng, data_type, length, nd, |rng| rng.gen::<f64>()),
+        // dates and timestamps between the years 1900 and 2100
+        Date32 => random_primitive(rng, data_type, length, nd, |rng| {
+            rng.gen_range(value_ranges::DAYS)
+        }),
+        Date64 => random_primitive(rng, data_type, length, nd, |rng| {
+            rng.gen_range(value_ranges::DAYS) as i64 * 86_400_000
+        }),
+        Timestamp(unit, _) => {
+            let factor = time_unit_factor(*unit);
+            random_primitive(rng, data_type, length, nd, move |rng| {
+                rng.gen_range(value_ranges::SECONDS) * factor
+            })
+        }
+        Time32(unit) => {
+            let max = 86_400 * time_unit_factor(*unit) as i32;
+            random_primitive(rng, data_type, length, nd, move |rng| rng.gen_range(0..max))
+        }
+        Time64(unit) => {
+            let max = 86_400 * time_unit_factor(*unit);
+            random_primitive(rng, data_type, length, nd, move |rng| rng.gen_range(0..max))
+        }
+        Interval(IntervalUnit::YearMonth) => random_primitive(rng, data_type, length, nd, |rng| {
+            rng.gen_range(-1200..1200i32)
+        }),
+        Interval(IntervalUnit::DayTime) => random_primitive(rng, data_type, length, nd, |rng| {
+            days_ms::new(rng.gen_range(-36500..36500), rng.gen_range(0..86_400_000))
+        }),
+        Interval(IntervalUnit::MonthDayNano) => {
+            random_primitive(rng, data_type, length, nd, |rng| {
+                months_days_ns::new(
+                    rng.gen_range(-1200..1200),
+                    rng.gen_range(-36500..36500),
+                    rng.gen_range(0..86_400_000_000_000),
+                )
+            })
+        }
+        Decimal(precision, _) => {
+            let precision = *precision;
+            random_primitive(rng, data_type, length, nd, move |rng| {
+                random_decimal(rng, precision)
+            })
+        }
+        Decimal256(precision, _) => {
+            let precision = *precision;
+            random_primitive(rng, data_type, length, nd, move |rng| {
+                i256::from(random_decimal(rng, precision))
+            })
+        }
+        Binary => random_binary::<i32, _>(rng, data_type, length, nd),
+        LargeBinary => random_binary::<i64, _>(rng, data_type, length, nd),
+        Utf8 => random_utf8::<i32, _>(rng, data_type, length, nd),
+        LargeUtf8 => random_utf8::<i64, _>(rng, data_type, length, nd),
+        FixedSizeBinary(size) => {
+            let values = (0..length * size)
+                .map(|_| rng.gen::<u8>())
+                .collect::<Vec<_>>();
+            let validity = random_validity(rng, length, nd);
+            Ok(FixedSizeBinaryArray::try_new(data_type.clone(), values.into(), validity)?.boxed())
+        }
+        List(_) => random_list::<i32, _>(rng, data_type, length, nd),
+        LargeList(_) => random_list::<i64, _>(rng, data_type, length, nd),
+        FixedSizeList(child, size) => {
+            let values = random_child(rng, child.is_nullable, &child.data_type, length * size, nd)?;
+            let validity = random_validity(rng, length, nd);
+            Ok(FixedSizeListArray::try_new(data_type.clone(), values, validity)?.boxed())
+        }
+        Struct(fields) => {
+            let values = fields
+                .iter()
+                .map(|field| random_child(rng, field.is_nullable, &field.data_type, length, nd))
+                .collect::<Result<Vec<_>>>()?;
+            let validity = random_validity(rng, length, nd);
+            Ok(StructArray::try_new(data_type.clone(), values, validity)?.boxed())
+        }
+        Map(field, _) => {
+            let offsets = random_offsets::<i32, _>(rng, length)?;
+            // the entries of a map are never null
+            let entries = random_array(&field.data_type, *offsets.last() as usize, 0.0, rng)?;
+            let validity = random_validity(rng, length, nd);
+            Ok(MapArray::try_new(data_type.clone(), offsets.into(), entries, validity)?.boxed())
+        }
+        Union(fields, ids, mode) => {
+            let types = (0..length)
+                .map(|_| rng.gen_range(0..fields.len().max(1)))
+                .collect::<Vec<_>>();
+            let counts = fields
+                .iter()
+                .enumerate()
+                .map(|(i, _)| match mode {
+                    UnionMode::Sparse => length,
+                    UnionMode::Dense => types.iter().filter(|x| **x == i).count(),
+                })
+                .collect::<Vec<_>>();
+            let values = fields
+                .iter()
+                .zip(counts)
+                .map(|(field, length)| {
+                    random_child(rng, field.is_nullable, &field.data_type, length, nd)
+                })
+                .collect::<Result<Vec<_>>>()?;
+            let offsets = mode.is_dense().then(|| {
+                let mut next = vec![0i32; fields.len()];
+                types
+                    .iter()
+                    .map(|x| {
+                        next[*x] += 1;
+                        next[*x] - 1
+                    })
+                    .collect::<Vec<_>>()
+                    .into()
+            });
+            let types = types
+                .into_iter()
+                .map(|x| match ids {
+                    Some(ids) => ids[x] as i8,
+                    None => x as i8,
+                })
+                .collect::<Vec<_>>();
+            Ok(UnionArray::try_new(data_type.clone(), types.into(), values, offsets)?.boxed())
+        }
+        Dictionary(key_type, values_type, _) => match_integer_type!(key_type, |$T| {
+            random_dictionary::<$T, _>(rng, data_type, values_type, length, nd)
+        }),
+        Extension(_, _, _) => unreachable!(),
+    }
+}
+
+/// Returns a [`Chunk`] of random arrays of the fields of `schema` and `length`, generated
+/// by [`random_array`] from a [`StdRng`] seeded with `seed`.
+/// # Errors
+/// This function errors iff a [`DataType`] of `schema` is invalid.
+pub fn random_chunk(
+    schema: &Schema,
+    length: usize,
+    null_density: f32,
+    seed: u64,
+) -> Result<Chunk<Box<dyn Array>>> {
+    let mut rng = seedable_rng(seed);
+    let arrays = schema
+        .fields
+        .iter()
+        .map(|field| {
+            random_child(
+                &mut rng,
+                field.is_nullable,
+                &field.data_type,
+                length,
+                null_density,
+            )
+        })
+        .collect::<Result<Vec<_>>>()?;
+    Chunk::try_new(arrays)
+}
@@ -0,0 +1,26 @@
+//! Ranges of the values of logical types, shared by the generators of random and arbitrary arrays.
+use core::ops::{Range, RangeInclusive};
+
+use crate::datatypes::TimeUnit;
+
+/// The days since the epoch of the dates between the years 1900 and 2100
+pub(crate) const DAYS: Range<i32> = -25567..47482;
+
+/// The seconds since the epoch of the timestamps between the years 1900 and 2100
+pub(crate) const SECONDS: Range<i64> = -2_208_988_800..4_102_444_800;
+
+/// The number of `unit`s in a second
+pub(crate) fn time_unit_factor(unit: TimeUnit) -> i64 {
+    match unit {
+        TimeUnit::Second => 1,
+        TimeUnit::Millisecond => 1_000,
+        TimeUnit::Microsecond => 1_000_000,
+        TimeUnit::Nanosecond => 1_000_000_000,
+    }
+}
+
+/// The values of a decimal of `precision` digits, up to 38
+pub(crate) fn decimal(precision: usize) -> RangeInclusive<i128> {
+    let max = 10i128.pow(precision.min(38) as u32) - 1;
+    -max..=max
+}
@@ -0,0 +1,101 @@
+use arrow2::array::arbitrary::array;
+use arrow2::array::*;
+use arrow2::datatypes::*;
+use proptest::prelude::*;
+
+/// Whether `data_type` has a map within a union, whose values can't be compared
+/// because maps have no scalars.
+fn has_map_in_union(data_type: &DataType, in_union: bool) -> bool {
+    match data_type.to_logical_type() {
+        DataType::Map(field, _) => in_union || has_map_in_union(&field.data_type, in_union),
+        DataType::Union(fields, _, _) => {
+            fields.iter().any(|x| has_map_in_union(&x.data_type, true))
+        }
+        DataType::List(field) | DataType::LargeList(field) | DataType::FixedSizeList(field, _) => {
+            has_map_in_union(&field.data_type, in_union)
+        }
+        DataType::Struct(fields) => fields
+            .iter()
+            .any(|x| has_map_in_union(&x.data_type, in_union)),
+        _ => false,
+    }
+}
+
+fn comparable_array() -> impl Strategy<Value = Box<dyn Array>> {
+    any::<DataType>()
+        .prop_filter("maps within unions can't be compared", |x| {
+            !has_map_in_union(x, false)
+        })
+        .prop_flat_map(|data_type| array(data_type, 0..16))
+}
+
+proptest! {
+    #[test]
+    fn arrays_are_of_their_data_type(
+        (data_type, array) in any::<DataType>()
+            .prop_flat_map(|data_type| (Just(data_type.clone()), array(data_type, 5..6)))
+    ) {
+        prop_assert_eq!(array.data_type(), &data_type);
+        prop_assert_eq!(array.len(), 5);
+    }
+
+    #[test]
+    fn arrays_equal_themselves(array in comparable_array()) {
+        let cloned = array.clone();
+        prop_assert_eq!(array.as_ref(), cloned.as_ref());
+    }
+
+    #[test]
+    fn non_nullable_children_have_no_nulls(
+        array in array(DataType::Struct(vec![Field::new("a", DataType::Utf8, false)]), 0..16)
+    ) {
+        let array = array.as_any().downcast_ref::<StructArray>().unwrap();
+        prop_assert_eq!(array.values()[0].null_count(), 0);
+    }
+
+    #[test]
+    fn decimals_fit_in_precision(array in array(DataType::Decimal(3, 1), 0..16)) {
+        let array = array.as_any().downcast_ref::<PrimitiveArray<i128>>().unwrap();
+        prop_assert!(array.values().iter().all(|x| x.abs() < 1000));
+    }
+
+    #[test]
+    fn unions_use_their_type_ids(
+        array in array(
+            DataType::Union(
+                vec![Field::new("a", DataType::Int32, true), Field::new("b", DataType::Utf8, true)],
+                Some(vec![5, 2]),
+                UnionMode::Dense,
+            ),
+            0..16,
+        )
+    ) {
+        let array = array.as_any().downcast_ref::<UnionArray>().unwrap();
+        prop_assert!(array.types().iter().all(|x| *x == 5 || *x == 2));
+        for i in 0..array.len() {
+            let (field, _) = array.index(i);
+            prop_assert_eq!(field, (array.types()[i] == 2) as usize);
+        }
+    }
+}
+
+#[cfg(feature = "io_ipc")]
+proptest! {
+    #[test]
+    fn ipc_round_trip(array in comparable_array()) {
+        use arrow2::chunk::Chunk;
+        use arrow2::io::ipc::{read, write};
+
+        let schema = Schema::from(vec![Field::new("a", array.data_type().clone(), true)]);
+        let mut writer = write::FileWriter::try_new(vec![], schema, None, Default::default()).unwrap();
+        writer.write(&Chunk::new(vec![array.clone()]), None).unwrap();
+        writer.finish().unwrap();
+        let data = writer.into_inner();
+
+        let mut reader = std::io::Cursor::new(data);
+        let metadata = read::read_file_metadata(&mut reader).unwrap();
+        let mut reader = read::FileReader::new(reader, metadata, None, None);
+        let chunk = reader.next().unwrap().unwrap();
+        prop_assert_eq!(array.as_ref(), chunk.arrays()[0].as_ref());
+    }
+}
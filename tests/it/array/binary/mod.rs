@@ -213,3 +213,9 @@ fn iter_nth() {
     assert_eq!(array.iter().nth(1), Some(Some(" ".as_bytes())));
     assert_eq!(array.iter().nth(10), None);
 }
+
+#[test]
+fn try_from_iter() {
+    let array = BinaryArray::<i64>::try_from_iter([Some(b"a"), None]).unwrap();
+    assert_eq!(array, BinaryArray::<i64>::from([Some(b"a"), None]));
+}
@@ -163,3 +163,14 @@ fn extend_from_self() {
         MutableBinaryArray::<i32>::from([Some(b"aa"), None, Some(b"aa"), None])
     );
 }
+
+#[test]
+fn extend_from_array() {
+    let other = BinaryArray::<i32>::from([Some(b"a".as_ref()), None, Some(b"bc")]).slice(1, 2);
+
+    let mut a = MutableBinaryArray::<i32>::from([Some(b"x")]);
+    a.try_extend_from_array(&other, 0, 2).unwrap();
+    let array: BinaryArray<i32> = a.into();
+    let expected = BinaryArray::<i32>::from([Some(b"x".as_ref()), None, Some(b"bc")]);
+    assert_eq!(array, expected);
+}
@@ -143,3 +143,15 @@ fn into_iter() {
 
     assert!(array.into_iter().rev().eq(rev))
 }
+
+#[test]
+fn from_packed() {
+    let array = BooleanArray::from_packed(vec![0b00011010, 0b1], 9, 1).unwrap();
+    assert_eq!(array.validity(), None);
+    assert_eq!(
+        array,
+        BooleanArray::from_slice([true, false, true, true, false, false, false, true, false])
+    );
+
+    assert!(BooleanArray::from_packed(vec![0b1], 9, 0).is_err());
+}
@@ -1,4 +1,4 @@
-use arrow2::array::{MutableArray, MutableBooleanArray, TryExtendFromSelf};
+use arrow2::array::{BooleanArray, MutableArray, MutableBooleanArray, TryExtendFromSelf};
 use arrow2::bitmap::MutableBitmap;
 use arrow2::datatypes::DataType;
 use arrow2::error::Result;
@@ -175,3 +175,17 @@ fn extend_from_self() {
         MutableBooleanArray::from([Some(true), None, Some(true), None])
     );
 }
+
+#[test]
+fn extend_from_array() {
+    let other = BooleanArray::from([Some(true), None, Some(false)]).slice(1, 2);
+
+    let mut a = MutableBooleanArray::from([Some(false)]);
+    a.try_extend_from_array(&other, 1, 1).unwrap();
+    assert_eq!(a.validity(), None);
+    a.try_extend_from_array(&other, 0, 2).unwrap();
+    assert_eq!(
+        a,
+        MutableBooleanArray::from([Some(false), Some(false), None, Some(false)])
+    );
+}
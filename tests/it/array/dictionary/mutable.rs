@@ -74,3 +74,25 @@ fn push_utf8() {
         .collect::<HashedMap<_, _>>();
     assert_eq!(*new.map(), expected_map);
 }
+
+#[test]
+fn extend_from_array() -> Result<()> {
+    let mut other = MutableDictionaryArray::<i32, MutableUtf8Array<i32>>::new();
+    other.try_extend(vec![Some("a"), Some("b"), None, Some("c"), Some("b")])?;
+    let other: DictionaryArray<i32> = other.into();
+
+    let mut a = MutableDictionaryArray::<i32, MutableUtf8Array<i32>>::new();
+    a.try_extend(vec![Some("x")])?;
+    a.try_extend_from_array(&other, 2, 3)?;
+    a.try_extend(vec![Some("y")])?;
+    let array: DictionaryArray<i32> = a.into();
+
+    // only the values `b` and `c` are referenced in the range
+    assert_eq!(array.values().len(), 4);
+    let values = array.iter_typed::<Utf8Array<i32>>()?.collect::<Vec<_>>();
+    assert_eq!(
+        values,
+        vec![Some("x"), None, Some("c"), Some("b"), Some("y")]
+    );
+    Ok(())
+}
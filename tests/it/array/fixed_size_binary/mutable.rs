@@ -168,3 +168,16 @@ fn extend_from_self() {
         MutableFixedSizeBinaryArray::from([Some([1u8, 2u8]), None, Some([1u8, 2u8]), None])
     );
 }
+
+#[test]
+fn extend_from_array() {
+    let other = FixedSizeBinaryArray::from([Some([1, 2]), None, Some([3, 4])]);
+
+    let mut a = MutableFixedSizeBinaryArray::new(2);
+    a.try_extend_from_array(&other, 1, 2).unwrap();
+    let array: FixedSizeBinaryArray = a.into();
+    assert_eq!(array, FixedSizeBinaryArray::from([None, Some([3, 4])]));
+
+    let mut a = MutableFixedSizeBinaryArray::new(3);
+    assert!(a.try_extend_from_array(&other, 0, 1).is_err());
+}
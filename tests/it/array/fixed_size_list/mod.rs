@@ -102,3 +102,46 @@ fn wrong_data_type() {
     )
     .is_err());
 }
+
+#[test]
+fn to_values_matrix() {
+    let array = data();
+    assert_eq!(
+        array
+            .to_values_matrix::<i32>(NullRowPolicy::Keep)
+            .unwrap()
+            .as_slice(),
+        &[10, 20, 0, 0]
+    );
+    let array = array.with_validity(Some([false, true].into()));
+    assert_eq!(
+        array
+            .to_values_matrix::<i32>(NullRowPolicy::Zero)
+            .unwrap()
+            .as_slice(),
+        &[0, 0, 0, 0]
+    );
+    assert_eq!(
+        array
+            .to_values_matrix::<i32>(NullRowPolicy::Skip)
+            .unwrap()
+            .as_slice(),
+        &[0, 0]
+    );
+    assert!(array.to_values_matrix::<i32>(NullRowPolicy::Error).is_err());
+    assert!(array.to_values_matrix::<i64>(NullRowPolicy::Keep).is_err());
+}
+
+#[test]
+fn reshape() {
+    let array = data().with_validity(None);
+    let reshaped = array.reshape(1).unwrap();
+    assert_eq!(reshaped.size(), 1);
+    assert_eq!(reshaped.len(), 4);
+    assert_eq!(reshaped.values(), array.values());
+    assert_eq!(reshaped.reshape(4).unwrap().len(), 1);
+
+    assert!(array.reshape(3).is_err());
+    assert!(array.reshape(0).is_err());
+    assert!(data().reshape(1).is_err());
+}
@@ -64,3 +64,57 @@ fn test_multi() -> Result<()> {
     assert_eq!(result, expected);
     Ok(())
 }
+
+#[test]
+fn test_shared_dictionary() -> Result<()> {
+    let values = Utf8Array::<i32>::from_slice(["a", "b", "c"]).boxed();
+    let array1 = DictionaryArray::try_from_keys(
+        PrimitiveArray::from(&[Some(0), None, Some(2)]),
+        values.clone(),
+    )?;
+    // equal, but distinct, values
+    let array2 = DictionaryArray::try_from_keys(
+        PrimitiveArray::from_vec(vec![1, 2]),
+        Utf8Array::<i32>::from_slice(["a", "b", "c"]).boxed(),
+    )?;
+
+    let mut growable = GrowableDictionary::new(&[&array1, &array2, &array1], false, 0);
+    growable.extend(0, 0, 3);
+    growable.extend(1, 0, 2);
+    growable.extend(2, 2, 1);
+    let result: DictionaryArray<i32> = growable.into();
+
+    let expected = DictionaryArray::try_from_keys(
+        PrimitiveArray::from(&[Some(0), None, Some(2), Some(1), Some(2), Some(2)]),
+        values,
+    )?;
+    assert_eq!(result, expected);
+    assert_eq!(result.values().len(), 3);
+    Ok(())
+}
+
+#[test]
+fn test_partially_shared_dictionary() -> Result<()> {
+    let array1 = DictionaryArray::try_from_keys(
+        PrimitiveArray::from_vec(vec![0, 1]),
+        Utf8Array::<i32>::from_slice(["a", "b"]).boxed(),
+    )?;
+    let array2 = DictionaryArray::try_from_keys(
+        PrimitiveArray::from_vec(vec![0]),
+        Utf8Array::<i32>::from_slice(["c"]).boxed(),
+    )?;
+
+    let mut growable = GrowableDictionary::new(&[&array1, &array2, &array1], false, 0);
+    growable.extend(2, 1, 1);
+    growable.extend(1, 0, 1);
+    growable.extend(0, 0, 1);
+    let result: DictionaryArray<i32> = growable.into();
+
+    let expected = DictionaryArray::try_from_keys(
+        PrimitiveArray::from_vec(vec![1, 2, 0]),
+        Utf8Array::<i32>::from_slice(["a", "b", "c"]).boxed(),
+    )?;
+    assert_eq!(result, expected);
+    assert_eq!(result.values().len(), 3);
+    Ok(())
+}
@@ -0,0 +1,76 @@
+use arrow2::{
+    array::{
+        growable::{Growable, GrowableMap},
+        Array, Int32Array, MapArray, StructArray, Utf8Array,
+    },
+    bitmap::Bitmap,
+    datatypes::{DataType, Field},
+    offset::OffsetsBuffer,
+};
+
+fn some_values() -> (DataType, Box<dyn Array>) {
+    let fields = vec![
+        Field::new("key", DataType::Utf8, false),
+        Field::new("val", DataType::Int32, true),
+    ];
+    let data_type = DataType::Struct(fields);
+    let values = StructArray::new(
+        data_type.clone(),
+        vec![
+            Utf8Array::<i32>::from_slice(["a", "b", "c", "d"]).boxed(),
+            Int32Array::from([Some(1), None, Some(3), Some(4)]).boxed(),
+        ],
+        None,
+    )
+    .boxed();
+    (data_type, values)
+}
+
+fn map_array(offsets: Vec<i32>, validity: Option<Bitmap>) -> MapArray {
+    let (data_type, values) = some_values();
+    let data_type = DataType::Map(Box::new(Field::new("entries", data_type, false)), false);
+    MapArray::new(data_type, offsets.try_into().unwrap(), values, validity)
+}
+
+#[test]
+fn basic() {
+    let array = map_array(vec![0, 2, 2, 4], None);
+
+    let mut a = GrowableMap::new(vec![&array], false, 0);
+    a.extend(0, 2, 1);
+    a.extend(0, 0, 1);
+    assert_eq!(a.len(), 2);
+
+    let result: MapArray = a.into();
+
+    let (_, values) = some_values();
+    let expected_values = StructArray::new(
+        values.data_type().clone(),
+        vec![
+            Utf8Array::<i32>::from_slice(["c", "d", "a", "b"]).boxed(),
+            Int32Array::from([Some(3), Some(4), Some(1), None]).boxed(),
+        ],
+        None,
+    );
+    let expected = MapArray::new(
+        array.data_type().clone(),
+        OffsetsBuffer::try_from(vec![0, 2, 4]).unwrap(),
+        expected_values.boxed(),
+        None,
+    );
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn null_offset() {
+    let array = map_array(vec![0, 2, 2, 4], Some([true, false, true].into()));
+
+    let mut a = GrowableMap::new(vec![&array], false, 0);
+    a.extend(0, 1, 2);
+    assert_eq!(a.len(), 2);
+
+    let result: MapArray = a.into();
+    assert_eq!(result.validity(), Some(&[false, true].into()));
+    assert_eq!(result.offsets().as_slice(), &[0, 0, 2]);
+    assert_eq!(result.field().len(), 2);
+}
@@ -1,7 +1,7 @@
 use arrow2::{
     array::{
-        growable::{Growable, GrowableNull},
-        NullArray,
+        growable::{make_growable, Growable, GrowableNull},
+        Array, ListArray, MutableListArray, MutablePrimitiveArray, NullArray, TryExtend,
     },
     datatypes::DataType,
 };
@@ -19,3 +19,28 @@ fn null() {
     let expected = NullArray::new(DataType::Null, 3);
     assert_eq!(result, expected);
 }
+
+#[test]
+fn mixed_with_typed() {
+    let mut list = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+    list.try_extend([Some(vec![Some(1)]), Some(vec![Some(2), None])])
+        .unwrap();
+    let list: ListArray<i32> = list.into();
+    let nulls = NullArray::new(DataType::Null, 3);
+
+    let mut mutable = make_growable(&[&nulls, &list], false, 0);
+    mutable.extend(1, 1, 1);
+    mutable.extend(0, 0, 2);
+    mutable.extend(1, 0, 1);
+    assert_eq!(mutable.len(), 4);
+
+    let result = mutable.as_box();
+    assert_eq!(result.data_type(), list.data_type());
+
+    let mut expected = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+    expected
+        .try_extend([Some(vec![Some(2), None]), None, None, Some(vec![Some(1)])])
+        .unwrap();
+    let expected: ListArray<i32> = expected.into();
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+}
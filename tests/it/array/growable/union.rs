@@ -151,3 +151,51 @@ fn complex_dense() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn sparse_sliced_with_ids() -> Result<()> {
+    let fields = vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+    ];
+    let data_type = DataType::Union(fields, Some(vec![5, 7]), UnionMode::Sparse);
+    let types = vec![5, 5, 7, 7].into();
+    let fields = vec![
+        Int32Array::from(&[Some(1), None, Some(2), Some(3)]).boxed(),
+        Utf8Array::<i32>::from([Some("a"), Some("b"), Some("c"), Some("d")]).boxed(),
+    ];
+    let array = UnionArray::new(data_type, types, fields, None);
+    let sliced = array.slice(1, 3);
+
+    let mut a = GrowableUnion::new(vec![&sliced, &array], 10);
+    a.extend(0, 1, 2);
+    a.extend(1, 0, 1);
+    let result: UnionArray = a.into();
+
+    let expected = [sliced.value(1), sliced.value(2), array.value(0)];
+    assert_eq!(result.iter().collect::<Vec<_>>(), expected);
+    Ok(())
+}
+
+#[test]
+fn dense_with_ids() -> Result<()> {
+    let fields = vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+    ];
+    let data_type = DataType::Union(fields, Some(vec![5, 7]), UnionMode::Dense);
+    let types = vec![7, 5, 7].into();
+    let fields = vec![
+        Int32Array::from(&[Some(1)]).boxed(),
+        Utf8Array::<i32>::from([Some("a"), Some("b")]).boxed(),
+    ];
+    let offsets = Some(vec![0, 0, 1].into());
+    let array = UnionArray::new(data_type, types, fields, offsets);
+
+    let mut a = GrowableUnion::new(vec![&array], 10);
+    a.extend(0, 1, 2);
+    let result: UnionArray = a.into();
+
+    assert_eq!(result, array.slice(1, 2));
+    Ok(())
+}
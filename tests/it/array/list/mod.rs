@@ -1,6 +1,7 @@
 use arrow2::array::*;
 use arrow2::buffer::Buffer;
 use arrow2::datatypes::DataType;
+use arrow2::error::Error;
 
 mod mutable;
 
@@ -68,3 +69,40 @@ fn test_nested_display() {
     let expected = "ListArray[[[1, 2], [3, 4]], [[5, 6, 7], [], [8]], [[9, 10]]]";
     assert_eq!(format!("{nested:?}"), expected);
 }
+
+#[test]
+fn from_iter() {
+    let data = vec![Some(vec![Some(1i32), None]), None, Some(vec![Some(3)])];
+
+    let array =
+        ListArray::<i32>::try_from_iter(MutablePrimitiveArray::<i32>::new(), data.clone()).unwrap();
+    let mut expected = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+    expected.try_extend(data.clone()).unwrap();
+    let expected: ListArray<i32> = expected.into();
+    assert_eq!(array, expected);
+
+    let array = ListArray::<i32>::from_trusted_len_iter(
+        MutablePrimitiveArray::<i32>::new(),
+        data.clone().into_iter(),
+    );
+    assert_eq!(array, expected);
+
+    let array = ListArray::<i32>::try_from_trusted_len_iter::<Error, _, _, _, _>(
+        MutablePrimitiveArray::<i32>::new(),
+        data.into_iter().map(Ok),
+    )
+    .unwrap();
+    assert_eq!(array, expected);
+}
+
+#[test]
+fn try_from_trusted_len_iter_error() {
+    let data = vec![Ok(Some(vec![Some(1i32)])), Err("error"), Ok(None)];
+
+    let result = ListArray::<i64>::try_from_trusted_len_iter::<Error, _, _, _, _>(
+        MutablePrimitiveArray::<i32>::new(),
+        data.into_iter()
+            .map(|x| x.map_err(|e| Error::ExternalFormat(e.to_string()))),
+    );
+    assert!(result.is_err());
+}
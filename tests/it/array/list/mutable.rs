@@ -71,3 +71,36 @@ fn extend_from_self() {
 
     assert_eq!(a, b);
 }
+
+#[test]
+fn extend_from_array() {
+    let data = vec![
+        Some(vec![Some(1i32), Some(2)]),
+        None,
+        Some(vec![Some(3), None]),
+        Some(vec![Some(4)]),
+    ];
+    let mut other = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+    other.try_extend(data).unwrap();
+    let other: ListArray<i32> = other.into();
+    let other = other.slice(1, 3);
+
+    let mut a = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+    a.try_extend(vec![Some(vec![Some(0)])]).unwrap();
+    a.try_extend_from_array(&other, 1, 2).unwrap();
+    assert_eq!(a.validity(), None);
+    a.try_extend_from_array(&other, 0, 1).unwrap();
+    let array: ListArray<i32> = a.into();
+
+    let mut expected = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+    expected
+        .try_extend(vec![
+            Some(vec![Some(0)]),
+            Some(vec![Some(3), None]),
+            Some(vec![Some(4)]),
+            None,
+        ])
+        .unwrap();
+    let expected: ListArray<i32> = expected.into();
+    assert_eq!(array, expected);
+}
@@ -52,3 +52,139 @@ fn basics() {
         )) as Box<dyn Array>
     );
 }
+
+#[test]
+fn mutable() {
+    let dt = DataType::Struct(vec![
+        Field::new("a", DataType::Utf8, true),
+        Field::new("b", DataType::Int32, true),
+    ]);
+    let data_type = DataType::Map(Box::new(Field::new("a", dt.clone(), true)), false);
+
+    let field = MutableStructArray::new(
+        dt.clone(),
+        vec![
+            Box::new(MutableUtf8Array::<i32>::new()) as _,
+            Box::new(MutablePrimitiveArray::<i32>::new()),
+        ],
+    );
+    let mut array = MutableMapArray::try_new(data_type.clone(), field).unwrap();
+
+    let field = array.mut_field();
+    field
+        .value::<MutableUtf8Array<i32>>(0)
+        .unwrap()
+        .push(Some("x"));
+    field
+        .value::<MutablePrimitiveArray<i32>>(1)
+        .unwrap()
+        .push(Some(1));
+    field.push(true);
+    array.try_push_valid().unwrap();
+    array.push_null();
+    array.try_push_valid().unwrap();
+
+    let field = StructArray::new(
+        dt,
+        vec![
+            Utf8Array::<i32>::from_slice(["x"]).boxed(),
+            Int32Array::from_slice([1]).boxed(),
+        ],
+        None,
+    );
+    let expected = MapArray::new(
+        data_type.clone(),
+        vec![0, 1, 1, 1].try_into().unwrap(),
+        field.boxed(),
+        Some([true, false, true].into()),
+    );
+    assert_eq!(MapArray::from(array), expected);
+
+    let field = MutableStructArray::new(
+        DataType::Struct(vec![Field::new("a", DataType::Utf8, true)]),
+        vec![Box::new(MutableUtf8Array::<i32>::new()) as _],
+    );
+    assert!(MutableMapArray::try_new(data_type, field).is_err());
+}
+
+#[test]
+fn from_iter() {
+    let data = vec![
+        Some(vec![("a", Some(1i32)), ("b", None)]),
+        None,
+        Some(vec![("c", Some(3))]),
+    ];
+
+    let array = MapArray::try_from_iter(
+        MutableUtf8Array::<i32>::new(),
+        MutablePrimitiveArray::<i32>::new(),
+        data.clone(),
+    )
+    .unwrap();
+    assert_eq!(array.offsets().as_slice(), &[0, 2, 2, 3]);
+    assert_eq!(
+        array.validity(),
+        Some(&[true, false, true].iter().copied().collect())
+    );
+
+    let field = array
+        .field()
+        .as_any()
+        .downcast_ref::<StructArray>()
+        .unwrap();
+    assert_eq!(
+        field.values()[0].as_ref(),
+        &Utf8Array::<i32>::from_slice(["a", "b", "c"]) as &dyn Array
+    );
+    assert_eq!(
+        field.values()[1].as_ref(),
+        &Int32Array::from([Some(1), None, Some(3)]) as &dyn Array
+    );
+
+    let other = MapArray::from_trusted_len_iter(
+        MutableUtf8Array::<i32>::new(),
+        MutablePrimitiveArray::<i32>::new(),
+        data.clone().into_iter(),
+    );
+    assert_eq!(array, other);
+
+    let other = MapArray::try_from_trusted_len_iter::<arrow2::error::Error, _, _, _, _, _, _>(
+        MutableUtf8Array::<i32>::new(),
+        MutablePrimitiveArray::<i32>::new(),
+        data.into_iter().map(Ok),
+    )
+    .unwrap();
+    assert_eq!(array, other);
+}
+
+#[test]
+fn keys_values_array() {
+    let array = MapArray::try_from_iter(
+        MutableUtf8Array::<i32>::new(),
+        MutablePrimitiveArray::<i32>::new(),
+        vec![
+            Some(vec![("a", Some(1i32)), ("b", None)]),
+            None,
+            Some(vec![("c", Some(3))]),
+        ],
+    )
+    .unwrap();
+    assert_eq!(
+        array.keys_array().as_ref(),
+        &Utf8Array::<i32>::from_slice(["a", "b", "c"]) as &dyn Array
+    );
+    assert_eq!(
+        array.values_array().as_ref(),
+        &Int32Array::from([Some(1), None, Some(3)]) as &dyn Array
+    );
+
+    let sliced = array.slice(1, 2);
+    assert_eq!(
+        sliced.keys_array().as_ref(),
+        &Utf8Array::<i32>::from_slice(["c"]) as &dyn Array
+    );
+    assert_eq!(
+        sliced.values_array().as_ref(),
+        &Int32Array::from_slice([3]) as &dyn Array
+    );
+}
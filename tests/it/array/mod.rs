@@ -1,3 +1,5 @@
+#[cfg(feature = "proptest")]
+mod arbitrary;
 mod binary;
 mod boolean;
 mod dictionary;
@@ -7,14 +9,26 @@ mod fixed_size_list;
 mod growable;
 mod list;
 mod map;
+#[cfg(feature = "nalgebra")]
+mod nalgebra;
+#[cfg(feature = "ndarray")]
+mod ndarray;
 mod ord;
 mod primitive;
+#[cfg(feature = "pyo3")]
+mod python;
 mod struct_;
 mod union;
 mod utf8;
 
-use arrow2::array::{clone, new_empty_array, new_null_array, Array, PrimitiveArray};
+use arrow2::array::{
+    clone, new_empty_array, new_mutable, new_null_array, Array, ArrayValidation, BinaryArray,
+    DictionaryArray, Int32Array, ListArray, MutableArray, MutableDictionaryArray, MutableListArray,
+    MutablePrimitiveArray, MutableUnionArray, MutableUtf8Array, PrimitiveArray, TryExtend, TryPush,
+    UnionArray, Utf8Array,
+};
 use arrow2::bitmap::Bitmap;
+use arrow2::datatypes::IntegerType;
 use arrow2::datatypes::{DataType, Field, UnionMode};
 
 #[test]
@@ -109,6 +123,140 @@ fn empty_extension() {
     assert!(a);
 }
 
+#[test]
+fn mutable() {
+    let map = DataType::Struct(vec![
+        Field::new("key", DataType::Utf8, false),
+        Field::new("value", DataType::Int32, true),
+    ]);
+    let datatypes = vec![
+        DataType::Null,
+        DataType::Boolean,
+        DataType::Int32,
+        DataType::Float64,
+        DataType::Utf8,
+        DataType::LargeBinary,
+        DataType::FixedSizeBinary(3),
+        DataType::List(Box::new(Field::new("a", DataType::Binary, true))),
+        DataType::FixedSizeList(Box::new(Field::new("a", DataType::Int8, true)), 2),
+        DataType::Struct(vec![Field::new("a", DataType::Int32, true)]),
+        DataType::Map(Box::new(Field::new("entries", map, false)), false),
+        DataType::Dictionary(IntegerType::Int8, Box::new(DataType::Utf8), true),
+        DataType::Dictionary(
+            IntegerType::UInt32,
+            Box::new(DataType::List(Box::new(Field::new(
+                "a",
+                DataType::Int32,
+                true,
+            )))),
+            false,
+        ),
+        DataType::Extension("ext".to_owned(), Box::new(DataType::Int32), None),
+    ];
+    for data_type in datatypes {
+        let mut array = new_mutable(data_type.clone(), 10).unwrap();
+        assert_eq!(array.data_type(), &data_type);
+        array.push_null();
+        let array = array.as_box();
+        assert_eq!(array.data_type(), &data_type);
+        assert_eq!(array.len(), 1);
+        assert_eq!(array.null_count(), 1);
+    }
+}
+
+#[test]
+fn mutable_nested() {
+    let data_type = DataType::List(Box::new(Field::new(
+        "item",
+        DataType::Dictionary(IntegerType::Int32, Box::new(DataType::Utf8), false),
+        true,
+    )));
+    let mut array = new_mutable(data_type.clone(), 2).unwrap();
+
+    let list = array
+        .as_mut_any()
+        .downcast_mut::<MutableListArray<i32, Box<dyn MutableArray>>>()
+        .unwrap();
+    let values = list
+        .mut_values()
+        .as_mut_any()
+        .downcast_mut::<MutableDictionaryArray<i32, MutableUtf8Array<i32>>>()
+        .unwrap();
+    values.try_push(Some("a")).unwrap();
+    values.try_push(Some("b")).unwrap();
+    values.try_push(Some("a")).unwrap();
+    list.try_push_valid().unwrap();
+    list.push_null();
+
+    let values = DictionaryArray::try_new(
+        DataType::Dictionary(IntegerType::Int32, Box::new(DataType::Utf8), false),
+        PrimitiveArray::from_slice([0, 1, 0]),
+        Utf8Array::<i32>::from_slice(["a", "b"]).boxed(),
+    )
+    .unwrap();
+    let expected = ListArray::<i32>::new(
+        data_type,
+        vec![0, 3, 3].try_into().unwrap(),
+        values.boxed(),
+        Some([true, false].into()),
+    );
+    assert_eq!(array.as_box(), expected.boxed());
+
+    let mut array = new_mutable(DataType::Int32, 0).unwrap();
+    array
+        .as_mut_any()
+        .downcast_mut::<MutablePrimitiveArray<i32>>()
+        .unwrap()
+        .push(Some(1));
+    assert_eq!(array.as_box(), Int32Array::from_slice([1]).boxed());
+}
+
+#[test]
+fn mutable_union() {
+    let fields = vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+    ];
+    let data_type = DataType::Union(fields, Some(vec![5, 7]), UnionMode::Dense);
+    let mut array = new_mutable(data_type.clone(), 3).unwrap();
+    assert_eq!(array.data_type(), &data_type);
+
+    let union = array
+        .as_mut_any()
+        .downcast_mut::<MutableUnionArray>()
+        .unwrap();
+    union
+        .push_variant::<MutablePrimitiveArray<i32>, _>(0, Some(1))
+        .unwrap();
+    union
+        .push_variant::<MutableUtf8Array<i32>, _>(1, Some("a"))
+        .unwrap();
+    union.push_null();
+
+    let expected = UnionArray::new(
+        data_type,
+        vec![5, 7, 5].into(),
+        vec![
+            Int32Array::from([Some(1), None]).boxed(),
+            Utf8Array::<i32>::from_slice(["a"]).boxed(),
+        ],
+        Some(vec![0, 0, 1].into()),
+    );
+    assert_eq!(array.as_box(), expected.boxed());
+}
+
+#[test]
+fn mutable_invalid_union() {
+    let fields = (0..200)
+        .map(|i| Field::new(i.to_string(), DataType::Int32, true))
+        .collect();
+    let data_type = DataType::Union(fields, None, UnionMode::Sparse);
+    assert!(new_mutable(data_type.clone(), 1).is_err());
+
+    let data_type = DataType::List(Box::new(Field::new("item", data_type, true)));
+    assert!(new_mutable(data_type, 1).is_err());
+}
+
 #[test]
 fn test_clone() {
     let datatypes = vec![
@@ -135,8 +283,306 @@ fn test_with_validity() {
     assert_eq!(arr_ref, &expected);
 }
 
+#[test]
+fn sliced() {
+    let array = Utf8Array::<i32>::from([Some("a"), None, Some("c"), Some("d")]);
+    let expected = Utf8Array::<i32>::from([None, Some("c")]);
+    assert_eq!(array.sliced(1..3).as_ref(), &expected as &dyn Array);
+    assert_eq!(array.sliced(4..4).len(), 0);
+}
+
+#[test]
+#[should_panic]
+fn sliced_out_of_bounds() {
+    Int32Array::from_slice([1, 2]).sliced(1..3);
+}
+
+#[test]
+fn try_slice() {
+    let array = Int32Array::from([Some(1), None, Some(3)]).boxed();
+    let sliced = array.try_slice(1, 2).unwrap();
+    assert_eq!(sliced, Int32Array::from([None, Some(3)]).boxed());
+
+    assert!(array.try_slice(2, 2).is_err());
+    assert!(array.try_slice(usize::MAX, 2).is_err());
+    assert!(new_null_array(DataType::Null, 2).try_slice(1, 2).is_err());
+}
+
+#[test]
+fn buffers() {
+    let array = Utf8Array::<i32>::from([Some("a"), None, Some("cd")]).sliced(1..3);
+    let buffers = array.buffers();
+    assert_eq!(buffers.len(), 3);
+    // the validity is copied to start at a byte boundary
+    assert_eq!(buffers[0].as_deref(), Some([0b10].as_ref()));
+    let offsets = [1i32, 1, 3]
+        .iter()
+        .flat_map(|x| x.to_le_bytes())
+        .collect::<Vec<_>>();
+    assert_eq!(buffers[1].as_deref(), Some(offsets.as_ref()));
+    assert_eq!(buffers[2].as_deref(), Some(b"acd".as_ref()));
+    assert!(array.direct_children().is_empty());
+
+    let array = Int32Array::from_slice([1, 2]);
+    assert_eq!(array.buffers()[0], None);
+    assert_eq!(
+        array.buffers()[1].as_deref(),
+        Some([1, 0, 0, 0, 2, 0, 0, 0].as_ref())
+    );
+}
+
+#[test]
+fn direct_children() {
+    let mut array = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+    array
+        .try_extend(vec![Some(vec![Some(1), Some(2)]), None])
+        .unwrap();
+    let array: ListArray<i32> = array.into();
+    assert_eq!(array.buffers().len(), 2);
+    assert_eq!(
+        array.direct_children(),
+        vec![Int32Array::from_slice([1, 2]).boxed()]
+    );
+
+    let array = DictionaryArray::try_from_keys(
+        PrimitiveArray::from_vec(vec![1i32, 0]),
+        Utf8Array::<i32>::from_slice(["a", "b"]).boxed(),
+    )
+    .unwrap();
+    assert_eq!(array.buffers().len(), 2);
+    assert_eq!(array.direct_children(), vec![array.values().clone()]);
+}
+
+#[test]
+fn validate() {
+    use arrow2::offset::OffsetsBuffer;
+
+    let valid: Vec<Box<dyn Array>> = vec![
+        Utf8Array::<i32>::from([Some("a"), None, Some("ç")]).boxed(),
+        DictionaryArray::try_from_keys(
+            PrimitiveArray::from_vec(vec![1i32, 0]),
+            Utf8Array::<i32>::from_slice(["a", "b"]).boxed(),
+        )
+        .unwrap()
+        .boxed(),
+        new_null_array(DataType::Int32, 2),
+    ];
+    for array in valid {
+        assert!(array.validate(ArrayValidation::Basic).is_ok());
+        assert!(array.validate_full().is_ok());
+    }
+
+    // invalid utf8, only detected by a full validation, also of children
+    let values = unsafe {
+        Utf8Array::<i32>::new_unchecked(
+            DataType::Utf8,
+            vec![0, 1].try_into().unwrap(),
+            vec![0xff].into(),
+            None,
+        )
+    };
+    assert!(values.validate(ArrayValidation::Basic).is_ok());
+    assert!(values.validate_full().is_err());
+    let list = ListArray::<i32>::new(
+        ListArray::<i32>::default_datatype(DataType::Utf8),
+        vec![0, 1].try_into().unwrap(),
+        values.boxed(),
+        None,
+    );
+    assert!(list.validate(ArrayValidation::Off).is_ok());
+    assert!(list.validate(ArrayValidation::Basic).is_ok());
+    assert!(list.validate_full().is_err());
+
+    // offsets that are not monotonically increasing
+    let offsets = unsafe { OffsetsBuffer::new_unchecked(vec![0, 2, 1].into()) };
+    let array = BinaryArray::<i32>::new(DataType::Binary, offsets, vec![0, 1].into(), None);
+    assert!(array.validate(ArrayValidation::Basic).is_ok());
+    assert!(array.validate_full().is_err());
+
+    // dictionary keys out of bounds
+    let array = unsafe {
+        DictionaryArray::<i32>::try_new_unchecked(
+            DataType::Dictionary(IntegerType::Int32, Box::new(DataType::Utf8), false),
+            PrimitiveArray::from_vec(vec![0, 2]),
+            Utf8Array::<i32>::from_slice(["a", "b"]).boxed(),
+        )
+        .unwrap()
+    };
+    assert!(array.validate(ArrayValidation::Basic).is_ok());
+    assert!(array.validate_full().is_err());
+
+    // dense union offsets out of bounds
+    let fields = vec![Field::new("a", DataType::Int32, true)];
+    let data_type = DataType::Union(fields, None, UnionMode::Dense);
+    let array = UnionArray::new(
+        data_type,
+        vec![0, 0].into(),
+        vec![Int32Array::from_slice([1]).boxed()],
+        Some(vec![0, 1].into()),
+    );
+    assert!(array.validate(ArrayValidation::Basic).is_ok());
+    assert!(array.validate_full().is_err());
+
+    // union types not declared by the data type
+    let fields = vec![Field::new("a", DataType::Int32, true)];
+    let data_type = DataType::Union(fields, Some(vec![5]), UnionMode::Sparse);
+    let array = UnionArray::new(
+        data_type,
+        vec![5, 0].into(),
+        vec![Int32Array::from_slice([1, 2]).boxed()],
+        None,
+    );
+    assert!(array.validate(ArrayValidation::Basic).is_ok());
+    assert!(array.validate_full().is_err());
+}
+
+#[test]
+fn try_new_with_validation() {
+    let offsets: arrow2::offset::OffsetsBuffer<i32> = vec![0, 1].try_into().unwrap();
+    let utf8 = |validation| unsafe {
+        Utf8Array::<i32>::try_new_with_validation(
+            DataType::Utf8,
+            offsets.clone(),
+            vec![0xff].into(),
+            None,
+            validation,
+        )
+    };
+    assert!(utf8(ArrayValidation::Basic).is_ok());
+    assert!(utf8(ArrayValidation::Full).is_err());
+
+    let data_type = DataType::Dictionary(IntegerType::Int32, Box::new(DataType::Utf8), false);
+    let dictionary = |validation| unsafe {
+        DictionaryArray::<i32>::try_new_with_validation(
+            data_type.clone(),
+            PrimitiveArray::from_vec(vec![0, 2]),
+            Utf8Array::<i32>::from_slice(["a", "b"]).boxed(),
+            validation,
+        )
+    };
+    assert!(dictionary(ArrayValidation::Basic).is_ok());
+    assert!(dictionary(ArrayValidation::Full).is_err());
+
+    let fields = vec![Field::new("a", DataType::Int32, true)];
+    let data_type = DataType::Union(fields, None, UnionMode::Sparse);
+    let union = |validation| unsafe {
+        UnionArray::try_new_with_validation(
+            data_type.clone(),
+            vec![0, 1].into(),
+            vec![Int32Array::from_slice([1, 2]).boxed()],
+            None,
+            validation,
+        )
+    };
+    assert!(union(ArrayValidation::Basic).is_ok());
+    assert!(union(ArrayValidation::Full).is_err());
+    // the lengths are checked from `ArrayValidation::Basic` on
+    let union = unsafe {
+        UnionArray::try_new_with_validation(
+            data_type.clone(),
+            vec![0].into(),
+            vec![],
+            None,
+            ArrayValidation::Off,
+        )
+    };
+    assert!(union.unwrap().validate(ArrayValidation::Basic).is_err());
+}
+
 // check that we ca derive stuff
 #[derive(PartialEq, Clone, Debug)]
 struct A {
     array: Box<dyn Array>,
 }
+
+#[test]
+fn get() {
+    use arrow2::array::{MapArray, StructArray};
+    use arrow2::scalar::{
+        DictionaryScalar, ListScalar, MapScalar, NullScalar, PrimitiveScalar, Scalar, StructScalar,
+        Utf8Scalar,
+    };
+
+    let array = Int32Array::from([Some(1), None]);
+    assert_eq!(
+        array.get(0).as_ref(),
+        &PrimitiveScalar::from(Some(1i32)) as &dyn Scalar
+    );
+    assert!(!array.get(1).is_valid());
+
+    let array = new_null_array(DataType::Null, 1);
+    assert_eq!(array.get(0).as_ref(), &NullScalar::new() as &dyn Scalar);
+
+    let mut list = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+    list.try_extend(vec![Some(vec![Some(1), None]), None])
+        .unwrap();
+    let list: ListArray<i32> = list.into();
+    let expected = ListScalar::<i32>::new(
+        list.data_type().clone(),
+        Some(Int32Array::from([Some(1), None]).boxed()),
+    );
+    assert_eq!(list.get(0).as_ref(), &expected as &dyn Scalar);
+    assert!(!list.get(1).is_valid());
+
+    let fields = vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+    ];
+    let array = StructArray::new(
+        DataType::Struct(fields.clone()),
+        vec![
+            Int32Array::from_slice([1, 2]).boxed(),
+            Utf8Array::<i32>::from([Some("a"), None]).boxed(),
+        ],
+        None,
+    );
+    let expected = StructScalar::new(
+        DataType::Struct(fields.clone()),
+        Some(vec![
+            Box::new(PrimitiveScalar::from(Some(2i32))) as Box<dyn Scalar>,
+            Box::new(Utf8Scalar::<i32>::new(None::<&str>)),
+        ]),
+    );
+    assert_eq!(array.get(1).as_ref(), &expected as &dyn Scalar);
+
+    let data_type = DataType::Map(
+        Box::new(Field::new("entries", DataType::Struct(fields), false)),
+        false,
+    );
+    let array = MapArray::new(
+        data_type.clone(),
+        vec![0, 2, 2].try_into().unwrap(),
+        array.boxed(),
+        Some(Bitmap::from([true, false])),
+    );
+    let expected = MapScalar::new(data_type.clone(), Some(array.value(0)));
+    assert_eq!(array.get(0).as_ref(), &expected as &dyn Scalar);
+    let expected = MapScalar::new(data_type, None);
+    assert_eq!(array.get(1).as_ref(), &expected as &dyn Scalar);
+
+    let array = DictionaryArray::try_from_keys(
+        PrimitiveArray::from([Some(1i32), None]),
+        Utf8Array::<i32>::from_slice(["a", "b"]).boxed(),
+    )
+    .unwrap();
+    let scalar = array.get(0);
+    let scalar = scalar
+        .as_any()
+        .downcast_ref::<DictionaryScalar<i32>>()
+        .unwrap();
+    assert_eq!(
+        scalar.value().unwrap().as_ref(),
+        &Utf8Scalar::<i32>::new(Some("b")) as &dyn Scalar
+    );
+    assert!(!array.get(1).is_valid());
+
+    // the same scalar is returned through `dyn Array`
+    let array: Box<dyn Array> = array.boxed();
+    assert_eq!(array.get(0).as_ref(), array.sliced(0..1).get(0).as_ref());
+}
+
+#[test]
+#[should_panic]
+fn get_out_of_bounds() {
+    new_null_array(DataType::Null, 1).get(1);
+}
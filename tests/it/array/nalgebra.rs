@@ -0,0 +1,37 @@
+use arrow2::array::*;
+use arrow2::bitmap::Bitmap;
+use arrow2::error::Result;
+use nalgebra::{DMatrix, DVector};
+
+#[test]
+fn primitive() -> Result<()> {
+    let array = Float32Array::from([Some(1.0), None, Some(3.0)]);
+    let vector = array.as_nalgebra();
+    assert_eq!(vector.len(), 3);
+    assert_eq!(vector[2], 3.0);
+
+    let values = DVector::from_vec(vec![1.0f32, 2.0, 3.0]);
+    let ptr = values.as_ptr();
+    let array = Float32Array::from_nalgebra(values, Some(Bitmap::from([true, false, true])))?;
+    assert_eq!(array.values().as_ptr(), ptr);
+    assert_eq!(array, Float32Array::from([Some(1.0), None, Some(3.0)]));
+    Ok(())
+}
+
+#[test]
+fn fixed_size_list() -> Result<()> {
+    let values = DMatrix::from_row_slice(2, 3, &[1u16, 2, 3, 4, 5, 6]);
+    let array = FixedSizeListArray::from_nalgebra(values.clone(), None)?;
+    assert_eq!(array.size(), 3);
+    assert_eq!(
+        array.values().as_ref(),
+        &UInt16Array::from_slice([1, 2, 3, 4, 5, 6]) as &dyn Array
+    );
+
+    let matrix = array.as_nalgebra::<u16>()?;
+    assert_eq!(matrix, values);
+    assert_eq!(matrix.row(1)[0], 4);
+
+    assert!(array.as_nalgebra::<i32>().is_err());
+    Ok(())
+}
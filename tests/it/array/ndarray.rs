@@ -0,0 +1,60 @@
+use arrow2::array::*;
+use arrow2::bitmap::Bitmap;
+use arrow2::error::Result;
+use ndarray::{array, s, Array1};
+
+#[test]
+fn primitive() -> Result<()> {
+    let array = Int32Array::from([Some(1), None, Some(3)]);
+    let view = array.as_ndarray();
+    assert_eq!(view.as_ptr(), array.values().as_ptr());
+    assert_eq!(view[2], 3);
+
+    let values = Array1::from_vec(vec![1, 2, 3]);
+    let ptr = values.as_ptr();
+    let validity = Bitmap::from([true, false, true]);
+    let array = Int32Array::from_ndarray(values, Some(validity))?;
+    assert_eq!(array.values().as_ptr(), ptr);
+    assert_eq!(array, Int32Array::from([Some(1), None, Some(3)]));
+    Ok(())
+}
+
+#[test]
+fn primitive_from_sliced() -> Result<()> {
+    let values = Array1::from_vec(vec![1.0f64, 2.0, 3.0, 4.0]);
+    let array = Float64Array::from_ndarray(values.slice_move(s![1..3]), None)?;
+    assert_eq!(array, Float64Array::from_slice([2.0, 3.0]));
+
+    let values = Array1::from_vec(vec![1.0f64, 2.0, 3.0, 4.0]);
+    let array = Float64Array::from_ndarray(values.slice_move(s![..;-2]), None)?;
+    assert_eq!(array, Float64Array::from_slice([4.0, 2.0]));
+
+    let values = Array1::from_vec(vec![1, 2]);
+    assert!(Int32Array::from_ndarray(values, Some(Bitmap::from([true]))).is_err());
+    Ok(())
+}
+
+#[test]
+fn fixed_size_list() -> Result<()> {
+    let values = array![[1i64, 2, 3], [4, 5, 6]];
+    let array =
+        FixedSizeListArray::from_ndarray(values.clone(), Some(Bitmap::from([true, false])))?;
+    assert_eq!(array.size(), 3);
+    assert_eq!(array.len(), 2);
+    assert_eq!(array.null_count(), 1);
+    assert_eq!(array.as_ndarray::<i64>()?, values);
+
+    let sliced = array.slice(1, 1);
+    let sliced = sliced
+        .as_any()
+        .downcast_ref::<FixedSizeListArray>()
+        .unwrap();
+    assert_eq!(sliced.as_ndarray::<i64>()?, array![[4i64, 5, 6]]);
+
+    // column-major layouts are copied in logical order
+    let array = FixedSizeListArray::from_ndarray(values.t().to_owned(), None)?;
+    assert_eq!(array.as_ndarray::<i64>()?, values.t());
+
+    assert!(array.as_ndarray::<i32>().is_err());
+    Ok(())
+}
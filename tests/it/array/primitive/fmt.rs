@@ -221,3 +221,13 @@ fn debug_months_days_ns() {
         "Interval(MonthDayNano)[1m1d2ns, None, 2m3d3ns]"
     );
 }
+
+#[test]
+fn debug_decimal_leading_zeros() {
+    let array =
+        Int128Array::from(&[Some(105), Some(-50), Some(-5), None]).to(DataType::Decimal(5, 2));
+    assert_eq!(
+        format!("{array:?}"),
+        "Decimal(5, 2)[1.05, -0.50, -0.05, None]"
+    );
+}
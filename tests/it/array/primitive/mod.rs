@@ -71,6 +71,14 @@ fn from() {
     assert_eq!(array.len(), 3);
 }
 
+#[test]
+fn from_vec_shared() {
+    let values = std::sync::Arc::new(vec![1, 2, 3]);
+    let array = Int32Array::from_vec_shared(values.clone());
+    assert_eq!(array, Int32Array::from_slice([1, 2, 3]));
+    assert_eq!(array.values().as_slice().as_ptr(), values.as_ptr());
+}
+
 #[test]
 fn months_days_ns_from_slice() {
     let data = &[
@@ -329,3 +329,28 @@ fn extend_from_self() {
         MutablePrimitiveArray::from([Some(1), None, Some(1), None])
     );
 }
+
+#[test]
+fn extend_from_array() {
+    let other = Int32Array::from([Some(1), None, Some(3), Some(4)]).slice(1, 3);
+
+    let mut a = MutablePrimitiveArray::from_slice([0]);
+    a.try_extend_from_array(&other, 1, 2).unwrap();
+    assert_eq!(a.validity(), None);
+    a.try_extend_from_array(&other, 0, 2).unwrap();
+    assert_eq!(
+        a,
+        MutablePrimitiveArray::from([Some(0), Some(3), Some(4), None, Some(3)])
+    );
+    a.try_extend_from_array(&Int32Array::from_slice([5]), 0, 1)
+        .unwrap();
+    assert_eq!(a.len(), 6);
+    assert_eq!(a.validity().unwrap().len(), 6);
+
+    // out of bounds and of a different type
+    assert!(a.try_extend_from_array(&other, 2, 2).is_err());
+    assert!(a
+        .try_extend_from_array(&Int64Array::from_slice([1]), 0, 1)
+        .is_err());
+    assert_eq!(a.len(), 6);
+}
@@ -0,0 +1,40 @@
+use arrow2::array::*;
+use pyo3::prelude::*;
+
+fn round_trip(array: Box<dyn Array>) {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let py_array = to_py(array.as_ref(), py).unwrap();
+        let result = from_py(py_array.as_ref(py)).unwrap();
+        assert_eq!(result, array);
+    })
+}
+
+#[test]
+fn round_trip_primitive() {
+    round_trip(Int32Array::from([Some(1), None, Some(3)]).boxed())
+}
+
+#[test]
+fn round_trip_utf8_sliced() {
+    round_trip(Utf8Array::<i32>::from([Some("a"), None, Some("ccc")]).sliced(1..3))
+}
+
+#[test]
+fn round_trip_list() {
+    let mut array = MutableListArray::<i32, MutablePrimitiveArray<i64>>::new();
+    array
+        .try_extend([Some(vec![Some(1), None]), None, Some(vec![])])
+        .unwrap();
+    let array: ListArray<i32> = array.into();
+    round_trip(array.boxed())
+}
+
+#[test]
+fn from_py_not_exportable() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let error = from_py(py.None().as_ref(py)).unwrap_err();
+        assert!(error.is_instance_of::<pyo3::exceptions::PyAttributeError>(py));
+    })
+}
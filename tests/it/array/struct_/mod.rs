@@ -25,3 +25,44 @@ fn debug() {
         "StructArray[{b: false, c: 42}, {b: false, c: 28}, None, {b: true, c: 31}]"
     );
 }
+
+#[test]
+fn from_iter() {
+    let data = vec![Some((Some(1i32), Some("a"))), None, Some((None, Some("c")))];
+    let values = || {
+        (
+            MutablePrimitiveArray::<i32>::new(),
+            MutableUtf8Array::<i32>::new(),
+        )
+    };
+
+    let array = StructArray::try_from_iter(&["a", "b"], values(), data.clone()).unwrap();
+
+    let fields = vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+    ];
+    let expected = StructArray::new(
+        DataType::Struct(fields),
+        vec![
+            Int32Array::from([Some(1), None, None]).boxed(),
+            Utf8Array::<i32>::from([Some("a"), None, Some("c")]).boxed(),
+        ],
+        Some(Bitmap::from([true, false, true])),
+    );
+    assert_eq!(array, expected);
+
+    let array = StructArray::from_trusted_len_iter(&["a", "b"], values(), data.clone().into_iter());
+    assert_eq!(array, expected);
+
+    let array = StructArray::try_from_trusted_len_iter::<arrow2::error::Error, _, _, _>(
+        &["a", "b"],
+        values(),
+        data.clone().into_iter().map(Ok),
+    )
+    .unwrap();
+    assert_eq!(array, expected);
+
+    // the names must match the fields
+    assert!(StructArray::try_from_iter(&["a"], values(), data).is_err());
+}
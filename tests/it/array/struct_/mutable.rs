@@ -31,3 +31,34 @@ fn push() {
         &Vec::from([1, 0, 2])
     );
 }
+
+#[test]
+fn extend_from_array() {
+    let data_type = DataType::Struct(vec![
+        Field::new("f1", DataType::Int32, true),
+        Field::new("f2", DataType::Utf8, true),
+    ]);
+    let other = StructArray::new(
+        data_type.clone(),
+        vec![
+            Int32Array::from([Some(1), Some(2), None]).boxed(),
+            Utf8Array::<i32>::from([Some("a"), None, Some("c")]).boxed(),
+        ],
+        Some([true, false, true].into()),
+    );
+
+    let values = vec![
+        Box::new(MutablePrimitiveArray::<i32>::new()) as Box<dyn MutableArray>,
+        Box::new(MutableUtf8Array::<i32>::new()),
+    ];
+    let mut a = MutableStructArray::new(data_type, values);
+    a.try_extend_from_array(&other, 1, 2).unwrap();
+    assert_eq!(a.len(), 2);
+    let array = a.as_box();
+    assert_eq!(array.as_ref(), &other.slice(1, 2) as &dyn Array);
+
+    // an array of a different type
+    assert!(a
+        .try_extend_from_array(&Int32Array::from_slice([1]), 0, 1)
+        .is_err());
+}
@@ -371,3 +371,137 @@ fn types_must_be_in_bounds() -> Result<()> {
     assert!(UnionArray::try_new(data_type, types, fields.clone(), None).is_err());
     Ok(())
 }
+
+fn mutable_union(mode: UnionMode, ids: Option<Vec<i32>>) -> MutableUnionArray {
+    let fields = vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+    ];
+    let data_type = DataType::Union(fields, ids, mode);
+    let mut array = MutableUnionArray::new(
+        data_type,
+        vec![
+            Box::new(MutablePrimitiveArray::<i32>::new()),
+            Box::new(MutableUtf8Array::<i32>::new()),
+        ],
+    );
+    array
+        .push_variant::<MutablePrimitiveArray<i32>, _>(0, Some(1))
+        .unwrap();
+    array
+        .push_variant::<MutableUtf8Array<i32>, _>(1, Some("a"))
+        .unwrap();
+    array.push_null();
+    array
+        .push_variant::<MutableUtf8Array<i32>, _>(1, Some("b"))
+        .unwrap();
+    array
+}
+
+#[test]
+fn mutable_dense() -> Result<()> {
+    let array = mutable_union(UnionMode::Dense, None).into_dense();
+
+    let fields = vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+    ];
+    let expected = UnionArray::try_new(
+        DataType::Union(fields, None, UnionMode::Dense),
+        vec![0, 1, 0, 1].into(),
+        vec![
+            Int32Array::from([Some(1), None]).boxed(),
+            Utf8Array::<i32>::from_slice(["a", "b"]).boxed(),
+        ],
+        Some(vec![0, 0, 1, 1].into()),
+    )?;
+    assert_eq!(array, expected);
+    Ok(())
+}
+
+#[test]
+fn mutable_sparse() -> Result<()> {
+    let array: UnionArray = mutable_union(UnionMode::Sparse, Some(vec![5, 7])).into();
+
+    let fields = vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+    ];
+    let expected = UnionArray::try_new(
+        DataType::Union(fields, Some(vec![5, 7]), UnionMode::Sparse),
+        vec![5, 7, 5, 7].into(),
+        vec![
+            Int32Array::from([Some(1), None, None, None]).boxed(),
+            Utf8Array::<i32>::from([None, Some("a"), None, Some("b")]).boxed(),
+        ],
+        None,
+    )?;
+    assert_eq!(array, expected);
+
+    // the layout is independent of the builder's mode
+    let dense = mutable_union(UnionMode::Sparse, Some(vec![5, 7])).into_dense();
+    assert_eq!(dense.offsets().unwrap().as_slice(), &[0, 0, 1, 1]);
+    assert_eq!(format!("{dense:?}"), format!("{array:?}"));
+    Ok(())
+}
+
+#[test]
+fn mutable_errors() {
+    let mut array = mutable_union(UnionMode::Dense, None);
+    assert!(array
+        .push_variant::<MutableUtf8Array<i32>, _>(0, Some("a"))
+        .is_err());
+    assert!(array
+        .push_variant::<MutablePrimitiveArray<i32>, _>(2, Some(1))
+        .is_err());
+    assert_eq!(array.len(), 4);
+
+    let fields = vec![Field::new("a", DataType::Int32, true)];
+    let data_type = DataType::Union(fields, None, UnionMode::Dense);
+    let values = MutablePrimitiveArray::<i32>::from([Some(1)]);
+    assert!(MutableUnionArray::try_new(data_type.clone(), vec![Box::new(values)]).is_err());
+    let values = MutableUtf8Array::<i32>::new();
+    assert!(MutableUnionArray::try_new(data_type, vec![Box::new(values)]).is_err());
+}
+
+#[test]
+fn child_selected() -> Result<()> {
+    let fields = vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+    ];
+    let data_type = DataType::Union(fields.clone(), Some(vec![5, 7]), UnionMode::Sparse);
+    let array = UnionArray::new(
+        data_type,
+        vec![5, 7, 5, 7].into(),
+        vec![
+            Int32Array::from([Some(1), Some(2), None, Some(4)]).boxed(),
+            Utf8Array::<i32>::from([Some("a"), Some("b"), Some("c"), None]).boxed(),
+        ],
+        None,
+    );
+    assert_eq!(
+        array.child_selected(0),
+        Int32Array::from([Some(1), None]).boxed()
+    );
+    assert_eq!(
+        array.slice(1, 3).child_selected(1),
+        Utf8Array::<i32>::from([Some("b"), None]).boxed()
+    );
+
+    let data_type = DataType::Union(fields, None, UnionMode::Dense);
+    let array = UnionArray::new(
+        data_type,
+        vec![1, 0, 1].into(),
+        vec![
+            Int32Array::from_slice([1]).boxed(),
+            Utf8Array::<i32>::from_slice(["a", "b"]).boxed(),
+        ],
+        Some(vec![1, 0, 0].into()),
+    );
+    assert_eq!(
+        array.child_selected(1),
+        Utf8Array::<i32>::from_slice(["b", "a"]).boxed()
+    );
+    Ok(())
+}
@@ -0,0 +1,50 @@
+use arrow2::array::{DeferredUtf8Builder, Utf8Array};
+use arrow2::error::Error;
+
+#[test]
+fn push() {
+    let data = "abcdef".to_string();
+    let mut builder = DeferredUtf8Builder::<i32>::new();
+    assert!(builder.is_empty());
+    builder.push_value(&data[..2]);
+    builder.push(Some(&data[2..3]));
+    builder.push_null();
+    builder.push(Some(""));
+    assert_eq!(builder.len(), 4);
+    assert_eq!(builder.values_len(), 3);
+
+    let array = builder.freeze().unwrap();
+    assert_eq!(
+        array,
+        Utf8Array::<i32>::from([Some("ab"), Some("c"), None, Some("")])
+    );
+    assert_eq!(array.values().len(), 3);
+}
+
+#[test]
+fn without_nulls() {
+    let builder = ["a", "bc"]
+        .into_iter()
+        .map(Some)
+        .collect::<DeferredUtf8Builder<i64>>();
+    let array = builder.freeze().unwrap();
+    assert_eq!(array, Utf8Array::<i64>::from_slice(["a", "bc"]));
+    assert!(array.validity().is_none());
+}
+
+#[test]
+fn extend_nulls_first() {
+    let mut builder = DeferredUtf8Builder::<i32>::default();
+    builder.extend([None, None, Some("a")]);
+    let array = builder.freeze().unwrap();
+    assert_eq!(array, Utf8Array::<i32>::from([None, None, Some("a")]));
+}
+
+#[test]
+fn overflow() {
+    let value = "a".repeat(1 << 16);
+    let mut builder = DeferredUtf8Builder::<i32>::new();
+    // the values are not copied until `freeze`, so this does not allocate 2GB
+    (0..(1 << 15) + 1).for_each(|_| builder.push_value(&value));
+    assert!(matches!(builder.freeze(), Err(Error::Overflow)));
+}
@@ -3,6 +3,7 @@ use arrow2::{
     offset::OffsetsBuffer,
 };
 
+mod deferred;
 mod mutable;
 mod mutable_values;
 mod to_mutable;
@@ -233,3 +234,9 @@ fn test_apply_validity() {
     assert!(!array.is_valid(1));
     assert!(!array.is_valid(2));
 }
+
+#[test]
+fn try_from_iter() {
+    let array = Utf8Array::<i32>::try_from_iter([Some("a"), None]).unwrap();
+    assert_eq!(array, Utf8Array::<i32>::from([Some("a"), None]));
+}
@@ -199,3 +199,17 @@ fn extend_from_self() {
         MutableUtf8Array::<i32>::from([Some("aa"), None, Some("aa"), None])
     );
 }
+
+#[test]
+fn extend_from_array() {
+    let other = Utf8Array::<i32>::from([Some("a"), None, Some("bc"), Some("d")]).slice(1, 3);
+
+    let mut a = MutableUtf8Array::<i32>::from([Some("x")]);
+    a.try_extend_from_array(&other, 1, 2).unwrap();
+    assert_eq!(a.validity(), None);
+    a.try_extend_from_array(&other, 0, 2).unwrap();
+    a.try_extend_from_array(&other, 3, 0).unwrap();
+    let array: Utf8Array<i32> = a.into();
+    let expected = Utf8Array::<i32>::from([Some("x"), Some("bc"), Some("d"), None, Some("bc")]);
+    assert_eq!(array, expected);
+}
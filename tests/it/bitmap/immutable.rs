@@ -39,3 +39,42 @@ fn debug() {
 
     assert_eq!(format!("{b:?}"), "[0b111110__, 0b_______1]");
 }
+
+#[test]
+fn from_packed() {
+    let b = Bitmap::from_packed(vec![0b00011010, 0b1], 9, 1).unwrap();
+    assert_eq!(b.len(), 9);
+    assert_eq!(b.unset_bits(), 5);
+    assert_eq!(
+        b.iter().collect::<Vec<_>>(),
+        vec![true, false, true, true, false, false, false, true, false]
+    );
+
+    assert!(Bitmap::from_packed(vec![0b1], 8, 1).is_err());
+}
+
+#[test]
+fn into_packed() {
+    let b = Bitmap::from_packed(vec![0b00011010, 0b1], 9, 1).unwrap();
+    let b = b.slice(1, 3);
+    let (bytes, offset, length) = b.clone().into_packed();
+    assert_eq!(Bitmap::from_packed(bytes, length, offset).unwrap(), b);
+}
+
+#[test]
+fn sliced() {
+    let b = Bitmap::from([true, false, true, true]);
+    let sliced = b.sliced(1, 2);
+    assert_eq!(sliced, Bitmap::from([false, true]));
+    assert_eq!(sliced.unset_bits(), 1);
+    assert_eq!(b.len(), 4);
+}
+
+#[test]
+fn slice_all_set_or_unset() {
+    let b = Bitmap::from([true; 20]).slice(3, 10);
+    assert_eq!(b.unset_bits(), 0);
+
+    let b = Bitmap::from([false; 20]).slice(3, 10);
+    assert_eq!(b.unset_bits(), 10);
+}
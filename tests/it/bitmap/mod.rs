@@ -4,6 +4,9 @@ mod immutable;
 mod mutable;
 mod utils;
 
+#[cfg(feature = "roaring")]
+mod roaring;
+
 use proptest::prelude::*;
 
 use arrow2::bitmap::Bitmap;
@@ -0,0 +1,52 @@
+use proptest::prelude::*;
+use roaring::RoaringBitmap;
+
+use arrow2::array::UInt32Array;
+use arrow2::bitmap::Bitmap;
+
+use super::bitmap_strategy;
+
+#[test]
+fn to_roaring() {
+    let bitmap = Bitmap::from([false, true, true, false, true]).slice(1, 4);
+    let roaring = bitmap.to_roaring().unwrap();
+    assert_eq!(roaring.iter().collect::<Vec<_>>(), vec![0, 1, 3]);
+}
+
+#[test]
+fn from_roaring() {
+    let roaring = RoaringBitmap::from_iter([1, 8, 9]);
+    let bitmap = Bitmap::from_roaring(&roaring, 10).unwrap();
+    assert_eq!(bitmap.unset_bits(), 7);
+    assert_eq!(
+        bitmap.iter().collect::<Vec<_>>(),
+        vec![false, true, false, false, false, false, false, false, true, true]
+    );
+
+    assert!(Bitmap::from_roaring(&roaring, 9).is_err());
+    assert_eq!(
+        Bitmap::from_roaring(&RoaringBitmap::new(), 0).unwrap(),
+        Bitmap::new()
+    );
+}
+
+#[test]
+fn indices() {
+    let roaring = RoaringBitmap::from_iter([7, 1, 4]);
+    let indices = UInt32Array::from_roaring(&roaring);
+    assert_eq!(indices, UInt32Array::from_slice([1, 4, 7]));
+    assert_eq!(indices.to_roaring(), roaring);
+
+    let indices = UInt32Array::from([Some(2), None, Some(0)]);
+    assert_eq!(indices.to_roaring(), RoaringBitmap::from_iter([0, 2]));
+}
+
+proptest! {
+    #[test]
+    #[cfg_attr(miri, ignore)] // miri and proptest do not work well :(
+    fn round_trip(bitmap in bitmap_strategy()) {
+        let roaring = bitmap.to_roaring().unwrap();
+        assert_eq!(roaring.len() as usize, bitmap.len() - bitmap.unset_bits());
+        assert_eq!(Bitmap::from_roaring(&roaring, bitmap.len()).unwrap(), bitmap);
+    }
+}
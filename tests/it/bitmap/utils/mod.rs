@@ -66,6 +66,19 @@ fn count_zeros_basics() {
     assert_eq!(count_zeros(input, 14, 2), 1);
 }
 
+#[test]
+fn count_zeros_large() {
+    let input = (0..200u8).map(|x| x.wrapping_mul(37)).collect::<Vec<_>>();
+    for offset in [0, 3, 8, 13] {
+        for len in [64, 100, 1000, 1500 - offset] {
+            let expected = (offset..offset + len)
+                .filter(|i| !get_bit(&input, *i))
+                .count();
+            assert_eq!(count_zeros(&input, offset, len), expected);
+        }
+    }
+}
+
 #[test]
 fn count_zeros_1() {
     // offset = 10, len = 90 => remainder
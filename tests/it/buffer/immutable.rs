@@ -43,3 +43,46 @@ fn from_vec() {
     assert_eq!(buffer.len(), 3);
     assert_eq!(buffer.as_slice(), &[0, 1, 2]);
 }
+
+#[test]
+fn into_mut() {
+    let buffer = Buffer::<i32>::from(vec![0, 1, 2, 3]);
+    let shared = buffer.clone();
+    let buffer = buffer.into_mut().unwrap_left();
+    drop(shared);
+    assert_eq!(buffer.into_mut().unwrap_right(), vec![0, 1, 2, 3]);
+
+    let buffer = Buffer::<i32>::from(vec![0, 1, 2, 3]);
+    assert!(buffer.clone().slice(1, 2).into_mut().is_left());
+    // slices at the start keep only their values
+    assert_eq!(buffer.slice(0, 2).into_mut().unwrap_right(), vec![0, 1]);
+}
+
+#[test]
+fn from_arc_vec() {
+    let values = std::sync::Arc::new(vec![0, 1, 2]);
+    let buffer = Buffer::<i32>::from(values.clone());
+    assert_eq!(buffer.as_slice(), &[0, 1, 2]);
+    // the region is shared, not copied
+    assert_eq!(buffer.as_slice().as_ptr(), values.as_ptr());
+
+    let mut buffer = buffer.slice(1, 2);
+    assert_eq!(buffer.as_slice(), &[1, 2]);
+    assert_eq!(buffer.get_mut(), None);
+}
+
+#[test]
+fn from_unique_arc_vec() {
+    let mut buffer = Buffer::<i32>::from(std::sync::Arc::new(vec![0, 1, 2]));
+    assert_eq!(buffer.get_mut(), Some(&mut vec![0, 1, 2]));
+}
+
+#[test]
+fn from_arc_slice() {
+    let values: std::sync::Arc<[i32]> = vec![0, 1, 2].into();
+    let buffer = Buffer::<i32>::from(values.clone());
+    assert_eq!(buffer.as_slice(), &[0, 1, 2]);
+    assert_eq!(buffer.as_slice().as_ptr(), values.as_ptr());
+    drop(buffer);
+    assert_eq!(std::sync::Arc::strong_count(&values), 1);
+}
@@ -0,0 +1,141 @@
+use arrow2::array::*;
+use arrow2::chunk::Chunk;
+use arrow2::datatypes::{DataType, Field, Schema};
+use arrow2::error::Error;
+
+fn schema() -> Schema {
+    Schema::from(vec![
+        Field::new("a", DataType::Int32, false),
+        Field::new("b", DataType::Utf8, true),
+    ])
+}
+
+#[test]
+fn with_schema() {
+    let arrays = vec![
+        Int32Array::from_slice([1, 2]).boxed(),
+        Utf8Array::<i32>::from([Some("a"), None]).boxed(),
+    ];
+    let chunk = Chunk::try_new_with_schema(&schema(), arrays).unwrap();
+    assert_eq!(chunk.len(), 2);
+}
+
+#[test]
+fn with_schema_reports_all_errors() {
+    let arrays = vec![
+        Int32Array::from([Some(1), None]).boxed(),
+        Int64Array::from_slice([1, 2, 3]).boxed(),
+    ];
+    let error = Chunk::try_new_with_schema(&schema(), arrays).unwrap_err();
+    let message = match error {
+        Error::InvalidArgumentError(message) => message,
+        _ => panic!(),
+    };
+    assert!(message.contains("field \"a\" is not nullable"), "{message}");
+    assert!(message.contains("field \"b\" has 3 rows"), "{message}");
+    assert!(
+        message.contains("field \"b\" has data type Utf8"),
+        "{message}"
+    );
+}
+
+#[test]
+fn with_schema_number_of_fields() {
+    let arrays = vec![Int32Array::from_slice([1]).boxed()];
+    assert!(Chunk::try_new_with_schema(&schema(), arrays).is_err());
+}
+
+#[test]
+fn equals() {
+    let boxed = Chunk::new(vec![Int32Array::from_slice([1, 2]).boxed()]);
+    let arced = Chunk::new(vec![Int32Array::from_slice([1, 2]).arced()]);
+    assert!(boxed.equals(&arced));
+
+    let other = Chunk::new(vec![Int32Array::from([Some(1), None]).arced()]);
+    assert!(!boxed.equals(&other));
+    let other = Chunk::new(vec![arced.arrays()[0].clone(), arced.arrays()[0].clone()]);
+    assert!(!boxed.equals(&other));
+}
+
+mod diff {
+    use arrow2::util::diff::*;
+
+    use super::*;
+
+    fn structs(values: [Option<i32>; 2]) -> Box<dyn Array> {
+        let data_type = DataType::Struct(vec![Field::new("a", DataType::Int32, true)]);
+        StructArray::new(data_type, vec![Int32Array::from(values).boxed()], None).boxed()
+    }
+
+    #[test]
+    fn equal() {
+        let chunk = Chunk::new(vec![structs([Some(1), None])]);
+        assert_eq!(diff_chunks(&chunk, &chunk), None);
+    }
+
+    #[test]
+    fn value() {
+        let left = Chunk::new(vec![
+            Int32Array::from_slice([1, 2]).boxed(),
+            structs([Some(1), Some(2)]),
+        ]);
+        let right = Chunk::new(vec![
+            Int32Array::from_slice([1, 2]).boxed(),
+            structs([Some(1), None]),
+        ]);
+        let difference = diff_chunks(&left, &right).unwrap();
+        assert_eq!(
+            difference,
+            ChunkDifference::Column {
+                index: 1,
+                difference: Difference::Value {
+                    row: 1,
+                    left: "{a: 2}".to_string(),
+                    right: "{a: null}".to_string(),
+                },
+            }
+        );
+        assert_eq!(
+            difference.to_string(),
+            "column 1: row 1 differs: left is {a: 2}, right is {a: null}"
+        );
+    }
+
+    #[test]
+    fn shape() {
+        let left = Int32Array::from_slice([1, 2]);
+        assert_eq!(
+            diff(&left, &Int64Array::from_slice([1, 2])),
+            Some(Difference::DataType {
+                left: DataType::Int32,
+                right: DataType::Int64
+            })
+        );
+        assert_eq!(
+            diff(&left, &Int32Array::from_slice([1])),
+            Some(Difference::Length { left: 2, right: 1 })
+        );
+
+        let left = Chunk::new(vec![left.boxed()]);
+        let right = Chunk::new(vec![left.arrays()[0].clone(), left.arrays()[0].clone()]);
+        assert_eq!(
+            diff_chunks(&left, &right),
+            Some(ChunkDifference::Columns { left: 1, right: 2 })
+        );
+    }
+}
+
+#[test]
+fn slice() {
+    let chunk = Chunk::new(vec![
+        Int32Array::from_slice([1, 2, 3]).boxed(),
+        Utf8Array::<i32>::from([Some("a"), None, Some("c")]).boxed(),
+    ]);
+    let expected = Chunk::new(vec![
+        Int32Array::from_slice([2, 3]).boxed(),
+        Utf8Array::<i32>::from([None, Some("c")]).boxed(),
+    ]);
+    assert_eq!(chunk.sliced(1..3), expected);
+    assert_eq!(chunk.try_slice(1, 2).unwrap(), expected);
+    assert!(chunk.try_slice(2, 2).is_err());
+}
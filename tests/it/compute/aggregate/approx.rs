@@ -0,0 +1,106 @@
+use arrow2::array::*;
+use arrow2::compute::aggregate::approx::*;
+use arrow2::error::Result;
+
+fn assert_close(estimate: u64, expected: u64, tolerance: f64) {
+    let error = (estimate as f64 - expected as f64).abs() / expected as f64;
+    assert!(
+        error < tolerance,
+        "estimate {estimate} is too far from {expected}"
+    );
+}
+
+#[test]
+fn distinct_primitive() -> Result<()> {
+    let array = Int64Array::from_vec((0..100_000).map(|x| x % 10_000).collect());
+    assert_close(approx_distinct(&array)?, 10_000, 0.03);
+    Ok(())
+}
+
+#[test]
+fn distinct_small() -> Result<()> {
+    let array = Int32Array::from([Some(1), None, Some(2), Some(1), None, Some(3)]);
+    assert_eq!(approx_distinct(&array)?, 3);
+
+    let array = Int32Array::from([None, None]);
+    assert_eq!(approx_distinct(&array)?, 0);
+    Ok(())
+}
+
+#[test]
+fn distinct_utf8_and_dictionary() -> Result<()> {
+    let values = (0..1000).map(|x| format!("value {}", x % 100));
+    let array = Utf8Array::<i32>::from_iter_values(values.clone());
+    assert_eq!(approx_distinct(&array)?, 100);
+
+    let mut dictionary = MutableDictionaryArray::<i32, MutableUtf8Array<i32>>::new();
+    dictionary.try_extend(values.map(Some))?;
+    let dictionary: DictionaryArray<i32> = dictionary.into();
+    assert_eq!(approx_distinct(&dictionary)?, 100);
+    Ok(())
+}
+
+#[test]
+fn distinct_merge() -> Result<()> {
+    let mut lhs = HyperLogLog::default();
+    lhs.update(&Int64Array::from_vec((0..5000).collect()))?;
+    let mut rhs = HyperLogLog::default();
+    rhs.update(&Int64Array::from_vec((2500..7500).collect()))?;
+
+    lhs.merge(&rhs)?;
+    assert_close(lhs.estimate(), 7500, 0.03);
+
+    assert!(lhs.merge(&HyperLogLog::new(10)).is_err());
+    assert!(HyperLogLog::try_new(3).is_err());
+    Ok(())
+}
+
+#[test]
+fn quantile() {
+    let array = Float64Array::from_vec((0..=10_000).map(|x| x as f64).collect());
+    assert_eq!(approx_quantile(&array, 0.0), Some(0.0));
+    assert_eq!(approx_quantile(&array, 1.0), Some(10_000.0));
+
+    let median = approx_quantile(&array, 0.5).unwrap();
+    assert!((median - 5000.0).abs() < 50.0, "{median}");
+    let p99 = approx_quantile(&array, 0.99).unwrap();
+    assert!((p99 - 9900.0).abs() < 10.0, "{p99}");
+}
+
+#[test]
+fn quantile_nulls() {
+    let array = Int32Array::from([None, Some(3), None, Some(1), Some(2)]);
+    assert_eq!(approx_quantile(&array, 0.5), Some(2.0));
+
+    let array = Int32Array::from([None, None]);
+    assert_eq!(approx_quantile(&array, 0.5), None);
+}
+
+#[test]
+fn quantile_merge() {
+    let mut lhs = TDigest::default();
+    lhs.update(&Int32Array::from_vec((0..5000).collect()));
+    let mut rhs = TDigest::default();
+    rhs.update(&Int32Array::from_vec((5000..10_000).collect()));
+
+    lhs.merge(&rhs);
+    assert_eq!(lhs.count(), 10_000);
+    let median = lhs.quantile(0.5).unwrap();
+    assert!((median - 5000.0).abs() < 50.0, "{median}");
+    assert!(lhs.centroids().len() < 10_000);
+}
+
+#[test]
+fn quantile_merge_compressed() {
+    let mut lhs = TDigest::new(10.0);
+    lhs.update(&Int32Array::from_vec((0..5000).collect()));
+    let mut rhs = TDigest::new(10.0);
+    rhs.update(&Int32Array::from_vec((5000..10_000).collect()));
+    // compress both digests, so that their extremes are not centroids
+    assert!(lhs.centroids().len() < 5000);
+    assert!(rhs.centroids().len() < 5000);
+
+    lhs.merge(&rhs);
+    assert_eq!(lhs.quantile(0.0), Some(0.0));
+    assert_eq!(lhs.quantile(1.0), Some(9999.0));
+}
@@ -0,0 +1,78 @@
+use arrow2::array::*;
+use arrow2::compute::aggregate::{can_count_distinct, count_distinct, count_false, count_true};
+use arrow2::datatypes::DataType;
+
+#[test]
+fn boolean() {
+    let array = BooleanArray::from_slice([true, false, true]);
+    assert_eq!(count_true(&array), 2);
+    assert_eq!(count_false(&array), 1);
+
+    // more than 64 slots, sliced so that neither bitmap starts at a byte boundary
+    let array = BooleanArray::from_iter((0..200).map(|i| match i % 3 {
+        0 => Some(true),
+        1 => Some(false),
+        _ => None,
+    }));
+    let array = array.slice(3, 190);
+    let expected = array.iter().filter(|x| *x == Some(true)).count();
+    assert_eq!(count_true(&array), expected);
+    let expected = array.iter().filter(|x| *x == Some(false)).count();
+    assert_eq!(count_false(&array), expected);
+}
+
+#[test]
+fn boolean_empty() {
+    let array = BooleanArray::new_null(DataType::Boolean, 10);
+    assert_eq!(count_true(&array), 0);
+    assert_eq!(count_false(&array), 0);
+}
+
+#[test]
+fn distinct() {
+    let array = Int32Array::from([Some(1), None, Some(2), Some(1), None]);
+    assert_eq!(count_distinct(&array).unwrap(), 2);
+
+    let array = Float64Array::from_slice([f64::NAN, f64::NAN, 1.0]);
+    assert_eq!(count_distinct(&array).unwrap(), 2);
+
+    // zeros of either sign and NaNs of any payload are equal
+    let other_nan = f64::from_bits(f64::NAN.to_bits() | 1);
+    let array = Float64Array::from_slice([0.0, -0.0, f64::NAN, -f64::NAN, other_nan]);
+    assert_eq!(count_distinct(&array).unwrap(), 2);
+
+    let array = Float32Array::from_slice([0.0, -0.0, f32::NAN, -f32::NAN, 1.0]);
+    assert_eq!(count_distinct(&array).unwrap(), 3);
+
+    let array = BooleanArray::from([Some(true), None, Some(true)]);
+    assert_eq!(count_distinct(&array).unwrap(), 1);
+
+    let array = Utf8Array::<i64>::from([Some("a"), Some("b"), None, Some("a")]);
+    assert_eq!(count_distinct(&array).unwrap(), 2);
+
+    assert_eq!(
+        count_distinct(&NullArray::new(DataType::Null, 3)).unwrap(),
+        0
+    );
+}
+
+#[test]
+fn distinct_dictionary() {
+    // keys 0 and 2 point to equal values, and key 1 to a null value
+    let values = Utf8Array::<i32>::from([Some("a"), None, Some("a"), Some("b")]).boxed();
+    let keys = PrimitiveArray::from([Some(0u8), Some(1), Some(2), None, Some(0)]);
+    let array = DictionaryArray::try_from_keys(keys, values).unwrap();
+    assert_eq!(count_distinct(&array).unwrap(), 1);
+}
+
+#[test]
+fn distinct_unsupported() {
+    let array = ListArray::<i32>::new_null(ListArray::<i32>::default_datatype(DataType::Int32), 1);
+    assert!(!can_count_distinct(array.data_type()));
+    assert!(count_distinct(&array).is_err());
+    assert!(can_count_distinct(&DataType::Dictionary(
+        arrow2::datatypes::IntegerType::Int32,
+        Box::new(DataType::Utf8),
+        false
+    )));
+}
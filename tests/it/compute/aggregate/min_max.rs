@@ -1,7 +1,10 @@
 use arrow2::compute::aggregate::{
-    max_binary, max_boolean, max_primitive, max_string, min_binary, min_boolean, min_primitive,
-    min_string,
+    max, max_binary, max_boolean, max_f16, max_primitive, max_string, min, min_binary, min_boolean,
+    min_f16, min_max, min_max_binary, min_max_boolean, min_max_primitive, min_max_string,
+    min_primitive, min_string,
 };
+use arrow2::scalar::{BinaryScalar, PrimitiveScalar, Scalar};
+use arrow2::types::f16;
 use arrow2::{array::*, datatypes::DataType};
 
 #[test]
@@ -39,6 +42,20 @@ fn min_max_f32() {
     assert_eq!(Some(5.0), max_primitive(&a));
 }
 
+#[test]
+fn min_max_f16() {
+    let a: Float16Array = [None, Some(5.0), Some(-2.5), Some(3.0)]
+        .iter()
+        .map(|x| x.map(f16::from_f32))
+        .collect();
+    assert_eq!(Some(f16::from_f32(-2.5)), min_f16(&a));
+    assert_eq!(Some(f16::from_f32(5.0)), max_f16(&a));
+    assert_eq!(
+        &PrimitiveScalar::from(Some(f16::from_f32(5.0))) as &dyn Scalar,
+        max(&a).unwrap().as_ref()
+    );
+}
+
 #[test]
 fn min_max_f64() {
     let a = Float64Array::from(&[None, None, Some(5.0), Some(2.0)]);
@@ -228,6 +245,19 @@ fn test_binary_min_max_1() {
     assert_eq!(Some("b".as_bytes()), max_binary(&a));
 }
 
+#[test]
+fn large_binary_min_max_dyn() {
+    let a = BinaryArray::<i64>::from([None, Some(b"b"), Some(b"c"), Some(b"a")]);
+    assert_eq!(
+        &BinaryScalar::<i64>::new(Some(b"a")) as &dyn Scalar,
+        min(&a).unwrap().as_ref()
+    );
+    assert_eq!(
+        &BinaryScalar::<i64>::new(Some(b"c")) as &dyn Scalar,
+        max(&a).unwrap().as_ref()
+    );
+}
+
 #[test]
 fn test_max_not_lexi() {
     let values = [0, 10, 0, 0, 0, 0, 0, 0, 1, 0];
@@ -237,3 +267,81 @@ fn test_max_not_lexi() {
     let out = max_primitive(&arr).unwrap();
     assert_eq!(out, maximum);
 }
+
+#[test]
+fn fused_min_max_primitive() {
+    let a = Int32Array::from_slice([5, 6, 7, 8, 9]);
+    assert_eq!(Some((5, 9)), min_max_primitive(&a));
+
+    let a = Int32Array::from(&[None, None, Some(5), Some(2)]);
+    assert_eq!(Some((2, 5)), min_max_primitive(&a));
+
+    let a = Int32Array::from(&[None, None]);
+    assert_eq!(None, min_max_primitive(&a));
+
+    // longer than the lanes and with an offset validity
+    let a: Int64Array = (0..100)
+        .map(|x| if x % 3 == 0 { None } else { Some(x - 50) })
+        .collect();
+    let a = a.slice(3, 90);
+    assert_eq!(
+        min_max_primitive(&a),
+        Some((min_primitive(&a).unwrap(), max_primitive(&a).unwrap()))
+    );
+
+    let a = Float64Array::from(&[None, Some(1.0), Some(f64::NAN)]);
+    assert_eq!(
+        min_max_primitive(&a),
+        Some((min_primitive(&a).unwrap(), max_primitive(&a).unwrap()))
+    );
+}
+
+#[test]
+fn fused_min_max_boolean() {
+    let a = BooleanArray::from(&[Some(true), None, Some(false)]);
+    assert_eq!(Some((false, true)), min_max_boolean(&a));
+
+    let a = BooleanArray::from(&[Some(true), None, Some(true)]);
+    assert_eq!(Some((true, true)), min_max_boolean(&a));
+
+    let a = BooleanArray::from(&[Some(false), None]);
+    assert_eq!(Some((false, false)), min_max_boolean(&a));
+
+    let a = BooleanArray::from_slice([false, true]).slice(1, 1);
+    assert_eq!(Some((true, true)), min_max_boolean(&a));
+
+    let a = BooleanArray::from(&[None, None]);
+    assert_eq!(None, min_max_boolean(&a));
+}
+
+#[test]
+fn fused_min_max_binary_utf8() {
+    let a = Utf8Array::<i32>::from([Some("b"), None, None, Some("a"), Some("c")]);
+    assert_eq!(Some(("a", "c")), min_max_string(&a));
+
+    let a = Utf8Array::<i32>::from([None::<&str>, None]);
+    assert_eq!(None, min_max_string(&a));
+
+    let a = BinaryArray::<i64>::from_slice([b"abd".as_ref(), b"abc", b"bbb"]);
+    assert_eq!(Some((b"abc".as_ref(), b"bbb".as_ref())), min_max_binary(&a));
+}
+
+#[test]
+fn fused_min_max_dyn() {
+    let arrays: Vec<Box<dyn Array>> = vec![
+        Int8Array::from(&[Some(-1), None, Some(3)]).boxed(),
+        Float32Array::from(&[None, None]).boxed(),
+        PrimitiveArray::<f16>::from(&[Some(f16::from_f32(1.5)), Some(f16::from_f32(-0.5))]).boxed(),
+        BooleanArray::from(&[Some(true), None]).boxed(),
+        Utf8Array::<i64>::from([Some("z"), Some("a")]).boxed(),
+        BinaryArray::<i32>::from([Some(b"a".as_ref()), None]).boxed(),
+    ];
+    for array in arrays {
+        let (min_, max_) = min_max(array.as_ref()).unwrap();
+        assert_eq!(min_, min(array.as_ref()).unwrap());
+        assert_eq!(max_, max(array.as_ref()).unwrap());
+    }
+
+    let array = ListArray::<i32>::new_null(ListArray::<i32>::default_datatype(DataType::Int32), 1);
+    assert!(min_max(&array).is_err());
+}
@@ -1,3 +1,7 @@
+mod approx;
+mod count;
 mod memory;
 mod min_max;
+mod segmented;
+mod statistics;
 mod sum;
@@ -0,0 +1,77 @@
+use arrow2::array::*;
+use arrow2::compute::aggregate::{segmented_max, segmented_min, segmented_sum};
+use arrow2::datatypes::DataType;
+
+#[test]
+fn sum() {
+    let values = Int64Array::from([Some(1), Some(2), None, Some(4), Some(5)]);
+    let group_ids = UInt32Array::from([Some(0), Some(2), Some(0), None, Some(0)]);
+
+    let result = segmented_sum(&values, &group_ids, 4).unwrap();
+    assert_eq!(result, Int64Array::from([Some(6), None, Some(2), None]));
+}
+
+#[test]
+fn sum_overflow() {
+    let values = Int8Array::from_slice([100, 100, 100]);
+    let group_ids = UInt32Array::from_slice([0, 1, 0]);
+    assert!(matches!(
+        segmented_sum(&values, &group_ids, 2),
+        Err(arrow2::error::Error::Overflow)
+    ));
+
+    let values = Float64Array::from_slice([f64::MAX, f64::MAX, 1.0]);
+    let group_ids = UInt32Array::from_slice([0, 0, 1]);
+    let result = segmented_sum(&values, &group_ids, 2).unwrap();
+    assert_eq!(result, Float64Array::from_slice([f64::INFINITY, 1.0]));
+}
+
+#[test]
+fn sum_logical_type() {
+    let values = Int32Array::from_slice([1, 2, 3]).to(DataType::Date32);
+    let group_ids = UInt32Array::from_slice([1, 1, 0]);
+
+    let result = segmented_sum(&values, &group_ids, 2).unwrap();
+    assert_eq!(result, Int32Array::from_slice([3, 3]).to(DataType::Date32));
+}
+
+#[test]
+fn min_max() {
+    let values = Float64Array::from([Some(1.5), Some(-2.0), None, Some(4.0), Some(0.5)]);
+    let group_ids = UInt32Array::from_slice([0, 0, 1, 2, 2]);
+
+    let result = segmented_min(&values, &group_ids, 3).unwrap();
+    assert_eq!(result, Float64Array::from([Some(-2.0), None, Some(0.5)]));
+
+    let result = segmented_max(&values, &group_ids, 3).unwrap();
+    assert_eq!(result, Float64Array::from([Some(1.5), None, Some(4.0)]));
+}
+
+#[test]
+fn sliced() {
+    let values = Int32Array::from_slice([1, 2, 3, 4]).slice(1, 3);
+    let group_ids = UInt32Array::from_slice([0, 1, 0, 1]).slice(1, 3);
+
+    let result = segmented_max(&values, &group_ids, 2).unwrap();
+    assert_eq!(result, Int32Array::from_slice([3, 4]));
+}
+
+#[test]
+fn errors() {
+    let values = Int32Array::from_slice([1, 2]);
+
+    let group_ids = UInt32Array::from_slice([0]);
+    assert!(segmented_sum(&values, &group_ids, 1).is_err());
+
+    let group_ids = UInt32Array::from_slice([0, 1]);
+    assert!(segmented_min(&values, &group_ids, 1).is_err());
+}
+
+#[test]
+fn empty() {
+    let values = Int32Array::from_slice([]);
+    let group_ids = UInt32Array::from_slice([]);
+
+    let result = segmented_sum(&values, &group_ids, 2).unwrap();
+    assert_eq!(result, Int32Array::from([None, None]));
+}
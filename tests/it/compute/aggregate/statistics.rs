@@ -0,0 +1,66 @@
+use arrow2::array::*;
+use arrow2::chunk::Chunk;
+use arrow2::compute::aggregate::{chunk_statistics, ColumnStatistics};
+use arrow2::datatypes::DataType;
+use arrow2::scalar::{BooleanScalar, PrimitiveScalar, Scalar, Utf8Scalar};
+
+#[test]
+fn column() {
+    let array = Int32Array::from([Some(3), None, Some(1), Some(3), None]);
+    let statistics = ColumnStatistics::new(&array);
+
+    let min: Box<dyn Scalar> = Box::new(PrimitiveScalar::from(Some(1i32)));
+    let max: Box<dyn Scalar> = Box::new(PrimitiveScalar::from(Some(3i32)));
+    assert_eq!(
+        statistics,
+        ColumnStatistics {
+            min: Some(min),
+            max: Some(max),
+            null_count: 2,
+            distinct_count: Some(2),
+        }
+    );
+}
+
+#[test]
+fn all_null() {
+    let array = BooleanArray::from([None, None]);
+    let statistics = ColumnStatistics::new(&array);
+
+    let null: Box<dyn Scalar> = Box::new(BooleanScalar::new(None));
+    assert_eq!(statistics.min, Some(null.clone()));
+    assert_eq!(statistics.max, Some(null));
+    assert_eq!(statistics.null_count, 2);
+    assert_eq!(statistics.distinct_count, Some(0));
+}
+
+#[test]
+fn unsupported() {
+    let array = ListArray::<i32>::new_null(ListArray::<i32>::default_datatype(DataType::Int32), 2);
+    let statistics = ColumnStatistics::new(&array);
+
+    assert_eq!(statistics.min, None);
+    assert_eq!(statistics.max, None);
+    assert_eq!(statistics.null_count, 2);
+    assert_eq!(statistics.distinct_count, None);
+}
+
+#[test]
+fn chunk() {
+    let chunk = Chunk::new(vec![
+        Int64Array::from_slice([1, 2, 3]).boxed(),
+        Utf8Array::<i32>::from([Some("b"), None, Some("a")]).boxed(),
+    ]);
+    let statistics = chunk_statistics(&chunk);
+
+    assert_eq!(statistics.len(), 2);
+    assert_eq!(
+        statistics[0],
+        ColumnStatistics::new(chunk.arrays()[0].as_ref())
+    );
+
+    let min: Box<dyn Scalar> = Box::new(Utf8Scalar::<i32>::new(Some("a")));
+    assert_eq!(statistics[1].min, Some(min));
+    assert_eq!(statistics[1].null_count, 1);
+    assert_eq!(statistics[1].distinct_count, Some(2));
+}
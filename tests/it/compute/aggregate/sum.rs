@@ -1,8 +1,9 @@
 use arrow2::array::*;
-use arrow2::compute::aggregate::{sum, sum_primitive};
+use arrow2::compute::aggregate::{sum, sum_f16, sum_float, sum_primitive, sum_with, Summation};
 use arrow2::compute::arithmetics;
 use arrow2::datatypes::DataType;
 use arrow2::scalar::{PrimitiveScalar, Scalar};
+use arrow2::types::f16;
 
 #[test]
 fn test_primitive_array_sum() {
@@ -52,3 +53,82 @@ fn test_primitive_array_sum_large_64() {
         sum_primitive(&c)
     );
 }
+
+#[test]
+fn sum_f16_in_f32() {
+    // the sum is computed in f32: 2048 + 1 is not representable in f16
+    let a: Float16Array = [Some(2048.0), None, Some(1.0), Some(1.0)]
+        .iter()
+        .map(|x| x.map(f16::from_f32))
+        .collect();
+    assert_eq!(Some(f16::from_f32(2050.0)), sum_f16(&a));
+    assert_eq!(
+        &PrimitiveScalar::from(Some(f16::from_f32(2050.0))) as &dyn Scalar,
+        sum(&a).unwrap().as_ref()
+    );
+}
+
+#[test]
+fn sum_float_summations() {
+    let a = Float32Array::from_vec(vec![0.1; 1_000_000]);
+
+    // within one ulp of the sum, 100000.0015
+    let compensated = sum_float(&a, Summation::Compensated).unwrap();
+    assert!((compensated - 100_000.0).abs() <= 0.0079);
+    let pairwise = sum_float(&a, Summation::Pairwise).unwrap();
+    assert!((pairwise - 100_000.0).abs() < 0.1);
+    let naive = sum_float(&a, Summation::Naive).unwrap();
+    assert!((naive - 100_000.0).abs() > 0.1);
+    assert_eq!(sum_float(&a, Summation::Naive), sum_primitive(&a));
+}
+
+#[test]
+fn sum_float_with_nulls() {
+    let a = Float64Array::from([Some(1e100), None, Some(1.0), Some(-1e100)]);
+    assert_eq!(sum_float(&a, Summation::Compensated), Some(1.0));
+
+    let a: Float64Array = (0..1000)
+        .map(|i| (i % 3 != 0).then_some(i as f64))
+        .collect();
+    let expected = (0..1000).filter(|i| i % 3 != 0).sum::<i32>() as f64;
+    assert_eq!(sum_float(&a, Summation::Pairwise), Some(expected));
+    assert_eq!(sum_float(&a, Summation::Compensated), Some(expected));
+
+    let a = Float32Array::from([None, None]);
+    assert_eq!(sum_float(&a, Summation::Pairwise), None);
+    assert_eq!(sum_float(&a, Summation::Compensated), None);
+}
+
+#[test]
+fn sum_float_non_finite() {
+    let a = Float64Array::from_slice([1.0, f64::INFINITY, 2.0]);
+    assert_eq!(sum_float(&a, Summation::Compensated), Some(f64::INFINITY));
+
+    let a = Float64Array::from_slice([f64::MAX, f64::MAX, 1.0]);
+    assert_eq!(sum_float(&a, Summation::Compensated), Some(f64::INFINITY));
+
+    let a = Float64Array::from_slice([f64::NEG_INFINITY, 1.0]);
+    assert_eq!(
+        sum_float(&a, Summation::Compensated),
+        Some(f64::NEG_INFINITY)
+    );
+
+    let a = Float64Array::from_slice([f64::INFINITY, f64::NEG_INFINITY]);
+    assert!(sum_float(&a, Summation::Compensated).unwrap().is_nan());
+}
+
+#[test]
+fn sum_with_scalar() {
+    let a = Float32Array::from_slice([1e8, 1.0, -1e8]);
+    let result = sum_with(&a, Summation::Compensated).unwrap();
+    assert_eq!(
+        result.as_ref(),
+        &PrimitiveScalar::<f32>::from(Some(1.0)) as &dyn Scalar
+    );
+
+    let a = Int32Array::from_slice([1, 2]);
+    assert_eq!(
+        sum_with(&a, Summation::Compensated).unwrap(),
+        sum(&a).unwrap()
+    );
+}
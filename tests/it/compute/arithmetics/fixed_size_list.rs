@@ -0,0 +1,83 @@
+use arrow2::array::*;
+use arrow2::compute::arithmetics::fixed_size_list::*;
+use arrow2::datatypes::DataType;
+
+fn vectors(values: &[Option<f32>], validity: Option<&[bool]>) -> FixedSizeListArray {
+    let data_type = FixedSizeListArray::default_datatype(DataType::Float32, 2);
+    FixedSizeListArray::new(
+        data_type,
+        Float32Array::from(values).boxed(),
+        validity.map(|x| x.iter().copied().collect()),
+    )
+}
+
+#[test]
+fn element_wise() {
+    let lhs = vectors(
+        &[Some(1.0), Some(2.0), Some(3.0), Some(4.0)],
+        Some(&[true, false]),
+    );
+    let rhs = vectors(&[Some(2.0), Some(2.0), Some(1.0), None], None);
+
+    let result = add::<f32>(&lhs, &rhs).unwrap();
+    assert_eq!(result.validity(), lhs.validity());
+    assert_eq!(
+        result.values().as_ref(),
+        &Float32Array::from([Some(3.0), Some(4.0), Some(4.0), None]) as &dyn Array
+    );
+
+    let result = sub::<f32>(&lhs, &rhs).unwrap();
+    assert_eq!(
+        result.values().as_ref(),
+        &Float32Array::from([Some(-1.0), Some(0.0), Some(2.0), None]) as &dyn Array
+    );
+
+    let result = mul::<f32>(&lhs, &rhs).unwrap();
+    assert_eq!(
+        result.values().as_ref(),
+        &Float32Array::from([Some(2.0), Some(4.0), Some(3.0), None]) as &dyn Array
+    );
+
+    let result = div::<f32>(&lhs, &rhs).unwrap();
+    assert_eq!(
+        result.values().as_ref(),
+        &Float32Array::from([Some(0.5), Some(1.0), Some(3.0), None]) as &dyn Array
+    );
+
+    let result = mul_scalar::<f32>(&lhs, &2.0).unwrap();
+    assert_eq!(result.validity(), lhs.validity());
+    assert_eq!(
+        result.values().as_ref(),
+        &Float32Array::from_slice([2.0, 4.0, 6.0, 8.0]) as &dyn Array
+    );
+}
+
+#[test]
+fn dot_product() {
+    let lhs = vectors(
+        &[
+            Some(1.0),
+            Some(2.0),
+            Some(3.0),
+            Some(4.0),
+            Some(1.0),
+            Some(1.0),
+        ],
+        Some(&[true, true, false]),
+    );
+    let rhs = vectors(
+        &[Some(2.0), Some(2.0), Some(1.0), None, Some(1.0), Some(1.0)],
+        None,
+    );
+    let result = dot::<f32>(&lhs, &rhs).unwrap();
+    assert_eq!(result, Float32Array::from([Some(6.0), None, None]));
+}
+
+#[test]
+fn errors() {
+    let lhs = vectors(&[Some(1.0), Some(2.0)], None);
+    let rhs = vectors(&[Some(1.0), Some(2.0), Some(3.0), Some(4.0)], None);
+    assert!(add::<f32>(&lhs, &rhs).is_err());
+    assert!(dot::<f32>(&lhs, &rhs).is_err());
+    assert!(add::<f64>(&lhs, &lhs).is_err());
+}
@@ -1,5 +1,6 @@
 mod basic;
 mod decimal;
+mod fixed_size_list;
 mod time;
 
 use arrow2::array::*;
@@ -7,6 +8,7 @@ use arrow2::compute::arithmetics::*;
 use arrow2::datatypes::DataType::*;
 use arrow2::datatypes::{IntervalUnit, TimeUnit};
 use arrow2::scalar::PrimitiveScalar;
+use arrow2::types::f16;
 
 #[test]
 fn test_add() {
@@ -26,6 +28,23 @@ fn test_add_scalar() {
     assert_eq!(expected, result.as_ref());
 }
 
+#[test]
+fn test_f16() {
+    let a = Float16Array::from([Some(f16::from_f32(1.5)), None, Some(f16::from_f32(-2.0))]);
+    let b = Float16Array::from_slice([f16::from_f32(0.5); 3]);
+    let result = add(&a, &b);
+    let expected = Float16Array::from([Some(f16::from_f32(2.0)), None, Some(f16::from_f32(-1.5))]);
+    assert_eq!(expected, result.as_ref());
+
+    let result = div_scalar(&a, &PrimitiveScalar::from(Some(f16::from_f32(0.5))));
+    let expected = Float16Array::from([Some(f16::from_f32(3.0)), None, Some(f16::from_f32(-4.0))]);
+    assert_eq!(expected, result.as_ref());
+
+    let result = neg(&a);
+    let expected = Float16Array::from([Some(f16::from_f32(-1.5)), None, Some(f16::from_f32(2.0))]);
+    assert_eq!(expected, result.as_ref());
+}
+
 #[test]
 fn consistency() {
     let datatypes = vec![
@@ -39,6 +58,7 @@ fn consistency() {
         Int16,
         Int32,
         Int64,
+        Float16,
         Float32,
         Float64,
         Timestamp(TimeUnit::Second, None),
@@ -1,6 +1,6 @@
 use arrow2::array::*;
 use arrow2::compute::arithmetics::time::*;
-use arrow2::datatypes::{DataType, TimeUnit};
+use arrow2::datatypes::{DataType, IntervalUnit, TimeUnit};
 use arrow2::scalar::*;
 use arrow2::types::months_days_ns;
 
@@ -383,3 +383,163 @@ fn test_add_interval_tz() {
     let result = add_interval_scalar(&timestamp, &Some(interval).into()).unwrap();
     assert_eq!(result, expected);
 }
+
+#[test]
+fn durations_different_units() {
+    let seconds =
+        PrimitiveArray::from([Some(1i64), None, Some(-2)]).to(DataType::Duration(TimeUnit::Second));
+    let micros = PrimitiveArray::from([Some(10i64), Some(20), Some(30)])
+        .to(DataType::Duration(TimeUnit::Microsecond));
+
+    let expected = PrimitiveArray::from([Some(1_000_010i64), None, Some(-1_999_970)])
+        .to(DataType::Duration(TimeUnit::Microsecond));
+    assert_eq!(add_durations(&seconds, &micros).unwrap(), expected);
+    assert_eq!(
+        arrow2::compute::arithmetics::add(&micros, &seconds).as_ref(),
+        &expected as &dyn Array
+    );
+
+    let expected = PrimitiveArray::from([Some(999_990i64), None, Some(-2_000_030)])
+        .to(DataType::Duration(TimeUnit::Microsecond));
+    assert_eq!(subtract_durations(&seconds, &micros).unwrap(), expected);
+    assert_eq!(
+        arrow2::compute::arithmetics::sub(&seconds, &micros).as_ref(),
+        &expected as &dyn Array
+    );
+
+    // overflows are null
+    let seconds = PrimitiveArray::from_slice([i64::MAX]).to(DataType::Duration(TimeUnit::Second));
+    let nanos = PrimitiveArray::from_slice([1i64]).to(DataType::Duration(TimeUnit::Nanosecond));
+    assert_eq!(add_durations(&seconds, &nanos).unwrap().null_count(), 1);
+
+    assert!(add_durations(&seconds, &PrimitiveArray::from_slice([1i64])).is_err());
+}
+
+#[test]
+fn sub_timestamps_different_units() {
+    let millis = PrimitiveArray::from([Some(1_500i64), None, Some(-1)])
+        .to(DataType::Timestamp(TimeUnit::Millisecond, None));
+    let nanos = PrimitiveArray::from([Some(1i64), Some(2), Some(3)])
+        .to(DataType::Timestamp(TimeUnit::Nanosecond, None));
+
+    let expected = PrimitiveArray::from([Some(1_499_999_999i64), None, Some(-1_000_003)])
+        .to(DataType::Duration(TimeUnit::Nanosecond));
+    assert_eq!(sub_timestamps(&millis, &nanos).unwrap(), expected);
+
+    let expected = PrimitiveArray::from([Some(-1_499_999_999i64), None, Some(1_000_003)])
+        .to(DataType::Duration(TimeUnit::Nanosecond));
+    assert_eq!(sub_timestamps(&nanos, &millis).unwrap(), expected);
+
+    // overflows are null
+    let seconds = PrimitiveArray::from_slice([i64::MAX / 1_000, i64::MIN])
+        .to(DataType::Timestamp(TimeUnit::Second, None));
+    let millis = PrimitiveArray::from_slice([-1_000i64, 0])
+        .to(DataType::Timestamp(TimeUnit::Millisecond, None));
+    let result = sub_timestamps(&seconds, &millis).unwrap();
+    assert_eq!(result.null_count(), 2);
+
+    let duration = PrimitiveArray::from_slice([1i64]).to(DataType::Duration(TimeUnit::Second));
+    assert!(sub_timestamps(&seconds, &duration).is_err());
+}
+
+#[test]
+fn checked_add_duration_different_units() {
+    let timestamp = PrimitiveArray::from([Some(1i64), None, Some(3)]).to(DataType::Timestamp(
+        TimeUnit::Millisecond,
+        Some("America/New_York".to_string()),
+    ));
+    let seconds = PrimitiveArray::from([Some(1i64), Some(2), Some(-1)])
+        .to(DataType::Duration(TimeUnit::Second));
+
+    let expected = PrimitiveArray::from([Some(1_001i64), None, Some(-997)]).to(
+        DataType::Timestamp(TimeUnit::Millisecond, Some("America/New_York".to_string())),
+    );
+    assert_eq!(
+        checked_add_duration(&timestamp, &seconds).unwrap(),
+        expected
+    );
+
+    let nanos = PrimitiveArray::from([Some(1i64), Some(2), Some(-1)])
+        .to(DataType::Duration(TimeUnit::Nanosecond));
+    let expected = PrimitiveArray::from([Some(1_000_001i64), None, Some(2_999_999)]).to(
+        DataType::Timestamp(TimeUnit::Nanosecond, Some("America/New_York".to_string())),
+    );
+    assert_eq!(checked_add_duration(&timestamp, &nanos).unwrap(), expected);
+
+    // overflows are null
+    let timestamp =
+        PrimitiveArray::from_slice([i64::MAX]).to(DataType::Timestamp(TimeUnit::Nanosecond, None));
+    let result = checked_add_duration(&timestamp, &nanos.slice(0, 1)).unwrap();
+    assert_eq!(result.null_count(), 1);
+
+    assert!(checked_add_duration(&seconds, &timestamp).is_err());
+}
+
+#[test]
+fn date_diff_units() {
+    let date32 =
+        PrimitiveArray::from([Some(2i32), None, Some(0), Some(i32::MIN)]).to(DataType::Date32);
+    let date64 =
+        PrimitiveArray::from([Some(86_400_001i64), Some(0), Some(1), Some(0)]).to(DataType::Date64);
+
+    let result = date_diff(&date32, &date64, TimeUnit::Millisecond).unwrap();
+    let expected = PrimitiveArray::from([
+        Some(86_399_999i64),
+        None,
+        Some(-1),
+        Some(i32::MIN as i64 * 86_400_000),
+    ])
+    .to(DataType::Duration(TimeUnit::Millisecond));
+    assert_eq!(result, expected);
+
+    // truncated towards zero
+    let result = date_diff(&date32, &date64, TimeUnit::Second).unwrap();
+    let expected = PrimitiveArray::from([
+        Some(86_399i64),
+        None,
+        Some(0),
+        Some(i32::MIN as i64 * 86_400),
+    ])
+    .to(DataType::Duration(TimeUnit::Second));
+    assert_eq!(result, expected);
+
+    // overflows are null
+    let result = date_diff(&date32, &date64, TimeUnit::Nanosecond).unwrap();
+    let expected =
+        PrimitiveArray::from([Some(86_399_999_000_000i64), None, Some(-1_000_000), None])
+            .to(DataType::Duration(TimeUnit::Nanosecond));
+    assert_eq!(result, expected);
+
+    let result = date_diff(&date32, &date32, TimeUnit::Microsecond).unwrap();
+    let expected = PrimitiveArray::from([Some(0i64), None, Some(0), Some(0)])
+        .to(DataType::Duration(TimeUnit::Microsecond));
+    assert_eq!(result, expected);
+
+    let timestamp = date64.to(DataType::Timestamp(TimeUnit::Millisecond, None));
+    assert!(date_diff(&date32, &timestamp, TimeUnit::Second).is_err());
+}
+
+#[test]
+fn normalize_intervals() {
+    let day = 86_400_000_000_000;
+    let interval = PrimitiveArray::from([
+        Some(months_days_ns::new(0, 0, 3 * day + 5)),
+        Some(months_days_ns::new(0, 65, -1)),
+        Some(months_days_ns::new(-1, 1, 0)),
+        Some(months_days_ns::new(0, -31, day)),
+        None,
+        Some(months_days_ns::new(i32::MAX, 30, 0)),
+    ])
+    .to(DataType::Interval(IntervalUnit::MonthDayNano));
+
+    let expected = PrimitiveArray::from([
+        Some(months_days_ns::new(0, 3, 5)),
+        Some(months_days_ns::new(2, 4, day - 1)),
+        Some(months_days_ns::new(0, -29, 0)),
+        Some(months_days_ns::new(-1, 0, 0)),
+        None,
+        None,
+    ])
+    .to(DataType::Interval(IntervalUnit::MonthDayNano));
+    assert_eq!(normalize_interval(&interval), expected);
+}
@@ -19,3 +19,25 @@ fn test_binary_assign() {
 
     assert_eq!(a, Int32Array::from([Some(6), Some(8), None, None]))
 }
+
+#[test]
+fn test_unary_assign_in_place() {
+    let mut a = Int32Array::from_slice([1, 2, 3]);
+    let ptr = a.values().as_ptr();
+    unary(&mut a, |x| x * 2);
+    assert_eq!(a.values().as_ptr(), ptr);
+    assert_eq!(a, Int32Array::from_slice([2, 4, 6]));
+
+    // shared values are not mutated
+    let shared = a.clone();
+    unary(&mut a, |x| x + 1);
+    assert_ne!(a.values().as_ptr(), ptr);
+    assert_eq!(a, Int32Array::from_slice([3, 5, 7]));
+    assert_eq!(shared, Int32Array::from_slice([2, 4, 6]));
+
+    // sliced at the start
+    drop(shared);
+    let mut a = a.slice(0, 2);
+    unary(&mut a, |x| x - 1);
+    assert_eq!(a, Int32Array::from_slice([2, 4]));
+}
@@ -1,7 +1,13 @@
 use arrow2::array::*;
-use arrow2::compute::cast::{can_cast_types, cast, CastOptions};
+use arrow2::chunk::Chunk;
+use arrow2::compute::cast::{
+    can_cast_types, cast, cast_chunk_to_schema, cast_with_failures, decimal256_to_utf8,
+    decimal_to_utf8, dictionary_decode, dictionary_encode, promote_large, utf8_to_decimal,
+    utf8_to_decimal256, CastOptions,
+};
+use arrow2::compute::decimal::RoundingMode;
 use arrow2::datatypes::*;
-use arrow2::types::{days_ms, months_days_ns, NativeType};
+use arrow2::types::{days_ms, f16, i256, months_days_ns, NativeType};
 
 #[test]
 fn i32_to_f64() {
@@ -90,6 +96,35 @@ fn f32_as_u8_overflow() {
     assert_eq!(expected, b.as_ref());
 }
 
+#[test]
+fn f16_casts() {
+    let array = Float16Array::from([Some(f16::from_f32(1.5)), None, Some(f16::from_f32(300.0))]);
+
+    let b = cast(&array, &DataType::Float64, CastOptions::default()).unwrap();
+    let expected = Float64Array::from([Some(1.5), None, Some(300.0)]);
+    assert_eq!(expected, b.as_ref());
+
+    let b = cast(&array, &DataType::UInt8, CastOptions::default()).unwrap();
+    let expected = UInt8Array::from([Some(1), None, None]);
+    assert_eq!(expected, b.as_ref());
+
+    let array = Float32Array::from([Some(1.5), None, Some(1e6), Some(1.0001)]);
+    let b = cast(&array, &DataType::Float16, CastOptions::default()).unwrap();
+    let expected = Float16Array::from([
+        Some(f16::from_f32(1.5)),
+        None,
+        Some(f16::from_f32(f32::INFINITY)),
+        Some(f16::from_f32(1.0)),
+    ]);
+    assert_eq!(expected, b.as_ref());
+
+    let array = Int32Array::from_slice([1, -2]);
+    assert!(can_cast_types(array.data_type(), &DataType::Float16));
+    let b = cast(&array, &DataType::Float16, CastOptions::default()).unwrap();
+    let expected = Float16Array::from_slice([f16::from_f32(1.0), f16::from_f32(-2.0)]);
+    assert_eq!(expected, b.as_ref());
+}
+
 #[test]
 fn i32_to_u8() {
     let array = Int32Array::from_slice([-5, 6, -7, 8, 100000000]);
@@ -238,6 +273,36 @@ fn utf8_to_i32() {
     assert_eq!(c, &expected);
 }
 
+#[test]
+fn utf8_to_large_binary() {
+    let array = Utf8Array::<i32>::from([Some("a"), None, Some("bc")]);
+    assert!(can_cast_types(array.data_type(), &DataType::LargeBinary));
+    let b = cast(&array, &DataType::LargeBinary, CastOptions::default()).unwrap();
+
+    let expected = BinaryArray::<i64>::from([Some(b"a".as_ref()), None, Some(b"bc".as_ref())]);
+    assert_eq!(b.as_ref(), &expected as &dyn Array);
+}
+
+#[test]
+fn large_utf8_to_binary() {
+    let array = Utf8Array::<i64>::from([Some("a"), None, Some("bc")]);
+    assert!(can_cast_types(array.data_type(), &DataType::Binary));
+    let b = cast(&array, &DataType::Binary, CastOptions::default()).unwrap();
+
+    let expected = BinaryArray::<i32>::from([Some(b"a".as_ref()), None, Some(b"bc".as_ref())]);
+    assert_eq!(b.as_ref(), &expected as &dyn Array);
+}
+
+#[test]
+fn large_binary_to_utf8() {
+    let array = BinaryArray::<i64>::from([Some(b"a".as_ref()), None, Some(b"bc".as_ref())]);
+    assert!(can_cast_types(array.data_type(), &DataType::Utf8));
+    let b = cast(&array, &DataType::Utf8, CastOptions::default()).unwrap();
+
+    let expected = Utf8Array::<i32>::from([Some("a"), None, Some("bc")]);
+    assert_eq!(b.as_ref(), &expected as &dyn Array);
+}
+
 #[test]
 fn int32_to_decimal() {
     // 10 and -10 can be represented with precision 1 and scale 0
@@ -298,26 +363,40 @@ fn decimal_to_decimal() {
     let array = Int128Array::from(&[Some(2), Some(10), Some(-2), Some(-10), None])
         .to(DataType::Decimal(1, 0));
 
-    let b = cast(&array, &DataType::Decimal(2, 1), CastOptions::default()).unwrap();
+    let b = cast(&array, &DataType::Decimal(3, 1), CastOptions::default()).unwrap();
     let c = b.as_any().downcast_ref::<PrimitiveArray<i128>>().unwrap();
 
     let expected = Int128Array::from(&[Some(20), Some(100), Some(-20), Some(-100), None])
-        .to(DataType::Decimal(2, 1));
+        .to(DataType::Decimal(3, 1));
     assert_eq!(c, &expected)
 }
 
 #[test]
 fn decimal_to_decimal_scaled() {
-    // decrease precision
-    // 10 and -10 can't be represented with precision 1 and scale 1
+    // increase scale
+    // 10 and -10 can't be represented with precision 2 and scale 1
     let array = Int128Array::from(&[Some(2), Some(10), Some(-2), Some(-10), None])
-        .to(DataType::Decimal(1, 0));
+        .to(DataType::Decimal(2, 0));
 
-    let b = cast(&array, &DataType::Decimal(1, 1), CastOptions::default()).unwrap();
+    let b = cast(&array, &DataType::Decimal(2, 1), CastOptions::default()).unwrap();
     let c = b.as_any().downcast_ref::<PrimitiveArray<i128>>().unwrap();
 
     let expected =
-        Int128Array::from(&[Some(20), None, Some(-20), None, None]).to(DataType::Decimal(1, 1));
+        Int128Array::from(&[Some(20), None, Some(-20), None, None]).to(DataType::Decimal(2, 1));
+    assert_eq!(c, &expected)
+}
+
+#[test]
+fn decimal_to_decimal_rounded() {
+    // decrease scale: 0.15, 0.14, -0.15 and 9.95, which does not fit in precision 2
+    let array = Int128Array::from(&[Some(15), Some(14), Some(-15), Some(995), None])
+        .to(DataType::Decimal(3, 2));
+
+    let b = cast(&array, &DataType::Decimal(2, 1), CastOptions::default()).unwrap();
+    let c = b.as_any().downcast_ref::<PrimitiveArray<i128>>().unwrap();
+
+    let expected =
+        Int128Array::from(&[Some(2), Some(1), Some(-2), None, None]).to(DataType::Decimal(2, 1));
     assert_eq!(c, &expected)
 }
 
@@ -336,6 +415,14 @@ fn decimal_to_decimal_fast() {
     assert_eq!(c, &expected)
 }
 
+#[test]
+fn decimal_to_decimal_invalid_precision() {
+    let array = Int128Array::from(&[Some(2), None]).to(DataType::Decimal(1, 1));
+
+    assert!(arrow2::compute::cast::decimal_to_decimal(&array, 0, 1).is_err());
+    assert!(cast(&array, &DataType::Decimal(39, 1), CastOptions::default()).is_err());
+}
+
 #[test]
 fn decimal_to_float() {
     let array = Int128Array::from(&[Some(2), Some(10), Some(-2), Some(-10), None])
@@ -360,6 +447,164 @@ fn decimal_to_integer() {
     assert_eq!(c, &expected)
 }
 
+#[test]
+fn utf8_to_decimal_exact() {
+    let array = Utf8Array::<i32>::from([
+        Some("1.05"),
+        Some("-0.5"),
+        Some("+12"),
+        Some(".25"),
+        Some("1.2e1"),
+        Some("-125E-2"),
+        None,
+        Some("1.2.3"),
+        Some(""),
+        Some("-"),
+        Some("1e"),
+        Some("a"),
+    ]);
+    let result = utf8_to_decimal(&array, 5, 2, None);
+
+    let expected = Int128Array::from([
+        Some(105),
+        Some(-50),
+        Some(1200),
+        Some(25),
+        Some(1200),
+        Some(-125),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ])
+    .to(DataType::Decimal(5, 2));
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn utf8_to_decimal_precision() {
+    let max = "9".repeat(38);
+    let array = Utf8Array::<i32>::from_slice([
+        max.as_str(),
+        &format!("-{max}"),
+        &format!("1{max}"),
+        "999.99",
+        "1000",
+    ]);
+
+    let result = utf8_to_decimal(&array, 38, 0, None);
+    let expected = Int128Array::from([
+        Some(max.parse::<i128>().unwrap()),
+        Some(-max.parse::<i128>().unwrap()),
+        None,
+        None,
+        Some(1000),
+    ])
+    .to(DataType::Decimal(38, 0));
+    assert_eq!(result, expected);
+
+    let result = utf8_to_decimal(&array, 5, 2, None);
+    let expected =
+        Int128Array::from([None, None, None, Some(99999), None]).to(DataType::Decimal(5, 2));
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn utf8_to_decimal_rounding() {
+    let array = Utf8Array::<i32>::from_slice([
+        "1.125", "1.135", "1.1251", "-1.125", "1.120", "0.005", "0.0049", "9.995",
+    ]);
+
+    let result = utf8_to_decimal(&array, 3, 2, None);
+    let expected = Int128Array::from([None, None, None, None, Some(112), None, None, None])
+        .to(DataType::Decimal(3, 2));
+    assert_eq!(result, expected);
+
+    let result = utf8_to_decimal(&array, 3, 2, Some(RoundingMode::HalfUp));
+    let expected = Int128Array::from([
+        Some(113),
+        Some(114),
+        Some(113),
+        Some(-113),
+        Some(112),
+        Some(1),
+        Some(0),
+        None,
+    ])
+    .to(DataType::Decimal(3, 2));
+    assert_eq!(result, expected);
+
+    let result = utf8_to_decimal(&array, 3, 2, Some(RoundingMode::HalfEven));
+    let expected = Int128Array::from([
+        Some(112),
+        Some(114),
+        Some(113),
+        Some(-112),
+        Some(112),
+        Some(0),
+        Some(0),
+        None,
+    ])
+    .to(DataType::Decimal(3, 2));
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn utf8_to_decimal256_exact() {
+    let max = format!("{}.99", "9".repeat(74));
+    let array = Utf8Array::<i64>::from([Some(max.as_str()), Some("-0.01"), None]);
+    let result = utf8_to_decimal256(&array, 76, 2, None);
+
+    let expected = Int256Array::from([
+        Some(i256("9".repeat(76).parse().unwrap())),
+        Some(i256((-1).into())),
+        None,
+    ])
+    .to(DataType::Decimal256(76, 2));
+    assert_eq!(result, expected);
+    assert_eq!(
+        decimal256_to_utf8::<i64>(&result),
+        Utf8Array::<i64>::from([Some(max.as_str()), Some("-0.01"), None])
+    );
+}
+
+#[test]
+fn decimal_to_utf8_exact() {
+    let array = Int128Array::from([Some(105), Some(-50), Some(-5), Some(0), None])
+        .to(DataType::Decimal(5, 2));
+    let result = decimal_to_utf8::<i32>(&array);
+    let expected = Utf8Array::<i32>::from([
+        Some("1.05"),
+        Some("-0.50"),
+        Some("-0.05"),
+        Some("0.00"),
+        None,
+    ]);
+    assert_eq!(result, expected);
+
+    let array = Int128Array::from_slice([i128::MAX, i128::MIN]).to(DataType::Decimal(38, 0));
+    let result = decimal_to_utf8::<i64>(&array);
+    let expected = Utf8Array::<i64>::from_slice([i128::MAX.to_string(), i128::MIN.to_string()]);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn utf8_decimal_roundtrip() {
+    let array = Utf8Array::<i32>::from([Some("1.05"), Some("-0.50"), Some("12345.67"), None]);
+    assert!(can_cast_types(array.data_type(), &DataType::Decimal(7, 2)));
+    assert!(can_cast_types(&DataType::Decimal(7, 2), array.data_type()));
+
+    let decimal = cast(&array, &DataType::Decimal(7, 2), CastOptions::default()).unwrap();
+    let result = cast(decimal.as_ref(), &DataType::Utf8, CastOptions::default()).unwrap();
+    assert_eq!(array, result.as_ref());
+
+    let decimal = cast(&array, &DataType::Decimal256(7, 2), CastOptions::default()).unwrap();
+    let result = cast(decimal.as_ref(), &DataType::Utf8, CastOptions::default()).unwrap();
+    assert_eq!(array, result.as_ref());
+}
+
 #[test]
 fn utf8_to_i32_partial() {
     let array = Utf8Array::<i32>::from_slice(["5", "6", "seven", "8aa", "9.1aa"]);
@@ -762,6 +1007,47 @@ fn utf8_to_naive_timestamp() {
     assert_eq!(expected, result.as_ref());
 }
 
+#[test]
+fn utf8_to_timestamp_multi() {
+    let array = Utf8Array::<i32>::from([
+        Some("1996-12-19T16:39:57-02:00"),
+        Some("1996-12-19 18:39:57"),
+        None,
+        Some("19/12/1996 18:39:57.25"),
+        Some("1996-12-19 18:39:57.5"),
+        Some("not a timestamp"),
+    ]);
+    let formats = ["%Y-%m-%d %H:%M:%S", "%d/%m/%Y %H:%M:%S"];
+
+    let result = arrow2::compute::cast::utf8_to_timestamp_multi(&array, &formats, None).unwrap();
+    let expected = Int64Array::from([
+        Some(851013597000000000),
+        Some(851020797000000000),
+        None,
+        Some(851020797250000000),
+        Some(851020797500000000),
+        None,
+    ])
+    .to(DataType::Timestamp(TimeUnit::Nanosecond, None));
+    assert_eq!(result, expected);
+
+    // values without an offset are null when the timestamp has a timezone
+    let tz = "+01:00".to_string();
+    let formats = ["%Y-%m-%d %H:%M:%S%z", "%d/%m/%Y %H:%M:%S"];
+    let result =
+        arrow2::compute::cast::utf8_to_timestamp_multi(&array, &formats, Some(tz.clone())).unwrap();
+    let expected = Int64Array::from([Some(851020797000000000), None, None, None, None, None])
+        .to(DataType::Timestamp(TimeUnit::Nanosecond, Some(tz)));
+    assert_eq!(result, expected);
+
+    assert!(arrow2::compute::cast::utf8_to_timestamp_multi(
+        &array,
+        &formats,
+        Some("x".to_string())
+    )
+    .is_err());
+}
+
 #[test]
 fn naive_timestamp_to_utf8() {
     let array = Int64Array::from_slice([851013597000000000, 851017197000000000])
@@ -773,6 +1059,21 @@ fn naive_timestamp_to_utf8() {
     assert_eq!(expected, result.as_ref());
 }
 
+#[test]
+fn null_array_to_nested() {
+    let array = NullArray::new(DataType::Null, 3);
+    let types = [
+        DataType::List(Box::new(Field::new("item", DataType::Int32, true))),
+        DataType::Struct(vec![Field::new("a", DataType::Utf8, true)]),
+        DataType::Dictionary(IntegerType::Int32, Box::new(DataType::Utf8), false),
+        DataType::FixedSizeBinary(2),
+    ];
+    for data_type in types {
+        let result = cast(&array, &data_type, CastOptions::default()).unwrap();
+        assert_eq!(result.as_ref(), new_null_array(data_type, 3).as_ref());
+    }
+}
+
 #[test]
 fn null_array_from_and_to_others() {
     macro_rules! typed_test {
@@ -857,3 +1158,389 @@ fn dict_keys() {
 
     assert_eq!(expected, result.as_ref());
 }
+
+#[test]
+fn dict_to_dict() {
+    let values = Utf8Array::<i32>::from_slice(["a", "b"]);
+    let keys = Int32Array::from([Some(1), None, Some(0), Some(1)]);
+    let data_type = DataType::Dictionary(IntegerType::Int32, Box::new(DataType::Utf8), false);
+    let array = DictionaryArray::try_new(data_type, keys, values.boxed()).unwrap();
+    let expected = vec![Some("b"), None, Some("a"), Some("b")];
+
+    // narrower keys
+    let to_type = DataType::Dictionary(IntegerType::Int8, Box::new(DataType::Utf8), false);
+    assert!(can_cast_types(array.data_type(), &to_type));
+    let result = cast(&array, &to_type, CastOptions::default()).unwrap();
+    assert_eq!(result.data_type(), &to_type);
+    let result = result
+        .as_any()
+        .downcast_ref::<DictionaryArray<i8>>()
+        .unwrap();
+    assert_eq!(
+        result.keys(),
+        &Int8Array::from([Some(1), None, Some(0), Some(1)])
+    );
+    assert_eq!(result.values().len(), 2);
+
+    // cast values
+    let to_type = DataType::Dictionary(IntegerType::Int32, Box::new(DataType::LargeUtf8), false);
+    let result = cast(&array, &to_type, CastOptions::default()).unwrap();
+    assert_eq!(result.data_type(), &to_type);
+    let decoded = dictionary_decode(result.as_ref());
+    assert_eq!(
+        decoded.as_ref(),
+        &Utf8Array::<i64>::from(&expected) as &dyn Array
+    );
+
+    // both
+    let to_type = DataType::Dictionary(IntegerType::UInt16, Box::new(DataType::LargeUtf8), true);
+    let result = cast(&array, &to_type, CastOptions::default()).unwrap();
+    assert_eq!(result.data_type(), &to_type);
+    let decoded = dictionary_decode(result.as_ref());
+    assert_eq!(
+        decoded.as_ref(),
+        &Utf8Array::<i64>::from(&expected) as &dyn Array
+    );
+}
+
+#[test]
+fn dict_to_dict_key_overflow() {
+    let values = Int32Array::from_vec((0..200).collect());
+    let keys = Int32Array::from_slice([0, 199]);
+    let data_type = DataType::Dictionary(IntegerType::Int32, Box::new(DataType::Int32), false);
+    let array = DictionaryArray::try_new(data_type, keys, values.boxed()).unwrap();
+
+    let to_type = DataType::Dictionary(IntegerType::Int8, Box::new(DataType::Int32), false);
+    assert!(cast(&array, &to_type, CastOptions::default()).is_err());
+
+    // keys that fit are re-typed even when the values are longer than the key type allows
+    let array = array.slice(0, 1);
+    let result = cast(&array, &to_type, CastOptions::default()).unwrap();
+    let result = result
+        .as_any()
+        .downcast_ref::<DictionaryArray<i8>>()
+        .unwrap();
+    assert_eq!(result.keys(), &Int8Array::from_slice([0]));
+}
+
+#[test]
+fn dictionary_encode_decode() {
+    let array = Int64Array::from([Some(3), None, Some(3), Some(1)]).to(DataType::Date64);
+    let encoded = dictionary_encode(&array, IntegerType::UInt16).unwrap();
+    let data_type = DataType::Dictionary(IntegerType::UInt16, Box::new(DataType::Date64), false);
+    let expected = DictionaryArray::try_new(
+        data_type,
+        UInt16Array::from([Some(0), None, Some(0), Some(1)]),
+        Int64Array::from_slice([3, 1]).to(DataType::Date64).boxed(),
+    )
+    .unwrap();
+    assert_eq!(expected, encoded.as_ref());
+    assert_eq!(array, dictionary_decode(encoded.as_ref()).as_ref());
+
+    let array = BooleanArray::from([Some(true), Some(false), Some(true)]);
+    let encoded = dictionary_encode(&array, IntegerType::Int8).unwrap();
+    assert_eq!(array, dictionary_decode(encoded.as_ref()).as_ref());
+
+    let array = BinaryArray::<i64>::from_slice([b"a", b"b", b"b"]);
+    let encoded = dictionary_encode(&array, IntegerType::Int32).unwrap();
+    let encoded = encoded
+        .as_any()
+        .downcast_ref::<DictionaryArray<i32>>()
+        .unwrap();
+    assert_eq!(encoded.keys(), &Int32Array::from_slice([0, 1, 1]));
+
+    // not a dictionary
+    assert_eq!(array, dictionary_decode(&array).as_ref());
+}
+
+#[test]
+fn dictionary_encode_dictionary() {
+    let data_type = DataType::Dictionary(IntegerType::Int32, Box::new(DataType::Utf8), true);
+    let array = DictionaryArray::try_new(
+        data_type,
+        Int32Array::from_slice([1, 0, 1]),
+        Utf8Array::<i32>::from_slice(["a", "b"]).boxed(),
+    )
+    .unwrap();
+    let encoded = dictionary_encode(&array, IntegerType::UInt8).unwrap();
+    // the values and whether they are ordered are kept
+    let data_type = DataType::Dictionary(IntegerType::UInt8, Box::new(DataType::Utf8), true);
+    assert_eq!(encoded.data_type(), &data_type);
+    assert_eq!(
+        dictionary_decode(&array).as_ref(),
+        dictionary_decode(encoded.as_ref()).as_ref()
+    );
+}
+
+#[test]
+fn dictionary_encode_errors() {
+    let array = Int32Array::from_iter((0..200).map(Some));
+    assert!(dictionary_encode(&array, IntegerType::UInt8).is_ok());
+    assert!(dictionary_encode(&array, IntegerType::Int8).is_err());
+
+    let array = NullArray::new(DataType::Null, 1);
+    assert!(dictionary_encode(&array, IntegerType::Int8).is_err());
+}
+
+#[test]
+fn dictionary_encode_floats() {
+    let nan = f64::from_bits(f64::NAN.to_bits() + 1);
+    let array = Float64Array::from_slice([0.0, -0.0, f64::NAN, nan, -f64::NAN, 1.0]);
+    let encoded = dictionary_encode(&array, IntegerType::Int8).unwrap();
+    let encoded = encoded
+        .as_any()
+        .downcast_ref::<DictionaryArray<i8>>()
+        .unwrap();
+    // zeros and NaNs are encoded with the same key
+    assert_eq!(encoded.keys(), &Int8Array::from_slice([0, 0, 1, 1, 1, 2]));
+
+    let array = Float32Array::from([Some(-0.0), None, Some(0.0), Some(f32::NAN)]);
+    let encoded = dictionary_encode(&array, IntegerType::Int8).unwrap();
+    let encoded = encoded
+        .as_any()
+        .downcast_ref::<DictionaryArray<i8>>()
+        .unwrap();
+    assert_eq!(
+        encoded.keys(),
+        &Int8Array::from([Some(0), None, Some(0), Some(1)])
+    );
+}
+
+#[test]
+fn promote_large_nested() {
+    let values = Utf8Array::<i32>::from([Some("a"), None, Some("bc")]);
+    let data_type = ListArray::<i32>::default_datatype(DataType::Utf8);
+    let list = ListArray::<i32>::new(
+        data_type,
+        vec![0, 1, 3].try_into().unwrap(),
+        values.boxed(),
+        None,
+    );
+    let ints = Int32Array::from_slice([1, 2]);
+    let data_type = DataType::Struct(vec![
+        Field::new("list", list.data_type().clone(), true),
+        Field::new("int", DataType::Int32, false),
+    ]);
+    let array = StructArray::new(data_type, vec![list.boxed(), ints.clone().boxed()], None);
+
+    let result = promote_large(&array);
+
+    let values = Utf8Array::<i64>::from([Some("a"), None, Some("bc")]);
+    let data_type = ListArray::<i64>::default_datatype(DataType::LargeUtf8);
+    let list = ListArray::<i64>::new(
+        data_type,
+        vec![0, 1, 3].try_into().unwrap(),
+        values.boxed(),
+        None,
+    );
+    let data_type = DataType::Struct(vec![
+        Field::new("list", list.data_type().clone(), true),
+        Field::new("int", DataType::Int32, false),
+    ]);
+    let expected = StructArray::new(data_type, vec![list.boxed(), ints.boxed()], None);
+    assert_eq!(expected, result.as_ref());
+
+    let array = Int32Array::from_slice([1]);
+    assert_eq!(array, promote_large(&array).as_ref());
+}
+
+#[test]
+fn chunk_to_merged_schema() {
+    let inner = Int32Array::from_slice([1, 2]);
+    let data_type = DataType::Struct(vec![Field::new("a", DataType::Int32, false)]);
+    let structs = StructArray::new(data_type.clone(), vec![inner.boxed()], None);
+    let values = Utf8Array::<i32>::from_slice(["a", "b", "c"]);
+    let list_type = ListArray::<i32>::default_datatype(DataType::Utf8);
+    let list = ListArray::<i32>::new(
+        list_type.clone(),
+        vec![0, 1, 3].try_into().unwrap(),
+        values.boxed(),
+        None,
+    );
+    let schema = Schema::from(vec![
+        Field::new("s", data_type, false),
+        Field::new("l", list_type, false),
+    ]);
+    let chunk = Chunk::new(vec![structs.boxed(), list.boxed()]);
+
+    let other = Schema::from(vec![
+        Field::new("x", DataType::Int8, false),
+        Field::new(
+            "s",
+            DataType::Struct(vec![Field::new("b", DataType::Boolean, false)]),
+            false,
+        ),
+        Field::new(
+            "l",
+            ListArray::<i64>::default_datatype(DataType::LargeUtf8),
+            false,
+        ),
+    ]);
+    let to = merge_schemas(&[schema.clone(), other]).unwrap();
+
+    let result = cast_chunk_to_schema(&chunk, &schema, &to, CastOptions::default()).unwrap();
+
+    let data_type = DataType::Struct(vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Boolean, true),
+    ]);
+    let structs = StructArray::new(
+        data_type,
+        vec![
+            Int32Array::from_slice([1, 2]).boxed(),
+            BooleanArray::new_null(DataType::Boolean, 2).boxed(),
+        ],
+        None,
+    );
+    let values = Utf8Array::<i64>::from_slice(["a", "b", "c"]);
+    let list = ListArray::<i64>::new(
+        ListArray::<i64>::default_datatype(DataType::LargeUtf8),
+        vec![0, 1, 3].try_into().unwrap(),
+        values.boxed(),
+        None,
+    );
+    let expected = vec![
+        structs.boxed(),
+        list.boxed(),
+        Int8Array::new_null(DataType::Int8, 2).boxed(),
+    ];
+    assert_eq!(result.into_arrays(), expected);
+
+    // a missing field that is not nullable can't be filled
+    let to = Schema::from(vec![Field::new("x", DataType::Int8, false)]);
+    assert!(cast_chunk_to_schema(&chunk, &schema, &to, CastOptions::default()).is_err());
+}
+
+#[test]
+fn duration_and_interval_to_utf8() {
+    let array =
+        Int64Array::from([Some(10), None, Some(-5)]).to(DataType::Duration(TimeUnit::Millisecond));
+    let result = cast(&array, &DataType::Utf8, CastOptions::default()).unwrap();
+    let expected = Utf8Array::<i32>::from([Some("10ms"), None, Some("-5ms")]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+    let array = Int32Array::from([Some(14), None]).to(DataType::Interval(IntervalUnit::YearMonth));
+    let result = cast(&array, &DataType::LargeUtf8, CastOptions::default()).unwrap();
+    let expected = Utf8Array::<i64>::from([Some("14m"), None]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+    let array = PrimitiveArray::from_slice([months_days_ns::new(1, 2, 3)])
+        .to(DataType::Interval(IntervalUnit::MonthDayNano));
+    assert!(can_cast_types(array.data_type(), &DataType::Utf8));
+    let result = cast(&array, &DataType::Utf8, CastOptions::default()).unwrap();
+    let expected = Utf8Array::<i32>::from_slice(["1m 2d 3ns"]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+    let array = PrimitiveArray::from_slice([days_ms::new(1, 2)])
+        .to(DataType::Interval(IntervalUnit::DayTime));
+    let result = cast(&array, &DataType::Utf8, CastOptions::default()).unwrap();
+    let expected = Utf8Array::<i32>::from_slice(["1d 2ms"]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+}
+
+#[test]
+fn union_sparse_to_dense() {
+    let fields = vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+    ];
+    let sparse_type = DataType::Union(fields.clone(), Some(vec![5, 7]), UnionMode::Sparse);
+    let dense_type = DataType::Union(fields, Some(vec![5, 7]), UnionMode::Dense);
+    let sparse = UnionArray::new(
+        sparse_type.clone(),
+        vec![5, 7, 7, 5].into(),
+        vec![
+            Int32Array::from([Some(1), Some(2), Some(3), None]).boxed(),
+            Utf8Array::<i32>::from([Some("a"), Some("b"), None, Some("d")]).boxed(),
+        ],
+        None,
+    );
+    assert!(can_cast_types(&sparse_type, &dense_type));
+    assert!(can_cast_types(&dense_type, &sparse_type));
+
+    let dense = cast(&sparse, &dense_type, CastOptions::default()).unwrap();
+    let dense = dense.as_any().downcast_ref::<UnionArray>().unwrap();
+    assert_eq!(dense.data_type(), &dense_type);
+    assert_eq!(dense.offsets().unwrap().as_slice(), &[0, 0, 1, 1]);
+    assert_eq!(dense.fields()[0], Int32Array::from([Some(1), None]).boxed());
+    assert_eq!(
+        dense.fields()[1],
+        Utf8Array::<i32>::from([Some("b"), None]).boxed()
+    );
+
+    let back = cast(dense, &sparse_type, CastOptions::default()).unwrap();
+    let back = back.as_any().downcast_ref::<UnionArray>().unwrap();
+    assert_eq!(back.data_type(), &sparse_type);
+    assert_eq!(back.fields()[0].len(), 4);
+    assert_eq!(
+        back.iter().collect::<Vec<_>>(),
+        sparse.iter().collect::<Vec<_>>()
+    );
+
+    let sliced = sparse.slice(1, 2);
+    let dense = cast(&sliced, &dense_type, CastOptions::default()).unwrap();
+    let dense = dense.as_any().downcast_ref::<UnionArray>().unwrap();
+    assert_eq!(
+        dense.iter().collect::<Vec<_>>(),
+        sliced.iter().collect::<Vec<_>>()
+    );
+
+    let other_type = DataType::Union(
+        vec![Field::new("a", DataType::Int32, true)],
+        None,
+        UnionMode::Dense,
+    );
+    assert!(!can_cast_types(&sparse_type, &other_type));
+}
+
+#[test]
+fn cast_with_failures_collects_failed_rows() {
+    let array = Int32Array::from([Some(1), Some(300), None, Some(-1), Some(2)]);
+    let options = CastOptions::default();
+    let (result, failures) = cast_with_failures(&array, &DataType::UInt8, options, true).unwrap();
+
+    let expected = UInt8Array::from([Some(1), None, None, None, Some(2)]);
+    assert_eq!(expected, result.as_ref());
+    assert_eq!(failures.len(), 2);
+    assert_eq!(failures.indices().collect::<Vec<_>>(), vec![1, 3]);
+    assert_eq!(
+        failures.message(),
+        Some("Unable to cast the value 300 at row 1 from Int32 to UInt8")
+    );
+
+    let error = cast_with_failures(&array, &DataType::UInt8, options, false).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "Invalid argument error: Unable to cast the value 300 at row 1 from Int32 to UInt8"
+    );
+}
+
+#[test]
+fn cast_with_failures_without_failures() {
+    let array = Int32Array::from([Some(1), None, Some(2)]);
+    let (result, failures) =
+        cast_with_failures(&array, &DataType::Int64, CastOptions::default(), false).unwrap();
+
+    assert_eq!(Int64Array::from([Some(1), None, Some(2)]), result.as_ref());
+    assert!(failures.is_empty());
+    assert_eq!(failures.rows().len(), 3);
+    assert_eq!(failures.message(), None);
+
+    let array = NullArray::new(DataType::Null, 2);
+    let (_, failures) =
+        cast_with_failures(&array, &DataType::Int32, CastOptions::default(), false).unwrap();
+    assert!(failures.is_empty());
+}
+
+#[test]
+fn cast_with_failures_dictionary_values() {
+    let values = Utf8Array::<i32>::from_slice(["1", "a"]);
+    let keys = Int32Array::from([Some(0), Some(1), None, Some(1)]);
+    let array = DictionaryArray::try_from_keys(keys, values.boxed()).unwrap();
+    let to_type = DataType::Dictionary(IntegerType::Int32, Box::new(DataType::Int32), false);
+
+    let (_, failures) = cast_with_failures(&array, &to_type, CastOptions::default(), true).unwrap();
+    assert_eq!(failures.indices().collect::<Vec<_>>(), vec![1, 3]);
+
+    assert!(cast_with_failures(&array, &to_type, CastOptions::default(), false).is_err());
+}
@@ -2,7 +2,8 @@ use arrow2::array::*;
 use arrow2::bitmap::Bitmap;
 use arrow2::compute::comparison::{self, boolean::*, primitive, utf8};
 use arrow2::datatypes::{DataType, DataType::*, IntegerType, IntervalUnit, TimeUnit};
-use arrow2::scalar::new_scalar;
+use arrow2::scalar::{new_scalar, PrimitiveScalar};
+use arrow2::types::f16;
 
 #[test]
 fn consistency() {
@@ -110,6 +111,26 @@ fn test_eq_scalar() {
     cmp_bool_scalar!(eq_scalar, &[false, true], true, &[false, true]);
 }
 
+#[test]
+fn test_f16() {
+    let a = Float16Array::from([Some(f16::from_f32(1.0)), Some(f16::from_f32(2.0)), None]);
+    let b = Float16Array::from_slice([f16::from_f32(2.0); 3]);
+    assert_eq!(
+        comparison::lt(&a, &b),
+        BooleanArray::from([Some(true), Some(false), None])
+    );
+    assert_eq!(
+        comparison::eq(&a, &b),
+        BooleanArray::from([Some(false), Some(true), None])
+    );
+
+    let b = PrimitiveScalar::from(Some(f16::from_f32(1.0)));
+    assert_eq!(
+        comparison::gt_eq_scalar(&a, &b),
+        BooleanArray::from([Some(true), Some(true), None])
+    );
+}
+
 #[test]
 fn test_eq_with_slice() {
     let a = BooleanArray::from_slice([true, true, false]);
@@ -414,3 +435,203 @@ fn primitive_and_validity() {
     let expected = BooleanArray::from_slice([false, true]);
     assert_eq!(primitive::eq_and_validity(&a1, &a2), expected);
 }
+
+#[test]
+fn dictionaries() {
+    use arrow2::compute::comparison::dictionary;
+
+    let mut lhs = MutableDictionaryArray::<i32, MutableUtf8Array<i32>>::new();
+    lhs.try_extend([Some("b"), Some("a"), None, Some("c"), Some("b")])
+        .unwrap();
+    let lhs: DictionaryArray<i32> = lhs.into();
+    let mut rhs = MutableDictionaryArray::<i32, MutableUtf8Array<i32>>::new();
+    rhs.try_extend([Some("c"), Some("a"), Some("a"), Some("b"), None])
+        .unwrap();
+    let rhs: DictionaryArray<i32> = rhs.into();
+
+    let expected = BooleanArray::from([Some(false), Some(true), None, Some(false), None]);
+    assert_eq!(dictionary::eq(&lhs, &rhs), expected);
+    assert_eq!(comparison::eq(&lhs, &rhs), expected);
+    let expected = BooleanArray::from([Some(true), Some(false), None, Some(false), None]);
+    assert_eq!(dictionary::lt(&lhs, &rhs), expected);
+    let expected = BooleanArray::from([Some(false), Some(false), None, Some(true), None]);
+    assert_eq!(comparison::gt(&lhs, &rhs), expected);
+    let expected = BooleanArray::from([
+        Some(false),
+        Some(true),
+        Some(false),
+        Some(false),
+        Some(false),
+    ]);
+    assert_eq!(dictionary::eq_and_validity(&lhs, &rhs), expected);
+}
+
+#[test]
+fn dictionaries_null_values() {
+    use arrow2::compute::comparison::dictionary;
+
+    let values = Int32Array::from([Some(1), None, Some(2)]).boxed();
+    let keys = Int32Array::from_slice([0, 1, 2]);
+    let lhs = DictionaryArray::try_from_keys(keys, values).unwrap();
+    let rhs = DictionaryArray::try_from_keys(
+        Int32Array::from_slice([0, 0, 0]),
+        Int32Array::from_slice([2]).boxed(),
+    )
+    .unwrap();
+
+    let expected = BooleanArray::from([Some(true), None, Some(false)]);
+    assert_eq!(dictionary::lt(&lhs, &rhs), expected);
+}
+
+#[test]
+fn dictionary_and_plain() {
+    use arrow2::compute::comparison::dictionary;
+
+    let mut lhs = MutableDictionaryArray::<i32, MutableUtf8Array<i32>>::new();
+    lhs.try_extend([Some("b"), Some("a"), None, Some("c"), Some("b")])
+        .unwrap();
+    let lhs: DictionaryArray<i32> = lhs.into();
+    let rhs = Utf8Array::<i32>::from([Some("c"), Some("a"), Some("a"), Some("b"), None]);
+
+    let expected = BooleanArray::from([Some(false), Some(true), None, Some(false), None]);
+    assert_eq!(dictionary::eq_plain(&lhs, &rhs), expected);
+    assert_eq!(comparison::eq(&lhs, &rhs), expected);
+    assert_eq!(comparison::eq(&rhs, &lhs), expected);
+
+    let expected = BooleanArray::from([Some(true), Some(false), None, Some(false), None]);
+    assert_eq!(comparison::lt(&lhs, &rhs), expected);
+    assert_eq!(comparison::gt(&rhs, &lhs), expected);
+    let expected = BooleanArray::from([Some(false), Some(true), None, Some(true), None]);
+    assert_eq!(comparison::gt_eq(&lhs, &rhs), expected);
+    assert_eq!(comparison::lt_eq(&rhs, &lhs), expected);
+
+    let expected = BooleanArray::from_slice([true, false, true, true, true]);
+    assert_eq!(comparison::neq_and_validity(&lhs, &rhs), expected);
+    assert_eq!(comparison::neq_and_validity(&rhs, &lhs), expected);
+}
+
+#[test]
+fn dictionary_and_scalar() {
+    use arrow2::compute::comparison::dictionary;
+    use arrow2::scalar::{DictionaryScalar, Utf8Scalar};
+
+    let values = Utf8Array::<i32>::from([Some("a"), Some("b"), None]).boxed();
+    let keys = Int32Array::from([Some(1), Some(0), None, Some(2), Some(1)]);
+    let lhs = DictionaryArray::try_from_keys(keys, values).unwrap();
+
+    let b = Utf8Scalar::<i32>::from(Some("b"));
+    let expected = BooleanArray::from([Some(true), Some(false), None, None, Some(true)]);
+    assert_eq!(dictionary::eq_scalar(&lhs, &b), expected);
+    assert_eq!(comparison::eq_scalar(&lhs, &b), expected);
+    let expected = BooleanArray::from([Some(false), Some(true), None, None, Some(false)]);
+    assert_eq!(comparison::lt_scalar(&lhs, &b), expected);
+    let expected = BooleanArray::from_slice([true, false, false, false, true]);
+    assert_eq!(comparison::eq_scalar_and_validity(&lhs, &b), expected);
+    let expected = BooleanArray::from_slice([false, true, true, true, false]);
+    assert_eq!(comparison::neq_scalar_and_validity(&lhs, &b), expected);
+
+    // a dictionary scalar is compared by its value
+    let scalar = DictionaryScalar::<i8>::new(
+        DataType::Dictionary(IntegerType::Int8, Box::new(Utf8), false),
+        Some(Box::new(b.clone())),
+    );
+    let expected = BooleanArray::from([Some(false), Some(true), None, None, Some(false)]);
+    assert_eq!(comparison::neq_scalar(&lhs, &scalar), expected);
+
+    let null = Utf8Scalar::<i32>::from(None::<&str>);
+    assert_eq!(
+        comparison::gt_scalar(&lhs, &null),
+        BooleanArray::new_null(DataType::Boolean, 5)
+    );
+}
+
+#[test]
+fn dictionary_and_scalar_constant() {
+    use arrow2::scalar::Utf8Scalar;
+
+    let values = Utf8Array::<i32>::from_slice(["a", "b"]).boxed();
+    let keys = Int32Array::from([Some(1), Some(0), None, Some(1)]);
+    let lhs = DictionaryArray::try_from_keys(keys, values).unwrap();
+
+    // no value matches
+    let result = comparison::eq_scalar(&lhs, &Utf8Scalar::<i32>::from(Some("c")));
+    assert_eq!(result.values().unset_bits(), 4);
+    assert_eq!(
+        result,
+        BooleanArray::from([Some(false), Some(false), None, Some(false)])
+    );
+
+    // all values match
+    let result = comparison::lt_scalar(&lhs, &Utf8Scalar::<i32>::from(Some("c")));
+    assert_eq!(result.values().unset_bits(), 0);
+    assert_eq!(
+        result,
+        BooleanArray::from([Some(true), Some(true), None, Some(true)])
+    );
+}
+
+#[test]
+fn kleene_and_distinctness() {
+    let lhs = Utf8Array::<i32>::from([Some("a"), None, Some("b"), None, Some("c")]);
+    let rhs = Utf8Array::<i32>::from([Some("a"), Some("a"), Some("a"), None, Some("c")]);
+
+    assert_eq!(
+        comparison::eq_kleene(&lhs, &rhs),
+        BooleanArray::from([Some(true), None, Some(false), None, Some(true)])
+    );
+    assert_eq!(
+        comparison::neq_kleene(&lhs, &rhs),
+        BooleanArray::from([Some(false), None, Some(true), None, Some(false)])
+    );
+    assert_eq!(
+        comparison::is_distinct_from(&lhs, &rhs),
+        BooleanArray::from_slice([false, true, true, false, false])
+    );
+    assert_eq!(
+        comparison::is_not_distinct_from(&lhs, &rhs),
+        BooleanArray::from_slice([true, false, false, true, true])
+    );
+}
+
+#[test]
+fn kleene_and_distinctness_nested() {
+    let lhs = ListArray::<i32>::new(
+        ListArray::<i32>::default_datatype(Int32),
+        vec![0, 2, 3, 4, 5].try_into().unwrap(),
+        Int32Array::from([Some(1), Some(2), Some(3), None, Some(4)]).boxed(),
+        Some([true, false, true, true].into()),
+    );
+    let rhs = ListArray::<i32>::new(
+        ListArray::<i32>::default_datatype(Int32),
+        vec![0, 2, 3, 4, 5].try_into().unwrap(),
+        Int32Array::from([Some(1), Some(2), Some(3), None, Some(5)]).boxed(),
+        Some([true, true, false, true].into()),
+    );
+
+    assert_eq!(
+        comparison::eq_kleene(&lhs, &rhs),
+        BooleanArray::from([Some(true), None, None, Some(false)])
+    );
+    assert_eq!(
+        comparison::neq_kleene(&lhs, &rhs),
+        BooleanArray::from([Some(false), None, None, Some(true)])
+    );
+    assert_eq!(
+        comparison::is_distinct_from(&lhs, &rhs),
+        BooleanArray::from_slice([false, true, true, true])
+    );
+    assert_eq!(
+        comparison::is_not_distinct_from(&lhs, &rhs),
+        BooleanArray::from_slice([true, false, false, false])
+    );
+
+    let nulls = NullArray::new(Null, 2);
+    assert_eq!(
+        comparison::eq_kleene(&nulls, &nulls),
+        BooleanArray::from([None, None])
+    );
+    assert_eq!(
+        comparison::is_not_distinct_from(&nulls, &nulls),
+        BooleanArray::from_slice([true, true])
+    );
+}
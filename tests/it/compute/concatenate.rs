@@ -1,5 +1,6 @@
 use arrow2::array::*;
 use arrow2::compute::concatenate::concatenate;
+use arrow2::datatypes::DataType;
 use arrow2::error::Result;
 
 #[test]
@@ -17,6 +18,27 @@ fn incompatible_datatypes() {
     assert!(re.is_err());
 }
 
+#[test]
+fn with_null_arrays() -> Result<()> {
+    let arr = concatenate(&[
+        &NullArray::new(DataType::Null, 2),
+        &Int64Array::from([Some(-1), None]),
+        &NullArray::new(DataType::Null, 1),
+        &Int64Array::from_slice([3]),
+    ])?;
+
+    let expected = Int64Array::from([None, None, Some(-1), None, None, Some(3)]);
+    assert_eq!(arr.as_ref(), &expected as &dyn Array);
+
+    let re = concatenate(&[
+        &NullArray::new(DataType::Null, 2),
+        &Int64Array::from([Some(-1), None]),
+        &Int32Array::from([Some(-1), None]),
+    ]);
+    assert!(re.is_err());
+    Ok(())
+}
+
 #[test]
 fn string_arrays() -> Result<()> {
     let arr = concatenate(&[
@@ -115,3 +137,30 @@ fn boolean_primitive_arrays() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn unions() -> Result<()> {
+    use arrow2::datatypes::{Field, UnionMode};
+
+    let fields = vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+    ];
+    let data_type = DataType::Union(fields, Some(vec![5, 7]), UnionMode::Sparse);
+    let array = UnionArray::new(
+        data_type,
+        vec![5, 7, 7].into(),
+        vec![
+            Int32Array::from_slice([1, 2, 3]).boxed(),
+            Utf8Array::<i32>::from_slice(["a", "b", "c"]).boxed(),
+        ],
+        None,
+    );
+    let sliced = array.slice(1, 2);
+
+    let result = concatenate(&[&array, &sliced])?;
+    let result = result.as_any().downcast_ref::<UnionArray>().unwrap();
+    let expected = array.iter().chain(sliced.iter()).collect::<Vec<_>>();
+    assert_eq!(result.iter().collect::<Vec<_>>(), expected);
+    Ok(())
+}
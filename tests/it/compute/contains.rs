@@ -1,5 +1,6 @@
 use arrow2::array::*;
-use arrow2::compute::contains::contains;
+use arrow2::compute::contains::{contains, contains_list, in_list};
+use arrow2::datatypes::DataType;
 
 // disable wrapping inside literal vectors used for test data and assertions
 #[rustfmt::skip::macros(vec)]
@@ -59,3 +60,86 @@ fn test_contains_binary() {
 
     assert_eq!(result, expected);
 }
+
+#[test]
+fn contains_list_boolean_and_timestamp() {
+    let mut a = MutableListArray::<i64, MutableBooleanArray>::new();
+    a.try_extend(vec![Some(vec![Some(true)]), Some(vec![Some(false), None])])
+        .unwrap();
+    let a: ListArray<i64> = a.into();
+    let result = contains_list(&a, &BooleanArray::from_slice([true, true])).unwrap();
+    assert_eq!(result, BooleanArray::from_slice([true, false]));
+
+    let data_type = DataType::Timestamp(arrow2::datatypes::TimeUnit::Second, None);
+    let values = Int64Array::from_slice([1, 2, 3]).to(data_type.clone());
+    let a = ListArray::<i32>::new(
+        ListArray::<i32>::default_datatype(data_type.clone()),
+        vec![0, 2, 3].try_into().unwrap(),
+        values.boxed(),
+        None,
+    );
+    let result = contains(&a, &Int64Array::from_slice([2, 2]).to(data_type)).unwrap();
+    assert_eq!(result, BooleanArray::from_slice([true, false]));
+
+    // the inner type must match
+    assert!(contains(&a, &Int64Array::from_slice([2, 2])).is_err());
+}
+
+#[test]
+fn in_list_primitive() {
+    let array = Int32Array::from([Some(1), Some(2), None, Some(4)]);
+    let set = Int32Array::from([Some(4), None, Some(1)]);
+    let result = in_list(&array, &set).unwrap();
+    assert_eq!(
+        result,
+        BooleanArray::from([Some(true), Some(false), None, Some(true)])
+    );
+
+    // a large set uses a hash set
+    let set = Int32Array::from_iter((0..100).map(|x| Some(x * 2)));
+    let result = in_list(&array, &set).unwrap();
+    assert_eq!(
+        result,
+        BooleanArray::from([Some(false), Some(true), None, Some(true)])
+    );
+
+    let array = Float64Array::from_slice([f64::NAN, 0.0, -0.0]);
+    let set = Float64Array::from_slice([f64::NAN, 0.0]);
+    let result = in_list(&array, &set).unwrap();
+    assert_eq!(result, BooleanArray::from_slice([true, true, false]));
+}
+
+#[test]
+fn in_list_utf8() {
+    let array = Utf8Array::<i32>::from([Some("a"), Some("b"), None]);
+    let set = Utf8Array::<i32>::from_slice(["b", "c"]);
+    let result = in_list(&array, &set).unwrap();
+    assert_eq!(result, BooleanArray::from([Some(false), Some(true), None]));
+
+    let set = (0..100).map(|x| x.to_string()).collect::<Vec<_>>();
+    let set = Utf8Array::<i32>::from_slice(set);
+    let array = Utf8Array::<i32>::from_slice(["1", "a", "99"]);
+    let result = in_list(&array, &set).unwrap();
+    assert_eq!(result, BooleanArray::from_slice([true, false, true]));
+}
+
+#[test]
+fn in_list_boolean_and_binary() {
+    let array = BooleanArray::from([Some(true), Some(false), None]);
+    let set = BooleanArray::from([Some(false), None]);
+    let result = in_list(&array, &set).unwrap();
+    assert_eq!(result, BooleanArray::from([Some(false), Some(true), None]));
+
+    let array = BinaryArray::<i64>::from_slice([b"a", b"b"]);
+    let set = BinaryArray::<i64>::from_slice([b"b"]);
+    let result = in_list(&array, &set).unwrap();
+    assert_eq!(result, BooleanArray::from_slice([false, true]));
+}
+
+#[test]
+fn in_list_errors() {
+    let array = Int32Array::from_slice([1]);
+    assert!(in_list(&array, &Int64Array::from_slice([1])).is_err());
+    let array = NullArray::new(DataType::Null, 1);
+    assert!(in_list(&array, &array).is_err());
+}
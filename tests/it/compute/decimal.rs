@@ -0,0 +1,77 @@
+use arrow2::array::*;
+use arrow2::compute::decimal::*;
+use arrow2::datatypes::DataType;
+use arrow2::error::Error;
+
+#[test]
+fn rescale_increase_scale() {
+    let array = Int128Array::from(&[Some(12), None, Some(-3)]).to(DataType::Decimal(2, 1));
+
+    let result = rescale(&array, 5, 3, RoundingMode::HalfUp).unwrap();
+    let expected = Int128Array::from(&[Some(1200), None, Some(-300)]).to(DataType::Decimal(5, 3));
+    assert_eq!(result, expected);
+
+    // 1.200 does not fit in precision 3
+    assert!(matches!(
+        rescale(&array, 3, 3, RoundingMode::HalfUp),
+        Err(Error::Overflow)
+    ));
+}
+
+#[test]
+fn rescale_decrease_scale() {
+    // 0.05, 0.15, 0.25, -0.05, -0.15, 0.04, 0.06
+    let array = Int128Array::from_slice([5, 15, 25, -5, -15, 4, 6]).to(DataType::Decimal(3, 2));
+
+    let result = rescale(&array, 2, 1, RoundingMode::HalfUp).unwrap();
+    assert_eq!(result.values().as_slice(), &[1, 2, 3, -1, -2, 0, 1]);
+    assert_eq!(result.data_type(), &DataType::Decimal(2, 1));
+
+    let result = rescale(&array, 2, 1, RoundingMode::HalfEven).unwrap();
+    assert_eq!(result.values().as_slice(), &[0, 2, 2, 0, -2, 0, 1]);
+}
+
+#[test]
+fn rescale_large_factors() {
+    let array = Int128Array::from_slice([i128::MAX / 10, 0]).to(DataType::Decimal(38, 0));
+
+    assert!(matches!(
+        rescale(&array, 38, 1, RoundingMode::HalfUp),
+        Err(Error::Overflow)
+    ));
+
+    let array = Int128Array::from_slice([10_i128.pow(37) * 5, -(10_i128.pow(37) * 5)])
+        .to(DataType::Decimal(38, 38));
+    let result = rescale(&array, 1, 0, RoundingMode::HalfUp).unwrap();
+    assert_eq!(result.values().as_slice(), &[1, -1]);
+    let result = rescale(&array, 1, 0, RoundingMode::HalfEven).unwrap();
+    assert_eq!(result.values().as_slice(), &[0, 0]);
+}
+
+#[test]
+fn rescale_ignores_nulls() {
+    // the value of the null slot overflows, but is not rescaled
+    let array = PrimitiveArray::new(
+        DataType::Decimal(38, 0),
+        vec![1, i128::MAX].into(),
+        Some([true, false].into()),
+    );
+    let result = rescale(&array, 38, 1, RoundingMode::HalfUp).unwrap();
+    assert_eq!(result.iter().collect::<Vec<_>>(), vec![Some(&10), None]);
+}
+
+#[test]
+fn rescale_or_null_overflows() {
+    let array = Int128Array::from(&[Some(99), None, Some(100)]).to(DataType::Decimal(3, 0));
+
+    let result = rescale_or_null(&array, 3, 1, RoundingMode::HalfUp).unwrap();
+    let expected = Int128Array::from(&[Some(990), None, None]).to(DataType::Decimal(3, 1));
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn rescale_invalid() {
+    let array = Int128Array::from_slice([1]).to(DataType::Decimal(2, 0));
+    assert!(rescale(&array, 0, 0, RoundingMode::HalfUp).is_err());
+    assert!(rescale(&array, 39, 0, RoundingMode::HalfUp).is_err());
+}
@@ -0,0 +1,69 @@
+use arrow2::array::*;
+use arrow2::bitmap::Bitmap;
+use arrow2::chunk::Chunk;
+use arrow2::compute::deletes::*;
+use arrow2::error::Result;
+
+fn chunk() -> Chunk<Box<dyn Array>> {
+    Chunk::new(vec![
+        Int32Array::from([Some(1), None, Some(3), None]).boxed(),
+        Utf8Array::<i32>::from_slice(["a", "b", "c", "d"]).boxed(),
+    ])
+}
+
+#[test]
+fn no_deletes() -> Result<()> {
+    let masked = MaskedChunk::new(chunk());
+    assert_eq!(masked.len(), 4);
+    assert_eq!(masked.deleted_count(), 0);
+    assert_eq!(masked.null_count(0), 2);
+    assert_eq!(masked.materialize()?, chunk());
+    Ok(())
+}
+
+#[test]
+fn delete() -> Result<()> {
+    let mut masked = MaskedChunk::new(chunk());
+    masked.delete(&Bitmap::from([false, true, false, false]))?;
+    masked.delete_rows(&[2, 1])?;
+    assert_eq!(masked.len(), 2);
+    assert_eq!(masked.deleted_count(), 2);
+    assert_eq!(masked.null_count(0), 1);
+
+    let expected = Int32Array::from([Some(1), None]);
+    assert_eq!(expected, masked.column(0)?.as_ref());
+
+    let result = masked.materialize()?;
+    let expected = Utf8Array::<i32>::from_slice(["a", "d"]);
+    assert_eq!(expected, result.arrays()[1].as_ref());
+    Ok(())
+}
+
+#[test]
+fn retain() -> Result<()> {
+    let mut masked = MaskedChunk::new(chunk());
+    masked.delete_rows(&[0])?;
+    masked.retain(&BooleanArray::from([
+        Some(true),
+        Some(true),
+        None,
+        Some(false),
+    ]))?;
+    assert_eq!(masked.len(), 1);
+    assert_eq!(
+        masked.selection(),
+        BooleanArray::from_slice([false, true, false, false])
+    );
+
+    let (_, deleted) = masked.into_inner();
+    assert_eq!(deleted, Bitmap::from([true, false, true, true]));
+    Ok(())
+}
+
+#[test]
+fn invalid() {
+    assert!(MaskedChunk::try_new(chunk(), Bitmap::new_zeroed(3)).is_err());
+    let mut masked = MaskedChunk::new(chunk());
+    assert!(masked.delete(&Bitmap::new_zeroed(5)).is_err());
+    assert!(masked.delete_rows(&[4]).is_err());
+}
@@ -0,0 +1,119 @@
+use arrow2::array::*;
+use arrow2::chunk::Chunk;
+use arrow2::compute::distinct::*;
+use arrow2::datatypes::DataType;
+
+#[test]
+fn primitive() {
+    let array = Int64Array::from([Some(3), None, Some(3), Some(1), None]);
+    let indices = distinct_indices::<u32>(&array).unwrap();
+    assert_eq!(indices, UInt32Array::from_slice([0, 1, 3]));
+
+    let result = unique(&array).unwrap();
+    assert_eq!(Int64Array::from([Some(3), None, Some(1)]), result.as_ref());
+
+    let array = Float32Array::from_slice([f32::NAN, 0.0, f32::NAN, -0.0, 0.0]);
+    let indices = distinct_indices::<u32>(&array).unwrap();
+    assert_eq!(indices, UInt32Array::from_slice([0, 1, 3]));
+}
+
+#[test]
+fn utf8_binary_and_boolean() {
+    let array = Utf8Array::<i64>::from([Some("a"), Some("b"), None, Some("a")]);
+    let result = unique(&array).unwrap();
+    assert_eq!(
+        Utf8Array::<i64>::from([Some("a"), Some("b"), None]),
+        result.as_ref()
+    );
+
+    let array = BinaryArray::<i32>::from_slice([b"a", b"a", b"c"]);
+    let indices = distinct_indices::<u64>(&array).unwrap();
+    assert_eq!(indices, UInt64Array::from_slice([0, 2]));
+
+    let array = BooleanArray::from([Some(true), Some(true), None, Some(false)]);
+    let indices = distinct_indices::<u32>(&array).unwrap();
+    assert_eq!(indices, UInt32Array::from_slice([0, 2, 3]));
+}
+
+#[test]
+fn dictionary() {
+    // the values of a dictionary can contain duplicates
+    let keys = Int32Array::from([Some(0), Some(1), Some(2), None, Some(0)]);
+    let values = Utf8Array::<i32>::from_slice(["a", "b", "a"]).boxed();
+    let data_type = DataType::Dictionary(
+        arrow2::datatypes::IntegerType::Int32,
+        Box::new(DataType::Utf8),
+        false,
+    );
+    let array = DictionaryArray::try_new(data_type, keys, values).unwrap();
+
+    let indices = distinct_indices::<u32>(&array).unwrap();
+    assert_eq!(indices, UInt32Array::from_slice([0, 1, 3]));
+}
+
+#[test]
+fn empty() {
+    let array = Int32Array::from_slice([]);
+    let result = unique(&array).unwrap();
+    assert_eq!(result.len(), 0);
+}
+
+#[test]
+fn overflow() {
+    let array = Int32Array::from_iter((0..300).map(Some));
+    assert!(distinct_indices::<u8>(&array).is_err());
+}
+
+#[test]
+fn chunk() {
+    let chunk = Chunk::new(vec![
+        Int32Array::from_slice([1, 1, 2, 1]).boxed(),
+        Utf8Array::<i32>::from([Some("a"), Some("a"), Some("a"), None]).boxed(),
+    ]);
+    let indices = distinct_chunk_indices::<u32, _>(&chunk).unwrap();
+    assert_eq!(indices, UInt32Array::from_slice([0, 2, 3]));
+
+    let result = unique_chunk(&chunk).unwrap();
+    let expected = Chunk::new(vec![
+        Int32Array::from_slice([1, 2, 1]).boxed(),
+        Utf8Array::<i32>::from([Some("a"), Some("a"), None]).boxed(),
+    ]);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn value_counts_primitive() {
+    let array = Int32Array::from([Some(2), None, Some(1), Some(2), None, Some(2)]);
+    let (values, counts) = value_counts(&array, true).unwrap();
+    assert_eq!(Int32Array::from([Some(2), None, Some(1)]), values.as_ref());
+    assert_eq!(counts, Int64Array::from_slice([3, 2, 1]));
+
+    let (values, counts) = value_counts(&array, false).unwrap();
+    assert_eq!(Int32Array::from_slice([2, 1]), values.as_ref());
+    assert_eq!(counts, Int64Array::from_slice([3, 1]));
+}
+
+#[test]
+fn value_counts_dictionary() {
+    // "a" is repeated in the values and the values contain a null
+    let keys = Int32Array::from([Some(1), Some(0), None, Some(2), Some(3), Some(1)]);
+    let values = Utf8Array::<i32>::from([Some("a"), Some("b"), Some("a"), None]).boxed();
+    let data_type = DataType::Dictionary(
+        arrow2::datatypes::IntegerType::Int32,
+        Box::new(DataType::Utf8),
+        false,
+    );
+    let array = DictionaryArray::try_new(data_type.clone(), keys, values.clone()).unwrap();
+
+    let (result, counts) = value_counts(&array, true).unwrap();
+    let keys = Int32Array::from([Some(1), Some(0), Some(3)]);
+    let expected = DictionaryArray::try_new(data_type.clone(), keys, values.clone()).unwrap();
+    assert_eq!(expected, result.as_ref());
+    assert_eq!(counts, Int64Array::from_slice([2, 2, 2]));
+
+    let (result, counts) = value_counts(&array, false).unwrap();
+    let keys = Int32Array::from_slice([1, 0]);
+    let expected = DictionaryArray::try_new(data_type, keys, values).unwrap();
+    assert_eq!(expected, result.as_ref());
+    assert_eq!(counts, Int64Array::from_slice([2, 2]));
+}
@@ -0,0 +1,70 @@
+use arrow2::array::*;
+use arrow2::compute::encode::*;
+use arrow2::error::Result;
+
+#[test]
+fn hex_roundtrip() -> Result<()> {
+    let array = BinaryArray::<i64>::from([Some(b"".as_ref()), None, Some(&[0x01, 0xab, 0xff])]);
+    let encoded = hex_encode(&array)?;
+    assert_eq!(
+        encoded,
+        Utf8Array::<i64>::from([Some(""), None, Some("01abff")])
+    );
+    assert_eq!(hex_decode(&encoded)?, array);
+
+    // uppercase is also decoded
+    let upper = Utf8Array::<i32>::from_slice(["01ABFF"]);
+    assert_eq!(
+        hex_decode(&upper)?,
+        BinaryArray::<i32>::from_slice([[0x01, 0xab, 0xff]])
+    );
+    Ok(())
+}
+
+#[test]
+fn hex_decode_invalid() {
+    let odd = Utf8Array::<i32>::from_slice(["abc"]);
+    assert!(hex_decode(&odd).is_err());
+    let invalid = Utf8Array::<i32>::from_slice(["zz"]);
+    assert!(hex_decode(&invalid).is_err());
+
+    // null slots are not decoded
+    let null = Utf8Array::<i32>::from_slice(["zz", "ab"]).with_validity(Some([false, true].into()));
+    assert_eq!(
+        hex_decode(&null).unwrap(),
+        BinaryArray::<i32>::from([None, Some([0xab])])
+    );
+}
+
+#[test]
+fn base64_roundtrip() -> Result<()> {
+    let array = BinaryArray::<i32>::from([
+        Some(b"".as_ref()),
+        Some(b"a"),
+        Some(b"ab"),
+        Some(b"abc"),
+        None,
+        Some(b"abcd"),
+    ]);
+    let encoded = base64_encode(&array)?;
+    assert_eq!(
+        encoded,
+        Utf8Array::<i32>::from([
+            Some(""),
+            Some("YQ=="),
+            Some("YWI="),
+            Some("YWJj"),
+            None,
+            Some("YWJjZA==")
+        ])
+    );
+    assert_eq!(base64_decode(&encoded)?, array);
+    assert_eq!(base64_decode(&encoded.slice(2, 3))?, array.slice(2, 3));
+    Ok(())
+}
+
+#[test]
+fn base64_decode_invalid() {
+    let invalid = Utf8Array::<i32>::from_slice(["YQ=", "!!!!"]);
+    assert!(base64_decode(&invalid).is_err());
+}
@@ -0,0 +1,49 @@
+use arrow2::array::*;
+use arrow2::bitmap::Bitmap;
+use arrow2::compute::explode::explode;
+use arrow2::datatypes::DataType;
+use arrow2::error::Result;
+
+#[test]
+fn list() -> Result<()> {
+    let values = Utf8Array::<i32>::from([Some("a"), None, Some("b"), Some("c"), Some("d")]);
+    let data_type = ListArray::<i64>::default_datatype(DataType::Utf8);
+    let list = ListArray::<i64>::new(
+        data_type,
+        vec![0, 2, 2, 4, 5].try_into().unwrap(),
+        values.boxed(),
+        None,
+    );
+
+    let (values, indices) = explode(&list)?;
+    let expected = Utf8Array::<i32>::from([Some("a"), None, Some("b"), Some("c"), Some("d")]);
+    assert_eq!(expected, values.as_ref());
+    assert_eq!(indices, Int64Array::from_slice([0, 0, 2, 2, 3]));
+
+    let (values, indices) = explode(&list.slice(1, 2))?;
+    assert_eq!(Utf8Array::<i32>::from_slice(["b", "c"]), values.as_ref());
+    assert_eq!(indices, Int64Array::from_slice([1, 1]));
+    Ok(())
+}
+
+#[test]
+fn list_nulls() -> Result<()> {
+    let values = Int32Array::from_slice([1, 2, 3, 4, 5]);
+    let data_type = ListArray::<i32>::default_datatype(DataType::Int32);
+    let list = ListArray::<i32>::new(
+        data_type,
+        vec![0, 2, 3, 3, 5].try_into().unwrap(),
+        values.boxed(),
+        Some(Bitmap::from([true, false, true, true])),
+    );
+
+    let (values, indices) = explode(&list)?;
+    assert_eq!(Int32Array::from_slice([1, 2, 4, 5]), values.as_ref());
+    assert_eq!(indices, Int64Array::from_slice([0, 0, 3, 3]));
+    Ok(())
+}
+
+#[test]
+fn not_a_list() {
+    assert!(explode(&Int32Array::from_slice([1])).is_err());
+}
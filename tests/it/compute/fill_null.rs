@@ -0,0 +1,130 @@
+use arrow2::array::*;
+use arrow2::compute::fill_null::*;
+use arrow2::error::Result;
+use arrow2::scalar::Utf8Scalar;
+
+#[test]
+fn forward_primitive() {
+    let array = Int32Array::from(&[None, Some(1), None, None, Some(4), None]);
+    let result = fill_null_forward(&array);
+    let expected = Int32Array::from(&[None, Some(1), Some(1), Some(1), Some(4), Some(4)]);
+    assert_eq!(expected, result.as_ref());
+}
+
+#[test]
+fn backward_primitive() {
+    let array = Int32Array::from(&[None, Some(1), None, None, Some(4), None]);
+    let result = fill_null_backward(&array);
+    let expected = Int32Array::from(&[Some(1), Some(1), Some(4), Some(4), Some(4), None]);
+    assert_eq!(expected, result.as_ref());
+}
+
+#[test]
+fn no_nulls() {
+    let array = Int32Array::from_slice([1, 2]);
+    assert_eq!(array, fill_null_forward(&array).as_ref());
+    assert_eq!(array, fill_null_backward(&array).as_ref());
+
+    let array = NullArray::new(arrow2::datatypes::DataType::Null, 2);
+    assert_eq!(array, fill_null_forward(&array).as_ref());
+}
+
+#[test]
+fn utf8() {
+    let array = Utf8Array::<i64>::from([Some("a"), None, Some("c"), None]);
+    let result = fill_null_forward(&array);
+    let expected = Utf8Array::<i64>::from([Some("a"), Some("a"), Some("c"), Some("c")]);
+    assert_eq!(expected, result.as_ref());
+}
+
+#[test]
+fn dictionary() -> Result<()> {
+    let mut array = MutableDictionaryArray::<i32, MutableUtf8Array<i32>>::new();
+    array.try_extend([Some("a"), None, Some("b"), None])?;
+    let array: DictionaryArray<i32> = array.into();
+
+    let result = fill_null_backward(&array);
+    let result = result
+        .as_any()
+        .downcast_ref::<DictionaryArray<i32>>()
+        .unwrap();
+    let values = result
+        .iter()
+        .map(|x| {
+            x.map(|x| {
+                x.as_any()
+                    .downcast_ref::<Utf8Scalar<i32>>()
+                    .unwrap()
+                    .value()
+                    .unwrap()
+                    .to_string()
+            })
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        values,
+        vec![
+            Some("a".to_string()),
+            Some("b".to_string()),
+            Some("b".to_string()),
+            None
+        ]
+    );
+    Ok(())
+}
+
+#[test]
+fn forward_chunks() -> Result<()> {
+    let a = Int32Array::from(&[None, Some(1), None]);
+    let b = Int32Array::from(&[None, None]);
+    let c = Int32Array::from(&[None, Some(3)]);
+    let result = fill_null_forward_chunks(&[&a, &b, &c])?;
+
+    assert_eq!(
+        Int32Array::from(&[None, Some(1), Some(1)]),
+        result[0].as_ref()
+    );
+    assert_eq!(Int32Array::from(&[Some(1), Some(1)]), result[1].as_ref());
+    assert_eq!(Int32Array::from(&[Some(1), Some(3)]), result[2].as_ref());
+    Ok(())
+}
+
+#[test]
+fn backward_chunks() -> Result<()> {
+    let a = Int32Array::from(&[None, Some(1), None]);
+    let b = Int32Array::from(&[None, None]);
+    let c = Int32Array::from(&[Some(3), None]);
+    let result = fill_null_backward_chunks(&[&a, &b, &c])?;
+
+    assert_eq!(
+        Int32Array::from(&[Some(1), Some(1), Some(3)]),
+        result[0].as_ref()
+    );
+    assert_eq!(Int32Array::from(&[Some(3), Some(3)]), result[1].as_ref());
+    assert_eq!(Int32Array::from(&[Some(3), None]), result[2].as_ref());
+
+    let d = Int64Array::from(&[Some(3)]);
+    assert!(fill_null_backward_chunks(&[&a, &d]).is_err());
+    Ok(())
+}
+
+#[test]
+fn with_scalar() -> Result<()> {
+    let array = Int32Array::from(&[None, Some(1), None]);
+    let result = fill_null_with(&array, &Int32Array::from_slice([0]))?;
+    assert_eq!(Int32Array::from_slice([0, 1, 0]), result.as_ref());
+    Ok(())
+}
+
+#[test]
+fn with_array() -> Result<()> {
+    let array = Utf8Array::<i32>::from([None, Some("b"), None]);
+    let fill = Utf8Array::<i32>::from([Some("x"), Some("y"), None]);
+    let result = fill_null_with(&array, &fill)?;
+    let expected = Utf8Array::<i32>::from([Some("x"), Some("b"), None]);
+    assert_eq!(expected, result.as_ref());
+
+    assert!(fill_null_with(&array, &Utf8Array::<i32>::from_slice(["a", "b"])).is_err());
+    assert!(fill_null_with(&array, &Int32Array::from_slice([1])).is_err());
+    Ok(())
+}
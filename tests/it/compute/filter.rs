@@ -133,6 +133,17 @@ fn binary_array_with_null() {
     assert!(d.is_null(1));
 }
 
+#[test]
+fn large_binary_array_with_null() {
+    let data: Vec<Option<&[u8]>> = vec![Some(b"hello"), None, Some(b"world"), None];
+    let a = BinaryArray::<i64>::from(data);
+    let b = BooleanArray::from_slice(vec![true, false, true, true]);
+    let c = filter(&a, &b).unwrap();
+
+    let expected = BinaryArray::<i64>::from([Some(b"hello".as_ref()), Some(b"world"), None]);
+    assert_eq!(c.as_ref(), &expected as &dyn Array);
+}
+
 #[test]
 fn masked_true_values() {
     let a = Int32Array::from_slice([1, 2, 3]);
@@ -213,3 +224,85 @@ fn list_array() {
     assert_eq!(&make_array(expected), &result);
 }
 */
+
+#[test]
+fn filter_chunk_scratch() {
+    use arrow2::chunk::Chunk;
+
+    let chunk = Chunk::new(vec![
+        Int32Array::from([Some(1), None, Some(3), Some(4)]).boxed(),
+        Utf8Array::<i32>::from([Some("a"), Some("bb"), None, Some("d")]).boxed(),
+        BooleanArray::from_slice([true, false, true, false]).boxed(),
+    ]);
+    let mut scratch = Scratch::default();
+
+    for filter_values in [
+        BooleanArray::from_slice([true, false, true, true]),
+        BooleanArray::from([Some(true), None, Some(false), Some(true)]),
+        BooleanArray::from_slice([false; 4]),
+        BooleanArray::from_slice([true; 4]),
+    ] {
+        let result = filter_chunk_with_scratch(&chunk, &filter_values, &mut scratch).unwrap();
+        assert_eq!(result, filter_chunk(&chunk, &filter_values).unwrap());
+    }
+}
+
+#[cfg(feature = "roaring")]
+#[test]
+fn by_roaring() {
+    use arrow2::chunk::Chunk;
+    use roaring::RoaringBitmap;
+
+    let columns = Chunk::new(vec![
+        Int32Array::from([Some(1), None, Some(3), Some(4), Some(5)]).boxed(),
+        Utf8Array::<i32>::from_slice(["a", "b", "c", "d", "e"]).boxed(),
+    ]);
+    let selection = RoaringBitmap::from_iter([0, 2, 3]);
+    let filtered = filter_by_roaring(&columns, &selection).unwrap();
+    assert_eq!(
+        filtered.arrays()[0].as_ref(),
+        &Int32Array::from([Some(1), Some(3), Some(4)]) as &dyn Array
+    );
+    assert_eq!(
+        filtered.arrays()[1].as_ref(),
+        &Utf8Array::<i32>::from_slice(["a", "c", "d"]) as &dyn Array
+    );
+
+    let filtered = filter_by_roaring(&columns, &RoaringBitmap::new()).unwrap();
+    assert_eq!(filtered.len(), 0);
+
+    assert!(filter_by_roaring(&columns, &RoaringBitmap::from_iter([5])).is_err());
+}
+
+#[test]
+fn union() {
+    use arrow2::datatypes::{DataType, Field, UnionMode};
+
+    let fields = vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+    ];
+    for (mode, offsets) in [
+        (UnionMode::Sparse, None),
+        (UnionMode::Dense, Some(vec![0, 0, 1, 1].into())),
+    ] {
+        let data_type = DataType::Union(fields.clone(), Some(vec![5, 7]), mode);
+        let values = if mode.is_sparse() {
+            vec![
+                Int32Array::from_slice([1, 2, 3, 4]).boxed(),
+                Utf8Array::<i32>::from_slice(["a", "b", "c", "d"]).boxed(),
+            ]
+        } else {
+            vec![
+                Int32Array::from_slice([1, 3]).boxed(),
+                Utf8Array::<i32>::from_slice(["b", "d"]).boxed(),
+            ]
+        };
+        let array = UnionArray::new(data_type, vec![5, 7, 5, 7].into(), values, offsets);
+        let mask = BooleanArray::from_slice([false, true, true, false]);
+
+        let result = filter(&array, &mask).unwrap();
+        let result = result.as_any().downcast_ref::<UnionArray>().unwrap();
+        assert_eq!(result, &array.slice(1, 2));
+    }
+}
@@ -1,5 +1,6 @@
 use arrow2::array::*;
-use arrow2::compute::if_then_else::if_then_else;
+use arrow2::compute::if_then_else::{coalesce, if_then_else, nullif};
+use arrow2::datatypes::DataType;
 use arrow2::error::Result;
 
 #[test]
@@ -40,3 +41,67 @@ fn basics_nulls_pred() -> Result<()> {
     assert_eq!(expected, result.as_ref());
     Ok(())
 }
+
+#[test]
+fn broadcast() -> Result<()> {
+    let lhs = Int32Array::from_slice([1, 2, 3]);
+    let rhs = Int32Array::from(&[None]);
+    let predicate = BooleanArray::from(&[Some(true), None, Some(false)]);
+    let result = if_then_else(&predicate, &lhs, &rhs)?;
+    assert_eq!(Int32Array::from(&[Some(1), None, None]), result.as_ref());
+
+    let lhs = Utf8Array::<i32>::from_slice(["a"]);
+    let rhs = Utf8Array::<i32>::from_slice(["b", "c", "d", "e"]);
+    let predicate = BooleanArray::from_slice([false, true, true, false]);
+    let result = if_then_else(&predicate, &lhs, &rhs)?;
+    let expected = Utf8Array::<i32>::from_slice(["b", "a", "a", "e"]);
+    assert_eq!(expected, result.as_ref());
+
+    let rhs = Utf8Array::<i32>::from_slice(["b", "c"]);
+    assert!(if_then_else(&predicate, &lhs, &rhs).is_err());
+    Ok(())
+}
+
+#[test]
+fn nullif_condition() -> Result<()> {
+    let array = Int32Array::from(&[Some(1), Some(2), None, Some(4), Some(5)]);
+    let condition = BooleanArray::from(&[Some(false), Some(true), Some(true), None, Some(true)]);
+    let result = nullif(&array, &condition)?;
+    assert_eq!(
+        Int32Array::from(&[Some(1), None, None, Some(4), None]),
+        result.as_ref()
+    );
+
+    let array = NullArray::new(DataType::Null, 5);
+    assert_eq!(array, nullif(&array, &condition)?.as_ref());
+
+    assert!(nullif(&array, &BooleanArray::from_slice([true])).is_err());
+    Ok(())
+}
+
+#[test]
+fn coalesce_arrays() -> Result<()> {
+    let a = Utf8Array::<i32>::from([Some("a"), None, None, None]);
+    let b = Utf8Array::<i32>::from([None, Some("b"), None, None]);
+    let c = Utf8Array::<i32>::from([Some("x"), None, None, Some("c")]);
+    let result = coalesce(&[&a, &b, &c])?;
+    let expected = Utf8Array::<i32>::from([Some("a"), Some("b"), None, Some("c")]);
+    assert_eq!(expected, result.as_ref());
+
+    let default = Utf8Array::<i32>::from_slice(["z"]);
+    let result = coalesce(&[&a, &b, &c, &default])?;
+    let expected = Utf8Array::<i32>::from_slice(["a", "b", "z", "c"]);
+    assert_eq!(expected, result.as_ref());
+
+    let result = coalesce(&[&default])?;
+    assert_eq!(default, result.as_ref());
+
+    let empty = Utf8Array::<i32>::from([None::<&str>]).slice(0, 0);
+    let result = coalesce(&[&empty, &default])?;
+    assert_eq!(result.len(), 0);
+
+    assert!(coalesce(&[]).is_err());
+    assert!(coalesce(&[&a, &Int32Array::from_slice([1])]).is_err());
+    assert!(coalesce(&[&a, &default, &Utf8Array::<i32>::from_slice(["a", "b"])]).is_err());
+    Ok(())
+}
@@ -41,6 +41,64 @@ fn utf8() {
     length_test_string::<i32>()
 }
 
+#[test]
+fn binary() {
+    let array = BinaryArray::<i64>::from([Some(b"ab".as_ref()), None, Some(b"")]);
+    let result = length(&array).unwrap();
+    assert_eq!(Int64Array::from([Some(2), None, Some(0)]), result.as_ref());
+}
+
+#[test]
+fn list() {
+    let mut array = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+    array
+        .try_extend([Some(vec![Some(1), None]), None, Some(vec![])])
+        .unwrap();
+    let array: ListArray<i32> = array.into();
+
+    let result = length(&array).unwrap();
+    assert_eq!(Int32Array::from([Some(2), None, Some(0)]), result.as_ref());
+}
+
+#[test]
+fn fixed_size_list() {
+    let data_type = DataType::FixedSizeList(Box::new(Field::new("a", DataType::Int32, true)), 2);
+    let values = Int32Array::from_slice([1, 2, 3, 4, 5, 6]).boxed();
+    let array = FixedSizeListArray::new(data_type, values, Some([true, false, true].into()));
+
+    let result = length(&array).unwrap();
+    assert_eq!(Int32Array::from([Some(2), None, Some(2)]), result.as_ref());
+}
+
+#[test]
+fn map() {
+    let fields = vec![
+        Field::new("key", DataType::Utf8, false),
+        Field::new("value", DataType::Int32, true),
+    ];
+    let field = StructArray::new(
+        DataType::Struct(fields),
+        vec![
+            Utf8Array::<i32>::from_slice(["a", "b", "c"]).boxed(),
+            Int32Array::from_slice([1, 2, 3]).boxed(),
+        ],
+        None,
+    );
+    let data_type = DataType::Map(
+        Box::new(Field::new("entries", field.data_type().clone(), false)),
+        false,
+    );
+    let array = MapArray::new(
+        data_type,
+        vec![0, 2, 2, 3].try_into().unwrap(),
+        field.boxed(),
+        Some([true, false, true].into()),
+    );
+
+    let result = length(&array).unwrap();
+    assert_eq!(Int32Array::from([Some(2), None, Some(1)]), result.as_ref());
+}
+
 #[test]
 fn consistency() {
     use arrow2::datatypes::DataType::*;
@@ -79,3 +79,96 @@ fn test_nlike_binary_scalar() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_like_utf8_with_escape() -> Result<()> {
+    let strings = Utf8Array::<i32>::from_slice(["100%", "100$", "a#b"]);
+    let patterns = Utf8Array::<i32>::from_slice(["100#%", "100#%", "a#b"]);
+    let result = like_utf8_with_escape(&strings, &patterns, '#')?;
+    assert_eq!(result, BooleanArray::from_slice([true, false, true]));
+
+    let array = Utf8Array::<i32>::from_slice(["a_b", "axb", "a\\b", "a_bc"]);
+    let result = like_utf8_scalar_with_escape(&array, "a#_b", '#')?;
+    assert_eq!(
+        result,
+        BooleanArray::from_slice([true, false, false, false])
+    );
+    // the default escape character is a regular character
+    let result = like_utf8_scalar_with_escape(&array, "a\\_", '#')?;
+    assert_eq!(
+        result,
+        BooleanArray::from_slice([false, false, true, false])
+    );
+    let result = like_utf8_scalar_with_escape(&array, "a#_b%", '#')?;
+    assert_eq!(result, BooleanArray::from_slice([true, false, false, true]));
+    Ok(())
+}
+
+#[test]
+fn test_ilike_utf8() -> Result<()> {
+    let strings = Utf8Array::<i64>::from([Some("Arrow"), Some("arrow"), None, Some("Parquet")]);
+    let patterns = Utf8Array::<i64>::from_slice(["a%", "%RO_", "a%", "a%"]);
+    let result = ilike_utf8(&strings, &patterns)?;
+    assert_eq!(
+        result,
+        BooleanArray::from([Some(true), Some(true), None, Some(false)])
+    );
+    let result = nilike_utf8(&strings, &patterns)?;
+    assert_eq!(
+        result,
+        BooleanArray::from([Some(false), Some(false), None, Some(true)])
+    );
+    Ok(())
+}
+
+#[test]
+fn test_ilike_utf8_scalar() -> Result<()> {
+    let array = Utf8Array::<i64>::from_slice(["Arrow", "ARROW", "BA", "arrow"]);
+    let result = ilike_utf8_scalar(&array, "arrow")?;
+    assert_eq!(result, BooleanArray::from_slice([true, true, false, true]));
+    let result = ilike_utf8_scalar(&array, "%A")?;
+    assert_eq!(
+        result,
+        BooleanArray::from_slice([false, false, true, false])
+    );
+    let result = nilike_utf8_scalar(&array, "a%")?;
+    assert_eq!(
+        result,
+        BooleanArray::from_slice([false, false, true, false])
+    );
+    Ok(())
+}
+
+#[test]
+fn test_like_dictionary_scalar() -> Result<()> {
+    let mut array = MutableDictionaryArray::<i32, MutableUtf8Array<i64>>::new();
+    array.try_extend([Some("Arrow"), None, Some("BA"), Some("arrow")])?;
+    let array: DictionaryArray<i32> = array.into();
+
+    let result = like_dictionary_scalar(&array, "A%")?;
+    assert_eq!(
+        result,
+        BooleanArray::from([Some(true), None, Some(false), Some(false)])
+    );
+    let result = nlike_dictionary_scalar(&array, "A%")?;
+    assert_eq!(
+        result,
+        BooleanArray::from([Some(false), None, Some(true), Some(true)])
+    );
+    let result = ilike_dictionary_scalar(&array, "A%")?;
+    assert_eq!(
+        result,
+        BooleanArray::from([Some(true), None, Some(false), Some(true)])
+    );
+    let result = nilike_dictionary_scalar(&array, "A%")?;
+    assert_eq!(
+        result,
+        BooleanArray::from([Some(false), None, Some(true), Some(false)])
+    );
+
+    let mut array = MutableDictionaryArray::<i32, MutablePrimitiveArray<i32>>::new();
+    array.try_extend([Some(1)])?;
+    let array: DictionaryArray<i32> = array.into();
+    assert!(like_dictionary_scalar(&array, "A%").is_err());
+    Ok(())
+}
@@ -0,0 +1,103 @@
+use arrow2::array::*;
+use arrow2::compute::list::list_element;
+use arrow2::datatypes::*;
+
+fn list_array<O: arrow2::offset::Offset>() -> ListArray<O> {
+    let mut array = MutableListArray::<O, MutablePrimitiveArray<i32>>::new();
+    array
+        .try_extend([
+            Some(vec![Some(1), Some(2)]),
+            None,
+            Some(vec![]),
+            Some(vec![Some(3), None, Some(4)]),
+        ])
+        .unwrap();
+    array.into()
+}
+
+#[test]
+fn list() {
+    let array = list_array::<i32>();
+
+    let result = list_element(&array, 0).unwrap();
+    assert_eq!(
+        Int32Array::from([Some(1), None, None, Some(3)]),
+        result.as_ref()
+    );
+
+    let result = list_element(&array, 1).unwrap();
+    assert_eq!(
+        Int32Array::from([Some(2), None, None, None]),
+        result.as_ref()
+    );
+
+    let result = list_element(&array, 2).unwrap();
+    assert_eq!(
+        Int32Array::from([None, None, None, Some(4)]),
+        result.as_ref()
+    );
+}
+
+#[test]
+fn large_list_sliced() {
+    let array = list_array::<i64>().slice(2, 2);
+
+    let result = list_element(&array, 2).unwrap();
+    assert_eq!(Int32Array::from([None, Some(4)]), result.as_ref());
+}
+
+#[test]
+fn fixed_size_list() {
+    let data_type = DataType::FixedSizeList(Box::new(Field::new("a", DataType::Int32, true)), 2);
+    let values = Int32Array::from_slice([1, 2, 3, 4, 5, 6]).boxed();
+    let array = FixedSizeListArray::new(data_type, values, Some([true, false, true].into()));
+
+    let result = list_element(&array, 1).unwrap();
+    assert_eq!(Int32Array::from([Some(2), None, Some(6)]), result.as_ref());
+
+    let result = list_element(&array, 2).unwrap();
+    assert_eq!(Int32Array::from([None, None, None]), result.as_ref());
+}
+
+#[test]
+fn map() {
+    let fields = vec![
+        Field::new("key", DataType::Utf8, false),
+        Field::new("value", DataType::Int32, true),
+    ];
+    let field = StructArray::new(
+        DataType::Struct(fields.clone()),
+        vec![
+            Utf8Array::<i32>::from_slice(["a", "b", "c"]).boxed(),
+            Int32Array::from_slice([1, 2, 3]).boxed(),
+        ],
+        None,
+    );
+    let data_type = DataType::Map(
+        Box::new(Field::new("entries", field.data_type().clone(), false)),
+        false,
+    );
+    let array = MapArray::new(
+        data_type,
+        vec![0, 2, 3].try_into().unwrap(),
+        field.boxed(),
+        None,
+    );
+
+    let result = list_element(&array, 1).unwrap();
+    let expected = StructArray::new(
+        DataType::Struct(fields),
+        vec![
+            Utf8Array::<i32>::from([Some("b"), None]).boxed(),
+            Int32Array::from([Some(2), None]).boxed(),
+        ],
+        Some([true, false].into()),
+    );
+    assert_eq!(expected, result.as_ref());
+}
+
+#[test]
+fn error() {
+    let array = Int32Array::from_slice([1]);
+    assert!(list_element(&array, 0).is_err());
+}
@@ -0,0 +1,62 @@
+use arrow2::array::*;
+use arrow2::compute::map::*;
+use arrow2::datatypes::DataType;
+use arrow2::scalar::{PrimitiveScalar, Utf8Scalar};
+
+fn map() -> MapArray {
+    MapArray::try_from_iter(
+        MutableUtf8Array::<i32>::new(),
+        MutablePrimitiveArray::<i32>::new(),
+        vec![
+            Some(vec![("a", Some(1i32)), ("b", None)]),
+            None,
+            Some(vec![("b", Some(3)), ("a", Some(4)), ("a", Some(5))]),
+            Some(vec![]),
+        ],
+    )
+    .unwrap()
+}
+
+#[test]
+fn extract() {
+    let map = map();
+
+    let result = map_extract(&map, &Utf8Scalar::<i32>::new(Some("a"))).unwrap();
+    let expected = Int32Array::from([Some(1), None, Some(4), None]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+    let result = map_extract(&map, &Utf8Scalar::<i32>::new(Some("b"))).unwrap();
+    let expected = Int32Array::from([None, None, Some(3), None]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+    let result = map_extract(&map, &Utf8Scalar::<i32>::new(Some("c"))).unwrap();
+    assert_eq!(result.null_count(), 4);
+}
+
+#[test]
+fn extract_sliced() {
+    let map = map().slice(2, 2);
+
+    let result = map_extract(&map, &Utf8Scalar::<i32>::new(Some("a"))).unwrap();
+    let expected = Int32Array::from([Some(4), None]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+}
+
+#[test]
+fn extract_wrong_type() {
+    let scalar = PrimitiveScalar::<i32>::new(DataType::Int32, Some(1));
+    assert!(map_extract(&map(), &scalar).is_err());
+}
+
+#[test]
+fn entries_to_struct_list() {
+    let map = map();
+    let list = map_entries_to_struct_list(&map);
+
+    assert_eq!(list.len(), 4);
+    assert_eq!(list.offsets(), map.offsets());
+    assert_eq!(list.validity(), map.validity());
+    assert_eq!(list.values(), map.field());
+    assert_eq!(list.value(0), map.value(0));
+    assert!(matches!(list.data_type(), DataType::List(_)));
+}
@@ -1,6 +1,7 @@
 use std::iter::once;
 
 use arrow2::array::*;
+use arrow2::chunk::Chunk;
 use arrow2::compute::merge_sort::*;
 use arrow2::compute::sort::sort;
 use arrow2::error::Result;
@@ -285,3 +286,86 @@ fn test_sort() -> Result<()> {
     assert_eq!(expected, result.as_ref());
     Ok(())
 }
+
+fn chunks(chunks: &[(&[i32], &[&str])]) -> std::vec::IntoIter<Result<Chunk<Box<dyn Array>>>> {
+    chunks
+        .iter()
+        .map(|(a, b)| {
+            Chunk::try_new(vec![
+                Int32Array::from_slice(a).boxed(),
+                Utf8Array::<i32>::from_slice(b).boxed(),
+            ])
+        })
+        .collect::<Vec<_>>()
+        .into_iter()
+}
+
+#[test]
+fn merge_sort_chunks_multiple_columns() -> Result<()> {
+    let a = chunks(&[(&[0, 1], &["b", "a"]), (&[], &[]), (&[3], &["a"])]);
+    let b = chunks(&[(&[0, 1, 1], &["a", "c", "a"]), (&[2, 3], &["a", "b"])]);
+    let c = chunks(&[]);
+
+    let descending = SortOptions {
+        descending: true,
+        nulls_first: false,
+    };
+    let merged = merge_sort_chunks(
+        vec![a, b, c],
+        &[(0, SortOptions::default()), (1, descending)],
+        3,
+    )?
+    .collect::<Result<Vec<_>>>()?;
+
+    let lengths = merged.iter().map(|chunk| chunk.len()).collect::<Vec<_>>();
+    assert_eq!(lengths, vec![3, 3, 2]);
+    let arrays = merged
+        .iter()
+        .map(|chunk| chunk.arrays().to_vec())
+        .collect::<Vec<_>>();
+    let ints = arrays.iter().map(|x| x[0].as_ref()).collect::<Vec<_>>();
+    let strings = arrays.iter().map(|x| x[1].as_ref()).collect::<Vec<_>>();
+    let ints = arrow2::compute::concatenate::concatenate(&ints)?;
+    let strings = arrow2::compute::concatenate::concatenate(&strings)?;
+    assert_eq!(
+        Int32Array::from_slice([0, 0, 1, 1, 1, 2, 3, 3]),
+        ints.as_ref()
+    );
+    assert_eq!(
+        Utf8Array::<i32>::from_slice(["b", "a", "c", "a", "a", "a", "b", "a"]),
+        strings.as_ref()
+    );
+    Ok(())
+}
+
+#[test]
+fn merge_sort_chunks_stable() -> Result<()> {
+    let a = chunks(&[(&[1, 1], &["a0", "a1"])]);
+    let b = chunks(&[(&[0, 1], &["b0", "b1"])]);
+
+    let merged = merge_sort_chunks(vec![a, b], &[(0, SortOptions::default())], 10)?
+        .collect::<Result<Vec<_>>>()?;
+
+    assert_eq!(merged.len(), 1);
+    assert_eq!(
+        Utf8Array::<i32>::from_slice(["b0", "a0", "a1", "b1"]),
+        merged[0].arrays()[1].as_ref()
+    );
+    Ok(())
+}
+
+#[test]
+fn merge_sort_chunks_errors() -> Result<()> {
+    assert!(merge_sort_chunks(vec![chunks(&[])], &[(0, SortOptions::default())], 0).is_err());
+
+    let a = chunks(&[(&[1], &["a"])]);
+    let mut merged = merge_sort_chunks(vec![a], &[(2, SortOptions::default())], 10)?;
+    assert!(merged.next().unwrap().is_err());
+    assert!(merged.next().is_none());
+
+    let a = chunks(&[(&[1], &["a"])]);
+    let b = vec![Chunk::try_new(vec![Int32Array::from_slice([1]).boxed()])].into_iter();
+    let mut merged = merge_sort_chunks(vec![a, b], &[(0, SortOptions::default())], 10)?;
+    assert!(merged.next().unwrap().is_err());
+    Ok(())
+}
@@ -16,6 +16,18 @@ mod comparison;
 mod concatenate;
 #[cfg(feature = "compute_contains")]
 mod contains;
+#[cfg(feature = "compute_decimal")]
+mod decimal;
+#[cfg(feature = "compute_deletes")]
+mod deletes;
+#[cfg(feature = "compute_distinct")]
+mod distinct;
+#[cfg(feature = "compute_encode")]
+mod encode;
+#[cfg(feature = "compute_explode")]
+mod explode;
+#[cfg(feature = "compute_fill_null")]
+mod fill_null;
 #[cfg(feature = "compute_filter")]
 mod filter;
 #[cfg(feature = "compute_hash")]
@@ -28,14 +40,26 @@ mod length;
 mod like;
 #[cfg(feature = "compute_limit")]
 mod limit;
+#[cfg(feature = "compute_list")]
+mod list;
+#[cfg(feature = "compute_map")]
+mod map;
 #[cfg(feature = "compute_merge_sort")]
 mod merge_sort;
 #[cfg(feature = "compute_partition")]
 mod partition;
+#[cfg(feature = "compute_rank")]
+mod rank;
 #[cfg(feature = "compute_regex_match")]
 mod regex_match;
+#[cfg(feature = "compute_resample")]
+mod resample;
+#[cfg(feature = "compute_selection")]
+mod selection;
 #[cfg(feature = "compute_sort")]
 mod sort;
+#[cfg(feature = "compute_struct")]
+mod struct_;
 #[cfg(feature = "compute_substring")]
 mod substring;
 #[cfg(feature = "compute_take")]
@@ -44,6 +68,8 @@ mod take;
 mod temporal;
 #[cfg(feature = "compute_utf8")]
 mod utf8;
+#[cfg(feature = "compute_vector")]
+mod vector;
 #[cfg(feature = "compute_window")]
 mod window;
 
@@ -1,4 +1,5 @@
 use arrow2::array::*;
+use arrow2::chunk::Chunk;
 use arrow2::compute::partition::*;
 use arrow2::compute::sort::{SortColumn, SortOptions};
 use arrow2::datatypes::DataType;
@@ -189,3 +190,124 @@ fn lexicographical_partition_non_unique_column_1() -> Result<()> {
     }
     Ok(())
 }
+
+#[test]
+fn hash_partition_basics() -> Result<()> {
+    let chunk = Chunk::new(vec![
+        Int32Array::from([Some(1), Some(2), Some(1), None, Some(3), None]).boxed(),
+        Utf8Array::<i32>::from_slice(["a", "b", "a", "c", "d", "c"]).boxed(),
+    ]);
+
+    for key_columns in [&[0][..], &[0, 1], &[1]] {
+        let partitions = hash_partition::<u32, _>(&chunk, key_columns, 3)?;
+        assert_eq!(partitions.len(), 3);
+
+        let mut rows = partitions
+            .iter()
+            .flat_map(|p| p.values().iter().copied())
+            .collect::<Vec<_>>();
+        rows.sort_unstable();
+        assert_eq!(rows, vec![0, 1, 2, 3, 4, 5]);
+
+        // equal keys (including nulls) are in the same partition, in order
+        let partition_of = |row: u32| partitions.iter().position(|p| p.values().contains(&row));
+        assert_eq!(partition_of(0), partition_of(2));
+        assert_eq!(partition_of(3), partition_of(5));
+        for partition in &partitions {
+            assert!(partition.values().windows(2).all(|w| w[0] < w[1]));
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn hash_partition_errors() {
+    let chunk = Chunk::new(vec![Int32Array::from_slice([1, 2]).boxed()]);
+    assert!(hash_partition::<u32, _>(&chunk, &[0], 0).is_err());
+    assert!(hash_partition::<u32, _>(&chunk, &[], 2).is_err());
+    assert!(hash_partition::<u32, _>(&chunk, &[1], 2).is_err());
+}
+
+#[test]
+fn range_partition_basics() -> Result<()> {
+    let chunk = Chunk::new(vec![Int32Array::from([
+        Some(5),
+        Some(1),
+        None,
+        Some(10),
+        Some(3),
+        Some(7),
+    ])
+    .boxed()]);
+    let bounds = Int32Array::from_slice([3, 7]);
+    let bounds = vec![SortColumn {
+        values: &bounds,
+        options: None,
+    }];
+
+    let partitions = range_partition::<u32, _>(&chunk, &[0], &bounds)?;
+    // nulls are first by default
+    assert_eq!(
+        partitions,
+        vec![
+            UInt32Array::from_slice([1, 2]),
+            UInt32Array::from_slice([0, 4]),
+            UInt32Array::from_slice([3, 5]),
+        ]
+    );
+    Ok(())
+}
+
+#[test]
+fn range_partition_multiple_columns() -> Result<()> {
+    let chunk = Chunk::new(vec![
+        Utf8Array::<i32>::from_slice(["a", "b", "b", "c"]).boxed(),
+        Int32Array::from_slice([1, 5, 1, 0]).boxed(),
+    ]);
+    let options = Some(SortOptions {
+        descending: true,
+        nulls_first: false,
+    });
+    let bounds0 = Utf8Array::<i32>::from_slice(["b"]);
+    let bounds1 = Int32Array::from_slice([2]);
+    let bounds = vec![
+        SortColumn {
+            values: &bounds0,
+            options: None,
+        },
+        SortColumn {
+            values: &bounds1,
+            options,
+        },
+    ];
+
+    let partitions = range_partition::<u64, _>(&chunk, &[0, 1], &bounds)?;
+    assert_eq!(
+        partitions,
+        vec![
+            UInt64Array::from_slice([0, 1]),
+            UInt64Array::from_slice([2, 3])
+        ]
+    );
+    Ok(())
+}
+
+#[test]
+fn range_partition_errors() {
+    let chunk = Chunk::new(vec![Int32Array::from_slice([1, 2]).boxed()]);
+
+    let unsorted = Int32Array::from_slice([3, 1]);
+    let bounds = vec![SortColumn {
+        values: &unsorted,
+        options: None,
+    }];
+    assert!(range_partition::<u32, _>(&chunk, &[0], &bounds).is_err());
+
+    let wrong_type = Int64Array::from_slice([1]);
+    let bounds = vec![SortColumn {
+        values: &wrong_type,
+        options: None,
+    }];
+    assert!(range_partition::<u32, _>(&chunk, &[0], &bounds).is_err());
+    assert!(range_partition::<u32, _>(&chunk, &[0], &[]).is_err());
+}
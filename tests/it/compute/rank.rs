@@ -0,0 +1,86 @@
+use arrow2::array::*;
+use arrow2::compute::rank::*;
+use arrow2::compute::sort::SortOptions;
+
+fn options(descending: bool, nulls_first: bool, ties: RankTies) -> RankOptions {
+    RankOptions {
+        sort: SortOptions {
+            descending,
+            nulls_first,
+        },
+        ties,
+    }
+}
+
+#[test]
+fn ties() {
+    let array = Int32Array::from_slice([2, 1, 2, 3, 2]);
+
+    let result = rank(&array, &options(false, true, RankTies::Min)).unwrap();
+    assert_eq!(result, UInt64Array::from_slice([2, 1, 2, 5, 2]));
+
+    let result = rank(&array, &options(false, true, RankTies::Max)).unwrap();
+    assert_eq!(result, UInt64Array::from_slice([4, 1, 4, 5, 4]));
+
+    let result = rank(&array, &options(false, true, RankTies::First)).unwrap();
+    assert_eq!(result, UInt64Array::from_slice([2, 1, 3, 5, 4]));
+
+    let result = rank(&array, &options(true, true, RankTies::First)).unwrap();
+    assert_eq!(result, UInt64Array::from_slice([2, 5, 3, 1, 4]));
+}
+
+#[test]
+fn nulls() {
+    let array = Utf8Array::<i32>::from([Some("b"), None, Some("a"), None]);
+
+    let result = rank(&array, &options(false, true, RankTies::Min)).unwrap();
+    assert_eq!(result, UInt64Array::from_slice([4, 1, 3, 1]));
+
+    let result = rank(&array, &options(false, false, RankTies::Min)).unwrap();
+    assert_eq!(result, UInt64Array::from_slice([2, 3, 1, 3]));
+
+    let result = rank(&array, &options(false, false, RankTies::First)).unwrap();
+    assert_eq!(result, UInt64Array::from_slice([2, 3, 1, 4]));
+
+    let sort = SortOptions {
+        descending: true,
+        nulls_first: false,
+    };
+    let result = dense_rank(&array, &sort).unwrap();
+    assert_eq!(result, UInt64Array::from_slice([1, 3, 2, 3]));
+}
+
+#[test]
+fn dense() {
+    let array = Float64Array::from_slice([1.5, 0.5, 1.5, f64::NAN, 0.5]);
+    let result = dense_rank(&array, &SortOptions::default()).unwrap();
+    assert_eq!(result, UInt64Array::from_slice([2, 1, 2, 3, 1]));
+}
+
+#[test]
+fn percent() {
+    let array = Int32Array::from([Some(1), None, Some(1), Some(2), Some(3)]);
+    let result = percent_rank(&array, &SortOptions::default()).unwrap();
+    assert_eq!(
+        result,
+        Float64Array::from_slice([0.25, 0.0, 0.25, 0.75, 1.0])
+    );
+
+    let array = Int32Array::from_slice([1]);
+    let result = percent_rank(&array, &SortOptions::default()).unwrap();
+    assert_eq!(result, Float64Array::from_slice([0.0]));
+}
+
+#[test]
+fn empty() {
+    let array = Int32Array::new_empty(arrow2::datatypes::DataType::Int32);
+    assert_eq!(rank(&array, &RankOptions::default()).unwrap().len(), 0);
+    assert_eq!(
+        dense_rank(&array, &SortOptions::default()).unwrap().len(),
+        0
+    );
+    assert_eq!(
+        percent_rank(&array, &SortOptions::default()).unwrap().len(),
+        0
+    );
+}
@@ -56,3 +56,29 @@ fn test_like_scalar() {
         vec![true, false, false, false],
     )
 }
+
+#[test]
+fn test_compiled() {
+    let regex = regex::Regex::new("^ar").unwrap();
+    let op = |lhs: &Utf8Array<i64>, _: &str| Ok(regex_match_compiled(lhs, &regex));
+    test_generic_scalar::<i64, _>(vec!["arrow", "parquet"], "", op, vec![true, false]);
+    test_generic_scalar::<i64, _>(vec!["flight", "ar"], "", op, vec![false, true]);
+}
+
+#[test]
+fn test_set() {
+    let set = regex::RegexSet::new(["^ar", "fl", "^$"]).unwrap();
+    let lhs = Utf8Array::<i32>::from([
+        Some("arrow"),
+        Some("parquet"),
+        Some("flight"),
+        Some(""),
+        None,
+    ]);
+    let expected = BooleanArray::from([Some(true), Some(false), Some(true), Some(true), None]);
+    assert_eq!(regex_set_match(&lhs, &set), expected);
+
+    let set = regex::RegexSet::empty();
+    let expected = BooleanArray::from([Some(false), Some(false), Some(false), Some(false), None]);
+    assert_eq!(regex_set_match(&lhs, &set), expected);
+}
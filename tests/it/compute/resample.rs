@@ -0,0 +1,104 @@
+use arrow2::array::*;
+use arrow2::compute::resample::*;
+use arrow2::datatypes::{DataType, TimeUnit};
+use arrow2::error::Result;
+
+#[test]
+fn indices() -> Result<()> {
+    let timestamps =
+        Int64Array::from_slice([5, 12, 18, 41]).to(DataType::Timestamp(TimeUnit::Second, None));
+
+    let (grid, indices) = resample_indices(&timestamps, 10)?;
+
+    let expected =
+        Int64Array::from_slice([0, 10, 20, 30, 40]).to(DataType::Timestamp(TimeUnit::Second, None));
+    assert_eq!(grid, expected);
+    // the last row of each slot is taken
+    assert_eq!(
+        indices,
+        UInt32Array::from([Some(0), Some(2), None, None, Some(3)])
+    );
+    Ok(())
+}
+
+#[test]
+fn indices_negative() -> Result<()> {
+    let timestamps = Int64Array::from_slice([-15, 3]);
+    let (grid, indices) = resample_indices(&timestamps, 10)?;
+    assert_eq!(grid, Int64Array::from_slice([-20, -10, 0]));
+    assert_eq!(indices, UInt32Array::from([Some(0), None, Some(1)]));
+    Ok(())
+}
+
+#[test]
+fn indices_invalid() {
+    let timestamps = Int64Array::from_slice([2, 1]);
+    assert!(resample_indices(&timestamps, 10).is_err());
+    let timestamps = Int64Array::from([Some(1), None]);
+    assert!(resample_indices(&timestamps, 10).is_err());
+    let timestamps = Int64Array::from_slice([1, 2]);
+    assert!(resample_indices(&timestamps, 0).is_err());
+}
+
+#[test]
+fn indices_too_large() {
+    let timestamps = Int64Array::from_slice([i64::MIN, i64::MAX]);
+    assert!(resample_indices(&timestamps, 1).is_err());
+    // the grid would start before `i64::MIN`
+    let timestamps = Int64Array::from_slice([i64::MIN]);
+    assert!(resample_indices(&timestamps, 3).is_err());
+    let timestamps = Int64Array::from_slice([0, 1 << 40]);
+    assert!(resample_indices(&timestamps, 1).is_err());
+}
+
+#[test]
+fn indices_empty() -> Result<()> {
+    let (grid, indices) = resample_indices(&Int64Array::from_slice([]), 10)?;
+    assert!(grid.is_empty());
+    assert!(indices.is_empty());
+    Ok(())
+}
+
+#[test]
+fn fill() -> Result<()> {
+    let values = Int32Array::from([Some(1), None, Some(3)]);
+    let indices = UInt32Array::from([Some(0), None, Some(1), None, Some(2)]);
+
+    let result = fill_gaps(&values, &indices, FillStrategy::Null)?;
+    let expected = Int32Array::from([Some(1), None, None, None, Some(3)]);
+    assert_eq!(expected, result.as_ref());
+
+    let result = fill_gaps(&values, &indices, FillStrategy::Forward)?;
+    let expected = Int32Array::from([Some(1), Some(1), None, None, Some(3)]);
+    assert_eq!(expected, result.as_ref());
+
+    // nulls of the original series are preserved
+    let result = fill_gaps(&values, &indices, FillStrategy::Zero)?;
+    let expected = Int32Array::from([Some(1), Some(0), None, Some(0), Some(3)]);
+    assert_eq!(expected, result.as_ref());
+    Ok(())
+}
+
+#[test]
+fn fill_leading_gap() -> Result<()> {
+    let values = Utf8Array::<i32>::from_slice(["a", "b"]);
+    let indices = UInt32Array::from([None, Some(0), None, Some(1)]);
+
+    let result = fill_gaps(&values, &indices, FillStrategy::Forward)?;
+    let expected = Utf8Array::<i32>::from([None, Some("a"), Some("a"), Some("b")]);
+    assert_eq!(expected, result.as_ref());
+
+    assert!(fill_gaps(&values, &indices, FillStrategy::Zero).is_err());
+    Ok(())
+}
+
+#[test]
+fn fill_zero_boolean() -> Result<()> {
+    let values = BooleanArray::from_slice([true, true]);
+    let indices = UInt32Array::from([Some(0), None, Some(1)]);
+
+    let result = fill_gaps(&values, &indices, FillStrategy::Zero)?;
+    let expected = BooleanArray::from_slice([true, false, true]);
+    assert_eq!(expected, result.as_ref());
+    Ok(())
+}
@@ -0,0 +1,95 @@
+use arrow2::array::*;
+use arrow2::compute::filter::filter;
+use arrow2::compute::selection::{filter_then_take, Selection};
+use arrow2::compute::take::take;
+use arrow2::error::Result;
+
+#[test]
+fn filter_then_take_equals_unfused() -> Result<()> {
+    let array = Utf8Array::<i32>::from([Some("a"), None, Some("c"), Some("d"), Some("e")]);
+    let mask = BooleanArray::from([Some(true), Some(true), None, Some(false), Some(true)]);
+    let indices = Int64Array::from([Some(2), Some(0), None, Some(1)]);
+
+    let result = filter_then_take(&array, &mask, &indices)?;
+    let expected = take(filter(&array, &mask)?.as_ref(), &indices)?;
+    assert_eq!(result, expected);
+    assert_eq!(
+        Utf8Array::<i32>::from([Some("e"), Some("a"), None, None]),
+        result.as_ref()
+    );
+    Ok(())
+}
+
+#[test]
+fn filter_then_take_errors() {
+    let array = Int32Array::from_slice([1, 2, 3]);
+    let mask = BooleanArray::from_slice([true, false, true]);
+
+    assert!(filter_then_take(&array, &mask, &UInt32Array::from_slice([2])).is_err());
+    assert!(filter_then_take(
+        &array,
+        &BooleanArray::from_slice([true, false]),
+        &UInt32Array::from_slice([0])
+    )
+    .is_err());
+}
+
+#[test]
+fn to_indices() -> Result<()> {
+    let mask = BooleanArray::from([Some(false), Some(true), None, Some(true)]);
+    let selection = Selection::from(mask);
+    assert_eq!(selection.len(), 2);
+    assert_eq!(selection.to_indices()?, UInt32Array::from_slice([1, 3]));
+    Ok(())
+}
+
+#[test]
+fn then() -> Result<()> {
+    let array = Int32Array::from([Some(0), Some(1), None, Some(3), Some(4), Some(5)]);
+    let masks = [
+        BooleanArray::from_slice([true, false, true, true, false, true]),
+        BooleanArray::from([Some(false), None, Some(true), Some(true)]),
+    ];
+    let selections = [
+        Selection::from(masks[0].clone()),
+        Selection::from(UInt32Array::from([Some(5), None, Some(2), Some(0)])),
+    ];
+    let seconds = [
+        Selection::from(masks[1].clone()),
+        Selection::from(UInt32Array::from([Some(3), Some(0), None, Some(2)])),
+    ];
+
+    for first in &selections {
+        for second in &seconds {
+            let fused = first.then(second)?;
+            let expected = second.apply(first.apply(&array)?.as_ref())?;
+            assert_eq!(fused.apply(&array)?, expected);
+            assert_eq!(fused.len(), expected.len());
+        }
+    }
+
+    // two masks compose into a mask
+    let fused = selections[0].then(&seconds[0])?;
+    assert_eq!(
+        fused,
+        Selection::Mask(BooleanArray::from_slice([
+            false, false, false, true, false, true
+        ]))
+    );
+    Ok(())
+}
+
+#[test]
+fn then_errors() {
+    let first = Selection::from(BooleanArray::from_slice([true, false, true]));
+    assert!(first
+        .then(&Selection::from(BooleanArray::from_slice([true])))
+        .is_err());
+    assert!(first
+        .then(&Selection::from(UInt32Array::from_slice([2])))
+        .is_err());
+    assert!(first.apply(&Int32Array::from_slice([1, 2])).is_err());
+    assert!(Selection::from(UInt32Array::from_slice([2]))
+        .apply(&Int32Array::from_slice([1, 2]))
+        .is_err());
+}
@@ -0,0 +1,107 @@
+use arrow2::array::*;
+use arrow2::chunk::Chunk;
+use arrow2::compute::sort::external::{external_sort, ExternalSortOptions};
+use arrow2::compute::sort::SortOptions;
+use arrow2::datatypes::*;
+use arrow2::error::Result;
+
+type ArrowChunk = Chunk<Box<dyn Array>>;
+
+fn data() -> (Schema, Vec<Result<ArrowChunk>>) {
+    let schema = Schema::from(vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, false),
+    ]);
+    let chunks = (0..10)
+        .map(|i| {
+            let a = (0..100)
+                .map(|j| ((i * 37 + j * 11) % 23 != 0).then_some((i * 13 + j * 7) % 17))
+                .collect::<Int32Array>();
+            let b = (0..100)
+                .map(|j| Some(format!("{i}-{j}")))
+                .collect::<Utf8Array<i32>>();
+            Ok(Chunk::new(vec![a.boxed(), b.boxed()]))
+        })
+        .collect();
+    (schema, chunks)
+}
+
+fn sorted(options: &ExternalSortOptions) -> Result<(usize, Vec<ArrowChunk>)> {
+    let (schema, chunks) = data();
+    let sorted = external_sort(chunks.into_iter(), &schema, options)?;
+    let runs = sorted.num_spilled_runs();
+    Ok((runs, sorted.collect::<Result<Vec<_>>>()?))
+}
+
+fn spill_dir(name: &str) -> std::path::PathBuf {
+    let dir = std::env::temp_dir().join(format!("arrow2-{name}-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+#[test]
+fn in_memory_and_spilled_agree() -> Result<()> {
+    let descending = SortOptions {
+        descending: true,
+        nulls_first: true,
+    };
+    let mut options = ExternalSortOptions::new(vec![(0, descending)]);
+    options.chunk_size = 128;
+    let (runs, expected) = sorted(&options)?;
+    assert_eq!(runs, 0);
+
+    options.memory_budget = 2000;
+    options.spill_dir = spill_dir("external-sort");
+    let (runs, result) = sorted(&options)?;
+    assert!(runs > 1);
+    assert_eq!(result, expected);
+
+    assert!(result.iter().all(|chunk| chunk.len() <= 128));
+    assert_eq!(result.iter().map(|chunk| chunk.len()).sum::<usize>(), 1000);
+    let a = result
+        .iter()
+        .flat_map(|chunk| {
+            let a = chunk.arrays()[0]
+                .as_any()
+                .downcast_ref::<Int32Array>()
+                .unwrap();
+            a.iter().map(|x| x.copied()).collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    let mut expected = a.clone();
+    expected.sort_by(|lhs, rhs| rhs.cmp(lhs));
+    expected.rotate_right(a.iter().filter(|x| x.is_none()).count());
+    assert_eq!(a, expected);
+
+    // the spilled runs are deleted
+    assert_eq!(std::fs::read_dir(&options.spill_dir)?.count(), 0);
+    std::fs::remove_dir(&options.spill_dir)?;
+    Ok(())
+}
+
+#[cfg(feature = "io_ipc_compression")]
+#[test]
+fn compressed_runs() -> Result<()> {
+    let mut options = ExternalSortOptions::new(vec![(0, SortOptions::default())]);
+    let (_, expected) = sorted(&options)?;
+
+    options.memory_budget = 1;
+    options.spill_dir = spill_dir("external-sort-compressed");
+    options.write_options.compression = Some(arrow2::io::ipc::write::Compression::ZSTD);
+    let (runs, result) = sorted(&options)?;
+    assert_eq!(runs, 10);
+    assert_eq!(result, expected);
+    std::fs::remove_dir(&options.spill_dir)?;
+    Ok(())
+}
+
+#[test]
+fn invalid_sort_column() {
+    let (schema, chunks) = data();
+    let options = ExternalSortOptions::new(vec![(2, SortOptions::default())]);
+    assert!(external_sort(chunks.into_iter(), &schema, &options).is_err());
+
+    let (schema, chunks) = data();
+    let options = ExternalSortOptions::new(vec![]);
+    assert!(external_sort(chunks.into_iter(), &schema, &options).is_err());
+}
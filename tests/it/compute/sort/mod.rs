@@ -1,3 +1,5 @@
+#[cfg(feature = "compute_sort_external")]
+mod external;
 mod lex_sort;
 mod row;
 
@@ -444,6 +446,128 @@ fn string_dicts() {
     );
 }
 
+#[test]
+fn primitive_dicts() {
+    let values = Int32Array::from([Some(10), None, Some(-1), Some(10)]).boxed();
+    let keys = Int32Array::from([Some(0), Some(2), None, Some(1), Some(3), Some(2)]);
+    let array = DictionaryArray::try_from_keys(keys, values).unwrap();
+
+    let options = SortOptions {
+        descending: false,
+        nulls_first: true,
+    };
+    let indices = sort_to_indices::<i32>(&array, &options, None).unwrap();
+    // nulls keys and keys of null values are nulls
+    assert_eq!(&indices.values()[..2], &[2, 3]);
+    assert_eq!(&indices.values()[2..4], &[1, 5]);
+    let mut last = indices.values()[4..].to_vec();
+    last.sort_unstable();
+    assert_eq!(last, vec![0, 4]);
+    assert!(can_sort(array.data_type()));
+}
+
+fn list_array(data: Vec<Option<Vec<Option<i32>>>>) -> ListArray<i32> {
+    let mut array = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+    array.try_extend(data).unwrap();
+    array.into()
+}
+
+#[test]
+fn lists() {
+    let input = list_array(vec![
+        Some(vec![Some(2), Some(1)]),
+        None,
+        Some(vec![Some(1), Some(3)]),
+        Some(vec![Some(1)]),
+        Some(vec![Some(1), None]),
+        Some(vec![]),
+    ]);
+
+    let options = SortOptions::default();
+    let output = sort_to_indices::<i32>(&input, &options, None).unwrap();
+    assert_eq!(output, Int32Array::from_slice([1, 5, 3, 4, 2, 0]));
+
+    let options = SortOptions {
+        descending: true,
+        nulls_first: false,
+    };
+    let output = sort_to_indices::<i32>(&input, &options, None).unwrap();
+    assert_eq!(output, Int32Array::from_slice([0, 2, 4, 3, 5, 1]));
+
+    let output = sort(&input, &options, Some(2)).unwrap();
+    let expected = list_array(vec![
+        Some(vec![Some(2), Some(1)]),
+        Some(vec![Some(1), Some(3)]),
+    ]);
+    assert_eq!(output.as_ref(), &expected as &dyn Array);
+}
+
+#[test]
+fn lists_of_strings() {
+    let mut input = MutableListArray::<i64, MutableUtf8Array<i32>>::new();
+    input
+        .try_extend([
+            Some(vec![Some("b")]),
+            Some(vec![Some("a"), Some("c")]),
+            Some(vec![Some("a")]),
+        ])
+        .unwrap();
+    let input: ListArray<i64> = input.into();
+    assert!(can_sort(input.data_type()));
+
+    let output = sort_to_indices::<i32>(&input, &SortOptions::default(), None).unwrap();
+    assert_eq!(output, Int32Array::from_slice([2, 1, 0]));
+}
+
+#[test]
+fn fixed_size_lists() {
+    let data_type = DataType::FixedSizeList(Box::new(Field::new("item", DataType::Int32, true)), 2);
+    let input = FixedSizeListArray::new(
+        data_type,
+        Int32Array::from([Some(1), Some(2), Some(0), Some(5), Some(1), None]).boxed(),
+        None,
+    );
+
+    let output = sort_to_indices::<i32>(&input, &SortOptions::default(), None).unwrap();
+    assert_eq!(output, Int32Array::from_slice([1, 2, 0]));
+}
+
+#[test]
+fn structs() {
+    let fields = vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+    ];
+    let input = StructArray::new(
+        DataType::Struct(fields),
+        vec![
+            Int32Array::from([Some(2), Some(1), Some(1), None, Some(3)]).boxed(),
+            Utf8Array::<i32>::from([Some("a"), Some("b"), Some("a"), Some("c"), None]).boxed(),
+        ],
+        Some([true, true, true, true, false].into()),
+    );
+    assert!(can_sort(input.data_type()));
+
+    let options = SortOptions {
+        descending: false,
+        nulls_first: false,
+    };
+    let output = sort_to_indices::<i32>(&input, &options, None).unwrap();
+    assert_eq!(output, Int32Array::from_slice([3, 2, 1, 0, 4]));
+
+    let sliced = input.slice(1, 3);
+    let output = sort_to_indices::<i32>(&sliced, &options, None).unwrap();
+    assert_eq!(output, Int32Array::from_slice([2, 1, 0]));
+}
+
+#[test]
+fn nested_unsupported() {
+    let data_type = DataType::List(Box::new(Field::new("item", DataType::Null, true)));
+    assert!(!can_sort(&data_type));
+    let input = new_empty_array(data_type);
+    assert!(sort_to_indices::<i32>(input.as_ref(), &SortOptions::default(), None).is_err());
+}
+
 /*
 #[test]
 fn list() {
@@ -0,0 +1,91 @@
+use arrow2::array::*;
+use arrow2::bitmap::Bitmap;
+use arrow2::compute::struct_::{struct_field, struct_pack};
+use arrow2::datatypes::{DataType, Field};
+use arrow2::error::Result;
+
+#[test]
+fn pack() -> Result<()> {
+    let a = Int32Array::from([Some(1), None]);
+    let b = Utf8Array::<i32>::from_slice(["x", "y"]);
+    let result = struct_pack(vec![("a", &a), ("b", &b)])?;
+
+    let expected = DataType::Struct(vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+    ]);
+    assert_eq!(result.data_type(), &expected);
+    assert_eq!(result.values(), &[a.clone().boxed(), b.boxed()]);
+    assert_eq!(result.validity(), None);
+
+    assert!(struct_pack(vec![]).is_err());
+    let c = Int32Array::from_slice([1]);
+    assert!(struct_pack(vec![("a", &a), ("c", &c)]).is_err());
+    Ok(())
+}
+
+#[test]
+fn field_merges_validity() -> Result<()> {
+    let c = Int32Array::from([Some(1), None, Some(3), Some(4)]);
+    let inner =
+        struct_pack(vec![("c", &c)])?.with_validity(Some(Bitmap::from([true, true, false, true])));
+    let outer = struct_pack(vec![("b", &inner)])?
+        .with_validity(Some(Bitmap::from([true, true, true, false])));
+
+    let result = struct_field(&outer, "b.c")?;
+    assert_eq!(
+        Int32Array::from([Some(1), None, None, None]),
+        result.as_ref()
+    );
+
+    let result = struct_field(&outer.slice(1, 2), "b.c")?;
+    assert_eq!(Int32Array::from([None, None]), result.as_ref());
+
+    assert!(struct_field(&outer, "b.d").is_err());
+    assert!(struct_field(&outer, "b.c.d").is_err());
+    Ok(())
+}
+
+#[test]
+fn field_through_list_and_map() -> Result<()> {
+    let a = Int32Array::from_slice([1, 2, 3]);
+    let values = struct_pack(vec![("a", &a)])?;
+    let data_type = ListArray::<i32>::default_datatype(values.data_type().clone());
+    let list = ListArray::<i32>::new(
+        data_type,
+        vec![0, 2, 3].try_into().unwrap(),
+        values.boxed(),
+        None,
+    );
+    let result = struct_field(&list, "a")?;
+    let expected = ListArray::<i32>::new(
+        ListArray::<i32>::default_datatype(DataType::Int32),
+        vec![0, 2, 3].try_into().unwrap(),
+        a.boxed(),
+        None,
+    );
+    assert_eq!(expected, result.as_ref());
+
+    let keys = Utf8Array::<i32>::from_slice(["k1", "k2", "k3"]);
+    let values = Int32Array::from_slice([1, 2, 3]);
+    let entries = struct_pack(vec![("key", &keys as &dyn Array), ("value", &values)])?;
+    let data_type = DataType::Map(
+        Box::new(Field::new("entries", entries.data_type().clone(), false)),
+        false,
+    );
+    let map = MapArray::new(
+        data_type,
+        vec![0, 1, 3].try_into().unwrap(),
+        entries.boxed(),
+        Some(Bitmap::from([true, false])),
+    );
+    let result = struct_field(&map, "value")?;
+    let expected = ListArray::<i32>::new(
+        ListArray::<i32>::default_datatype(DataType::Int32),
+        vec![0, 1, 3].try_into().unwrap(),
+        values.boxed(),
+        Some(Bitmap::from([true, false])),
+    );
+    assert_eq!(expected, result.as_ref());
+    Ok(())
+}
@@ -342,3 +342,89 @@ fn consistency() {
         }
     });
 }
+
+fn list<O: Offset>(data: Vec<Option<Vec<Option<i32>>>>) -> Result<ListArray<O>> {
+    let mut array = MutableListArray::<O, MutablePrimitiveArray<i32>>::new();
+    array.try_extend(data)?;
+    Ok(array.into())
+}
+
+#[test]
+fn list_slice_cases() -> Result<()> {
+    let array = list::<i32>(vec![
+        Some(vec![Some(1), Some(2), Some(3)]),
+        None,
+        Some(vec![Some(4), None]),
+        Some(vec![]),
+    ])?;
+
+    let cases = vec![
+        // identity
+        (0, None, array.clone()),
+        (
+            1,
+            Some(1),
+            list(vec![
+                Some(vec![Some(2)]),
+                None,
+                Some(vec![None]),
+                Some(vec![]),
+            ])?,
+        ),
+        (
+            -1,
+            None,
+            list(vec![
+                Some(vec![Some(3)]),
+                None,
+                Some(vec![None]),
+                Some(vec![]),
+            ])?,
+        ),
+        // high negative offset -> from the start
+        (
+            -1000,
+            Some(2),
+            list(vec![
+                Some(vec![Some(1), Some(2)]),
+                None,
+                Some(vec![Some(4), None]),
+                Some(vec![]),
+            ])?,
+        ),
+        // high offset -> nothing
+        (
+            1000,
+            None,
+            list(vec![Some(vec![]), None, Some(vec![]), Some(vec![])])?,
+        ),
+    ];
+
+    for (offset, length, expected) in cases {
+        let result = list_slice(&array, offset, length)?;
+        assert_eq!(expected, result.as_ref(), "{offset} {length:?}");
+    }
+    Ok(())
+}
+
+#[test]
+fn list_slice_large_and_sliced() -> Result<()> {
+    let array = list::<i64>(vec![
+        Some(vec![Some(1), Some(2)]),
+        Some(vec![Some(3), Some(4)]),
+        Some(vec![Some(5)]),
+    ])?
+    .slice(1, 2);
+    let result = list_slice(&array, -1, None)?;
+
+    let expected = list::<i64>(vec![Some(vec![Some(4)]), Some(vec![Some(5)])])?;
+    assert_eq!(expected, result.as_ref());
+    Ok(())
+}
+
+#[test]
+fn list_slice_invalid() {
+    let array = Int32Array::from_slice([1]);
+    assert!(list_slice(&array, 0, None).is_err());
+    assert!(!can_list_slice(array.data_type()));
+}
@@ -1,5 +1,5 @@
 use arrow2::compute::take::{can_take, take};
-use arrow2::datatypes::{DataType, Field, IntervalUnit};
+use arrow2::datatypes::{DataType, Field, IntervalUnit, UnionMode};
 use arrow2::error::Result;
 use arrow2::{array::*, bitmap::MutableBitmap, types::NativeType};
 use arrow2::{bitmap::Bitmap, buffer::Buffer};
@@ -305,3 +305,70 @@ fn test_nested() {
 
     assert_eq!(expected, result.as_ref());
 }
+
+#[test]
+fn take_chunk_with_scratch() -> Result<()> {
+    use arrow2::chunk::Chunk;
+    use arrow2::compute::take::{take_chunk, Scratch};
+
+    let chunk = Chunk::new(vec![
+        Int32Array::from([Some(1), None, Some(3)]).boxed(),
+        Utf8Array::<i32>::from([Some("a"), Some("bb"), None]).boxed(),
+        BinaryArray::<i64>::from_slice([b"a".as_ref(), b"bb", b"ccc"]).boxed(),
+        BooleanArray::from_slice([true, false, true]).boxed(),
+    ]);
+    let mut scratch = Scratch::default();
+
+    for indices in [
+        UInt32Array::from_slice([2, 0, 1, 1]),
+        UInt32Array::from([Some(1), None, Some(2)]),
+        UInt32Array::from_slice([]),
+    ] {
+        let result = take_chunk(&chunk, &indices, &mut scratch)?;
+        for (array, taken) in chunk.arrays().iter().zip(result.arrays()) {
+            assert_eq!(taken, &take(array.as_ref(), &indices)?);
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn union() -> Result<()> {
+    let fields = vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+    ];
+    let indices = Int32Array::from([Some(2), None, Some(0), Some(2)]);
+
+    for mode in [UnionMode::Sparse, UnionMode::Dense] {
+        let data_type = DataType::Union(fields.clone(), Some(vec![5, 7]), mode);
+        let mut array = MutableUnionArray::new(
+            data_type.clone(),
+            vec![
+                Box::new(MutablePrimitiveArray::<i32>::new()),
+                Box::new(MutableUtf8Array::<i32>::new()),
+            ],
+        );
+        array.push_variant::<MutablePrimitiveArray<i32>, _>(0, Some(1))?;
+        array.push_variant::<MutablePrimitiveArray<i32>, _>(0, Some(2))?;
+        array.push_variant::<MutableUtf8Array<i32>, _>(1, Some("a"))?;
+        let array: UnionArray = array.into();
+
+        let result = take(&array, &indices)?;
+        let result = result.as_any().downcast_ref::<UnionArray>().unwrap();
+        assert_eq!(result.data_type(), &data_type);
+        assert_eq!(result.types().as_slice(), &[7, 5, 5, 7]);
+        assert_eq!(result.value(0), array.value(2));
+        assert!(!result.value(1).is_valid());
+        assert_eq!(result.value(2), array.value(0));
+        assert_eq!(result.value(3), array.value(2));
+
+        // a sliced union is taken relative to its offset
+        let result = take(&array.slice(1, 2), &Int32Array::from_slice([1, 0]))?;
+        let result = result.as_any().downcast_ref::<UnionArray>().unwrap();
+        assert_eq!(result.value(0), array.value(2));
+        assert_eq!(result.value(1), array.value(1));
+    }
+    assert!(can_take(&DataType::Union(fields, None, UnionMode::Dense)));
+    Ok(())
+}
@@ -369,3 +369,225 @@ fn consistency_upper() {
         }
     });
 }
+
+#[test]
+fn concat_str_propagate() {
+    let a = Utf8Array::<i32>::from([Some("a"), Some("b"), None, Some("")]);
+    let b = Utf8Array::<i32>::from([Some("c"), None, Some("e"), Some("f")]);
+    let c = Utf8Array::<i32>::from_slice(["g", "h", "i", "j"]);
+
+    let result = concat_str(&[&a, &b, &c], Some(", ")).unwrap();
+    let expected = Utf8Array::<i32>::from([Some("a, c, g"), None, None, Some(", f, j")]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+    let result = concat_str(&[&a, &b], None).unwrap();
+    let expected = Utf8Array::<i32>::from([Some("ac"), None, None, Some("f")]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+}
+
+#[test]
+fn concat_str_skip() {
+    let a = Utf8Array::<i64>::from([Some("a"), Some("b"), None, None]);
+    let b = Utf8Array::<i64>::from([Some("c"), None, Some("e"), None]);
+
+    let result = concat_str_with(&[&a, &b], Some("-"), ConcatNulls::Skip).unwrap();
+    let expected = Utf8Array::<i64>::from([Some("a-c"), Some("b"), Some("e"), None]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+}
+
+#[test]
+fn concat_str_sliced() {
+    let a = Utf8Array::<i32>::from_slice(["a", "bb", "ccc"]);
+    let a = a.slice(1, 2);
+    let b = Utf8Array::<i32>::from_slice(["d", "e"]);
+
+    let result = concat_str(&[&a, &b], Some("|")).unwrap();
+    let expected = Utf8Array::<i32>::from_slice(["bb|d", "ccc|e"]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+}
+
+#[test]
+fn concat_binary_values() {
+    let a = BinaryArray::<i32>::from([Some(b"a".as_ref()), None]);
+    let b = BinaryArray::<i32>::from_slice([b"b", b"c"]);
+
+    let result = concat_binary(&[&a, &b], Some(b"\x00"), ConcatNulls::Skip).unwrap();
+    let expected = BinaryArray::<i32>::from_slice([b"a\x00b".as_ref(), b"c"]);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn concat_str_errors() {
+    let a = Utf8Array::<i32>::from_slice(["a"]);
+    let b = Utf8Array::<i64>::from_slice(["b"]);
+    let c = Utf8Array::<i32>::from_slice(["c", "d"]);
+
+    assert!(concat_str(&[], None).is_err());
+    assert!(concat_str(&[&a, &b], None).is_err());
+    assert!(concat_str(&[&a, &c], None).is_err());
+    assert!(concat_str(&[&Int32Array::from_slice([1])], None).is_err());
+}
+
+#[test]
+fn strpos_values() -> Result<()> {
+    let haystack =
+        Utf8Array::<i32>::from([Some("héllo"), Some("abc"), None, Some("abc"), Some("")]);
+    let needle = Utf8Array::<i32>::from([Some("lo"), Some("d"), Some("a"), None, Some("")]);
+    let expected = Int32Array::from([Some(4), Some(0), None, None, Some(1)]);
+    assert_eq!(strpos(&haystack, &needle)?, expected);
+    assert_eq!(instr(&haystack, &needle)?, expected);
+
+    let haystack = Utf8Array::<i64>::from([Some("aXbX"), None, Some("b")]);
+    assert_eq!(
+        strpos_scalar(&haystack, "X")?,
+        Int32Array::from([Some(2), None, Some(0)])
+    );
+    Ok(())
+}
+
+#[test]
+fn strpos_errors() {
+    let a = Utf8Array::<i32>::from_slice(["a"]);
+    let b = Utf8Array::<i64>::from_slice(["a"]);
+    let c = Utf8Array::<i32>::from_slice(["a", "b"]);
+    assert!(strpos(&a, &b).is_err());
+    assert!(strpos(&a, &c).is_err());
+    assert!(strpos_scalar(&Int32Array::from_slice([1]), "a").is_err());
+}
+
+#[test]
+fn starts_ends_with() -> Result<()> {
+    let lhs = Utf8Array::<i32>::from([Some("arrow"), Some("parquet"), None, Some("orc")]);
+    let rhs = Utf8Array::<i32>::from([Some("ar"), Some("quet"), Some(""), None]);
+    assert_eq!(
+        starts_with(&lhs, &rhs)?,
+        BooleanArray::from([Some(true), Some(false), None, None])
+    );
+    assert_eq!(
+        ends_with(&lhs, &rhs)?,
+        BooleanArray::from([Some(false), Some(true), None, None])
+    );
+
+    let lhs = Utf8Array::<i64>::from([Some("arrow"), None, Some("row")]);
+    assert_eq!(
+        starts_with_scalar(&lhs, "ar")?,
+        BooleanArray::from([Some(true), None, Some(false)])
+    );
+    assert_eq!(
+        ends_with_scalar(&lhs, "row")?,
+        BooleanArray::from([Some(true), None, Some(true)])
+    );
+    assert!(starts_with_scalar(&Int32Array::from_slice([1]), "a").is_err());
+    Ok(())
+}
+
+#[test]
+fn substring_index_values() -> Result<()> {
+    let array = Utf8Array::<i32>::from([Some("a.b.c"), Some("abc"), None, Some("")]);
+    let cases = [
+        (1, [Some("a"), Some("abc"), None, Some("")]),
+        (2, [Some("a.b"), Some("abc"), None, Some("")]),
+        (5, [Some("a.b.c"), Some("abc"), None, Some("")]),
+        (-1, [Some("c"), Some("abc"), None, Some("")]),
+        (-2, [Some("b.c"), Some("abc"), None, Some("")]),
+        (0, [Some(""), Some(""), None, Some("")]),
+    ];
+    for (count, expected) in cases {
+        let result = substring_index(&array, ".", count)?;
+        assert_eq!(
+            result.as_ref(),
+            &Utf8Array::<i32>::from(expected) as &dyn Array,
+            "count {count}"
+        );
+    }
+
+    let array = Utf8Array::<i64>::from_slice(["a::b::c"]);
+    let result = substring_index(&array, "::", -2)?;
+    assert_eq!(
+        result.as_ref(),
+        &Utf8Array::<i64>::from_slice(["b::c"]) as &dyn Array
+    );
+    assert!(substring_index(&Int32Array::from_slice([1]), ".", 1).is_err());
+    Ok(())
+}
+
+#[test]
+fn initcap_values() -> Result<()> {
+    let cases = [
+        vec![Some("hello WORLD"), None, Some("o'neil-smith 2nd")],
+        vec![Some("élan vital"), Some("ÉCOLE normale"), None],
+    ];
+    let expected = [
+        vec![Some("Hello World"), None, Some("O'Neil-Smith 2nd")],
+        vec![Some("Élan Vital"), Some("École Normale"), None],
+    ];
+    for (case, expected) in cases.into_iter().zip(expected) {
+        let result = initcap(&Utf8Array::<i32>::from(&case))?;
+        assert_eq!(
+            result.as_ref(),
+            &Utf8Array::<i32>::from(&expected) as &dyn Array
+        );
+        let result = initcap(&Utf8Array::<i64>::from(&case))?;
+        assert_eq!(
+            result.as_ref(),
+            &Utf8Array::<i64>::from(&expected) as &dyn Array
+        );
+    }
+
+    // every value starts a word, also when the values are contiguous
+    let array = Utf8Array::<i32>::from_slice(["ab", "cd", "ef"]).slice(1, 2);
+    let result = initcap(&array)?;
+    assert_eq!(
+        result.as_ref(),
+        &Utf8Array::<i32>::from_slice(["Cd", "Ef"]) as &dyn Array
+    );
+    assert!(initcap(&Int32Array::from_slice([1])).is_err());
+    Ok(())
+}
+
+#[test]
+fn upper_ascii_sliced() -> Result<()> {
+    let array = Utf8Array::<i32>::from([Some("é"), Some("ab"), None, Some("cd")]).slice(1, 3);
+    let result = upper(&array)?;
+    assert_eq!(
+        result.as_ref(),
+        &Utf8Array::<i32>::from([Some("AB"), None, Some("CD")]) as &dyn Array
+    );
+    Ok(())
+}
+
+#[cfg(feature = "compute_utf8_normalization")]
+#[test]
+fn normalize_values() -> Result<()> {
+    let array = Utf8Array::<i64>::from([Some("e\u{301}"), None, Some("\u{fb01}")]);
+    let cases = [
+        (
+            NormalizationForm::Nfc,
+            [Some("\u{e9}"), None, Some("\u{fb01}")],
+        ),
+        (
+            NormalizationForm::Nfd,
+            [Some("e\u{301}"), None, Some("\u{fb01}")],
+        ),
+        (NormalizationForm::Nfkc, [Some("\u{e9}"), None, Some("fi")]),
+        (
+            NormalizationForm::Nfkd,
+            [Some("e\u{301}"), None, Some("fi")],
+        ),
+    ];
+    for (form, expected) in cases {
+        let result = normalize(&array, form)?;
+        assert_eq!(
+            result.as_ref(),
+            &Utf8Array::<i64>::from(expected) as &dyn Array
+        );
+    }
+
+    let array = Utf8Array::<i32>::from_slice(["ascii"]);
+    assert_eq!(
+        normalize(&array, NormalizationForm::Nfkd)?.as_ref(),
+        &array as &dyn Array
+    );
+    assert!(normalize(&Int32Array::from_slice([1]), NormalizationForm::Nfc).is_err());
+    Ok(())
+}
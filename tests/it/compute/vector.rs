@@ -0,0 +1,117 @@
+use arrow2::array::*;
+use arrow2::compute::vector::*;
+use arrow2::datatypes::DataType;
+
+fn vectors(values: &[Option<f32>], size: usize, validity: Option<&[bool]>) -> FixedSizeListArray {
+    let data_type = FixedSizeListArray::default_datatype(DataType::Float32, size);
+    FixedSizeListArray::new(
+        data_type,
+        Float32Array::from(values).boxed(),
+        validity.map(|x| x.iter().copied().collect()),
+    )
+}
+
+#[test]
+fn pairwise() {
+    let lhs = vectors(
+        &[
+            Some(3.0),
+            Some(4.0),
+            Some(1.0),
+            Some(0.0),
+            Some(1.0),
+            None,
+            Some(1.0),
+            Some(1.0),
+        ],
+        2,
+        Some(&[true, true, true, false]),
+    );
+    let rhs = vectors(
+        &[
+            Some(3.0),
+            Some(4.0),
+            Some(0.0),
+            Some(2.0),
+            Some(1.0),
+            Some(1.0),
+            Some(1.0),
+            Some(1.0),
+        ],
+        2,
+        None,
+    );
+
+    let result = dot::<f32>(&lhs, &rhs).unwrap();
+    assert_eq!(
+        result,
+        Float32Array::from([Some(25.0), Some(0.0), None, None])
+    );
+
+    let result = cosine_similarity::<f32>(&lhs, &rhs).unwrap();
+    assert_eq!(
+        result,
+        Float32Array::from([Some(1.0), Some(0.0), None, None])
+    );
+
+    let result = l2_distance::<f32>(&lhs, &rhs).unwrap();
+    assert_eq!(
+        result,
+        Float32Array::from([Some(0.0), Some(5.0f32.sqrt()), None, None])
+    );
+}
+
+#[test]
+fn query() {
+    let array = vectors(
+        &[
+            Some(3.0),
+            Some(4.0),
+            Some(0.0),
+            Some(0.0),
+            Some(1.0),
+            Some(1.0),
+        ],
+        2,
+        Some(&[true, true, false]),
+    );
+
+    let result = dot_scalar(&array, &[1.0f32, 2.0]).unwrap();
+    assert_eq!(result, Float32Array::from([Some(11.0), Some(0.0), None]));
+
+    // the similarity to a vector of norm zero is undefined
+    let result = cosine_similarity_scalar(&array, &[0.0f32, 2.0]).unwrap();
+    assert_eq!(result, Float32Array::from([Some(0.8), None, None]));
+
+    let result = l2_distance_scalar(&array, &[0.0f32, 0.0]).unwrap();
+    assert_eq!(result, Float32Array::from([Some(5.0), Some(0.0), None]));
+}
+
+#[test]
+fn long_vectors() {
+    // longer than the number of lanes, with a remainder
+    let size = 37;
+    let lhs = (0..size * 2).map(|x| Some(x as f64)).collect::<Vec<_>>();
+    let data_type = FixedSizeListArray::default_datatype(DataType::Float64, size);
+    let lhs = FixedSizeListArray::new(data_type, Float64Array::from(lhs).boxed(), None);
+
+    let query = vec![1.0f64; size];
+    let expected = [
+        (0..size).sum::<usize>() as f64,
+        (size..size * 2).sum::<usize>() as f64,
+    ];
+    let result = dot_scalar(&lhs, &query).unwrap();
+    assert_eq!(result, Float64Array::from_slice(expected));
+
+    let result = l2_distance::<f64>(&lhs, &lhs).unwrap();
+    assert_eq!(result, Float64Array::from_slice([0.0, 0.0]));
+}
+
+#[test]
+fn errors() {
+    let lhs = vectors(&[Some(1.0), Some(2.0)], 2, None);
+    let rhs = vectors(&[Some(1.0), Some(2.0), Some(3.0)], 3, None);
+    assert!(dot::<f32>(&lhs, &rhs).is_err());
+    assert!(cosine_similarity::<f64>(&lhs, &lhs).is_err());
+    assert!(l2_distance_scalar(&lhs, &[1.0f32]).is_err());
+}
@@ -0,0 +1,583 @@
+use std::fmt::Write;
+use std::sync::Arc;
+
+use arrow2::array::*;
+use arrow2::datatypes::*;
+use arrow2::error::{Error, Result};
+
+/// An extension whose storage must be `Int32` and that writes its values in hexadecimal
+#[derive(Debug)]
+struct Hex(&'static str);
+
+impl ExtensionType for Hex {
+    fn name(&self) -> &str {
+        self.0
+    }
+
+    fn validate(&self, storage: &DataType, _: Option<&str>) -> Result<()> {
+        if storage != &DataType::Int32 {
+            return Err(Error::InvalidArgumentError(
+                "The storage must be Int32".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    fn write_value(&self, array: &dyn Array, index: usize, f: &mut dyn Write) -> std::fmt::Result {
+        let array = array.as_any().downcast_ref::<Int32Array>().unwrap();
+        write!(f, "{:#x}", array.value(index))
+    }
+}
+
+fn extension(name: &str, storage: DataType) -> DataType {
+    DataType::Extension(name.to_string(), Box::new(storage), None)
+}
+
+#[test]
+fn registry() {
+    assert!(get_extension_type("test.registry").is_none());
+    assert!(register_extension_type(Arc::new(Hex("test.registry"))).is_none());
+    assert_eq!(
+        get_extension_type("test.registry").unwrap().name(),
+        "test.registry"
+    );
+    assert!(register_extension_type(Arc::new(Hex("test.registry"))).is_some());
+    assert!(unregister_extension_type("test.registry").is_some());
+    assert!(get_extension_type("test.registry").is_none());
+}
+
+#[test]
+fn validate() {
+    let invalid = extension("test.validate", DataType::Utf8);
+    let nested = DataType::List(Box::new(Field::new("item", invalid.clone(), true)));
+    // unregistered extensions are valid
+    assert!(validate_extension(&invalid).is_ok());
+    assert!(validate_extension(&nested).is_ok());
+
+    register_extension_type(Arc::new(Hex("test.validate")));
+    assert!(validate_extension(&extension("test.validate", DataType::Int32)).is_ok());
+    assert!(validate_extension(&invalid).is_err());
+    assert!(validate_extension(&nested).is_err());
+}
+
+#[test]
+fn display() {
+    let data_type = extension("test.display", DataType::Int32);
+    let array = Int32Array::from([Some(255), None]).to(data_type);
+
+    let mut result = String::new();
+    let display = get_display(&array, "null");
+    display(&mut result, 0).unwrap();
+    assert_eq!(result, "255");
+
+    register_extension_type(Arc::new(Hex("test.display")));
+    let mut result = String::new();
+    let display = get_display(&array, "null");
+    display(&mut result, 0).unwrap();
+    display(&mut result, 1).unwrap();
+    assert_eq!(result, "0xffnull");
+}
+
+#[cfg(feature = "compute_cast")]
+#[test]
+fn cast() -> Result<()> {
+    use arrow2::compute::cast::{can_cast_types, cast, CastOptions};
+
+    register_extension_type(Arc::new(Hex("test.cast")));
+    let data_type = extension("test.cast", DataType::Int32);
+    let array = Int8Array::from_slice([1, 2]);
+
+    // to the extension, via its storage
+    assert!(can_cast_types(array.data_type(), &data_type));
+    let result = cast(&array, &data_type, CastOptions::default())?;
+    assert_eq!(
+        Int32Array::from_slice([1, 2]).to(data_type.clone()),
+        result.as_ref()
+    );
+
+    // from the extension, via its storage
+    let result = cast(result.as_ref(), &DataType::Int64, CastOptions::default())?;
+    assert_eq!(Int64Array::from_slice([1, 2]), result.as_ref());
+
+    // to an invalid extension
+    let invalid = extension("test.cast", DataType::Int64);
+    assert!(!can_cast_types(array.data_type(), &invalid));
+    assert!(cast(&array, &invalid, CastOptions::default()).is_err());
+    Ok(())
+}
+
+#[cfg(feature = "io_ipc")]
+#[test]
+fn ipc_schema() -> Result<()> {
+    use arrow2::io::ipc::read::deserialize_schema;
+    use arrow2::io::ipc::write::{default_ipc_fields, schema_to_bytes};
+
+    let schema = Schema::from(vec![Field::new(
+        "a",
+        DataType::Struct(vec![Field::new(
+            "b",
+            extension("test.ipc_schema", DataType::Utf8),
+            true,
+        )]),
+        true,
+    )]);
+    let bytes = schema_to_bytes(&schema, &default_ipc_fields(&schema.fields));
+    assert!(deserialize_schema(&bytes).is_ok());
+
+    register_extension_type(Arc::new(Hex("test.ipc_schema")));
+    assert!(deserialize_schema(&bytes).is_err());
+    Ok(())
+}
+
+/// An extension that counts how often it is validated
+#[cfg(all(feature = "io_ipc", feature = "compute_cast"))]
+#[derive(Debug, Default)]
+struct Counted(std::sync::atomic::AtomicUsize);
+
+#[cfg(all(feature = "io_ipc", feature = "compute_cast"))]
+impl ExtensionType for Counted {
+    fn name(&self) -> &str {
+        "test.counted"
+    }
+
+    fn validate(&self, _: &DataType, _: Option<&str>) -> Result<()> {
+        self.0.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+        Ok(())
+    }
+}
+
+#[cfg(all(feature = "io_ipc", feature = "compute_cast"))]
+#[test]
+fn validate_once() -> Result<()> {
+    use std::sync::atomic::Ordering;
+
+    use arrow2::compute::cast::{cast, CastOptions};
+    use arrow2::io::ipc::read::deserialize_schema;
+    use arrow2::io::ipc::write::{default_ipc_fields, schema_to_bytes};
+
+    let counted = Arc::new(Counted::default());
+    register_extension_type(counted.clone());
+    let data_type = extension("test.counted", DataType::Int32);
+
+    // nested in a struct in a struct
+    let inner = DataType::Struct(vec![Field::new("c", data_type.clone(), true)]);
+    let schema = Schema::from(vec![Field::new(
+        "a",
+        DataType::Struct(vec![Field::new("b", inner, true)]),
+        true,
+    )]);
+    let bytes = schema_to_bytes(&schema, &default_ipc_fields(&schema.fields));
+    deserialize_schema(&bytes)?;
+    assert_eq!(counted.0.swap(0, Ordering::Relaxed), 1);
+
+    // an extension whose storage is the extension
+    let outer = extension("test.counted", data_type);
+    cast(&Int8Array::from_slice([1]), &outer, CastOptions::default())?;
+    assert_eq!(counted.0.load(Ordering::Relaxed), 2);
+    Ok(())
+}
+
+#[cfg(feature = "canonical_extensions")]
+mod canonical {
+    use arrow2::datatypes::canonical::*;
+
+    use super::*;
+
+    #[test]
+    fn uuid_display() {
+        register_canonical_extension_types();
+        let value = *b"\x12\x3e\x45\x67\xe8\x9b\x12\xd3\xa4\x56\x42\x66\x14\x17\x40\x00";
+        let array = FixedSizeBinaryArray::new(uuid(), value.to_vec().into(), None);
+
+        let mut result = String::new();
+        get_display(&array, "null")(&mut result, 0).unwrap();
+        assert_eq!(result, "123e4567-e89b-12d3-a456-426614174000");
+    }
+
+    #[test]
+    fn validate() {
+        register_canonical_extension_types();
+        assert!(validate_extension(&uuid()).is_ok());
+        assert!(validate_extension(&json()).is_ok());
+        assert!(validate_extension(&large_json()).is_ok());
+
+        let invalid = DataType::Extension(UUID.to_string(), Box::new(DataType::Binary), None);
+        assert!(validate_extension(&invalid).is_err());
+        let metadata = Some("{}".to_string());
+        let valid = DataType::Extension(JSON.to_string(), Box::new(DataType::Utf8), metadata);
+        assert!(validate_extension(&valid).is_ok());
+        let metadata = Some("{\"a\":1}".to_string());
+        let invalid = DataType::Extension(JSON.to_string(), Box::new(DataType::Utf8), metadata);
+        assert!(validate_extension(&invalid).is_err());
+
+        let tensor = FixedShapeTensor::try_new(vec![2, 3], None, None).unwrap();
+        assert!(validate_extension(&tensor.data_type(DataType::Float32)).is_ok());
+        let invalid = DataType::Extension(
+            FIXED_SHAPE_TENSOR.to_string(),
+            Box::new(DataType::FixedSizeList(
+                Box::new(Field::new("item", DataType::Float32, true)),
+                5,
+            )),
+            Some(tensor.to_metadata()),
+        );
+        assert!(validate_extension(&invalid).is_err());
+    }
+
+    #[test]
+    fn tensor_metadata() -> Result<()> {
+        let tensor = FixedShapeTensor::try_new(vec![2, 3, 4], None, None)?;
+        assert_eq!(tensor.to_metadata(), r#"{"shape":[2,3,4]}"#);
+        assert_eq!(
+            FixedShapeTensor::try_from_metadata(&tensor.to_metadata())?,
+            tensor
+        );
+
+        let tensor = FixedShapeTensor::try_new(
+            vec![100, 200, 500],
+            Some(vec![
+                "C".to_string(),
+                "H \"x\"".to_string(),
+                "W".to_string(),
+            ]),
+            Some(vec![2, 0, 1]),
+        )?;
+        assert_eq!(
+            tensor.to_metadata(),
+            r#"{"shape":[100,200,500],"dim_names":["C","H \"x\"","W"],"permutation":[2,0,1]}"#
+        );
+        assert_eq!(
+            FixedShapeTensor::try_from_metadata(&tensor.to_metadata())?,
+            tensor
+        );
+
+        let metadata = r#" { "permutation" : [1, 0], "shape": [ 2 , 3 ] } "#;
+        let tensor = FixedShapeTensor::try_from_metadata(metadata)?;
+        assert_eq!(tensor.shape(), &[2, 3]);
+        assert_eq!(tensor.permutation(), Some([1, 0].as_ref()));
+        assert_eq!(tensor.dim_names(), None);
+
+        for invalid in [
+            "",
+            "{}",
+            r#"{"shape":[2,3]"#,
+            r#"{"shape":[2,-3]}"#,
+            r#"{"shape":[2,0]}"#,
+            r#"{"shape":[2,3],"other":[]}"#,
+            r#"{"shape":[2,3],"permutation":[0,0]}"#,
+            r#"{"shape":[2,3],"dim_names":["a"]}"#,
+            r#"{"shape":[4294967296,4294967296]}"#,
+        ] {
+            assert!(FixedShapeTensor::try_from_metadata(invalid).is_err());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn tensor_conversion() -> Result<()> {
+        let values = Int32Array::from_slice([1, 2, 3, 4, 5, 6]).boxed();
+        let data_type =
+            DataType::FixedSizeList(Box::new(Field::new("item", DataType::Int32, true)), 3);
+        let array = FixedSizeListArray::new(data_type, values, None);
+
+        let tensor = FixedShapeTensor::try_new(vec![1, 3], None, None)?;
+        let tensors = to_tensor(&array, &tensor)?;
+        assert_eq!(tensors.data_type(), &tensor.data_type(DataType::Int32));
+        assert_eq!(tensors.values(), array.values());
+
+        let (result, result_tensor) = from_tensor(&tensors)?;
+        assert_eq!(result, array);
+        assert_eq!(result_tensor, tensor);
+
+        let tensor = FixedShapeTensor::try_new(vec![2, 2], None, None)?;
+        assert!(to_tensor(&array, &tensor).is_err());
+        assert!(from_tensor(&array).is_err());
+        Ok(())
+    }
+
+    #[cfg(feature = "io_ipc")]
+    #[test]
+    fn ipc_round_trip() -> Result<()> {
+        use std::io::Cursor;
+
+        use arrow2::chunk::Chunk;
+        use arrow2::io::ipc::read::{read_file_metadata, FileReader};
+        use arrow2::io::ipc::write::{FileWriter, WriteOptions};
+
+        register_canonical_extension_types();
+        let uuids = FixedSizeBinaryArray::new(uuid(), vec![1u8; 32].into(), None);
+        let jsons = Utf8Array::<i32>::from_slice(["{}", "[1]"]);
+        let jsons = Utf8Array::<i32>::new(
+            json(),
+            jsons.offsets().clone(),
+            jsons.values().clone(),
+            None,
+        );
+        let tensor = FixedShapeTensor::try_new(vec![2], Some(vec!["x".to_string()]), None)?;
+        let data_type =
+            DataType::FixedSizeList(Box::new(Field::new("item", DataType::Int32, true)), 2);
+        let values = Int32Array::from_slice([1, 2, 3, 4]).boxed();
+        let tensors = to_tensor(&FixedSizeListArray::new(data_type, values, None), &tensor)?;
+
+        let schema = Schema::from(vec![
+            Field::new("uuid", uuids.data_type().clone(), true),
+            Field::new("json", jsons.data_type().clone(), true),
+            Field::new("tensor", tensors.data_type().clone(), true),
+        ]);
+        let chunk = Chunk::new(vec![uuids.boxed(), jsons.boxed(), tensors.boxed()]);
+
+        let mut writer = FileWriter::try_new(
+            Cursor::new(vec![]),
+            schema.clone(),
+            None,
+            WriteOptions::default(),
+        )?;
+        writer.write(&chunk, None)?;
+        writer.finish()?;
+
+        let mut reader = Cursor::new(writer.into_inner().into_inner());
+        let metadata = read_file_metadata(&mut reader)?;
+        let data_types = metadata
+            .schema
+            .fields
+            .iter()
+            .map(|field| field.data_type().clone())
+            .collect::<Vec<_>>();
+        let expected = schema
+            .fields
+            .iter()
+            .map(|field| field.data_type().clone())
+            .collect::<Vec<_>>();
+        assert_eq!(data_types, expected);
+
+        let chunks = FileReader::new(reader, metadata, None, None).collect::<Result<Vec<_>>>()?;
+        assert_eq!(chunks, vec![chunk]);
+        Ok(())
+    }
+}
+
+#[test]
+fn merge_schemas_widens() -> Result<()> {
+    let metadata = Metadata::from([("a".to_string(), "1".to_string())]);
+    let schema1 = Schema::from(vec![
+        Field::new("i", DataType::Int8, false),
+        Field::new("u", DataType::UInt32, false),
+        Field::new("n", DataType::Null, true),
+        Field::new(
+            "s",
+            DataType::Struct(vec![Field::new("a", DataType::Utf8, false)]),
+            false,
+        ),
+        Field::new(
+            "l",
+            DataType::List(Box::new(Field::new("item", DataType::Float32, true))),
+            false,
+        ),
+    ])
+    .with_metadata(metadata.clone());
+    let schema2 = Schema::from(vec![
+        Field::new("u", DataType::Int16, false),
+        Field::new("i", DataType::UInt8, false),
+        Field::new("n", DataType::Boolean, false),
+        Field::new(
+            "s",
+            DataType::Struct(vec![
+                Field::new("b", DataType::Int32, false),
+                Field::new("a", DataType::LargeUtf8, false),
+            ]),
+            false,
+        ),
+        Field::new(
+            "l",
+            DataType::LargeList(Box::new(Field::new("item", DataType::Float64, false))),
+            true,
+        ),
+        Field::new("new", DataType::Int32, false),
+    ])
+    .with_metadata(Metadata::from([
+        ("a".to_string(), "2".to_string()),
+        ("b".to_string(), "2".to_string()),
+    ]));
+
+    let result = merge_schemas(&[schema1, schema2])?;
+
+    let expected = Schema::from(vec![
+        Field::new("i", DataType::Int16, false),
+        Field::new("u", DataType::Int64, false),
+        Field::new("n", DataType::Boolean, true),
+        Field::new(
+            "s",
+            DataType::Struct(vec![
+                Field::new("a", DataType::LargeUtf8, false),
+                Field::new("b", DataType::Int32, true),
+            ]),
+            false,
+        ),
+        Field::new(
+            "l",
+            DataType::LargeList(Box::new(Field::new("item", DataType::Float64, true))),
+            true,
+        ),
+        Field::new("new", DataType::Int32, true),
+    ])
+    .with_metadata(Metadata::from([
+        ("a".to_string(), "1".to_string()),
+        ("b".to_string(), "2".to_string()),
+    ]));
+    assert_eq!(result, expected);
+    Ok(())
+}
+
+#[test]
+fn merge_schemas_incompatible() {
+    let schema1 = Schema::from(vec![Field::new("a", DataType::UInt64, false)]);
+    let schema2 = Schema::from(vec![Field::new("a", DataType::Int8, false)]);
+    assert!(merge_schemas(&[schema1.clone(), schema2]).is_err());
+
+    let schema2 = Schema::from(vec![Field::new("a", DataType::Utf8, false)]);
+    assert!(merge_schemas(&[schema1, schema2]).is_err());
+
+    assert!(merge_schemas(&[]).is_err());
+}
+
+#[test]
+fn text_round_trip() -> Result<()> {
+    let item = |data_type| Box::new(Field::new("item", data_type, true));
+    let entries = Field::new(
+        "entries",
+        DataType::Struct(vec![
+            Field::new("key", DataType::Utf8, false),
+            Field::new("value", DataType::Int32, true),
+        ]),
+        false,
+    );
+    let data_types = vec![
+        (DataType::Null, "null"),
+        (DataType::UInt16, "uint16"),
+        (DataType::Float16, "float16"),
+        (
+            DataType::Timestamp(TimeUnit::Nanosecond, None),
+            "timestamp(ns)",
+        ),
+        (
+            DataType::Timestamp(TimeUnit::Second, Some("+01:00".to_string())),
+            "timestamp(s, \"+01:00\")",
+        ),
+        (DataType::Time64(TimeUnit::Microsecond), "time64(us)"),
+        (DataType::Duration(TimeUnit::Millisecond), "duration(ms)"),
+        (
+            DataType::Interval(IntervalUnit::MonthDayNano),
+            "interval(month_day_nano)",
+        ),
+        (DataType::FixedSizeBinary(16), "fixed_size_binary(16)"),
+        (DataType::LargeUtf8, "large_utf8"),
+        (DataType::Decimal(10, 2), "decimal(10, 2)"),
+        (DataType::Decimal256(76, 0), "decimal256(76, 0)"),
+        (DataType::List(item(DataType::Int32)), "list<int32>"),
+        (
+            DataType::LargeList(Box::new(Field::new("element", DataType::Utf8, false))),
+            "large_list<element: utf8 not null>",
+        ),
+        (
+            DataType::FixedSizeList(item(DataType::Float32), 3),
+            "fixed_size_list<float32>(3)",
+        ),
+        (
+            DataType::Struct(vec![
+                Field::new("a", DataType::Boolean, false),
+                Field::new("b c", DataType::Date32, true),
+            ]),
+            "struct<a: boolean not null, \"b c\": date32>",
+        ),
+        (DataType::Struct(vec![]), "struct<>"),
+        (
+            DataType::Union(
+                vec![Field::new("a", DataType::Int8, true)],
+                Some(vec![5]),
+                UnionMode::Sparse,
+            ),
+            "sparse_union<a: int8>(5)",
+        ),
+        (
+            DataType::Union(
+                vec![Field::new("a", DataType::Int8, true)],
+                None,
+                UnionMode::Dense,
+            ),
+            "dense_union<a: int8>",
+        ),
+        (
+            DataType::Map(Box::new(entries.clone()), false),
+            "map<utf8 not null, int32>",
+        ),
+        (
+            DataType::Map(Box::new(entries), true),
+            "map<utf8 not null, int32>(sorted)",
+        ),
+        (
+            DataType::Dictionary(IntegerType::UInt8, Box::new(DataType::Utf8), false),
+            "dictionary<uint8, utf8>",
+        ),
+        (
+            DataType::Dictionary(IntegerType::Int32, Box::new(DataType::Binary), true),
+            "dictionary<int32, binary>(sorted)",
+        ),
+        (
+            DataType::Extension(
+                "a \"quoted\" name".to_string(),
+                Box::new(DataType::FixedSizeBinary(16)),
+                Some("\\".to_string()),
+            ),
+            "extension<fixed_size_binary(16)>(\"a \\\"quoted\\\" name\", \"\\\\\")",
+        ),
+    ];
+
+    for (data_type, text) in data_types {
+        assert_eq!(data_type.to_string(), text);
+        assert_eq!(text.parse::<DataType>()?, data_type);
+    }
+    Ok(())
+}
+
+#[test]
+fn text_schema() -> Result<()> {
+    let schema: Schema = " a :int64 not null,b: list< x : utf8 > ".parse()?;
+    assert_eq!(
+        schema,
+        Schema::from(vec![
+            Field::new("a", DataType::Int64, false),
+            Field::new(
+                "b",
+                DataType::List(Box::new(Field::new("x", DataType::Utf8, true))),
+                true
+            ),
+        ])
+    );
+    assert_eq!(schema.to_string(), "a: int64 not null, b: list<x: utf8>");
+
+    assert_eq!("".parse::<Schema>()?, Schema::default());
+    assert_eq!(
+        "\"not null\": null not null".parse::<Field>()?,
+        Field::new("not null", DataType::Null, false)
+    );
+    Ok(())
+}
+
+#[test]
+fn text_errors() {
+    for text in [
+        "",
+        "int",
+        "int32 int32",
+        "list<int32",
+        "timestamp(h)",
+        "decimal(10)",
+        "dictionary<utf8, utf8>",
+        "struct<int32>",
+        "extension<int32>(\"unterminated)",
+        "fixed_size_binary(-1)",
+    ] {
+        assert!(text.parse::<DataType>().is_err(), "{text}");
+    }
+    assert!("a int32".parse::<Schema>().is_err());
+    assert!("a: int32,".parse::<Schema>().is_err());
+}
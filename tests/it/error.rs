@@ -0,0 +1,102 @@
+use std::error::Error as _;
+
+use arrow2::error::{Error, ErrorKind, Result, ResultExt};
+
+#[test]
+fn kind() {
+    let cases = [
+        (
+            Error::NotYetImplemented("a".to_string()),
+            ErrorKind::NotYetImplemented,
+        ),
+        (
+            Error::from_external_error(std::fmt::Error),
+            ErrorKind::External,
+        ),
+        (Error::from(std::io::Error::other("a")), ErrorKind::Io),
+        (
+            Error::InvalidArgumentError("a".to_string()),
+            ErrorKind::InvalidArgument,
+        ),
+        (
+            Error::ExternalFormat("a".to_string()),
+            ErrorKind::ExternalFormat,
+        ),
+        (Error::Overflow, ErrorKind::Overflow),
+        (Error::OutOfSpec("a".to_string()), ErrorKind::OutOfSpec),
+    ];
+    for (error, kind) in cases {
+        assert_eq!(error.kind(), kind);
+        assert_eq!(error.context("b").kind(), kind);
+    }
+    assert_eq!(ErrorKind::OutOfSpec.code(), "out_of_spec");
+    assert_eq!(ErrorKind::InvalidArgument.to_string(), "invalid_argument");
+}
+
+#[test]
+fn context() {
+    let result: Result<()> = Err(Error::OutOfSpec("invalid offsets".to_string()));
+    let line = line!() + 1;
+    let error = result.context("reading column \"a\"").unwrap_err();
+    let error = error.context("reading file");
+
+    assert_eq!(
+        error.to_string(),
+        "reading file: reading column \"a\": invalid offsets"
+    );
+    assert!(matches!(error.root(), Error::OutOfSpec(message) if message == "invalid offsets"));
+    assert_eq!(error.location().unwrap().file(), file!());
+
+    // the location of the inner context
+    let inner = match &error {
+        Error::Context { source, .. } => source,
+        _ => unreachable!(),
+    };
+    assert_eq!(inner.location().unwrap().line(), line);
+
+    // the chain of sources ends at the original error
+    let source = error.source().unwrap().source().unwrap();
+    assert_eq!(source.to_string(), "invalid offsets");
+    assert!(source.source().is_none());
+}
+
+#[test]
+fn with_context_is_lazy() {
+    let result: Result<u8> = Ok(1);
+    let result = result.with_context(|| -> String { unreachable!() });
+    assert_eq!(result.unwrap(), 1);
+
+    let result: Result<u8> = Err(Error::Overflow);
+    let error = result.with_context(|| format!("column {}", 1)).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "column 1: Operation overflew the backing container."
+    );
+}
+
+#[test]
+fn external_source() {
+    let error = Error::from(std::io::Error::new(std::io::ErrorKind::NotFound, "missing"));
+    let source = error.source().unwrap();
+    let source = source.downcast_ref::<std::io::Error>().unwrap();
+    assert_eq!(source.kind(), std::io::ErrorKind::NotFound);
+
+    let error = Error::from_external_error(std::fmt::Error).context("formatting");
+    let source = error.root().source().unwrap();
+    assert!(source.downcast_ref::<std::fmt::Error>().is_some());
+}
+
+#[test]
+fn out_of_spec_location() {
+    use arrow2::array::PrimitiveArray;
+    use arrow2::datatypes::DataType;
+
+    let error =
+        PrimitiveArray::<i32>::try_new(DataType::Int32, vec![1, 2].into(), Some([true].into()))
+            .unwrap_err();
+    match &error {
+        Error::OutOfSpec(message) => assert!(message.contains("primitive")),
+        other => panic!("expected an OutOfSpec error, got {other:?}"),
+    }
+    assert_eq!(error.location(), None);
+}
@@ -362,3 +362,180 @@ fn extension_children() -> Result<()> {
     );
     test_round_trip_schema(field)
 }
+
+#[test]
+fn extension_metadata() -> Result<()> {
+    let mut metadata = BTreeMap::new();
+    metadata.insert("some".to_string(), "stuff".to_string());
+    let field = Field::new(
+        "a",
+        DataType::Extension("a".to_string(), Box::new(DataType::Int32), None),
+        true,
+    )
+    .with_metadata(metadata);
+    test_round_trip_schema(field.clone())?;
+
+    // the extension keys are read from the metadata
+    let schema_ffi = ffi::export_field_to_c(&field);
+    let schema_ffi = ffi::export_field_to_c(&Field::new(
+        "a",
+        DataType::Struct(vec![unsafe { ffi::import_field_from_c(&schema_ffi)? }]),
+        true,
+    ));
+    let result = unsafe { ffi::import_field_from_c(&schema_ffi)? };
+    assert_eq!(result.data_type(), &DataType::Struct(vec![field]));
+    Ok(())
+}
+
+#[test]
+fn extension_keys_in_metadata_are_ignored() -> Result<()> {
+    let mut metadata = BTreeMap::new();
+    metadata.insert("ARROW:extension:name".to_string(), "b".to_string());
+    metadata.insert("some".to_string(), "stuff".to_string());
+    let field = Field::new("a", DataType::Int32, true).with_metadata(metadata);
+
+    let schema_ffi = ffi::export_field_to_c(&field);
+    let result = unsafe { ffi::import_field_from_c(&schema_ffi)? };
+
+    let mut metadata = BTreeMap::new();
+    metadata.insert("some".to_string(), "stuff".to_string());
+    assert_eq!(
+        result,
+        Field::new("a", DataType::Int32, true).with_metadata(metadata)
+    );
+    Ok(())
+}
+
+#[test]
+fn extension_dict() -> Result<()> {
+    let data = vec![Some("a"), Some("a"), None, Some("b")];
+
+    let mut array = MutableDictionaryArray::<i32, MutableUtf8Array<i32>>::new();
+    array.try_extend(data)?;
+    let array: DictionaryArray<i32> = array.into();
+
+    let data_type = DataType::Extension(
+        "a".to_string(),
+        Box::new(array.data_type().clone()),
+        Some("bla".to_string()),
+    );
+    let array = DictionaryArray::try_new(data_type, array.keys().clone(), array.values().clone())?;
+
+    test_round_trip(array)
+}
+
+#[test]
+fn dict_of_extension() -> Result<()> {
+    let field = Field::new(
+        "a",
+        DataType::Dictionary(
+            u32::KEY_TYPE,
+            Box::new(DataType::Extension(
+                "a".to_string(),
+                Box::new(DataType::Utf8),
+                None,
+            )),
+            true,
+        ),
+        true,
+    );
+    test_round_trip_schema(field)
+}
+
+#[test]
+fn struct_sliced() -> Result<()> {
+    let data_type = DataType::Struct(vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+    ]);
+    let values = vec![
+        Int32Array::from([Some(1), None, Some(3), Some(4)]).boxed(),
+        Utf8Array::<i32>::from([Some("a"), Some("b"), None, Some("d")]).boxed(),
+    ];
+    let validity = Bitmap::from([true, true, false, true]);
+
+    let array = StructArray::new(data_type, values, validity.into());
+
+    test_round_trip(array.slice(1, 3))
+}
+
+/// Slices an exported [`ffi::ArrowArray`] via its `offset`, as producers that
+/// export sliced arrays without copying (e.g. pyarrow) do.
+fn slice_ffi(array: &mut ffi::ArrowArray, offset: usize, length: usize, null_count: usize) {
+    // the struct is `repr(C)` and starts with `length`, `null_count`, `offset` (all `i64`)
+    let ptr = array as *mut ffi::ArrowArray as *mut i64;
+    unsafe {
+        *ptr = length as i64;
+        *ptr.add(1) = null_count as i64;
+        *ptr.add(2) = offset as i64;
+    }
+}
+
+fn test_import_with_offset(array: Box<dyn Array>, offset: usize, length: usize) -> Result<()> {
+    let expected = array.slice(offset, length);
+
+    let data_type = array.data_type().clone();
+    let mut array_ffi = ffi::export_array_to_c(array);
+    slice_ffi(&mut array_ffi, offset, length, expected.null_count());
+
+    let result = unsafe { ffi::import_array_from_c(array_ffi, data_type)? };
+    assert_eq!(result, expected);
+    Ok(())
+}
+
+#[test]
+fn import_offset_bool_and_utf8() -> Result<()> {
+    let array = BooleanArray::from([Some(true), None, Some(false), Some(true)]);
+    test_import_with_offset(array.boxed(), 1, 3)?;
+
+    let array = Utf8Array::<i32>::from([Some("a"), None, Some("cc"), Some("ddd")]);
+    test_import_with_offset(array.boxed(), 2, 2)
+}
+
+#[test]
+fn import_offset_list() -> Result<()> {
+    let mut array = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+    array.try_extend(vec![
+        Some(vec![Some(1i32), Some(2)]),
+        None,
+        Some(vec![Some(4), None]),
+        Some(vec![Some(5)]),
+    ])?;
+    let array: ListArray<i32> = array.into();
+    test_import_with_offset(array.boxed(), 1, 3)
+}
+
+#[test]
+fn import_offset_struct() -> Result<()> {
+    let data_type = DataType::Struct(vec![Field::new("a", DataType::Int32, true)]);
+    let values = vec![Int32Array::from([Some(1), None, Some(3), Some(4)]).boxed()];
+    let validity = Bitmap::from([true, true, false, true]);
+    let array = StructArray::new(data_type, values, validity.into());
+    test_import_with_offset(array.boxed(), 1, 2)
+}
+
+#[test]
+fn import_offset_fixed_size_list() -> Result<()> {
+    let array = FixedSizeListArray::try_new(
+        DataType::FixedSizeList(Box::new(Field::new("a", DataType::Int32, true)), 2),
+        Box::new(PrimitiveArray::<i32>::from_vec(vec![1, 2, 3, 4, 5, 6])),
+        Some(Bitmap::from([true, false, true])),
+    )?;
+    test_import_with_offset(array.boxed(), 1, 2)
+}
+
+#[test]
+fn import_offset_sparse_union() -> Result<()> {
+    let fields = vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+    ];
+    let data_type = DataType::Union(fields, None, arrow2::datatypes::UnionMode::Sparse);
+    let types = vec![0, 1, 1, 0].into();
+    let fields = vec![
+        Int32Array::from([Some(1), None, Some(3), Some(4)]).boxed(),
+        Utf8Array::<i32>::from([Some("a"), Some("b"), None, Some("d")]).boxed(),
+    ];
+    let array = UnionArray::try_new(data_type, types, fields, None)?;
+    test_import_with_offset(array.boxed(), 1, 3)
+}
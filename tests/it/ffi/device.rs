@@ -0,0 +1,58 @@
+use arrow2::array::*;
+use arrow2::datatypes::DataType;
+use arrow2::error::Result;
+use arrow2::ffi::{self, device::*};
+
+#[test]
+fn cpu_round_trip() -> Result<()> {
+    let expected = Utf8Array::<i32>::from([Some("a"), None, Some("c")]).boxed();
+
+    let array = ffi::export_array_to_c_device(expected.clone());
+    assert_eq!(array.device_type(), ARROW_DEVICE_CPU);
+    assert!(array.sync_event().is_null());
+
+    let result = unsafe { ffi::import_device_array_from_c(array, DataType::Utf8)? };
+    assert_eq!(result, expected);
+    Ok(())
+}
+
+/// Returns an array on the CPU that claims to be on a CUDA device
+fn cuda_array(expected: Box<dyn Array>) -> ArrowDeviceArray {
+    let array = ffi::export_array_to_c(expected);
+    ArrowDeviceArray::new(array, ARROW_DEVICE_CUDA, 0, std::ptr::null_mut())
+}
+
+#[test]
+fn gpu_errors() {
+    let array = cuda_array(Int32Array::from_slice([1, 2]).boxed());
+    assert!(!array.is_cpu_accessible());
+    assert!(unsafe { ffi::import_device_array_from_c(array, DataType::Int32) }.is_err());
+}
+
+#[test]
+fn gpu_with_copy() -> Result<()> {
+    let expected = Int32Array::from_slice([1, 2]).boxed();
+
+    let mut copies = 0;
+    let result = unsafe {
+        ffi::import_device_array_from_c_with_copy(
+            cuda_array(expected.clone()),
+            DataType::Int32,
+            |array| {
+                copies += 1;
+                assert_eq!(array.device_id(), 0);
+                Ok(array.into_array())
+            },
+        )?
+    };
+    assert_eq!(copies, 1);
+    assert_eq!(result, expected);
+
+    // arrays on the CPU are not copied
+    let array = ffi::export_array_to_c_device(expected.clone());
+    let result = unsafe {
+        ffi::import_device_array_from_c_with_copy(array, DataType::Int32, |_| unreachable!())?
+    };
+    assert_eq!(result, expected);
+    Ok(())
+}
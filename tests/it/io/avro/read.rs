@@ -384,3 +384,224 @@ fn test_list() -> Result<()> {
     assert_eq!(result, expected);
     Ok(())
 }
+
+fn write_evolved() -> std::result::Result<(Vec<u8>, AvroSchema), avro_rs::Error> {
+    let raw_schema = r#"
+    {
+        "type": "record",
+        "name": "test",
+        "fields": [
+            {"name": "a", "type": "long"},
+            {"name": "b", "type": "string"},
+            {"name": "unknown", "type": "string"},
+            {"name": "c", "type": ["null", "int"]},
+            {"name": "s", "type": {
+                "type": "record",
+                "name": "s",
+                "fields": [
+                    {"name": "x", "type": "int"},
+                    {"name": "unknown", "type": "double"}
+                ]
+            }}
+        ]
+    }
+"#;
+    let schema = AvroSchema::parse_str(raw_schema).unwrap();
+    let mut writer = Writer::new(&schema, Vec::new());
+
+    for (a, c) in [(1i64, Some(10)), (2, None)] {
+        let mut record = Record::new(writer.schema()).unwrap();
+        record.put("a", a);
+        record.put("b", format!("b{a}"));
+        record.put("unknown", "ignored");
+        record.put(
+            "c",
+            Value::Union(Box::new(c.map(Value::Int).unwrap_or(Value::Null))),
+        );
+        record.put(
+            "s",
+            Value::Record(vec![
+                ("x".to_string(), Value::Int(a as i32 * 100)),
+                ("unknown".to_string(), Value::Double(0.5)),
+            ]),
+        );
+        writer.append(record)?;
+    }
+    Ok((writer.into_inner().unwrap(), schema))
+}
+
+#[test]
+fn read_with_resolution() -> Result<()> {
+    let (avro, _) = write_evolved().unwrap();
+    let file = &mut &avro[..];
+    let metadata = read_metadata(file)?;
+
+    let s = vec![
+        Field::new("x", DataType::Int64, false),
+        Field::new("w", DataType::Int32, false),
+    ];
+    let fields = vec![
+        Field::new("new", DataType::Utf8, true),
+        Field::new("a", DataType::Float64, false),
+        Field::new("b", DataType::Binary, false),
+        Field::new("c", DataType::Int64, true),
+        Field::new("s", DataType::Struct(s.clone()), false),
+        Field::new("d", DataType::Int32, false),
+    ];
+    let defaults = [
+        ("s.w".to_string(), Int32Array::from_slice([7]).boxed()),
+        ("d".to_string(), Int32Array::from_slice([5]).boxed()),
+    ]
+    .into_iter()
+    .collect();
+
+    let resolution = read::SchemaResolution::try_new(&metadata.record, fields.clone(), defaults)?;
+    assert_eq!(resolution.fields(), fields.as_slice());
+
+    let mut reader = read::Reader::with_resolution(file, metadata, resolution);
+    let result = reader.next().unwrap()?;
+
+    let expected = Chunk::new(vec![
+        Utf8Array::<i32>::new_null(DataType::Utf8, 2).boxed(),
+        Float64Array::from_slice([1.0, 2.0]).boxed(),
+        BinaryArray::<i32>::from_slice([b"b1", b"b2"]).boxed(),
+        Int64Array::from([Some(10), None]).boxed(),
+        StructArray::new(
+            DataType::Struct(s),
+            vec![
+                Int64Array::from_slice([100, 200]).boxed(),
+                Int32Array::from_slice([7, 7]).boxed(),
+            ],
+            None,
+        )
+        .boxed(),
+        Int32Array::from_slice([5, 5]).boxed(),
+    ]);
+    assert_eq!(result, expected);
+    assert!(reader.next().is_none());
+    Ok(())
+}
+
+#[test]
+fn resolution_errors() -> Result<()> {
+    let (avro, _) = write_evolved().unwrap();
+    let metadata = read_metadata(&mut &avro[..])?;
+    let resolve = |field: Field| {
+        read::SchemaResolution::try_new(&metadata.record, vec![field], Default::default())
+    };
+
+    // missing and not nullable, without default
+    assert!(resolve(Field::new("new", DataType::Int32, false)).is_err());
+    // not promotable
+    assert!(resolve(Field::new("a", DataType::Int32, false)).is_err());
+    assert!(resolve(Field::new("b", DataType::Int64, false)).is_err());
+
+    // the default must have the field's type
+    let defaults = [("new".to_string(), Int64Array::from_slice([1]).boxed())]
+        .into_iter()
+        .collect();
+    let field = Field::new("new", DataType::Int32, false);
+    assert!(read::SchemaResolution::try_new(&metadata.record, vec![field], defaults).is_err());
+
+    // nullable in the writer with nulls, but not nullable in the reader
+    let file = &mut &avro[..];
+    let metadata = read_metadata(file)?;
+    let resolution = read::SchemaResolution::try_new(
+        &metadata.record,
+        vec![Field::new("c", DataType::Int32, false)],
+        Default::default(),
+    )?;
+    let mut reader = read::Reader::with_resolution(file, metadata, resolution);
+    assert!(reader.next().unwrap().is_err());
+    Ok(())
+}
+
+fn confluent_message(schema: &AvroSchema, schema_id: u32, record: Value) -> Vec<u8> {
+    let mut message = vec![read::CONFLUENT_MAGIC_BYTE];
+    message.extend_from_slice(&schema_id.to_be_bytes());
+    message.extend(avro_rs::to_avro_datum(schema, record).unwrap());
+    message
+}
+
+#[test]
+fn decode_single() -> Result<()> {
+    let raw_schema = r#"
+    {
+        "type": "record",
+        "name": "test",
+        "fields": [
+            {"name": "a", "type": "long"},
+            {"name": "b", "type": ["null", "string"]},
+            {"name": "c", "type": {"type": "array", "items": "int"}}
+        ]
+    }
+"#;
+    let schema = AvroSchema::parse_str(raw_schema).unwrap();
+
+    let message = |a: i64, b: Option<&str>, c: Vec<i32>| {
+        let b = b
+            .map(|b| Value::String(b.to_string()))
+            .unwrap_or(Value::Null);
+        let record = Value::Record(vec![
+            ("a".to_string(), Value::Long(a)),
+            ("b".to_string(), Value::Union(Box::new(b))),
+            (
+                "c".to_string(),
+                Value::Array(c.into_iter().map(Value::Int).collect()),
+            ),
+        ]);
+        confluent_message(&schema, 7, record)
+    };
+
+    let mut writer = Writer::new(&schema, vec![]);
+    writer
+        .append(Value::Record(vec![
+            ("a".to_string(), Value::Long(0)),
+            ("b".to_string(), Value::Union(Box::new(Value::Null))),
+            ("c".to_string(), Value::Array(vec![])),
+        ]))
+        .unwrap();
+    let file = writer.into_inner().unwrap();
+    let record = read_metadata(&mut &file[..])?.record;
+
+    let mut decoder = read::SingleDecoder::try_new(7, &record, Some(vec![true, true, false]))?;
+    assert_eq!(
+        decoder.fields(),
+        vec![
+            Field::new("a", DataType::Int64, false),
+            Field::new("b", DataType::Utf8, true),
+        ]
+    );
+
+    decoder.decode_single(&message(1, Some("x"), vec![1, 2]))?;
+    decoder.decode_single(&message(-5, None, vec![]))?;
+
+    // invalid messages are not added
+    let valid = message(3, Some("y"), vec![3]);
+    assert!(decoder.decode_single(&valid[..valid.len() - 1]).is_err());
+    assert!(decoder
+        .decode_single(&[valid.as_slice(), &[0]].concat())
+        .is_err());
+    assert!(decoder.decode_single(&valid[1..]).is_err());
+    let mut other_schema_id = valid.clone();
+    other_schema_id[4] = 8;
+    assert!(decoder.decode_single(&other_schema_id).is_err());
+    assert_eq!(decoder.len(), 2);
+
+    let expected = Chunk::new(vec![
+        Int64Array::from_slice([1, -5]).boxed(),
+        Utf8Array::<i32>::from([Some("x"), None]).boxed(),
+    ]);
+    assert_eq!(decoder.flush()?, expected);
+    assert!(decoder.is_empty());
+
+    decoder.decode_single(&valid)?;
+    let expected = Chunk::new(vec![
+        Int64Array::from_slice([3]).boxed(),
+        Utf8Array::<i32>::from([Some("y")]).boxed(),
+    ]);
+    assert_eq!(decoder.flush()?, expected);
+
+    assert_eq!(read::read_confluent_header(&valid)?, (7, &valid[5..]));
+    Ok(())
+}
@@ -0,0 +1,216 @@
+use std::io::Cursor;
+
+use arrow2::array::*;
+use arrow2::chunk::Chunk;
+use arrow2::datatypes::{DataType, Field, Schema};
+use arrow2::error::Result;
+use arrow2::io::cache::{chunk_bytes_size, lock, CacheKey, ChunkCache};
+
+fn chunk(len: usize) -> Chunk<Box<dyn Array>> {
+    Chunk::new(vec![Int32Array::from_vec((0..len as i32).collect()).boxed()])
+}
+
+#[test]
+fn evicts_least_recently_used() {
+    let weight = chunk_bytes_size(&chunk(10));
+    let mut cache = ChunkCache::new(weight * 2);
+
+    let a = CacheKey::new("a", 0, None);
+    let b = CacheKey::new("b", 0, None);
+    let c = CacheKey::new("c", 0, None);
+    assert!(cache.insert(a.clone(), chunk(10)));
+    assert!(cache.insert(b.clone(), chunk(10)));
+    assert_eq!(cache.weight(), weight * 2);
+
+    // `a` becomes the most recently used
+    assert_eq!(cache.get(&a), Some(chunk(10)));
+    assert!(cache.insert(c.clone(), chunk(10)));
+
+    assert!(cache.contains(&a));
+    assert!(!cache.contains(&b));
+    assert!(cache.contains(&c));
+    assert_eq!(cache.weight(), weight * 2);
+    assert_eq!(cache.stats().evictions, 1);
+}
+
+#[test]
+fn too_large() {
+    let mut cache = ChunkCache::new(1);
+    assert!(!cache.insert(CacheKey::new("a", 0, None), chunk(10)));
+    assert!(cache.is_empty());
+    assert_eq!(cache.weight(), 0);
+
+    // a rejected chunk does not replace the existing entry
+    let mut cache = ChunkCache::new(chunk_bytes_size(&chunk(1)));
+    let key = CacheKey::new("a", 0, None);
+    assert!(cache.insert(key.clone(), chunk(1)));
+    assert!(!cache.insert(key.clone(), chunk(10)));
+    assert_eq!(cache.get(&key), Some(chunk(1)));
+    assert_eq!(cache.stats().evictions, 0);
+}
+
+#[test]
+fn poisoned() {
+    let cache = ChunkCache::new_shared(usize::MAX);
+    let key = CacheKey::new("a", 0, None);
+    lock(&cache).insert(key.clone(), chunk(1));
+
+    let shared = cache.clone();
+    std::thread::spawn(move || {
+        let _guard = shared.lock().unwrap();
+        panic!();
+    })
+    .join()
+    .unwrap_err();
+
+    assert!(cache.is_poisoned());
+    assert_eq!(lock(&cache).get(&key), Some(chunk(1)));
+}
+
+#[test]
+fn replace_and_remove() {
+    let mut cache = ChunkCache::new(usize::MAX);
+    let key = CacheKey::new("a", 0, Some(vec![0]));
+    cache.insert(key.clone(), chunk(10));
+    cache.insert(key.clone(), chunk(20));
+    assert_eq!(cache.len(), 1);
+    assert_eq!(cache.weight(), chunk_bytes_size(&chunk(20)));
+
+    // projections are part of the key
+    assert_eq!(cache.get(&CacheKey::new("a", 0, None)), None);
+
+    cache.insert(CacheKey::new("a", 1, None), chunk(1));
+    cache.insert(CacheKey::new("b", 0, None), chunk(1));
+    cache.remove_source("a");
+    assert_eq!(cache.len(), 1);
+    assert_eq!(cache.weight(), chunk_bytes_size(&chunk(1)));
+    assert_eq!(cache.stats().misses, 1);
+}
+
+#[test]
+fn custom_weigher() {
+    let mut cache = ChunkCache::with_weigher(2, Box::new(|_| 1));
+    cache.insert(CacheKey::new("a", 0, None), chunk(10));
+    cache.insert(CacheKey::new("a", 1, None), chunk(10));
+    cache.insert(CacheKey::new("a", 2, None), chunk(10));
+    assert_eq!(cache.len(), 2);
+    assert!(!cache.contains(&CacheKey::new("a", 0, None)));
+
+    cache.set_capacity(1);
+    assert_eq!(cache.len(), 1);
+    assert!(cache.contains(&CacheKey::new("a", 2, None)));
+}
+
+#[test]
+fn get_or_try_insert_with() -> Result<()> {
+    let mut cache = ChunkCache::new(usize::MAX);
+    let key = CacheKey::new("a", 0, None);
+
+    assert!(cache
+        .get_or_try_insert_with(key.clone(), || Err(arrow2::error::Error::Overflow))
+        .is_err());
+    assert!(cache.is_empty());
+
+    let result = cache.get_or_try_insert_with(key.clone(), || Ok(chunk(2)))?;
+    assert_eq!(result, chunk(2));
+    let result = cache.get_or_try_insert_with(key, || unreachable!())?;
+    assert_eq!(result, chunk(2));
+    Ok(())
+}
+
+#[cfg(feature = "io_ipc")]
+#[test]
+fn ipc_file_reader() -> Result<()> {
+    use arrow2::io::ipc::read::{read_file_metadata, FileReader};
+    use arrow2::io::ipc::write::{FileWriter, WriteOptions};
+
+    let schema = Schema::from(vec![Field::new("a", DataType::Int32, false)]);
+    let mut writer = FileWriter::try_new(vec![], schema, None, WriteOptions::default())?;
+    writer.write(&chunk(3), None)?;
+    writer.write(&chunk(5), None)?;
+    writer.finish()?;
+    let data = writer.into_inner();
+
+    let cache = ChunkCache::new_shared(usize::MAX);
+
+    let mut reader = Cursor::new(&data);
+    let metadata = read_file_metadata(&mut reader)?;
+    let mut reader = FileReader::new(reader, metadata.clone(), None, None);
+    reader.set_cache(cache.clone(), "file");
+    let expected = reader.collect::<Result<Vec<_>>>()?;
+    assert_eq!(cache.lock().unwrap().len(), 2);
+
+    // served from the cache: the underlying data is never read
+    let mut reader = FileReader::new(Cursor::new(vec![]), metadata.clone(), None, None);
+    reader.set_cache(cache.clone(), "file");
+    let result = reader.collect::<Result<Vec<_>>>()?;
+    assert_eq!(result, expected);
+
+    // the limit is applied to cached chunks
+    let mut reader = FileReader::new(Cursor::new(vec![]), metadata, None, Some(4));
+    reader.set_cache(cache.clone(), "file");
+    let result = reader.collect::<Result<Vec<_>>>()?;
+    assert_eq!(result, vec![chunk(3), chunk(1)]);
+    assert_eq!(cache.lock().unwrap().stats().hits, 4);
+    Ok(())
+}
+
+#[cfg(feature = "io_parquet")]
+#[test]
+fn parquet_row_group() -> Result<()> {
+    use arrow2::io::parquet::read::{infer_schema, read_metadata, read_row_group_cached};
+    use arrow2::io::parquet::write::{
+        CompressionOptions, Encoding, FileWriter, RowGroupIterator, Version, WriteOptions,
+    };
+
+    let schema = Schema::from(vec![Field::new("a", DataType::Int32, false)]);
+    let options = WriteOptions {
+        write_statistics: false,
+        compression: CompressionOptions::Uncompressed,
+        version: Version::V1,
+        data_pagesize_limit: None,
+        int96_timestamps: false,
+    };
+    let row_groups = RowGroupIterator::try_new(
+        vec![Ok(chunk(3)), Ok(chunk(5))].into_iter(),
+        &schema,
+        options,
+        vec![vec![Encoding::Plain]],
+    )?;
+    let mut writer = FileWriter::try_new(Cursor::new(vec![]), schema, options)?;
+    for group in row_groups {
+        writer.write(group?)?;
+    }
+    writer.end(None)?;
+    let data = writer.into_inner().into_inner();
+
+    let mut reader = Cursor::new(data);
+    let metadata = read_metadata(&mut reader)?;
+    let schema = infer_schema(&metadata)?;
+
+    let cache = ChunkCache::new_shared(usize::MAX);
+    let result = read_row_group_cached(
+        &cache,
+        "file",
+        &mut reader,
+        &metadata.row_groups,
+        1,
+        &schema,
+        None,
+    )?;
+    assert_eq!(result, chunk(5));
+
+    // served from the cache: the underlying data is never read
+    let result = read_row_group_cached(
+        &cache,
+        "file",
+        &mut Cursor::new(vec![]),
+        &metadata.row_groups,
+        1,
+        &schema,
+        None,
+    )?;
+    assert_eq!(result, chunk(5));
+    assert_eq!(cache.lock().unwrap().stats().hits, 1);
+    Ok(())
+}
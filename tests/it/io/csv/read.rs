@@ -125,6 +125,20 @@ fn utf8() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn utf8_promote_large() -> Result<()> {
+    let reader = std::io::Cursor::new("1,\n,\n3,");
+    let mut reader = ReaderBuilder::new().has_headers(false).from_reader(reader);
+    let mut rows = vec![ByteRecord::default(); 10];
+    let rows_read = read_rows(&mut reader, 0, &mut rows)?;
+
+    // the values fit in `i32` offsets and are therefore not promoted
+    let result = deserialize_column_promote_large(&rows[..rows_read], 0, DataType::Utf8, 0)?;
+    let expected = Utf8Array::<i32>::from([Some("1"), Some(""), Some("3")]);
+    assert_eq!(expected, result.as_ref());
+    Ok(())
+}
+
 #[test]
 fn large_utf8() -> Result<()> {
     let data = "1,\n,\n3,";
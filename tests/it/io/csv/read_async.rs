@@ -48,3 +48,57 @@ async fn read() -> Result<()> {
     assert_eq!("Aberdeen, Aberdeen City, UK", city.value(13));
     Ok(())
 }
+
+#[tokio::test]
+async fn read_stream_unseekable() -> Result<()> {
+    use futures::TryStreamExt;
+
+    let data = "a,b\n1,x\n2,y\n3,z\n4,\n5,w\n";
+    // an unseekable reader: an `AsyncRead` that is not `AsyncSeek`
+    let stream = futures::stream::iter(
+        data.as_bytes()
+            .chunks(3)
+            .map(|chunk| Ok::<_, std::io::Error>(chunk.to_vec())),
+    );
+    let mut reader = AsyncReaderBuilder::new().create_reader(stream.into_async_read());
+
+    let (fields, rows) = infer_schema_buffered(&mut reader, Some(2), true, &infer).await?;
+    assert_eq!(fields.len(), 2);
+    assert_eq!(rows.len(), 2);
+
+    let first = deserialize_batch(&rows, &fields, None, 0, deserialize_column)?;
+    let rest = deserialize_stream(
+        read_rows_stream(&mut reader, 2),
+        fields,
+        Some(vec![0]),
+        rows.len(),
+        deserialize_column,
+    );
+    let rest = rest.try_collect::<Vec<_>>().await?;
+
+    assert_eq!(
+        first.arrays()[0].as_ref(),
+        &Int64Array::from_slice([1, 2]) as &dyn Array
+    );
+    assert_eq!(
+        first.arrays()[1].as_ref(),
+        &Utf8Array::<i32>::from_slice(["x", "y"]) as &dyn Array
+    );
+    assert_eq!(rest.len(), 2);
+    assert_eq!(rest[0].arrays().len(), 1);
+    assert_eq!(
+        rest[0].arrays()[0].as_ref(),
+        &Int64Array::from_slice([3, 4]) as &dyn Array
+    );
+    assert_eq!(
+        rest[1].arrays()[0].as_ref(),
+        &Int64Array::from_slice([5]) as &dyn Array
+    );
+
+    // the stream ends once the reader is exhausted
+    let remaining = read_rows_stream(&mut reader, 2)
+        .try_collect::<Vec<_>>()
+        .await?;
+    assert!(remaining.is_empty());
+    Ok(())
+}
@@ -443,3 +443,28 @@ fn serialize_vec() -> Result<()> {
     );
     Ok(())
 }
+
+#[test]
+fn csv_serializer() -> Result<()> {
+    let columns = data();
+    let options = SerializeOptions::default();
+
+    let mut expected = vec![];
+    write_chunk(&mut expected, &columns, &options)?;
+    write_chunk(&mut expected, &columns, &options)?;
+
+    for parallel in [false, true] {
+        let mut serializer = CsvSerializer::new(options.clone()).with_parallel(parallel);
+        let mut writer = vec![];
+        serializer.write_chunk(&mut writer, &columns)?;
+        serializer.write_chunk(&mut writer, &columns)?;
+        assert_eq!(writer, expected);
+
+        // buffers of previous chunks with more columns and rows are not written
+        let chunk = Chunk::new(vec![Int32Array::from_slice([1]).boxed()]);
+        let mut writer = vec![];
+        serializer.write_chunk(&mut writer, &chunk)?;
+        assert_eq!(writer, b"1\n");
+    }
+    Ok(())
+}
@@ -1,10 +1,11 @@
-use arrow2::array::Array;
+use arrow2::array::{Array, DictionaryArray, Int32Array, StructArray, Utf8Array};
 use arrow2::chunk::Chunk;
-use arrow2::datatypes::Schema;
+use arrow2::datatypes::{DataType, Field, IntegerType, Schema};
 use arrow2::error::Error;
 
 use arrow2::io::flight::*;
-use arrow2::io::ipc::write::{default_ipc_fields, WriteOptions};
+use arrow2::io::ipc::write::WriteOptions;
+use arrow2::io::ipc::IpcField;
 
 use super::ipc::read_gzip_json;
 
@@ -14,7 +15,7 @@ fn round_trip(schema: Schema, chunk: Chunk<Box<dyn Array>>) -> Result<(), Error>
     let (result, ipc_schema) = deserialize_schemas(&serialized.data_header)?;
     assert_eq!(schema, result);
 
-    let (_, batch) = serialize_batch(&chunk, &fields, &WriteOptions { compression: None })?;
+    let (_, batch) = serialize_batch(&chunk, &fields, &WriteOptions::default())?;
 
     let result = deserialize_batch(&batch, &result.fields, &ipc_schema, &Default::default())?;
     assert_eq!(result, chunk);
@@ -30,3 +31,176 @@ fn generated_nested_dictionary() -> Result<(), Error> {
 
     Ok(())
 }
+
+#[test]
+fn serialize_batches_split() -> Result<(), Error> {
+    let array = Int32Array::from_iter((0..10).map(Some)).boxed();
+    let schema = Schema::from(vec![Field::new("a", DataType::Int32, true)]);
+    let fields = default_ipc_fields(&schema.fields);
+    let chunk = Chunk::new(vec![array.clone()]);
+    let options = WriteOptions::default().with_max_batch_rows(Some(4));
+
+    let batches = serialize_batches(&chunk, &fields, &options)?;
+    assert_eq!(batches.len(), 3);
+
+    let serialized = serialize_schema(&schema, Some(&fields));
+    let (schema, ipc_schema) = deserialize_schemas(&serialized.data_header)?;
+    let mut offset = 0;
+    for (_, batch) in batches {
+        let result = deserialize_batch(&batch, &schema.fields, &ipc_schema, &Default::default())?;
+        let length = result.len();
+        assert_eq!(result.arrays()[0], array.slice(offset, length));
+        offset += length;
+    }
+    assert_eq!(offset, 10);
+    Ok(())
+}
+
+fn dictionary_schema() -> Schema {
+    let dictionary = DataType::Dictionary(IntegerType::Int32, Box::new(DataType::Utf8), false);
+    Schema::from(vec![
+        Field::new("a", dictionary.clone(), true),
+        Field::new(
+            "b",
+            DataType::Struct(vec![Field::new("c", dictionary, true)]),
+            true,
+        ),
+    ])
+}
+
+#[test]
+fn dictionary_ids_from() -> Result<(), Error> {
+    let schema = dictionary_schema();
+
+    let fields = default_ipc_fields_from(&schema.fields, 10);
+    assert_eq!(fields[0].dictionary_id, Some(10));
+    assert_eq!(fields[1].fields[0].dictionary_id, Some(11));
+    check_ipc_fields(&schema.fields, &fields)
+}
+
+#[test]
+fn hashed_dictionary_ids() -> Result<(), Error> {
+    let schema = dictionary_schema();
+
+    let fields = hashed_ipc_fields(&schema.fields, "writer-1");
+    check_ipc_fields(&schema.fields, &fields)?;
+    // deterministic
+    assert_eq!(fields, hashed_ipc_fields(&schema.fields, "writer-1"));
+
+    let other = hashed_ipc_fields(&schema.fields, "writer-2");
+    assert_ne!(fields[0].dictionary_id, other[0].dictionary_id);
+
+    let keys = Int32Array::from_slice([0, 1, 0]);
+    let values = Utf8Array::<i32>::from_slice(["x", "y"]).boxed();
+    let a = DictionaryArray::try_from_keys(keys, values)?.boxed();
+    let b = StructArray::new(schema.fields[1].data_type().clone(), vec![a.clone()], None).boxed();
+    round_trip_fields(schema, Chunk::new(vec![a, b]), &fields)
+}
+
+fn round_trip_fields(
+    schema: Schema,
+    chunk: Chunk<Box<dyn Array>>,
+    fields: &[IpcField],
+) -> Result<(), Error> {
+    let serialized = serialize_schema(&schema, Some(fields));
+    let (result, ipc_schema) = deserialize_schemas(&serialized.data_header)?;
+    assert_eq!(ipc_schema.fields[0].dictionary_id, fields[0].dictionary_id);
+    check_ipc_fields(&result.fields, &ipc_schema.fields)?;
+
+    let (dictionaries, batch) = serialize_batch(&chunk, fields, &WriteOptions::default())?;
+
+    let mut dicts = Default::default();
+    for dictionary in &dictionaries {
+        deserialize_message(dictionary, &result.fields, &ipc_schema, &mut dicts)?;
+    }
+    let result = deserialize_batch(&batch, &result.fields, &ipc_schema, &dicts)?;
+    assert_eq!(result, chunk);
+    Ok(())
+}
+
+#[test]
+fn check_invalid_ipc_fields() {
+    let schema = dictionary_schema();
+
+    let mut fields = default_ipc_fields(&schema.fields);
+    fields[1].fields[0].dictionary_id = fields[0].dictionary_id;
+    assert!(check_ipc_fields(&schema.fields, &fields).is_err());
+
+    let mut fields = default_ipc_fields(&schema.fields);
+    fields[0].dictionary_id = None;
+    assert!(check_ipc_fields(&schema.fields, &fields).is_err());
+
+    let mut fields = default_ipc_fields(&schema.fields);
+    fields[1].fields.clear();
+    assert!(check_ipc_fields(&schema.fields, &fields).is_err());
+}
+
+#[test]
+fn fingerprint() {
+    let schema = dictionary_schema();
+    assert_eq!(
+        schema_fingerprint(&schema),
+        schema_fingerprint(&dictionary_schema())
+    );
+
+    let mut other = dictionary_schema();
+    other.fields[0].name = "z".to_string();
+    assert_ne!(schema_fingerprint(&schema), schema_fingerprint(&other));
+
+    let other = dictionary_schema().with_metadata([("k".to_string(), "v".to_string())].into());
+    assert_ne!(schema_fingerprint(&schema), schema_fingerprint(&other));
+}
+
+#[test]
+fn deserialize_with_limits() -> Result<(), Error> {
+    use arrow2::io::ipc::read::ReadOptions;
+
+    let array = Int32Array::from_vec(vec![0; 1000]).boxed();
+    let schema = Schema::from(vec![Field::new("a", DataType::Int32, false)]);
+    let fields = default_ipc_fields(&schema.fields);
+    let chunk = Chunk::new(vec![array]);
+    let (_, batch) = serialize_batch(&chunk, &fields, &WriteOptions::default())?;
+
+    let serialized = serialize_schema(&schema, Some(&fields));
+    let (schema, ipc_schema) = deserialize_schemas(&serialized.data_header)?;
+
+    let options = ReadOptions::default().with_max_message_size(4032);
+    let result = deserialize_batch_with_options(
+        &batch,
+        &schema.fields,
+        &ipc_schema,
+        &Default::default(),
+        &options,
+    )?;
+    assert_eq!(result, chunk);
+
+    // the body of the batch is 4000 bytes long, padded to 64 bytes
+    let options = ReadOptions::default().with_max_message_size(4031);
+    assert!(deserialize_batch_with_options(
+        &batch,
+        &schema.fields,
+        &ipc_schema,
+        &Default::default(),
+        &options
+    )
+    .is_err());
+    assert!(deserialize_message_with_options(
+        &batch,
+        &schema.fields,
+        &ipc_schema,
+        &mut Default::default(),
+        &options
+    )
+    .is_err());
+
+    let options = ReadOptions::default().with_max_array_len(999);
+    assert!(deserialize_message_with_options(
+        &batch,
+        &schema.fields,
+        &ipc_schema,
+        &mut Default::default(),
+        &options
+    )
+    .is_err());
+    Ok(())
+}
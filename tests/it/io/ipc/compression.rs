@@ -0,0 +1,121 @@
+use std::io::Cursor;
+
+use arrow2::array::*;
+use arrow2::chunk::Chunk;
+use arrow2::datatypes::{DataType, Field, Schema};
+use arrow2::error::Result;
+use arrow2::io::ipc::read::{read_file_metadata, read_stream_metadata, FileReader, StreamReader};
+use arrow2::io::ipc::write::{Compression, FileWriter, StreamWriter, WriteOptions};
+
+fn data() -> (Schema, Vec<Chunk<Box<dyn Array>>>) {
+    let int = Int32Array::from([Some(1), None, Some(3), Some(1), Some(1)]);
+    let utf8 = Utf8Array::<i32>::from([Some("aa"), Some("aa"), None, Some("b"), Some("aa")]);
+    let list = ListArray::<i32>::new(
+        DataType::List(Box::new(Field::new("item", DataType::Int64, true))),
+        vec![0, 2, 2, 3, 5, 6].try_into().unwrap(),
+        Int64Array::from([Some(1), None, Some(2), Some(3), Some(4), Some(5)]).boxed(),
+        None,
+    );
+    let mut dictionary = MutableDictionaryArray::<i32, MutableUtf8Array<i32>>::new();
+    dictionary
+        .try_extend([Some("x"), None, Some("y"), Some("x"), Some("x")])
+        .unwrap();
+    let dictionary: DictionaryArray<i32> = dictionary.into();
+
+    let schema = Schema::from(vec![
+        Field::new("int", int.data_type().clone(), true),
+        Field::new("utf8", utf8.data_type().clone(), true),
+        Field::new("list", list.data_type().clone(), true),
+        Field::new("dictionary", dictionary.data_type().clone(), true),
+    ]);
+    let chunk = Chunk::new(vec![
+        int.boxed(),
+        utf8.boxed(),
+        list.boxed(),
+        dictionary.boxed(),
+    ]);
+    let sliced = Chunk::new(chunk.arrays().iter().map(|x| x.slice(1, 3)).collect());
+    let empty = Chunk::new(chunk.arrays().iter().map(|x| x.slice(0, 0)).collect());
+    (schema, vec![chunk, sliced, empty])
+}
+
+fn file_round_trip(compression: Compression) -> Result<()> {
+    let (schema, chunks) = data();
+    let options = WriteOptions::default().with_compression(Some(compression));
+    let mut writer = FileWriter::try_new(vec![], schema.clone(), None, options)?;
+    for chunk in &chunks {
+        writer.write(chunk, None)?;
+    }
+    writer.finish()?;
+
+    let mut reader = Cursor::new(writer.into_inner());
+    let metadata = read_file_metadata(&mut reader)?;
+    assert_eq!(metadata.schema, schema);
+    let result = FileReader::new(reader, metadata, None, None).collect::<Result<Vec<_>>>()?;
+    assert_eq!(result, chunks);
+    Ok(())
+}
+
+fn stream_round_trip(compression: Compression) -> Result<()> {
+    let (schema, chunks) = data();
+    let options = WriteOptions::default().with_compression(Some(compression));
+    let mut writer = StreamWriter::new(vec![], options);
+    writer.start(&schema, None)?;
+    for chunk in &chunks {
+        writer.write(chunk, None)?;
+    }
+    writer.finish()?;
+
+    let mut reader = Cursor::new(writer.into_inner());
+    let metadata = read_stream_metadata(&mut reader)?;
+    assert_eq!(metadata.schema, schema);
+    let result = StreamReader::new(reader, metadata, None)
+        .map(|state| state.map(|state| state.unwrap()))
+        .collect::<Result<Vec<_>>>()?;
+    assert_eq!(result, chunks);
+    Ok(())
+}
+
+#[test]
+#[cfg_attr(miri, ignore)] // compression uses FFI, which miri does not support
+fn file_lz4() -> Result<()> {
+    file_round_trip(Compression::LZ4)
+}
+
+#[test]
+#[cfg_attr(miri, ignore)] // compression uses FFI, which miri does not support
+fn file_zstd() -> Result<()> {
+    file_round_trip(Compression::ZSTD)
+}
+
+#[test]
+#[cfg_attr(miri, ignore)] // compression uses FFI, which miri does not support
+fn stream_lz4() -> Result<()> {
+    stream_round_trip(Compression::LZ4)
+}
+
+#[test]
+#[cfg_attr(miri, ignore)] // compression uses FFI, which miri does not support
+fn stream_zstd() -> Result<()> {
+    stream_round_trip(Compression::ZSTD)
+}
+
+#[test]
+#[cfg_attr(miri, ignore)] // compression uses FFI, which miri does not support
+fn compressed_is_smaller() -> Result<()> {
+    let array = Int64Array::from_vec(vec![1; 10_000]);
+    let schema = Schema::from(vec![Field::new("a", array.data_type().clone(), false)]);
+    let chunk = Chunk::new(vec![array.boxed()]);
+
+    let write = |compression| -> Result<Vec<u8>> {
+        let options = WriteOptions::default().with_compression(compression);
+        let mut writer = FileWriter::try_new(vec![], schema.clone(), None, options)?;
+        writer.write(&chunk, None)?;
+        writer.finish()?;
+        Ok(writer.into_inner())
+    };
+    let uncompressed = write(None)?.len();
+    assert!(write(Some(Compression::LZ4))?.len() < uncompressed);
+    assert!(write(Some(Compression::ZSTD))?.len() < uncompressed);
+    Ok(())
+}
@@ -0,0 +1,161 @@
+use std::sync::Arc;
+
+use arrow2::array::*;
+use arrow2::chunk::Chunk;
+use arrow2::datatypes::{DataType, Field, Schema};
+use arrow2::error::Result;
+use arrow2::io::ipc::framed::*;
+use arrow2::io::ipc::write::{default_ipc_fields, Compression, WriteOptions};
+
+fn data() -> (Schema, Chunk<Box<dyn Array>>) {
+    let mut dictionary = MutableDictionaryArray::<i32, MutableUtf8Array<i32>>::new();
+    dictionary.try_extend([Some("a"), None, Some("b")]).unwrap();
+    let dictionary: DictionaryArray<i32> = dictionary.into();
+
+    let chunk = Chunk::new(vec![
+        Int32Array::from([Some(1), None, Some(3)]).boxed(),
+        Utf8Array::<i32>::from([Some("x"), Some("yy"), None]).boxed(),
+        dictionary.boxed(),
+    ]);
+    let schema = Schema::from(vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+        Field::new("c", chunk.arrays()[2].data_type().clone(), true),
+    ]);
+    (schema, chunk)
+}
+
+fn write(
+    schema: &Schema,
+    chunks: &[Chunk<Box<dyn Array>>],
+    options: WriteOptions,
+) -> Result<(Vec<u8>, Vec<u8>)> {
+    let mut schema_frame = vec![];
+    write_schema_frame(&mut schema_frame, schema, None)?;
+
+    let fields = default_ipc_fields(&schema.fields);
+    let mut frames = vec![];
+    for chunk in chunks {
+        write_chunk_frames(&mut frames, chunk, &fields, &options)?;
+    }
+    Ok((schema_frame, frames))
+}
+
+#[test]
+fn round_trip() -> Result<()> {
+    let (schema, chunk) = data();
+    let (schema_frame, frames) = write(
+        &schema,
+        &[chunk.clone(), chunk.clone()],
+        WriteOptions::default(),
+    )?;
+
+    let mut reader = FrameReader::try_new(&schema_frame)?;
+    assert_eq!(reader.schema(), &schema);
+    assert_eq!(reader.read(&frames)?, vec![chunk.clone(), chunk]);
+    Ok(())
+}
+
+#[cfg(feature = "io_ipc_compression")]
+#[test]
+fn round_trip_compressed() -> Result<()> {
+    let (schema, chunk) = data();
+    let options = WriteOptions::default().with_compression(Some(Compression::LZ4));
+    let (schema_frame, frames) = write(&schema, std::slice::from_ref(&chunk), options)?;
+
+    let mut reader = FrameReader::try_new(&schema_frame)?;
+    assert_eq!(reader.read(&frames)?, vec![chunk]);
+    Ok(())
+}
+
+#[test]
+fn zero_copy() -> Result<()> {
+    let (schema, chunk) = data();
+    let (schema_frame, frames) = write(
+        &schema,
+        std::slice::from_ref(&chunk),
+        WriteOptions::default(),
+    )?;
+
+    let mut reader = FrameReader::try_new(&schema_frame)?;
+    let data = Arc::new(frames);
+    let chunks = unsafe { arrow2::mmap::mmap_frames_unchecked(&mut reader, data.clone())? };
+    assert_eq!(chunks, vec![chunk]);
+
+    // the arrays share the buffer
+    let array = chunks[0].arrays()[0]
+        .as_any()
+        .downcast_ref::<Int32Array>()
+        .unwrap();
+    let range = data.as_ptr_range();
+    assert!(range.contains(&(array.values().as_ptr() as *const u8)));
+    Ok(())
+}
+
+#[test]
+fn invalid() -> Result<()> {
+    let (schema, chunk) = data();
+    let (schema_frame, frames) = write(&schema, &[chunk], WriteOptions::default())?;
+
+    // a chunk is not a schema
+    assert!(FrameReader::try_new(&frames).is_err());
+
+    let mut reader = FrameReader::try_new(&schema_frame)?;
+    // truncated frames
+    assert!(reader.read(&frames[..frames.len() - 1]).is_err());
+    assert!(reader.read(&frames[..4]).is_err());
+    // a schema is not a chunk
+    assert!(reader.read(&schema_frame).is_err());
+    Ok(())
+}
+
+#[test]
+fn read_with_limits() -> Result<()> {
+    use arrow2::io::ipc::read::ReadOptions;
+
+    let (schema, chunk) = data();
+    let (schema_frame, frames) = write(
+        &schema,
+        std::slice::from_ref(&chunk),
+        WriteOptions::default(),
+    )?;
+
+    let options = ReadOptions::default().with_max_array_len(3);
+    let mut reader = FrameReader::try_new(&schema_frame)?.with_read_options(options);
+    assert_eq!(reader.read(&frames)?, vec![chunk]);
+
+    let options = ReadOptions::default().with_max_message_size(16);
+    let mut reader = FrameReader::try_new(&schema_frame)?.with_read_options(options);
+    assert!(reader.read(&frames).is_err());
+
+    let options = ReadOptions::default().with_max_array_len(2);
+    let mut reader = FrameReader::try_new(&schema_frame)?.with_read_options(options);
+    assert!(reader.read(&frames).is_err());
+    Ok(())
+}
+
+#[test]
+fn invalid_body_length() -> Result<()> {
+    let array = Int32Array::from([Some(1), None, Some(3)]).boxed();
+    let schema = Schema::from(vec![Field::new("a", DataType::Int32, true)]);
+    let (schema_frame, mut frame) =
+        write(&schema, &[Chunk::new(vec![array])], WriteOptions::default())?;
+
+    // replace the body length of the message by one that overflows its offset
+    let length = u64::from_le_bytes(frame[..8].try_into().unwrap()) as usize;
+    let metadata_length = i32::from_le_bytes(frame[12..16].try_into().unwrap()) as usize;
+    let body_length = ((length - 8 - metadata_length) as i64).to_le_bytes();
+    let metadata = &mut frame[16..16 + metadata_length];
+    let positions = metadata
+        .windows(8)
+        .enumerate()
+        .filter(|(_, x)| *x == body_length)
+        .map(|(i, _)| i)
+        .collect::<Vec<_>>();
+    assert_eq!(positions.len(), 1);
+    metadata[positions[0]..positions[0] + 8].copy_from_slice(&i64::MAX.to_le_bytes());
+
+    let mut reader = FrameReader::try_new(&schema_frame)?;
+    assert!(reader.read(&frame).is_err());
+    Ok(())
+}
@@ -5,7 +5,9 @@ use arrow2::error::Result;
 use arrow2::io::ipc::read::read_file_metadata;
 use std::sync::Arc;
 
-use super::write::file::write;
+use arrow2::io::ipc::write::WriteOptions;
+
+use super::write::file::{dictionary_chunk, write, write_dictionary_chunk};
 
 fn round_trip(array: Box<dyn Array>) -> Result<()> {
     let schema = Schema::from(vec![Field::new("a", array.data_type().clone(), true)]);
@@ -18,6 +20,11 @@ fn round_trip(array: Box<dyn Array>) -> Result<()> {
     let dictionaries =
         unsafe { arrow2::mmap::mmap_dictionaries_unchecked(&metadata, data.clone())? };
 
+    assert_eq!(
+        arrow2::mmap::mmap_batch_length(&metadata, data.as_ref(), 0)?,
+        array.len()
+    );
+
     let new_array = unsafe { arrow2::mmap::mmap_unchecked(&metadata, &dictionaries, data, 0)? };
     assert_eq!(new_array.into_arrays()[0], array);
     Ok(())
@@ -117,3 +124,79 @@ fn dict() -> Result<()> {
 
     round_trip(array)
 }
+
+#[test]
+fn checksums() -> Result<()> {
+    let data = write_dictionary_chunk(WriteOptions::default().with_checksum(true))?;
+    let metadata = read_file_metadata(&mut std::io::Cursor::new(&data))?;
+
+    let mmap = |data: Vec<u8>| {
+        let data = Arc::new(data);
+        let dictionaries =
+            unsafe { arrow2::mmap::mmap_dictionaries_unchecked(&metadata, data.clone())? };
+        unsafe { arrow2::mmap::mmap_unchecked(&metadata, &dictionaries, data, 0) }
+    };
+
+    assert_eq!(mmap(data.clone())?, dictionary_chunk());
+
+    // corrupt the first value of the record batch
+    let block = metadata.blocks[0];
+    let mut corrupted = data;
+    corrupted[(block.offset + block.meta_data_length as i64) as usize] ^= 1;
+    assert!(mmap(corrupted).is_err());
+    Ok(())
+}
+
+/// A file whose buffers are not aligned, by starting it one byte into an allocation
+struct Unaligned(Vec<u8>);
+
+impl AsRef<[u8]> for Unaligned {
+    fn as_ref(&self) -> &[u8] {
+        &self.0[1..]
+    }
+}
+
+#[test]
+fn copy_unaligned() -> Result<()> {
+    let array = Utf8Array::<i32>::from([Some("a"), None, Some("bb")]).boxed();
+    let schema = Schema::from(vec![Field::new("a", array.data_type().clone(), true)]);
+    let columns = Chunk::try_new(vec![array])?;
+
+    let mut data = vec![0];
+    data.extend(write(std::slice::from_ref(&columns), &schema, None, None)?);
+    let data = Arc::new(Unaligned(data));
+
+    let metadata = read_file_metadata(&mut std::io::Cursor::new(data.as_ref().as_ref()))?;
+    let dictionaries =
+        unsafe { arrow2::mmap::mmap_dictionaries_unchecked(&metadata, data.clone())? };
+
+    assert!(
+        unsafe { arrow2::mmap::mmap_unchecked(&metadata, &dictionaries, data.clone(), 0) }.is_err()
+    );
+
+    let (new_columns, copied) =
+        unsafe { arrow2::mmap::mmap_unchecked_copy_unaligned(&metadata, &dictionaries, data, 0)? };
+    assert_eq!(new_columns, columns);
+    // only the offsets are copied: the validity and values are bytes, which are always aligned
+    assert_eq!(copied.len(), 1);
+    assert_eq!(copied[0].length, 4 * 4);
+    assert_eq!(copied[0].alignment, 4);
+    Ok(())
+}
+
+#[test]
+fn copy_unaligned_aligned() -> Result<()> {
+    let data = Arc::new(write_dictionary_chunk(Default::default())?);
+    let metadata = read_file_metadata(&mut std::io::Cursor::new(data.as_ref()))?;
+
+    let (dictionaries, copied) = unsafe {
+        arrow2::mmap::mmap_dictionaries_unchecked_copy_unaligned(&metadata, data.clone())?
+    };
+    assert!(copied.is_empty());
+
+    let (chunk, copied) =
+        unsafe { arrow2::mmap::mmap_unchecked_copy_unaligned(&metadata, &dictionaries, data, 0)? };
+    assert_eq!(chunk, dictionary_chunk());
+    assert!(copied.is_empty());
+    Ok(())
+}
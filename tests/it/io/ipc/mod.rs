@@ -16,4 +16,8 @@ mod read_stream_async;
 #[cfg(feature = "io_ipc_read_async")]
 mod read_file_async;
 
+#[cfg(feature = "io_ipc_compression")]
+mod compression;
+
+mod framed;
 mod mmap;
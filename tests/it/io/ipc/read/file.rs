@@ -274,3 +274,104 @@ fn read_limited() -> Result<()> {
     test_limit("1.0.0-littleendian", "generated_nested", 2)?;
     Ok(())
 }
+
+#[test]
+fn read_batch_by_index() -> Result<()> {
+    use arrow2::array::*;
+    use arrow2::datatypes::{Field, Schema};
+
+    let chunks = (0..3)
+        .map(|i| {
+            let keys = Int32Array::from_slice(vec![i % 2; i as usize + 1]);
+            let values = Utf8Array::<i32>::from_slice(["a", "b"]);
+            Chunk::new(vec![DictionaryArray::try_from_keys(keys, values.boxed())
+                .unwrap()
+                .boxed()])
+        })
+        .collect::<Vec<_>>();
+    let schema = Schema::from(vec![Field::new(
+        "a",
+        chunks[0].arrays()[0].data_type().clone(),
+        false,
+    )]);
+    let data = super::super::write::file::write(&chunks, &schema, None, None)?;
+
+    let mut reader = std::io::Cursor::new(data);
+    let metadata = read_file_metadata(&mut reader)?;
+    let mut reader = FileReader::new(reader, metadata, None, None);
+
+    assert_eq!(reader.num_batches(), 3);
+    assert_eq!(reader.batch_length(2)?, 3);
+    assert_eq!(reader.read_batch(2)?, chunks[2]);
+    assert_eq!(reader.read_batch(1)?, chunks[1]);
+    assert!(reader.read_batch(3).is_err());
+
+    // random access does not advance the iterator
+    assert_eq!(reader.collect::<Result<Vec<_>>>()?, chunks);
+    Ok(())
+}
+
+fn read_with_options(
+    data: &[u8],
+    options: ReadOptions,
+) -> Result<Vec<Chunk<Box<dyn arrow2::array::Array>>>> {
+    let mut reader = std::io::Cursor::new(data);
+    let metadata = read_file_metadata_with_options(&mut reader, options)?;
+    FileReader::new(reader, metadata, None, None).collect()
+}
+
+fn assert_out_of_spec(result: Result<impl std::fmt::Debug>, kind: &str) {
+    match result {
+        Err(arrow2::error::Error::OutOfSpec(message)) => assert!(message.starts_with(kind)),
+        other => panic!("expected {kind}, got {other:?}"),
+    }
+}
+
+fn limited_chunks() -> (
+    Vec<Chunk<Box<dyn arrow2::array::Array>>>,
+    arrow2::datatypes::Schema,
+) {
+    use arrow2::array::*;
+    use arrow2::datatypes::{DataType, Field, Schema};
+
+    let chunks = vec![Chunk::new(
+        vec![Int32Array::from_vec(vec![0; 1000]).boxed()],
+    )];
+    let schema = Schema::from(vec![Field::new("a", DataType::Int32, false)]);
+    (chunks, schema)
+}
+
+#[test]
+fn read_with_limits() -> Result<()> {
+    let (chunks, schema) = limited_chunks();
+    let data = super::super::write::file::write(&chunks, &schema, None, None)?;
+    assert_eq!(read_with_options(&data, Default::default())?, chunks);
+
+    let options = ReadOptions::default().with_max_message_size(16);
+    assert_out_of_spec(read_with_options(&data, options), "MessageTooLarge");
+
+    let options = ReadOptions::default().with_max_array_len(999);
+    assert_out_of_spec(read_with_options(&data, options), "ArrayTooLong");
+
+    let options = ReadOptions::default().with_max_total_allocation(1000);
+    assert_out_of_spec(read_with_options(&data, options), "AllocationTooLarge");
+    Ok(())
+}
+
+#[test]
+#[cfg_attr(miri, ignore)] // LZ4 uses foreign calls that miri does not support
+fn read_compressed_with_limits() -> Result<()> {
+    use arrow2::io::ipc::write::Compression;
+
+    let (chunks, schema) = limited_chunks();
+    let data = super::super::write::file::write(&chunks, &schema, None, Some(Compression::LZ4))?;
+
+    // the compressed buffers are within the limit, but decompress beyond it
+    let options = ReadOptions::default().with_max_total_allocation(1000);
+    assert_out_of_spec(read_with_options(&data, options), "AllocationTooLarge");
+
+    // the limit includes both the compressed and the decompressed buffers
+    let options = ReadOptions::default().with_max_total_allocation(8000);
+    assert_eq!(read_with_options(&data, options)?, chunks);
+    Ok(())
+}
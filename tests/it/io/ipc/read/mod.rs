@@ -1,2 +1,2 @@
 mod file;
-mod stream;
+pub mod stream;
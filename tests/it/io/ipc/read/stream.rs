@@ -1,4 +1,6 @@
+use arrow2::array::*;
 use arrow2::chunk::Chunk;
+use arrow2::datatypes::{DataType, Field, Schema};
 use std::fs::File;
 
 use arrow2::error::Result;
@@ -150,3 +152,181 @@ fn read_projected() -> Result<()> {
     test_projection("1.0.0-littleendian", "generated_primitive", vec![2, 1])?;
     test_projection("1.0.0-littleendian", "generated_primitive", vec![0, 2, 1])
 }
+
+type Chunks = Vec<Chunk<Box<dyn Array>>>;
+
+/// Returns a stream whose schema is replaced by a schema with an additional column
+pub(crate) fn evolving_stream() -> Result<(Vec<u8>, Chunks)> {
+    use arrow2::io::ipc::write::{StreamWriter, WriteOptions};
+
+    let first = Chunk::new(vec![Int32Array::from_slice([1, 2]).boxed()]);
+    let second = Chunk::new(vec![
+        Int32Array::from_slice([3]).boxed(),
+        Utf8Array::<i32>::from_slice(["a"]).boxed(),
+    ]);
+
+    let schema = Schema::from(vec![Field::new("a", DataType::Int32, false)]);
+    let mut writer = StreamWriter::new(vec![], WriteOptions::default());
+    writer.start(&schema, None)?;
+    writer.write(&first, None)?;
+
+    let schema = Schema::from(vec![
+        Field::new("a", DataType::Int32, false),
+        Field::new("b", DataType::Utf8, false),
+    ]);
+    let mut writer = StreamWriter::new(writer.into_inner(), WriteOptions::default());
+    writer.start(&schema, None)?;
+    writer.write(&second, None)?;
+    writer.finish()?;
+    Ok((writer.into_inner(), vec![first, second]))
+}
+
+#[test]
+fn schema_evolution() -> Result<()> {
+    let (data, chunks) = evolving_stream()?;
+    let mut reader = std::io::Cursor::new(data);
+    let metadata = read_stream_metadata(&mut reader)?;
+    let reader = StreamReader::new(reader, metadata, None).with_schema_evolution(true);
+
+    let states = reader.collect::<Result<Vec<_>>>()?;
+    assert_eq!(states.len(), 3);
+    match &states[..] {
+        [StreamState::Some(first), StreamState::NewSchema(schema), StreamState::Some(second)] => {
+            assert_eq!(first, &chunks[0]);
+            assert_eq!(schema.fields.len(), 2);
+            assert_eq!(schema.fields[1].name, "b");
+            assert_eq!(second, &chunks[1]);
+        }
+        _ => panic!("unexpected states"),
+    }
+    Ok(())
+}
+
+#[test]
+fn schema_evolution_projected() -> Result<()> {
+    let (data, chunks) = evolving_stream()?;
+    let mut reader = std::io::Cursor::new(data);
+    let metadata = read_stream_metadata(&mut reader)?;
+    let mut reader = StreamReader::new(reader, metadata, Some(vec![0])).with_schema_evolution(true);
+
+    assert_eq!(reader.next().unwrap()?.unwrap(), chunks[0]);
+    match reader.next().unwrap()? {
+        StreamState::NewSchema(schema) => assert_eq!(schema.fields.len(), 1),
+        _ => panic!("expected a new schema"),
+    };
+    assert_eq!(reader.schema().fields.len(), 1);
+    let expected = Chunk::new(vec![chunks[1].arrays()[0].clone()]);
+    assert_eq!(reader.next().unwrap()?.unwrap(), expected);
+    assert!(reader.next().is_none());
+    Ok(())
+}
+
+#[test]
+fn schema_evolution_disabled() -> Result<()> {
+    let (data, _) = evolving_stream()?;
+    let mut reader = std::io::Cursor::new(data);
+    let metadata = read_stream_metadata(&mut reader)?;
+    let mut reader = StreamReader::new(reader, metadata, None);
+
+    assert!(reader.next().unwrap().is_ok());
+    assert!(reader.next().unwrap().is_err());
+    Ok(())
+}
+
+#[test]
+fn recovery() -> Result<()> {
+    use arrow2::io::ipc::write::{StreamWriter, WriteOptions};
+
+    let chunks = (0..3)
+        .map(|i| Chunk::new(vec![Int32Array::from_slice([i, i + 1]).boxed()]))
+        .collect::<Vec<_>>();
+    let schema = Schema::from(vec![Field::new("a", DataType::Int32, false)]);
+    let mut writer = StreamWriter::new(vec![], WriteOptions::default());
+    writer.start(&schema, None)?;
+    for chunk in &chunks {
+        writer.write(chunk, None)?;
+    }
+    writer.finish()?;
+    let mut data = writer.into_inner();
+
+    let mut reader = std::io::Cursor::new(&data);
+    let metadata = read_stream_metadata(&mut reader)?;
+    let start = reader.position() as usize;
+    let mut reader = StreamReader::new(reader, metadata, None);
+    let offsets = (0..3)
+        .map(|_| {
+            reader.next().unwrap()?;
+            Ok(reader.message_offset().unwrap() as usize)
+        })
+        .collect::<Result<Vec<_>>>()?;
+    assert_eq!(offsets[0], 0);
+    assert!(reader.next().is_none());
+
+    // corrupt the length of the second message
+    let position = start + offsets[1] + 4;
+    data[position..position + 4].copy_from_slice(&[255; 4]);
+
+    let mut reader = std::io::Cursor::new(&data);
+    let metadata = read_stream_metadata(&mut reader)?;
+    let mut reader = StreamReader::new(reader, metadata, None).with_recovery(true);
+
+    assert_eq!(reader.next().unwrap()?.unwrap(), chunks[0]);
+    assert!(reader.next().unwrap().is_err());
+    assert_eq!(reader.next().unwrap()?.unwrap(), chunks[2]);
+    assert_eq!(reader.message_offset(), Some(offsets[2] as u64));
+    assert!(reader.next().is_none());
+    Ok(())
+}
+
+#[test]
+fn recover_skips_schema_messages() -> Result<()> {
+    let (data, _) = evolving_stream()?;
+    let mut reader = std::io::Cursor::new(data);
+    let metadata = read_stream_metadata(&mut reader)?;
+    let mut reader = StreamReader::new(reader, metadata, None);
+
+    reader.next().unwrap()?;
+    let position = reader.position();
+    // the next message is a schema, which is not valid without schema evolution
+    assert_eq!(reader.recover()?, Some(reader.position()));
+    assert!(reader.position() > position);
+    Ok(())
+}
+
+#[test]
+fn read_with_limits() -> Result<()> {
+    use arrow2::io::ipc::write::{StreamWriter, WriteOptions};
+
+    let chunk = Chunk::new(vec![Int32Array::from_vec(vec![0; 1000]).boxed()]);
+    let schema = Schema::from(vec![Field::new("a", DataType::Int32, false)]);
+    let mut writer = StreamWriter::new(vec![], WriteOptions::default());
+    writer.start(&schema, None)?;
+    writer.write(&chunk, None)?;
+    writer.finish()?;
+    let data = writer.into_inner();
+
+    let options = ReadOptions::default().with_max_message_size(16);
+    assert!(read_stream_metadata_with_options(&mut data.as_slice(), options).is_err());
+
+    let read = |options: ReadOptions| -> Result<Vec<StreamState>> {
+        let mut reader = data.as_slice();
+        let metadata = read_stream_metadata(&mut reader)?;
+        StreamReader::new(reader, metadata, None)
+            .with_read_options(options)
+            .collect()
+    };
+
+    let states = read(
+        ReadOptions::default()
+            .with_max_array_len(1000)
+            .with_max_total_allocation(4000),
+    )?;
+    assert!(matches!(&states[..], [StreamState::Some(read)] if read == &chunk));
+
+    let options = ReadOptions::default().with_max_array_len(999);
+    assert!(read(options).is_err());
+
+    let options = ReadOptions::default().with_max_total_allocation(1000);
+    assert!(read(options).is_err());
+    Ok(())
+}
@@ -42,3 +42,41 @@ async fn test_file(version: &str, file_name: &str) -> Result<()> {
 async fn write_async() -> Result<()> {
     test_file("1.0.0-littleendian", "generated_primitive").await
 }
+
+#[tokio::test]
+async fn read_with_limits() -> Result<()> {
+    use arrow2::array::Int32Array;
+    use arrow2::chunk::Chunk;
+    use arrow2::datatypes::{DataType, Field, Schema};
+    use arrow2::io::ipc::read::ReadOptions;
+    use futures::io::Cursor;
+
+    let chunk = Chunk::new(vec![Int32Array::from_vec(vec![0; 1000]).boxed()]);
+    let schema = Schema::from(vec![Field::new("a", DataType::Int32, false)]);
+    let data =
+        crate::io::ipc::write::file::write(std::slice::from_ref(&chunk), &schema, None, None)?;
+
+    let options = ReadOptions::default().with_max_message_size(16);
+    let mut reader = Cursor::new(&data);
+    assert!(read_file_metadata_async_with_options(&mut reader, options)
+        .await
+        .is_err());
+
+    let read = |options: ReadOptions| async {
+        let mut reader = Cursor::new(&data);
+        let metadata = read_file_metadata_async_with_options(&mut reader, options).await?;
+        FileStream::new(reader, metadata, None, None)
+            .collect::<Vec<_>>()
+            .await
+            .into_iter()
+            .collect::<Result<Vec<_>>>()
+    };
+
+    assert_eq!(read(ReadOptions::default()).await?, vec![chunk]);
+    // the footer fits, but the body of the batch (4000 bytes) does not
+    let options = ReadOptions::default().with_max_message_size(3999);
+    assert!(read(options).await.is_err());
+    let options = ReadOptions::default().with_max_total_allocation(1000);
+    assert!(read(options).await.is_err());
+    Ok(())
+}
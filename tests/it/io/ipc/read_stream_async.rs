@@ -42,3 +42,115 @@ async fn test_file(version: &str, file_name: &str) -> Result<()> {
 async fn write_async() -> Result<()> {
     test_file("1.0.0-littleendian", "generated_primitive").await
 }
+
+#[tokio::test]
+async fn read_with_limits() -> Result<()> {
+    use arrow2::array::Int32Array;
+    use arrow2::chunk::Chunk;
+    use arrow2::datatypes::{DataType, Field, Schema};
+    use arrow2::io::ipc::read::ReadOptions;
+    use arrow2::io::ipc::write::{StreamWriter, WriteOptions};
+
+    let chunk = Chunk::new(vec![Int32Array::from_vec(vec![0; 1000]).boxed()]);
+    let schema = Schema::from(vec![Field::new("a", DataType::Int32, false)]);
+    let mut writer = StreamWriter::new(vec![], WriteOptions::default());
+    writer.start(&schema, None)?;
+    writer.write(&chunk, None)?;
+    writer.finish()?;
+    let data = writer.into_inner();
+
+    let options = ReadOptions::default().with_max_message_size(16);
+    let mut reader = data.as_slice();
+    assert!(
+        read_stream_metadata_async_with_options(&mut reader, options)
+            .await
+            .is_err()
+    );
+
+    let read = |options: ReadOptions| async {
+        let mut reader = data.as_slice();
+        let metadata = read_stream_metadata_async(&mut reader).await?;
+        AsyncStreamReader::new_with_options(reader, metadata, options)
+            .collect::<Vec<_>>()
+            .await
+            .into_iter()
+            .collect::<Result<Vec<_>>>()
+    };
+
+    assert_eq!(read(ReadOptions::default()).await?, vec![chunk]);
+    // the body of the batch is 4000 bytes long
+    let options = ReadOptions::default().with_max_message_size(3999);
+    assert!(read(options).await.is_err());
+    let options = ReadOptions::default().with_max_array_len(999);
+    assert!(read(options).await.is_err());
+    Ok(())
+}
+
+#[tokio::test]
+async fn schema_evolution() -> Result<()> {
+    let (data, chunks) = crate::io::ipc::read::stream::evolving_stream()?;
+
+    let mut reader = data.as_slice();
+    let metadata = read_stream_metadata_async(&mut reader).await?;
+    let mut stream = AsyncStreamReader::new(reader, metadata.clone()).with_schema_evolution(true);
+    assert_eq!(stream.next().await.unwrap()?, chunks[0]);
+    assert_eq!(stream.metadata().schema.fields.len(), 1);
+    assert_eq!(stream.next().await.unwrap()?, chunks[1]);
+    assert_eq!(stream.metadata().schema.fields.len(), 2);
+    assert!(stream.next().await.is_none());
+
+    // without schema evolution, the schema message errors
+    let mut reader = data.as_slice();
+    let metadata = read_stream_metadata_async(&mut reader).await?;
+    let mut stream = AsyncStreamReader::new(reader, metadata);
+    assert_eq!(stream.next().await.unwrap()?, chunks[0]);
+    assert!(stream.next().await.unwrap().is_err());
+    Ok(())
+}
+
+#[tokio::test]
+async fn schema_evolution_projected() -> Result<()> {
+    use arrow2::chunk::Chunk;
+    use arrow2::datatypes::{DataType, Field, Schema};
+    use arrow2::io::ipc::write::{StreamWriter, WriteOptions};
+
+    let (data, chunks) = crate::io::ipc::read::stream::evolving_stream()?;
+
+    // the projection is applied to the new schema
+    let mut reader = data.as_slice();
+    let metadata = read_stream_metadata_async(&mut reader).await?;
+    let mut stream = AsyncStreamReader::new(reader, metadata)
+        .with_schema_evolution(true)
+        .with_projection(vec![0]);
+    assert_eq!(stream.next().await.unwrap()?, chunks[0]);
+    let expected = Chunk::new(vec![chunks[1].arrays()[0].clone()]);
+    assert_eq!(stream.next().await.unwrap()?, expected);
+    assert_eq!(stream.metadata().schema.fields.len(), 2);
+    assert_eq!(stream.schema().fields.len(), 1);
+
+    // a projection out of bounds of the new schema errors
+    let first = Schema::from(vec![
+        Field::new("a", DataType::Int32, false),
+        Field::new("b", DataType::Utf8, false),
+    ]);
+    let second = Schema::from(vec![Field::new("a", DataType::Int32, false)]);
+    let mut writer = StreamWriter::new(vec![], WriteOptions::default());
+    writer.start(&first, None)?;
+    writer.write(&chunks[1], None)?;
+    let mut writer = StreamWriter::new(writer.into_inner(), WriteOptions::default());
+    writer.start(&second, None)?;
+    writer.write(&chunks[0], None)?;
+    writer.finish()?;
+    let data = writer.into_inner();
+
+    let mut reader = data.as_slice();
+    let metadata = read_stream_metadata_async(&mut reader).await?;
+    let mut stream = AsyncStreamReader::new(reader, metadata)
+        .with_schema_evolution(true)
+        .with_projection(vec![1]);
+    let expected = Chunk::new(vec![chunks[1].arrays()[1].clone()]);
+    assert_eq!(stream.next().await.unwrap()?, expected);
+    assert_eq!(stream.schema().fields[0].name, "b");
+    assert!(stream.next().await.unwrap().is_err());
+    Ok(())
+}
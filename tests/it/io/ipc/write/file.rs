@@ -1,10 +1,11 @@
 use std::io::Cursor;
+use std::sync::Arc;
 
 use arrow2::array::*;
 use arrow2::chunk::Chunk;
-use arrow2::datatypes::{Field, Schema};
+use arrow2::datatypes::{DataType, Field, Metadata, Schema};
 use arrow2::error::Result;
-use arrow2::io::ipc::read::{read_file_metadata, FileReader};
+use arrow2::io::ipc::read::{read_file_from_bytes, read_file_metadata, FileMetadata, FileReader};
 use arrow2::io::ipc::{write::*, IpcField};
 use arrow2::types::{i256, months_days_ns};
 
@@ -17,7 +18,7 @@ pub(crate) fn write(
     compression: Option<Compression>,
 ) -> Result<Vec<u8>> {
     let result = vec![];
-    let options = WriteOptions { compression };
+    let options = WriteOptions::default().with_compression(compression);
     let mut writer = FileWriter::try_new(result, schema.clone(), ipc_fields.clone(), options)?;
     for batch in batches {
         writer.write(batch, ipc_fields.as_ref().map(|x| x.as_ref()))?;
@@ -387,3 +388,290 @@ fn write_decimal256() -> Result<()> {
     let columns = Chunk::try_new(vec![array])?;
     round_trip(columns, schema, None, None)
 }
+
+fn split_chunk() -> Chunk<Box<dyn Array>> {
+    let mut dictionary = MutableDictionaryArray::<i32, MutableUtf8Array<i32>>::new();
+    dictionary
+        .try_extend((0..10).map(|x| Some(if x % 2 == 0 { "even" } else { "odd" })))
+        .unwrap();
+    let dictionary: DictionaryArray<i32> = dictionary.into();
+    Chunk::new(vec![
+        Int64Array::from_iter((0..10).map(Some)).boxed(),
+        dictionary.boxed(),
+    ])
+}
+
+fn write_split(options: WriteOptions) -> Result<Vec<Chunk<Box<dyn Array>>>> {
+    let chunk = split_chunk();
+    let schema = Schema::from(vec![
+        Field::new("a", chunk.arrays()[0].data_type().clone(), true),
+        Field::new("b", chunk.arrays()[1].data_type().clone(), true),
+    ]);
+    let mut writer = FileWriter::try_new(vec![], schema, None, options)?;
+    writer.write(&chunk, None)?;
+    writer.finish()?;
+
+    let mut reader = Cursor::new(writer.into_inner());
+    let metadata = read_file_metadata(&mut reader)?;
+    FileReader::new(reader, metadata, None, None).collect()
+}
+
+fn assert_split(batches: &[Chunk<Box<dyn Array>>]) {
+    let expected = split_chunk();
+    let mut offset = 0;
+    for batch in batches {
+        for (array, expected) in batch.arrays().iter().zip(expected.arrays()) {
+            assert_eq!(array, &expected.slice(offset, batch.len()));
+        }
+        offset += batch.len();
+    }
+    assert_eq!(offset, expected.len());
+}
+
+#[test]
+fn write_split_by_rows() -> Result<()> {
+    let batches = write_split(WriteOptions::default().with_max_batch_rows(Some(3)))?;
+    let lengths = batches.iter().map(|x| x.len()).collect::<Vec<_>>();
+    assert_eq!(lengths, vec![3, 3, 3, 1]);
+    assert_split(&batches);
+    Ok(())
+}
+
+#[test]
+fn write_split_by_bytes() -> Result<()> {
+    // 12 bytes per row: an int64 value and an int32 key
+    let batches = write_split(WriteOptions::default().with_max_batch_bytes(Some(64)))?;
+    let lengths = batches.iter().map(|x| x.len()).collect::<Vec<_>>();
+    assert_eq!(lengths, vec![5, 5]);
+    assert_split(&batches);
+
+    // rows are never split
+    let batches = write_split(WriteOptions::default().with_max_batch_bytes(Some(1)))?;
+    assert_eq!(batches.len(), 10);
+    assert_split(&batches);
+    Ok(())
+}
+
+pub(crate) fn dictionary_chunk() -> Chunk<Box<dyn Array>> {
+    let keys = Int32Array::from_slice([0, 1, 0, 1]);
+    let values = Utf8Array::<i32>::from_slice(["repeated value", "another repeated value"]);
+    let dictionary = DictionaryArray::try_from_keys(keys, values.boxed()).unwrap();
+    Chunk::new(vec![
+        Int64Array::from_slice([1, 2, 3, 4]).boxed(),
+        dictionary.boxed(),
+    ])
+}
+
+pub(crate) fn write_dictionary_chunk(options: WriteOptions) -> Result<Vec<u8>> {
+    let chunk = dictionary_chunk();
+    let schema = Schema::from(vec![
+        Field::new("a", chunk.arrays()[0].data_type().clone(), false),
+        Field::new("b", chunk.arrays()[1].data_type().clone(), false),
+    ]);
+    let mut writer = FileWriter::try_new(vec![], schema, None, options)?;
+    writer.write(&chunk, None)?;
+    writer.finish()?;
+    Ok(writer.into_inner())
+}
+
+#[test]
+#[cfg_attr(miri, ignore)] // compression uses FFI, which miri does not support
+fn write_zstd_dictionary_and_level() -> Result<()> {
+    let zstd_dictionary: Arc<[u8]> = b"repeated value".repeat(4).into();
+    let data = write_dictionary_chunk(
+        WriteOptions::default()
+            .with_compression(Some(Compression::ZSTD))
+            .with_compression_level(Some(19))
+            .with_zstd_dictionary(Some(zstd_dictionary.clone())),
+    )?;
+
+    let mut reader = Cursor::new(data);
+    let metadata = read_file_metadata(&mut reader)?;
+    let mut reader = FileReader::new(reader, metadata, None, None);
+    reader.set_zstd_dictionary(zstd_dictionary);
+    let batches = reader.collect::<Result<Vec<_>>>()?;
+    assert_eq!(batches, vec![dictionary_chunk()]);
+    Ok(())
+}
+
+#[test]
+#[cfg_attr(miri, ignore)] // compression uses FFI, which miri does not support
+fn write_lz4_level() -> Result<()> {
+    let data = write_dictionary_chunk(
+        WriteOptions::default()
+            .with_compression(Some(Compression::LZ4))
+            .with_compression_level(Some(9)),
+    )?;
+
+    let mut reader = Cursor::new(data);
+    let metadata = read_file_metadata(&mut reader)?;
+    let batches = FileReader::new(reader, metadata, None, None).collect::<Result<Vec<_>>>()?;
+    assert_eq!(batches, vec![dictionary_chunk()]);
+    Ok(())
+}
+
+/// Returns the position of the first byte of the body of the first record batch
+fn body_start(metadata: &FileMetadata) -> usize {
+    let block = metadata.blocks[0];
+    (block.offset + block.meta_data_length as i64) as usize
+}
+
+#[test]
+fn write_checksums() -> Result<()> {
+    let data = write_dictionary_chunk(WriteOptions::default().with_checksum(true))?;
+
+    let mut reader = Cursor::new(data.clone());
+    let metadata = read_file_metadata(&mut reader)?;
+    assert!(metadata.has_checksums());
+    let batches = FileReader::new(reader, metadata, None, None).collect::<Result<Vec<_>>>()?;
+    assert_eq!(batches, vec![dictionary_chunk()]);
+
+    // corrupt the first value of the record batch
+    let mut corrupted = data.clone();
+    let metadata = read_file_metadata(&mut Cursor::new(&data))?;
+    corrupted[body_start(&metadata)] ^= 1;
+    let mut reader = Cursor::new(corrupted);
+    let metadata = read_file_metadata(&mut reader)?;
+    let result = FileReader::new(reader, metadata, None, None).collect::<Result<Vec<_>>>();
+    assert!(result.is_err());
+    Ok(())
+}
+
+/// A reader that counts the bytes read from it
+struct CountingReader<R> {
+    reader: R,
+    read: usize,
+}
+
+impl<R: std::io::Read> std::io::Read for CountingReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let read = self.reader.read(buf)?;
+        self.read += read;
+        Ok(read)
+    }
+}
+
+impl<R: std::io::Seek> std::io::Seek for CountingReader<R> {
+    fn seek(&mut self, pos: std::io::SeekFrom) -> std::io::Result<u64> {
+        self.reader.seek(pos)
+    }
+}
+
+/// Returns the number of bytes read to read the dictionaries and batches of `data`
+fn bytes_read(data: Vec<u8>) -> Result<usize> {
+    let mut reader = Cursor::new(data);
+    let metadata = read_file_metadata(&mut reader)?;
+    let reader = CountingReader { reader, read: 0 };
+    let mut reader = FileReader::new(reader, metadata, None, None);
+    assert_eq!(reader.next().unwrap()?, dictionary_chunk());
+    Ok(reader.into_inner().read)
+}
+
+#[test]
+fn checksums_read_blocks_once() -> Result<()> {
+    let without = bytes_read(write_dictionary_chunk(WriteOptions::default())?)?;
+    let with = bytes_read(write_dictionary_chunk(
+        WriteOptions::default().with_checksum(true),
+    )?)?;
+    // the blocks are read once, to verify their checksums, and decoded from memory
+    assert!(with < 2 * without);
+    Ok(())
+}
+
+#[test]
+fn write_without_checksums() -> Result<()> {
+    let data = write_dictionary_chunk(WriteOptions::default())?;
+
+    let mut corrupted = data.clone();
+    let metadata = read_file_metadata(&mut Cursor::new(&data))?;
+    assert!(!metadata.has_checksums());
+    corrupted[body_start(&metadata)] ^= 1;
+
+    // corruption is not detected
+    let mut reader = Cursor::new(corrupted);
+    let metadata = read_file_metadata(&mut reader)?;
+    let batches = FileReader::new(reader, metadata, None, None).collect::<Result<Vec<_>>>()?;
+    assert_ne!(batches, vec![dictionary_chunk()]);
+    Ok(())
+}
+
+#[test]
+fn write_custom_metadata() -> Result<()> {
+    let chunk = dictionary_chunk();
+    let schema = Schema::from(vec![
+        Field::new("a", chunk.arrays()[0].data_type().clone(), false),
+        Field::new("b", chunk.arrays()[1].data_type().clone(), false),
+    ]);
+    let options = WriteOptions::default()
+        .with_checksum(true)
+        .with_max_batch_rows(Some(2));
+    let file_metadata = Metadata::from([("lineage".to_string(), "job-1".to_string())]);
+    let batch_metadata = Metadata::from([("trace".to_string(), "span-1".to_string())]);
+
+    let mut writer = FileWriter::try_new(vec![], schema, None, options)?;
+    writer.set_custom_metadata(file_metadata.clone());
+    writer.write(&chunk, None)?;
+    writer.write_with_metadata(&chunk, None, &batch_metadata)?;
+    writer.finish()?;
+
+    let mut reader = Cursor::new(writer.into_inner());
+    let metadata = read_file_metadata(&mut reader)?;
+    assert!(metadata.has_checksums());
+    assert_eq!(metadata.custom_metadata, file_metadata);
+
+    let mut reader = FileReader::new(reader, metadata, None, None);
+    assert_eq!(reader.num_batches(), 4);
+    assert_eq!(reader.batch_custom_metadata(0)?, Metadata::default());
+    assert_eq!(reader.batch_custom_metadata(2)?, batch_metadata);
+    assert_eq!(reader.batch_custom_metadata(3)?, batch_metadata);
+    assert_eq!(reader.read_batch(3)?, chunk.sliced(2..4));
+    Ok(())
+}
+
+#[test]
+fn write_aligned_to_64() -> Result<()> {
+    let data = write_dictionary_chunk(WriteOptions::default().with_align_to_64(true))?;
+
+    let mut reader = Cursor::new(data);
+    let metadata = read_file_metadata(&mut reader)?;
+    assert_eq!(metadata.blocks.len(), 1);
+    for block in &metadata.blocks {
+        // the body of every message starts at a multiple of 64
+        assert_eq!((block.offset + block.meta_data_length as i64) % 64, 0);
+        assert_eq!(block.body_length % 64, 0);
+    }
+
+    let batches = FileReader::new(reader, metadata, None, None).collect::<Result<Vec<_>>>()?;
+    assert_eq!(batches, vec![dictionary_chunk()]);
+    Ok(())
+}
+
+#[test]
+fn bytes_round_trip() -> Result<()> {
+    let schema = Schema::from(vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, false),
+    ]);
+    let chunks = vec![
+        Chunk::new(vec![
+            Int32Array::from([Some(1), None]).boxed(),
+            Utf8Array::<i32>::from_slice(["a", "b"]).boxed(),
+        ]),
+        Chunk::new(vec![
+            Int32Array::from([Some(3)]).boxed(),
+            Utf8Array::<i32>::from_slice(["c"]).boxed(),
+        ]),
+    ];
+
+    let bytes = write_file_to_vec(schema.clone(), None, &chunks, WriteOptions::default())?;
+    let (read_schema, read_chunks) = read_file_from_bytes(&bytes, None)?;
+    assert_eq!(read_schema, schema);
+    assert_eq!(read_chunks, chunks);
+
+    let (read_schema, read_chunks) = read_file_from_bytes(&bytes, Some(vec![1]))?;
+    assert_eq!(read_schema.fields, vec![schema.fields[1].clone()]);
+    assert_eq!(read_chunks[1].arrays(), &chunks[1].arrays()[1..]);
+
+    assert!(read_file_from_bytes(&bytes[..bytes.len() - 1], None).is_err());
+    Ok(())
+}
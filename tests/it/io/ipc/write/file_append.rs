@@ -5,7 +5,7 @@ use arrow2::error::Result;
 use arrow2::io::ipc::read;
 use arrow2::io::ipc::write::{FileWriter, WriteOptions};
 
-use super::file::write;
+use super::file::{dictionary_chunk, write, write_dictionary_chunk};
 
 #[test]
 fn basic() -> Result<()> {
@@ -22,7 +22,7 @@ fn basic() -> Result<()> {
     // read the file to append
     let mut file = std::io::Cursor::new(result);
     let metadata = read::read_file_metadata(&mut file)?;
-    let mut writer = FileWriter::try_from_file(file, metadata, WriteOptions { compression: None })?;
+    let mut writer = FileWriter::try_from_file(file, metadata, WriteOptions::default())?;
 
     // write a new column
     writer.write(&columns, None)?;
@@ -42,3 +42,22 @@ fn basic() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn checksums() -> Result<()> {
+    // a file without checksums gains checksums of its existing blocks
+    let data = write_dictionary_chunk(WriteOptions::default())?;
+    let mut file = std::io::Cursor::new(data);
+    let metadata = read::read_file_metadata(&mut file)?;
+    let options = WriteOptions::default().with_checksum(true);
+    let mut writer = FileWriter::try_from_file(file, metadata, options)?;
+    writer.write(&dictionary_chunk(), None)?;
+    writer.finish()?;
+
+    let mut reader = std::io::Cursor::new(writer.into_inner().into_inner());
+    let metadata = read::read_file_metadata(&mut reader)?;
+    assert!(metadata.has_checksums());
+    let chunks = read::FileReader::new(reader, metadata, None, None).collect::<Result<Vec<_>>>()?;
+    assert_eq!(chunks, vec![dictionary_chunk(), dictionary_chunk()]);
+    Ok(())
+}
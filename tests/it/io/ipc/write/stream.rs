@@ -1,12 +1,13 @@
 use std::io::Cursor;
+use std::sync::Arc;
 
-use arrow2::array::Array;
+use arrow2::array::{Array, Utf8Array};
 use arrow2::chunk::Chunk;
-use arrow2::datatypes::Schema;
+use arrow2::datatypes::{DataType, Field, Schema};
 use arrow2::error::Result;
 use arrow2::io::ipc::read::read_stream_metadata;
 use arrow2::io::ipc::read::StreamReader;
-use arrow2::io::ipc::write::{StreamWriter, WriteOptions};
+use arrow2::io::ipc::write::{Compression, StreamWriter, WriteOptions};
 use arrow2::io::ipc::IpcField;
 
 use crate::io::ipc::common::read_arrow_stream;
@@ -19,7 +20,7 @@ fn write_(
 ) -> Vec<u8> {
     let mut result = vec![];
 
-    let options = WriteOptions { compression: None };
+    let options = WriteOptions::default();
     let mut writer = StreamWriter::new(&mut result, options);
     writer.start(schema, ipc_fields).unwrap();
     for batch in batches {
@@ -146,3 +147,56 @@ fn write_100_custom_metadata() {
 fn write_100_decimal() {
     test_file("1.0.0-littleendian", "generated_decimal");
 }
+
+#[test]
+#[cfg_attr(miri, ignore)] // compression uses FFI, which miri does not support
+fn write_zstd_dictionary() -> Result<()> {
+    let zstd_dictionary: Arc<[u8]> = b"abcdefgh".repeat(8).into();
+    let schema = Schema::from(vec![Field::new("a", DataType::Utf8, false)]);
+    let chunk = Chunk::new(vec![Utf8Array::<i32>::from_slice([
+        "abcdefgh",
+        "abcdefghabcd",
+    ])
+    .boxed()]);
+
+    let mut result = vec![];
+    let options = WriteOptions::default()
+        .with_compression(Some(Compression::ZSTD))
+        .with_zstd_dictionary(Some(zstd_dictionary.clone()));
+    let mut writer = StreamWriter::new(&mut result, options);
+    writer.start(&schema, None)?;
+    writer.write(&chunk, None)?;
+    writer.finish()?;
+
+    let mut reader = Cursor::new(result);
+    let metadata = read_stream_metadata(&mut reader)?;
+    let reader = StreamReader::new(reader, metadata, None).with_zstd_dictionary(zstd_dictionary);
+    let batches = reader
+        .map(|state| state.map(|state| state.unwrap()))
+        .collect::<Result<Vec<_>>>()?;
+    assert_eq!(batches, vec![chunk]);
+    Ok(())
+}
+
+#[test]
+fn write_aligned_to_64() -> Result<()> {
+    let schema = Schema::from(vec![Field::new("a", DataType::Utf8, false)]);
+    let chunk = Chunk::new(vec![Utf8Array::<i32>::from_slice(["a", "bc"]).boxed()]);
+
+    let mut result = vec![];
+    let options = WriteOptions::default().with_align_to_64(true);
+    let mut writer = StreamWriter::new(&mut result, options);
+    writer.start(&schema, None)?;
+    writer.write(&chunk, None)?;
+    writer.finish()?;
+    // every message is a multiple of 64 bytes, followed by the 8 bytes of the end of the stream
+    assert_eq!((result.len() - 8) % 64, 0);
+
+    let mut reader = Cursor::new(result);
+    let metadata = read_stream_metadata(&mut reader)?;
+    let batches = StreamReader::new(reader, metadata, None)
+        .map(|state| state.map(|state| state.unwrap()))
+        .collect::<Result<Vec<_>>>()?;
+    assert_eq!(batches, vec![chunk]);
+    Ok(())
+}
@@ -21,7 +21,7 @@ async fn write_(
 ) -> Result<Vec<u8>> {
     let mut result = AsyncCursor::new(vec![]);
 
-    let options = stream_async::WriteOptions { compression: None };
+    let options = stream_async::WriteOptions::default();
     let mut sink = StreamSink::new(&mut result, schema, Some(ipc_fields.to_vec()), options);
     for batch in batches {
         sink.feed((batch, Some(ipc_fields)).into()).await?;
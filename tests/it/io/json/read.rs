@@ -163,3 +163,16 @@ fn read_json_fixed_size_records() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn read_json_promote_large() -> Result<()> {
+    let data = br#"[{"a": ["x", "yz"]}, {"a": null}]"#;
+    let json = json_deserializer::parse(data)?;
+    let data_type = read::infer(&json)?;
+
+    // the values fit in `i32` offsets and are therefore not promoted
+    let result = read::deserialize_promote_large(&json, data_type.clone())?;
+    let expected = read::deserialize(&json, data_type)?;
+    assert_eq!(expected, result);
+    Ok(())
+}
@@ -28,3 +28,12 @@ mod csv;
 
 #[cfg(feature = "io_flight")]
 mod flight;
+
+#[cfg(any(feature = "io_ipc", feature = "io_parquet"))]
+mod cache;
+
+#[cfg(feature = "io_parquet")]
+mod util;
+
+#[cfg(feature = "io_serde")]
+mod serde;
@@ -1,7 +1,7 @@
 use std::io::Cursor;
 
 use arrow2::array::*;
-use arrow2::datatypes::{DataType, Field};
+use arrow2::datatypes::{DataType, Field, UnionMode};
 use arrow2::error::{Error, Result};
 use arrow2::io::ndjson::read as ndjson_read;
 use arrow2::io::ndjson::read::FallibleStreamingIterator;
@@ -294,3 +294,87 @@ fn utf8_array() -> Result<()> {
     assert_eq!(field_names, vec!["a".to_string(), "b".to_string()]);
     Ok(())
 }
+
+#[test]
+fn infer_and_read_mixed_types_as_utf8() -> Result<()> {
+    let ndjson = r#"{"a": 1}
+    {"a": "b"}
+    {"a": 1.5}
+    {"a": true}
+    "#;
+
+    let data_type = infer(ndjson)?;
+    let expected_data_type = DataType::Struct(vec![Field::new("a", DataType::Utf8, true)]);
+    assert_eq!(data_type, expected_data_type);
+
+    let arrays = read_and_deserialize(ndjson, &data_type, 1000)?;
+    let array = arrays[0].as_any().downcast_ref::<StructArray>().unwrap();
+    let expected = Utf8Array::<i32>::from_slice(["1", "b", "1.5", "true"]);
+    assert_eq!(array.values()[0].as_ref(), &expected as &dyn Array);
+    Ok(())
+}
+
+#[test]
+fn infer_and_read_mixed_types_as_union() -> Result<()> {
+    let ndjson = r#"{"a": 1}
+    {"a": "b"}
+    {"a": null}
+    {"a": 2}
+    {"a": "c"}
+    "#;
+
+    let options = ndjson_read::InferOptions {
+        mixed_types: ndjson_read::MixedTypes::Union,
+    };
+    let data_type = ndjson_read::infer_with_options(&mut Cursor::new(ndjson), None, &options)?;
+    let union = DataType::Union(
+        vec![
+            Field::new("number", DataType::Int64, true),
+            Field::new("string", DataType::Utf8, true),
+        ],
+        None,
+        UnionMode::Dense,
+    );
+    let expected_data_type = DataType::Struct(vec![Field::new("a", union.clone(), true)]);
+    assert_eq!(data_type, expected_data_type);
+
+    let arrays = read_and_deserialize(ndjson, &data_type, 1000)?;
+    let array = arrays[0].as_any().downcast_ref::<StructArray>().unwrap();
+
+    let expected = UnionArray::new(
+        union,
+        vec![0, 1, 0, 0, 1].into(),
+        vec![
+            Int64Array::from([Some(1), None, Some(2)]).boxed(),
+            Utf8Array::<i32>::from_slice(["b", "c"]).boxed(),
+        ],
+        Some(vec![0, 0, 1, 2, 1].into()),
+    );
+    assert_eq!(array.values()[0].as_ref(), &expected as &dyn Array);
+    Ok(())
+}
+
+#[test]
+fn infer_union_coerces_numbers() -> Result<()> {
+    let rows = [r#"[1, "a", 1.5, true]"#, r#"[false]"#];
+
+    let options = ndjson_read::InferOptions {
+        mixed_types: ndjson_read::MixedTypes::Union,
+    };
+    let data_type = ndjson_read::infer_iter_with_options(rows.iter(), &options)?;
+    let expected = DataType::List(Box::new(Field::new(
+        "item",
+        DataType::Union(
+            vec![
+                Field::new("number", DataType::Float64, true),
+                Field::new("string", DataType::Utf8, true),
+                Field::new("boolean", DataType::Boolean, true),
+            ],
+            None,
+            UnionMode::Dense,
+        ),
+        true,
+    )));
+    assert_eq!(data_type, expected);
+    Ok(())
+}
@@ -122,3 +122,68 @@ fn utf8() -> Result<(), Error> {
     );
     Ok(())
 }
+
+#[test]
+fn stripe() -> Result<(), Error> {
+    let mut reader = std::fs::File::open("fixtures/pyorc/test.orc").unwrap();
+    let metadata = format::read::read_metadata(&mut reader)?;
+    let schema = read::infer_schema(&metadata.footer)?;
+
+    let stripe = read::read_stripe(&mut reader, &metadata, 0)?;
+    assert_eq!(stripe.number_of_rows(), 5);
+
+    // stripes are decoded independently of the reader, e.g. in another thread
+    let chunk = std::thread::spawn(move || stripe.deserialize(&schema))
+        .join()
+        .unwrap()?;
+    assert_eq!(chunk.len(), 5);
+    assert_eq!(chunk.arrays()[0], deserialize_column("float_nullable")?);
+
+    assert!(read::read_stripe(&mut reader, &metadata, 1).is_err());
+    Ok(())
+}
+
+#[cfg(feature = "io_orc_async")]
+#[tokio::test]
+async fn stripe_async() -> Result<(), Error> {
+    use tokio_util::compat::*;
+
+    let mut reader = tokio::fs::File::open("fixtures/pyorc/test.orc")
+        .await?
+        .compat();
+    let metadata = read::read_metadata_async(&mut reader).await?;
+    let schema = read::infer_schema(&metadata.footer)?;
+    assert_eq!(schema.fields.len(), 12);
+
+    let stripe = read::read_stripe_async(&mut reader, &metadata, 0).await?;
+    let chunk = stripe.deserialize(&schema)?;
+    assert_eq!(chunk.arrays()[0], deserialize_column("float_nullable")?);
+    Ok(())
+}
+
+#[cfg(feature = "io_orc_async")]
+#[tokio::test]
+async fn metadata_async() -> Result<(), Error> {
+    use futures::io::Cursor;
+
+    // an uncompressed file with an empty metadata and a footer declaring 7 rows
+    let footer = [0x30, 7];
+    let postscript = [0x08, footer.len() as u8, 0x10, 0, 0x28, 0];
+    let mut data = b"ORC".to_vec();
+    data.extend_from_slice(&footer);
+    data.extend_from_slice(&postscript);
+    data.push(postscript.len() as u8);
+
+    let metadata = read::read_metadata_async(&mut Cursor::new(data)).await?;
+    assert_eq!(metadata.footer.number_of_rows, Some(7));
+
+    // a postscript or a footer larger than the file
+    let mut reader = Cursor::new(vec![255u8]);
+    assert!(read::read_metadata_async(&mut reader).await.is_err());
+    let mut reader = Cursor::new(vec![0x08, 100, 2]);
+    assert!(read::read_metadata_async(&mut reader).await.is_err());
+    assert!(read::read_metadata_async(&mut Cursor::new(vec![]))
+        .await
+        .is_err());
+    Ok(())
+}
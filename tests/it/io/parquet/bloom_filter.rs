@@ -0,0 +1,61 @@
+use std::io::Cursor;
+
+use arrow2::array::*;
+use arrow2::chunk::Chunk;
+use arrow2::datatypes::*;
+use arrow2::error::Result;
+use arrow2::io::parquet::read::{self as p_read, BloomFilter, PhysicalType};
+use arrow2::io::parquet::write::*;
+use arrow2::scalar::*;
+
+#[test]
+fn might_contain() {
+    // a single block without any bit set contains nothing
+    let filter = BloomFilter::new(PhysicalType::Int32, vec![0; 32]);
+    assert!(!filter.might_contain(&PrimitiveScalar::from(Some(1i32))));
+    assert!(!filter.might_contain(&PrimitiveScalar::from(Some(1u8))));
+
+    // nulls and types that can't be hashed as the physical type are conservative
+    assert!(filter.might_contain(&PrimitiveScalar::<i32>::from(None)));
+    assert!(filter.might_contain(&BooleanScalar::from(Some(true))));
+    assert!(filter.might_contain(&PrimitiveScalar::from(Some(1.0f32))));
+
+    let filter = BloomFilter::new(PhysicalType::ByteArray, vec![0; 32]);
+    assert!(!filter.might_contain(&Utf8Scalar::<i32>::from(Some("a"))));
+    assert!(!filter.might_contain(&BinaryScalar::<i64>::from(Some(b"a"))));
+
+    // a single block with all bits set contains everything
+    let filter = BloomFilter::new(PhysicalType::Int64, vec![u8::MAX; 32]);
+    assert!(filter.might_contain(&PrimitiveScalar::from(Some(1i64))));
+}
+
+#[test]
+fn read_without_bloom_filter() -> Result<()> {
+    let array = Int32Array::from_slice([1, 2, 3]);
+    let schema = Schema::from(vec![Field::new("a", DataType::Int32, false)]);
+    let options = WriteOptions {
+        write_statistics: true,
+        compression: CompressionOptions::Uncompressed,
+        version: Version::V2,
+        data_pagesize_limit: None,
+        int96_timestamps: false,
+    };
+
+    let row_groups = RowGroupIterator::try_new(
+        vec![Ok(Chunk::new(vec![array.boxed()]))].into_iter(),
+        &schema,
+        options,
+        vec![vec![Encoding::Plain]],
+    )?;
+    let mut writer = FileWriter::try_new(Cursor::new(vec![]), schema, options)?;
+    for group in row_groups {
+        writer.write(group?)?;
+    }
+    writer.end(None)?;
+    let mut reader = writer.into_inner();
+
+    let metadata = p_read::read_metadata(&mut reader)?;
+    let column = &metadata.row_groups[0].columns()[0];
+    assert_eq!(p_read::read_bloom_filter(column, &mut reader)?, None);
+    Ok(())
+}
@@ -0,0 +1,346 @@
+use std::fs::File;
+use std::path::PathBuf;
+
+use arrow2::array::*;
+use arrow2::chunk::Chunk;
+use arrow2::datatypes::*;
+use arrow2::error::Result;
+use arrow2::io::parquet::read::{
+    infer_schema, parse_partition, read_metadata, DatasetReader, FileReader,
+};
+use arrow2::io::parquet::write::*;
+
+fn root(name: &str) -> PathBuf {
+    let root = std::env::temp_dir().join(format!("arrow2-dataset-{name}-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&root);
+    root
+}
+
+fn options() -> WriteOptions {
+    WriteOptions {
+        write_statistics: true,
+        compression: CompressionOptions::Uncompressed,
+        version: Version::V2,
+        data_pagesize_limit: None,
+        int96_timestamps: false,
+    }
+}
+
+fn schema() -> Schema {
+    Schema::from(vec![
+        Field::new("country", DataType::Utf8, true),
+        Field::new("value", DataType::Int32, true),
+        Field::new("year", DataType::Int16, true),
+    ])
+}
+
+fn read(file: &WrittenFile) -> Result<Vec<Chunk<Box<dyn Array>>>> {
+    let mut reader = File::open(&file.path)?;
+    let metadata = read_metadata(&mut reader)?;
+    let schema = infer_schema(&metadata)?;
+    FileReader::new(reader, metadata.row_groups, schema, None, None, None).collect()
+}
+
+#[test]
+fn partitioned() -> Result<()> {
+    let root = root("partitioned");
+    let mut writer = DatasetWriter::try_new(
+        &root,
+        schema(),
+        &["country", "year"],
+        options(),
+        vec![vec![Encoding::Plain]],
+    )?;
+    assert_eq!(writer.file_schema().fields.len(), 1);
+
+    writer.write(&Chunk::new(vec![
+        Utf8Array::<i32>::from([Some("pt"), Some("de"), Some("pt"), None]).boxed(),
+        Int32Array::from_slice([1, 2, 3, 4]).boxed(),
+        Int16Array::from_slice([2020, 2020, 2020, 2021]).boxed(),
+    ]))?;
+    writer.write(&Chunk::new(vec![
+        Utf8Array::<i32>::from_slice(["a/b", "pt"]).boxed(),
+        Int32Array::from_slice([5, 6]).boxed(),
+        Int16Array::from_slice([2021, 2020]).boxed(),
+    ]))?;
+    let mut files = writer.finish()?;
+    files.sort_by(|a, b| a.path.cmp(&b.path));
+
+    let paths = files
+        .iter()
+        .map(|file| file.path.strip_prefix(&root).unwrap().to_path_buf())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        paths,
+        vec![
+            PathBuf::from("country=__HIVE_DEFAULT_PARTITION__/year=2021/part-00002.parquet"),
+            PathBuf::from("country=a%2Fb/year=2021/part-00003.parquet"),
+            PathBuf::from("country=de/year=2020/part-00001.parquet"),
+            PathBuf::from("country=pt/year=2020/part-00000.parquet"),
+        ]
+    );
+    assert_eq!(
+        files[0].partition,
+        vec![
+            ("country".to_string(), None),
+            ("year".to_string(), Some("2021".to_string()))
+        ]
+    );
+    assert_eq!(
+        partition_path(&root, &files[1].partition),
+        root.join("country=a%2Fb").join("year=2021")
+    );
+
+    let pt = &files[3];
+    assert_eq!(pt.num_rows, 3);
+    assert_eq!(pt.size, std::fs::metadata(&pt.path)?.len());
+    assert_eq!(pt.metadata.num_rows, 3);
+
+    let chunks = read(pt)?;
+    assert_eq!(chunks.len(), 2);
+    assert_eq!(
+        chunks[0].arrays()[0].as_ref(),
+        &Int32Array::from_slice([1, 3]) as &dyn Array
+    );
+    assert_eq!(
+        chunks[1].arrays()[0].as_ref(),
+        &Int32Array::from_slice([6]) as &dyn Array
+    );
+
+    std::fs::remove_dir_all(&root)?;
+    Ok(())
+}
+
+#[test]
+fn rotation() -> Result<()> {
+    let root = root("rotation");
+    let mut writer = DatasetWriter::try_new(
+        &root,
+        schema(),
+        &["country"],
+        options(),
+        vec![vec![Encoding::Plain], vec![Encoding::Plain]],
+    )?
+    .with_max_rows_per_file(2);
+
+    writer.write(&Chunk::new(vec![
+        Utf8Array::<i32>::from_slice(["pt", "pt", "pt", "de", "pt"]).boxed(),
+        Int32Array::from_slice([1, 2, 3, 4, 5]).boxed(),
+        Int16Array::from_slice([1, 2, 3, 4, 5]).boxed(),
+    ]))?;
+    let files = writer.finish()?;
+
+    let rows = files
+        .iter()
+        .map(|file| (file.partition[0].1.clone().unwrap(), file.num_rows))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        rows,
+        vec![
+            ("pt".to_string(), 2),
+            ("pt".to_string(), 2),
+            ("de".to_string(), 1)
+        ]
+    );
+    let chunks = read(&files[1])?;
+    assert_eq!(
+        chunks[0].arrays()[0].as_ref(),
+        &Int32Array::from_slice([3, 5]) as &dyn Array
+    );
+
+    // every row group exceeds one byte, so every file has one row group
+    let root_bytes = root.join("bytes");
+    let mut writer = DatasetWriter::try_new(
+        &root_bytes,
+        schema(),
+        &["country"],
+        options(),
+        vec![vec![Encoding::Plain], vec![Encoding::Plain]],
+    )?
+    .with_max_bytes_per_file(1);
+    for _ in 0..3 {
+        writer.write(&Chunk::new(vec![
+            Utf8Array::<i32>::from_slice(["pt"]).boxed(),
+            Int32Array::from_slice([1]).boxed(),
+            Int16Array::from_slice([1]).boxed(),
+        ]))?;
+    }
+    assert_eq!(writer.finish()?.len(), 3);
+
+    std::fs::remove_dir_all(&root)?;
+    Ok(())
+}
+
+#[test]
+fn errors() {
+    let encodings = || vec![vec![Encoding::Plain], vec![Encoding::Plain]];
+    let root = root("errors");
+    assert!(DatasetWriter::try_new(&root, schema(), &["unknown"], options(), encodings()).is_err());
+    assert!(DatasetWriter::try_new(
+        &root,
+        schema(),
+        &["country", "value", "year"],
+        options(),
+        vec![]
+    )
+    .is_err());
+    assert!(DatasetWriter::try_new(&root, schema(), &["country"], options(), vec![]).is_err());
+
+    let mut writer =
+        DatasetWriter::try_new(&root, schema(), &["country"], options(), encodings()).unwrap();
+    let chunk = Chunk::new(vec![Utf8Array::<i32>::from_slice(["pt"]).boxed()]);
+    assert!(writer.write(&chunk).is_err());
+}
+
+fn evolved_dataset(root: &PathBuf) -> Result<Vec<PathBuf>> {
+    let mut writer = DatasetWriter::try_new(
+        root,
+        Schema::from(vec![
+            Field::new("country", DataType::Utf8, true),
+            Field::new("value", DataType::Int32, false),
+        ]),
+        &["country"],
+        options(),
+        vec![vec![Encoding::Plain]],
+    )?;
+    writer.write(&Chunk::new(vec![
+        Utf8Array::<i32>::from_slice(["pt", "pt"]).boxed(),
+        Int32Array::from_slice([1, 2]).boxed(),
+    ]))?;
+    writer.write(&Chunk::new(vec![
+        Utf8Array::<i32>::from_slice(["pt"]).boxed(),
+        Int32Array::from_slice([3]).boxed(),
+    ]))?;
+    let mut paths = writer
+        .finish()?
+        .into_iter()
+        .map(|file| file.path)
+        .collect::<Vec<_>>();
+
+    // a later file with a widened and an additional field
+    let mut writer = DatasetWriter::try_new(
+        root.join("evolved"),
+        Schema::from(vec![
+            Field::new("value", DataType::Int64, false),
+            Field::new("name", DataType::Utf8, false),
+            Field::new("country", DataType::Utf8, true),
+        ]),
+        &["country"],
+        options(),
+        vec![vec![Encoding::Plain], vec![Encoding::Plain]],
+    )?;
+    writer.write(&Chunk::new(vec![
+        Int64Array::from_slice([4]).boxed(),
+        Utf8Array::<i32>::from_slice(["a"]).boxed(),
+        Utf8Array::<i32>::from([None::<&str>]).boxed(),
+    ]))?;
+    paths.extend(writer.finish()?.into_iter().map(|file| {
+        // move the file next to the others, so that all files have the same root
+        let path = root
+            .join(file.path.parent().unwrap().file_name().unwrap())
+            .join("part-00001.parquet");
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::rename(&file.path, &path).unwrap();
+        path
+    }));
+    Ok(paths)
+}
+
+fn dictionary(values: &[Option<&str>]) -> Box<dyn Array> {
+    let mut array = MutableDictionaryArray::<i32, MutableUtf8Array<i32>>::new();
+    array.try_extend(values.iter().copied()).unwrap();
+    let array: DictionaryArray<i32> = array.into();
+    array.boxed()
+}
+
+#[test]
+fn read_unified() -> Result<()> {
+    let root = root("read_unified");
+    let paths = evolved_dataset(&root)?;
+
+    let reader = DatasetReader::try_from_paths(&root, &paths)?;
+    let dictionary_type = DataType::Dictionary(IntegerType::Int32, Box::new(DataType::Utf8), false);
+    assert_eq!(
+        reader.schema(),
+        Schema::from(vec![
+            Field::new("value", DataType::Int64, false),
+            Field::new("name", DataType::Utf8, true),
+            Field::new("country", dictionary_type, true),
+        ])
+    );
+
+    let chunks = reader.collect::<Result<Vec<_>>>()?;
+    assert_eq!(chunks.len(), 3);
+    assert_eq!(
+        chunks[0].arrays()[0].as_ref(),
+        &Int64Array::from_slice([1, 2]) as &dyn Array
+    );
+    assert_eq!(
+        chunks[0].arrays()[1].as_ref(),
+        &Utf8Array::<i32>::new_null(DataType::Utf8, 2) as &dyn Array
+    );
+    assert_eq!(chunks[0].arrays()[2], dictionary(&[Some("pt"), Some("pt")]));
+    assert_eq!(
+        chunks[2].arrays()[1].as_ref(),
+        &Utf8Array::<i32>::from_slice(["a"]) as &dyn Array
+    );
+    assert_eq!(
+        chunks[2].arrays()[2].data_type(),
+        chunks[0].arrays()[2].data_type()
+    );
+    assert!(chunks[2].arrays()[2].is_null(0));
+
+    std::fs::remove_dir_all(&root)?;
+    Ok(())
+}
+
+#[test]
+fn read_projected_and_filtered() -> Result<()> {
+    let root = root("read_projected");
+    let paths = evolved_dataset(&root)?;
+
+    let reader = DatasetReader::try_from_paths(&root, &paths)?
+        .with_projection(&["country", "name"])?
+        .with_row_group_filter(|_, row_group| row_group.num_rows() == 1);
+    assert_eq!(reader.schema().fields.len(), 2);
+    let chunks = reader.collect::<Result<Vec<_>>>()?;
+    // the first file lacks "name", so its second row group is all nulls
+    assert_eq!(chunks.len(), 2);
+    assert_eq!(
+        chunks[0].arrays()[0].as_ref(),
+        &Utf8Array::<i32>::new_null(DataType::Utf8, 1) as &dyn Array
+    );
+    assert_eq!(chunks[0].arrays()[1], dictionary(&[Some("pt")]));
+    assert_eq!(
+        chunks[1].arrays()[0].as_ref(),
+        &Utf8Array::<i32>::from_slice(["a"]) as &dyn Array
+    );
+
+    // only partition columns
+    let chunks = DatasetReader::try_from_paths(&root, &paths)?
+        .with_projection(&["country"])?
+        .collect::<Result<Vec<_>>>()?;
+    let lengths = chunks.iter().map(|chunk| chunk.len()).collect::<Vec<_>>();
+    assert_eq!(lengths, vec![2, 1, 1]);
+
+    assert!(DatasetReader::try_from_paths(&root, &paths)?
+        .with_projection(&["unknown"])
+        .is_err());
+
+    std::fs::remove_dir_all(&root)?;
+    Ok(())
+}
+
+#[test]
+fn parse_partitions() -> Result<()> {
+    assert_eq!(
+        parse_partition("root", "root/a=1/other/b=x%3Dy/part-00000.parquet")?,
+        vec![
+            ("a".to_string(), Some("1".to_string())),
+            ("b".to_string(), Some("x=y".to_string())),
+        ]
+    );
+    assert!(parse_partition("root", "elsewhere/a=1/part-00000.parquet").is_err());
+    assert!(parse_partition("root", "root/a=%zz/part-00000.parquet").is_err());
+    Ok(())
+}
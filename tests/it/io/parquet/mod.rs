@@ -14,7 +14,13 @@ use arrow2::{
 };
 
 #[cfg(feature = "io_json_integration")]
+#[cfg(feature = "io_parquet_bloom_filter")]
+mod bloom_filter;
+#[cfg(feature = "io_parquet_dataset")]
+mod dataset;
 mod integration;
+#[cfg(feature = "io_parquet_progress")]
+mod progress;
 mod read;
 mod read_indexes;
 mod write;
@@ -451,8 +457,8 @@ pub fn pyarrow_nullable_statistics(column: &str) -> Statistics {
             };
 
             Statistics {
-                distinct_count: UInt64Array::from([None]).boxed(),
-                null_count: UInt64Array::from([Some(0)]).boxed(),
+                distinct_count: UInt64Array::from([Some(2)]).boxed(),
+                null_count: UInt64Array::from([Some(1)]).boxed(),
                 min_value: new_dict(Box::new(Int32Array::from_slice([10]))),
                 max_value: new_dict(Box::new(Int32Array::from_slice([200]))),
             }
@@ -1121,6 +1127,7 @@ fn integration_write(schema: &Schema, chunks: &[Chunk<Box<dyn Array>>]) -> Resul
         compression: CompressionOptions::Uncompressed,
         version: Version::V1,
         data_pagesize_limit: None,
+        int96_timestamps: false,
     };
 
     let encodings = schema
@@ -1301,6 +1308,57 @@ fn arrow_type() -> Result<()> {
     assert_roundtrip(schema, chunk, None)
 }
 
+#[test]
+fn timestamp_timezones() -> Result<()> {
+    let array1 =
+        Int64Array::from_slice([1, 2]).to(DataType::Timestamp(TimeUnit::Millisecond, None));
+    let array2 = Int64Array::from_slice([1, 2]).to(DataType::Timestamp(
+        TimeUnit::Millisecond,
+        Some("+01:00".to_string()),
+    ));
+    let schema = Schema::from(vec![
+        Field::new("a1", array1.data_type().clone(), true),
+        Field::new("a2", array2.data_type().clone(), true),
+    ]);
+    let chunk = Chunk::try_new(vec![array1.boxed(), array2.boxed()])?;
+    assert_roundtrip(schema, chunk, None)
+}
+
+#[test]
+fn arrow_schema_is_replaced() -> Result<()> {
+    let array = Int64Array::from_slice([1, 2]).to(DataType::Duration(TimeUnit::Second));
+    let schema = Schema::from(vec![Field::new("a1", array.data_type().clone(), true)]);
+
+    let stale = Schema::from(vec![Field::new("a1", DataType::Int64, true)]);
+    let key_value_metadata = add_arrow_schema(&stale, None);
+    let key_value_metadata = add_arrow_schema(&schema, key_value_metadata).unwrap();
+    assert_eq!(key_value_metadata.len(), 1);
+
+    let mut metadata = p_read::read_metadata(&mut Cursor::new(integration_write(
+        &schema,
+        &[Chunk::try_new(vec![array.boxed()])?],
+    )?))?;
+    assert_eq!(p_read::infer_schema(&metadata)?, schema);
+
+    // fields of the arrow schema that do not match the parquet schema are ignored
+    let renamed = Schema::from(vec![Field::new(
+        "b",
+        schema.fields[0].data_type.clone(),
+        true,
+    )]);
+    metadata.key_value_metadata = add_arrow_schema(&renamed, None);
+    let expected = Schema::from(vec![Field::new("a1", DataType::Int64, true)]);
+    assert_eq!(p_read::infer_schema(&metadata)?, expected);
+
+    // an arrow schema that can't be decoded is an error
+    metadata.key_value_metadata = Some(vec![KeyValue {
+        key: "ARROW:schema".to_string(),
+        value: Some("AAAA".to_string()),
+    }]);
+    assert!(p_read::infer_schema(&metadata).is_err());
+    Ok(())
+}
+
 fn data<T: NativeType, I: Iterator<Item = T>>(
     mut iter: I,
     inner_is_nullable: bool,
@@ -1476,6 +1534,56 @@ fn list_int_nullable() -> Result<()> {
     list_array_generic(true, array.into(), None)
 }
 
+fn fixed_size_list_data() -> FixedSizeListArray {
+    let data_type = FixedSizeListArray::default_datatype(DataType::Int32, 2);
+    let values = Int32Array::from([
+        Some(1),
+        Some(2),
+        None,
+        None,
+        Some(3),
+        None,
+        Some(4),
+        Some(5),
+    ]);
+    FixedSizeListArray::new(
+        data_type,
+        values.boxed(),
+        Some([true, false, true, true].into()),
+    )
+}
+
+#[test]
+fn fixed_size_list() -> Result<()> {
+    let array = fixed_size_list_data();
+    let schema = Schema::from(vec![Field::new("a1", array.data_type().clone(), true)]);
+    let chunk = Chunk::try_new(vec![array.boxed()])?;
+    assert_roundtrip(schema.clone(), chunk.clone(), None)?;
+    assert_roundtrip(schema, chunk, Some(3))
+}
+
+#[test]
+fn fixed_size_list_invalid_size() -> Result<()> {
+    // a list whose lengths are not all 2 can't be read as a fixed size list of 2
+    let mut array = MutableListArray::<i32, _>::new_with_field(
+        MutablePrimitiveArray::<i32>::new(),
+        "item",
+        true,
+    );
+    array.try_extend(vec![Some(vec![Some(1), Some(2)]), Some(vec![Some(3)])])?;
+    let array: ListArray<i32> = array.into();
+    let schema = Schema::from(vec![Field::new("a1", array.data_type().clone(), true)]);
+    let data = integration_write(&schema, &[Chunk::try_new(vec![array.boxed()])?])?;
+
+    let mut reader = Cursor::new(&data);
+    let metadata = p_read::read_metadata(&mut reader)?;
+    let data_type = FixedSizeListArray::default_datatype(DataType::Int32, 2);
+    let schema = Schema::from(vec![Field::new("a1", data_type, true)]);
+    let mut reader = p_read::FileReader::new(reader, metadata.row_groups, schema, None, None, None);
+    assert!(reader.next().unwrap().is_err());
+    Ok(())
+}
+
 #[test]
 fn limit() -> Result<()> {
     let (schema, chunk) = generic_data()?;
@@ -1491,6 +1599,7 @@ fn nested_dict_data(data_type: DataType) -> Result<(Schema, Chunk<Box<dyn Array>
     let values = match data_type {
         DataType::Float32 => PrimitiveArray::from_slice([1.0f32, 3.0]).boxed(),
         DataType::Utf8 => Utf8Array::<i32>::from_slice(["a", "b"]).boxed(),
+        DataType::UInt64 => PrimitiveArray::from_slice([1u64, u64::MAX]).boxed(),
         _ => unreachable!(),
     };
 
@@ -1527,6 +1636,13 @@ fn nested_dict_utf8() -> Result<()> {
     assert_roundtrip(schema, chunk, None)
 }
 
+#[test]
+fn nested_dict_u64() -> Result<()> {
+    let (schema, chunk) = nested_dict_data(DataType::UInt64)?;
+
+    assert_roundtrip(schema, chunk, None)
+}
+
 #[test]
 fn nested_dict_limit() -> Result<()> {
     let (schema, chunk) = nested_dict_data(DataType::Float32)?;
@@ -1534,6 +1650,280 @@ fn nested_dict_limit() -> Result<()> {
     assert_roundtrip(schema, chunk, Some(2))
 }
 
+#[test]
+fn nested_dict_statistics() -> Result<()> {
+    let (schema, chunk) = nested_dict_data(DataType::Utf8)?;
+
+    let data = integration_write(&schema, &[chunk])?;
+    let metadata = p_read::read_metadata(&mut Cursor::new(data))?;
+    let statistics = deserialize(&schema.fields[0], &metadata.row_groups)?;
+
+    let leaves = statistics.leaves();
+    assert_eq!(leaves.len(), 1);
+    assert_eq!(
+        leaves[0].null_count.as_ref(),
+        &UInt64Array::from([Some(0)]) as &dyn Array
+    );
+    assert_eq!(
+        leaves[0].distinct_count.as_ref(),
+        &UInt64Array::from([Some(2)]) as &dyn Array
+    );
+    Ok(())
+}
+
+#[test]
+fn dict_statistics_repeated_values() -> Result<()> {
+    // e.g. after concatenating dictionaries
+    let values = Utf8Array::<i32>::from_slice(["a", "b", "a", "c", "b"]);
+    let keys = PrimitiveArray::from([Some(0u32), Some(1), Some(2), None, Some(4), Some(0)]);
+    let array = DictionaryArray::try_from_keys(keys, values.boxed())?;
+    let schema = Schema::from(vec![Field::new("a", array.data_type().clone(), true)]);
+
+    let data = integration_write(&schema, &[Chunk::new(vec![array.boxed()])])?;
+    let metadata = p_read::read_metadata(&mut Cursor::new(data))?;
+    let statistics = deserialize(&schema.fields[0], &metadata.row_groups)?;
+
+    // "c" is not referenced
+    assert_eq!(
+        statistics.distinct_count.as_ref(),
+        &UInt64Array::from([Some(2)]) as &dyn Array
+    );
+    Ok(())
+}
+
+#[test]
+fn unsigned() -> Result<()> {
+    let array1 = UInt8Array::from([Some(1), None, Some(u8::MAX)]);
+    let array2 = UInt16Array::from([Some(1), None, Some(u16::MAX)]);
+    let array3 = UInt32Array::from([Some(1), None, Some(u32::MAX)]);
+    let array4 = UInt64Array::from([Some(1), None, Some(u64::MAX)]);
+    let schema = Schema::from(vec![
+        Field::new("a1", DataType::UInt8, true),
+        Field::new("a2", DataType::UInt16, true),
+        Field::new("a3", DataType::UInt32, true),
+        Field::new("a4", DataType::UInt64, true),
+    ]);
+    let chunk = Chunk::try_new(vec![
+        array1.boxed(),
+        array2.boxed(),
+        array3.boxed(),
+        array4.boxed(),
+    ])?;
+    assert_roundtrip(schema.clone(), chunk.clone(), None)?;
+
+    let data = integration_write(&schema, &[chunk])?;
+    let metadata = p_read::read_metadata(&mut Cursor::new(data))?;
+
+    // the types are inferred from the parquet annotations, not only from the arrow schema
+    let fields = p_read::schema::parquet_to_arrow_schema(metadata.schema().fields());
+    assert_eq!(fields, schema.fields);
+
+    // statistics are ordered as unsigned integers
+    let statistics = deserialize(&schema.fields[2], &metadata.row_groups)?;
+    let typed = statistics.to_typed::<UInt32Array>()?;
+    assert_eq!(typed.min_value, UInt32Array::from_slice([1]));
+    assert_eq!(typed.max_value, UInt32Array::from_slice([u32::MAX]));
+
+    let statistics = deserialize(&schema.fields[3], &metadata.row_groups)?;
+    let typed = statistics.to_typed::<UInt64Array>()?;
+    assert_eq!(typed.min_value, UInt64Array::from_slice([1]));
+    assert_eq!(typed.max_value, UInt64Array::from_slice([u64::MAX]));
+    Ok(())
+}
+
+#[test]
+fn typed_statistics() -> Result<()> {
+    let array = Int32Array::from([Some(3), None, Some(1)]);
+    let schema = Schema::from(vec![Field::new("c1", DataType::Int32, true)]);
+    let chunk = Chunk::new(vec![array.boxed()]);
+
+    let data = integration_write(&schema, &[chunk])?;
+    let metadata = p_read::read_metadata(&mut Cursor::new(data))?;
+    let statistics = deserialize(&schema.fields[0], &metadata.row_groups)?;
+
+    let typed = statistics.to_typed::<Int32Array>()?;
+    assert_eq!(typed.null_count, UInt64Array::from([Some(1)]));
+    assert_eq!(typed.min_value, Int32Array::from_slice([1]));
+    assert_eq!(typed.max_value, Int32Array::from_slice([3]));
+
+    assert!(statistics.to_typed::<Int64Array>().is_err());
+    assert_eq!(statistics.leaves(), vec![statistics]);
+    Ok(())
+}
+
+#[test]
+fn file_summary() -> Result<()> {
+    let schema = Schema::from(vec![
+        Field::new("c1", DataType::Int32, true),
+        Field::new("c2", DataType::Utf8, false),
+    ]);
+    let chunks = [
+        Chunk::new(vec![
+            Int32Array::from([Some(3), None, Some(1)]).boxed(),
+            Utf8Array::<i32>::from_slice(["b", "a", "c"]).boxed(),
+        ]),
+        Chunk::new(vec![
+            Int32Array::from([None, None]).boxed(),
+            Utf8Array::<i32>::from_slice(["e", "d"]).boxed(),
+        ]),
+    ];
+
+    let data = integration_write(&schema, &chunks)?;
+    let metadata = p_read::read_metadata(&mut Cursor::new(data))?;
+    let summary = p_read::ParquetFileSummary::try_new(&metadata)?;
+
+    assert_eq!(summary.schema(), &schema);
+    assert_eq!(summary.num_row_groups(), 2);
+    assert_eq!(summary.num_rows(), &UInt64Array::from_slice([3, 2]));
+    assert_eq!(summary.total_byte_size().len(), 2);
+    assert_eq!(summary.fields_statistics().len(), 2);
+    assert!(summary.statistics("c3").is_none());
+
+    let c1 = summary.statistics("c1").unwrap().to_typed::<Int32Array>()?;
+    assert_eq!(c1.null_count, UInt64Array::from_slice([1, 2]));
+    assert_eq!(c1.min_value, Int32Array::from([Some(1), None]));
+    assert_eq!(c1.max_value, Int32Array::from([Some(3), None]));
+
+    let (summary_schema, chunk) = summary.to_chunk();
+    let names = summary_schema
+        .fields
+        .iter()
+        .map(|field| field.name.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        names,
+        [
+            "num_rows",
+            "total_byte_size",
+            "c1.null_count",
+            "c1.min",
+            "c1.max",
+            "c2.null_count",
+            "c2.min",
+            "c2.max"
+        ]
+    );
+    assert_eq!(chunk.len(), 2);
+    assert_eq!(
+        chunk.arrays()[6].as_ref(),
+        &Utf8Array::<i32>::from_slice(["a", "d"]) as &dyn Array
+    );
+    assert_eq!(
+        chunk.arrays()[7].as_ref(),
+        &Utf8Array::<i32>::from_slice(["c", "e"]) as &dyn Array
+    );
+    Ok(())
+}
+
+/// Reads the rows of the first row group of `data` selected by `selection`, in chunks of 3 rows.
+pub(crate) fn read_selected(
+    data: &[u8],
+    selection: &p_read::RowSelection,
+    limit: Option<usize>,
+) -> Result<Vec<Chunk<Box<dyn Array>>>> {
+    let mut reader = Cursor::new(data);
+    let metadata = p_read::read_metadata(&mut reader)?;
+    let schema = p_read::infer_schema(&metadata)?;
+    let row_group = &metadata.row_groups[0];
+
+    let columns = p_read::read_columns_many_with_selection(
+        &mut reader,
+        row_group,
+        schema.fields,
+        Some(3),
+        limit,
+        selection,
+    )?;
+    p_read::RowGroupDeserializer::new(columns, selection.selected_rows(), limit).collect()
+}
+
+#[test]
+fn row_selection() -> Result<()> {
+    use p_read::RowSelector;
+
+    let int = Int64Array::from_iter((0..10).map(|x| (x % 3 != 0).then_some(x)));
+    let mut list = MutableListArray::<i32, MutablePrimitiveArray<i64>>::new();
+    list.try_extend((0..10).map(|x| match x % 4 {
+        0 => None,
+        1 => Some(vec![]),
+        _ => Some(vec![Some(x), None, Some(x + 1)]),
+    }))?;
+    let list: ListArray<i32> = list.into();
+    let struct_ = StructArray::new(
+        DataType::Struct(vec![
+            Field::new("a", DataType::Int32, true),
+            Field::new("b", DataType::Utf8, true),
+        ]),
+        vec![
+            Int32Array::from_iter((0..10).map(Some)).boxed(),
+            Utf8Array::<i32>::from_iter((0..10).map(|x| Some("a".repeat(x)))).boxed(),
+        ],
+        None,
+    );
+
+    let schema = Schema::from(vec![
+        Field::new("int", int.data_type().clone(), true),
+        Field::new("list", list.data_type().clone(), true),
+        Field::new("struct", struct_.data_type().clone(), true),
+    ]);
+    let chunk = Chunk::new(vec![int.boxed(), list.boxed(), struct_.boxed()]);
+    let data = integration_write(&schema, std::slice::from_ref(&chunk))?;
+
+    let selection = p_read::RowSelection::from(vec![
+        RowSelector::skip(2),
+        RowSelector::select(3),
+        RowSelector::skip(1),
+        RowSelector::select(1),
+        RowSelector::skip(1),
+        RowSelector::select(2),
+    ]);
+
+    let select = |intervals: &[(usize, usize)]| {
+        let arrays = chunk
+            .arrays()
+            .iter()
+            .map(|array| {
+                let mut growable = growable::make_growable(&[array.as_ref()], false, 0);
+                intervals
+                    .iter()
+                    .for_each(|(start, length)| growable.extend(0, *start, *length));
+                growable.as_box()
+            })
+            .collect();
+        Chunk::new(arrays)
+    };
+
+    // chunks of 3 selected rows: [2, 3, 4], [6, 8, 9]
+    let result = read_selected(&data, &selection, None)?;
+    assert_eq!(result, vec![select(&[(2, 3)]), select(&[(6, 1), (8, 2)])]);
+
+    // the limit applies to the selected rows
+    let result = read_selected(&data, &selection, Some(4))?;
+    assert_eq!(result, vec![select(&[(2, 3)]), select(&[(6, 1)])]);
+
+    let result = read_selected(&data, &p_read::RowSelection::default(), None)?;
+    assert!(result.is_empty());
+    Ok(())
+}
+
+#[test]
+fn row_selection_invalid() {
+    use p_read::indexes::Interval;
+    use p_read::RowSelection;
+
+    let intervals = [Interval::new(2, 2), Interval::new(3, 1)];
+    assert!(RowSelection::from_intervals(&intervals, 10).is_err());
+    assert!(RowSelection::from_intervals(&[Interval::new(8, 3)], 10).is_err());
+    assert!(RowSelection::from_intervals(&[Interval::new(8, usize::MAX)], 10).is_err());
+    assert_eq!(
+        RowSelection::from_intervals(&[Interval::new(8, 2)], 10).unwrap(),
+        RowSelection::from(vec![
+            p_read::RowSelector::skip(8),
+            p_read::RowSelector::select(2)
+        ])
+    );
+}
+
 #[test]
 fn filter_chunk() -> Result<()> {
     let chunk1 = Chunk::new(vec![PrimitiveArray::from_slice([1i16, 3]).boxed()]);
@@ -1565,3 +1955,31 @@ fn filter_chunk() -> Result<()> {
     assert_eq!(new_chunks, vec![chunk1]);
     Ok(())
 }
+
+#[cfg(feature = "canonical_extensions")]
+#[test]
+fn canonical_extensions() -> Result<()> {
+    use arrow2::datatypes::canonical::{json, uuid};
+
+    let uuids = FixedSizeBinaryArray::new(uuid(), vec![1u8; 32].into(), None);
+    let jsons = Utf8Array::<i32>::from_slice(["{}", "[1]"]);
+    let jsons = Utf8Array::<i32>::new(
+        json(),
+        jsons.offsets().clone(),
+        jsons.values().clone(),
+        None,
+    );
+    let schema = Schema::from(vec![
+        Field::new("uuid", uuid(), true),
+        Field::new("json", json(), true),
+    ]);
+    let chunk = Chunk::try_new(vec![uuids.boxed(), jsons.boxed()])?;
+
+    let data = integration_write(&schema, std::slice::from_ref(&chunk))?;
+    let (new_schema, new_chunks) = integration_read(&data, None)?;
+
+    assert_eq!(new_schema.fields[0].data_type(), &uuid());
+    assert_eq!(new_schema.fields[1].data_type(), &json());
+    assert_eq!(new_chunks, vec![chunk]);
+    Ok(())
+}
@@ -0,0 +1,101 @@
+use std::io::Cursor;
+use std::sync::{Arc, Mutex};
+
+use arrow2::array::*;
+use arrow2::chunk::Chunk;
+use arrow2::datatypes::*;
+use arrow2::error::Result;
+use arrow2::io::parquet::read::*;
+use arrow2::io::parquet::write::*;
+
+fn write(chunk: Chunk<Box<dyn Array>>, schema: &Schema) -> Result<Vec<u8>> {
+    let options = WriteOptions {
+        write_statistics: false,
+        compression: CompressionOptions::Uncompressed,
+        version: Version::V1,
+        data_pagesize_limit: Some(1024),
+        int96_timestamps: false,
+    };
+    let encodings = vec![vec![Encoding::Plain], vec![Encoding::RleDictionary]];
+    let row_groups =
+        RowGroupIterator::try_new(vec![Ok(chunk)].into_iter(), schema, options, encodings)?;
+
+    let mut writer = FileWriter::try_new(Cursor::new(vec![]), schema.clone(), options)?;
+    for group in row_groups {
+        writer.write(group?)?;
+    }
+    writer.end(None)?;
+    Ok(writer.into_inner().into_inner())
+}
+
+#[test]
+fn read_with_progress() -> Result<()> {
+    let values = Int64Array::from_values(0..1000);
+    let keys = PrimitiveArray::<i32>::from_values((0..1000).map(|x| x % 3));
+    let dictionary = DictionaryArray::try_from_keys(
+        keys,
+        Utf8Array::<i32>::from_slice(["a", "b", "c"]).boxed(),
+    )?;
+    let schema = Schema::from(vec![
+        Field::new("values", values.data_type().clone(), false),
+        Field::new("dictionary", dictionary.data_type().clone(), false),
+    ]);
+    let data = write(
+        Chunk::new(vec![values.clone().boxed(), dictionary.clone().boxed()]),
+        &schema,
+    )?;
+
+    let mut reader = Cursor::new(data);
+    let metadata = read_metadata(&mut reader)?;
+    let row_group = &metadata.row_groups[0];
+
+    let mut arrays = vec![];
+    let mut progresses = vec![];
+    for field in schema.fields {
+        let progress = Arc::new(Mutex::new(vec![]));
+        let callback = {
+            let progress = progress.clone();
+            Arc::new(move |page: &PageProgress| progress.lock().unwrap().push(page.clone()))
+        };
+        let columns = read_columns(&mut reader, row_group.columns(), &field.name)?;
+        let iter = to_deserializer_with_progress(
+            columns,
+            field,
+            row_group.num_rows(),
+            None,
+            None,
+            callback,
+        )?;
+        arrays.extend(iter.collect::<Result<Vec<_>>>()?);
+        progresses.push(Arc::try_unwrap(progress).unwrap().into_inner().unwrap());
+    }
+    assert_eq!(arrays, vec![values.boxed(), dictionary.boxed()]);
+
+    // 8000 bytes of values in pages of at most 1024 bytes
+    let values = &progresses[0];
+    assert!(values.len() >= 8);
+    assert!(values
+        .iter()
+        .all(|page| !page.is_dictionary && page.column == 0));
+    assert_eq!(
+        values.iter().map(|page| page.num_values).sum::<usize>(),
+        1000
+    );
+    values.iter().enumerate().for_each(|(index, page)| {
+        assert_eq!(page.page, index);
+        assert_eq!(page.decode_elapsed.is_some(), index > 0);
+    });
+    let last = values.last().unwrap();
+    assert_eq!(last.total_values, 1000);
+    assert_eq!(
+        last.total_bytes,
+        values.iter().map(|page| page.num_bytes).sum::<usize>()
+    );
+
+    let dictionary = &progresses[1];
+    assert!(dictionary[0].is_dictionary);
+    assert_eq!(dictionary[0].num_values, 3);
+    assert!(dictionary[1..].iter().all(|page| !page.is_dictionary));
+    assert_eq!(dictionary.last().unwrap().total_values, 1000);
+    Ok(())
+}
@@ -28,6 +28,7 @@ fn pages(arrays: &[&dyn Array], encoding: Encoding) -> Result<(Vec<Page>, Vec<Pa
         compression: CompressionOptions::Uncompressed,
         version: Version::V1,
         data_pagesize_limit: None,
+        int96_timestamps: false,
     };
 
     let pages1 = [array11, array12, array13]
@@ -78,6 +79,7 @@ fn read_with_indexes(
         compression: CompressionOptions::Uncompressed,
         version: Version::V1,
         data_pagesize_limit: None,
+        int96_timestamps: false,
     };
 
     let to_compressed = |pages: Vec<Page>| {
@@ -291,3 +293,108 @@ fn indexed_dict() -> Result<()> {
 
     read_with_indexes(pages(&[&array], Encoding::RleDictionary)?, expected)
 }
+
+/// Returns the pages of `array`, the leaf of `column`, split at the rows `splits`
+fn split_pages(
+    array: &dyn Array,
+    column: &ColumnDescriptor,
+    is_struct: bool,
+    splits: &[usize],
+) -> Result<Vec<Page>> {
+    let options = WriteOptions {
+        write_statistics: true,
+        compression: CompressionOptions::Uncompressed,
+        version: Version::V1,
+        data_pagesize_limit: None,
+        int96_timestamps: false,
+    };
+    splits
+        .windows(2)
+        .map(|x| {
+            let array = array.sliced(x[0]..x[1]);
+            let mut nested = vec![Nested::Primitive(array.validity(), true, array.len())];
+            if is_struct {
+                nested.insert(0, Nested::Struct(None, true, array.len()));
+            }
+            let type_ = column.descriptor.primitive_type.clone();
+            array_to_page(array.as_ref(), type_, &nested, options, Encoding::Plain)
+        })
+        .collect()
+}
+
+#[test]
+fn row_selection_pages() -> Result<()> {
+    use crate::io::parquet::read_selected;
+
+    let int = Int64Array::from_iter((0..10).map(|x| (x % 3 != 0).then_some(x)));
+    let a = Int32Array::from_iter((0..10).map(Some));
+    let b = Utf8Array::<i32>::from_iter((0..10).map(|x| (x % 4 != 0).then(|| "a".repeat(x))));
+    let struct_ = StructArray::new(
+        DataType::Struct(vec![
+            Field::new("a", DataType::Int32, true),
+            Field::new("b", DataType::Utf8, true),
+        ]),
+        vec![a.clone().boxed(), b.clone().boxed()],
+        None,
+    );
+    let schema = Schema::from(vec![
+        Field::new("int", DataType::Int64, true),
+        Field::new("struct", struct_.data_type().clone(), true),
+    ]);
+    let parquet_schema = to_parquet_schema(&schema)?;
+    let types = parquet_schema.columns();
+
+    // the pages of each column start at different rows
+    let columns = vec![
+        split_pages(&int, &types[0], false, &[0, 4, 5, 10])?,
+        split_pages(&a, &types[1], true, &[0, 3, 7, 10])?,
+        split_pages(&b, &types[2], true, &[0, 3, 6, 10])?,
+    ];
+
+    let options = WriteOptions {
+        write_statistics: true,
+        compression: CompressionOptions::Uncompressed,
+        version: Version::V1,
+        data_pagesize_limit: None,
+        int96_timestamps: false,
+    };
+    let row_group = DynIter::new(columns.into_iter().map(|pages| {
+        let pages = DynIter::new(pages.into_iter().map(Ok));
+        let pages = Compressor::new(pages, options.compression, vec![]).map_err(Error::from);
+        Ok(DynStreamingIterator::new(pages))
+    }));
+    let mut writer = FileWriter::try_new(vec![], schema, options)?;
+    writer.write(row_group)?;
+    writer.end(None)?;
+    let data = writer.into_inner();
+
+    let chunk = Chunk::new(vec![int.boxed(), struct_.boxed()]);
+    let select = |rows: &[usize]| {
+        let arrays = chunk
+            .arrays()
+            .iter()
+            .map(|array| {
+                let mut growable = growable::make_growable(&[array.as_ref()], false, 0);
+                rows.iter().for_each(|row| growable.extend(0, *row, 1));
+                growable.as_box()
+            })
+            .collect();
+        Chunk::new(arrays)
+    };
+
+    // the struct decodes its first page only
+    let selection = RowSelection::from(vec![RowSelector::skip(1), RowSelector::select(1)]);
+    let result = read_selected(&data, &selection, None)?;
+    assert_eq!(result, vec![select(&[1])]);
+
+    // the struct decodes all its pages, as their boundaries differ between columns
+    let selection = RowSelection::from(vec![
+        RowSelector::skip(1),
+        RowSelector::select(1),
+        RowSelector::skip(6),
+        RowSelector::select(2),
+    ]);
+    let result = read_selected(&data, &selection, None)?;
+    assert_eq!(result, vec![select(&[1, 8, 9])]);
+    Ok(())
+}
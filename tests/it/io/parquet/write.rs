@@ -48,6 +48,7 @@ fn round_trip_opt_stats(
         compression,
         version,
         data_pagesize_limit: None,
+        int96_timestamps: false,
     };
 
     let iter = vec![Chunk::try_new(vec![array.clone()])];
@@ -585,3 +586,134 @@ fn struct_v2() -> Result<()> {
         vec![Encoding::Plain, Encoding::Plain],
     )
 }
+
+#[test]
+fn int96_timestamps() -> Result<()> {
+    // 3000-01-01 is outside of the range of nanoseconds in an `i64`
+    let array = Int64Array::from([Some(-1), None, Some(1_000), Some(32_503_680_000_000)])
+        .to(DataType::Timestamp(TimeUnit::Millisecond, None));
+    let schema = Schema::from(vec![Field::new("a1", array.data_type().clone(), true)]);
+
+    let options = WriteOptions {
+        write_statistics: false,
+        compression: CompressionOptions::Uncompressed,
+        version: Version::V1,
+        data_pagesize_limit: None,
+        int96_timestamps: true,
+    };
+
+    let iter = vec![Chunk::try_new(vec![array.clone().boxed()])];
+    let row_groups = RowGroupIterator::try_new(
+        iter.into_iter(),
+        &schema,
+        options,
+        vec![vec![Encoding::Plain]],
+    )?;
+
+    let writer = Cursor::new(vec![]);
+    let mut writer = FileWriter::try_new(writer, schema.clone(), options)?;
+    for group in row_groups {
+        writer.write(group?)?;
+    }
+    writer.end(None)?;
+    let data = writer.into_inner().into_inner();
+
+    let metadata = p_read::read_metadata(&mut Cursor::new(&data))?;
+    assert_eq!(
+        metadata.schema().columns()[0]
+            .descriptor
+            .primitive_type
+            .physical_type,
+        p_read::PhysicalType::Int96
+    );
+
+    // without the arrow schema, the unit is declared by the options
+    let options = p_read::SchemaInferenceOptions {
+        int96_coerce_to_timeunit: TimeUnit::Millisecond,
+    };
+    let fields = p_read::schema::parquet_to_arrow_schema_with_options(
+        metadata.schema().fields(),
+        &Some(options),
+    );
+    assert_eq!(fields, schema.fields);
+
+    let inferred = p_read::infer_schema(&metadata)?;
+    assert_eq!(inferred, schema);
+
+    let reader = p_read::FileReader::new(
+        Cursor::new(data),
+        metadata.row_groups,
+        inferred,
+        None,
+        None,
+        None,
+    );
+    let chunks = reader.collect::<Result<Vec<_>>>()?;
+    assert_eq!(chunks[0].arrays()[0].as_ref(), &array as &dyn Array);
+    Ok(())
+}
+
+#[test]
+fn int96_timestamps_overflow() -> Result<()> {
+    // 3000-01-01 is outside of the range of nanoseconds in an `i64`
+    let array = Int64Array::from([Some(-1), None, Some(1_000), Some(32_503_680_000_000)])
+        .to(DataType::Timestamp(TimeUnit::Millisecond, None));
+    let schema = Schema::from(vec![Field::new("a1", array.data_type().clone(), true)]);
+
+    let mut options = WriteOptions {
+        write_statistics: true,
+        compression: CompressionOptions::Uncompressed,
+        version: Version::V1,
+        data_pagesize_limit: None,
+        int96_timestamps: true,
+    };
+    assert!(FileWriter::try_new(Cursor::new(vec![]), schema.clone(), options).is_err());
+    options.write_statistics = false;
+
+    let iter = vec![Chunk::try_new(vec![array.boxed()])];
+    let row_groups = RowGroupIterator::try_new(
+        iter.into_iter(),
+        &schema,
+        options,
+        vec![vec![Encoding::Plain]],
+    )?;
+    let mut writer = FileWriter::try_new(Cursor::new(vec![]), schema, options)?;
+    for group in row_groups {
+        writer.write(group?)?;
+    }
+    writer.end(None)?;
+    let data = writer.into_inner().into_inner();
+
+    let metadata = p_read::read_metadata(&mut Cursor::new(&data))?;
+    let field = Field::new("a1", DataType::Timestamp(TimeUnit::Nanosecond, None), true);
+
+    let reader = p_read::FileReader::new(
+        Cursor::new(&data),
+        metadata.row_groups.clone(),
+        Schema::from(vec![field.clone()]),
+        None,
+        None,
+        None,
+    );
+    assert!(matches!(
+        reader.collect::<Result<Vec<_>>>(),
+        Err(arrow2::error::Error::Overflow)
+    ));
+
+    let column = &metadata.row_groups[0].columns()[0];
+    let pages = p_read::get_page_iterator(column, Cursor::new(&data), None, vec![], 1024 * 1024)?;
+    let pages = p_read::BasicDecompressor::new(pages, vec![]);
+    let arrays = p_read::column_iter_to_arrays_with_int96_overflow(
+        vec![pages],
+        vec![&column.descriptor().descriptor.primitive_type],
+        field,
+        None,
+        4,
+        p_read::Int96Overflow::Null,
+    )?
+    .collect::<Result<Vec<_>>>()?;
+    let expected = Int64Array::from([Some(-1_000_000), None, Some(1_000_000_000), None])
+        .to(DataType::Timestamp(TimeUnit::Nanosecond, None));
+    assert_eq!(arrays[0].as_ref(), &expected as &dyn Array);
+    Ok(())
+}
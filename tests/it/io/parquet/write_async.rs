@@ -32,6 +32,7 @@ async fn test_parquet_async_roundtrip() {
         compression: CompressionOptions::Uncompressed,
         version: Version::V2,
         data_pagesize_limit: None,
+        int96_timestamps: false,
     };
 
     let mut buffer = Cursor::new(Vec::new());
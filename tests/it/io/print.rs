@@ -379,3 +379,176 @@ fn write_union() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn write_timestamp_with_offset_tz() {
+    let expected = vec![
+        "+----------------------------+",
+        "| f                          |",
+        "+----------------------------+",
+        "| 1970-05-09 15:25:11 +01:00 |",
+        "|                            |",
+        "+----------------------------+",
+    ];
+    check_datetime!(
+        i64,
+        DataType::Timestamp(TimeUnit::Second, Some("+01:00".to_string())),
+        11111111,
+        expected
+    );
+}
+
+#[cfg(feature = "chrono-tz")]
+#[test]
+fn write_timestamp_with_named_tz() {
+    let expected = vec![
+        "+-------------------------+",
+        "| f                       |",
+        "+-------------------------+",
+        "| 1970-05-09 15:25:11 CET |",
+        "|                         |",
+        "+-------------------------+",
+    ];
+    check_datetime!(
+        i64,
+        DataType::Timestamp(TimeUnit::Second, Some("Europe/Berlin".to_string())),
+        11111111,
+        expected
+    );
+}
+
+#[test]
+fn write_map() -> Result<()> {
+    let fields = vec![
+        Field::new("key", DataType::Utf8, false),
+        Field::new("value", DataType::Int32, true),
+    ];
+    let field = StructArray::new(
+        DataType::Struct(fields.clone()),
+        vec![
+            Utf8Array::<i32>::from_slice(["a", "b", "c"]).boxed(),
+            Int32Array::from([Some(1), None, Some(3)]).boxed(),
+        ],
+        None,
+    );
+    let data_type = DataType::Map(
+        Box::new(Field::new("entries", DataType::Struct(fields), false)),
+        false,
+    );
+    let array = MapArray::try_new(
+        data_type,
+        vec![0, 2, 2, 3].try_into()?,
+        field.boxed(),
+        Some(Bitmap::from([true, false, true])),
+    )?;
+
+    let batch = Chunk::new(vec![&array as &dyn Array]);
+    let options = WriteOptions {
+        null: "null",
+        ..Default::default()
+    };
+    let table = write_with_options(&[batch], &["a"], &options);
+
+    let expected = vec![
+        "+-----------------+",
+        "| a               |",
+        "+-----------------+",
+        "| {a: 1, b: null} |",
+        "| null            |",
+        "| {c: 3}          |",
+        "+-----------------+",
+    ];
+
+    let actual: Vec<&str> = table.lines().collect();
+
+    assert_eq!(expected, actual, "Actual result:\n{table}");
+
+    Ok(())
+}
+
+#[test]
+fn write_list_of_structs() -> Result<()> {
+    let fields = vec![Field::new("a", DataType::Int32, true)];
+    let values = StructArray::new(
+        DataType::Struct(fields.clone()),
+        vec![Int32Array::from([Some(1), None, Some(3)]).boxed()],
+        None,
+    );
+    let data_type = ListArray::<i32>::default_datatype(DataType::Struct(fields));
+    let array = ListArray::<i32>::new(data_type, vec![0, 2, 3].try_into()?, values.boxed(), None);
+
+    let batch = Chunk::new(vec![&array as &dyn Array]);
+    let table = write(&[batch], &["a"]);
+
+    let expected = vec![
+        "+-----------------+",
+        "| a               |",
+        "+-----------------+",
+        "| [{a: 1}, {a: }] |",
+        "| [{a: 3}]        |",
+        "+-----------------+",
+    ];
+
+    let actual: Vec<&str> = table.lines().collect();
+
+    assert_eq!(expected, actual, "Actual result:\n{table}");
+
+    Ok(())
+}
+
+#[test]
+fn write_max_rows_and_width() -> Result<()> {
+    let a = Int32Array::from_slice([1, 2, 3]);
+    let b = Utf8Array::<i32>::from_slice(["a", "bbbbbb", "c"]);
+    let first = Chunk::try_new(vec![&a as &dyn Array, &b])?;
+    let a = Int32Array::from_slice([4, 5]);
+    let b = Utf8Array::<i32>::from_slice(["dddddd", "e"]);
+    let second = Chunk::try_new(vec![&a as &dyn Array, &b])?;
+
+    let options = WriteOptions {
+        max_rows: Some(3),
+        max_column_width: Some(4),
+        ..Default::default()
+    };
+    let table = write_with_options(&[first, second], &["a", "b"], &options);
+
+    let expected = vec![
+        "+---+------+",
+        "| a | b    |",
+        "+---+------+",
+        "| 1 | a    |",
+        "| 2 | bbb… |",
+        "| … | …    |",
+        "| 5 | e    |",
+        "+---+------+",
+    ];
+
+    let actual: Vec<&str> = table.lines().collect();
+
+    assert_eq!(expected, actual, "Actual result:\n{table}");
+
+    Ok(())
+}
+
+#[test]
+fn display_chunk() -> Result<()> {
+    let a = Int32Array::from_slice([1, 2]);
+    let b = Utf8Array::<i32>::from([Some("a"), None]);
+    let chunk = Chunk::try_new(vec![&a as &dyn Array, &b])?;
+
+    let expected = vec![
+        "+---+---+",
+        "| 0 | 1 |",
+        "+---+---+",
+        "| 1 | a |",
+        "| 2 |   |",
+        "+---+---+",
+    ];
+    let table = DisplayChunk::new(&chunk).to_string();
+    let actual: Vec<&str> = table.lines().collect();
+    assert_eq!(expected, actual, "Actual result:\n{table}");
+
+    let table = format!("{}", DisplayChunk::new(&chunk).with_names(&["a", "b"]));
+    assert_eq!(table, write(&[chunk], &["a", "b"]));
+    Ok(())
+}
@@ -0,0 +1,288 @@
+use std::collections::BTreeMap;
+
+use arrow2::array::*;
+use arrow2::chunk::Chunk;
+use arrow2::datatypes::TimeUnit;
+use arrow2::datatypes::{DataType, Field, IntegerType, Schema};
+use arrow2::error::Result;
+use arrow2::io::serde::{deserialize_rows, from_chunk, infer_schema, to_chunk};
+use serde_derive::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct Point {
+    x: f64,
+    y: f64,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+enum Kind {
+    A,
+    B,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct Row {
+    id: u32,
+    name: Option<String>,
+    kind: Kind,
+    point: Option<Point>,
+    values: Vec<i16>,
+    attributes: BTreeMap<String, bool>,
+}
+
+fn rows() -> Vec<Row> {
+    vec![
+        Row {
+            id: 1,
+            name: Some("a".to_string()),
+            kind: Kind::A,
+            point: Some(Point { x: 1.0, y: 2.0 }),
+            values: vec![1, 2],
+            attributes: [("c".to_string(), true)].into_iter().collect(),
+        },
+        Row {
+            id: 2,
+            name: None,
+            kind: Kind::B,
+            point: None,
+            values: vec![],
+            attributes: BTreeMap::new(),
+        },
+    ]
+}
+
+#[test]
+fn infer() -> Result<()> {
+    let point = DataType::Struct(vec![
+        Field::new("x", DataType::Float64, false),
+        Field::new("y", DataType::Float64, false),
+    ]);
+    let entries = DataType::Struct(vec![
+        Field::new("key", DataType::Utf8, false),
+        Field::new("value", DataType::Boolean, false),
+    ]);
+    let expected = Schema::from(vec![
+        Field::new("id", DataType::UInt32, false),
+        Field::new("name", DataType::Utf8, true),
+        Field::new("kind", DataType::Utf8, false),
+        Field::new("point", point, true),
+        Field::new(
+            "values",
+            DataType::List(Box::new(Field::new("item", DataType::Int16, false))),
+            false,
+        ),
+        Field::new(
+            "attributes",
+            DataType::Map(Box::new(Field::new("entries", entries, false)), false),
+            false,
+        ),
+    ]);
+    assert_eq!(infer_schema(&rows())?, expected);
+    assert_eq!(infer_schema::<Row>(&[])?, Schema::default());
+    assert!(infer_schema(&[1, 2]).is_err());
+    Ok(())
+}
+
+#[test]
+fn round_trip() -> Result<()> {
+    let rows = rows();
+    let schema = infer_schema(&rows)?;
+    let chunk = to_chunk(&rows, &schema.fields)?;
+    let arrays = chunk.arrays();
+
+    assert_eq!(UInt32Array::from_slice([1, 2]), arrays[0].as_ref());
+    assert_eq!(
+        Utf8Array::<i32>::from([Some("a"), None]),
+        arrays[1].as_ref()
+    );
+    assert_eq!(Utf8Array::<i32>::from_slice(["A", "B"]), arrays[2].as_ref());
+
+    let point = StructArray::new(
+        schema.fields[3].data_type().clone(),
+        vec![
+            Float64Array::from([Some(1.0), None]).boxed(),
+            Float64Array::from([Some(2.0), None]).boxed(),
+        ],
+        Some([true, false].into()),
+    );
+    assert_eq!(point, arrays[3].as_ref());
+
+    let values = ListArray::<i32>::new(
+        schema.fields[4].data_type().clone(),
+        vec![0, 2, 2].try_into().unwrap(),
+        Int16Array::from_slice([1, 2]).boxed(),
+        None,
+    );
+    assert_eq!(values, arrays[4].as_ref());
+
+    let attributes = arrays[5].as_any().downcast_ref::<MapArray>().unwrap();
+    assert_eq!(attributes.offsets().as_slice(), &[0, 1, 1]);
+    let entries = attributes
+        .field()
+        .as_any()
+        .downcast_ref::<StructArray>()
+        .unwrap();
+    assert_eq!(
+        Utf8Array::<i32>::from_slice(["c"]),
+        entries.values()[0].as_ref()
+    );
+    assert_eq!(
+        BooleanArray::from_slice([true]),
+        entries.values()[1].as_ref()
+    );
+    Ok(())
+}
+
+#[test]
+fn with_schema() -> Result<()> {
+    let fields = vec![
+        // integers are cast to the type of the field
+        Field::new("id", DataType::Int64, false),
+        Field::new(
+            "kind",
+            DataType::Dictionary(IntegerType::Int8, Box::new(DataType::LargeUtf8), false),
+            false,
+        ),
+        // fields missing from the rows are null
+        Field::new("other", DataType::Boolean, true),
+    ];
+    let chunk = to_chunk(&rows(), &fields)?;
+    let arrays = chunk.arrays();
+
+    assert_eq!(Int64Array::from_slice([1, 2]), arrays[0].as_ref());
+    let kind = arrays[1]
+        .as_any()
+        .downcast_ref::<DictionaryArray<i8>>()
+        .unwrap();
+    assert_eq!(kind.keys(), &Int8Array::from_slice([0, 1]));
+    assert_eq!(
+        Utf8Array::<i64>::from_slice(["A", "B"]),
+        kind.values().as_ref()
+    );
+    assert_eq!(BooleanArray::from([None, None]), arrays[2].as_ref());
+    Ok(())
+}
+
+#[test]
+fn errors() {
+    let rows = rows();
+    // a string to an integer
+    let fields = vec![Field::new("name", DataType::Int32, true)];
+    assert!(to_chunk(&rows, &fields).is_err());
+    // a number that does not fit
+    let fields = vec![Field::new("id", DataType::Int8, false)];
+    assert!(to_chunk(&[Point { x: 1.0, y: 0.0 }], &fields).is_ok());
+    let fields = vec![Field::new("x", DataType::Int8, false)];
+    assert!(to_chunk(&[Point { x: 1.0, y: 0.0 }], &fields).is_err());
+    // a sequence of the wrong size
+    let fields = vec![Field::new(
+        "values",
+        DataType::FixedSizeList(Box::new(Field::new("item", DataType::Int16, false)), 3),
+        false,
+    )];
+    assert!(to_chunk(&rows, &fields).is_err());
+    // unions are not supported
+    let fields = vec![Field::new(
+        "id",
+        DataType::Union(
+            vec![Field::new("a", DataType::Int32, true)],
+            None,
+            arrow2::datatypes::UnionMode::Dense,
+        ),
+        false,
+    )];
+    assert!(to_chunk(&rows, &fields).is_err());
+}
+
+#[test]
+fn deserialize_round_trip() -> Result<()> {
+    let rows = rows();
+    let schema = infer_schema(&rows)?;
+    let chunk = to_chunk(&rows, &schema.fields)?;
+    assert_eq!(from_chunk::<Row, _>(&chunk, &schema.fields)?, rows);
+    Ok(())
+}
+
+#[test]
+fn deserialize() -> Result<()> {
+    use chrono::TimeZone;
+
+    #[derive(Deserialize, Debug, PartialEq)]
+    struct Record<'a> {
+        name: &'a str,
+        kind: Option<Kind>,
+        data: Vec<u8>,
+        timestamp: chrono::NaiveDateTime,
+        utc: chrono::DateTime<chrono::Utc>,
+        date: chrono::NaiveDate,
+        raw_date: i32,
+    }
+
+    let kind = DictionaryArray::try_from_keys(
+        Int8Array::from([Some(1), None]),
+        Utf8Array::<i32>::from_slice(["A", "B"]).boxed(),
+    )?;
+    let dates = Int32Array::from_slice([0, 1]).to(DataType::Date32);
+    let chunk = Chunk::new(vec![
+        Utf8Array::<i64>::from_slice(["a", "b"]).boxed(),
+        kind.boxed(),
+        BinaryArray::<i32>::from_slice([b"ab".as_ref(), b""]).boxed(),
+        Int64Array::from_slice([0, 1_000])
+            .to(DataType::Timestamp(TimeUnit::Millisecond, None))
+            .boxed(),
+        Int64Array::from_slice([0, 1])
+            .to(DataType::Timestamp(
+                TimeUnit::Second,
+                Some("+01:00".to_string()),
+            ))
+            .boxed(),
+        dates.clone().boxed(),
+        dates.boxed(),
+    ]);
+    let fields = [
+        "name",
+        "kind",
+        "data",
+        "timestamp",
+        "utc",
+        "date",
+        "raw_date",
+    ]
+    .iter()
+    .zip(chunk.arrays())
+    .map(|(name, array)| Field::new(*name, array.data_type().clone(), true))
+    .collect::<Vec<_>>();
+
+    let records = from_chunk::<Record, _>(&chunk, &fields)?;
+    let epoch = chrono::NaiveDate::from_ymd_opt(1970, 1, 1).unwrap();
+    let midnight = epoch.and_hms_opt(0, 0, 0).unwrap();
+    assert_eq!(
+        records,
+        vec![
+            Record {
+                name: "a",
+                kind: Some(Kind::B),
+                data: b"ab".to_vec(),
+                timestamp: midnight,
+                utc: chrono::Utc.from_utc_datetime(&midnight),
+                date: epoch,
+                raw_date: 0,
+            },
+            Record {
+                name: "b",
+                kind: None,
+                data: vec![],
+                timestamp: epoch.and_hms_opt(0, 0, 1).unwrap(),
+                utc: chrono::Utc.from_utc_datetime(&epoch.and_hms_opt(0, 0, 1).unwrap()),
+                date: epoch.succ_opt().unwrap(),
+                raw_date: 1,
+            },
+        ]
+    );
+
+    // rows are deserialized lazily
+    let mut iter = deserialize_rows::<Point, _>(&chunk, &fields)?;
+    assert!(iter.next().unwrap().is_err());
+    assert!(deserialize_rows::<Point, _>(&chunk, &fields[..1]).is_err());
+    Ok(())
+}
@@ -0,0 +1,238 @@
+use std::io::{Cursor, SeekFrom};
+use std::ops::Range;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use arrow2::array::*;
+use arrow2::chunk::Chunk;
+use arrow2::datatypes::{DataType, Field, Schema};
+use arrow2::error::Result;
+use arrow2::io::util::{coalesce_ranges, RangedAsyncReader, RangedReaderOptions};
+use futures::{AsyncReadExt, AsyncSeekExt, FutureExt};
+
+fn ranged_reader(data: Vec<u8>) -> RangedAsyncReader {
+    let data = Arc::new(data);
+    RangedAsyncReader::new(data.len() as u64, move |range: Range<u64>| {
+        let data = data.clone();
+        async move { Ok(data[range.start as usize..range.end as usize].to_vec()) }.boxed()
+    })
+}
+
+#[test]
+fn coalesce() {
+    assert_eq!(coalesce_ranges(&[], 10), vec![]);
+    assert_eq!(coalesce_ranges(&[0..10, 5..7, 10..12], 0), vec![0..12]);
+    assert_eq!(coalesce_ranges(&[20..30, 0..10], 9), vec![0..10, 20..30]);
+    assert_eq!(coalesce_ranges(&[20..30, 0..10], 10), vec![0..30]);
+}
+
+#[tokio::test]
+async fn reads_are_buffered() {
+    let data = (0..=255u8).collect::<Vec<_>>();
+    let mut reader = ranged_reader(data.clone()).with_options(RangedReaderOptions {
+        min_request_size: 100,
+        ..Default::default()
+    });
+
+    let mut buffer = [0; 10];
+    for i in 0..10 {
+        reader.read_exact(&mut buffer).await.unwrap();
+        assert_eq!(buffer.as_ref(), &data[i * 10..(i + 1) * 10]);
+    }
+    assert_eq!(reader.num_requests(), 1);
+
+    reader.seek(SeekFrom::End(-6)).await.unwrap();
+    let mut end = vec![];
+    reader.read_to_end(&mut end).await.unwrap();
+    assert_eq!(end, &data[250..]);
+    assert_eq!(reader.num_requests(), 2);
+
+    assert!(reader.seek(SeekFrom::Current(-1000)).await.is_err());
+}
+
+#[tokio::test]
+async fn prefetch_is_shared_and_retried() {
+    let data = (0..=255u8).collect::<Vec<_>>();
+    let failures = Arc::new(AtomicUsize::new(2));
+    let fetch = {
+        let failures = failures.clone();
+        move |range: Range<u64>| {
+            let result = if failures.load(Ordering::Relaxed) > 0 {
+                failures.fetch_sub(1, Ordering::Relaxed);
+                Err(std::io::ErrorKind::TimedOut.into())
+            } else {
+                Ok(data[range.start as usize..range.end as usize].to_vec())
+            };
+            futures::future::ready(result).boxed()
+        }
+    };
+    let sleeps = Arc::new(Mutex::new(vec![]));
+    let reader = RangedAsyncReader::new(256, fetch)
+        .with_options(RangedReaderOptions {
+            max_gap: 4,
+            max_retries: 2,
+            initial_backoff: Duration::from_millis(10),
+            ..Default::default()
+        })
+        .with_sleep({
+            let sleeps = sleeps.clone();
+            move |duration| {
+                sleeps.lock().unwrap().push(duration);
+                futures::future::ready(()).boxed()
+            }
+        });
+
+    reader.prefetch(&[10..20, 22..30, 100..300]).await.unwrap();
+    // 2 failures and 2 coalesced ranges
+    assert_eq!(reader.num_requests(), 4);
+    assert_eq!(sleeps.lock().unwrap().len(), 2);
+
+    // already prefetched
+    reader
+        .prefetch(std::slice::from_ref(&(12..20)))
+        .await
+        .unwrap();
+    assert_eq!(reader.num_requests(), 4);
+
+    let mut clone = reader.clone();
+    clone.seek(SeekFrom::Start(18)).await.unwrap();
+    let mut buffer = [0; 8];
+    clone.read_exact(&mut buffer).await.unwrap();
+    assert_eq!(buffer, [18, 19, 20, 21, 22, 23, 24, 25]);
+    assert_eq!(reader.num_requests(), 4);
+
+    sleeps.lock().unwrap().clear();
+    failures.store(3, Ordering::Relaxed);
+    assert!(reader
+        .prefetch(std::slice::from_ref(&(40..50)))
+        .await
+        .is_err());
+    // the backoff doubles on every retry
+    assert_eq!(
+        *sleeps.lock().unwrap(),
+        vec![Duration::from_millis(10), Duration::from_millis(20)]
+    );
+}
+
+#[tokio::test]
+async fn non_transient_errors_are_not_retried() {
+    let reader = RangedAsyncReader::new(256, |_| {
+        futures::future::ready(Err(std::io::Error::other("forbidden"))).boxed()
+    });
+
+    assert!(reader
+        .prefetch(std::slice::from_ref(&(10..20)))
+        .await
+        .is_err());
+    assert_eq!(reader.num_requests(), 1);
+}
+
+#[tokio::test]
+async fn parquet_projection() -> Result<()> {
+    use arrow2::io::parquet::{read, write};
+
+    let schema = Schema::from(vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+        Field::new("c", DataType::Int64, true),
+    ]);
+    let chunk = Chunk::new(vec![
+        Int32Array::from([Some(1), None, Some(3)]).boxed(),
+        Utf8Array::<i32>::from([Some("a"), Some("bb"), None]).boxed(),
+        Int64Array::from_slice([4, 5, 6]).boxed(),
+    ]);
+    let options = write::WriteOptions {
+        write_statistics: true,
+        compression: write::CompressionOptions::Uncompressed,
+        version: write::Version::V2,
+        data_pagesize_limit: None,
+        int96_timestamps: false,
+    };
+    let encodings = schema
+        .fields
+        .iter()
+        .map(|field| write::transverse(&field.data_type, |_| write::Encoding::Plain))
+        .collect();
+    let row_groups = write::RowGroupIterator::try_new(
+        vec![Ok(chunk.clone()), Ok(chunk.clone())].into_iter(),
+        &schema,
+        options,
+        encodings,
+    )?;
+    let mut writer = write::FileWriter::try_new(Cursor::new(vec![]), schema.clone(), options)?;
+    for group in row_groups {
+        writer.write(group?)?;
+    }
+    writer.end(None)?;
+    let data = writer.into_inner().into_inner();
+
+    let mut reader = ranged_reader(data);
+    let metadata = read::read_metadata_async(&mut reader).await?;
+    let fields = vec![schema.fields[0].clone(), schema.fields[2].clone()];
+
+    let ranges = metadata
+        .row_groups
+        .iter()
+        .flat_map(|group| read::column_chunk_ranges(group, &fields))
+        .collect::<Vec<_>>();
+    assert_eq!(ranges.len(), 4);
+    reader.prefetch(&ranges).await?;
+    let requests = reader.num_requests();
+
+    let factory = || futures::future::ready(Ok(reader.clone())).boxed();
+    for group in &metadata.row_groups {
+        let columns =
+            read::read_columns_many_async(factory, group, fields.clone(), None, None, None).await?;
+        let chunks = read::RowGroupDeserializer::new(columns, group.num_rows(), None)
+            .collect::<Result<Vec<_>>>()?;
+        assert_eq!(
+            chunks,
+            vec![Chunk::new(vec![
+                chunk.arrays()[0].clone(),
+                chunk.arrays()[2].clone()
+            ])]
+        );
+    }
+    // all column chunks were prefetched
+    assert_eq!(reader.num_requests(), requests);
+    Ok(())
+}
+
+#[cfg(feature = "io_ipc_read_async")]
+#[tokio::test]
+async fn ipc_blocks() -> Result<()> {
+    use arrow2::io::ipc::{read, write};
+    use futures::TryStreamExt;
+
+    let schema = Schema::from(vec![Field::new("a", DataType::Int32, true)]);
+    let chunk = Chunk::new(vec![Int32Array::from([Some(1), None, Some(3)]).boxed()]);
+    let mut writer =
+        write::FileWriter::try_new(vec![], schema, None, write::WriteOptions::default())?;
+    writer.write(&chunk, None)?;
+    writer.write(&chunk, None)?;
+    writer.finish()?;
+    let data = writer.into_inner();
+
+    let mut reader = ranged_reader(data).with_options(RangedReaderOptions {
+        min_request_size: 8,
+        ..Default::default()
+    });
+    let metadata = read::file_async::read_file_metadata_async(&mut reader).await?;
+    assert_eq!(metadata.block_ranges(Some(&[1]))?.len(), 1);
+
+    reader.prefetch(&metadata.block_ranges(None)?).await?;
+    let requests = reader.num_requests();
+
+    let chunks = read::file_async::FileStream::new(reader.clone(), metadata, None, None)
+        .try_collect::<Vec<_>>()
+        .await?;
+    assert_eq!(chunks, vec![chunk.clone(), chunk]);
+    assert_eq!(reader.num_requests(), requests);
+
+    let mut metadata = read::file_async::read_file_metadata_async(&mut reader).await?;
+    metadata.blocks[0].offset = i64::MAX;
+    metadata.blocks[0].body_length = i64::MAX;
+    assert!(metadata.block_ranges(None).is_err());
+    Ok(())
+}
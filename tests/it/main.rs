@@ -4,10 +4,15 @@
 mod array;
 mod bitmap;
 mod buffer;
+mod chunk;
+mod datatypes;
+mod error;
 mod ffi;
+mod record_batch;
 mod scalar;
 mod temporal_conversions;
 mod types;
+mod util;
 
 mod io;
 mod test_util;
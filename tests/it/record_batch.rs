@@ -0,0 +1,96 @@
+use std::sync::Arc;
+
+use arrow2::array::*;
+use arrow2::chunk::Chunk;
+use arrow2::datatypes::{DataType, Field, Schema};
+use arrow2::record_batch::{with_schema, RecordBatch};
+
+fn schema() -> Arc<Schema> {
+    Arc::new(Schema::from(vec![
+        Field::new("a", DataType::Int32, false),
+        Field::new("b", DataType::Utf8, true),
+    ]))
+}
+
+fn chunk() -> Chunk<Box<dyn Array>> {
+    Chunk::new(vec![
+        Int32Array::from_slice([1, 2]).boxed(),
+        Utf8Array::<i32>::from([Some("x"), None]).boxed(),
+    ])
+}
+
+#[test]
+fn basics() {
+    let batch = RecordBatch::try_new(schema(), chunk()).unwrap();
+    assert_eq!(batch.len(), 2);
+    assert_eq!(batch.columns().len(), 2);
+    assert_eq!(batch.index_of("b"), Some(1));
+    assert_eq!(
+        batch.column_by_name("a").unwrap().as_ref(),
+        &Int32Array::from_slice([1, 2]) as &dyn Array
+    );
+    assert!(batch.column_by_name("c").is_none());
+
+    let (schema, chunk) = batch.into_parts();
+    assert_eq!(schema.fields.len(), 2);
+    assert_eq!(chunk.len(), 2);
+}
+
+#[test]
+fn try_new_invalid() {
+    // wrong number of arrays
+    let chunk = Chunk::new(vec![Int32Array::from_slice([1]).boxed()]);
+    assert!(RecordBatch::try_new(schema(), chunk).is_err());
+
+    // wrong data type
+    let chunk = Chunk::new(vec![
+        Int64Array::from_slice([1]).boxed(),
+        Utf8Array::<i32>::from_slice(["x"]).boxed(),
+    ]);
+    assert!(RecordBatch::try_new(schema(), chunk).is_err());
+
+    // nulls in a non-nullable field
+    let chunk = Chunk::new(vec![
+        Int32Array::from([None]).boxed(),
+        Utf8Array::<i32>::from_slice(["x"]).boxed(),
+    ]);
+    assert!(RecordBatch::try_new(schema(), chunk).is_err());
+}
+
+#[test]
+fn project() {
+    let batch = RecordBatch::new(schema(), chunk());
+
+    let projected = batch.project(&[1, 0]).unwrap();
+    assert_eq!(projected.schema().fields[0].name, "b");
+    assert_eq!(projected.schema().fields[1].name, "a");
+    assert_eq!(projected.columns()[1], batch.columns()[0]);
+
+    assert!(batch.project(&[2]).is_err());
+}
+
+#[test]
+fn rename() {
+    let batch = RecordBatch::new(schema(), chunk());
+
+    let renamed = batch.clone().rename("a", "id").unwrap();
+    assert_eq!(renamed.index_of("id"), Some(0));
+    assert_eq!(renamed.index_of("a"), None);
+    assert_eq!(renamed.chunk(), batch.chunk());
+    // the original schema is not modified
+    assert_eq!(batch.index_of("a"), Some(0));
+
+    assert!(batch.rename("c", "d").is_err());
+}
+
+#[test]
+fn from_chunks() {
+    let batches = with_schema(schema(), vec![Ok(chunk()), Ok(chunk())])
+        .collect::<arrow2::error::Result<Vec<_>>>()
+        .unwrap();
+    assert_eq!(batches.len(), 2);
+
+    let invalid = Chunk::new(vec![Int32Array::from_slice([1]).boxed()]);
+    let mut batches = with_schema(schema(), vec![Ok(invalid)]);
+    assert!(batches.next().unwrap().is_err());
+}
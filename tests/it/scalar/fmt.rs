@@ -0,0 +1,125 @@
+use arrow2::{
+    array::*,
+    datatypes::{DataType, Field, IntervalUnit, TimeUnit, UnionMode},
+    scalar::*,
+    types::months_days_ns,
+};
+
+#[test]
+fn primitive() {
+    let scalar = PrimitiveScalar::<i32>::from(Some(1));
+    assert_eq!(scalar.to_string(), "1");
+
+    let scalar = PrimitiveScalar::<i32>::from(None);
+    assert_eq!(scalar.to_string(), "null");
+
+    let scalar = PrimitiveScalar::new(DataType::Decimal(10, 2), Some(12345i128));
+    assert_eq!(scalar.to_string(), "123.45");
+
+    let scalar = PrimitiveScalar::new(
+        DataType::Timestamp(TimeUnit::Second, Some("+02:00".to_string())),
+        Some(0i64),
+    );
+    assert_eq!(scalar.to_string(), "1970-01-01 02:00:00 +02:00");
+
+    let scalar = PrimitiveScalar::new(
+        DataType::Interval(IntervalUnit::MonthDayNano),
+        Some(months_days_ns::new(1, 2, 3)),
+    );
+    assert_eq!(scalar.to_string(), "1m2d3ns");
+}
+
+#[test]
+fn binary_and_utf8() {
+    assert_eq!(Utf8Scalar::<i32>::from(Some("a")).to_string(), "a");
+    assert_eq!(Utf8Scalar::<i64>::from(None::<&str>).to_string(), "null");
+    assert_eq!(
+        BinaryScalar::<i32>::from(Some(b"ab")).to_string(),
+        "[97, 98]"
+    );
+    assert_eq!(BooleanScalar::from(Some(true)).to_string(), "true");
+    assert_eq!(NullScalar::new().to_string(), "null");
+    assert_eq!(
+        FixedSizeBinaryScalar::new(DataType::FixedSizeBinary(1), Some(vec![1u8])).to_string(),
+        "[1]"
+    );
+}
+
+#[test]
+fn nested() {
+    let data_type = DataType::List(Box::new(Field::new("item", DataType::Int32, true)));
+    let scalar = ListScalar::<i32>::new(
+        data_type.clone(),
+        Some(Int32Array::from([Some(1), None]).boxed()),
+    );
+    assert_eq!(scalar.to_string(), "[1, null]");
+    assert_eq!(ListScalar::<i32>::new(data_type, None).to_string(), "null");
+
+    let data_type = DataType::FixedSizeList(Box::new(Field::new("item", DataType::Int32, true)), 2);
+    let scalar = FixedSizeListScalar::new(data_type, Some(Int32Array::from_slice([1, 2]).boxed()));
+    assert_eq!(scalar.to_string(), "[1, 2]");
+
+    let fields = vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+    ];
+    let scalar = StructScalar::new(
+        DataType::Struct(fields.clone()),
+        Some(vec![
+            Box::new(PrimitiveScalar::<i32>::from(Some(1))) as Box<dyn Scalar>,
+            Box::new(Utf8Scalar::<i32>::from(None::<&str>)),
+        ]),
+    );
+    assert_eq!(scalar.to_string(), "{a: 1, b: null}");
+    assert_eq!(
+        StructScalar::new(DataType::Struct(fields.clone()), None).to_string(),
+        "null"
+    );
+
+    let entries = StructArray::new(
+        DataType::Struct(fields.clone()),
+        vec![
+            Int32Array::from_slice([1]).boxed(),
+            Utf8Array::<i32>::from_slice(["x"]).boxed(),
+        ],
+        None,
+    );
+    let data_type = DataType::Map(
+        Box::new(Field::new(
+            "entries",
+            DataType::Struct(fields.clone()),
+            false,
+        )),
+        false,
+    );
+    let scalar = MapScalar::new(data_type, Some(entries.boxed()));
+    assert_eq!(scalar.to_string(), "{1: x}");
+
+    for mode in [UnionMode::Sparse, UnionMode::Dense] {
+        let data_type = DataType::Union(fields.clone(), Some(vec![3, 5]), mode);
+        let scalar = UnionScalar::new(data_type, 5, Box::new(Utf8Scalar::<i32>::from(Some("u"))));
+        assert_eq!(scalar.to_string(), "u");
+    }
+
+    let data_type = DataType::Dictionary(
+        arrow2::datatypes::IntegerType::Int32,
+        Box::new(DataType::Utf8),
+        false,
+    );
+    let scalar = DictionaryScalar::<i32>::new(
+        data_type.clone(),
+        Some(Box::new(Utf8Scalar::<i32>::from(Some("d")))),
+    );
+    assert_eq!(scalar.to_string(), "d");
+    assert_eq!(
+        DictionaryScalar::<i32>::new(data_type, None).to_string(),
+        "null"
+    );
+}
+
+#[test]
+fn dyn_scalar() {
+    let array = Int32Array::from([Some(1), None]);
+    assert_eq!(array.get(0).to_string(), "1");
+    assert_eq!(array.get(1).to_string(), "null");
+}
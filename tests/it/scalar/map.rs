@@ -0,0 +1,55 @@
+use arrow2::{
+    array::{Array, BooleanArray, StructArray, Utf8Array},
+    datatypes::{DataType, Field},
+    scalar::{MapScalar, Scalar},
+};
+
+fn entries(values: [bool; 2]) -> StructArray {
+    let fields = vec![
+        Field::new("key", DataType::Utf8, false),
+        Field::new("value", DataType::Boolean, true),
+    ];
+    StructArray::new(
+        DataType::Struct(fields),
+        vec![
+            Utf8Array::<i32>::from_slice(["a", "b"]).boxed(),
+            BooleanArray::from_slice(values).boxed(),
+        ],
+        None,
+    )
+}
+
+fn data_type() -> DataType {
+    DataType::Map(
+        Box::new(Field::new(
+            "entries",
+            entries([true, true]).data_type().clone(),
+            false,
+        )),
+        false,
+    )
+}
+
+#[allow(clippy::eq_op)]
+#[test]
+fn equal() {
+    let a = MapScalar::new(data_type(), Some(entries([true, false]).boxed()));
+    let b = MapScalar::new(data_type(), None);
+    assert_eq!(a, a);
+    assert_eq!(b, b);
+    assert!(a != b);
+    let b = MapScalar::new(data_type(), Some(entries([true, true]).boxed()));
+    assert!(a != b);
+    assert_eq!(b, b);
+}
+
+#[test]
+fn basics() {
+    let a = MapScalar::new(data_type(), Some(entries([true, false]).boxed()));
+
+    assert_eq!(entries([true, false]), a.values().as_ref());
+    assert_eq!(a.data_type(), &data_type());
+    assert!(a.is_valid());
+
+    let _: &dyn std::any::Any = a.as_any();
+}
@@ -2,7 +2,9 @@ mod binary;
 mod boolean;
 mod fixed_size_binary;
 mod fixed_size_list;
+mod fmt;
 mod list;
+mod map;
 mod null;
 mod primitive;
 mod struct_;
@@ -1,4 +1,4 @@
-use arrow2::types::{days_ms, months_days_ns, BitChunkIter, BitChunkOnes, NativeType};
+use arrow2::types::{days_ms, f16, months_days_ns, BitChunkIter, BitChunkOnes, NativeType};
 
 #[test]
 fn test_basic1() {
@@ -38,3 +38,175 @@ fn days_ms_roundtrip() {
     let a = days_ms(1, 2);
     assert_eq!(a, days_ms::from_be_bytes(a.to_be_bytes()));
 }
+
+#[test]
+fn f16_to_f32() {
+    assert_eq!(f16::from_bits(0x3C00).to_f32(), 1.0);
+    assert_eq!(f16::from_bits(0xC000).to_f32(), -2.0);
+    assert_eq!(f16::from_bits(0x7BFF).to_f32(), 65504.0);
+    // smallest subnormal
+    assert_eq!(f16::from_bits(0x0001).to_f32(), 2.0f32.powi(-24));
+    assert_eq!(
+        f16::from_bits(0x8000).to_f32().to_bits(),
+        (-0.0f32).to_bits()
+    );
+    assert_eq!(f16::from_bits(0xFC00).to_f32(), f32::NEG_INFINITY);
+    assert_eq!(f16::from_bits(0x7C01).to_f32().to_bits(), 0x7FC0_2000);
+
+    // every value round-trips
+    for bits in 0..=u16::MAX {
+        let value = f16::from_bits(bits);
+        let result = f16::from_f32(value.to_f32());
+        if value.to_f32().is_nan() {
+            assert!(result.to_f32().is_nan());
+        } else {
+            assert_eq!(result.to_bits(), bits);
+        }
+    }
+}
+
+#[test]
+fn f16_arithmetics() {
+    let one = f16::from_f32(1.0);
+    let two = f16::from_f32(2.0);
+    assert_eq!(one + two, f16::from_f32(3.0));
+    assert_eq!(one - two, f16::from_f32(-1.0));
+    assert_eq!(two * two, f16::from_f32(4.0));
+    assert_eq!(one / two, f16::from_f32(0.5));
+    assert_eq!(-one, f16::from_f32(-1.0));
+    assert!(one < two);
+    // rounds to the nearest `f16`
+    assert_eq!((one + f16::EPSILON / two).to_f32(), 1.0);
+}
+
+mod i256 {
+    use arrow2::types::{i256, NativeType};
+    use num_bigint::BigInt;
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    fn to_big(value: i256) -> BigInt {
+        BigInt::from_signed_bytes_be(&value.to_be_bytes())
+    }
+
+    /// Returns `value` as an [`i256`], or `None` if it does not fit
+    fn from_big(value: &BigInt) -> Option<i256> {
+        let bytes = value.to_signed_bytes_be();
+        if bytes.len() > 32 {
+            return None;
+        }
+        let fill = if value.sign() == num_bigint::Sign::Minus {
+            0xff
+        } else {
+            0
+        };
+        let mut padded = [fill; 32];
+        padded[32 - bytes.len()..].copy_from_slice(&bytes);
+        Some(i256::from_be_bytes(padded))
+    }
+
+    fn values() -> Vec<i256> {
+        let mut rng = StdRng::seed_from_u64(42);
+        let mut values = vec![
+            i256::MIN,
+            i256::MAX,
+            i256::from(0),
+            i256::from(1),
+            i256::from(-1),
+            i256::from(i128::MIN),
+            i256::from(i128::MAX),
+        ];
+        values.extend((0..50).map(|_| i256::from_words(rng.gen(), rng.gen())));
+        values.extend((0..50).map(|_| i256::from(rng.gen::<i64>() as i128)));
+        values
+    }
+
+    #[test]
+    fn bytes() {
+        let value = i256::from_words(1, 2);
+        let mut expected = [0u8; 32];
+        expected[15] = 1;
+        expected[31] = 2;
+        assert_eq!(value.to_be_bytes(), expected);
+        expected.reverse();
+        assert_eq!(value.to_le_bytes(), expected);
+
+        for value in values() {
+            assert_eq!(i256::from_be_bytes(value.to_be_bytes()), value);
+            assert_eq!(i256::from_le_bytes(value.to_le_bytes()), value);
+            assert_eq!(from_big(&to_big(value)), Some(value));
+        }
+    }
+
+    #[test]
+    fn strings() {
+        for value in values() {
+            let string = value.to_string();
+            assert_eq!(string, to_big(value).to_string());
+            assert_eq!(string.parse::<i256>().unwrap(), value);
+            let hex = to_big(value).to_str_radix(16);
+            assert_eq!(i256::from_str_radix(&hex, 16).unwrap(), value);
+        }
+        assert!("".parse::<i256>().is_err());
+        assert!("1a".parse::<i256>().is_err());
+        let too_large = (to_big(i256::MAX) + BigInt::from(1)).to_string();
+        assert!(too_large.parse::<i256>().is_err());
+    }
+
+    #[test]
+    fn arithmetics() {
+        let values = values();
+        for &lhs in &values {
+            for &rhs in &values {
+                let (l, r) = (to_big(lhs), to_big(rhs));
+                assert_eq!(lhs.checked_add(rhs), from_big(&(&l + &r)));
+                assert_eq!(lhs.checked_sub(rhs), from_big(&(&l - &r)));
+                assert_eq!(lhs.checked_mul(rhs), from_big(&(&l * &r)));
+                if rhs == i256::from(0) {
+                    assert_eq!(lhs.checked_div(rhs), None);
+                    assert_eq!(lhs.checked_rem(rhs), None);
+                } else {
+                    // both truncate towards zero
+                    assert_eq!(lhs.checked_div(rhs), from_big(&(&l / &r)));
+                    if lhs == i256::MIN && rhs == i256::from(-1) {
+                        // as for `i128`, `MIN % -1` overflows
+                        assert_eq!(lhs.checked_rem(rhs), None);
+                    } else {
+                        assert_eq!(lhs.checked_rem(rhs), from_big(&(&l % &r)));
+                    }
+                }
+                assert_eq!(lhs.cmp(&rhs), l.cmp(&r));
+            }
+            assert_eq!(lhs.checked_neg(), from_big(&-to_big(lhs)));
+        }
+
+        let a = i256::from(7);
+        let b = i256::from(-2);
+        assert_eq!(a + b, i256::from(5));
+        assert_eq!(a - b, i256::from(9));
+        assert_eq!(a * b, i256::from(-14));
+        assert_eq!(a / b, i256::from(-3));
+        assert_eq!(a % b, i256::from(1));
+        assert_eq!(-a, i256::from(-7));
+        assert_eq!(b.abs(), i256::from(2));
+        assert_eq!(b.signum(), i256::from(-1));
+    }
+
+    #[test]
+    fn pow() {
+        let ten = i256::from(10);
+        assert_eq!(ten.pow(76).to_string(), format!("1{}", "0".repeat(76)));
+        assert_eq!(ten.checked_pow(76), Some(ten.pow(76)));
+        assert_eq!(ten.checked_pow(77), None);
+        assert_eq!(i256::from(-2).checked_pow(255), Some(i256::MIN));
+        assert_eq!(i256::from(2).checked_pow(255), None);
+        assert_eq!(ten.checked_pow(0), Some(i256::from(1)));
+    }
+
+    #[test]
+    fn words() {
+        let value = i256::from_words(-5, 7);
+        assert_eq!(value.into_words(), (-5, 7));
+        assert_eq!(i128::try_from(i256::from(-5)), Ok(-5));
+        assert!(i128::try_from(value).is_err());
+    }
+}
@@ -0,0 +1,107 @@
+use arrow2::util::bitpack::*;
+
+fn round_trip<T: Unpackable>(values: &[T], num_bits: usize) {
+    let mut packed = vec![0; packed_len(values.len(), num_bits)];
+    pack(values, num_bits, &mut packed);
+
+    let mut unpacked = vec![T::default(); values.len()];
+    unpack(&packed, num_bits, &mut unpacked);
+    assert_eq!(unpacked, values, "{num_bits} bits");
+}
+
+#[test]
+fn basics() {
+    let mut packed = [0; 2];
+    pack(&[1u8, 2, 3, 4], 3, &mut packed);
+    // 001 | 010 | 011 | 100, least-significant bit first
+    assert_eq!(packed, [0b11010001, 0b1000]);
+
+    let mut values = [0u8; 4];
+    unpack(&packed, 3, &mut values);
+    assert_eq!(values, [1, 2, 3, 4]);
+}
+
+#[test]
+fn all_widths() {
+    for num_bits in 0..=8 {
+        let values = (0..100u64)
+            .map(|x| (x * 7) as u8 & ((1u16 << num_bits) - 1) as u8)
+            .collect::<Vec<_>>();
+        round_trip(&values, num_bits);
+    }
+    for num_bits in 0..=16 {
+        let values = (0..100u64)
+            .map(|x| (x * 1031) as u16 & ((1u32 << num_bits) - 1) as u16)
+            .collect::<Vec<_>>();
+        round_trip(&values, num_bits);
+    }
+    for num_bits in 0..=32 {
+        let values = (0..100u64)
+            .map(|x| (x * 2_654_435_761) as u32 & ((1u64 << num_bits) - 1) as u32)
+            .collect::<Vec<_>>();
+        round_trip(&values, num_bits);
+    }
+    for num_bits in 0..=64 {
+        let mask = if num_bits == 64 {
+            u64::MAX
+        } else {
+            (1 << num_bits) - 1
+        };
+        let values = (0..100u64)
+            .map(|x| x.wrapping_mul(0x9E37_79B9_7F4A_7C15) & mask)
+            .collect::<Vec<_>>();
+        round_trip(&values, num_bits);
+    }
+}
+
+#[test]
+fn truncates() {
+    let mut packed = [0; 1];
+    pack(&[0b1111u32, 0b0110], 2, &mut packed);
+    assert_eq!(packed, [0b1011]);
+}
+
+#[test]
+#[should_panic]
+fn too_many_bits() {
+    pack(&[1u8], 9, &mut [0; 2]);
+}
+
+#[test]
+#[should_panic]
+fn too_small() {
+    unpack(&[0; 1], 3, &mut [0u32; 3]);
+}
+
+#[test]
+fn widths() {
+    assert_eq!(bit_width(0), 0);
+    assert_eq!(bit_width(1), 1);
+    assert_eq!(bit_width(255), 8);
+    assert_eq!(bit_width(256), 9);
+    assert_eq!(bit_width(u64::MAX), 64);
+    assert_eq!(packed_len(3, 3), 2);
+    assert_eq!(packed_len(8, 1), 1);
+}
+
+#[test]
+fn delta() {
+    let mut values = [5u16, 3, 3, 10];
+    delta_encode(&mut values);
+    assert_eq!(values, [5, u16::MAX - 1, 0, 7]);
+    delta_decode(&mut values);
+    assert_eq!(values, [5, 3, 3, 10]);
+
+    let mut empty: [u64; 0] = [];
+    delta_encode(&mut empty);
+    delta_decode(&mut empty);
+}
+
+#[test]
+fn zigzag() {
+    for (value, expected) in [(0, 0), (-1, 1), (1, 2), (-2, 3), (i64::MAX, u64::MAX - 1)] {
+        assert_eq!(zigzag_encode(value), expected);
+        assert_eq!(zigzag_decode(expected), value);
+    }
+    assert_eq!(zigzag_decode(zigzag_encode(i64::MIN)), i64::MIN);
+}
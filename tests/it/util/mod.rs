@@ -0,0 +1,3 @@
+mod bitpack;
+#[cfg(feature = "benchmarks")]
+mod random;
@@ -0,0 +1,123 @@
+use arrow2::array::*;
+use arrow2::datatypes::*;
+use arrow2::error::Result;
+use arrow2::util::random::*;
+
+fn data_types() -> Vec<DataType> {
+    let item = |data_type: DataType| Box::new(Field::new("item", data_type, true));
+    vec![
+        DataType::Null,
+        DataType::Boolean,
+        DataType::Int8,
+        DataType::UInt64,
+        DataType::Float16,
+        DataType::Float64,
+        DataType::Date64,
+        DataType::Timestamp(TimeUnit::Nanosecond, Some("+01:00".to_string())),
+        DataType::Time64(TimeUnit::Microsecond),
+        DataType::Duration(TimeUnit::Millisecond),
+        DataType::Interval(IntervalUnit::MonthDayNano),
+        DataType::Decimal(5, 2),
+        DataType::Decimal256(50, 2),
+        DataType::LargeUtf8,
+        DataType::Binary,
+        DataType::FixedSizeBinary(3),
+        DataType::List(item(DataType::Utf8)),
+        DataType::LargeList(item(DataType::Int32)),
+        DataType::FixedSizeList(item(DataType::Float32), 4),
+        DataType::Struct(vec![
+            Field::new("a", DataType::Int16, false),
+            Field::new("b", DataType::List(item(DataType::Boolean)), true),
+        ]),
+        DataType::Map(
+            Box::new(Field::new(
+                "entries",
+                DataType::Struct(vec![
+                    Field::new("key", DataType::Utf8, false),
+                    Field::new("value", DataType::Int64, true),
+                ]),
+                false,
+            )),
+            false,
+        ),
+        DataType::Dictionary(IntegerType::Int8, Box::new(DataType::Utf8), false),
+        DataType::Union(
+            vec![
+                Field::new("a", DataType::Int32, true),
+                Field::new("b", DataType::Utf8, true),
+            ],
+            Some(vec![3, 5]),
+            UnionMode::Dense,
+        ),
+        DataType::Union(
+            vec![
+                Field::new("a", DataType::Int32, true),
+                Field::new("b", DataType::Utf8, true),
+            ],
+            None,
+            UnionMode::Sparse,
+        ),
+        DataType::Extension("ext".to_string(), Box::new(DataType::Int32), None),
+    ]
+}
+
+#[test]
+fn random_arrays() -> Result<()> {
+    for data_type in data_types() {
+        let array = random_array(&data_type, 100, 0.5, &mut seedable_rng(0))?;
+        assert_eq!(array.len(), 100);
+        assert_eq!(array.data_type(), &data_type);
+        if !matches!(data_type, DataType::Null | DataType::Union(..)) {
+            assert!(array.null_count() > 0 && array.null_count() < 100);
+        }
+
+        // the same seed generates the same array
+        let other = random_array(&data_type, 100, 0.5, &mut seedable_rng(0))?;
+        assert_eq!(array, other);
+    }
+    Ok(())
+}
+
+#[test]
+fn non_nullable_children() -> Result<()> {
+    let data_type = DataType::Struct(vec![Field::new("a", DataType::Int16, false)]);
+    let array = random_array(&data_type, 100, 1.0, &mut seedable_rng(0))?;
+    let array = array.as_any().downcast_ref::<StructArray>().unwrap();
+    assert_eq!(array.null_count(), 100);
+    assert_eq!(array.values()[0].null_count(), 0);
+    Ok(())
+}
+
+#[test]
+fn decimals_fit_in_precision() -> Result<()> {
+    let array = random_array(&DataType::Decimal(3, 0), 1000, 0.0, &mut seedable_rng(1))?;
+    let array = array.as_any().downcast_ref::<Int128Array>().unwrap();
+    assert!(array.values().iter().all(|x| x.abs() < 1000));
+    Ok(())
+}
+
+#[cfg(feature = "io_ipc")]
+#[test]
+fn ipc_round_trip() -> Result<()> {
+    use arrow2::io::ipc::{read, write};
+
+    let fields = data_types()
+        .into_iter()
+        .enumerate()
+        .map(|(i, data_type)| Field::new(format!("c{i}"), data_type, true))
+        .collect::<Vec<_>>();
+    let schema = Schema::from(fields);
+    let chunk = random_chunk(&schema, 50, 0.2, 7)?;
+
+    let mut writer = write::FileWriter::try_new(vec![], schema, None, Default::default())?;
+    writer.write(&chunk, None)?;
+    writer.finish()?;
+    let data = writer.into_inner();
+
+    let mut reader = std::io::Cursor::new(data);
+    let metadata = read::read_file_metadata(&mut reader)?;
+    let reader = read::FileReader::new(reader, metadata, None, None);
+    let chunks = reader.collect::<Result<Vec<_>>>()?;
+    assert_eq!(chunks, vec![chunk]);
+    Ok(())
+}